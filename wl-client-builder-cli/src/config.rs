@@ -0,0 +1,153 @@
+use {
+    serde::Deserialize,
+    std::{
+        collections::BTreeMap,
+        io,
+        path::{Path, PathBuf},
+    },
+    thiserror::Error,
+    wl_client_builder::Builder,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The default name of the configuration file.
+pub const CONFIG_FILE: &str = "wl-client-builder.toml";
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Could not read {}", .0.display())]
+    Read(PathBuf, #[source] io::Error),
+    #[error("Could not parse {}", .0.display())]
+    Parse(PathBuf, #[source] toml::de::Error),
+    #[error("Could not generate {}", .0.display())]
+    Generate(PathBuf, #[source] wl_client_builder::Error),
+}
+
+/// The contents of a `wl-client-builder.toml` file.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    /// Formats the generated code with rustfmt.
+    ///
+    /// This is either `true` or the rustfmt command, for example
+    /// `["rustfmt", "+nightly", "--edition", "2024"]`.
+    #[serde(default)]
+    rustfmt: Option<Rustfmt>,
+    /// The directories to generate code into.
+    #[serde(default, rename = "target")]
+    pub targets: Vec<Target>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Rustfmt {
+    Enabled(bool),
+    Command(Vec<String>),
+}
+
+/// A directory to generate code into.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Target {
+    pub out_dir: PathBuf,
+    #[serde(default)]
+    xml_files: Vec<PathBuf>,
+    #[serde(default)]
+    xml_dirs: Vec<PathBuf>,
+    #[serde(default)]
+    system_protocols: Vec<String>,
+    #[serde(default)]
+    system_protocol_files: Vec<PathBuf>,
+    #[serde(default)]
+    wl_client_path: Option<String>,
+    /// Deprecated. Event handlers with mutable data are always generated.
+    #[serde(default)]
+    mutable_data: Option<bool>,
+    #[serde(default)]
+    protocol_features: bool,
+    #[serde(default)]
+    libwayland_interfaces: bool,
+    #[serde(default)]
+    prefer_protocols: BTreeMap<String, String>,
+    #[serde(default)]
+    extern_protocols: BTreeMap<String, String>,
+    #[serde(default)]
+    export_interface_symbols: Vec<String>,
+    #[serde(default)]
+    only_interfaces: Vec<String>,
+    #[serde(default)]
+    only_protocols: Vec<String>,
+}
+
+impl Config {
+    /// Loads a configuration file.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => return Err(ConfigError::Read(path.to_owned(), e)),
+        };
+        toml::from_str(&contents).map_err(|e| ConfigError::Parse(path.to_owned(), e))
+    }
+
+    /// Returns the rustfmt command, if any.
+    fn rustfmt(&self) -> Option<Vec<String>> {
+        match self.rustfmt.as_ref()? {
+            Rustfmt::Enabled(false) => None,
+            Rustfmt::Enabled(true) => Some(vec![
+                "rustfmt".to_string(),
+                "--edition".to_string(),
+                "2024".to_string(),
+            ]),
+            Rustfmt::Command(command) => Some(command.clone()),
+        }
+    }
+}
+
+impl Target {
+    /// Returns whether the target sets the deprecated `mutable-data` key.
+    pub fn sets_mutable_data(&self) -> bool {
+        self.mutable_data.is_some()
+    }
+
+    /// Applies the target to a builder.
+    ///
+    /// Relative paths are interpreted relative to `base`, the directory containing the
+    /// configuration file.
+    pub fn apply(&self, config: &Config, base: &Path, mut builder: Builder) -> Builder {
+        for dir in &self.xml_dirs {
+            builder = builder.xml_dir(base.join(dir));
+        }
+        for file in &self.xml_files {
+            builder = builder.xml_file(base.join(file));
+        }
+        for name in &self.system_protocols {
+            builder = builder.system_protocol(name);
+        }
+        for file in &self.system_protocol_files {
+            builder = builder.system_protocol_file(file);
+        }
+        if let Some(path) = &self.wl_client_path {
+            builder = builder.wl_client_path(path);
+        }
+        for (interface, protocol) in &self.prefer_protocols {
+            builder = builder.prefer_protocol(interface, protocol);
+        }
+        for (protocol, path) in &self.extern_protocols {
+            builder = builder.extern_protocol(protocol, path);
+        }
+        for protocol in &self.export_interface_symbols {
+            builder = builder.export_interface_symbols(protocol);
+        }
+        if let Some(command) = config.rustfmt() {
+            builder = builder.rustfmt(command);
+        }
+        builder
+            .target_dir(base.join(&self.out_dir))
+            .with_protocol_features(self.protocol_features)
+            .with_libwayland_interfaces(self.libwayland_interfaces)
+            .only_interfaces(&self.only_interfaces)
+            .only_protocols(&self.only_protocols)
+    }
+}
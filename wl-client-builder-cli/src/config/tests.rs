@@ -0,0 +1,245 @@
+use {
+    crate::config::{Config, ConfigError},
+    std::{
+        collections::BTreeMap,
+        error::Error,
+        fs,
+        path::{Path, PathBuf},
+    },
+    wl_client_builder::Builder,
+};
+
+fn parse(toml: &str) -> Config {
+    toml::from_str(toml).unwrap()
+}
+
+fn parse_error(toml: &str) -> String {
+    toml::from_str::<Config>(toml).unwrap_err().to_string()
+}
+
+fn map(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+    entries
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn all_keys() {
+    let config = parse(
+        r#"
+            rustfmt = ["rustfmt", "+nightly"]
+
+            [[target]]
+            out-dir = "out"
+            xml-files = ["a.xml", "b.xml"]
+            xml-dirs = ["protocols"]
+            system-protocols = ["xdg-shell"]
+            system-protocol-files = ["stable/viewporter/viewporter.xml"]
+            wl-client-path = "crate"
+            mutable-data = true
+            protocol-features = true
+            libwayland-interfaces = true
+            prefer-protocols = { zwp_tablet_tool_v2 = "tablet_v2" }
+            extern-protocols = { wayland = "::other::wayland" }
+            export-interface-symbols = ["private"]
+            only-interfaces = ["wl_compositor"]
+            only-protocols = ["xdg_shell"]
+
+            [[target]]
+            out-dir = "out2"
+        "#,
+    );
+    assert_eq!(
+        config.rustfmt(),
+        Some(vec!["rustfmt".to_string(), "+nightly".to_string()]),
+    );
+    assert_eq!(config.targets.len(), 2);
+    let target = &config.targets[0];
+    assert_eq!(target.out_dir, Path::new("out"));
+    assert_eq!(
+        target.xml_files,
+        [PathBuf::from("a.xml"), PathBuf::from("b.xml")],
+    );
+    assert_eq!(target.xml_dirs, [PathBuf::from("protocols")]);
+    assert_eq!(target.system_protocols, ["xdg-shell"]);
+    assert_eq!(
+        target.system_protocol_files,
+        [PathBuf::from("stable/viewporter/viewporter.xml")],
+    );
+    assert_eq!(target.wl_client_path.as_deref(), Some("crate"));
+    assert!(target.sets_mutable_data());
+    assert!(target.protocol_features);
+    assert!(target.libwayland_interfaces);
+    assert_eq!(
+        target.prefer_protocols,
+        map(&[("zwp_tablet_tool_v2", "tablet_v2")]),
+    );
+    assert_eq!(
+        target.extern_protocols,
+        map(&[("wayland", "::other::wayland")]),
+    );
+    assert_eq!(target.export_interface_symbols, ["private"]);
+    assert_eq!(target.only_interfaces, ["wl_compositor"]);
+    assert_eq!(target.only_protocols, ["xdg_shell"]);
+}
+
+#[test]
+fn defaults() {
+    let config = parse(
+        r#"
+            [[target]]
+            out-dir = "out"
+        "#,
+    );
+    assert_eq!(config.rustfmt(), None);
+    let target = &config.targets[0];
+    assert!(target.xml_files.is_empty());
+    assert!(target.xml_dirs.is_empty());
+    assert!(target.system_protocols.is_empty());
+    assert!(target.system_protocol_files.is_empty());
+    assert_eq!(target.wl_client_path, None);
+    assert!(!target.sets_mutable_data());
+    assert!(!target.protocol_features);
+    assert!(!target.libwayland_interfaces);
+    assert!(target.prefer_protocols.is_empty());
+    assert!(target.extern_protocols.is_empty());
+    assert!(target.export_interface_symbols.is_empty());
+    assert!(target.only_interfaces.is_empty());
+    assert!(target.only_protocols.is_empty());
+    assert!(parse("").targets.is_empty());
+}
+
+#[test]
+fn rustfmt() {
+    let default = vec![
+        "rustfmt".to_string(),
+        "--edition".to_string(),
+        "2024".to_string(),
+    ];
+    assert_eq!(parse("rustfmt = true").rustfmt(), Some(default));
+    assert_eq!(parse("rustfmt = false").rustfmt(), None);
+    assert_eq!(
+        parse(r#"rustfmt = ["fmt"]"#).rustfmt(),
+        Some(vec!["fmt".to_string()]),
+    );
+}
+
+#[test]
+fn invalid() {
+    let err = parse_error("unknown = 1");
+    assert!(err.contains("unknown field `unknown`"), "{err}");
+    let err = parse_error(
+        r#"
+            [[target]]
+            out-dir = "out"
+            unknown = 1
+        "#,
+    );
+    assert!(err.contains("unknown field `unknown`"), "{err}");
+    let err = parse_error(
+        r#"
+            [[target]]
+            xml-files = ["a.xml"]
+        "#,
+    );
+    assert!(err.contains("missing field `out-dir`"), "{err}");
+    let err = parse_error(
+        r#"
+            [[target]]
+            out-dir = "out"
+            protocol-features = "yes"
+        "#,
+    );
+    assert!(err.contains("invalid type"), "{err}");
+    let err = parse_error(r#"rustfmt = "rustfmt""#);
+    assert!(err.contains("rustfmt"), "{err}");
+}
+
+#[test]
+fn load() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("wl-client-builder.toml");
+
+    let err = Config::load(&path).unwrap_err();
+    assert!(matches!(err, ConfigError::Read(..)));
+    assert_eq!(
+        err.to_string(),
+        format!("Could not read {}", path.display())
+    );
+
+    fs::write(&path, "[[target]]\n").unwrap();
+    let err = Config::load(&path).unwrap_err();
+    assert!(matches!(err, ConfigError::Parse(..)));
+    assert_eq!(
+        err.to_string(),
+        format!("Could not parse {}", path.display())
+    );
+    assert!(err.source().is_some());
+
+    fs::write(&path, "[[target]]\nout-dir = \"out\"\n").unwrap();
+    let config = Config::load(&path).unwrap();
+    assert_eq!(config.targets[0].out_dir, Path::new("out"));
+}
+
+const CORE: &str = r#"
+    <protocol name="core">
+        <interface name="wl_surface" version="1"/>
+    </protocol>
+"#;
+
+const USER: &str = r#"
+    <protocol name="user">
+        <interface name="user" version="1">
+            <request name="set_surface">
+                <arg name="surface" type="object" interface="wl_surface"/>
+            </request>
+        </interface>
+        <interface name="unused" version="1"/>
+    </protocol>
+"#;
+
+#[test]
+fn apply() {
+    let dir = tempfile::tempdir().unwrap();
+    let base = dir.path();
+    fs::create_dir(base.join("protocols")).unwrap();
+    fs::write(base.join("protocols/user.xml"), USER).unwrap();
+    fs::write(base.join("core.xml"), CORE).unwrap();
+    let config = parse(
+        r#"
+            [[target]]
+            out-dir = "out"
+            xml-files = ["core.xml"]
+            xml-dirs = ["protocols"]
+            wl-client-path = "crate::wl"
+            protocol-features = true
+            extern-protocols = { core = "::other::core" }
+            only-interfaces = ["user"]
+        "#,
+    );
+    let build = |check: bool| {
+        config.targets[0]
+            .apply(&config, base, Builder::default())
+            .with_default_dir(false)
+            .for_build_rs(false)
+            .check(check)
+            .build()
+    };
+    build(false).unwrap();
+    let out = base.join("out");
+    assert!(out.join("user/user.rs").exists());
+    assert!(!out.join("user/unused.rs").exists());
+    assert!(!out.join("core.rs").exists());
+    let features = fs::read_to_string(out.join("features.toml")).unwrap();
+    assert_eq!(features, "user = []\n");
+    let module = fs::read_to_string(out.join("mod.rs")).unwrap();
+    assert!(module.contains("crate::wl::info::ProtocolInfo"), "{module}");
+    assert!(
+        module.contains("pub(super) use ::other::core::wl_surface::WlSurface;"),
+        "{module}",
+    );
+    build(true).unwrap();
+    fs::write(out.join("mod.rs"), "").unwrap();
+    assert!(build(true).is_err());
+}
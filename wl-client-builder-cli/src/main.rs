@@ -1,29 +1,204 @@
 use {
-    clap::{Parser, ValueHint},
+    crate::config::{CONFIG_FILE, Config, ConfigError},
+    clap::{Args, Parser, Subcommand, ValueEnum, ValueHint},
     error_reporter::Report,
-    std::path::PathBuf,
-    wl_client_builder::Builder,
+    std::{
+        error::Error,
+        io::{self, Read},
+        path::{Path, PathBuf},
+    },
+    thiserror::Error,
+    wl_client_builder::{Builder, Side},
 };
 
+mod config;
+
 /// Generate safe protocol wrappers for the `wl-client` crate.
 #[derive(Parser, Debug)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-    /// Enables mutable data parameters.
+    #[clap(subcommand)]
+    command: Option<Command>,
+    /// Deprecated. Has no effect.
     ///
-    /// If this flag is enabled, event handlers take an additional parameter that gives
-    /// access to mutable data that was passed in when dispatching a queue.
-    #[clap(long)]
+    /// Event handlers with mutable data are always generated.
+    #[clap(long, hide = true)]
     mutable_data: bool,
+    /// Puts each protocol behind a cargo feature with the same name.
+    ///
+    /// If this flag is enabled, a `features.toml` file is written into the output
+    /// directory. It contains the feature definitions, including dependencies between
+    /// protocols, that should be copied into the `[features]` section of the
+    /// `Cargo.toml`.
+    #[clap(long)]
+    protocol_features: bool,
+    /// Uses libwayland-client's definitions of the core wayland interfaces.
+    ///
+    /// If this flag is enabled, the core interfaces of the `wayland` protocol use the
+    /// `wl_*_interface` symbols of libwayland-client instead of their own tables. The
+    /// symbols are looked up at runtime through `Libwayland`.
+    #[clap(long)]
+    libwayland_interfaces: bool,
+    /// Checks that the generated code in the output directory is up to date.
+    ///
+    /// If this flag is enabled, the output directory is not modified. Instead, the
+    /// application exits with a non-zero exit code if any file would be added, changed,
+    /// or removed.
+    #[clap(long)]
+    check: bool,
     /// The directory to generate code into.
     ///
     /// The generated code will be available via the `mod.rs` file in this directory.
     ///
-    /// The directory will be created if it does not already exist. Files that already
-    /// exist in the directory will not be removed but might be overwritten. You should
-    /// remove the directory before generating code if you want to ensure that it only
-    /// contains required files.
-    #[clap(value_hint = ValueHint::DirPath)]
-    out_dir: PathBuf,
+    /// The directory will be created if it does not already exist. Files are only
+    /// written if their contents change. A `.wl-client-builder-manifest` file in the
+    /// directory lists all generated files. Files that were generated previously but are
+    /// no longer generated are removed. Other files in the directory are left untouched.
+    #[clap(required = true, value_hint = ValueHint::DirPath)]
+    out_dir: Option<PathBuf>,
+    #[clap(flatten)]
+    xml: XmlArgs,
+    /// The rust module path to the `wl-client` crate.
+    ///
+    /// By default, the generated code assumes that the crate can be accessed via
+    /// `::wl_client`. If your crate imports the crate under a different name, the path
+    /// can be modified with this parameter.
+    #[clap(long)]
+    wl_client_path: Option<String>,
+    /// Selects the protocol whose definition of an interface is used by other protocols.
+    ///
+    /// The value has the form `INTERFACE=PROTOCOL`. This is required if an interface
+    /// that is defined by multiple protocols is used by another protocol.
+    #[clap(long, value_name = "INTERFACE=PROTOCOL", value_parser = parse_key_value)]
+    prefer_protocol: Vec<(String, String)>,
+    /// Uses the code of a protocol that was generated by another crate.
+    ///
+    /// The value has the form `PROTOCOL=PATH` where `PATH` is the absolute rust module
+    /// path of the generated protocol module, for example
+    /// `wayland=::crate_a::protocols::wayland`. The XML file of the protocol must still
+    /// be added, but no code is generated for it.
+    #[clap(long, value_name = "PROTOCOL=PATH", value_parser = parse_key_value)]
+    extern_protocol: Vec<(String, String)>,
+    /// Exports the `wl_interface` tables of this protocol as C symbols.
+    ///
+    /// Each interface `name` of the protocol gets a `#[unsafe(no_mangle)]` static
+    /// `{name}_interface`, the symbol that code generated by `wayland-scanner` expects.
+    /// Each protocol should only be exported by a single crate per process.
+    #[clap(long, value_name = "PROTOCOL")]
+    export_interface_symbols: Vec<String>,
+    /// Only generates code for this interface and the interfaces it uses.
+    ///
+    /// If this or `--only-protocol` is specified, code is only generated for the
+    /// selected interfaces and all interfaces that are reachable from them via object
+    /// arguments, `new_id` arguments, or enums.
+    #[clap(long, value_name = "INTERFACE")]
+    only_interface: Vec<String>,
+    /// Only generates code for the interfaces of this protocol and the interfaces they
+    /// use.
+    #[clap(long, value_name = "PROTOCOL")]
+    only_protocol: Vec<String>,
+    /// Prints why each interface is generated.
+    ///
+    /// This only has an effect if `--only-interface` or `--only-protocol` is specified.
+    #[clap(long)]
+    explain: bool,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generate code for all targets in a configuration file.
+    ///
+    /// The configuration file is a TOML file that contains one `[[target]]` table per
+    /// output directory. Relative paths in the file are relative to the directory
+    /// containing the file.
+    Generate {
+        /// The path of the configuration file.
+        #[clap(long, default_value = CONFIG_FILE, value_hint = ValueHint::FilePath)]
+        config: PathBuf,
+        /// Checks that the generated code of all targets is up to date.
+        ///
+        /// If this flag is enabled, no files are modified. Instead, the application exits
+        /// with a non-zero exit code if any file would be added, changed, or removed.
+        #[clap(long)]
+        check: bool,
+    },
+    /// Check protocol XML files for problems.
+    ///
+    /// This reports files that cannot be parsed, references to undefined interfaces and
+    /// enums, invalid `since` and `deprecated-since` values, duplicate names, and invalid
+    /// `allow-null` attributes. Each problem is printed as `FILE:LINE:COLUMN: MESSAGE`.
+    ///
+    /// The application exits with a non-zero exit code if any problems are found.
+    Lint {
+        #[clap(flatten)]
+        xml: XmlArgs,
+    },
+    /// Compare two versions of a protocol XML file.
+    ///
+    /// This reports added and removed protocols, interfaces, messages, arguments, enums,
+    /// and entries, as well as changed opcodes, argument types, enum values, and
+    /// interface versions. Each change is classified as compatible or breaking.
+    ///
+    /// The application exits with a non-zero exit code if any change is breaking.
+    Diff {
+        /// Prints the changes as JSON.
+        #[clap(long)]
+        json: bool,
+        /// The old version of the XML file.
+        #[clap(value_hint = ValueHint::FilePath)]
+        old: PathBuf,
+        /// The new version of the XML file.
+        #[clap(value_hint = ValueHint::FilePath)]
+        new: PathBuf,
+    },
+    /// Decode a `WAYLAND_DEBUG=1` log or a raw wire capture.
+    ///
+    /// Each message is printed in the format used by libwayland with the name of each
+    /// argument in front of its value. Enum values are replaced by the names of their
+    /// entries. The interfaces of objects are tracked by following the `new_id`
+    /// arguments of the messages. Lines of a debug log that do not contain a message are
+    /// printed unchanged.
+    Decode {
+        #[clap(flatten)]
+        xml: XmlArgs,
+        /// Selects the protocol whose definition of an interface is used.
+        ///
+        /// The value has the form `INTERFACE=PROTOCOL`. If an interface is defined by
+        /// multiple protocols and no protocol is selected, the first definition is used.
+        #[clap(long, value_name = "INTERFACE=PROTOCOL", value_parser = parse_key_value)]
+        prefer_protocol: Vec<(String, String)>,
+        /// Treats the input as the raw bytes written to the wayland socket.
+        ///
+        /// The input must start at a message boundary. File descriptors are sent out of
+        /// band and are printed as `fd`.
+        #[clap(long)]
+        wire: bool,
+        /// The side of the connection that produced the input.
+        ///
+        /// For debug logs, this is the process that ran with `WAYLAND_DEBUG=1`. For wire
+        /// captures, this is the process that wrote the bytes.
+        #[clap(long, value_enum, default_value_t = SideArg::Client)]
+        side: SideArg,
+        /// The file to decode. `-` reads from stdin.
+        #[clap(value_hint = ValueHint::FilePath)]
+        input: PathBuf,
+    },
+}
+
+#[derive(ValueEnum, Copy, Clone, Debug)]
+enum SideArg {
+    Client,
+    Server,
+}
+
+#[derive(Debug, Error)]
+enum DecodeError {
+    #[error("Could not read {}", .0.display())]
+    ReadInput(PathBuf, #[source] io::Error),
+}
+
+#[derive(Args, Debug)]
+struct XmlArgs {
     /// The path to an XML protocol file.
     #[clap(long, value_hint = ValueHint::FilePath)]
     xml_file: Vec<PathBuf>,
@@ -33,33 +208,227 @@ struct Cli {
     /// had been specified explicitly via `--xml-file` arguments.
     #[clap(long, value_hint = ValueHint::DirPath)]
     xml_dir: Vec<PathBuf>,
-    /// The rust module path to the `wl-client` crate.
+    /// The name of a protocol installed on the system.
     ///
-    /// By default, the generated code assumes that the crate can be accessed via
-    /// `::wl_client`. If your crate imports the crate under a different name, the path
-    /// can be modified with this parameter.
-    #[clap(long)]
-    wl_client_path: Option<String>,
+    /// The XML file is located via pkg-config. The name is either the name of an XML
+    /// file without the `.xml` extension, e.g. `linux-dmabuf-v1`, or the name of a
+    /// protocol directory of `wayland-protocols` that contains a single XML file, e.g.
+    /// `xdg-shell`. `wayland` refers to the `wayland.xml` installed by `wayland-scanner`.
+    #[clap(long, value_name = "NAME")]
+    system_protocol: Vec<String>,
+    /// The path of a protocol XML file installed on the system.
+    ///
+    /// The path is relative to the `pkgdatadir` of `wayland-protocols` or
+    /// `wayland-scanner`, e.g. `stable/xdg-shell/xdg-shell.xml`.
+    #[clap(long, value_name = "PATH")]
+    system_protocol_file: Vec<PathBuf>,
+}
+
+impl XmlArgs {
+    fn apply(&self, mut builder: Builder) -> Builder {
+        for dir in &self.xml_dir {
+            builder = builder.xml_dir(dir);
+        }
+        for file in &self.xml_file {
+            builder = builder.xml_file(file);
+        }
+        for name in &self.system_protocol {
+            builder = builder.system_protocol(name);
+        }
+        for file in &self.system_protocol_file {
+            builder = builder.system_protocol_file(file);
+        }
+        builder
+    }
+}
+
+fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) => Ok((key.to_string(), value.to_string())),
+        None => Err("expected a value of the form KEY=VALUE".to_string()),
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
-    let mut builder = Builder::default()
-        .with_mutable_data(cli.mutable_data)
+    let builder = Builder::default()
         .with_default_dir(false)
-        .for_build_rs(false)
-        .target_dir(&cli.out_dir);
-    for dir in &cli.xml_dir {
-        builder = builder.xml_dir(dir);
+        .for_build_rs(false);
+    match &cli.command {
+        Some(Command::Lint { xml }) => lint(xml.apply(builder)),
+        Some(Command::Generate { config, check }) => generate_config(config, *check),
+        Some(Command::Diff { json, old, new }) => diff(*json, old, new),
+        Some(Command::Decode {
+            xml,
+            prefer_protocol,
+            wire,
+            side,
+            input,
+        }) => {
+            let mut builder = xml.apply(builder);
+            for (interface, protocol) in prefer_protocol {
+                builder = builder.prefer_protocol(interface, protocol);
+            }
+            decode(builder, *wire, *side, input)
+        }
+        None => generate(&cli, builder),
+    }
+}
+
+fn lint(builder: Builder) {
+    let diagnostics = match builder.lint() {
+        Ok(d) => d,
+        Err(e) => fail(e),
+    };
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+    if !diagnostics.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn diff(json: bool, old: &Path, new: &Path) {
+    let changes = match wl_client_builder::diff(old, new) {
+        Ok(c) => c,
+        Err(e) => fail(e),
+    };
+    let breaking = changes.iter().any(|c| c.breaking);
+    if json {
+        let changes: Vec<_> = changes
+            .iter()
+            .map(|c| {
+                serde_json::json!({
+                    "kind": c.kind.name(),
+                    "breaking": c.breaking,
+                    "path": c.path,
+                    "description": c.description,
+                })
+            })
+            .collect();
+        let output = serde_json::json!({
+            "breaking": breaking,
+            "changes": changes,
+        });
+        println!("{output:#}");
+    } else {
+        for change in &changes {
+            println!("{change}");
+        }
+    }
+    if breaking {
+        std::process::exit(1);
+    }
+}
+
+fn decode(builder: Builder, wire: bool, side: SideArg, input: &Path) {
+    let mut decoder = match builder.decoder() {
+        Ok(d) => d,
+        Err(e) => fail(e),
+    };
+    let side = match side {
+        SideArg::Client => Side::Client,
+        SideArg::Server => Side::Server,
+    };
+    let data = match input == Path::new("-") {
+        true => {
+            let mut data = vec![];
+            io::stdin().read_to_end(&mut data).map(|_| data)
+        }
+        false => std::fs::read(input),
+    };
+    let data = match data {
+        Ok(d) => d,
+        Err(e) => fail(DecodeError::ReadInput(input.to_path_buf(), e)),
+    };
+    if wire {
+        if let Err(e) = decoder.decode_wire(side, &data, |msg| println!("{msg}")) {
+            fail(e);
+        }
+    } else {
+        for line in String::from_utf8_lossy(&data).lines() {
+            match decoder.decode_debug_line(side, line) {
+                Some(msg) => println!("{msg}"),
+                None => println!("{line}"),
+            }
+        }
+    }
+}
+
+fn generate(cli: &Cli, builder: Builder) {
+    if cli.mutable_data {
+        eprintln!(
+            "Warning: --mutable-data is deprecated and has no effect. Event handlers with \
+            mutable data are always generated."
+        );
     }
-    for file in &cli.xml_file {
-        builder = builder.xml_file(file);
+    let mut builder = cli
+        .xml
+        .apply(builder)
+        .with_protocol_features(cli.protocol_features)
+        .with_libwayland_interfaces(cli.libwayland_interfaces)
+        .check(cli.check);
+    if let Some(out_dir) = &cli.out_dir {
+        builder = builder.target_dir(out_dir);
     }
     if let Some(pf) = &cli.wl_client_path {
         builder = builder.wl_client_path(pf);
     }
+    for (interface, protocol) in &cli.prefer_protocol {
+        builder = builder.prefer_protocol(interface, protocol);
+    }
+    for (protocol, path) in &cli.extern_protocol {
+        builder = builder.extern_protocol(protocol, path);
+    }
+    for protocol in &cli.export_interface_symbols {
+        builder = builder.export_interface_symbols(protocol);
+    }
+    builder = builder
+        .only_interfaces(&cli.only_interface)
+        .only_protocols(&cli.only_protocol);
+    if cli.explain {
+        builder = builder.explain(|line| println!("{line}"));
+    }
     if let Err(e) = builder.build() {
-        eprintln!("Error: {}", Report::new(e).pretty(true));
+        fail(e);
+    }
+}
+
+fn generate_config(path: &Path, check: bool) {
+    let config = match Config::load(path) {
+        Ok(c) => c,
+        Err(e) => fail(e),
+    };
+    let base = path.parent().unwrap_or(Path::new(""));
+    let mut failed = false;
+    for target in &config.targets {
+        if target.sets_mutable_data() {
+            eprintln!(
+                "Warning: {}: the `mutable-data` key is deprecated and has no effect",
+                path.display(),
+            );
+        }
+        let builder = target
+            .apply(&config, base, Builder::default())
+            .with_default_dir(false)
+            .for_build_rs(false)
+            .check(check);
+        if let Err(e) = builder.build() {
+            let out_dir = base.join(&target.out_dir);
+            report(ConfigError::Generate(out_dir, e));
+            failed = true;
+        }
+    }
+    if failed {
         std::process::exit(1);
     }
 }
+
+fn report(e: impl Error) {
+    eprintln!("Error: {}", Report::new(e).pretty(true));
+}
+
+fn fail(e: impl Error) -> ! {
+    report(e);
+    std::process::exit(1);
+}
@@ -1,4 +1,41 @@
+use std::fmt::{Display, Formatter};
+
+/// The byte offset of the start tag of an element in its XML file.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub(crate) struct Span(pub(crate) usize);
+
+/// A line and column in an XML file, both starting at 1.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Location {
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl Location {
+    pub(crate) fn new(source: &[u8], span: Span) -> Self {
+        let prefix = &source[..span.0.min(source.len())];
+        let line_start = match prefix.iter().rposition(|&c| c == b'\n') {
+            Some(pos) => pos + 1,
+            None => 0,
+        };
+        Self {
+            line: prefix.iter().filter(|&&c| c == b'\n').count() + 1,
+            column: String::from_utf8_lossy(&prefix[line_start..])
+                .chars()
+                .count()
+                + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 pub(crate) struct Protocol {
+    pub(crate) span: Span,
     pub(crate) name: String,
     pub(crate) _copyright: Option<Copyright>,
     pub(crate) description: Option<Description>,
@@ -16,6 +53,7 @@ pub(crate) struct Description {
 }
 
 pub(crate) struct Interface {
+    pub(crate) span: Span,
     pub(crate) name: String,
     pub(crate) version: u32,
     pub(crate) description: Option<Description>,
@@ -26,6 +64,7 @@ pub(crate) struct Interface {
 
 #[derive(Debug)]
 pub(crate) struct Arg {
+    pub(crate) span: Span,
     pub(crate) name: String,
     pub(crate) ty: ArgType,
     pub(crate) summary: Option<String>,
@@ -53,6 +92,7 @@ pub(crate) enum MessageType {
 }
 
 pub(crate) struct Entry {
+    pub(crate) span: Span,
     pub(crate) name: String,
     pub(crate) value: String,
     pub(crate) value_u32: u32,
@@ -63,14 +103,16 @@ pub(crate) struct Entry {
 }
 
 pub(crate) struct Enum {
+    pub(crate) span: Span,
     pub(crate) name: String,
-    pub(crate) _since: Option<u32>,
+    pub(crate) since: Option<u32>,
     pub(crate) bitfield: bool,
     pub(crate) description: Option<Description>,
     pub(crate) entries: Vec<Entry>,
 }
 
 pub(crate) struct Message {
+    pub(crate) span: Span,
     pub(crate) name: String,
     pub(crate) ty: Option<MessageType>,
     pub(crate) since: Option<u32>,
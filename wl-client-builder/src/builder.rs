@@ -1,17 +1,32 @@
 use {
     crate::{
-        formatter::{format_interface_file, format_mod_file, format_protocol_file},
+        ast::Location,
+        decode::Decoder,
+        formatter::{
+            InterfaceSymbol, format_features_file, format_interface_file, format_mod_file,
+            format_protocol_file,
+        },
+        namespace::{NamespaceError, closure, resolve},
         parser::{ParserError, parse},
+        system::{self, PkgDataDirs, SystemError, SystemProtocol},
+        validate::{Diagnostic, SourceFile, validate},
     },
     std::{
+        collections::{BTreeMap, BTreeSet, HashMap},
         env::VarError,
-        fs::File,
-        io::{self, BufWriter, Write},
-        path::{Path, PathBuf},
+        error::Error,
+        ffi::{OsStr, OsString},
+        io::{self, ErrorKind, Write},
+        mem,
+        path::{Component, Path, PathBuf},
+        process::{Command, Stdio},
     },
     thiserror::Error,
 };
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Error)]
 enum BuilderError {
     #[error("Could not read {}", .0.display())]
@@ -20,8 +35,8 @@ enum BuilderError {
     OpenDir(PathBuf, #[source] io::Error),
     #[error("Could not read from {}", .0.display())]
     ReadDir(PathBuf, #[source] io::Error),
-    #[error("Could not parse {}", .0.display())]
-    ParseFile(PathBuf, #[source] ParserError),
+    #[error("Could not parse {}:{}", .0.display(), .1)]
+    ParseFile(PathBuf, Location, #[source] ParserError),
     #[error("Could not format {}", .0.display())]
     FormatFile(PathBuf, #[source] io::Error),
     #[error("Could not determine OUT_DIR")]
@@ -30,17 +45,72 @@ enum BuilderError {
     CreateDir(PathBuf, #[source] io::Error),
     #[error("Could not open {} for writing", .0.display())]
     OpenFile(PathBuf, #[source] io::Error),
+    #[error("Protocol {} is defined in both {} and {}", .0, .1.display(), .2.display())]
+    DuplicateProtocol(String, PathBuf, PathBuf),
+    #[error("Extern protocol {0} is not defined by any XML file")]
+    UnknownExternProtocol(String),
+    #[error(
+        "Protocol {0} cannot export interface symbols because it is not defined by any XML file"
+    )]
+    UnknownExportedProtocol(String),
+    #[error("Protocol {0} cannot export interface symbols because it is an extern protocol")]
+    ExternExportedProtocol(String),
+    #[error(
+        "Protocol {1} cannot export the symbol {0}_interface because interface {0} is also defined by protocol {2}"
+    )]
+    DuplicateExportedInterface(String, String, String),
+    #[error(
+        "Protocol {1} cannot export the symbol {0}_interface because libwayland-client defines the same symbol"
+    )]
+    LibwaylandExportedInterface(String, String),
+    #[error("Could not remove {}", .0.display())]
+    RemoveFile(PathBuf, #[source] io::Error),
+    #[error(
+        "The generated code is out of date:{}",
+        .0.iter().map(|p| format!("\n  {}", p.display())).collect::<String>()
+    )]
+    OutOfDate(Vec<PathBuf>),
+    #[error("Could not resolve interface references")]
+    Namespace(#[source] NamespaceError),
+    #[error("Could not locate a system protocol")]
+    SystemProtocol(#[source] SystemError),
+    #[error("Could not run {} to format {}", .0.display(), .1.display())]
+    RunRustfmt(PathBuf, PathBuf, #[source] io::Error),
+    #[error("{} could not format {}: {}", .0.display(), .1.display(), .2)]
+    Rustfmt(PathBuf, PathBuf, String),
+}
+
+/// Code generated by [`Builder::build_inline`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct InlineCode {
+    /// The generated code.
+    pub code: String,
+    /// The XML files that were read to generate the code.
+    pub xml_files: Vec<PathBuf>,
 }
 
 /// A builder for `wl-client` wrappers.
 pub struct Builder {
     build_script: bool,
     add_default_dir: bool,
-    mutable_data: bool,
+    protocol_features: bool,
+    libwayland_interfaces: bool,
+    check: bool,
+    rustfmt: Option<Vec<OsString>>,
     target_dir: Option<PathBuf>,
     files: Vec<PathBuf>,
+    xml_strings: Vec<Vec<u8>>,
     dirs: Vec<PathBuf>,
+    system_protocols: Vec<SystemProtocol>,
     wl_client_path: Option<String>,
+    preferred_protocols: HashMap<String, String>,
+    extern_protocols: HashMap<String, String>,
+    exported_protocols: BTreeSet<String>,
+    only_protocols: BTreeSet<String>,
+    only_interfaces: BTreeSet<String>,
+    #[allow(clippy::type_complexity)]
+    explain: Option<Box<dyn FnMut(&str)>>,
 }
 
 impl Default for Builder {
@@ -48,11 +118,22 @@ impl Default for Builder {
         Self {
             build_script: true,
             add_default_dir: true,
-            mutable_data: false,
+            protocol_features: false,
+            libwayland_interfaces: false,
+            check: false,
+            rustfmt: None,
             target_dir: Default::default(),
             files: Default::default(),
+            xml_strings: Default::default(),
             dirs: Default::default(),
+            system_protocols: Default::default(),
             wl_client_path: None,
+            preferred_protocols: Default::default(),
+            extern_protocols: Default::default(),
+            exported_protocols: Default::default(),
+            only_protocols: Default::default(),
+            only_interfaces: Default::default(),
+            explain: None,
         }
     }
 }
@@ -73,6 +154,15 @@ impl Builder {
         self
     }
 
+    /// Adds the contents of a protocol XML file.
+    ///
+    /// This can be used for protocols that are not stored in a file, for example,
+    /// private protocols that are defined inline.
+    pub fn xml_string(mut self, xml: impl Into<Vec<u8>>) -> Self {
+        self.xml_strings.push(xml.into());
+        self
+    }
+
     /// Adds a protocol XML dir.
     ///
     /// This behaves as if all XML files in this directory (but not in any
@@ -82,6 +172,46 @@ impl Builder {
         self
     }
 
+    /// Adds a protocol XML file installed on the system.
+    ///
+    /// The file is located via pkg-config. `name` is either the name of an XML file
+    /// without the `.xml` extension, e.g. `linux-dmabuf-v1`, or the name of a protocol
+    /// directory of `wayland-protocols` that contains a single XML file, e.g.
+    /// `xdg-shell`. The `stable`, `staging`, and `unstable` directories of
+    /// `wayland-protocols` are searched in this order. `wayland` refers to the
+    /// `wayland.xml` file installed by `wayland-scanner`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .with_default_dir(false)
+    ///     .system_protocol("wayland")
+    ///     .system_protocol("xdg-shell")
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn system_protocol(mut self, name: &str) -> Self {
+        self.system_protocols
+            .push(SystemProtocol::Name(name.to_string()));
+        self
+    }
+
+    /// Adds a protocol XML file installed on the system by its path.
+    ///
+    /// The path is relative to the `pkgdatadir` of `wayland-protocols` or, if the file
+    /// does not exist there, the `pkgdatadir` of `wayland-scanner`. Both directories are
+    /// located via pkg-config. For example, `stable/xdg-shell/xdg-shell.xml`.
+    pub fn system_protocol_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.system_protocols
+            .push(SystemProtocol::File(path.as_ref().to_path_buf()));
+        self
+    }
+
     /// Enables or disables the default `wayland-protocols` dir.
     ///
     /// By default, the builder will try to load XML files from the `wayland-protocols`
@@ -112,17 +242,276 @@ impl Builder {
         self
     }
 
-    /// Enables or disables mutable data parameters.
+    /// This function has no effect.
+    ///
+    /// Event handlers with mutable data are always generated. They implement the
+    /// `*EventHandlerWithData<T>` traits and can be attached to the same proxies as
+    /// event handlers without mutable data.
+    #[deprecated(note = "event handlers with mutable data are always generated")]
+    pub fn with_mutable_data(self, mutable_data: bool) -> Self {
+        let _ = mutable_data;
+        self
+    }
+
+    /// Enables or disables cargo features for protocols.
+    ///
+    /// This is disabled by default. If it is enabled, each protocol module is only
+    /// compiled if the cargo feature with the same name as the protocol is enabled.
+    ///
+    /// The builder additionally writes a `features.toml` file into the target directory.
+    /// This file contains one line per protocol that can be copied into the `[features]`
+    /// section of a `Cargo.toml`. Each feature enables the features of the protocols
+    /// whose interfaces are referenced by the protocol.
+    pub fn with_protocol_features(mut self, protocol_features: bool) -> Self {
+        self.protocol_features = protocol_features;
+        self
+    }
+
+    /// Enables or disables the use of libwayland-client's core interface definitions.
+    ///
+    /// This is disabled by default. If it is enabled, the core interfaces of the
+    /// `wayland` protocol, such as `wl_surface`, use the `wl_*_interface` symbols
+    /// defined by libwayland-client. Proxies created by the generated code then have the
+    /// same interface pointers as proxies created by C code, and C code that compares
+    /// interface pointers accepts them.
     ///
-    /// This is disabled by default. If it is enabled, event handlers take an additional
-    /// parameter that gives access to mutable data that was passed in when dispatching
-    /// a queue.
-    pub fn with_mutable_data(mut self, mutable_data: bool) -> Self {
-        self.mutable_data = mutable_data;
+    /// The generated code does not link against libwayland-client. The symbols are
+    /// looked up in the library loaded by `Libwayland::open` the first time they are
+    /// needed. If the library cannot be loaded or does not define a symbol, the
+    /// generated code uses its own `wl_interface` table instead.
+    ///
+    /// The definitions in libwayland-client can be older than the XML file of the
+    /// `wayland` protocol. Sending a request that libwayland-client does not know about
+    /// panics. `wl_fixes` is not defined by all versions of libwayland-client and always
+    /// uses its own table.
+    pub fn with_libwayland_interfaces(mut self, libwayland_interfaces: bool) -> Self {
+        self.libwayland_interfaces = libwayland_interfaces;
+        self
+    }
+
+    /// Selects the protocol whose definition of an interface is used by other protocols.
+    ///
+    /// Multiple protocols can define interfaces with the same name, for example, if
+    /// both the stable and the unstable version of a protocol are added to the builder.
+    /// A protocol that defines such an interface always uses its own definition. If any
+    /// other protocol uses the interface, the builder returns an error unless a protocol
+    /// has been selected with this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .prefer_protocol("zwp_tablet_tool_v2", "tablet_v2")
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn prefer_protocol(mut self, interface: &str, protocol: &str) -> Self {
+        self.preferred_protocols
+            .insert(interface.to_string(), protocol.to_string());
+        self
+    }
+
+    /// Uses the code of a protocol that was generated by another crate.
+    ///
+    /// The XML file of the protocol must still be added to the builder, but no code is
+    /// generated for the protocol. Instead, protocols that use interfaces of this
+    /// protocol use the types found at `path`. This allows multiple crates to share the
+    /// same types, for example, for the core `wayland` protocol.
+    ///
+    /// `path` is the rust module path of the generated protocol module. It must be an
+    /// absolute path, that is, it must start with `::` or `crate::`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .xml_file("wayland.xml")
+    ///     .extern_protocol("wayland", "::crate_a::protocols::wayland")
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn extern_protocol(mut self, protocol: &str, path: &str) -> Self {
+        self.extern_protocols
+            .insert(protocol.to_string(), path.to_string());
+        self
+    }
+
+    /// Exports the `wl_interface` tables of a protocol as C symbols.
+    ///
+    /// By default, the `wl_interface` of each interface is a private static. If this
+    /// function is used, the `wl_interface` of each interface `name` of the protocol is
+    /// instead defined as `#[unsafe(no_mangle)] pub static {name}_interface`. This is the
+    /// symbol that code generated by `wayland-scanner` expects. C libraries in the same
+    /// process can then use the tables of the generated code.
+    ///
+    /// The build fails if
+    ///
+    /// - the protocol is not defined by any XML file,
+    /// - the protocol is an [extern protocol](Builder::extern_protocol),
+    /// - one of its interfaces is also defined by another protocol, or
+    /// - one of its interfaces is a core interface whose symbol is defined by
+    ///   libwayland-client, e.g. `wl_surface`.
+    ///
+    /// Symbols are global to the process. Each protocol should therefore only be
+    /// exported by a single crate and no C library in the process should define the
+    /// same symbols.
+    ///
+    /// This function can be called multiple times to export multiple protocols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .xml_file("linux-dmabuf-v1.xml")
+    ///     .export_interface_symbols("linux_dmabuf_v1")
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn export_interface_symbols(mut self, protocol: &str) -> Self {
+        self.exported_protocols.insert(protocol.to_string());
+        self
+    }
+
+    /// Only generates code for the given interfaces and the interfaces they use.
+    ///
+    /// By default, code is generated for all interfaces of all protocols. If this
+    /// function or [`Builder::only_protocols`] is used, code is only generated for the
+    /// selected interfaces and all interfaces that are reachable from them via object
+    /// arguments, `new_id` arguments, or enums. Protocols without any such interfaces are
+    /// omitted.
+    ///
+    /// If an interface is defined by multiple protocols, the definition selected with
+    /// [`Builder::prefer_protocol`] is used. If no definition has been selected, all
+    /// definitions are used.
+    ///
+    /// This function can be called multiple times to select more interfaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .only_interfaces(["wl_compositor", "wl_shm", "xdg_wm_base"])
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn only_interfaces(
+        mut self,
+        interfaces: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        self.only_interfaces
+            .extend(interfaces.into_iter().map(|i| i.as_ref().to_string()));
+        self
+    }
+
+    /// Only generates code for the interfaces of the given protocols and the interfaces
+    /// they use.
+    ///
+    /// This behaves as if all interfaces of the protocols had been selected with
+    /// [`Builder::only_interfaces`].
+    pub fn only_protocols(mut self, protocols: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.only_protocols
+            .extend(protocols.into_iter().map(|p| p.as_ref().to_string()));
+        self
+    }
+
+    /// Sets a callback that explains why interfaces are generated.
+    ///
+    /// If [`Builder::only_interfaces`] or [`Builder::only_protocols`] is used, the
+    /// callback is invoked once for each generated interface with a line of the form
+    /// `protocol::interface: reason`, for example
+    ///
+    /// ```text
+    /// xdg_shell::xdg_surface: new_id argument `id` of xdg_shell::xdg_wm_base.get_xdg_surface
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .only_interfaces(["xdg_wm_base"])
+    ///     .explain(|line| println!("cargo::warning={line}"))
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn explain(mut self, f: impl FnMut(&str) + 'static) -> Self {
+        self.explain = Some(Box::new(f));
+        self
+    }
+
+    /// Enables or disables check mode.
+    ///
+    /// This is disabled by default. If it is enabled, the builder does not modify the
+    /// target directory. Instead, [`Builder::build`] returns an error if any file in the
+    /// target directory differs from the file that would have been generated, if any
+    /// file is missing, or if any previously generated file is no longer generated.
+    ///
+    /// This can be used in CI to verify that checked-in code is up to date.
+    pub fn check(mut self, check: bool) -> Self {
+        self.check = check;
+        self
+    }
+
+    /// Formats the generated files with rustfmt.
+    ///
+    /// The first element of `command` is the rustfmt executable, the remaining elements
+    /// are passed as arguments. The generated code is passed to rustfmt via stdin and the
+    /// formatted code is read from stdout. Since rustfmt defaults to the 2015 edition
+    /// when reading from stdin, the command should usually contain an `--edition`
+    /// argument. rustfmt runs in the target directory or its closest existing ancestor
+    /// so that it picks up the same `rustfmt.toml` as when formatting the files in
+    /// place.
+    ///
+    /// Formatting happens before the generated files are compared with the files on
+    /// disk. Checked-in formatted code is therefore not rewritten by the next build and
+    /// can be verified with [`Builder::check`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// Builder::default()
+    ///     .rustfmt(["rustfmt", "+nightly", "--edition", "2024"])
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn rustfmt(mut self, command: impl IntoIterator<Item = impl AsRef<OsStr>>) -> Self {
+        let command: Vec<_> = command
+            .into_iter()
+            .map(|a| a.as_ref().to_os_string())
+            .collect();
+        self.rustfmt = (!command.is_empty()).then_some(command);
         self
     }
 
     /// Generates the code.
+    ///
+    /// Files are only written if their contents change. The builder writes a
+    /// `.wl-client-builder-manifest` file into the target directory that lists all
+    /// generated files. Files that were listed in the manifest by a previous build but
+    /// are no longer generated are removed.
     pub fn build(self) -> Result<(), crate::Error> {
         self.build_().map_err(|e| crate::Error(Box::new(e)))
     }
@@ -138,14 +527,157 @@ impl Builder {
         } else {
             target_dir.push("wayland-protocols");
         }
-        create_dir(&target_dir)?;
+        let mut output = Output {
+            dir: target_dir,
+            check: self.check,
+            rustfmt: self.rustfmt.take(),
+            files: Default::default(),
+            out_of_date: vec![],
+            memory: None,
+        };
+        self.generate(&mut output)?;
+        output.finish()
+    }
 
-        let mut protocol_objects = vec![];
+    /// Generates the code into a single string.
+    ///
+    /// All modules are inlined into the string instead of being written to files, and
+    /// the target directory is not used. The string contains the same items as the
+    /// `mod.rs` written by [`Builder::build`].
+    ///
+    /// This is used by the `wl-client-macros` crate.
+    pub fn build_inline(self) -> Result<InlineCode, crate::Error> {
+        self.build_inline_().map_err(|e| crate::Error(Box::new(e)))
+    }
+
+    fn build_inline_(mut self) -> Result<InlineCode, BuilderError> {
+        let mut output = Output {
+            dir: PathBuf::new(),
+            check: false,
+            rustfmt: self.rustfmt.take(),
+            files: Default::default(),
+            out_of_date: vec![],
+            memory: Some(Default::default()),
+        };
+        let xml_files = self.generate(&mut output)?;
+        let memory = output.memory.unwrap_or_default();
+        let mut code = String::new();
+        inline_module(&mut code, &memory, Path::new(""), Path::new("mod.rs"));
+        Ok(InlineCode { code, xml_files })
+    }
+
+    /// Checks the XML files for problems.
+    ///
+    /// This function does not generate any code. Instead, it returns all problems found
+    /// in the XML files, sorted by file and location. This includes
+    ///
+    /// - files that cannot be parsed,
+    /// - references to interfaces or enums that are not defined,
+    /// - `since` and `deprecated-since` values above the version of the interface,
+    /// - `deprecated-since` values below the `since` value,
+    /// - names that are defined multiple times,
+    /// - `allow-null` attributes on arguments that cannot be null.
+    ///
+    /// References are resolved across all XML files added to the builder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client_builder::Builder;
+    /// #
+    /// # fn no_run() {
+    /// let diagnostics = Builder::default()
+    ///     .for_build_rs(false)
+    ///     .xml_file("my-protocol.xml")
+    ///     .lint()
+    ///     .unwrap();
+    /// for diagnostic in diagnostics {
+    ///     eprintln!("{diagnostic}");
+    /// }
+    /// # }
+    /// ```
+    pub fn lint(self) -> Result<Vec<Diagnostic>, crate::Error> {
+        self.lint_().map_err(|e| crate::Error(Box::new(e)))
+    }
 
+    fn lint_(mut self) -> Result<Vec<Diagnostic>, BuilderError> {
+        let sources = self.load()?;
+        let mut diagnostics = vec![];
+        let mut parsed = vec![];
+        for (path, contents) in &sources {
+            match parse(contents) {
+                Ok(p) => parsed.push((path, contents, p)),
+                Err((location, e)) => diagnostics.push(Diagnostic {
+                    file: path.clone(),
+                    line: location.line,
+                    column: location.column,
+                    message: error_chain(&e),
+                }),
+            }
+        }
+        let mut protocol_files = HashMap::new();
+        for (path, contents, protocols) in &parsed {
+            for protocol in protocols {
+                let prev = protocol_files.insert(&protocol.name, path);
+                if let Some(prev) = prev {
+                    let location = Location::new(contents, protocol.span);
+                    diagnostics.push(Diagnostic {
+                        file: path.to_path_buf(),
+                        line: location.line,
+                        column: location.column,
+                        message: format!(
+                            "protocol {} is also defined in {}",
+                            protocol.name,
+                            prev.display(),
+                        ),
+                    });
+                }
+            }
+        }
+        let files: Vec<_> = parsed
+            .iter()
+            .map(|(path, contents, protocols)| SourceFile {
+                path,
+                contents,
+                protocols,
+            })
+            .collect();
+        diagnostics.extend(validate(&files));
+        diagnostics.sort();
+        Ok(diagnostics)
+    }
+
+    /// Creates a [`Decoder`] for wayland messages of the protocols in the XML files.
+    ///
+    /// This function does not generate any code. If an interface is defined by multiple
+    /// protocols, the protocol selected by [`Self::prefer_protocol`] is used. Otherwise
+    /// the first definition is used.
+    ///
+    /// See the documentation of [`Decoder`] for an example.
+    pub fn decoder(self) -> Result<Decoder, crate::Error> {
+        self.decoder_().map_err(|e| crate::Error(Box::new(e)))
+    }
+
+    fn decoder_(mut self) -> Result<Decoder, BuilderError> {
+        let sources = self.load()?;
+        let mut protocols = vec![];
+        for (file, contents) in sources {
+            match parse(&contents) {
+                Ok(p) => protocols.extend(p),
+                Err((location, e)) => return Err(BuilderError::ParseFile(file, location, e)),
+            }
+        }
+        Ok(Decoder::new(protocols, &self.preferred_protocols))
+    }
+
+    /// Reads all XML files and inline XML strings.
+    ///
+    /// The files of XML directories are added to `self.files`.
+    fn load(&mut self) -> Result<Vec<(PathBuf, Vec<u8>)>, BuilderError> {
         if self.add_default_dir {
             self.dirs.push(PathBuf::from("wayland-protocols"));
         }
-        for dir in self.dirs {
+        for dir in mem::take(&mut self.dirs) {
             if self.build_script {
                 println!("cargo::rerun-if-changed={}", dir.display());
             }
@@ -164,53 +696,243 @@ impl Builder {
                 self.files.push(file.path());
             }
         }
-        for file in self.files {
+        if !self.system_protocols.is_empty() {
+            if self.build_script {
+                for var in system::ENV_VARS {
+                    println!("cargo::rerun-if-env-changed={var}");
+                }
+            }
+            let mut dirs = PkgDataDirs::default();
+            for protocol in &self.system_protocols {
+                let file = dirs
+                    .resolve(protocol)
+                    .map_err(BuilderError::SystemProtocol)?;
+                self.files.push(file);
+            }
+        }
+        let mut sources = vec![];
+        for file in &self.files {
             if self.build_script {
                 println!("cargo::rerun-if-changed={}", file.display());
             }
-            let contents = match std::fs::read(&file) {
+            let contents = match std::fs::read(file) {
                 Ok(c) => c,
-                Err(e) => return Err(BuilderError::ReadFile(file, e)),
+                Err(e) => return Err(BuilderError::ReadFile(file.clone(), e)),
             };
-            let protocols = match parse(&contents) {
+            sources.push((file.clone(), contents));
+        }
+        for (idx, xml) in self.xml_strings.drain(..).enumerate() {
+            sources.push((PathBuf::from(format!("<inline XML #{}>", idx + 1)), xml));
+        }
+        Ok(sources)
+    }
+
+    /// Generates the code into `output` and returns the XML files that were read.
+    fn generate(mut self, output: &mut Output) -> Result<Vec<PathBuf>, BuilderError> {
+        let sources = self.load()?;
+        let mut protocols = vec![];
+        let mut protocol_files = HashMap::<String, PathBuf>::new();
+        for (file, contents) in sources {
+            let parsed = match parse(&contents) {
                 Ok(c) => c,
-                Err(e) => return Err(BuilderError::ParseFile(file, e)),
+                Err((location, e)) => return Err(BuilderError::ParseFile(file, location, e)),
             };
-            for protocol in protocols {
-                let protocol_file = format!("{}.rs", protocol.name);
-                format_file(&target_dir.join(&protocol_file), |f| {
-                    format_protocol_file(f, &protocol)
-                })?;
-                let dir = target_dir.join(&protocol.name);
-                create_dir(&dir)?;
+            for protocol in parsed {
+                if let Some(prev) = protocol_files.get(&protocol.name) {
+                    return Err(BuilderError::DuplicateProtocol(
+                        protocol.name,
+                        prev.clone(),
+                        file,
+                    ));
+                }
+                protocol_files.insert(protocol.name.clone(), file.clone());
+                protocols.push(protocol);
+            }
+        }
+        let mut namespaces =
+            resolve(&protocols, &self.preferred_protocols).map_err(BuilderError::Namespace)?;
+        if !self.only_protocols.is_empty() || !self.only_interfaces.is_empty() {
+            let reachable = closure(
+                &protocols,
+                &self.preferred_protocols,
+                &self.only_protocols,
+                &self.only_interfaces,
+                &mut |line| {
+                    if let Some(explain) = &mut self.explain {
+                        explain(line);
+                    }
+                },
+            )
+            .map_err(BuilderError::Namespace)?;
+            let is_reachable = |protocol: &str, interface: &str| {
+                reachable.contains(&(protocol.to_string(), interface.to_string()))
+            };
+            for protocol in &mut protocols {
+                protocol
+                    .interfaces
+                    .retain(|i| is_reachable(&protocol.name, &i.name));
+            }
+            protocols.retain(|p| !p.interfaces.is_empty());
+            self.preferred_protocols
+                .retain(|interface, protocol| is_reachable(protocol, interface));
+            namespaces =
+                resolve(&protocols, &self.preferred_protocols).map_err(BuilderError::Namespace)?;
+        }
+
+        for protocol in self.extern_protocols.keys() {
+            if !protocol_files.contains_key(protocol) {
+                return Err(BuilderError::UnknownExternProtocol(protocol.clone()));
+            }
+        }
+
+        for protocol in &self.exported_protocols {
+            if !protocol_files.contains_key(protocol) {
+                return Err(BuilderError::UnknownExportedProtocol(protocol.clone()));
+            }
+            if self.extern_protocols.contains_key(protocol) {
+                return Err(BuilderError::ExternExportedProtocol(protocol.clone()));
+            }
+        }
+        for protocol in &protocols {
+            if !self.exported_protocols.contains(&protocol.name) {
+                continue;
+            }
+            for interface in &protocol.interfaces {
+                if LIBWAYLAND_INTERFACES.contains(&&*interface.name) {
+                    return Err(BuilderError::LibwaylandExportedInterface(
+                        interface.name.clone(),
+                        protocol.name.clone(),
+                    ));
+                }
+                let other = protocols.iter().find(|p| {
+                    p.name != protocol.name && p.interfaces.iter().any(|i| i.name == interface.name)
+                });
+                if let Some(other) = other {
+                    return Err(BuilderError::DuplicateExportedInterface(
+                        interface.name.clone(),
+                        protocol.name.clone(),
+                        other.name.clone(),
+                    ));
+                }
+            }
+        }
+
+        let mut protocol_objects = vec![];
+        for protocol in protocols {
+            if self.extern_protocols.contains_key(&protocol.name) {
                 let mut interfaces = vec![];
                 for interface in protocol.interfaces {
-                    let file_name = format!("{}.rs", interface.name);
-                    format_file(&dir.join(&file_name), |f| {
-                        format_interface_file(
-                            f,
-                            self.wl_client_path.as_deref().unwrap_or("::wl_client"),
-                            self.mutable_data,
-                            &interface,
-                        )
-                    })?;
-                    let mut enums = vec![];
-                    for enum_ in interface.enums {
-                        enums.push(enum_.name);
-                    }
+                    let enums = interface.enums.into_iter().map(|e| e.name).collect();
                     interfaces.push((interface.name, enums));
                 }
                 protocol_objects.push((protocol.name, interfaces));
+                continue;
             }
+            output.write(format!("{}.rs", protocol.name), |f| {
+                format_protocol_file(
+                    f,
+                    self.wl_client_path.as_deref().unwrap_or("::wl_client"),
+                    &protocol,
+                )
+            })?;
+            let dir = Path::new(&protocol.name);
+            let types = match namespaces.scoped.contains_key(&protocol.name) {
+                true => format!("all_types::{}", protocol.name),
+                false => "all_types".to_string(),
+            };
+            let export_symbols = self.exported_protocols.contains(&protocol.name);
+            let mut interfaces = vec![];
+            for interface in protocol.interfaces {
+                let file_name = format!("{}.rs", interface.name);
+                let symbol = if export_symbols {
+                    InterfaceSymbol::Exported
+                } else if self.libwayland_interfaces
+                    && protocol.name == "wayland"
+                    && LIBWAYLAND_INTERFACES.contains(&&*interface.name)
+                    && !OPTIONAL_LIBWAYLAND_INTERFACES.contains(&&*interface.name)
+                {
+                    InterfaceSymbol::Libwayland
+                } else {
+                    InterfaceSymbol::Private
+                };
+                output.write(dir.join(&file_name), |f| {
+                    format_interface_file(
+                        f,
+                        self.wl_client_path.as_deref().unwrap_or("::wl_client"),
+                        &types,
+                        &protocol.name,
+                        &interface,
+                        symbol,
+                    )
+                })?;
+                let mut enums = vec![];
+                for enum_ in interface.enums {
+                    enums.push(enum_.name);
+                }
+                interfaces.push((interface.name, enums));
+            }
+            protocol_objects.push((protocol.name, interfaces));
         }
 
-        format_file(&target_dir.join("mod.rs"), |f| {
-            format_mod_file(f, &protocol_objects)
+        output.write("mod.rs", |f| {
+            format_mod_file(
+                f,
+                self.wl_client_path.as_deref().unwrap_or("::wl_client"),
+                self.protocol_features,
+                &protocol_objects,
+                &self.extern_protocols,
+                &self.preferred_protocols,
+                &namespaces,
+            )
         })?;
-        Ok(())
+        if self.protocol_features {
+            let is_local = |p: &String| !self.extern_protocols.contains_key(p);
+            let features: Vec<_> = namespaces
+                .dependencies
+                .iter()
+                .filter(|(protocol, _)| is_local(protocol))
+                .map(|(protocol, deps)| {
+                    let deps = deps.iter().filter(|p| is_local(p)).cloned().collect();
+                    (protocol.clone(), deps)
+                })
+                .collect();
+            output.write("features.toml", |f| format_features_file(f, &features))?;
+        }
+        Ok(self.files)
     }
 }
 
+/// The interfaces whose `wl_interface` symbols are defined by libwayland-client.
+const LIBWAYLAND_INTERFACES: &[&str] = &[
+    "wl_display",
+    "wl_registry",
+    "wl_callback",
+    "wl_compositor",
+    "wl_shm_pool",
+    "wl_shm",
+    "wl_buffer",
+    "wl_data_offer",
+    "wl_data_source",
+    "wl_data_device",
+    "wl_data_device_manager",
+    "wl_shell",
+    "wl_shell_surface",
+    "wl_surface",
+    "wl_seat",
+    "wl_pointer",
+    "wl_keyboard",
+    "wl_touch",
+    "wl_output",
+    "wl_region",
+    "wl_subcompositor",
+    "wl_subsurface",
+    "wl_fixes",
+];
+
+/// The interfaces whose `wl_interface` symbols are only defined by recent versions of
+/// libwayland-client.
+const OPTIONAL_LIBWAYLAND_INTERFACES: &[&str] = &["wl_fixes"];
+
 fn create_dir(path: &Path) -> Result<(), BuilderError> {
     if let Err(e) = std::fs::create_dir_all(path) {
         return Err(BuilderError::CreateDir(path.to_owned(), e));
@@ -218,29 +940,200 @@ fn create_dir(path: &Path) -> Result<(), BuilderError> {
     Ok(())
 }
 
-fn open_file(path: &Path) -> Result<BufWriter<File>, BuilderError> {
-    let file = File::options()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path);
-    match file {
-        Ok(f) => Ok(BufWriter::new(f)),
-        Err(e) => Err(BuilderError::OpenFile(path.to_owned(), e)),
+/// The name of the file in the target directory that lists all generated files.
+const MANIFEST: &str = ".wl-client-builder-manifest";
+
+/// The files generated into the target directory.
+struct Output {
+    dir: PathBuf,
+    check: bool,
+    /// The rustfmt command used to format generated rust files.
+    rustfmt: Option<Vec<OsString>>,
+    /// The generated files, relative to `dir`.
+    files: BTreeSet<PathBuf>,
+    /// The files that are out of date in check mode.
+    out_of_date: Vec<PathBuf>,
+    /// If this is set, files are stored here instead of being written to disk.
+    memory: Option<BTreeMap<PathBuf, Vec<u8>>>,
+}
+
+impl Output {
+    /// Generates a file.
+    ///
+    /// The file is only written if its contents differ from the contents on disk. In
+    /// check mode, the file is never written but recorded as out of date instead.
+    fn write(
+        &mut self,
+        path: impl AsRef<Path>,
+        f: impl FnOnce(&mut Vec<u8>) -> io::Result<()>,
+    ) -> Result<(), BuilderError> {
+        let path = path.as_ref();
+        let full = self.dir.join(path);
+        let mut contents = vec![];
+        if let Err(e) = f(&mut contents) {
+            return Err(BuilderError::FormatFile(full, e));
+        }
+        if path.extension() == Some("rs".as_ref()) {
+            contents = self.rustfmt(&full, contents)?;
+        }
+        self.files.insert(path.to_owned());
+        if let Some(memory) = &mut self.memory {
+            memory.insert(path.to_owned(), contents);
+            return Ok(());
+        }
+        self.write_raw(full, &contents)
+    }
+
+    /// Formats the contents of a rust file if a rustfmt command was configured.
+    fn rustfmt(&self, path: &Path, contents: Vec<u8>) -> Result<Vec<u8>, BuilderError> {
+        let Some(command) = &self.rustfmt else {
+            return Ok(contents);
+        };
+        let program = PathBuf::from(&command[0]);
+        let mut cmd = Command::new(&program);
+        cmd.args(&command[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let cwd = self
+            .dir
+            .ancestors()
+            .find(|d| !d.as_os_str().is_empty() && d.is_dir());
+        if let Some(cwd) = cwd {
+            cmd.current_dir(cwd);
+        }
+        let run_error = |e| BuilderError::RunRustfmt(program.clone(), path.to_owned(), e);
+        let mut child = cmd.spawn().map_err(run_error)?;
+        let mut stdin = child.stdin.take().unwrap();
+        // Writing from a separate thread prevents a deadlock if rustfmt writes to stdout
+        // before it has consumed all of stdin.
+        let writer = std::thread::spawn(move || stdin.write_all(&contents));
+        let output = child.wait_with_output().map_err(run_error)?;
+        let written = writer.join().unwrap();
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let mut msg = stderr.trim().to_string();
+            if msg.is_empty() {
+                msg = output.status.to_string();
+            }
+            return Err(BuilderError::Rustfmt(program, path.to_owned(), msg));
+        }
+        written.map_err(run_error)?;
+        Ok(output.stdout)
+    }
+
+    fn write_raw(&mut self, path: PathBuf, contents: &[u8]) -> Result<(), BuilderError> {
+        match std::fs::read(&path) {
+            Ok(c) if c == contents => return Ok(()),
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(BuilderError::ReadFile(path, e)),
+        }
+        if self.check {
+            self.out_of_date.push(path);
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            create_dir(parent)?;
+        }
+        if let Err(e) = std::fs::write(&path, contents) {
+            return Err(BuilderError::OpenFile(path, e));
+        }
+        Ok(())
+    }
+
+    /// Removes the files that were generated previously but not by this build and
+    /// updates the manifest.
+    fn finish(mut self) -> Result<(), BuilderError> {
+        let manifest = self.dir.join(MANIFEST);
+        let previous = match std::fs::read_to_string(&manifest) {
+            Ok(c) => c,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(BuilderError::ReadFile(manifest, e)),
+        };
+        for line in previous.lines() {
+            if line.starts_with('#') || line.is_empty() {
+                continue;
+            }
+            let path = Path::new(line);
+            let is_relative = path.components().all(|c| matches!(c, Component::Normal(_)));
+            if !is_relative || self.files.contains(path) {
+                continue;
+            }
+            let full = self.dir.join(path);
+            if !full.exists() {
+                continue;
+            }
+            if self.check {
+                self.out_of_date.push(full);
+                continue;
+            }
+            if let Err(e) = std::fs::remove_file(&full) {
+                return Err(BuilderError::RemoveFile(full, e));
+            }
+            if let Some(parent) = full.parent() {
+                if parent != self.dir {
+                    // Remove the directory of the protocol if it is now empty.
+                    let _ = std::fs::remove_dir(parent);
+                }
+            }
+        }
+        let mut contents = String::new();
+        contents.push_str("# This file was generated by wl-client-builder. Do not edit.\n");
+        for file in &self.files {
+            let file: Vec<_> = file.iter().map(|c| c.to_string_lossy()).collect();
+            contents.push_str(&file.join("/"));
+            contents.push('\n');
+        }
+        self.write_raw(manifest, contents.as_bytes())?;
+        if !self.out_of_date.is_empty() {
+            self.out_of_date.sort_unstable();
+            return Err(BuilderError::OutOfDate(self.out_of_date));
+        }
+        Ok(())
     }
 }
 
-fn format_file(
-    path: &Path,
-    f: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>,
-) -> Result<(), BuilderError> {
-    let mut file = open_file(path)?;
-    let mut res = f(&mut file);
-    if res.is_ok() {
-        res = file.flush();
+/// Appends the contents of `file` to `out`, replacing each `pub mod name;` line by an
+/// inline module containing the contents of `name.rs` in `module_dir`.
+fn inline_module(
+    out: &mut String,
+    files: &BTreeMap<PathBuf, Vec<u8>>,
+    module_dir: &Path,
+    file: &Path,
+) {
+    let contents = String::from_utf8_lossy(&files[file]);
+    for line in contents.lines() {
+        let name = line
+            .strip_prefix("pub mod ")
+            .and_then(|l| l.strip_suffix(';'));
+        match name {
+            Some(name) => {
+                out.push_str(&format!("pub mod {name} {{\n"));
+                let file = module_dir.join(format!("{name}.rs"));
+                inline_module(out, files, &module_dir.join(name), &file);
+                out.push_str("}\n");
+            }
+            None => {
+                out.push_str(line);
+                out.push('\n');
+            }
+        }
     }
-    if let Err(e) = res {
-        return Err(BuilderError::FormatFile(path.to_owned(), e));
+}
+
+/// Formats an error and all of its sources.
+fn error_chain(e: &dyn Error) -> String {
+    let mut msg = e.to_string();
+    let mut source = e.source();
+    while let Some(e) = source {
+        let s = e.to_string();
+        // Some errors include the message of their source in their own message.
+        if !msg.ends_with(&s) {
+            msg.push_str(": ");
+            msg.push_str(&s);
+        }
+        source = e.source();
     }
-    Ok(())
+    msg
 }
@@ -0,0 +1,404 @@
+use {
+    crate::builder::{Builder, MANIFEST, error_chain},
+    std::{
+        cell::RefCell,
+        fs::{self, File},
+        path::Path,
+        rc::Rc,
+        time::{Duration, SystemTime},
+    },
+    tempfile::TempDir,
+};
+
+/// Writes the XML files into `dir` and returns a builder that reads them and generates
+/// code into `dir/out`.
+fn builder(dir: &Path, xml: &[&str]) -> Builder {
+    let xml_dir = dir.join("xml");
+    fs::create_dir_all(&xml_dir).unwrap();
+    let mut builder = Builder::default()
+        .for_build_rs(false)
+        .with_default_dir(false)
+        .target_dir(dir.join("out"));
+    for (idx, xml) in xml.iter().enumerate() {
+        let path = xml_dir.join(format!("{idx}.xml"));
+        fs::write(&path, xml).unwrap();
+        builder = builder.xml_file(path);
+    }
+    builder
+}
+
+fn generate(xml: &[&str], f: impl FnOnce(Builder) -> Builder) -> Result<TempDir, String> {
+    let dir = tempfile::tempdir().unwrap();
+    f(builder(dir.path(), xml))
+        .build()
+        .map_err(|e| error_chain(&e))?;
+    Ok(dir)
+}
+
+fn read(dir: &TempDir, path: &str) -> String {
+    fs::read_to_string(dir.path().join("out").join(path)).unwrap()
+}
+
+fn exists(dir: &TempDir, path: &str) -> bool {
+    dir.path().join("out").join(path).exists()
+}
+
+const STABLE: &str = r#"
+    <protocol name="stable">
+        <interface name="tool" version="1"/>
+    </protocol>
+"#;
+
+const UNSTABLE: &str = r#"
+    <protocol name="unstable">
+        <interface name="tool" version="1"/>
+    </protocol>
+"#;
+
+const USER: &str = r#"
+    <protocol name="user">
+        <interface name="user" version="1">
+            <request name="use_tool">
+                <arg name="tool" type="object" interface="tool"/>
+            </request>
+        </interface>
+    </protocol>
+"#;
+
+#[test]
+fn collision_requires_preference() {
+    let err = generate(&[STABLE, UNSTABLE, USER], |b| b).unwrap_err();
+    assert!(
+        err.contains("Could not resolve interface references"),
+        "{err}"
+    );
+    assert!(
+        err.contains("Protocol user uses interface tool which is defined by multiple protocols (stable, unstable)"),
+        "{err}",
+    );
+}
+
+#[test]
+fn collision_with_preference() {
+    let dir = generate(&[STABLE, UNSTABLE, USER], |b| {
+        b.prefer_protocol("tool", "unstable")
+    })
+    .unwrap();
+    assert!(exists(&dir, "stable/tool.rs"));
+    assert!(exists(&dir, "unstable/tool.rs"));
+    let code = read(&dir, "mod.rs");
+    let start = code.find("pub(super) mod user {").unwrap();
+    let user = &code[start..start + code[start..].find('}').unwrap()];
+    assert!(
+        user.contains("pub(in super::super) use super::super::unstable::tool::Tool;"),
+        "{code}",
+    );
+    assert!(!user.contains("super::super::stable::"), "{code}");
+    assert!(
+        code.contains(r#""tool" => &unstable::tool::INFO,"#),
+        "{code}"
+    );
+    assert!(
+        !code.contains(r#""tool" => &stable::tool::INFO,"#),
+        "{code}"
+    );
+    let user_rs = read(&dir, "user/user.rs");
+    assert!(user_rs.contains("all_types::user"), "{user_rs}");
+}
+
+#[test]
+fn invalid_preference() {
+    let err = generate(&[STABLE, UNSTABLE], |b| b.prefer_protocol("tool", "user")).unwrap_err();
+    assert!(
+        err.contains("Protocol user is preferred for interface tool but does not define it"),
+        "{err}",
+    );
+}
+
+const CORE: &str = r#"
+    <protocol name="core">
+        <interface name="wl_surface" version="1">
+            <enum name="error">
+                <entry name="invalid" value="0"/>
+            </enum>
+        </interface>
+    </protocol>
+"#;
+
+const CORE_USER: &str = r#"
+    <protocol name="core_user">
+        <interface name="core_user" version="1">
+            <request name="set_surface">
+                <arg name="surface" type="object" interface="wl_surface"/>
+            </request>
+        </interface>
+    </protocol>
+"#;
+
+#[test]
+fn extern_protocol() {
+    let dir = generate(&[CORE, CORE_USER], |b| {
+        b.extern_protocol("core", "::crate_a::protocols::core")
+    })
+    .unwrap();
+    assert!(!exists(&dir, "core.rs"));
+    assert!(!exists(&dir, "core"));
+    assert!(exists(&dir, "core_user/core_user.rs"));
+    let code = read(&dir, "mod.rs");
+    assert!(!code.contains("pub mod core;"), "{code}");
+    assert!(code.contains("pub mod core_user;"), "{code}");
+    assert!(
+        code.contains("pub(super) use ::crate_a::protocols::core::wl_surface::WlSurface;"),
+        "{code}",
+    );
+    assert!(
+        code.contains("pub(super) use ::crate_a::protocols::core::wl_surface::WlSurfaceRef;"),
+        "{code}",
+    );
+    assert!(
+        code.contains("pub(super) use ::crate_a::protocols::core::wl_surface::WlSurfaceError;"),
+        "{code}",
+    );
+    assert!(!code.contains("&core::PROTOCOL"), "{code}");
+    assert!(!code.contains(r#""wl_surface" =>"#), "{code}");
+}
+
+#[test]
+fn extern_protocol_with_collision() {
+    let dir = generate(&[STABLE, UNSTABLE, USER], |b| {
+        b.extern_protocol("unstable", "crate::unstable")
+            .prefer_protocol("tool", "unstable")
+    })
+    .unwrap();
+    assert!(!exists(&dir, "unstable.rs"));
+    let code = read(&dir, "mod.rs");
+    assert!(!code.contains("pub mod unstable;"), "{code}");
+    assert!(
+        code.contains("pub(in super::super) use crate::unstable::tool::Tool;"),
+        "{code}",
+    );
+    assert!(!code.contains("pub(super) mod unstable {"), "{code}");
+}
+
+#[test]
+fn extern_protocol_features() {
+    let dir = generate(&[CORE, CORE_USER], |b| {
+        b.with_protocol_features(true)
+            .extern_protocol("core", "::crate_a::protocols::core")
+    })
+    .unwrap();
+    assert_eq!(read(&dir, "features.toml"), "core_user = []\n");
+}
+
+#[test]
+fn unknown_extern_protocol() {
+    let err = generate(&[CORE], |b| b.extern_protocol("unknown", "crate::unknown")).unwrap_err();
+    assert_eq!(
+        err,
+        "Extern protocol unknown is not defined by any XML file"
+    );
+}
+
+#[test]
+fn only_interfaces() {
+    let lines = Rc::new(RefCell::new(vec![]));
+    let lines2 = lines.clone();
+    let dir = generate(&[CORE, CORE_USER, STABLE, UNSTABLE, USER], |b| {
+        b.prefer_protocol("tool", "unstable")
+            .only_interfaces(["core_user"])
+            .explain(move |line| lines2.borrow_mut().push(line.to_string()))
+    })
+    .unwrap();
+    assert!(exists(&dir, "core.rs"));
+    assert!(exists(&dir, "core_user.rs"));
+    assert!(!exists(&dir, "stable.rs"));
+    assert!(!exists(&dir, "unstable.rs"));
+    assert!(!exists(&dir, "user.rs"));
+    let code = read(&dir, "mod.rs");
+    assert!(code.contains("pub mod core;"), "{code}");
+    assert!(code.contains("pub mod core_user;"), "{code}");
+    assert!(!code.contains("pub mod user;"), "{code}");
+    assert_eq!(
+        *lines.borrow(),
+        [
+            "core_user::core_user: interface was selected",
+            "core::wl_surface: object argument `surface` of core_user::core_user.set_surface",
+        ],
+    );
+}
+
+#[test]
+fn only_protocols() {
+    let dir = generate(&[CORE, CORE_USER, STABLE, UNSTABLE, USER], |b| {
+        b.prefer_protocol("tool", "unstable")
+            .only_protocols(["user"])
+    })
+    .unwrap();
+    assert!(exists(&dir, "user.rs"));
+    assert!(exists(&dir, "unstable.rs"));
+    assert!(!exists(&dir, "stable.rs"));
+    assert!(!exists(&dir, "core.rs"));
+    // Only one protocol defines tool after filtering.
+    let code = read(&dir, "mod.rs");
+    assert!(
+        code.contains("pub(super) use super::unstable::tool::Tool;"),
+        "{code}"
+    );
+}
+
+#[test]
+fn only_unknown_interface() {
+    let err = generate(&[CORE], |b| b.only_interfaces(["unknown"])).unwrap_err();
+    assert_eq!(
+        err,
+        "Could not resolve interface references: Interface unknown is not defined by any protocol",
+    );
+}
+
+fn build(dir: &Path, xml: &[&str]) -> Result<(), String> {
+    builder(dir, xml).build().map_err(|e| error_chain(&e))
+}
+
+fn check(dir: &Path, xml: &[&str]) -> Result<(), String> {
+    builder(dir, xml)
+        .check(true)
+        .build()
+        .map_err(|e| error_chain(&e))
+}
+
+#[test]
+fn manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let out = dir.join("out");
+    build(dir, &[CORE, CORE_USER]).unwrap();
+    let manifest = fs::read_to_string(out.join(MANIFEST)).unwrap();
+    assert_eq!(
+        manifest,
+        "# This file was generated by wl-client-builder. Do not edit.\n\
+         core/wl_surface.rs\n\
+         core.rs\n\
+         core_user/core_user.rs\n\
+         core_user.rs\n\
+         mod.rs\n",
+    );
+}
+
+#[test]
+fn unchanged_files_are_not_written() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let out = dir.join("out");
+    build(dir, &[CORE]).unwrap();
+    let path = out.join("core/wl_surface.rs");
+    let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+    File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(old)
+        .unwrap();
+    build(dir, &[CORE]).unwrap();
+    assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), old);
+}
+
+#[test]
+fn stale_files_are_removed() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let out = dir.join("out");
+    build(dir, &[CORE, CORE_USER]).unwrap();
+    fs::write(out.join("user.rs"), "").unwrap();
+    fs::write(out.join("core_user/user.rs"), "").unwrap();
+    build(dir, &[CORE]).unwrap();
+    assert!(out.join("core/wl_surface.rs").exists());
+    assert!(!out.join("core_user.rs").exists());
+    assert!(!out.join("core_user/core_user.rs").exists());
+    // Files that were not generated by the builder are kept.
+    assert!(out.join("user.rs").exists());
+    assert!(out.join("core_user/user.rs").exists());
+    let manifest = fs::read_to_string(out.join(MANIFEST)).unwrap();
+    assert!(!manifest.contains("core_user"), "{manifest}");
+}
+
+#[test]
+fn stale_directories_are_removed() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let out = dir.join("out");
+    build(dir, &[CORE, CORE_USER]).unwrap();
+    build(dir, &[CORE]).unwrap();
+    assert!(!out.join("core_user").exists());
+}
+
+#[test]
+fn manifest_paths_outside_of_target_dir_are_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let target = dir.join("out");
+    build(dir, &[CORE]).unwrap();
+    let outside = dir.join("outside.rs");
+    fs::write(&outside, "").unwrap();
+    let manifest = format!("../outside.rs\n{}\n", outside.display());
+    fs::write(target.join(MANIFEST), manifest).unwrap();
+    build(dir, &[CORE]).unwrap();
+    assert!(outside.exists());
+}
+
+#[test]
+fn check_up_to_date() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    build(dir, &[CORE, CORE_USER]).unwrap();
+    check(dir, &[CORE, CORE_USER]).unwrap();
+}
+
+#[test]
+fn check_out_of_date() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    let out = dir.join("out");
+    build(dir, &[CORE, CORE_USER]).unwrap();
+    fs::write(out.join("core/wl_surface.rs"), "").unwrap();
+    fs::remove_file(out.join("core.rs")).unwrap();
+    let err = check(dir, &[CORE, STABLE]).unwrap_err();
+    let expected = [
+        "The generated code is out of date:",
+        &format!("  {}", out.join(MANIFEST).display()),
+        &format!("  {}", out.join("core/wl_surface.rs").display()),
+        &format!("  {}", out.join("core.rs").display()),
+        &format!("  {}", out.join("core_user/core_user.rs").display()),
+        &format!("  {}", out.join("core_user.rs").display()),
+        &format!("  {}", out.join("mod.rs").display()),
+        &format!("  {}", out.join("stable/tool.rs").display()),
+        &format!("  {}", out.join("stable.rs").display()),
+    ]
+    .join("\n");
+    assert_eq!(err, expected);
+    // Check mode does not modify the target directory.
+    assert_eq!(
+        fs::read_to_string(out.join("core/wl_surface.rs")).unwrap(),
+        ""
+    );
+    assert!(!out.join("core.rs").exists());
+    assert!(out.join("core_user.rs").exists());
+    assert!(!out.join("stable.rs").exists());
+}
+
+#[test]
+fn lint() {
+    let mut builder = Builder::default().with_default_dir(false);
+    for xml in [CORE, "<protocol", CORE, USER] {
+        builder = builder.xml_string(xml);
+    }
+    let diagnostics = builder.lint().unwrap();
+    let diagnostics: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+    assert_eq!(
+        diagnostics,
+        [
+            "<inline XML #2>:1:10: Could not read the next event: syntax error: tag not closed: `>` not found before end of input",
+            "<inline XML #3>:2:5: protocol core is also defined in <inline XML #1>",
+            "<inline XML #4>:5:17: argument tool of request user.use_tool uses interface tool which is not defined",
+        ],
+    );
+}
@@ -0,0 +1,665 @@
+use {
+    crate::ast::{Arg, ArgType, Enum, Interface, Message, Protocol},
+    std::{
+        collections::HashMap,
+        fmt::{Display, Formatter},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error)]
+enum DecodeError {
+    #[error("The message at offset {0} is truncated")]
+    Truncated(usize),
+    #[error("The message at offset {0} has invalid size {1}")]
+    InvalidSize(usize, usize),
+    #[error(
+        "The arguments of {interface}#{object}.{message} at offset {offset} do not match the protocol"
+    )]
+    InvalidArguments {
+        offset: usize,
+        interface: String,
+        object: u32,
+        message: String,
+    },
+}
+
+/// The side of a wayland connection.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Side {
+    /// The client.
+    Client,
+    /// The compositor.
+    Server,
+}
+
+/// A message decoded by a [`Decoder`].
+///
+/// The [`Display`] implementation prints the message in the format used by libwayland
+/// with the name of each argument in front of its value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct DecodedMessage {
+    /// The text that precedes the message, for example, the timestamp of a debug log
+    /// line and ` -> ` for messages sent by the side that produced the log.
+    pub prefix: String,
+    /// Whether the message is a request.
+    pub request: bool,
+    /// The ID of the object that the message was sent to.
+    pub object: u32,
+    /// The interface of the object, if known.
+    pub interface: Option<String>,
+    /// The name of the message or `[opcode N]` if the message is not known.
+    pub message: String,
+    /// The arguments of the message.
+    pub args: Vec<DecodedArg>,
+}
+
+/// An argument of a [`DecodedMessage`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct DecodedArg {
+    /// The name of the argument, if known.
+    pub name: Option<String>,
+    /// The value of the argument. Enum values are replaced by the names of the entries.
+    pub value: String,
+}
+
+impl Display for DecodedMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let interface = self.interface.as_deref().unwrap_or("[unknown]");
+        write!(
+            f,
+            "{}{}#{}.{}(",
+            self.prefix, interface, self.object, self.message
+        )?;
+        for (idx, arg) in self.args.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            if let Some(name) = &arg.name {
+                write!(f, "{name}: ")?;
+            }
+            f.write_str(&arg.value)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Decodes wayland messages with the help of protocol XML files.
+///
+/// A decoder is created by [`Builder::decoder`](crate::Builder::decoder). It tracks the
+/// interfaces of objects by following the `new_id` arguments of the decoded messages.
+/// Therefore, the messages of a log should be decoded in order.
+///
+/// # Example
+///
+/// ```
+/// # use wl_client_builder::{Builder, Side};
+/// #
+/// let mut decoder = Builder::default()
+///     .for_build_rs(false)
+///     .with_default_dir(false)
+///     .xml_string(
+///         r#"
+///         <protocol name="example">
+///           <interface name="wl_display" version="1">
+///             <request name="get_registry">
+///               <arg name="registry" type="new_id" interface="wl_registry"/>
+///             </request>
+///           </interface>
+///           <interface name="wl_registry" version="1">
+///             <request name="bind">
+///               <arg name="name" type="uint"/>
+///               <arg name="id" type="new_id"/>
+///             </request>
+///           </interface>
+///           <interface name="wl_shm" version="1">
+///             <enum name="format">
+///               <entry name="argb8888" value="0"/>
+///               <entry name="xrgb8888" value="1"/>
+///             </enum>
+///             <event name="format">
+///               <arg name="format" type="uint" enum="format"/>
+///             </event>
+///           </interface>
+///         </protocol>
+///         "#,
+///     )
+///     .decoder()
+///     .unwrap();
+///
+/// let log = r#"
+/// [1234.567]  -> wl_display#1.get_registry(new id wl_registry#2)
+/// [1234.568]  -> wl_registry#2.bind(1, "wl_shm", 1, new id [unknown]#3)
+/// [1234.569] wl_shm#3.format(1)
+/// "#;
+/// let decoded: Vec<_> = log
+///     .lines()
+///     .filter_map(|line| decoder.decode_debug_line(Side::Client, line))
+///     .map(|msg| msg.to_string())
+///     .collect();
+/// assert_eq!(
+///     decoded,
+///     [
+///         "[1234.567]  -> wl_display#1.get_registry(registry: new id wl_registry#2)",
+///         r#"[1234.568]  -> wl_registry#2.bind(name: 1, id_interface: "wl_shm", id_version: 1, id: new id wl_shm#3)"#,
+///         "[1234.569] wl_shm#3.format(format: xrgb8888)",
+///     ],
+/// );
+///
+/// // A request of the client as raw bytes.
+/// let words = [
+///     2,        // object ID
+///     32 << 16, // size and opcode
+///     1,        // name
+///     7,        // length of the interface name including the nul byte
+///     u32::from_ne_bytes(*b"wl_s"),
+///     u32::from_ne_bytes(*b"hm\0\0"),
+///     1, // version
+///     4, // ID of the new object
+/// ];
+/// let wire: Vec<u8> = words.iter().flat_map(|w| w.to_ne_bytes()).collect();
+/// let mut decoded = vec![];
+/// decoder
+///     .decode_wire(Side::Client, &wire, |msg| decoded.push(msg.to_string()))
+///     .unwrap();
+/// assert_eq!(
+///     decoded,
+///     [r#" -> wl_registry#2.bind(name: 1, id_interface: "wl_shm", id_version: 1, id: new id wl_shm#4)"#],
+/// );
+/// ```
+pub struct Decoder {
+    protocols: Protocols,
+    objects: HashMap<u32, String>,
+}
+
+/// The parsed protocols of a [`Decoder`].
+struct Protocols {
+    protocols: Vec<Protocol>,
+    /// Maps each interface name to the indices of the protocol and the interface.
+    interfaces: HashMap<String, (usize, usize)>,
+}
+
+/// A parameter of a message as it appears on the wire.
+///
+/// An untyped `new_id` argument is transmitted as three parameters: the name of the
+/// interface, the version, and the ID.
+struct Param<'a> {
+    name: String,
+    ty: ArgType,
+    arg: &'a Arg,
+}
+
+impl Protocols {
+    fn interface(&self, name: &str) -> Option<&Interface> {
+        let &(protocol, interface) = self.interfaces.get(name)?;
+        Some(&self.protocols[protocol].interfaces[interface])
+    }
+
+    /// Returns the enum referenced by an argument of `interface`.
+    fn enum_<'a>(&'a self, interface: &'a Interface, arg: &Arg) -> Option<&'a Enum> {
+        let name = arg.enum_.as_deref()?;
+        let (interface, name) = match name.split_once('.') {
+            Some((i, n)) => (self.interface(i)?, n),
+            None => (interface, name),
+        };
+        interface.enums.iter().find(|e| e.name == name)
+    }
+
+    /// Formats an `int` or `uint` value, replacing enum values by the names of the
+    /// entries.
+    fn format_number(&self, interface: &Interface, param: &Param<'_>, value: u32) -> String {
+        let enum_ = match param.arg.ty == param.ty {
+            true => self.enum_(interface, param.arg),
+            false => None,
+        };
+        match enum_ {
+            Some(e) => format_enum(e, value),
+            None if param.ty == ArgType::Int => (value as i32).to_string(),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// Formats an enum value like `wl_client::info::EnumInfo::format`.
+fn format_enum(e: &Enum, value: u32) -> String {
+    if !e.bitfield || value == 0 {
+        return match e.entries.iter().find(|entry| entry.value_u32 == value) {
+            Some(entry) => entry.name.clone(),
+            None => value.to_string(),
+        };
+    }
+    let mut parts = vec![];
+    let mut remaining = value;
+    for entry in &e.entries {
+        if entry.value_u32 != 0 && remaining & entry.value_u32 == entry.value_u32 {
+            parts.push(entry.name.clone());
+            remaining &= !entry.value_u32;
+        }
+    }
+    if remaining != 0 {
+        parts.push(format!("0x{remaining:x}"));
+    }
+    parts.join("|")
+}
+
+fn params(message: &Message) -> Vec<Param<'_>> {
+    let mut params = vec![];
+    for arg in &message.args {
+        if arg.ty == ArgType::NewId && arg.interface.is_none() {
+            params.push(Param {
+                name: format!("{}_interface", arg.name),
+                ty: ArgType::String,
+                arg,
+            });
+            params.push(Param {
+                name: format!("{}_version", arg.name),
+                ty: ArgType::Uint,
+                arg,
+            });
+        }
+        params.push(Param {
+            name: arg.name.clone(),
+            ty: arg.ty,
+            arg,
+        });
+    }
+    params
+}
+
+/// Returns the request or event with the given name, preferring the requested direction.
+fn find_message<'a>(
+    interface: &'a Interface,
+    request: bool,
+    name: &str,
+) -> Option<(&'a Message, bool)> {
+    let find = |request: bool| {
+        let messages = match request {
+            true => &interface.requests,
+            false => &interface.events,
+        };
+        messages
+            .iter()
+            .find(|m| m.name == name)
+            .map(|m| (m, request))
+    };
+    find(request).or_else(|| find(!request))
+}
+
+impl Decoder {
+    pub(crate) fn new(
+        protocols: Vec<Protocol>,
+        preferred_protocols: &HashMap<String, String>,
+    ) -> Self {
+        let mut interfaces = HashMap::new();
+        for (protocol_idx, protocol) in protocols.iter().enumerate() {
+            for (interface_idx, interface) in protocol.interfaces.iter().enumerate() {
+                let preferred = preferred_protocols.get(&interface.name) == Some(&protocol.name);
+                if preferred || !interfaces.contains_key(&interface.name) {
+                    interfaces.insert(interface.name.clone(), (protocol_idx, interface_idx));
+                }
+            }
+        }
+        let mut slf = Self {
+            protocols: Protocols {
+                protocols,
+                interfaces,
+            },
+            objects: Default::default(),
+        };
+        slf.objects.insert(1, "wl_display".to_string());
+        slf
+    }
+
+    /// Decodes a line of a `WAYLAND_DEBUG=1` log.
+    ///
+    /// `side` is the side of the connection that wrote the log. Lines that contain a
+    /// message are returned with named arguments and enum values replaced by the names of
+    /// the entries. If the message is not known or its arguments do not match the
+    /// protocol, the arguments are returned as a single unnamed argument. Other lines
+    /// return `None`.
+    pub fn decode_debug_line(&mut self, side: Side, line: &str) -> Option<DecodedMessage> {
+        let body = line.trim_end().strip_suffix(')')?;
+        for (idx, _) in body.match_indices('(') {
+            let head = &body[..idx];
+            let start = head.rfind(' ').map(|i| i + 1).unwrap_or(0);
+            let Some((target, message)) = head[start..].split_once('.') else {
+                continue;
+            };
+            let Some((interface, object)) = target.rsplit_once('#') else {
+                continue;
+            };
+            let Ok(object) = object.parse() else {
+                continue;
+            };
+            if message.is_empty()
+                || !message
+                    .bytes()
+                    .all(|c| c.is_ascii_alphanumeric() || c == b'_')
+            {
+                continue;
+            }
+            let prefix = &head[..start];
+            let sent = prefix.trim_end().ends_with("->");
+            let request = sent == (side == Side::Client);
+            let interface = match interface {
+                "[unknown]" => self.objects.get(&object).cloned(),
+                _ => Some(interface.to_string()),
+            };
+            return Some(self.decode_text(
+                prefix,
+                request,
+                object,
+                interface,
+                message,
+                &body[idx + 1..],
+            ));
+        }
+        None
+    }
+
+    fn decode_text(
+        &mut self,
+        prefix: &str,
+        request: bool,
+        object: u32,
+        interface: Option<String>,
+        message: &str,
+        args: &str,
+    ) -> DecodedMessage {
+        if let Some(interface) = &interface {
+            self.objects.insert(object, interface.clone());
+        }
+        let found = interface
+            .as_deref()
+            .and_then(|i| self.protocols.interface(i))
+            .and_then(|i| find_message(i, request, message).map(|m| (i, m)));
+        let mut decoded = DecodedMessage {
+            prefix: prefix.to_string(),
+            request,
+            object,
+            interface,
+            message: message.to_string(),
+            args: vec![],
+        };
+        if let Some((interface, (message, request))) = found {
+            decoded.request = request;
+            let parsed = parse_text_args(&self.protocols, interface, message, args);
+            if let Some((args, objects)) = parsed {
+                decoded.args = args;
+                self.objects.extend(objects);
+                return decoded;
+            }
+        }
+        if !args.is_empty() {
+            decoded.args.push(DecodedArg {
+                name: None,
+                value: args.to_string(),
+            });
+        }
+        decoded
+    }
+
+    /// Decodes raw wire data.
+    ///
+    /// `data` must contain the bytes written to the socket by `side`, starting at a
+    /// message boundary. File descriptors are transmitted out of band and are therefore
+    /// printed as `fd`. `f` is called with every decoded message.
+    ///
+    /// Messages sent to objects with unknown interfaces are returned with their opcode and
+    /// size. An error is returned if the data ends in the middle of a message or if the
+    /// size of a message does not match its arguments.
+    pub fn decode_wire(
+        &mut self,
+        side: Side,
+        data: &[u8],
+        f: impl FnMut(DecodedMessage),
+    ) -> Result<(), crate::Error> {
+        self.decode_wire_(side, data, f)
+            .map_err(|e| crate::Error(Box::new(e)))
+    }
+
+    fn decode_wire_(
+        &mut self,
+        side: Side,
+        data: &[u8],
+        mut f: impl FnMut(DecodedMessage),
+    ) -> Result<(), DecodeError> {
+        let request = side == Side::Client;
+        let mut offset = 0;
+        while offset < data.len() {
+            let header = data
+                .get(offset..offset + 8)
+                .ok_or(DecodeError::Truncated(offset))?;
+            let object = u32::from_ne_bytes(header[..4].try_into().unwrap());
+            let word = u32::from_ne_bytes(header[4..].try_into().unwrap());
+            let opcode = word & 0xffff;
+            let size = (word >> 16) as usize;
+            if size < 8 || size % 4 != 0 {
+                return Err(DecodeError::InvalidSize(offset, size));
+            }
+            let body = data
+                .get(offset + 8..offset + size)
+                .ok_or(DecodeError::Truncated(offset))?;
+            let interface = self.objects.get(&object).cloned();
+            let found = interface.as_deref().and_then(|i| {
+                let i = self.protocols.interface(i)?;
+                let messages = match request {
+                    true => &i.requests,
+                    false => &i.events,
+                };
+                messages.get(opcode as usize).map(|m| (i, m))
+            });
+            let mut decoded = DecodedMessage {
+                prefix: " -> ".to_string(),
+                request,
+                object,
+                interface,
+                message: format!("[opcode {opcode}]"),
+                args: vec![],
+            };
+            match found {
+                Some((interface, message)) => {
+                    let Some((args, objects)) =
+                        parse_wire_args(&self.protocols, &self.objects, interface, message, body)
+                    else {
+                        return Err(DecodeError::InvalidArguments {
+                            offset,
+                            interface: interface.name.clone(),
+                            object,
+                            message: message.name.clone(),
+                        });
+                    };
+                    decoded.message = message.name.clone();
+                    decoded.args = args;
+                    self.objects.extend(objects);
+                }
+                None => decoded.args.push(DecodedArg {
+                    name: None,
+                    value: format!("<{} bytes>", body.len()),
+                }),
+            }
+            f(decoded);
+            offset += size;
+        }
+        Ok(())
+    }
+}
+
+/// The decoded arguments of a message and the objects created by the message.
+type ParsedArgs = (Vec<DecodedArg>, Vec<(u32, String)>);
+
+/// Parses the arguments of a message in a debug log.
+fn parse_text_args(
+    protocols: &Protocols,
+    interface: &Interface,
+    message: &Message,
+    args: &str,
+) -> Option<ParsedArgs> {
+    let params = params(message);
+    let types: Vec<_> = params.iter().map(|p| p.ty).collect();
+    let mut tokens = vec![];
+    if !split_text_args(&types, args, &mut tokens) {
+        return None;
+    }
+    let mut args = vec![];
+    let mut objects = vec![];
+    let mut untyped_interface = None;
+    for (param, token) in params.iter().zip(tokens) {
+        let value = match param.ty {
+            ArgType::Int => match token.parse::<i32>() {
+                Ok(n) => protocols.format_number(interface, param, n as u32),
+                Err(_) => token.to_string(),
+            },
+            ArgType::Uint => match token.parse::<u32>() {
+                Ok(n) => protocols.format_number(interface, param, n),
+                Err(_) => token.to_string(),
+            },
+            ArgType::String => {
+                if param.arg.ty == ArgType::NewId {
+                    untyped_interface = token
+                        .strip_prefix('"')
+                        .and_then(|s| s.strip_suffix('"'))
+                        .map(|s| s.to_string());
+                }
+                token.to_string()
+            }
+            ArgType::NewId => {
+                let new_interface = param.arg.interface.clone().or(untyped_interface.take());
+                let (_, id) = token.rsplit_once('#')?;
+                match new_interface {
+                    Some(new_interface) => {
+                        objects.push((id.parse().ok()?, new_interface.clone()));
+                        token.replace("[unknown]#", &format!("{new_interface}#"))
+                    }
+                    None => token.to_string(),
+                }
+            }
+            _ => token.to_string(),
+        };
+        args.push(DecodedArg {
+            name: Some(param.name.clone()),
+            value,
+        });
+    }
+    Some((args, objects))
+}
+
+/// Splits the arguments of a message in a debug log at the separating `, `.
+///
+/// Strings are printed without escaping and might themselves contain `", `. Therefore
+/// every possible end of a string is tried until the remaining arguments can be split.
+fn split_text_args<'a>(types: &[ArgType], args: &'a str, tokens: &mut Vec<&'a str>) -> bool {
+    let Some((&ty, types)) = types.split_first() else {
+        return args.is_empty();
+    };
+    if types.is_empty() {
+        if ty != ArgType::String && args.contains(", ") {
+            return false;
+        }
+        tokens.push(args);
+        return true;
+    }
+    let ends: Vec<_> = match ty == ArgType::String && args.starts_with('"') {
+        true => args
+            .match_indices("\", ")
+            .filter(|&(idx, _)| idx > 0)
+            .map(|(idx, _)| idx + 1)
+            .collect(),
+        false => args.find(", ").into_iter().collect(),
+    };
+    for end in ends {
+        tokens.push(&args[..end]);
+        if split_text_args(types, &args[end + 2..], tokens) {
+            return true;
+        }
+        tokens.pop();
+    }
+    false
+}
+
+/// Parses the arguments of a message in wire format.
+fn parse_wire_args(
+    protocols: &Protocols,
+    known_objects: &HashMap<u32, String>,
+    interface: &Interface,
+    message: &Message,
+    mut body: &[u8],
+) -> Option<ParsedArgs> {
+    fn read_u32(body: &mut &[u8]) -> Option<u32> {
+        let (word, rest) = body.split_first_chunk::<4>()?;
+        *body = rest;
+        Some(u32::from_ne_bytes(*word))
+    }
+    fn read_bytes<'a>(body: &mut &'a [u8]) -> Option<&'a [u8]> {
+        let len = read_u32(body)? as usize;
+        let padded = len.checked_add(3)? & !3;
+        if padded > body.len() {
+            return None;
+        }
+        let bytes = &body[..len];
+        *body = &body[padded..];
+        Some(bytes)
+    }
+    let mut args = vec![];
+    let mut objects = vec![];
+    let mut untyped_interface = None;
+    for param in params(message) {
+        let value = match param.ty {
+            ArgType::Int | ArgType::Uint => {
+                let n = read_u32(&mut body)?;
+                protocols.format_number(interface, &param, n)
+            }
+            ArgType::Fixed => {
+                let n = read_u32(&mut body)? as i32;
+                (n as f64 / 256.0).to_string()
+            }
+            ArgType::String => {
+                let bytes = read_bytes(&mut body)?;
+                match bytes.split_last() {
+                    None => "nil".to_string(),
+                    Some((_, s)) => {
+                        let s = String::from_utf8_lossy(s);
+                        if param.arg.ty == ArgType::NewId {
+                            untyped_interface = Some(s.to_string());
+                        }
+                        format!("\"{s}\"")
+                    }
+                }
+            }
+            ArgType::Object => match read_u32(&mut body)? {
+                0 => "nil".to_string(),
+                id => {
+                    let interface = known_objects.get(&id).map(|s| &**s);
+                    format!("{}#{id}", interface.unwrap_or("[unknown]"))
+                }
+            },
+            ArgType::NewId => {
+                let id = read_u32(&mut body)?;
+                let new_interface = param.arg.interface.clone().or(untyped_interface.take());
+                match new_interface {
+                    Some(new_interface) => {
+                        let value = format!("new id {new_interface}#{id}");
+                        objects.push((id, new_interface));
+                        value
+                    }
+                    None => format!("new id [unknown]#{id}"),
+                }
+            }
+            ArgType::Array => format!("array[{}]", read_bytes(&mut body)?.len()),
+            ArgType::Fd => "fd".to_string(),
+        };
+        args.push(DecodedArg {
+            name: Some(param.name),
+            value,
+        });
+    }
+    if !body.is_empty() {
+        return None;
+    }
+    Some((args, objects))
+}
@@ -0,0 +1,407 @@
+use crate::{Builder, DecodedArg, DecodedMessage, Decoder, Side};
+
+const XML: &str = r#"
+    <protocol name="test">
+        <interface name="wl_display" version="1">
+            <request name="get_registry">
+                <arg name="registry" type="new_id" interface="wl_registry"/>
+            </request>
+            <event name="error">
+                <arg name="object_id" type="object"/>
+                <arg name="code" type="uint"/>
+                <arg name="message" type="string"/>
+            </event>
+        </interface>
+        <interface name="wl_registry" version="1">
+            <request name="bind">
+                <arg name="name" type="uint"/>
+                <arg name="id" type="new_id"/>
+            </request>
+            <event name="global">
+                <arg name="name" type="uint"/>
+                <arg name="interface" type="string"/>
+                <arg name="version" type="uint"/>
+            </event>
+        </interface>
+        <interface name="test_object" version="1">
+            <enum name="flags" bitfield="true">
+                <entry name="none" value="0"/>
+                <entry name="a" value="1"/>
+                <entry name="b" value="2"/>
+            </enum>
+            <enum name="kind">
+                <entry name="first" value="0"/>
+                <entry name="second" value="1"/>
+            </enum>
+            <request name="set">
+                <arg name="flags" type="uint" enum="flags"/>
+                <arg name="kind" type="int" enum="kind"/>
+                <arg name="n" type="int"/>
+                <arg name="f" type="fixed"/>
+            </request>
+            <request name="send">
+                <arg name="s" type="string" allow-null="true"/>
+                <arg name="o" type="object" interface="test_object" allow-null="true"/>
+                <arg name="a" type="array"/>
+                <arg name="fd" type="fd"/>
+            </request>
+            <event name="done"/>
+        </interface>
+    </protocol>
+"#;
+
+fn decoder() -> Decoder {
+    Builder::default()
+        .for_build_rs(false)
+        .with_default_dir(false)
+        .xml_string(XML)
+        .decoder()
+        .unwrap()
+}
+
+fn decode_lines(decoder: &mut Decoder, side: Side, log: &str) -> Vec<String> {
+    log.lines()
+        .filter_map(|line| decoder.decode_debug_line(side, line.trim_start()))
+        .map(|msg| msg.to_string())
+        .collect()
+}
+
+/// Encodes a message in wire format.
+fn message(object: u32, opcode: u32, args: &[u32]) -> Vec<u8> {
+    let size = 8 + 4 * args.len() as u32;
+    [object, size << 16 | opcode]
+        .iter()
+        .chain(args)
+        .flat_map(|w| w.to_ne_bytes())
+        .collect()
+}
+
+/// Encodes a string argument in wire format.
+fn string(s: &str) -> Vec<u32> {
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.push(0);
+    let len = bytes.len() as u32;
+    bytes.resize(bytes.len().next_multiple_of(4), 0);
+    let mut words = vec![len];
+    words.extend(
+        bytes
+            .chunks(4)
+            .map(|c| u32::from_ne_bytes(c.try_into().unwrap())),
+    );
+    words
+}
+
+fn decode_wire(decoder: &mut Decoder, side: Side, data: &[u8]) -> Result<Vec<String>, String> {
+    let mut decoded = vec![];
+    decoder
+        .decode_wire(side, data, |msg| decoded.push(msg.to_string()))
+        .map_err(|e| e.to_string())?;
+    Ok(decoded)
+}
+
+fn bind_test_object(decoder: &mut Decoder) {
+    let log = r#"
+        [1.000]  -> wl_display#1.get_registry(new id wl_registry#2)
+        [1.001]  -> wl_registry#2.bind(1, "test_object", 1, new id [unknown]#3)
+    "#;
+    decode_lines(decoder, Side::Client, log);
+}
+
+#[test]
+fn debug_client() {
+    let mut decoder = decoder();
+    let log = r#"
+        [1.000]  -> wl_display#1.get_registry(new id wl_registry#2)
+        [1.001] wl_registry#2.global(1, "test_object", 1)
+        [1.002]  -> wl_registry#2.bind(1, "test_object", 1, new id [unknown]#3)
+        [1.003]  -> test_object#3.set(3, 1, -5, 1.5)
+        [1.004]  -> test_object#3.send("a, b", nil, array[4], fd 7)
+        [1.005] test_object#3.done()
+    "#;
+    assert_eq!(
+        decode_lines(&mut decoder, Side::Client, log),
+        [
+            "[1.000]  -> wl_display#1.get_registry(registry: new id wl_registry#2)",
+            r#"[1.001] wl_registry#2.global(name: 1, interface: "test_object", version: 1)"#,
+            r#"[1.002]  -> wl_registry#2.bind(name: 1, id_interface: "test_object", id_version: 1, id: new id test_object#3)"#,
+            "[1.003]  -> test_object#3.set(flags: a|b, kind: second, n: -5, f: 1.5)",
+            r#"[1.004]  -> test_object#3.send(s: "a, b", o: nil, a: array[4], fd: fd 7)"#,
+            "[1.005] test_object#3.done()",
+        ],
+    );
+}
+
+#[test]
+fn debug_server() {
+    let mut decoder = decoder();
+    let log = r#"
+        [2.000] wl_display#1.get_registry(new id wl_registry#2)
+        [2.001]  -> wl_registry#2.global(1, "test_object", 1)
+    "#;
+    let decoded: Vec<_> = log
+        .lines()
+        .filter_map(|line| decoder.decode_debug_line(Side::Server, line.trim_start()))
+        .collect();
+    assert_eq!(decoded.len(), 2);
+    assert!(decoded[0].request);
+    assert_eq!(decoded[0].prefix, "[2.000] ");
+    assert!(!decoded[1].request);
+    assert_eq!(decoded[1].message, "global");
+    assert_eq!(
+        decoded[1].args[1],
+        DecodedArg {
+            name: Some("interface".to_string()),
+            value: r#""test_object""#.to_string(),
+        },
+    );
+}
+
+#[test]
+fn debug_bitfield() {
+    let mut decoder = decoder();
+    bind_test_object(&mut decoder);
+    let log = r#"
+        -> test_object#3.set(0, 0, 0, 0.0)
+        -> test_object#3.set(7, 5, 0, 0.0)
+    "#;
+    assert_eq!(
+        decode_lines(&mut decoder, Side::Client, log),
+        [
+            "-> test_object#3.set(flags: none, kind: first, n: 0, f: 0.0)",
+            "-> test_object#3.set(flags: a|b|0x4, kind: 5, n: 0, f: 0.0)",
+        ],
+    );
+}
+
+#[test]
+fn debug_unknown_interface() {
+    let mut decoder = decoder();
+    bind_test_object(&mut decoder);
+    let log = r#"
+        [3.000] [unknown]#3.done()
+        [3.001] [unknown]#4.done()
+        [3.002] other#5.message(1, 2)
+    "#;
+    assert_eq!(
+        decode_lines(&mut decoder, Side::Client, log),
+        [
+            "[3.000] test_object#3.done()",
+            "[3.001] [unknown]#4.done()",
+            "[3.002] other#5.message(1, 2)",
+        ],
+    );
+}
+
+#[test]
+fn debug_mismatch() {
+    let mut decoder = decoder();
+    bind_test_object(&mut decoder);
+    let log = r#"
+        -> test_object#3.set(1, 2)
+        -> test_object#3.unknown(1)
+    "#;
+    let decoded: Vec<_> = log
+        .lines()
+        .filter_map(|line| decoder.decode_debug_line(Side::Client, line.trim_start()))
+        .collect();
+    assert_eq!(
+        decoded[0].args,
+        [DecodedArg {
+            name: None,
+            value: "1, 2".to_string(),
+        }],
+    );
+    assert_eq!(decoded[1].to_string(), "-> test_object#3.unknown(1)");
+}
+
+#[test]
+fn debug_not_a_message() {
+    let mut decoder = decoder();
+    for line in [
+        "",
+        "hello world",
+        "[1.000] wl_display#1.get_registry(new id wl_registry#2",
+        "[1.000] wl_display.get_registry()",
+        "[1.000] wl_display#x.get_registry()",
+        "[1.000] wl_display#1.()",
+        "[1.000] wl_display#1.get-registry()",
+        "(wl_display#1)",
+    ] {
+        assert_eq!(
+            decoder.decode_debug_line(Side::Client, line),
+            None,
+            "{line}"
+        );
+    }
+}
+
+#[test]
+fn debug_message_fields() {
+    let mut decoder = decoder();
+    let decoded = decoder
+        .decode_debug_line(
+            Side::Client,
+            " -> wl_display#1.get_registry(new id wl_registry#2)",
+        )
+        .unwrap();
+    assert_eq!(
+        decoded,
+        DecodedMessage {
+            prefix: " -> ".to_string(),
+            request: true,
+            object: 1,
+            interface: Some("wl_display".to_string()),
+            message: "get_registry".to_string(),
+            args: vec![DecodedArg {
+                name: Some("registry".to_string()),
+                value: "new id wl_registry#2".to_string(),
+            }],
+        },
+    );
+}
+
+#[test]
+fn wire_requests() {
+    let mut decoder = decoder();
+    let mut data = message(1, 0, &[2]);
+    let mut bind = vec![1];
+    bind.extend(string("test_object"));
+    bind.extend([1, 3]);
+    data.extend(message(2, 0, &bind));
+    data.extend(message(3, 0, &[3, 1, (-5i32) as u32, 384]));
+    let mut send = string("text");
+    send.extend([3, 4, 0]);
+    data.extend(message(3, 1, &send));
+    data.extend(message(3, 1, &[0, 0, 0]));
+    assert_eq!(
+        decode_wire(&mut decoder, Side::Client, &data).unwrap(),
+        [
+            " -> wl_display#1.get_registry(registry: new id wl_registry#2)",
+            r#" -> wl_registry#2.bind(name: 1, id_interface: "test_object", id_version: 1, id: new id test_object#3)"#,
+            " -> test_object#3.set(flags: a|b, kind: second, n: -5, f: 1.5)",
+            r#" -> test_object#3.send(s: "text", o: test_object#3, a: array[4], fd: fd)"#,
+            " -> test_object#3.send(s: nil, o: nil, a: array[0], fd: fd)",
+        ],
+    );
+}
+
+#[test]
+fn wire_events() {
+    let mut decoder = decoder();
+    bind_test_object(&mut decoder);
+    let mut error = vec![3, 1];
+    error.extend(string("bad"));
+    let mut data = message(1, 0, &error);
+    data.extend(message(3, 0, &[]));
+    data.extend(message(4, 2, &[1, 2]));
+    assert_eq!(
+        decode_wire(&mut decoder, Side::Server, &data).unwrap(),
+        [
+            r#" -> wl_display#1.error(object_id: test_object#3, code: 1, message: "bad")"#,
+            " -> test_object#3.done()",
+            " -> [unknown]#4.[opcode 2](<8 bytes>)",
+        ],
+    );
+}
+
+#[test]
+fn wire_unknown_opcode() {
+    let mut decoder = decoder();
+    let data = message(1, 5, &[1]);
+    assert_eq!(
+        decode_wire(&mut decoder, Side::Client, &data).unwrap(),
+        [" -> wl_display#1.[opcode 5](<4 bytes>)"],
+    );
+}
+
+#[test]
+fn wire_truncated() {
+    let mut decoder = decoder();
+    let mut data = message(1, 0, &[2]);
+    data.extend(&message(2, 0, &[1])[..6]);
+    let mut decoded = vec![];
+    let err = decoder
+        .decode_wire(Side::Client, &data, |msg| decoded.push(msg.to_string()))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "The message at offset 12 is truncated");
+    assert_eq!(decoded.len(), 1);
+
+    let data = message(1, 0, &[2]);
+    let err = decode_wire(&mut decoder, Side::Client, &data[..10]).unwrap_err();
+    assert_eq!(err, "The message at offset 0 is truncated");
+}
+
+#[test]
+fn wire_invalid_size() {
+    let mut decoder = decoder();
+    let data: Vec<u8> = [1u32, 4 << 16]
+        .iter()
+        .flat_map(|w| w.to_ne_bytes())
+        .collect();
+    let err = decode_wire(&mut decoder, Side::Client, &data).unwrap_err();
+    assert_eq!(err, "The message at offset 0 has invalid size 4");
+    let data: Vec<u8> = [1u32, 10 << 16, 0, 0]
+        .iter()
+        .flat_map(|w| w.to_ne_bytes())
+        .collect();
+    let err = decode_wire(&mut decoder, Side::Client, &data).unwrap_err();
+    assert_eq!(err, "The message at offset 0 has invalid size 10");
+}
+
+#[test]
+fn wire_invalid_arguments() {
+    let mut decoder = decoder();
+    // Too many arguments.
+    let data = message(1, 0, &[2, 3]);
+    let err = decode_wire(&mut decoder, Side::Client, &data).unwrap_err();
+    assert_eq!(
+        err,
+        "The arguments of wl_display#1.get_registry at offset 0 do not match the protocol",
+    );
+    // Too few arguments.
+    let data = message(1, 0, &[]);
+    assert!(decode_wire(&mut decoder, Side::Client, &data).is_err());
+    // A string that is longer than the message.
+    let data = message(2, 0, &[1, 100, 0]);
+    decode_wire(&mut decoder, Side::Client, &message(1, 0, &[2])).unwrap();
+    let err = decode_wire(&mut decoder, Side::Client, &data).unwrap_err();
+    assert_eq!(
+        err,
+        "The arguments of wl_registry#2.bind at offset 0 do not match the protocol",
+    );
+}
+
+#[test]
+fn preferred_protocol() {
+    let other = r#"
+        <protocol name="other">
+            <interface name="test_object" version="1">
+                <event name="other_done"/>
+            </interface>
+        </protocol>
+    "#;
+    let decoder = |prefer: Option<&str>| {
+        let mut builder = Builder::default()
+            .for_build_rs(false)
+            .with_default_dir(false)
+            .xml_string(XML)
+            .xml_string(other);
+        if let Some(protocol) = prefer {
+            builder = builder.prefer_protocol("test_object", protocol);
+        }
+        builder.decoder().unwrap()
+    };
+    let data = message(3, 0, &[]);
+    let mut first = decoder(None);
+    bind_test_object(&mut first);
+    assert_eq!(
+        decode_wire(&mut first, Side::Server, &data).unwrap(),
+        [" -> test_object#3.done()"],
+    );
+    let mut preferred = decoder(Some("other"));
+    bind_test_object(&mut preferred);
+    assert_eq!(
+        decode_wire(&mut preferred, Side::Server, &data).unwrap(),
+        [" -> test_object#3.other_done()"],
+    );
+}
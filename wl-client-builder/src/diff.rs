@@ -0,0 +1,433 @@
+use {
+    crate::{
+        ast::{Arg, ArgType, Enum, Interface, Location, Message, Protocol},
+        parser::{ParserError, parse},
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        fmt::{Display, Formatter},
+        io,
+        path::{Path, PathBuf},
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error)]
+enum DiffError {
+    #[error("Could not read {}", .0.display())]
+    ReadFile(PathBuf, #[source] io::Error),
+    #[error("Could not parse {}:{}", .0.display(), .1)]
+    ParseFile(PathBuf, Location, #[source] ParserError),
+}
+
+/// The kind of a [`Change`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ChangeKind {
+    /// A protocol was added.
+    ProtocolAdded,
+    /// A protocol was removed.
+    ProtocolRemoved,
+    /// An interface was added.
+    InterfaceAdded,
+    /// An interface was removed.
+    InterfaceRemoved,
+    /// The version of an interface changed.
+    VersionChanged,
+    /// A request or event was added.
+    MessageAdded,
+    /// A request or event was added without a `since` attribute above the previous
+    /// version of the interface.
+    MessageAddedWithoutSince,
+    /// A request or event was removed.
+    MessageRemoved,
+    /// The opcode of a request or event changed.
+    MessageReordered,
+    /// The type of a request or event changed, for example, it became a destructor.
+    MessageTypeChanged,
+    /// An argument was added.
+    ArgAdded,
+    /// An argument was removed.
+    ArgRemoved,
+    /// An argument was renamed.
+    ArgRenamed,
+    /// The type, interface, enum, or nullability of an argument changed.
+    ArgChanged,
+    /// An enum was added.
+    EnumAdded,
+    /// An enum was removed.
+    EnumRemoved,
+    /// An enum became a bitfield or stopped being a bitfield.
+    EnumBitfieldChanged,
+    /// An enum entry was added.
+    EntryAdded,
+    /// An enum entry was removed.
+    EntryRemoved,
+    /// The value of an enum entry changed.
+    EntryValueChanged,
+}
+
+impl ChangeKind {
+    /// Returns the name of this kind in snake case.
+    pub fn name(self) -> &'static str {
+        match self {
+            ChangeKind::ProtocolAdded => "protocol_added",
+            ChangeKind::ProtocolRemoved => "protocol_removed",
+            ChangeKind::InterfaceAdded => "interface_added",
+            ChangeKind::InterfaceRemoved => "interface_removed",
+            ChangeKind::VersionChanged => "version_changed",
+            ChangeKind::MessageAdded => "message_added",
+            ChangeKind::MessageAddedWithoutSince => "message_added_without_since",
+            ChangeKind::MessageRemoved => "message_removed",
+            ChangeKind::MessageReordered => "message_reordered",
+            ChangeKind::MessageTypeChanged => "message_type_changed",
+            ChangeKind::ArgAdded => "arg_added",
+            ChangeKind::ArgRemoved => "arg_removed",
+            ChangeKind::ArgRenamed => "arg_renamed",
+            ChangeKind::ArgChanged => "arg_changed",
+            ChangeKind::EnumAdded => "enum_added",
+            ChangeKind::EnumRemoved => "enum_removed",
+            ChangeKind::EnumBitfieldChanged => "enum_bitfield_changed",
+            ChangeKind::EntryAdded => "entry_added",
+            ChangeKind::EntryRemoved => "entry_removed",
+            ChangeKind::EntryValueChanged => "entry_value_changed",
+        }
+    }
+}
+
+/// A change between two versions of a protocol.
+///
+/// Changes are produced by [`diff`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Change {
+    /// The kind of the change.
+    pub kind: ChangeKind,
+    /// Whether the change breaks existing clients or compositors.
+    pub breaking: bool,
+    /// The changed element, for example, `xdg_shell::xdg_toplevel.set_title`.
+    pub path: String,
+    /// A description of the change.
+    pub description: String,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let compat = match self.breaking {
+            true => "breaking",
+            false => "compatible",
+        };
+        write!(f, "{compat}: {}: {}", self.path, self.description)
+    }
+}
+
+/// Compares two versions of a protocol XML file.
+///
+/// Protocols, interfaces, messages, enums, and entries are matched by name. Arguments
+/// are matched by position. Each change is classified as compatible or breaking:
+///
+/// - Adding protocols, interfaces, enums, and entries is compatible.
+/// - Adding a request or event at the end of an interface is compatible if its
+///   `since` attribute is above the previous version of the interface. Otherwise
+///   existing code that binds the interface with its previous version would receive or
+///   be able to send the new message.
+/// - Increasing the version of an interface is compatible.
+/// - Renaming an argument is compatible.
+/// - Allowing null for an argument of a request is compatible.
+/// - All other changes are breaking.
+///
+/// # Example
+///
+/// ```
+/// # fn no_run() {
+/// let changes = wl_client_builder::diff("old/my-protocol.xml", "new/my-protocol.xml").unwrap();
+/// for change in changes {
+///     println!("{change}");
+/// }
+/// # }
+/// ```
+pub fn diff(old: impl AsRef<Path>, new: impl AsRef<Path>) -> Result<Vec<Change>, crate::Error> {
+    diff_(old.as_ref(), new.as_ref()).map_err(|e| crate::Error(Box::new(e)))
+}
+
+fn diff_(old: &Path, new: &Path) -> Result<Vec<Change>, DiffError> {
+    let old = load(old)?;
+    let new = load(new)?;
+    let mut differ = Differ { changes: vec![] };
+    differ.diff_protocols(&old, &new);
+    Ok(differ.changes)
+}
+
+fn load(path: &Path) -> Result<Vec<Protocol>, DiffError> {
+    let contents = match std::fs::read(path) {
+        Ok(c) => c,
+        Err(e) => return Err(DiffError::ReadFile(path.to_owned(), e)),
+    };
+    match parse(&contents) {
+        Ok(p) => Ok(p),
+        Err((location, e)) => Err(DiffError::ParseFile(path.to_owned(), location, e)),
+    }
+}
+
+/// Returns the name of the type as used in the XML.
+fn type_name(ty: ArgType) -> &'static str {
+    match ty {
+        ArgType::NewId => "new_id",
+        ArgType::Int => "int",
+        ArgType::Uint => "uint",
+        ArgType::Fixed => "fixed",
+        ArgType::String => "string",
+        ArgType::Object => "object",
+        ArgType::Array => "array",
+        ArgType::Fd => "fd",
+    }
+}
+
+struct Differ {
+    changes: Vec<Change>,
+}
+
+/// Returns the elements that are only in `old`, the elements that are in both, and the
+/// elements that are only in `new`.
+///
+/// The common elements are returned together with their indices in `old` and `new`.
+#[allow(clippy::type_complexity)]
+fn split<'a, T>(
+    old: &'a [T],
+    new: &'a [T],
+    name: impl Fn(&T) -> &str,
+) -> (
+    Vec<&'a T>,
+    Vec<((usize, &'a T), (usize, &'a T))>,
+    Vec<(usize, &'a T)>,
+) {
+    let new_by_name: HashMap<_, _> = new
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (name(t), (i, t)))
+        .collect();
+    let old_names: HashSet<_> = old.iter().map(&name).collect();
+    let mut removed = vec![];
+    let mut common = vec![];
+    for (idx, o) in old.iter().enumerate() {
+        match new_by_name.get(name(o)) {
+            Some(&n) => common.push(((idx, o), n)),
+            None => removed.push(o),
+        }
+    }
+    let added = new
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| !old_names.contains(name(n)))
+        .collect();
+    (removed, common, added)
+}
+
+impl Differ {
+    fn add(&mut self, kind: ChangeKind, breaking: bool, path: String, description: String) {
+        self.changes.push(Change {
+            kind,
+            breaking,
+            path,
+            description,
+        });
+    }
+
+    fn diff_protocols(&mut self, old: &[Protocol], new: &[Protocol]) {
+        let (removed, common, added) = split(old, new, |p| &p.name);
+        for p in removed {
+            let desc = "protocol was removed".to_string();
+            self.add(ChangeKind::ProtocolRemoved, true, p.name.clone(), desc);
+        }
+        for ((_, o), (_, n)) in common {
+            self.diff_protocol(o, n);
+        }
+        for (_, p) in added {
+            let desc = "protocol was added".to_string();
+            self.add(ChangeKind::ProtocolAdded, false, p.name.clone(), desc);
+        }
+    }
+
+    fn diff_protocol(&mut self, old: &Protocol, new: &Protocol) {
+        let path = |i: &Interface| format!("{}::{}", old.name, i.name);
+        let (removed, common, added) = split(&old.interfaces, &new.interfaces, |i| &i.name);
+        for i in removed {
+            let desc = "interface was removed".to_string();
+            self.add(ChangeKind::InterfaceRemoved, true, path(i), desc);
+        }
+        for ((_, o), (_, n)) in common {
+            self.diff_interface(&path(o), o, n);
+        }
+        for (_, i) in added {
+            let desc = "interface was added".to_string();
+            self.add(ChangeKind::InterfaceAdded, false, path(i), desc);
+        }
+    }
+
+    fn diff_interface(&mut self, path: &str, old: &Interface, new: &Interface) {
+        if old.version != new.version {
+            let desc = format!("version changed from {} to {}", old.version, new.version);
+            let breaking = new.version < old.version;
+            self.add(ChangeKind::VersionChanged, breaking, path.to_string(), desc);
+        }
+        self.diff_messages(path, "request", old, &old.requests, &new.requests);
+        self.diff_messages(path, "event", old, &old.events, &new.events);
+        let (removed, common, added) = split(&old.enums, &new.enums, |e| &e.name);
+        let enum_path = |e: &Enum| format!("{path}.{}", e.name);
+        for e in removed {
+            let desc = "enum was removed".to_string();
+            self.add(ChangeKind::EnumRemoved, true, enum_path(e), desc);
+        }
+        for ((_, o), (_, n)) in common {
+            self.diff_enum(&enum_path(o), o, n);
+        }
+        for (_, e) in added {
+            let desc = "enum was added".to_string();
+            self.add(ChangeKind::EnumAdded, false, enum_path(e), desc);
+        }
+    }
+
+    fn diff_messages(
+        &mut self,
+        path: &str,
+        kind: &str,
+        old_interface: &Interface,
+        old: &[Message],
+        new: &[Message],
+    ) {
+        let msg_path = |m: &Message| format!("{path}.{}", m.name);
+        let (removed, common, added) = split(old, new, |m| &m.name);
+        for m in removed {
+            let desc = format!("{kind} was removed");
+            self.add(ChangeKind::MessageRemoved, true, msg_path(m), desc);
+        }
+        for ((old_idx, o), (new_idx, n)) in common {
+            let path = msg_path(o);
+            if old_idx != new_idx {
+                let desc = format!("opcode of {kind} changed from {old_idx} to {new_idx}");
+                self.add(ChangeKind::MessageReordered, true, path.clone(), desc);
+            }
+            self.diff_message(&path, kind, o, n);
+        }
+        for (_, m) in added {
+            let since = m.since.unwrap_or(1);
+            if since <= old_interface.version {
+                let desc = format!(
+                    "{kind} was added with since={since} but the interface previously had version {}",
+                    old_interface.version,
+                );
+                self.add(
+                    ChangeKind::MessageAddedWithoutSince,
+                    true,
+                    msg_path(m),
+                    desc,
+                );
+            } else {
+                let desc = format!("{kind} was added with since={since}");
+                self.add(ChangeKind::MessageAdded, false, msg_path(m), desc);
+            }
+        }
+    }
+
+    fn diff_message(&mut self, path: &str, kind: &str, old: &Message, new: &Message) {
+        if old.ty != new.ty {
+            let ty = |m: &Message| match m.ty {
+                Some(_) => "a destructor",
+                None => "not a destructor",
+            };
+            let desc = format!("{kind} was {} and is now {}", ty(old), ty(new));
+            self.add(ChangeKind::MessageTypeChanged, true, path.to_string(), desc);
+        }
+        for (idx, (o, n)) in old.args.iter().zip(&new.args).enumerate() {
+            let arg_path = format!("{path}.{}", n.name);
+            if o.name != n.name {
+                let desc = format!("argument {idx} was renamed from {} to {}", o.name, n.name);
+                self.add(ChangeKind::ArgRenamed, false, arg_path.clone(), desc);
+            }
+            let signature = |a: &Arg| {
+                let mut s = type_name(a.ty).to_string();
+                if let Some(i) = &a.interface {
+                    s.push_str(&format!(" of interface {i}"));
+                }
+                if let Some(e) = &a.enum_ {
+                    s.push_str(&format!(" with enum {e}"));
+                }
+                if a.allow_null {
+                    s.push_str(" allowing null");
+                }
+                s
+            };
+            let (old_sig, new_sig) = (signature(o), signature(n));
+            if old_sig != new_sig {
+                // Clients can always send null to a request that newly allows it.
+                let only_null_allowed = kind == "request"
+                    && !o.allow_null
+                    && n.allow_null
+                    && o.ty == n.ty
+                    && o.interface == n.interface
+                    && o.enum_ == n.enum_;
+                let desc = format!("argument changed from {old_sig} to {new_sig}");
+                self.add(ChangeKind::ArgChanged, !only_null_allowed, arg_path, desc);
+            }
+        }
+        for a in old.args.iter().skip(new.args.len()) {
+            let desc = "argument was removed".to_string();
+            self.add(
+                ChangeKind::ArgRemoved,
+                true,
+                format!("{path}.{}", a.name),
+                desc,
+            );
+        }
+        for a in new.args.iter().skip(old.args.len()) {
+            let desc = "argument was added".to_string();
+            self.add(
+                ChangeKind::ArgAdded,
+                true,
+                format!("{path}.{}", a.name),
+                desc,
+            );
+        }
+    }
+
+    fn diff_enum(&mut self, path: &str, old: &Enum, new: &Enum) {
+        if old.bitfield != new.bitfield {
+            let desc = format!("bitfield changed from {} to {}", old.bitfield, new.bitfield);
+            self.add(
+                ChangeKind::EnumBitfieldChanged,
+                true,
+                path.to_string(),
+                desc,
+            );
+        }
+        let (removed, common, added) = split(&old.entries, &new.entries, |e| &e.name);
+        for e in removed {
+            let desc = "entry was removed".to_string();
+            self.add(
+                ChangeKind::EntryRemoved,
+                true,
+                format!("{path}.{}", e.name),
+                desc,
+            );
+        }
+        for ((_, o), (_, n)) in common {
+            if o.value_u32 != n.value_u32 {
+                let desc = format!("value changed from {} to {}", o.value, n.value);
+                let path = format!("{path}.{}", o.name);
+                self.add(ChangeKind::EntryValueChanged, true, path, desc);
+            }
+        }
+        for (_, e) in added {
+            let desc = format!("entry was added with value {}", e.value);
+            self.add(
+                ChangeKind::EntryAdded,
+                false,
+                format!("{path}.{}", e.name),
+                desc,
+            );
+        }
+    }
+}
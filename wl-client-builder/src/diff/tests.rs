@@ -0,0 +1,329 @@
+use {
+    crate::{
+        diff::{Change, ChangeKind, Differ, diff},
+        parser::parse,
+    },
+    std::fs,
+};
+
+fn changes(old: &str, new: &str) -> Vec<Change> {
+    let old = parse(old.as_bytes()).unwrap_or_else(|(_, e)| panic!("{e}"));
+    let new = parse(new.as_bytes()).unwrap_or_else(|(_, e)| panic!("{e}"));
+    let mut differ = Differ { changes: vec![] };
+    differ.diff_protocols(&old, &new);
+    differ.changes
+}
+
+fn check(old: &str, new: &str, expected: &[(ChangeKind, &str)]) {
+    let changes = changes(old, new);
+    let actual: Vec<_> = changes.iter().map(|c| (c.kind, c.to_string())).collect();
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|(kind, s)| (*kind, s.to_string()))
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+fn protocol(interfaces: &str) -> String {
+    format!(r#"<protocol name="p">{interfaces}</protocol>"#)
+}
+
+#[test]
+fn unchanged() {
+    let xml = protocol(
+        r#"
+        <interface name="a" version="1">
+            <request name="r">
+                <arg name="x" type="int"/>
+            </request>
+            <enum name="e">
+                <entry name="x" value="0"/>
+            </enum>
+        </interface>
+    "#,
+    );
+    check(&xml, &xml, &[]);
+}
+
+#[test]
+fn protocols() {
+    check(
+        r#"<protocol name="a"/>"#,
+        r#"<protocol name="b"/>"#,
+        &[
+            (
+                ChangeKind::ProtocolRemoved,
+                "breaking: a: protocol was removed",
+            ),
+            (
+                ChangeKind::ProtocolAdded,
+                "compatible: b: protocol was added",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn interfaces() {
+    check(
+        &protocol(r#"<interface name="a" version="1"/>"#),
+        &protocol(r#"<interface name="b" version="1"/>"#),
+        &[
+            (
+                ChangeKind::InterfaceRemoved,
+                "breaking: p::a: interface was removed",
+            ),
+            (
+                ChangeKind::InterfaceAdded,
+                "compatible: p::b: interface was added",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn versions() {
+    check(
+        &protocol(r#"<interface name="a" version="1"/>"#),
+        &protocol(r#"<interface name="a" version="2"/>"#),
+        &[(
+            ChangeKind::VersionChanged,
+            "compatible: p::a: version changed from 1 to 2",
+        )],
+    );
+    check(
+        &protocol(r#"<interface name="a" version="2"/>"#),
+        &protocol(r#"<interface name="a" version="1"/>"#),
+        &[(
+            ChangeKind::VersionChanged,
+            "breaking: p::a: version changed from 2 to 1",
+        )],
+    );
+}
+
+#[test]
+fn messages() {
+    check(
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <request name="r1"/>
+                <request name="r2"/>
+                <event name="e1"/>
+            </interface>
+        "#,
+        ),
+        &protocol(
+            r#"
+            <interface name="a" version="2">
+                <request name="r2"/>
+                <request name="r3" since="2"/>
+                <request name="r4"/>
+                <event name="e1" type="destructor"/>
+            </interface>
+        "#,
+        ),
+        &[
+            (
+                ChangeKind::VersionChanged,
+                "compatible: p::a: version changed from 1 to 2",
+            ),
+            (
+                ChangeKind::MessageRemoved,
+                "breaking: p::a.r1: request was removed",
+            ),
+            (
+                ChangeKind::MessageReordered,
+                "breaking: p::a.r2: opcode of request changed from 1 to 0",
+            ),
+            (
+                ChangeKind::MessageAdded,
+                "compatible: p::a.r3: request was added with since=2",
+            ),
+            (
+                ChangeKind::MessageAddedWithoutSince,
+                "breaking: p::a.r4: request was added with since=1 but the interface previously had version 1",
+            ),
+            (
+                ChangeKind::MessageTypeChanged,
+                "breaking: p::a.e1: event was not a destructor and is now a destructor",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn args() {
+    check(
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <request name="r">
+                    <arg name="x" type="int"/>
+                    <arg name="o" type="object" interface="a"/>
+                    <arg name="removed" type="uint"/>
+                </request>
+                <event name="ev">
+                    <arg name="o" type="object" interface="a"/>
+                </event>
+            </interface>
+        "#,
+        ),
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <request name="r">
+                    <arg name="y" type="uint"/>
+                    <arg name="o" type="object" interface="a" allow-null="true"/>
+                </request>
+                <event name="ev">
+                    <arg name="o" type="object" interface="a" allow-null="true"/>
+                    <arg name="added" type="fd"/>
+                </event>
+            </interface>
+        "#,
+        ),
+        &[
+            (
+                ChangeKind::ArgRenamed,
+                "compatible: p::a.r.y: argument 0 was renamed from x to y",
+            ),
+            (
+                ChangeKind::ArgChanged,
+                "breaking: p::a.r.y: argument changed from int to uint",
+            ),
+            (
+                ChangeKind::ArgChanged,
+                "compatible: p::a.r.o: argument changed from object of interface a to object of interface a allowing null",
+            ),
+            (
+                ChangeKind::ArgRemoved,
+                "breaking: p::a.r.removed: argument was removed",
+            ),
+            (
+                ChangeKind::ArgChanged,
+                "breaking: p::a.ev.o: argument changed from object of interface a to object of interface a allowing null",
+            ),
+            (
+                ChangeKind::ArgAdded,
+                "breaking: p::a.ev.added: argument was added",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn arg_enum() {
+    check(
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <request name="r">
+                    <arg name="x" type="uint"/>
+                </request>
+            </interface>
+        "#,
+        ),
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <request name="r">
+                    <arg name="x" type="uint" enum="e"/>
+                </request>
+            </interface>
+        "#,
+        ),
+        &[(
+            ChangeKind::ArgChanged,
+            "breaking: p::a.r.x: argument changed from uint to uint with enum e",
+        )],
+    );
+}
+
+#[test]
+fn enums() {
+    check(
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <enum name="removed"/>
+                <enum name="e">
+                    <entry name="removed" value="0"/>
+                    <entry name="changed" value="1"/>
+                    <entry name="same" value="0x2"/>
+                </enum>
+            </interface>
+        "#,
+        ),
+        &protocol(
+            r#"
+            <interface name="a" version="1">
+                <enum name="e" bitfield="true">
+                    <entry name="changed" value="4"/>
+                    <entry name="same" value="2"/>
+                    <entry name="added" value="8"/>
+                </enum>
+                <enum name="added"/>
+            </interface>
+        "#,
+        ),
+        &[
+            (
+                ChangeKind::EnumRemoved,
+                "breaking: p::a.removed: enum was removed",
+            ),
+            (
+                ChangeKind::EnumBitfieldChanged,
+                "breaking: p::a.e: bitfield changed from false to true",
+            ),
+            (
+                ChangeKind::EntryRemoved,
+                "breaking: p::a.e.removed: entry was removed",
+            ),
+            (
+                ChangeKind::EntryValueChanged,
+                "breaking: p::a.e.changed: value changed from 1 to 4",
+            ),
+            (
+                ChangeKind::EntryAdded,
+                "compatible: p::a.e.added: entry was added with value 8",
+            ),
+            (
+                ChangeKind::EnumAdded,
+                "compatible: p::a.added: enum was added",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn kind_names() {
+    assert_eq!(ChangeKind::ProtocolAdded.name(), "protocol_added");
+    assert_eq!(
+        ChangeKind::MessageAddedWithoutSince.name(),
+        "message_added_without_since",
+    );
+    assert_eq!(ChangeKind::EntryValueChanged.name(), "entry_value_changed");
+}
+
+#[test]
+fn files() {
+    let dir = tempfile::tempdir().unwrap();
+    let old = dir.path().join("old.xml");
+    let new = dir.path().join("new.xml");
+    fs::write(&old, protocol(r#"<interface name="a" version="1"/>"#)).unwrap();
+    fs::write(&new, protocol(r#"<interface name="a" version="2"/>"#)).unwrap();
+    let changes = diff(&old, &new).unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].kind, ChangeKind::VersionChanged);
+    assert!(!changes[0].breaking);
+    assert_eq!(changes[0].path, "p::a");
+
+    let missing = dir.path().join("missing.xml");
+    let err = diff(&old, &missing).unwrap_err().to_string();
+    assert_eq!(err, format!("Could not read {}", missing.display()));
+
+    fs::write(&new, "<protocol").unwrap();
+    let err = diff(&old, &new).unwrap_err().to_string();
+    assert_eq!(err, format!("Could not parse {}:1:10", new.display()));
+}
@@ -1,8 +1,12 @@
 use {
-    crate::ast::{Arg, ArgType, Description, Interface, Message, MessageType, Protocol},
+    crate::{
+        ast::{Arg, ArgType, Description, Enum, Interface, Message, MessageType, Protocol},
+        namespace::Namespaces,
+    },
     debug_fn::debug_fn,
     phf::phf_set,
     std::{
+        collections::HashMap,
         fmt::{Display, Write as FmtWrite},
         io::{self, Write},
     },
@@ -30,6 +34,7 @@ macro_rules! define_w {
 fn format_interface_header(
     w: &mut impl Write,
     prefix: &str,
+    types: &str,
     interface: &Interface,
 ) -> io::Result<()> {
     define_w!(w);
@@ -38,40 +43,74 @@ fn format_interface_header(
         wl!()?;
     }
     wl!("use {prefix}::builder::prelude::*;")?;
-    wl!("use super::super::all_types::*;")?;
+    wl!("use super::super::{types}::*;")?;
     Ok(())
 }
 
+/// How the `wl_interface` of an interface is defined.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InterfaceSymbol {
+    /// A private static.
+    Private,
+    /// A static that is exported as a C symbol.
+    Exported,
+    /// The symbol defined by libwayland-client.
+    Libwayland,
+}
+
 pub fn format_interface_file(
     w: &mut impl Write,
     root: &str,
-    mutable_data: bool,
+    types: &str,
+    protocol: &str,
     interface: &Interface,
+    symbol: InterfaceSymbol,
 ) -> io::Result<()> {
     define_w!(w);
-    format_interface_header(w, root, interface)?;
+    format_interface_header(w, root, types, interface)?;
     wl!()?;
-    format_wl_interface(w, interface)?;
+    format_wl_interface(w, interface, symbol)?;
+    wl!()?;
+    format_interface_info(w, protocol, interface)?;
     wl!()?;
     format_interface_types(w, interface)?;
     wl!()?;
-    format_interface_trait_impls(w, interface)?;
+    format_interface_trait_impls(w, interface, symbol)?;
     wl!()?;
-    format_interface_requests(w, interface)?;
+    format_interface_requests(w, interface, symbol)?;
     wl!()?;
-    format_interface_event_handler(w, mutable_data, interface)?;
+    format_interface_event_handler(w, interface)?;
     wl!()?;
-    format_event_handler(w, mutable_data, interface)?;
+    format_event_handler(w, interface, symbol)?;
     wl!()?;
     format_interface_enums(w, interface)?;
     wl!()?;
-    format_event_handlers(w, mutable_data, interface)?;
+    format_event_handlers(w, interface)?;
     Ok(())
 }
 
-fn format_wl_interface(w: &mut impl Write, interface: &Interface) -> io::Result<()> {
+fn format_wl_interface(
+    w: &mut impl Write,
+    interface: &Interface,
+    symbol: InterfaceSymbol,
+) -> io::Result<()> {
     define_w!(w);
-    wl!(r#"static INTERFACE: wl_interface = wl_interface {{"#)?;
+    if symbol == InterfaceSymbol::Exported {
+        wl!(r#"use {}_interface as INTERFACE;"#, interface.name)?;
+        wl!()?;
+        wl!(
+            r#"/// The `wl_interface` of the {} interface, exported as a C symbol."#,
+            interface.name
+        )?;
+        wl!(r#"#[unsafe(no_mangle)]"#)?;
+        wl!(r#"#[allow(non_upper_case_globals)]"#)?;
+        wl!(
+            r#"pub static {}_interface: wl_interface = wl_interface {{"#,
+            interface.name
+        )?;
+    } else {
+        wl!(r#"static INTERFACE: wl_interface = wl_interface {{"#)?;
+    }
     wl!(r#"    name: c"{}".as_ptr(),"#, interface.name)?;
     wl!(r#"    version: {},"#, interface.version)?;
     wl!(r#"    method_count: {},"#, interface.requests.len())?;
@@ -85,9 +124,153 @@ fn format_wl_interface(w: &mut impl Write, interface: &Interface) -> io::Result<
         format_wl_interface_messages(&interface.events)
     )?;
     wl!(r#"}};"#)?;
+    if symbol == InterfaceSymbol::Libwayland {
+        wl!()?;
+        wl!(
+            r#"/// The `wl_interface` of the {} interface, defined by libwayland-client."#,
+            interface.name
+        )?;
+        wl!(r#"///"#)?;
+        wl!(r#"/// INTERFACE is used if libwayland-client does not define the symbol."#)?;
+        wl!(
+            r#"static LIBWAYLAND_INTERFACE: LibwaylandInterface = LibwaylandInterface::new(c"{}_interface", &INTERFACE);"#,
+            interface.name
+        )?;
+    }
+    Ok(())
+}
+
+fn format_interface_info(
+    w: &mut impl Write,
+    protocol: &str,
+    interface: &Interface,
+) -> io::Result<()> {
+    define_w!(w);
+    wl!(r#"/// Metadata about the {} interface."#, interface.name)?;
+    wl!(r#"#[allow(dead_code)]"#)?;
+    wl!(r#"pub static INFO: info::InterfaceInfo = info::InterfaceInfo {{"#)?;
+    wl!(r#"    name: "{}","#, interface.name)?;
+    wl!(r#"    protocol: "{protocol}","#)?;
+    wl!(r#"    version: {},"#, interface.version)?;
+    wl!(
+        r#"    requests: {},"#,
+        format_messages_info(&interface.requests)
+    )?;
+    wl!(
+        r#"    events: {},"#,
+        format_messages_info(&interface.events)
+    )?;
+    wl!(r#"    enums: {},"#, format_enums_info(&interface.enums))?;
+    wl!(r#"}};"#)?;
     Ok(())
 }
 
+fn format_optional_u32(v: Option<u32>) -> impl Display {
+    debug_fn(move |f| match v {
+        Some(v) => write!(f, "Some({v})"),
+        None => f.write_str("None"),
+    })
+}
+
+fn format_optional_str(v: Option<&str>) -> impl Display + use<'_> {
+    debug_fn(move |f| match v {
+        Some(v) => write!(f, r#"Some("{v}")"#),
+        None => f.write_str("None"),
+    })
+}
+
+fn format_messages_info(messages: &[Message]) -> impl Display + use<'_> {
+    debug_fn(move |f| {
+        define_w!(f);
+        if messages.is_empty() {
+            return f.write_str("&[]");
+        }
+        wl!(r#"&["#)?;
+        for message in messages {
+            wl!(r#"        info::MessageInfo {{"#)?;
+            wl!(r#"            name: "{}","#, message.name)?;
+            wl!(r#"            since: {},"#, message.since.unwrap_or(1))?;
+            wl!(
+                r#"            deprecated_since: {},"#,
+                format_optional_u32(message.deprecated_since),
+            )?;
+            wl!(
+                r#"            destructor: {},"#,
+                message.ty == Some(MessageType::Destructor),
+            )?;
+            if message.args.is_empty() {
+                wl!(r#"            args: &[],"#)?;
+            } else {
+                wl!(r#"            args: &["#)?;
+                for arg in &message.args {
+                    let ty = match arg.ty {
+                        ArgType::NewId => "NewId",
+                        ArgType::Int => "Int",
+                        ArgType::Uint => "Uint",
+                        ArgType::Fixed => "Fixed",
+                        ArgType::String => "String",
+                        ArgType::Object => "Object",
+                        ArgType::Array => "Array",
+                        ArgType::Fd => "Fd",
+                    };
+                    wl!(r#"                info::ArgInfo {{"#)?;
+                    wl!(r#"                    name: "{}","#, arg.name)?;
+                    wl!(r#"                    ty: info::ArgType::{ty},"#)?;
+                    wl!(
+                        r#"                    interface: {},"#,
+                        format_optional_str(arg.interface.as_deref()),
+                    )?;
+                    wl!(r#"                    allow_null: {},"#, arg.allow_null)?;
+                    wl!(
+                        r#"                    enum_: {},"#,
+                        format_optional_str(arg.enum_.as_deref()),
+                    )?;
+                    wl!(r#"                }},"#)?;
+                }
+                wl!(r#"            ],"#)?;
+            }
+            wl!(r#"        }},"#)?;
+        }
+        w!(r#"    ]"#)?;
+        Ok(())
+    })
+}
+
+fn format_enums_info(enums: &[Enum]) -> impl Display + use<'_> {
+    debug_fn(move |f| {
+        define_w!(f);
+        if enums.is_empty() {
+            return f.write_str("&[]");
+        }
+        wl!(r#"&["#)?;
+        for enum_ in enums {
+            wl!(r#"        info::EnumInfo {{"#)?;
+            wl!(r#"            name: "{}","#, enum_.name)?;
+            wl!(r#"            since: {},"#, enum_.since.unwrap_or(1))?;
+            wl!(r#"            bitfield: {},"#, enum_.bitfield)?;
+            wl!(r#"            entries: &["#)?;
+            for entry in &enum_.entries {
+                wl!(r#"                info::EntryInfo {{"#)?;
+                wl!(r#"                    name: "{}","#, entry.name)?;
+                wl!(r#"                    value: {},"#, entry.value_u32)?;
+                wl!(
+                    r#"                    since: {},"#,
+                    entry.since.unwrap_or(1)
+                )?;
+                wl!(
+                    r#"                    deprecated_since: {},"#,
+                    format_optional_u32(entry.deprecated_since),
+                )?;
+                wl!(r#"                }},"#)?;
+            }
+            wl!(r#"            ],"#)?;
+            wl!(r#"        }},"#)?;
+        }
+        w!(r#"    ]"#)?;
+        Ok(())
+    })
+}
+
 fn format_wl_interface_messages(messages: &[Message]) -> impl Display + use<'_> {
     debug_fn(move |f| {
         define_w!(f);
@@ -232,7 +415,11 @@ fn format_interface_types(w: &mut impl Write, interface: &Interface) -> io::Resu
     Ok(())
 }
 
-fn format_interface_requests(w: &mut impl Write, interface: &Interface) -> io::Result<()> {
+fn format_interface_requests(
+    w: &mut impl Write,
+    interface: &Interface,
+    symbol: InterfaceSymbol,
+) -> io::Result<()> {
     define_w!(w);
     if interface.requests.is_empty() {
         return Ok(());
@@ -451,11 +638,26 @@ fn format_interface_requests(w: &mut impl Write, interface: &Interface) -> io::R
                 wl!(r#"{prefix}let mut args = [];"#)?;
             };
             let is_destructor = request.ty == Some(MessageType::Destructor);
+            if symbol == InterfaceSymbol::Libwayland {
+                wl!(
+                    r#"{prefix}check_request_opcode(&self.proxy, "{}", {idx});"#,
+                    interface.name,
+                )?;
+            }
             wl!(r#"{prefix}// SAFETY: - self.proxy has the interface INTERFACE"#,)?;
-            wl!(
-                r#"{prefix}//         - {idx} < INTERFACE.method_count = {}"#,
-                interface.requests.len(),
-            )?;
+            if symbol == InterfaceSymbol::Libwayland {
+                wl!(
+                    r#"{prefix}//           or LIBWAYLAND_INTERFACE, whose requests are a prefix of INTERFACE"#
+                )?;
+                wl!(
+                    r#"{prefix}//         - {idx} < the method_count of the interface of self.proxy was checked above"#
+                )?;
+            } else {
+                wl!(
+                    r#"{prefix}//         - {idx} < INTERFACE.method_count = {}"#,
+                    interface.requests.len(),
+                )?;
+            }
             wl!(
                 r#"{prefix}//         - the request signature is `{}`"#,
                 format_signature(request),
@@ -466,10 +668,14 @@ fn format_interface_requests(w: &mut impl Write, interface: &Interface) -> io::R
                 )?;
                 wl!(r#"{prefix}let data = unsafe {{"#)?;
                 let interface = debug_fn(|f| {
-                    if let Some(i) = &arg.interface {
-                        write!(f, "{}::WL_INTERFACE", format_camel(i))
+                    let camel = match &arg.interface {
+                        Some(i) => format_camel(i).to_string(),
+                        None => "P".to_string(),
+                    };
+                    if symbol == InterfaceSymbol::Libwayland {
+                        write!(f, "proxy_interface::<{camel}>()")
                     } else {
-                        f.write_str("P::WL_INTERFACE")
+                        write!(f, "{camel}::WL_INTERFACE")
                     }
                 });
                 let version = debug_fn(|f| {
@@ -558,11 +764,7 @@ fn format_since(
     Ok(())
 }
 
-fn format_interface_event_handler(
-    w: &mut impl Write,
-    mutable_data: bool,
-    interface: &Interface,
-) -> io::Result<()> {
+fn format_interface_event_handler(w: &mut impl Write, interface: &Interface) -> io::Result<()> {
     define_w!(w);
     let snake = &interface.name;
     let camel = format_camel(snake).to_string();
@@ -578,22 +780,144 @@ fn format_interface_event_handler(
         wl!()?;
     }
     wl!(r#"/// An event handler for [{camel}] proxies."#)?;
-    wl!(r#"#[allow(dead_code)]"#)?;
-    wl!(r#"pub trait {camel}EventHandler {{"#)?;
-    if mutable_data {
-        wl!(r#"    type Data: 'static;"#)?;
-        wl!()?;
+    format_event_handler_trait(w, interface, false, false)?;
+    wl!()?;
+    wl!(r#"impl {camel}EventHandler for private::NoOpEventHandler {{ }}"#)?;
+    wl!()?;
+    wl!(r#"/// An event handler for [{camel}] proxies with mutable data."#)?;
+    wl!(r#"///"#)?;
+    wl!(
+        r#"/// This trait is the same as [{camel}EventHandler] except that the functions take an"#
+    )?;
+    wl!(
+        r#"/// additional parameter that gives access to the mutable data that was passed in when"#
+    )?;
+    wl!(r#"/// dispatching the queue."#)?;
+    format_event_handler_trait(w, interface, false, true)?;
+    wl!()?;
+    wl!(r#"/// A mutable event handler for [{camel}] proxies."#)?;
+    wl!(r#"///"#)?;
+    wl!(r#"/// This trait is the same as [{camel}EventHandler] except that the functions take"#)?;
+    wl!(r#"/// `&mut self`. Such event handlers can be attached to proxies on local queues with"#)?;
+    wl!(r#"/// [`proxy::set_event_handler_local_mut`]."#)?;
+    format_event_handler_trait(w, interface, true, false)?;
+    wl!()?;
+    wl!(r#"/// A mutable event handler for [{camel}] proxies with mutable data."#)?;
+    wl!(r#"///"#)?;
+    wl!(
+        r#"/// This trait is the same as [{camel}EventHandlerWithData] except that the functions"#
+    )?;
+    wl!(r#"/// take `&mut self`. Such event handlers can be attached to proxies on local queues"#)?;
+    wl!(r#"/// with [`proxy::set_event_handler_local_mut`]."#)?;
+    format_event_handler_trait(w, interface, true, true)?;
+    wl!()?;
+    format_mut_event_handler_impl(w, interface, false)?;
+    wl!()?;
+    format_mut_event_handler_impl(w, interface, true)?;
+    wl!()?;
+    format_projected_event_handler_impl(w, interface, false)?;
+    wl!()?;
+    format_projected_event_handler_impl(w, interface, true)?;
+    Ok(())
+}
+
+fn format_projected_event_handler_impl(
+    w: &mut impl Write,
+    interface: &Interface,
+    mutable: bool,
+) -> io::Result<()> {
+    define_w!(w);
+    let camel = format_camel(&interface.name).to_string();
+    let slf = match mutable {
+        true => "&mut self",
+        false => "&self",
+    };
+    wl!(
+        r#"impl<T, U, H> {} for ProjectedEventHandler<H, T, U>"#,
+        handler_trait(&camel, mutable, true)
+    )?;
+    wl!(r#"where"#)?;
+    wl!(
+        r#"    H: {},"#,
+        handler_trait(&camel, mutable, true).replace("<T>", "<U>")
+    )?;
+    wl!(r#"{{"#)?;
+    for (idx, event) in interface.events.iter().enumerate() {
+        if idx > 0 {
+            wl!()?;
+        }
+        let name = escape_name(&event.name);
+        wl!(r#"    #[inline]"#)?;
+        w!(r#"    fn {name}({slf}, _data: &mut T, _slf: &{camel}Ref"#)?;
+        for arg in &event.args {
+            w!(
+                ", {}: {}",
+                escape_name(&arg.name),
+                arg_type(interface, arg, false)
+            )?;
+        }
+        wl!(r#") {{"#)?;
+        wl!(r#"        let _data = self.project(_data);"#)?;
+        match mutable {
+            true => w!(r#"        self.handler_mut().{name}(_data, _slf"#)?,
+            false => w!(r#"        self.handler().{name}(_data, _slf"#)?,
+        }
+        for arg in &event.args {
+            w!(", {}", escape_name(&arg.name))?;
+        }
+        wl!(r#")"#)?;
+        wl!(r#"    }}"#)?;
     }
+    wl!(r#"}}"#)?;
+    Ok(())
+}
+
+/// Returns the name of an event handler trait of an interface.
+///
+/// `mutable` selects the trait whose functions take `&mut self`. `data` selects the
+/// trait whose functions take mutable data of type `T`.
+fn handler_trait(camel: &str, mutable: bool, data: bool) -> String {
+    let mut name = format!("{camel}EventHandler");
+    if mutable {
+        name.push_str("Mut");
+    }
+    if data {
+        name.push_str("WithData<T>");
+    }
+    name
+}
+
+fn format_event_handler_trait(
+    w: &mut impl Write,
+    interface: &Interface,
+    mutable: bool,
+    data: bool,
+) -> io::Result<()> {
+    define_w!(w);
+    let camel = format_camel(&interface.name).to_string();
+    wl!(r#"#[allow(dead_code)]"#)?;
+    wl!(r#"pub trait {} {{"#, handler_trait(&camel, mutable, data))?;
     for (idx, event) in interface.events.iter().enumerate() {
         if idx > 0 {
             wl!()?;
         }
-        format_message_doc(w, false, false, event)?;
+        let name = escape_name(&event.name);
+        if mutable {
+            wl!(
+                r#"    /// See [{}::{name}]."#,
+                handler_trait(&camel, false, false)
+            )?;
+        } else {
+            format_message_doc(w, false, false, event)?;
+        }
         wl!(r#"    #[inline]"#)?;
-        wl!(r#"    fn {}("#, escape_name(&event.name))?;
-        wl!(r#"        &self,"#)?;
-        if mutable_data {
-            wl!(r#"        _data: &mut Self::Data,"#)?;
+        wl!(r#"    fn {name}("#)?;
+        match mutable {
+            true => wl!(r#"        &mut self,"#)?,
+            false => wl!(r#"        &self,"#)?,
+        }
+        if data {
+            wl!(r#"        _data: &mut T,"#)?;
         }
         wl!(r#"        _slf: &{camel}Ref,"#)?;
         for arg in &event.args {
@@ -610,15 +934,50 @@ fn format_interface_event_handler(
         wl!(r#"    }}"#)?;
     }
     wl!(r#"}}"#)?;
-    wl!()?;
-    w!(r#"impl {camel}EventHandler for private::NoOpEventHandler {{"#)?;
-    if mutable_data {
-        wl!()?;
-        wl!(r#"    type Data = ();"#)?;
-        wl!(r#"}}"#)?;
-    } else {
-        wl!(r#" }}"#)?;
+    Ok(())
+}
+
+fn format_mut_event_handler_impl(
+    w: &mut impl Write,
+    interface: &Interface,
+    data: bool,
+) -> io::Result<()> {
+    define_w!(w);
+    let camel = format_camel(&interface.name).to_string();
+    let (generics, data_param, data_arg) = match data {
+        true => ("T, H", ", _data: &mut T", "_data, "),
+        false => ("H", "", ""),
+    };
+    wl!(
+        r#"impl<{generics}> {} for MutEventHandler<H>"#,
+        handler_trait(&camel, false, data)
+    )?;
+    wl!(r#"where"#)?;
+    wl!(r#"    H: {},"#, handler_trait(&camel, true, data))?;
+    wl!(r#"{{"#)?;
+    for (idx, event) in interface.events.iter().enumerate() {
+        if idx > 0 {
+            wl!()?;
+        }
+        let name = escape_name(&event.name);
+        wl!(r#"    #[inline]"#)?;
+        w!(r#"    fn {name}(&self{data_param}, _slf: &{camel}Ref"#)?;
+        for arg in &event.args {
+            w!(
+                ", {}: {}",
+                escape_name(&arg.name),
+                arg_type(interface, arg, false)
+            )?;
+        }
+        wl!(r#") {{"#)?;
+        w!(r#"        self.with(|h| h.{name}({data_arg}_slf"#)?;
+        for arg in &event.args {
+            w!(", {}", escape_name(&arg.name))?;
+        }
+        wl!(r#"))"#)?;
+        wl!(r#"    }}"#)?;
     }
+    wl!(r#"}}"#)?;
     Ok(())
 }
 
@@ -672,32 +1031,160 @@ fn arg_type<'a>(interface: &'a Interface, arg: &'a Arg, request: bool) -> impl D
 #[allow(clippy::type_complexity)]
 pub fn format_mod_file(
     w: &mut impl Write,
+    root: &str,
+    protocol_features: bool,
     protocols: &[(String, Vec<(String, Vec<String>)>)],
+    extern_protocols: &HashMap<String, String>,
+    preferred_protocols: &HashMap<String, String>,
+    namespaces: &Namespaces,
 ) -> io::Result<()> {
     define_w!(w);
-    for (protocol, _) in protocols {
+    let local_protocols = || {
+        protocols
+            .iter()
+            .filter(|(protocol, _)| !extern_protocols.contains_key(protocol))
+    };
+    let cfg = |protocol: &str| match protocol_features {
+        true => format!(r#"#[cfg(feature = "{protocol}")] "#),
+        false => String::new(),
+    };
+    for (protocol, _) in local_protocols() {
+        if protocol_features {
+            wl!(r#"#[cfg(feature = "{protocol}")]"#)?;
+        }
         wl!(r#"pub mod {};"#, protocol)?;
     }
     wl!()?;
+    wl!(r#"/// Metadata about all protocols in this module."#)?;
+    wl!(r#"#[allow(dead_code)]"#)?;
+    wl!(r#"pub static PROTOCOLS: &[&{root}::info::ProtocolInfo] = &["#)?;
+    for (protocol, _) in local_protocols() {
+        wl!(r#"    {}&{protocol}::PROTOCOL,"#, cfg(protocol))?;
+    }
+    wl!(r#"];"#)?;
+    wl!()?;
+    wl!(r#"/// Returns the metadata of the interface with the given name."#)?;
+    wl!(r#"///"#)?;
+    wl!(r#"/// If multiple protocols define an interface with this name, the metadata of the"#)?;
+    wl!(r#"/// preferred protocol is returned. If no protocol is preferred, `None` is returned"#)?;
+    wl!(r#"/// and the interface can only be found via [`PROTOCOLS`]."#)?;
+    wl!(r#"#[allow(dead_code, unreachable_code, clippy::match_single_binding)]"#)?;
+    wl!(r#"pub fn interface_info(name: &str) -> Option<&'static {root}::info::InterfaceInfo> {{"#)?;
+    wl!(r#"    let info = match name {{"#)?;
+    for (protocol, interfaces) in local_protocols() {
+        for (snake, _) in interfaces {
+            if namespaces.duplicates.contains(snake)
+                && preferred_protocols.get(snake) != Some(protocol)
+            {
+                continue;
+            }
+            wl!(
+                r#"        {}"{snake}" => &{protocol}::{snake}::INFO,"#,
+                cfg(protocol),
+            )?;
+        }
+    }
+    wl!(r#"        _ => return None,"#)?;
+    wl!(r#"    }};"#)?;
+    wl!(r#"    Some(info)"#)?;
+    wl!(r#"}}"#)?;
+    wl!()?;
     wl!("#[allow(unused_imports)]")?;
     wl!("mod all_types {{")?;
     for (proto, interfaces) in protocols {
+        let interfaces: Vec<_> = interfaces
+            .iter()
+            .filter(|(snake, _)| !namespaces.duplicates.contains(snake))
+            .collect();
+        if interfaces.is_empty() {
+            continue;
+        }
+        let (path, gated) = match extern_protocols.get(proto) {
+            Some(path) => (path.clone(), false),
+            None => (format!("super::{proto}"), protocol_features),
+        };
+        if gated {
+            wl!(r#"    #[cfg(feature = "{proto}")]"#)?;
+            wl!(r#"    pub(super) use {path}::{{"#)?;
+        }
         for (snake, enums) in interfaces {
             let camel = format_camel(snake).to_string();
-            let prefix =
-                debug_fn(|f| write!(f, r#"    pub(super) use super::{proto}::{snake}::{camel}"#));
+            let prefix = debug_fn(|f| match gated {
+                true => write!(f, r#"        {snake}::{camel}"#),
+                false => write!(f, r#"    pub(super) use {path}::{snake}::{camel}"#),
+            });
+            let end = match gated {
+                true => ",",
+                false => ";",
+            };
+            wl!(r#"{prefix}{end}"#)?;
+            wl!(r#"{prefix}Ref{end}"#)?;
+            for enum_ in enums {
+                wl!(r#"{prefix}{}{end}"#, format_camel(enum_))?;
+            }
+        }
+        if gated {
+            wl!(r#"    }};"#)?;
+        }
+    }
+    for (proto, scoped) in &namespaces.scoped {
+        if extern_protocols.contains_key(proto) {
+            continue;
+        }
+        if protocol_features {
+            wl!(r#"    #[cfg(feature = "{proto}")]"#)?;
+        }
+        wl!("    pub(super) mod {proto} {{")?;
+        wl!("        pub(in super::super) use super::*;")?;
+        for (snake, definer) in scoped {
+            let enums = protocols
+                .iter()
+                .filter(|(p, _)| p == definer)
+                .flat_map(|(_, interfaces)| interfaces)
+                .filter(|(i, _)| i == snake)
+                .flat_map(|(_, enums)| enums);
+            let path = match extern_protocols.get(definer) {
+                Some(path) => path.clone(),
+                None => format!("super::super::{definer}"),
+            };
+            let camel = format_camel(snake).to_string();
+            let prefix = debug_fn(|f| {
+                write!(
+                    f,
+                    r#"        pub(in super::super) use {path}::{snake}::{camel}"#
+                )
+            });
             wl!(r#"{prefix};"#)?;
             wl!(r#"{prefix}Ref;"#)?;
             for enum_ in enums {
                 wl!(r#"{prefix}{};"#, format_camel(enum_))?;
             }
         }
+        wl!("    }}")?;
     }
     wl!("}}")?;
     Ok(())
 }
 
-pub fn format_protocol_file(w: &mut impl Write, protocol: &Protocol) -> io::Result<()> {
+pub fn format_features_file(
+    w: &mut impl Write,
+    features: &[(String, Vec<String>)],
+) -> io::Result<()> {
+    define_w!(w);
+    for (protocol, dependencies) in features {
+        w!("{protocol} = [")?;
+        for (idx, dependency) in dependencies.iter().enumerate() {
+            if idx > 0 {
+                w!(", ")?;
+            }
+            w!(r#""{dependency}""#)?;
+        }
+        wl!("]")?;
+    }
+    Ok(())
+}
+
+pub fn format_protocol_file(w: &mut impl Write, root: &str, protocol: &Protocol) -> io::Result<()> {
     define_w!(w);
     if let Some(description) = &protocol.description {
         format_description(w, "//!", description)?;
@@ -717,6 +1204,17 @@ pub fn format_protocol_file(w: &mut impl Write, protocol: &Protocol) -> io::Resu
         let snake = &interface.name;
         wl!(r#"pub mod {snake};"#)?;
     }
+    wl!()?;
+    wl!(r#"/// Metadata about the {} protocol."#, protocol.name)?;
+    wl!(r#"#[allow(dead_code)]"#)?;
+    wl!(r#"pub static PROTOCOL: {root}::info::ProtocolInfo = {root}::info::ProtocolInfo {{"#)?;
+    wl!(r#"    name: "{}","#, protocol.name)?;
+    wl!(r#"    interfaces: &["#)?;
+    for interface in &protocol.interfaces {
+        wl!(r#"        &{}::INFO,"#, interface.name)?;
+    }
+    wl!(r#"    ],"#)?;
+    wl!(r#"}};"#)?;
     Ok(())
 }
 
@@ -852,9 +1350,48 @@ fn format_description(
 }
 
 fn format_event_handler(
+    w: &mut impl Write,
+    interface: &Interface,
+    symbol: InterfaceSymbol,
+) -> io::Result<()> {
+    define_w!(w);
+    let camel = format_camel(&interface.name).to_string();
+    format_event_handler_impl(w, false, interface, symbol)?;
+    wl!()?;
+    format_event_handler_impl(w, true, interface, symbol)?;
+    wl!()?;
+    wl!(r#"impl<H> CreateEventHandler<H> for private::ProxyApi"#)?;
+    wl!(r#"where"#)?;
+    wl!(r#"    H: {camel}EventHandler,"#)?;
+    wl!(r#"{{"#)?;
+    wl!(r#"    type EventHandler = private::EventHandler<H>;"#)?;
+    wl!()?;
+    wl!(r#"    #[inline]"#)?;
+    wl!(r#"    fn create_event_handler(handler: H) -> Self::EventHandler {{"#)?;
+    wl!(r#"        private::EventHandler(handler)"#)?;
+    wl!(r#"    }}"#)?;
+    wl!(r#"}}"#)?;
+    wl!()?;
+    wl!(r#"impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi"#)?;
+    wl!(r#"where"#)?;
+    wl!(r#"    H: {camel}EventHandlerWithData<T>,"#)?;
+    wl!(r#"    T: 'static,"#)?;
+    wl!(r#"{{"#)?;
+    wl!(r#"    type EventHandler = private::EventHandlerWithData<H, T>;"#)?;
+    wl!()?;
+    wl!(r#"    #[inline]"#)?;
+    wl!(r#"    fn create_event_handler(handler: H) -> Self::EventHandler {{"#)?;
+    wl!(r#"        private::EventHandlerWithData(handler, PhantomData)"#)?;
+    wl!(r#"    }}"#)?;
+    wl!(r#"}}"#)?;
+    Ok(())
+}
+
+fn format_event_handler_impl(
     w: &mut impl Write,
     mutable_data: bool,
     interface: &Interface,
+    symbol: InterfaceSymbol,
 ) -> io::Result<()> {
     define_w!(w);
     let snake = &interface.name;
@@ -862,18 +1399,28 @@ fn format_event_handler(
     wl!(r#"// SAFETY: - INTERFACE is a valid wl_interface"#)?;
     if mutable_data {
         wl!(r#"//         - mutable_type always returns the same value"#)?;
+        wl!(r#"unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>"#)?;
+        wl!(r#"where"#)?;
+        wl!(r#"    H: {camel}EventHandlerWithData<T>,"#)?;
+        wl!(r#"    T: 'static,"#)?;
+    } else {
+        wl!(r#"unsafe impl<H> EventHandler for private::EventHandler<H>"#)?;
+        wl!(r#"where"#)?;
+        wl!(r#"    H: {camel}EventHandler,"#)?;
     }
-    wl!(r#"unsafe impl<H> EventHandler for private::EventHandler<H>"#)?;
-    wl!(r#"where"#)?;
-    wl!(r#"    H: {camel}EventHandler,"#)?;
     wl!(r#"{{"#)?;
     wl!(r#"    const WL_INTERFACE: &'static wl_interface = &INTERFACE;"#)?;
+    if symbol == InterfaceSymbol::Libwayland {
+        wl!(
+            r#"    const LIBWAYLAND_INTERFACE: Option<&'static LibwaylandInterface> = Some(&LIBWAYLAND_INTERFACE);"#
+        )?;
+    }
     if mutable_data {
         wl!()?;
         wl!(r#"    #[inline]"#)?;
         wl!(r#"    fn mutable_type() -> Option<(TypeId, &'static str)> {{"#)?;
-        wl!(r#"        let id = TypeId::of::<H::Data>();"#)?;
-        wl!(r#"        let name = std::any::type_name::<H::Data>();"#)?;
+        wl!(r#"        let id = TypeId::of::<T>();"#)?;
+        wl!(r#"        let name = std::any::type_name::<T>();"#)?;
         wl!(r#"        Some((id, name))"#)?;
         wl!(r#"    }}"#)?;
     }
@@ -894,9 +1441,8 @@ fn format_event_handler(
         )?;
         if mutable_data {
             wl!(r#"        // SAFETY: This function requires that data is `&mut T` where `T`"#)?;
-            wl!(r#"        //         has the type id returned by `Self::mutable_type`, i.e.,"#)?;
-            wl!(r#"        //         `T = H::Data`."#)?;
-            wl!(r#"        let data: &mut H::Data = unsafe {{ &mut *data.cast() }};"#)?;
+            wl!(r#"        //         has the type id returned by `Self::mutable_type`."#)?;
+            wl!(r#"        let data: &mut T = unsafe {{ &mut *data.cast() }};"#)?;
         }
         wl!(r#"        match opcode {{"#)?;
         for (idx, event) in interface.events.iter().enumerate() {
@@ -946,7 +1492,11 @@ fn format_event_handler(
                                     wl!(
                                         r#"{prefix}        NonNull::new_unchecked(args[{idx}].o.cast()),"#
                                     )?;
-                                    wl!(r#"{prefix}        {camel}::WL_INTERFACE,"#)?;
+                                    if symbol == InterfaceSymbol::Libwayland {
+                                        wl!(r#"{prefix}        proxy_interface::<{camel}>(),"#)?;
+                                    } else {
+                                        wl!(r#"{prefix}        {camel}::WL_INTERFACE,"#)?;
+                                    }
                                     wl!(r#"{prefix}    )"#)?;
                                     wl!(r#"{prefix}}};"#)?;
                                     wl!(
@@ -1057,18 +1607,6 @@ fn format_event_handler(
     }
     wl!(r#"    }}"#)?;
     wl!(r#"}}"#)?;
-    wl!()?;
-    wl!(r#"impl<H> CreateEventHandler<H> for private::ProxyApi"#)?;
-    wl!(r#"where"#)?;
-    wl!(r#"    H: {camel}EventHandler,"#)?;
-    wl!(r#"{{"#)?;
-    wl!(r#"    type EventHandler = private::EventHandler<H>;"#)?;
-    wl!()?;
-    wl!(r#"    #[inline]"#)?;
-    wl!(r#"    fn create_event_handler(handler: H) -> Self::EventHandler {{"#)?;
-    wl!(r#"        private::EventHandler(handler)"#)?;
-    wl!(r#"    }}"#)?;
-    wl!(r#"}}"#)?;
     Ok(())
 }
 
@@ -1377,55 +1915,146 @@ fn format_interface_enums(w: &mut impl Write, interface: &Interface) -> io::Resu
     Ok(())
 }
 
-fn format_event_handlers(
-    w: &mut impl Write,
-    mutable_data: bool,
-    interface: &Interface,
-) -> io::Result<()> {
+fn format_event_handlers(w: &mut impl Write, interface: &Interface) -> io::Result<()> {
     define_w!(w);
     let if_camel = format_camel(&interface.name).to_string();
     wl!(r#"/// Functional event handlers."#)?;
     wl!(r#"pub mod event_handlers {{"#)?;
     wl!(r#"    use super::*;"#)?;
-    for event in &interface.events {
-        let camel = format_camel(&event.name).to_string();
+    for mutable in [false, true] {
+        for event in &interface.events {
+            format_closure_event_handler(w, interface, event, mutable)?;
+        }
+    }
+    wl!()?;
+    wl!(r#"    impl {if_camel} {{"#)?;
+    let mut first = true;
+    for mutable in [false, true] {
+        for event in &interface.events {
+            for data in [false, true] {
+                if !first {
+                    wl!()?;
+                }
+                first = false;
+                let camel = format_camel(&event.name).to_string();
+                let (suffix, ty) = match mutable {
+                    true => ("_mut", "Mut"),
+                    false => ("", ""),
+                };
+                let (data_suffix, data_param, data_desc) = match data {
+                    true => ("_with_data", "T, ", " with mutable data"),
+                    false => ("", "", ""),
+                };
+                let kind = match mutable {
+                    true => "a mutable event handler",
+                    false => "an event handler",
+                };
+                wl!(
+                    r#"        /// Creates {kind}{data_desc} for {} events."#,
+                    event.name
+                )?;
+                wl!(r#"        ///"#)?;
+                if mutable {
+                    wl!(
+                        r#"        /// The event handler ignores all other events. It can be attached with"#
+                    )?;
+                    wl!(r#"        /// [`proxy::set_event_handler_local_mut`]."#)?;
+                } else {
+                    wl!(r#"        /// The event handler ignores all other events."#)?;
+                }
+                wl!(r#"        #[allow(dead_code)]"#)?;
+                wl!(
+                    r#"        pub fn on_{}{suffix}{data_suffix}<{data_param}F>(f: F) -> {camel}{ty}<F>"#,
+                    event.name,
+                )?;
+                wl!(r#"        where"#)?;
+                wl!(
+                    r#"            F: {},"#,
+                    closure_bound(interface, event, mutable, data)
+                )?;
+                wl!(r#"        {{"#)?;
+                wl!(r#"            {camel}{ty}(f)"#)?;
+                wl!(r#"        }}"#)?;
+            }
+        }
+    }
+    if has_multi_event_handler(interface) {
         wl!()?;
-        wl!(r#"    /// Event handler for {} events."#, event.name)?;
-        let data_param = match mutable_data {
-            true => "T, ",
-            false => "",
-        };
-        let phantom_data = match mutable_data {
-            true => ", PhantomData<fn(&mut T)>",
-            false => "",
-        };
-        wl!(r#"    pub struct {camel}<{data_param}F>(F{phantom_data});"#)?;
-        wl!(r#"    impl<{data_param}F> {if_camel}EventHandler for {camel}<{data_param}F>"#)?;
-        wl!(r#"    where"#)?;
-        if mutable_data {
-            wl!(r#"        T: 'static,"#)?;
+        wl!(r#"        /// Creates an event handler for multiple events."#)?;
+        wl!(r#"        ///"#)?;
+        wl!(
+            r#"        /// Use the `on_*` functions of the returned [`Handler`] to add closures for"#
+        )?;
+        wl!(r#"        /// individual events. The event handler ignores all other events."#)?;
+        wl!(r#"        #[allow(dead_code)]"#)?;
+        wl!(r#"        pub fn handler() -> Handler {{"#)?;
+        wl!(r#"            Handler {{"#)?;
+        for event in &interface.events {
+            wl!(r#"                {}: Ignore,"#, escape_name(&event.name))?;
         }
-        let data_param = match mutable_data {
-            true => "&mut T, ",
-            false => "",
+        wl!(r#"            }}"#)?;
+        wl!(r#"        }}"#)?;
+    }
+    wl!(r#"    }}"#)?;
+    if has_multi_event_handler(interface) {
+        format_multi_event_handler(w, interface)?;
+    }
+    wl!(r#"}}"#)?;
+    Ok(())
+}
+
+/// Returns the closure bound of a functional event handler.
+fn closure_bound(interface: &Interface, event: &Message, mutable: bool, data: bool) -> String {
+    let if_camel = format_camel(&interface.name);
+    let mut bound = match mutable {
+        true => "FnMut(".to_string(),
+        false => "Fn(".to_string(),
+    };
+    if data {
+        bound.push_str("&mut T, ");
+    }
+    let _ = write!(bound, "&{if_camel}Ref");
+    for arg in &event.args {
+        let _ = write!(bound, ", {}", arg_type(interface, arg, false));
+    }
+    bound.push(')');
+    bound
+}
+
+fn format_closure_event_handler(
+    w: &mut impl Write,
+    interface: &Interface,
+    event: &Message,
+    mutable: bool,
+) -> io::Result<()> {
+    define_w!(w);
+    let if_camel = format_camel(&interface.name).to_string();
+    let camel = format_camel(&event.name).to_string();
+    let (ty, slf, kind) = match mutable {
+        true => ("Mut", "&mut self", "Mutable event handler"),
+        false => ("", "&self", "Event handler"),
+    };
+    wl!()?;
+    wl!(r#"    /// {kind} for {} events."#, event.name)?;
+    wl!(r#"    pub struct {camel}{ty}<F>(F);"#)?;
+    for data in [false, true] {
+        let (generics, data_param, data_arg) = match data {
+            true => ("T, F", ", _data: &mut T", "_data, "),
+            false => ("F", "", ""),
         };
-        w!(r#"        F: Fn({data_param}&{if_camel}Ref"#)?;
-        for arg in &event.args {
-            w!(", {}", arg_type(interface, arg, false))?;
-        }
-        wl!(r#"),"#)?;
+        wl!(
+            r#"    impl<{generics}> {} for {camel}{ty}<F>"#,
+            handler_trait(&if_camel, mutable, data)
+        )?;
+        wl!(r#"    where"#)?;
+        wl!(
+            r#"        F: {},"#,
+            closure_bound(interface, event, mutable, data)
+        )?;
         wl!(r#"    {{"#)?;
-        if mutable_data {
-            wl!(r#"        type Data = T;"#)?;
-            wl!()?;
-        }
         wl!(r#"        #[inline]"#)?;
-        let data_param = match mutable_data {
-            true => ", _data: &mut T",
-            false => "",
-        };
         w!(
-            r#"        fn {}(&self{data_param}, _slf: &{if_camel}Ref"#,
+            r#"        fn {}({slf}{data_param}, _slf: &{if_camel}Ref"#,
             escape_name(&event.name),
         )?;
         for arg in &event.args {
@@ -1436,11 +2065,7 @@ fn format_event_handlers(
             )?;
         }
         wl!(r#") {{"#)?;
-        w!(r#"            self.0("#)?;
-        if mutable_data {
-            w!(r#"_data, "#)?;
-        }
-        w!(r#"_slf"#)?;
+        w!(r#"            self.0({data_arg}_slf"#)?;
         for arg in &event.args {
             w!(", {}", escape_name(&arg.name))?;
         }
@@ -1448,55 +2073,158 @@ fn format_event_handlers(
         wl!(r#"        }}"#)?;
         wl!(r#"    }}"#)?;
     }
+    Ok(())
+}
+
+/// Returns whether the `Handler` and `Ignore` types can be generated for an interface
+/// without conflicting with the generated event handler types or requests.
+fn has_multi_event_handler(interface: &Interface) -> bool {
+    if interface.events.is_empty() {
+        return false;
+    }
+    let conflict = interface.events.iter().any(|e| {
+        let camel = format_camel(&e.name).to_string();
+        camel == "Handler" || camel == "Ignore"
+    });
+    let request_conflict = interface.requests.iter().any(|r| r.name == "handler");
+    !conflict && !request_conflict
+}
+
+fn format_multi_event_handler(w: &mut impl Write, interface: &Interface) -> io::Result<()> {
+    define_w!(w);
+    let if_camel = format_camel(&interface.name).to_string();
     wl!()?;
-    wl!(r#"    impl {if_camel} {{"#)?;
-    for (idx, event) in interface.events.iter().enumerate() {
+    wl!(r#"    /// An event handler that ignores all events."#)?;
+    wl!(r#"    ///"#)?;
+    wl!(r#"    /// This is used by [`Handler`] for events without a closure."#)?;
+    wl!(r#"    pub struct Ignore;"#)?;
+    wl!(r#"    impl {if_camel}EventHandler for Ignore {{ }}"#)?;
+    wl!(r#"    impl<T> {if_camel}EventHandlerWithData<T> for Ignore {{ }}"#)?;
+    wl!()?;
+    wl!(r#"    /// An event handler for multiple events."#)?;
+    wl!(r#"    ///"#)?;
+    wl!(
+        r#"    /// This event handler is created with [`{if_camel}::handler`]. Each type parameter"#
+    )?;
+    wl!(r#"    /// is the event handler of one event. Events without a closure are ignored."#)?;
+    wl!(r#"    ///"#)?;
+    wl!(r#"    /// The closures must either all take mutable data or all not take mutable data."#)?;
+    w!(r#"    pub struct Handler<"#)?;
+    for idx in 0..interface.events.len() {
         if idx > 0 {
-            wl!()?;
+            w!(", ")?;
         }
-        let camel = format_camel(&event.name).to_string();
-        wl!(
-            r#"        /// Creates an event handler for {} events."#,
-            event.name
-        )?;
-        wl!(r#"        ///"#)?;
-        wl!(r#"        /// The event handler ignores all other events."#)?;
-        wl!(r#"        #[allow(dead_code)]"#)?;
-        let data_param = match mutable_data {
-            true => "T, ",
-            false => "",
+        w!("H{idx} = Ignore")?;
+    }
+    wl!(r#"> {{"#)?;
+    for (idx, event) in interface.events.iter().enumerate() {
+        wl!(r#"        {}: H{idx},"#, escape_name(&event.name))?;
+    }
+    wl!(r#"    }}"#)?;
+    let all: Vec<_> = (0..interface.events.len())
+        .map(|idx| format!("H{idx}"))
+        .collect();
+    let all = all.join(", ");
+    for data in [false, true] {
+        let (generics, data_param, data_arg) = match data {
+            true => ("T, ", ", _data: &mut T", "_data, "),
+            false => ("", "", ""),
         };
-        wl!(
-            r#"        pub fn on_{}<{data_param}F>(f: F) -> {camel}<{data_param}F>"#,
-            event.name,
-        )?;
-        wl!(r#"        where"#)?;
-        if mutable_data {
-            wl!(r#"            T: 'static,"#)?;
+        let handler_trait = handler_trait(&if_camel, false, data);
+        wl!()?;
+        wl!(r#"    impl<{generics}{all}> {handler_trait} for Handler<{all}>"#)?;
+        wl!(r#"    where"#)?;
+        for idx in 0..interface.events.len() {
+            wl!(r#"        H{idx}: {handler_trait},"#)?;
         }
-        let data_param = match mutable_data {
-            true => "&mut T, ",
-            false => "",
-        };
-        w!(r#"            F: Fn({data_param}&{if_camel}Ref"#)?;
-        for arg in &event.args {
-            w!(", {}", arg_type(interface, arg, false))?;
+        wl!(r#"    {{"#)?;
+        for (idx, event) in interface.events.iter().enumerate() {
+            if idx > 0 {
+                wl!()?;
+            }
+            let name = escape_name(&event.name);
+            wl!(r#"        #[inline]"#)?;
+            w!(r#"        fn {name}(&self{data_param}, _slf: &{if_camel}Ref"#)?;
+            for arg in &event.args {
+                w!(
+                    ", {}: {}",
+                    escape_name(&arg.name),
+                    arg_type(interface, arg, false)
+                )?;
+            }
+            wl!(r#") {{"#)?;
+            w!(r#"            self.{name}.{name}({data_arg}_slf"#)?;
+            for arg in &event.args {
+                w!(", {}", escape_name(&arg.name))?;
+            }
+            wl!(r#")"#)?;
+            wl!(r#"        }}"#)?;
+        }
+        wl!(r#"    }}"#)?;
+    }
+    wl!()?;
+    wl!(r#"    #[allow(clippy::type_complexity)]"#)?;
+    wl!(r#"    impl<{all}> Handler<{all}> {{"#)?;
+    let mut first = true;
+    for (idx, event) in interface.events.iter().enumerate() {
+        for data in [false, true] {
+            if !first {
+                wl!()?;
+            }
+            first = false;
+            let camel = format_camel(&event.name).to_string();
+            let (data_suffix, data_param, data_desc) = match data {
+                true => ("_with_data", "T, ", " that takes mutable data"),
+                false => ("", "", ""),
+            };
+            wl!(
+                r#"        /// Sets the closure{data_desc} for {} events."#,
+                event.name
+            )?;
+            wl!(r#"        #[allow(dead_code)]"#)?;
+            w!(
+                r#"        pub fn on_{}{data_suffix}<{data_param}F>(self, f: F) -> Handler<"#,
+                event.name
+            )?;
+            for idx2 in 0..interface.events.len() {
+                if idx2 > 0 {
+                    w!(", ")?;
+                }
+                if idx2 == idx {
+                    w!("{camel}<F>")?;
+                } else {
+                    w!("H{idx2}")?;
+                }
+            }
+            wl!(r#">"#)?;
+            wl!(r#"        where"#)?;
+            wl!(
+                r#"            F: {},"#,
+                closure_bound(interface, event, false, data)
+            )?;
+            wl!(r#"        {{"#)?;
+            wl!(r#"            Handler {{"#)?;
+            for (idx2, event2) in interface.events.iter().enumerate() {
+                let name = escape_name(&event2.name);
+                if idx2 == idx {
+                    wl!(r#"                {name}: {camel}(f),"#)?;
+                } else {
+                    wl!(r#"                {name}: self.{name},"#)?;
+                }
+            }
+            wl!(r#"            }}"#)?;
+            wl!(r#"        }}"#)?;
         }
-        wl!(r#"),"#)?;
-        wl!(r#"        {{"#)?;
-        let phantom_data = match mutable_data {
-            true => ", PhantomData",
-            false => "",
-        };
-        wl!(r#"            {camel}(f{phantom_data})"#)?;
-        wl!(r#"        }}"#)?;
     }
     wl!(r#"    }}"#)?;
-    wl!(r#"}}"#)?;
     Ok(())
 }
 
-fn format_interface_trait_impls(w: &mut impl Write, interface: &Interface) -> io::Result<()> {
+fn format_interface_trait_impls(
+    w: &mut impl Write,
+    interface: &Interface,
+    symbol: InterfaceSymbol,
+) -> io::Result<()> {
     define_w!(w);
     let snake = &interface.name;
     let camel = format_camel(snake).to_string();
@@ -1508,6 +2236,11 @@ fn format_interface_trait_impls(w: &mut impl Write, interface: &Interface) -> io
     wl!(r#"unsafe impl OwnedProxy for {camel} {{"#)?;
     wl!(r#"    const INTERFACE: &'static str = "{snake}";"#)?;
     wl!(r#"    const WL_INTERFACE: &'static wl_interface = &INTERFACE;"#)?;
+    if symbol == InterfaceSymbol::Libwayland {
+        wl!(
+            r#"    const LIBWAYLAND_INTERFACE: Option<&'static LibwaylandInterface> = Some(&LIBWAYLAND_INTERFACE);"#
+        )?;
+    }
     wl!(
         r#"    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler = private::EventHandler(private::NoOpEventHandler);"#
     )?;
@@ -1535,12 +2268,19 @@ fn format_interface_trait_impls(w: &mut impl Write, interface: &Interface) -> io
     wl!(r#"}}"#)?;
     wl!()?;
     wl!(r#"mod private {{"#)?;
+    wl!(r#"    use std::marker::PhantomData;"#)?;
+    wl!()?;
     wl!(r#"    pub struct ProxyApi;"#)?;
     wl!()?;
     wl!(r#"    #[allow(dead_code)]"#)?;
     wl!(r#"    pub struct EventHandler<H>(pub(super) H);"#)?;
     wl!()?;
     wl!(r#"    #[allow(dead_code)]"#)?;
+    wl!(
+        r#"    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);"#
+    )?;
+    wl!()?;
+    wl!(r#"    #[allow(dead_code)]"#)?;
     wl!(r#"    pub struct NoOpEventHandler;"#)?;
     wl!(r#"}}"#)?;
     wl!()?;
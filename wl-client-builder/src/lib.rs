@@ -32,16 +32,143 @@
 //! }
 //! ```
 //!
-//! # Enabling mutable data
+//! # Mutable data
 //!
-//! If you want to pass mutable data to event handlers, you must explicitly enable the
-//! `mutable_data` flag:
+//! For each interface, the generated code contains the `*EventHandler` trait for event
+//! handlers that don't take mutable data and the `*EventHandlerWithData<T>` trait for event
+//! handlers that take mutable data of type `T` that is passed in when dispatching a queue.
+//! Both kinds of event handlers can be attached to the same proxy types.
+//!
+//! # Metadata
+//!
+//! The generated code contains metadata about the protocols that can be inspected at
+//! runtime: an `INFO` static in each interface module, a `PROTOCOL` static in each
+//! protocol module, and a `PROTOCOLS` static and an `interface_info` function in the
+//! `mod.rs`. See the `wl_client::info` module for details. Protocols that are used from
+//! other crates are not included.
+//!
+//! # Duplicate interface names
+//!
+//! Multiple protocols can define interfaces with the same name, for example, the stable
+//! and the unstable version of a protocol. Such protocols can be generated in one build.
+//! Each protocol uses its own definitions of the interfaces it defines. If another
+//! protocol uses such an interface, you must select the protocol whose definition is
+//! used:
+//!
+//! ```
+//! # use wl_client_builder::Builder;
+//! #
+//! # fn no_run() {
+//! Builder::default()
+//!     .prefer_protocol("zwp_tablet_tool_v2", "tablet_v2")
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! Protocols with the same name cannot be generated in one build.
+//!
+//! # Generating a subset of interfaces
+//!
+//! Most applications only use a small number of interfaces. You can restrict the
+//! generated code to the interfaces you need. The builder automatically adds all
+//! interfaces that are reachable from them via object arguments, `new_id` arguments,
+//! or enums:
+//!
+//! ```
+//! # use wl_client_builder::Builder;
+//! #
+//! # fn no_run() {
+//! Builder::default()
+//!     .only_interfaces(["wl_compositor", "wl_shm", "wl_seat"])
+//!     .only_protocols(["xdg_shell"])
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! Use [`Builder::explain`] to find out why an interface was added.
+//!
+//! # Using system protocols
+//!
+//! Instead of vendoring XML files, you can use the protocols installed on the system by
+//! `wayland-protocols` and `wayland-scanner`. The files are located via pkg-config and
+//! the builder tells cargo to rerun `build.rs` if the files or the pkg-config
+//! environment variables change:
+//!
+//! ```
+//! # use wl_client_builder::Builder;
+//! #
+//! # fn no_run() {
+//! Builder::default()
+//!     .with_default_dir(false)
+//!     .system_protocol("wayland")
+//!     .system_protocol("xdg-shell")
+//!     .system_protocol_file("staging/cursor-shape/cursor-shape-v1.xml")
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! # Using protocols generated by other crates
+//!
+//! If another crate already contains generated code for a protocol, for example, the
+//! core `wayland` protocol, you can use its types instead of generating the protocol
+//! again. The XML file of the protocol must still be added so that the builder knows
+//! which interfaces it defines:
+//!
+//! ```
+//! # use wl_client_builder::Builder;
+//! #
+//! # fn no_run() {
+//! Builder::default()
+//!     .xml_file("wayland.xml")
+//!     .extern_protocol("wayland", "::crate_a::protocols::wayland")
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! # Exporting interface symbols
+//!
+//! C libraries that use a protocol expect the `wl_interface` of each interface `name`
+//! to be available as the symbol `{name}_interface`. This symbol is usually defined by
+//! code generated by `wayland-scanner`. The builder can instead export the tables of the
+//! generated code as C symbols:
 //!
 //! ```
 //! # use wl_client_builder::Builder;
 //! #
 //! # fn no_run() {
-//! Builder::default().with_mutable_data(true).build().unwrap();
+//! Builder::default()
+//!     .xml_file("linux-dmabuf-v1.xml")
+//!     .export_interface_symbols("linux_dmabuf_v1")
+//!     .build()
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! Since symbols are global to the process, each protocol should only be exported by a
+//! single crate. Core protocol symbols such as `wl_surface_interface` are defined by
+//! libwayland-client and cannot be exported.
+//!
+//! # Using libwayland-client's core interfaces
+//!
+//! C code identifies the core interfaces by the `wl_*_interface` symbols of
+//! libwayland-client. The builder can make the generated code use these symbols instead
+//! of its own tables so that proxies can be passed between the generated code and C
+//! code. The symbols are looked up at runtime through `Libwayland`, so the generated
+//! code does not link against libwayland-client:
+//!
+//! ```
+//! # use wl_client_builder::Builder;
+//! #
+//! # fn no_run() {
+//! Builder::default()
+//!     .xml_file("wayland.xml")
+//!     .with_libwayland_interfaces(true)
+//!     .build()
+//!     .unwrap();
 //! # }
 //! ```
 //!
@@ -63,6 +190,33 @@
 //! $ rustfmt src/protocols/mod.rs
 //! ```
 //!
+//! If you generate code into multiple directories, you can describe all of them in a
+//! `wl-client-builder.toml` file and regenerate them with `wl-client-builder generate`.
+//! The file can also ask the application to format the generated code with rustfmt. See
+//! the README of the application for details.
+//!
+//! # Checking protocol files
+//!
+//! [`Builder::lint`] checks the XML files for problems such as references to enums that
+//! do not exist and reports each problem with its file, line, and column. The CLI
+//! exposes this as the `lint` subcommand:
+//!
+//! ```shell
+//! $ wl-client-builder lint --xml-dir protocols
+//! ```
+//!
+//! # Decoding wayland messages
+//!
+//! [`Builder::decoder`] returns a [`Decoder`] that decodes `WAYLAND_DEBUG=1` logs and
+//! raw wire captures. Each message is printed with the names of its arguments and enum
+//! values are replaced by the names of their entries. The CLI exposes this as the
+//! `decode` subcommand:
+//!
+//! ```shell
+//! $ WAYLAND_DEBUG=1 my-client 2> client.log
+//! $ wl-client-builder decode --xml-dir protocols client.log
+//! ```
+//!
 //! # Generating code with a custom application
 //!
 //! By default the [`Builder`] assumes that it is being used from `build.rs` and will emit
@@ -84,10 +238,21 @@
 
 #![allow(clippy::collapsible_else_if, clippy::len_zero)]
 
-pub use {builder::Builder, error::Error};
+pub use {
+    builder::{Builder, InlineCode},
+    decode::{DecodedArg, DecodedMessage, Decoder, Side},
+    diff::{Change, ChangeKind, diff},
+    error::Error,
+    validate::Diagnostic,
+};
 
 mod ast;
 mod builder;
+mod decode;
+mod diff;
 mod error;
 mod formatter;
+mod namespace;
 mod parser;
+mod system;
+mod validate;
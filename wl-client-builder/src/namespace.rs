@@ -0,0 +1,241 @@
+use {
+    crate::ast::{Arg, ArgType, Interface, Message, Protocol},
+    debug_fn::debug_fn,
+    std::{
+        collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+        fmt::Display,
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Error)]
+pub(crate) enum NamespaceError {
+    #[error(
+        "Protocol {protocol} uses interface {interface} which is defined by multiple protocols ({}). Add a protocol preference for the interface to select one",
+        .candidates.join(", ")
+    )]
+    Ambiguous {
+        protocol: String,
+        interface: String,
+        candidates: Vec<String>,
+    },
+    #[error("Protocol {protocol} is preferred for interface {interface} but does not define it")]
+    InvalidPreference { interface: String, protocol: String },
+    #[error("Protocol {0} is not defined by any XML file")]
+    UnknownProtocol(String),
+    #[error("Interface {0} is not defined by any protocol")]
+    UnknownInterface(String),
+}
+
+/// The result of resolving interface references across protocols.
+#[derive(Default)]
+pub(crate) struct Namespaces {
+    /// The interfaces that are defined by more than one protocol.
+    pub(crate) duplicates: HashSet<String>,
+    /// For each protocol that uses a duplicate interface, maps each such interface to
+    /// the protocol that defines the interface used by the protocol.
+    pub(crate) scoped: BTreeMap<String, BTreeMap<String, String>>,
+    /// For each protocol, the other protocols whose interfaces it uses.
+    pub(crate) dependencies: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// Maps interface names to the protocols that define them.
+fn definers(protocols: &[Protocol]) -> HashMap<&str, Vec<&str>> {
+    let mut definers = HashMap::<&str, Vec<&str>>::new();
+    for protocol in protocols {
+        for interface in &protocol.interfaces {
+            definers
+                .entry(&interface.name)
+                .or_default()
+                .push(&protocol.name);
+        }
+    }
+    definers
+}
+
+/// Returns the interfaces used by the messages of an interface.
+///
+/// Each element contains the message, the argument, and the name of the interface. The
+/// last element is true if the interface is referenced via the enum of the argument.
+fn used_interfaces(interface: &Interface) -> impl Iterator<Item = (&Message, &Arg, &str, bool)> {
+    interface
+        .requests
+        .iter()
+        .chain(&interface.events)
+        .flat_map(|message| message.args.iter().map(move |arg| (message, arg)))
+        .flat_map(|(message, arg)| {
+            let object = arg.interface.as_deref().map(|i| (message, arg, i, false));
+            let enum_ = arg
+                .enum_
+                .as_deref()
+                .and_then(|e| e.split_once('.'))
+                .map(|(i, _)| (message, arg, i, true));
+            object.into_iter().chain(enum_)
+        })
+}
+
+/// Selects the protocol whose definition of `interface` is used by `protocol`.
+///
+/// Returns `None` if no protocol defines the interface.
+fn select<'a>(
+    protocol: &'a Protocol,
+    interface: &str,
+    definers: &HashMap<&str, Vec<&'a str>>,
+    preferences: &'a HashMap<String, String>,
+) -> Result<Option<&'a str>, NamespaceError> {
+    let Some(candidates) = definers.get(interface) else {
+        return Ok(None);
+    };
+    if protocol.interfaces.iter().any(|i| i.name == interface) {
+        Ok(Some(&protocol.name))
+    } else if candidates.len() == 1 {
+        Ok(Some(candidates[0]))
+    } else if let Some(preferred) = preferences.get(interface) {
+        Ok(Some(preferred))
+    } else {
+        Err(NamespaceError::Ambiguous {
+            protocol: protocol.name.clone(),
+            interface: interface.to_string(),
+            candidates: candidates.iter().map(|c| c.to_string()).collect(),
+        })
+    }
+}
+
+/// Resolves the interfaces used by each protocol.
+///
+/// If an interface is defined by multiple protocols, a protocol that defines the
+/// interface itself uses its own definition. Otherwise the definition is selected via
+/// `preferences`, which maps interface names to protocol names.
+pub(crate) fn resolve(
+    protocols: &[Protocol],
+    preferences: &HashMap<String, String>,
+) -> Result<Namespaces, NamespaceError> {
+    let definers = definers(protocols);
+    for (interface, protocol) in preferences {
+        let defined = definers
+            .get(&**interface)
+            .is_some_and(|d| d.contains(&&**protocol));
+        if !defined {
+            return Err(NamespaceError::InvalidPreference {
+                interface: interface.clone(),
+                protocol: protocol.clone(),
+            });
+        }
+    }
+    let mut namespaces = Namespaces::default();
+    for (interface, protocols) in &definers {
+        if protocols.len() > 1 {
+            namespaces.duplicates.insert(interface.to_string());
+        }
+    }
+    for protocol in protocols {
+        let mut used = BTreeSet::new();
+        for interface in &protocol.interfaces {
+            used.insert(&*interface.name);
+            used.extend(used_interfaces(interface).map(|(_, _, i, _)| i));
+        }
+        let dependencies = namespaces
+            .dependencies
+            .entry(protocol.name.clone())
+            .or_default();
+        for interface in used {
+            let Some(definer) = select(protocol, interface, &definers, preferences)? else {
+                continue;
+            };
+            if definer != protocol.name {
+                dependencies.insert(definer.to_string());
+            }
+            if namespaces.duplicates.contains(interface) {
+                namespaces
+                    .scoped
+                    .entry(protocol.name.clone())
+                    .or_default()
+                    .insert(interface.to_string(), definer.to_string());
+            }
+        }
+    }
+    Ok(namespaces)
+}
+
+/// Computes the interfaces reachable from the selected protocols and interfaces.
+///
+/// An interface is reachable if it is selected, if it is part of a selected protocol, or
+/// if it is used by a reachable interface via an object argument, a `new_id` argument,
+/// or an enum. Each reachable interface is identified by its protocol and its name.
+///
+/// `explain` is called once for each reachable interface with a description of why
+/// it is reachable.
+pub(crate) fn closure(
+    protocols: &[Protocol],
+    preferences: &HashMap<String, String>,
+    only_protocols: &BTreeSet<String>,
+    only_interfaces: &BTreeSet<String>,
+    explain: &mut dyn FnMut(&str),
+) -> Result<HashSet<(String, String)>, NamespaceError> {
+    let definers = definers(protocols);
+    let protocol_by_name: HashMap<_, _> = protocols.iter().map(|p| (&*p.name, p)).collect();
+    let mut reachable = HashSet::new();
+    let mut todo = VecDeque::new();
+    let mut add =
+        |todo: &mut VecDeque<_>, protocol: &str, interface: &str, reason: &dyn Display| {
+            if reachable.insert((protocol.to_string(), interface.to_string())) {
+                explain(&format!("{protocol}::{interface}: {reason}"));
+                todo.push_back((protocol.to_string(), interface.to_string()));
+            }
+        };
+    for name in only_protocols {
+        let Some(protocol) = protocol_by_name.get(&**name) else {
+            return Err(NamespaceError::UnknownProtocol(name.clone()));
+        };
+        for interface in &protocol.interfaces {
+            add(
+                &mut todo,
+                &protocol.name,
+                &interface.name,
+                &"protocol was selected",
+            );
+        }
+    }
+    for name in only_interfaces {
+        let Some(candidates) = definers.get(&**name) else {
+            return Err(NamespaceError::UnknownInterface(name.clone()));
+        };
+        let candidates = match preferences.get(name) {
+            Some(preferred) => &[&**preferred][..],
+            None => candidates,
+        };
+        for protocol in candidates {
+            add(&mut todo, protocol, name, &"interface was selected");
+        }
+    }
+    while let Some((protocol, interface)) = todo.pop_front() {
+        let protocol = protocol_by_name[&*protocol];
+        let interface = protocol
+            .interfaces
+            .iter()
+            .find(|i| i.name == interface)
+            .unwrap();
+        for (message, arg, used, is_enum) in used_interfaces(interface) {
+            let Some(definer) = select(protocol, used, &definers, preferences)? else {
+                continue;
+            };
+            let kind = match (is_enum, arg.ty) {
+                (true, _) => "enum of argument",
+                (false, ArgType::NewId) => "new_id argument",
+                (false, _) => "object argument",
+            };
+            let reason = debug_fn(|f| {
+                write!(
+                    f,
+                    "{kind} `{}` of {}::{}.{}",
+                    arg.name, protocol.name, interface.name, message.name,
+                )
+            });
+            add(&mut todo, definer, used, &reason);
+        }
+    }
+    Ok(reachable)
+}
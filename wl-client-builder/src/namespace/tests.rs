@@ -0,0 +1,296 @@
+use {
+    crate::{
+        ast::Protocol,
+        namespace::{NamespaceError, closure, resolve},
+        parser::parse,
+    },
+    std::collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+};
+
+fn protocols(xml: &[&str]) -> Vec<Protocol> {
+    xml.iter()
+        .flat_map(|xml| parse(xml.as_bytes()).unwrap_or_else(|(_, e)| panic!("{e}")))
+        .collect()
+}
+
+fn preferences(p: &[(&str, &str)]) -> HashMap<String, String> {
+    p.iter()
+        .map(|(i, p)| (i.to_string(), p.to_string()))
+        .collect()
+}
+
+const CORE: &str = r#"
+    <protocol name="core">
+        <interface name="wl_surface" version="1"/>
+    </protocol>
+"#;
+
+const STABLE: &str = r#"
+    <protocol name="stable">
+        <interface name="tool" version="1">
+            <request name="set_surface">
+                <arg name="surface" type="object" interface="wl_surface"/>
+            </request>
+        </interface>
+    </protocol>
+"#;
+
+const UNSTABLE: &str = r#"
+    <protocol name="unstable">
+        <interface name="tool" version="1"/>
+        <interface name="manager" version="1">
+            <request name="get_tool">
+                <arg name="id" type="new_id" interface="tool"/>
+            </request>
+        </interface>
+    </protocol>
+"#;
+
+const USER: &str = r#"
+    <protocol name="user">
+        <interface name="user" version="1">
+            <request name="use_tool">
+                <arg name="tool" type="object" interface="tool"/>
+            </request>
+        </interface>
+    </protocol>
+"#;
+
+#[test]
+fn dependencies() {
+    let protocols = protocols(&[CORE, STABLE]);
+    let namespaces = resolve(&protocols, &HashMap::new()).unwrap();
+    assert!(namespaces.duplicates.is_empty());
+    assert!(namespaces.scoped.is_empty());
+    let expected = BTreeMap::from([
+        ("core".to_string(), BTreeSet::new()),
+        ("stable".to_string(), BTreeSet::from(["core".to_string()])),
+    ]);
+    assert_eq!(namespaces.dependencies, expected);
+}
+
+#[test]
+fn unknown_interface_is_ignored() {
+    let protocols = protocols(&[STABLE]);
+    let namespaces = resolve(&protocols, &HashMap::new()).unwrap();
+    assert!(namespaces.dependencies["stable"].is_empty());
+}
+
+#[test]
+fn collision_uses_own_definition() {
+    let protocols = protocols(&[STABLE, UNSTABLE]);
+    let namespaces = resolve(&protocols, &HashMap::new()).unwrap();
+    assert_eq!(namespaces.duplicates, ["tool".to_string()].into());
+    assert_eq!(namespaces.scoped["stable"]["tool"], "stable");
+    assert_eq!(namespaces.scoped["unstable"]["tool"], "unstable");
+    assert!(namespaces.dependencies["stable"].is_empty());
+    assert!(namespaces.dependencies["unstable"].is_empty());
+}
+
+#[test]
+fn collision_is_ambiguous() {
+    let protocols = protocols(&[STABLE, UNSTABLE, USER]);
+    let err = resolve(&protocols, &HashMap::new()).err().unwrap();
+    let NamespaceError::Ambiguous {
+        protocol,
+        interface,
+        candidates,
+    } = err
+    else {
+        panic!("{err}");
+    };
+    assert_eq!(protocol, "user");
+    assert_eq!(interface, "tool");
+    assert_eq!(candidates, ["stable", "unstable"]);
+}
+
+#[test]
+fn collision_uses_preference() {
+    let protocols = protocols(&[STABLE, UNSTABLE, USER]);
+    let namespaces = resolve(&protocols, &preferences(&[("tool", "unstable")])).unwrap();
+    assert_eq!(namespaces.scoped["user"]["tool"], "unstable");
+    assert_eq!(
+        namespaces.dependencies["user"],
+        ["unstable".to_string()].into(),
+    );
+    // A protocol that defines the interface ignores the preference.
+    assert_eq!(namespaces.scoped["stable"]["tool"], "stable");
+}
+
+#[test]
+fn invalid_preference() {
+    let protocols = protocols(&[CORE, STABLE, UNSTABLE]);
+    let err = resolve(&protocols, &preferences(&[("tool", "core")]))
+        .err()
+        .unwrap();
+    assert!(matches!(
+        err,
+        NamespaceError::InvalidPreference { ref interface, ref protocol }
+            if interface == "tool" && protocol == "core",
+    ));
+    let err = resolve(&protocols, &preferences(&[("unknown", "core")]))
+        .err()
+        .unwrap();
+    assert!(matches!(err, NamespaceError::InvalidPreference { .. }));
+}
+
+const SHELL: &str = r#"
+    <protocol name="shell">
+        <interface name="wm_base" version="1">
+            <request name="get_shell_surface">
+                <arg name="id" type="new_id" interface="shell_surface"/>
+                <arg name="surface" type="object" interface="wl_surface"/>
+            </request>
+        </interface>
+        <interface name="shell_surface" version="1">
+            <request name="set_anchor">
+                <arg name="anchor" type="uint" enum="positioner.anchor"/>
+            </request>
+        </interface>
+        <interface name="positioner" version="1">
+            <enum name="anchor">
+                <entry name="none" value="0"/>
+            </enum>
+        </interface>
+        <interface name="unused" version="1"/>
+    </protocol>
+"#;
+
+fn set(s: &[&str]) -> BTreeSet<String> {
+    s.iter().map(|s| s.to_string()).collect()
+}
+
+fn reachable(r: &[(&str, &str)]) -> HashSet<(String, String)> {
+    r.iter()
+        .map(|(p, i)| (p.to_string(), i.to_string()))
+        .collect()
+}
+
+#[test]
+fn closure_of_interface() {
+    let protocols = protocols(&[CORE, SHELL]);
+    let mut explanation = vec![];
+    let reachable_ = closure(
+        &protocols,
+        &HashMap::new(),
+        &set(&[]),
+        &set(&["wm_base"]),
+        &mut |line| explanation.push(line.to_string()),
+    )
+    .unwrap();
+    assert_eq!(
+        reachable_,
+        reachable(&[
+            ("shell", "wm_base"),
+            ("shell", "shell_surface"),
+            ("shell", "positioner"),
+            ("core", "wl_surface"),
+        ]),
+    );
+    assert_eq!(
+        explanation,
+        [
+            "shell::wm_base: interface was selected",
+            "shell::shell_surface: new_id argument `id` of shell::wm_base.get_shell_surface",
+            "core::wl_surface: object argument `surface` of shell::wm_base.get_shell_surface",
+            "shell::positioner: enum of argument `anchor` of shell::shell_surface.set_anchor",
+        ],
+    );
+}
+
+#[test]
+fn closure_of_protocol() {
+    let protocols = protocols(&[CORE, SHELL]);
+    let mut explanation = vec![];
+    let reachable_ = closure(
+        &protocols,
+        &HashMap::new(),
+        &set(&["shell"]),
+        &set(&[]),
+        &mut |line| explanation.push(line.to_string()),
+    )
+    .unwrap();
+    assert_eq!(reachable_.len(), 5);
+    assert!(reachable_.contains(&("shell".to_string(), "unused".to_string())));
+    assert!(reachable_.contains(&("core".to_string(), "wl_surface".to_string())));
+    assert_eq!(explanation.len(), 5);
+    assert_eq!(explanation[0], "shell::wm_base: protocol was selected");
+}
+
+#[test]
+fn closure_of_collision() {
+    let protocols = protocols(&[CORE, STABLE, UNSTABLE, USER]);
+    let err = closure(
+        &protocols,
+        &HashMap::new(),
+        &set(&[]),
+        &set(&["user"]),
+        &mut |_| (),
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, NamespaceError::Ambiguous { .. }));
+    let reachable_ = closure(
+        &protocols,
+        &preferences(&[("tool", "stable")]),
+        &set(&[]),
+        &set(&["user"]),
+        &mut |_| (),
+    )
+    .unwrap();
+    assert_eq!(
+        reachable_,
+        reachable(&[("user", "user"), ("stable", "tool"), ("core", "wl_surface"),]),
+    );
+}
+
+#[test]
+fn closure_of_duplicate_interface() {
+    let protocols = protocols(&[STABLE, UNSTABLE]);
+    let reachable_ = closure(
+        &protocols,
+        &HashMap::new(),
+        &set(&[]),
+        &set(&["tool"]),
+        &mut |_| (),
+    )
+    .unwrap();
+    assert_eq!(
+        reachable_,
+        reachable(&[("stable", "tool"), ("unstable", "tool")]),
+    );
+    let reachable_ = closure(
+        &protocols,
+        &preferences(&[("tool", "unstable")]),
+        &set(&[]),
+        &set(&["tool"]),
+        &mut |_| (),
+    )
+    .unwrap();
+    assert_eq!(reachable_, reachable(&[("unstable", "tool")]));
+}
+
+#[test]
+fn closure_of_unknown() {
+    let protocols = protocols(&[CORE]);
+    let err = closure(
+        &protocols,
+        &HashMap::new(),
+        &set(&["unknown"]),
+        &set(&[]),
+        &mut |_| (),
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, NamespaceError::UnknownProtocol(ref p) if p == "unknown"));
+    let err = closure(
+        &protocols,
+        &HashMap::new(),
+        &set(&[]),
+        &set(&["unknown"]),
+        &mut |_| (),
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, NamespaceError::UnknownInterface(ref i) if i == "unknown"));
+}
@@ -1,7 +1,7 @@
 use {
     crate::ast::{
-        Arg, ArgType, Copyright, Description, Entry, Enum, Interface, Message, MessageType,
-        Protocol,
+        Arg, ArgType, Copyright, Description, Entry, Enum, Interface, Location, Message,
+        MessageType, Protocol, Span,
     },
     quick_xml::{
         Reader,
@@ -167,11 +167,48 @@ pub(crate) enum AttributeError {
     DecodeUtf8(#[from] quick_xml::Error),
 }
 
-pub(crate) fn parse(input: &[u8]) -> Result<Vec<Protocol>, ParserError> {
-    let mut reader = Reader::from_reader(input);
+/// A reader that tracks the elements that are currently being parsed.
+struct Cursor<'a> {
+    reader: Reader<&'a [u8]>,
+    /// The start offsets of the elements that are currently being parsed.
+    open: Vec<Span>,
+}
+
+impl<'a> Cursor<'a> {
+    /// Reads the next event and returns it together with its start offset.
+    fn next(&mut self) -> Result<(usize, Event<'a>), quick_xml::Error> {
+        let pos = self.reader.buffer_position() as usize;
+        let event = self.reader.read_event()?;
+        Ok((pos, event))
+    }
+}
+
+/// Parses the protocols in an XML file.
+///
+/// If parsing fails, the error contains the location of the element that could not be
+/// parsed.
+pub(crate) fn parse(input: &[u8]) -> Result<Vec<Protocol>, (Location, ParserError)> {
+    let mut cursor = Cursor {
+        reader: Reader::from_reader(input),
+        open: vec![],
+    };
+    parse_(&mut cursor).map_err(|e| {
+        let offset = match cursor.reader.error_position() {
+            0 => cursor
+                .open
+                .last()
+                .map(|s| s.0)
+                .unwrap_or(cursor.reader.buffer_position() as usize),
+            n => n as usize,
+        };
+        (Location::new(input, Span(offset)), e)
+    })
+}
+
+fn parse_(cursor: &mut Cursor) -> Result<Vec<Protocol>, ParserError> {
     let mut protocols = Vec::new();
     loop {
-        let event = reader.read_event().map_err(ParserError::ReadEvent)?;
+        let (pos, event) = cursor.next().map_err(ParserError::ReadEvent)?;
         let (start, empty) = match event {
             Event::Start(s) => (s, false),
             Event::Empty(s) => (s, true),
@@ -179,7 +216,12 @@ pub(crate) fn parse(input: &[u8]) -> Result<Vec<Protocol>, ParserError> {
             _ => continue,
         };
         match start.local_name().as_ref() {
-            b"protocol" => protocols.push(parse_protocol(&mut reader, start.attributes(), empty)?),
+            b"protocol" => protocols.push(parse_protocol(
+                cursor,
+                Span(pos),
+                start.attributes(),
+                empty,
+            )?),
             _ => continue,
         }
     }
@@ -202,10 +244,12 @@ fn parse_attr<'a>(attr: &'a Attribute) -> Result<(&'a [u8], Cow<'a, str>), Attri
 }
 
 fn parse_protocol(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
+    span: Span,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Protocol, ProtocolError> {
+    cursor.open.push(span);
     let mut name = None;
     for attr in attributes {
         let (n, value) = parse_attr!(attr)?;
@@ -219,7 +263,7 @@ fn parse_protocol(
     let mut interfaces = vec![];
     if !empty {
         loop {
-            let event = reader.read_event().map_err(ProtocolError::ReadEvent)?;
+            let (pos, event) = cursor.next().map_err(ProtocolError::ReadEvent)?;
             let (start, empty) = match event {
                 Event::Start(s) => (s, false),
                 Event::End(_) => break,
@@ -228,35 +272,41 @@ fn parse_protocol(
             };
             match start.local_name().as_ref() {
                 b"copyright" => {
-                    copyright = Some(parse_copyright(reader, start.attributes(), empty)?)
+                    copyright = Some(parse_copyright(cursor, start.attributes(), empty)?)
                 }
                 b"description" => {
-                    description = Some(parse_description(reader, start.attributes(), empty)?)
-                }
-                b"interface" => {
-                    interfaces.push(parse_interface(reader, start.attributes(), empty)?)
+                    description = Some(parse_description(cursor, start.attributes(), empty)?)
                 }
+                b"interface" => interfaces.push(parse_interface(
+                    cursor,
+                    Span(pos),
+                    start.attributes(),
+                    empty,
+                )?),
                 _ => continue,
             }
         }
     }
-    Ok(Protocol {
+    let res = Protocol {
+        span,
         name: name.ok_or(ProtocolError::MissingName)?,
         _copyright: copyright,
         description,
         interfaces,
-    })
+    };
+    cursor.open.pop();
+    Ok(res)
 }
 
 fn parse_copyright(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
     _attributes: Attributes,
     empty: bool,
 ) -> Result<Copyright, CopyrightError> {
     let mut body = Vec::new();
     if !empty {
         loop {
-            let event = reader.read_event().map_err(CopyrightError::ReadEvent)?;
+            let (_, event) = cursor.next().map_err(CopyrightError::ReadEvent)?;
             match event {
                 Event::Text(s) => body.extend_from_slice(s.as_ref()),
                 Event::End(_) => break,
@@ -270,7 +320,7 @@ fn parse_copyright(
 }
 
 fn parse_description(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Description, DescriptionError> {
@@ -285,7 +335,7 @@ fn parse_description(
     let mut body = Vec::new();
     if !empty {
         loop {
-            let event = reader.read_event().map_err(DescriptionError::ReadEvent)?;
+            let (_, event) = cursor.next().map_err(DescriptionError::ReadEvent)?;
             match event {
                 Event::Text(s) => body.extend_from_slice(s.as_ref()),
                 Event::End(_) => break,
@@ -300,10 +350,12 @@ fn parse_description(
 }
 
 fn parse_interface(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
+    span: Span,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Interface, InterfaceError> {
+    cursor.open.push(span);
     let mut name = None;
     let mut version = None;
     for attr in attributes {
@@ -320,7 +372,7 @@ fn parse_interface(
     let mut enums = Vec::new();
     if !empty {
         loop {
-            let event = reader.read_event().map_err(InterfaceError::ReadEvent)?;
+            let (pos, event) = cursor.next().map_err(InterfaceError::ReadEvent)?;
             let (start, empty) = match event {
                 Event::Start(s) => (s, false),
                 Event::End(_) => break,
@@ -329,36 +381,41 @@ fn parse_interface(
             };
             match start.local_name().as_ref() {
                 b"description" => {
-                    description = Some(parse_description(reader, start.attributes(), empty)?)
+                    description = Some(parse_description(cursor, start.attributes(), empty)?)
                 }
                 b"request" => requests.push(
-                    parse_message(reader, start.attributes(), empty)
+                    parse_message(cursor, Span(pos), start.attributes(), empty)
                         .map_err(InterfaceError::Request)?,
                 ),
                 b"event" => events.push(
-                    parse_message(reader, start.attributes(), empty)
+                    parse_message(cursor, Span(pos), start.attributes(), empty)
                         .map_err(InterfaceError::Event)?,
                 ),
-                b"enum" => enums.push(parse_enum(reader, start.attributes(), empty)?),
+                b"enum" => enums.push(parse_enum(cursor, Span(pos), start.attributes(), empty)?),
                 _ => continue,
             }
         }
     }
-    Ok(Interface {
+    let res = Interface {
+        span,
         name: name.ok_or(InterfaceError::MissingName)?,
         version: version.ok_or(InterfaceError::MissingVersion)?,
         description,
         requests,
         events,
         enums,
-    })
+    };
+    cursor.open.pop();
+    Ok(res)
 }
 
 fn parse_message(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
+    span: Span,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Message, MessageError> {
+    cursor.open.push(span);
     let mut name = None;
     let mut ty = None;
     let mut since = None;
@@ -382,7 +439,7 @@ fn parse_message(
     let mut args = Vec::new();
     if !empty {
         loop {
-            let event = reader.read_event().map_err(MessageError::ReadEvent)?;
+            let (pos, event) = cursor.next().map_err(MessageError::ReadEvent)?;
             let (start, empty) = match event {
                 Event::Start(s) => (s, false),
                 Event::End(_) => break,
@@ -391,28 +448,33 @@ fn parse_message(
             };
             match start.local_name().as_ref() {
                 b"description" => {
-                    description = Some(parse_description(reader, start.attributes(), empty)?)
+                    description = Some(parse_description(cursor, start.attributes(), empty)?)
                 }
-                b"arg" => args.push(parse_arg(reader, start.attributes(), empty)?),
+                b"arg" => args.push(parse_arg(cursor, Span(pos), start.attributes(), empty)?),
                 _ => continue,
             }
         }
     }
-    Ok(Message {
+    let res = Message {
+        span,
         name: name.ok_or(MessageError::MissingName)?,
         ty,
         since,
         deprecated_since,
         description,
         args,
-    })
+    };
+    cursor.open.pop();
+    Ok(res)
 }
 
 fn parse_arg(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
+    span: Span,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Arg, ArgError> {
+    cursor.open.push(span);
     let mut name = None;
     let mut ty = None;
     let mut summary = None;
@@ -446,7 +508,7 @@ fn parse_arg(
     let mut description = None;
     if !empty {
         loop {
-            let event = reader.read_event().map_err(ArgError::ReadEvent)?;
+            let (_, event) = cursor.next().map_err(ArgError::ReadEvent)?;
             let (start, empty) = match event {
                 Event::Start(s) => (s, false),
                 Event::End(_) => break,
@@ -455,13 +517,14 @@ fn parse_arg(
             };
             match start.local_name().as_ref() {
                 b"description" => {
-                    description = Some(parse_description(reader, start.attributes(), empty)?)
+                    description = Some(parse_description(cursor, start.attributes(), empty)?)
                 }
                 _ => continue,
             }
         }
     }
-    Ok(Arg {
+    let res = Arg {
+        span,
         name: name.ok_or(ArgError::MissingName)?,
         ty: ty.ok_or(ArgError::MissingType)?,
         summary,
@@ -469,14 +532,18 @@ fn parse_arg(
         interface,
         allow_null: allow_null.unwrap_or_default(),
         enum_,
-    })
+    };
+    cursor.open.pop();
+    Ok(res)
 }
 
 fn parse_enum(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
+    span: Span,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Enum, EnumError> {
+    cursor.open.push(span);
     let mut name = None;
     let mut since = None;
     let mut bitfield = None;
@@ -493,7 +560,7 @@ fn parse_enum(
     let mut entries = Vec::new();
     if !empty {
         loop {
-            let event = reader.read_event().map_err(EnumError::ReadEvent)?;
+            let (pos, event) = cursor.next().map_err(EnumError::ReadEvent)?;
             let (start, empty) = match event {
                 Event::Start(s) => (s, false),
                 Event::End(_) => break,
@@ -502,27 +569,34 @@ fn parse_enum(
             };
             match start.local_name().as_ref() {
                 b"description" => {
-                    description = Some(parse_description(reader, start.attributes(), empty)?)
+                    description = Some(parse_description(cursor, start.attributes(), empty)?)
+                }
+                b"entry" => {
+                    entries.push(parse_entry(cursor, Span(pos), start.attributes(), empty)?)
                 }
-                b"entry" => entries.push(parse_entry(reader, start.attributes(), empty)?),
                 _ => continue,
             }
         }
     }
-    Ok(Enum {
+    let res = Enum {
+        span,
         name: name.ok_or(EnumError::MissingName)?,
-        _since: since,
+        since,
         bitfield: bitfield.unwrap_or_default(),
         description,
         entries,
-    })
+    };
+    cursor.open.pop();
+    Ok(res)
 }
 
 fn parse_entry(
-    reader: &mut Reader<&[u8]>,
+    cursor: &mut Cursor,
+    span: Span,
     attributes: Attributes,
     empty: bool,
 ) -> Result<Entry, EntryError> {
+    cursor.open.push(span);
     let mut name = None;
     let mut value = None;
     let mut summary = None;
@@ -544,7 +618,7 @@ fn parse_entry(
     let mut description = None;
     if !empty {
         loop {
-            let event = reader.read_event().map_err(EntryError::ReadEvent)?;
+            let (_, event) = cursor.next().map_err(EntryError::ReadEvent)?;
             let (start, empty) = match event {
                 Event::Start(s) => (s, false),
                 Event::End(_) => break,
@@ -553,7 +627,7 @@ fn parse_entry(
             };
             match start.local_name().as_ref() {
                 b"description" => {
-                    description = Some(parse_description(reader, start.attributes(), empty)?)
+                    description = Some(parse_description(cursor, start.attributes(), empty)?)
                 }
                 _ => continue,
             }
@@ -565,7 +639,8 @@ fn parse_entry(
     } else {
         u32::from_str(&value).map_err(EntryError::InvalidValue)?
     };
-    Ok(Entry {
+    let res = Entry {
+        span,
         name: name.ok_or(EntryError::MissingName)?,
         value,
         value_u32,
@@ -573,5 +648,7 @@ fn parse_entry(
         since,
         deprecated_since,
         description,
-    })
+    };
+    cursor.open.pop();
+    Ok(res)
 }
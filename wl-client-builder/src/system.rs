@@ -0,0 +1,221 @@
+use {
+    std::{
+        collections::HashMap,
+        ffi::OsString,
+        io,
+        path::{Path, PathBuf},
+        process::Command,
+    },
+    thiserror::Error,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The pkg-config package that contains the XML files of `wayland-protocols`.
+const PROTOCOLS_PACKAGE: &str = "wayland-protocols";
+/// The pkg-config package that contains `wayland.xml`.
+const SCANNER_PACKAGE: &str = "wayland-scanner";
+
+/// The sub-directories of the `wayland-protocols` directory in the order in which they
+/// are searched.
+const STABILITIES: [&str; 3] = ["stable", "staging", "unstable"];
+
+/// The environment variables that affect the output of pkg-config.
+pub(crate) const ENV_VARS: [&str; 4] = [
+    "PKG_CONFIG",
+    "PKG_CONFIG_PATH",
+    "PKG_CONFIG_LIBDIR",
+    "PKG_CONFIG_SYSROOT_DIR",
+];
+
+#[derive(Debug, Error)]
+pub(crate) enum SystemError {
+    #[error("Could not run {}: {}", .0.display(), .1)]
+    RunPkgConfig(PathBuf, io::Error),
+    #[error("pkg-config could not find the pkgdatadir of {0}: {1}")]
+    PkgConfig(&'static str, String),
+    #[error("System protocol {} was not found{}", .0, search_paths(.1, .2))]
+    NotFound(String, Vec<PathBuf>, Vec<SystemError>),
+    #[error(
+        "System protocol {} is ambiguous, select one of these files explicitly:{}",
+        .0,
+        .1.iter().map(|p| format!("\n  {}", p.display())).collect::<String>()
+    )]
+    Ambiguous(String, Vec<PathBuf>),
+}
+
+fn search_paths(searched: &[PathBuf], errors: &[SystemError]) -> String {
+    let mut s = String::new();
+    if !searched.is_empty() {
+        s.push_str("\nSearched in:");
+        for path in searched {
+            s.push_str(&format!("\n  {}", path.display()));
+        }
+    }
+    for error in errors {
+        s.push_str(&format!("\n{error}"));
+    }
+    s
+}
+
+/// A protocol installed on the system.
+pub(crate) enum SystemProtocol {
+    /// A protocol identified by its name, e.g. `xdg-shell`.
+    Name(String),
+    /// A path relative to the pkgdatadir of `wayland-protocols` or `wayland-scanner`.
+    File(PathBuf),
+}
+
+/// Locates system protocols via pkg-config.
+///
+/// Each package is only queried once.
+#[derive(Default)]
+pub(crate) struct PkgDataDirs {
+    dirs: HashMap<&'static str, Result<PathBuf, String>>,
+}
+
+impl PkgDataDirs {
+    fn get(&mut self, package: &'static str) -> Result<PathBuf, SystemError> {
+        if !self.dirs.contains_key(package) {
+            let dir = query(package)?;
+            self.dirs.insert(package, dir);
+        }
+        match &self.dirs[package] {
+            Ok(dir) => Ok(dir.clone()),
+            Err(e) => Err(SystemError::PkgConfig(package, e.clone())),
+        }
+    }
+
+    /// Returns the path of the XML file of a system protocol.
+    pub(crate) fn resolve(&mut self, protocol: &SystemProtocol) -> Result<PathBuf, SystemError> {
+        match protocol {
+            SystemProtocol::Name(name) => self.resolve_name(name),
+            SystemProtocol::File(file) => self.resolve_file(file),
+        }
+    }
+
+    /// Searches for `<name>.xml` in the pkgdatadir of `wayland-scanner` and in the
+    /// protocol directories of `wayland-protocols`. If no such file exists, a protocol
+    /// directory named `name` that contains a single XML file is used instead.
+    fn resolve_name(&mut self, name: &str) -> Result<PathBuf, SystemError> {
+        let file_name = format!("{name}.xml");
+        let mut searched = vec![];
+        let mut errors = vec![];
+        match self.get(SCANNER_PACKAGE) {
+            Ok(dir) => {
+                let candidate = dir.join(&file_name);
+                if candidate.is_file() {
+                    return Ok(candidate);
+                }
+                searched.push(dir);
+            }
+            Err(e) => errors.push(e),
+        }
+        match self.get(PROTOCOLS_PACKAGE) {
+            Ok(dir) => {
+                let mut by_dir_name = None;
+                for stability in STABILITIES {
+                    let stability_dir = dir.join(stability);
+                    for protocol_dir in sub_dirs(&stability_dir) {
+                        let candidate = protocol_dir.join(&file_name);
+                        if candidate.is_file() {
+                            return Ok(candidate);
+                        }
+                        if by_dir_name.is_none() && protocol_dir.file_name() == Some(name.as_ref())
+                        {
+                            let files = xml_files(&protocol_dir);
+                            if !files.is_empty() {
+                                by_dir_name = Some(files);
+                            }
+                        }
+                    }
+                    searched.push(stability_dir);
+                }
+                if let Some(mut files) = by_dir_name {
+                    if files.len() > 1 {
+                        return Err(SystemError::Ambiguous(name.to_string(), files));
+                    }
+                    return Ok(files.pop().unwrap());
+                }
+            }
+            Err(e) => errors.push(e),
+        }
+        Err(SystemError::NotFound(name.to_string(), searched, errors))
+    }
+
+    /// Searches for `file` relative to the pkgdatadir of `wayland-protocols` and
+    /// `wayland-scanner`.
+    fn resolve_file(&mut self, file: &Path) -> Result<PathBuf, SystemError> {
+        let mut searched = vec![];
+        let mut errors = vec![];
+        for package in [PROTOCOLS_PACKAGE, SCANNER_PACKAGE] {
+            match self.get(package) {
+                Ok(dir) => {
+                    let candidate = dir.join(file);
+                    if candidate.is_file() {
+                        return Ok(candidate);
+                    }
+                    searched.push(dir);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+        Err(SystemError::NotFound(
+            file.display().to_string(),
+            searched,
+            errors,
+        ))
+    }
+}
+
+/// Runs `pkg-config --variable=pkgdatadir <package>`.
+///
+/// The outer error is returned if pkg-config could not be run at all. The inner error
+/// contains the output of pkg-config if the package could not be found.
+fn query(package: &'static str) -> Result<Result<PathBuf, String>, SystemError> {
+    let program = std::env::var_os("PKG_CONFIG").unwrap_or_else(|| OsString::from("pkg-config"));
+    let output = Command::new(&program)
+        .arg("--variable=pkgdatadir")
+        .arg(package)
+        .output()
+        .map_err(|e| SystemError::RunPkgConfig(program.into(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stdout = stdout.trim();
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut msg = stderr.trim().to_string();
+        if msg.is_empty() {
+            msg = "the package is not installed or not in PKG_CONFIG_PATH".to_string();
+        }
+        return Ok(Err(msg));
+    }
+    if stdout.is_empty() {
+        return Ok(Err("the variable is not set".to_string()));
+    }
+    Ok(Ok(PathBuf::from(stdout)))
+}
+
+fn sub_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn xml_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "xml"))
+        .collect();
+    files.sort();
+    files
+}
@@ -0,0 +1,171 @@
+use {
+    crate::system::{PROTOCOLS_PACKAGE, PkgDataDirs, SCANNER_PACKAGE, SystemError, SystemProtocol},
+    std::{
+        collections::HashMap,
+        fs,
+        path::{Path, PathBuf},
+    },
+    tempfile::TempDir,
+};
+
+struct System {
+    dir: TempDir,
+    dirs: PkgDataDirs,
+}
+
+impl System {
+    /// Creates pkgdatadirs for wayland-scanner and wayland-protocols.
+    fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let scanner = dir.path().join("wayland");
+        let protocols = dir.path().join("wayland-protocols");
+        let dirs = PkgDataDirs {
+            dirs: HashMap::from([
+                (SCANNER_PACKAGE, Ok(scanner.clone())),
+                (PROTOCOLS_PACKAGE, Ok(protocols.clone())),
+            ]),
+        };
+        let slf = Self { dir, dirs };
+        slf.touch("wayland/wayland.xml");
+        slf.touch("wayland-protocols/stable/xdg-shell/xdg-shell.xml");
+        slf.touch("wayland-protocols/staging/cursor-shape/cursor-shape-v1.xml");
+        slf.touch("wayland-protocols/staging/xdg-shell/xdg-shell.xml");
+        slf.touch("wayland-protocols/unstable/tablet/tablet-unstable-v2.xml");
+        slf.touch("wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v5.xml");
+        slf.touch("wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v6.xml");
+        slf
+    }
+
+    fn touch(&self, path: &str) {
+        let path = self.dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.dir.path().join(path)
+    }
+
+    fn resolve_name(&mut self, name: &str) -> Result<PathBuf, SystemError> {
+        self.dirs.resolve(&SystemProtocol::Name(name.to_string()))
+    }
+
+    fn resolve_file(&mut self, file: &str) -> Result<PathBuf, SystemError> {
+        self.dirs
+            .resolve(&SystemProtocol::File(Path::new(file).into()))
+    }
+}
+
+#[test]
+fn name() {
+    let mut system = System::new();
+    assert_eq!(
+        system.resolve_name("wayland").unwrap(),
+        system.path("wayland/wayland.xml"),
+    );
+    assert_eq!(
+        system.resolve_name("cursor-shape-v1").unwrap(),
+        system.path("wayland-protocols/staging/cursor-shape/cursor-shape-v1.xml"),
+    );
+    assert_eq!(
+        system.resolve_name("xdg-shell-unstable-v6").unwrap(),
+        system.path("wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v6.xml"),
+    );
+}
+
+#[test]
+fn name_prefers_stable() {
+    let mut system = System::new();
+    assert_eq!(
+        system.resolve_name("xdg-shell").unwrap(),
+        system.path("wayland-protocols/stable/xdg-shell/xdg-shell.xml"),
+    );
+}
+
+#[test]
+fn directory_name() {
+    let mut system = System::new();
+    assert_eq!(
+        system.resolve_name("tablet").unwrap(),
+        system.path("wayland-protocols/unstable/tablet/tablet-unstable-v2.xml"),
+    );
+}
+
+#[test]
+fn ambiguous_directory_name() {
+    let mut system = System::new();
+    fs::remove_file(system.path("wayland-protocols/stable/xdg-shell/xdg-shell.xml")).unwrap();
+    fs::remove_file(system.path("wayland-protocols/staging/xdg-shell/xdg-shell.xml")).unwrap();
+    let err = system.resolve_name("xdg-shell").unwrap_err();
+    let SystemError::Ambiguous(name, files) = &err else {
+        panic!("{err}");
+    };
+    assert_eq!(name, "xdg-shell");
+    assert_eq!(
+        *files,
+        [
+            system.path("wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v5.xml"),
+            system.path("wayland-protocols/unstable/xdg-shell/xdg-shell-unstable-v6.xml"),
+        ],
+    );
+}
+
+#[test]
+fn name_not_found() {
+    let mut system = System::new();
+    let err = system.resolve_name("unknown").unwrap_err();
+    assert!(matches!(err, SystemError::NotFound(..)));
+    let expected = format!(
+        "System protocol unknown was not found\n\
+         Searched in:\n  {}\n  {}\n  {}\n  {}",
+        system.path("wayland").display(),
+        system.path("wayland-protocols/stable").display(),
+        system.path("wayland-protocols/staging").display(),
+        system.path("wayland-protocols/unstable").display(),
+    );
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn file() {
+    let mut system = System::new();
+    assert_eq!(
+        system
+            .resolve_file("staging/xdg-shell/xdg-shell.xml")
+            .unwrap(),
+        system.path("wayland-protocols/staging/xdg-shell/xdg-shell.xml"),
+    );
+    assert_eq!(
+        system.resolve_file("wayland.xml").unwrap(),
+        system.path("wayland/wayland.xml"),
+    );
+    let err = system.resolve_file("stable/unknown.xml").unwrap_err();
+    let expected = format!(
+        "System protocol stable/unknown.xml was not found\n\
+         Searched in:\n  {}\n  {}",
+        system.path("wayland-protocols").display(),
+        system.path("wayland").display(),
+    );
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn package_not_found() {
+    let mut system = System::new();
+    system.dirs.dirs.insert(
+        PROTOCOLS_PACKAGE,
+        Err("Package wayland-protocols was not found".to_string()),
+    );
+    assert_eq!(
+        system.resolve_name("wayland").unwrap(),
+        system.path("wayland/wayland.xml"),
+    );
+    let err = system.resolve_name("xdg-shell").unwrap_err();
+    let expected = format!(
+        "System protocol xdg-shell was not found\n\
+         Searched in:\n  {}\n\
+         pkg-config could not find the pkgdatadir of wayland-protocols: Package wayland-protocols was not found",
+        system.path("wayland").display(),
+    );
+    assert_eq!(err.to_string(), expected);
+}
@@ -0,0 +1,254 @@
+use {
+    crate::ast::{ArgType, Interface, Location, Message, Protocol, Span},
+    std::{
+        collections::{HashMap, HashSet},
+        fmt::{Display, Formatter},
+        path::{Path, PathBuf},
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A problem in a protocol XML file.
+///
+/// Diagnostics are produced by [`Builder::lint`](crate::Builder::lint).
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// The XML file that contains the problem.
+    pub file: PathBuf,
+    /// The line of the element that contains the problem, starting at 1.
+    pub line: usize,
+    /// The column of the element that contains the problem, starting at 1.
+    pub column: usize,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file.display(),
+            self.line,
+            self.column,
+            self.message,
+        )
+    }
+}
+
+/// A parsed XML file.
+pub(crate) struct SourceFile<'a> {
+    pub(crate) path: &'a Path,
+    pub(crate) contents: &'a [u8],
+    pub(crate) protocols: &'a [Protocol],
+}
+
+struct Validator<'a> {
+    file: &'a SourceFile<'a>,
+    interfaces: &'a HashMap<&'a str, &'a Interface>,
+    diagnostics: &'a mut Vec<Diagnostic>,
+}
+
+/// Checks the protocols for semantic problems.
+///
+/// References to interfaces and enums are resolved across all files.
+pub(crate) fn validate(files: &[SourceFile<'_>]) -> Vec<Diagnostic> {
+    let mut interfaces = HashMap::new();
+    for file in files {
+        for protocol in file.protocols {
+            for interface in &protocol.interfaces {
+                interfaces.entry(&*interface.name).or_insert(interface);
+            }
+        }
+    }
+    let mut diagnostics = vec![];
+    for file in files {
+        let mut validator = Validator {
+            file,
+            interfaces: &interfaces,
+            diagnostics: &mut diagnostics,
+        };
+        for protocol in file.protocols {
+            validator.validate_protocol(protocol);
+        }
+    }
+    diagnostics.sort();
+    diagnostics
+}
+
+fn names(messages: &[Message]) -> impl Iterator<Item = (&str, Span)> {
+    messages.iter().map(|m| (&*m.name, m.span))
+}
+
+impl Validator<'_> {
+    fn report(&mut self, span: Span, message: String) {
+        let location = Location::new(self.file.contents, span);
+        self.diagnostics.push(Diagnostic {
+            file: self.file.path.to_owned(),
+            line: location.line,
+            column: location.column,
+            message,
+        });
+    }
+
+    fn check_unique<'a>(
+        &mut self,
+        kind: &str,
+        parent: &str,
+        items: impl IntoIterator<Item = (&'a str, Span)>,
+    ) {
+        let mut seen = HashSet::new();
+        for (name, span) in items {
+            if !seen.insert(name) {
+                self.report(
+                    span,
+                    format!("{kind} {name} is defined multiple times in {parent}"),
+                );
+            }
+        }
+    }
+
+    fn check_since(
+        &mut self,
+        span: Span,
+        what: &str,
+        interface: &Interface,
+        since: Option<u32>,
+        deprecated_since: Option<u32>,
+    ) {
+        let version = interface.version;
+        if let Some(since) = since {
+            if since > version {
+                self.report(
+                    span,
+                    format!(
+                        "{what} has since={since} but interface {} has version {version}",
+                        interface.name,
+                    ),
+                );
+            }
+        }
+        if let Some(deprecated_since) = deprecated_since {
+            if deprecated_since > version {
+                self.report(
+                    span,
+                    format!(
+                        "{what} has deprecated-since={deprecated_since} but interface {} has version {version}",
+                        interface.name,
+                    ),
+                );
+            }
+            let since = since.unwrap_or(1);
+            if deprecated_since < since {
+                self.report(
+                    span,
+                    format!(
+                        "{what} has deprecated-since={deprecated_since} which is less than since={since}"
+                    ),
+                );
+            }
+        }
+    }
+
+    fn validate_protocol(&mut self, protocol: &Protocol) {
+        self.check_unique(
+            "interface",
+            &format!("protocol {}", protocol.name),
+            protocol.interfaces.iter().map(|i| (&*i.name, i.span)),
+        );
+        for interface in &protocol.interfaces {
+            self.validate_interface(interface);
+        }
+    }
+
+    fn validate_interface(&mut self, interface: &Interface) {
+        let parent = format!("interface {}", interface.name);
+        self.check_unique("request", &parent, names(&interface.requests));
+        self.check_unique("event", &parent, names(&interface.events));
+        self.check_unique(
+            "enum",
+            &parent,
+            interface.enums.iter().map(|e| (&*e.name, e.span)),
+        );
+        for (kind, messages) in [
+            ("request", &interface.requests),
+            ("event", &interface.events),
+        ] {
+            for message in messages {
+                self.validate_message(interface, kind, message);
+            }
+        }
+        for enum_ in &interface.enums {
+            let what = format!("enum {}.{}", interface.name, enum_.name);
+            self.check_since(enum_.span, &what, interface, enum_.since, None);
+            self.check_unique(
+                "entry",
+                &format!("enum {}.{}", interface.name, enum_.name),
+                enum_.entries.iter().map(|e| (&*e.name, e.span)),
+            );
+            for entry in &enum_.entries {
+                let what = format!("entry {}.{}.{}", interface.name, enum_.name, entry.name);
+                self.check_since(
+                    entry.span,
+                    &what,
+                    interface,
+                    entry.since,
+                    entry.deprecated_since,
+                );
+            }
+        }
+    }
+
+    fn validate_message(&mut self, interface: &Interface, kind: &str, message: &Message) {
+        let what = format!("{kind} {}.{}", interface.name, message.name);
+        self.check_since(
+            message.span,
+            &what,
+            interface,
+            message.since,
+            message.deprecated_since,
+        );
+        self.check_unique(
+            "argument",
+            &format!("{kind} {}.{}", interface.name, message.name),
+            message.args.iter().map(|a| (&*a.name, a.span)),
+        );
+        for arg in &message.args {
+            let what = format!("argument {} of {what}", arg.name);
+            let nullable = matches!(
+                arg.ty,
+                ArgType::Object | ArgType::NewId | ArgType::String | ArgType::Array,
+            );
+            if arg.allow_null && !nullable {
+                self.report(
+                    arg.span,
+                    format!("{what} has allow-null but is not an object, string, or array"),
+                );
+            }
+            if let Some(name) = &arg.interface {
+                if !self.interfaces.contains_key(&**name) {
+                    self.report(
+                        arg.span,
+                        format!("{what} uses interface {name} which is not defined"),
+                    );
+                }
+            }
+            if let Some(name) = &arg.enum_ {
+                let (owner, enum_name) = match name.split_once('.') {
+                    Some((i, e)) => (self.interfaces.get(i).copied(), e),
+                    None => (Some(interface), &**name),
+                };
+                let defined = owner.is_some_and(|i| i.enums.iter().any(|e| e.name == enum_name));
+                if !defined {
+                    self.report(
+                        arg.span,
+                        format!("{what} uses enum {name} which is not defined"),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use {
+    crate::{
+        parser::parse,
+        validate::{SourceFile, validate},
+    },
+    std::path::Path,
+};
+
+fn lint(files: &[(&str, &str)]) -> Vec<String> {
+    let parsed: Vec<_> = files
+        .iter()
+        .map(|(path, xml)| {
+            let protocols = parse(xml.as_bytes()).unwrap_or_else(|(_, e)| panic!("{e}"));
+            (Path::new(path), xml.as_bytes(), protocols)
+        })
+        .collect();
+    let files: Vec<_> = parsed
+        .iter()
+        .map(|(path, contents, protocols)| SourceFile {
+            path,
+            contents,
+            protocols,
+        })
+        .collect();
+    validate(&files).iter().map(|d| d.to_string()).collect()
+}
+
+#[test]
+fn valid() {
+    let xml = r#"<protocol name="p">
+        <interface name="a" version="2">
+            <request name="r" since="2">
+                <arg name="b" type="object" interface="b" allow-null="true"/>
+                <arg name="e" type="uint" enum="e"/>
+                <arg name="f" type="uint" enum="b.f"/>
+            </request>
+            <enum name="e">
+                <entry name="x" value="0" since="1" deprecated-since="2"/>
+            </enum>
+        </interface>
+        <interface name="b" version="1">
+            <enum name="f" bitfield="true">
+                <entry name="y" value="1"/>
+            </enum>
+        </interface>
+    </protocol>"#;
+    assert_eq!(lint(&[("p.xml", xml)]), Vec::<String>::new());
+}
+
+#[test]
+fn undefined_references() {
+    let xml = r#"<protocol name="p">
+        <interface name="a" version="1">
+            <request name="r">
+                <arg name="o" type="object" interface="missing"/>
+                <arg name="e" type="uint" enum="missing"/>
+                <arg name="f" type="uint" enum="a.missing"/>
+                <arg name="g" type="uint" enum="missing.e"/>
+            </request>
+        </interface>
+    </protocol>"#;
+    assert_eq!(
+        lint(&[("p.xml", xml)]),
+        [
+            "p.xml:4:17: argument o of request a.r uses interface missing which is not defined",
+            "p.xml:5:17: argument e of request a.r uses enum missing which is not defined",
+            "p.xml:6:17: argument f of request a.r uses enum a.missing which is not defined",
+            "p.xml:7:17: argument g of request a.r uses enum missing.e which is not defined",
+        ],
+    );
+}
+
+#[test]
+fn references_across_files() {
+    let a = r#"<protocol name="a">
+        <interface name="a" version="1">
+            <event name="ev">
+                <arg name="b" type="new_id" interface="b"/>
+                <arg name="e" type="uint" enum="b.e"/>
+            </event>
+        </interface>
+    </protocol>"#;
+    let b = r#"<protocol name="b">
+        <interface name="b" version="1">
+            <enum name="e">
+                <entry name="x" value="0"/>
+            </enum>
+        </interface>
+    </protocol>"#;
+    assert_eq!(lint(&[("a.xml", a), ("b.xml", b)]), Vec::<String>::new());
+    assert_eq!(lint(&[("a.xml", a)]).len(), 2);
+}
+
+#[test]
+fn versions() {
+    let xml = r#"<protocol name="p">
+        <interface name="a" version="2">
+            <request name="r" since="3"/>
+            <event name="ev" deprecated-since="3"/>
+            <event name="ev2" since="2" deprecated-since="1"/>
+            <enum name="e" since="3">
+                <entry name="x" value="0" since="3" deprecated-since="2"/>
+            </enum>
+        </interface>
+    </protocol>"#;
+    assert_eq!(
+        lint(&[("p.xml", xml)]),
+        [
+            "p.xml:3:13: request a.r has since=3 but interface a has version 2",
+            "p.xml:4:13: event a.ev has deprecated-since=3 but interface a has version 2",
+            "p.xml:5:13: event a.ev2 has deprecated-since=1 which is less than since=2",
+            "p.xml:6:13: enum a.e has since=3 but interface a has version 2",
+            "p.xml:7:17: entry a.e.x has deprecated-since=2 which is less than since=3",
+            "p.xml:7:17: entry a.e.x has since=3 but interface a has version 2",
+        ],
+    );
+}
+
+#[test]
+fn duplicates() {
+    let xml = r#"<protocol name="p">
+        <interface name="a" version="1">
+            <request name="r">
+                <arg name="x" type="int"/>
+                <arg name="x" type="int"/>
+            </request>
+            <request name="r"/>
+            <event name="ev"/>
+            <event name="ev"/>
+            <enum name="e">
+                <entry name="x" value="0"/>
+                <entry name="x" value="1"/>
+            </enum>
+            <enum name="e"/>
+        </interface>
+        <interface name="a" version="1"/>
+    </protocol>"#;
+    assert_eq!(
+        lint(&[("p.xml", xml)]),
+        [
+            "p.xml:5:17: argument x is defined multiple times in request a.r",
+            "p.xml:7:13: request r is defined multiple times in interface a",
+            "p.xml:9:13: event ev is defined multiple times in interface a",
+            "p.xml:12:17: entry x is defined multiple times in enum a.e",
+            "p.xml:14:13: enum e is defined multiple times in interface a",
+            "p.xml:16:9: interface a is defined multiple times in protocol p",
+        ],
+    );
+}
+
+#[test]
+fn allow_null() {
+    let xml = r#"<protocol name="p">
+        <interface name="a" version="1">
+            <request name="r">
+                <arg name="i" type="int" allow-null="true"/>
+                <arg name="fd" type="fd" allow-null="true"/>
+                <arg name="s" type="string" allow-null="true"/>
+                <arg name="a" type="array" allow-null="true"/>
+            </request>
+        </interface>
+    </protocol>"#;
+    assert_eq!(
+        lint(&[("p.xml", xml)]),
+        [
+            "p.xml:4:17: argument i of request a.r has allow-null but is not an object, string, or array",
+            "p.xml:5:17: argument fd of request a.r has allow-null but is not an object, string, or array",
+        ],
+    );
+}
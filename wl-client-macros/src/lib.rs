@@ -0,0 +1,207 @@
+//! This crate provides the [`generate!`] macro that generates type-safe wrappers for the
+//! [`wl-client`] crate without a `build.rs`.
+//!
+//! You should usually use this macro via `wl_client::generate!` by enabling the `macros`
+//! feature of the [`wl-client`] crate.
+//!
+//! [`wl-client`]: https://docs.rs/wl-client
+
+use {
+    proc_macro2::{Span, TokenStream},
+    quote::quote,
+    std::{error::Error, path::PathBuf},
+    syn::{
+        Expr, ExprLit, Lit, MetaNameValue, Token, parse::Parser, punctuated::Punctuated,
+        spanned::Spanned,
+    },
+    wl_client_builder::Builder,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Generates wrappers for wayland protocols.
+///
+/// The macro expands to the same items that the `mod.rs` generated by
+/// `wl_client_builder::Builder` contains. It should therefore usually be invoked in a
+/// dedicated module.
+///
+/// The macro accepts a comma-separated list of the following arguments:
+///
+/// - `path = "..."`: The path of a protocol XML file. Relative paths are relative to the
+///   directory containing the `Cargo.toml` of the crate.
+/// - `dir = "..."`: The path of a directory containing protocol XML files. Relative
+///   paths are relative to the directory containing the `Cargo.toml` of the crate.
+/// - `xml = "..."`: The contents of a protocol XML file. This can be used for private
+///   protocols that are only used by a single application.
+/// - `mutable_data = true|false`: Ignored. Event handlers with mutable data implement
+///   the `*EventHandlerWithData<T>` traits, which are always generated.
+/// - `wl_client_path = "..."`: The rust module path of the `wl-client` crate. Defaults
+///   to `::wl_client`.
+/// - `libwayland_interfaces = true|false`: Whether the core interfaces of the `wayland`
+///   protocol use the `wl_*_interface` symbols of libwayland-client. Defaults to
+///   `false`. See `wl_client_builder::Builder::with_libwayland_interfaces`.
+/// - `export_interface_symbols = "..."`: The name of a protocol whose `wl_interface`
+///   tables are exported as C symbols. See
+///   `wl_client_builder::Builder::export_interface_symbols`.
+///
+/// `path`, `dir`, `xml`, and `export_interface_symbols` can be specified multiple times.
+///
+/// The crate is recompiled if any of the XML files used by the macro change. Files that
+/// are added to or removed from a directory are only detected after the next
+/// recompilation.
+///
+/// # Example
+///
+/// ```
+/// mod protocols {
+///     wl_client_macros::generate!(
+///         xml = r#"
+///             <protocol name="hello">
+///                 <interface name="hello_world" version="1">
+///                     <request name="greet">
+///                         <arg name="name" type="string"/>
+///                     </request>
+///                 </interface>
+///             </protocol>
+///         "#,
+///     );
+/// }
+///
+/// use protocols::hello::hello_world::HelloWorld;
+/// ```
+///
+/// Exporting the `wl_interface` tables as C symbols:
+///
+/// ```
+/// mod protocols {
+///     wl_client_macros::generate!(
+///         xml = r#"
+///             <protocol name="hello">
+///                 <interface name="hello_world" version="1"/>
+///             </protocol>
+///         "#,
+///         export_interface_symbols = "hello",
+///     );
+/// }
+///
+/// use {
+///     protocols::hello::hello_world::HelloWorld,
+///     wl_client::{ffi::wl_interface, proxy::OwnedProxy},
+/// };
+///
+/// unsafe extern "C" {
+///     static hello_world_interface: wl_interface;
+/// }
+///
+/// let symbol = unsafe { &raw const hello_world_interface };
+/// assert_eq!(symbol, HelloWorld::WL_INTERFACE as *const wl_interface);
+/// ```
+///
+/// Using libwayland-client's definitions of the core interfaces:
+///
+/// ```
+/// mod protocols {
+///     wl_client_macros::generate!(
+///         xml = r#"
+///             <protocol name="wayland">
+///                 <interface name="wl_callback" version="1">
+///                     <event name="done" type="destructor">
+///                         <arg name="callback_data" type="uint"/>
+///                     </event>
+///                 </interface>
+///             </protocol>
+///         "#,
+///         libwayland_interfaces = true,
+///     );
+/// }
+///
+/// use {
+///     protocols::wayland::wl_callback::WlCallback,
+///     std::ffi::CStr,
+///     wl_client::proxy::OwnedProxy,
+/// };
+///
+/// // Falls back to the generated table if libwayland-client is not available.
+/// let interface = WlCallback::LIBWAYLAND_INTERFACE.unwrap().get();
+/// let name = unsafe { CStr::from_ptr(interface.name) };
+/// assert_eq!(name, c"wl_callback");
+/// ```
+#[proc_macro]
+pub fn generate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match generate_(input.into()) {
+        Ok(s) => s.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn generate_(input: TokenStream) -> syn::Result<TokenStream> {
+    let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(input)?;
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+    let path = |s: String| match &manifest_dir {
+        Some(dir) => dir.join(s),
+        None => PathBuf::from(s),
+    };
+    let mut builder = Builder::default()
+        .for_build_rs(false)
+        .with_default_dir(false);
+    for arg in args {
+        let Some(name) = arg.path.get_ident() else {
+            return Err(syn::Error::new(arg.path.span(), "expected an identifier"));
+        };
+        let lit = match &arg.value {
+            Expr::Lit(ExprLit { lit, .. }) => lit,
+            _ => return Err(syn::Error::new(arg.value.span(), "expected a literal")),
+        };
+        builder = match (&*name.to_string(), lit) {
+            ("path", Lit::Str(s)) => builder.xml_file(path(s.value())),
+            ("dir", Lit::Str(s)) => builder.xml_dir(path(s.value())),
+            ("xml", Lit::Str(s)) => builder.xml_string(s.value()),
+            ("wl_client_path", Lit::Str(s)) => builder.wl_client_path(&s.value()),
+            ("export_interface_symbols", Lit::Str(s)) => {
+                builder.export_interface_symbols(&s.value())
+            }
+            ("mutable_data", Lit::Bool(_)) => builder,
+            ("libwayland_interfaces", Lit::Bool(b)) => builder.with_libwayland_interfaces(b.value),
+            ("path" | "dir" | "xml" | "wl_client_path" | "export_interface_symbols", _) => {
+                return Err(syn::Error::new(lit.span(), "expected a string literal"));
+            }
+            ("mutable_data" | "libwayland_interfaces", _) => {
+                return Err(syn::Error::new(lit.span(), "expected a boolean literal"));
+            }
+            _ => {
+                return Err(syn::Error::new(name.span(), "unknown argument"));
+            }
+        };
+    }
+    let generated = match builder.build_inline() {
+        Ok(g) => g,
+        Err(e) => return Err(syn::Error::new(Span::call_site(), format_error(&e))),
+    };
+    let mut tokens: TokenStream = generated.code.parse()?;
+    for file in generated.xml_files {
+        let Some(file) = file.to_str() else {
+            continue;
+        };
+        // Makes cargo recompile the crate if the file changes.
+        tokens.extend(quote! {
+            const _: &[u8] = include_bytes!(#file);
+        });
+    }
+    Ok(tokens)
+}
+
+fn format_error(e: &dyn Error) -> String {
+    let mut msg = e.to_string();
+    let mut source = e.source();
+    while let Some(e) = source {
+        let s = e.to_string();
+        // Some errors include the message of their source in their own message.
+        if !msg.ends_with(&s) {
+            msg.push_str(": ");
+            msg.push_str(&s);
+        }
+        source = e.source();
+    }
+    msg
+}
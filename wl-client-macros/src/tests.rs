@@ -0,0 +1,113 @@
+use {crate::generate_, proc_macro2::TokenStream, quote::quote};
+
+const XML: &str = r#"
+    <protocol name="hello">
+        <interface name="hello_world" version="1"/>
+    </protocol>
+"#;
+
+fn generate(input: TokenStream) -> String {
+    generate_(input).unwrap().to_string()
+}
+
+fn error(input: TokenStream) -> String {
+    generate_(input).unwrap_err().to_string()
+}
+
+#[test]
+fn xml() {
+    let code = generate(quote! { xml = #XML });
+    assert!(code.contains("pub mod hello"), "{code}");
+    assert!(code.contains("pub mod hello_world"), "{code}");
+    assert!(code.contains(":: wl_client ::"), "{code}");
+    assert!(!code.contains("include_bytes"), "{code}");
+}
+
+#[test]
+fn path() {
+    let code = generate(quote! {
+        path = "../wl-client-protocols/protocols/wayland.xml",
+    });
+    assert!(code.contains("pub mod wayland"), "{code}");
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let file = format!("{manifest_dir}/../wl-client-protocols/protocols/wayland.xml");
+    assert!(
+        code.contains(&format!("include_bytes ! ({file:?})")),
+        "{code}",
+    );
+}
+
+#[test]
+fn dir() {
+    let code = generate(quote! {
+        dir = "../wl-client-protocols/protocols",
+    });
+    assert!(code.contains("pub mod wayland"), "{code}");
+    assert!(code.contains("include_bytes"), "{code}");
+}
+
+#[test]
+fn wl_client_path() {
+    let code = generate(quote! {
+        xml = #XML,
+        wl_client_path = "crate::wl",
+    });
+    assert!(code.contains("crate :: wl ::"), "{code}");
+    assert!(!code.contains(":: wl_client ::"), "{code}");
+}
+
+#[test]
+fn export_interface_symbols() {
+    let code = generate(quote! {
+        xml = #XML,
+        export_interface_symbols = "hello",
+    });
+    assert!(code.contains("hello_world_interface"), "{code}");
+}
+
+#[test]
+fn mutable_data_is_ignored() {
+    let with = generate(quote! { xml = #XML, mutable_data = true });
+    let without = generate(quote! { xml = #XML });
+    assert_eq!(with, without);
+}
+
+#[test]
+fn libwayland_interfaces() {
+    let xml = r#"
+        <protocol name="wayland">
+            <interface name="wl_callback" version="1"/>
+        </protocol>
+    "#;
+    let code = generate(quote! { xml = #xml, libwayland_interfaces = true });
+    assert!(code.contains("LibwaylandInterface"), "{code}");
+    let code = generate(quote! { xml = #xml, libwayland_interfaces = false });
+    assert!(!code.contains("LibwaylandInterface"), "{code}");
+}
+
+#[test]
+fn invalid_arguments() {
+    assert_eq!(error(quote! { unknown = "" }), "unknown argument");
+    assert_eq!(error(quote! { a::b = "" }), "expected an identifier");
+    assert_eq!(error(quote! { xml = XML }), "expected a literal");
+    assert_eq!(error(quote! { xml = true }), "expected a string literal");
+    assert_eq!(error(quote! { path = 1 }), "expected a string literal");
+    assert_eq!(
+        error(quote! { mutable_data = "true" }),
+        "expected a boolean literal",
+    );
+    assert_eq!(
+        error(quote! { libwayland_interfaces = 1 }),
+        "expected a boolean literal",
+    );
+    assert!(generate_(quote! { xml }).is_err());
+}
+
+#[test]
+fn builder_errors() {
+    let err = error(quote! { xml = "<protocol" });
+    assert!(err.starts_with("Could not parse <inline XML #1>:"), "{err}");
+    let err = error(quote! { path = "does-not-exist.xml" });
+    assert!(err.starts_with("Could not read "), "{err}");
+    assert!(err.contains("does-not-exist.xml"), "{err}");
+}
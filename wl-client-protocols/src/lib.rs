@@ -0,0 +1,44 @@
+//! This crate provides pre-generated bindings for the [`wl-client`] crate.
+//!
+//! [`wl-client`]: https://docs.rs/wl-client
+//!
+//! The bindings cover the core [wayland] protocol and the stable, staging, and unstable
+//! protocols from [wayland-protocols]. If you need other protocols, you can generate
+//! bindings yourself with [`wl-client-builder`].
+//!
+//! [wayland]: https://gitlab.freedesktop.org/wayland/wayland
+//! [wayland-protocols]: https://gitlab.freedesktop.org/wayland/wayland-protocols
+//! [`wl-client-builder`]: https://docs.rs/wl-client-builder
+//!
+//! # Features
+//!
+//! Each protocol sits behind a cargo feature with the same name as the protocol, for
+//! example `xdg_shell` or `viewporter`. Enabling a feature also enables the features of
+//! all protocols whose interfaces are used by the protocol. For example,
+//! `cursor_shape_v1` enables `tablet_v2` and `wayland`. The `wayland` feature is enabled
+//! by default.
+//!
+//! # Mutable data
+//!
+//! Event handlers that take mutable data, which is passed in when dispatching a queue,
+//! implement the `*EventHandlerWithData<T>` traits. They can be attached to the same
+//! proxies as event handlers that implement the `*EventHandler` traits.
+//!
+//! # Example
+//!
+//! ```
+//! use wl_client::Libwayland;
+//! use wl_client_protocols::wayland::wl_display::WlDisplay;
+//!
+//! # fn no_run() {
+//! let lib = Libwayland::open().unwrap();
+//! let con = lib.connect_to_default_display().unwrap();
+//! let queue = con.create_local_queue(c"queue name");
+//! let _registry = queue.display::<WlDisplay>().get_registry();
+//! # }
+//! ```
+
+#[allow(unused_imports)]
+pub use protocols::*;
+
+mod protocols;
@@ -0,0 +1,388 @@
+//! alpha modifier object for a surface
+//!
+//! This interface allows the client to set a factor for the alpha values on
+//! a surface, which can be used to offload such operations to the compositor.
+//! The default factor is UINT32_MAX.
+//!
+//! This object has to be destroyed before the associated wl_surface. Once the
+//! wl_surface is destroyed, all request on this object will raise the
+//! no_surface error.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_alpha_modifier_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_multiplier".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_alpha_modifier_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_alpha_modifier_surface_v1",
+    protocol: "alpha_modifier_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_multiplier",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "factor",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "no_surface",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_alpha_modifier_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpAlphaModifierSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_alpha_modifier_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpAlphaModifierSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpAlphaModifierSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpAlphaModifierSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpAlphaModifierSurfaceV1 {
+    const INTERFACE: &'static str = "wp_alpha_modifier_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpAlphaModifierSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpAlphaModifierSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpAlphaModifierSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpAlphaModifierSurfaceV1Ref {
+    type Owned = WpAlphaModifierSurfaceV1;
+}
+
+impl Deref for WpAlphaModifierSurfaceV1 {
+    type Target = WpAlphaModifierSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpAlphaModifierSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_alpha_modifier_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpAlphaModifierSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_alpha_modifier_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpAlphaModifierSurfaceV1Ref> for WpAlphaModifierSurfaceV1 {
+    fn eq(&self, other: &WpAlphaModifierSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpAlphaModifierSurfaceV1> for WpAlphaModifierSurfaceV1Ref {
+    fn eq(&self, other: &WpAlphaModifierSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpAlphaModifierSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the alpha modifier object
+    ///
+    /// This destroys the object, and is equivalent to set_multiplier with
+    /// a value of UINT32_MAX, with the same double-buffered semantics as
+    /// set_multiplier.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpAlphaModifierSurfaceV1Ref {
+    /// specify the alpha multiplier
+    ///
+    /// Sets the alpha multiplier for the surface. The alpha multiplier is
+    /// double-buffered state, see wl_surface.commit for details.
+    ///
+    /// This factor is applied in the compositor's blending space, as an
+    /// additional step after the processing of per-pixel alpha values for the
+    /// wl_surface. The exact meaning of the factor is thus undefined, unless
+    /// the blending space is specified in a different extension.
+    ///
+    /// This multiplier is applied even if the buffer attached to the
+    /// wl_surface doesn't have an alpha channel; in that case an alpha value
+    /// of one is used instead.
+    ///
+    /// Zero means completely transparent, UINT32_MAX means completely opaque.
+    ///
+    /// # Arguments
+    ///
+    /// - `factor`:
+    #[inline]
+    pub fn set_multiplier(&self, factor: u32) {
+        let (arg0,) = (factor,);
+        let mut args = [wl_argument { u: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpAlphaModifierSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait WpAlphaModifierSurfaceV1EventHandler {}
+
+impl WpAlphaModifierSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpAlphaModifierSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpAlphaModifierSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpAlphaModifierSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpAlphaModifierSurfaceV1] proxies.
+///
+/// This trait is the same as [WpAlphaModifierSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpAlphaModifierSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [WpAlphaModifierSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpAlphaModifierSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpAlphaModifierSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> WpAlphaModifierSurfaceV1EventHandler for MutEventHandler<H> where
+    H: WpAlphaModifierSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> WpAlphaModifierSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpAlphaModifierSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpAlphaModifierSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpAlphaModifierSurfaceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpAlphaModifierSurfaceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpAlphaModifierSurfaceV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpAlphaModifierSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_alpha_modifier_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpAlphaModifierSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_alpha_modifier_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpAlphaModifierSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpAlphaModifierSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpAlphaModifierSurfaceV1 {
+    /// Since when the error.no_surface enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_SURFACE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpAlphaModifierSurfaceV1Error(pub u32);
+
+impl WpAlphaModifierSurfaceV1Error {
+    /// wl_surface was destroyed
+    #[allow(dead_code)]
+    pub const NO_SURFACE: Self = Self(0);
+}
+
+impl Debug for WpAlphaModifierSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NO_SURFACE => "NO_SURFACE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpAlphaModifierSurfaceV1 {}
+}
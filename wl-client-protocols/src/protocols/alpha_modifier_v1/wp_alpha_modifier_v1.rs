@@ -0,0 +1,437 @@
+//! surface alpha modifier manager
+//!
+//! This interface allows a client to set a factor for the alpha values on a
+//! surface, which can be used to offload such operations to the compositor,
+//! which can in turn for example offload them to KMS.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_alpha_modifier_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpAlphaModifierSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_alpha_modifier_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_alpha_modifier_v1",
+    protocol: "alpha_modifier_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_alpha_modifier_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "already_constructed",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_alpha_modifier_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpAlphaModifierV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_alpha_modifier_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpAlphaModifierV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpAlphaModifierV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpAlphaModifierV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpAlphaModifierV1 {
+    const INTERFACE: &'static str = "wp_alpha_modifier_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpAlphaModifierV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpAlphaModifierV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpAlphaModifierV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpAlphaModifierV1Ref {
+    type Owned = WpAlphaModifierV1;
+}
+
+impl Deref for WpAlphaModifierV1 {
+    type Target = WpAlphaModifierV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpAlphaModifierV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_alpha_modifier_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpAlphaModifierV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_alpha_modifier_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpAlphaModifierV1Ref> for WpAlphaModifierV1 {
+    fn eq(&self, other: &WpAlphaModifierV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpAlphaModifierV1> for WpAlphaModifierV1Ref {
+    fn eq(&self, other: &WpAlphaModifierV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpAlphaModifierV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the alpha modifier manager object
+    ///
+    /// Destroy the alpha modifier manager. This doesn't destroy objects
+    /// created with the manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE__SINCE: u32 = 1;
+
+    /// create a new alpha modifier surface object
+    ///
+    /// Create a new alpha modifier surface object associated with the
+    /// given wl_surface. If there is already such an object associated with
+    /// the wl_surface, the already_constructed error will be raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface(&self, surface: &WlSurfaceRef) -> WpAlphaModifierSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpAlphaModifierSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpAlphaModifierSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpAlphaModifierV1Ref {
+    /// create a new alpha modifier surface object
+    ///
+    /// Create a new alpha modifier surface object associated with the
+    /// given wl_surface. If there is already such an object associated with
+    /// the wl_surface, the already_constructed error will be raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface(&self, _queue: &Queue, surface: &WlSurfaceRef) -> WpAlphaModifierSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpAlphaModifierSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpAlphaModifierSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpAlphaModifierV1] proxies.
+#[allow(dead_code)]
+pub trait WpAlphaModifierV1EventHandler {}
+
+impl WpAlphaModifierV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpAlphaModifierV1] proxies with mutable data.
+///
+/// This trait is the same as [WpAlphaModifierV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpAlphaModifierV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpAlphaModifierV1] proxies.
+///
+/// This trait is the same as [WpAlphaModifierV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpAlphaModifierV1EventHandlerMut {}
+
+/// A mutable event handler for [WpAlphaModifierV1] proxies with mutable data.
+///
+/// This trait is the same as [WpAlphaModifierV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpAlphaModifierV1EventHandlerMutWithData<T> {}
+
+impl<H> WpAlphaModifierV1EventHandler for MutEventHandler<H> where
+    H: WpAlphaModifierV1EventHandlerMut
+{
+}
+
+impl<T, H> WpAlphaModifierV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpAlphaModifierV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpAlphaModifierV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpAlphaModifierV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpAlphaModifierV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpAlphaModifierV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpAlphaModifierV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_alpha_modifier_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpAlphaModifierV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_alpha_modifier_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpAlphaModifierV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpAlphaModifierV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpAlphaModifierV1 {
+    /// Since when the error.already_constructed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_CONSTRUCTED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpAlphaModifierV1Error(pub u32);
+
+impl WpAlphaModifierV1Error {
+    /// wl_surface already has a alpha modifier object
+    #[allow(dead_code)]
+    pub const ALREADY_CONSTRUCTED: Self = Self(0);
+}
+
+impl Debug for WpAlphaModifierV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_CONSTRUCTED => "ALREADY_CONSTRUCTED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpAlphaModifierV1 {}
+}
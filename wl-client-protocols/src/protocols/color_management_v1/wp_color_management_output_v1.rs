@@ -0,0 +1,705 @@
+//! output color properties
+//!
+//! A wp_color_management_output_v1 describes the color properties of an
+//! output.
+//!
+//! The wp_color_management_output_v1 is associated with the wl_output global
+//! underlying the wl_output object. Therefore the client destroying the
+//! wl_output object has no impact, but the compositor removing the output
+//! global makes the wp_color_management_output_v1 object inert.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_color_management_output_v1".as_ptr(),
+    version: 2,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_image_description".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"image_description_changed".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_color_management_output_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_color_management_output_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_image_description",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "image_description",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "image_description_changed",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[],
+    }],
+    enums: &[],
+};
+
+/// An owned wp_color_management_output_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagementOutputV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_color_management_output_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagementOutputV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpColorManagementOutputV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpColorManagementOutputV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpColorManagementOutputV1 {
+    const INTERFACE: &'static str = "wp_color_management_output_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpColorManagementOutputV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpColorManagementOutputV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpColorManagementOutputV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpColorManagementOutputV1Ref {
+    type Owned = WpColorManagementOutputV1;
+}
+
+impl Deref for WpColorManagementOutputV1 {
+    type Target = WpColorManagementOutputV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpColorManagementOutputV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_management_output_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpColorManagementOutputV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_management_output_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpColorManagementOutputV1Ref> for WpColorManagementOutputV1 {
+    fn eq(&self, other: &WpColorManagementOutputV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpColorManagementOutputV1> for WpColorManagementOutputV1Ref {
+    fn eq(&self, other: &WpColorManagementOutputV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagementOutputV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the color management output
+    ///
+    /// Destroy the color wp_color_management_output_v1 object. This does not
+    /// affect any remaining protocol objects.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_image_description request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_IMAGE_DESCRIPTION__SINCE: u32 = 1;
+
+    /// get the image description of the output
+    ///
+    /// This creates a new wp_image_description_v1 object for the current image
+    /// description of the output. There always is exactly one image description
+    /// active for an output so the client should destroy the image description
+    /// created by earlier invocations of this request. This request is usually
+    /// sent as a reaction to the image_description_changed event or when
+    /// creating a wp_color_management_output_v1 object.
+    ///
+    /// The image description of an output represents the color encoding the
+    /// output expects. There might be performance and power advantages, as well
+    /// as improved color reproduction, if a content update matches the image
+    /// description of the output it is being shown on. If a content update is
+    /// shown on any other output than the one it matches the image description
+    /// of, then the color reproduction on those outputs might be considerably
+    /// worse.
+    ///
+    /// The created wp_image_description_v1 object preserves the image
+    /// description of the output from the time the object was created.
+    ///
+    /// The resulting image description object allows get_information request.
+    ///
+    /// If this protocol object is inert, the resulting image description object
+    /// shall immediately deliver the wp_image_description_v1.failed event with
+    /// the no_output cause.
+    ///
+    /// If the interface version is inadequate for the output's image
+    /// description, meaning that the client does not support all the events
+    /// needed to deliver the crucial information, the resulting image
+    /// description object shall immediately deliver the
+    /// wp_image_description_v1.failed event with the low_version cause.
+    ///
+    /// Otherwise the object shall immediately deliver the ready event.
+    #[inline]
+    pub fn get_image_description(&self) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagementOutputV1Ref {
+    /// get the image description of the output
+    ///
+    /// This creates a new wp_image_description_v1 object for the current image
+    /// description of the output. There always is exactly one image description
+    /// active for an output so the client should destroy the image description
+    /// created by earlier invocations of this request. This request is usually
+    /// sent as a reaction to the image_description_changed event or when
+    /// creating a wp_color_management_output_v1 object.
+    ///
+    /// The image description of an output represents the color encoding the
+    /// output expects. There might be performance and power advantages, as well
+    /// as improved color reproduction, if a content update matches the image
+    /// description of the output it is being shown on. If a content update is
+    /// shown on any other output than the one it matches the image description
+    /// of, then the color reproduction on those outputs might be considerably
+    /// worse.
+    ///
+    /// The created wp_image_description_v1 object preserves the image
+    /// description of the output from the time the object was created.
+    ///
+    /// The resulting image description object allows get_information request.
+    ///
+    /// If this protocol object is inert, the resulting image description object
+    /// shall immediately deliver the wp_image_description_v1.failed event with
+    /// the no_output cause.
+    ///
+    /// If the interface version is inadequate for the output's image
+    /// description, meaning that the client does not support all the events
+    /// needed to deliver the crucial information, the resulting image
+    /// description object shall immediately deliver the
+    /// wp_image_description_v1.failed event with the low_version cause.
+    ///
+    /// Otherwise the object shall immediately deliver the ready event.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_image_description(&self, _queue: &Queue) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl WpColorManagementOutputV1 {
+    /// Since when the image_description_changed event is available.
+    #[allow(dead_code)]
+    pub const EVT__IMAGE_DESCRIPTION_CHANGED__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpColorManagementOutputV1] proxies.
+#[allow(dead_code)]
+pub trait WpColorManagementOutputV1EventHandler {
+    /// image description changed
+    ///
+    /// This event is sent whenever the image description of the output changed,
+    /// followed by one wl_output.done event common to output events across all
+    /// extensions.
+    ///
+    /// If the client wants to use the updated image description, it needs to do
+    /// get_image_description again, because image description objects are
+    /// immutable.
+    #[inline]
+    fn image_description_changed(&self, _slf: &WpColorManagementOutputV1Ref) {}
+}
+
+impl WpColorManagementOutputV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpColorManagementOutputV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagementOutputV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpColorManagementOutputV1EventHandlerWithData<T> {
+    /// image description changed
+    ///
+    /// This event is sent whenever the image description of the output changed,
+    /// followed by one wl_output.done event common to output events across all
+    /// extensions.
+    ///
+    /// If the client wants to use the updated image description, it needs to do
+    /// get_image_description again, because image description objects are
+    /// immutable.
+    #[inline]
+    fn image_description_changed(&self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {}
+}
+
+/// A mutable event handler for [WpColorManagementOutputV1] proxies.
+///
+/// This trait is the same as [WpColorManagementOutputV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagementOutputV1EventHandlerMut {
+    /// See [WpColorManagementOutputV1EventHandler::image_description_changed].
+    #[inline]
+    fn image_description_changed(&mut self, _slf: &WpColorManagementOutputV1Ref) {}
+}
+
+/// A mutable event handler for [WpColorManagementOutputV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagementOutputV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagementOutputV1EventHandlerMutWithData<T> {
+    /// See [WpColorManagementOutputV1EventHandler::image_description_changed].
+    #[inline]
+    fn image_description_changed(&mut self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {}
+}
+
+impl<H> WpColorManagementOutputV1EventHandler for MutEventHandler<H>
+where
+    H: WpColorManagementOutputV1EventHandlerMut,
+{
+    #[inline]
+    fn image_description_changed(&self, _slf: &WpColorManagementOutputV1Ref) {
+        self.with(|h| h.image_description_changed(_slf))
+    }
+}
+
+impl<T, H> WpColorManagementOutputV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpColorManagementOutputV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn image_description_changed(&self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {
+        self.with(|h| h.image_description_changed(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpColorManagementOutputV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagementOutputV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn image_description_changed(&self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {
+        let _data = self.project(_data);
+        self.handler().image_description_changed(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpColorManagementOutputV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagementOutputV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn image_description_changed(&mut self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().image_description_changed(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpColorManagementOutputV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpColorManagementOutputV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.image_description_changed(slf);
+            }
+            _ => {
+                invalid_opcode("wp_color_management_output_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpColorManagementOutputV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpColorManagementOutputV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.image_description_changed(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_color_management_output_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpColorManagementOutputV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpColorManagementOutputV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for image_description_changed events.
+    pub struct ImageDescriptionChanged<F>(F);
+    impl<F> WpColorManagementOutputV1EventHandler for ImageDescriptionChanged<F>
+    where
+        F: Fn(&WpColorManagementOutputV1Ref),
+    {
+        #[inline]
+        fn image_description_changed(&self, _slf: &WpColorManagementOutputV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpColorManagementOutputV1EventHandlerWithData<T> for ImageDescriptionChanged<F>
+    where
+        F: Fn(&mut T, &WpColorManagementOutputV1Ref),
+    {
+        #[inline]
+        fn image_description_changed(&self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for image_description_changed events.
+    pub struct ImageDescriptionChangedMut<F>(F);
+    impl<F> WpColorManagementOutputV1EventHandlerMut for ImageDescriptionChangedMut<F>
+    where
+        F: FnMut(&WpColorManagementOutputV1Ref),
+    {
+        #[inline]
+        fn image_description_changed(&mut self, _slf: &WpColorManagementOutputV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpColorManagementOutputV1EventHandlerMutWithData<T> for ImageDescriptionChangedMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagementOutputV1Ref),
+    {
+        #[inline]
+        fn image_description_changed(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagementOutputV1Ref,
+        ) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpColorManagementOutputV1 {
+        /// Creates an event handler for image_description_changed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_image_description_changed<F>(f: F) -> ImageDescriptionChanged<F>
+        where
+            F: Fn(&WpColorManagementOutputV1Ref),
+        {
+            ImageDescriptionChanged(f)
+        }
+
+        /// Creates an event handler with mutable data for image_description_changed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_image_description_changed_with_data<T, F>(f: F) -> ImageDescriptionChanged<F>
+        where
+            F: Fn(&mut T, &WpColorManagementOutputV1Ref),
+        {
+            ImageDescriptionChanged(f)
+        }
+
+        /// Creates a mutable event handler for image_description_changed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_image_description_changed_mut<F>(f: F) -> ImageDescriptionChangedMut<F>
+        where
+            F: FnMut(&WpColorManagementOutputV1Ref),
+        {
+            ImageDescriptionChangedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for image_description_changed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_image_description_changed_mut_with_data<T, F>(
+            f: F,
+        ) -> ImageDescriptionChangedMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagementOutputV1Ref),
+        {
+            ImageDescriptionChangedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                image_description_changed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpColorManagementOutputV1EventHandler for Ignore {}
+    impl<T> WpColorManagementOutputV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpColorManagementOutputV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        image_description_changed: H0,
+    }
+
+    impl<H0> WpColorManagementOutputV1EventHandler for Handler<H0>
+    where
+        H0: WpColorManagementOutputV1EventHandler,
+    {
+        #[inline]
+        fn image_description_changed(&self, _slf: &WpColorManagementOutputV1Ref) {
+            self.image_description_changed
+                .image_description_changed(_slf)
+        }
+    }
+
+    impl<T, H0> WpColorManagementOutputV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WpColorManagementOutputV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn image_description_changed(&self, _data: &mut T, _slf: &WpColorManagementOutputV1Ref) {
+            self.image_description_changed
+                .image_description_changed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for image_description_changed events.
+        #[allow(dead_code)]
+        pub fn on_image_description_changed<F>(self, f: F) -> Handler<ImageDescriptionChanged<F>>
+        where
+            F: Fn(&WpColorManagementOutputV1Ref),
+        {
+            Handler {
+                image_description_changed: ImageDescriptionChanged(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for image_description_changed events.
+        #[allow(dead_code)]
+        pub fn on_image_description_changed_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<ImageDescriptionChanged<F>>
+        where
+            F: Fn(&mut T, &WpColorManagementOutputV1Ref),
+        {
+            Handler {
+                image_description_changed: ImageDescriptionChanged(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1277 @@
+//! color management extension to a surface
+//!
+//! A wp_color_management_surface_feedback_v1 allows the client to get the
+//! preferred image description of a surface.
+//!
+//! If the wl_surface associated with this object is destroyed, the
+//! wp_color_management_surface_feedback_v1 object becomes inert.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_color_management_surface_feedback_v1".as_ptr(),
+    version: 2,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_preferred".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_preferred_parametric".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"preferred_changed".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preferred_changed2".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_color_management_surface_feedback_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_color_management_surface_feedback_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_preferred",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "image_description",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_preferred_parametric",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "image_description",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "preferred_changed",
+            since: 1,
+            deprecated_since: Some(2),
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "identity",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "preferred_changed2",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "identity_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "identity_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "inert",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "unsupported_feature",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_color_management_surface_feedback_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagementSurfaceFeedbackV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_color_management_surface_feedback_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagementSurfaceFeedbackV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpColorManagementSurfaceFeedbackV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpColorManagementSurfaceFeedbackV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpColorManagementSurfaceFeedbackV1 {
+    const INTERFACE: &'static str = "wp_color_management_surface_feedback_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpColorManagementSurfaceFeedbackV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpColorManagementSurfaceFeedbackV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpColorManagementSurfaceFeedbackV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpColorManagementSurfaceFeedbackV1Ref {
+    type Owned = WpColorManagementSurfaceFeedbackV1;
+}
+
+impl Deref for WpColorManagementSurfaceFeedbackV1 {
+    type Target = WpColorManagementSurfaceFeedbackV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpColorManagementSurfaceFeedbackV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wp_color_management_surface_feedback_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for WpColorManagementSurfaceFeedbackV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wp_color_management_surface_feedback_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<WpColorManagementSurfaceFeedbackV1Ref> for WpColorManagementSurfaceFeedbackV1 {
+    fn eq(&self, other: &WpColorManagementSurfaceFeedbackV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpColorManagementSurfaceFeedbackV1> for WpColorManagementSurfaceFeedbackV1Ref {
+    fn eq(&self, other: &WpColorManagementSurfaceFeedbackV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagementSurfaceFeedbackV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the color management interface for a surface
+    ///
+    /// Destroy the wp_color_management_surface_feedback_v1 object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_preferred request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_PREFERRED__SINCE: u32 = 1;
+
+    /// get the preferred image description
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// The preferred image description represents the compositor's preferred
+    /// color encoding for this wl_surface at the current time. There might be
+    /// performance and power advantages, as well as improved color
+    /// reproduction, if the image description of a content update matches the
+    /// preferred image description.
+    ///
+    /// This creates a new wp_image_description_v1 object for the currently
+    /// preferred image description for the wl_surface. The client should
+    /// stop using and destroy the image descriptions created by earlier
+    /// invocations of this request for the associated wl_surface.
+    /// This request is usually sent as a reaction to the preferred_changed
+    /// event or when creating a wp_color_management_surface_feedback_v1 object
+    /// if the client is capable of adapting to image descriptions.
+    ///
+    /// The created wp_image_description_v1 object preserves the preferred image
+    /// description of the wl_surface from the time the object was created.
+    ///
+    /// The resulting image description object allows get_information request.
+    ///
+    /// If the image description is parametric, the client should set it on its
+    /// wl_surface only if the image description is an exact match with the
+    /// client content. Particularly if everything else matches, but the target
+    /// color volume is greater than what the client needs, the client should
+    /// create its own parameric image description with its exact parameters.
+    ///
+    /// If the interface version is inadequate for the preferred image
+    /// description, meaning that the client does not support all the
+    /// events needed to deliver the crucial information, the resulting image
+    /// description object shall immediately deliver the
+    /// wp_image_description_v1.failed event with the low_version cause,
+    /// otherwise the object shall immediately deliver the ready event.
+    #[inline]
+    pub fn get_preferred(&self) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_preferred_parametric request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_PREFERRED_PARAMETRIC__SINCE: u32 = 1;
+
+    /// get the preferred image description
+    ///
+    /// The same description as for get_preferred applies, except the returned
+    /// image description is guaranteed to be parametric. This is meant for
+    /// clients that can only deal with parametric image descriptions.
+    ///
+    /// If the compositor doesn't support parametric image descriptions, the
+    /// unsupported_feature error is emitted.
+    #[inline]
+    pub fn get_preferred_parametric(&self) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagementSurfaceFeedbackV1Ref {
+    /// get the preferred image description
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// The preferred image description represents the compositor's preferred
+    /// color encoding for this wl_surface at the current time. There might be
+    /// performance and power advantages, as well as improved color
+    /// reproduction, if the image description of a content update matches the
+    /// preferred image description.
+    ///
+    /// This creates a new wp_image_description_v1 object for the currently
+    /// preferred image description for the wl_surface. The client should
+    /// stop using and destroy the image descriptions created by earlier
+    /// invocations of this request for the associated wl_surface.
+    /// This request is usually sent as a reaction to the preferred_changed
+    /// event or when creating a wp_color_management_surface_feedback_v1 object
+    /// if the client is capable of adapting to image descriptions.
+    ///
+    /// The created wp_image_description_v1 object preserves the preferred image
+    /// description of the wl_surface from the time the object was created.
+    ///
+    /// The resulting image description object allows get_information request.
+    ///
+    /// If the image description is parametric, the client should set it on its
+    /// wl_surface only if the image description is an exact match with the
+    /// client content. Particularly if everything else matches, but the target
+    /// color volume is greater than what the client needs, the client should
+    /// create its own parameric image description with its exact parameters.
+    ///
+    /// If the interface version is inadequate for the preferred image
+    /// description, meaning that the client does not support all the
+    /// events needed to deliver the crucial information, the resulting image
+    /// description object shall immediately deliver the
+    /// wp_image_description_v1.failed event with the low_version cause,
+    /// otherwise the object shall immediately deliver the ready event.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_preferred(&self, _queue: &Queue) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// get the preferred image description
+    ///
+    /// The same description as for get_preferred applies, except the returned
+    /// image description is guaranteed to be parametric. This is meant for
+    /// clients that can only deal with parametric image descriptions.
+    ///
+    /// If the compositor doesn't support parametric image descriptions, the
+    /// unsupported_feature error is emitted.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_preferred_parametric(&self, _queue: &Queue) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl WpColorManagementSurfaceFeedbackV1 {
+    /// Since when the preferred_changed event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREFERRED_CHANGED__SINCE: u32 = 1;
+
+    /// Since when the preferred_changed event is deprecated.
+    #[allow(dead_code)]
+    pub const EVT__PREFERRED_CHANGED__DEPRECATED_SINCE: u32 = 2;
+
+    /// Since when the preferred_changed2 event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREFERRED_CHANGED2__SINCE: u32 = 2;
+}
+
+/// An event handler for [WpColorManagementSurfaceFeedbackV1] proxies.
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceFeedbackV1EventHandler {
+    /// the preferred image description changed (32-bit)
+    ///
+    /// Starting from interface version 2, 'preferred_changed2' is sent instead
+    /// of this event. See the 'preferred_changed2' event for the definition.
+    ///
+    /// # Arguments
+    ///
+    /// - `identity`: the 32-bit image description id number
+    #[inline]
+    fn preferred_changed(&self, _slf: &WpColorManagementSurfaceFeedbackV1Ref, identity: u32) {
+        let _ = identity;
+    }
+
+    /// the preferred image description changed
+    ///
+    /// The preferred image description is the one which likely has the most
+    /// performance and/or quality benefits for the compositor if used by the
+    /// client for its wl_surface contents. This event is sent whenever the
+    /// compositor changes the wl_surface's preferred image description.
+    ///
+    /// This event sends the identity of the new preferred state as the argument,
+    /// so clients who are aware of the image description already can reuse it.
+    /// Otherwise, if the client client wants to know what the preferred image
+    /// description is, it shall use the get_preferred request.
+    ///
+    /// The preferred image description is not automatically used for anything.
+    /// It is only a hint, and clients may set any valid image description with
+    /// set_image_description, but there might be performance and color accuracy
+    /// improvements by providing the wl_surface contents in the preferred
+    /// image description. Therefore clients that can, should render according
+    /// to the preferred image description
+    ///
+    /// # Arguments
+    ///
+    /// - `identity_hi`: high 32 bits of the 64-bit image description id number
+    /// - `identity_lo`: low 32 bits of the 64-bit image description id number
+    #[inline]
+    fn preferred_changed2(
+        &self,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+impl WpColorManagementSurfaceFeedbackV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpColorManagementSurfaceFeedbackV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagementSurfaceFeedbackV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T> {
+    /// the preferred image description changed (32-bit)
+    ///
+    /// Starting from interface version 2, 'preferred_changed2' is sent instead
+    /// of this event. See the 'preferred_changed2' event for the definition.
+    ///
+    /// # Arguments
+    ///
+    /// - `identity`: the 32-bit image description id number
+    #[inline]
+    fn preferred_changed(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity: u32,
+    ) {
+        let _ = identity;
+    }
+
+    /// the preferred image description changed
+    ///
+    /// The preferred image description is the one which likely has the most
+    /// performance and/or quality benefits for the compositor if used by the
+    /// client for its wl_surface contents. This event is sent whenever the
+    /// compositor changes the wl_surface's preferred image description.
+    ///
+    /// This event sends the identity of the new preferred state as the argument,
+    /// so clients who are aware of the image description already can reuse it.
+    /// Otherwise, if the client client wants to know what the preferred image
+    /// description is, it shall use the get_preferred request.
+    ///
+    /// The preferred image description is not automatically used for anything.
+    /// It is only a hint, and clients may set any valid image description with
+    /// set_image_description, but there might be performance and color accuracy
+    /// improvements by providing the wl_surface contents in the preferred
+    /// image description. Therefore clients that can, should render according
+    /// to the preferred image description
+    ///
+    /// # Arguments
+    ///
+    /// - `identity_hi`: high 32 bits of the 64-bit image description id number
+    /// - `identity_lo`: low 32 bits of the 64-bit image description id number
+    #[inline]
+    fn preferred_changed2(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+/// A mutable event handler for [WpColorManagementSurfaceFeedbackV1] proxies.
+///
+/// This trait is the same as [WpColorManagementSurfaceFeedbackV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceFeedbackV1EventHandlerMut {
+    /// See [WpColorManagementSurfaceFeedbackV1EventHandler::preferred_changed].
+    #[inline]
+    fn preferred_changed(&mut self, _slf: &WpColorManagementSurfaceFeedbackV1Ref, identity: u32) {
+        let _ = identity;
+    }
+
+    /// See [WpColorManagementSurfaceFeedbackV1EventHandler::preferred_changed2].
+    #[inline]
+    fn preferred_changed2(
+        &mut self,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+/// A mutable event handler for [WpColorManagementSurfaceFeedbackV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagementSurfaceFeedbackV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceFeedbackV1EventHandlerMutWithData<T> {
+    /// See [WpColorManagementSurfaceFeedbackV1EventHandler::preferred_changed].
+    #[inline]
+    fn preferred_changed(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity: u32,
+    ) {
+        let _ = identity;
+    }
+
+    /// See [WpColorManagementSurfaceFeedbackV1EventHandler::preferred_changed2].
+    #[inline]
+    fn preferred_changed2(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+impl<H> WpColorManagementSurfaceFeedbackV1EventHandler for MutEventHandler<H>
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandlerMut,
+{
+    #[inline]
+    fn preferred_changed(&self, _slf: &WpColorManagementSurfaceFeedbackV1Ref, identity: u32) {
+        self.with(|h| h.preferred_changed(_slf, identity))
+    }
+
+    #[inline]
+    fn preferred_changed2(
+        &self,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        self.with(|h| h.preferred_changed2(_slf, identity_hi, identity_lo))
+    }
+}
+
+impl<T, H> WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn preferred_changed(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity: u32,
+    ) {
+        self.with(|h| h.preferred_changed(_data, _slf, identity))
+    }
+
+    #[inline]
+    fn preferred_changed2(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        self.with(|h| h.preferred_changed2(_data, _slf, identity_hi, identity_lo))
+    }
+}
+
+impl<T, U, H> WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn preferred_changed(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().preferred_changed(_data, _slf, identity)
+    }
+
+    #[inline]
+    fn preferred_changed2(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .preferred_changed2(_data, _slf, identity_hi, identity_lo)
+    }
+}
+
+impl<T, U, H> WpColorManagementSurfaceFeedbackV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn preferred_changed(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().preferred_changed(_data, _slf, identity)
+    }
+
+    #[inline]
+    fn preferred_changed2(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .preferred_changed2(_data, _slf, identity_hi, identity_lo)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<WpColorManagementSurfaceFeedbackV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.preferred_changed(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.preferred_changed2(slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("wp_color_management_surface_feedback_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<WpColorManagementSurfaceFeedbackV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.preferred_changed(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.preferred_changed2(data, slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("wp_color_management_surface_feedback_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpColorManagementSurfaceFeedbackV1 {
+    /// Since when the error.inert enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INERT__SINCE: u32 = 1;
+    /// Since when the error.unsupported_feature enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_UNSUPPORTED_FEATURE__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagementSurfaceFeedbackV1Error(pub u32);
+
+impl WpColorManagementSurfaceFeedbackV1Error {
+    /// forbidden request on inert object
+    #[allow(dead_code)]
+    pub const INERT: Self = Self(0);
+
+    /// attempted to use an unsupported feature
+    #[allow(dead_code)]
+    pub const UNSUPPORTED_FEATURE: Self = Self(1);
+}
+
+impl Debug for WpColorManagementSurfaceFeedbackV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INERT => "INERT",
+            Self::UNSUPPORTED_FEATURE => "UNSUPPORTED_FEATURE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for preferred_changed events.
+    pub struct PreferredChanged<F>(F);
+    impl<F> WpColorManagementSurfaceFeedbackV1EventHandler for PreferredChanged<F>
+    where
+        F: Fn(&WpColorManagementSurfaceFeedbackV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_changed(&self, _slf: &WpColorManagementSurfaceFeedbackV1Ref, identity: u32) {
+            self.0(_slf, identity)
+        }
+    }
+    impl<T, F> WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T> for PreferredChanged<F>
+    where
+        F: Fn(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_changed(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity: u32,
+        ) {
+            self.0(_data, _slf, identity)
+        }
+    }
+
+    /// Event handler for preferred_changed2 events.
+    pub struct PreferredChanged2<F>(F);
+    impl<F> WpColorManagementSurfaceFeedbackV1EventHandler for PreferredChanged2<F>
+    where
+        F: Fn(&WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+    {
+        #[inline]
+        fn preferred_changed2(
+            &self,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.0(_slf, identity_hi, identity_lo)
+        }
+    }
+    impl<T, F> WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T> for PreferredChanged2<F>
+    where
+        F: Fn(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+    {
+        #[inline]
+        fn preferred_changed2(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.0(_data, _slf, identity_hi, identity_lo)
+        }
+    }
+
+    /// Mutable event handler for preferred_changed events.
+    pub struct PreferredChangedMut<F>(F);
+    impl<F> WpColorManagementSurfaceFeedbackV1EventHandlerMut for PreferredChangedMut<F>
+    where
+        F: FnMut(&WpColorManagementSurfaceFeedbackV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_changed(
+            &mut self,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity: u32,
+        ) {
+            self.0(_slf, identity)
+        }
+    }
+    impl<T, F> WpColorManagementSurfaceFeedbackV1EventHandlerMutWithData<T> for PreferredChangedMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_changed(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity: u32,
+        ) {
+            self.0(_data, _slf, identity)
+        }
+    }
+
+    /// Mutable event handler for preferred_changed2 events.
+    pub struct PreferredChanged2Mut<F>(F);
+    impl<F> WpColorManagementSurfaceFeedbackV1EventHandlerMut for PreferredChanged2Mut<F>
+    where
+        F: FnMut(&WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+    {
+        #[inline]
+        fn preferred_changed2(
+            &mut self,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.0(_slf, identity_hi, identity_lo)
+        }
+    }
+    impl<T, F> WpColorManagementSurfaceFeedbackV1EventHandlerMutWithData<T> for PreferredChanged2Mut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+    {
+        #[inline]
+        fn preferred_changed2(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.0(_data, _slf, identity_hi, identity_lo)
+        }
+    }
+
+    impl WpColorManagementSurfaceFeedbackV1 {
+        /// Creates an event handler for preferred_changed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed<F>(f: F) -> PreferredChanged<F>
+        where
+            F: Fn(&WpColorManagementSurfaceFeedbackV1Ref, u32),
+        {
+            PreferredChanged(f)
+        }
+
+        /// Creates an event handler with mutable data for preferred_changed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed_with_data<T, F>(f: F) -> PreferredChanged<F>
+        where
+            F: Fn(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32),
+        {
+            PreferredChanged(f)
+        }
+
+        /// Creates an event handler for preferred_changed2 events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed2<F>(f: F) -> PreferredChanged2<F>
+        where
+            F: Fn(&WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+        {
+            PreferredChanged2(f)
+        }
+
+        /// Creates an event handler with mutable data for preferred_changed2 events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed2_with_data<T, F>(f: F) -> PreferredChanged2<F>
+        where
+            F: Fn(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+        {
+            PreferredChanged2(f)
+        }
+
+        /// Creates a mutable event handler for preferred_changed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_changed_mut<F>(f: F) -> PreferredChangedMut<F>
+        where
+            F: FnMut(&WpColorManagementSurfaceFeedbackV1Ref, u32),
+        {
+            PreferredChangedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preferred_changed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_changed_mut_with_data<T, F>(f: F) -> PreferredChangedMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32),
+        {
+            PreferredChangedMut(f)
+        }
+
+        /// Creates a mutable event handler for preferred_changed2 events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_changed2_mut<F>(f: F) -> PreferredChanged2Mut<F>
+        where
+            F: FnMut(&WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+        {
+            PreferredChanged2Mut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preferred_changed2 events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_changed2_mut_with_data<T, F>(f: F) -> PreferredChanged2Mut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+        {
+            PreferredChanged2Mut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                preferred_changed: Ignore,
+                preferred_changed2: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpColorManagementSurfaceFeedbackV1EventHandler for Ignore {}
+    impl<T> WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpColorManagementSurfaceFeedbackV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        preferred_changed: H0,
+        preferred_changed2: H1,
+    }
+
+    impl<H0, H1> WpColorManagementSurfaceFeedbackV1EventHandler for Handler<H0, H1>
+    where
+        H0: WpColorManagementSurfaceFeedbackV1EventHandler,
+        H1: WpColorManagementSurfaceFeedbackV1EventHandler,
+    {
+        #[inline]
+        fn preferred_changed(&self, _slf: &WpColorManagementSurfaceFeedbackV1Ref, identity: u32) {
+            self.preferred_changed.preferred_changed(_slf, identity)
+        }
+
+        #[inline]
+        fn preferred_changed2(
+            &self,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.preferred_changed2
+                .preferred_changed2(_slf, identity_hi, identity_lo)
+        }
+    }
+
+    impl<T, H0, H1> WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T>,
+        H1: WpColorManagementSurfaceFeedbackV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn preferred_changed(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity: u32,
+        ) {
+            self.preferred_changed
+                .preferred_changed(_data, _slf, identity)
+        }
+
+        #[inline]
+        fn preferred_changed2(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagementSurfaceFeedbackV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.preferred_changed2
+                .preferred_changed2(_data, _slf, identity_hi, identity_lo)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for preferred_changed events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed<F>(self, f: F) -> Handler<PreferredChanged<F>, H1>
+        where
+            F: Fn(&WpColorManagementSurfaceFeedbackV1Ref, u32),
+        {
+            Handler {
+                preferred_changed: PreferredChanged(f),
+                preferred_changed2: self.preferred_changed2,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preferred_changed events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed_with_data<T, F>(self, f: F) -> Handler<PreferredChanged<F>, H1>
+        where
+            F: Fn(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32),
+        {
+            Handler {
+                preferred_changed: PreferredChanged(f),
+                preferred_changed2: self.preferred_changed2,
+            }
+        }
+
+        /// Sets the closure for preferred_changed2 events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed2<F>(self, f: F) -> Handler<H0, PreferredChanged2<F>>
+        where
+            F: Fn(&WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+        {
+            Handler {
+                preferred_changed: self.preferred_changed,
+                preferred_changed2: PreferredChanged2(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preferred_changed2 events.
+        #[allow(dead_code)]
+        pub fn on_preferred_changed2_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, PreferredChanged2<F>>
+        where
+            F: Fn(&mut T, &WpColorManagementSurfaceFeedbackV1Ref, u32, u32),
+        {
+            Handler {
+                preferred_changed: self.preferred_changed,
+                preferred_changed2: PreferredChanged2(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,495 @@
+//! color management extension to a surface
+//!
+//! A wp_color_management_surface_v1 allows the client to set the color
+//! space and HDR properties of a surface.
+//!
+//! If the wl_surface associated with the wp_color_management_surface_v1 is
+//! destroyed, the wp_color_management_surface_v1 object becomes inert.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_color_management_surface_v1".as_ptr(),
+    version: 2,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_image_description".as_ptr(),
+                signature: c"ou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"unset_image_description".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_color_management_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_color_management_surface_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_image_description",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "image_description",
+                    ty: info::ArgType::Object,
+                    interface: Some("wp_image_description_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "render_intent",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("wp_color_manager_v1.render_intent"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "unset_image_description",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "render_intent",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "image_description",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "inert",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_color_management_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagementSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_color_management_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagementSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpColorManagementSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpColorManagementSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpColorManagementSurfaceV1 {
+    const INTERFACE: &'static str = "wp_color_management_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpColorManagementSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpColorManagementSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpColorManagementSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpColorManagementSurfaceV1Ref {
+    type Owned = WpColorManagementSurfaceV1;
+}
+
+impl Deref for WpColorManagementSurfaceV1 {
+    type Target = WpColorManagementSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpColorManagementSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_management_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpColorManagementSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_management_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpColorManagementSurfaceV1Ref> for WpColorManagementSurfaceV1 {
+    fn eq(&self, other: &WpColorManagementSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpColorManagementSurfaceV1> for WpColorManagementSurfaceV1Ref {
+    fn eq(&self, other: &WpColorManagementSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagementSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the color management interface for a surface
+    ///
+    /// Destroy the wp_color_management_surface_v1 object and do the same as
+    /// unset_image_description.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagementSurfaceV1Ref {
+    /// set the surface image description
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// Set the image description of the underlying surface. The image
+    /// description and rendering intent are double-buffered state, see
+    /// wl_surface.commit.
+    ///
+    /// It is the client's responsibility to understand the image description
+    /// it sets on a surface, and to provide content that matches that image
+    /// description. Compositors might convert images to match their own or any
+    /// other image descriptions.
+    ///
+    /// Image descriptions which are not ready (see wp_image_description_v1)
+    /// are forbidden in this request, and in such case the protocol error
+    /// image_description is raised.
+    ///
+    /// All image descriptions which are ready (see wp_image_description_v1)
+    /// are allowed and must always be accepted by the compositor.
+    ///
+    /// When an image description is set on a surface, it establishes an
+    /// explicit link between surface pixel values and surface colorimetry.
+    /// This link may be undefined for some pixel values, see the image
+    /// description creator interfaces for the conditions. Non-finite
+    /// floating-point values (NaN, Inf) always have an undefined colorimetry.
+    ///
+    /// A rendering intent provides the client's preference on how surface
+    /// colorimetry should be mapped to each output. The render_intent value
+    /// must be one advertised by the compositor with
+    /// wp_color_manager_v1.render_intent event, otherwise the protocol error
+    /// render_intent is raised.
+    ///
+    /// By default, a surface does not have an associated image description
+    /// nor a rendering intent. The handling of color on such surfaces is
+    /// compositor implementation defined. Compositors should handle such
+    /// surfaces as sRGB, but may handle them differently if they have specific
+    /// requirements.
+    ///
+    /// Setting the image description has copy semantics; after this request,
+    /// the image description can be immediately destroyed without affecting
+    /// the pending state of the surface.
+    ///
+    /// # Arguments
+    ///
+    /// - `image_description`:
+    /// - `render_intent`: rendering intent
+    #[inline]
+    pub fn set_image_description(
+        &self,
+        image_description: &WpImageDescriptionV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let (arg0, arg1) = (image_description, render_intent);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("image_description", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { u: arg1.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `ou`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// remove the surface image description
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// This request removes any image description from the surface. See
+    /// set_image_description for how a compositor handles a surface without
+    /// an image description. This is double-buffered state, see
+    /// wl_surface.commit.
+    #[inline]
+    pub fn unset_image_description(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpColorManagementSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceV1EventHandler {}
+
+impl WpColorManagementSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpColorManagementSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagementSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpColorManagementSurfaceV1] proxies.
+///
+/// This trait is the same as [WpColorManagementSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [WpColorManagementSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagementSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagementSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> WpColorManagementSurfaceV1EventHandler for MutEventHandler<H> where
+    H: WpColorManagementSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> WpColorManagementSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpColorManagementSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpColorManagementSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpColorManagementSurfaceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpColorManagementSurfaceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagementSurfaceV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpColorManagementSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_color_management_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpColorManagementSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_color_management_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpColorManagementSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpColorManagementSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpColorManagementSurfaceV1 {
+    /// Since when the error.render_intent enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_RENDER_INTENT__SINCE: u32 = 1;
+    /// Since when the error.image_description enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_IMAGE_DESCRIPTION__SINCE: u32 = 1;
+    /// Since when the error.inert enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INERT__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagementSurfaceV1Error(pub u32);
+
+impl WpColorManagementSurfaceV1Error {
+    /// unsupported rendering intent
+    #[allow(dead_code)]
+    pub const RENDER_INTENT: Self = Self(0);
+
+    /// invalid image description
+    #[allow(dead_code)]
+    pub const IMAGE_DESCRIPTION: Self = Self(1);
+
+    /// forbidden request on inert object
+    #[allow(dead_code)]
+    pub const INERT: Self = Self(2);
+}
+
+impl Debug for WpColorManagementSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::RENDER_INTENT => "RENDER_INTENT",
+            Self::IMAGE_DESCRIPTION => "IMAGE_DESCRIPTION",
+            Self::INERT => "INERT",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpColorManagementSurfaceV1 {}
+}
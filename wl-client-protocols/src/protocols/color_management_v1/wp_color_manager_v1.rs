@@ -0,0 +1,3399 @@
+//! color manager singleton
+//!
+//! A singleton global interface used for getting color management extensions
+//! for wl_surface and wl_output objects, and for creating client defined
+//! image description objects. The extension interfaces allow
+//! getting the image description of outputs and setting the image
+//! description of surfaces.
+//!
+//! Compositors should never remove this global.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_color_manager_v1".as_ptr(),
+    version: 2,
+    method_count: 8,
+    methods: {
+        static MESSAGES: [wl_message; 8] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_output".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpColorManagementOutputV1::WL_INTERFACE),
+                        Some(WlOutput::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpColorManagementSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface_feedback".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpColorManagementSurfaceFeedbackV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_icc_creator".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionCreatorIccV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_parametric_creator".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionCreatorParamsV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_windows_scrgb".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_image_description".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpImageDescriptionV1::WL_INTERFACE),
+                        Some(WpImageDescriptionReferenceV1::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 5,
+    events: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"supported_intent".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"supported_feature".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"supported_tf_named".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"supported_primaries_named".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_color_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_color_manager_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_output",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_color_management_output_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "output",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_output"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_color_management_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_surface_feedback",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_color_management_surface_feedback_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "create_icc_creator",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "obj",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_creator_icc_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "create_parametric_creator",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "obj",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_creator_params_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "create_windows_scrgb",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "image_description",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_image_description",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "image_description",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_image_description_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "reference",
+                    ty: info::ArgType::Object,
+                    interface: Some("wp_image_description_reference_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "supported_intent",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "render_intent",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("render_intent"),
+            }],
+        },
+        info::MessageInfo {
+            name: "supported_feature",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "feature",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("feature"),
+            }],
+        },
+        info::MessageInfo {
+            name: "supported_tf_named",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "tf",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("transfer_function"),
+            }],
+        },
+        info::MessageInfo {
+            name: "supported_primaries_named",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "primaries",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("primaries"),
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "unsupported_feature",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "surface_exists",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "render_intent",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "perceptual",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "relative",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "saturation",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "absolute",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "relative_bpc",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "absolute_no_adaptation",
+                    value: 5,
+                    since: 2,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "feature",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "icc_v2_v4",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "parametric",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "set_primaries",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "set_tf_power",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "set_luminances",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "set_mastering_display_primaries",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "extended_target_volume",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "windows_scrgb",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "primaries",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "srgb",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pal_m",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pal",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ntsc",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "generic_film",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bt2020",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "cie1931_xyz",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "dci_p3",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "display_p3",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "adobe_rgb",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "transfer_function",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "bt1886",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "gamma22",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "gamma28",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "st240",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ext_linear",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "log_100",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "log_316",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xvycc",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "srgb",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: Some(2),
+                },
+                info::EntryInfo {
+                    name: "ext_srgb",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: Some(2),
+                },
+                info::EntryInfo {
+                    name: "st2084_pq",
+                    value: 11,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "st428",
+                    value: 12,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "hlg",
+                    value: 13,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "compound_power_2_4",
+                    value: 14,
+                    since: 2,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned wp_color_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_color_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpColorManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpColorManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpColorManagerV1 {
+    const INTERFACE: &'static str = "wp_color_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpColorManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpColorManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpColorManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpColorManagerV1Ref {
+    type Owned = WpColorManagerV1;
+}
+
+impl Deref for WpColorManagerV1 {
+    type Target = WpColorManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpColorManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpColorManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpColorManagerV1Ref> for WpColorManagerV1 {
+    fn eq(&self, other: &WpColorManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpColorManagerV1> for WpColorManagerV1Ref {
+    fn eq(&self, other: &WpColorManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the color manager
+    ///
+    /// Destroy the wp_color_manager_v1 object. This does not affect any other
+    /// objects in any way.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 8
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_output request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_OUTPUT__SINCE: u32 = 1;
+
+    /// create a color management interface for a wl_output
+    ///
+    /// This creates a new wp_color_management_output_v1 object for the
+    /// given wl_output.
+    ///
+    /// See the wp_color_management_output_v1 interface for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`:
+    #[inline]
+    pub fn get_output(&self, output: &WlOutputRef) -> WpColorManagementOutputV1 {
+        let (arg1,) = (output,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("output", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpColorManagementOutputV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorManagementOutputV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE__SINCE: u32 = 1;
+
+    /// create a color management interface for a wl_surface
+    ///
+    /// If a wp_color_management_surface_v1 object already exists for the given
+    /// wl_surface, the protocol error surface_exists is raised.
+    ///
+    /// This creates a new color wp_color_management_surface_v1 object for the
+    /// given wl_surface.
+    ///
+    /// See the wp_color_management_surface_v1 interface for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface(&self, surface: &WlSurfaceRef) -> WpColorManagementSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                WpColorManagementSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorManagementSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_surface_feedback request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE_FEEDBACK__SINCE: u32 = 1;
+
+    /// create a color management feedback interface
+    ///
+    /// This creates a new color wp_color_management_surface_feedback_v1 object
+    /// for the given wl_surface.
+    ///
+    /// See the wp_color_management_surface_feedback_v1 interface for more
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface_feedback(
+        &self,
+        surface: &WlSurfaceRef,
+    ) -> WpColorManagementSurfaceFeedbackV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                3,
+                &mut args,
+                WpColorManagementSurfaceFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorManagementSurfaceFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the create_icc_creator request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_ICC_CREATOR__SINCE: u32 = 1;
+
+    /// make a new ICC-based image description creator object
+    ///
+    /// Makes a new ICC-based image description creator object with all
+    /// properties initially unset. The client can then use the object's
+    /// interface to define all the required properties for an image description
+    /// and finally create a wp_image_description_v1 object.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.icc_v2_v4.
+    /// Otherwise this request raises the protocol error unsupported_feature.
+    #[inline]
+    pub fn create_icc_creator(&self) -> WpImageDescriptionCreatorIccV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 8
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                4,
+                &mut args,
+                WpImageDescriptionCreatorIccV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionCreatorIccV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the create_parametric_creator request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_PARAMETRIC_CREATOR__SINCE: u32 = 1;
+
+    /// make a new parametric image description creator object
+    ///
+    /// Makes a new parametric image description creator object with all
+    /// properties initially unset. The client can then use the object's
+    /// interface to define all the required properties for an image description
+    /// and finally create a wp_image_description_v1 object.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.parametric.
+    /// Otherwise this request raises the protocol error unsupported_feature.
+    #[inline]
+    pub fn create_parametric_creator(&self) -> WpImageDescriptionCreatorParamsV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 5 < INTERFACE.method_count = 8
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                5,
+                &mut args,
+                WpImageDescriptionCreatorParamsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionCreatorParamsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the create_windows_scrgb request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_WINDOWS_SCRGB__SINCE: u32 = 1;
+
+    /// create Windows-scRGB image description object
+    ///
+    /// This creates a pre-defined image description for the so-called
+    /// Windows-scRGB stimulus encoding. This comes from the Windows 10 handling
+    /// of its own definition of an scRGB color space for an HDR screen
+    /// driven in BT.2100/PQ signalling mode.
+    ///
+    /// Windows-scRGB uses sRGB (BT.709) color primaries and white point.
+    /// The transfer characteristic is extended linear.
+    ///
+    /// The nominal color channel value range is extended, meaning it includes
+    /// negative and greater than 1.0 values. Negative values are used to
+    /// escape the sRGB color gamut boundaries. To make use of the extended
+    /// range, the client needs to use a pixel format that can represent those
+    /// values, e.g. floating-point 16 bits per channel.
+    ///
+    /// Nominal color value R=G=B=0.0 corresponds to BT.2100/PQ system
+    /// 0 cd/m², and R=G=B=1.0 corresponds to BT.2100/PQ system 80 cd/m².
+    /// The maximum is R=G=B=125.0 corresponding to 10k cd/m².
+    ///
+    /// Windows-scRGB is displayed by Windows 10 by converting it to
+    /// BT.2100/PQ, maintaining the CIE 1931 chromaticity and mapping the
+    /// luminance as above. No adjustment is made to the signal to account
+    /// for the viewing conditions.
+    ///
+    /// The reference white level of Windows-scRGB is unknown. If a
+    /// reference white level must be assumed for compositor processing, it
+    /// should be R=G=B=2.5375 corresponding to 203 cd/m² of Report ITU-R
+    /// BT.2408-7.
+    ///
+    /// The target color volume of Windows-scRGB is unknown. The color gamut
+    /// may be anything between sRGB and BT.2100.
+    ///
+    /// Note: EGL_EXT_gl_colorspace_scrgb_linear definition differs from
+    /// Windows-scRGB by using R=G=B=1.0 as the reference white level, while
+    /// Windows-scRGB reference white level is unknown or varies. However,
+    /// it seems probable that Windows implements both
+    /// EGL_EXT_gl_colorspace_scrgb_linear and Vulkan
+    /// VK_COLOR_SPACE_EXTENDED_SRGB_LINEAR_EXT as Windows-scRGB.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.windows_scrgb.
+    /// Otherwise this request raises the protocol error unsupported_feature.
+    ///
+    /// The resulting image description object does not allow get_information
+    /// request. The wp_image_description_v1.ready event shall be sent.
+    #[inline]
+    pub fn create_windows_scrgb(&self) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 6 < INTERFACE.method_count = 8
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                6,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_image_description request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_IMAGE_DESCRIPTION__SINCE: u32 = 2;
+
+    /// create an image description from a reference
+    ///
+    /// This request retrieves the image description backing a reference.
+    ///
+    /// The get_information request can be used if and only if the request that
+    /// creates the reference allows it.
+    ///
+    /// # Arguments
+    ///
+    /// - `reference`:
+    #[inline]
+    pub fn get_image_description(
+        &self,
+        reference: &WpImageDescriptionReferenceV1Ref,
+    ) -> WpImageDescriptionV1 {
+        let (arg1,) = (reference,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("reference", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 7 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                7,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorManagerV1Ref {
+    /// create a color management interface for a wl_output
+    ///
+    /// This creates a new wp_color_management_output_v1 object for the
+    /// given wl_output.
+    ///
+    /// See the wp_color_management_output_v1 interface for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `output`:
+    #[inline]
+    pub fn get_output(&self, _queue: &Queue, output: &WlOutputRef) -> WpColorManagementOutputV1 {
+        let (arg1,) = (output,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("output", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpColorManagementOutputV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorManagementOutputV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// create a color management interface for a wl_surface
+    ///
+    /// If a wp_color_management_surface_v1 object already exists for the given
+    /// wl_surface, the protocol error surface_exists is raised.
+    ///
+    /// This creates a new color wp_color_management_surface_v1 object for the
+    /// given wl_surface.
+    ///
+    /// See the wp_color_management_surface_v1 interface for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> WpColorManagementSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                WpColorManagementSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorManagementSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// create a color management feedback interface
+    ///
+    /// This creates a new color wp_color_management_surface_feedback_v1 object
+    /// for the given wl_surface.
+    ///
+    /// See the wp_color_management_surface_feedback_v1 interface for more
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface_feedback(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> WpColorManagementSurfaceFeedbackV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                3,
+                &mut args,
+                WpColorManagementSurfaceFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorManagementSurfaceFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// make a new ICC-based image description creator object
+    ///
+    /// Makes a new ICC-based image description creator object with all
+    /// properties initially unset. The client can then use the object's
+    /// interface to define all the required properties for an image description
+    /// and finally create a wp_image_description_v1 object.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.icc_v2_v4.
+    /// Otherwise this request raises the protocol error unsupported_feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_icc_creator(&self, _queue: &Queue) -> WpImageDescriptionCreatorIccV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 8
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                4,
+                &mut args,
+                WpImageDescriptionCreatorIccV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionCreatorIccV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// make a new parametric image description creator object
+    ///
+    /// Makes a new parametric image description creator object with all
+    /// properties initially unset. The client can then use the object's
+    /// interface to define all the required properties for an image description
+    /// and finally create a wp_image_description_v1 object.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.parametric.
+    /// Otherwise this request raises the protocol error unsupported_feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_parametric_creator(&self, _queue: &Queue) -> WpImageDescriptionCreatorParamsV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 5 < INTERFACE.method_count = 8
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                5,
+                &mut args,
+                WpImageDescriptionCreatorParamsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionCreatorParamsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// create Windows-scRGB image description object
+    ///
+    /// This creates a pre-defined image description for the so-called
+    /// Windows-scRGB stimulus encoding. This comes from the Windows 10 handling
+    /// of its own definition of an scRGB color space for an HDR screen
+    /// driven in BT.2100/PQ signalling mode.
+    ///
+    /// Windows-scRGB uses sRGB (BT.709) color primaries and white point.
+    /// The transfer characteristic is extended linear.
+    ///
+    /// The nominal color channel value range is extended, meaning it includes
+    /// negative and greater than 1.0 values. Negative values are used to
+    /// escape the sRGB color gamut boundaries. To make use of the extended
+    /// range, the client needs to use a pixel format that can represent those
+    /// values, e.g. floating-point 16 bits per channel.
+    ///
+    /// Nominal color value R=G=B=0.0 corresponds to BT.2100/PQ system
+    /// 0 cd/m², and R=G=B=1.0 corresponds to BT.2100/PQ system 80 cd/m².
+    /// The maximum is R=G=B=125.0 corresponding to 10k cd/m².
+    ///
+    /// Windows-scRGB is displayed by Windows 10 by converting it to
+    /// BT.2100/PQ, maintaining the CIE 1931 chromaticity and mapping the
+    /// luminance as above. No adjustment is made to the signal to account
+    /// for the viewing conditions.
+    ///
+    /// The reference white level of Windows-scRGB is unknown. If a
+    /// reference white level must be assumed for compositor processing, it
+    /// should be R=G=B=2.5375 corresponding to 203 cd/m² of Report ITU-R
+    /// BT.2408-7.
+    ///
+    /// The target color volume of Windows-scRGB is unknown. The color gamut
+    /// may be anything between sRGB and BT.2100.
+    ///
+    /// Note: EGL_EXT_gl_colorspace_scrgb_linear definition differs from
+    /// Windows-scRGB by using R=G=B=1.0 as the reference white level, while
+    /// Windows-scRGB reference white level is unknown or varies. However,
+    /// it seems probable that Windows implements both
+    /// EGL_EXT_gl_colorspace_scrgb_linear and Vulkan
+    /// VK_COLOR_SPACE_EXTENDED_SRGB_LINEAR_EXT as Windows-scRGB.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.windows_scrgb.
+    /// Otherwise this request raises the protocol error unsupported_feature.
+    ///
+    /// The resulting image description object does not allow get_information
+    /// request. The wp_image_description_v1.ready event shall be sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_windows_scrgb(&self, _queue: &Queue) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 6 < INTERFACE.method_count = 8
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                6,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// create an image description from a reference
+    ///
+    /// This request retrieves the image description backing a reference.
+    ///
+    /// The get_information request can be used if and only if the request that
+    /// creates the reference allows it.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `reference`:
+    #[inline]
+    pub fn get_image_description(
+        &self,
+        _queue: &Queue,
+        reference: &WpImageDescriptionReferenceV1Ref,
+    ) -> WpImageDescriptionV1 {
+        let (arg1,) = (reference,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("reference", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 7 < INTERFACE.method_count = 8
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                7,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl WpColorManagerV1 {
+    /// Since when the supported_intent event is available.
+    #[allow(dead_code)]
+    pub const EVT__SUPPORTED_INTENT__SINCE: u32 = 1;
+
+    /// Since when the supported_feature event is available.
+    #[allow(dead_code)]
+    pub const EVT__SUPPORTED_FEATURE__SINCE: u32 = 1;
+
+    /// Since when the supported_tf_named event is available.
+    #[allow(dead_code)]
+    pub const EVT__SUPPORTED_TF_NAMED__SINCE: u32 = 1;
+
+    /// Since when the supported_primaries_named event is available.
+    #[allow(dead_code)]
+    pub const EVT__SUPPORTED_PRIMARIES_NAMED__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpColorManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpColorManagerV1EventHandler {
+    /// supported rendering intent
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each rendering intent the compositor supports.
+    ///
+    /// A compositor must not advertise intents that are deprecated in the
+    /// bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `render_intent`: rendering intent
+    #[inline]
+    fn supported_intent(
+        &self,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let _ = render_intent;
+    }
+
+    /// supported features
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each compositor supported feature listed in the enumeration.
+    ///
+    /// A compositor must not advertise features that are deprecated in the
+    /// bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `feature`: supported feature
+    #[inline]
+    fn supported_feature(&self, _slf: &WpColorManagerV1Ref, feature: WpColorManagerV1Feature) {
+        let _ = feature;
+    }
+
+    /// supported named transfer characteristic
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each named transfer function the compositor supports with the
+    /// parametric image description creator.
+    ///
+    /// A compositor must not advertise transfer functions that are deprecated
+    /// in the bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `tf`: Named transfer function
+    #[inline]
+    fn supported_tf_named(&self, _slf: &WpColorManagerV1Ref, tf: WpColorManagerV1TransferFunction) {
+        let _ = tf;
+    }
+
+    /// supported named primaries
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each named set of primaries the compositor supports with the
+    /// parametric image description creator.
+    ///
+    /// A compositor must not advertise names that are deprecated in the
+    /// bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `primaries`: Named color primaries
+    #[inline]
+    fn supported_primaries_named(
+        &self,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// all features have been sent
+    ///
+    /// This event is sent when all supported rendering intents, features,
+    /// transfer functions and named primaries have been sent.
+    #[inline]
+    fn done(&self, _slf: &WpColorManagerV1Ref) {}
+}
+
+impl WpColorManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpColorManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpColorManagerV1EventHandlerWithData<T> {
+    /// supported rendering intent
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each rendering intent the compositor supports.
+    ///
+    /// A compositor must not advertise intents that are deprecated in the
+    /// bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `render_intent`: rendering intent
+    #[inline]
+    fn supported_intent(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let _ = render_intent;
+    }
+
+    /// supported features
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each compositor supported feature listed in the enumeration.
+    ///
+    /// A compositor must not advertise features that are deprecated in the
+    /// bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `feature`: supported feature
+    #[inline]
+    fn supported_feature(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        feature: WpColorManagerV1Feature,
+    ) {
+        let _ = feature;
+    }
+
+    /// supported named transfer characteristic
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each named transfer function the compositor supports with the
+    /// parametric image description creator.
+    ///
+    /// A compositor must not advertise transfer functions that are deprecated
+    /// in the bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `tf`: Named transfer function
+    #[inline]
+    fn supported_tf_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _ = tf;
+    }
+
+    /// supported named primaries
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each named set of primaries the compositor supports with the
+    /// parametric image description creator.
+    ///
+    /// A compositor must not advertise names that are deprecated in the
+    /// bound version of the interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `primaries`: Named color primaries
+    #[inline]
+    fn supported_primaries_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// all features have been sent
+    ///
+    /// This event is sent when all supported rendering intents, features,
+    /// transfer functions and named primaries have been sent.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpColorManagerV1Ref) {}
+}
+
+/// A mutable event handler for [WpColorManagerV1] proxies.
+///
+/// This trait is the same as [WpColorManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagerV1EventHandlerMut {
+    /// See [WpColorManagerV1EventHandler::supported_intent].
+    #[inline]
+    fn supported_intent(
+        &mut self,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let _ = render_intent;
+    }
+
+    /// See [WpColorManagerV1EventHandler::supported_feature].
+    #[inline]
+    fn supported_feature(&mut self, _slf: &WpColorManagerV1Ref, feature: WpColorManagerV1Feature) {
+        let _ = feature;
+    }
+
+    /// See [WpColorManagerV1EventHandler::supported_tf_named].
+    #[inline]
+    fn supported_tf_named(
+        &mut self,
+        _slf: &WpColorManagerV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _ = tf;
+    }
+
+    /// See [WpColorManagerV1EventHandler::supported_primaries_named].
+    #[inline]
+    fn supported_primaries_named(
+        &mut self,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// See [WpColorManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &WpColorManagerV1Ref) {}
+}
+
+/// A mutable event handler for [WpColorManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorManagerV1EventHandlerMutWithData<T> {
+    /// See [WpColorManagerV1EventHandler::supported_intent].
+    #[inline]
+    fn supported_intent(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let _ = render_intent;
+    }
+
+    /// See [WpColorManagerV1EventHandler::supported_feature].
+    #[inline]
+    fn supported_feature(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        feature: WpColorManagerV1Feature,
+    ) {
+        let _ = feature;
+    }
+
+    /// See [WpColorManagerV1EventHandler::supported_tf_named].
+    #[inline]
+    fn supported_tf_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _ = tf;
+    }
+
+    /// See [WpColorManagerV1EventHandler::supported_primaries_named].
+    #[inline]
+    fn supported_primaries_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// See [WpColorManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpColorManagerV1Ref) {}
+}
+
+impl<H> WpColorManagerV1EventHandler for MutEventHandler<H>
+where
+    H: WpColorManagerV1EventHandlerMut,
+{
+    #[inline]
+    fn supported_intent(
+        &self,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        self.with(|h| h.supported_intent(_slf, render_intent))
+    }
+
+    #[inline]
+    fn supported_feature(&self, _slf: &WpColorManagerV1Ref, feature: WpColorManagerV1Feature) {
+        self.with(|h| h.supported_feature(_slf, feature))
+    }
+
+    #[inline]
+    fn supported_tf_named(&self, _slf: &WpColorManagerV1Ref, tf: WpColorManagerV1TransferFunction) {
+        self.with(|h| h.supported_tf_named(_slf, tf))
+    }
+
+    #[inline]
+    fn supported_primaries_named(
+        &self,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        self.with(|h| h.supported_primaries_named(_slf, primaries))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &WpColorManagerV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+}
+
+impl<T, H> WpColorManagerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpColorManagerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn supported_intent(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        self.with(|h| h.supported_intent(_data, _slf, render_intent))
+    }
+
+    #[inline]
+    fn supported_feature(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        feature: WpColorManagerV1Feature,
+    ) {
+        self.with(|h| h.supported_feature(_data, _slf, feature))
+    }
+
+    #[inline]
+    fn supported_tf_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        self.with(|h| h.supported_tf_named(_data, _slf, tf))
+    }
+
+    #[inline]
+    fn supported_primaries_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        self.with(|h| h.supported_primaries_named(_data, _slf, primaries))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpColorManagerV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpColorManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn supported_intent(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let _data = self.project(_data);
+        self.handler().supported_intent(_data, _slf, render_intent)
+    }
+
+    #[inline]
+    fn supported_feature(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        feature: WpColorManagerV1Feature,
+    ) {
+        let _data = self.project(_data);
+        self.handler().supported_feature(_data, _slf, feature)
+    }
+
+    #[inline]
+    fn supported_tf_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _data = self.project(_data);
+        self.handler().supported_tf_named(_data, _slf, tf)
+    }
+
+    #[inline]
+    fn supported_primaries_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .supported_primaries_named(_data, _slf, primaries)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpColorManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpColorManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorManagerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn supported_intent(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        render_intent: WpColorManagerV1RenderIntent,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .supported_intent(_data, _slf, render_intent)
+    }
+
+    #[inline]
+    fn supported_feature(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        feature: WpColorManagerV1Feature,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().supported_feature(_data, _slf, feature)
+    }
+
+    #[inline]
+    fn supported_tf_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().supported_tf_named(_data, _slf, tf)
+    }
+
+    #[inline]
+    fn supported_primaries_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorManagerV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .supported_primaries_named(_data, _slf, primaries)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpColorManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpColorManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpColorManagerV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1RenderIntent(args[0].u) };
+                self.0.supported_intent(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1Feature(args[0].u) };
+                self.0.supported_feature(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1TransferFunction(args[0].u) };
+                self.0.supported_tf_named(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1Primaries(args[0].u) };
+                self.0.supported_primaries_named(slf, arg0);
+            }
+            4 => {
+                self.0.done(slf);
+            }
+            _ => {
+                invalid_opcode("wp_color_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpColorManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpColorManagerV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1RenderIntent(args[0].u) };
+                self.0.supported_intent(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1Feature(args[0].u) };
+                self.0.supported_feature(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1TransferFunction(args[0].u) };
+                self.0.supported_tf_named(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1Primaries(args[0].u) };
+                self.0.supported_primaries_named(data, slf, arg0);
+            }
+            4 => {
+                self.0.done(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_color_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpColorManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpColorManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpColorManagerV1 {
+    /// Since when the error.unsupported_feature enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_UNSUPPORTED_FEATURE__SINCE: u32 = 1;
+    /// Since when the error.surface_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SURFACE_EXISTS__SINCE: u32 = 1;
+
+    /// Since when the render_intent.perceptual enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RENDER_INTENT_PERCEPTUAL__SINCE: u32 = 1;
+    /// Since when the render_intent.relative enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RENDER_INTENT_RELATIVE__SINCE: u32 = 1;
+    /// Since when the render_intent.saturation enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RENDER_INTENT_SATURATION__SINCE: u32 = 1;
+    /// Since when the render_intent.absolute enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RENDER_INTENT_ABSOLUTE__SINCE: u32 = 1;
+    /// Since when the render_intent.relative_bpc enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RENDER_INTENT_RELATIVE_BPC__SINCE: u32 = 1;
+    /// Since when the render_intent.absolute_no_adaptation enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RENDER_INTENT_ABSOLUTE_NO_ADAPTATION__SINCE: u32 = 2;
+
+    /// Since when the feature.icc_v2_v4 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_ICC_V2_V4__SINCE: u32 = 1;
+    /// Since when the feature.parametric enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_PARAMETRIC__SINCE: u32 = 1;
+    /// Since when the feature.set_primaries enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_SET_PRIMARIES__SINCE: u32 = 1;
+    /// Since when the feature.set_tf_power enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_SET_TF_POWER__SINCE: u32 = 1;
+    /// Since when the feature.set_luminances enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_SET_LUMINANCES__SINCE: u32 = 1;
+    /// Since when the feature.set_mastering_display_primaries enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_SET_MASTERING_DISPLAY_PRIMARIES__SINCE: u32 = 1;
+    /// Since when the feature.extended_target_volume enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_EXTENDED_TARGET_VOLUME__SINCE: u32 = 1;
+    /// Since when the feature.windows_scrgb enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FEATURE_WINDOWS_SCRGB__SINCE: u32 = 1;
+
+    /// Since when the primaries.srgb enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_SRGB__SINCE: u32 = 1;
+    /// Since when the primaries.pal_m enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_PAL_M__SINCE: u32 = 1;
+    /// Since when the primaries.pal enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_PAL__SINCE: u32 = 1;
+    /// Since when the primaries.ntsc enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_NTSC__SINCE: u32 = 1;
+    /// Since when the primaries.generic_film enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_GENERIC_FILM__SINCE: u32 = 1;
+    /// Since when the primaries.bt2020 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_BT2020__SINCE: u32 = 1;
+    /// Since when the primaries.cie1931_xyz enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_CIE1931_XYZ__SINCE: u32 = 1;
+    /// Since when the primaries.dci_p3 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_DCI_P3__SINCE: u32 = 1;
+    /// Since when the primaries.display_p3 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_DISPLAY_P3__SINCE: u32 = 1;
+    /// Since when the primaries.adobe_rgb enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRIMARIES_ADOBE_RGB__SINCE: u32 = 1;
+
+    /// Since when the transfer_function.bt1886 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_BT1886__SINCE: u32 = 1;
+    /// Since when the transfer_function.gamma22 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_GAMMA22__SINCE: u32 = 1;
+    /// Since when the transfer_function.gamma28 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_GAMMA28__SINCE: u32 = 1;
+    /// Since when the transfer_function.st240 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_ST240__SINCE: u32 = 1;
+    /// Since when the transfer_function.ext_linear enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_EXT_LINEAR__SINCE: u32 = 1;
+    /// Since when the transfer_function.log_100 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_LOG_100__SINCE: u32 = 1;
+    /// Since when the transfer_function.log_316 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_LOG_316__SINCE: u32 = 1;
+    /// Since when the transfer_function.xvycc enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_XVYCC__SINCE: u32 = 1;
+    /// Since when the transfer_function.srgb enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_SRGB__SINCE: u32 = 1;
+
+    /// Since when the transfer_function.srgb enum variant is deprecated.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_SRGB__DEPRECATED_SINCE: u32 = 2;
+    /// Since when the transfer_function.ext_srgb enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_EXT_SRGB__SINCE: u32 = 1;
+
+    /// Since when the transfer_function.ext_srgb enum variant is deprecated.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_EXT_SRGB__DEPRECATED_SINCE: u32 = 2;
+    /// Since when the transfer_function.st2084_pq enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_ST2084_PQ__SINCE: u32 = 1;
+    /// Since when the transfer_function.st428 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_ST428__SINCE: u32 = 1;
+    /// Since when the transfer_function.hlg enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_HLG__SINCE: u32 = 1;
+    /// Since when the transfer_function.compound_power_2_4 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANSFER_FUNCTION_COMPOUND_POWER_2_4__SINCE: u32 = 2;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagerV1Error(pub u32);
+
+impl WpColorManagerV1Error {
+    /// request not supported
+    #[allow(dead_code)]
+    pub const UNSUPPORTED_FEATURE: Self = Self(0);
+
+    /// color management surface exists already
+    #[allow(dead_code)]
+    pub const SURFACE_EXISTS: Self = Self(1);
+}
+
+impl Debug for WpColorManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::UNSUPPORTED_FEATURE => "UNSUPPORTED_FEATURE",
+            Self::SURFACE_EXISTS => "SURFACE_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// rendering intents
+///
+/// See the ICC.1:2022 specification from the International Color Consortium
+/// for more details about rendering intents.
+///
+/// The principles of ICC defined rendering intents apply with all types of
+/// image descriptions, not only those with ICC file profiles.
+///
+/// Compositors must support the perceptual rendering intent. Other
+/// rendering intents are optional.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagerV1RenderIntent(pub u32);
+
+impl WpColorManagerV1RenderIntent {
+    /// perceptual
+    #[allow(dead_code)]
+    pub const PERCEPTUAL: Self = Self(0);
+
+    /// media-relative colorimetric
+    #[allow(dead_code)]
+    pub const RELATIVE: Self = Self(1);
+
+    /// saturation
+    #[allow(dead_code)]
+    pub const SATURATION: Self = Self(2);
+
+    /// ICC-absolute colorimetric
+    #[allow(dead_code)]
+    pub const ABSOLUTE: Self = Self(3);
+
+    /// media-relative colorimetric + black point compensation
+    #[allow(dead_code)]
+    pub const RELATIVE_BPC: Self = Self(4);
+
+    /// ICC-absolute colorimetric without adaptation
+    ///
+    /// This rendering intent is a modified absolute rendering intent that
+    /// assumes the viewer is not adapted to the display white point, so no
+    /// chromatic adaptation between surface and display is done.
+    /// This can be useful for color proofing applications.
+    #[allow(dead_code)]
+    pub const ABSOLUTE_NO_ADAPTATION: Self = Self(5);
+}
+
+impl Debug for WpColorManagerV1RenderIntent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::PERCEPTUAL => "PERCEPTUAL",
+            Self::RELATIVE => "RELATIVE",
+            Self::SATURATION => "SATURATION",
+            Self::ABSOLUTE => "ABSOLUTE",
+            Self::RELATIVE_BPC => "RELATIVE_BPC",
+            Self::ABSOLUTE_NO_ADAPTATION => "ABSOLUTE_NO_ADAPTATION",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// compositor supported features
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagerV1Feature(pub u32);
+
+impl WpColorManagerV1Feature {
+    /// create_icc_creator request
+    #[allow(dead_code)]
+    pub const ICC_V2_V4: Self = Self(0);
+
+    /// create_parametric_creator request
+    #[allow(dead_code)]
+    pub const PARAMETRIC: Self = Self(1);
+
+    /// parametric set_primaries request
+    #[allow(dead_code)]
+    pub const SET_PRIMARIES: Self = Self(2);
+
+    /// parametric set_tf_power request
+    #[allow(dead_code)]
+    pub const SET_TF_POWER: Self = Self(3);
+
+    /// parametric set_luminances request
+    #[allow(dead_code)]
+    pub const SET_LUMINANCES: Self = Self(4);
+
+    /// parametric set_mastering_display_primaries request
+    ///
+    /// The compositor supports set_mastering_display_primaries request with a
+    /// target color volume fully contained inside the primary color volume.
+    #[allow(dead_code)]
+    pub const SET_MASTERING_DISPLAY_PRIMARIES: Self = Self(5);
+
+    /// parametric target exceeds primary color volume
+    ///
+    /// The compositor additionally supports target color volumes that
+    /// extend outside of the primary color volume.
+    ///
+    /// This can only be advertised if feature set_mastering_display_primaries
+    /// is supported as well.
+    #[allow(dead_code)]
+    pub const EXTENDED_TARGET_VOLUME: Self = Self(6);
+
+    /// create_windows_scrgb request
+    #[allow(dead_code)]
+    pub const WINDOWS_SCRGB: Self = Self(7);
+}
+
+impl Debug for WpColorManagerV1Feature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ICC_V2_V4 => "ICC_V2_V4",
+            Self::PARAMETRIC => "PARAMETRIC",
+            Self::SET_PRIMARIES => "SET_PRIMARIES",
+            Self::SET_TF_POWER => "SET_TF_POWER",
+            Self::SET_LUMINANCES => "SET_LUMINANCES",
+            Self::SET_MASTERING_DISPLAY_PRIMARIES => "SET_MASTERING_DISPLAY_PRIMARIES",
+            Self::EXTENDED_TARGET_VOLUME => "EXTENDED_TARGET_VOLUME",
+            Self::WINDOWS_SCRGB => "WINDOWS_SCRGB",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// named color primaries
+///
+/// Named color primaries used to encode well-known sets of primaries.
+///
+/// A value of 0 is invalid and will never be present in the list of enums.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagerV1Primaries(pub u32);
+
+impl WpColorManagerV1Primaries {
+    /// Color primaries for the sRGB color space as defined by the BT.709 standard
+    ///
+    /// Color primaries as defined by
+    /// - Rec. ITU-R BT.709-6
+    /// - Rec. ITU-R BT.1361-0 conventional colour gamut system and extended
+    ///   colour gamut system (historical)
+    /// - IEC 61966-2-1 sRGB or sYCC
+    /// - IEC 61966-2-4
+    /// - Society of Motion Picture and Television Engineers (SMPTE) RP 177
+    ///   (1993) Annex B
+    #[allow(dead_code)]
+    pub const SRGB: Self = Self(1);
+
+    /// Color primaries for PAL-M as defined by the BT.470 standard
+    ///
+    /// Color primaries as defined by
+    /// - Rec. ITU-R BT.470-6 System M (historical)
+    /// - United States National Television System Committee 1953
+    ///   Recommendation for transmission standards for color television
+    /// - United States Federal Communications Commission (2003) Title 47 Code
+    ///   of Federal Regulations 73.682 (a)(20)
+    #[allow(dead_code)]
+    pub const PAL_M: Self = Self(2);
+
+    /// Color primaries for PAL as defined by the BT.601 standard
+    ///
+    /// Color primaries as defined by
+    /// - Rec. ITU-R BT.470-6 System B, G (historical)
+    /// - Rec. ITU-R BT.601-7 625
+    /// - Rec. ITU-R BT.1358-0 625 (historical)
+    /// - Rec. ITU-R BT.1700-0 625 PAL and 625 SECAM
+    #[allow(dead_code)]
+    pub const PAL: Self = Self(3);
+
+    /// Color primaries for NTSC as defined by the BT.601 standard
+    ///
+    /// Color primaries as defined by
+    /// - Rec. ITU-R BT.601-7 525
+    /// - Rec. ITU-R BT.1358-1 525 or 625 (historical)
+    /// - Rec. ITU-R BT.1700-0 NTSC
+    /// - SMPTE 170M (2004)
+    /// - SMPTE 240M (1999) (historical)
+    #[allow(dead_code)]
+    pub const NTSC: Self = Self(4);
+
+    /// Generic film with colour filters using Illuminant C
+    ///
+    /// Color primaries as defined by Recommendation ITU-T H.273
+    /// "Coding-independent code points for video signal type identification"
+    /// for "generic film".
+    #[allow(dead_code)]
+    pub const GENERIC_FILM: Self = Self(5);
+
+    /// Color primaries as defined by the BT.2020 and BT.2100 standard
+    ///
+    /// Color primaries as defined by
+    /// - Rec. ITU-R BT.2020-2
+    /// - Rec. ITU-R BT.2100-0
+    #[allow(dead_code)]
+    pub const BT2020: Self = Self(6);
+
+    /// Color primaries of the full CIE 1931 XYZ color space
+    ///
+    /// Color primaries as defined as the maximum of the CIE 1931 XYZ color
+    /// space by
+    /// - SMPTE ST 428-1
+    /// - (CIE 1931 XYZ as in ISO 11664-1)
+    #[allow(dead_code)]
+    pub const CIE1931_XYZ: Self = Self(7);
+
+    /// Color primaries of the DCI P3 color space as defined by the SMPTE RP 431 standard
+    ///
+    /// Color primaries as defined by Digital Cinema System and published in
+    /// SMPTE RP 431-2 (2011).
+    #[allow(dead_code)]
+    pub const DCI_P3: Self = Self(8);
+
+    /// Color primaries of Display P3 variant of the DCI-P3 color space as defined by the SMPTE EG 432 standard
+    ///
+    /// Color primaries as defined by Digital Cinema System and published in
+    /// SMPTE EG 432-1 (2010).
+    #[allow(dead_code)]
+    pub const DISPLAY_P3: Self = Self(9);
+
+    /// Color primaries of the Adobe RGB color space as defined by the ISO 12640 standard
+    ///
+    /// Color primaries as defined by Adobe as "Adobe RGB" and later published
+    /// by ISO 12640-4 (2011).
+    #[allow(dead_code)]
+    pub const ADOBE_RGB: Self = Self(10);
+}
+
+impl Debug for WpColorManagerV1Primaries {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::SRGB => "SRGB",
+            Self::PAL_M => "PAL_M",
+            Self::PAL => "PAL",
+            Self::NTSC => "NTSC",
+            Self::GENERIC_FILM => "GENERIC_FILM",
+            Self::BT2020 => "BT2020",
+            Self::CIE1931_XYZ => "CIE1931_XYZ",
+            Self::DCI_P3 => "DCI_P3",
+            Self::DISPLAY_P3 => "DISPLAY_P3",
+            Self::ADOBE_RGB => "ADOBE_RGB",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// named transfer functions
+///
+/// Named transfer functions used to represent well-known transfer
+/// characteristics of displays.
+///
+/// A value of 0 is invalid and will never be present in the list of enums.
+///
+/// See appendix.md for the formulae.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorManagerV1TransferFunction(pub u32);
+
+impl WpColorManagerV1TransferFunction {
+    /// BT.1886 display transfer characteristic
+    ///
+    /// Rec. ITU-R BT.1886 is the display transfer characteristic assumed by
+    /// - Rec. ITU-R BT.601-7 525 and 625
+    /// - Rec. ITU-R BT.709-6
+    /// - Rec. ITU-R BT.2020-2
+    ///
+    /// This TF implies these default luminances from Rec. ITU-R BT.2035:
+    /// - primary color volume minimum: 0.01 cd/m²
+    /// - primary color volume maximum: 100 cd/m²
+    /// - reference white: 100 cd/m²
+    #[allow(dead_code)]
+    pub const BT1886: Self = Self(1);
+
+    /// Assumed display gamma 2.2 transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - Rec. ITU-R BT.470-6 System M (historical)
+    /// - United States National Television System Committee 1953
+    ///   Recommendation for transmission standards for color television
+    /// - United States Federal Communications Commission (2003) Title 47 Code
+    ///   of Federal Regulations 73.682 (a) (20)
+    /// - Rec. ITU-R BT.1700-0 625 PAL and 625 SECAM
+    /// - IEC 61966-2-1 (reference display)
+    #[allow(dead_code)]
+    pub const GAMMA22: Self = Self(2);
+
+    /// Assumed display gamma 2.8 transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - Rec. ITU-R BT.470-6 System B, G (historical)
+    #[allow(dead_code)]
+    pub const GAMMA28: Self = Self(3);
+
+    /// SMPTE ST 240 transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - SMPTE ST 240 (1999)
+    #[allow(dead_code)]
+    pub const ST240: Self = Self(4);
+
+    /// extended linear transfer function
+    ///
+    /// Linear transfer function defined over all real numbers.
+    /// Normalised electrical values are equal the normalised optical values.
+    #[allow(dead_code)]
+    pub const EXT_LINEAR: Self = Self(5);
+
+    /// logarithmic 100:1 transfer function
+    ///
+    /// Logarithmic transfer characteristic (100:1 range).
+    #[allow(dead_code)]
+    pub const LOG_100: Self = Self(6);
+
+    /// logarithmic (100*Sqrt(10) : 1) transfer function
+    ///
+    /// Logarithmic transfer characteristic (100 * Sqrt(10) : 1 range).
+    #[allow(dead_code)]
+    pub const LOG_316: Self = Self(7);
+
+    /// IEC 61966-2-4 transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - IEC 61966-2-4
+    #[allow(dead_code)]
+    pub const XVYCC: Self = Self(8);
+
+    /// Deprecated (ambiguous sRGB transfer function)
+    ///
+    /// Transfer characteristics as defined by
+    /// - IEC 61966-2-1 sRGB
+    ///
+    /// As a rule of thumb, use gamma22 for video, motion picture and
+    /// computer graphics, or compound_power_2_4 for ICC calibrated print
+    /// workflows.
+    #[allow(dead_code)]
+    pub const SRGB: Self = Self(9);
+
+    /// Deprecated (Extended sRGB piece-wise transfer function)
+    ///
+    /// Transfer characteristics as defined by
+    /// - IEC 61966-2-1 sYCC
+    #[allow(dead_code)]
+    pub const EXT_SRGB: Self = Self(10);
+
+    /// perceptual quantizer transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - SMPTE ST 2084 (2014) for 10-, 12-, 14- and 16-bit systems
+    /// - Rec. ITU-R BT.2100-2 perceptual quantization (PQ) system
+    ///
+    /// This TF implies these default luminances
+    /// - primary color volume minimum: 0.005 cd/m²
+    /// - primary color volume maximum: 10000 cd/m²
+    /// - reference white: 203 cd/m²
+    ///
+    /// The difference between the primary color volume minimum and maximum
+    /// must be approximately 10000 cd/m² as that is the swing of the EOTF
+    /// defined by ST 2084 and BT.2100. The default value for the
+    /// reference white is a protocol addition: it is suggested by
+    /// Report ITU-R BT.2408-7 and is not part of ST 2084 or BT.2100.
+    #[allow(dead_code)]
+    pub const ST2084_PQ: Self = Self(11);
+
+    /// SMPTE ST 428 transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - SMPTE ST 428-1 (2019)
+    #[allow(dead_code)]
+    pub const ST428: Self = Self(12);
+
+    /// hybrid log-gamma transfer function
+    ///
+    /// Transfer characteristics as defined by
+    /// - ARIB STD-B67 (2015)
+    /// - Rec. ITU-R BT.2100-2 hybrid log-gamma (HLG) system
+    ///
+    /// This TF implies these default luminances
+    /// - primary color volume minimum: 0.005 cd/m²
+    /// - primary color volume maximum: 1000 cd/m²
+    /// - reference white: 203 cd/m²
+    ///
+    /// HLG is a relative display-referred signal with a specified
+    /// non-linear mapping to the display peak luminance (the HLG OOTF).
+    /// All absolute luminance values used here for HLG assume a 1000 cd/m²
+    /// peak display.
+    ///
+    /// The default value for the reference white is a protocol addition:
+    /// it is suggested by Report ITU-R BT.2408-7 and is not part of
+    /// ARIB STD-B67 or BT.2100.
+    #[allow(dead_code)]
+    pub const HLG: Self = Self(13);
+
+    /// IEC 61966-2-1 encoding function
+    ///
+    /// Encoding characteristics as defined by IEC 61966-2-1, for displays
+    /// that invert the encoding function.
+    #[allow(dead_code)]
+    pub const COMPOUND_POWER_2_4: Self = Self(14);
+}
+
+impl Debug for WpColorManagerV1TransferFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::BT1886 => "BT1886",
+            Self::GAMMA22 => "GAMMA22",
+            Self::GAMMA28 => "GAMMA28",
+            Self::ST240 => "ST240",
+            Self::EXT_LINEAR => "EXT_LINEAR",
+            Self::LOG_100 => "LOG_100",
+            Self::LOG_316 => "LOG_316",
+            Self::XVYCC => "XVYCC",
+            Self::SRGB => "SRGB",
+            Self::EXT_SRGB => "EXT_SRGB",
+            Self::ST2084_PQ => "ST2084_PQ",
+            Self::ST428 => "ST428",
+            Self::HLG => "HLG",
+            Self::COMPOUND_POWER_2_4 => "COMPOUND_POWER_2_4",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for supported_intent events.
+    pub struct SupportedIntent<F>(F);
+    impl<F> WpColorManagerV1EventHandler for SupportedIntent<F>
+    where
+        F: Fn(&WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+    {
+        #[inline]
+        fn supported_intent(
+            &self,
+            _slf: &WpColorManagerV1Ref,
+            render_intent: WpColorManagerV1RenderIntent,
+        ) {
+            self.0(_slf, render_intent)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerWithData<T> for SupportedIntent<F>
+    where
+        F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+    {
+        #[inline]
+        fn supported_intent(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            render_intent: WpColorManagerV1RenderIntent,
+        ) {
+            self.0(_data, _slf, render_intent)
+        }
+    }
+
+    /// Event handler for supported_feature events.
+    pub struct SupportedFeature<F>(F);
+    impl<F> WpColorManagerV1EventHandler for SupportedFeature<F>
+    where
+        F: Fn(&WpColorManagerV1Ref, WpColorManagerV1Feature),
+    {
+        #[inline]
+        fn supported_feature(&self, _slf: &WpColorManagerV1Ref, feature: WpColorManagerV1Feature) {
+            self.0(_slf, feature)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerWithData<T> for SupportedFeature<F>
+    where
+        F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Feature),
+    {
+        #[inline]
+        fn supported_feature(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            feature: WpColorManagerV1Feature,
+        ) {
+            self.0(_data, _slf, feature)
+        }
+    }
+
+    /// Event handler for supported_tf_named events.
+    pub struct SupportedTfNamed<F>(F);
+    impl<F> WpColorManagerV1EventHandler for SupportedTfNamed<F>
+    where
+        F: Fn(&WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn supported_tf_named(
+            &self,
+            _slf: &WpColorManagerV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_slf, tf)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerWithData<T> for SupportedTfNamed<F>
+    where
+        F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn supported_tf_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_data, _slf, tf)
+        }
+    }
+
+    /// Event handler for supported_primaries_named events.
+    pub struct SupportedPrimariesNamed<F>(F);
+    impl<F> WpColorManagerV1EventHandler for SupportedPrimariesNamed<F>
+    where
+        F: Fn(&WpColorManagerV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn supported_primaries_named(
+            &self,
+            _slf: &WpColorManagerV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_slf, primaries)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerWithData<T> for SupportedPrimariesNamed<F>
+    where
+        F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn supported_primaries_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_data, _slf, primaries)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> WpColorManagerV1EventHandler for Done<F>
+    where
+        F: Fn(&WpColorManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &WpColorManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &WpColorManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpColorManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for supported_intent events.
+    pub struct SupportedIntentMut<F>(F);
+    impl<F> WpColorManagerV1EventHandlerMut for SupportedIntentMut<F>
+    where
+        F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+    {
+        #[inline]
+        fn supported_intent(
+            &mut self,
+            _slf: &WpColorManagerV1Ref,
+            render_intent: WpColorManagerV1RenderIntent,
+        ) {
+            self.0(_slf, render_intent)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerMutWithData<T> for SupportedIntentMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+    {
+        #[inline]
+        fn supported_intent(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            render_intent: WpColorManagerV1RenderIntent,
+        ) {
+            self.0(_data, _slf, render_intent)
+        }
+    }
+
+    /// Mutable event handler for supported_feature events.
+    pub struct SupportedFeatureMut<F>(F);
+    impl<F> WpColorManagerV1EventHandlerMut for SupportedFeatureMut<F>
+    where
+        F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1Feature),
+    {
+        #[inline]
+        fn supported_feature(
+            &mut self,
+            _slf: &WpColorManagerV1Ref,
+            feature: WpColorManagerV1Feature,
+        ) {
+            self.0(_slf, feature)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerMutWithData<T> for SupportedFeatureMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Feature),
+    {
+        #[inline]
+        fn supported_feature(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            feature: WpColorManagerV1Feature,
+        ) {
+            self.0(_data, _slf, feature)
+        }
+    }
+
+    /// Mutable event handler for supported_tf_named events.
+    pub struct SupportedTfNamedMut<F>(F);
+    impl<F> WpColorManagerV1EventHandlerMut for SupportedTfNamedMut<F>
+    where
+        F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn supported_tf_named(
+            &mut self,
+            _slf: &WpColorManagerV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_slf, tf)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerMutWithData<T> for SupportedTfNamedMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn supported_tf_named(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_data, _slf, tf)
+        }
+    }
+
+    /// Mutable event handler for supported_primaries_named events.
+    pub struct SupportedPrimariesNamedMut<F>(F);
+    impl<F> WpColorManagerV1EventHandlerMut for SupportedPrimariesNamedMut<F>
+    where
+        F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn supported_primaries_named(
+            &mut self,
+            _slf: &WpColorManagerV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_slf, primaries)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerMutWithData<T> for SupportedPrimariesNamedMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn supported_primaries_named(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_data, _slf, primaries)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> WpColorManagerV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&WpColorManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &WpColorManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpColorManagerV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &WpColorManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &WpColorManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpColorManagerV1 {
+        /// Creates an event handler for supported_intent events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_intent<F>(f: F) -> SupportedIntent<F>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+        {
+            SupportedIntent(f)
+        }
+
+        /// Creates an event handler with mutable data for supported_intent events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_intent_with_data<T, F>(f: F) -> SupportedIntent<F>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+        {
+            SupportedIntent(f)
+        }
+
+        /// Creates an event handler for supported_feature events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_feature<F>(f: F) -> SupportedFeature<F>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1Feature),
+        {
+            SupportedFeature(f)
+        }
+
+        /// Creates an event handler with mutable data for supported_feature events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_feature_with_data<T, F>(f: F) -> SupportedFeature<F>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Feature),
+        {
+            SupportedFeature(f)
+        }
+
+        /// Creates an event handler for supported_tf_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_tf_named<F>(f: F) -> SupportedTfNamed<F>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+        {
+            SupportedTfNamed(f)
+        }
+
+        /// Creates an event handler with mutable data for supported_tf_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_tf_named_with_data<T, F>(f: F) -> SupportedTfNamed<F>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+        {
+            SupportedTfNamed(f)
+        }
+
+        /// Creates an event handler for supported_primaries_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_primaries_named<F>(f: F) -> SupportedPrimariesNamed<F>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1Primaries),
+        {
+            SupportedPrimariesNamed(f)
+        }
+
+        /// Creates an event handler with mutable data for supported_primaries_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_primaries_named_with_data<T, F>(f: F) -> SupportedPrimariesNamed<F>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Primaries),
+        {
+            SupportedPrimariesNamed(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&WpColorManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates a mutable event handler for supported_intent events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_intent_mut<F>(f: F) -> SupportedIntentMut<F>
+        where
+            F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+        {
+            SupportedIntentMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for supported_intent events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_intent_mut_with_data<T, F>(f: F) -> SupportedIntentMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+        {
+            SupportedIntentMut(f)
+        }
+
+        /// Creates a mutable event handler for supported_feature events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_feature_mut<F>(f: F) -> SupportedFeatureMut<F>
+        where
+            F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1Feature),
+        {
+            SupportedFeatureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for supported_feature events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_feature_mut_with_data<T, F>(f: F) -> SupportedFeatureMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Feature),
+        {
+            SupportedFeatureMut(f)
+        }
+
+        /// Creates a mutable event handler for supported_tf_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_tf_named_mut<F>(f: F) -> SupportedTfNamedMut<F>
+        where
+            F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+        {
+            SupportedTfNamedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for supported_tf_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_tf_named_mut_with_data<T, F>(f: F) -> SupportedTfNamedMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+        {
+            SupportedTfNamedMut(f)
+        }
+
+        /// Creates a mutable event handler for supported_primaries_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_primaries_named_mut<F>(f: F) -> SupportedPrimariesNamedMut<F>
+        where
+            F: FnMut(&WpColorManagerV1Ref, WpColorManagerV1Primaries),
+        {
+            SupportedPrimariesNamedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for supported_primaries_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_primaries_named_mut_with_data<T, F>(
+            f: F,
+        ) -> SupportedPrimariesNamedMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Primaries),
+        {
+            SupportedPrimariesNamedMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&WpColorManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &WpColorManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                supported_intent: Ignore,
+                supported_feature: Ignore,
+                supported_tf_named: Ignore,
+                supported_primaries_named: Ignore,
+                done: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpColorManagerV1EventHandler for Ignore {}
+    impl<T> WpColorManagerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpColorManagerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore> {
+        supported_intent: H0,
+        supported_feature: H1,
+        supported_tf_named: H2,
+        supported_primaries_named: H3,
+        done: H4,
+    }
+
+    impl<H0, H1, H2, H3, H4> WpColorManagerV1EventHandler for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: WpColorManagerV1EventHandler,
+        H1: WpColorManagerV1EventHandler,
+        H2: WpColorManagerV1EventHandler,
+        H3: WpColorManagerV1EventHandler,
+        H4: WpColorManagerV1EventHandler,
+    {
+        #[inline]
+        fn supported_intent(
+            &self,
+            _slf: &WpColorManagerV1Ref,
+            render_intent: WpColorManagerV1RenderIntent,
+        ) {
+            self.supported_intent.supported_intent(_slf, render_intent)
+        }
+
+        #[inline]
+        fn supported_feature(&self, _slf: &WpColorManagerV1Ref, feature: WpColorManagerV1Feature) {
+            self.supported_feature.supported_feature(_slf, feature)
+        }
+
+        #[inline]
+        fn supported_tf_named(
+            &self,
+            _slf: &WpColorManagerV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.supported_tf_named.supported_tf_named(_slf, tf)
+        }
+
+        #[inline]
+        fn supported_primaries_named(
+            &self,
+            _slf: &WpColorManagerV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.supported_primaries_named
+                .supported_primaries_named(_slf, primaries)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &WpColorManagerV1Ref) {
+            self.done.done(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4> WpColorManagerV1EventHandlerWithData<T> for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: WpColorManagerV1EventHandlerWithData<T>,
+        H1: WpColorManagerV1EventHandlerWithData<T>,
+        H2: WpColorManagerV1EventHandlerWithData<T>,
+        H3: WpColorManagerV1EventHandlerWithData<T>,
+        H4: WpColorManagerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn supported_intent(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            render_intent: WpColorManagerV1RenderIntent,
+        ) {
+            self.supported_intent
+                .supported_intent(_data, _slf, render_intent)
+        }
+
+        #[inline]
+        fn supported_feature(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            feature: WpColorManagerV1Feature,
+        ) {
+            self.supported_feature
+                .supported_feature(_data, _slf, feature)
+        }
+
+        #[inline]
+        fn supported_tf_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.supported_tf_named.supported_tf_named(_data, _slf, tf)
+        }
+
+        #[inline]
+        fn supported_primaries_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorManagerV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.supported_primaries_named
+                .supported_primaries_named(_data, _slf, primaries)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpColorManagerV1Ref) {
+            self.done.done(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4> Handler<H0, H1, H2, H3, H4> {
+        /// Sets the closure for supported_intent events.
+        #[allow(dead_code)]
+        pub fn on_supported_intent<F>(self, f: F) -> Handler<SupportedIntent<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+        {
+            Handler {
+                supported_intent: SupportedIntent(f),
+                supported_feature: self.supported_feature,
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: self.supported_primaries_named,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for supported_intent events.
+        #[allow(dead_code)]
+        pub fn on_supported_intent_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<SupportedIntent<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1RenderIntent),
+        {
+            Handler {
+                supported_intent: SupportedIntent(f),
+                supported_feature: self.supported_feature,
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: self.supported_primaries_named,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for supported_feature events.
+        #[allow(dead_code)]
+        pub fn on_supported_feature<F>(self, f: F) -> Handler<H0, SupportedFeature<F>, H2, H3, H4>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1Feature),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: SupportedFeature(f),
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: self.supported_primaries_named,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for supported_feature events.
+        #[allow(dead_code)]
+        pub fn on_supported_feature_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, SupportedFeature<F>, H2, H3, H4>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Feature),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: SupportedFeature(f),
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: self.supported_primaries_named,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for supported_tf_named events.
+        #[allow(dead_code)]
+        pub fn on_supported_tf_named<F>(self, f: F) -> Handler<H0, H1, SupportedTfNamed<F>, H3, H4>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: self.supported_feature,
+                supported_tf_named: SupportedTfNamed(f),
+                supported_primaries_named: self.supported_primaries_named,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for supported_tf_named events.
+        #[allow(dead_code)]
+        pub fn on_supported_tf_named_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, SupportedTfNamed<F>, H3, H4>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1TransferFunction),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: self.supported_feature,
+                supported_tf_named: SupportedTfNamed(f),
+                supported_primaries_named: self.supported_primaries_named,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for supported_primaries_named events.
+        #[allow(dead_code)]
+        pub fn on_supported_primaries_named<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, SupportedPrimariesNamed<F>, H4>
+        where
+            F: Fn(&WpColorManagerV1Ref, WpColorManagerV1Primaries),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: self.supported_feature,
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: SupportedPrimariesNamed(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for supported_primaries_named events.
+        #[allow(dead_code)]
+        pub fn on_supported_primaries_named_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, SupportedPrimariesNamed<F>, H4>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref, WpColorManagerV1Primaries),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: self.supported_feature,
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: SupportedPrimariesNamed(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, H2, H3, Done<F>>
+        where
+            F: Fn(&WpColorManagerV1Ref),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: self.supported_feature,
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: self.supported_primaries_named,
+                done: Done(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Done<F>>
+        where
+            F: Fn(&mut T, &WpColorManagerV1Ref),
+        {
+            Handler {
+                supported_intent: self.supported_intent,
+                supported_feature: self.supported_feature,
+                supported_tf_named: self.supported_tf_named,
+                supported_primaries_named: self.supported_primaries_named,
+                done: Done(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,543 @@
+//! holder of image description ICC information
+//!
+//! This type of object is used for collecting all the information required
+//! to create a wp_image_description_v1 object from an ICC file. A complete
+//! set of required parameters consists of these properties:
+//! - ICC file
+//!
+//! Each required property must be set exactly once if the client is to create
+//! an image description. The set requests verify that a property was not
+//! already set. The create request verifies that all required properties are
+//! set. There may be several alternative requests for setting each property,
+//! and in that case the client must choose one of them.
+//!
+//! Once all properties have been set, the create request must be used to
+//! create the image description object, destroying the creator in the
+//! process.
+//!
+//! The link between a pixel value (a device value in ICC) and its respective
+//! colorimetry is defined by the details of the particular ICC profile.
+//! Those details also determine when colorimetry becomes undefined.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_image_description_creator_icc_v1".as_ptr(),
+    version: 2,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"create".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_icc_file".as_ptr(),
+                signature: c"huu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_image_description_creator_icc_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_image_description_creator_icc_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "create",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[info::ArgInfo {
+                name: "image_description",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_icc_file",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "icc_profile",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "offset",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "incomplete_set",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "already_set",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "bad_fd",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "bad_size",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "out_of_file",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_image_description_creator_icc_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionCreatorIccV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_image_description_creator_icc_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionCreatorIccV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpImageDescriptionCreatorIccV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpImageDescriptionCreatorIccV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpImageDescriptionCreatorIccV1 {
+    const INTERFACE: &'static str = "wp_image_description_creator_icc_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpImageDescriptionCreatorIccV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpImageDescriptionCreatorIccV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpImageDescriptionCreatorIccV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpImageDescriptionCreatorIccV1Ref {
+    type Owned = WpImageDescriptionCreatorIccV1;
+}
+
+impl Deref for WpImageDescriptionCreatorIccV1 {
+    type Target = WpImageDescriptionCreatorIccV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpImageDescriptionCreatorIccV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_creator_icc_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpImageDescriptionCreatorIccV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_creator_icc_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpImageDescriptionCreatorIccV1Ref> for WpImageDescriptionCreatorIccV1 {
+    fn eq(&self, other: &WpImageDescriptionCreatorIccV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpImageDescriptionCreatorIccV1> for WpImageDescriptionCreatorIccV1Ref {
+    fn eq(&self, other: &WpImageDescriptionCreatorIccV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionCreatorIccV1 {
+    /// Since when the create request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE__SINCE: u32 = 1;
+
+    /// Create the image description object from ICC data
+    ///
+    /// Create an image description object based on the ICC information
+    /// previously set on this object. A compositor must parse the ICC data in
+    /// some undefined but finite amount of time.
+    ///
+    /// The completeness of the parameter set is verified. If the set is not
+    /// complete, the protocol error incomplete_set is raised. For the
+    /// definition of a complete set, see the description of this interface.
+    ///
+    /// If the particular combination of the information is not supported
+    /// by the compositor, the resulting image description object shall
+    /// immediately deliver the wp_image_description_v1.failed event with the
+    /// 'unsupported' cause. If a valid image description was created from the
+    /// information, the wp_image_description_v1.ready event will eventually
+    /// be sent instead.
+    ///
+    /// This request destroys the wp_image_description_creator_icc_v1 object.
+    ///
+    /// The resulting image description object does not allow get_information
+    /// request.
+    #[inline]
+    pub fn create(&self) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<true>(
+                0,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionCreatorIccV1Ref {
+    /// set the ICC profile file
+    ///
+    /// Sets the ICC profile file to be used as the basis of the image
+    /// description.
+    ///
+    /// The data shall be found through the given fd at the given offset, having
+    /// the given length. The fd must be seekable and readable. Violating these
+    /// requirements raises the bad_fd protocol error.
+    ///
+    /// If reading the data fails due to an error independent of the client, the
+    /// compositor shall send the wp_image_description_v1.failed event on the
+    /// created wp_image_description_v1 with the 'operating_system' cause.
+    ///
+    /// The maximum size of the ICC profile is 32 MB. If length is greater than
+    /// that or zero, the protocol error bad_size is raised. If offset + length
+    /// exceeds the file size, the protocol error out_of_file is raised.
+    ///
+    /// A compositor may read the file at any time starting from this request
+    /// and only until whichever happens first:
+    /// - If create request was issued, the wp_image_description_v1 object
+    ///   delivers either failed or ready event; or
+    /// - if create request was not issued, this
+    ///   wp_image_description_creator_icc_v1 object is destroyed.
+    ///
+    /// A compositor shall not modify the contents of the file, and the fd may
+    /// be sealed for writes and size changes. The client must ensure to its
+    /// best ability that the data does not change while the compositor is
+    /// reading it.
+    ///
+    /// The data must represent a valid ICC profile. The ICC profile version
+    /// must be 2 or 4, it must be a 3 channel profile and the class must be
+    /// Display or ColorSpace. Violating these requirements will not result in a
+    /// protocol error, but will eventually send the
+    /// wp_image_description_v1.failed event on the created
+    /// wp_image_description_v1 with the 'unsupported' cause.
+    ///
+    /// See the International Color Consortium specification ICC.1:2022 for more
+    /// details about ICC profiles.
+    ///
+    /// If ICC file has already been set on this object, the protocol error
+    /// already_set is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `icc_profile`: ICC profile
+    /// - `offset`: byte offset in fd to start of ICC data
+    /// - `length`: length of ICC data in bytes
+    #[inline]
+    pub fn set_icc_file(&self, icc_profile: BorrowedFd<'_>, offset: u32, length: u32) {
+        let (arg0, arg1, arg2) = (icc_profile, offset, length);
+        let mut args = [
+            wl_argument {
+                h: arg0.as_raw_fd(),
+            },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `huu`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpImageDescriptionCreatorIccV1] proxies.
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorIccV1EventHandler {}
+
+impl WpImageDescriptionCreatorIccV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpImageDescriptionCreatorIccV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionCreatorIccV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorIccV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpImageDescriptionCreatorIccV1] proxies.
+///
+/// This trait is the same as [WpImageDescriptionCreatorIccV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorIccV1EventHandlerMut {}
+
+/// A mutable event handler for [WpImageDescriptionCreatorIccV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionCreatorIccV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorIccV1EventHandlerMutWithData<T> {}
+
+impl<H> WpImageDescriptionCreatorIccV1EventHandler for MutEventHandler<H> where
+    H: WpImageDescriptionCreatorIccV1EventHandlerMut
+{
+}
+
+impl<T, H> WpImageDescriptionCreatorIccV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpImageDescriptionCreatorIccV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpImageDescriptionCreatorIccV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionCreatorIccV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> WpImageDescriptionCreatorIccV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionCreatorIccV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpImageDescriptionCreatorIccV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_image_description_creator_icc_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpImageDescriptionCreatorIccV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_image_description_creator_icc_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpImageDescriptionCreatorIccV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpImageDescriptionCreatorIccV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpImageDescriptionCreatorIccV1 {
+    /// Since when the error.incomplete_set enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INCOMPLETE_SET__SINCE: u32 = 1;
+    /// Since when the error.already_set enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_SET__SINCE: u32 = 1;
+    /// Since when the error.bad_fd enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_BAD_FD__SINCE: u32 = 1;
+    /// Since when the error.bad_size enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_BAD_SIZE__SINCE: u32 = 1;
+    /// Since when the error.out_of_file enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_OUT_OF_FILE__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpImageDescriptionCreatorIccV1Error(pub u32);
+
+impl WpImageDescriptionCreatorIccV1Error {
+    /// incomplete parameter set
+    #[allow(dead_code)]
+    pub const INCOMPLETE_SET: Self = Self(0);
+
+    /// property already set
+    #[allow(dead_code)]
+    pub const ALREADY_SET: Self = Self(1);
+
+    /// fd not seekable and readable
+    #[allow(dead_code)]
+    pub const BAD_FD: Self = Self(2);
+
+    /// no or too much data
+    #[allow(dead_code)]
+    pub const BAD_SIZE: Self = Self(3);
+
+    /// offset + length exceeds file size
+    #[allow(dead_code)]
+    pub const OUT_OF_FILE: Self = Self(4);
+}
+
+impl Debug for WpImageDescriptionCreatorIccV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INCOMPLETE_SET => "INCOMPLETE_SET",
+            Self::ALREADY_SET => "ALREADY_SET",
+            Self::BAD_FD => "BAD_FD",
+            Self::BAD_SIZE => "BAD_SIZE",
+            Self::OUT_OF_FILE => "OUT_OF_FILE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpImageDescriptionCreatorIccV1 {}
+}
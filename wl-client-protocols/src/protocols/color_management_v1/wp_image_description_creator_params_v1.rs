@@ -0,0 +1,1226 @@
+//! holder of image description parameters
+//!
+//! This type of object is used for collecting all the parameters required
+//! to create a wp_image_description_v1 object. A complete set of required
+//! parameters consists of these properties:
+//! - transfer characteristic function (tf)
+//! - chromaticities of primaries and white point (primary color volume)
+//!
+//! The following properties are optional and have a well-defined default
+//! if not explicitly set:
+//! - primary color volume luminance range
+//! - reference white luminance level
+//! - mastering display primaries and white point (target color volume)
+//! - mastering luminance range
+//!
+//! The following properties are optional and will be ignored
+//! if not explicitly set:
+//! - maximum content light level
+//! - maximum frame-average light level
+//!
+//! Each required property must be set exactly once if the client is to create
+//! an image description. The set requests verify that a property was not
+//! already set. The create request verifies that all required properties are
+//! set. There may be several alternative requests for setting each property,
+//! and in that case the client must choose one of them.
+//!
+//! Once all properties have been set, the create request must be used to
+//! create the image description object, destroying the creator in the
+//! process.
+//!
+//! A viewer, who is viewing the display defined by the resulting image
+//! description (the viewing environment included), is assumed to be fully
+//! adapted to the primary color volume's white point.
+//!
+//! Any of the following conditions will cause the colorimetry of a pixel
+//! to become undefined:
+//! - Values outside of the defined range of the transfer characteristic.
+//! - Tristimulus that exceeds the target color volume.
+//! - If extended_target_volume is not supported: tristimulus that exceeds
+//! the primary color volume.
+//!
+//! The closest correspondence to an image description created through this
+//! interface is the Display class of profiles in ICC.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_image_description_creator_params_v1".as_ptr(),
+    version: 2,
+    method_count: 10,
+    methods: {
+        static MESSAGES: [wl_message; 10] = [
+            wl_message {
+                name: c"create".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_tf_named".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_tf_power".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_primaries_named".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_primaries".as_ptr(),
+                signature: c"iiiiiiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 8] =
+                        [None, None, None, None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_luminances".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_mastering_display_primaries".as_ptr(),
+                signature: c"iiiiiiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 8] =
+                        [None, None, None, None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_mastering_luminance".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_max_cll".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_max_fall".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_image_description_creator_params_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_image_description_creator_params_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "create",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[info::ArgInfo {
+                name: "image_description",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_tf_named",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "tf",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wp_color_manager_v1.transfer_function"),
+            }],
+        },
+        info::MessageInfo {
+            name: "set_tf_power",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "eexp",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_primaries_named",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "primaries",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wp_color_manager_v1.primaries"),
+            }],
+        },
+        info::MessageInfo {
+            name: "set_primaries",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "r_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "r_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_luminances",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "min_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "max_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "reference_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_mastering_display_primaries",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "r_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "r_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_mastering_luminance",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "min_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "max_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_max_cll",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "max_cll",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_max_fall",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "max_fall",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "incomplete_set",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "already_set",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "unsupported_feature",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_tf",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_primaries_named",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_luminance",
+                value: 5,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_image_description_creator_params_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionCreatorParamsV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_image_description_creator_params_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionCreatorParamsV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpImageDescriptionCreatorParamsV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpImageDescriptionCreatorParamsV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpImageDescriptionCreatorParamsV1 {
+    const INTERFACE: &'static str = "wp_image_description_creator_params_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpImageDescriptionCreatorParamsV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpImageDescriptionCreatorParamsV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpImageDescriptionCreatorParamsV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpImageDescriptionCreatorParamsV1Ref {
+    type Owned = WpImageDescriptionCreatorParamsV1;
+}
+
+impl Deref for WpImageDescriptionCreatorParamsV1 {
+    type Target = WpImageDescriptionCreatorParamsV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpImageDescriptionCreatorParamsV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wp_image_description_creator_params_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for WpImageDescriptionCreatorParamsV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "wp_image_description_creator_params_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<WpImageDescriptionCreatorParamsV1Ref> for WpImageDescriptionCreatorParamsV1 {
+    fn eq(&self, other: &WpImageDescriptionCreatorParamsV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpImageDescriptionCreatorParamsV1> for WpImageDescriptionCreatorParamsV1Ref {
+    fn eq(&self, other: &WpImageDescriptionCreatorParamsV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionCreatorParamsV1 {
+    /// Since when the create request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE__SINCE: u32 = 1;
+
+    /// Create the image description object using params
+    ///
+    /// Create an image description object based on the parameters previously
+    /// set on this object.
+    ///
+    /// The completeness of the parameter set is verified. If the set is not
+    /// complete, the protocol error incomplete_set is raised. For the
+    /// definition of a complete set, see the description of this interface.
+    ///
+    /// When both max_cll and max_fall are set, max_fall must be less or equal
+    /// to max_cll otherwise the invalid_luminance protocol error is raised.
+    ///
+    /// In version 1, these following conditions also result in the
+    /// invalid_luminance protocol error. Version 2 and later do not have this
+    /// requirement.
+    /// - When max_cll is set, it must be greater than min L and less or equal
+    ///   to max L of the mastering luminance range.
+    /// - When max_fall is set, it must be greater than min L and less or equal
+    ///   to max L of the mastering luminance range.
+    ///
+    /// If the particular combination of the parameter set is not supported
+    /// by the compositor, the resulting image description object shall
+    /// immediately deliver the wp_image_description_v1.failed event with the
+    /// 'unsupported' cause. If a valid image description was created from the
+    /// parameter set, the wp_image_description_v1.ready event will eventually
+    /// be sent instead.
+    ///
+    /// This request destroys the wp_image_description_creator_params_v1
+    /// object.
+    ///
+    /// The resulting image description object does not allow get_information
+    /// request.
+    #[inline]
+    pub fn create(&self) -> WpImageDescriptionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 10
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<true>(
+                0,
+                &mut args,
+                WpImageDescriptionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionCreatorParamsV1Ref {
+    /// named transfer characteristic
+    ///
+    /// Sets the transfer characteristic using explicitly enumerated named
+    /// functions.
+    ///
+    /// When the resulting image description is attached to an image, the
+    /// content should be decoded according to the industry standard
+    /// practices for the transfer characteristic.
+    ///
+    /// Only names advertised with wp_color_manager_v1 event supported_tf_named
+    /// are allowed. Other values shall raise the protocol error invalid_tf.
+    ///
+    /// If transfer characteristic has already been set on this object, the
+    /// protocol error already_set is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `tf`: named transfer function
+    #[inline]
+    pub fn set_tf_named(&self, tf: WpColorManagerV1TransferFunction) {
+        let (arg0,) = (tf,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 10
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// transfer characteristic as a power curve
+    ///
+    /// Sets the color component transfer characteristic to a power curve with
+    /// the given exponent. Negative values are handled by mirroring the
+    /// positive half of the curve through the origin. The valid domain and
+    /// range of the curve are all finite real numbers. This curve represents
+    /// the conversion from electrical to optical color channel values.
+    ///
+    /// The curve exponent shall be multiplied by 10000 to get the argument eexp
+    /// value to carry the precision of 4 decimals.
+    ///
+    /// The curve exponent must be at least 1.0 and at most 10.0. Otherwise the
+    /// protocol error invalid_tf is raised.
+    ///
+    /// If transfer characteristic has already been set on this object, the
+    /// protocol error already_set is raised.
+    ///
+    /// This request can be used when the compositor advertises
+    /// wp_color_manager_v1.feature.set_tf_power. Otherwise this request raises
+    /// the protocol error unsupported_feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `eexp`: the exponent * 10000
+    #[inline]
+    pub fn set_tf_power(&self, eexp: u32) {
+        let (arg0,) = (eexp,);
+        let mut args = [wl_argument { u: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 10
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// named primaries
+    ///
+    /// Sets the color primaries and white point using explicitly named sets.
+    /// This describes the primary color volume which is the basis for color
+    /// value encoding.
+    ///
+    /// Only names advertised with wp_color_manager_v1 event
+    /// supported_primaries_named are allowed. Other values shall raise the
+    /// protocol error invalid_primaries_named.
+    ///
+    /// If primaries have already been set on this object, the protocol error
+    /// already_set is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `primaries`: named primaries
+    #[inline]
+    pub fn set_primaries_named(&self, primaries: WpColorManagerV1Primaries) {
+        let (arg0,) = (primaries,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 10
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+
+    /// primaries as chromaticity coordinates
+    ///
+    /// Sets the color primaries and white point using CIE 1931 xy chromaticity
+    /// coordinates. This describes the primary color volume which is the basis
+    /// for color value encoding.
+    ///
+    /// Each coordinate value is multiplied by 1 million to get the argument
+    /// value to carry precision of 6 decimals.
+    ///
+    /// If primaries have already been set on this object, the protocol error
+    /// already_set is raised.
+    ///
+    /// This request can be used if the compositor advertises
+    /// wp_color_manager_v1.feature.set_primaries. Otherwise this request raises
+    /// the protocol error unsupported_feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `r_x`: Red x * 1M
+    /// - `r_y`: Red y * 1M
+    /// - `g_x`: Green x * 1M
+    /// - `g_y`: Green y * 1M
+    /// - `b_x`: Blue x * 1M
+    /// - `b_y`: Blue y * 1M
+    /// - `w_x`: White x * 1M
+    /// - `w_y`: White y * 1M
+    #[inline]
+    pub fn set_primaries(
+        &self,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7) =
+            (r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y);
+        let mut args = [
+            wl_argument { i: arg0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { i: arg3 },
+            wl_argument { i: arg4 },
+            wl_argument { i: arg5 },
+            wl_argument { i: arg6 },
+            wl_argument { i: arg7 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 10
+        //         - the request signature is `iiiiiiii`
+        unsafe {
+            self.proxy.send_request(4, &mut args);
+        }
+    }
+
+    /// primary color volume luminance range and reference white
+    ///
+    /// Sets the primary color volume luminance range and the reference white
+    /// luminance level. These values include the minimum display emission, but
+    /// not external flare. The minimum display emission is assumed to have
+    /// the chromaticity of the primary color volume white point.
+    ///
+    /// The default luminances from
+    /// https://www.color.org/chardata/rgb/srgb.xalter are
+    /// - primary color volume minimum: 0.2 cd/m²
+    /// - primary color volume maximum: 80 cd/m²
+    /// - reference white: 80 cd/m²
+    ///
+    /// Setting a named transfer characteristic can imply other default
+    /// luminances.
+    ///
+    /// The default luminances get overwritten when this request is used.
+    /// With transfer_function.st2084_pq the given 'max_lum' value is ignored,
+    /// and 'max_lum' is taken as 'min_lum' + 10000 cd/m².
+    ///
+    /// 'min_lum' and 'max_lum' specify the minimum and maximum luminances of
+    /// the primary color volume as reproduced by the targeted display.
+    ///
+    /// 'reference_lum' specifies the luminance of the reference white as
+    /// reproduced by the targeted display, and reflects the targeted viewing
+    /// environment.
+    ///
+    /// Compositors should make sure that all content is anchored, meaning that
+    /// an input signal level of 'reference_lum' on one image description and
+    /// another input signal level of 'reference_lum' on another image
+    /// description should produce the same output level, even though the
+    /// 'reference_lum' on both image representations can be different.
+    ///
+    /// 'reference_lum' may be higher than 'max_lum'. In that case reaching
+    /// the reference white output level in image content requires the
+    /// 'extended_target_volume' feature support.
+    ///
+    /// If 'max_lum' or 'reference_lum' are less than or equal to 'min_lum',
+    /// the protocol error invalid_luminance is raised.
+    ///
+    /// The minimum luminance is multiplied by 10000 to get the argument
+    /// 'min_lum' value and carries precision of 4 decimals. The maximum
+    /// luminance and reference white luminance values are unscaled.
+    ///
+    /// If the primary color volume luminance range and the reference white
+    /// luminance level have already been set on this object, the protocol error
+    /// already_set is raised.
+    ///
+    /// This request can be used if the compositor advertises
+    /// wp_color_manager_v1.feature.set_luminances. Otherwise this request
+    /// raises the protocol error unsupported_feature.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_lum`: minimum luminance (cd/m²) * 10000
+    /// - `max_lum`: maximum luminance (cd/m²)
+    /// - `reference_lum`: reference white luminance (cd/m²)
+    #[inline]
+    pub fn set_luminances(&self, min_lum: u32, max_lum: u32, reference_lum: u32) {
+        let (arg0, arg1, arg2) = (min_lum, max_lum, reference_lum);
+        let mut args = [
+            wl_argument { u: arg0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 5 < INTERFACE.method_count = 10
+        //         - the request signature is `uuu`
+        unsafe {
+            self.proxy.send_request(5, &mut args);
+        }
+    }
+
+    /// mastering display primaries as chromaticity coordinates
+    ///
+    /// Provides the color primaries and white point of the mastering display
+    /// using CIE 1931 xy chromaticity coordinates. This is compatible with the
+    /// SMPTE ST 2086 definition of HDR static metadata.
+    ///
+    /// The mastering display primaries and mastering display luminances define
+    /// the target color volume.
+    ///
+    /// If mastering display primaries are not explicitly set, the target color
+    /// volume is assumed to have the same primaries as the primary color volume.
+    ///
+    /// The target color volume is defined by all tristimulus values between 0.0
+    /// and 1.0 (inclusive) of the color space defined by the given mastering
+    /// display primaries and white point. The colorimetry is identical between
+    /// the container color space and the mastering display color space,
+    /// including that no chromatic adaptation is applied even if the white
+    /// points differ.
+    ///
+    /// The target color volume can exceed the primary color volume to allow for
+    /// a greater color volume with an existing color space definition (for
+    /// example scRGB). It can be smaller than the primary color volume to
+    /// minimize gamut and tone mapping distances for big color spaces (HDR
+    /// metadata).
+    ///
+    /// To make use of the entire target color volume a suitable pixel format
+    /// has to be chosen (e.g. floating point to exceed the primary color
+    /// volume, or abusing limited quantization range as with xvYCC).
+    ///
+    /// Each coordinate value is multiplied by 1 million to get the argument
+    /// value to carry precision of 6 decimals.
+    ///
+    /// If mastering display primaries have already been set on this object, the
+    /// protocol error already_set is raised.
+    ///
+    /// This request can be used if the compositor advertises
+    /// wp_color_manager_v1.feature.set_mastering_display_primaries. Otherwise
+    /// this request raises the protocol error unsupported_feature. The
+    /// advertisement implies support only for target color volumes fully
+    /// contained within the primary color volume.
+    ///
+    /// If a compositor additionally supports target color volume exceeding the
+    /// primary color volume, it must advertise
+    /// wp_color_manager_v1.feature.extended_target_volume. If a client uses
+    /// target color volume exceeding the primary color volume and the
+    /// compositor does not support it, the result is implementation defined.
+    /// Compositors are recommended to detect this case and fail the image
+    /// description gracefully, but it may as well result in color artifacts.
+    ///
+    /// # Arguments
+    ///
+    /// - `r_x`: Red x * 1M
+    /// - `r_y`: Red y * 1M
+    /// - `g_x`: Green x * 1M
+    /// - `g_y`: Green y * 1M
+    /// - `b_x`: Blue x * 1M
+    /// - `b_y`: Blue y * 1M
+    /// - `w_x`: White x * 1M
+    /// - `w_y`: White y * 1M
+    #[inline]
+    pub fn set_mastering_display_primaries(
+        &self,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7) =
+            (r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y);
+        let mut args = [
+            wl_argument { i: arg0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { i: arg3 },
+            wl_argument { i: arg4 },
+            wl_argument { i: arg5 },
+            wl_argument { i: arg6 },
+            wl_argument { i: arg7 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 6 < INTERFACE.method_count = 10
+        //         - the request signature is `iiiiiiii`
+        unsafe {
+            self.proxy.send_request(6, &mut args);
+        }
+    }
+
+    /// display mastering luminance range
+    ///
+    /// Sets the luminance range that was used during the content mastering
+    /// process as the minimum and maximum absolute luminance L. These values
+    /// include the minimum display emission and ambient flare luminances,
+    /// assumed to be optically additive and have the chromaticity of the
+    /// primary color volume white point. This should be
+    /// compatible with the SMPTE ST 2086 definition of HDR static metadata.
+    ///
+    /// The mastering display primaries and mastering display luminances define
+    /// the target color volume.
+    ///
+    /// If mastering luminances are not explicitly set, the target color volume
+    /// is assumed to have the same min and max luminances as the primary color
+    /// volume.
+    ///
+    /// If max L is less than or equal to min L, the protocol error
+    /// invalid_luminance is raised.
+    ///
+    /// Min L value is multiplied by 10000 to get the argument min_lum value
+    /// and carry precision of 4 decimals. Max L value is unscaled for max_lum.
+    ///
+    /// This request can be used if the compositor advertises
+    /// wp_color_manager_v1.feature.set_mastering_display_primaries. Otherwise
+    /// this request raises the protocol error unsupported_feature. The
+    /// advertisement implies support only for target color volumes fully
+    /// contained within the primary color volume.
+    ///
+    /// If a compositor additionally supports target color volume exceeding the
+    /// primary color volume, it must advertise
+    /// wp_color_manager_v1.feature.extended_target_volume. If a client uses
+    /// target color volume exceeding the primary color volume and the
+    /// compositor does not support it, the result is implementation defined.
+    /// Compositors are recommended to detect this case and fail the image
+    /// description gracefully, but it may as well result in color artifacts.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_lum`: min L (cd/m²) * 10000
+    /// - `max_lum`: max L (cd/m²)
+    #[inline]
+    pub fn set_mastering_luminance(&self, min_lum: u32, max_lum: u32) {
+        let (arg0, arg1) = (min_lum, max_lum);
+        let mut args = [wl_argument { u: arg0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 7 < INTERFACE.method_count = 10
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(7, &mut args);
+        }
+    }
+
+    /// maximum content light level
+    ///
+    /// Sets the maximum content light level (max_cll) as defined by CTA-861-H.
+    ///
+    /// max_cll is undefined by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_cll`: Maximum content light level (cd/m²)
+    #[inline]
+    pub fn set_max_cll(&self, max_cll: u32) {
+        let (arg0,) = (max_cll,);
+        let mut args = [wl_argument { u: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 8 < INTERFACE.method_count = 10
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(8, &mut args);
+        }
+    }
+
+    /// maximum frame-average light level
+    ///
+    /// Sets the maximum frame-average light level (max_fall) as defined by
+    /// CTA-861-H.
+    ///
+    /// max_fall is undefined by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_fall`: Maximum frame-average light level (cd/m²)
+    #[inline]
+    pub fn set_max_fall(&self, max_fall: u32) {
+        let (arg0,) = (max_fall,);
+        let mut args = [wl_argument { u: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 9 < INTERFACE.method_count = 10
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(9, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpImageDescriptionCreatorParamsV1] proxies.
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorParamsV1EventHandler {}
+
+impl WpImageDescriptionCreatorParamsV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpImageDescriptionCreatorParamsV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionCreatorParamsV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorParamsV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpImageDescriptionCreatorParamsV1] proxies.
+///
+/// This trait is the same as [WpImageDescriptionCreatorParamsV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorParamsV1EventHandlerMut {}
+
+/// A mutable event handler for [WpImageDescriptionCreatorParamsV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionCreatorParamsV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionCreatorParamsV1EventHandlerMutWithData<T> {}
+
+impl<H> WpImageDescriptionCreatorParamsV1EventHandler for MutEventHandler<H> where
+    H: WpImageDescriptionCreatorParamsV1EventHandlerMut
+{
+}
+
+impl<T, H> WpImageDescriptionCreatorParamsV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpImageDescriptionCreatorParamsV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpImageDescriptionCreatorParamsV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionCreatorParamsV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> WpImageDescriptionCreatorParamsV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionCreatorParamsV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpImageDescriptionCreatorParamsV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_image_description_creator_params_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpImageDescriptionCreatorParamsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_image_description_creator_params_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpImageDescriptionCreatorParamsV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpImageDescriptionCreatorParamsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpImageDescriptionCreatorParamsV1 {
+    /// Since when the error.incomplete_set enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INCOMPLETE_SET__SINCE: u32 = 1;
+    /// Since when the error.already_set enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_SET__SINCE: u32 = 1;
+    /// Since when the error.unsupported_feature enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_UNSUPPORTED_FEATURE__SINCE: u32 = 1;
+    /// Since when the error.invalid_tf enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_TF__SINCE: u32 = 1;
+    /// Since when the error.invalid_primaries_named enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_PRIMARIES_NAMED__SINCE: u32 = 1;
+    /// Since when the error.invalid_luminance enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_LUMINANCE__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpImageDescriptionCreatorParamsV1Error(pub u32);
+
+impl WpImageDescriptionCreatorParamsV1Error {
+    /// incomplete parameter set
+    #[allow(dead_code)]
+    pub const INCOMPLETE_SET: Self = Self(0);
+
+    /// property already set
+    #[allow(dead_code)]
+    pub const ALREADY_SET: Self = Self(1);
+
+    /// request not supported
+    #[allow(dead_code)]
+    pub const UNSUPPORTED_FEATURE: Self = Self(2);
+
+    /// invalid transfer characteristic
+    #[allow(dead_code)]
+    pub const INVALID_TF: Self = Self(3);
+
+    /// invalid primaries named
+    #[allow(dead_code)]
+    pub const INVALID_PRIMARIES_NAMED: Self = Self(4);
+
+    /// invalid luminance value or range
+    #[allow(dead_code)]
+    pub const INVALID_LUMINANCE: Self = Self(5);
+}
+
+impl Debug for WpImageDescriptionCreatorParamsV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INCOMPLETE_SET => "INCOMPLETE_SET",
+            Self::ALREADY_SET => "ALREADY_SET",
+            Self::UNSUPPORTED_FEATURE => "UNSUPPORTED_FEATURE",
+            Self::INVALID_TF => "INVALID_TF",
+            Self::INVALID_PRIMARIES_NAMED => "INVALID_PRIMARIES_NAMED",
+            Self::INVALID_LUMINANCE => "INVALID_LUMINANCE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpImageDescriptionCreatorParamsV1 {}
+}
@@ -0,0 +1,4146 @@
+//! Colorimetric image description information
+//!
+//! Sends all matching events describing an image description object exactly
+//! once and finally sends the 'done' event.
+//!
+//! This means
+//! - if the image description is parametric, it must send
+//!   - primaries
+//!   - named_primaries, if applicable
+//!   - at least one of tf_power and tf_named, as applicable
+//!   - luminances
+//!   - target_primaries
+//!   - target_luminance
+//! - if the image description is parametric, it may send, if applicable,
+//!   - target_max_cll
+//!   - target_max_fall
+//! - if the image description contains an ICC profile, it must send the
+//!   icc_file event
+//!
+//! Once a wp_image_description_info_v1 object has delivered a 'done' event it
+//! is automatically destroyed.
+//!
+//! Every wp_image_description_info_v1 created from the same
+//! wp_image_description_v1 shall always return the exact same data.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_image_description_info_v1".as_ptr(),
+    version: 2,
+    method_count: 0,
+    methods: ptr::null(),
+    event_count: 11,
+    events: {
+        static MESSAGES: [wl_message; 11] = [
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"icc_file".as_ptr(),
+                signature: c"hu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"primaries".as_ptr(),
+                signature: c"iiiiiiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 8] =
+                        [None, None, None, None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"primaries_named".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tf_power".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tf_named".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"luminances".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"target_primaries".as_ptr(),
+                signature: c"iiiiiiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 8] =
+                        [None, None, None, None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"target_luminance".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"target_max_cll".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"target_max_fall".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_image_description_info_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_image_description_info_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[],
+    events: &[
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "icc_file",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "icc",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "icc_size",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "primaries",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "r_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "r_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "primaries_named",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "primaries",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wp_color_manager_v1.primaries"),
+            }],
+        },
+        info::MessageInfo {
+            name: "tf_power",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "eexp",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tf_named",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "tf",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wp_color_manager_v1.transfer_function"),
+            }],
+        },
+        info::MessageInfo {
+            name: "luminances",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "min_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "max_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "reference_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "target_primaries",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "r_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "r_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "w_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "target_luminance",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "min_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "max_lum",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "target_max_cll",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "max_cll",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "target_max_fall",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "max_fall",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned wp_image_description_info_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionInfoV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_image_description_info_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionInfoV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpImageDescriptionInfoV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpImageDescriptionInfoV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpImageDescriptionInfoV1 {
+    const INTERFACE: &'static str = "wp_image_description_info_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpImageDescriptionInfoV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpImageDescriptionInfoV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpImageDescriptionInfoV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpImageDescriptionInfoV1Ref {
+    type Owned = WpImageDescriptionInfoV1;
+}
+
+impl Deref for WpImageDescriptionInfoV1 {
+    type Target = WpImageDescriptionInfoV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpImageDescriptionInfoV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_info_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpImageDescriptionInfoV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_info_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpImageDescriptionInfoV1Ref> for WpImageDescriptionInfoV1 {
+    fn eq(&self, other: &WpImageDescriptionInfoV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpImageDescriptionInfoV1> for WpImageDescriptionInfoV1Ref {
+    fn eq(&self, other: &WpImageDescriptionInfoV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl WpImageDescriptionInfoV1 {
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the icc_file event is available.
+    #[allow(dead_code)]
+    pub const EVT__ICC_FILE__SINCE: u32 = 1;
+
+    /// Since when the primaries event is available.
+    #[allow(dead_code)]
+    pub const EVT__PRIMARIES__SINCE: u32 = 1;
+
+    /// Since when the primaries_named event is available.
+    #[allow(dead_code)]
+    pub const EVT__PRIMARIES_NAMED__SINCE: u32 = 1;
+
+    /// Since when the tf_power event is available.
+    #[allow(dead_code)]
+    pub const EVT__TF_POWER__SINCE: u32 = 1;
+
+    /// Since when the tf_named event is available.
+    #[allow(dead_code)]
+    pub const EVT__TF_NAMED__SINCE: u32 = 1;
+
+    /// Since when the luminances event is available.
+    #[allow(dead_code)]
+    pub const EVT__LUMINANCES__SINCE: u32 = 1;
+
+    /// Since when the target_primaries event is available.
+    #[allow(dead_code)]
+    pub const EVT__TARGET_PRIMARIES__SINCE: u32 = 1;
+
+    /// Since when the target_luminance event is available.
+    #[allow(dead_code)]
+    pub const EVT__TARGET_LUMINANCE__SINCE: u32 = 1;
+
+    /// Since when the target_max_cll event is available.
+    #[allow(dead_code)]
+    pub const EVT__TARGET_MAX_CLL__SINCE: u32 = 1;
+
+    /// Since when the target_max_fall event is available.
+    #[allow(dead_code)]
+    pub const EVT__TARGET_MAX_FALL__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpImageDescriptionInfoV1] proxies.
+#[allow(dead_code)]
+pub trait WpImageDescriptionInfoV1EventHandler {
+    /// end of information
+    ///
+    /// Signals the end of information events and destroys the object.
+    #[inline]
+    fn done(&self, _slf: &WpImageDescriptionInfoV1Ref) {}
+
+    /// ICC profile matching the image description
+    ///
+    /// The icc argument provides a file descriptor to the client which may be
+    /// memory-mapped to provide the ICC profile matching the image description.
+    /// The fd is read-only, and if mapped then it must be mapped with
+    /// MAP_PRIVATE by the client.
+    ///
+    /// The ICC profile version and other details are determined by the
+    /// compositor. There is no provision for a client to ask for a specific
+    /// kind of a profile.
+    ///
+    /// # Arguments
+    ///
+    /// - `icc`: ICC profile file descriptor
+    /// - `icc_size`: ICC profile size, in bytes
+    #[inline]
+    fn icc_file(&self, _slf: &WpImageDescriptionInfoV1Ref, icc: OwnedFd, icc_size: u32) {
+        let _ = icc;
+        let _ = icc_size;
+    }
+
+    /// primaries as chromaticity coordinates
+    ///
+    /// Delivers the primary color volume primaries and white point using CIE
+    /// 1931 xy chromaticity coordinates.
+    ///
+    /// Each coordinate value is multiplied by 1 million to get the argument
+    /// value to carry precision of 6 decimals.
+    ///
+    /// # Arguments
+    ///
+    /// - `r_x`: Red x * 1M
+    /// - `r_y`: Red y * 1M
+    /// - `g_x`: Green x * 1M
+    /// - `g_y`: Green y * 1M
+    /// - `b_x`: Blue x * 1M
+    /// - `b_y`: Blue y * 1M
+    /// - `w_x`: White x * 1M
+    /// - `w_y`: White y * 1M
+    #[inline]
+    fn primaries(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// named primaries
+    ///
+    /// Delivers the primary color volume primaries and white point using an
+    /// explicitly enumerated named set.
+    ///
+    /// # Arguments
+    ///
+    /// - `primaries`: named primaries
+    #[inline]
+    fn primaries_named(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// transfer characteristic as a power curve
+    ///
+    /// The color component transfer characteristic of this image description is
+    /// a pure power curve. This event provides the exponent of the power
+    /// function. This curve represents the conversion from electrical to
+    /// optical pixel or color values.
+    ///
+    /// The curve exponent has been multiplied by 10000 to get the argument eexp
+    /// value to carry the precision of 4 decimals.
+    ///
+    /// # Arguments
+    ///
+    /// - `eexp`: the exponent * 10000
+    #[inline]
+    fn tf_power(&self, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        let _ = eexp;
+    }
+
+    /// named transfer characteristic
+    ///
+    /// Delivers the transfer characteristic using an explicitly enumerated
+    /// named function.
+    ///
+    /// # Arguments
+    ///
+    /// - `tf`: named transfer function
+    #[inline]
+    fn tf_named(&self, _slf: &WpImageDescriptionInfoV1Ref, tf: WpColorManagerV1TransferFunction) {
+        let _ = tf;
+    }
+
+    /// primary color volume luminance range and reference white
+    ///
+    /// Delivers the primary color volume luminance range and the reference
+    /// white luminance level. These values include the minimum display emission
+    /// and ambient flare luminances, assumed to be optically additive and have
+    /// the chromaticity of the primary color volume white point.
+    ///
+    /// The minimum luminance is multiplied by 10000 to get the argument
+    /// 'min_lum' value and carries precision of 4 decimals. The maximum
+    /// luminance and reference white luminance values are unscaled.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_lum`: minimum luminance (cd/m²) * 10000
+    /// - `max_lum`: maximum luminance (cd/m²)
+    /// - `reference_lum`: reference white luminance (cd/m²)
+    #[inline]
+    fn luminances(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        let _ = min_lum;
+        let _ = max_lum;
+        let _ = reference_lum;
+    }
+
+    /// target primaries as chromaticity coordinates
+    ///
+    /// Provides the color primaries and white point of the target color volume
+    /// using CIE 1931 xy chromaticity coordinates. This is compatible with the
+    /// SMPTE ST 2086 definition of HDR static metadata for mastering displays.
+    ///
+    /// While primary color volume is about how color is encoded, the target
+    /// color volume is the actually displayable color volume. If target color
+    /// volume is equal to the primary color volume, then this event is not
+    /// sent.
+    ///
+    /// Each coordinate value is multiplied by 1 million to get the argument
+    /// value to carry precision of 6 decimals.
+    ///
+    /// # Arguments
+    ///
+    /// - `r_x`: Red x * 1M
+    /// - `r_y`: Red y * 1M
+    /// - `g_x`: Green x * 1M
+    /// - `g_y`: Green y * 1M
+    /// - `b_x`: Blue x * 1M
+    /// - `b_y`: Blue y * 1M
+    /// - `w_x`: White x * 1M
+    /// - `w_y`: White y * 1M
+    #[inline]
+    fn target_primaries(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// target luminance range
+    ///
+    /// Provides the luminance range that the image description is targeting as
+    /// the minimum and maximum absolute luminance L. These values include the
+    /// minimum display emission and ambient flare luminances, assumed to be
+    /// optically additive and have the chromaticity of the primary color
+    /// volume white point. This should be compatible with the SMPTE ST 2086
+    /// definition of HDR static metadata.
+    ///
+    /// This luminance range is only theoretical and may not correspond to the
+    /// luminance of light emitted on an actual display.
+    ///
+    /// Min L value is multiplied by 10000 to get the argument min_lum value and
+    /// carry precision of 4 decimals. Max L value is unscaled for max_lum.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_lum`: min L (cd/m²) * 10000
+    /// - `max_lum`: max L (cd/m²)
+    #[inline]
+    fn target_luminance(&self, _slf: &WpImageDescriptionInfoV1Ref, min_lum: u32, max_lum: u32) {
+        let _ = min_lum;
+        let _ = max_lum;
+    }
+
+    /// target maximum content light level
+    ///
+    /// Provides the targeted max_cll of the image description. max_cll is
+    /// defined by CTA-861-H.
+    ///
+    /// This luminance is only theoretical and may not correspond to the
+    /// luminance of light emitted on an actual display.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_cll`: Maximum content light-level (cd/m²)
+    #[inline]
+    fn target_max_cll(&self, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        let _ = max_cll;
+    }
+
+    /// target maximum frame-average light level
+    ///
+    /// Provides the targeted max_fall of the image description. max_fall is
+    /// defined by CTA-861-H.
+    ///
+    /// This luminance is only theoretical and may not correspond to the
+    /// luminance of light emitted on an actual display.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_fall`: Maximum frame-average light level (cd/m²)
+    #[inline]
+    fn target_max_fall(&self, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+        let _ = max_fall;
+    }
+}
+
+impl WpImageDescriptionInfoV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpImageDescriptionInfoV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionInfoV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpImageDescriptionInfoV1EventHandlerWithData<T> {
+    /// end of information
+    ///
+    /// Signals the end of information events and destroys the object.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {}
+
+    /// ICC profile matching the image description
+    ///
+    /// The icc argument provides a file descriptor to the client which may be
+    /// memory-mapped to provide the ICC profile matching the image description.
+    /// The fd is read-only, and if mapped then it must be mapped with
+    /// MAP_PRIVATE by the client.
+    ///
+    /// The ICC profile version and other details are determined by the
+    /// compositor. There is no provision for a client to ask for a specific
+    /// kind of a profile.
+    ///
+    /// # Arguments
+    ///
+    /// - `icc`: ICC profile file descriptor
+    /// - `icc_size`: ICC profile size, in bytes
+    #[inline]
+    fn icc_file(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        icc: OwnedFd,
+        icc_size: u32,
+    ) {
+        let _ = icc;
+        let _ = icc_size;
+    }
+
+    /// primaries as chromaticity coordinates
+    ///
+    /// Delivers the primary color volume primaries and white point using CIE
+    /// 1931 xy chromaticity coordinates.
+    ///
+    /// Each coordinate value is multiplied by 1 million to get the argument
+    /// value to carry precision of 6 decimals.
+    ///
+    /// # Arguments
+    ///
+    /// - `r_x`: Red x * 1M
+    /// - `r_y`: Red y * 1M
+    /// - `g_x`: Green x * 1M
+    /// - `g_y`: Green y * 1M
+    /// - `b_x`: Blue x * 1M
+    /// - `b_y`: Blue y * 1M
+    /// - `w_x`: White x * 1M
+    /// - `w_y`: White y * 1M
+    #[inline]
+    fn primaries(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// named primaries
+    ///
+    /// Delivers the primary color volume primaries and white point using an
+    /// explicitly enumerated named set.
+    ///
+    /// # Arguments
+    ///
+    /// - `primaries`: named primaries
+    #[inline]
+    fn primaries_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// transfer characteristic as a power curve
+    ///
+    /// The color component transfer characteristic of this image description is
+    /// a pure power curve. This event provides the exponent of the power
+    /// function. This curve represents the conversion from electrical to
+    /// optical pixel or color values.
+    ///
+    /// The curve exponent has been multiplied by 10000 to get the argument eexp
+    /// value to carry the precision of 4 decimals.
+    ///
+    /// # Arguments
+    ///
+    /// - `eexp`: the exponent * 10000
+    #[inline]
+    fn tf_power(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        let _ = eexp;
+    }
+
+    /// named transfer characteristic
+    ///
+    /// Delivers the transfer characteristic using an explicitly enumerated
+    /// named function.
+    ///
+    /// # Arguments
+    ///
+    /// - `tf`: named transfer function
+    #[inline]
+    fn tf_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _ = tf;
+    }
+
+    /// primary color volume luminance range and reference white
+    ///
+    /// Delivers the primary color volume luminance range and the reference
+    /// white luminance level. These values include the minimum display emission
+    /// and ambient flare luminances, assumed to be optically additive and have
+    /// the chromaticity of the primary color volume white point.
+    ///
+    /// The minimum luminance is multiplied by 10000 to get the argument
+    /// 'min_lum' value and carries precision of 4 decimals. The maximum
+    /// luminance and reference white luminance values are unscaled.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_lum`: minimum luminance (cd/m²) * 10000
+    /// - `max_lum`: maximum luminance (cd/m²)
+    /// - `reference_lum`: reference white luminance (cd/m²)
+    #[inline]
+    fn luminances(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        let _ = min_lum;
+        let _ = max_lum;
+        let _ = reference_lum;
+    }
+
+    /// target primaries as chromaticity coordinates
+    ///
+    /// Provides the color primaries and white point of the target color volume
+    /// using CIE 1931 xy chromaticity coordinates. This is compatible with the
+    /// SMPTE ST 2086 definition of HDR static metadata for mastering displays.
+    ///
+    /// While primary color volume is about how color is encoded, the target
+    /// color volume is the actually displayable color volume. If target color
+    /// volume is equal to the primary color volume, then this event is not
+    /// sent.
+    ///
+    /// Each coordinate value is multiplied by 1 million to get the argument
+    /// value to carry precision of 6 decimals.
+    ///
+    /// # Arguments
+    ///
+    /// - `r_x`: Red x * 1M
+    /// - `r_y`: Red y * 1M
+    /// - `g_x`: Green x * 1M
+    /// - `g_y`: Green y * 1M
+    /// - `b_x`: Blue x * 1M
+    /// - `b_y`: Blue y * 1M
+    /// - `w_x`: White x * 1M
+    /// - `w_y`: White y * 1M
+    #[inline]
+    fn target_primaries(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// target luminance range
+    ///
+    /// Provides the luminance range that the image description is targeting as
+    /// the minimum and maximum absolute luminance L. These values include the
+    /// minimum display emission and ambient flare luminances, assumed to be
+    /// optically additive and have the chromaticity of the primary color
+    /// volume white point. This should be compatible with the SMPTE ST 2086
+    /// definition of HDR static metadata.
+    ///
+    /// This luminance range is only theoretical and may not correspond to the
+    /// luminance of light emitted on an actual display.
+    ///
+    /// Min L value is multiplied by 10000 to get the argument min_lum value and
+    /// carry precision of 4 decimals. Max L value is unscaled for max_lum.
+    ///
+    /// # Arguments
+    ///
+    /// - `min_lum`: min L (cd/m²) * 10000
+    /// - `max_lum`: max L (cd/m²)
+    #[inline]
+    fn target_luminance(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+    ) {
+        let _ = min_lum;
+        let _ = max_lum;
+    }
+
+    /// target maximum content light level
+    ///
+    /// Provides the targeted max_cll of the image description. max_cll is
+    /// defined by CTA-861-H.
+    ///
+    /// This luminance is only theoretical and may not correspond to the
+    /// luminance of light emitted on an actual display.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_cll`: Maximum content light-level (cd/m²)
+    #[inline]
+    fn target_max_cll(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        let _ = max_cll;
+    }
+
+    /// target maximum frame-average light level
+    ///
+    /// Provides the targeted max_fall of the image description. max_fall is
+    /// defined by CTA-861-H.
+    ///
+    /// This luminance is only theoretical and may not correspond to the
+    /// luminance of light emitted on an actual display.
+    ///
+    /// # Arguments
+    ///
+    /// - `max_fall`: Maximum frame-average light level (cd/m²)
+    #[inline]
+    fn target_max_fall(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+        let _ = max_fall;
+    }
+}
+
+/// A mutable event handler for [WpImageDescriptionInfoV1] proxies.
+///
+/// This trait is the same as [WpImageDescriptionInfoV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionInfoV1EventHandlerMut {
+    /// See [WpImageDescriptionInfoV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &WpImageDescriptionInfoV1Ref) {}
+
+    /// See [WpImageDescriptionInfoV1EventHandler::icc_file].
+    #[inline]
+    fn icc_file(&mut self, _slf: &WpImageDescriptionInfoV1Ref, icc: OwnedFd, icc_size: u32) {
+        let _ = icc;
+        let _ = icc_size;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::primaries].
+    #[inline]
+    fn primaries(
+        &mut self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::primaries_named].
+    #[inline]
+    fn primaries_named(
+        &mut self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::tf_power].
+    #[inline]
+    fn tf_power(&mut self, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        let _ = eexp;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::tf_named].
+    #[inline]
+    fn tf_named(
+        &mut self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _ = tf;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::luminances].
+    #[inline]
+    fn luminances(
+        &mut self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        let _ = min_lum;
+        let _ = max_lum;
+        let _ = reference_lum;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_primaries].
+    #[inline]
+    fn target_primaries(
+        &mut self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_luminance].
+    #[inline]
+    fn target_luminance(&mut self, _slf: &WpImageDescriptionInfoV1Ref, min_lum: u32, max_lum: u32) {
+        let _ = min_lum;
+        let _ = max_lum;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_max_cll].
+    #[inline]
+    fn target_max_cll(&mut self, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        let _ = max_cll;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_max_fall].
+    #[inline]
+    fn target_max_fall(&mut self, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+        let _ = max_fall;
+    }
+}
+
+/// A mutable event handler for [WpImageDescriptionInfoV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionInfoV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionInfoV1EventHandlerMutWithData<T> {
+    /// See [WpImageDescriptionInfoV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {}
+
+    /// See [WpImageDescriptionInfoV1EventHandler::icc_file].
+    #[inline]
+    fn icc_file(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        icc: OwnedFd,
+        icc_size: u32,
+    ) {
+        let _ = icc;
+        let _ = icc_size;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::primaries].
+    #[inline]
+    fn primaries(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::primaries_named].
+    #[inline]
+    fn primaries_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _ = primaries;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::tf_power].
+    #[inline]
+    fn tf_power(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        let _ = eexp;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::tf_named].
+    #[inline]
+    fn tf_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _ = tf;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::luminances].
+    #[inline]
+    fn luminances(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        let _ = min_lum;
+        let _ = max_lum;
+        let _ = reference_lum;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_primaries].
+    #[inline]
+    fn target_primaries(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _ = r_x;
+        let _ = r_y;
+        let _ = g_x;
+        let _ = g_y;
+        let _ = b_x;
+        let _ = b_y;
+        let _ = w_x;
+        let _ = w_y;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_luminance].
+    #[inline]
+    fn target_luminance(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+    ) {
+        let _ = min_lum;
+        let _ = max_lum;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_max_cll].
+    #[inline]
+    fn target_max_cll(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        let _ = max_cll;
+    }
+
+    /// See [WpImageDescriptionInfoV1EventHandler::target_max_fall].
+    #[inline]
+    fn target_max_fall(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        max_fall: u32,
+    ) {
+        let _ = max_fall;
+    }
+}
+
+impl<H> WpImageDescriptionInfoV1EventHandler for MutEventHandler<H>
+where
+    H: WpImageDescriptionInfoV1EventHandlerMut,
+{
+    #[inline]
+    fn done(&self, _slf: &WpImageDescriptionInfoV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn icc_file(&self, _slf: &WpImageDescriptionInfoV1Ref, icc: OwnedFd, icc_size: u32) {
+        self.with(|h| h.icc_file(_slf, icc, icc_size))
+    }
+
+    #[inline]
+    fn primaries(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        self.with(|h| h.primaries(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y))
+    }
+
+    #[inline]
+    fn primaries_named(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        self.with(|h| h.primaries_named(_slf, primaries))
+    }
+
+    #[inline]
+    fn tf_power(&self, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        self.with(|h| h.tf_power(_slf, eexp))
+    }
+
+    #[inline]
+    fn tf_named(&self, _slf: &WpImageDescriptionInfoV1Ref, tf: WpColorManagerV1TransferFunction) {
+        self.with(|h| h.tf_named(_slf, tf))
+    }
+
+    #[inline]
+    fn luminances(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        self.with(|h| h.luminances(_slf, min_lum, max_lum, reference_lum))
+    }
+
+    #[inline]
+    fn target_primaries(
+        &self,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        self.with(|h| h.target_primaries(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y))
+    }
+
+    #[inline]
+    fn target_luminance(&self, _slf: &WpImageDescriptionInfoV1Ref, min_lum: u32, max_lum: u32) {
+        self.with(|h| h.target_luminance(_slf, min_lum, max_lum))
+    }
+
+    #[inline]
+    fn target_max_cll(&self, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        self.with(|h| h.target_max_cll(_slf, max_cll))
+    }
+
+    #[inline]
+    fn target_max_fall(&self, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+        self.with(|h| h.target_max_fall(_slf, max_fall))
+    }
+}
+
+impl<T, H> WpImageDescriptionInfoV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpImageDescriptionInfoV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn icc_file(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        icc: OwnedFd,
+        icc_size: u32,
+    ) {
+        self.with(|h| h.icc_file(_data, _slf, icc, icc_size))
+    }
+
+    #[inline]
+    fn primaries(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        self.with(|h| h.primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y))
+    }
+
+    #[inline]
+    fn primaries_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        self.with(|h| h.primaries_named(_data, _slf, primaries))
+    }
+
+    #[inline]
+    fn tf_power(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        self.with(|h| h.tf_power(_data, _slf, eexp))
+    }
+
+    #[inline]
+    fn tf_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        self.with(|h| h.tf_named(_data, _slf, tf))
+    }
+
+    #[inline]
+    fn luminances(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        self.with(|h| h.luminances(_data, _slf, min_lum, max_lum, reference_lum))
+    }
+
+    #[inline]
+    fn target_primaries(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        self.with(|h| h.target_primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y))
+    }
+
+    #[inline]
+    fn target_luminance(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+    ) {
+        self.with(|h| h.target_luminance(_data, _slf, min_lum, max_lum))
+    }
+
+    #[inline]
+    fn target_max_cll(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        self.with(|h| h.target_max_cll(_data, _slf, max_cll))
+    }
+
+    #[inline]
+    fn target_max_fall(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+        self.with(|h| h.target_max_fall(_data, _slf, max_fall))
+    }
+}
+
+impl<T, U, H> WpImageDescriptionInfoV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionInfoV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn icc_file(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        icc: OwnedFd,
+        icc_size: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().icc_file(_data, _slf, icc, icc_size)
+    }
+
+    #[inline]
+    fn primaries(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+    }
+
+    #[inline]
+    fn primaries_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _data = self.project(_data);
+        self.handler().primaries_named(_data, _slf, primaries)
+    }
+
+    #[inline]
+    fn tf_power(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        let _data = self.project(_data);
+        self.handler().tf_power(_data, _slf, eexp)
+    }
+
+    #[inline]
+    fn tf_named(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _data = self.project(_data);
+        self.handler().tf_named(_data, _slf, tf)
+    }
+
+    #[inline]
+    fn luminances(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .luminances(_data, _slf, min_lum, max_lum, reference_lum)
+    }
+
+    #[inline]
+    fn target_primaries(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .target_primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+    }
+
+    #[inline]
+    fn target_luminance(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .target_luminance(_data, _slf, min_lum, max_lum)
+    }
+
+    #[inline]
+    fn target_max_cll(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        let _data = self.project(_data);
+        self.handler().target_max_cll(_data, _slf, max_cll)
+    }
+
+    #[inline]
+    fn target_max_fall(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+        let _data = self.project(_data);
+        self.handler().target_max_fall(_data, _slf, max_fall)
+    }
+}
+
+impl<T, U, H> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionInfoV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn icc_file(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        icc: OwnedFd,
+        icc_size: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().icc_file(_data, _slf, icc, icc_size)
+    }
+
+    #[inline]
+    fn primaries(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+    }
+
+    #[inline]
+    fn primaries_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        primaries: WpColorManagerV1Primaries,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().primaries_named(_data, _slf, primaries)
+    }
+
+    #[inline]
+    fn tf_power(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().tf_power(_data, _slf, eexp)
+    }
+
+    #[inline]
+    fn tf_named(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        tf: WpColorManagerV1TransferFunction,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().tf_named(_data, _slf, tf)
+    }
+
+    #[inline]
+    fn luminances(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+        reference_lum: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .luminances(_data, _slf, min_lum, max_lum, reference_lum)
+    }
+
+    #[inline]
+    fn target_primaries(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        r_x: i32,
+        r_y: i32,
+        g_x: i32,
+        g_y: i32,
+        b_x: i32,
+        b_y: i32,
+        w_x: i32,
+        w_y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .target_primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+    }
+
+    #[inline]
+    fn target_luminance(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        min_lum: u32,
+        max_lum: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .target_luminance(_data, _slf, min_lum, max_lum)
+    }
+
+    #[inline]
+    fn target_max_cll(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().target_max_cll(_data, _slf, max_cll)
+    }
+
+    #[inline]
+    fn target_max_fall(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionInfoV1Ref,
+        max_fall: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().target_max_fall(_data, _slf, max_fall)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpImageDescriptionInfoV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpImageDescriptionInfoV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.done(slf);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.icc_file(slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 8 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 8]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                // SAFETY: - INTERFACE requires that args[4] contains an int
+                let arg4 = unsafe { args[4].i };
+                // SAFETY: - INTERFACE requires that args[5] contains an int
+                let arg5 = unsafe { args[5].i };
+                // SAFETY: - INTERFACE requires that args[6] contains an int
+                let arg6 = unsafe { args[6].i };
+                // SAFETY: - INTERFACE requires that args[7] contains an int
+                let arg7 = unsafe { args[7].i };
+                self.0
+                    .primaries(slf, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1Primaries(args[0].u) };
+                self.0.primaries_named(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.tf_power(slf, arg0);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1TransferFunction(args[0].u) };
+                self.0.tf_named(slf, arg0);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.luminances(slf, arg0, arg1, arg2);
+            }
+            7 => {
+                // SAFETY: INTERFACE requires that there are 8 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 8]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                // SAFETY: - INTERFACE requires that args[4] contains an int
+                let arg4 = unsafe { args[4].i };
+                // SAFETY: - INTERFACE requires that args[5] contains an int
+                let arg5 = unsafe { args[5].i };
+                // SAFETY: - INTERFACE requires that args[6] contains an int
+                let arg6 = unsafe { args[6].i };
+                // SAFETY: - INTERFACE requires that args[7] contains an int
+                let arg7 = unsafe { args[7].i };
+                self.0
+                    .target_primaries(slf, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.target_luminance(slf, arg0, arg1);
+            }
+            9 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.target_max_cll(slf, arg0);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.target_max_fall(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wp_image_description_info_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpImageDescriptionInfoV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.done(data, slf);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.icc_file(data, slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 8 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 8]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                // SAFETY: - INTERFACE requires that args[4] contains an int
+                let arg4 = unsafe { args[4].i };
+                // SAFETY: - INTERFACE requires that args[5] contains an int
+                let arg5 = unsafe { args[5].i };
+                // SAFETY: - INTERFACE requires that args[6] contains an int
+                let arg6 = unsafe { args[6].i };
+                // SAFETY: - INTERFACE requires that args[7] contains an int
+                let arg7 = unsafe { args[7].i };
+                self.0
+                    .primaries(data, slf, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1Primaries(args[0].u) };
+                self.0.primaries_named(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.tf_power(data, slf, arg0);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorManagerV1TransferFunction(args[0].u) };
+                self.0.tf_named(data, slf, arg0);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.luminances(data, slf, arg0, arg1, arg2);
+            }
+            7 => {
+                // SAFETY: INTERFACE requires that there are 8 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 8]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                // SAFETY: - INTERFACE requires that args[4] contains an int
+                let arg4 = unsafe { args[4].i };
+                // SAFETY: - INTERFACE requires that args[5] contains an int
+                let arg5 = unsafe { args[5].i };
+                // SAFETY: - INTERFACE requires that args[6] contains an int
+                let arg6 = unsafe { args[6].i };
+                // SAFETY: - INTERFACE requires that args[7] contains an int
+                let arg7 = unsafe { args[7].i };
+                self.0
+                    .target_primaries(data, slf, arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.target_luminance(data, slf, arg0, arg1);
+            }
+            9 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.target_max_cll(data, slf, arg0);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.target_max_fall(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wp_image_description_info_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpImageDescriptionInfoV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for Done<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &WpImageDescriptionInfoV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for icc_file events.
+    pub struct IccFile<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for IccFile<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn icc_file(&self, _slf: &WpImageDescriptionInfoV1Ref, icc: OwnedFd, icc_size: u32) {
+            self.0(_slf, icc, icc_size)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for IccFile<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn icc_file(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            icc: OwnedFd,
+            icc_size: u32,
+        ) {
+            self.0(_data, _slf, icc, icc_size)
+        }
+    }
+
+    /// Event handler for primaries events.
+    pub struct Primaries<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for Primaries<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn primaries(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for Primaries<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn primaries(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+
+    /// Event handler for primaries_named events.
+    pub struct PrimariesNamed<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for PrimariesNamed<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn primaries_named(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_slf, primaries)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for PrimariesNamed<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn primaries_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_data, _slf, primaries)
+        }
+    }
+
+    /// Event handler for tf_power events.
+    pub struct TfPower<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for TfPower<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn tf_power(&self, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+            self.0(_slf, eexp)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for TfPower<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn tf_power(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+            self.0(_data, _slf, eexp)
+        }
+    }
+
+    /// Event handler for tf_named events.
+    pub struct TfNamed<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for TfNamed<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn tf_named(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_slf, tf)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for TfNamed<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn tf_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_data, _slf, tf)
+        }
+    }
+
+    /// Event handler for luminances events.
+    pub struct Luminances<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for Luminances<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn luminances(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+            reference_lum: u32,
+        ) {
+            self.0(_slf, min_lum, max_lum, reference_lum)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for Luminances<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn luminances(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+            reference_lum: u32,
+        ) {
+            self.0(_data, _slf, min_lum, max_lum, reference_lum)
+        }
+    }
+
+    /// Event handler for target_primaries events.
+    pub struct TargetPrimaries<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for TargetPrimaries<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn target_primaries(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for TargetPrimaries<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn target_primaries(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+
+    /// Event handler for target_luminance events.
+    pub struct TargetLuminance<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for TargetLuminance<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, u32, u32),
+    {
+        #[inline]
+        fn target_luminance(&self, _slf: &WpImageDescriptionInfoV1Ref, min_lum: u32, max_lum: u32) {
+            self.0(_slf, min_lum, max_lum)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for TargetLuminance<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32),
+    {
+        #[inline]
+        fn target_luminance(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+        ) {
+            self.0(_data, _slf, min_lum, max_lum)
+        }
+    }
+
+    /// Event handler for target_max_cll events.
+    pub struct TargetMaxCll<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for TargetMaxCll<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_cll(&self, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+            self.0(_slf, max_cll)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for TargetMaxCll<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_cll(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+            self.0(_data, _slf, max_cll)
+        }
+    }
+
+    /// Event handler for target_max_fall events.
+    pub struct TargetMaxFall<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandler for TargetMaxFall<F>
+    where
+        F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_fall(&self, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+            self.0(_slf, max_fall)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerWithData<T> for TargetMaxFall<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_fall(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            max_fall: u32,
+        ) {
+            self.0(_data, _slf, max_fall)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &WpImageDescriptionInfoV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for icc_file events.
+    pub struct IccFileMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for IccFileMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn icc_file(&mut self, _slf: &WpImageDescriptionInfoV1Ref, icc: OwnedFd, icc_size: u32) {
+            self.0(_slf, icc, icc_size)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for IccFileMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn icc_file(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            icc: OwnedFd,
+            icc_size: u32,
+        ) {
+            self.0(_data, _slf, icc, icc_size)
+        }
+    }
+
+    /// Mutable event handler for primaries events.
+    pub struct PrimariesMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for PrimariesMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn primaries(
+            &mut self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for PrimariesMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn primaries(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+
+    /// Mutable event handler for primaries_named events.
+    pub struct PrimariesNamedMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for PrimariesNamedMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn primaries_named(
+            &mut self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_slf, primaries)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for PrimariesNamedMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+    {
+        #[inline]
+        fn primaries_named(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.0(_data, _slf, primaries)
+        }
+    }
+
+    /// Mutable event handler for tf_power events.
+    pub struct TfPowerMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for TfPowerMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn tf_power(&mut self, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+            self.0(_slf, eexp)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for TfPowerMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn tf_power(&mut self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+            self.0(_data, _slf, eexp)
+        }
+    }
+
+    /// Mutable event handler for tf_named events.
+    pub struct TfNamedMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for TfNamedMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn tf_named(
+            &mut self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_slf, tf)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for TfNamedMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+    {
+        #[inline]
+        fn tf_named(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.0(_data, _slf, tf)
+        }
+    }
+
+    /// Mutable event handler for luminances events.
+    pub struct LuminancesMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for LuminancesMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn luminances(
+            &mut self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+            reference_lum: u32,
+        ) {
+            self.0(_slf, min_lum, max_lum, reference_lum)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for LuminancesMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn luminances(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+            reference_lum: u32,
+        ) {
+            self.0(_data, _slf, min_lum, max_lum, reference_lum)
+        }
+    }
+
+    /// Mutable event handler for target_primaries events.
+    pub struct TargetPrimariesMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for TargetPrimariesMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn target_primaries(
+            &mut self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for TargetPrimariesMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn target_primaries(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.0(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+    }
+
+    /// Mutable event handler for target_luminance events.
+    pub struct TargetLuminanceMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for TargetLuminanceMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, u32, u32),
+    {
+        #[inline]
+        fn target_luminance(
+            &mut self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+        ) {
+            self.0(_slf, min_lum, max_lum)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for TargetLuminanceMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32),
+    {
+        #[inline]
+        fn target_luminance(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+        ) {
+            self.0(_data, _slf, min_lum, max_lum)
+        }
+    }
+
+    /// Mutable event handler for target_max_cll events.
+    pub struct TargetMaxCllMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for TargetMaxCllMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_cll(&mut self, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+            self.0(_slf, max_cll)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for TargetMaxCllMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_cll(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            max_cll: u32,
+        ) {
+            self.0(_data, _slf, max_cll)
+        }
+    }
+
+    /// Mutable event handler for target_max_fall events.
+    pub struct TargetMaxFallMut<F>(F);
+    impl<F> WpImageDescriptionInfoV1EventHandlerMut for TargetMaxFallMut<F>
+    where
+        F: FnMut(&WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_fall(&mut self, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+            self.0(_slf, max_fall)
+        }
+    }
+    impl<T, F> WpImageDescriptionInfoV1EventHandlerMutWithData<T> for TargetMaxFallMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+    {
+        #[inline]
+        fn target_max_fall(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            max_fall: u32,
+        ) {
+            self.0(_data, _slf, max_fall)
+        }
+    }
+
+    impl WpImageDescriptionInfoV1 {
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for icc_file events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_icc_file<F>(f: F) -> IccFile<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+        {
+            IccFile(f)
+        }
+
+        /// Creates an event handler with mutable data for icc_file events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_icc_file_with_data<T, F>(f: F) -> IccFile<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+        {
+            IccFile(f)
+        }
+
+        /// Creates an event handler for primaries events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_primaries<F>(f: F) -> Primaries<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            Primaries(f)
+        }
+
+        /// Creates an event handler with mutable data for primaries events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_primaries_with_data<T, F>(f: F) -> Primaries<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            Primaries(f)
+        }
+
+        /// Creates an event handler for primaries_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_primaries_named<F>(f: F) -> PrimariesNamed<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+        {
+            PrimariesNamed(f)
+        }
+
+        /// Creates an event handler with mutable data for primaries_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_primaries_named_with_data<T, F>(f: F) -> PrimariesNamed<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+        {
+            PrimariesNamed(f)
+        }
+
+        /// Creates an event handler for tf_power events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tf_power<F>(f: F) -> TfPower<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            TfPower(f)
+        }
+
+        /// Creates an event handler with mutable data for tf_power events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tf_power_with_data<T, F>(f: F) -> TfPower<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            TfPower(f)
+        }
+
+        /// Creates an event handler for tf_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tf_named<F>(f: F) -> TfNamed<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+        {
+            TfNamed(f)
+        }
+
+        /// Creates an event handler with mutable data for tf_named events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tf_named_with_data<T, F>(f: F) -> TfNamed<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+        {
+            TfNamed(f)
+        }
+
+        /// Creates an event handler for luminances events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_luminances<F>(f: F) -> Luminances<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32, u32, u32),
+        {
+            Luminances(f)
+        }
+
+        /// Creates an event handler with mutable data for luminances events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_luminances_with_data<T, F>(f: F) -> Luminances<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32, u32),
+        {
+            Luminances(f)
+        }
+
+        /// Creates an event handler for target_primaries events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_primaries<F>(f: F) -> TargetPrimaries<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            TargetPrimaries(f)
+        }
+
+        /// Creates an event handler with mutable data for target_primaries events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_primaries_with_data<T, F>(f: F) -> TargetPrimaries<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            TargetPrimaries(f)
+        }
+
+        /// Creates an event handler for target_luminance events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_luminance<F>(f: F) -> TargetLuminance<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32, u32),
+        {
+            TargetLuminance(f)
+        }
+
+        /// Creates an event handler with mutable data for target_luminance events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_luminance_with_data<T, F>(f: F) -> TargetLuminance<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32),
+        {
+            TargetLuminance(f)
+        }
+
+        /// Creates an event handler for target_max_cll events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_max_cll<F>(f: F) -> TargetMaxCll<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxCll(f)
+        }
+
+        /// Creates an event handler with mutable data for target_max_cll events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_max_cll_with_data<T, F>(f: F) -> TargetMaxCll<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxCll(f)
+        }
+
+        /// Creates an event handler for target_max_fall events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_max_fall<F>(f: F) -> TargetMaxFall<F>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxFall(f)
+        }
+
+        /// Creates an event handler with mutable data for target_max_fall events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target_max_fall_with_data<T, F>(f: F) -> TargetMaxFall<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxFall(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for icc_file events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_icc_file_mut<F>(f: F) -> IccFileMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+        {
+            IccFileMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for icc_file events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_icc_file_mut_with_data<T, F>(f: F) -> IccFileMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+        {
+            IccFileMut(f)
+        }
+
+        /// Creates a mutable event handler for primaries events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_primaries_mut<F>(f: F) -> PrimariesMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            PrimariesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for primaries events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_primaries_mut_with_data<T, F>(f: F) -> PrimariesMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            PrimariesMut(f)
+        }
+
+        /// Creates a mutable event handler for primaries_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_primaries_named_mut<F>(f: F) -> PrimariesNamedMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+        {
+            PrimariesNamedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for primaries_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_primaries_named_mut_with_data<T, F>(f: F) -> PrimariesNamedMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+        {
+            PrimariesNamedMut(f)
+        }
+
+        /// Creates a mutable event handler for tf_power events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tf_power_mut<F>(f: F) -> TfPowerMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            TfPowerMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tf_power events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tf_power_mut_with_data<T, F>(f: F) -> TfPowerMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            TfPowerMut(f)
+        }
+
+        /// Creates a mutable event handler for tf_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tf_named_mut<F>(f: F) -> TfNamedMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+        {
+            TfNamedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tf_named events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tf_named_mut_with_data<T, F>(f: F) -> TfNamedMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+        {
+            TfNamedMut(f)
+        }
+
+        /// Creates a mutable event handler for luminances events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_luminances_mut<F>(f: F) -> LuminancesMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, u32, u32, u32),
+        {
+            LuminancesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for luminances events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_luminances_mut_with_data<T, F>(f: F) -> LuminancesMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32, u32),
+        {
+            LuminancesMut(f)
+        }
+
+        /// Creates a mutable event handler for target_primaries events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_primaries_mut<F>(f: F) -> TargetPrimariesMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            TargetPrimariesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for target_primaries events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_primaries_mut_with_data<T, F>(f: F) -> TargetPrimariesMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            TargetPrimariesMut(f)
+        }
+
+        /// Creates a mutable event handler for target_luminance events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_luminance_mut<F>(f: F) -> TargetLuminanceMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, u32, u32),
+        {
+            TargetLuminanceMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for target_luminance events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_luminance_mut_with_data<T, F>(f: F) -> TargetLuminanceMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32),
+        {
+            TargetLuminanceMut(f)
+        }
+
+        /// Creates a mutable event handler for target_max_cll events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_max_cll_mut<F>(f: F) -> TargetMaxCllMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxCllMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for target_max_cll events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_max_cll_mut_with_data<T, F>(f: F) -> TargetMaxCllMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxCllMut(f)
+        }
+
+        /// Creates a mutable event handler for target_max_fall events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_max_fall_mut<F>(f: F) -> TargetMaxFallMut<F>
+        where
+            F: FnMut(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxFallMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for target_max_fall events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_max_fall_mut_with_data<T, F>(f: F) -> TargetMaxFallMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            TargetMaxFallMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                done: Ignore,
+                icc_file: Ignore,
+                primaries: Ignore,
+                primaries_named: Ignore,
+                tf_power: Ignore,
+                tf_named: Ignore,
+                luminances: Ignore,
+                target_primaries: Ignore,
+                target_luminance: Ignore,
+                target_max_cll: Ignore,
+                target_max_fall: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpImageDescriptionInfoV1EventHandler for Ignore {}
+    impl<T> WpImageDescriptionInfoV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpImageDescriptionInfoV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<
+        H0 = Ignore,
+        H1 = Ignore,
+        H2 = Ignore,
+        H3 = Ignore,
+        H4 = Ignore,
+        H5 = Ignore,
+        H6 = Ignore,
+        H7 = Ignore,
+        H8 = Ignore,
+        H9 = Ignore,
+        H10 = Ignore,
+    > {
+        done: H0,
+        icc_file: H1,
+        primaries: H2,
+        primaries_named: H3,
+        tf_power: H4,
+        tf_named: H5,
+        luminances: H6,
+        target_primaries: H7,
+        target_luminance: H8,
+        target_max_cll: H9,
+        target_max_fall: H10,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10> WpImageDescriptionInfoV1EventHandler
+        for Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+    where
+        H0: WpImageDescriptionInfoV1EventHandler,
+        H1: WpImageDescriptionInfoV1EventHandler,
+        H2: WpImageDescriptionInfoV1EventHandler,
+        H3: WpImageDescriptionInfoV1EventHandler,
+        H4: WpImageDescriptionInfoV1EventHandler,
+        H5: WpImageDescriptionInfoV1EventHandler,
+        H6: WpImageDescriptionInfoV1EventHandler,
+        H7: WpImageDescriptionInfoV1EventHandler,
+        H8: WpImageDescriptionInfoV1EventHandler,
+        H9: WpImageDescriptionInfoV1EventHandler,
+        H10: WpImageDescriptionInfoV1EventHandler,
+    {
+        #[inline]
+        fn done(&self, _slf: &WpImageDescriptionInfoV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn icc_file(&self, _slf: &WpImageDescriptionInfoV1Ref, icc: OwnedFd, icc_size: u32) {
+            self.icc_file.icc_file(_slf, icc, icc_size)
+        }
+
+        #[inline]
+        fn primaries(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.primaries
+                .primaries(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+
+        #[inline]
+        fn primaries_named(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.primaries_named.primaries_named(_slf, primaries)
+        }
+
+        #[inline]
+        fn tf_power(&self, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+            self.tf_power.tf_power(_slf, eexp)
+        }
+
+        #[inline]
+        fn tf_named(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.tf_named.tf_named(_slf, tf)
+        }
+
+        #[inline]
+        fn luminances(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+            reference_lum: u32,
+        ) {
+            self.luminances
+                .luminances(_slf, min_lum, max_lum, reference_lum)
+        }
+
+        #[inline]
+        fn target_primaries(
+            &self,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.target_primaries
+                .target_primaries(_slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+
+        #[inline]
+        fn target_luminance(&self, _slf: &WpImageDescriptionInfoV1Ref, min_lum: u32, max_lum: u32) {
+            self.target_luminance
+                .target_luminance(_slf, min_lum, max_lum)
+        }
+
+        #[inline]
+        fn target_max_cll(&self, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+            self.target_max_cll.target_max_cll(_slf, max_cll)
+        }
+
+        #[inline]
+        fn target_max_fall(&self, _slf: &WpImageDescriptionInfoV1Ref, max_fall: u32) {
+            self.target_max_fall.target_max_fall(_slf, max_fall)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        WpImageDescriptionInfoV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+    where
+        H0: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H1: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H2: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H3: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H4: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H5: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H6: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H7: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H8: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H9: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+        H10: WpImageDescriptionInfoV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn icc_file(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            icc: OwnedFd,
+            icc_size: u32,
+        ) {
+            self.icc_file.icc_file(_data, _slf, icc, icc_size)
+        }
+
+        #[inline]
+        fn primaries(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.primaries
+                .primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+
+        #[inline]
+        fn primaries_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            primaries: WpColorManagerV1Primaries,
+        ) {
+            self.primaries_named.primaries_named(_data, _slf, primaries)
+        }
+
+        #[inline]
+        fn tf_power(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, eexp: u32) {
+            self.tf_power.tf_power(_data, _slf, eexp)
+        }
+
+        #[inline]
+        fn tf_named(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            tf: WpColorManagerV1TransferFunction,
+        ) {
+            self.tf_named.tf_named(_data, _slf, tf)
+        }
+
+        #[inline]
+        fn luminances(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+            reference_lum: u32,
+        ) {
+            self.luminances
+                .luminances(_data, _slf, min_lum, max_lum, reference_lum)
+        }
+
+        #[inline]
+        fn target_primaries(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            r_x: i32,
+            r_y: i32,
+            g_x: i32,
+            g_y: i32,
+            b_x: i32,
+            b_y: i32,
+            w_x: i32,
+            w_y: i32,
+        ) {
+            self.target_primaries
+                .target_primaries(_data, _slf, r_x, r_y, g_x, g_y, b_x, b_y, w_x, w_y)
+        }
+
+        #[inline]
+        fn target_luminance(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            min_lum: u32,
+            max_lum: u32,
+        ) {
+            self.target_luminance
+                .target_luminance(_data, _slf, min_lum, max_lum)
+        }
+
+        #[inline]
+        fn target_max_cll(&self, _data: &mut T, _slf: &WpImageDescriptionInfoV1Ref, max_cll: u32) {
+            self.target_max_cll.target_max_cll(_data, _slf, max_cll)
+        }
+
+        #[inline]
+        fn target_max_fall(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionInfoV1Ref,
+            max_fall: u32,
+        ) {
+            self.target_max_fall.target_max_fall(_data, _slf, max_fall)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+    {
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<Done<F>, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref),
+        {
+            Handler {
+                done: Done(f),
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<Done<F>, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref),
+        {
+            Handler {
+                done: Done(f),
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for icc_file events.
+        #[allow(dead_code)]
+        pub fn on_icc_file<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, IccFile<F>, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: IccFile(f),
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for icc_file events.
+        #[allow(dead_code)]
+        pub fn on_icc_file_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, IccFile<F>, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, OwnedFd, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: IccFile(f),
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for primaries events.
+        #[allow(dead_code)]
+        pub fn on_primaries<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, Primaries<F>, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: Primaries(f),
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for primaries events.
+        #[allow(dead_code)]
+        pub fn on_primaries_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, Primaries<F>, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: Primaries(f),
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for primaries_named events.
+        #[allow(dead_code)]
+        pub fn on_primaries_named<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, PrimariesNamed<F>, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: PrimariesNamed(f),
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for primaries_named events.
+        #[allow(dead_code)]
+        pub fn on_primaries_named_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, PrimariesNamed<F>, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1Primaries),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: PrimariesNamed(f),
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for tf_power events.
+        #[allow(dead_code)]
+        pub fn on_tf_power<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, TfPower<F>, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: TfPower(f),
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tf_power events.
+        #[allow(dead_code)]
+        pub fn on_tf_power_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, TfPower<F>, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: TfPower(f),
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for tf_named events.
+        #[allow(dead_code)]
+        pub fn on_tf_named<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, TfNamed<F>, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: TfNamed(f),
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tf_named events.
+        #[allow(dead_code)]
+        pub fn on_tf_named_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, TfNamed<F>, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, WpColorManagerV1TransferFunction),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: TfNamed(f),
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for luminances events.
+        #[allow(dead_code)]
+        pub fn on_luminances<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, Luminances<F>, H7, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32, u32, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: Luminances(f),
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for luminances events.
+        #[allow(dead_code)]
+        pub fn on_luminances_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, Luminances<F>, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: Luminances(f),
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for target_primaries events.
+        #[allow(dead_code)]
+        pub fn on_target_primaries<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, TargetPrimaries<F>, H8, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: TargetPrimaries(f),
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for target_primaries events.
+        #[allow(dead_code)]
+        pub fn on_target_primaries_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, TargetPrimaries<F>, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, i32, i32, i32, i32, i32, i32, i32, i32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: TargetPrimaries(f),
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for target_luminance events.
+        #[allow(dead_code)]
+        pub fn on_target_luminance<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, TargetLuminance<F>, H9, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: TargetLuminance(f),
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for target_luminance events.
+        #[allow(dead_code)]
+        pub fn on_target_luminance_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, TargetLuminance<F>, H9, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: TargetLuminance(f),
+                target_max_cll: self.target_max_cll,
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for target_max_cll events.
+        #[allow(dead_code)]
+        pub fn on_target_max_cll<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, TargetMaxCll<F>, H10>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: TargetMaxCll(f),
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for target_max_cll events.
+        #[allow(dead_code)]
+        pub fn on_target_max_cll_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, TargetMaxCll<F>, H10>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: TargetMaxCll(f),
+                target_max_fall: self.target_max_fall,
+            }
+        }
+
+        /// Sets the closure for target_max_fall events.
+        #[allow(dead_code)]
+        pub fn on_target_max_fall<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, TargetMaxFall<F>>
+        where
+            F: Fn(&WpImageDescriptionInfoV1Ref, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: TargetMaxFall(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for target_max_fall events.
+        #[allow(dead_code)]
+        pub fn on_target_max_fall_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, TargetMaxFall<F>>
+        where
+            F: Fn(&mut T, &WpImageDescriptionInfoV1Ref, u32),
+        {
+            Handler {
+                done: self.done,
+                icc_file: self.icc_file,
+                primaries: self.primaries,
+                primaries_named: self.primaries_named,
+                tf_power: self.tf_power,
+                tf_named: self.tf_named,
+                luminances: self.luminances,
+                target_primaries: self.target_primaries,
+                target_luminance: self.target_luminance,
+                target_max_cll: self.target_max_cll,
+                target_max_fall: TargetMaxFall(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Reference to an image description
+//!
+//! This object is a reference to an image description. This interface is
+//! frozen at version 1 to allow other protocols to create
+//! wp_image_description_v1 objects.
+//!
+//! The wp_color_manager_v1.get_image_description request can be used to
+//! retrieve the underlying image description.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_image_description_reference_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_image_description_reference_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_image_description_reference_v1",
+    protocol: "color_management_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned wp_image_description_reference_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionReferenceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_image_description_reference_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionReferenceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpImageDescriptionReferenceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpImageDescriptionReferenceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpImageDescriptionReferenceV1 {
+    const INTERFACE: &'static str = "wp_image_description_reference_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpImageDescriptionReferenceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpImageDescriptionReferenceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpImageDescriptionReferenceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpImageDescriptionReferenceV1Ref {
+    type Owned = WpImageDescriptionReferenceV1;
+}
+
+impl Deref for WpImageDescriptionReferenceV1 {
+    type Target = WpImageDescriptionReferenceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpImageDescriptionReferenceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_reference_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpImageDescriptionReferenceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_reference_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpImageDescriptionReferenceV1Ref> for WpImageDescriptionReferenceV1 {
+    fn eq(&self, other: &WpImageDescriptionReferenceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpImageDescriptionReferenceV1> for WpImageDescriptionReferenceV1Ref {
+    fn eq(&self, other: &WpImageDescriptionReferenceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionReferenceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the reference
+    ///
+    /// Destroy this object. This has no effect on the referenced image
+    /// description.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpImageDescriptionReferenceV1] proxies.
+#[allow(dead_code)]
+pub trait WpImageDescriptionReferenceV1EventHandler {}
+
+impl WpImageDescriptionReferenceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpImageDescriptionReferenceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionReferenceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpImageDescriptionReferenceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpImageDescriptionReferenceV1] proxies.
+///
+/// This trait is the same as [WpImageDescriptionReferenceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionReferenceV1EventHandlerMut {}
+
+/// A mutable event handler for [WpImageDescriptionReferenceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionReferenceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionReferenceV1EventHandlerMutWithData<T> {}
+
+impl<H> WpImageDescriptionReferenceV1EventHandler for MutEventHandler<H> where
+    H: WpImageDescriptionReferenceV1EventHandlerMut
+{
+}
+
+impl<T, H> WpImageDescriptionReferenceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpImageDescriptionReferenceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpImageDescriptionReferenceV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionReferenceV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> WpImageDescriptionReferenceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionReferenceV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpImageDescriptionReferenceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_image_description_reference_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpImageDescriptionReferenceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_image_description_reference_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpImageDescriptionReferenceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpImageDescriptionReferenceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpImageDescriptionReferenceV1 {}
+}
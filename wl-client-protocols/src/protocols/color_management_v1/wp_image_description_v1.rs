@@ -0,0 +1,1519 @@
+//! Colorimetric image description
+//!
+//! An image description carries information about the pixel color encoding
+//! and its intended display and viewing environment. The image description is
+//! attached to a wl_surface via
+//! wp_color_management_surface_v1.set_image_description. A compositor can use
+//! this information to decode pixel values into colorimetrically meaningful
+//! quantities, which allows the compositor to transform the surface contents
+//! to become suitable for various displays and viewing environments.
+//!
+//! Note, that the wp_image_description_v1 object is not ready to be used
+//! immediately after creation. The object eventually delivers either the
+//! 'ready' or the 'failed' event, specified in all requests creating it. The
+//! object is deemed "ready" after receiving the 'ready' event.
+//!
+//! An object which is not ready is illegal to use, it can only be destroyed.
+//! Any other request in this interface shall result in the 'not_ready'
+//! protocol error. Attempts to use an object which is not ready through other
+//! interfaces shall raise protocol errors defined there.
+//!
+//! Once created and regardless of how it was created, a
+//! wp_image_description_v1 object always refers to one fixed image
+//! description. It cannot change after creation.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_image_description_v1".as_ptr(),
+    version: 2,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_information".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpImageDescriptionInfoV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 3,
+    events: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"failed".as_ptr(),
+                signature: c"us".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"ready".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"ready2".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_image_description_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_image_description_v1",
+    protocol: "color_management_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_information",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "information",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_image_description_info_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "failed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "cause",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("cause"),
+                },
+                info::ArgInfo {
+                    name: "msg",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "ready",
+            since: 1,
+            deprecated_since: Some(2),
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "identity",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "ready2",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "identity_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "identity_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "not_ready",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "no_information",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "cause",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "low_version",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "unsupported",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "operating_system",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "no_output",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned wp_image_description_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_image_description_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpImageDescriptionV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpImageDescriptionV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpImageDescriptionV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpImageDescriptionV1 {
+    const INTERFACE: &'static str = "wp_image_description_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpImageDescriptionV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpImageDescriptionV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpImageDescriptionV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpImageDescriptionV1Ref {
+    type Owned = WpImageDescriptionV1;
+}
+
+impl Deref for WpImageDescriptionV1 {
+    type Target = WpImageDescriptionV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpImageDescriptionV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpImageDescriptionV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_image_description_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpImageDescriptionV1Ref> for WpImageDescriptionV1 {
+    fn eq(&self, other: &WpImageDescriptionV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpImageDescriptionV1> for WpImageDescriptionV1Ref {
+    fn eq(&self, other: &WpImageDescriptionV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the image description
+    ///
+    /// Destroy this object. It is safe to destroy an object which is not ready.
+    ///
+    /// Destroying a wp_image_description_v1 object has no side-effects, not
+    /// even if a wp_color_management_surface_v1.set_image_description has not
+    /// yet been followed by a wl_surface.commit.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_information request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_INFORMATION__SINCE: u32 = 1;
+
+    /// get information about the image description
+    ///
+    /// Creates a wp_image_description_info_v1 object which delivers the
+    /// information that makes up the image description.
+    ///
+    /// Not all image description protocol objects allow get_information
+    /// request. Whether it is allowed or not is defined by the request that
+    /// created the object. If get_information is not allowed, the protocol
+    /// error no_information is raised.
+    #[inline]
+    pub fn get_information(&self) -> WpImageDescriptionInfoV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpImageDescriptionInfoV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionInfoV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpImageDescriptionV1Ref {
+    /// get information about the image description
+    ///
+    /// Creates a wp_image_description_info_v1 object which delivers the
+    /// information that makes up the image description.
+    ///
+    /// Not all image description protocol objects allow get_information
+    /// request. Whether it is allowed or not is defined by the request that
+    /// created the object. If get_information is not allowed, the protocol
+    /// error no_information is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_information(&self, _queue: &Queue) -> WpImageDescriptionInfoV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpImageDescriptionInfoV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpImageDescriptionInfoV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl WpImageDescriptionV1 {
+    /// Since when the failed event is available.
+    #[allow(dead_code)]
+    pub const EVT__FAILED__SINCE: u32 = 1;
+
+    /// Since when the ready event is available.
+    #[allow(dead_code)]
+    pub const EVT__READY__SINCE: u32 = 1;
+
+    /// Since when the ready event is deprecated.
+    #[allow(dead_code)]
+    pub const EVT__READY__DEPRECATED_SINCE: u32 = 2;
+
+    /// Since when the ready2 event is available.
+    #[allow(dead_code)]
+    pub const EVT__READY2__SINCE: u32 = 2;
+}
+
+/// An event handler for [WpImageDescriptionV1] proxies.
+#[allow(dead_code)]
+pub trait WpImageDescriptionV1EventHandler {
+    /// graceful error on creating the image description
+    ///
+    /// If creating a wp_image_description_v1 object fails for a reason that is
+    /// not defined as a protocol error, this event is sent.
+    ///
+    /// The requests that create image description objects define whether and
+    /// when this can occur. Only such creation requests can trigger this event.
+    /// This event cannot be triggered after the image description was
+    /// successfully formed.
+    ///
+    /// Once this event has been sent, the wp_image_description_v1 object will
+    /// never become ready and it can only be destroyed.
+    ///
+    /// # Arguments
+    ///
+    /// - `cause`: generic reason
+    /// - `msg`: ad hoc human-readable explanation
+    #[inline]
+    fn failed(&self, _slf: &WpImageDescriptionV1Ref, cause: WpImageDescriptionV1Cause, msg: &str) {
+        let _ = cause;
+        let _ = msg;
+    }
+
+    /// the object is ready to be used (32-bit)
+    ///
+    /// Starting from interface version 2, the 'ready2' event is sent instead
+    /// of this event.
+    ///
+    /// For the definition of this event, see the 'ready2' event. The
+    /// difference to this event is as follows.
+    ///
+    /// The id number is valid only as long as the protocol object is alive. If
+    /// all protocol objects referring to the same image description record are
+    /// destroyed, the id number may be recycled for a different image
+    /// description record.
+    ///
+    /// # Arguments
+    ///
+    /// - `identity`: the 32-bit image description id number
+    #[inline]
+    fn ready(&self, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        let _ = identity;
+    }
+
+    /// the object is ready to be used
+    ///
+    /// Once this event has been sent, the wp_image_description_v1 object is
+    /// deemed "ready". Ready objects can be used to send requests and can be
+    /// used through other interfaces.
+    ///
+    /// Every ready wp_image_description_v1 protocol object refers to an
+    /// underlying image description record in the compositor. Multiple protocol
+    /// objects may end up referring to the same record. Clients may identify
+    /// these "copies" by comparing their id numbers: if the numbers from two
+    /// protocol objects are identical, the protocol objects refer to the same
+    /// image description record. Two different image description records
+    /// cannot have the same id number simultaneously. The id number does not
+    /// change during the lifetime of the image description record.
+    ///
+    /// Image description id number is not a protocol object id. Zero is
+    /// reserved as an invalid id number. It shall not be possible for a client
+    /// to refer to an image description by its id number in protocol. The id
+    /// numbers might not be portable between Wayland connections. A compositor
+    /// shall not send an invalid id number.
+    ///
+    /// Compositors must not recycle image description id numbers.
+    ///
+    /// This identity allows clients to de-duplicate image description records
+    /// and avoid get_information request if they already have the image
+    /// description information.
+    ///
+    /// # Arguments
+    ///
+    /// - `identity_hi`: high 32 bits of the 64-bit image description id number
+    /// - `identity_lo`: low 32 bits of the 64-bit image description id number
+    #[inline]
+    fn ready2(&self, _slf: &WpImageDescriptionV1Ref, identity_hi: u32, identity_lo: u32) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+impl WpImageDescriptionV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpImageDescriptionV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpImageDescriptionV1EventHandlerWithData<T> {
+    /// graceful error on creating the image description
+    ///
+    /// If creating a wp_image_description_v1 object fails for a reason that is
+    /// not defined as a protocol error, this event is sent.
+    ///
+    /// The requests that create image description objects define whether and
+    /// when this can occur. Only such creation requests can trigger this event.
+    /// This event cannot be triggered after the image description was
+    /// successfully formed.
+    ///
+    /// Once this event has been sent, the wp_image_description_v1 object will
+    /// never become ready and it can only be destroyed.
+    ///
+    /// # Arguments
+    ///
+    /// - `cause`: generic reason
+    /// - `msg`: ad hoc human-readable explanation
+    #[inline]
+    fn failed(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        cause: WpImageDescriptionV1Cause,
+        msg: &str,
+    ) {
+        let _ = cause;
+        let _ = msg;
+    }
+
+    /// the object is ready to be used (32-bit)
+    ///
+    /// Starting from interface version 2, the 'ready2' event is sent instead
+    /// of this event.
+    ///
+    /// For the definition of this event, see the 'ready2' event. The
+    /// difference to this event is as follows.
+    ///
+    /// The id number is valid only as long as the protocol object is alive. If
+    /// all protocol objects referring to the same image description record are
+    /// destroyed, the id number may be recycled for a different image
+    /// description record.
+    ///
+    /// # Arguments
+    ///
+    /// - `identity`: the 32-bit image description id number
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        let _ = identity;
+    }
+
+    /// the object is ready to be used
+    ///
+    /// Once this event has been sent, the wp_image_description_v1 object is
+    /// deemed "ready". Ready objects can be used to send requests and can be
+    /// used through other interfaces.
+    ///
+    /// Every ready wp_image_description_v1 protocol object refers to an
+    /// underlying image description record in the compositor. Multiple protocol
+    /// objects may end up referring to the same record. Clients may identify
+    /// these "copies" by comparing their id numbers: if the numbers from two
+    /// protocol objects are identical, the protocol objects refer to the same
+    /// image description record. Two different image description records
+    /// cannot have the same id number simultaneously. The id number does not
+    /// change during the lifetime of the image description record.
+    ///
+    /// Image description id number is not a protocol object id. Zero is
+    /// reserved as an invalid id number. It shall not be possible for a client
+    /// to refer to an image description by its id number in protocol. The id
+    /// numbers might not be portable between Wayland connections. A compositor
+    /// shall not send an invalid id number.
+    ///
+    /// Compositors must not recycle image description id numbers.
+    ///
+    /// This identity allows clients to de-duplicate image description records
+    /// and avoid get_information request if they already have the image
+    /// description information.
+    ///
+    /// # Arguments
+    ///
+    /// - `identity_hi`: high 32 bits of the 64-bit image description id number
+    /// - `identity_lo`: low 32 bits of the 64-bit image description id number
+    #[inline]
+    fn ready2(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+/// A mutable event handler for [WpImageDescriptionV1] proxies.
+///
+/// This trait is the same as [WpImageDescriptionV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionV1EventHandlerMut {
+    /// See [WpImageDescriptionV1EventHandler::failed].
+    #[inline]
+    fn failed(
+        &mut self,
+        _slf: &WpImageDescriptionV1Ref,
+        cause: WpImageDescriptionV1Cause,
+        msg: &str,
+    ) {
+        let _ = cause;
+        let _ = msg;
+    }
+
+    /// See [WpImageDescriptionV1EventHandler::ready].
+    #[inline]
+    fn ready(&mut self, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        let _ = identity;
+    }
+
+    /// See [WpImageDescriptionV1EventHandler::ready2].
+    #[inline]
+    fn ready2(&mut self, _slf: &WpImageDescriptionV1Ref, identity_hi: u32, identity_lo: u32) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+/// A mutable event handler for [WpImageDescriptionV1] proxies with mutable data.
+///
+/// This trait is the same as [WpImageDescriptionV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpImageDescriptionV1EventHandlerMutWithData<T> {
+    /// See [WpImageDescriptionV1EventHandler::failed].
+    #[inline]
+    fn failed(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        cause: WpImageDescriptionV1Cause,
+        msg: &str,
+    ) {
+        let _ = cause;
+        let _ = msg;
+    }
+
+    /// See [WpImageDescriptionV1EventHandler::ready].
+    #[inline]
+    fn ready(&mut self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        let _ = identity;
+    }
+
+    /// See [WpImageDescriptionV1EventHandler::ready2].
+    #[inline]
+    fn ready2(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _ = identity_hi;
+        let _ = identity_lo;
+    }
+}
+
+impl<H> WpImageDescriptionV1EventHandler for MutEventHandler<H>
+where
+    H: WpImageDescriptionV1EventHandlerMut,
+{
+    #[inline]
+    fn failed(&self, _slf: &WpImageDescriptionV1Ref, cause: WpImageDescriptionV1Cause, msg: &str) {
+        self.with(|h| h.failed(_slf, cause, msg))
+    }
+
+    #[inline]
+    fn ready(&self, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        self.with(|h| h.ready(_slf, identity))
+    }
+
+    #[inline]
+    fn ready2(&self, _slf: &WpImageDescriptionV1Ref, identity_hi: u32, identity_lo: u32) {
+        self.with(|h| h.ready2(_slf, identity_hi, identity_lo))
+    }
+}
+
+impl<T, H> WpImageDescriptionV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpImageDescriptionV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn failed(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        cause: WpImageDescriptionV1Cause,
+        msg: &str,
+    ) {
+        self.with(|h| h.failed(_data, _slf, cause, msg))
+    }
+
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        self.with(|h| h.ready(_data, _slf, identity))
+    }
+
+    #[inline]
+    fn ready2(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        self.with(|h| h.ready2(_data, _slf, identity_hi, identity_lo))
+    }
+}
+
+impl<T, U, H> WpImageDescriptionV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn failed(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        cause: WpImageDescriptionV1Cause,
+        msg: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler().failed(_data, _slf, cause, msg)
+    }
+
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        let _data = self.project(_data);
+        self.handler().ready(_data, _slf, identity)
+    }
+
+    #[inline]
+    fn ready2(
+        &self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().ready2(_data, _slf, identity_hi, identity_lo)
+    }
+}
+
+impl<T, U, H> WpImageDescriptionV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpImageDescriptionV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn failed(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        cause: WpImageDescriptionV1Cause,
+        msg: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().failed(_data, _slf, cause, msg)
+    }
+
+    #[inline]
+    fn ready(&mut self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().ready(_data, _slf, identity)
+    }
+
+    #[inline]
+    fn ready2(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpImageDescriptionV1Ref,
+        identity_hi: u32,
+        identity_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .ready2(_data, _slf, identity_hi, identity_lo)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpImageDescriptionV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpImageDescriptionV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpImageDescriptionV1Cause(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 =
+                    unsafe { convert_string_arg("wp_image_description_v1", "msg", args[1].s) };
+                self.0.failed(slf, arg0, arg1);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.ready(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.ready2(slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("wp_image_description_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpImageDescriptionV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpImageDescriptionV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpImageDescriptionV1Cause(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 =
+                    unsafe { convert_string_arg("wp_image_description_v1", "msg", args[1].s) };
+                self.0.failed(data, slf, arg0, arg1);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.ready(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.ready2(data, slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("wp_image_description_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpImageDescriptionV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpImageDescriptionV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpImageDescriptionV1 {
+    /// Since when the error.not_ready enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NOT_READY__SINCE: u32 = 1;
+    /// Since when the error.no_information enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_INFORMATION__SINCE: u32 = 1;
+
+    /// Since when the cause.low_version enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAUSE_LOW_VERSION__SINCE: u32 = 1;
+    /// Since when the cause.unsupported enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAUSE_UNSUPPORTED__SINCE: u32 = 1;
+    /// Since when the cause.operating_system enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAUSE_OPERATING_SYSTEM__SINCE: u32 = 1;
+    /// Since when the cause.no_output enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAUSE_NO_OUTPUT__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpImageDescriptionV1Error(pub u32);
+
+impl WpImageDescriptionV1Error {
+    /// attempted to use an object which is not ready
+    #[allow(dead_code)]
+    pub const NOT_READY: Self = Self(0);
+
+    /// get_information not allowed
+    #[allow(dead_code)]
+    pub const NO_INFORMATION: Self = Self(1);
+}
+
+impl Debug for WpImageDescriptionV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NOT_READY => "NOT_READY",
+            Self::NO_INFORMATION => "NO_INFORMATION",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// generic reason for failure
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpImageDescriptionV1Cause(pub u32);
+
+impl WpImageDescriptionV1Cause {
+    /// interface version too low
+    #[allow(dead_code)]
+    pub const LOW_VERSION: Self = Self(0);
+
+    /// unsupported image description data
+    #[allow(dead_code)]
+    pub const UNSUPPORTED: Self = Self(1);
+
+    /// error independent of the client
+    #[allow(dead_code)]
+    pub const OPERATING_SYSTEM: Self = Self(2);
+
+    /// the relevant output no longer exists
+    #[allow(dead_code)]
+    pub const NO_OUTPUT: Self = Self(3);
+}
+
+impl Debug for WpImageDescriptionV1Cause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::LOW_VERSION => "LOW_VERSION",
+            Self::UNSUPPORTED => "UNSUPPORTED",
+            Self::OPERATING_SYSTEM => "OPERATING_SYSTEM",
+            Self::NO_OUTPUT => "NO_OUTPUT",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for failed events.
+    pub struct Failed<F>(F);
+    impl<F> WpImageDescriptionV1EventHandler for Failed<F>
+    where
+        F: Fn(&WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+    {
+        #[inline]
+        fn failed(
+            &self,
+            _slf: &WpImageDescriptionV1Ref,
+            cause: WpImageDescriptionV1Cause,
+            msg: &str,
+        ) {
+            self.0(_slf, cause, msg)
+        }
+    }
+    impl<T, F> WpImageDescriptionV1EventHandlerWithData<T> for Failed<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+    {
+        #[inline]
+        fn failed(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionV1Ref,
+            cause: WpImageDescriptionV1Cause,
+            msg: &str,
+        ) {
+            self.0(_data, _slf, cause, msg)
+        }
+    }
+
+    /// Event handler for ready events.
+    pub struct Ready<F>(F);
+    impl<F> WpImageDescriptionV1EventHandler for Ready<F>
+    where
+        F: Fn(&WpImageDescriptionV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&self, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+            self.0(_slf, identity)
+        }
+    }
+    impl<T, F> WpImageDescriptionV1EventHandlerWithData<T> for Ready<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+            self.0(_data, _slf, identity)
+        }
+    }
+
+    /// Event handler for ready2 events.
+    pub struct Ready2<F>(F);
+    impl<F> WpImageDescriptionV1EventHandler for Ready2<F>
+    where
+        F: Fn(&WpImageDescriptionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn ready2(&self, _slf: &WpImageDescriptionV1Ref, identity_hi: u32, identity_lo: u32) {
+            self.0(_slf, identity_hi, identity_lo)
+        }
+    }
+    impl<T, F> WpImageDescriptionV1EventHandlerWithData<T> for Ready2<F>
+    where
+        F: Fn(&mut T, &WpImageDescriptionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn ready2(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.0(_data, _slf, identity_hi, identity_lo)
+        }
+    }
+
+    /// Mutable event handler for failed events.
+    pub struct FailedMut<F>(F);
+    impl<F> WpImageDescriptionV1EventHandlerMut for FailedMut<F>
+    where
+        F: FnMut(&WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+    {
+        #[inline]
+        fn failed(
+            &mut self,
+            _slf: &WpImageDescriptionV1Ref,
+            cause: WpImageDescriptionV1Cause,
+            msg: &str,
+        ) {
+            self.0(_slf, cause, msg)
+        }
+    }
+    impl<T, F> WpImageDescriptionV1EventHandlerMutWithData<T> for FailedMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+    {
+        #[inline]
+        fn failed(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionV1Ref,
+            cause: WpImageDescriptionV1Cause,
+            msg: &str,
+        ) {
+            self.0(_data, _slf, cause, msg)
+        }
+    }
+
+    /// Mutable event handler for ready events.
+    pub struct ReadyMut<F>(F);
+    impl<F> WpImageDescriptionV1EventHandlerMut for ReadyMut<F>
+    where
+        F: FnMut(&WpImageDescriptionV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&mut self, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+            self.0(_slf, identity)
+        }
+    }
+    impl<T, F> WpImageDescriptionV1EventHandlerMutWithData<T> for ReadyMut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&mut self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+            self.0(_data, _slf, identity)
+        }
+    }
+
+    /// Mutable event handler for ready2 events.
+    pub struct Ready2Mut<F>(F);
+    impl<F> WpImageDescriptionV1EventHandlerMut for Ready2Mut<F>
+    where
+        F: FnMut(&WpImageDescriptionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn ready2(&mut self, _slf: &WpImageDescriptionV1Ref, identity_hi: u32, identity_lo: u32) {
+            self.0(_slf, identity_hi, identity_lo)
+        }
+    }
+    impl<T, F> WpImageDescriptionV1EventHandlerMutWithData<T> for Ready2Mut<F>
+    where
+        F: FnMut(&mut T, &WpImageDescriptionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn ready2(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.0(_data, _slf, identity_hi, identity_lo)
+        }
+    }
+
+    impl WpImageDescriptionV1 {
+        /// Creates an event handler for failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_failed<F>(f: F) -> Failed<F>
+        where
+            F: Fn(&WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+        {
+            Failed(f)
+        }
+
+        /// Creates an event handler with mutable data for failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_failed_with_data<T, F>(f: F) -> Failed<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+        {
+            Failed(f)
+        }
+
+        /// Creates an event handler for ready events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready<F>(f: F) -> Ready<F>
+        where
+            F: Fn(&WpImageDescriptionV1Ref, u32),
+        {
+            Ready(f)
+        }
+
+        /// Creates an event handler with mutable data for ready events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready_with_data<T, F>(f: F) -> Ready<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionV1Ref, u32),
+        {
+            Ready(f)
+        }
+
+        /// Creates an event handler for ready2 events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready2<F>(f: F) -> Ready2<F>
+        where
+            F: Fn(&WpImageDescriptionV1Ref, u32, u32),
+        {
+            Ready2(f)
+        }
+
+        /// Creates an event handler with mutable data for ready2 events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready2_with_data<T, F>(f: F) -> Ready2<F>
+        where
+            F: Fn(&mut T, &WpImageDescriptionV1Ref, u32, u32),
+        {
+            Ready2(f)
+        }
+
+        /// Creates a mutable event handler for failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_failed_mut<F>(f: F) -> FailedMut<F>
+        where
+            F: FnMut(&WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+        {
+            FailedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_failed_mut_with_data<T, F>(f: F) -> FailedMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+        {
+            FailedMut(f)
+        }
+
+        /// Creates a mutable event handler for ready events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready_mut<F>(f: F) -> ReadyMut<F>
+        where
+            F: FnMut(&WpImageDescriptionV1Ref, u32),
+        {
+            ReadyMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ready events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready_mut_with_data<T, F>(f: F) -> ReadyMut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionV1Ref, u32),
+        {
+            ReadyMut(f)
+        }
+
+        /// Creates a mutable event handler for ready2 events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready2_mut<F>(f: F) -> Ready2Mut<F>
+        where
+            F: FnMut(&WpImageDescriptionV1Ref, u32, u32),
+        {
+            Ready2Mut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ready2 events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready2_mut_with_data<T, F>(f: F) -> Ready2Mut<F>
+        where
+            F: FnMut(&mut T, &WpImageDescriptionV1Ref, u32, u32),
+        {
+            Ready2Mut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                failed: Ignore,
+                ready: Ignore,
+                ready2: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpImageDescriptionV1EventHandler for Ignore {}
+    impl<T> WpImageDescriptionV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpImageDescriptionV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        failed: H0,
+        ready: H1,
+        ready2: H2,
+    }
+
+    impl<H0, H1, H2> WpImageDescriptionV1EventHandler for Handler<H0, H1, H2>
+    where
+        H0: WpImageDescriptionV1EventHandler,
+        H1: WpImageDescriptionV1EventHandler,
+        H2: WpImageDescriptionV1EventHandler,
+    {
+        #[inline]
+        fn failed(
+            &self,
+            _slf: &WpImageDescriptionV1Ref,
+            cause: WpImageDescriptionV1Cause,
+            msg: &str,
+        ) {
+            self.failed.failed(_slf, cause, msg)
+        }
+
+        #[inline]
+        fn ready(&self, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+            self.ready.ready(_slf, identity)
+        }
+
+        #[inline]
+        fn ready2(&self, _slf: &WpImageDescriptionV1Ref, identity_hi: u32, identity_lo: u32) {
+            self.ready2.ready2(_slf, identity_hi, identity_lo)
+        }
+    }
+
+    impl<T, H0, H1, H2> WpImageDescriptionV1EventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: WpImageDescriptionV1EventHandlerWithData<T>,
+        H1: WpImageDescriptionV1EventHandlerWithData<T>,
+        H2: WpImageDescriptionV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn failed(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionV1Ref,
+            cause: WpImageDescriptionV1Cause,
+            msg: &str,
+        ) {
+            self.failed.failed(_data, _slf, cause, msg)
+        }
+
+        #[inline]
+        fn ready(&self, _data: &mut T, _slf: &WpImageDescriptionV1Ref, identity: u32) {
+            self.ready.ready(_data, _slf, identity)
+        }
+
+        #[inline]
+        fn ready2(
+            &self,
+            _data: &mut T,
+            _slf: &WpImageDescriptionV1Ref,
+            identity_hi: u32,
+            identity_lo: u32,
+        ) {
+            self.ready2.ready2(_data, _slf, identity_hi, identity_lo)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for failed events.
+        #[allow(dead_code)]
+        pub fn on_failed<F>(self, f: F) -> Handler<Failed<F>, H1, H2>
+        where
+            F: Fn(&WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+        {
+            Handler {
+                failed: Failed(f),
+                ready: self.ready,
+                ready2: self.ready2,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for failed events.
+        #[allow(dead_code)]
+        pub fn on_failed_with_data<T, F>(self, f: F) -> Handler<Failed<F>, H1, H2>
+        where
+            F: Fn(&mut T, &WpImageDescriptionV1Ref, WpImageDescriptionV1Cause, &str),
+        {
+            Handler {
+                failed: Failed(f),
+                ready: self.ready,
+                ready2: self.ready2,
+            }
+        }
+
+        /// Sets the closure for ready events.
+        #[allow(dead_code)]
+        pub fn on_ready<F>(self, f: F) -> Handler<H0, Ready<F>, H2>
+        where
+            F: Fn(&WpImageDescriptionV1Ref, u32),
+        {
+            Handler {
+                failed: self.failed,
+                ready: Ready(f),
+                ready2: self.ready2,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for ready events.
+        #[allow(dead_code)]
+        pub fn on_ready_with_data<T, F>(self, f: F) -> Handler<H0, Ready<F>, H2>
+        where
+            F: Fn(&mut T, &WpImageDescriptionV1Ref, u32),
+        {
+            Handler {
+                failed: self.failed,
+                ready: Ready(f),
+                ready2: self.ready2,
+            }
+        }
+
+        /// Sets the closure for ready2 events.
+        #[allow(dead_code)]
+        pub fn on_ready2<F>(self, f: F) -> Handler<H0, H1, Ready2<F>>
+        where
+            F: Fn(&WpImageDescriptionV1Ref, u32, u32),
+        {
+            Handler {
+                failed: self.failed,
+                ready: self.ready,
+                ready2: Ready2(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for ready2 events.
+        #[allow(dead_code)]
+        pub fn on_ready2_with_data<T, F>(self, f: F) -> Handler<H0, H1, Ready2<F>>
+        where
+            F: Fn(&mut T, &WpImageDescriptionV1Ref, u32, u32),
+        {
+            Handler {
+                failed: self.failed,
+                ready: self.ready,
+                ready2: Ready2(f),
+            }
+        }
+    }
+}
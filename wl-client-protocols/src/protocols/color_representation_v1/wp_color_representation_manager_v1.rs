@@ -0,0 +1,1424 @@
+//! color representation manager singleton
+//!
+//! A singleton global interface used for getting color representation
+//! extensions for wl_surface. The extension interfaces allow setting the
+//! color representation of surfaces.
+//!
+//! Compositors should never remove this global.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_color_representation_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpColorRepresentationSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 3,
+    events: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"supported_alpha_mode".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"supported_coefficients_and_ranges".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_color_representation_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_color_representation_manager_v1",
+    protocol: "color_representation_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_color_representation_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "supported_alpha_mode",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "alpha_mode",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wp_color_representation_surface_v1.alpha_mode"),
+            }],
+        },
+        info::MessageInfo {
+            name: "supported_coefficients_and_ranges",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "coefficients",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("wp_color_representation_surface_v1.coefficients"),
+                },
+                info::ArgInfo {
+                    name: "range",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("wp_color_representation_surface_v1.range"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "surface_exists",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_color_representation_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorRepresentationManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_color_representation_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorRepresentationManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpColorRepresentationManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpColorRepresentationManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpColorRepresentationManagerV1 {
+    const INTERFACE: &'static str = "wp_color_representation_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpColorRepresentationManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpColorRepresentationManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpColorRepresentationManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpColorRepresentationManagerV1Ref {
+    type Owned = WpColorRepresentationManagerV1;
+}
+
+impl Deref for WpColorRepresentationManagerV1 {
+    type Target = WpColorRepresentationManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpColorRepresentationManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_representation_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpColorRepresentationManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_representation_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpColorRepresentationManagerV1Ref> for WpColorRepresentationManagerV1 {
+    fn eq(&self, other: &WpColorRepresentationManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpColorRepresentationManagerV1> for WpColorRepresentationManagerV1Ref {
+    fn eq(&self, other: &WpColorRepresentationManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorRepresentationManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager
+    ///
+    /// Destroy the wp_color_representation_manager_v1 object. This does not
+    /// affect any other objects in any way.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE__SINCE: u32 = 1;
+
+    /// create a color representation interface for a wl_surface
+    ///
+    /// If a wp_color_representation_surface_v1 object already exists for the
+    /// given wl_surface, the protocol error surface_exists is raised.
+    ///
+    /// This creates a new color wp_color_representation_surface_v1 object for
+    /// the given wl_surface.
+    ///
+    /// See the wp_color_representation_surface_v1 interface for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface(&self, surface: &WlSurfaceRef) -> WpColorRepresentationSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpColorRepresentationSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorRepresentationSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorRepresentationManagerV1Ref {
+    /// create a color representation interface for a wl_surface
+    ///
+    /// If a wp_color_representation_surface_v1 object already exists for the
+    /// given wl_surface, the protocol error surface_exists is raised.
+    ///
+    /// This creates a new color wp_color_representation_surface_v1 object for
+    /// the given wl_surface.
+    ///
+    /// See the wp_color_representation_surface_v1 interface for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> WpColorRepresentationSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpColorRepresentationSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpColorRepresentationSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl WpColorRepresentationManagerV1 {
+    /// Since when the supported_alpha_mode event is available.
+    #[allow(dead_code)]
+    pub const EVT__SUPPORTED_ALPHA_MODE__SINCE: u32 = 1;
+
+    /// Since when the supported_coefficients_and_ranges event is available.
+    #[allow(dead_code)]
+    pub const EVT__SUPPORTED_COEFFICIENTS_AND_RANGES__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpColorRepresentationManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpColorRepresentationManagerV1EventHandler {
+    /// supported alpha modes
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each alpha mode the compositor supports.
+    ///
+    /// For the definition of the supported values, see the
+    /// wp_color_representation_surface_v1::alpha_mode enum.
+    ///
+    /// # Arguments
+    ///
+    /// - `alpha_mode`: supported alpha mode
+    #[inline]
+    fn supported_alpha_mode(
+        &self,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        let _ = alpha_mode;
+    }
+
+    /// supported matrix coefficients and ranges
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each matrix coefficient and color range combination the compositor
+    /// supports.
+    ///
+    /// For the definition of the supported values, see the
+    /// wp_color_representation_surface_v1::coefficients and
+    /// wp_color_representation_surface_v1::range enums.
+    ///
+    /// # Arguments
+    ///
+    /// - `coefficients`: supported matrix coefficients
+    /// - `range`: full range flag
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &self,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let _ = coefficients;
+        let _ = range;
+    }
+
+    /// all features have been sent
+    ///
+    /// This event is sent when all supported features have been sent.
+    #[inline]
+    fn done(&self, _slf: &WpColorRepresentationManagerV1Ref) {}
+}
+
+impl WpColorRepresentationManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpColorRepresentationManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorRepresentationManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpColorRepresentationManagerV1EventHandlerWithData<T> {
+    /// supported alpha modes
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each alpha mode the compositor supports.
+    ///
+    /// For the definition of the supported values, see the
+    /// wp_color_representation_surface_v1::alpha_mode enum.
+    ///
+    /// # Arguments
+    ///
+    /// - `alpha_mode`: supported alpha mode
+    #[inline]
+    fn supported_alpha_mode(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        let _ = alpha_mode;
+    }
+
+    /// supported matrix coefficients and ranges
+    ///
+    /// When this object is created, it shall immediately send this event once
+    /// for each matrix coefficient and color range combination the compositor
+    /// supports.
+    ///
+    /// For the definition of the supported values, see the
+    /// wp_color_representation_surface_v1::coefficients and
+    /// wp_color_representation_surface_v1::range enums.
+    ///
+    /// # Arguments
+    ///
+    /// - `coefficients`: supported matrix coefficients
+    /// - `range`: full range flag
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let _ = coefficients;
+        let _ = range;
+    }
+
+    /// all features have been sent
+    ///
+    /// This event is sent when all supported features have been sent.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {}
+}
+
+/// A mutable event handler for [WpColorRepresentationManagerV1] proxies.
+///
+/// This trait is the same as [WpColorRepresentationManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorRepresentationManagerV1EventHandlerMut {
+    /// See [WpColorRepresentationManagerV1EventHandler::supported_alpha_mode].
+    #[inline]
+    fn supported_alpha_mode(
+        &mut self,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        let _ = alpha_mode;
+    }
+
+    /// See [WpColorRepresentationManagerV1EventHandler::supported_coefficients_and_ranges].
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &mut self,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let _ = coefficients;
+        let _ = range;
+    }
+
+    /// See [WpColorRepresentationManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &WpColorRepresentationManagerV1Ref) {}
+}
+
+/// A mutable event handler for [WpColorRepresentationManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorRepresentationManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorRepresentationManagerV1EventHandlerMutWithData<T> {
+    /// See [WpColorRepresentationManagerV1EventHandler::supported_alpha_mode].
+    #[inline]
+    fn supported_alpha_mode(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        let _ = alpha_mode;
+    }
+
+    /// See [WpColorRepresentationManagerV1EventHandler::supported_coefficients_and_ranges].
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let _ = coefficients;
+        let _ = range;
+    }
+
+    /// See [WpColorRepresentationManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {}
+}
+
+impl<H> WpColorRepresentationManagerV1EventHandler for MutEventHandler<H>
+where
+    H: WpColorRepresentationManagerV1EventHandlerMut,
+{
+    #[inline]
+    fn supported_alpha_mode(
+        &self,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        self.with(|h| h.supported_alpha_mode(_slf, alpha_mode))
+    }
+
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &self,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        self.with(|h| h.supported_coefficients_and_ranges(_slf, coefficients, range))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &WpColorRepresentationManagerV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+}
+
+impl<T, H> WpColorRepresentationManagerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpColorRepresentationManagerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn supported_alpha_mode(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        self.with(|h| h.supported_alpha_mode(_data, _slf, alpha_mode))
+    }
+
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        self.with(|h| h.supported_coefficients_and_ranges(_data, _slf, coefficients, range))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpColorRepresentationManagerV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorRepresentationManagerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn supported_alpha_mode(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        let _data = self.project(_data);
+        self.handler().supported_alpha_mode(_data, _slf, alpha_mode)
+    }
+
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .supported_coefficients_and_ranges(_data, _slf, coefficients, range)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpColorRepresentationManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorRepresentationManagerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn supported_alpha_mode(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .supported_alpha_mode(_data, _slf, alpha_mode)
+    }
+
+    #[inline]
+    fn supported_coefficients_and_ranges(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpColorRepresentationManagerV1Ref,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .supported_coefficients_and_ranges(_data, _slf, coefficients, range)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpColorRepresentationManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<WpColorRepresentationManagerV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorRepresentationSurfaceV1AlphaMode(args[0].u) };
+                self.0.supported_alpha_mode(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorRepresentationSurfaceV1Coefficients(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WpColorRepresentationSurfaceV1Range(args[1].u) };
+                self.0.supported_coefficients_and_ranges(slf, arg0, arg1);
+            }
+            2 => {
+                self.0.done(slf);
+            }
+            _ => {
+                invalid_opcode("wp_color_representation_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpColorRepresentationManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<WpColorRepresentationManagerV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorRepresentationSurfaceV1AlphaMode(args[0].u) };
+                self.0.supported_alpha_mode(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WpColorRepresentationSurfaceV1Coefficients(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WpColorRepresentationSurfaceV1Range(args[1].u) };
+                self.0
+                    .supported_coefficients_and_ranges(data, slf, arg0, arg1);
+            }
+            2 => {
+                self.0.done(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_color_representation_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpColorRepresentationManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpColorRepresentationManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpColorRepresentationManagerV1 {
+    /// Since when the error.surface_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SURFACE_EXISTS__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorRepresentationManagerV1Error(pub u32);
+
+impl WpColorRepresentationManagerV1Error {
+    /// color representation surface exists already
+    #[allow(dead_code)]
+    pub const SURFACE_EXISTS: Self = Self(1);
+}
+
+impl Debug for WpColorRepresentationManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::SURFACE_EXISTS => "SURFACE_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for supported_alpha_mode events.
+    pub struct SupportedAlphaMode<F>(F);
+    impl<F> WpColorRepresentationManagerV1EventHandler for SupportedAlphaMode<F>
+    where
+        F: Fn(&WpColorRepresentationManagerV1Ref, WpColorRepresentationSurfaceV1AlphaMode),
+    {
+        #[inline]
+        fn supported_alpha_mode(
+            &self,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+        ) {
+            self.0(_slf, alpha_mode)
+        }
+    }
+    impl<T, F> WpColorRepresentationManagerV1EventHandlerWithData<T> for SupportedAlphaMode<F>
+    where
+        F: Fn(&mut T, &WpColorRepresentationManagerV1Ref, WpColorRepresentationSurfaceV1AlphaMode),
+    {
+        #[inline]
+        fn supported_alpha_mode(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+        ) {
+            self.0(_data, _slf, alpha_mode)
+        }
+    }
+
+    /// Event handler for supported_coefficients_and_ranges events.
+    pub struct SupportedCoefficientsAndRanges<F>(F);
+    impl<F> WpColorRepresentationManagerV1EventHandler for SupportedCoefficientsAndRanges<F>
+    where
+        F: Fn(
+            &WpColorRepresentationManagerV1Ref,
+            WpColorRepresentationSurfaceV1Coefficients,
+            WpColorRepresentationSurfaceV1Range,
+        ),
+    {
+        #[inline]
+        fn supported_coefficients_and_ranges(
+            &self,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            coefficients: WpColorRepresentationSurfaceV1Coefficients,
+            range: WpColorRepresentationSurfaceV1Range,
+        ) {
+            self.0(_slf, coefficients, range)
+        }
+    }
+    impl<T, F> WpColorRepresentationManagerV1EventHandlerWithData<T>
+        for SupportedCoefficientsAndRanges<F>
+    where
+        F: Fn(
+            &mut T,
+            &WpColorRepresentationManagerV1Ref,
+            WpColorRepresentationSurfaceV1Coefficients,
+            WpColorRepresentationSurfaceV1Range,
+        ),
+    {
+        #[inline]
+        fn supported_coefficients_and_ranges(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            coefficients: WpColorRepresentationSurfaceV1Coefficients,
+            range: WpColorRepresentationSurfaceV1Range,
+        ) {
+            self.0(_data, _slf, coefficients, range)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> WpColorRepresentationManagerV1EventHandler for Done<F>
+    where
+        F: Fn(&WpColorRepresentationManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &WpColorRepresentationManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpColorRepresentationManagerV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &WpColorRepresentationManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for supported_alpha_mode events.
+    pub struct SupportedAlphaModeMut<F>(F);
+    impl<F> WpColorRepresentationManagerV1EventHandlerMut for SupportedAlphaModeMut<F>
+    where
+        F: FnMut(&WpColorRepresentationManagerV1Ref, WpColorRepresentationSurfaceV1AlphaMode),
+    {
+        #[inline]
+        fn supported_alpha_mode(
+            &mut self,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+        ) {
+            self.0(_slf, alpha_mode)
+        }
+    }
+    impl<T, F> WpColorRepresentationManagerV1EventHandlerMutWithData<T> for SupportedAlphaModeMut<F>
+    where
+        F: FnMut(
+            &mut T,
+            &WpColorRepresentationManagerV1Ref,
+            WpColorRepresentationSurfaceV1AlphaMode,
+        ),
+    {
+        #[inline]
+        fn supported_alpha_mode(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+        ) {
+            self.0(_data, _slf, alpha_mode)
+        }
+    }
+
+    /// Mutable event handler for supported_coefficients_and_ranges events.
+    pub struct SupportedCoefficientsAndRangesMut<F>(F);
+    impl<F> WpColorRepresentationManagerV1EventHandlerMut for SupportedCoefficientsAndRangesMut<F>
+    where
+        F: FnMut(
+            &WpColorRepresentationManagerV1Ref,
+            WpColorRepresentationSurfaceV1Coefficients,
+            WpColorRepresentationSurfaceV1Range,
+        ),
+    {
+        #[inline]
+        fn supported_coefficients_and_ranges(
+            &mut self,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            coefficients: WpColorRepresentationSurfaceV1Coefficients,
+            range: WpColorRepresentationSurfaceV1Range,
+        ) {
+            self.0(_slf, coefficients, range)
+        }
+    }
+    impl<T, F> WpColorRepresentationManagerV1EventHandlerMutWithData<T>
+        for SupportedCoefficientsAndRangesMut<F>
+    where
+        F: FnMut(
+            &mut T,
+            &WpColorRepresentationManagerV1Ref,
+            WpColorRepresentationSurfaceV1Coefficients,
+            WpColorRepresentationSurfaceV1Range,
+        ),
+    {
+        #[inline]
+        fn supported_coefficients_and_ranges(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            coefficients: WpColorRepresentationSurfaceV1Coefficients,
+            range: WpColorRepresentationSurfaceV1Range,
+        ) {
+            self.0(_data, _slf, coefficients, range)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> WpColorRepresentationManagerV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&WpColorRepresentationManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &WpColorRepresentationManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpColorRepresentationManagerV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &WpColorRepresentationManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpColorRepresentationManagerV1 {
+        /// Creates an event handler for supported_alpha_mode events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_alpha_mode<F>(f: F) -> SupportedAlphaMode<F>
+        where
+            F: Fn(&WpColorRepresentationManagerV1Ref, WpColorRepresentationSurfaceV1AlphaMode),
+        {
+            SupportedAlphaMode(f)
+        }
+
+        /// Creates an event handler with mutable data for supported_alpha_mode events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_alpha_mode_with_data<T, F>(f: F) -> SupportedAlphaMode<F>
+        where
+            F: Fn(
+                &mut T,
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1AlphaMode,
+            ),
+        {
+            SupportedAlphaMode(f)
+        }
+
+        /// Creates an event handler for supported_coefficients_and_ranges events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_coefficients_and_ranges<F>(f: F) -> SupportedCoefficientsAndRanges<F>
+        where
+            F: Fn(
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1Coefficients,
+                WpColorRepresentationSurfaceV1Range,
+            ),
+        {
+            SupportedCoefficientsAndRanges(f)
+        }
+
+        /// Creates an event handler with mutable data for supported_coefficients_and_ranges events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_supported_coefficients_and_ranges_with_data<T, F>(
+            f: F,
+        ) -> SupportedCoefficientsAndRanges<F>
+        where
+            F: Fn(
+                &mut T,
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1Coefficients,
+                WpColorRepresentationSurfaceV1Range,
+            ),
+        {
+            SupportedCoefficientsAndRanges(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&WpColorRepresentationManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &WpColorRepresentationManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates a mutable event handler for supported_alpha_mode events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_alpha_mode_mut<F>(f: F) -> SupportedAlphaModeMut<F>
+        where
+            F: FnMut(&WpColorRepresentationManagerV1Ref, WpColorRepresentationSurfaceV1AlphaMode),
+        {
+            SupportedAlphaModeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for supported_alpha_mode events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_alpha_mode_mut_with_data<T, F>(f: F) -> SupportedAlphaModeMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1AlphaMode,
+            ),
+        {
+            SupportedAlphaModeMut(f)
+        }
+
+        /// Creates a mutable event handler for supported_coefficients_and_ranges events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_coefficients_and_ranges_mut<F>(
+            f: F,
+        ) -> SupportedCoefficientsAndRangesMut<F>
+        where
+            F: FnMut(
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1Coefficients,
+                WpColorRepresentationSurfaceV1Range,
+            ),
+        {
+            SupportedCoefficientsAndRangesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for supported_coefficients_and_ranges events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_supported_coefficients_and_ranges_mut_with_data<T, F>(
+            f: F,
+        ) -> SupportedCoefficientsAndRangesMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1Coefficients,
+                WpColorRepresentationSurfaceV1Range,
+            ),
+        {
+            SupportedCoefficientsAndRangesMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&WpColorRepresentationManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &WpColorRepresentationManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                supported_alpha_mode: Ignore,
+                supported_coefficients_and_ranges: Ignore,
+                done: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpColorRepresentationManagerV1EventHandler for Ignore {}
+    impl<T> WpColorRepresentationManagerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpColorRepresentationManagerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        supported_alpha_mode: H0,
+        supported_coefficients_and_ranges: H1,
+        done: H2,
+    }
+
+    impl<H0, H1, H2> WpColorRepresentationManagerV1EventHandler for Handler<H0, H1, H2>
+    where
+        H0: WpColorRepresentationManagerV1EventHandler,
+        H1: WpColorRepresentationManagerV1EventHandler,
+        H2: WpColorRepresentationManagerV1EventHandler,
+    {
+        #[inline]
+        fn supported_alpha_mode(
+            &self,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+        ) {
+            self.supported_alpha_mode
+                .supported_alpha_mode(_slf, alpha_mode)
+        }
+
+        #[inline]
+        fn supported_coefficients_and_ranges(
+            &self,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            coefficients: WpColorRepresentationSurfaceV1Coefficients,
+            range: WpColorRepresentationSurfaceV1Range,
+        ) {
+            self.supported_coefficients_and_ranges
+                .supported_coefficients_and_ranges(_slf, coefficients, range)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &WpColorRepresentationManagerV1Ref) {
+            self.done.done(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2> WpColorRepresentationManagerV1EventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: WpColorRepresentationManagerV1EventHandlerWithData<T>,
+        H1: WpColorRepresentationManagerV1EventHandlerWithData<T>,
+        H2: WpColorRepresentationManagerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn supported_alpha_mode(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            alpha_mode: WpColorRepresentationSurfaceV1AlphaMode,
+        ) {
+            self.supported_alpha_mode
+                .supported_alpha_mode(_data, _slf, alpha_mode)
+        }
+
+        #[inline]
+        fn supported_coefficients_and_ranges(
+            &self,
+            _data: &mut T,
+            _slf: &WpColorRepresentationManagerV1Ref,
+            coefficients: WpColorRepresentationSurfaceV1Coefficients,
+            range: WpColorRepresentationSurfaceV1Range,
+        ) {
+            self.supported_coefficients_and_ranges
+                .supported_coefficients_and_ranges(_data, _slf, coefficients, range)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpColorRepresentationManagerV1Ref) {
+            self.done.done(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for supported_alpha_mode events.
+        #[allow(dead_code)]
+        pub fn on_supported_alpha_mode<F>(self, f: F) -> Handler<SupportedAlphaMode<F>, H1, H2>
+        where
+            F: Fn(&WpColorRepresentationManagerV1Ref, WpColorRepresentationSurfaceV1AlphaMode),
+        {
+            Handler {
+                supported_alpha_mode: SupportedAlphaMode(f),
+                supported_coefficients_and_ranges: self.supported_coefficients_and_ranges,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for supported_alpha_mode events.
+        #[allow(dead_code)]
+        pub fn on_supported_alpha_mode_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<SupportedAlphaMode<F>, H1, H2>
+        where
+            F: Fn(
+                &mut T,
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1AlphaMode,
+            ),
+        {
+            Handler {
+                supported_alpha_mode: SupportedAlphaMode(f),
+                supported_coefficients_and_ranges: self.supported_coefficients_and_ranges,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for supported_coefficients_and_ranges events.
+        #[allow(dead_code)]
+        pub fn on_supported_coefficients_and_ranges<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, SupportedCoefficientsAndRanges<F>, H2>
+        where
+            F: Fn(
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1Coefficients,
+                WpColorRepresentationSurfaceV1Range,
+            ),
+        {
+            Handler {
+                supported_alpha_mode: self.supported_alpha_mode,
+                supported_coefficients_and_ranges: SupportedCoefficientsAndRanges(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for supported_coefficients_and_ranges events.
+        #[allow(dead_code)]
+        pub fn on_supported_coefficients_and_ranges_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, SupportedCoefficientsAndRanges<F>, H2>
+        where
+            F: Fn(
+                &mut T,
+                &WpColorRepresentationManagerV1Ref,
+                WpColorRepresentationSurfaceV1Coefficients,
+                WpColorRepresentationSurfaceV1Range,
+            ),
+        {
+            Handler {
+                supported_alpha_mode: self.supported_alpha_mode,
+                supported_coefficients_and_ranges: SupportedCoefficientsAndRanges(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, Done<F>>
+        where
+            F: Fn(&WpColorRepresentationManagerV1Ref),
+        {
+            Handler {
+                supported_alpha_mode: self.supported_alpha_mode,
+                supported_coefficients_and_ranges: self.supported_coefficients_and_ranges,
+                done: Done(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, Done<F>>
+        where
+            F: Fn(&mut T, &WpColorRepresentationManagerV1Ref),
+        {
+            Handler {
+                supported_alpha_mode: self.supported_alpha_mode,
+                supported_coefficients_and_ranges: self.supported_coefficients_and_ranges,
+                done: Done(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1081 @@
+//! color representation extension to a surface
+//!
+//! A wp_color_representation_surface_v1 allows the client to set the color
+//! representation metadata of a surface.
+//!
+//! By default, a surface does not have any color representation metadata set.
+//! The reconstruction of R, G, B signals on such surfaces is compositor
+//! implementation defined. The alpha mode is assumed to be
+//! premultiplied_electrical when the alpha mode is unset.
+//!
+//! If the wl_surface associated with the wp_color_representation_surface_v1
+//! is destroyed, the wp_color_representation_surface_v1 object becomes inert.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_color_representation_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 4,
+    methods: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_alpha_mode".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_coefficients_and_range".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_chroma_location".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_color_representation_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_color_representation_surface_v1",
+    protocol: "color_representation_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_alpha_mode",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "alpha_mode",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("alpha_mode"),
+            }],
+        },
+        info::MessageInfo {
+            name: "set_coefficients_and_range",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "coefficients",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("coefficients"),
+                },
+                info::ArgInfo {
+                    name: "range",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("range"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_chroma_location",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "chroma_location",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("chroma_location"),
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "alpha_mode",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "coefficients",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pixel_format",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "inert",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "chroma_location",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "alpha_mode",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "premultiplied_electrical",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "premultiplied_optical",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "straight",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "coefficients",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "identity",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bt709",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "fcc",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bt601",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "smpte240",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bt2020",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bt2020_cl",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ictcp",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "range",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "full",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "limited",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "chroma_location",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "type_0",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "type_1",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "type_2",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "type_3",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "type_4",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "type_5",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned wp_color_representation_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorRepresentationSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_color_representation_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpColorRepresentationSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpColorRepresentationSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpColorRepresentationSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpColorRepresentationSurfaceV1 {
+    const INTERFACE: &'static str = "wp_color_representation_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpColorRepresentationSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpColorRepresentationSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpColorRepresentationSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpColorRepresentationSurfaceV1Ref {
+    type Owned = WpColorRepresentationSurfaceV1;
+}
+
+impl Deref for WpColorRepresentationSurfaceV1 {
+    type Target = WpColorRepresentationSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpColorRepresentationSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_representation_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpColorRepresentationSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_color_representation_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpColorRepresentationSurfaceV1Ref> for WpColorRepresentationSurfaceV1 {
+    fn eq(&self, other: &WpColorRepresentationSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpColorRepresentationSurfaceV1> for WpColorRepresentationSurfaceV1Ref {
+    fn eq(&self, other: &WpColorRepresentationSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorRepresentationSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the color representation
+    ///
+    /// Destroy the wp_color_representation_surface_v1 object.
+    ///
+    /// Destroying this object unsets all the color representation metadata from
+    /// the surface. See the wp_color_representation_surface_v1 interface
+    /// description for how a compositor handles a surface without color
+    /// representation metadata. Unsetting is double-buffered state, see
+    /// wl_surface.commit.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpColorRepresentationSurfaceV1Ref {
+    /// set the surface alpha mode
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// Assuming an alpha channel exists, it is always linear. The alpha mode
+    /// determines whether and how the color channels include pre-multiplied
+    /// alpha. Using straight alpha might have performance benefits.
+    ///
+    /// Only alpha modes advertised by the compositor are allowed to be used as
+    /// argument for this request. The "alpha_mode" protocol error is raised
+    /// otherwise.
+    ///
+    /// Alpha mode is double buffered, see wl_surface.commit.
+    ///
+    /// # Arguments
+    ///
+    /// - `alpha_mode`: alpha mode
+    #[inline]
+    pub fn set_alpha_mode(&self, alpha_mode: WpColorRepresentationSurfaceV1AlphaMode) {
+        let (arg0,) = (alpha_mode,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// set the matrix coefficients and range
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// Set the matrix coefficients and video range which defines the formula
+    /// and the related constants used to derive red, green and blue signals.
+    /// Usually coefficients correspond to MatrixCoefficients code points in
+    /// H.273.
+    ///
+    /// Only combinations advertised by the compositor are allowed to be used as
+    /// argument for this request. The "coefficients" protocol error is raised
+    /// otherwise.
+    ///
+    /// A call to wl_surface.commit verifies that the pixel format and the
+    /// coefficients-range combination in the committed surface contents are
+    /// compatible, if contents exist. The "pixel_format" protocol error is
+    /// raised otherwise.
+    ///
+    /// A pixel format is compatible with the coefficients-range combination if
+    /// the related equations and conventions as defined in H.273 can produce
+    /// the color channels (RGB or YCbCr) of the pixel format.
+    ///
+    /// For the definition of the supported combination, see the
+    /// wp_color_representation_surface_v1::coefficients and
+    /// wp_color_representation_surface_v1::range enums.
+    ///
+    /// The coefficients-range combination is double-buffered, see
+    /// wl_surface.commit.
+    ///
+    /// # Arguments
+    ///
+    /// - `coefficients`: matrix coefficients
+    /// - `range`: range
+    #[inline]
+    pub fn set_coefficients_and_range(
+        &self,
+        coefficients: WpColorRepresentationSurfaceV1Coefficients,
+        range: WpColorRepresentationSurfaceV1Range,
+    ) {
+        let (arg0, arg1) = (coefficients, range);
+        let mut args = [wl_argument { u: arg0.0 }, wl_argument { u: arg1.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// set the chroma location
+    ///
+    /// If this protocol object is inert, the protocol error inert is raised.
+    ///
+    /// Set the chroma location type which defines the position of downsampled
+    /// chroma samples, corresponding to Chroma420SampleLocType code points in
+    /// H.273.
+    ///
+    /// An invalid chroma location enum value raises the "chroma_location"
+    /// protocol error.
+    ///
+    /// A call to wl_surface.commit verifies that the pixel format and chroma
+    /// location type in the committed surface contents are compatible, if
+    /// contents exist. The "pixel_format" protocol error is raised otherwise.
+    ///
+    /// For the definition of the supported chroma location types, see the
+    /// wp_color_representation_surface_v1::chroma_location enum.
+    ///
+    /// The chroma location type is double-buffered, see wl_surface.commit.
+    ///
+    /// # Arguments
+    ///
+    /// - `chroma_location`: chroma sample location
+    #[inline]
+    pub fn set_chroma_location(
+        &self,
+        chroma_location: WpColorRepresentationSurfaceV1ChromaLocation,
+    ) {
+        let (arg0,) = (chroma_location,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpColorRepresentationSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait WpColorRepresentationSurfaceV1EventHandler {}
+
+impl WpColorRepresentationSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpColorRepresentationSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorRepresentationSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpColorRepresentationSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpColorRepresentationSurfaceV1] proxies.
+///
+/// This trait is the same as [WpColorRepresentationSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorRepresentationSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [WpColorRepresentationSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpColorRepresentationSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpColorRepresentationSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> WpColorRepresentationSurfaceV1EventHandler for MutEventHandler<H> where
+    H: WpColorRepresentationSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> WpColorRepresentationSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpColorRepresentationSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpColorRepresentationSurfaceV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorRepresentationSurfaceV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> WpColorRepresentationSurfaceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpColorRepresentationSurfaceV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpColorRepresentationSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_color_representation_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpColorRepresentationSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_color_representation_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpColorRepresentationSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpColorRepresentationSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpColorRepresentationSurfaceV1 {
+    /// Since when the error.alpha_mode enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALPHA_MODE__SINCE: u32 = 1;
+    /// Since when the error.coefficients enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_COEFFICIENTS__SINCE: u32 = 1;
+    /// Since when the error.pixel_format enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_PIXEL_FORMAT__SINCE: u32 = 1;
+    /// Since when the error.inert enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INERT__SINCE: u32 = 1;
+    /// Since when the error.chroma_location enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_CHROMA_LOCATION__SINCE: u32 = 1;
+
+    /// Since when the alpha_mode.premultiplied_electrical enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ALPHA_MODE_PREMULTIPLIED_ELECTRICAL__SINCE: u32 = 1;
+    /// Since when the alpha_mode.premultiplied_optical enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ALPHA_MODE_PREMULTIPLIED_OPTICAL__SINCE: u32 = 1;
+    /// Since when the alpha_mode.straight enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ALPHA_MODE_STRAIGHT__SINCE: u32 = 1;
+
+    /// Since when the coefficients.identity enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_IDENTITY__SINCE: u32 = 1;
+    /// Since when the coefficients.bt709 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_BT709__SINCE: u32 = 1;
+    /// Since when the coefficients.fcc enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_FCC__SINCE: u32 = 1;
+    /// Since when the coefficients.bt601 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_BT601__SINCE: u32 = 1;
+    /// Since when the coefficients.smpte240 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_SMPTE240__SINCE: u32 = 1;
+    /// Since when the coefficients.bt2020 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_BT2020__SINCE: u32 = 1;
+    /// Since when the coefficients.bt2020_cl enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_BT2020_CL__SINCE: u32 = 1;
+    /// Since when the coefficients.ictcp enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__COEFFICIENTS_ICTCP__SINCE: u32 = 1;
+
+    /// Since when the range.full enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RANGE_FULL__SINCE: u32 = 1;
+    /// Since when the range.limited enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__RANGE_LIMITED__SINCE: u32 = 1;
+
+    /// Since when the chroma_location.type_0 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHROMA_LOCATION_TYPE_0__SINCE: u32 = 1;
+    /// Since when the chroma_location.type_1 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHROMA_LOCATION_TYPE_1__SINCE: u32 = 1;
+    /// Since when the chroma_location.type_2 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHROMA_LOCATION_TYPE_2__SINCE: u32 = 1;
+    /// Since when the chroma_location.type_3 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHROMA_LOCATION_TYPE_3__SINCE: u32 = 1;
+    /// Since when the chroma_location.type_4 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHROMA_LOCATION_TYPE_4__SINCE: u32 = 1;
+    /// Since when the chroma_location.type_5 enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHROMA_LOCATION_TYPE_5__SINCE: u32 = 1;
+}
+
+/// protocol errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorRepresentationSurfaceV1Error(pub u32);
+
+impl WpColorRepresentationSurfaceV1Error {
+    /// unsupported alpha mode
+    #[allow(dead_code)]
+    pub const ALPHA_MODE: Self = Self(1);
+
+    /// unsupported coefficients
+    #[allow(dead_code)]
+    pub const COEFFICIENTS: Self = Self(2);
+
+    /// the pixel format and a set value are incompatible
+    #[allow(dead_code)]
+    pub const PIXEL_FORMAT: Self = Self(3);
+
+    /// forbidden request on inert object
+    #[allow(dead_code)]
+    pub const INERT: Self = Self(4);
+
+    /// invalid chroma location
+    #[allow(dead_code)]
+    pub const CHROMA_LOCATION: Self = Self(5);
+}
+
+impl Debug for WpColorRepresentationSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALPHA_MODE => "ALPHA_MODE",
+            Self::COEFFICIENTS => "COEFFICIENTS",
+            Self::PIXEL_FORMAT => "PIXEL_FORMAT",
+            Self::INERT => "INERT",
+            Self::CHROMA_LOCATION => "CHROMA_LOCATION",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// alpha mode
+///
+/// Specifies how the alpha channel affects the color channels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorRepresentationSurfaceV1AlphaMode(pub u32);
+
+impl WpColorRepresentationSurfaceV1AlphaMode {
+    /// premultiplied alpha in electrical values
+    ///
+    /// Electrical color channel values (after transfer function encoding)
+    /// are already multiplied with the alpha channel value.
+    #[allow(dead_code)]
+    pub const PREMULTIPLIED_ELECTRICAL: Self = Self(0);
+
+    /// premultiplied alpha in optical values
+    ///
+    /// Optical color channel values (before transfer function encoding)
+    /// are already multiplied with the alpha channel value.
+    #[allow(dead_code)]
+    pub const PREMULTIPLIED_OPTICAL: Self = Self(1);
+
+    /// straight alpha
+    ///
+    /// Alpha channel has not been pre-multiplied into color channels.
+    #[allow(dead_code)]
+    pub const STRAIGHT: Self = Self(2);
+}
+
+impl Debug for WpColorRepresentationSurfaceV1AlphaMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::PREMULTIPLIED_ELECTRICAL => "PREMULTIPLIED_ELECTRICAL",
+            Self::PREMULTIPLIED_OPTICAL => "PREMULTIPLIED_OPTICAL",
+            Self::STRAIGHT => "STRAIGHT",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// named coefficients
+///
+/// Named matrix coefficients used to encode well-known sets of
+/// coefficients. H.273 is the authority, when it comes to the exact values
+/// of coefficients and authoritative specifications, where an equivalent
+/// code point exists.
+///
+/// A value of 0 is invalid and will never be present in the list of enums.
+///
+/// Descriptions do list the specifications for convenience.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorRepresentationSurfaceV1Coefficients(pub u32);
+
+impl WpColorRepresentationSurfaceV1Coefficients {
+    /// The identity matrix
+    ///
+    /// Coefficients as defined by
+    /// - IEC 61966-2-1 sRGB
+    /// - SMPTE ST 428-1 (2019)
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 0.
+    /// Compatible with pixel formats of the RGB family.
+    #[allow(dead_code)]
+    pub const IDENTITY: Self = Self(1);
+
+    /// BT.709 matrix coefficients
+    ///
+    /// Coefficients as defined by
+    /// - Rec. ITU-R BT.709-6
+    /// - Rec. ITU-R BT.1361-0 conventional colour gamut system (historical)
+    /// - Rec. ITU-R BT.1361-0 conventional colour gamut system and extended
+    ///   colour gamut system (historical)
+    /// - IEC 61966-2-4 xvYCC709
+    /// - SMPTE RP 177 (1993) Annex B
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 1.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const BT709: Self = Self(2);
+
+    /// FCC matrix coefficients
+    ///
+    /// Coefficients as defined by
+    /// - United States Federal Communications Commission (2003) Title 47
+    ///   Code of Federal Regulations 73.682 (a) (20)
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 4.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const FCC: Self = Self(3);
+
+    /// BT.601-7 matrix coefficients
+    ///
+    /// Coefficients as defined by
+    /// - Rec. ITU-R BT.470-6 System B, G (historical)
+    /// - Rec. ITU-R BT.601-7 625
+    /// - Rec. ITU-R BT.601-7 525
+    /// - Rec. ITU-R BT.1358-0 625 (historical)
+    /// - Rec. ITU-R BT.1358-1 525 or 625 (historical)
+    /// - Rec. ITU-R BT.1700-0 625 PAL and 625 SECAM
+    /// - Rec. ITU-R BT.1700-0 NTSC
+    /// - IEC 61966-2-1 sYCC
+    /// - IEC 61966-2-4 xvYCC601
+    /// - SMPTE ST 170 (2004)
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 5, 6.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const BT601: Self = Self(4);
+
+    /// SMPTE ST 240 matrix coefficients
+    ///
+    /// Coefficients as defined by
+    /// - SMPTE ST 240 (1999)
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 7.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const SMPTE240: Self = Self(5);
+
+    /// BT.2020 and BT.2100 YCbCr matrix coefficients
+    ///
+    /// Coefficients as defined by
+    /// - Rec. ITU-R BT.2020-2 (non-constant luminance)
+    /// - Rec. ITU-R BT.2100-2 Y′CbCr
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 9.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const BT2020: Self = Self(6);
+
+    /// BT.2020 matrix coefficients for constant luminance
+    ///
+    /// Coefficients as defined by
+    /// - Rec. ITU-R BT.2020-2 (constant luminance)
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 10.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const BT2020_CL: Self = Self(7);
+
+    /// BT.2100 ICtCp matrix coefficients
+    ///
+    /// Coefficients as defined by
+    /// - Rec. ITU-R BT.2100-2 ICTCP
+    ///
+    /// Equivalent to H.273 MatrixCoefficients code point 14.
+    /// Compatible with pixel formats of the YCbCr family.
+    #[allow(dead_code)]
+    pub const ICTCP: Self = Self(8);
+}
+
+impl Debug for WpColorRepresentationSurfaceV1Coefficients {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::IDENTITY => "IDENTITY",
+            Self::BT709 => "BT709",
+            Self::FCC => "FCC",
+            Self::BT601 => "BT601",
+            Self::SMPTE240 => "SMPTE240",
+            Self::BT2020 => "BT2020",
+            Self::BT2020_CL => "BT2020_CL",
+            Self::ICTCP => "ICTCP",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Color range values
+///
+/// Possible color range values.
+///
+/// A value of 0 is invalid and will never be present in the list of enums.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorRepresentationSurfaceV1Range(pub u32);
+
+impl WpColorRepresentationSurfaceV1Range {
+    /// Full color range
+    #[allow(dead_code)]
+    pub const FULL: Self = Self(1);
+
+    /// Limited color range
+    #[allow(dead_code)]
+    pub const LIMITED: Self = Self(2);
+}
+
+impl Debug for WpColorRepresentationSurfaceV1Range {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::FULL => "FULL",
+            Self::LIMITED => "LIMITED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Chroma sample location for 4:2:0 YCbCr
+///
+/// Chroma sample location as defined by H.273 Chroma420SampleLocType.
+///
+/// A value of 0 is invalid and will never be present in the list of enums.
+///
+/// The descriptions list the matching Vulkan VkChromaLocation combinations
+/// for convenience.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpColorRepresentationSurfaceV1ChromaLocation(pub u32);
+
+impl WpColorRepresentationSurfaceV1ChromaLocation {
+    /// Horizontal offset of 0, vertical offset of 0.5
+    ///
+    /// Corresponding to VkChromaLocations:
+    /// - xChromaOffset: VK_CHROMA_LOCATION_COSITED_EVEN
+    /// - yChromaOffset: VK_CHROMA_LOCATION_MIDPOINT
+    ///
+    /// Equivalent to H.273 Chroma420SampleLocType 0.
+    #[allow(dead_code)]
+    pub const TYPE_0: Self = Self(1);
+
+    /// Horizontal offset of 0.5, vertical offset of 0.5
+    ///
+    /// Corresponding to VkChromaLocations:
+    /// - xChromaOffset: VK_CHROMA_LOCATION_MIDPOINT
+    /// - yChromaOffset: VK_CHROMA_LOCATION_MIDPOINT
+    ///
+    /// Equivalent to H.273 Chroma420SampleLocType 1.
+    #[allow(dead_code)]
+    pub const TYPE_1: Self = Self(2);
+
+    /// Horizontal offset of 0, vertical offset of 0
+    ///
+    /// Corresponding to VkChromaLocations:
+    /// - xChromaOffset: VK_CHROMA_LOCATION_COSITED_EVEN
+    /// - yChromaOffset: VK_CHROMA_LOCATION_COSITED_EVEN
+    ///
+    /// Equivalent to H.273 Chroma420SampleLocType 2.
+    #[allow(dead_code)]
+    pub const TYPE_2: Self = Self(3);
+
+    /// Horizontal offset of 0.5, vertical offset of 0
+    ///
+    /// Corresponding to VkChromaLocations:
+    /// - xChromaOffset: VK_CHROMA_LOCATION_MIDPOINT
+    /// - yChromaOffset: VK_CHROMA_LOCATION_COSITED_EVEN
+    ///
+    /// Equivalent to H.273 Chroma420SampleLocType 3.
+    #[allow(dead_code)]
+    pub const TYPE_3: Self = Self(4);
+
+    /// Horizontal offset of 0, vertical offset of 1
+    ///
+    /// Equivalent to H.273 Chroma420SampleLocType 4.
+    #[allow(dead_code)]
+    pub const TYPE_4: Self = Self(5);
+
+    /// Horizontal offset of 0.5, vertical offset of 1
+    ///
+    /// Equivalent to H.273 Chroma420SampleLocType 5.
+    #[allow(dead_code)]
+    pub const TYPE_5: Self = Self(6);
+}
+
+impl Debug for WpColorRepresentationSurfaceV1ChromaLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::TYPE_0 => "TYPE_0",
+            Self::TYPE_1 => "TYPE_1",
+            Self::TYPE_2 => "TYPE_2",
+            Self::TYPE_3 => "TYPE_3",
+            Self::TYPE_4 => "TYPE_4",
+            Self::TYPE_5 => "TYPE_5",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpColorRepresentationSurfaceV1 {}
+}
@@ -0,0 +1,22 @@
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod wp_commit_timer_v1;
+pub mod wp_commit_timing_manager_v1;
+
+/// Metadata about the commit_timing_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "commit_timing_v1",
+    interfaces: &[
+        &wp_commit_timing_manager_v1::INFO,
+        &wp_commit_timer_v1::INFO,
+    ],
+};
@@ -0,0 +1,433 @@
+//! Surface commit timer
+//!
+//! An object to set a time constraint for a content update on a surface.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_commit_timer_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"set_timestamp".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_commit_timer_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_commit_timer_v1",
+    protocol: "commit_timing_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_timestamp",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "tv_sec_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tv_sec_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tv_nsec",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_timestamp",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "timestamp_exists",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "surface_destroyed",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_commit_timer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpCommitTimerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_commit_timer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpCommitTimerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpCommitTimerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpCommitTimerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpCommitTimerV1 {
+    const INTERFACE: &'static str = "wp_commit_timer_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpCommitTimerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpCommitTimerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpCommitTimerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpCommitTimerV1Ref {
+    type Owned = WpCommitTimerV1;
+}
+
+impl Deref for WpCommitTimerV1 {
+    type Target = WpCommitTimerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpCommitTimerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_commit_timer_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpCommitTimerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_commit_timer_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpCommitTimerV1Ref> for WpCommitTimerV1 {
+    fn eq(&self, other: &WpCommitTimerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpCommitTimerV1> for WpCommitTimerV1Ref {
+    fn eq(&self, other: &WpCommitTimerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpCommitTimerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// Destroy the timer
+    ///
+    /// Informs the server that the client will no longer be using
+    /// this protocol object.
+    ///
+    /// Existing timing constraints are not affected by the destruction.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpCommitTimerV1Ref {
+    /// Specify time the following commit takes effect
+    ///
+    /// Provide a timing constraint for a surface content update.
+    ///
+    /// A set_timestamp request may be made before a wl_surface.commit to
+    /// tell the compositor that the content is intended to be presented
+    /// as closely as possible to, but not before, the specified time.
+    /// The time is in the domain of the compositor's presentation clock.
+    ///
+    /// An invalid_timestamp error will be generated for invalid tv_nsec.
+    ///
+    /// If a timestamp already exists on the surface, a timestamp_exists
+    /// error is generated.
+    ///
+    /// Requesting set_timestamp after the commit_timer object's surface is
+    /// destroyed will generate a "surface_destroyed" error.
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of target time
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of target time
+    /// - `tv_nsec`: nanoseconds part of target time
+    #[inline]
+    pub fn set_timestamp(&self, tv_sec_hi: u32, tv_sec_lo: u32, tv_nsec: u32) {
+        let (arg0, arg1, arg2) = (tv_sec_hi, tv_sec_lo, tv_nsec);
+        let mut args = [
+            wl_argument { u: arg0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `uuu`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpCommitTimerV1] proxies.
+#[allow(dead_code)]
+pub trait WpCommitTimerV1EventHandler {}
+
+impl WpCommitTimerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpCommitTimerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCommitTimerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpCommitTimerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpCommitTimerV1] proxies.
+///
+/// This trait is the same as [WpCommitTimerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCommitTimerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpCommitTimerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCommitTimerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCommitTimerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpCommitTimerV1EventHandler for MutEventHandler<H> where H: WpCommitTimerV1EventHandlerMut {}
+
+impl<T, H> WpCommitTimerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpCommitTimerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpCommitTimerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCommitTimerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpCommitTimerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCommitTimerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpCommitTimerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_commit_timer_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpCommitTimerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_commit_timer_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpCommitTimerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpCommitTimerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpCommitTimerV1 {
+    /// Since when the error.invalid_timestamp enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_TIMESTAMP__SINCE: u32 = 1;
+    /// Since when the error.timestamp_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_TIMESTAMP_EXISTS__SINCE: u32 = 1;
+    /// Since when the error.surface_destroyed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SURFACE_DESTROYED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpCommitTimerV1Error(pub u32);
+
+impl WpCommitTimerV1Error {
+    /// timestamp contains an invalid value
+    #[allow(dead_code)]
+    pub const INVALID_TIMESTAMP: Self = Self(0);
+
+    /// timestamp exists
+    #[allow(dead_code)]
+    pub const TIMESTAMP_EXISTS: Self = Self(1);
+
+    /// the associated surface no longer exists
+    #[allow(dead_code)]
+    pub const SURFACE_DESTROYED: Self = Self(2);
+}
+
+impl Debug for WpCommitTimerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_TIMESTAMP => "INVALID_TIMESTAMP",
+            Self::TIMESTAMP_EXISTS => "TIMESTAMP_EXISTS",
+            Self::SURFACE_DESTROYED => "SURFACE_DESTROYED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpCommitTimerV1 {}
+}
@@ -0,0 +1,444 @@
+//! commit timing
+//!
+//! When a compositor latches on to new content updates it will check for
+//! any number of requirements of the available content updates (such as
+//! fences of all buffers being signalled) to consider the update ready.
+//!
+//! This protocol provides a method for adding a time constraint to surface
+//! content. This constraint indicates to the compositor that a content
+//! update should be presented as closely as possible to, but not before,
+//! a specified time.
+//!
+//! This protocol does not change the Wayland property that content
+//! updates are applied in the order they are received, even when some
+//! content updates contain timestamps and others do not.
+//!
+//! To provide timestamps, this global factory interface must be used to
+//! acquire a wp_commit_timing_v1 object for a surface, which may then be
+//! used to provide timestamp information for commits.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_commit_timing_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_timer".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpCommitTimerV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_commit_timing_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_commit_timing_manager_v1",
+    protocol: "commit_timing_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_timer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_commit_timer_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "commit_timer_exists",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_commit_timing_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpCommitTimingManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_commit_timing_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpCommitTimingManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpCommitTimingManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpCommitTimingManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpCommitTimingManagerV1 {
+    const INTERFACE: &'static str = "wp_commit_timing_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpCommitTimingManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpCommitTimingManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpCommitTimingManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpCommitTimingManagerV1Ref {
+    type Owned = WpCommitTimingManagerV1;
+}
+
+impl Deref for WpCommitTimingManagerV1 {
+    type Target = WpCommitTimingManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpCommitTimingManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_commit_timing_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpCommitTimingManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_commit_timing_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpCommitTimingManagerV1Ref> for WpCommitTimingManagerV1 {
+    fn eq(&self, other: &WpCommitTimingManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpCommitTimingManagerV1> for WpCommitTimingManagerV1Ref {
+    fn eq(&self, other: &WpCommitTimingManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpCommitTimingManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// unbind from the commit timing interface
+    ///
+    /// Informs the server that the client will no longer be using
+    /// this protocol object. Existing objects created by this object
+    /// are not affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_timer request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_TIMER__SINCE: u32 = 1;
+
+    /// request commit timer interface for surface
+    ///
+    /// Establish a timing controller for a surface.
+    ///
+    /// Only one commit timer can be created for a surface, or a
+    /// commit_timer_exists protocol error will be generated.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_timer(&self, surface: &WlSurfaceRef) -> WpCommitTimerV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, WpCommitTimerV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpCommitTimerV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpCommitTimingManagerV1Ref {
+    /// request commit timer interface for surface
+    ///
+    /// Establish a timing controller for a surface.
+    ///
+    /// Only one commit timer can be created for a surface, or a
+    /// commit_timer_exists protocol error will be generated.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_timer(&self, _queue: &Queue, surface: &WlSurfaceRef) -> WpCommitTimerV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, WpCommitTimerV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpCommitTimerV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpCommitTimingManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpCommitTimingManagerV1EventHandler {}
+
+impl WpCommitTimingManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpCommitTimingManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCommitTimingManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpCommitTimingManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpCommitTimingManagerV1] proxies.
+///
+/// This trait is the same as [WpCommitTimingManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCommitTimingManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpCommitTimingManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCommitTimingManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCommitTimingManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpCommitTimingManagerV1EventHandler for MutEventHandler<H> where
+    H: WpCommitTimingManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> WpCommitTimingManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpCommitTimingManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpCommitTimingManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCommitTimingManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpCommitTimingManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCommitTimingManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpCommitTimingManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_commit_timing_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpCommitTimingManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_commit_timing_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpCommitTimingManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpCommitTimingManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpCommitTimingManagerV1 {
+    /// Since when the error.commit_timer_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_COMMIT_TIMER_EXISTS__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpCommitTimingManagerV1Error(pub u32);
+
+impl WpCommitTimingManagerV1Error {
+    /// commit timer already exists for surface
+    #[allow(dead_code)]
+    pub const COMMIT_TIMER_EXISTS: Self = Self(0);
+}
+
+impl Debug for WpCommitTimingManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::COMMIT_TIMER_EXISTS => "COMMIT_TIMER_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpCommitTimingManagerV1 {}
+}
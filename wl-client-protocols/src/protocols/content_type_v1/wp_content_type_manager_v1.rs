@@ -0,0 +1,433 @@
+//! surface content type manager
+//!
+//! This interface allows a client to describe the kind of content a surface
+//! will display, to allow the compositor to optimize its behavior for it.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_content_type_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface_content_type".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpContentTypeV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_content_type_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_content_type_manager_v1",
+    protocol: "content_type_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_surface_content_type",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_content_type_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "already_constructed",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_content_type_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpContentTypeManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_content_type_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpContentTypeManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpContentTypeManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpContentTypeManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpContentTypeManagerV1 {
+    const INTERFACE: &'static str = "wp_content_type_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpContentTypeManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpContentTypeManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpContentTypeManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpContentTypeManagerV1Ref {
+    type Owned = WpContentTypeManagerV1;
+}
+
+impl Deref for WpContentTypeManagerV1 {
+    type Target = WpContentTypeManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpContentTypeManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_content_type_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpContentTypeManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_content_type_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpContentTypeManagerV1Ref> for WpContentTypeManagerV1 {
+    fn eq(&self, other: &WpContentTypeManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpContentTypeManagerV1> for WpContentTypeManagerV1Ref {
+    fn eq(&self, other: &WpContentTypeManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpContentTypeManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the content type manager object
+    ///
+    /// Destroy the content type manager. This doesn't destroy objects created
+    /// with the manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_surface_content_type request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE_CONTENT_TYPE__SINCE: u32 = 1;
+
+    /// create a new content type object
+    ///
+    /// Create a new content type object associated with the given surface.
+    ///
+    /// Creating a wp_content_type_v1 from a wl_surface which already has one
+    /// attached is a client error: already_constructed.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface_content_type(&self, surface: &WlSurfaceRef) -> WpContentTypeV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, WpContentTypeV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpContentTypeV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpContentTypeManagerV1Ref {
+    /// create a new content type object
+    ///
+    /// Create a new content type object associated with the given surface.
+    ///
+    /// Creating a wp_content_type_v1 from a wl_surface which already has one
+    /// attached is a client error: already_constructed.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface_content_type(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> WpContentTypeV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, WpContentTypeV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpContentTypeV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpContentTypeManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpContentTypeManagerV1EventHandler {}
+
+impl WpContentTypeManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpContentTypeManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpContentTypeManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpContentTypeManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpContentTypeManagerV1] proxies.
+///
+/// This trait is the same as [WpContentTypeManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpContentTypeManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpContentTypeManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpContentTypeManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpContentTypeManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpContentTypeManagerV1EventHandler for MutEventHandler<H> where
+    H: WpContentTypeManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> WpContentTypeManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpContentTypeManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpContentTypeManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpContentTypeManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpContentTypeManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpContentTypeManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpContentTypeManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_content_type_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpContentTypeManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_content_type_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpContentTypeManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpContentTypeManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpContentTypeManagerV1 {
+    /// Since when the error.already_constructed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_CONSTRUCTED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpContentTypeManagerV1Error(pub u32);
+
+impl WpContentTypeManagerV1Error {
+    /// wl_surface already has a content type object
+    #[allow(dead_code)]
+    pub const ALREADY_CONSTRUCTED: Self = Self(0);
+}
+
+impl Debug for WpContentTypeManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_CONSTRUCTED => "ALREADY_CONSTRUCTED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpContentTypeManagerV1 {}
+}
@@ -0,0 +1,441 @@
+//! content type object for a surface
+//!
+//! The content type object allows the compositor to optimize for the kind
+//! of content shown on the surface. A compositor may for example use it to
+//! set relevant drm properties like "content type".
+//!
+//! The client may request to switch to another content type at any time.
+//! When the associated surface gets destroyed, this object becomes inert and
+//! the client should destroy it.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_content_type_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_content_type".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_content_type_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_content_type_v1",
+    protocol: "content_type_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_content_type",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "content_type",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("type"),
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "type",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "none",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "photo",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "video",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "game",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_content_type_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpContentTypeV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_content_type_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpContentTypeV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpContentTypeV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpContentTypeV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpContentTypeV1 {
+    const INTERFACE: &'static str = "wp_content_type_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpContentTypeV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpContentTypeV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpContentTypeV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpContentTypeV1Ref {
+    type Owned = WpContentTypeV1;
+}
+
+impl Deref for WpContentTypeV1 {
+    type Target = WpContentTypeV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpContentTypeV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_content_type_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpContentTypeV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_content_type_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpContentTypeV1Ref> for WpContentTypeV1 {
+    fn eq(&self, other: &WpContentTypeV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpContentTypeV1> for WpContentTypeV1Ref {
+    fn eq(&self, other: &WpContentTypeV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpContentTypeV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the content type object
+    ///
+    /// Switch back to not specifying the content type of this surface. This is
+    /// equivalent to setting the content type to none, including double
+    /// buffering semantics. See set_content_type for details.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpContentTypeV1Ref {
+    /// specify the content type
+    ///
+    /// Set the surface content type. This informs the compositor that the
+    /// client believes it is displaying buffers matching this content type.
+    ///
+    /// This is purely a hint for the compositor, which can be used to adjust
+    /// its behavior or hardware settings to fit the presented content best.
+    ///
+    /// The content type is double-buffered state, see wl_surface.commit for
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// - `content_type`: the content type
+    #[inline]
+    pub fn set_content_type(&self, content_type: WpContentTypeV1Type) {
+        let (arg0,) = (content_type,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpContentTypeV1] proxies.
+#[allow(dead_code)]
+pub trait WpContentTypeV1EventHandler {}
+
+impl WpContentTypeV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpContentTypeV1] proxies with mutable data.
+///
+/// This trait is the same as [WpContentTypeV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpContentTypeV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpContentTypeV1] proxies.
+///
+/// This trait is the same as [WpContentTypeV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpContentTypeV1EventHandlerMut {}
+
+/// A mutable event handler for [WpContentTypeV1] proxies with mutable data.
+///
+/// This trait is the same as [WpContentTypeV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpContentTypeV1EventHandlerMutWithData<T> {}
+
+impl<H> WpContentTypeV1EventHandler for MutEventHandler<H> where H: WpContentTypeV1EventHandlerMut {}
+
+impl<T, H> WpContentTypeV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpContentTypeV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpContentTypeV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpContentTypeV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpContentTypeV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpContentTypeV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpContentTypeV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_content_type_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpContentTypeV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_content_type_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpContentTypeV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpContentTypeV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpContentTypeV1 {
+    /// Since when the type.none enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_NONE__SINCE: u32 = 1;
+    /// Since when the type.photo enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_PHOTO__SINCE: u32 = 1;
+    /// Since when the type.video enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_VIDEO__SINCE: u32 = 1;
+    /// Since when the type.game enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_GAME__SINCE: u32 = 1;
+}
+
+/// possible content types
+///
+/// These values describe the available content types for a surface.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpContentTypeV1Type(pub u32);
+
+impl WpContentTypeV1Type {
+    /// no content type applies
+    ///
+    /// The content type none means that either the application has no data
+    /// about the content type, or that the content doesn't fit into one of
+    /// the other categories.
+    #[allow(dead_code)]
+    pub const NONE: Self = Self(0);
+
+    /// photo content type
+    ///
+    /// The content type photo describes content derived from digital still
+    /// pictures and may be presented with minimal processing.
+    #[allow(dead_code)]
+    pub const PHOTO: Self = Self(1);
+
+    /// video content type
+    ///
+    /// The content type video describes a video or animation and may be
+    /// presented with more accurate timing to avoid stutter. Where scaling
+    /// is needed, scaling methods more appropriate for video may be used.
+    #[allow(dead_code)]
+    pub const VIDEO: Self = Self(2);
+
+    /// game content type
+    ///
+    /// The content type game describes a running game. Its content may be
+    /// presented with reduced latency.
+    #[allow(dead_code)]
+    pub const GAME: Self = Self(3);
+}
+
+impl Debug for WpContentTypeV1Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NONE => "NONE",
+            Self::PHOTO => "PHOTO",
+            Self::VIDEO => "VIDEO",
+            Self::GAME => "GAME",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpContentTypeV1 {}
+}
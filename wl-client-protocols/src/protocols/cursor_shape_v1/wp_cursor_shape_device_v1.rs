@@ -6,7 +6,7 @@ use {super::super::all_types::*, ::wl_client::builder::prelude::*};
 
 static INTERFACE: wl_interface = wl_interface {
     name: c"wp_cursor_shape_device_v1".as_ptr(),
-    version: 1,
+    version: 2,
     method_count: 2,
     methods: {
         static MESSAGES: [wl_message; 2] = [
@@ -33,6 +33,282 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wp_cursor_shape_device_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_cursor_shape_device_v1",
+    protocol: "cursor_shape_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_shape",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "shape",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("shape"),
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[
+        info::EnumInfo {
+            name: "shape",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "default",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "context_menu",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "help",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pointer",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "progress",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "wait",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "cell",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "crosshair",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "text",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "vertical_text",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "alias",
+                    value: 11,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "copy",
+                    value: 12,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "move",
+                    value: 13,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "no_drop",
+                    value: 14,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "not_allowed",
+                    value: 15,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "grab",
+                    value: 16,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "grabbing",
+                    value: 17,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "e_resize",
+                    value: 18,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "n_resize",
+                    value: 19,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ne_resize",
+                    value: 20,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nw_resize",
+                    value: 21,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "s_resize",
+                    value: 22,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "se_resize",
+                    value: 23,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "sw_resize",
+                    value: 24,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "w_resize",
+                    value: 25,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ew_resize",
+                    value: 26,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ns_resize",
+                    value: 27,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nesw_resize",
+                    value: 28,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nwse_resize",
+                    value: 29,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "col_resize",
+                    value: 30,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "row_resize",
+                    value: 31,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "all_scroll",
+                    value: 32,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "zoom_in",
+                    value: 33,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "zoom_out",
+                    value: 34,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "dnd_ask",
+                    value: 35,
+                    since: 2,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "all_resize",
+                    value: 36,
+                    since: 2,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "invalid_shape",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+    ],
+};
+
 /// An owned wp_cursor_shape_device_v1 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -63,7 +339,7 @@ unsafe impl OwnedProxy for WpCursorShapeDeviceV1 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
     const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
         private::EventHandler(private::NoOpEventHandler);
-    const MAX_VERSION: u32 = 1;
+    const MAX_VERSION: u32 = 2;
 
     type Borrowed = WpCursorShapeDeviceV1Ref;
     type Api = private::ProxyApi;
@@ -87,11 +363,16 @@ impl Deref for WpCursorShapeDeviceV1 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -185,26 +466,87 @@ impl WpCursorShapeDeviceV1Ref {
 
 /// An event handler for [WpCursorShapeDeviceV1] proxies.
 #[allow(dead_code)]
-pub trait WpCursorShapeDeviceV1EventHandler {
-    type Data: 'static;
+pub trait WpCursorShapeDeviceV1EventHandler {}
+
+impl WpCursorShapeDeviceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpCursorShapeDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCursorShapeDeviceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpCursorShapeDeviceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpCursorShapeDeviceV1] proxies.
+///
+/// This trait is the same as [WpCursorShapeDeviceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCursorShapeDeviceV1EventHandlerMut {}
+
+/// A mutable event handler for [WpCursorShapeDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCursorShapeDeviceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCursorShapeDeviceV1EventHandlerMutWithData<T> {}
+
+impl<H> WpCursorShapeDeviceV1EventHandler for MutEventHandler<H> where
+    H: WpCursorShapeDeviceV1EventHandlerMut
+{
 }
 
-impl WpCursorShapeDeviceV1EventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, H> WpCursorShapeDeviceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpCursorShapeDeviceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpCursorShapeDeviceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCursorShapeDeviceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpCursorShapeDeviceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCursorShapeDeviceV1EventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WpCursorShapeDeviceV1EventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_cursor_shape_device_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpCursorShapeDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -233,6 +575,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpCursorShapeDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WpCursorShapeDeviceV1 {
     /// Since when the shape.default enum variant is available.
     #[allow(dead_code)]
@@ -336,6 +691,12 @@ impl WpCursorShapeDeviceV1 {
     /// Since when the shape.zoom_out enum variant is available.
     #[allow(dead_code)]
     pub const ENM__SHAPE_ZOOM_OUT__SINCE: u32 = 1;
+    /// Since when the shape.dnd_ask enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__SHAPE_DND_ASK__SINCE: u32 = 2;
+    /// Since when the shape.all_resize enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__SHAPE_ALL_RESIZE__SINCE: u32 = 2;
 
     /// Since when the error.invalid_shape enum variant is available.
     #[allow(dead_code)]
@@ -348,6 +709,14 @@ impl WpCursorShapeDeviceV1 {
 ///
 /// The names are taken from the CSS W3C specification:
 /// https://w3c.github.io/csswg-drafts/css-ui/#cursor
+/// with a few additions.
+///
+/// Note that there are some groups of cursor shapes that are related:
+/// The first group is drag-and-drop cursors which are used to indicate
+/// the selected action during dnd operations. The second group is resize
+/// cursors which are used to indicate resizing and moving possibilities
+/// on window borders. It is recommended that the shapes in these groups
+/// should use visually compatible images and metaphors.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[allow(dead_code)]
 pub struct WpCursorShapeDeviceV1Shape(pub u32);
@@ -488,6 +857,14 @@ impl WpCursorShapeDeviceV1Shape {
     /// something can be zoomed out
     #[allow(dead_code)]
     pub const ZOOM_OUT: Self = Self(34);
+
+    /// drag-and-drop: the user will select which action will be carried out (non-css value)
+    #[allow(dead_code)]
+    pub const DND_ASK: Self = Self(35);
+
+    /// resizing: something can be moved or resized in any direction (non-css value)
+    #[allow(dead_code)]
+    pub const ALL_RESIZE: Self = Self(36);
 }
 
 impl Debug for WpCursorShapeDeviceV1Shape {
@@ -527,6 +904,8 @@ impl Debug for WpCursorShapeDeviceV1Shape {
             Self::ALL_SCROLL => "ALL_SCROLL",
             Self::ZOOM_IN => "ZOOM_IN",
             Self::ZOOM_OUT => "ZOOM_OUT",
+            Self::DND_ASK => "DND_ASK",
+            Self::ALL_RESIZE => "ALL_RESIZE",
             _ => return Debug::fmt(&self.0, f),
         };
         f.write_str(name)
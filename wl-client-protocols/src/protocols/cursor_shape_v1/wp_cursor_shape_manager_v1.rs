@@ -13,7 +13,7 @@ use {super::super::all_types::*, ::wl_client::builder::prelude::*};
 
 static INTERFACE: wl_interface = wl_interface {
     name: c"wp_cursor_shape_manager_v1".as_ptr(),
-    version: 1,
+    version: 2,
     method_count: 3,
     methods: {
         static MESSAGES: [wl_message; 3] = [
@@ -54,6 +54,69 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wp_cursor_shape_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_cursor_shape_manager_v1",
+    protocol: "cursor_shape_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_pointer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "cursor_shape_device",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_cursor_shape_device_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_tablet_tool_v2",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "cursor_shape_device",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_cursor_shape_device_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tablet_tool",
+                    ty: info::ArgType::Object,
+                    interface: Some("zwp_tablet_tool_v2"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
 /// An owned wp_cursor_shape_manager_v1 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -84,7 +147,7 @@ unsafe impl OwnedProxy for WpCursorShapeManagerV1 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
     const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
         private::EventHandler(private::NoOpEventHandler);
-    const MAX_VERSION: u32 = 1;
+    const MAX_VERSION: u32 = 2;
 
     type Borrowed = WpCursorShapeManagerV1Ref;
     type Api = private::ProxyApi;
@@ -108,11 +171,16 @@ impl Deref for WpCursorShapeManagerV1 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -311,26 +379,87 @@ impl WpCursorShapeManagerV1Ref {
 
 /// An event handler for [WpCursorShapeManagerV1] proxies.
 #[allow(dead_code)]
-pub trait WpCursorShapeManagerV1EventHandler {
-    type Data: 'static;
+pub trait WpCursorShapeManagerV1EventHandler {}
+
+impl WpCursorShapeManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpCursorShapeManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCursorShapeManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpCursorShapeManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpCursorShapeManagerV1] proxies.
+///
+/// This trait is the same as [WpCursorShapeManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCursorShapeManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpCursorShapeManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpCursorShapeManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpCursorShapeManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpCursorShapeManagerV1EventHandler for MutEventHandler<H> where
+    H: WpCursorShapeManagerV1EventHandlerMut
+{
 }
 
-impl WpCursorShapeManagerV1EventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, H> WpCursorShapeManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpCursorShapeManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpCursorShapeManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCursorShapeManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpCursorShapeManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpCursorShapeManagerV1EventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WpCursorShapeManagerV1EventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_cursor_shape_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpCursorShapeManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -359,6 +488,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpCursorShapeManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
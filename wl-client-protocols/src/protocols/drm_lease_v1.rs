@@ -0,0 +1,26 @@
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod wp_drm_lease_connector_v1;
+pub mod wp_drm_lease_device_v1;
+pub mod wp_drm_lease_request_v1;
+pub mod wp_drm_lease_v1;
+
+/// Metadata about the drm_lease_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "drm_lease_v1",
+    interfaces: &[
+        &wp_drm_lease_device_v1::INFO,
+        &wp_drm_lease_connector_v1::INFO,
+        &wp_drm_lease_request_v1::INFO,
+        &wp_drm_lease_v1::INFO,
+    ],
+};
@@ -0,0 +1,1515 @@
+//! a leasable DRM connector
+//!
+//! Represents a DRM connector which is available for lease. These objects are
+//! created via wp_drm_lease_device_v1.connector events, and should be passed
+//! to lease requests via wp_drm_lease_request_v1.request_connector.
+//! Immediately after the wp_drm_lease_connector_v1 object is created the
+//! compositor will send a name, a description, a connector_id and a done
+//! event. When the description is updated the compositor will send a
+//! description event followed by a done event.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_drm_lease_connector_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 5,
+    events: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"name".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"description".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"connector_id".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"withdrawn".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_drm_lease_connector_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_drm_lease_connector_v1",
+    protocol: "drm_lease_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "name",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "description",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "description",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "connector_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "connector_id",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "withdrawn",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned wp_drm_lease_connector_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseConnectorV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_drm_lease_connector_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseConnectorV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpDrmLeaseConnectorV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpDrmLeaseConnectorV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpDrmLeaseConnectorV1 {
+    const INTERFACE: &'static str = "wp_drm_lease_connector_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpDrmLeaseConnectorV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpDrmLeaseConnectorV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpDrmLeaseConnectorV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpDrmLeaseConnectorV1Ref {
+    type Owned = WpDrmLeaseConnectorV1;
+}
+
+impl Deref for WpDrmLeaseConnectorV1 {
+    type Target = WpDrmLeaseConnectorV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpDrmLeaseConnectorV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_connector_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpDrmLeaseConnectorV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_connector_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpDrmLeaseConnectorV1Ref> for WpDrmLeaseConnectorV1 {
+    fn eq(&self, other: &WpDrmLeaseConnectorV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpDrmLeaseConnectorV1> for WpDrmLeaseConnectorV1Ref {
+    fn eq(&self, other: &WpDrmLeaseConnectorV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpDrmLeaseConnectorV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy connector
+    ///
+    /// The client may send this request to indicate that it will not use this
+    /// connector. Clients are encouraged to send this after receiving the
+    /// "withdrawn" event so that the server can release the resources
+    /// associated with this connector offer. Neither existing lease requests
+    /// nor leases will be affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl WpDrmLeaseConnectorV1 {
+    /// Since when the name event is available.
+    #[allow(dead_code)]
+    pub const EVT__NAME__SINCE: u32 = 1;
+
+    /// Since when the description event is available.
+    #[allow(dead_code)]
+    pub const EVT__DESCRIPTION__SINCE: u32 = 1;
+
+    /// Since when the connector_id event is available.
+    #[allow(dead_code)]
+    pub const EVT__CONNECTOR_ID__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the withdrawn event is available.
+    #[allow(dead_code)]
+    pub const EVT__WITHDRAWN__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpDrmLeaseConnectorV1] proxies.
+#[allow(dead_code)]
+pub trait WpDrmLeaseConnectorV1EventHandler {
+    /// name
+    ///
+    /// The compositor sends this event once the connector is created to
+    /// indicate the name of this connector. This will not change for the
+    /// duration of the Wayland session, but is not guaranteed to be consistent
+    /// between sessions.
+    ///
+    /// If the compositor supports wl_output version 4 and this connector
+    /// corresponds to a wl_output, the compositor should use the same name as
+    /// for the wl_output.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: connector name
+    #[inline]
+    fn name(&self, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// description
+    ///
+    /// The compositor sends this event once the connector is created to provide
+    /// a human-readable description for this connector, which may be presented
+    /// to the user. The compositor may send this event multiple times over the
+    /// lifetime of this object to reflect changes in the description.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: connector description
+    #[inline]
+    fn description(&self, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        let _ = description;
+    }
+
+    /// connector_id
+    ///
+    /// The compositor sends this event once the connector is created to
+    /// indicate the DRM object ID which represents the underlying connector
+    /// that is being offered. Note that the final lease may include additional
+    /// object IDs, such as CRTCs and planes.
+    ///
+    /// # Arguments
+    ///
+    /// - `connector_id`: DRM connector ID
+    #[inline]
+    fn connector_id(&self, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        let _ = connector_id;
+    }
+
+    /// all properties have been sent
+    ///
+    /// This event is sent after all properties of a connector have been sent.
+    /// This allows changes to the properties to be seen as atomic even if they
+    /// happen via multiple events.
+    #[inline]
+    fn done(&self, _slf: &WpDrmLeaseConnectorV1Ref) {}
+
+    /// lease offer withdrawn
+    ///
+    /// Sent to indicate that the compositor will no longer honor requests for
+    /// DRM leases which include this connector. The client may still issue a
+    /// lease request including this connector, but the compositor will send
+    /// wp_drm_lease_v1.finished without issuing a lease fd. Compositors are
+    /// encouraged to send this event when they lose access to connector, for
+    /// example when the connector is hot-unplugged, when the connector gets
+    /// leased to a client or when the compositor loses DRM master.
+    ///
+    /// If a client holds a lease for the connector, the status of the lease
+    /// remains the same. The client should destroy the object after receiving
+    /// this event.
+    #[inline]
+    fn withdrawn(&self, _slf: &WpDrmLeaseConnectorV1Ref) {}
+}
+
+impl WpDrmLeaseConnectorV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpDrmLeaseConnectorV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseConnectorV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpDrmLeaseConnectorV1EventHandlerWithData<T> {
+    /// name
+    ///
+    /// The compositor sends this event once the connector is created to
+    /// indicate the name of this connector. This will not change for the
+    /// duration of the Wayland session, but is not guaranteed to be consistent
+    /// between sessions.
+    ///
+    /// If the compositor supports wl_output version 4 and this connector
+    /// corresponds to a wl_output, the compositor should use the same name as
+    /// for the wl_output.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: connector name
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// description
+    ///
+    /// The compositor sends this event once the connector is created to provide
+    /// a human-readable description for this connector, which may be presented
+    /// to the user. The compositor may send this event multiple times over the
+    /// lifetime of this object to reflect changes in the description.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: connector description
+    #[inline]
+    fn description(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        let _ = description;
+    }
+
+    /// connector_id
+    ///
+    /// The compositor sends this event once the connector is created to
+    /// indicate the DRM object ID which represents the underlying connector
+    /// that is being offered. Note that the final lease may include additional
+    /// object IDs, such as CRTCs and planes.
+    ///
+    /// # Arguments
+    ///
+    /// - `connector_id`: DRM connector ID
+    #[inline]
+    fn connector_id(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        let _ = connector_id;
+    }
+
+    /// all properties have been sent
+    ///
+    /// This event is sent after all properties of a connector have been sent.
+    /// This allows changes to the properties to be seen as atomic even if they
+    /// happen via multiple events.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {}
+
+    /// lease offer withdrawn
+    ///
+    /// Sent to indicate that the compositor will no longer honor requests for
+    /// DRM leases which include this connector. The client may still issue a
+    /// lease request including this connector, but the compositor will send
+    /// wp_drm_lease_v1.finished without issuing a lease fd. Compositors are
+    /// encouraged to send this event when they lose access to connector, for
+    /// example when the connector is hot-unplugged, when the connector gets
+    /// leased to a client or when the compositor loses DRM master.
+    ///
+    /// If a client holds a lease for the connector, the status of the lease
+    /// remains the same. The client should destroy the object after receiving
+    /// this event.
+    #[inline]
+    fn withdrawn(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {}
+}
+
+/// A mutable event handler for [WpDrmLeaseConnectorV1] proxies.
+///
+/// This trait is the same as [WpDrmLeaseConnectorV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseConnectorV1EventHandlerMut {
+    /// See [WpDrmLeaseConnectorV1EventHandler::name].
+    #[inline]
+    fn name(&mut self, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::description].
+    #[inline]
+    fn description(&mut self, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        let _ = description;
+    }
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::connector_id].
+    #[inline]
+    fn connector_id(&mut self, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        let _ = connector_id;
+    }
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &WpDrmLeaseConnectorV1Ref) {}
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::withdrawn].
+    #[inline]
+    fn withdrawn(&mut self, _slf: &WpDrmLeaseConnectorV1Ref) {}
+}
+
+/// A mutable event handler for [WpDrmLeaseConnectorV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseConnectorV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseConnectorV1EventHandlerMutWithData<T> {
+    /// See [WpDrmLeaseConnectorV1EventHandler::name].
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::description].
+    #[inline]
+    fn description(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        let _ = description;
+    }
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::connector_id].
+    #[inline]
+    fn connector_id(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        let _ = connector_id;
+    }
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {}
+
+    /// See [WpDrmLeaseConnectorV1EventHandler::withdrawn].
+    #[inline]
+    fn withdrawn(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {}
+}
+
+impl<H> WpDrmLeaseConnectorV1EventHandler for MutEventHandler<H>
+where
+    H: WpDrmLeaseConnectorV1EventHandlerMut,
+{
+    #[inline]
+    fn name(&self, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        self.with(|h| h.name(_slf, name))
+    }
+
+    #[inline]
+    fn description(&self, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        self.with(|h| h.description(_slf, description))
+    }
+
+    #[inline]
+    fn connector_id(&self, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        self.with(|h| h.connector_id(_slf, connector_id))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &WpDrmLeaseConnectorV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn withdrawn(&self, _slf: &WpDrmLeaseConnectorV1Ref) {
+        self.with(|h| h.withdrawn(_slf))
+    }
+}
+
+impl<T, H> WpDrmLeaseConnectorV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpDrmLeaseConnectorV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        self.with(|h| h.name(_data, _slf, name))
+    }
+
+    #[inline]
+    fn description(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        self.with(|h| h.description(_data, _slf, description))
+    }
+
+    #[inline]
+    fn connector_id(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        self.with(|h| h.connector_id(_data, _slf, connector_id))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn withdrawn(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+        self.with(|h| h.withdrawn(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpDrmLeaseConnectorV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpDrmLeaseConnectorV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn description(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        let _data = self.project(_data);
+        self.handler().description(_data, _slf, description)
+    }
+
+    #[inline]
+    fn connector_id(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        let _data = self.project(_data);
+        self.handler().connector_id(_data, _slf, connector_id)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn withdrawn(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+        let _data = self.project(_data);
+        self.handler().withdrawn(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpDrmLeaseConnectorV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpDrmLeaseConnectorV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn description(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().description(_data, _slf, description)
+    }
+
+    #[inline]
+    fn connector_id(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().connector_id(_data, _slf, connector_id)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn withdrawn(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().withdrawn(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpDrmLeaseConnectorV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpDrmLeaseConnectorV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("wp_drm_lease_connector_v1", "name", args[0].s) };
+                self.0.name(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("wp_drm_lease_connector_v1", "description", args[0].s)
+                };
+                self.0.description(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.connector_id(slf, arg0);
+            }
+            3 => {
+                self.0.done(slf);
+            }
+            4 => {
+                self.0.withdrawn(slf);
+            }
+            _ => {
+                invalid_opcode("wp_drm_lease_connector_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpDrmLeaseConnectorV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("wp_drm_lease_connector_v1", "name", args[0].s) };
+                self.0.name(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("wp_drm_lease_connector_v1", "description", args[0].s)
+                };
+                self.0.description(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.connector_id(data, slf, arg0);
+            }
+            3 => {
+                self.0.done(data, slf);
+            }
+            4 => {
+                self.0.withdrawn(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_drm_lease_connector_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpDrmLeaseConnectorV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for name events.
+    pub struct Name<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandler for Name<F>
+    where
+        F: Fn(&WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerWithData<T> for Name<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Event handler for description events.
+    pub struct Description<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandler for Description<F>
+    where
+        F: Fn(&WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn description(&self, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+            self.0(_slf, description)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerWithData<T> for Description<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn description(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+            self.0(_data, _slf, description)
+        }
+    }
+
+    /// Event handler for connector_id events.
+    pub struct ConnectorId<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandler for ConnectorId<F>
+    where
+        F: Fn(&WpDrmLeaseConnectorV1Ref, u32),
+    {
+        #[inline]
+        fn connector_id(&self, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+            self.0(_slf, connector_id)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerWithData<T> for ConnectorId<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, u32),
+    {
+        #[inline]
+        fn connector_id(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+            self.0(_data, _slf, connector_id)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandler for Done<F>
+    where
+        F: Fn(&WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for withdrawn events.
+    pub struct Withdrawn<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandler for Withdrawn<F>
+    where
+        F: Fn(&WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn withdrawn(&self, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerWithData<T> for Withdrawn<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn withdrawn(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for name events.
+    pub struct NameMut<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandlerMut for NameMut<F>
+    where
+        F: FnMut(&WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerMutWithData<T> for NameMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Mutable event handler for description events.
+    pub struct DescriptionMut<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandlerMut for DescriptionMut<F>
+    where
+        F: FnMut(&WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn description(&mut self, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+            self.0(_slf, description)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerMutWithData<T> for DescriptionMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+    {
+        #[inline]
+        fn description(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpDrmLeaseConnectorV1Ref,
+            description: &str,
+        ) {
+            self.0(_data, _slf, description)
+        }
+    }
+
+    /// Mutable event handler for connector_id events.
+    pub struct ConnectorIdMut<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandlerMut for ConnectorIdMut<F>
+    where
+        F: FnMut(&WpDrmLeaseConnectorV1Ref, u32),
+    {
+        #[inline]
+        fn connector_id(&mut self, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+            self.0(_slf, connector_id)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerMutWithData<T> for ConnectorIdMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref, u32),
+    {
+        #[inline]
+        fn connector_id(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpDrmLeaseConnectorV1Ref,
+            connector_id: u32,
+        ) {
+            self.0(_data, _slf, connector_id)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for withdrawn events.
+    pub struct WithdrawnMut<F>(F);
+    impl<F> WpDrmLeaseConnectorV1EventHandlerMut for WithdrawnMut<F>
+    where
+        F: FnMut(&WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn withdrawn(&mut self, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseConnectorV1EventHandlerMutWithData<T> for WithdrawnMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref),
+    {
+        #[inline]
+        fn withdrawn(&mut self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpDrmLeaseConnectorV1 {
+        /// Creates an event handler for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(f: F) -> Name<F>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates an event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(f: F) -> Name<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates an event handler for description events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_description<F>(f: F) -> Description<F>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Description(f)
+        }
+
+        /// Creates an event handler with mutable data for description events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_description_with_data<T, F>(f: F) -> Description<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Description(f)
+        }
+
+        /// Creates an event handler for connector_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_connector_id<F>(f: F) -> ConnectorId<F>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref, u32),
+        {
+            ConnectorId(f)
+        }
+
+        /// Creates an event handler with mutable data for connector_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_connector_id_with_data<T, F>(f: F) -> ConnectorId<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, u32),
+        {
+            ConnectorId(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for withdrawn events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_withdrawn<F>(f: F) -> Withdrawn<F>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref),
+        {
+            Withdrawn(f)
+        }
+
+        /// Creates an event handler with mutable data for withdrawn events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_withdrawn_with_data<T, F>(f: F) -> Withdrawn<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref),
+        {
+            Withdrawn(f)
+        }
+
+        /// Creates a mutable event handler for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut<F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&WpDrmLeaseConnectorV1Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut_with_data<T, F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler for description events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_description_mut<F>(f: F) -> DescriptionMut<F>
+        where
+            F: FnMut(&WpDrmLeaseConnectorV1Ref, &str),
+        {
+            DescriptionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for description events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_description_mut_with_data<T, F>(f: F) -> DescriptionMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+        {
+            DescriptionMut(f)
+        }
+
+        /// Creates a mutable event handler for connector_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_connector_id_mut<F>(f: F) -> ConnectorIdMut<F>
+        where
+            F: FnMut(&WpDrmLeaseConnectorV1Ref, u32),
+        {
+            ConnectorIdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for connector_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_connector_id_mut_with_data<T, F>(f: F) -> ConnectorIdMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref, u32),
+        {
+            ConnectorIdMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&WpDrmLeaseConnectorV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for withdrawn events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_withdrawn_mut<F>(f: F) -> WithdrawnMut<F>
+        where
+            F: FnMut(&WpDrmLeaseConnectorV1Ref),
+        {
+            WithdrawnMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for withdrawn events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_withdrawn_mut_with_data<T, F>(f: F) -> WithdrawnMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseConnectorV1Ref),
+        {
+            WithdrawnMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                name: Ignore,
+                description: Ignore,
+                connector_id: Ignore,
+                done: Ignore,
+                withdrawn: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpDrmLeaseConnectorV1EventHandler for Ignore {}
+    impl<T> WpDrmLeaseConnectorV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpDrmLeaseConnectorV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore> {
+        name: H0,
+        description: H1,
+        connector_id: H2,
+        done: H3,
+        withdrawn: H4,
+    }
+
+    impl<H0, H1, H2, H3, H4> WpDrmLeaseConnectorV1EventHandler for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: WpDrmLeaseConnectorV1EventHandler,
+        H1: WpDrmLeaseConnectorV1EventHandler,
+        H2: WpDrmLeaseConnectorV1EventHandler,
+        H3: WpDrmLeaseConnectorV1EventHandler,
+        H4: WpDrmLeaseConnectorV1EventHandler,
+    {
+        #[inline]
+        fn name(&self, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+            self.name.name(_slf, name)
+        }
+
+        #[inline]
+        fn description(&self, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+            self.description.description(_slf, description)
+        }
+
+        #[inline]
+        fn connector_id(&self, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+            self.connector_id.connector_id(_slf, connector_id)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn withdrawn(&self, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.withdrawn.withdrawn(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4> WpDrmLeaseConnectorV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+        H1: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+        H2: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+        H3: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+        H4: WpDrmLeaseConnectorV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, name: &str) {
+            self.name.name(_data, _slf, name)
+        }
+
+        #[inline]
+        fn description(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, description: &str) {
+            self.description.description(_data, _slf, description)
+        }
+
+        #[inline]
+        fn connector_id(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref, connector_id: u32) {
+            self.connector_id.connector_id(_data, _slf, connector_id)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn withdrawn(&self, _data: &mut T, _slf: &WpDrmLeaseConnectorV1Ref) {
+            self.withdrawn.withdrawn(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4> Handler<H0, H1, H2, H3, H4> {
+        /// Sets the closure for name events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(self, f: F) -> Handler<Name<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Handler {
+                name: Name(f),
+                description: self.description,
+                connector_id: self.connector_id,
+                done: self.done,
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for name events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(self, f: F) -> Handler<Name<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Handler {
+                name: Name(f),
+                description: self.description,
+                connector_id: self.connector_id,
+                done: self.done,
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure for description events.
+        #[allow(dead_code)]
+        pub fn on_description<F>(self, f: F) -> Handler<H0, Description<F>, H2, H3, H4>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Handler {
+                name: self.name,
+                description: Description(f),
+                connector_id: self.connector_id,
+                done: self.done,
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for description events.
+        #[allow(dead_code)]
+        pub fn on_description_with_data<T, F>(self, f: F) -> Handler<H0, Description<F>, H2, H3, H4>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, &str),
+        {
+            Handler {
+                name: self.name,
+                description: Description(f),
+                connector_id: self.connector_id,
+                done: self.done,
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure for connector_id events.
+        #[allow(dead_code)]
+        pub fn on_connector_id<F>(self, f: F) -> Handler<H0, H1, ConnectorId<F>, H3, H4>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref, u32),
+        {
+            Handler {
+                name: self.name,
+                description: self.description,
+                connector_id: ConnectorId(f),
+                done: self.done,
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for connector_id events.
+        #[allow(dead_code)]
+        pub fn on_connector_id_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, ConnectorId<F>, H3, H4>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref, u32),
+        {
+            Handler {
+                name: self.name,
+                description: self.description,
+                connector_id: ConnectorId(f),
+                done: self.done,
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, H2, Done<F>, H4>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref),
+        {
+            Handler {
+                name: self.name,
+                description: self.description,
+                connector_id: self.connector_id,
+                done: Done(f),
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Done<F>, H4>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref),
+        {
+            Handler {
+                name: self.name,
+                description: self.description,
+                connector_id: self.connector_id,
+                done: Done(f),
+                withdrawn: self.withdrawn,
+            }
+        }
+
+        /// Sets the closure for withdrawn events.
+        #[allow(dead_code)]
+        pub fn on_withdrawn<F>(self, f: F) -> Handler<H0, H1, H2, H3, Withdrawn<F>>
+        where
+            F: Fn(&WpDrmLeaseConnectorV1Ref),
+        {
+            Handler {
+                name: self.name,
+                description: self.description,
+                connector_id: self.connector_id,
+                done: self.done,
+                withdrawn: Withdrawn(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for withdrawn events.
+        #[allow(dead_code)]
+        pub fn on_withdrawn_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Withdrawn<F>>
+        where
+            F: Fn(&mut T, &WpDrmLeaseConnectorV1Ref),
+        {
+            Handler {
+                name: self.name,
+                description: self.description,
+                connector_id: self.connector_id,
+                done: self.done,
+                withdrawn: Withdrawn(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1406 @@
+//! lease device
+//!
+//! This protocol is used by Wayland compositors which act as Direct
+//! Rendering Manager (DRM) masters to lease DRM resources to Wayland
+//! clients.
+//!
+//! The compositor will advertise one wp_drm_lease_device_v1 global for each
+//! DRM node. Some time after a client binds to the wp_drm_lease_device_v1
+//! global, the compositor will send a drm_fd event followed by zero, one or
+//! more connector events. After all currently available connectors have been
+//! sent, the compositor will send a wp_drm_lease_device_v1.done event.
+//!
+//! When the list of connectors available for lease changes the compositor
+//! will send wp_drm_lease_device_v1.connector events for added connectors and
+//! wp_drm_lease_connector_v1.withdrawn events for removed connectors,
+//! followed by a wp_drm_lease_device_v1.done event.
+//!
+//! The compositor will indicate when a device is gone by removing the global
+//! via a wl_registry.global_remove event. Upon receiving this event, the
+//! client should destroy any matching wp_drm_lease_device_v1 object.
+//!
+//! To destroy a wp_drm_lease_device_v1 object, the client must first issue
+//! a release request. Upon receiving this request, the compositor will
+//! immediately send a released event and destroy the object. The client must
+//! continue to process and discard drm_fd and connector events until it
+//! receives the released event. Upon receiving the released event, the
+//! client can safely cleanup any client-side resources.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_drm_lease_device_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"create_lease_request".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpDrmLeaseRequestV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"release".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 4,
+    events: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"drm_fd".as_ptr(),
+                signature: c"h".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"connector".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpDrmLeaseConnectorV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"released".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_drm_lease_device_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_drm_lease_device_v1",
+    protocol: "drm_lease_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_lease_request",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_drm_lease_request_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "release",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "drm_fd",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "fd",
+                ty: info::ArgType::Fd,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "connector",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_drm_lease_connector_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "released",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned wp_drm_lease_device_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseDeviceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_drm_lease_device_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseDeviceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpDrmLeaseDeviceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpDrmLeaseDeviceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpDrmLeaseDeviceV1 {
+    const INTERFACE: &'static str = "wp_drm_lease_device_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpDrmLeaseDeviceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpDrmLeaseDeviceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpDrmLeaseDeviceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpDrmLeaseDeviceV1Ref {
+    type Owned = WpDrmLeaseDeviceV1;
+}
+
+impl Deref for WpDrmLeaseDeviceV1 {
+    type Target = WpDrmLeaseDeviceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpDrmLeaseDeviceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_device_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpDrmLeaseDeviceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_device_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpDrmLeaseDeviceV1Ref> for WpDrmLeaseDeviceV1 {
+    fn eq(&self, other: &WpDrmLeaseDeviceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpDrmLeaseDeviceV1> for WpDrmLeaseDeviceV1Ref {
+    fn eq(&self, other: &WpDrmLeaseDeviceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpDrmLeaseDeviceV1 {
+    /// Since when the create_lease_request request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_LEASE_REQUEST__SINCE: u32 = 1;
+
+    /// create a lease request object
+    ///
+    /// Creates a lease request object.
+    ///
+    /// See the documentation for wp_drm_lease_request_v1 for details.
+    #[inline]
+    pub fn create_lease_request(&self) -> WpDrmLeaseRequestV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                WpDrmLeaseRequestV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpDrmLeaseRequestV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpDrmLeaseDeviceV1Ref {
+    /// create a lease request object
+    ///
+    /// Creates a lease request object.
+    ///
+    /// See the documentation for wp_drm_lease_request_v1 for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_lease_request(&self, _queue: &Queue) -> WpDrmLeaseRequestV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                WpDrmLeaseRequestV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpDrmLeaseRequestV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// release this object
+    ///
+    /// Indicates the client no longer wishes to use this object. In response
+    /// the compositor will immediately send the released event and destroy
+    /// this object. It can however not guarantee that the client won't receive
+    /// connector events before the released event. The client must not send any
+    /// requests after this one, doing so will raise a wl_display error.
+    /// Existing connectors, lease request and leases will not be affected.
+    #[inline]
+    pub fn release(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+impl WpDrmLeaseDeviceV1 {
+    /// Since when the drm_fd event is available.
+    #[allow(dead_code)]
+    pub const EVT__DRM_FD__SINCE: u32 = 1;
+
+    /// Since when the connector event is available.
+    #[allow(dead_code)]
+    pub const EVT__CONNECTOR__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the released event is available.
+    #[allow(dead_code)]
+    pub const EVT__RELEASED__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpDrmLeaseDeviceV1] proxies.
+#[allow(dead_code)]
+pub trait WpDrmLeaseDeviceV1EventHandler {
+    /// open a non-master fd for this DRM node
+    ///
+    /// The compositor will send this event when the wp_drm_lease_device_v1
+    /// global is bound, although there are no guarantees as to how long this
+    /// takes - the compositor might need to wait until regaining DRM master.
+    /// The included fd is a non-master DRM file descriptor opened for this
+    /// device and the compositor must not authenticate it.
+    /// The purpose of this event is to give the client the ability to
+    /// query DRM and discover information which may help them pick the
+    /// appropriate DRM device or select the appropriate connectors therein.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: DRM file descriptor
+    #[inline]
+    fn drm_fd(&self, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        let _ = fd;
+    }
+
+    /// advertise connectors available for leases
+    ///
+    /// The compositor will use this event to advertise connectors available for
+    /// lease by clients. This object may be passed into a lease request to
+    /// indicate the client would like to lease that connector, see
+    /// wp_drm_lease_request_v1.request_connector for details. While the
+    /// compositor will make a best effort to not send disconnected connectors,
+    /// no guarantees can be made.
+    ///
+    /// The compositor must send the drm_fd event before sending connectors.
+    /// After the drm_fd event it will send all available connectors but may
+    /// send additional connectors at any time.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn connector(&self, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+        let _ = id;
+    }
+
+    /// signals grouping of connectors
+    ///
+    /// The compositor will send this event to indicate that it has sent all
+    /// currently available connectors after the client binds to the global or
+    /// when it updates the connector list, for example on hotplug, drm master
+    /// change or when a leased connector becomes available again. It will
+    /// similarly send this event to group wp_drm_lease_connector_v1.withdrawn
+    /// events of connectors of this device.
+    #[inline]
+    fn done(&self, _slf: &WpDrmLeaseDeviceV1Ref) {}
+
+    /// the compositor has finished using the device
+    ///
+    /// This event is sent in response to the release request and indicates
+    /// that the compositor is done sending connector events.
+    /// The compositor will destroy this object immediately after sending the
+    /// event and it will become invalid. The client should release any
+    /// resources associated with this device after receiving this event.
+    #[inline]
+    fn released(&self, _slf: &WpDrmLeaseDeviceV1Ref) {}
+}
+
+impl WpDrmLeaseDeviceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpDrmLeaseDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseDeviceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpDrmLeaseDeviceV1EventHandlerWithData<T> {
+    /// open a non-master fd for this DRM node
+    ///
+    /// The compositor will send this event when the wp_drm_lease_device_v1
+    /// global is bound, although there are no guarantees as to how long this
+    /// takes - the compositor might need to wait until regaining DRM master.
+    /// The included fd is a non-master DRM file descriptor opened for this
+    /// device and the compositor must not authenticate it.
+    /// The purpose of this event is to give the client the ability to
+    /// query DRM and discover information which may help them pick the
+    /// appropriate DRM device or select the appropriate connectors therein.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: DRM file descriptor
+    #[inline]
+    fn drm_fd(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        let _ = fd;
+    }
+
+    /// advertise connectors available for leases
+    ///
+    /// The compositor will use this event to advertise connectors available for
+    /// lease by clients. This object may be passed into a lease request to
+    /// indicate the client would like to lease that connector, see
+    /// wp_drm_lease_request_v1.request_connector for details. While the
+    /// compositor will make a best effort to not send disconnected connectors,
+    /// no guarantees can be made.
+    ///
+    /// The compositor must send the drm_fd event before sending connectors.
+    /// After the drm_fd event it will send all available connectors but may
+    /// send additional connectors at any time.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn connector(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+        let _ = id;
+    }
+
+    /// signals grouping of connectors
+    ///
+    /// The compositor will send this event to indicate that it has sent all
+    /// currently available connectors after the client binds to the global or
+    /// when it updates the connector list, for example on hotplug, drm master
+    /// change or when a leased connector becomes available again. It will
+    /// similarly send this event to group wp_drm_lease_connector_v1.withdrawn
+    /// events of connectors of this device.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {}
+
+    /// the compositor has finished using the device
+    ///
+    /// This event is sent in response to the release request and indicates
+    /// that the compositor is done sending connector events.
+    /// The compositor will destroy this object immediately after sending the
+    /// event and it will become invalid. The client should release any
+    /// resources associated with this device after receiving this event.
+    #[inline]
+    fn released(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {}
+}
+
+/// A mutable event handler for [WpDrmLeaseDeviceV1] proxies.
+///
+/// This trait is the same as [WpDrmLeaseDeviceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseDeviceV1EventHandlerMut {
+    /// See [WpDrmLeaseDeviceV1EventHandler::drm_fd].
+    #[inline]
+    fn drm_fd(&mut self, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        let _ = fd;
+    }
+
+    /// See [WpDrmLeaseDeviceV1EventHandler::connector].
+    #[inline]
+    fn connector(&mut self, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+        let _ = id;
+    }
+
+    /// See [WpDrmLeaseDeviceV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &WpDrmLeaseDeviceV1Ref) {}
+
+    /// See [WpDrmLeaseDeviceV1EventHandler::released].
+    #[inline]
+    fn released(&mut self, _slf: &WpDrmLeaseDeviceV1Ref) {}
+}
+
+/// A mutable event handler for [WpDrmLeaseDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseDeviceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseDeviceV1EventHandlerMutWithData<T> {
+    /// See [WpDrmLeaseDeviceV1EventHandler::drm_fd].
+    #[inline]
+    fn drm_fd(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        let _ = fd;
+    }
+
+    /// See [WpDrmLeaseDeviceV1EventHandler::connector].
+    #[inline]
+    fn connector(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpDrmLeaseDeviceV1Ref,
+        id: WpDrmLeaseConnectorV1,
+    ) {
+        let _ = id;
+    }
+
+    /// See [WpDrmLeaseDeviceV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {}
+
+    /// See [WpDrmLeaseDeviceV1EventHandler::released].
+    #[inline]
+    fn released(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {}
+}
+
+impl<H> WpDrmLeaseDeviceV1EventHandler for MutEventHandler<H>
+where
+    H: WpDrmLeaseDeviceV1EventHandlerMut,
+{
+    #[inline]
+    fn drm_fd(&self, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        self.with(|h| h.drm_fd(_slf, fd))
+    }
+
+    #[inline]
+    fn connector(&self, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+        self.with(|h| h.connector(_slf, id))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &WpDrmLeaseDeviceV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn released(&self, _slf: &WpDrmLeaseDeviceV1Ref) {
+        self.with(|h| h.released(_slf))
+    }
+}
+
+impl<T, H> WpDrmLeaseDeviceV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpDrmLeaseDeviceV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn drm_fd(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        self.with(|h| h.drm_fd(_data, _slf, fd))
+    }
+
+    #[inline]
+    fn connector(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+        self.with(|h| h.connector(_data, _slf, id))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn released(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+        self.with(|h| h.released(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpDrmLeaseDeviceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpDrmLeaseDeviceV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn drm_fd(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler().drm_fd(_data, _slf, fd)
+    }
+
+    #[inline]
+    fn connector(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+        let _data = self.project(_data);
+        self.handler().connector(_data, _slf, id)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn released(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+        let _data = self.project(_data);
+        self.handler().released(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpDrmLeaseDeviceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpDrmLeaseDeviceV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn drm_fd(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler_mut().drm_fd(_data, _slf, fd)
+    }
+
+    #[inline]
+    fn connector(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpDrmLeaseDeviceV1Ref,
+        id: WpDrmLeaseConnectorV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().connector(_data, _slf, id)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn released(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().released(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpDrmLeaseDeviceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpDrmLeaseDeviceV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                self.0.drm_fd(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface WpDrmLeaseConnectorV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        WpDrmLeaseConnectorV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WpDrmLeaseConnectorV1::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<WpDrmLeaseConnectorV1>(arg0) };
+                self.0.connector(slf, arg0);
+            }
+            2 => {
+                self.0.done(slf);
+            }
+            3 => {
+                self.0.released(slf);
+            }
+            _ => {
+                invalid_opcode("wp_drm_lease_device_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpDrmLeaseDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpDrmLeaseDeviceV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                self.0.drm_fd(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface WpDrmLeaseConnectorV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        WpDrmLeaseConnectorV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WpDrmLeaseConnectorV1::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<WpDrmLeaseConnectorV1>(arg0) };
+                self.0.connector(data, slf, arg0);
+            }
+            2 => {
+                self.0.done(data, slf);
+            }
+            3 => {
+                self.0.released(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_drm_lease_device_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpDrmLeaseDeviceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpDrmLeaseDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for drm_fd events.
+    pub struct DrmFd<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandler for DrmFd<F>
+    where
+        F: Fn(&WpDrmLeaseDeviceV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn drm_fd(&self, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+            self.0(_slf, fd)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerWithData<T> for DrmFd<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn drm_fd(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+            self.0(_data, _slf, fd)
+        }
+    }
+
+    /// Event handler for connector events.
+    pub struct Connector<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandler for Connector<F>
+    where
+        F: Fn(&WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+    {
+        #[inline]
+        fn connector(&self, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerWithData<T> for Connector<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+    {
+        #[inline]
+        fn connector(
+            &self,
+            _data: &mut T,
+            _slf: &WpDrmLeaseDeviceV1Ref,
+            id: WpDrmLeaseConnectorV1,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandler for Done<F>
+    where
+        F: Fn(&WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for released events.
+    pub struct Released<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandler for Released<F>
+    where
+        F: Fn(&WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn released(&self, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerWithData<T> for Released<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn released(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for drm_fd events.
+    pub struct DrmFdMut<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandlerMut for DrmFdMut<F>
+    where
+        F: FnMut(&WpDrmLeaseDeviceV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn drm_fd(&mut self, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+            self.0(_slf, fd)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerMutWithData<T> for DrmFdMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn drm_fd(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+            self.0(_data, _slf, fd)
+        }
+    }
+
+    /// Mutable event handler for connector events.
+    pub struct ConnectorMut<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandlerMut for ConnectorMut<F>
+    where
+        F: FnMut(&WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+    {
+        #[inline]
+        fn connector(&mut self, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerMutWithData<T> for ConnectorMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+    {
+        #[inline]
+        fn connector(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpDrmLeaseDeviceV1Ref,
+            id: WpDrmLeaseConnectorV1,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for released events.
+    pub struct ReleasedMut<F>(F);
+    impl<F> WpDrmLeaseDeviceV1EventHandlerMut for ReleasedMut<F>
+    where
+        F: FnMut(&WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn released(&mut self, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseDeviceV1EventHandlerMutWithData<T> for ReleasedMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref),
+    {
+        #[inline]
+        fn released(&mut self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpDrmLeaseDeviceV1 {
+        /// Creates an event handler for drm_fd events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_drm_fd<F>(f: F) -> DrmFd<F>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref, OwnedFd),
+        {
+            DrmFd(f)
+        }
+
+        /// Creates an event handler with mutable data for drm_fd events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_drm_fd_with_data<T, F>(f: F) -> DrmFd<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref, OwnedFd),
+        {
+            DrmFd(f)
+        }
+
+        /// Creates an event handler for connector events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_connector<F>(f: F) -> Connector<F>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+        {
+            Connector(f)
+        }
+
+        /// Creates an event handler with mutable data for connector events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_connector_with_data<T, F>(f: F) -> Connector<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+        {
+            Connector(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for released events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_released<F>(f: F) -> Released<F>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref),
+        {
+            Released(f)
+        }
+
+        /// Creates an event handler with mutable data for released events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_released_with_data<T, F>(f: F) -> Released<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref),
+        {
+            Released(f)
+        }
+
+        /// Creates a mutable event handler for drm_fd events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_drm_fd_mut<F>(f: F) -> DrmFdMut<F>
+        where
+            F: FnMut(&WpDrmLeaseDeviceV1Ref, OwnedFd),
+        {
+            DrmFdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for drm_fd events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_drm_fd_mut_with_data<T, F>(f: F) -> DrmFdMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref, OwnedFd),
+        {
+            DrmFdMut(f)
+        }
+
+        /// Creates a mutable event handler for connector events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_connector_mut<F>(f: F) -> ConnectorMut<F>
+        where
+            F: FnMut(&WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+        {
+            ConnectorMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for connector events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_connector_mut_with_data<T, F>(f: F) -> ConnectorMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+        {
+            ConnectorMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&WpDrmLeaseDeviceV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for released events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_released_mut<F>(f: F) -> ReleasedMut<F>
+        where
+            F: FnMut(&WpDrmLeaseDeviceV1Ref),
+        {
+            ReleasedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for released events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_released_mut_with_data<T, F>(f: F) -> ReleasedMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseDeviceV1Ref),
+        {
+            ReleasedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                drm_fd: Ignore,
+                connector: Ignore,
+                done: Ignore,
+                released: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpDrmLeaseDeviceV1EventHandler for Ignore {}
+    impl<T> WpDrmLeaseDeviceV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpDrmLeaseDeviceV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        drm_fd: H0,
+        connector: H1,
+        done: H2,
+        released: H3,
+    }
+
+    impl<H0, H1, H2, H3> WpDrmLeaseDeviceV1EventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: WpDrmLeaseDeviceV1EventHandler,
+        H1: WpDrmLeaseDeviceV1EventHandler,
+        H2: WpDrmLeaseDeviceV1EventHandler,
+        H3: WpDrmLeaseDeviceV1EventHandler,
+    {
+        #[inline]
+        fn drm_fd(&self, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+            self.drm_fd.drm_fd(_slf, fd)
+        }
+
+        #[inline]
+        fn connector(&self, _slf: &WpDrmLeaseDeviceV1Ref, id: WpDrmLeaseConnectorV1) {
+            self.connector.connector(_slf, id)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn released(&self, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.released.released(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> WpDrmLeaseDeviceV1EventHandlerWithData<T> for Handler<H0, H1, H2, H3>
+    where
+        H0: WpDrmLeaseDeviceV1EventHandlerWithData<T>,
+        H1: WpDrmLeaseDeviceV1EventHandlerWithData<T>,
+        H2: WpDrmLeaseDeviceV1EventHandlerWithData<T>,
+        H3: WpDrmLeaseDeviceV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn drm_fd(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref, fd: OwnedFd) {
+            self.drm_fd.drm_fd(_data, _slf, fd)
+        }
+
+        #[inline]
+        fn connector(
+            &self,
+            _data: &mut T,
+            _slf: &WpDrmLeaseDeviceV1Ref,
+            id: WpDrmLeaseConnectorV1,
+        ) {
+            self.connector.connector(_data, _slf, id)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn released(&self, _data: &mut T, _slf: &WpDrmLeaseDeviceV1Ref) {
+            self.released.released(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for drm_fd events.
+        #[allow(dead_code)]
+        pub fn on_drm_fd<F>(self, f: F) -> Handler<DrmFd<F>, H1, H2, H3>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref, OwnedFd),
+        {
+            Handler {
+                drm_fd: DrmFd(f),
+                connector: self.connector,
+                done: self.done,
+                released: self.released,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for drm_fd events.
+        #[allow(dead_code)]
+        pub fn on_drm_fd_with_data<T, F>(self, f: F) -> Handler<DrmFd<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref, OwnedFd),
+        {
+            Handler {
+                drm_fd: DrmFd(f),
+                connector: self.connector,
+                done: self.done,
+                released: self.released,
+            }
+        }
+
+        /// Sets the closure for connector events.
+        #[allow(dead_code)]
+        pub fn on_connector<F>(self, f: F) -> Handler<H0, Connector<F>, H2, H3>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+        {
+            Handler {
+                drm_fd: self.drm_fd,
+                connector: Connector(f),
+                done: self.done,
+                released: self.released,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for connector events.
+        #[allow(dead_code)]
+        pub fn on_connector_with_data<T, F>(self, f: F) -> Handler<H0, Connector<F>, H2, H3>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref, WpDrmLeaseConnectorV1),
+        {
+            Handler {
+                drm_fd: self.drm_fd,
+                connector: Connector(f),
+                done: self.done,
+                released: self.released,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, Done<F>, H3>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref),
+        {
+            Handler {
+                drm_fd: self.drm_fd,
+                connector: self.connector,
+                done: Done(f),
+                released: self.released,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, Done<F>, H3>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref),
+        {
+            Handler {
+                drm_fd: self.drm_fd,
+                connector: self.connector,
+                done: Done(f),
+                released: self.released,
+            }
+        }
+
+        /// Sets the closure for released events.
+        #[allow(dead_code)]
+        pub fn on_released<F>(self, f: F) -> Handler<H0, H1, H2, Released<F>>
+        where
+            F: Fn(&WpDrmLeaseDeviceV1Ref),
+        {
+            Handler {
+                drm_fd: self.drm_fd,
+                connector: self.connector,
+                done: self.done,
+                released: Released(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for released events.
+        #[allow(dead_code)]
+        pub fn on_released_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Released<F>>
+        where
+            F: Fn(&mut T, &WpDrmLeaseDeviceV1Ref),
+        {
+            Handler {
+                drm_fd: self.drm_fd,
+                connector: self.connector,
+                done: self.done,
+                released: Released(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! DRM lease request
+//!
+//! A client that wishes to lease DRM resources will attach the list of
+//! connectors advertised with wp_drm_lease_device_v1.connector that they
+//! wish to lease, then use wp_drm_lease_request_v1.submit to submit the
+//! request.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_drm_lease_request_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"request_connector".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpDrmLeaseConnectorV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"submit".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WpDrmLeaseV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_drm_lease_request_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_drm_lease_request_v1",
+    protocol: "drm_lease_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "request_connector",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "connector",
+                ty: info::ArgType::Object,
+                interface: Some("wp_drm_lease_connector_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "submit",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wp_drm_lease_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "wrong_device",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "duplicate_connector",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "empty_lease",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_drm_lease_request_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseRequestV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_drm_lease_request_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseRequestV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpDrmLeaseRequestV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpDrmLeaseRequestV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpDrmLeaseRequestV1 {
+    const INTERFACE: &'static str = "wp_drm_lease_request_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpDrmLeaseRequestV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpDrmLeaseRequestV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpDrmLeaseRequestV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpDrmLeaseRequestV1Ref {
+    type Owned = WpDrmLeaseRequestV1;
+}
+
+impl Deref for WpDrmLeaseRequestV1 {
+    type Target = WpDrmLeaseRequestV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpDrmLeaseRequestV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_request_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpDrmLeaseRequestV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_request_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpDrmLeaseRequestV1Ref> for WpDrmLeaseRequestV1 {
+    fn eq(&self, other: &WpDrmLeaseRequestV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpDrmLeaseRequestV1> for WpDrmLeaseRequestV1Ref {
+    fn eq(&self, other: &WpDrmLeaseRequestV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpDrmLeaseRequestV1 {
+    /// Since when the submit request is available.
+    #[allow(dead_code)]
+    pub const REQ__SUBMIT__SINCE: u32 = 1;
+
+    /// submit the lease request
+    ///
+    /// Submits the lease request and creates a new wp_drm_lease_v1 object.
+    /// After calling submit the compositor will immediately destroy this
+    /// object, issuing any more requests will cause a wl_display error.
+    /// The compositor doesn't make any guarantees about the events of the
+    /// lease object, clients cannot expect an immediate response.
+    /// Not requesting any connectors before submitting the lease request
+    /// will raise the empty_lease error.
+    #[inline]
+    pub fn submit(&self) -> WpDrmLeaseV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<true>(1, &mut args, WpDrmLeaseV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpDrmLeaseV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpDrmLeaseRequestV1Ref {
+    /// request a connector for this lease
+    ///
+    /// Indicates that the client would like to lease the given connector.
+    /// This is only used as a suggestion, the compositor may choose to
+    /// include any resources in the lease it issues, or change the set of
+    /// leased resources at any time. Compositors are however encouraged to
+    /// include the requested connector and other resources necessary
+    /// to drive the connected output in the lease.
+    ///
+    /// Requesting a connector that was created from a different lease device
+    /// than this lease request raises the wrong_device error. Requesting a
+    /// connector twice will raise the duplicate_connector error.
+    ///
+    /// # Arguments
+    ///
+    /// - `connector`:
+    #[inline]
+    pub fn request_connector(&self, connector: &WpDrmLeaseConnectorV1Ref) {
+        let (arg0,) = (connector,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("connector", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `o`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpDrmLeaseRequestV1] proxies.
+#[allow(dead_code)]
+pub trait WpDrmLeaseRequestV1EventHandler {}
+
+impl WpDrmLeaseRequestV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpDrmLeaseRequestV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseRequestV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpDrmLeaseRequestV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpDrmLeaseRequestV1] proxies.
+///
+/// This trait is the same as [WpDrmLeaseRequestV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseRequestV1EventHandlerMut {}
+
+/// A mutable event handler for [WpDrmLeaseRequestV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseRequestV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseRequestV1EventHandlerMutWithData<T> {}
+
+impl<H> WpDrmLeaseRequestV1EventHandler for MutEventHandler<H> where
+    H: WpDrmLeaseRequestV1EventHandlerMut
+{
+}
+
+impl<T, H> WpDrmLeaseRequestV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpDrmLeaseRequestV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpDrmLeaseRequestV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpDrmLeaseRequestV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpDrmLeaseRequestV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpDrmLeaseRequestV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpDrmLeaseRequestV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_drm_lease_request_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpDrmLeaseRequestV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_drm_lease_request_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpDrmLeaseRequestV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpDrmLeaseRequestV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpDrmLeaseRequestV1 {
+    /// Since when the error.wrong_device enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_WRONG_DEVICE__SINCE: u32 = 1;
+    /// Since when the error.duplicate_connector enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DUPLICATE_CONNECTOR__SINCE: u32 = 1;
+    /// Since when the error.empty_lease enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_EMPTY_LEASE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpDrmLeaseRequestV1Error(pub u32);
+
+impl WpDrmLeaseRequestV1Error {
+    /// requested a connector from a different lease device
+    #[allow(dead_code)]
+    pub const WRONG_DEVICE: Self = Self(0);
+
+    /// requested a connector twice
+    #[allow(dead_code)]
+    pub const DUPLICATE_CONNECTOR: Self = Self(1);
+
+    /// requested a lease without requesting a connector
+    #[allow(dead_code)]
+    pub const EMPTY_LEASE: Self = Self(2);
+}
+
+impl Debug for WpDrmLeaseRequestV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::WRONG_DEVICE => "WRONG_DEVICE",
+            Self::DUPLICATE_CONNECTOR => "DUPLICATE_CONNECTOR",
+            Self::EMPTY_LEASE => "EMPTY_LEASE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpDrmLeaseRequestV1 {}
+}
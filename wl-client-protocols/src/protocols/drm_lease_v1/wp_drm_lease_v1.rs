@@ -0,0 +1,809 @@
+//! a DRM lease
+//!
+//! A DRM lease object is used to transfer the DRM file descriptor to the
+//! client and manage the lifetime of the lease.
+//!
+//! Some time after the wp_drm_lease_v1 object is created, the compositor
+//! will reply with the lease request's result. If the lease request is
+//! granted, the compositor will send a lease_fd event. If the lease request
+//! is denied, the compositor will send a finished event without a lease_fd
+//! event.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_drm_lease_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"lease_fd".as_ptr(),
+                signature: c"h".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"finished".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_drm_lease_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_drm_lease_v1",
+    protocol: "drm_lease_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "lease_fd",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "leased_fd",
+                ty: info::ArgType::Fd,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "finished",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned wp_drm_lease_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_drm_lease_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpDrmLeaseV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpDrmLeaseV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpDrmLeaseV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpDrmLeaseV1 {
+    const INTERFACE: &'static str = "wp_drm_lease_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpDrmLeaseV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpDrmLeaseV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpDrmLeaseV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpDrmLeaseV1Ref {
+    type Owned = WpDrmLeaseV1;
+}
+
+impl Deref for WpDrmLeaseV1 {
+    type Target = WpDrmLeaseV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpDrmLeaseV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpDrmLeaseV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_drm_lease_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpDrmLeaseV1Ref> for WpDrmLeaseV1 {
+    fn eq(&self, other: &WpDrmLeaseV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpDrmLeaseV1> for WpDrmLeaseV1Ref {
+    fn eq(&self, other: &WpDrmLeaseV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpDrmLeaseV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroys the lease object
+    ///
+    /// The client should send this to indicate that it no longer wishes to use
+    /// this lease. The compositor should use drmModeRevokeLease on the
+    /// appropriate file descriptor, if necessary.
+    ///
+    /// Upon destruction, the compositor should advertise the connector for
+    /// leasing again by sending the connector event through the
+    /// wp_drm_lease_device_v1 interface.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl WpDrmLeaseV1 {
+    /// Since when the lease_fd event is available.
+    #[allow(dead_code)]
+    pub const EVT__LEASE_FD__SINCE: u32 = 1;
+
+    /// Since when the finished event is available.
+    #[allow(dead_code)]
+    pub const EVT__FINISHED__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpDrmLeaseV1] proxies.
+#[allow(dead_code)]
+pub trait WpDrmLeaseV1EventHandler {
+    /// shares the DRM file descriptor
+    ///
+    /// This event returns a file descriptor suitable for use with DRM-related
+    /// ioctls. The client should use drmModeGetLease to enumerate the DRM
+    /// objects which have been leased to them. The compositor guarantees it
+    /// will not use the leased DRM objects itself until it sends the finished
+    /// event. If the compositor cannot or will not grant a lease for the
+    /// requested connectors, it will not send this event, instead sending the
+    /// finished event.
+    ///
+    /// The compositor will send this event at most once during this objects
+    /// lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// - `leased_fd`: leased DRM file descriptor
+    #[inline]
+    fn lease_fd(&self, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        let _ = leased_fd;
+    }
+
+    /// sent when the lease has been revoked
+    ///
+    /// The compositor uses this event to either reject a lease request, or if
+    /// it previously sent a lease_fd, to notify the client that the lease has
+    /// been revoked. If the client requires a new lease, they should destroy
+    /// this object and submit a new lease request. The compositor will send
+    /// no further events for this object after sending the finish event.
+    /// Compositors should revoke the lease when any of the leased resources
+    /// become unavailable, namely when a hot-unplug occurs or when the
+    /// compositor loses DRM master. Compositors may advertise the connector
+    /// for leasing again, if the resource is available, by sending the
+    /// connector event through the wp_drm_lease_device_v1 interface.
+    #[inline]
+    fn finished(&self, _slf: &WpDrmLeaseV1Ref) {}
+}
+
+impl WpDrmLeaseV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpDrmLeaseV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpDrmLeaseV1EventHandlerWithData<T> {
+    /// shares the DRM file descriptor
+    ///
+    /// This event returns a file descriptor suitable for use with DRM-related
+    /// ioctls. The client should use drmModeGetLease to enumerate the DRM
+    /// objects which have been leased to them. The compositor guarantees it
+    /// will not use the leased DRM objects itself until it sends the finished
+    /// event. If the compositor cannot or will not grant a lease for the
+    /// requested connectors, it will not send this event, instead sending the
+    /// finished event.
+    ///
+    /// The compositor will send this event at most once during this objects
+    /// lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// - `leased_fd`: leased DRM file descriptor
+    #[inline]
+    fn lease_fd(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        let _ = leased_fd;
+    }
+
+    /// sent when the lease has been revoked
+    ///
+    /// The compositor uses this event to either reject a lease request, or if
+    /// it previously sent a lease_fd, to notify the client that the lease has
+    /// been revoked. If the client requires a new lease, they should destroy
+    /// this object and submit a new lease request. The compositor will send
+    /// no further events for this object after sending the finish event.
+    /// Compositors should revoke the lease when any of the leased resources
+    /// become unavailable, namely when a hot-unplug occurs or when the
+    /// compositor loses DRM master. Compositors may advertise the connector
+    /// for leasing again, if the resource is available, by sending the
+    /// connector event through the wp_drm_lease_device_v1 interface.
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {}
+}
+
+/// A mutable event handler for [WpDrmLeaseV1] proxies.
+///
+/// This trait is the same as [WpDrmLeaseV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseV1EventHandlerMut {
+    /// See [WpDrmLeaseV1EventHandler::lease_fd].
+    #[inline]
+    fn lease_fd(&mut self, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        let _ = leased_fd;
+    }
+
+    /// See [WpDrmLeaseV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _slf: &WpDrmLeaseV1Ref) {}
+}
+
+/// A mutable event handler for [WpDrmLeaseV1] proxies with mutable data.
+///
+/// This trait is the same as [WpDrmLeaseV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpDrmLeaseV1EventHandlerMutWithData<T> {
+    /// See [WpDrmLeaseV1EventHandler::lease_fd].
+    #[inline]
+    fn lease_fd(&mut self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        let _ = leased_fd;
+    }
+
+    /// See [WpDrmLeaseV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {}
+}
+
+impl<H> WpDrmLeaseV1EventHandler for MutEventHandler<H>
+where
+    H: WpDrmLeaseV1EventHandlerMut,
+{
+    #[inline]
+    fn lease_fd(&self, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        self.with(|h| h.lease_fd(_slf, leased_fd))
+    }
+
+    #[inline]
+    fn finished(&self, _slf: &WpDrmLeaseV1Ref) {
+        self.with(|h| h.finished(_slf))
+    }
+}
+
+impl<T, H> WpDrmLeaseV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpDrmLeaseV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn lease_fd(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        self.with(|h| h.lease_fd(_data, _slf, leased_fd))
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {
+        self.with(|h| h.finished(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpDrmLeaseV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpDrmLeaseV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn lease_fd(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler().lease_fd(_data, _slf, leased_fd)
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {
+        let _data = self.project(_data);
+        self.handler().finished(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpDrmLeaseV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpDrmLeaseV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn lease_fd(&mut self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler_mut().lease_fd(_data, _slf, leased_fd)
+    }
+
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().finished(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpDrmLeaseV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpDrmLeaseV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                self.0.lease_fd(slf, arg0);
+            }
+            1 => {
+                self.0.finished(slf);
+            }
+            _ => {
+                invalid_opcode("wp_drm_lease_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpDrmLeaseV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpDrmLeaseV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                self.0.lease_fd(data, slf, arg0);
+            }
+            1 => {
+                self.0.finished(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_drm_lease_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpDrmLeaseV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpDrmLeaseV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for lease_fd events.
+    pub struct LeaseFd<F>(F);
+    impl<F> WpDrmLeaseV1EventHandler for LeaseFd<F>
+    where
+        F: Fn(&WpDrmLeaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn lease_fd(&self, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+            self.0(_slf, leased_fd)
+        }
+    }
+    impl<T, F> WpDrmLeaseV1EventHandlerWithData<T> for LeaseFd<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn lease_fd(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+            self.0(_data, _slf, leased_fd)
+        }
+    }
+
+    /// Event handler for finished events.
+    pub struct Finished<F>(F);
+    impl<F> WpDrmLeaseV1EventHandler for Finished<F>
+    where
+        F: Fn(&WpDrmLeaseV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _slf: &WpDrmLeaseV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseV1EventHandlerWithData<T> for Finished<F>
+    where
+        F: Fn(&mut T, &WpDrmLeaseV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for lease_fd events.
+    pub struct LeaseFdMut<F>(F);
+    impl<F> WpDrmLeaseV1EventHandlerMut for LeaseFdMut<F>
+    where
+        F: FnMut(&WpDrmLeaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn lease_fd(&mut self, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+            self.0(_slf, leased_fd)
+        }
+    }
+    impl<T, F> WpDrmLeaseV1EventHandlerMutWithData<T> for LeaseFdMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn lease_fd(&mut self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+            self.0(_data, _slf, leased_fd)
+        }
+    }
+
+    /// Mutable event handler for finished events.
+    pub struct FinishedMut<F>(F);
+    impl<F> WpDrmLeaseV1EventHandlerMut for FinishedMut<F>
+    where
+        F: FnMut(&WpDrmLeaseV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _slf: &WpDrmLeaseV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpDrmLeaseV1EventHandlerMutWithData<T> for FinishedMut<F>
+    where
+        F: FnMut(&mut T, &WpDrmLeaseV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpDrmLeaseV1 {
+        /// Creates an event handler for lease_fd events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_lease_fd<F>(f: F) -> LeaseFd<F>
+        where
+            F: Fn(&WpDrmLeaseV1Ref, OwnedFd),
+        {
+            LeaseFd(f)
+        }
+
+        /// Creates an event handler with mutable data for lease_fd events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_lease_fd_with_data<T, F>(f: F) -> LeaseFd<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseV1Ref, OwnedFd),
+        {
+            LeaseFd(f)
+        }
+
+        /// Creates an event handler for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(f: F) -> Finished<F>
+        where
+            F: Fn(&WpDrmLeaseV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates an event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(f: F) -> Finished<F>
+        where
+            F: Fn(&mut T, &WpDrmLeaseV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates a mutable event handler for lease_fd events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_lease_fd_mut<F>(f: F) -> LeaseFdMut<F>
+        where
+            F: FnMut(&WpDrmLeaseV1Ref, OwnedFd),
+        {
+            LeaseFdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for lease_fd events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_lease_fd_mut_with_data<T, F>(f: F) -> LeaseFdMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseV1Ref, OwnedFd),
+        {
+            LeaseFdMut(f)
+        }
+
+        /// Creates a mutable event handler for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut<F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&WpDrmLeaseV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut_with_data<T, F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&mut T, &WpDrmLeaseV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                lease_fd: Ignore,
+                finished: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpDrmLeaseV1EventHandler for Ignore {}
+    impl<T> WpDrmLeaseV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpDrmLeaseV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        lease_fd: H0,
+        finished: H1,
+    }
+
+    impl<H0, H1> WpDrmLeaseV1EventHandler for Handler<H0, H1>
+    where
+        H0: WpDrmLeaseV1EventHandler,
+        H1: WpDrmLeaseV1EventHandler,
+    {
+        #[inline]
+        fn lease_fd(&self, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+            self.lease_fd.lease_fd(_slf, leased_fd)
+        }
+
+        #[inline]
+        fn finished(&self, _slf: &WpDrmLeaseV1Ref) {
+            self.finished.finished(_slf)
+        }
+    }
+
+    impl<T, H0, H1> WpDrmLeaseV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WpDrmLeaseV1EventHandlerWithData<T>,
+        H1: WpDrmLeaseV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn lease_fd(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref, leased_fd: OwnedFd) {
+            self.lease_fd.lease_fd(_data, _slf, leased_fd)
+        }
+
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &WpDrmLeaseV1Ref) {
+            self.finished.finished(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for lease_fd events.
+        #[allow(dead_code)]
+        pub fn on_lease_fd<F>(self, f: F) -> Handler<LeaseFd<F>, H1>
+        where
+            F: Fn(&WpDrmLeaseV1Ref, OwnedFd),
+        {
+            Handler {
+                lease_fd: LeaseFd(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for lease_fd events.
+        #[allow(dead_code)]
+        pub fn on_lease_fd_with_data<T, F>(self, f: F) -> Handler<LeaseFd<F>, H1>
+        where
+            F: Fn(&mut T, &WpDrmLeaseV1Ref, OwnedFd),
+        {
+            Handler {
+                lease_fd: LeaseFd(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(self, f: F) -> Handler<H0, Finished<F>>
+        where
+            F: Fn(&WpDrmLeaseV1Ref),
+        {
+            Handler {
+                lease_fd: self.lease_fd,
+                finished: Finished(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(self, f: F) -> Handler<H0, Finished<F>>
+        where
+            F: Fn(&mut T, &WpDrmLeaseV1Ref),
+        {
+            Handler {
+                lease_fd: self.lease_fd,
+                finished: Finished(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1030 @@
+//! background effect factory
+//!
+//! This protocol provides a way to improve visuals of translucent surfaces
+//! by applying effects like blur to the background behind them.
+//!
+//! The capabilities are send when the global is bound, and every time they
+//! change. Note that when the capability goes away, the corresponding effect
+//! is no longer applied by the compositor, even if it was set before.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_background_effect_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_background_effect".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ExtBackgroundEffectSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"capabilities".as_ptr(),
+            signature: c"u".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_background_effect_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_background_effect_manager_v1",
+    protocol: "ext_background_effect_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_background_effect",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_background_effect_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "capabilities",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "flags",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: Some("capability"),
+        }],
+    }],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "background_effect_exists",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+        info::EnumInfo {
+            name: "capability",
+            since: 1,
+            bitfield: true,
+            entries: &[info::EntryInfo {
+                name: "blur",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+    ],
+};
+
+/// An owned ext_background_effect_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtBackgroundEffectManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_background_effect_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtBackgroundEffectManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtBackgroundEffectManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtBackgroundEffectManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtBackgroundEffectManagerV1 {
+    const INTERFACE: &'static str = "ext_background_effect_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtBackgroundEffectManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtBackgroundEffectManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtBackgroundEffectManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtBackgroundEffectManagerV1Ref {
+    type Owned = ExtBackgroundEffectManagerV1;
+}
+
+impl Deref for ExtBackgroundEffectManagerV1 {
+    type Target = ExtBackgroundEffectManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtBackgroundEffectManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_background_effect_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtBackgroundEffectManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_background_effect_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtBackgroundEffectManagerV1Ref> for ExtBackgroundEffectManagerV1 {
+    fn eq(&self, other: &ExtBackgroundEffectManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtBackgroundEffectManagerV1> for ExtBackgroundEffectManagerV1Ref {
+    fn eq(&self, other: &ExtBackgroundEffectManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtBackgroundEffectManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the background effect manager
+    ///
+    /// Informs the server that the client will no longer be using this
+    /// protocol object. Existing objects created by this object are not
+    /// affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_background_effect request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_BACKGROUND_EFFECT__SINCE: u32 = 1;
+
+    /// get a background effects object
+    ///
+    /// Instantiate an interface extension for the given wl_surface to add
+    /// effects like blur for the background behind it.
+    ///
+    /// If the given wl_surface already has a ext_background_effect_surface_v1
+    /// object associated, the background_effect_exists protocol error will be
+    /// raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface
+    #[inline]
+    pub fn get_background_effect(&self, surface: &WlSurfaceRef) -> ExtBackgroundEffectSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ExtBackgroundEffectSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtBackgroundEffectSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtBackgroundEffectManagerV1Ref {
+    /// get a background effects object
+    ///
+    /// Instantiate an interface extension for the given wl_surface to add
+    /// effects like blur for the background behind it.
+    ///
+    /// If the given wl_surface already has a ext_background_effect_surface_v1
+    /// object associated, the background_effect_exists protocol error will be
+    /// raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface
+    #[inline]
+    pub fn get_background_effect(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> ExtBackgroundEffectSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ExtBackgroundEffectSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtBackgroundEffectSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ExtBackgroundEffectManagerV1 {
+    /// Since when the capabilities event is available.
+    #[allow(dead_code)]
+    pub const EVT__CAPABILITIES__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtBackgroundEffectManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectManagerV1EventHandler {
+    /// capabilities of the compositor
+    ///
+    /// # Arguments
+    ///
+    /// - `flags`:
+    #[inline]
+    fn capabilities(
+        &self,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        let _ = flags;
+    }
+}
+
+impl ExtBackgroundEffectManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtBackgroundEffectManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtBackgroundEffectManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectManagerV1EventHandlerWithData<T> {
+    /// capabilities of the compositor
+    ///
+    /// # Arguments
+    ///
+    /// - `flags`:
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        let _ = flags;
+    }
+}
+
+/// A mutable event handler for [ExtBackgroundEffectManagerV1] proxies.
+///
+/// This trait is the same as [ExtBackgroundEffectManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectManagerV1EventHandlerMut {
+    /// See [ExtBackgroundEffectManagerV1EventHandler::capabilities].
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        let _ = flags;
+    }
+}
+
+/// A mutable event handler for [ExtBackgroundEffectManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtBackgroundEffectManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectManagerV1EventHandlerMutWithData<T> {
+    /// See [ExtBackgroundEffectManagerV1EventHandler::capabilities].
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        let _ = flags;
+    }
+}
+
+impl<H> ExtBackgroundEffectManagerV1EventHandler for MutEventHandler<H>
+where
+    H: ExtBackgroundEffectManagerV1EventHandlerMut,
+{
+    #[inline]
+    fn capabilities(
+        &self,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        self.with(|h| h.capabilities(_slf, flags))
+    }
+}
+
+impl<T, H> ExtBackgroundEffectManagerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtBackgroundEffectManagerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        self.with(|h| h.capabilities(_data, _slf, flags))
+    }
+}
+
+impl<T, U, H> ExtBackgroundEffectManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtBackgroundEffectManagerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        let _data = self.project(_data);
+        self.handler().capabilities(_data, _slf, flags)
+    }
+}
+
+impl<T, U, H> ExtBackgroundEffectManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtBackgroundEffectManagerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtBackgroundEffectManagerV1Ref,
+        flags: ExtBackgroundEffectManagerV1Capability,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().capabilities(_data, _slf, flags)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtBackgroundEffectManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtBackgroundEffectManagerV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtBackgroundEffectManagerV1Capability(args[0].u) };
+                self.0.capabilities(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_background_effect_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtBackgroundEffectManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtBackgroundEffectManagerV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtBackgroundEffectManagerV1Capability(args[0].u) };
+                self.0.capabilities(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_background_effect_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtBackgroundEffectManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtBackgroundEffectManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtBackgroundEffectManagerV1 {
+    /// Since when the error.background_effect_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_BACKGROUND_EFFECT_EXISTS__SINCE: u32 = 1;
+
+    /// Since when the capability.blur enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_BLUR__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtBackgroundEffectManagerV1Error(pub u32);
+
+impl ExtBackgroundEffectManagerV1Error {
+    /// the surface already has a background effect object
+    #[allow(dead_code)]
+    pub const BACKGROUND_EFFECT_EXISTS: Self = Self(0);
+}
+
+impl Debug for ExtBackgroundEffectManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::BACKGROUND_EFFECT_EXISTS => "BACKGROUND_EFFECT_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ExtBackgroundEffectManagerV1Capability(pub u32);
+
+/// An iterator over the set bits in a [ExtBackgroundEffectManagerV1Capability].
+///
+/// You can construct this with the `IntoIterator` implementation of `ExtBackgroundEffectManagerV1Capability`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ExtBackgroundEffectManagerV1CapabilityIter(pub u32);
+
+impl ExtBackgroundEffectManagerV1Capability {
+    /// the compositor supports applying blur
+    #[allow(dead_code)]
+    pub const BLUR: Self = Self(1);
+}
+
+#[allow(dead_code)]
+impl ExtBackgroundEffectManagerV1Capability {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1)
+    }
+}
+
+impl Iterator for ExtBackgroundEffectManagerV1CapabilityIter {
+    type Item = ExtBackgroundEffectManagerV1Capability;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ExtBackgroundEffectManagerV1Capability(bit))
+    }
+}
+
+impl IntoIterator for ExtBackgroundEffectManagerV1Capability {
+    type Item = ExtBackgroundEffectManagerV1Capability;
+    type IntoIter = ExtBackgroundEffectManagerV1CapabilityIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ExtBackgroundEffectManagerV1CapabilityIter(self.0)
+    }
+}
+
+impl BitAnd for ExtBackgroundEffectManagerV1Capability {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ExtBackgroundEffectManagerV1Capability {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ExtBackgroundEffectManagerV1Capability {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ExtBackgroundEffectManagerV1Capability {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ExtBackgroundEffectManagerV1Capability {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ExtBackgroundEffectManagerV1Capability {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ExtBackgroundEffectManagerV1Capability {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ExtBackgroundEffectManagerV1Capability {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ExtBackgroundEffectManagerV1Capability {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ExtBackgroundEffectManagerV1Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("BLUR")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for capabilities events.
+    pub struct Capabilities<F>(F);
+    impl<F> ExtBackgroundEffectManagerV1EventHandler for Capabilities<F>
+    where
+        F: Fn(&ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _slf: &ExtBackgroundEffectManagerV1Ref,
+            flags: ExtBackgroundEffectManagerV1Capability,
+        ) {
+            self.0(_slf, flags)
+        }
+    }
+    impl<T, F> ExtBackgroundEffectManagerV1EventHandlerWithData<T> for Capabilities<F>
+    where
+        F: Fn(&mut T, &ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _data: &mut T,
+            _slf: &ExtBackgroundEffectManagerV1Ref,
+            flags: ExtBackgroundEffectManagerV1Capability,
+        ) {
+            self.0(_data, _slf, flags)
+        }
+    }
+
+    /// Mutable event handler for capabilities events.
+    pub struct CapabilitiesMut<F>(F);
+    impl<F> ExtBackgroundEffectManagerV1EventHandlerMut for CapabilitiesMut<F>
+    where
+        F: FnMut(&ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _slf: &ExtBackgroundEffectManagerV1Ref,
+            flags: ExtBackgroundEffectManagerV1Capability,
+        ) {
+            self.0(_slf, flags)
+        }
+    }
+    impl<T, F> ExtBackgroundEffectManagerV1EventHandlerMutWithData<T> for CapabilitiesMut<F>
+    where
+        F: FnMut(&mut T, &ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtBackgroundEffectManagerV1Ref,
+            flags: ExtBackgroundEffectManagerV1Capability,
+        ) {
+            self.0(_data, _slf, flags)
+        }
+    }
+
+    impl ExtBackgroundEffectManagerV1 {
+        /// Creates an event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates an event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&mut T, &ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates a mutable event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut<F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut_with_data<T, F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &ExtBackgroundEffectManagerV1Ref,
+                ExtBackgroundEffectManagerV1Capability,
+            ),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                capabilities: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtBackgroundEffectManagerV1EventHandler for Ignore {}
+    impl<T> ExtBackgroundEffectManagerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtBackgroundEffectManagerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        capabilities: H0,
+    }
+
+    impl<H0> ExtBackgroundEffectManagerV1EventHandler for Handler<H0>
+    where
+        H0: ExtBackgroundEffectManagerV1EventHandler,
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _slf: &ExtBackgroundEffectManagerV1Ref,
+            flags: ExtBackgroundEffectManagerV1Capability,
+        ) {
+            self.capabilities.capabilities(_slf, flags)
+        }
+    }
+
+    impl<T, H0> ExtBackgroundEffectManagerV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ExtBackgroundEffectManagerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _data: &mut T,
+            _slf: &ExtBackgroundEffectManagerV1Ref,
+            flags: ExtBackgroundEffectManagerV1Capability,
+        ) {
+            self.capabilities.capabilities(_data, _slf, flags)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(self, f: F) -> Handler<Capabilities<F>>
+        where
+            F: Fn(&ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(self, f: F) -> Handler<Capabilities<F>>
+        where
+            F: Fn(&mut T, &ExtBackgroundEffectManagerV1Ref, ExtBackgroundEffectManagerV1Capability),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+            }
+        }
+    }
+}
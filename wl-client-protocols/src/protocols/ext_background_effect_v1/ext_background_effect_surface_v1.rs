@@ -0,0 +1,396 @@
+//! background effects for a surface
+//!
+//! The background effect object provides a way to specify a region behind
+//! a surface that should have background effects like blur applied.
+//!
+//! If the wl_surface associated with the ext_background_effect_surface_v1
+//! object has been destroyed, this object becomes inert.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_background_effect_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_blur_region".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlRegion::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the ext_background_effect_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_background_effect_surface_v1",
+    protocol: "ext_background_effect_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_blur_region",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "region",
+                ty: info::ArgType::Object,
+                interface: Some("wl_region"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "surface_destroyed",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned ext_background_effect_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtBackgroundEffectSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_background_effect_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtBackgroundEffectSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtBackgroundEffectSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtBackgroundEffectSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtBackgroundEffectSurfaceV1 {
+    const INTERFACE: &'static str = "ext_background_effect_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtBackgroundEffectSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtBackgroundEffectSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtBackgroundEffectSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtBackgroundEffectSurfaceV1Ref {
+    type Owned = ExtBackgroundEffectSurfaceV1;
+}
+
+impl Deref for ExtBackgroundEffectSurfaceV1 {
+    type Target = ExtBackgroundEffectSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtBackgroundEffectSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_background_effect_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtBackgroundEffectSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_background_effect_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtBackgroundEffectSurfaceV1Ref> for ExtBackgroundEffectSurfaceV1 {
+    fn eq(&self, other: &ExtBackgroundEffectSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtBackgroundEffectSurfaceV1> for ExtBackgroundEffectSurfaceV1Ref {
+    fn eq(&self, other: &ExtBackgroundEffectSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtBackgroundEffectSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// release the blur object
+    ///
+    /// Informs the server that the client will no longer be using this protocol
+    /// object. The effect regions will be removed on the next commit.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtBackgroundEffectSurfaceV1Ref {
+    /// set blur region
+    ///
+    /// This request sets the region of the surface that will have its
+    /// background blurred.
+    ///
+    /// The blur region is specified in the surface-local coordinates, and
+    /// clipped by the compositor to the surface size.
+    ///
+    /// The initial value for the blur region is empty. Setting the pending
+    /// blur region has copy semantics, and the wl_region object can be
+    /// destroyed immediately. A NULL wl_region removes the effect.
+    ///
+    /// The blur region is double-buffered state, and will be applied on
+    /// the next wl_surface.commit.
+    ///
+    /// The blur algorithm is subject to compositor policies.
+    ///
+    /// If the associated surface has been destroyed, the surface_destroyed
+    /// error will be raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `region`: blur region of the surface
+    #[inline]
+    pub fn set_blur_region(&self, region: Option<&WlRegionRef>) {
+        let (arg0,) = (region,);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("region", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `?o`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [ExtBackgroundEffectSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectSurfaceV1EventHandler {}
+
+impl ExtBackgroundEffectSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtBackgroundEffectSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtBackgroundEffectSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ExtBackgroundEffectSurfaceV1] proxies.
+///
+/// This trait is the same as [ExtBackgroundEffectSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [ExtBackgroundEffectSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtBackgroundEffectSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtBackgroundEffectSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> ExtBackgroundEffectSurfaceV1EventHandler for MutEventHandler<H> where
+    H: ExtBackgroundEffectSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> ExtBackgroundEffectSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ExtBackgroundEffectSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ExtBackgroundEffectSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtBackgroundEffectSurfaceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ExtBackgroundEffectSurfaceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtBackgroundEffectSurfaceV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtBackgroundEffectSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_background_effect_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtBackgroundEffectSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_background_effect_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtBackgroundEffectSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtBackgroundEffectSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtBackgroundEffectSurfaceV1 {
+    /// Since when the error.surface_destroyed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SURFACE_DESTROYED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtBackgroundEffectSurfaceV1Error(pub u32);
+
+impl ExtBackgroundEffectSurfaceV1Error {
+    /// the associated surface has been destroyed
+    #[allow(dead_code)]
+    pub const SURFACE_DESTROYED: Self = Self(0);
+}
+
+impl Debug for ExtBackgroundEffectSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::SURFACE_DESTROYED => "SURFACE_DESTROYED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ExtBackgroundEffectSurfaceV1 {}
+}
@@ -0,0 +1,37 @@
+//! control data devices
+//!
+//! This protocol allows a privileged client to control data devices. In
+//! particular, the client will be able to manage the current selection and take
+//! the role of a clipboard manager.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod ext_data_control_device_v1;
+pub mod ext_data_control_manager_v1;
+pub mod ext_data_control_offer_v1;
+pub mod ext_data_control_source_v1;
+
+/// Metadata about the ext_data_control_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "ext_data_control_v1",
+    interfaces: &[
+        &ext_data_control_manager_v1::INFO,
+        &ext_data_control_device_v1::INFO,
+        &ext_data_control_source_v1::INFO,
+        &ext_data_control_offer_v1::INFO,
+    ],
+};
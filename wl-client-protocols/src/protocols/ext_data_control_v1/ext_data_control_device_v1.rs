@@ -0,0 +1,1684 @@
+//! manage a data device for a seat
+//!
+//! This interface allows a client to manage a seat's selection.
+//!
+//! When the seat is destroyed, this object becomes inert.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_data_control_device_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"set_selection".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtDataControlSourceV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_primary_selection".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtDataControlSourceV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 4,
+    events: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"data_offer".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtDataControlOfferV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"selection".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtDataControlOfferV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"finished".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"primary_selection".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtDataControlOfferV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_data_control_device_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_data_control_device_v1",
+    protocol: "ext_data_control_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_selection",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "source",
+                ty: info::ArgType::Object,
+                interface: Some("ext_data_control_source_v1"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_primary_selection",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "source",
+                ty: info::ArgType::Object,
+                interface: Some("ext_data_control_source_v1"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "data_offer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_data_control_offer_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "selection",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::Object,
+                interface: Some("ext_data_control_offer_v1"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "finished",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "primary_selection",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::Object,
+                interface: Some("ext_data_control_offer_v1"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "used_source",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned ext_data_control_device_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtDataControlDeviceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_data_control_device_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtDataControlDeviceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtDataControlDeviceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtDataControlDeviceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtDataControlDeviceV1 {
+    const INTERFACE: &'static str = "ext_data_control_device_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtDataControlDeviceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtDataControlDeviceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtDataControlDeviceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtDataControlDeviceV1Ref {
+    type Owned = ExtDataControlDeviceV1;
+}
+
+impl Deref for ExtDataControlDeviceV1 {
+    type Target = ExtDataControlDeviceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtDataControlDeviceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_data_control_device_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtDataControlDeviceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_data_control_device_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtDataControlDeviceV1Ref> for ExtDataControlDeviceV1 {
+    fn eq(&self, other: &ExtDataControlDeviceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtDataControlDeviceV1> for ExtDataControlDeviceV1Ref {
+    fn eq(&self, other: &ExtDataControlDeviceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtDataControlDeviceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy this data device
+    ///
+    /// Destroys the data device object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtDataControlDeviceV1Ref {
+    /// copy data to the selection
+    ///
+    /// This request asks the compositor to set the selection to the data from
+    /// the source on behalf of the client.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// set_primary_selection requests. Attempting to use a previously used
+    /// source triggers the used_source protocol error.
+    ///
+    /// To unset the selection, set the source to NULL.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`:
+    #[inline]
+    pub fn set_selection(&self, source: Option<&ExtDataControlSourceV1Ref>) {
+        let (arg0,) = (source,);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("source", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `?o`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+
+    /// copy data to the primary selection
+    ///
+    /// This request asks the compositor to set the primary selection to the
+    /// data from the source on behalf of the client.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// set_primary_selection requests. Attempting to use a previously used
+    /// source triggers the used_source protocol error.
+    ///
+    /// To unset the primary selection, set the source to NULL.
+    ///
+    /// The compositor will ignore this request if it does not support primary
+    /// selection.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`:
+    #[inline]
+    pub fn set_primary_selection(&self, source: Option<&ExtDataControlSourceV1Ref>) {
+        let (arg0,) = (source,);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("source", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `?o`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+impl ExtDataControlDeviceV1 {
+    /// Since when the data_offer event is available.
+    #[allow(dead_code)]
+    pub const EVT__DATA_OFFER__SINCE: u32 = 1;
+
+    /// Since when the selection event is available.
+    #[allow(dead_code)]
+    pub const EVT__SELECTION__SINCE: u32 = 1;
+
+    /// Since when the finished event is available.
+    #[allow(dead_code)]
+    pub const EVT__FINISHED__SINCE: u32 = 1;
+
+    /// Since when the primary_selection event is available.
+    #[allow(dead_code)]
+    pub const EVT__PRIMARY_SELECTION__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtDataControlDeviceV1] proxies.
+#[allow(dead_code)]
+pub trait ExtDataControlDeviceV1EventHandler {
+    /// introduce a new ext_data_control_offer
+    ///
+    /// The data_offer event introduces a new ext_data_control_offer object,
+    /// which will subsequently be used in either the
+    /// ext_data_control_device.selection event (for the regular clipboard
+    /// selections) or the ext_data_control_device.primary_selection event (for
+    /// the primary clipboard selections). Immediately following the
+    /// ext_data_control_device.data_offer event, the new data_offer object
+    /// will send out ext_data_control_offer.offer events to describe the MIME
+    /// types it offers.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn data_offer(&self, _slf: &ExtDataControlDeviceV1Ref, id: ExtDataControlOfferV1) {
+        let _ = id;
+    }
+
+    /// advertise new selection
+    ///
+    /// The selection event is sent out to notify the client of a new
+    /// ext_data_control_offer for the selection for this device. The
+    /// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The selection event is sent to a client when a new
+    /// selection is set. The ext_data_control_offer is valid until a new
+    /// ext_data_control_offer or NULL is received. The client must destroy the
+    /// previous selection ext_data_control_offer, if any, upon receiving this
+    /// event. Regardless, the previous selection will be ignored once a new
+    /// selection ext_data_control_offer is received.
+    ///
+    /// The first selection event is sent upon binding the
+    /// ext_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn selection(&self, _slf: &ExtDataControlDeviceV1Ref, id: Option<&ExtDataControlOfferV1Ref>) {
+        let _ = id;
+    }
+
+    /// this data control is no longer valid
+    ///
+    /// This data control object is no longer valid and should be destroyed by
+    /// the client.
+    #[inline]
+    fn finished(&self, _slf: &ExtDataControlDeviceV1Ref) {}
+
+    /// advertise new primary selection
+    ///
+    /// The primary_selection event is sent out to notify the client of a new
+    /// ext_data_control_offer for the primary selection for this device. The
+    /// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The primary_selection event is sent to a client when a
+    /// new primary selection is set. The ext_data_control_offer is valid until
+    /// a new ext_data_control_offer or NULL is received. The client must
+    /// destroy the previous primary selection ext_data_control_offer, if any,
+    /// upon receiving this event. Regardless, the previous primary selection
+    /// will be ignored once a new primary selection ext_data_control_offer is
+    /// received.
+    ///
+    /// If the compositor supports primary selection, the first
+    /// primary_selection event is sent upon binding the
+    /// ext_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn primary_selection(
+        &self,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+impl ExtDataControlDeviceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtDataControlDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtDataControlDeviceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtDataControlDeviceV1EventHandlerWithData<T> {
+    /// introduce a new ext_data_control_offer
+    ///
+    /// The data_offer event introduces a new ext_data_control_offer object,
+    /// which will subsequently be used in either the
+    /// ext_data_control_device.selection event (for the regular clipboard
+    /// selections) or the ext_data_control_device.primary_selection event (for
+    /// the primary clipboard selections). Immediately following the
+    /// ext_data_control_device.data_offer event, the new data_offer object
+    /// will send out ext_data_control_offer.offer events to describe the MIME
+    /// types it offers.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn data_offer(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: ExtDataControlOfferV1,
+    ) {
+        let _ = id;
+    }
+
+    /// advertise new selection
+    ///
+    /// The selection event is sent out to notify the client of a new
+    /// ext_data_control_offer for the selection for this device. The
+    /// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The selection event is sent to a client when a new
+    /// selection is set. The ext_data_control_offer is valid until a new
+    /// ext_data_control_offer or NULL is received. The client must destroy the
+    /// previous selection ext_data_control_offer, if any, upon receiving this
+    /// event. Regardless, the previous selection will be ignored once a new
+    /// selection ext_data_control_offer is received.
+    ///
+    /// The first selection event is sent upon binding the
+    /// ext_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn selection(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+
+    /// this data control is no longer valid
+    ///
+    /// This data control object is no longer valid and should be destroyed by
+    /// the client.
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {}
+
+    /// advertise new primary selection
+    ///
+    /// The primary_selection event is sent out to notify the client of a new
+    /// ext_data_control_offer for the primary selection for this device. The
+    /// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The primary_selection event is sent to a client when a
+    /// new primary selection is set. The ext_data_control_offer is valid until
+    /// a new ext_data_control_offer or NULL is received. The client must
+    /// destroy the previous primary selection ext_data_control_offer, if any,
+    /// upon receiving this event. Regardless, the previous primary selection
+    /// will be ignored once a new primary selection ext_data_control_offer is
+    /// received.
+    ///
+    /// If the compositor supports primary selection, the first
+    /// primary_selection event is sent upon binding the
+    /// ext_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn primary_selection(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ExtDataControlDeviceV1] proxies.
+///
+/// This trait is the same as [ExtDataControlDeviceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtDataControlDeviceV1EventHandlerMut {
+    /// See [ExtDataControlDeviceV1EventHandler::data_offer].
+    #[inline]
+    fn data_offer(&mut self, _slf: &ExtDataControlDeviceV1Ref, id: ExtDataControlOfferV1) {
+        let _ = id;
+    }
+
+    /// See [ExtDataControlDeviceV1EventHandler::selection].
+    #[inline]
+    fn selection(
+        &mut self,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+
+    /// See [ExtDataControlDeviceV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _slf: &ExtDataControlDeviceV1Ref) {}
+
+    /// See [ExtDataControlDeviceV1EventHandler::primary_selection].
+    #[inline]
+    fn primary_selection(
+        &mut self,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ExtDataControlDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtDataControlDeviceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtDataControlDeviceV1EventHandlerMutWithData<T> {
+    /// See [ExtDataControlDeviceV1EventHandler::data_offer].
+    #[inline]
+    fn data_offer(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: ExtDataControlOfferV1,
+    ) {
+        let _ = id;
+    }
+
+    /// See [ExtDataControlDeviceV1EventHandler::selection].
+    #[inline]
+    fn selection(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+
+    /// See [ExtDataControlDeviceV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {}
+
+    /// See [ExtDataControlDeviceV1EventHandler::primary_selection].
+    #[inline]
+    fn primary_selection(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+impl<H> ExtDataControlDeviceV1EventHandler for MutEventHandler<H>
+where
+    H: ExtDataControlDeviceV1EventHandlerMut,
+{
+    #[inline]
+    fn data_offer(&self, _slf: &ExtDataControlDeviceV1Ref, id: ExtDataControlOfferV1) {
+        self.with(|h| h.data_offer(_slf, id))
+    }
+
+    #[inline]
+    fn selection(&self, _slf: &ExtDataControlDeviceV1Ref, id: Option<&ExtDataControlOfferV1Ref>) {
+        self.with(|h| h.selection(_slf, id))
+    }
+
+    #[inline]
+    fn finished(&self, _slf: &ExtDataControlDeviceV1Ref) {
+        self.with(|h| h.finished(_slf))
+    }
+
+    #[inline]
+    fn primary_selection(
+        &self,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        self.with(|h| h.primary_selection(_slf, id))
+    }
+}
+
+impl<T, H> ExtDataControlDeviceV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtDataControlDeviceV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn data_offer(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: ExtDataControlOfferV1,
+    ) {
+        self.with(|h| h.data_offer(_data, _slf, id))
+    }
+
+    #[inline]
+    fn selection(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        self.with(|h| h.selection(_data, _slf, id))
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {
+        self.with(|h| h.finished(_data, _slf))
+    }
+
+    #[inline]
+    fn primary_selection(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        self.with(|h| h.primary_selection(_data, _slf, id))
+    }
+}
+
+impl<T, U, H> ExtDataControlDeviceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtDataControlDeviceV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn data_offer(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: ExtDataControlOfferV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler().data_offer(_data, _slf, id)
+    }
+
+    #[inline]
+    fn selection(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().selection(_data, _slf, id)
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {
+        let _data = self.project(_data);
+        self.handler().finished(_data, _slf)
+    }
+
+    #[inline]
+    fn primary_selection(
+        &self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().primary_selection(_data, _slf, id)
+    }
+}
+
+impl<T, U, H> ExtDataControlDeviceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtDataControlDeviceV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn data_offer(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: ExtDataControlOfferV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().data_offer(_data, _slf, id)
+    }
+
+    #[inline]
+    fn selection(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().selection(_data, _slf, id)
+    }
+
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().finished(_data, _slf)
+    }
+
+    #[inline]
+    fn primary_selection(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlDeviceV1Ref,
+        id: Option<&ExtDataControlOfferV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().primary_selection(_data, _slf, id)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtDataControlDeviceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtDataControlDeviceV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtDataControlOfferV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ExtDataControlOfferV1>(arg0) };
+                self.0.data_offer(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtDataControlOfferV1Ref>(arg0)
+                });
+                self.0.selection(slf, arg0);
+            }
+            2 => {
+                self.0.finished(slf);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtDataControlOfferV1Ref>(arg0)
+                });
+                self.0.primary_selection(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_data_control_device_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtDataControlDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtDataControlDeviceV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtDataControlOfferV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ExtDataControlOfferV1>(arg0) };
+                self.0.data_offer(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtDataControlOfferV1Ref>(arg0)
+                });
+                self.0.selection(data, slf, arg0);
+            }
+            2 => {
+                self.0.finished(data, slf);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtDataControlOfferV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtDataControlOfferV1Ref>(arg0)
+                });
+                self.0.primary_selection(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_data_control_device_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtDataControlDeviceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtDataControlDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtDataControlDeviceV1 {
+    /// Since when the error.used_source enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_USED_SOURCE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtDataControlDeviceV1Error(pub u32);
+
+impl ExtDataControlDeviceV1Error {
+    /// source given to set_selection or set_primary_selection was already used before
+    #[allow(dead_code)]
+    pub const USED_SOURCE: Self = Self(1);
+}
+
+impl Debug for ExtDataControlDeviceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::USED_SOURCE => "USED_SOURCE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for data_offer events.
+    pub struct DataOffer<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandler for DataOffer<F>
+    where
+        F: Fn(&ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+    {
+        #[inline]
+        fn data_offer(&self, _slf: &ExtDataControlDeviceV1Ref, id: ExtDataControlOfferV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerWithData<T> for DataOffer<F>
+    where
+        F: Fn(&mut T, &ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+    {
+        #[inline]
+        fn data_offer(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: ExtDataControlOfferV1,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Event handler for selection events.
+    pub struct Selection<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandler for Selection<F>
+    where
+        F: Fn(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &self,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerWithData<T> for Selection<F>
+    where
+        F: Fn(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Event handler for finished events.
+    pub struct Finished<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandler for Finished<F>
+    where
+        F: Fn(&ExtDataControlDeviceV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _slf: &ExtDataControlDeviceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerWithData<T> for Finished<F>
+    where
+        F: Fn(&mut T, &ExtDataControlDeviceV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for primary_selection events.
+    pub struct PrimarySelection<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandler for PrimarySelection<F>
+    where
+        F: Fn(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn primary_selection(
+            &self,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerWithData<T> for PrimarySelection<F>
+    where
+        F: Fn(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn primary_selection(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for data_offer events.
+    pub struct DataOfferMut<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandlerMut for DataOfferMut<F>
+    where
+        F: FnMut(&ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+    {
+        #[inline]
+        fn data_offer(&mut self, _slf: &ExtDataControlDeviceV1Ref, id: ExtDataControlOfferV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerMutWithData<T> for DataOfferMut<F>
+    where
+        F: FnMut(&mut T, &ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+    {
+        #[inline]
+        fn data_offer(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: ExtDataControlOfferV1,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for selection events.
+    pub struct SelectionMut<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandlerMut for SelectionMut<F>
+    where
+        F: FnMut(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &mut self,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerMutWithData<T> for SelectionMut<F>
+    where
+        F: FnMut(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for finished events.
+    pub struct FinishedMut<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandlerMut for FinishedMut<F>
+    where
+        F: FnMut(&ExtDataControlDeviceV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _slf: &ExtDataControlDeviceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerMutWithData<T> for FinishedMut<F>
+    where
+        F: FnMut(&mut T, &ExtDataControlDeviceV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for primary_selection events.
+    pub struct PrimarySelectionMut<F>(F);
+    impl<F> ExtDataControlDeviceV1EventHandlerMut for PrimarySelectionMut<F>
+    where
+        F: FnMut(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn primary_selection(
+            &mut self,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtDataControlDeviceV1EventHandlerMutWithData<T> for PrimarySelectionMut<F>
+    where
+        F: FnMut(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+    {
+        #[inline]
+        fn primary_selection(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    impl ExtDataControlDeviceV1 {
+        /// Creates an event handler for data_offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_data_offer<F>(f: F) -> DataOffer<F>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+        {
+            DataOffer(f)
+        }
+
+        /// Creates an event handler with mutable data for data_offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_data_offer_with_data<T, F>(f: F) -> DataOffer<F>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+        {
+            DataOffer(f)
+        }
+
+        /// Creates an event handler for selection events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_selection<F>(f: F) -> Selection<F>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            Selection(f)
+        }
+
+        /// Creates an event handler with mutable data for selection events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_selection_with_data<T, F>(f: F) -> Selection<F>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            Selection(f)
+        }
+
+        /// Creates an event handler for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(f: F) -> Finished<F>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates an event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(f: F) -> Finished<F>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates an event handler for primary_selection events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_primary_selection<F>(f: F) -> PrimarySelection<F>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            PrimarySelection(f)
+        }
+
+        /// Creates an event handler with mutable data for primary_selection events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_primary_selection_with_data<T, F>(f: F) -> PrimarySelection<F>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            PrimarySelection(f)
+        }
+
+        /// Creates a mutable event handler for data_offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_data_offer_mut<F>(f: F) -> DataOfferMut<F>
+        where
+            F: FnMut(&ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+        {
+            DataOfferMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for data_offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_data_offer_mut_with_data<T, F>(f: F) -> DataOfferMut<F>
+        where
+            F: FnMut(&mut T, &ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+        {
+            DataOfferMut(f)
+        }
+
+        /// Creates a mutable event handler for selection events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_selection_mut<F>(f: F) -> SelectionMut<F>
+        where
+            F: FnMut(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            SelectionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for selection events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_selection_mut_with_data<T, F>(f: F) -> SelectionMut<F>
+        where
+            F: FnMut(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            SelectionMut(f)
+        }
+
+        /// Creates a mutable event handler for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut<F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&ExtDataControlDeviceV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut_with_data<T, F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&mut T, &ExtDataControlDeviceV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates a mutable event handler for primary_selection events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_primary_selection_mut<F>(f: F) -> PrimarySelectionMut<F>
+        where
+            F: FnMut(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            PrimarySelectionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for primary_selection events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_primary_selection_mut_with_data<T, F>(f: F) -> PrimarySelectionMut<F>
+        where
+            F: FnMut(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            PrimarySelectionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                data_offer: Ignore,
+                selection: Ignore,
+                finished: Ignore,
+                primary_selection: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtDataControlDeviceV1EventHandler for Ignore {}
+    impl<T> ExtDataControlDeviceV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtDataControlDeviceV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        data_offer: H0,
+        selection: H1,
+        finished: H2,
+        primary_selection: H3,
+    }
+
+    impl<H0, H1, H2, H3> ExtDataControlDeviceV1EventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: ExtDataControlDeviceV1EventHandler,
+        H1: ExtDataControlDeviceV1EventHandler,
+        H2: ExtDataControlDeviceV1EventHandler,
+        H3: ExtDataControlDeviceV1EventHandler,
+    {
+        #[inline]
+        fn data_offer(&self, _slf: &ExtDataControlDeviceV1Ref, id: ExtDataControlOfferV1) {
+            self.data_offer.data_offer(_slf, id)
+        }
+
+        #[inline]
+        fn selection(
+            &self,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.selection.selection(_slf, id)
+        }
+
+        #[inline]
+        fn finished(&self, _slf: &ExtDataControlDeviceV1Ref) {
+            self.finished.finished(_slf)
+        }
+
+        #[inline]
+        fn primary_selection(
+            &self,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.primary_selection.primary_selection(_slf, id)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> ExtDataControlDeviceV1EventHandlerWithData<T> for Handler<H0, H1, H2, H3>
+    where
+        H0: ExtDataControlDeviceV1EventHandlerWithData<T>,
+        H1: ExtDataControlDeviceV1EventHandlerWithData<T>,
+        H2: ExtDataControlDeviceV1EventHandlerWithData<T>,
+        H3: ExtDataControlDeviceV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn data_offer(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: ExtDataControlOfferV1,
+        ) {
+            self.data_offer.data_offer(_data, _slf, id)
+        }
+
+        #[inline]
+        fn selection(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.selection.selection(_data, _slf, id)
+        }
+
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtDataControlDeviceV1Ref) {
+            self.finished.finished(_data, _slf)
+        }
+
+        #[inline]
+        fn primary_selection(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlDeviceV1Ref,
+            id: Option<&ExtDataControlOfferV1Ref>,
+        ) {
+            self.primary_selection.primary_selection(_data, _slf, id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for data_offer events.
+        #[allow(dead_code)]
+        pub fn on_data_offer<F>(self, f: F) -> Handler<DataOffer<F>, H1, H2, H3>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+        {
+            Handler {
+                data_offer: DataOffer(f),
+                selection: self.selection,
+                finished: self.finished,
+                primary_selection: self.primary_selection,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for data_offer events.
+        #[allow(dead_code)]
+        pub fn on_data_offer_with_data<T, F>(self, f: F) -> Handler<DataOffer<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref, ExtDataControlOfferV1),
+        {
+            Handler {
+                data_offer: DataOffer(f),
+                selection: self.selection,
+                finished: self.finished,
+                primary_selection: self.primary_selection,
+            }
+        }
+
+        /// Sets the closure for selection events.
+        #[allow(dead_code)]
+        pub fn on_selection<F>(self, f: F) -> Handler<H0, Selection<F>, H2, H3>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: Selection(f),
+                finished: self.finished,
+                primary_selection: self.primary_selection,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for selection events.
+        #[allow(dead_code)]
+        pub fn on_selection_with_data<T, F>(self, f: F) -> Handler<H0, Selection<F>, H2, H3>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: Selection(f),
+                finished: self.finished,
+                primary_selection: self.primary_selection,
+            }
+        }
+
+        /// Sets the closure for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(self, f: F) -> Handler<H0, H1, Finished<F>, H3>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: self.selection,
+                finished: Finished(f),
+                primary_selection: self.primary_selection,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(self, f: F) -> Handler<H0, H1, Finished<F>, H3>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: self.selection,
+                finished: Finished(f),
+                primary_selection: self.primary_selection,
+            }
+        }
+
+        /// Sets the closure for primary_selection events.
+        #[allow(dead_code)]
+        pub fn on_primary_selection<F>(self, f: F) -> Handler<H0, H1, H2, PrimarySelection<F>>
+        where
+            F: Fn(&ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: self.selection,
+                finished: self.finished,
+                primary_selection: PrimarySelection(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for primary_selection events.
+        #[allow(dead_code)]
+        pub fn on_primary_selection_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, PrimarySelection<F>>
+        where
+            F: Fn(&mut T, &ExtDataControlDeviceV1Ref, Option<&ExtDataControlOfferV1Ref>),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: self.selection,
+                finished: self.finished,
+                primary_selection: PrimarySelection(f),
+            }
+        }
+    }
+}
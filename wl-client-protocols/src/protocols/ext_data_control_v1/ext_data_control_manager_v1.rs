@@ -0,0 +1,466 @@
+//! manager to control data devices
+//!
+//! This interface is a manager that allows creating per-seat data device
+//! controls.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_data_control_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"create_data_source".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtDataControlSourceV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_data_device".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ExtDataControlDeviceV1::WL_INTERFACE),
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the ext_data_control_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_data_control_manager_v1",
+    protocol: "ext_data_control_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_data_source",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_data_control_source_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_data_device",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_data_control_device_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned ext_data_control_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtDataControlManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_data_control_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtDataControlManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtDataControlManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtDataControlManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtDataControlManagerV1 {
+    const INTERFACE: &'static str = "ext_data_control_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtDataControlManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtDataControlManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtDataControlManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtDataControlManagerV1Ref {
+    type Owned = ExtDataControlManagerV1;
+}
+
+impl Deref for ExtDataControlManagerV1 {
+    type Target = ExtDataControlManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtDataControlManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_data_control_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtDataControlManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_data_control_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtDataControlManagerV1Ref> for ExtDataControlManagerV1 {
+    fn eq(&self, other: &ExtDataControlManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtDataControlManagerV1> for ExtDataControlManagerV1Ref {
+    fn eq(&self, other: &ExtDataControlManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtDataControlManagerV1 {
+    /// Since when the create_data_source request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_DATA_SOURCE__SINCE: u32 = 1;
+
+    /// create a new data source
+    ///
+    /// Create a new data source.
+    #[inline]
+    pub fn create_data_source(&self) -> ExtDataControlSourceV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ExtDataControlSourceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtDataControlSourceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_data_device request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_DATA_DEVICE__SINCE: u32 = 1;
+
+    /// get a data device for a seat
+    ///
+    /// Create a data device that can be used to manage a seat's selection.
+    ///
+    /// # Arguments
+    ///
+    /// - `seat`:
+    #[inline]
+    pub fn get_data_device(&self, seat: &WlSeatRef) -> ExtDataControlDeviceV1 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ExtDataControlDeviceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtDataControlDeviceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager
+    ///
+    /// All objects created by the manager will still remain valid, until their
+    /// appropriate destroy request has been called.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(2, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtDataControlManagerV1Ref {
+    /// create a new data source
+    ///
+    /// Create a new data source.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_data_source(&self, _queue: &Queue) -> ExtDataControlSourceV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ExtDataControlSourceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtDataControlSourceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// get a data device for a seat
+    ///
+    /// Create a data device that can be used to manage a seat's selection.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `seat`:
+    #[inline]
+    pub fn get_data_device(&self, _queue: &Queue, seat: &WlSeatRef) -> ExtDataControlDeviceV1 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ExtDataControlDeviceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtDataControlDeviceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ExtDataControlManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ExtDataControlManagerV1EventHandler {}
+
+impl ExtDataControlManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtDataControlManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtDataControlManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtDataControlManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ExtDataControlManagerV1] proxies.
+///
+/// This trait is the same as [ExtDataControlManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtDataControlManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ExtDataControlManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtDataControlManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtDataControlManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ExtDataControlManagerV1EventHandler for MutEventHandler<H> where
+    H: ExtDataControlManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ExtDataControlManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ExtDataControlManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ExtDataControlManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtDataControlManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ExtDataControlManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtDataControlManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtDataControlManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_data_control_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtDataControlManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_data_control_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtDataControlManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtDataControlManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ExtDataControlManagerV1 {}
+}
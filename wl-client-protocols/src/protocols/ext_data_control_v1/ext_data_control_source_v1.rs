@@ -0,0 +1,921 @@
+//! offer to transfer data
+//!
+//! The ext_data_control_source object is the source side of a
+//! ext_data_control_offer. It is created by the source client in a data
+//! transfer and provides a way to describe the offered data and a way to
+//! respond to requests to transfer the data.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_data_control_source_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"offer".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"send".as_ptr(),
+                signature: c"sh".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"cancelled".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_data_control_source_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_data_control_source_v1",
+    protocol: "ext_data_control_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "offer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "mime_type",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "send",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "mime_type",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "cancelled",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "invalid_offer",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned ext_data_control_source_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtDataControlSourceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_data_control_source_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtDataControlSourceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtDataControlSourceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtDataControlSourceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtDataControlSourceV1 {
+    const INTERFACE: &'static str = "ext_data_control_source_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtDataControlSourceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtDataControlSourceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtDataControlSourceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtDataControlSourceV1Ref {
+    type Owned = ExtDataControlSourceV1;
+}
+
+impl Deref for ExtDataControlSourceV1 {
+    type Target = ExtDataControlSourceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtDataControlSourceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_data_control_source_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtDataControlSourceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_data_control_source_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtDataControlSourceV1Ref> for ExtDataControlSourceV1 {
+    fn eq(&self, other: &ExtDataControlSourceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtDataControlSourceV1> for ExtDataControlSourceV1Ref {
+    fn eq(&self, other: &ExtDataControlSourceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtDataControlSourceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy this source
+    ///
+    /// Destroys the data source object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtDataControlSourceV1Ref {
+    /// add an offered MIME type
+    ///
+    /// This request adds a MIME type to the set of MIME types advertised to
+    /// targets. Can be called several times to offer multiple types.
+    ///
+    /// Calling this after ext_data_control_device.set_selection is a protocol
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`: MIME type offered by the data source
+    #[inline]
+    pub fn offer(&self, mime_type: &str) {
+        let (arg0,) = (mime_type,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 0 < INTERFACE.method_count = 2
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(0, &mut args);
+            }
+        })
+    }
+}
+
+impl ExtDataControlSourceV1 {
+    /// Since when the send event is available.
+    #[allow(dead_code)]
+    pub const EVT__SEND__SINCE: u32 = 1;
+
+    /// Since when the cancelled event is available.
+    #[allow(dead_code)]
+    pub const EVT__CANCELLED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtDataControlSourceV1] proxies.
+#[allow(dead_code)]
+pub trait ExtDataControlSourceV1EventHandler {
+    /// send the data
+    ///
+    /// Request for data from the client. Send the data as the specified MIME
+    /// type over the passed file descriptor, then close it.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`: MIME type for the data
+    /// - `fd`: file descriptor for the data
+    #[inline]
+    fn send(&self, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// selection was cancelled
+    ///
+    /// This data source is no longer valid. The data source has been replaced
+    /// by another data source.
+    ///
+    /// The client should clean up and destroy this data source.
+    #[inline]
+    fn cancelled(&self, _slf: &ExtDataControlSourceV1Ref) {}
+}
+
+impl ExtDataControlSourceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtDataControlSourceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtDataControlSourceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtDataControlSourceV1EventHandlerWithData<T> {
+    /// send the data
+    ///
+    /// Request for data from the client. Send the data as the specified MIME
+    /// type over the passed file descriptor, then close it.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`: MIME type for the data
+    /// - `fd`: file descriptor for the data
+    #[inline]
+    fn send(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// selection was cancelled
+    ///
+    /// This data source is no longer valid. The data source has been replaced
+    /// by another data source.
+    ///
+    /// The client should clean up and destroy this data source.
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {}
+}
+
+/// A mutable event handler for [ExtDataControlSourceV1] proxies.
+///
+/// This trait is the same as [ExtDataControlSourceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtDataControlSourceV1EventHandlerMut {
+    /// See [ExtDataControlSourceV1EventHandler::send].
+    #[inline]
+    fn send(&mut self, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// See [ExtDataControlSourceV1EventHandler::cancelled].
+    #[inline]
+    fn cancelled(&mut self, _slf: &ExtDataControlSourceV1Ref) {}
+}
+
+/// A mutable event handler for [ExtDataControlSourceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtDataControlSourceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtDataControlSourceV1EventHandlerMutWithData<T> {
+    /// See [ExtDataControlSourceV1EventHandler::send].
+    #[inline]
+    fn send(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// See [ExtDataControlSourceV1EventHandler::cancelled].
+    #[inline]
+    fn cancelled(&mut self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {}
+}
+
+impl<H> ExtDataControlSourceV1EventHandler for MutEventHandler<H>
+where
+    H: ExtDataControlSourceV1EventHandlerMut,
+{
+    #[inline]
+    fn send(&self, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        self.with(|h| h.send(_slf, mime_type, fd))
+    }
+
+    #[inline]
+    fn cancelled(&self, _slf: &ExtDataControlSourceV1Ref) {
+        self.with(|h| h.cancelled(_slf))
+    }
+}
+
+impl<T, H> ExtDataControlSourceV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtDataControlSourceV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn send(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        self.with(|h| h.send(_data, _slf, mime_type, fd))
+    }
+
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {
+        self.with(|h| h.cancelled(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtDataControlSourceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtDataControlSourceV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn send(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler().send(_data, _slf, mime_type, fd)
+    }
+
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {
+        let _data = self.project(_data);
+        self.handler().cancelled(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtDataControlSourceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtDataControlSourceV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn send(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtDataControlSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().send(_data, _slf, mime_type, fd)
+    }
+
+    #[inline]
+    fn cancelled(&mut self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().cancelled(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtDataControlSourceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtDataControlSourceV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_data_control_source_v1", "mime_type", args[0].s)
+                };
+                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
+                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
+                self.0.send(slf, arg0, arg1);
+            }
+            1 => {
+                self.0.cancelled(slf);
+            }
+            _ => {
+                invalid_opcode("ext_data_control_source_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtDataControlSourceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtDataControlSourceV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_data_control_source_v1", "mime_type", args[0].s)
+                };
+                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
+                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
+                self.0.send(data, slf, arg0, arg1);
+            }
+            1 => {
+                self.0.cancelled(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_data_control_source_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtDataControlSourceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtDataControlSourceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtDataControlSourceV1 {
+    /// Since when the error.invalid_offer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_OFFER__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtDataControlSourceV1Error(pub u32);
+
+impl ExtDataControlSourceV1Error {
+    /// offer sent after ext_data_control_device.set_selection
+    #[allow(dead_code)]
+    pub const INVALID_OFFER: Self = Self(1);
+}
+
+impl Debug for ExtDataControlSourceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_OFFER => "INVALID_OFFER",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for send events.
+    pub struct Send<F>(F);
+    impl<F> ExtDataControlSourceV1EventHandler for Send<F>
+    where
+        F: Fn(&ExtDataControlSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&self, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+            self.0(_slf, mime_type, fd)
+        }
+    }
+    impl<T, F> ExtDataControlSourceV1EventHandlerWithData<T> for Send<F>
+    where
+        F: Fn(&mut T, &ExtDataControlSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlSourceV1Ref,
+            mime_type: &str,
+            fd: OwnedFd,
+        ) {
+            self.0(_data, _slf, mime_type, fd)
+        }
+    }
+
+    /// Event handler for cancelled events.
+    pub struct Cancelled<F>(F);
+    impl<F> ExtDataControlSourceV1EventHandler for Cancelled<F>
+    where
+        F: Fn(&ExtDataControlSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&self, _slf: &ExtDataControlSourceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtDataControlSourceV1EventHandlerWithData<T> for Cancelled<F>
+    where
+        F: Fn(&mut T, &ExtDataControlSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for send events.
+    pub struct SendMut<F>(F);
+    impl<F> ExtDataControlSourceV1EventHandlerMut for SendMut<F>
+    where
+        F: FnMut(&ExtDataControlSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&mut self, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+            self.0(_slf, mime_type, fd)
+        }
+    }
+    impl<T, F> ExtDataControlSourceV1EventHandlerMutWithData<T> for SendMut<F>
+    where
+        F: FnMut(&mut T, &ExtDataControlSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtDataControlSourceV1Ref,
+            mime_type: &str,
+            fd: OwnedFd,
+        ) {
+            self.0(_data, _slf, mime_type, fd)
+        }
+    }
+
+    /// Mutable event handler for cancelled events.
+    pub struct CancelledMut<F>(F);
+    impl<F> ExtDataControlSourceV1EventHandlerMut for CancelledMut<F>
+    where
+        F: FnMut(&ExtDataControlSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&mut self, _slf: &ExtDataControlSourceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtDataControlSourceV1EventHandlerMutWithData<T> for CancelledMut<F>
+    where
+        F: FnMut(&mut T, &ExtDataControlSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&mut self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtDataControlSourceV1 {
+        /// Creates an event handler for send events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_send<F>(f: F) -> Send<F>
+        where
+            F: Fn(&ExtDataControlSourceV1Ref, &str, OwnedFd),
+        {
+            Send(f)
+        }
+
+        /// Creates an event handler with mutable data for send events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_send_with_data<T, F>(f: F) -> Send<F>
+        where
+            F: Fn(&mut T, &ExtDataControlSourceV1Ref, &str, OwnedFd),
+        {
+            Send(f)
+        }
+
+        /// Creates an event handler for cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cancelled<F>(f: F) -> Cancelled<F>
+        where
+            F: Fn(&ExtDataControlSourceV1Ref),
+        {
+            Cancelled(f)
+        }
+
+        /// Creates an event handler with mutable data for cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cancelled_with_data<T, F>(f: F) -> Cancelled<F>
+        where
+            F: Fn(&mut T, &ExtDataControlSourceV1Ref),
+        {
+            Cancelled(f)
+        }
+
+        /// Creates a mutable event handler for send events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_send_mut<F>(f: F) -> SendMut<F>
+        where
+            F: FnMut(&ExtDataControlSourceV1Ref, &str, OwnedFd),
+        {
+            SendMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for send events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_send_mut_with_data<T, F>(f: F) -> SendMut<F>
+        where
+            F: FnMut(&mut T, &ExtDataControlSourceV1Ref, &str, OwnedFd),
+        {
+            SendMut(f)
+        }
+
+        /// Creates a mutable event handler for cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cancelled_mut<F>(f: F) -> CancelledMut<F>
+        where
+            F: FnMut(&ExtDataControlSourceV1Ref),
+        {
+            CancelledMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cancelled_mut_with_data<T, F>(f: F) -> CancelledMut<F>
+        where
+            F: FnMut(&mut T, &ExtDataControlSourceV1Ref),
+        {
+            CancelledMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                send: Ignore,
+                cancelled: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtDataControlSourceV1EventHandler for Ignore {}
+    impl<T> ExtDataControlSourceV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtDataControlSourceV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        send: H0,
+        cancelled: H1,
+    }
+
+    impl<H0, H1> ExtDataControlSourceV1EventHandler for Handler<H0, H1>
+    where
+        H0: ExtDataControlSourceV1EventHandler,
+        H1: ExtDataControlSourceV1EventHandler,
+    {
+        #[inline]
+        fn send(&self, _slf: &ExtDataControlSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+            self.send.send(_slf, mime_type, fd)
+        }
+
+        #[inline]
+        fn cancelled(&self, _slf: &ExtDataControlSourceV1Ref) {
+            self.cancelled.cancelled(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ExtDataControlSourceV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ExtDataControlSourceV1EventHandlerWithData<T>,
+        H1: ExtDataControlSourceV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn send(
+            &self,
+            _data: &mut T,
+            _slf: &ExtDataControlSourceV1Ref,
+            mime_type: &str,
+            fd: OwnedFd,
+        ) {
+            self.send.send(_data, _slf, mime_type, fd)
+        }
+
+        #[inline]
+        fn cancelled(&self, _data: &mut T, _slf: &ExtDataControlSourceV1Ref) {
+            self.cancelled.cancelled(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for send events.
+        #[allow(dead_code)]
+        pub fn on_send<F>(self, f: F) -> Handler<Send<F>, H1>
+        where
+            F: Fn(&ExtDataControlSourceV1Ref, &str, OwnedFd),
+        {
+            Handler {
+                send: Send(f),
+                cancelled: self.cancelled,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for send events.
+        #[allow(dead_code)]
+        pub fn on_send_with_data<T, F>(self, f: F) -> Handler<Send<F>, H1>
+        where
+            F: Fn(&mut T, &ExtDataControlSourceV1Ref, &str, OwnedFd),
+        {
+            Handler {
+                send: Send(f),
+                cancelled: self.cancelled,
+            }
+        }
+
+        /// Sets the closure for cancelled events.
+        #[allow(dead_code)]
+        pub fn on_cancelled<F>(self, f: F) -> Handler<H0, Cancelled<F>>
+        where
+            F: Fn(&ExtDataControlSourceV1Ref),
+        {
+            Handler {
+                send: self.send,
+                cancelled: Cancelled(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for cancelled events.
+        #[allow(dead_code)]
+        pub fn on_cancelled_with_data<T, F>(self, f: F) -> Handler<H0, Cancelled<F>>
+        where
+            F: Fn(&mut T, &ExtDataControlSourceV1Ref),
+        {
+            Handler {
+                send: self.send,
+                cancelled: Cancelled(f),
+            }
+        }
+    }
+}
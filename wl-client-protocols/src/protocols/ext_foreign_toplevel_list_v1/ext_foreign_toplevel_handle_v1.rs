@@ -0,0 +1,1569 @@
+//! a mapped toplevel
+//!
+//! A ext_foreign_toplevel_handle_v1 object represents a mapped toplevel
+//! window. A single app may have multiple mapped toplevels.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_foreign_toplevel_handle_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 5,
+    events: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"closed".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"title".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"app_id".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"identifier".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_foreign_toplevel_handle_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_foreign_toplevel_handle_v1",
+    protocol: "ext_foreign_toplevel_list_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "closed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "title",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "title",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "app_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "app_id",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "identifier",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "identifier",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned ext_foreign_toplevel_handle_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtForeignToplevelHandleV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_foreign_toplevel_handle_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtForeignToplevelHandleV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtForeignToplevelHandleV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtForeignToplevelHandleV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtForeignToplevelHandleV1 {
+    const INTERFACE: &'static str = "ext_foreign_toplevel_handle_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtForeignToplevelHandleV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtForeignToplevelHandleV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtForeignToplevelHandleV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtForeignToplevelHandleV1Ref {
+    type Owned = ExtForeignToplevelHandleV1;
+}
+
+impl Deref for ExtForeignToplevelHandleV1 {
+    type Target = ExtForeignToplevelHandleV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtForeignToplevelHandleV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_foreign_toplevel_handle_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtForeignToplevelHandleV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_foreign_toplevel_handle_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtForeignToplevelHandleV1Ref> for ExtForeignToplevelHandleV1 {
+    fn eq(&self, other: &ExtForeignToplevelHandleV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtForeignToplevelHandleV1> for ExtForeignToplevelHandleV1Ref {
+    fn eq(&self, other: &ExtForeignToplevelHandleV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtForeignToplevelHandleV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the ext_foreign_toplevel_handle_v1 object
+    ///
+    /// This request should be used when the client will no longer use the handle
+    /// or after the closed event has been received to allow destruction of the
+    /// object.
+    ///
+    /// When a handle is destroyed, a new handle may not be created by the server
+    /// until the toplevel is unmapped and then remapped. Destroying a toplevel handle
+    /// is not recommended unless the client is cleaning up child objects
+    /// before destroying the ext_foreign_toplevel_list_v1 object, the toplevel
+    /// was closed or the toplevel handle will not be used in the future.
+    ///
+    /// Other protocols which extend the ext_foreign_toplevel_handle_v1
+    /// interface should require destructors for extension interfaces be
+    /// called before allowing the toplevel handle to be destroyed.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ExtForeignToplevelHandleV1 {
+    /// Since when the closed event is available.
+    #[allow(dead_code)]
+    pub const EVT__CLOSED__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the title event is available.
+    #[allow(dead_code)]
+    pub const EVT__TITLE__SINCE: u32 = 1;
+
+    /// Since when the app_id event is available.
+    #[allow(dead_code)]
+    pub const EVT__APP_ID__SINCE: u32 = 1;
+
+    /// Since when the identifier event is available.
+    #[allow(dead_code)]
+    pub const EVT__IDENTIFIER__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtForeignToplevelHandleV1] proxies.
+#[allow(dead_code)]
+pub trait ExtForeignToplevelHandleV1EventHandler {
+    /// the toplevel has been closed
+    ///
+    /// The server will emit no further events on the ext_foreign_toplevel_handle_v1
+    /// after this event. Any requests received aside from the destroy request must
+    /// be ignored. Upon receiving this event, the client should destroy the handle.
+    ///
+    /// Other protocols which extend the ext_foreign_toplevel_handle_v1
+    /// interface must also ignore requests other than destructors.
+    #[inline]
+    fn closed(&self, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// all information about the toplevel has been sent
+    ///
+    /// This event is sent after all changes in the toplevel state have
+    /// been sent.
+    ///
+    /// This allows changes to the ext_foreign_toplevel_handle_v1 properties
+    /// to be atomically applied. Other protocols which extend the
+    /// ext_foreign_toplevel_handle_v1 interface may use this event to also
+    /// atomically apply any pending state.
+    ///
+    /// This event must not be sent after the ext_foreign_toplevel_handle_v1.closed
+    /// event.
+    #[inline]
+    fn done(&self, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// title change
+    ///
+    /// The title of the toplevel has changed.
+    ///
+    /// The configured state must not be applied immediately. See
+    /// ext_foreign_toplevel_handle_v1.done for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `title`:
+    #[inline]
+    fn title(&self, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        let _ = title;
+    }
+
+    /// app_id change
+    ///
+    /// The app id of the toplevel has changed.
+    ///
+    /// The configured state must not be applied immediately. See
+    /// ext_foreign_toplevel_handle_v1.done for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `app_id`:
+    #[inline]
+    fn app_id(&self, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        let _ = app_id;
+    }
+
+    /// a stable identifier for a toplevel
+    ///
+    /// This identifier is used to check if two or more toplevel handles belong
+    /// to the same toplevel.
+    ///
+    /// The identifier is useful for command line tools or privileged clients
+    /// which may need to reference an exact toplevel across processes or
+    /// instances of the ext_foreign_toplevel_list_v1 global.
+    ///
+    /// The compositor must only send this event when the handle is created.
+    ///
+    /// The identifier must be unique per toplevel and it's handles. Two different
+    /// toplevels must not have the same identifier. The identifier is only valid
+    /// as long as the toplevel is mapped. If the toplevel is unmapped the identifier
+    /// must not be reused. An identifier must not be reused by the compositor to
+    /// ensure there are no races when sharing identifiers between processes.
+    ///
+    /// An identifier is a string that contains up to 32 printable ASCII bytes.
+    /// An identifier must not be an empty string. It is recommended that a
+    /// compositor includes an opaque generation value in identifiers. How the
+    /// generation value is used when generating the identifier is implementation
+    /// dependent.
+    ///
+    /// # Arguments
+    ///
+    /// - `identifier`:
+    #[inline]
+    fn identifier(&self, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+        let _ = identifier;
+    }
+}
+
+impl ExtForeignToplevelHandleV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtForeignToplevelHandleV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtForeignToplevelHandleV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtForeignToplevelHandleV1EventHandlerWithData<T> {
+    /// the toplevel has been closed
+    ///
+    /// The server will emit no further events on the ext_foreign_toplevel_handle_v1
+    /// after this event. Any requests received aside from the destroy request must
+    /// be ignored. Upon receiving this event, the client should destroy the handle.
+    ///
+    /// Other protocols which extend the ext_foreign_toplevel_handle_v1
+    /// interface must also ignore requests other than destructors.
+    #[inline]
+    fn closed(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// all information about the toplevel has been sent
+    ///
+    /// This event is sent after all changes in the toplevel state have
+    /// been sent.
+    ///
+    /// This allows changes to the ext_foreign_toplevel_handle_v1 properties
+    /// to be atomically applied. Other protocols which extend the
+    /// ext_foreign_toplevel_handle_v1 interface may use this event to also
+    /// atomically apply any pending state.
+    ///
+    /// This event must not be sent after the ext_foreign_toplevel_handle_v1.closed
+    /// event.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// title change
+    ///
+    /// The title of the toplevel has changed.
+    ///
+    /// The configured state must not be applied immediately. See
+    /// ext_foreign_toplevel_handle_v1.done for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `title`:
+    #[inline]
+    fn title(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        let _ = title;
+    }
+
+    /// app_id change
+    ///
+    /// The app id of the toplevel has changed.
+    ///
+    /// The configured state must not be applied immediately. See
+    /// ext_foreign_toplevel_handle_v1.done for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `app_id`:
+    #[inline]
+    fn app_id(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        let _ = app_id;
+    }
+
+    /// a stable identifier for a toplevel
+    ///
+    /// This identifier is used to check if two or more toplevel handles belong
+    /// to the same toplevel.
+    ///
+    /// The identifier is useful for command line tools or privileged clients
+    /// which may need to reference an exact toplevel across processes or
+    /// instances of the ext_foreign_toplevel_list_v1 global.
+    ///
+    /// The compositor must only send this event when the handle is created.
+    ///
+    /// The identifier must be unique per toplevel and it's handles. Two different
+    /// toplevels must not have the same identifier. The identifier is only valid
+    /// as long as the toplevel is mapped. If the toplevel is unmapped the identifier
+    /// must not be reused. An identifier must not be reused by the compositor to
+    /// ensure there are no races when sharing identifiers between processes.
+    ///
+    /// An identifier is a string that contains up to 32 printable ASCII bytes.
+    /// An identifier must not be an empty string. It is recommended that a
+    /// compositor includes an opaque generation value in identifiers. How the
+    /// generation value is used when generating the identifier is implementation
+    /// dependent.
+    ///
+    /// # Arguments
+    ///
+    /// - `identifier`:
+    #[inline]
+    fn identifier(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+        let _ = identifier;
+    }
+}
+
+/// A mutable event handler for [ExtForeignToplevelHandleV1] proxies.
+///
+/// This trait is the same as [ExtForeignToplevelHandleV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtForeignToplevelHandleV1EventHandlerMut {
+    /// See [ExtForeignToplevelHandleV1EventHandler::closed].
+    #[inline]
+    fn closed(&mut self, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::title].
+    #[inline]
+    fn title(&mut self, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        let _ = title;
+    }
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::app_id].
+    #[inline]
+    fn app_id(&mut self, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        let _ = app_id;
+    }
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::identifier].
+    #[inline]
+    fn identifier(&mut self, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+        let _ = identifier;
+    }
+}
+
+/// A mutable event handler for [ExtForeignToplevelHandleV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtForeignToplevelHandleV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtForeignToplevelHandleV1EventHandlerMutWithData<T> {
+    /// See [ExtForeignToplevelHandleV1EventHandler::closed].
+    #[inline]
+    fn closed(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {}
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::title].
+    #[inline]
+    fn title(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        let _ = title;
+    }
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::app_id].
+    #[inline]
+    fn app_id(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        let _ = app_id;
+    }
+
+    /// See [ExtForeignToplevelHandleV1EventHandler::identifier].
+    #[inline]
+    fn identifier(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelHandleV1Ref,
+        identifier: &str,
+    ) {
+        let _ = identifier;
+    }
+}
+
+impl<H> ExtForeignToplevelHandleV1EventHandler for MutEventHandler<H>
+where
+    H: ExtForeignToplevelHandleV1EventHandlerMut,
+{
+    #[inline]
+    fn closed(&self, _slf: &ExtForeignToplevelHandleV1Ref) {
+        self.with(|h| h.closed(_slf))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ExtForeignToplevelHandleV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn title(&self, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        self.with(|h| h.title(_slf, title))
+    }
+
+    #[inline]
+    fn app_id(&self, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        self.with(|h| h.app_id(_slf, app_id))
+    }
+
+    #[inline]
+    fn identifier(&self, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+        self.with(|h| h.identifier(_slf, identifier))
+    }
+}
+
+impl<T, H> ExtForeignToplevelHandleV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtForeignToplevelHandleV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn closed(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+        self.with(|h| h.closed(_data, _slf))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn title(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        self.with(|h| h.title(_data, _slf, title))
+    }
+
+    #[inline]
+    fn app_id(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        self.with(|h| h.app_id(_data, _slf, app_id))
+    }
+
+    #[inline]
+    fn identifier(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+        self.with(|h| h.identifier(_data, _slf, identifier))
+    }
+}
+
+impl<T, U, H> ExtForeignToplevelHandleV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtForeignToplevelHandleV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn closed(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler().closed(_data, _slf)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn title(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        let _data = self.project(_data);
+        self.handler().title(_data, _slf, title)
+    }
+
+    #[inline]
+    fn app_id(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        let _data = self.project(_data);
+        self.handler().app_id(_data, _slf, app_id)
+    }
+
+    #[inline]
+    fn identifier(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+        let _data = self.project(_data);
+        self.handler().identifier(_data, _slf, identifier)
+    }
+}
+
+impl<T, U, H> ExtForeignToplevelHandleV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtForeignToplevelHandleV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn closed(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().closed(_data, _slf)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn title(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().title(_data, _slf, title)
+    }
+
+    #[inline]
+    fn app_id(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().app_id(_data, _slf, app_id)
+    }
+
+    #[inline]
+    fn identifier(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelHandleV1Ref,
+        identifier: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().identifier(_data, _slf, identifier)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtForeignToplevelHandleV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtForeignToplevelHandleV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                self.0.closed(slf);
+            }
+            1 => {
+                self.0.done(slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_foreign_toplevel_handle_v1", "title", args[0].s)
+                };
+                self.0.title(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_foreign_toplevel_handle_v1", "app_id", args[0].s)
+                };
+                self.0.app_id(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_foreign_toplevel_handle_v1", "identifier", args[0].s)
+                };
+                self.0.identifier(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_foreign_toplevel_handle_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtForeignToplevelHandleV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.closed(data, slf);
+            }
+            1 => {
+                self.0.done(data, slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_foreign_toplevel_handle_v1", "title", args[0].s)
+                };
+                self.0.title(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_foreign_toplevel_handle_v1", "app_id", args[0].s)
+                };
+                self.0.app_id(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("ext_foreign_toplevel_handle_v1", "identifier", args[0].s)
+                };
+                self.0.identifier(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_foreign_toplevel_handle_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtForeignToplevelHandleV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for closed events.
+    pub struct Closed<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandler for Closed<F>
+    where
+        F: Fn(&ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn closed(&self, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerWithData<T> for Closed<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn closed(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandler for Done<F>
+    where
+        F: Fn(&ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for title events.
+    pub struct Title<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandler for Title<F>
+    where
+        F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn title(&self, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+            self.0(_slf, title)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerWithData<T> for Title<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn title(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+            self.0(_data, _slf, title)
+        }
+    }
+
+    /// Event handler for app_id events.
+    pub struct AppId<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandler for AppId<F>
+    where
+        F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn app_id(&self, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+            self.0(_slf, app_id)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerWithData<T> for AppId<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn app_id(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+            self.0(_data, _slf, app_id)
+        }
+    }
+
+    /// Event handler for identifier events.
+    pub struct Identifier<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandler for Identifier<F>
+    where
+        F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn identifier(&self, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+            self.0(_slf, identifier)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerWithData<T> for Identifier<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn identifier(
+            &self,
+            _data: &mut T,
+            _slf: &ExtForeignToplevelHandleV1Ref,
+            identifier: &str,
+        ) {
+            self.0(_data, _slf, identifier)
+        }
+    }
+
+    /// Mutable event handler for closed events.
+    pub struct ClosedMut<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandlerMut for ClosedMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn closed(&mut self, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerMutWithData<T> for ClosedMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn closed(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for title events.
+    pub struct TitleMut<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandlerMut for TitleMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn title(&mut self, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+            self.0(_slf, title)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerMutWithData<T> for TitleMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn title(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+            self.0(_data, _slf, title)
+        }
+    }
+
+    /// Mutable event handler for app_id events.
+    pub struct AppIdMut<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandlerMut for AppIdMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn app_id(&mut self, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+            self.0(_slf, app_id)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerMutWithData<T> for AppIdMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn app_id(&mut self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+            self.0(_data, _slf, app_id)
+        }
+    }
+
+    /// Mutable event handler for identifier events.
+    pub struct IdentifierMut<F>(F);
+    impl<F> ExtForeignToplevelHandleV1EventHandlerMut for IdentifierMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn identifier(&mut self, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+            self.0(_slf, identifier)
+        }
+    }
+    impl<T, F> ExtForeignToplevelHandleV1EventHandlerMutWithData<T> for IdentifierMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+    {
+        #[inline]
+        fn identifier(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtForeignToplevelHandleV1Ref,
+            identifier: &str,
+        ) {
+            self.0(_data, _slf, identifier)
+        }
+    }
+
+    impl ExtForeignToplevelHandleV1 {
+        /// Creates an event handler for closed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_closed<F>(f: F) -> Closed<F>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref),
+        {
+            Closed(f)
+        }
+
+        /// Creates an event handler with mutable data for closed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_closed_with_data<T, F>(f: F) -> Closed<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref),
+        {
+            Closed(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for title events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_title<F>(f: F) -> Title<F>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Title(f)
+        }
+
+        /// Creates an event handler with mutable data for title events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_title_with_data<T, F>(f: F) -> Title<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Title(f)
+        }
+
+        /// Creates an event handler for app_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_app_id<F>(f: F) -> AppId<F>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            AppId(f)
+        }
+
+        /// Creates an event handler with mutable data for app_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_app_id_with_data<T, F>(f: F) -> AppId<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            AppId(f)
+        }
+
+        /// Creates an event handler for identifier events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_identifier<F>(f: F) -> Identifier<F>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Identifier(f)
+        }
+
+        /// Creates an event handler with mutable data for identifier events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_identifier_with_data<T, F>(f: F) -> Identifier<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Identifier(f)
+        }
+
+        /// Creates a mutable event handler for closed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_closed_mut<F>(f: F) -> ClosedMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelHandleV1Ref),
+        {
+            ClosedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for closed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_closed_mut_with_data<T, F>(f: F) -> ClosedMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref),
+        {
+            ClosedMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelHandleV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for title events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_title_mut<F>(f: F) -> TitleMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            TitleMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for title events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_title_mut_with_data<T, F>(f: F) -> TitleMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            TitleMut(f)
+        }
+
+        /// Creates a mutable event handler for app_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_app_id_mut<F>(f: F) -> AppIdMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            AppIdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for app_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_app_id_mut_with_data<T, F>(f: F) -> AppIdMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            AppIdMut(f)
+        }
+
+        /// Creates a mutable event handler for identifier events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_identifier_mut<F>(f: F) -> IdentifierMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            IdentifierMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for identifier events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_identifier_mut_with_data<T, F>(f: F) -> IdentifierMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            IdentifierMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                closed: Ignore,
+                done: Ignore,
+                title: Ignore,
+                app_id: Ignore,
+                identifier: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtForeignToplevelHandleV1EventHandler for Ignore {}
+    impl<T> ExtForeignToplevelHandleV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtForeignToplevelHandleV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore> {
+        closed: H0,
+        done: H1,
+        title: H2,
+        app_id: H3,
+        identifier: H4,
+    }
+
+    impl<H0, H1, H2, H3, H4> ExtForeignToplevelHandleV1EventHandler for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ExtForeignToplevelHandleV1EventHandler,
+        H1: ExtForeignToplevelHandleV1EventHandler,
+        H2: ExtForeignToplevelHandleV1EventHandler,
+        H3: ExtForeignToplevelHandleV1EventHandler,
+        H4: ExtForeignToplevelHandleV1EventHandler,
+    {
+        #[inline]
+        fn closed(&self, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.closed.closed(_slf)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn title(&self, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+            self.title.title(_slf, title)
+        }
+
+        #[inline]
+        fn app_id(&self, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+            self.app_id.app_id(_slf, app_id)
+        }
+
+        #[inline]
+        fn identifier(&self, _slf: &ExtForeignToplevelHandleV1Ref, identifier: &str) {
+            self.identifier.identifier(_slf, identifier)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4> ExtForeignToplevelHandleV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+        H1: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+        H2: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+        H3: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+        H4: ExtForeignToplevelHandleV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn closed(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.closed.closed(_data, _slf)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn title(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, title: &str) {
+            self.title.title(_data, _slf, title)
+        }
+
+        #[inline]
+        fn app_id(&self, _data: &mut T, _slf: &ExtForeignToplevelHandleV1Ref, app_id: &str) {
+            self.app_id.app_id(_data, _slf, app_id)
+        }
+
+        #[inline]
+        fn identifier(
+            &self,
+            _data: &mut T,
+            _slf: &ExtForeignToplevelHandleV1Ref,
+            identifier: &str,
+        ) {
+            self.identifier.identifier(_data, _slf, identifier)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4> Handler<H0, H1, H2, H3, H4> {
+        /// Sets the closure for closed events.
+        #[allow(dead_code)]
+        pub fn on_closed<F>(self, f: F) -> Handler<Closed<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref),
+        {
+            Handler {
+                closed: Closed(f),
+                done: self.done,
+                title: self.title,
+                app_id: self.app_id,
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for closed events.
+        #[allow(dead_code)]
+        pub fn on_closed_with_data<T, F>(self, f: F) -> Handler<Closed<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref),
+        {
+            Handler {
+                closed: Closed(f),
+                done: self.done,
+                title: self.title,
+                app_id: self.app_id,
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, Done<F>, H2, H3, H4>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref),
+        {
+            Handler {
+                closed: self.closed,
+                done: Done(f),
+                title: self.title,
+                app_id: self.app_id,
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, Done<F>, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref),
+        {
+            Handler {
+                closed: self.closed,
+                done: Done(f),
+                title: self.title,
+                app_id: self.app_id,
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure for title events.
+        #[allow(dead_code)]
+        pub fn on_title<F>(self, f: F) -> Handler<H0, H1, Title<F>, H3, H4>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Handler {
+                closed: self.closed,
+                done: self.done,
+                title: Title(f),
+                app_id: self.app_id,
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for title events.
+        #[allow(dead_code)]
+        pub fn on_title_with_data<T, F>(self, f: F) -> Handler<H0, H1, Title<F>, H3, H4>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Handler {
+                closed: self.closed,
+                done: self.done,
+                title: Title(f),
+                app_id: self.app_id,
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure for app_id events.
+        #[allow(dead_code)]
+        pub fn on_app_id<F>(self, f: F) -> Handler<H0, H1, H2, AppId<F>, H4>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Handler {
+                closed: self.closed,
+                done: self.done,
+                title: self.title,
+                app_id: AppId(f),
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for app_id events.
+        #[allow(dead_code)]
+        pub fn on_app_id_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, AppId<F>, H4>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Handler {
+                closed: self.closed,
+                done: self.done,
+                title: self.title,
+                app_id: AppId(f),
+                identifier: self.identifier,
+            }
+        }
+
+        /// Sets the closure for identifier events.
+        #[allow(dead_code)]
+        pub fn on_identifier<F>(self, f: F) -> Handler<H0, H1, H2, H3, Identifier<F>>
+        where
+            F: Fn(&ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Handler {
+                closed: self.closed,
+                done: self.done,
+                title: self.title,
+                app_id: self.app_id,
+                identifier: Identifier(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for identifier events.
+        #[allow(dead_code)]
+        pub fn on_identifier_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Identifier<F>>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelHandleV1Ref, &str),
+        {
+            Handler {
+                closed: self.closed,
+                done: self.done,
+                title: self.title,
+                app_id: self.app_id,
+                identifier: Identifier(f),
+            }
+        }
+    }
+}
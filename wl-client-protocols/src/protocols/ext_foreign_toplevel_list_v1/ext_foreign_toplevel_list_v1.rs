@@ -0,0 +1,928 @@
+//! list toplevels
+//!
+//! A toplevel is defined as a surface with a role similar to xdg_toplevel.
+//! XWayland surfaces may be treated like toplevels in this protocol.
+//!
+//! After a client binds the ext_foreign_toplevel_list_v1, each mapped
+//! toplevel window will be sent using the ext_foreign_toplevel_list_v1.toplevel
+//! event.
+//!
+//! Clients which only care about the current state can perform a roundtrip after
+//! binding this global.
+//!
+//! For each instance of ext_foreign_toplevel_list_v1, the compositor must
+//! create a new ext_foreign_toplevel_handle_v1 object for each mapped toplevel.
+//!
+//! If a compositor implementation sends the ext_foreign_toplevel_list_v1.finished
+//! event after the global is bound, the compositor must not send any
+//! ext_foreign_toplevel_list_v1.toplevel events.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_foreign_toplevel_list_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"stop".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"toplevel".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtForeignToplevelHandleV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"finished".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_foreign_toplevel_list_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_foreign_toplevel_list_v1",
+    protocol: "ext_foreign_toplevel_list_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "stop",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "toplevel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "toplevel",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_foreign_toplevel_handle_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "finished",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned ext_foreign_toplevel_list_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtForeignToplevelListV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_foreign_toplevel_list_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtForeignToplevelListV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtForeignToplevelListV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtForeignToplevelListV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtForeignToplevelListV1 {
+    const INTERFACE: &'static str = "ext_foreign_toplevel_list_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtForeignToplevelListV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtForeignToplevelListV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtForeignToplevelListV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtForeignToplevelListV1Ref {
+    type Owned = ExtForeignToplevelListV1;
+}
+
+impl Deref for ExtForeignToplevelListV1 {
+    type Target = ExtForeignToplevelListV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtForeignToplevelListV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_foreign_toplevel_list_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtForeignToplevelListV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_foreign_toplevel_list_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtForeignToplevelListV1Ref> for ExtForeignToplevelListV1 {
+    fn eq(&self, other: &ExtForeignToplevelListV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtForeignToplevelListV1> for ExtForeignToplevelListV1Ref {
+    fn eq(&self, other: &ExtForeignToplevelListV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtForeignToplevelListV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the ext_foreign_toplevel_list_v1 object
+    ///
+    /// This request should be called either when the client will no longer
+    /// use the ext_foreign_toplevel_list_v1 or after the finished event
+    /// has been received to allow destruction of the object.
+    ///
+    /// If a client wishes to destroy this object it should send a
+    /// ext_foreign_toplevel_list_v1.stop request and wait for a ext_foreign_toplevel_list_v1.finished
+    /// event, then destroy the handles and then this object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtForeignToplevelListV1Ref {
+    /// stop sending events
+    ///
+    /// This request indicates that the client no longer wishes to receive
+    /// events for new toplevels.
+    ///
+    /// The Wayland protocol is asynchronous, meaning the compositor may send
+    /// further toplevel events until the stop request is processed.
+    /// The client should wait for a ext_foreign_toplevel_list_v1.finished
+    /// event before destroying this object.
+    #[inline]
+    pub fn stop(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+}
+
+impl ExtForeignToplevelListV1 {
+    /// Since when the toplevel event is available.
+    #[allow(dead_code)]
+    pub const EVT__TOPLEVEL__SINCE: u32 = 1;
+
+    /// Since when the finished event is available.
+    #[allow(dead_code)]
+    pub const EVT__FINISHED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtForeignToplevelListV1] proxies.
+#[allow(dead_code)]
+pub trait ExtForeignToplevelListV1EventHandler {
+    /// a toplevel has been created
+    ///
+    /// This event is emitted whenever a new toplevel window is created. It is
+    /// emitted for all toplevels, regardless of the app that has created them.
+    ///
+    /// All initial properties of the toplevel (identifier, title, app_id) will be sent
+    /// immediately after this event using the corresponding events for
+    /// ext_foreign_toplevel_handle_v1. The compositor will use the
+    /// ext_foreign_toplevel_handle_v1.done event to indicate when all data has
+    /// been sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    #[inline]
+    fn toplevel(&self, _slf: &ExtForeignToplevelListV1Ref, toplevel: ExtForeignToplevelHandleV1) {
+        let _ = toplevel;
+    }
+
+    /// the compositor has finished with the toplevel manager
+    ///
+    /// This event indicates that the compositor is done sending events
+    /// to this object. The client should destroy the object.
+    /// See ext_foreign_toplevel_list_v1.destroy for more information.
+    ///
+    /// The compositor must not send any more toplevel events after this event.
+    #[inline]
+    fn finished(&self, _slf: &ExtForeignToplevelListV1Ref) {}
+}
+
+impl ExtForeignToplevelListV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtForeignToplevelListV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtForeignToplevelListV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtForeignToplevelListV1EventHandlerWithData<T> {
+    /// a toplevel has been created
+    ///
+    /// This event is emitted whenever a new toplevel window is created. It is
+    /// emitted for all toplevels, regardless of the app that has created them.
+    ///
+    /// All initial properties of the toplevel (identifier, title, app_id) will be sent
+    /// immediately after this event using the corresponding events for
+    /// ext_foreign_toplevel_handle_v1. The compositor will use the
+    /// ext_foreign_toplevel_handle_v1.done event to indicate when all data has
+    /// been sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    #[inline]
+    fn toplevel(
+        &self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelListV1Ref,
+        toplevel: ExtForeignToplevelHandleV1,
+    ) {
+        let _ = toplevel;
+    }
+
+    /// the compositor has finished with the toplevel manager
+    ///
+    /// This event indicates that the compositor is done sending events
+    /// to this object. The client should destroy the object.
+    /// See ext_foreign_toplevel_list_v1.destroy for more information.
+    ///
+    /// The compositor must not send any more toplevel events after this event.
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {}
+}
+
+/// A mutable event handler for [ExtForeignToplevelListV1] proxies.
+///
+/// This trait is the same as [ExtForeignToplevelListV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtForeignToplevelListV1EventHandlerMut {
+    /// See [ExtForeignToplevelListV1EventHandler::toplevel].
+    #[inline]
+    fn toplevel(
+        &mut self,
+        _slf: &ExtForeignToplevelListV1Ref,
+        toplevel: ExtForeignToplevelHandleV1,
+    ) {
+        let _ = toplevel;
+    }
+
+    /// See [ExtForeignToplevelListV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _slf: &ExtForeignToplevelListV1Ref) {}
+}
+
+/// A mutable event handler for [ExtForeignToplevelListV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtForeignToplevelListV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtForeignToplevelListV1EventHandlerMutWithData<T> {
+    /// See [ExtForeignToplevelListV1EventHandler::toplevel].
+    #[inline]
+    fn toplevel(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelListV1Ref,
+        toplevel: ExtForeignToplevelHandleV1,
+    ) {
+        let _ = toplevel;
+    }
+
+    /// See [ExtForeignToplevelListV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {}
+}
+
+impl<H> ExtForeignToplevelListV1EventHandler for MutEventHandler<H>
+where
+    H: ExtForeignToplevelListV1EventHandlerMut,
+{
+    #[inline]
+    fn toplevel(&self, _slf: &ExtForeignToplevelListV1Ref, toplevel: ExtForeignToplevelHandleV1) {
+        self.with(|h| h.toplevel(_slf, toplevel))
+    }
+
+    #[inline]
+    fn finished(&self, _slf: &ExtForeignToplevelListV1Ref) {
+        self.with(|h| h.finished(_slf))
+    }
+}
+
+impl<T, H> ExtForeignToplevelListV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtForeignToplevelListV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn toplevel(
+        &self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelListV1Ref,
+        toplevel: ExtForeignToplevelHandleV1,
+    ) {
+        self.with(|h| h.toplevel(_data, _slf, toplevel))
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {
+        self.with(|h| h.finished(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtForeignToplevelListV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtForeignToplevelListV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn toplevel(
+        &self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelListV1Ref,
+        toplevel: ExtForeignToplevelHandleV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler().toplevel(_data, _slf, toplevel)
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {
+        let _data = self.project(_data);
+        self.handler().finished(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtForeignToplevelListV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtForeignToplevelListV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn toplevel(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtForeignToplevelListV1Ref,
+        toplevel: ExtForeignToplevelHandleV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().toplevel(_data, _slf, toplevel)
+    }
+
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().finished(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtForeignToplevelListV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtForeignToplevelListV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtForeignToplevelHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtForeignToplevelHandleV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtForeignToplevelHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ExtForeignToplevelHandleV1>(arg0)
+                };
+                self.0.toplevel(slf, arg0);
+            }
+            1 => {
+                self.0.finished(slf);
+            }
+            _ => {
+                invalid_opcode("ext_foreign_toplevel_list_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtForeignToplevelListV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtForeignToplevelListV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtForeignToplevelHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtForeignToplevelHandleV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtForeignToplevelHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ExtForeignToplevelHandleV1>(arg0)
+                };
+                self.0.toplevel(data, slf, arg0);
+            }
+            1 => {
+                self.0.finished(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_foreign_toplevel_list_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtForeignToplevelListV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtForeignToplevelListV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for toplevel events.
+    pub struct Toplevel<F>(F);
+    impl<F> ExtForeignToplevelListV1EventHandler for Toplevel<F>
+    where
+        F: Fn(&ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+    {
+        #[inline]
+        fn toplevel(
+            &self,
+            _slf: &ExtForeignToplevelListV1Ref,
+            toplevel: ExtForeignToplevelHandleV1,
+        ) {
+            self.0(_slf, toplevel)
+        }
+    }
+    impl<T, F> ExtForeignToplevelListV1EventHandlerWithData<T> for Toplevel<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+    {
+        #[inline]
+        fn toplevel(
+            &self,
+            _data: &mut T,
+            _slf: &ExtForeignToplevelListV1Ref,
+            toplevel: ExtForeignToplevelHandleV1,
+        ) {
+            self.0(_data, _slf, toplevel)
+        }
+    }
+
+    /// Event handler for finished events.
+    pub struct Finished<F>(F);
+    impl<F> ExtForeignToplevelListV1EventHandler for Finished<F>
+    where
+        F: Fn(&ExtForeignToplevelListV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _slf: &ExtForeignToplevelListV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtForeignToplevelListV1EventHandlerWithData<T> for Finished<F>
+    where
+        F: Fn(&mut T, &ExtForeignToplevelListV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for toplevel events.
+    pub struct ToplevelMut<F>(F);
+    impl<F> ExtForeignToplevelListV1EventHandlerMut for ToplevelMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+    {
+        #[inline]
+        fn toplevel(
+            &mut self,
+            _slf: &ExtForeignToplevelListV1Ref,
+            toplevel: ExtForeignToplevelHandleV1,
+        ) {
+            self.0(_slf, toplevel)
+        }
+    }
+    impl<T, F> ExtForeignToplevelListV1EventHandlerMutWithData<T> for ToplevelMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+    {
+        #[inline]
+        fn toplevel(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtForeignToplevelListV1Ref,
+            toplevel: ExtForeignToplevelHandleV1,
+        ) {
+            self.0(_data, _slf, toplevel)
+        }
+    }
+
+    /// Mutable event handler for finished events.
+    pub struct FinishedMut<F>(F);
+    impl<F> ExtForeignToplevelListV1EventHandlerMut for FinishedMut<F>
+    where
+        F: FnMut(&ExtForeignToplevelListV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _slf: &ExtForeignToplevelListV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtForeignToplevelListV1EventHandlerMutWithData<T> for FinishedMut<F>
+    where
+        F: FnMut(&mut T, &ExtForeignToplevelListV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtForeignToplevelListV1 {
+        /// Creates an event handler for toplevel events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_toplevel<F>(f: F) -> Toplevel<F>
+        where
+            F: Fn(&ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+        {
+            Toplevel(f)
+        }
+
+        /// Creates an event handler with mutable data for toplevel events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_toplevel_with_data<T, F>(f: F) -> Toplevel<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+        {
+            Toplevel(f)
+        }
+
+        /// Creates an event handler for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(f: F) -> Finished<F>
+        where
+            F: Fn(&ExtForeignToplevelListV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates an event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(f: F) -> Finished<F>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelListV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates a mutable event handler for toplevel events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_toplevel_mut<F>(f: F) -> ToplevelMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+        {
+            ToplevelMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for toplevel events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_toplevel_mut_with_data<T, F>(f: F) -> ToplevelMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+        {
+            ToplevelMut(f)
+        }
+
+        /// Creates a mutable event handler for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut<F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&ExtForeignToplevelListV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut_with_data<T, F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&mut T, &ExtForeignToplevelListV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                toplevel: Ignore,
+                finished: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtForeignToplevelListV1EventHandler for Ignore {}
+    impl<T> ExtForeignToplevelListV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtForeignToplevelListV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        toplevel: H0,
+        finished: H1,
+    }
+
+    impl<H0, H1> ExtForeignToplevelListV1EventHandler for Handler<H0, H1>
+    where
+        H0: ExtForeignToplevelListV1EventHandler,
+        H1: ExtForeignToplevelListV1EventHandler,
+    {
+        #[inline]
+        fn toplevel(
+            &self,
+            _slf: &ExtForeignToplevelListV1Ref,
+            toplevel: ExtForeignToplevelHandleV1,
+        ) {
+            self.toplevel.toplevel(_slf, toplevel)
+        }
+
+        #[inline]
+        fn finished(&self, _slf: &ExtForeignToplevelListV1Ref) {
+            self.finished.finished(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ExtForeignToplevelListV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ExtForeignToplevelListV1EventHandlerWithData<T>,
+        H1: ExtForeignToplevelListV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn toplevel(
+            &self,
+            _data: &mut T,
+            _slf: &ExtForeignToplevelListV1Ref,
+            toplevel: ExtForeignToplevelHandleV1,
+        ) {
+            self.toplevel.toplevel(_data, _slf, toplevel)
+        }
+
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtForeignToplevelListV1Ref) {
+            self.finished.finished(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for toplevel events.
+        #[allow(dead_code)]
+        pub fn on_toplevel<F>(self, f: F) -> Handler<Toplevel<F>, H1>
+        where
+            F: Fn(&ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+        {
+            Handler {
+                toplevel: Toplevel(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for toplevel events.
+        #[allow(dead_code)]
+        pub fn on_toplevel_with_data<T, F>(self, f: F) -> Handler<Toplevel<F>, H1>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelListV1Ref, ExtForeignToplevelHandleV1),
+        {
+            Handler {
+                toplevel: Toplevel(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(self, f: F) -> Handler<H0, Finished<F>>
+        where
+            F: Fn(&ExtForeignToplevelListV1Ref),
+        {
+            Handler {
+                toplevel: self.toplevel,
+                finished: Finished(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(self, f: F) -> Handler<H0, Finished<F>>
+        where
+            F: Fn(&mut T, &ExtForeignToplevelListV1Ref),
+        {
+            Handler {
+                toplevel: self.toplevel,
+                finished: Finished(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod ext_idle_notification_v1;
+pub mod ext_idle_notifier_v1;
+
+/// Metadata about the ext_idle_notify_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "ext_idle_notify_v1",
+    interfaces: &[&ext_idle_notifier_v1::INFO, &ext_idle_notification_v1::INFO],
+};
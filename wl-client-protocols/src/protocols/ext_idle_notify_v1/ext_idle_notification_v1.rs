@@ -0,0 +1,767 @@
+//! idle notification
+//!
+//! This interface is used by the compositor to send idle notification events
+//! to clients.
+//!
+//! Initially the notification object is not idle. The notification object
+//! becomes idle when no user activity has happened for at least the timeout
+//! duration, starting from the creation of the notification object. User
+//! activity may include input events or a presence sensor, but is
+//! compositor-specific.
+//!
+//! How this notification responds to idle inhibitors depends on how
+//! it was constructed. If constructed from the
+//! get_idle_notification request, then if an idle inhibitor is
+//! active (e.g. another client has created a zwp_idle_inhibitor_v1
+//! on a visible surface), the compositor must not make the
+//! notification object idle. However, if constructed from the
+//! get_input_idle_notification request, then idle inhibitors are
+//! ignored, and only input from the user, e.g. from a keyboard or
+//! mouse, counts as activity.
+//!
+//! When the notification object becomes idle, an idled event is sent. When
+//! user activity starts again, the notification object stops being idle,
+//! a resumed event is sent and the timeout is restarted.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_idle_notification_v1".as_ptr(),
+    version: 2,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"idled".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"resumed".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_idle_notification_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_idle_notification_v1",
+    protocol: "ext_idle_notify_v1",
+    version: 2,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "idled",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "resumed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned ext_idle_notification_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtIdleNotificationV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_idle_notification_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtIdleNotificationV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtIdleNotificationV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtIdleNotificationV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtIdleNotificationV1 {
+    const INTERFACE: &'static str = "ext_idle_notification_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = ExtIdleNotificationV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtIdleNotificationV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtIdleNotificationV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtIdleNotificationV1Ref {
+    type Owned = ExtIdleNotificationV1;
+}
+
+impl Deref for ExtIdleNotificationV1 {
+    type Target = ExtIdleNotificationV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtIdleNotificationV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_idle_notification_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtIdleNotificationV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_idle_notification_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtIdleNotificationV1Ref> for ExtIdleNotificationV1 {
+    fn eq(&self, other: &ExtIdleNotificationV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtIdleNotificationV1> for ExtIdleNotificationV1Ref {
+    fn eq(&self, other: &ExtIdleNotificationV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtIdleNotificationV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the notification object
+    ///
+    /// Destroy the notification object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ExtIdleNotificationV1 {
+    /// Since when the idled event is available.
+    #[allow(dead_code)]
+    pub const EVT__IDLED__SINCE: u32 = 1;
+
+    /// Since when the resumed event is available.
+    #[allow(dead_code)]
+    pub const EVT__RESUMED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtIdleNotificationV1] proxies.
+#[allow(dead_code)]
+pub trait ExtIdleNotificationV1EventHandler {
+    /// notification object is idle
+    ///
+    /// This event is sent when the notification object becomes idle.
+    ///
+    /// It's a compositor protocol error to send this event twice without a
+    /// resumed event in-between.
+    #[inline]
+    fn idled(&self, _slf: &ExtIdleNotificationV1Ref) {}
+
+    /// notification object is no longer idle
+    ///
+    /// This event is sent when the notification object stops being idle.
+    ///
+    /// It's a compositor protocol error to send this event twice without an
+    /// idled event in-between. It's a compositor protocol error to send this
+    /// event prior to any idled event.
+    #[inline]
+    fn resumed(&self, _slf: &ExtIdleNotificationV1Ref) {}
+}
+
+impl ExtIdleNotificationV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtIdleNotificationV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtIdleNotificationV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtIdleNotificationV1EventHandlerWithData<T> {
+    /// notification object is idle
+    ///
+    /// This event is sent when the notification object becomes idle.
+    ///
+    /// It's a compositor protocol error to send this event twice without a
+    /// resumed event in-between.
+    #[inline]
+    fn idled(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {}
+
+    /// notification object is no longer idle
+    ///
+    /// This event is sent when the notification object stops being idle.
+    ///
+    /// It's a compositor protocol error to send this event twice without an
+    /// idled event in-between. It's a compositor protocol error to send this
+    /// event prior to any idled event.
+    #[inline]
+    fn resumed(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {}
+}
+
+/// A mutable event handler for [ExtIdleNotificationV1] proxies.
+///
+/// This trait is the same as [ExtIdleNotificationV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtIdleNotificationV1EventHandlerMut {
+    /// See [ExtIdleNotificationV1EventHandler::idled].
+    #[inline]
+    fn idled(&mut self, _slf: &ExtIdleNotificationV1Ref) {}
+
+    /// See [ExtIdleNotificationV1EventHandler::resumed].
+    #[inline]
+    fn resumed(&mut self, _slf: &ExtIdleNotificationV1Ref) {}
+}
+
+/// A mutable event handler for [ExtIdleNotificationV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtIdleNotificationV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtIdleNotificationV1EventHandlerMutWithData<T> {
+    /// See [ExtIdleNotificationV1EventHandler::idled].
+    #[inline]
+    fn idled(&mut self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {}
+
+    /// See [ExtIdleNotificationV1EventHandler::resumed].
+    #[inline]
+    fn resumed(&mut self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {}
+}
+
+impl<H> ExtIdleNotificationV1EventHandler for MutEventHandler<H>
+where
+    H: ExtIdleNotificationV1EventHandlerMut,
+{
+    #[inline]
+    fn idled(&self, _slf: &ExtIdleNotificationV1Ref) {
+        self.with(|h| h.idled(_slf))
+    }
+
+    #[inline]
+    fn resumed(&self, _slf: &ExtIdleNotificationV1Ref) {
+        self.with(|h| h.resumed(_slf))
+    }
+}
+
+impl<T, H> ExtIdleNotificationV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtIdleNotificationV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn idled(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+        self.with(|h| h.idled(_data, _slf))
+    }
+
+    #[inline]
+    fn resumed(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+        self.with(|h| h.resumed(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtIdleNotificationV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtIdleNotificationV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn idled(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+        let _data = self.project(_data);
+        self.handler().idled(_data, _slf)
+    }
+
+    #[inline]
+    fn resumed(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+        let _data = self.project(_data);
+        self.handler().resumed(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtIdleNotificationV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtIdleNotificationV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn idled(&mut self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().idled(_data, _slf)
+    }
+
+    #[inline]
+    fn resumed(&mut self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().resumed(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtIdleNotificationV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtIdleNotificationV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.idled(slf);
+            }
+            1 => {
+                self.0.resumed(slf);
+            }
+            _ => {
+                invalid_opcode("ext_idle_notification_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtIdleNotificationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtIdleNotificationV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.idled(data, slf);
+            }
+            1 => {
+                self.0.resumed(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_idle_notification_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtIdleNotificationV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtIdleNotificationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for idled events.
+    pub struct Idled<F>(F);
+    impl<F> ExtIdleNotificationV1EventHandler for Idled<F>
+    where
+        F: Fn(&ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn idled(&self, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtIdleNotificationV1EventHandlerWithData<T> for Idled<F>
+    where
+        F: Fn(&mut T, &ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn idled(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for resumed events.
+    pub struct Resumed<F>(F);
+    impl<F> ExtIdleNotificationV1EventHandler for Resumed<F>
+    where
+        F: Fn(&ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn resumed(&self, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtIdleNotificationV1EventHandlerWithData<T> for Resumed<F>
+    where
+        F: Fn(&mut T, &ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn resumed(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for idled events.
+    pub struct IdledMut<F>(F);
+    impl<F> ExtIdleNotificationV1EventHandlerMut for IdledMut<F>
+    where
+        F: FnMut(&ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn idled(&mut self, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtIdleNotificationV1EventHandlerMutWithData<T> for IdledMut<F>
+    where
+        F: FnMut(&mut T, &ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn idled(&mut self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for resumed events.
+    pub struct ResumedMut<F>(F);
+    impl<F> ExtIdleNotificationV1EventHandlerMut for ResumedMut<F>
+    where
+        F: FnMut(&ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn resumed(&mut self, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtIdleNotificationV1EventHandlerMutWithData<T> for ResumedMut<F>
+    where
+        F: FnMut(&mut T, &ExtIdleNotificationV1Ref),
+    {
+        #[inline]
+        fn resumed(&mut self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtIdleNotificationV1 {
+        /// Creates an event handler for idled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_idled<F>(f: F) -> Idled<F>
+        where
+            F: Fn(&ExtIdleNotificationV1Ref),
+        {
+            Idled(f)
+        }
+
+        /// Creates an event handler with mutable data for idled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_idled_with_data<T, F>(f: F) -> Idled<F>
+        where
+            F: Fn(&mut T, &ExtIdleNotificationV1Ref),
+        {
+            Idled(f)
+        }
+
+        /// Creates an event handler for resumed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_resumed<F>(f: F) -> Resumed<F>
+        where
+            F: Fn(&ExtIdleNotificationV1Ref),
+        {
+            Resumed(f)
+        }
+
+        /// Creates an event handler with mutable data for resumed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_resumed_with_data<T, F>(f: F) -> Resumed<F>
+        where
+            F: Fn(&mut T, &ExtIdleNotificationV1Ref),
+        {
+            Resumed(f)
+        }
+
+        /// Creates a mutable event handler for idled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_idled_mut<F>(f: F) -> IdledMut<F>
+        where
+            F: FnMut(&ExtIdleNotificationV1Ref),
+        {
+            IdledMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for idled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_idled_mut_with_data<T, F>(f: F) -> IdledMut<F>
+        where
+            F: FnMut(&mut T, &ExtIdleNotificationV1Ref),
+        {
+            IdledMut(f)
+        }
+
+        /// Creates a mutable event handler for resumed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_resumed_mut<F>(f: F) -> ResumedMut<F>
+        where
+            F: FnMut(&ExtIdleNotificationV1Ref),
+        {
+            ResumedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for resumed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_resumed_mut_with_data<T, F>(f: F) -> ResumedMut<F>
+        where
+            F: FnMut(&mut T, &ExtIdleNotificationV1Ref),
+        {
+            ResumedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                idled: Ignore,
+                resumed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtIdleNotificationV1EventHandler for Ignore {}
+    impl<T> ExtIdleNotificationV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtIdleNotificationV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        idled: H0,
+        resumed: H1,
+    }
+
+    impl<H0, H1> ExtIdleNotificationV1EventHandler for Handler<H0, H1>
+    where
+        H0: ExtIdleNotificationV1EventHandler,
+        H1: ExtIdleNotificationV1EventHandler,
+    {
+        #[inline]
+        fn idled(&self, _slf: &ExtIdleNotificationV1Ref) {
+            self.idled.idled(_slf)
+        }
+
+        #[inline]
+        fn resumed(&self, _slf: &ExtIdleNotificationV1Ref) {
+            self.resumed.resumed(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ExtIdleNotificationV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ExtIdleNotificationV1EventHandlerWithData<T>,
+        H1: ExtIdleNotificationV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn idled(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+            self.idled.idled(_data, _slf)
+        }
+
+        #[inline]
+        fn resumed(&self, _data: &mut T, _slf: &ExtIdleNotificationV1Ref) {
+            self.resumed.resumed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for idled events.
+        #[allow(dead_code)]
+        pub fn on_idled<F>(self, f: F) -> Handler<Idled<F>, H1>
+        where
+            F: Fn(&ExtIdleNotificationV1Ref),
+        {
+            Handler {
+                idled: Idled(f),
+                resumed: self.resumed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for idled events.
+        #[allow(dead_code)]
+        pub fn on_idled_with_data<T, F>(self, f: F) -> Handler<Idled<F>, H1>
+        where
+            F: Fn(&mut T, &ExtIdleNotificationV1Ref),
+        {
+            Handler {
+                idled: Idled(f),
+                resumed: self.resumed,
+            }
+        }
+
+        /// Sets the closure for resumed events.
+        #[allow(dead_code)]
+        pub fn on_resumed<F>(self, f: F) -> Handler<H0, Resumed<F>>
+        where
+            F: Fn(&ExtIdleNotificationV1Ref),
+        {
+            Handler {
+                idled: self.idled,
+                resumed: Resumed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for resumed events.
+        #[allow(dead_code)]
+        pub fn on_resumed_with_data<T, F>(self, f: F) -> Handler<H0, Resumed<F>>
+        where
+            F: Fn(&mut T, &ExtIdleNotificationV1Ref),
+        {
+            Handler {
+                idled: self.idled,
+                resumed: Resumed(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,572 @@
+//! idle notification manager
+//!
+//! This interface allows clients to monitor user idle status.
+//!
+//! After binding to this global, clients can create ext_idle_notification_v1
+//! objects to get notified when the user is idle for a given amount of time.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_idle_notifier_v1".as_ptr(),
+    version: 2,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_idle_notification".as_ptr(),
+                signature: c"nuo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ExtIdleNotificationV1::WL_INTERFACE),
+                        None,
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_input_idle_notification".as_ptr(),
+                signature: c"nuo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ExtIdleNotificationV1::WL_INTERFACE),
+                        None,
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the ext_idle_notifier_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_idle_notifier_v1",
+    protocol: "ext_idle_notify_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_idle_notification",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_idle_notification_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "timeout",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_input_idle_notification",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_idle_notification_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "timeout",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned ext_idle_notifier_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtIdleNotifierV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_idle_notifier_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtIdleNotifierV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtIdleNotifierV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtIdleNotifierV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtIdleNotifierV1 {
+    const INTERFACE: &'static str = "ext_idle_notifier_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = ExtIdleNotifierV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtIdleNotifierV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtIdleNotifierV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtIdleNotifierV1Ref {
+    type Owned = ExtIdleNotifierV1;
+}
+
+impl Deref for ExtIdleNotifierV1 {
+    type Target = ExtIdleNotifierV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtIdleNotifierV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_idle_notifier_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtIdleNotifierV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_idle_notifier_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtIdleNotifierV1Ref> for ExtIdleNotifierV1 {
+    fn eq(&self, other: &ExtIdleNotifierV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtIdleNotifierV1> for ExtIdleNotifierV1Ref {
+    fn eq(&self, other: &ExtIdleNotifierV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtIdleNotifierV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager
+    ///
+    /// Destroy the manager object. All objects created via this interface
+    /// remain valid.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_idle_notification request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_IDLE_NOTIFICATION__SINCE: u32 = 1;
+
+    /// create a notification object
+    ///
+    /// Create a new idle notification object.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout`: minimum idle timeout in msec
+    /// - `seat`:
+    #[inline]
+    pub fn get_idle_notification(&self, timeout: u32, seat: &WlSeatRef) -> ExtIdleNotificationV1 {
+        let (arg1, arg2) = (timeout, seat);
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { u: arg1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `nuo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ExtIdleNotificationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtIdleNotificationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_input_idle_notification request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_INPUT_IDLE_NOTIFICATION__SINCE: u32 = 2;
+
+    /// create a notification object
+    ///
+    /// Create a new idle notification object to track input from the
+    /// user, such as keyboard and mouse movement. Because this object is
+    /// meant to track user input alone, it ignores idle inhibitors.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeout`: minimum idle timeout in msec
+    /// - `seat`:
+    #[inline]
+    pub fn get_input_idle_notification(
+        &self,
+        timeout: u32,
+        seat: &WlSeatRef,
+    ) -> ExtIdleNotificationV1 {
+        let (arg1, arg2) = (timeout, seat);
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { u: arg1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `nuo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                ExtIdleNotificationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtIdleNotificationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtIdleNotifierV1Ref {
+    /// create a notification object
+    ///
+    /// Create a new idle notification object.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `timeout`: minimum idle timeout in msec
+    /// - `seat`:
+    #[inline]
+    pub fn get_idle_notification(
+        &self,
+        _queue: &Queue,
+        timeout: u32,
+        seat: &WlSeatRef,
+    ) -> ExtIdleNotificationV1 {
+        let (arg1, arg2) = (timeout, seat);
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { u: arg1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `nuo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ExtIdleNotificationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtIdleNotificationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// create a notification object
+    ///
+    /// Create a new idle notification object to track input from the
+    /// user, such as keyboard and mouse movement. Because this object is
+    /// meant to track user input alone, it ignores idle inhibitors.
+    ///
+    /// The notification object has a minimum timeout duration and is tied to a
+    /// seat. The client will be notified if the seat is inactive for at least
+    /// the provided timeout. See ext_idle_notification_v1 for more details.
+    ///
+    /// A zero timeout is valid and means the client wants to be notified as
+    /// soon as possible when the seat is inactive.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `timeout`: minimum idle timeout in msec
+    /// - `seat`:
+    #[inline]
+    pub fn get_input_idle_notification(
+        &self,
+        _queue: &Queue,
+        timeout: u32,
+        seat: &WlSeatRef,
+    ) -> ExtIdleNotificationV1 {
+        let (arg1, arg2) = (timeout, seat);
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { u: arg1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `nuo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                ExtIdleNotificationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtIdleNotificationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ExtIdleNotifierV1] proxies.
+#[allow(dead_code)]
+pub trait ExtIdleNotifierV1EventHandler {}
+
+impl ExtIdleNotifierV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtIdleNotifierV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtIdleNotifierV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtIdleNotifierV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ExtIdleNotifierV1] proxies.
+///
+/// This trait is the same as [ExtIdleNotifierV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtIdleNotifierV1EventHandlerMut {}
+
+/// A mutable event handler for [ExtIdleNotifierV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtIdleNotifierV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtIdleNotifierV1EventHandlerMutWithData<T> {}
+
+impl<H> ExtIdleNotifierV1EventHandler for MutEventHandler<H> where
+    H: ExtIdleNotifierV1EventHandlerMut
+{
+}
+
+impl<T, H> ExtIdleNotifierV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ExtIdleNotifierV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ExtIdleNotifierV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtIdleNotifierV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ExtIdleNotifierV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtIdleNotifierV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtIdleNotifierV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_idle_notifier_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtIdleNotifierV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_idle_notifier_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtIdleNotifierV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtIdleNotifierV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ExtIdleNotifierV1 {}
+}
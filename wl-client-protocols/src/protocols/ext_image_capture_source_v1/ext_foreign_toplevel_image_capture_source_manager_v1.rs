@@ -0,0 +1,425 @@
+//! image capture source manager for foreign toplevels
+//!
+//! A manager for creating image capture source objects for
+//! ext_foreign_toplevel_handle_v1 objects.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_foreign_toplevel_image_capture_source_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"create_source".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ExtImageCaptureSourceV1::WL_INTERFACE),
+                        Some(ExtForeignToplevelHandleV1::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the ext_foreign_toplevel_image_capture_source_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_foreign_toplevel_image_capture_source_manager_v1",
+    protocol: "ext_image_capture_source_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_source",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "source",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_image_capture_source_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "toplevel_handle",
+                    ty: info::ArgType::Object,
+                    interface: Some("ext_foreign_toplevel_handle_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned ext_foreign_toplevel_image_capture_source_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtForeignToplevelImageCaptureSourceManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_foreign_toplevel_image_capture_source_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtForeignToplevelImageCaptureSourceManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtForeignToplevelImageCaptureSourceManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtForeignToplevelImageCaptureSourceManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtForeignToplevelImageCaptureSourceManagerV1 {
+    const INTERFACE: &'static str = "ext_foreign_toplevel_image_capture_source_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtForeignToplevelImageCaptureSourceManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtForeignToplevelImageCaptureSourceManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtForeignToplevelImageCaptureSourceManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtForeignToplevelImageCaptureSourceManagerV1Ref {
+    type Owned = ExtForeignToplevelImageCaptureSourceManagerV1;
+}
+
+impl Deref for ExtForeignToplevelImageCaptureSourceManagerV1 {
+    type Target = ExtForeignToplevelImageCaptureSourceManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtForeignToplevelImageCaptureSourceManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ext_foreign_toplevel_image_capture_source_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ExtForeignToplevelImageCaptureSourceManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ext_foreign_toplevel_image_capture_source_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ExtForeignToplevelImageCaptureSourceManagerV1Ref>
+    for ExtForeignToplevelImageCaptureSourceManagerV1
+{
+    fn eq(&self, other: &ExtForeignToplevelImageCaptureSourceManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtForeignToplevelImageCaptureSourceManagerV1>
+    for ExtForeignToplevelImageCaptureSourceManagerV1Ref
+{
+    fn eq(&self, other: &ExtForeignToplevelImageCaptureSourceManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtForeignToplevelImageCaptureSourceManagerV1 {
+    /// Since when the create_source request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_SOURCE__SINCE: u32 = 1;
+
+    /// create source object for foreign toplevel
+    ///
+    /// Creates a source object for a foreign toplevel handle. Images captured
+    /// from this source will show the same content as the toplevel.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel_handle`:
+    #[inline]
+    pub fn create_source(
+        &self,
+        toplevel_handle: &ExtForeignToplevelHandleV1Ref,
+    ) -> ExtImageCaptureSourceV1 {
+        let (arg1,) = (toplevel_handle,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("toplevel_handle", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ExtImageCaptureSourceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCaptureSourceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// delete this object
+    ///
+    /// Destroys the manager. This request may be sent at any time by the client
+    /// and objects created by the manager will remain valid after its
+    /// destruction.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtForeignToplevelImageCaptureSourceManagerV1Ref {
+    /// create source object for foreign toplevel
+    ///
+    /// Creates a source object for a foreign toplevel handle. Images captured
+    /// from this source will show the same content as the toplevel.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `toplevel_handle`:
+    #[inline]
+    pub fn create_source(
+        &self,
+        _queue: &Queue,
+        toplevel_handle: &ExtForeignToplevelHandleV1Ref,
+    ) -> ExtImageCaptureSourceV1 {
+        let (arg1,) = (toplevel_handle,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("toplevel_handle", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ExtImageCaptureSourceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCaptureSourceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ExtForeignToplevelImageCaptureSourceManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ExtForeignToplevelImageCaptureSourceManagerV1EventHandler {}
+
+impl ExtForeignToplevelImageCaptureSourceManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtForeignToplevelImageCaptureSourceManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtForeignToplevelImageCaptureSourceManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ExtForeignToplevelImageCaptureSourceManagerV1] proxies.
+///
+/// This trait is the same as [ExtForeignToplevelImageCaptureSourceManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ExtForeignToplevelImageCaptureSourceManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ExtForeignToplevelImageCaptureSourceManagerV1EventHandler for MutEventHandler<H> where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData<T>
+    for MutEventHandler<H>
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerMutWithData<T>,
+{
+}
+
+impl<T, U, H> ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode(
+            "ext_foreign_toplevel_image_capture_source_manager_v1",
+            opcode,
+        );
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode(
+            "ext_foreign_toplevel_image_capture_source_manager_v1",
+            opcode,
+        );
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtForeignToplevelImageCaptureSourceManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ExtForeignToplevelImageCaptureSourceManagerV1 {}
+}
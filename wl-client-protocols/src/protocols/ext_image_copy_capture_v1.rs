@@ -0,0 +1,36 @@
+//! image capturing into client buffers
+//!
+//! This protocol allows clients to ask the compositor to capture image sources
+//! such as outputs and toplevels into user submitted buffers.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod ext_image_copy_capture_cursor_session_v1;
+pub mod ext_image_copy_capture_frame_v1;
+pub mod ext_image_copy_capture_manager_v1;
+pub mod ext_image_copy_capture_session_v1;
+
+/// Metadata about the ext_image_copy_capture_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "ext_image_copy_capture_v1",
+    interfaces: &[
+        &ext_image_copy_capture_manager_v1::INFO,
+        &ext_image_copy_capture_session_v1::INFO,
+        &ext_image_copy_capture_frame_v1::INFO,
+        &ext_image_copy_capture_cursor_session_v1::INFO,
+    ],
+};
@@ -0,0 +1,1504 @@
+//! cursor capture session
+//!
+//! This object represents a cursor capture session. It extends the base
+//! capture session with cursor-specific metadata.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_image_copy_capture_cursor_session_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_capture_session".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtImageCopyCaptureSessionV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 4,
+    events: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"enter".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"leave".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"position".as_ptr(),
+                signature: c"ii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"hotspot".as_ptr(),
+                signature: c"ii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_image_copy_capture_cursor_session_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_image_copy_capture_cursor_session_v1",
+    protocol: "ext_image_copy_capture_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_capture_session",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "session",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_image_copy_capture_session_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "position",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "hotspot",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "duplicate_session",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned ext_image_copy_capture_cursor_session_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureCursorSessionV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_image_copy_capture_cursor_session_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureCursorSessionV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtImageCopyCaptureCursorSessionV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtImageCopyCaptureCursorSessionV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtImageCopyCaptureCursorSessionV1 {
+    const INTERFACE: &'static str = "ext_image_copy_capture_cursor_session_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtImageCopyCaptureCursorSessionV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtImageCopyCaptureCursorSessionV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtImageCopyCaptureCursorSessionV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtImageCopyCaptureCursorSessionV1Ref {
+    type Owned = ExtImageCopyCaptureCursorSessionV1;
+}
+
+impl Deref for ExtImageCopyCaptureCursorSessionV1 {
+    type Target = ExtImageCopyCaptureCursorSessionV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtImageCopyCaptureCursorSessionV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ext_image_copy_capture_cursor_session_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ExtImageCopyCaptureCursorSessionV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ext_image_copy_capture_cursor_session_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureCursorSessionV1Ref> for ExtImageCopyCaptureCursorSessionV1 {
+    fn eq(&self, other: &ExtImageCopyCaptureCursorSessionV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureCursorSessionV1> for ExtImageCopyCaptureCursorSessionV1Ref {
+    fn eq(&self, other: &ExtImageCopyCaptureCursorSessionV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureCursorSessionV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// delete this object
+    ///
+    /// Destroys the session. This request can be sent at any time by the
+    /// client.
+    ///
+    /// This request doesn't affect ext_image_copy_capture_frame_v1 objects created by
+    /// this object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_capture_session request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_CAPTURE_SESSION__SINCE: u32 = 1;
+
+    /// get image copy capturer session
+    ///
+    /// Gets the image copy capture session for this cursor session.
+    ///
+    /// The session will produce frames of the cursor image. The compositor may
+    /// pause the session when the cursor leaves the captured area.
+    ///
+    /// This request must not be sent more than once, or else the
+    /// duplicate_session protocol error is raised.
+    #[inline]
+    pub fn get_capture_session(&self) -> ExtImageCopyCaptureSessionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ExtImageCopyCaptureSessionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureSessionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureCursorSessionV1Ref {
+    /// get image copy capturer session
+    ///
+    /// Gets the image copy capture session for this cursor session.
+    ///
+    /// The session will produce frames of the cursor image. The compositor may
+    /// pause the session when the cursor leaves the captured area.
+    ///
+    /// This request must not be sent more than once, or else the
+    /// duplicate_session protocol error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_capture_session(&self, _queue: &Queue) -> ExtImageCopyCaptureSessionV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ExtImageCopyCaptureSessionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureSessionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ExtImageCopyCaptureCursorSessionV1 {
+    /// Since when the enter event is available.
+    #[allow(dead_code)]
+    pub const EVT__ENTER__SINCE: u32 = 1;
+
+    /// Since when the leave event is available.
+    #[allow(dead_code)]
+    pub const EVT__LEAVE__SINCE: u32 = 1;
+
+    /// Since when the position event is available.
+    #[allow(dead_code)]
+    pub const EVT__POSITION__SINCE: u32 = 1;
+
+    /// Since when the hotspot event is available.
+    #[allow(dead_code)]
+    pub const EVT__HOTSPOT__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtImageCopyCaptureCursorSessionV1] proxies.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureCursorSessionV1EventHandler {
+    /// cursor entered captured area
+    ///
+    /// Sent when a cursor enters the captured area. It shall be generated
+    /// before the "position" and "hotspot" events when and only when a cursor
+    /// enters the area.
+    ///
+    /// The cursor enters the captured area when the cursor image intersects
+    /// with the captured area. Note, this is different from e.g.
+    /// wl_pointer.enter.
+    #[inline]
+    fn enter(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// cursor left captured area
+    ///
+    /// Sent when a cursor leaves the captured area. No "position" or "hotspot"
+    /// event is generated for the cursor until the cursor enters the captured
+    /// area again.
+    #[inline]
+    fn leave(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// position changed
+    ///
+    /// Cursors outside the image capture source do not get captured and no
+    /// event will be generated for them.
+    ///
+    /// The given position is the position of the cursor's hotspot and it is
+    /// relative to the main buffer's top left corner in transformed buffer
+    /// pixel coordinates. The coordinates may be negative or greater than the
+    /// main buffer size.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: position x coordinates
+    /// - `y`: position y coordinates
+    #[inline]
+    fn position(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// hotspot changed
+    ///
+    /// The hotspot describes the offset between the cursor image and the
+    /// position of the input device.
+    ///
+    /// The given coordinates are the hotspot's offset from the origin in
+    /// buffer coordinates.
+    ///
+    /// Clients should not apply the hotspot immediately: the hotspot becomes
+    /// effective when the next ext_image_copy_capture_frame_v1.ready event is received.
+    ///
+    /// Compositors may delay this event until the client captures a new frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: hotspot x coordinates
+    /// - `y`: hotspot y coordinates
+    #[inline]
+    fn hotspot(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+}
+
+impl ExtImageCopyCaptureCursorSessionV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtImageCopyCaptureCursorSessionV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureCursorSessionV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> {
+    /// cursor entered captured area
+    ///
+    /// Sent when a cursor enters the captured area. It shall be generated
+    /// before the "position" and "hotspot" events when and only when a cursor
+    /// enters the area.
+    ///
+    /// The cursor enters the captured area when the cursor image intersects
+    /// with the captured area. Note, this is different from e.g.
+    /// wl_pointer.enter.
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// cursor left captured area
+    ///
+    /// Sent when a cursor leaves the captured area. No "position" or "hotspot"
+    /// event is generated for the cursor until the cursor enters the captured
+    /// area again.
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// position changed
+    ///
+    /// Cursors outside the image capture source do not get captured and no
+    /// event will be generated for them.
+    ///
+    /// The given position is the position of the cursor's hotspot and it is
+    /// relative to the main buffer's top left corner in transformed buffer
+    /// pixel coordinates. The coordinates may be negative or greater than the
+    /// main buffer size.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: position x coordinates
+    /// - `y`: position y coordinates
+    #[inline]
+    fn position(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// hotspot changed
+    ///
+    /// The hotspot describes the offset between the cursor image and the
+    /// position of the input device.
+    ///
+    /// The given coordinates are the hotspot's offset from the origin in
+    /// buffer coordinates.
+    ///
+    /// Clients should not apply the hotspot immediately: the hotspot becomes
+    /// effective when the next ext_image_copy_capture_frame_v1.ready event is received.
+    ///
+    /// Compositors may delay this event until the client captures a new frame.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: hotspot x coordinates
+    /// - `y`: hotspot y coordinates
+    #[inline]
+    fn hotspot(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+}
+
+/// A mutable event handler for [ExtImageCopyCaptureCursorSessionV1] proxies.
+///
+/// This trait is the same as [ExtImageCopyCaptureCursorSessionV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureCursorSessionV1EventHandlerMut {
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::position].
+    #[inline]
+    fn position(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::hotspot].
+    #[inline]
+    fn hotspot(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+}
+
+/// A mutable event handler for [ExtImageCopyCaptureCursorSessionV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureCursorSessionV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T> {
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {}
+
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::position].
+    #[inline]
+    fn position(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// See [ExtImageCopyCaptureCursorSessionV1EventHandler::hotspot].
+    #[inline]
+    fn hotspot(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+    }
+}
+
+impl<H> ExtImageCopyCaptureCursorSessionV1EventHandler for MutEventHandler<H>
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandlerMut,
+{
+    #[inline]
+    fn enter(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        self.with(|h| h.enter(_slf))
+    }
+
+    #[inline]
+    fn leave(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        self.with(|h| h.leave(_slf))
+    }
+
+    #[inline]
+    fn position(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        self.with(|h| h.position(_slf, x, y))
+    }
+
+    #[inline]
+    fn hotspot(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        self.with(|h| h.hotspot(_slf, x, y))
+    }
+}
+
+impl<T, H> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        self.with(|h| h.enter(_data, _slf))
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        self.with(|h| h.leave(_data, _slf))
+    }
+
+    #[inline]
+    fn position(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        self.with(|h| h.position(_data, _slf, x, y))
+    }
+
+    #[inline]
+    fn hotspot(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        self.with(|h| h.hotspot(_data, _slf, x, y))
+    }
+}
+
+impl<T, U, H> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler().enter(_data, _slf)
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler().leave(_data, _slf)
+    }
+
+    #[inline]
+    fn position(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().position(_data, _slf, x, y)
+    }
+
+    #[inline]
+    fn hotspot(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+        let _data = self.project(_data);
+        self.handler().hotspot(_data, _slf, x, y)
+    }
+}
+
+impl<T, U, H> ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().enter(_data, _slf)
+    }
+
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().leave(_data, _slf)
+    }
+
+    #[inline]
+    fn position(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().position(_data, _slf, x, y)
+    }
+
+    #[inline]
+    fn hotspot(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+        x: i32,
+        y: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().hotspot(_data, _slf, x, y)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtImageCopyCaptureCursorSessionV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                self.0.enter(slf);
+            }
+            1 => {
+                self.0.leave(slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.position(slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.hotspot(slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("ext_image_copy_capture_cursor_session_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtImageCopyCaptureCursorSessionV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.enter(data, slf);
+            }
+            1 => {
+                self.0.leave(data, slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.position(data, slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.hotspot(data, slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("ext_image_copy_capture_cursor_session_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtImageCopyCaptureCursorSessionV1 {
+    /// Since when the error.duplicate_session enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DUPLICATE_SESSION__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureCursorSessionV1Error(pub u32);
+
+impl ExtImageCopyCaptureCursorSessionV1Error {
+    /// get_capture_session sent twice
+    #[allow(dead_code)]
+    pub const DUPLICATE_SESSION: Self = Self(1);
+}
+
+impl Debug for ExtImageCopyCaptureCursorSessionV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::DUPLICATE_SESSION => "DUPLICATE_SESSION",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for enter events.
+    pub struct Enter<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandler for Enter<F>
+    where
+        F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn enter(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> for Enter<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for leave events.
+    pub struct Leave<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandler for Leave<F>
+    where
+        F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn leave(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> for Leave<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for position events.
+    pub struct Position<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandler for Position<F>
+    where
+        F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn position(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> for Position<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn position(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+            x: i32,
+            y: i32,
+        ) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Event handler for hotspot events.
+    pub struct Hotspot<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandler for Hotspot<F>
+    where
+        F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn hotspot(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> for Hotspot<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn hotspot(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+            x: i32,
+            y: i32,
+        ) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Mutable event handler for enter events.
+    pub struct EnterMut<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandlerMut for EnterMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn enter(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T> for EnterMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn enter(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for leave events.
+    pub struct LeaveMut<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandlerMut for LeaveMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn leave(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T> for LeaveMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+    {
+        #[inline]
+        fn leave(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for position events.
+    pub struct PositionMut<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandlerMut for PositionMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn position(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T> for PositionMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn position(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+            x: i32,
+            y: i32,
+        ) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Mutable event handler for hotspot events.
+    pub struct HotspotMut<F>(F);
+    impl<F> ExtImageCopyCaptureCursorSessionV1EventHandlerMut for HotspotMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn hotspot(&mut self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureCursorSessionV1EventHandlerMutWithData<T> for HotspotMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+    {
+        #[inline]
+        fn hotspot(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+            x: i32,
+            y: i32,
+        ) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    impl ExtImageCopyCaptureCursorSessionV1 {
+        /// Creates an event handler for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(f: F) -> Enter<F>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(f: F) -> Enter<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(f: F) -> Leave<F>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(f: F) -> Leave<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler for position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_position<F>(f: F) -> Position<F>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Position(f)
+        }
+
+        /// Creates an event handler with mutable data for position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_position_with_data<T, F>(f: F) -> Position<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Position(f)
+        }
+
+        /// Creates an event handler for hotspot events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_hotspot<F>(f: F) -> Hotspot<F>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Hotspot(f)
+        }
+
+        /// Creates an event handler with mutable data for hotspot events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_hotspot_with_data<T, F>(f: F) -> Hotspot<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Hotspot(f)
+        }
+
+        /// Creates a mutable event handler for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut<F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut_with_data<T, F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut<F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut_with_data<T, F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_position_mut<F>(f: F) -> PositionMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            PositionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_position_mut_with_data<T, F>(f: F) -> PositionMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            PositionMut(f)
+        }
+
+        /// Creates a mutable event handler for hotspot events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_hotspot_mut<F>(f: F) -> HotspotMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            HotspotMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for hotspot events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_hotspot_mut_with_data<T, F>(f: F) -> HotspotMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            HotspotMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                enter: Ignore,
+                leave: Ignore,
+                position: Ignore,
+                hotspot: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtImageCopyCaptureCursorSessionV1EventHandler for Ignore {}
+    impl<T> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtImageCopyCaptureCursorSessionV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        enter: H0,
+        leave: H1,
+        position: H2,
+        hotspot: H3,
+    }
+
+    impl<H0, H1, H2, H3> ExtImageCopyCaptureCursorSessionV1EventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: ExtImageCopyCaptureCursorSessionV1EventHandler,
+        H1: ExtImageCopyCaptureCursorSessionV1EventHandler,
+        H2: ExtImageCopyCaptureCursorSessionV1EventHandler,
+        H3: ExtImageCopyCaptureCursorSessionV1EventHandler,
+    {
+        #[inline]
+        fn enter(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.enter.enter(_slf)
+        }
+
+        #[inline]
+        fn leave(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.leave.leave(_slf)
+        }
+
+        #[inline]
+        fn position(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+            self.position.position(_slf, x, y)
+        }
+
+        #[inline]
+        fn hotspot(&self, _slf: &ExtImageCopyCaptureCursorSessionV1Ref, x: i32, y: i32) {
+            self.hotspot.hotspot(_slf, x, y)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3>
+    where
+        H0: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>,
+        H1: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>,
+        H2: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>,
+        H3: ExtImageCopyCaptureCursorSessionV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.enter.enter(_data, _slf)
+        }
+
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &ExtImageCopyCaptureCursorSessionV1Ref) {
+            self.leave.leave(_data, _slf)
+        }
+
+        #[inline]
+        fn position(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+            x: i32,
+            y: i32,
+        ) {
+            self.position.position(_data, _slf, x, y)
+        }
+
+        #[inline]
+        fn hotspot(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureCursorSessionV1Ref,
+            x: i32,
+            y: i32,
+        ) {
+            self.hotspot.hotspot(_data, _slf, x, y)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                position: self.position,
+                hotspot: self.hotspot,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                position: self.position,
+                hotspot: self.hotspot,
+            }
+        }
+
+        /// Sets the closure for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                position: self.position,
+                hotspot: self.hotspot,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                position: self.position,
+                hotspot: self.hotspot,
+            }
+        }
+
+        /// Sets the closure for position events.
+        #[allow(dead_code)]
+        pub fn on_position<F>(self, f: F) -> Handler<H0, H1, Position<F>, H3>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                position: Position(f),
+                hotspot: self.hotspot,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for position events.
+        #[allow(dead_code)]
+        pub fn on_position_with_data<T, F>(self, f: F) -> Handler<H0, H1, Position<F>, H3>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                position: Position(f),
+                hotspot: self.hotspot,
+            }
+        }
+
+        /// Sets the closure for hotspot events.
+        #[allow(dead_code)]
+        pub fn on_hotspot<F>(self, f: F) -> Handler<H0, H1, H2, Hotspot<F>>
+        where
+            F: Fn(&ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                position: self.position,
+                hotspot: Hotspot(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for hotspot events.
+        #[allow(dead_code)]
+        pub fn on_hotspot_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Hotspot<F>>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureCursorSessionV1Ref, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                position: self.position,
+                hotspot: Hotspot(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,2252 @@
+//! image capture frame
+//!
+//! This object represents an image capture frame.
+//!
+//! The client should attach a buffer, damage the buffer, and then send a
+//! capture request.
+//!
+//! If the capture is successful, the compositor must send the frame metadata
+//! (transform, damage, presentation_time in any order) followed by the ready
+//! event.
+//!
+//! If the capture fails, the compositor must send the failed event.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_image_copy_capture_frame_v1".as_ptr(),
+    version: 1,
+    method_count: 4,
+    methods: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"attach_buffer".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlBuffer::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"damage_buffer".as_ptr(),
+                signature: c"iiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"capture".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 5,
+    events: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"transform".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"damage".as_ptr(),
+                signature: c"iiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"presentation_time".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"ready".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"failed".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_image_copy_capture_frame_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_image_copy_capture_frame_v1",
+    protocol: "ext_image_copy_capture_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "attach_buffer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "buffer",
+                ty: info::ArgType::Object,
+                interface: Some("wl_buffer"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "damage_buffer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "capture",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "transform",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "transform",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_output.transform"),
+            }],
+        },
+        info::MessageInfo {
+            name: "damage",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "presentation_time",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "tv_sec_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tv_sec_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tv_nsec",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "ready",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "failed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "reason",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("failure_reason"),
+            }],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "no_buffer",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_buffer_damage",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "already_captured",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "failure_reason",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "unknown",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "buffer_constraints",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "stopped",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned ext_image_copy_capture_frame_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureFrameV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_image_copy_capture_frame_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureFrameV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtImageCopyCaptureFrameV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtImageCopyCaptureFrameV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtImageCopyCaptureFrameV1 {
+    const INTERFACE: &'static str = "ext_image_copy_capture_frame_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtImageCopyCaptureFrameV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtImageCopyCaptureFrameV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtImageCopyCaptureFrameV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtImageCopyCaptureFrameV1Ref {
+    type Owned = ExtImageCopyCaptureFrameV1;
+}
+
+impl Deref for ExtImageCopyCaptureFrameV1 {
+    type Target = ExtImageCopyCaptureFrameV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtImageCopyCaptureFrameV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_image_copy_capture_frame_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtImageCopyCaptureFrameV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_image_copy_capture_frame_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureFrameV1Ref> for ExtImageCopyCaptureFrameV1 {
+    fn eq(&self, other: &ExtImageCopyCaptureFrameV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureFrameV1> for ExtImageCopyCaptureFrameV1Ref {
+    fn eq(&self, other: &ExtImageCopyCaptureFrameV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureFrameV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy this object
+    ///
+    /// Destroys the frame. This request can be sent at any time by the
+    /// client.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureFrameV1Ref {
+    /// attach buffer to session
+    ///
+    /// Attach a buffer to the session.
+    ///
+    /// The wl_buffer.release request is unused.
+    ///
+    /// The new buffer replaces any previously attached buffer.
+    ///
+    /// This request must not be sent after capture, or else the
+    /// already_captured protocol error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer`:
+    #[inline]
+    pub fn attach_buffer(&self, buffer: &WlBufferRef) {
+        let (arg0,) = (buffer,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("buffer", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `o`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// damage buffer
+    ///
+    /// Apply damage to the buffer which is to be captured next. This request
+    /// may be sent multiple times to describe a region.
+    ///
+    /// The client indicates the accumulated damage since this wl_buffer was
+    /// last captured. During capture, the compositor will update the buffer
+    /// with at least the union of the region passed by the client and the
+    /// region advertised by ext_image_copy_capture_frame_v1.damage.
+    ///
+    /// When a wl_buffer is captured for the first time, or when the client
+    /// doesn't track damage, the client must damage the whole buffer.
+    ///
+    /// This is for optimisation purposes. The compositor may use this
+    /// information to reduce copying.
+    ///
+    /// These coordinates originate from the upper left corner of the buffer.
+    ///
+    /// If x or y are strictly negative, or if width or height are negative or
+    /// zero, the invalid_buffer_damage protocol error is raised.
+    ///
+    /// This request must not be sent after capture, or else the
+    /// already_captured protocol error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: region x coordinate
+    /// - `y`: region y coordinate
+    /// - `width`: region width
+    /// - `height`: region height
+    #[inline]
+    pub fn damage_buffer(&self, x: i32, y: i32, width: i32, height: i32) {
+        let (arg0, arg1, arg2, arg3) = (x, y, width, height);
+        let mut args = [
+            wl_argument { i: arg0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { i: arg3 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `iiii`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// capture a frame
+    ///
+    /// Capture a frame.
+    ///
+    /// Unless this is the first successful captured frame performed in this
+    /// session, the compositor may wait an indefinite amount of time for the
+    /// source content to change before performing the copy.
+    ///
+    /// This request may only be sent once, or else the already_captured
+    /// protocol error is raised. A buffer must be attached before this request
+    /// is sent, or else the no_buffer protocol error is raised.
+    #[inline]
+    pub fn capture(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+}
+
+impl ExtImageCopyCaptureFrameV1 {
+    /// Since when the transform event is available.
+    #[allow(dead_code)]
+    pub const EVT__TRANSFORM__SINCE: u32 = 1;
+
+    /// Since when the damage event is available.
+    #[allow(dead_code)]
+    pub const EVT__DAMAGE__SINCE: u32 = 1;
+
+    /// Since when the presentation_time event is available.
+    #[allow(dead_code)]
+    pub const EVT__PRESENTATION_TIME__SINCE: u32 = 1;
+
+    /// Since when the ready event is available.
+    #[allow(dead_code)]
+    pub const EVT__READY__SINCE: u32 = 1;
+
+    /// Since when the failed event is available.
+    #[allow(dead_code)]
+    pub const EVT__FAILED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtImageCopyCaptureFrameV1] proxies.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureFrameV1EventHandler {
+    /// buffer transform
+    ///
+    /// This event is sent before the ready event and holds the transform that
+    /// the compositor has applied to the buffer contents.
+    ///
+    /// # Arguments
+    ///
+    /// - `transform`:
+    #[inline]
+    fn transform(&self, _slf: &ExtImageCopyCaptureFrameV1Ref, transform: WlOutputTransform) {
+        let _ = transform;
+    }
+
+    /// buffer damaged region
+    ///
+    /// This event is sent before the ready event. It may be generated multiple
+    /// times to describe a region.
+    ///
+    /// The first captured frame in a session will always carry full damage.
+    /// Subsequent frames' damaged regions describe which parts of the buffer
+    /// have changed since the last ready event.
+    ///
+    /// These coordinates originate in the upper left corner of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: damage x coordinate
+    /// - `y`: damage y coordinate
+    /// - `width`: damage width
+    /// - `height`: damage height
+    #[inline]
+    fn damage(
+        &self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// presentation time of the frame
+    ///
+    /// This event indicates the time at which the frame is presented to the
+    /// output in system monotonic time. This event is sent before the ready
+    /// event.
+    ///
+    /// The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+    /// each component being an unsigned 32-bit value. Whole seconds are in
+    /// tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+    /// and the additional fractional part in tv_nsec as nanoseconds. Hence,
+    /// for valid timestamps tv_nsec must be in [0, 999999999].
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of the timestamp
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of the timestamp
+    /// - `tv_nsec`: nanoseconds part of the timestamp
+    #[inline]
+    fn presentation_time(
+        &self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+
+    /// frame is available for reading
+    ///
+    /// Called as soon as the frame is copied, indicating it is available
+    /// for reading.
+    ///
+    /// The buffer may be re-used by the client after this event.
+    ///
+    /// After receiving this event, the client must destroy the object.
+    #[inline]
+    fn ready(&self, _slf: &ExtImageCopyCaptureFrameV1Ref) {}
+
+    /// capture failed
+    ///
+    /// This event indicates that the attempted frame copy has failed.
+    ///
+    /// After receiving this event, the client must destroy the object.
+    ///
+    /// # Arguments
+    ///
+    /// - `reason`:
+    #[inline]
+    fn failed(
+        &self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        let _ = reason;
+    }
+}
+
+impl ExtImageCopyCaptureFrameV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtImageCopyCaptureFrameV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureFrameV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureFrameV1EventHandlerWithData<T> {
+    /// buffer transform
+    ///
+    /// This event is sent before the ready event and holds the transform that
+    /// the compositor has applied to the buffer contents.
+    ///
+    /// # Arguments
+    ///
+    /// - `transform`:
+    #[inline]
+    fn transform(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        transform: WlOutputTransform,
+    ) {
+        let _ = transform;
+    }
+
+    /// buffer damaged region
+    ///
+    /// This event is sent before the ready event. It may be generated multiple
+    /// times to describe a region.
+    ///
+    /// The first captured frame in a session will always carry full damage.
+    /// Subsequent frames' damaged regions describe which parts of the buffer
+    /// have changed since the last ready event.
+    ///
+    /// These coordinates originate in the upper left corner of the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: damage x coordinate
+    /// - `y`: damage y coordinate
+    /// - `width`: damage width
+    /// - `height`: damage height
+    #[inline]
+    fn damage(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// presentation time of the frame
+    ///
+    /// This event indicates the time at which the frame is presented to the
+    /// output in system monotonic time. This event is sent before the ready
+    /// event.
+    ///
+    /// The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+    /// each component being an unsigned 32-bit value. Whole seconds are in
+    /// tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+    /// and the additional fractional part in tv_nsec as nanoseconds. Hence,
+    /// for valid timestamps tv_nsec must be in [0, 999999999].
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of the timestamp
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of the timestamp
+    /// - `tv_nsec`: nanoseconds part of the timestamp
+    #[inline]
+    fn presentation_time(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+
+    /// frame is available for reading
+    ///
+    /// Called as soon as the frame is copied, indicating it is available
+    /// for reading.
+    ///
+    /// The buffer may be re-used by the client after this event.
+    ///
+    /// After receiving this event, the client must destroy the object.
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {}
+
+    /// capture failed
+    ///
+    /// This event indicates that the attempted frame copy has failed.
+    ///
+    /// After receiving this event, the client must destroy the object.
+    ///
+    /// # Arguments
+    ///
+    /// - `reason`:
+    #[inline]
+    fn failed(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        let _ = reason;
+    }
+}
+
+/// A mutable event handler for [ExtImageCopyCaptureFrameV1] proxies.
+///
+/// This trait is the same as [ExtImageCopyCaptureFrameV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureFrameV1EventHandlerMut {
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::transform].
+    #[inline]
+    fn transform(&mut self, _slf: &ExtImageCopyCaptureFrameV1Ref, transform: WlOutputTransform) {
+        let _ = transform;
+    }
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::damage].
+    #[inline]
+    fn damage(
+        &mut self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::presentation_time].
+    #[inline]
+    fn presentation_time(
+        &mut self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::ready].
+    #[inline]
+    fn ready(&mut self, _slf: &ExtImageCopyCaptureFrameV1Ref) {}
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::failed].
+    #[inline]
+    fn failed(
+        &mut self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        let _ = reason;
+    }
+}
+
+/// A mutable event handler for [ExtImageCopyCaptureFrameV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureFrameV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T> {
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::transform].
+    #[inline]
+    fn transform(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        transform: WlOutputTransform,
+    ) {
+        let _ = transform;
+    }
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::damage].
+    #[inline]
+    fn damage(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::presentation_time].
+    #[inline]
+    fn presentation_time(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::ready].
+    #[inline]
+    fn ready(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {}
+
+    /// See [ExtImageCopyCaptureFrameV1EventHandler::failed].
+    #[inline]
+    fn failed(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        let _ = reason;
+    }
+}
+
+impl<H> ExtImageCopyCaptureFrameV1EventHandler for MutEventHandler<H>
+where
+    H: ExtImageCopyCaptureFrameV1EventHandlerMut,
+{
+    #[inline]
+    fn transform(&self, _slf: &ExtImageCopyCaptureFrameV1Ref, transform: WlOutputTransform) {
+        self.with(|h| h.transform(_slf, transform))
+    }
+
+    #[inline]
+    fn damage(
+        &self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.with(|h| h.damage(_slf, x, y, width, height))
+    }
+
+    #[inline]
+    fn presentation_time(
+        &self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        self.with(|h| h.presentation_time(_slf, tv_sec_hi, tv_sec_lo, tv_nsec))
+    }
+
+    #[inline]
+    fn ready(&self, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+        self.with(|h| h.ready(_slf))
+    }
+
+    #[inline]
+    fn failed(
+        &self,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        self.with(|h| h.failed(_slf, reason))
+    }
+}
+
+impl<T, H> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn transform(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        transform: WlOutputTransform,
+    ) {
+        self.with(|h| h.transform(_data, _slf, transform))
+    }
+
+    #[inline]
+    fn damage(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.with(|h| h.damage(_data, _slf, x, y, width, height))
+    }
+
+    #[inline]
+    fn presentation_time(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        self.with(|h| h.presentation_time(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec))
+    }
+
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+        self.with(|h| h.ready(_data, _slf))
+    }
+
+    #[inline]
+    fn failed(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        self.with(|h| h.failed(_data, _slf, reason))
+    }
+}
+
+impl<T, U, H> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureFrameV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn transform(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        transform: WlOutputTransform,
+    ) {
+        let _data = self.project(_data);
+        self.handler().transform(_data, _slf, transform)
+    }
+
+    #[inline]
+    fn damage(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().damage(_data, _slf, x, y, width, height)
+    }
+
+    #[inline]
+    fn presentation_time(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .presentation_time(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+    }
+
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+        let _data = self.project(_data);
+        self.handler().ready(_data, _slf)
+    }
+
+    #[inline]
+    fn failed(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        let _data = self.project(_data);
+        self.handler().failed(_data, _slf, reason)
+    }
+}
+
+impl<T, U, H> ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureFrameV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn transform(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        transform: WlOutputTransform,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().transform(_data, _slf, transform)
+    }
+
+    #[inline]
+    fn damage(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().damage(_data, _slf, x, y, width, height)
+    }
+
+    #[inline]
+    fn presentation_time(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .presentation_time(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+    }
+
+    #[inline]
+    fn ready(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().ready(_data, _slf)
+    }
+
+    #[inline]
+    fn failed(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureFrameV1Ref,
+        reason: ExtImageCopyCaptureFrameV1FailureReason,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().failed(_data, _slf, reason)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtImageCopyCaptureFrameV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtImageCopyCaptureFrameV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlOutputTransform(args[0].u) };
+                self.0.transform(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                self.0.damage(slf, arg0, arg1, arg2, arg3);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.presentation_time(slf, arg0, arg1, arg2);
+            }
+            3 => {
+                self.0.ready(slf);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtImageCopyCaptureFrameV1FailureReason(args[0].u) };
+                self.0.failed(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_image_copy_capture_frame_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtImageCopyCaptureFrameV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlOutputTransform(args[0].u) };
+                self.0.transform(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                self.0.damage(data, slf, arg0, arg1, arg2, arg3);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.presentation_time(data, slf, arg0, arg1, arg2);
+            }
+            3 => {
+                self.0.ready(data, slf);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtImageCopyCaptureFrameV1FailureReason(args[0].u) };
+                self.0.failed(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("ext_image_copy_capture_frame_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureFrameV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtImageCopyCaptureFrameV1 {
+    /// Since when the error.no_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.invalid_buffer_damage enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_BUFFER_DAMAGE__SINCE: u32 = 1;
+    /// Since when the error.already_captured enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_CAPTURED__SINCE: u32 = 1;
+
+    /// Since when the failure_reason.unknown enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FAILURE_REASON_UNKNOWN__SINCE: u32 = 1;
+    /// Since when the failure_reason.buffer_constraints enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FAILURE_REASON_BUFFER_CONSTRAINTS__SINCE: u32 = 1;
+    /// Since when the failure_reason.stopped enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FAILURE_REASON_STOPPED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureFrameV1Error(pub u32);
+
+impl ExtImageCopyCaptureFrameV1Error {
+    /// capture sent without attach_buffer
+    #[allow(dead_code)]
+    pub const NO_BUFFER: Self = Self(1);
+
+    /// invalid buffer damage
+    #[allow(dead_code)]
+    pub const INVALID_BUFFER_DAMAGE: Self = Self(2);
+
+    /// capture request has been sent
+    #[allow(dead_code)]
+    pub const ALREADY_CAPTURED: Self = Self(3);
+}
+
+impl Debug for ExtImageCopyCaptureFrameV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NO_BUFFER => "NO_BUFFER",
+            Self::INVALID_BUFFER_DAMAGE => "INVALID_BUFFER_DAMAGE",
+            Self::ALREADY_CAPTURED => "ALREADY_CAPTURED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureFrameV1FailureReason(pub u32);
+
+impl ExtImageCopyCaptureFrameV1FailureReason {
+    /// unknown runtime error
+    ///
+    /// An unspecified runtime error has occurred. The client may retry.
+    #[allow(dead_code)]
+    pub const UNKNOWN: Self = Self(0);
+
+    /// buffer constraints mismatch
+    ///
+    /// The buffer submitted by the client doesn't match the latest session
+    /// constraints. The client should re-allocate its buffers and retry.
+    #[allow(dead_code)]
+    pub const BUFFER_CONSTRAINTS: Self = Self(1);
+
+    /// session is no longer available
+    ///
+    /// The session has stopped. See ext_image_copy_capture_session_v1.stopped.
+    #[allow(dead_code)]
+    pub const STOPPED: Self = Self(2);
+}
+
+impl Debug for ExtImageCopyCaptureFrameV1FailureReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::UNKNOWN => "UNKNOWN",
+            Self::BUFFER_CONSTRAINTS => "BUFFER_CONSTRAINTS",
+            Self::STOPPED => "STOPPED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for transform events.
+    pub struct Transform<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandler for Transform<F>
+    where
+        F: Fn(&ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+    {
+        #[inline]
+        fn transform(&self, _slf: &ExtImageCopyCaptureFrameV1Ref, transform: WlOutputTransform) {
+            self.0(_slf, transform)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for Transform<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+    {
+        #[inline]
+        fn transform(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            transform: WlOutputTransform,
+        ) {
+            self.0(_data, _slf, transform)
+        }
+    }
+
+    /// Event handler for damage events.
+    pub struct Damage<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandler for Damage<F>
+    where
+        F: Fn(&ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn damage(
+            &self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_slf, x, y, width, height)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for Damage<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn damage(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, x, y, width, height)
+        }
+    }
+
+    /// Event handler for presentation_time events.
+    pub struct PresentationTime<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandler for PresentationTime<F>
+    where
+        F: Fn(&ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn presentation_time(
+            &self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for PresentationTime<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn presentation_time(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+
+    /// Event handler for ready events.
+    pub struct Ready<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandler for Ready<F>
+    where
+        F: Fn(&ExtImageCopyCaptureFrameV1Ref),
+    {
+        #[inline]
+        fn ready(&self, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for Ready<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref),
+    {
+        #[inline]
+        fn ready(&self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for failed events.
+    pub struct Failed<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandler for Failed<F>
+    where
+        F: Fn(&ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+    {
+        #[inline]
+        fn failed(
+            &self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            reason: ExtImageCopyCaptureFrameV1FailureReason,
+        ) {
+            self.0(_slf, reason)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for Failed<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+    {
+        #[inline]
+        fn failed(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            reason: ExtImageCopyCaptureFrameV1FailureReason,
+        ) {
+            self.0(_data, _slf, reason)
+        }
+    }
+
+    /// Mutable event handler for transform events.
+    pub struct TransformMut<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandlerMut for TransformMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+    {
+        #[inline]
+        fn transform(
+            &mut self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            transform: WlOutputTransform,
+        ) {
+            self.0(_slf, transform)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T> for TransformMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+    {
+        #[inline]
+        fn transform(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            transform: WlOutputTransform,
+        ) {
+            self.0(_data, _slf, transform)
+        }
+    }
+
+    /// Mutable event handler for damage events.
+    pub struct DamageMut<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandlerMut for DamageMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn damage(
+            &mut self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_slf, x, y, width, height)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T> for DamageMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn damage(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, x, y, width, height)
+        }
+    }
+
+    /// Mutable event handler for presentation_time events.
+    pub struct PresentationTimeMut<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandlerMut for PresentationTimeMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn presentation_time(
+            &mut self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T> for PresentationTimeMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn presentation_time(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+
+    /// Mutable event handler for ready events.
+    pub struct ReadyMut<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandlerMut for ReadyMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureFrameV1Ref),
+    {
+        #[inline]
+        fn ready(&mut self, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T> for ReadyMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref),
+    {
+        #[inline]
+        fn ready(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for failed events.
+    pub struct FailedMut<F>(F);
+    impl<F> ExtImageCopyCaptureFrameV1EventHandlerMut for FailedMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+    {
+        #[inline]
+        fn failed(
+            &mut self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            reason: ExtImageCopyCaptureFrameV1FailureReason,
+        ) {
+            self.0(_slf, reason)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureFrameV1EventHandlerMutWithData<T> for FailedMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+    {
+        #[inline]
+        fn failed(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            reason: ExtImageCopyCaptureFrameV1FailureReason,
+        ) {
+            self.0(_data, _slf, reason)
+        }
+    }
+
+    impl ExtImageCopyCaptureFrameV1 {
+        /// Creates an event handler for transform events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_transform<F>(f: F) -> Transform<F>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+        {
+            Transform(f)
+        }
+
+        /// Creates an event handler with mutable data for transform events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_transform_with_data<T, F>(f: F) -> Transform<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+        {
+            Transform(f)
+        }
+
+        /// Creates an event handler for damage events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_damage<F>(f: F) -> Damage<F>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+        {
+            Damage(f)
+        }
+
+        /// Creates an event handler with mutable data for damage events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_damage_with_data<T, F>(f: F) -> Damage<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+        {
+            Damage(f)
+        }
+
+        /// Creates an event handler for presentation_time events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_presentation_time<F>(f: F) -> PresentationTime<F>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+        {
+            PresentationTime(f)
+        }
+
+        /// Creates an event handler with mutable data for presentation_time events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_presentation_time_with_data<T, F>(f: F) -> PresentationTime<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+        {
+            PresentationTime(f)
+        }
+
+        /// Creates an event handler for ready events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready<F>(f: F) -> Ready<F>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref),
+        {
+            Ready(f)
+        }
+
+        /// Creates an event handler with mutable data for ready events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready_with_data<T, F>(f: F) -> Ready<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref),
+        {
+            Ready(f)
+        }
+
+        /// Creates an event handler for failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_failed<F>(f: F) -> Failed<F>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+        {
+            Failed(f)
+        }
+
+        /// Creates an event handler with mutable data for failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_failed_with_data<T, F>(f: F) -> Failed<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+        {
+            Failed(f)
+        }
+
+        /// Creates a mutable event handler for transform events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_transform_mut<F>(f: F) -> TransformMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+        {
+            TransformMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for transform events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_transform_mut_with_data<T, F>(f: F) -> TransformMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+        {
+            TransformMut(f)
+        }
+
+        /// Creates a mutable event handler for damage events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_damage_mut<F>(f: F) -> DamageMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+        {
+            DamageMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for damage events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_damage_mut_with_data<T, F>(f: F) -> DamageMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+        {
+            DamageMut(f)
+        }
+
+        /// Creates a mutable event handler for presentation_time events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_presentation_time_mut<F>(f: F) -> PresentationTimeMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+        {
+            PresentationTimeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for presentation_time events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_presentation_time_mut_with_data<T, F>(f: F) -> PresentationTimeMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+        {
+            PresentationTimeMut(f)
+        }
+
+        /// Creates a mutable event handler for ready events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready_mut<F>(f: F) -> ReadyMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureFrameV1Ref),
+        {
+            ReadyMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ready events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready_mut_with_data<T, F>(f: F) -> ReadyMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureFrameV1Ref),
+        {
+            ReadyMut(f)
+        }
+
+        /// Creates a mutable event handler for failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_failed_mut<F>(f: F) -> FailedMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+        {
+            FailedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_failed_mut_with_data<T, F>(f: F) -> FailedMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &ExtImageCopyCaptureFrameV1Ref,
+                ExtImageCopyCaptureFrameV1FailureReason,
+            ),
+        {
+            FailedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                transform: Ignore,
+                damage: Ignore,
+                presentation_time: Ignore,
+                ready: Ignore,
+                failed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtImageCopyCaptureFrameV1EventHandler for Ignore {}
+    impl<T> ExtImageCopyCaptureFrameV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtImageCopyCaptureFrameV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore> {
+        transform: H0,
+        damage: H1,
+        presentation_time: H2,
+        ready: H3,
+        failed: H4,
+    }
+
+    impl<H0, H1, H2, H3, H4> ExtImageCopyCaptureFrameV1EventHandler for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ExtImageCopyCaptureFrameV1EventHandler,
+        H1: ExtImageCopyCaptureFrameV1EventHandler,
+        H2: ExtImageCopyCaptureFrameV1EventHandler,
+        H3: ExtImageCopyCaptureFrameV1EventHandler,
+        H4: ExtImageCopyCaptureFrameV1EventHandler,
+    {
+        #[inline]
+        fn transform(&self, _slf: &ExtImageCopyCaptureFrameV1Ref, transform: WlOutputTransform) {
+            self.transform.transform(_slf, transform)
+        }
+
+        #[inline]
+        fn damage(
+            &self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.damage.damage(_slf, x, y, width, height)
+        }
+
+        #[inline]
+        fn presentation_time(
+            &self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.presentation_time
+                .presentation_time(_slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+
+        #[inline]
+        fn ready(&self, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+            self.ready.ready(_slf)
+        }
+
+        #[inline]
+        fn failed(
+            &self,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            reason: ExtImageCopyCaptureFrameV1FailureReason,
+        ) {
+            self.failed.failed(_slf, reason)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4> ExtImageCopyCaptureFrameV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+        H1: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+        H2: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+        H3: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+        H4: ExtImageCopyCaptureFrameV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn transform(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            transform: WlOutputTransform,
+        ) {
+            self.transform.transform(_data, _slf, transform)
+        }
+
+        #[inline]
+        fn damage(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.damage.damage(_data, _slf, x, y, width, height)
+        }
+
+        #[inline]
+        fn presentation_time(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.presentation_time
+                .presentation_time(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+
+        #[inline]
+        fn ready(&self, _data: &mut T, _slf: &ExtImageCopyCaptureFrameV1Ref) {
+            self.ready.ready(_data, _slf)
+        }
+
+        #[inline]
+        fn failed(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureFrameV1Ref,
+            reason: ExtImageCopyCaptureFrameV1FailureReason,
+        ) {
+            self.failed.failed(_data, _slf, reason)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4> Handler<H0, H1, H2, H3, H4> {
+        /// Sets the closure for transform events.
+        #[allow(dead_code)]
+        pub fn on_transform<F>(self, f: F) -> Handler<Transform<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+        {
+            Handler {
+                transform: Transform(f),
+                damage: self.damage,
+                presentation_time: self.presentation_time,
+                ready: self.ready,
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for transform events.
+        #[allow(dead_code)]
+        pub fn on_transform_with_data<T, F>(self, f: F) -> Handler<Transform<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, WlOutputTransform),
+        {
+            Handler {
+                transform: Transform(f),
+                damage: self.damage,
+                presentation_time: self.presentation_time,
+                ready: self.ready,
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure for damage events.
+        #[allow(dead_code)]
+        pub fn on_damage<F>(self, f: F) -> Handler<H0, Damage<F>, H2, H3, H4>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+        {
+            Handler {
+                transform: self.transform,
+                damage: Damage(f),
+                presentation_time: self.presentation_time,
+                ready: self.ready,
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for damage events.
+        #[allow(dead_code)]
+        pub fn on_damage_with_data<T, F>(self, f: F) -> Handler<H0, Damage<F>, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, i32, i32, i32, i32),
+        {
+            Handler {
+                transform: self.transform,
+                damage: Damage(f),
+                presentation_time: self.presentation_time,
+                ready: self.ready,
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure for presentation_time events.
+        #[allow(dead_code)]
+        pub fn on_presentation_time<F>(self, f: F) -> Handler<H0, H1, PresentationTime<F>, H3, H4>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+        {
+            Handler {
+                transform: self.transform,
+                damage: self.damage,
+                presentation_time: PresentationTime(f),
+                ready: self.ready,
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for presentation_time events.
+        #[allow(dead_code)]
+        pub fn on_presentation_time_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, PresentationTime<F>, H3, H4>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, u32, u32, u32),
+        {
+            Handler {
+                transform: self.transform,
+                damage: self.damage,
+                presentation_time: PresentationTime(f),
+                ready: self.ready,
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure for ready events.
+        #[allow(dead_code)]
+        pub fn on_ready<F>(self, f: F) -> Handler<H0, H1, H2, Ready<F>, H4>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref),
+        {
+            Handler {
+                transform: self.transform,
+                damage: self.damage,
+                presentation_time: self.presentation_time,
+                ready: Ready(f),
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for ready events.
+        #[allow(dead_code)]
+        pub fn on_ready_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Ready<F>, H4>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref),
+        {
+            Handler {
+                transform: self.transform,
+                damage: self.damage,
+                presentation_time: self.presentation_time,
+                ready: Ready(f),
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure for failed events.
+        #[allow(dead_code)]
+        pub fn on_failed<F>(self, f: F) -> Handler<H0, H1, H2, H3, Failed<F>>
+        where
+            F: Fn(&ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+        {
+            Handler {
+                transform: self.transform,
+                damage: self.damage,
+                presentation_time: self.presentation_time,
+                ready: self.ready,
+                failed: Failed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for failed events.
+        #[allow(dead_code)]
+        pub fn on_failed_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Failed<F>>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureFrameV1Ref, ExtImageCopyCaptureFrameV1FailureReason),
+        {
+            Handler {
+                transform: self.transform,
+                damage: self.damage,
+                presentation_time: self.presentation_time,
+                ready: self.ready,
+                failed: Failed(f),
+            }
+        }
+    }
+}
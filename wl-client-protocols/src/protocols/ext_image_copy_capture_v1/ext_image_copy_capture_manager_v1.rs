@@ -0,0 +1,835 @@
+//! manager to inform clients and begin capturing
+//!
+//! This object is a manager which offers requests to start capturing from a
+//! source.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_image_copy_capture_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"create_session".as_ptr(),
+                signature: c"nou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ExtImageCopyCaptureSessionV1::WL_INTERFACE),
+                        Some(ExtImageCaptureSourceV1::WL_INTERFACE),
+                        None,
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_pointer_cursor_session".as_ptr(),
+                signature: c"noo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ExtImageCopyCaptureCursorSessionV1::WL_INTERFACE),
+                        Some(ExtImageCaptureSourceV1::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the ext_image_copy_capture_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_image_copy_capture_manager_v1",
+    protocol: "ext_image_copy_capture_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_session",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "session",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_image_copy_capture_session_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "source",
+                    ty: info::ArgType::Object,
+                    interface: Some("ext_image_capture_source_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "options",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("options"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "create_pointer_cursor_session",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "session",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_image_copy_capture_cursor_session_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "source",
+                    ty: info::ArgType::Object,
+                    interface: Some("ext_image_capture_source_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "invalid_option",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+        info::EnumInfo {
+            name: "options",
+            since: 1,
+            bitfield: true,
+            entries: &[info::EntryInfo {
+                name: "paint_cursors",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+    ],
+};
+
+/// An owned ext_image_copy_capture_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_image_copy_capture_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtImageCopyCaptureManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtImageCopyCaptureManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtImageCopyCaptureManagerV1 {
+    const INTERFACE: &'static str = "ext_image_copy_capture_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtImageCopyCaptureManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtImageCopyCaptureManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtImageCopyCaptureManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtImageCopyCaptureManagerV1Ref {
+    type Owned = ExtImageCopyCaptureManagerV1;
+}
+
+impl Deref for ExtImageCopyCaptureManagerV1 {
+    type Target = ExtImageCopyCaptureManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtImageCopyCaptureManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_image_copy_capture_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtImageCopyCaptureManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_image_copy_capture_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureManagerV1Ref> for ExtImageCopyCaptureManagerV1 {
+    fn eq(&self, other: &ExtImageCopyCaptureManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureManagerV1> for ExtImageCopyCaptureManagerV1Ref {
+    fn eq(&self, other: &ExtImageCopyCaptureManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureManagerV1 {
+    /// Since when the create_session request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_SESSION__SINCE: u32 = 1;
+
+    /// capture an image capture source
+    ///
+    /// Create a capturing session for an image capture source.
+    ///
+    /// If the paint_cursors option is set, cursors shall be composited onto
+    /// the captured frame. The cursor must not be composited onto the frame
+    /// if this flag is not set.
+    ///
+    /// If the options bitfield is invalid, the invalid_option protocol error
+    /// is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`:
+    /// - `options`:
+    #[inline]
+    pub fn create_session(
+        &self,
+        source: &ExtImageCaptureSourceV1Ref,
+        options: ExtImageCopyCaptureManagerV1Options,
+    ) -> ExtImageCopyCaptureSessionV1 {
+        let (arg1, arg2) = (source, options);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("source", obj1_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { u: arg2.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `nou`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ExtImageCopyCaptureSessionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureSessionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the create_pointer_cursor_session request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_POINTER_CURSOR_SESSION__SINCE: u32 = 1;
+
+    /// capture the pointer cursor of an image capture source
+    ///
+    /// Create a cursor capturing session for the pointer of an image capture
+    /// source.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`:
+    /// - `pointer`:
+    #[inline]
+    pub fn create_pointer_cursor_session(
+        &self,
+        source: &ExtImageCaptureSourceV1Ref,
+        pointer: &WlPointerRef,
+    ) -> ExtImageCopyCaptureCursorSessionV1 {
+        let (arg1, arg2) = (source, pointer);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("source", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("pointer", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ExtImageCopyCaptureCursorSessionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureCursorSessionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager
+    ///
+    /// Destroy the manager object.
+    ///
+    /// Other objects created via this interface are unaffected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(2, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureManagerV1Ref {
+    /// capture an image capture source
+    ///
+    /// Create a capturing session for an image capture source.
+    ///
+    /// If the paint_cursors option is set, cursors shall be composited onto
+    /// the captured frame. The cursor must not be composited onto the frame
+    /// if this flag is not set.
+    ///
+    /// If the options bitfield is invalid, the invalid_option protocol error
+    /// is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `source`:
+    /// - `options`:
+    #[inline]
+    pub fn create_session(
+        &self,
+        _queue: &Queue,
+        source: &ExtImageCaptureSourceV1Ref,
+        options: ExtImageCopyCaptureManagerV1Options,
+    ) -> ExtImageCopyCaptureSessionV1 {
+        let (arg1, arg2) = (source, options);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("source", obj1_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { u: arg2.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `nou`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ExtImageCopyCaptureSessionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureSessionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// capture the pointer cursor of an image capture source
+    ///
+    /// Create a cursor capturing session for the pointer of an image capture
+    /// source.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `source`:
+    /// - `pointer`:
+    #[inline]
+    pub fn create_pointer_cursor_session(
+        &self,
+        _queue: &Queue,
+        source: &ExtImageCaptureSourceV1Ref,
+        pointer: &WlPointerRef,
+    ) -> ExtImageCopyCaptureCursorSessionV1 {
+        let (arg1, arg2) = (source, pointer);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("source", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("pointer", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ExtImageCopyCaptureCursorSessionV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureCursorSessionV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ExtImageCopyCaptureManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureManagerV1EventHandler {}
+
+impl ExtImageCopyCaptureManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtImageCopyCaptureManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ExtImageCopyCaptureManagerV1] proxies.
+///
+/// This trait is the same as [ExtImageCopyCaptureManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ExtImageCopyCaptureManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ExtImageCopyCaptureManagerV1EventHandler for MutEventHandler<H> where
+    H: ExtImageCopyCaptureManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ExtImageCopyCaptureManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ExtImageCopyCaptureManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ExtImageCopyCaptureManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtImageCopyCaptureManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ExtImageCopyCaptureManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtImageCopyCaptureManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_image_copy_capture_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtImageCopyCaptureManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_image_copy_capture_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtImageCopyCaptureManagerV1 {
+    /// Since when the error.invalid_option enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_OPTION__SINCE: u32 = 1;
+
+    /// Since when the options.paint_cursors enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__OPTIONS_PAINT_CURSORS__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureManagerV1Error(pub u32);
+
+impl ExtImageCopyCaptureManagerV1Error {
+    /// invalid option flag
+    #[allow(dead_code)]
+    pub const INVALID_OPTION: Self = Self(1);
+}
+
+impl Debug for ExtImageCopyCaptureManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_OPTION => "INVALID_OPTION",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureManagerV1Options(pub u32);
+
+/// An iterator over the set bits in a [ExtImageCopyCaptureManagerV1Options].
+///
+/// You can construct this with the `IntoIterator` implementation of `ExtImageCopyCaptureManagerV1Options`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureManagerV1OptionsIter(pub u32);
+
+impl ExtImageCopyCaptureManagerV1Options {
+    /// paint cursors onto captured frames
+    #[allow(dead_code)]
+    pub const PAINT_CURSORS: Self = Self(1);
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureManagerV1Options {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1)
+    }
+}
+
+impl Iterator for ExtImageCopyCaptureManagerV1OptionsIter {
+    type Item = ExtImageCopyCaptureManagerV1Options;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ExtImageCopyCaptureManagerV1Options(bit))
+    }
+}
+
+impl IntoIterator for ExtImageCopyCaptureManagerV1Options {
+    type Item = ExtImageCopyCaptureManagerV1Options;
+    type IntoIter = ExtImageCopyCaptureManagerV1OptionsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ExtImageCopyCaptureManagerV1OptionsIter(self.0)
+    }
+}
+
+impl BitAnd for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ExtImageCopyCaptureManagerV1Options {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ExtImageCopyCaptureManagerV1Options {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ExtImageCopyCaptureManagerV1Options {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ExtImageCopyCaptureManagerV1Options {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ExtImageCopyCaptureManagerV1Options {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ExtImageCopyCaptureManagerV1Options {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("PAINT_CURSORS")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ExtImageCopyCaptureManagerV1 {}
+}
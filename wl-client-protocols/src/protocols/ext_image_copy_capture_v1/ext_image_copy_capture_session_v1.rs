@@ -0,0 +1,2121 @@
+//! image copy capture session
+//!
+//! This object represents an active image copy capture session.
+//!
+//! After a capture session is created, buffer constraint events will be
+//! emitted from the compositor to tell the client which buffer types and
+//! formats are supported for reading from the session. The compositor may
+//! re-send buffer constraint events whenever they change.
+//!
+//! To advertise buffer constraints, the compositor must send in no
+//! particular order: zero or more shm_format and dmabuf_format events, zero
+//! or one dmabuf_device event, and exactly one buffer_size event. Then the
+//! compositor must send a done event.
+//!
+//! When the client has received all the buffer constraints, it can create a
+//! buffer accordingly, attach it to the capture session using the
+//! attach_buffer request, set the buffer damage using the damage_buffer
+//! request and then send the capture request.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_image_copy_capture_session_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"create_frame".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtImageCopyCaptureFrameV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 6,
+    events: {
+        static MESSAGES: [wl_message; 6] = [
+            wl_message {
+                name: c"buffer_size".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"shm_format".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"dmabuf_device".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"dmabuf_format".as_ptr(),
+                signature: c"ua".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"stopped".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_image_copy_capture_session_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_image_copy_capture_session_v1",
+    protocol: "ext_image_copy_capture_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_frame",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "frame",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_image_copy_capture_frame_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "buffer_size",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "shm_format",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "format",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_shm.format"),
+            }],
+        },
+        info::MessageInfo {
+            name: "dmabuf_device",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "device",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "dmabuf_format",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "format",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifiers",
+                    ty: info::ArgType::Array,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "stopped",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "duplicate_frame",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned ext_image_copy_capture_session_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureSessionV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_image_copy_capture_session_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtImageCopyCaptureSessionV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtImageCopyCaptureSessionV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtImageCopyCaptureSessionV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtImageCopyCaptureSessionV1 {
+    const INTERFACE: &'static str = "ext_image_copy_capture_session_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtImageCopyCaptureSessionV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtImageCopyCaptureSessionV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtImageCopyCaptureSessionV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtImageCopyCaptureSessionV1Ref {
+    type Owned = ExtImageCopyCaptureSessionV1;
+}
+
+impl Deref for ExtImageCopyCaptureSessionV1 {
+    type Target = ExtImageCopyCaptureSessionV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtImageCopyCaptureSessionV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_image_copy_capture_session_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtImageCopyCaptureSessionV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_image_copy_capture_session_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureSessionV1Ref> for ExtImageCopyCaptureSessionV1 {
+    fn eq(&self, other: &ExtImageCopyCaptureSessionV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtImageCopyCaptureSessionV1> for ExtImageCopyCaptureSessionV1Ref {
+    fn eq(&self, other: &ExtImageCopyCaptureSessionV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureSessionV1 {
+    /// Since when the create_frame request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_FRAME__SINCE: u32 = 1;
+
+    /// create a frame
+    ///
+    /// Create a capture frame for this session.
+    ///
+    /// At most one frame object can exist for a given session at any time. If
+    /// a client sends a create_frame request before a previous frame object
+    /// has been destroyed, the duplicate_frame protocol error is raised.
+    #[inline]
+    pub fn create_frame(&self) -> ExtImageCopyCaptureFrameV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ExtImageCopyCaptureFrameV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureFrameV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// delete this object
+    ///
+    /// Destroys the session. This request can be sent at any time by the
+    /// client.
+    ///
+    /// This request doesn't affect ext_image_copy_capture_frame_v1 objects created by
+    /// this object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtImageCopyCaptureSessionV1Ref {
+    /// create a frame
+    ///
+    /// Create a capture frame for this session.
+    ///
+    /// At most one frame object can exist for a given session at any time. If
+    /// a client sends a create_frame request before a previous frame object
+    /// has been destroyed, the duplicate_frame protocol error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_frame(&self, _queue: &Queue) -> ExtImageCopyCaptureFrameV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ExtImageCopyCaptureFrameV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtImageCopyCaptureFrameV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ExtImageCopyCaptureSessionV1 {
+    /// Since when the buffer_size event is available.
+    #[allow(dead_code)]
+    pub const EVT__BUFFER_SIZE__SINCE: u32 = 1;
+
+    /// Since when the shm_format event is available.
+    #[allow(dead_code)]
+    pub const EVT__SHM_FORMAT__SINCE: u32 = 1;
+
+    /// Since when the dmabuf_device event is available.
+    #[allow(dead_code)]
+    pub const EVT__DMABUF_DEVICE__SINCE: u32 = 1;
+
+    /// Since when the dmabuf_format event is available.
+    #[allow(dead_code)]
+    pub const EVT__DMABUF_FORMAT__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the stopped event is available.
+    #[allow(dead_code)]
+    pub const EVT__STOPPED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtImageCopyCaptureSessionV1] proxies.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureSessionV1EventHandler {
+    /// image capture source dimensions
+    ///
+    /// Provides the dimensions of the source image in buffer pixel coordinates.
+    ///
+    /// The client must attach buffers that match this size.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: buffer width
+    /// - `height`: buffer height
+    #[inline]
+    fn buffer_size(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, width: u32, height: u32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// shm buffer format
+    ///
+    /// Provides the format that must be used for shared-memory buffers.
+    ///
+    /// This event may be emitted multiple times, in which case the client may
+    /// choose any given format.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: shm format
+    #[inline]
+    fn shm_format(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: WlShmFormat) {
+        let _ = format;
+    }
+
+    /// dma-buf device
+    ///
+    /// This event advertises the device buffers must be allocated on for
+    /// dma-buf buffers.
+    ///
+    /// In general the device is a DRM node. The DRM node type (primary vs.
+    /// render) is unspecified. Clients must not rely on the compositor sending
+    /// a particular node type. Clients cannot check two devices for equality
+    /// by comparing the dev_t value.
+    ///
+    /// # Arguments
+    ///
+    /// - `device`: device dev_t value
+    #[inline]
+    fn dmabuf_device(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// dma-buf format
+    ///
+    /// Provides the format that must be used for dma-buf buffers.
+    ///
+    /// The client may choose any of the modifiers advertised in the array of
+    /// 64-bit unsigned integers.
+    ///
+    /// This event may be emitted multiple times, in which case the client may
+    /// choose any given format.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: drm format code
+    /// - `modifiers`: drm format modifiers
+    #[inline]
+    fn dmabuf_format(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: u32, modifiers: &[u8]) {
+        let _ = format;
+        let _ = modifiers;
+    }
+
+    /// all constraints have been sent
+    ///
+    /// This event is sent once when all buffer constraint events have been
+    /// sent.
+    ///
+    /// The compositor must always end a batch of buffer constraint events with
+    /// this event, regardless of whether it sends the initial constraints or
+    /// an update.
+    #[inline]
+    fn done(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+
+    /// session is no longer available
+    ///
+    /// This event indicates that the capture session has stopped and is no
+    /// longer available. This can happen in a number of cases, e.g. when the
+    /// underlying source is destroyed, if the user decides to end the image
+    /// capture, or if an unrecoverable runtime error has occurred.
+    ///
+    /// The client should destroy the session after receiving this event.
+    #[inline]
+    fn stopped(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+}
+
+impl ExtImageCopyCaptureSessionV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtImageCopyCaptureSessionV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureSessionV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureSessionV1EventHandlerWithData<T> {
+    /// image capture source dimensions
+    ///
+    /// Provides the dimensions of the source image in buffer pixel coordinates.
+    ///
+    /// The client must attach buffers that match this size.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: buffer width
+    /// - `height`: buffer height
+    #[inline]
+    fn buffer_size(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        width: u32,
+        height: u32,
+    ) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// shm buffer format
+    ///
+    /// Provides the format that must be used for shared-memory buffers.
+    ///
+    /// This event may be emitted multiple times, in which case the client may
+    /// choose any given format.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: shm format
+    #[inline]
+    fn shm_format(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: WlShmFormat,
+    ) {
+        let _ = format;
+    }
+
+    /// dma-buf device
+    ///
+    /// This event advertises the device buffers must be allocated on for
+    /// dma-buf buffers.
+    ///
+    /// In general the device is a DRM node. The DRM node type (primary vs.
+    /// render) is unspecified. Clients must not rely on the compositor sending
+    /// a particular node type. Clients cannot check two devices for equality
+    /// by comparing the dev_t value.
+    ///
+    /// # Arguments
+    ///
+    /// - `device`: device dev_t value
+    #[inline]
+    fn dmabuf_device(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// dma-buf format
+    ///
+    /// Provides the format that must be used for dma-buf buffers.
+    ///
+    /// The client may choose any of the modifiers advertised in the array of
+    /// 64-bit unsigned integers.
+    ///
+    /// This event may be emitted multiple times, in which case the client may
+    /// choose any given format.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: drm format code
+    /// - `modifiers`: drm format modifiers
+    #[inline]
+    fn dmabuf_format(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: u32,
+        modifiers: &[u8],
+    ) {
+        let _ = format;
+        let _ = modifiers;
+    }
+
+    /// all constraints have been sent
+    ///
+    /// This event is sent once when all buffer constraint events have been
+    /// sent.
+    ///
+    /// The compositor must always end a batch of buffer constraint events with
+    /// this event, regardless of whether it sends the initial constraints or
+    /// an update.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+
+    /// session is no longer available
+    ///
+    /// This event indicates that the capture session has stopped and is no
+    /// longer available. This can happen in a number of cases, e.g. when the
+    /// underlying source is destroyed, if the user decides to end the image
+    /// capture, or if an unrecoverable runtime error has occurred.
+    ///
+    /// The client should destroy the session after receiving this event.
+    #[inline]
+    fn stopped(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+}
+
+/// A mutable event handler for [ExtImageCopyCaptureSessionV1] proxies.
+///
+/// This trait is the same as [ExtImageCopyCaptureSessionV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureSessionV1EventHandlerMut {
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::buffer_size].
+    #[inline]
+    fn buffer_size(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref, width: u32, height: u32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::shm_format].
+    #[inline]
+    fn shm_format(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: WlShmFormat) {
+        let _ = format;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::dmabuf_device].
+    #[inline]
+    fn dmabuf_device(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::dmabuf_format].
+    #[inline]
+    fn dmabuf_format(
+        &mut self,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: u32,
+        modifiers: &[u8],
+    ) {
+        let _ = format;
+        let _ = modifiers;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::stopped].
+    #[inline]
+    fn stopped(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+}
+
+/// A mutable event handler for [ExtImageCopyCaptureSessionV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtImageCopyCaptureSessionV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> {
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::buffer_size].
+    #[inline]
+    fn buffer_size(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        width: u32,
+        height: u32,
+    ) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::shm_format].
+    #[inline]
+    fn shm_format(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: WlShmFormat,
+    ) {
+        let _ = format;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::dmabuf_device].
+    #[inline]
+    fn dmabuf_device(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        device: &[u8],
+    ) {
+        let _ = device;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::dmabuf_format].
+    #[inline]
+    fn dmabuf_format(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: u32,
+        modifiers: &[u8],
+    ) {
+        let _ = format;
+        let _ = modifiers;
+    }
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+
+    /// See [ExtImageCopyCaptureSessionV1EventHandler::stopped].
+    #[inline]
+    fn stopped(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {}
+}
+
+impl<H> ExtImageCopyCaptureSessionV1EventHandler for MutEventHandler<H>
+where
+    H: ExtImageCopyCaptureSessionV1EventHandlerMut,
+{
+    #[inline]
+    fn buffer_size(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, width: u32, height: u32) {
+        self.with(|h| h.buffer_size(_slf, width, height))
+    }
+
+    #[inline]
+    fn shm_format(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: WlShmFormat) {
+        self.with(|h| h.shm_format(_slf, format))
+    }
+
+    #[inline]
+    fn dmabuf_device(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+        self.with(|h| h.dmabuf_device(_slf, device))
+    }
+
+    #[inline]
+    fn dmabuf_format(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: u32, modifiers: &[u8]) {
+        self.with(|h| h.dmabuf_format(_slf, format, modifiers))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn stopped(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        self.with(|h| h.stopped(_slf))
+    }
+}
+
+impl<T, H> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn buffer_size(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        width: u32,
+        height: u32,
+    ) {
+        self.with(|h| h.buffer_size(_data, _slf, width, height))
+    }
+
+    #[inline]
+    fn shm_format(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: WlShmFormat,
+    ) {
+        self.with(|h| h.shm_format(_data, _slf, format))
+    }
+
+    #[inline]
+    fn dmabuf_device(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+        self.with(|h| h.dmabuf_device(_data, _slf, device))
+    }
+
+    #[inline]
+    fn dmabuf_format(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: u32,
+        modifiers: &[u8],
+    ) {
+        self.with(|h| h.dmabuf_format(_data, _slf, format, modifiers))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn stopped(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        self.with(|h| h.stopped(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureSessionV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn buffer_size(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        width: u32,
+        height: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().buffer_size(_data, _slf, width, height)
+    }
+
+    #[inline]
+    fn shm_format(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: WlShmFormat,
+    ) {
+        let _data = self.project(_data);
+        self.handler().shm_format(_data, _slf, format)
+    }
+
+    #[inline]
+    fn dmabuf_device(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().dmabuf_device(_data, _slf, device)
+    }
+
+    #[inline]
+    fn dmabuf_format(
+        &self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: u32,
+        modifiers: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler().dmabuf_format(_data, _slf, format, modifiers)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn stopped(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler().stopped(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ExtImageCopyCaptureSessionV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn buffer_size(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        width: u32,
+        height: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().buffer_size(_data, _slf, width, height)
+    }
+
+    #[inline]
+    fn shm_format(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: WlShmFormat,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().shm_format(_data, _slf, format)
+    }
+
+    #[inline]
+    fn dmabuf_device(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        device: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().dmabuf_device(_data, _slf, device)
+    }
+
+    #[inline]
+    fn dmabuf_format(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtImageCopyCaptureSessionV1Ref,
+        format: u32,
+        modifiers: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .dmabuf_format(_data, _slf, format, modifiers)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn stopped(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().stopped(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtImageCopyCaptureSessionV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtImageCopyCaptureSessionV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.buffer_size(slf, arg0, arg1);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlShmFormat(args[0].u) };
+                self.0.shm_format(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.dmabuf_device(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an array
+                let arg1 = unsafe {
+                    let a = &*args[1].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.dmabuf_format(slf, arg0, arg1);
+            }
+            4 => {
+                self.0.done(slf);
+            }
+            5 => {
+                self.0.stopped(slf);
+            }
+            _ => {
+                invalid_opcode("ext_image_copy_capture_session_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ExtImageCopyCaptureSessionV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.buffer_size(data, slf, arg0, arg1);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlShmFormat(args[0].u) };
+                self.0.shm_format(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.dmabuf_device(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an array
+                let arg1 = unsafe {
+                    let a = &*args[1].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.dmabuf_format(data, slf, arg0, arg1);
+            }
+            4 => {
+                self.0.done(data, slf);
+            }
+            5 => {
+                self.0.stopped(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_image_copy_capture_session_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureSessionV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtImageCopyCaptureSessionV1 {
+    /// Since when the error.duplicate_frame enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DUPLICATE_FRAME__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtImageCopyCaptureSessionV1Error(pub u32);
+
+impl ExtImageCopyCaptureSessionV1Error {
+    /// create_frame sent before destroying previous frame
+    #[allow(dead_code)]
+    pub const DUPLICATE_FRAME: Self = Self(1);
+}
+
+impl Debug for ExtImageCopyCaptureSessionV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::DUPLICATE_FRAME => "DUPLICATE_FRAME",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for buffer_size events.
+    pub struct BufferSize<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandler for BufferSize<F>
+    where
+        F: Fn(&ExtImageCopyCaptureSessionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn buffer_size(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, width: u32, height: u32) {
+            self.0(_slf, width, height)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for BufferSize<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn buffer_size(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            width: u32,
+            height: u32,
+        ) {
+            self.0(_data, _slf, width, height)
+        }
+    }
+
+    /// Event handler for shm_format events.
+    pub struct ShmFormat<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandler for ShmFormat<F>
+    where
+        F: Fn(&ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+    {
+        #[inline]
+        fn shm_format(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: WlShmFormat) {
+            self.0(_slf, format)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for ShmFormat<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+    {
+        #[inline]
+        fn shm_format(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: WlShmFormat,
+        ) {
+            self.0(_data, _slf, format)
+        }
+    }
+
+    /// Event handler for dmabuf_device events.
+    pub struct DmabufDevice<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandler for DmabufDevice<F>
+    where
+        F: Fn(&ExtImageCopyCaptureSessionV1Ref, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_device(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+            self.0(_slf, device)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for DmabufDevice<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_device(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            device: &[u8],
+        ) {
+            self.0(_data, _slf, device)
+        }
+    }
+
+    /// Event handler for dmabuf_format events.
+    pub struct DmabufFormat<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandler for DmabufFormat<F>
+    where
+        F: Fn(&ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_format(
+            &self,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: u32,
+            modifiers: &[u8],
+        ) {
+            self.0(_slf, format, modifiers)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for DmabufFormat<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_format(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: u32,
+            modifiers: &[u8],
+        ) {
+            self.0(_data, _slf, format, modifiers)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandler for Done<F>
+    where
+        F: Fn(&ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for stopped events.
+    pub struct Stopped<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandler for Stopped<F>
+    where
+        F: Fn(&ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn stopped(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for Stopped<F>
+    where
+        F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn stopped(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for buffer_size events.
+    pub struct BufferSizeMut<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandlerMut for BufferSizeMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureSessionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn buffer_size(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref, width: u32, height: u32) {
+            self.0(_slf, width, height)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> for BufferSizeMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, u32),
+    {
+        #[inline]
+        fn buffer_size(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            width: u32,
+            height: u32,
+        ) {
+            self.0(_data, _slf, width, height)
+        }
+    }
+
+    /// Mutable event handler for shm_format events.
+    pub struct ShmFormatMut<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandlerMut for ShmFormatMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+    {
+        #[inline]
+        fn shm_format(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: WlShmFormat) {
+            self.0(_slf, format)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> for ShmFormatMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+    {
+        #[inline]
+        fn shm_format(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: WlShmFormat,
+        ) {
+            self.0(_data, _slf, format)
+        }
+    }
+
+    /// Mutable event handler for dmabuf_device events.
+    pub struct DmabufDeviceMut<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandlerMut for DmabufDeviceMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureSessionV1Ref, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_device(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+            self.0(_slf, device)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> for DmabufDeviceMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_device(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            device: &[u8],
+        ) {
+            self.0(_data, _slf, device)
+        }
+    }
+
+    /// Mutable event handler for dmabuf_format events.
+    pub struct DmabufFormatMut<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandlerMut for DmabufFormatMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_format(
+            &mut self,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: u32,
+            modifiers: &[u8],
+        ) {
+            self.0(_slf, format, modifiers)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> for DmabufFormatMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+    {
+        #[inline]
+        fn dmabuf_format(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: u32,
+            modifiers: &[u8],
+        ) {
+            self.0(_data, _slf, format, modifiers)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for stopped events.
+    pub struct StoppedMut<F>(F);
+    impl<F> ExtImageCopyCaptureSessionV1EventHandlerMut for StoppedMut<F>
+    where
+        F: FnMut(&ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn stopped(&mut self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtImageCopyCaptureSessionV1EventHandlerMutWithData<T> for StoppedMut<F>
+    where
+        F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+    {
+        #[inline]
+        fn stopped(&mut self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtImageCopyCaptureSessionV1 {
+        /// Creates an event handler for buffer_size events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_buffer_size<F>(f: F) -> BufferSize<F>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, u32, u32),
+        {
+            BufferSize(f)
+        }
+
+        /// Creates an event handler with mutable data for buffer_size events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_buffer_size_with_data<T, F>(f: F) -> BufferSize<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, u32),
+        {
+            BufferSize(f)
+        }
+
+        /// Creates an event handler for shm_format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_shm_format<F>(f: F) -> ShmFormat<F>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+        {
+            ShmFormat(f)
+        }
+
+        /// Creates an event handler with mutable data for shm_format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_shm_format_with_data<T, F>(f: F) -> ShmFormat<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+        {
+            ShmFormat(f)
+        }
+
+        /// Creates an event handler for dmabuf_device events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_device<F>(f: F) -> DmabufDevice<F>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, &[u8]),
+        {
+            DmabufDevice(f)
+        }
+
+        /// Creates an event handler with mutable data for dmabuf_device events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_device_with_data<T, F>(f: F) -> DmabufDevice<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, &[u8]),
+        {
+            DmabufDevice(f)
+        }
+
+        /// Creates an event handler for dmabuf_format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_format<F>(f: F) -> DmabufFormat<F>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+        {
+            DmabufFormat(f)
+        }
+
+        /// Creates an event handler with mutable data for dmabuf_format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_format_with_data<T, F>(f: F) -> DmabufFormat<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+        {
+            DmabufFormat(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for stopped events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_stopped<F>(f: F) -> Stopped<F>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref),
+        {
+            Stopped(f)
+        }
+
+        /// Creates an event handler with mutable data for stopped events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_stopped_with_data<T, F>(f: F) -> Stopped<F>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+        {
+            Stopped(f)
+        }
+
+        /// Creates a mutable event handler for buffer_size events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_buffer_size_mut<F>(f: F) -> BufferSizeMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureSessionV1Ref, u32, u32),
+        {
+            BufferSizeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for buffer_size events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_buffer_size_mut_with_data<T, F>(f: F) -> BufferSizeMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, u32),
+        {
+            BufferSizeMut(f)
+        }
+
+        /// Creates a mutable event handler for shm_format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_shm_format_mut<F>(f: F) -> ShmFormatMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+        {
+            ShmFormatMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for shm_format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_shm_format_mut_with_data<T, F>(f: F) -> ShmFormatMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+        {
+            ShmFormatMut(f)
+        }
+
+        /// Creates a mutable event handler for dmabuf_device events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dmabuf_device_mut<F>(f: F) -> DmabufDeviceMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureSessionV1Ref, &[u8]),
+        {
+            DmabufDeviceMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for dmabuf_device events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dmabuf_device_mut_with_data<T, F>(f: F) -> DmabufDeviceMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, &[u8]),
+        {
+            DmabufDeviceMut(f)
+        }
+
+        /// Creates a mutable event handler for dmabuf_format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dmabuf_format_mut<F>(f: F) -> DmabufFormatMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+        {
+            DmabufFormatMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for dmabuf_format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dmabuf_format_mut_with_data<T, F>(f: F) -> DmabufFormatMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+        {
+            DmabufFormatMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureSessionV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for stopped events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_stopped_mut<F>(f: F) -> StoppedMut<F>
+        where
+            F: FnMut(&ExtImageCopyCaptureSessionV1Ref),
+        {
+            StoppedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for stopped events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_stopped_mut_with_data<T, F>(f: F) -> StoppedMut<F>
+        where
+            F: FnMut(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+        {
+            StoppedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                buffer_size: Ignore,
+                shm_format: Ignore,
+                dmabuf_device: Ignore,
+                dmabuf_format: Ignore,
+                done: Ignore,
+                stopped: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtImageCopyCaptureSessionV1EventHandler for Ignore {}
+    impl<T> ExtImageCopyCaptureSessionV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtImageCopyCaptureSessionV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        buffer_size: H0,
+        shm_format: H1,
+        dmabuf_device: H2,
+        dmabuf_format: H3,
+        done: H4,
+        stopped: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> ExtImageCopyCaptureSessionV1EventHandler
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ExtImageCopyCaptureSessionV1EventHandler,
+        H1: ExtImageCopyCaptureSessionV1EventHandler,
+        H2: ExtImageCopyCaptureSessionV1EventHandler,
+        H3: ExtImageCopyCaptureSessionV1EventHandler,
+        H4: ExtImageCopyCaptureSessionV1EventHandler,
+        H5: ExtImageCopyCaptureSessionV1EventHandler,
+    {
+        #[inline]
+        fn buffer_size(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, width: u32, height: u32) {
+            self.buffer_size.buffer_size(_slf, width, height)
+        }
+
+        #[inline]
+        fn shm_format(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, format: WlShmFormat) {
+            self.shm_format.shm_format(_slf, format)
+        }
+
+        #[inline]
+        fn dmabuf_device(&self, _slf: &ExtImageCopyCaptureSessionV1Ref, device: &[u8]) {
+            self.dmabuf_device.dmabuf_device(_slf, device)
+        }
+
+        #[inline]
+        fn dmabuf_format(
+            &self,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: u32,
+            modifiers: &[u8],
+        ) {
+            self.dmabuf_format.dmabuf_format(_slf, format, modifiers)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn stopped(&self, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.stopped.stopped(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> ExtImageCopyCaptureSessionV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+        H1: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+        H2: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+        H3: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+        H4: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+        H5: ExtImageCopyCaptureSessionV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn buffer_size(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            width: u32,
+            height: u32,
+        ) {
+            self.buffer_size.buffer_size(_data, _slf, width, height)
+        }
+
+        #[inline]
+        fn shm_format(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: WlShmFormat,
+        ) {
+            self.shm_format.shm_format(_data, _slf, format)
+        }
+
+        #[inline]
+        fn dmabuf_device(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            device: &[u8],
+        ) {
+            self.dmabuf_device.dmabuf_device(_data, _slf, device)
+        }
+
+        #[inline]
+        fn dmabuf_format(
+            &self,
+            _data: &mut T,
+            _slf: &ExtImageCopyCaptureSessionV1Ref,
+            format: u32,
+            modifiers: &[u8],
+        ) {
+            self.dmabuf_format
+                .dmabuf_format(_data, _slf, format, modifiers)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn stopped(&self, _data: &mut T, _slf: &ExtImageCopyCaptureSessionV1Ref) {
+            self.stopped.stopped(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for buffer_size events.
+        #[allow(dead_code)]
+        pub fn on_buffer_size<F>(self, f: F) -> Handler<BufferSize<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, u32, u32),
+        {
+            Handler {
+                buffer_size: BufferSize(f),
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for buffer_size events.
+        #[allow(dead_code)]
+        pub fn on_buffer_size_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<BufferSize<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, u32),
+        {
+            Handler {
+                buffer_size: BufferSize(f),
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure for shm_format events.
+        #[allow(dead_code)]
+        pub fn on_shm_format<F>(self, f: F) -> Handler<H0, ShmFormat<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: ShmFormat(f),
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for shm_format events.
+        #[allow(dead_code)]
+        pub fn on_shm_format_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, ShmFormat<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, WlShmFormat),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: ShmFormat(f),
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure for dmabuf_device events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_device<F>(self, f: F) -> Handler<H0, H1, DmabufDevice<F>, H3, H4, H5>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, &[u8]),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: DmabufDevice(f),
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for dmabuf_device events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_device_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, DmabufDevice<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, &[u8]),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: DmabufDevice(f),
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure for dmabuf_format events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_format<F>(self, f: F) -> Handler<H0, H1, H2, DmabufFormat<F>, H4, H5>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: DmabufFormat(f),
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for dmabuf_format events.
+        #[allow(dead_code)]
+        pub fn on_dmabuf_format_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, DmabufFormat<F>, H4, H5>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref, u32, &[u8]),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: DmabufFormat(f),
+                done: self.done,
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, H2, H3, Done<F>, H5>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: Done(f),
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Done<F>, H5>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: Done(f),
+                stopped: self.stopped,
+            }
+        }
+
+        /// Sets the closure for stopped events.
+        #[allow(dead_code)]
+        pub fn on_stopped<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Stopped<F>>
+        where
+            F: Fn(&ExtImageCopyCaptureSessionV1Ref),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: Stopped(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for stopped events.
+        #[allow(dead_code)]
+        pub fn on_stopped_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Stopped<F>>
+        where
+            F: Fn(&mut T, &ExtImageCopyCaptureSessionV1Ref),
+        {
+            Handler {
+                buffer_size: self.buffer_size,
+                shm_format: self.shm_format,
+                dmabuf_device: self.dmabuf_device,
+                dmabuf_format: self.dmabuf_format,
+                done: self.done,
+                stopped: Stopped(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,860 @@
+//! a surface displayed while the session is locked
+//!
+//! The client may use lock surfaces to display a screensaver, render a
+//! dialog to enter a password and unlock the session, or however else it
+//! sees fit.
+//!
+//! On binding this interface the compositor will immediately send the
+//! first configure event. After making the ack_configure request in
+//! response to this event the client should attach and commit the first
+//! buffer. Committing the surface before acking the first configure is a
+//! protocol error. Committing the surface with a null buffer at any time
+//! is a protocol error.
+//!
+//! The compositor is free to handle keyboard/pointer focus for lock
+//! surfaces however it chooses. A reasonable way to do this would be to
+//! give the first lock surface created keyboard focus and change keyboard
+//! focus if the user clicks on other surfaces.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_session_lock_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"ack_configure".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"configure".as_ptr(),
+            signature: c"uuu".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_session_lock_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_session_lock_surface_v1",
+    protocol: "ext_session_lock_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "ack_configure",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "configure",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[
+            info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "width",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "height",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+        ],
+    }],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "commit_before_first_ack",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "null_buffer",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "dimensions_mismatch",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_serial",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned ext_session_lock_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtSessionLockSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_session_lock_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtSessionLockSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtSessionLockSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtSessionLockSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtSessionLockSurfaceV1 {
+    const INTERFACE: &'static str = "ext_session_lock_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtSessionLockSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtSessionLockSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtSessionLockSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtSessionLockSurfaceV1Ref {
+    type Owned = ExtSessionLockSurfaceV1;
+}
+
+impl Deref for ExtSessionLockSurfaceV1 {
+    type Target = ExtSessionLockSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtSessionLockSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_session_lock_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtSessionLockSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_session_lock_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtSessionLockSurfaceV1Ref> for ExtSessionLockSurfaceV1 {
+    fn eq(&self, other: &ExtSessionLockSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtSessionLockSurfaceV1> for ExtSessionLockSurfaceV1Ref {
+    fn eq(&self, other: &ExtSessionLockSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtSessionLockSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the lock surface object
+    ///
+    /// This informs the compositor that the lock surface object will no
+    /// longer be used.
+    ///
+    /// It is recommended for a lock client to destroy lock surfaces if
+    /// their corresponding wl_output global is removed.
+    ///
+    /// If a lock surface on an active output is destroyed before the
+    /// ext_session_lock_v1.unlock_and_destroy event is sent, the compositor
+    /// must fall back to rendering a solid color.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtSessionLockSurfaceV1Ref {
+    /// ack a configure event
+    ///
+    /// When a configure event is received, if a client commits the surface
+    /// in response to the configure event, then the client must make an
+    /// ack_configure request sometime before the commit request, passing
+    /// along the serial of the configure event.
+    ///
+    /// If the client receives multiple configure events before it can
+    /// respond to one, it only has to ack the last configure event.
+    ///
+    /// A client is not required to commit immediately after sending an
+    /// ack_configure request - it may even ack_configure several times
+    /// before its next surface commit.
+    ///
+    /// A client may send multiple ack_configure requests before committing,
+    /// but only the last request sent before a commit indicates which
+    /// configure event the client really is responding to.
+    ///
+    /// Sending an ack_configure request consumes the configure event
+    /// referenced by the given serial, as well as all older configure events
+    /// sent on this object.
+    ///
+    /// It is a protocol error to issue multiple ack_configure requests
+    /// referencing the same configure event or to issue an ack_configure
+    /// request referencing a configure event older than the last configure
+    /// event acked for a given lock surface.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial from the configure event
+    #[inline]
+    pub fn ack_configure(&self, serial: u32) {
+        let (arg0,) = (serial,);
+        let mut args = [wl_argument { u: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+impl ExtSessionLockSurfaceV1 {
+    /// Since when the configure event is available.
+    #[allow(dead_code)]
+    pub const EVT__CONFIGURE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtSessionLockSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait ExtSessionLockSurfaceV1EventHandler {
+    /// the client should resize its surface
+    ///
+    /// This event is sent once on binding the interface and may be sent again
+    /// at the compositor's discretion, for example if output geometry changes.
+    ///
+    /// The width and height are in surface-local coordinates and are exact
+    /// requirements. Failing to match these surface dimensions in the next
+    /// commit after acking a configure is a protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial for use in ack_configure
+    /// - `width`:
+    /// - `height`:
+    #[inline]
+    fn configure(&self, _slf: &ExtSessionLockSurfaceV1Ref, serial: u32, width: u32, height: u32) {
+        let _ = serial;
+        let _ = width;
+        let _ = height;
+    }
+}
+
+impl ExtSessionLockSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtSessionLockSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtSessionLockSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtSessionLockSurfaceV1EventHandlerWithData<T> {
+    /// the client should resize its surface
+    ///
+    /// This event is sent once on binding the interface and may be sent again
+    /// at the compositor's discretion, for example if output geometry changes.
+    ///
+    /// The width and height are in surface-local coordinates and are exact
+    /// requirements. Failing to match these surface dimensions in the next
+    /// commit after acking a configure is a protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial for use in ack_configure
+    /// - `width`:
+    /// - `height`:
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &ExtSessionLockSurfaceV1Ref,
+        serial: u32,
+        width: u32,
+        height: u32,
+    ) {
+        let _ = serial;
+        let _ = width;
+        let _ = height;
+    }
+}
+
+/// A mutable event handler for [ExtSessionLockSurfaceV1] proxies.
+///
+/// This trait is the same as [ExtSessionLockSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtSessionLockSurfaceV1EventHandlerMut {
+    /// See [ExtSessionLockSurfaceV1EventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _slf: &ExtSessionLockSurfaceV1Ref,
+        serial: u32,
+        width: u32,
+        height: u32,
+    ) {
+        let _ = serial;
+        let _ = width;
+        let _ = height;
+    }
+}
+
+/// A mutable event handler for [ExtSessionLockSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtSessionLockSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtSessionLockSurfaceV1EventHandlerMutWithData<T> {
+    /// See [ExtSessionLockSurfaceV1EventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtSessionLockSurfaceV1Ref,
+        serial: u32,
+        width: u32,
+        height: u32,
+    ) {
+        let _ = serial;
+        let _ = width;
+        let _ = height;
+    }
+}
+
+impl<H> ExtSessionLockSurfaceV1EventHandler for MutEventHandler<H>
+where
+    H: ExtSessionLockSurfaceV1EventHandlerMut,
+{
+    #[inline]
+    fn configure(&self, _slf: &ExtSessionLockSurfaceV1Ref, serial: u32, width: u32, height: u32) {
+        self.with(|h| h.configure(_slf, serial, width, height))
+    }
+}
+
+impl<T, H> ExtSessionLockSurfaceV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtSessionLockSurfaceV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &ExtSessionLockSurfaceV1Ref,
+        serial: u32,
+        width: u32,
+        height: u32,
+    ) {
+        self.with(|h| h.configure(_data, _slf, serial, width, height))
+    }
+}
+
+impl<T, U, H> ExtSessionLockSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtSessionLockSurfaceV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &ExtSessionLockSurfaceV1Ref,
+        serial: u32,
+        width: u32,
+        height: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().configure(_data, _slf, serial, width, height)
+    }
+}
+
+impl<T, U, H> ExtSessionLockSurfaceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtSessionLockSurfaceV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtSessionLockSurfaceV1Ref,
+        serial: u32,
+        width: u32,
+        height: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .configure(_data, _slf, serial, width, height)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtSessionLockSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtSessionLockSurfaceV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.configure(slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("ext_session_lock_surface_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtSessionLockSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtSessionLockSurfaceV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.configure(data, slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("ext_session_lock_surface_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtSessionLockSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtSessionLockSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtSessionLockSurfaceV1 {
+    /// Since when the error.commit_before_first_ack enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_COMMIT_BEFORE_FIRST_ACK__SINCE: u32 = 1;
+    /// Since when the error.null_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NULL_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.dimensions_mismatch enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DIMENSIONS_MISMATCH__SINCE: u32 = 1;
+    /// Since when the error.invalid_serial enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SERIAL__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtSessionLockSurfaceV1Error(pub u32);
+
+impl ExtSessionLockSurfaceV1Error {
+    /// surface committed before first ack_configure request
+    #[allow(dead_code)]
+    pub const COMMIT_BEFORE_FIRST_ACK: Self = Self(0);
+
+    /// surface committed with a null buffer
+    #[allow(dead_code)]
+    pub const NULL_BUFFER: Self = Self(1);
+
+    /// failed to match ack'd width/height
+    #[allow(dead_code)]
+    pub const DIMENSIONS_MISMATCH: Self = Self(2);
+
+    /// serial provided in ack_configure is invalid
+    #[allow(dead_code)]
+    pub const INVALID_SERIAL: Self = Self(3);
+}
+
+impl Debug for ExtSessionLockSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::COMMIT_BEFORE_FIRST_ACK => "COMMIT_BEFORE_FIRST_ACK",
+            Self::NULL_BUFFER => "NULL_BUFFER",
+            Self::DIMENSIONS_MISMATCH => "DIMENSIONS_MISMATCH",
+            Self::INVALID_SERIAL => "INVALID_SERIAL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for configure events.
+    pub struct Configure<F>(F);
+    impl<F> ExtSessionLockSurfaceV1EventHandler for Configure<F>
+    where
+        F: Fn(&ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _slf: &ExtSessionLockSurfaceV1Ref,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {
+            self.0(_slf, serial, width, height)
+        }
+    }
+    impl<T, F> ExtSessionLockSurfaceV1EventHandlerWithData<T> for Configure<F>
+    where
+        F: Fn(&mut T, &ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &ExtSessionLockSurfaceV1Ref,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {
+            self.0(_data, _slf, serial, width, height)
+        }
+    }
+
+    /// Mutable event handler for configure events.
+    pub struct ConfigureMut<F>(F);
+    impl<F> ExtSessionLockSurfaceV1EventHandlerMut for ConfigureMut<F>
+    where
+        F: FnMut(&ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _slf: &ExtSessionLockSurfaceV1Ref,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {
+            self.0(_slf, serial, width, height)
+        }
+    }
+    impl<T, F> ExtSessionLockSurfaceV1EventHandlerMutWithData<T> for ConfigureMut<F>
+    where
+        F: FnMut(&mut T, &ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtSessionLockSurfaceV1Ref,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {
+            self.0(_data, _slf, serial, width, height)
+        }
+    }
+
+    impl ExtSessionLockSurfaceV1 {
+        /// Creates an event handler for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(f: F) -> Configure<F>
+        where
+            F: Fn(&ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+        {
+            Configure(f)
+        }
+
+        /// Creates an event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(f: F) -> Configure<F>
+        where
+            F: Fn(&mut T, &ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+        {
+            Configure(f)
+        }
+
+        /// Creates a mutable event handler for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut<F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut_with_data<T, F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&mut T, &ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { configure: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtSessionLockSurfaceV1EventHandler for Ignore {}
+    impl<T> ExtSessionLockSurfaceV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtSessionLockSurfaceV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        configure: H0,
+    }
+
+    impl<H0> ExtSessionLockSurfaceV1EventHandler for Handler<H0>
+    where
+        H0: ExtSessionLockSurfaceV1EventHandler,
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _slf: &ExtSessionLockSurfaceV1Ref,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {
+            self.configure.configure(_slf, serial, width, height)
+        }
+    }
+
+    impl<T, H0> ExtSessionLockSurfaceV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ExtSessionLockSurfaceV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &ExtSessionLockSurfaceV1Ref,
+            serial: u32,
+            width: u32,
+            height: u32,
+        ) {
+            self.configure.configure(_data, _slf, serial, width, height)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(self, f: F) -> Handler<Configure<F>>
+        where
+            F: Fn(&ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+        {
+            Handler {
+                configure: Configure(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(self, f: F) -> Handler<Configure<F>>
+        where
+            F: Fn(&mut T, &ExtSessionLockSurfaceV1Ref, u32, u32, u32),
+        {
+            Handler {
+                configure: Configure(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1147 @@
+//! manage lock state and create lock surfaces
+//!
+//! In response to the creation of this object the compositor must send
+//! either the locked or finished event.
+//!
+//! The locked event indicates that the session is locked. This means
+//! that the compositor must stop rendering and providing input to normal
+//! clients. Instead the compositor must blank all outputs with an opaque
+//! color such that their normal content is fully hidden.
+//!
+//! The only surfaces that should be rendered while the session is locked
+//! are the lock surfaces created through this interface and optionally,
+//! at the compositor's discretion, special privileged surfaces such as
+//! input methods or portions of desktop shell UIs.
+//!
+//! The locked event must not be sent until a new "locked" frame (either
+//! from a session lock surface or the compositor blanking the output) has
+//! been presented on all outputs and no security sensitive normal/unlocked
+//! content is possibly visible.
+//!
+//! The finished event should be sent immediately on creation of this
+//! object if the compositor decides that the locked event will not be sent.
+//!
+//! The compositor may wait for the client to create and render session lock
+//! surfaces before sending the locked event to avoid displaying intermediate
+//! blank frames. However, it must impose a reasonable time limit if
+//! waiting and send the locked event as soon as the hard requirements
+//! described above can be met if the time limit expires. Clients should
+//! immediately create lock surfaces for all outputs on creation of this
+//! object to make this possible.
+//!
+//! This behavior of the locked event is required in order to prevent
+//! possible race conditions with clients that wish to suspend the system
+//! or similar after locking the session. Without these semantics, clients
+//! triggering a suspend after receiving the locked event would race with
+//! the first "locked" frame being presented and normal/unlocked frames
+//! might be briefly visible as the system is resumed if the suspend
+//! operation wins the race.
+//!
+//! If the client dies while the session is locked, the compositor must not
+//! unlock the session in response. It is acceptable for the session to be
+//! permanently locked if this happens. The compositor may choose to continue
+//! to display the lock surfaces the client had mapped before it died or
+//! alternatively fall back to a solid color, this is compositor policy.
+//!
+//! Compositors may also allow a secure way to recover the session, the
+//! details of this are compositor policy. Compositors may allow a new
+//! client to create a ext_session_lock_v1 object and take responsibility
+//! for unlocking the session, they may even start a new lock client
+//! instance automatically.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_session_lock_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_lock_surface".as_ptr(),
+                signature: c"noo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ExtSessionLockSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlOutput::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"unlock_and_destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"locked".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"finished".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_session_lock_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_session_lock_v1",
+    protocol: "ext_session_lock_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_lock_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("ext_session_lock_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "output",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_output"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "unlock_and_destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "locked",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "finished",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_destroy",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_unlock",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "role",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "duplicate_output",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "already_constructed",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned ext_session_lock_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtSessionLockV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_session_lock_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtSessionLockV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtSessionLockV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtSessionLockV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtSessionLockV1 {
+    const INTERFACE: &'static str = "ext_session_lock_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtSessionLockV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtSessionLockV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtSessionLockV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtSessionLockV1Ref {
+    type Owned = ExtSessionLockV1;
+}
+
+impl Deref for ExtSessionLockV1 {
+    type Target = ExtSessionLockV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtSessionLockV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_session_lock_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtSessionLockV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_session_lock_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtSessionLockV1Ref> for ExtSessionLockV1 {
+    fn eq(&self, other: &ExtSessionLockV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtSessionLockV1> for ExtSessionLockV1Ref {
+    fn eq(&self, other: &ExtSessionLockV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtSessionLockV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the session lock
+    ///
+    /// This informs the compositor that the lock object will no longer be
+    /// used. Existing objects created through this interface remain valid.
+    ///
+    /// After this request is made, lock surfaces created through this object
+    /// should be destroyed by the client as they will no longer be used by
+    /// the compositor.
+    ///
+    /// It is a protocol error to make this request if the locked event was
+    /// sent, the unlock_and_destroy request must be used instead.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_lock_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_LOCK_SURFACE__SINCE: u32 = 1;
+
+    /// create a lock surface for a given output
+    ///
+    /// The client is expected to create lock surfaces for all outputs
+    /// currently present and any new outputs as they are advertised. These
+    /// won't be displayed by the compositor unless the lock is successful
+    /// and the locked event is sent.
+    ///
+    /// Providing a wl_surface which already has a role or already has a buffer
+    /// attached or committed is a protocol error, as is attaching/committing
+    /// a buffer before the first ext_session_lock_surface_v1.configure event.
+    ///
+    /// Attempting to create more than one lock surface for a given output
+    /// is a duplicate_output protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    /// - `output`:
+    #[inline]
+    pub fn get_lock_surface(
+        &self,
+        surface: &WlSurfaceRef,
+        output: &WlOutputRef,
+    ) -> ExtSessionLockSurfaceV1 {
+        let (arg1, arg2) = (surface, output);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("output", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ExtSessionLockSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtSessionLockSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the unlock_and_destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__UNLOCK_AND_DESTROY__SINCE: u32 = 1;
+
+    /// unlock the session, destroying the object
+    ///
+    /// This request indicates that the session should be unlocked, for
+    /// example because the user has entered their password and it has been
+    /// verified by the client.
+    ///
+    /// This request also informs the compositor that the lock object will
+    /// no longer be used and should be destroyed. Existing objects created
+    /// through this interface remain valid.
+    ///
+    /// After this request is made, lock surfaces created through this object
+    /// should be destroyed by the client as they will no longer be used by
+    /// the compositor.
+    ///
+    /// It is a protocol error to make this request if the locked event has
+    /// not been sent. In that case, the lock object must be destroyed using
+    /// the destroy request.
+    ///
+    /// Note that a correct client that wishes to exit directly after unlocking
+    /// the session must use the wl_display.sync request to ensure the server
+    /// receives and processes the unlock_and_destroy request. Otherwise
+    /// there is no guarantee that the server has unlocked the session due
+    /// to the asynchronous nature of the Wayland protocol. For example,
+    /// the server might terminate the client with a protocol error before
+    /// it processes the unlock_and_destroy request.
+    #[inline]
+    pub fn unlock_and_destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(2, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtSessionLockV1Ref {
+    /// create a lock surface for a given output
+    ///
+    /// The client is expected to create lock surfaces for all outputs
+    /// currently present and any new outputs as they are advertised. These
+    /// won't be displayed by the compositor unless the lock is successful
+    /// and the locked event is sent.
+    ///
+    /// Providing a wl_surface which already has a role or already has a buffer
+    /// attached or committed is a protocol error, as is attaching/committing
+    /// a buffer before the first ext_session_lock_surface_v1.configure event.
+    ///
+    /// Attempting to create more than one lock surface for a given output
+    /// is a duplicate_output protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    /// - `output`:
+    #[inline]
+    pub fn get_lock_surface(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+        output: &WlOutputRef,
+    ) -> ExtSessionLockSurfaceV1 {
+        let (arg1, arg2) = (surface, output);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("output", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ExtSessionLockSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtSessionLockSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ExtSessionLockV1 {
+    /// Since when the locked event is available.
+    #[allow(dead_code)]
+    pub const EVT__LOCKED__SINCE: u32 = 1;
+
+    /// Since when the finished event is available.
+    #[allow(dead_code)]
+    pub const EVT__FINISHED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtSessionLockV1] proxies.
+#[allow(dead_code)]
+pub trait ExtSessionLockV1EventHandler {
+    /// session successfully locked
+    ///
+    /// This client is now responsible for displaying graphics while the
+    /// session is locked and deciding when to unlock the session.
+    ///
+    /// The locked event must not be sent until a new "locked" frame has been
+    /// presented on all outputs and no security sensitive normal/unlocked
+    /// content is possibly visible.
+    ///
+    /// If this event is sent, making the destroy request is a protocol error,
+    /// the lock object must be destroyed using the unlock_and_destroy request.
+    #[inline]
+    fn locked(&self, _slf: &ExtSessionLockV1Ref) {}
+
+    /// the session lock object should be destroyed
+    ///
+    /// The compositor has decided that the session lock should be destroyed
+    /// as it will no longer be used by the compositor. Exactly when this
+    /// event is sent is compositor policy, but it must never be sent more
+    /// than once for a given session lock object.
+    ///
+    /// This might be sent because there is already another ext_session_lock_v1
+    /// object held by a client, or the compositor has decided to deny the
+    /// request to lock the session for some other reason. This might also
+    /// be sent because the compositor implements some alternative, secure
+    /// way to authenticate and unlock the session.
+    ///
+    /// The finished event should be sent immediately on creation of this
+    /// object if the compositor decides that the locked event will not
+    /// be sent.
+    ///
+    /// If the locked event is sent on creation of this object the finished
+    /// event may still be sent at some later time in this object's
+    /// lifetime. This is compositor policy.
+    ///
+    /// Upon receiving this event, the client should make either the destroy
+    /// request or the unlock_and_destroy request, depending on whether or
+    /// not the locked event was received on this object.
+    #[inline]
+    fn finished(&self, _slf: &ExtSessionLockV1Ref) {}
+}
+
+impl ExtSessionLockV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtSessionLockV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtSessionLockV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtSessionLockV1EventHandlerWithData<T> {
+    /// session successfully locked
+    ///
+    /// This client is now responsible for displaying graphics while the
+    /// session is locked and deciding when to unlock the session.
+    ///
+    /// The locked event must not be sent until a new "locked" frame has been
+    /// presented on all outputs and no security sensitive normal/unlocked
+    /// content is possibly visible.
+    ///
+    /// If this event is sent, making the destroy request is a protocol error,
+    /// the lock object must be destroyed using the unlock_and_destroy request.
+    #[inline]
+    fn locked(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {}
+
+    /// the session lock object should be destroyed
+    ///
+    /// The compositor has decided that the session lock should be destroyed
+    /// as it will no longer be used by the compositor. Exactly when this
+    /// event is sent is compositor policy, but it must never be sent more
+    /// than once for a given session lock object.
+    ///
+    /// This might be sent because there is already another ext_session_lock_v1
+    /// object held by a client, or the compositor has decided to deny the
+    /// request to lock the session for some other reason. This might also
+    /// be sent because the compositor implements some alternative, secure
+    /// way to authenticate and unlock the session.
+    ///
+    /// The finished event should be sent immediately on creation of this
+    /// object if the compositor decides that the locked event will not
+    /// be sent.
+    ///
+    /// If the locked event is sent on creation of this object the finished
+    /// event may still be sent at some later time in this object's
+    /// lifetime. This is compositor policy.
+    ///
+    /// Upon receiving this event, the client should make either the destroy
+    /// request or the unlock_and_destroy request, depending on whether or
+    /// not the locked event was received on this object.
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {}
+}
+
+/// A mutable event handler for [ExtSessionLockV1] proxies.
+///
+/// This trait is the same as [ExtSessionLockV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtSessionLockV1EventHandlerMut {
+    /// See [ExtSessionLockV1EventHandler::locked].
+    #[inline]
+    fn locked(&mut self, _slf: &ExtSessionLockV1Ref) {}
+
+    /// See [ExtSessionLockV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _slf: &ExtSessionLockV1Ref) {}
+}
+
+/// A mutable event handler for [ExtSessionLockV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtSessionLockV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtSessionLockV1EventHandlerMutWithData<T> {
+    /// See [ExtSessionLockV1EventHandler::locked].
+    #[inline]
+    fn locked(&mut self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {}
+
+    /// See [ExtSessionLockV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {}
+}
+
+impl<H> ExtSessionLockV1EventHandler for MutEventHandler<H>
+where
+    H: ExtSessionLockV1EventHandlerMut,
+{
+    #[inline]
+    fn locked(&self, _slf: &ExtSessionLockV1Ref) {
+        self.with(|h| h.locked(_slf))
+    }
+
+    #[inline]
+    fn finished(&self, _slf: &ExtSessionLockV1Ref) {
+        self.with(|h| h.finished(_slf))
+    }
+}
+
+impl<T, H> ExtSessionLockV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtSessionLockV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn locked(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+        self.with(|h| h.locked(_data, _slf))
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+        self.with(|h| h.finished(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtSessionLockV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtSessionLockV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn locked(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+        let _data = self.project(_data);
+        self.handler().locked(_data, _slf)
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+        let _data = self.project(_data);
+        self.handler().finished(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtSessionLockV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtSessionLockV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn locked(&mut self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().locked(_data, _slf)
+    }
+
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().finished(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtSessionLockV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ExtSessionLockV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.locked(slf);
+            }
+            1 => {
+                self.0.finished(slf);
+            }
+            _ => {
+                invalid_opcode("ext_session_lock_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtSessionLockV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ExtSessionLockV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.locked(data, slf);
+            }
+            1 => {
+                self.0.finished(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_session_lock_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtSessionLockV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtSessionLockV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtSessionLockV1 {
+    /// Since when the error.invalid_destroy enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_DESTROY__SINCE: u32 = 1;
+    /// Since when the error.invalid_unlock enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_UNLOCK__SINCE: u32 = 1;
+    /// Since when the error.role enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
+    /// Since when the error.duplicate_output enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DUPLICATE_OUTPUT__SINCE: u32 = 1;
+    /// Since when the error.already_constructed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_CONSTRUCTED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ExtSessionLockV1Error(pub u32);
+
+impl ExtSessionLockV1Error {
+    /// attempted to destroy session lock while locked
+    #[allow(dead_code)]
+    pub const INVALID_DESTROY: Self = Self(0);
+
+    /// unlock requested but locked event was never sent
+    #[allow(dead_code)]
+    pub const INVALID_UNLOCK: Self = Self(1);
+
+    /// given wl_surface already has a role
+    #[allow(dead_code)]
+    pub const ROLE: Self = Self(2);
+
+    /// given output already has a lock surface
+    #[allow(dead_code)]
+    pub const DUPLICATE_OUTPUT: Self = Self(3);
+
+    /// given wl_surface has a buffer attached or committed
+    #[allow(dead_code)]
+    pub const ALREADY_CONSTRUCTED: Self = Self(4);
+}
+
+impl Debug for ExtSessionLockV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_DESTROY => "INVALID_DESTROY",
+            Self::INVALID_UNLOCK => "INVALID_UNLOCK",
+            Self::ROLE => "ROLE",
+            Self::DUPLICATE_OUTPUT => "DUPLICATE_OUTPUT",
+            Self::ALREADY_CONSTRUCTED => "ALREADY_CONSTRUCTED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for locked events.
+    pub struct Locked<F>(F);
+    impl<F> ExtSessionLockV1EventHandler for Locked<F>
+    where
+        F: Fn(&ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn locked(&self, _slf: &ExtSessionLockV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtSessionLockV1EventHandlerWithData<T> for Locked<F>
+    where
+        F: Fn(&mut T, &ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn locked(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for finished events.
+    pub struct Finished<F>(F);
+    impl<F> ExtSessionLockV1EventHandler for Finished<F>
+    where
+        F: Fn(&ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _slf: &ExtSessionLockV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtSessionLockV1EventHandlerWithData<T> for Finished<F>
+    where
+        F: Fn(&mut T, &ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for locked events.
+    pub struct LockedMut<F>(F);
+    impl<F> ExtSessionLockV1EventHandlerMut for LockedMut<F>
+    where
+        F: FnMut(&ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn locked(&mut self, _slf: &ExtSessionLockV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtSessionLockV1EventHandlerMutWithData<T> for LockedMut<F>
+    where
+        F: FnMut(&mut T, &ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn locked(&mut self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for finished events.
+    pub struct FinishedMut<F>(F);
+    impl<F> ExtSessionLockV1EventHandlerMut for FinishedMut<F>
+    where
+        F: FnMut(&ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _slf: &ExtSessionLockV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtSessionLockV1EventHandlerMutWithData<T> for FinishedMut<F>
+    where
+        F: FnMut(&mut T, &ExtSessionLockV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtSessionLockV1 {
+        /// Creates an event handler for locked events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_locked<F>(f: F) -> Locked<F>
+        where
+            F: Fn(&ExtSessionLockV1Ref),
+        {
+            Locked(f)
+        }
+
+        /// Creates an event handler with mutable data for locked events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_locked_with_data<T, F>(f: F) -> Locked<F>
+        where
+            F: Fn(&mut T, &ExtSessionLockV1Ref),
+        {
+            Locked(f)
+        }
+
+        /// Creates an event handler for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(f: F) -> Finished<F>
+        where
+            F: Fn(&ExtSessionLockV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates an event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(f: F) -> Finished<F>
+        where
+            F: Fn(&mut T, &ExtSessionLockV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates a mutable event handler for locked events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_locked_mut<F>(f: F) -> LockedMut<F>
+        where
+            F: FnMut(&ExtSessionLockV1Ref),
+        {
+            LockedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for locked events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_locked_mut_with_data<T, F>(f: F) -> LockedMut<F>
+        where
+            F: FnMut(&mut T, &ExtSessionLockV1Ref),
+        {
+            LockedMut(f)
+        }
+
+        /// Creates a mutable event handler for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut<F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&ExtSessionLockV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut_with_data<T, F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&mut T, &ExtSessionLockV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                locked: Ignore,
+                finished: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtSessionLockV1EventHandler for Ignore {}
+    impl<T> ExtSessionLockV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtSessionLockV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        locked: H0,
+        finished: H1,
+    }
+
+    impl<H0, H1> ExtSessionLockV1EventHandler for Handler<H0, H1>
+    where
+        H0: ExtSessionLockV1EventHandler,
+        H1: ExtSessionLockV1EventHandler,
+    {
+        #[inline]
+        fn locked(&self, _slf: &ExtSessionLockV1Ref) {
+            self.locked.locked(_slf)
+        }
+
+        #[inline]
+        fn finished(&self, _slf: &ExtSessionLockV1Ref) {
+            self.finished.finished(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ExtSessionLockV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ExtSessionLockV1EventHandlerWithData<T>,
+        H1: ExtSessionLockV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn locked(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+            self.locked.locked(_data, _slf)
+        }
+
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtSessionLockV1Ref) {
+            self.finished.finished(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for locked events.
+        #[allow(dead_code)]
+        pub fn on_locked<F>(self, f: F) -> Handler<Locked<F>, H1>
+        where
+            F: Fn(&ExtSessionLockV1Ref),
+        {
+            Handler {
+                locked: Locked(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for locked events.
+        #[allow(dead_code)]
+        pub fn on_locked_with_data<T, F>(self, f: F) -> Handler<Locked<F>, H1>
+        where
+            F: Fn(&mut T, &ExtSessionLockV1Ref),
+        {
+            Handler {
+                locked: Locked(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(self, f: F) -> Handler<H0, Finished<F>>
+        where
+            F: Fn(&ExtSessionLockV1Ref),
+        {
+            Handler {
+                locked: self.locked,
+                finished: Finished(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(self, f: F) -> Handler<H0, Finished<F>>
+        where
+            F: Fn(&mut T, &ExtSessionLockV1Ref),
+        {
+            Handler {
+                locked: self.locked,
+                finished: Finished(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,378 @@
+//! transient seat manager
+//!
+//! The transient seat manager creates short-lived seats.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_transient_seat_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"create".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtTransientSeatV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the ext_transient_seat_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_transient_seat_manager_v1",
+    protocol: "ext_transient_seat_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "seat",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_transient_seat_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned ext_transient_seat_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtTransientSeatManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_transient_seat_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtTransientSeatManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtTransientSeatManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtTransientSeatManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtTransientSeatManagerV1 {
+    const INTERFACE: &'static str = "ext_transient_seat_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtTransientSeatManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtTransientSeatManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtTransientSeatManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtTransientSeatManagerV1Ref {
+    type Owned = ExtTransientSeatManagerV1;
+}
+
+impl Deref for ExtTransientSeatManagerV1 {
+    type Target = ExtTransientSeatManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtTransientSeatManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_transient_seat_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtTransientSeatManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_transient_seat_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtTransientSeatManagerV1Ref> for ExtTransientSeatManagerV1 {
+    fn eq(&self, other: &ExtTransientSeatManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtTransientSeatManagerV1> for ExtTransientSeatManagerV1Ref {
+    fn eq(&self, other: &ExtTransientSeatManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtTransientSeatManagerV1 {
+    /// Since when the create request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE__SINCE: u32 = 1;
+
+    /// create a transient seat
+    ///
+    /// Create a new seat that is removed when the client side transient seat
+    /// object is destroyed.
+    ///
+    /// The actual seat may be removed sooner, in which case the transient seat
+    /// object shall become inert.
+    #[inline]
+    pub fn create(&self) -> ExtTransientSeatV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ExtTransientSeatV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtTransientSeatV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager
+    ///
+    /// Destroy the manager.
+    ///
+    /// All objects created by the manager will remain valid until they are
+    /// destroyed themselves.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtTransientSeatManagerV1Ref {
+    /// create a transient seat
+    ///
+    /// Create a new seat that is removed when the client side transient seat
+    /// object is destroyed.
+    ///
+    /// The actual seat may be removed sooner, in which case the transient seat
+    /// object shall become inert.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create(&self, _queue: &Queue) -> ExtTransientSeatV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ExtTransientSeatV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ExtTransientSeatV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ExtTransientSeatManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ExtTransientSeatManagerV1EventHandler {}
+
+impl ExtTransientSeatManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtTransientSeatManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtTransientSeatManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtTransientSeatManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ExtTransientSeatManagerV1] proxies.
+///
+/// This trait is the same as [ExtTransientSeatManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtTransientSeatManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ExtTransientSeatManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtTransientSeatManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtTransientSeatManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ExtTransientSeatManagerV1EventHandler for MutEventHandler<H> where
+    H: ExtTransientSeatManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ExtTransientSeatManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ExtTransientSeatManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ExtTransientSeatManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtTransientSeatManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ExtTransientSeatManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ExtTransientSeatManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtTransientSeatManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_transient_seat_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtTransientSeatManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("ext_transient_seat_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtTransientSeatManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtTransientSeatManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ExtTransientSeatManagerV1 {}
+}
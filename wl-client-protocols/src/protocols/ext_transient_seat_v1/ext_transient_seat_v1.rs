@@ -0,0 +1,784 @@
+//! transient seat handle
+//!
+//! When the transient seat handle is destroyed, the seat itself will also be
+//! destroyed.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_transient_seat_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"ready".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"denied".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_transient_seat_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_transient_seat_v1",
+    protocol: "ext_transient_seat_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "ready",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "global_name",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "denied",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned ext_transient_seat_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtTransientSeatV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_transient_seat_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtTransientSeatV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtTransientSeatV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtTransientSeatV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtTransientSeatV1 {
+    const INTERFACE: &'static str = "ext_transient_seat_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtTransientSeatV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtTransientSeatV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtTransientSeatV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtTransientSeatV1Ref {
+    type Owned = ExtTransientSeatV1;
+}
+
+impl Deref for ExtTransientSeatV1 {
+    type Target = ExtTransientSeatV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtTransientSeatV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_transient_seat_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtTransientSeatV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_transient_seat_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtTransientSeatV1Ref> for ExtTransientSeatV1 {
+    fn eq(&self, other: &ExtTransientSeatV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtTransientSeatV1> for ExtTransientSeatV1Ref {
+    fn eq(&self, other: &ExtTransientSeatV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtTransientSeatV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy transient seat
+    ///
+    /// When the transient seat object is destroyed by the client, the
+    /// associated seat created by the compositor is also destroyed.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ExtTransientSeatV1 {
+    /// Since when the ready event is available.
+    #[allow(dead_code)]
+    pub const EVT__READY__SINCE: u32 = 1;
+
+    /// Since when the denied event is available.
+    #[allow(dead_code)]
+    pub const EVT__DENIED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtTransientSeatV1] proxies.
+#[allow(dead_code)]
+pub trait ExtTransientSeatV1EventHandler {
+    /// transient seat is ready
+    ///
+    /// This event advertises the global name for the wl_seat to be used with
+    /// wl_registry_bind.
+    ///
+    /// It is sent exactly once, immediately after the transient seat is created
+    /// and the new "wl_seat" global is advertised, if and only if the creation
+    /// of the transient seat was allowed.
+    ///
+    /// # Arguments
+    ///
+    /// - `global_name`:
+    #[inline]
+    fn ready(&self, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        let _ = global_name;
+    }
+
+    /// transient seat creation denied
+    ///
+    /// The event informs the client that the compositor denied its request to
+    /// create a transient seat.
+    ///
+    /// It is sent exactly once, immediately after the transient seat object is
+    /// created, if and only if the creation of the transient seat was denied.
+    ///
+    /// After receiving this event, the client should destroy the object.
+    #[inline]
+    fn denied(&self, _slf: &ExtTransientSeatV1Ref) {}
+}
+
+impl ExtTransientSeatV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtTransientSeatV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtTransientSeatV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtTransientSeatV1EventHandlerWithData<T> {
+    /// transient seat is ready
+    ///
+    /// This event advertises the global name for the wl_seat to be used with
+    /// wl_registry_bind.
+    ///
+    /// It is sent exactly once, immediately after the transient seat is created
+    /// and the new "wl_seat" global is advertised, if and only if the creation
+    /// of the transient seat was allowed.
+    ///
+    /// # Arguments
+    ///
+    /// - `global_name`:
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        let _ = global_name;
+    }
+
+    /// transient seat creation denied
+    ///
+    /// The event informs the client that the compositor denied its request to
+    /// create a transient seat.
+    ///
+    /// It is sent exactly once, immediately after the transient seat object is
+    /// created, if and only if the creation of the transient seat was denied.
+    ///
+    /// After receiving this event, the client should destroy the object.
+    #[inline]
+    fn denied(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {}
+}
+
+/// A mutable event handler for [ExtTransientSeatV1] proxies.
+///
+/// This trait is the same as [ExtTransientSeatV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtTransientSeatV1EventHandlerMut {
+    /// See [ExtTransientSeatV1EventHandler::ready].
+    #[inline]
+    fn ready(&mut self, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        let _ = global_name;
+    }
+
+    /// See [ExtTransientSeatV1EventHandler::denied].
+    #[inline]
+    fn denied(&mut self, _slf: &ExtTransientSeatV1Ref) {}
+}
+
+/// A mutable event handler for [ExtTransientSeatV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtTransientSeatV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtTransientSeatV1EventHandlerMutWithData<T> {
+    /// See [ExtTransientSeatV1EventHandler::ready].
+    #[inline]
+    fn ready(&mut self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        let _ = global_name;
+    }
+
+    /// See [ExtTransientSeatV1EventHandler::denied].
+    #[inline]
+    fn denied(&mut self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {}
+}
+
+impl<H> ExtTransientSeatV1EventHandler for MutEventHandler<H>
+where
+    H: ExtTransientSeatV1EventHandlerMut,
+{
+    #[inline]
+    fn ready(&self, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        self.with(|h| h.ready(_slf, global_name))
+    }
+
+    #[inline]
+    fn denied(&self, _slf: &ExtTransientSeatV1Ref) {
+        self.with(|h| h.denied(_slf))
+    }
+}
+
+impl<T, H> ExtTransientSeatV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtTransientSeatV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        self.with(|h| h.ready(_data, _slf, global_name))
+    }
+
+    #[inline]
+    fn denied(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {
+        self.with(|h| h.denied(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtTransientSeatV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtTransientSeatV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn ready(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        let _data = self.project(_data);
+        self.handler().ready(_data, _slf, global_name)
+    }
+
+    #[inline]
+    fn denied(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {
+        let _data = self.project(_data);
+        self.handler().denied(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtTransientSeatV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtTransientSeatV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn ready(&mut self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().ready(_data, _slf, global_name)
+    }
+
+    #[inline]
+    fn denied(&mut self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().denied(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtTransientSeatV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ExtTransientSeatV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.ready(slf, arg0);
+            }
+            1 => {
+                self.0.denied(slf);
+            }
+            _ => {
+                invalid_opcode("ext_transient_seat_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtTransientSeatV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ExtTransientSeatV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.ready(data, slf, arg0);
+            }
+            1 => {
+                self.0.denied(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_transient_seat_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtTransientSeatV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtTransientSeatV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for ready events.
+    pub struct Ready<F>(F);
+    impl<F> ExtTransientSeatV1EventHandler for Ready<F>
+    where
+        F: Fn(&ExtTransientSeatV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&self, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+            self.0(_slf, global_name)
+        }
+    }
+    impl<T, F> ExtTransientSeatV1EventHandlerWithData<T> for Ready<F>
+    where
+        F: Fn(&mut T, &ExtTransientSeatV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+            self.0(_data, _slf, global_name)
+        }
+    }
+
+    /// Event handler for denied events.
+    pub struct Denied<F>(F);
+    impl<F> ExtTransientSeatV1EventHandler for Denied<F>
+    where
+        F: Fn(&ExtTransientSeatV1Ref),
+    {
+        #[inline]
+        fn denied(&self, _slf: &ExtTransientSeatV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtTransientSeatV1EventHandlerWithData<T> for Denied<F>
+    where
+        F: Fn(&mut T, &ExtTransientSeatV1Ref),
+    {
+        #[inline]
+        fn denied(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for ready events.
+    pub struct ReadyMut<F>(F);
+    impl<F> ExtTransientSeatV1EventHandlerMut for ReadyMut<F>
+    where
+        F: FnMut(&ExtTransientSeatV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&mut self, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+            self.0(_slf, global_name)
+        }
+    }
+    impl<T, F> ExtTransientSeatV1EventHandlerMutWithData<T> for ReadyMut<F>
+    where
+        F: FnMut(&mut T, &ExtTransientSeatV1Ref, u32),
+    {
+        #[inline]
+        fn ready(&mut self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+            self.0(_data, _slf, global_name)
+        }
+    }
+
+    /// Mutable event handler for denied events.
+    pub struct DeniedMut<F>(F);
+    impl<F> ExtTransientSeatV1EventHandlerMut for DeniedMut<F>
+    where
+        F: FnMut(&ExtTransientSeatV1Ref),
+    {
+        #[inline]
+        fn denied(&mut self, _slf: &ExtTransientSeatV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtTransientSeatV1EventHandlerMutWithData<T> for DeniedMut<F>
+    where
+        F: FnMut(&mut T, &ExtTransientSeatV1Ref),
+    {
+        #[inline]
+        fn denied(&mut self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtTransientSeatV1 {
+        /// Creates an event handler for ready events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready<F>(f: F) -> Ready<F>
+        where
+            F: Fn(&ExtTransientSeatV1Ref, u32),
+        {
+            Ready(f)
+        }
+
+        /// Creates an event handler with mutable data for ready events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ready_with_data<T, F>(f: F) -> Ready<F>
+        where
+            F: Fn(&mut T, &ExtTransientSeatV1Ref, u32),
+        {
+            Ready(f)
+        }
+
+        /// Creates an event handler for denied events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_denied<F>(f: F) -> Denied<F>
+        where
+            F: Fn(&ExtTransientSeatV1Ref),
+        {
+            Denied(f)
+        }
+
+        /// Creates an event handler with mutable data for denied events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_denied_with_data<T, F>(f: F) -> Denied<F>
+        where
+            F: Fn(&mut T, &ExtTransientSeatV1Ref),
+        {
+            Denied(f)
+        }
+
+        /// Creates a mutable event handler for ready events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready_mut<F>(f: F) -> ReadyMut<F>
+        where
+            F: FnMut(&ExtTransientSeatV1Ref, u32),
+        {
+            ReadyMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ready events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ready_mut_with_data<T, F>(f: F) -> ReadyMut<F>
+        where
+            F: FnMut(&mut T, &ExtTransientSeatV1Ref, u32),
+        {
+            ReadyMut(f)
+        }
+
+        /// Creates a mutable event handler for denied events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_denied_mut<F>(f: F) -> DeniedMut<F>
+        where
+            F: FnMut(&ExtTransientSeatV1Ref),
+        {
+            DeniedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for denied events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_denied_mut_with_data<T, F>(f: F) -> DeniedMut<F>
+        where
+            F: FnMut(&mut T, &ExtTransientSeatV1Ref),
+        {
+            DeniedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                ready: Ignore,
+                denied: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtTransientSeatV1EventHandler for Ignore {}
+    impl<T> ExtTransientSeatV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtTransientSeatV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        ready: H0,
+        denied: H1,
+    }
+
+    impl<H0, H1> ExtTransientSeatV1EventHandler for Handler<H0, H1>
+    where
+        H0: ExtTransientSeatV1EventHandler,
+        H1: ExtTransientSeatV1EventHandler,
+    {
+        #[inline]
+        fn ready(&self, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+            self.ready.ready(_slf, global_name)
+        }
+
+        #[inline]
+        fn denied(&self, _slf: &ExtTransientSeatV1Ref) {
+            self.denied.denied(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ExtTransientSeatV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ExtTransientSeatV1EventHandlerWithData<T>,
+        H1: ExtTransientSeatV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn ready(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref, global_name: u32) {
+            self.ready.ready(_data, _slf, global_name)
+        }
+
+        #[inline]
+        fn denied(&self, _data: &mut T, _slf: &ExtTransientSeatV1Ref) {
+            self.denied.denied(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for ready events.
+        #[allow(dead_code)]
+        pub fn on_ready<F>(self, f: F) -> Handler<Ready<F>, H1>
+        where
+            F: Fn(&ExtTransientSeatV1Ref, u32),
+        {
+            Handler {
+                ready: Ready(f),
+                denied: self.denied,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for ready events.
+        #[allow(dead_code)]
+        pub fn on_ready_with_data<T, F>(self, f: F) -> Handler<Ready<F>, H1>
+        where
+            F: Fn(&mut T, &ExtTransientSeatV1Ref, u32),
+        {
+            Handler {
+                ready: Ready(f),
+                denied: self.denied,
+            }
+        }
+
+        /// Sets the closure for denied events.
+        #[allow(dead_code)]
+        pub fn on_denied<F>(self, f: F) -> Handler<H0, Denied<F>>
+        where
+            F: Fn(&ExtTransientSeatV1Ref),
+        {
+            Handler {
+                ready: self.ready,
+                denied: Denied(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for denied events.
+        #[allow(dead_code)]
+        pub fn on_denied_with_data<T, F>(self, f: F) -> Handler<H0, Denied<F>>
+        where
+            F: Fn(&mut T, &ExtTransientSeatV1Ref),
+        {
+            Handler {
+                ready: self.ready,
+                denied: Denied(f),
+            }
+        }
+    }
+}
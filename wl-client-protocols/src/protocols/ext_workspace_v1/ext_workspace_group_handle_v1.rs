@@ -0,0 +1,2471 @@
+//! a workspace group assigned to a set of outputs
+//!
+//! A ext_workspace_group_handle_v1 object represents a workspace group
+//! that is assigned a set of outputs and contains a number of workspaces.
+//!
+//! The set of outputs assigned to the workspace group is conveyed to the client via
+//! output_enter and output_leave events, and its workspaces are conveyed with
+//! workspace events.
+//!
+//! For example, a compositor which has a set of workspaces for each output may
+//! advertise a workspace group (and its workspaces) per output, whereas a compositor
+//! where a workspace spans all outputs may advertise a single workspace group for all
+//! outputs.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_workspace_group_handle_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"create_workspace".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 6,
+    events: {
+        static MESSAGES: [wl_message; 6] = [
+            wl_message {
+                name: c"capabilities".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"output_enter".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlOutput::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"output_leave".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlOutput::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"workspace_enter".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtWorkspaceHandleV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"workspace_leave".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtWorkspaceHandleV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"removed".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_workspace_group_handle_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_workspace_group_handle_v1",
+    protocol: "ext_workspace_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_workspace",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "workspace",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "capabilities",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "capabilities",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("group_capabilities"),
+            }],
+        },
+        info::MessageInfo {
+            name: "output_enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "output_leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "workspace_enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "workspace",
+                ty: info::ArgType::Object,
+                interface: Some("ext_workspace_handle_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "workspace_leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "workspace",
+                ty: info::ArgType::Object,
+                interface: Some("ext_workspace_handle_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "removed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "group_capabilities",
+        since: 1,
+        bitfield: true,
+        entries: &[info::EntryInfo {
+            name: "create_workspace",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned ext_workspace_group_handle_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtWorkspaceGroupHandleV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_workspace_group_handle_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtWorkspaceGroupHandleV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtWorkspaceGroupHandleV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtWorkspaceGroupHandleV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtWorkspaceGroupHandleV1 {
+    const INTERFACE: &'static str = "ext_workspace_group_handle_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtWorkspaceGroupHandleV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtWorkspaceGroupHandleV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtWorkspaceGroupHandleV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtWorkspaceGroupHandleV1Ref {
+    type Owned = ExtWorkspaceGroupHandleV1;
+}
+
+impl Deref for ExtWorkspaceGroupHandleV1 {
+    type Target = ExtWorkspaceGroupHandleV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtWorkspaceGroupHandleV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_workspace_group_handle_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtWorkspaceGroupHandleV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_workspace_group_handle_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtWorkspaceGroupHandleV1Ref> for ExtWorkspaceGroupHandleV1 {
+    fn eq(&self, other: &ExtWorkspaceGroupHandleV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtWorkspaceGroupHandleV1> for ExtWorkspaceGroupHandleV1Ref {
+    fn eq(&self, other: &ExtWorkspaceGroupHandleV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceGroupHandleV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the ext_workspace_group_handle_v1 object
+    ///
+    /// Destroys the ext_workspace_group_handle_v1 object.
+    ///
+    /// This request should be send either when the client does not want to
+    /// use the workspace group object any more or after the removed event to finalize
+    /// the destruction of the object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceGroupHandleV1Ref {
+    /// create a new workspace
+    ///
+    /// Request that the compositor create a new workspace with the given name
+    /// and assign it to this group.
+    ///
+    /// There is no guarantee that the compositor will create a new workspace,
+    /// or that the created workspace will have the provided name.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    #[inline]
+    pub fn create_workspace(&self, workspace: &str) {
+        let (arg0,) = (workspace,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 0 < INTERFACE.method_count = 2
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(0, &mut args);
+            }
+        })
+    }
+}
+
+impl ExtWorkspaceGroupHandleV1 {
+    /// Since when the capabilities event is available.
+    #[allow(dead_code)]
+    pub const EVT__CAPABILITIES__SINCE: u32 = 1;
+
+    /// Since when the output_enter event is available.
+    #[allow(dead_code)]
+    pub const EVT__OUTPUT_ENTER__SINCE: u32 = 1;
+
+    /// Since when the output_leave event is available.
+    #[allow(dead_code)]
+    pub const EVT__OUTPUT_LEAVE__SINCE: u32 = 1;
+
+    /// Since when the workspace_enter event is available.
+    #[allow(dead_code)]
+    pub const EVT__WORKSPACE_ENTER__SINCE: u32 = 1;
+
+    /// Since when the workspace_leave event is available.
+    #[allow(dead_code)]
+    pub const EVT__WORKSPACE_LEAVE__SINCE: u32 = 1;
+
+    /// Since when the removed event is available.
+    #[allow(dead_code)]
+    pub const EVT__REMOVED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtWorkspaceGroupHandleV1] proxies.
+#[allow(dead_code)]
+pub trait ExtWorkspaceGroupHandleV1EventHandler {
+    /// compositor capabilities
+    ///
+    /// This event advertises the capabilities supported by the compositor. If
+    /// a capability isn't supported, clients should hide or disable the UI
+    /// elements that expose this functionality. For instance, if the
+    /// compositor doesn't advertise support for creating workspaces, a button
+    /// triggering the create_workspace request should not be displayed.
+    ///
+    /// The compositor will ignore requests it doesn't support. For instance,
+    /// a compositor which doesn't advertise support for creating workspaces will ignore
+    /// create_workspace requests.
+    ///
+    /// Compositors must send this event once after creation of an
+    /// ext_workspace_group_handle_v1. When the capabilities change, compositors
+    /// must send this event again.
+    ///
+    /// # Arguments
+    ///
+    /// - `capabilities`: capabilities
+    #[inline]
+    fn capabilities(
+        &self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// output assigned to workspace group
+    ///
+    /// This event is emitted whenever an output is assigned to the workspace
+    /// group or a new `wl_output` object is bound by the client, which was already
+    /// assigned to this workspace_group.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn output_enter(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// output removed from workspace group
+    ///
+    /// This event is emitted whenever an output is removed from the workspace
+    /// group.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn output_leave(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// workspace added to workspace group
+    ///
+    /// This event is emitted whenever a workspace is assigned to this group.
+    /// A workspace may only ever be assigned to a single group at a single point
+    /// in time, but can be re-assigned during it's lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn workspace_enter(
+        &self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// workspace removed from workspace group
+    ///
+    /// This event is emitted whenever a workspace is removed from this group.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn workspace_leave(
+        &self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// this workspace group has been removed
+    ///
+    /// This event is send when the group associated with the ext_workspace_group_handle_v1
+    /// has been removed. After sending this request the compositor will immediately consider
+    /// the object inert. Any requests will be ignored except the destroy request.
+    /// It is guaranteed there won't be any more events referencing this
+    /// ext_workspace_group_handle_v1.
+    ///
+    /// The compositor must remove all workspaces belonging to a workspace group
+    /// via a workspace_leave event before removing the workspace group.
+    #[inline]
+    fn removed(&self, _slf: &ExtWorkspaceGroupHandleV1Ref) {}
+}
+
+impl ExtWorkspaceGroupHandleV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtWorkspaceGroupHandleV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtWorkspaceGroupHandleV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtWorkspaceGroupHandleV1EventHandlerWithData<T> {
+    /// compositor capabilities
+    ///
+    /// This event advertises the capabilities supported by the compositor. If
+    /// a capability isn't supported, clients should hide or disable the UI
+    /// elements that expose this functionality. For instance, if the
+    /// compositor doesn't advertise support for creating workspaces, a button
+    /// triggering the create_workspace request should not be displayed.
+    ///
+    /// The compositor will ignore requests it doesn't support. For instance,
+    /// a compositor which doesn't advertise support for creating workspaces will ignore
+    /// create_workspace requests.
+    ///
+    /// Compositors must send this event once after creation of an
+    /// ext_workspace_group_handle_v1. When the capabilities change, compositors
+    /// must send this event again.
+    ///
+    /// # Arguments
+    ///
+    /// - `capabilities`: capabilities
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// output assigned to workspace group
+    ///
+    /// This event is emitted whenever an output is assigned to the workspace
+    /// group or a new `wl_output` object is bound by the client, which was already
+    /// assigned to this workspace_group.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn output_enter(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _ = output;
+    }
+
+    /// output removed from workspace group
+    ///
+    /// This event is emitted whenever an output is removed from the workspace
+    /// group.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn output_leave(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _ = output;
+    }
+
+    /// workspace added to workspace group
+    ///
+    /// This event is emitted whenever a workspace is assigned to this group.
+    /// A workspace may only ever be assigned to a single group at a single point
+    /// in time, but can be re-assigned during it's lifetime.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn workspace_enter(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// workspace removed from workspace group
+    ///
+    /// This event is emitted whenever a workspace is removed from this group.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn workspace_leave(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// this workspace group has been removed
+    ///
+    /// This event is send when the group associated with the ext_workspace_group_handle_v1
+    /// has been removed. After sending this request the compositor will immediately consider
+    /// the object inert. Any requests will be ignored except the destroy request.
+    /// It is guaranteed there won't be any more events referencing this
+    /// ext_workspace_group_handle_v1.
+    ///
+    /// The compositor must remove all workspaces belonging to a workspace group
+    /// via a workspace_leave event before removing the workspace group.
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {}
+}
+
+/// A mutable event handler for [ExtWorkspaceGroupHandleV1] proxies.
+///
+/// This trait is the same as [ExtWorkspaceGroupHandleV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtWorkspaceGroupHandleV1EventHandlerMut {
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::capabilities].
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::output_enter].
+    #[inline]
+    fn output_enter(&mut self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::output_leave].
+    #[inline]
+    fn output_leave(&mut self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::workspace_enter].
+    #[inline]
+    fn workspace_enter(
+        &mut self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::workspace_leave].
+    #[inline]
+    fn workspace_leave(
+        &mut self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _slf: &ExtWorkspaceGroupHandleV1Ref) {}
+}
+
+/// A mutable event handler for [ExtWorkspaceGroupHandleV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtWorkspaceGroupHandleV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> {
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::capabilities].
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::output_enter].
+    #[inline]
+    fn output_enter(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _ = output;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::output_leave].
+    #[inline]
+    fn output_leave(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _ = output;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::workspace_enter].
+    #[inline]
+    fn workspace_enter(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::workspace_leave].
+    #[inline]
+    fn workspace_leave(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _ = workspace;
+    }
+
+    /// See [ExtWorkspaceGroupHandleV1EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {}
+}
+
+impl<H> ExtWorkspaceGroupHandleV1EventHandler for MutEventHandler<H>
+where
+    H: ExtWorkspaceGroupHandleV1EventHandlerMut,
+{
+    #[inline]
+    fn capabilities(
+        &self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        self.with(|h| h.capabilities(_slf, capabilities))
+    }
+
+    #[inline]
+    fn output_enter(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+        self.with(|h| h.output_enter(_slf, output))
+    }
+
+    #[inline]
+    fn output_leave(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+        self.with(|h| h.output_leave(_slf, output))
+    }
+
+    #[inline]
+    fn workspace_enter(
+        &self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        self.with(|h| h.workspace_enter(_slf, workspace))
+    }
+
+    #[inline]
+    fn workspace_leave(
+        &self,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        self.with(|h| h.workspace_leave(_slf, workspace))
+    }
+
+    #[inline]
+    fn removed(&self, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+        self.with(|h| h.removed(_slf))
+    }
+}
+
+impl<T, H> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        self.with(|h| h.capabilities(_data, _slf, capabilities))
+    }
+
+    #[inline]
+    fn output_enter(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        self.with(|h| h.output_enter(_data, _slf, output))
+    }
+
+    #[inline]
+    fn output_leave(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        self.with(|h| h.output_leave(_data, _slf, output))
+    }
+
+    #[inline]
+    fn workspace_enter(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        self.with(|h| h.workspace_enter(_data, _slf, workspace))
+    }
+
+    #[inline]
+    fn workspace_leave(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        self.with(|h| h.workspace_leave(_data, _slf, workspace))
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+        self.with(|h| h.removed(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtWorkspaceGroupHandleV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        let _data = self.project(_data);
+        self.handler().capabilities(_data, _slf, capabilities)
+    }
+
+    #[inline]
+    fn output_enter(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().output_enter(_data, _slf, output)
+    }
+
+    #[inline]
+    fn output_leave(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().output_leave(_data, _slf, output)
+    }
+
+    #[inline]
+    fn workspace_enter(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().workspace_enter(_data, _slf, workspace)
+    }
+
+    #[inline]
+    fn workspace_leave(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().workspace_leave(_data, _slf, workspace)
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler().removed(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtWorkspaceGroupHandleV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().capabilities(_data, _slf, capabilities)
+    }
+
+    #[inline]
+    fn output_enter(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().output_enter(_data, _slf, output)
+    }
+
+    #[inline]
+    fn output_leave(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().output_leave(_data, _slf, output)
+    }
+
+    #[inline]
+    fn workspace_enter(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().workspace_enter(_data, _slf, workspace)
+    }
+
+    #[inline]
+    fn workspace_leave(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceGroupHandleV1Ref,
+        workspace: Option<&ExtWorkspaceHandleV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().workspace_leave(_data, _slf, workspace)
+    }
+
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().removed(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtWorkspaceGroupHandleV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtWorkspaceGroupHandleV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtWorkspaceGroupHandleV1GroupCapabilities(args[0].u) };
+                self.0.capabilities(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.output_enter(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.output_leave(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtWorkspaceHandleV1Ref>(arg0)
+                });
+                self.0.workspace_enter(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtWorkspaceHandleV1Ref>(arg0)
+                });
+                self.0.workspace_leave(slf, arg0);
+            }
+            5 => {
+                self.0.removed(slf);
+            }
+            _ => {
+                invalid_opcode("ext_workspace_group_handle_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtWorkspaceGroupHandleV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtWorkspaceGroupHandleV1GroupCapabilities(args[0].u) };
+                self.0.capabilities(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.output_enter(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.output_leave(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtWorkspaceHandleV1Ref>(arg0)
+                });
+                self.0.workspace_enter(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ExtWorkspaceHandleV1Ref>(arg0)
+                });
+                self.0.workspace_leave(data, slf, arg0);
+            }
+            5 => {
+                self.0.removed(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_workspace_group_handle_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtWorkspaceGroupHandleV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtWorkspaceGroupHandleV1 {
+    /// Since when the group_capabilities.create_workspace enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__GROUP_CAPABILITIES_CREATE_WORKSPACE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ExtWorkspaceGroupHandleV1GroupCapabilities(pub u32);
+
+/// An iterator over the set bits in a [ExtWorkspaceGroupHandleV1GroupCapabilities].
+///
+/// You can construct this with the `IntoIterator` implementation of `ExtWorkspaceGroupHandleV1GroupCapabilities`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ExtWorkspaceGroupHandleV1GroupCapabilitiesIter(pub u32);
+
+impl ExtWorkspaceGroupHandleV1GroupCapabilities {
+    /// create_workspace request is available
+    #[allow(dead_code)]
+    pub const CREATE_WORKSPACE: Self = Self(1);
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceGroupHandleV1GroupCapabilities {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1)
+    }
+}
+
+impl Iterator for ExtWorkspaceGroupHandleV1GroupCapabilitiesIter {
+    type Item = ExtWorkspaceGroupHandleV1GroupCapabilities;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ExtWorkspaceGroupHandleV1GroupCapabilities(bit))
+    }
+}
+
+impl IntoIterator for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    type Item = ExtWorkspaceGroupHandleV1GroupCapabilities;
+    type IntoIter = ExtWorkspaceGroupHandleV1GroupCapabilitiesIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ExtWorkspaceGroupHandleV1GroupCapabilitiesIter(self.0)
+    }
+}
+
+impl BitAnd for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ExtWorkspaceGroupHandleV1GroupCapabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("CREATE_WORKSPACE")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for capabilities events.
+    pub struct Capabilities<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandler for Capabilities<F>
+    where
+        F: Fn(&ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for Capabilities<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
+    /// Event handler for output_enter events.
+    pub struct OutputEnter<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandler for OutputEnter<F>
+    where
+        F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_enter(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for OutputEnter<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_enter(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Event handler for output_leave events.
+    pub struct OutputLeave<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandler for OutputLeave<F>
+    where
+        F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_leave(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for OutputLeave<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_leave(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Event handler for workspace_enter events.
+    pub struct WorkspaceEnter<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandler for WorkspaceEnter<F>
+    where
+        F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_enter(
+            &self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_slf, workspace)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for WorkspaceEnter<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_enter(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_data, _slf, workspace)
+        }
+    }
+
+    /// Event handler for workspace_leave events.
+    pub struct WorkspaceLeave<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandler for WorkspaceLeave<F>
+    where
+        F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_leave(
+            &self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_slf, workspace)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for WorkspaceLeave<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_leave(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_data, _slf, workspace)
+        }
+    }
+
+    /// Event handler for removed events.
+    pub struct Removed<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandler for Removed<F>
+    where
+        F: Fn(&ExtWorkspaceGroupHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&self, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for Removed<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for capabilities events.
+    pub struct CapabilitiesMut<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandlerMut for CapabilitiesMut<F>
+    where
+        F: FnMut(&ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for CapabilitiesMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
+    /// Mutable event handler for output_enter events.
+    pub struct OutputEnterMut<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandlerMut for OutputEnterMut<F>
+    where
+        F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_enter(
+            &mut self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for OutputEnterMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_enter(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Mutable event handler for output_leave events.
+    pub struct OutputLeaveMut<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandlerMut for OutputLeaveMut<F>
+    where
+        F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_leave(
+            &mut self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for OutputLeaveMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn output_leave(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Mutable event handler for workspace_enter events.
+    pub struct WorkspaceEnterMut<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandlerMut for WorkspaceEnterMut<F>
+    where
+        F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_enter(
+            &mut self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_slf, workspace)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for WorkspaceEnterMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_enter(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_data, _slf, workspace)
+        }
+    }
+
+    /// Mutable event handler for workspace_leave events.
+    pub struct WorkspaceLeaveMut<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandlerMut for WorkspaceLeaveMut<F>
+    where
+        F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_leave(
+            &mut self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_slf, workspace)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for WorkspaceLeaveMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+    {
+        #[inline]
+        fn workspace_leave(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.0(_data, _slf, workspace)
+        }
+    }
+
+    /// Mutable event handler for removed events.
+    pub struct RemovedMut<F>(F);
+    impl<F> ExtWorkspaceGroupHandleV1EventHandlerMut for RemovedMut<F>
+    where
+        F: FnMut(&ExtWorkspaceGroupHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceGroupHandleV1EventHandlerMutWithData<T> for RemovedMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtWorkspaceGroupHandleV1 {
+        /// Creates an event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates an event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(
+                &mut T,
+                &ExtWorkspaceGroupHandleV1Ref,
+                ExtWorkspaceGroupHandleV1GroupCapabilities,
+            ),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates an event handler for output_enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_output_enter<F>(f: F) -> OutputEnter<F>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputEnter(f)
+        }
+
+        /// Creates an event handler with mutable data for output_enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_output_enter_with_data<T, F>(f: F) -> OutputEnter<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputEnter(f)
+        }
+
+        /// Creates an event handler for output_leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_output_leave<F>(f: F) -> OutputLeave<F>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputLeave(f)
+        }
+
+        /// Creates an event handler with mutable data for output_leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_output_leave_with_data<T, F>(f: F) -> OutputLeave<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputLeave(f)
+        }
+
+        /// Creates an event handler for workspace_enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_enter<F>(f: F) -> WorkspaceEnter<F>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceEnter(f)
+        }
+
+        /// Creates an event handler with mutable data for workspace_enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_enter_with_data<T, F>(f: F) -> WorkspaceEnter<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceEnter(f)
+        }
+
+        /// Creates an event handler for workspace_leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_leave<F>(f: F) -> WorkspaceLeave<F>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceLeave(f)
+        }
+
+        /// Creates an event handler with mutable data for workspace_leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_leave_with_data<T, F>(f: F) -> WorkspaceLeave<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceLeave(f)
+        }
+
+        /// Creates an event handler for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(f: F) -> Removed<F>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates an event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(f: F) -> Removed<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates a mutable event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut<F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut_with_data<T, F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &ExtWorkspaceGroupHandleV1Ref,
+                ExtWorkspaceGroupHandleV1GroupCapabilities,
+            ),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler for output_enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_output_enter_mut<F>(f: F) -> OutputEnterMut<F>
+        where
+            F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputEnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for output_enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_output_enter_mut_with_data<T, F>(f: F) -> OutputEnterMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputEnterMut(f)
+        }
+
+        /// Creates a mutable event handler for output_leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_output_leave_mut<F>(f: F) -> OutputLeaveMut<F>
+        where
+            F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputLeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for output_leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_output_leave_mut_with_data<T, F>(f: F) -> OutputLeaveMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            OutputLeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for workspace_enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_enter_mut<F>(f: F) -> WorkspaceEnterMut<F>
+        where
+            F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceEnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for workspace_enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_enter_mut_with_data<T, F>(f: F) -> WorkspaceEnterMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceEnterMut(f)
+        }
+
+        /// Creates a mutable event handler for workspace_leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_leave_mut<F>(f: F) -> WorkspaceLeaveMut<F>
+        where
+            F: FnMut(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceLeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for workspace_leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_leave_mut_with_data<T, F>(f: F) -> WorkspaceLeaveMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            WorkspaceLeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut<F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&ExtWorkspaceGroupHandleV1Ref),
+        {
+            RemovedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut_with_data<T, F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceGroupHandleV1Ref),
+        {
+            RemovedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                capabilities: Ignore,
+                output_enter: Ignore,
+                output_leave: Ignore,
+                workspace_enter: Ignore,
+                workspace_leave: Ignore,
+                removed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtWorkspaceGroupHandleV1EventHandler for Ignore {}
+    impl<T> ExtWorkspaceGroupHandleV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtWorkspaceGroupHandleV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        capabilities: H0,
+        output_enter: H1,
+        output_leave: H2,
+        workspace_enter: H3,
+        workspace_leave: H4,
+        removed: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> ExtWorkspaceGroupHandleV1EventHandler
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ExtWorkspaceGroupHandleV1EventHandler,
+        H1: ExtWorkspaceGroupHandleV1EventHandler,
+        H2: ExtWorkspaceGroupHandleV1EventHandler,
+        H3: ExtWorkspaceGroupHandleV1EventHandler,
+        H4: ExtWorkspaceGroupHandleV1EventHandler,
+        H5: ExtWorkspaceGroupHandleV1EventHandler,
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ) {
+            self.capabilities.capabilities(_slf, capabilities)
+        }
+
+        #[inline]
+        fn output_enter(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+            self.output_enter.output_enter(_slf, output)
+        }
+
+        #[inline]
+        fn output_leave(&self, _slf: &ExtWorkspaceGroupHandleV1Ref, output: Option<&WlOutputRef>) {
+            self.output_leave.output_leave(_slf, output)
+        }
+
+        #[inline]
+        fn workspace_enter(
+            &self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.workspace_enter.workspace_enter(_slf, workspace)
+        }
+
+        #[inline]
+        fn workspace_leave(
+            &self,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.workspace_leave.workspace_leave(_slf, workspace)
+        }
+
+        #[inline]
+        fn removed(&self, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+            self.removed.removed(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> ExtWorkspaceGroupHandleV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+        H1: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+        H2: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+        H3: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+        H4: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+        H5: ExtWorkspaceGroupHandleV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            capabilities: ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ) {
+            self.capabilities.capabilities(_data, _slf, capabilities)
+        }
+
+        #[inline]
+        fn output_enter(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.output_enter.output_enter(_data, _slf, output)
+        }
+
+        #[inline]
+        fn output_leave(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.output_leave.output_leave(_data, _slf, output)
+        }
+
+        #[inline]
+        fn workspace_enter(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.workspace_enter.workspace_enter(_data, _slf, workspace)
+        }
+
+        #[inline]
+        fn workspace_leave(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceGroupHandleV1Ref,
+            workspace: Option<&ExtWorkspaceHandleV1Ref>,
+        ) {
+            self.workspace_leave.workspace_leave(_data, _slf, workspace)
+        }
+
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceGroupHandleV1Ref) {
+            self.removed.removed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(self, f: F) -> Handler<Capabilities<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, ExtWorkspaceGroupHandleV1GroupCapabilities),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<Capabilities<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(
+                &mut T,
+                &ExtWorkspaceGroupHandleV1Ref,
+                ExtWorkspaceGroupHandleV1GroupCapabilities,
+            ),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for output_enter events.
+        #[allow(dead_code)]
+        pub fn on_output_enter<F>(self, f: F) -> Handler<H0, OutputEnter<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: OutputEnter(f),
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for output_enter events.
+        #[allow(dead_code)]
+        pub fn on_output_enter_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, OutputEnter<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: OutputEnter(f),
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for output_leave events.
+        #[allow(dead_code)]
+        pub fn on_output_leave<F>(self, f: F) -> Handler<H0, H1, OutputLeave<F>, H3, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: OutputLeave(f),
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for output_leave events.
+        #[allow(dead_code)]
+        pub fn on_output_leave_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, OutputLeave<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&WlOutputRef>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: OutputLeave(f),
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for workspace_enter events.
+        #[allow(dead_code)]
+        pub fn on_workspace_enter<F>(self, f: F) -> Handler<H0, H1, H2, WorkspaceEnter<F>, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: WorkspaceEnter(f),
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for workspace_enter events.
+        #[allow(dead_code)]
+        pub fn on_workspace_enter_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, WorkspaceEnter<F>, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: WorkspaceEnter(f),
+                workspace_leave: self.workspace_leave,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for workspace_leave events.
+        #[allow(dead_code)]
+        pub fn on_workspace_leave<F>(self, f: F) -> Handler<H0, H1, H2, H3, WorkspaceLeave<F>, H5>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: WorkspaceLeave(f),
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for workspace_leave events.
+        #[allow(dead_code)]
+        pub fn on_workspace_leave_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, WorkspaceLeave<F>, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref, Option<&ExtWorkspaceHandleV1Ref>),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: WorkspaceLeave(f),
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Removed<F>>
+        where
+            F: Fn(&ExtWorkspaceGroupHandleV1Ref),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: Removed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Removed<F>>
+        where
+            F: Fn(&mut T, &ExtWorkspaceGroupHandleV1Ref),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                output_enter: self.output_enter,
+                output_leave: self.output_leave,
+                workspace_enter: self.workspace_enter,
+                workspace_leave: self.workspace_leave,
+                removed: Removed(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,2703 @@
+//! a workspace handing a group of surfaces
+//!
+//! A ext_workspace_handle_v1 object represents a workspace that handles a
+//! group of surfaces.
+//!
+//! Each workspace has:
+//! - a name, conveyed to the client with the name event
+//! - potentially an id conveyed with the id event
+//! - a list of states, conveyed to the client with the state event
+//! - and optionally a set of coordinates, conveyed to the client with the
+//! coordinates event
+//!
+//! The client may request that the compositor activate or deactivate the workspace.
+//!
+//! Each workspace can belong to only a single workspace group.
+//! Depending on the compositor policy, there might be workspaces with
+//! the same name in different workspace groups, but these workspaces are still
+//! separate (e.g. one of them might be active while the other is not).
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_workspace_handle_v1".as_ptr(),
+    version: 1,
+    method_count: 5,
+    methods: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"activate".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"deactivate".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"assign".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtWorkspaceGroupHandleV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"remove".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 6,
+    events: {
+        static MESSAGES: [wl_message; 6] = [
+            wl_message {
+                name: c"id".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"name".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"coordinates".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"state".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"capabilities".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"removed".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_workspace_handle_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_workspace_handle_v1",
+    protocol: "ext_workspace_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "activate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "deactivate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "assign",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "workspace_group",
+                ty: info::ArgType::Object,
+                interface: Some("ext_workspace_group_handle_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "remove",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "name",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "coordinates",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "coordinates",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "state",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "state",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("state"),
+            }],
+        },
+        info::MessageInfo {
+            name: "capabilities",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "capabilities",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("workspace_capabilities"),
+            }],
+        },
+        info::MessageInfo {
+            name: "removed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "state",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "active",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "urgent",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "hidden",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "workspace_capabilities",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "activate",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "deactivate",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "remove",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "assign",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned ext_workspace_handle_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtWorkspaceHandleV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_workspace_handle_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtWorkspaceHandleV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtWorkspaceHandleV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtWorkspaceHandleV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtWorkspaceHandleV1 {
+    const INTERFACE: &'static str = "ext_workspace_handle_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtWorkspaceHandleV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtWorkspaceHandleV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtWorkspaceHandleV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtWorkspaceHandleV1Ref {
+    type Owned = ExtWorkspaceHandleV1;
+}
+
+impl Deref for ExtWorkspaceHandleV1 {
+    type Target = ExtWorkspaceHandleV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtWorkspaceHandleV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_workspace_handle_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtWorkspaceHandleV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_workspace_handle_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtWorkspaceHandleV1Ref> for ExtWorkspaceHandleV1 {
+    fn eq(&self, other: &ExtWorkspaceHandleV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtWorkspaceHandleV1> for ExtWorkspaceHandleV1Ref {
+    fn eq(&self, other: &ExtWorkspaceHandleV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceHandleV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the ext_workspace_handle_v1 object
+    ///
+    /// Destroys the ext_workspace_handle_v1 object.
+    ///
+    /// This request should be made either when the client does not want to
+    /// use the workspace object any more or after the remove event to finalize
+    /// the destruction of the object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceHandleV1Ref {
+    /// activate the workspace
+    ///
+    /// Request that this workspace be activated.
+    ///
+    /// There is no guarantee the workspace will be actually activated, and
+    /// behaviour may be compositor-dependent. For example, activating a
+    /// workspace may or may not deactivate all other workspaces in the same
+    /// group.
+    #[inline]
+    pub fn activate(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// deactivate the workspace
+    ///
+    /// Request that this workspace be deactivated.
+    ///
+    /// There is no guarantee the workspace will be actually deactivated.
+    #[inline]
+    pub fn deactivate(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// assign workspace to group
+    ///
+    /// Requests that this workspace is assigned to the given workspace group.
+    ///
+    /// There is no guarantee the workspace will be assigned.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace_group`:
+    #[inline]
+    pub fn assign(&self, workspace_group: &ExtWorkspaceGroupHandleV1Ref) {
+        let (arg0,) = (workspace_group,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("workspace_group", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 5
+        //         - the request signature is `o`
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+
+    /// remove the workspace
+    ///
+    /// Request that this workspace be removed.
+    ///
+    /// There is no guarantee the workspace will be actually removed.
+    #[inline]
+    pub fn remove(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(4, &mut args);
+        }
+    }
+}
+
+impl ExtWorkspaceHandleV1 {
+    /// Since when the id event is available.
+    #[allow(dead_code)]
+    pub const EVT__ID__SINCE: u32 = 1;
+
+    /// Since when the name event is available.
+    #[allow(dead_code)]
+    pub const EVT__NAME__SINCE: u32 = 1;
+
+    /// Since when the coordinates event is available.
+    #[allow(dead_code)]
+    pub const EVT__COORDINATES__SINCE: u32 = 1;
+
+    /// Since when the state event is available.
+    #[allow(dead_code)]
+    pub const EVT__STATE__SINCE: u32 = 1;
+
+    /// Since when the capabilities event is available.
+    #[allow(dead_code)]
+    pub const EVT__CAPABILITIES__SINCE: u32 = 1;
+
+    /// Since when the removed event is available.
+    #[allow(dead_code)]
+    pub const EVT__REMOVED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtWorkspaceHandleV1] proxies.
+#[allow(dead_code)]
+pub trait ExtWorkspaceHandleV1EventHandler {
+    /// workspace id
+    ///
+    /// If this event is emitted, it will be send immediately after the
+    /// ext_workspace_handle_v1 is created or when an id is assigned to
+    /// a workspace (at most once during it's lifetime).
+    ///
+    /// An id will never change during the lifetime of the `ext_workspace_handle_v1`
+    /// and is guaranteed to be unique during it's lifetime.
+    ///
+    /// Ids are not human-readable and shouldn't be displayed, use `name` for that purpose.
+    ///
+    /// Compositors are expected to only send ids for workspaces likely stable across multiple
+    /// sessions and can be used by clients to store preferences for workspaces. Workspaces without
+    /// ids should be considered temporary and any data associated with them should be deleted once
+    /// the respective object is lost.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn id(&self, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        let _ = id;
+    }
+
+    /// workspace name changed
+    ///
+    /// This event is emitted immediately after the ext_workspace_handle_v1 is
+    /// created and whenever the name of the workspace changes.
+    ///
+    /// A name is meant to be human-readable and can be displayed to a user.
+    /// Unlike the id it is neither stable nor unique.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`:
+    #[inline]
+    fn name(&self, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// workspace coordinates changed
+    ///
+    /// This event is used to organize workspaces into an N-dimensional grid
+    /// within a workspace group, and if supported, is emitted immediately after
+    /// the ext_workspace_handle_v1 is created and whenever the coordinates of
+    /// the workspace change. Compositors may not send this event if they do not
+    /// conceptually arrange workspaces in this way. If compositors simply
+    /// number workspaces, without any geometric interpretation, they may send
+    /// 1D coordinates, which clients should not interpret as implying any
+    /// geometry. Sending an empty array means that the compositor no longer
+    /// orders the workspace geometrically.
+    ///
+    /// Coordinates have an arbitrary number of dimensions N with an uint32
+    /// position along each dimension. By convention if N > 1, the first
+    /// dimension is X, the second Y, the third Z, and so on. The compositor may
+    /// chose to utilize these events for a more novel workspace layout
+    /// convention, however. No guarantee is made about the grid being filled or
+    /// bounded; there may be a workspace at coordinate 1 and another at
+    /// coordinate 1000 and none in between. Within a workspace group, however,
+    /// workspaces must have unique coordinates of equal dimensionality.
+    ///
+    /// # Arguments
+    ///
+    /// - `coordinates`:
+    #[inline]
+    fn coordinates(&self, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        let _ = coordinates;
+    }
+
+    /// the state of the workspace changed
+    ///
+    /// This event is emitted immediately after the ext_workspace_handle_v1 is
+    /// created and each time the workspace state changes, either because of a
+    /// compositor action or because of a request in this protocol.
+    ///
+    /// Missing states convey the opposite meaning, e.g. an unset active bit
+    /// means the workspace is currently inactive.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`:
+    #[inline]
+    fn state(&self, _slf: &ExtWorkspaceHandleV1Ref, state: ExtWorkspaceHandleV1State) {
+        let _ = state;
+    }
+
+    /// compositor capabilities
+    ///
+    /// This event advertises the capabilities supported by the compositor. If
+    /// a capability isn't supported, clients should hide or disable the UI
+    /// elements that expose this functionality. For instance, if the
+    /// compositor doesn't advertise support for removing workspaces, a button
+    /// triggering the remove request should not be displayed.
+    ///
+    /// The compositor will ignore requests it doesn't support. For instance,
+    /// a compositor which doesn't advertise support for remove will ignore
+    /// remove requests.
+    ///
+    /// Compositors must send this event once after creation of an
+    /// ext_workspace_handle_v1 . When the capabilities change, compositors
+    /// must send this event again.
+    ///
+    /// # Arguments
+    ///
+    /// - `capabilities`: capabilities
+    #[inline]
+    fn capabilities(
+        &self,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// this workspace has been removed
+    ///
+    /// This event is send when the workspace associated with the ext_workspace_handle_v1
+    /// has been removed. After sending this request, the compositor will immediately consider
+    /// the object inert. Any requests will be ignored except the destroy request.
+    ///
+    /// It is guaranteed there won't be any more events referencing this
+    /// ext_workspace_handle_v1.
+    ///
+    /// The compositor must only remove a workspaces not currently belonging to any
+    /// workspace_group.
+    #[inline]
+    fn removed(&self, _slf: &ExtWorkspaceHandleV1Ref) {}
+}
+
+impl ExtWorkspaceHandleV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtWorkspaceHandleV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtWorkspaceHandleV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtWorkspaceHandleV1EventHandlerWithData<T> {
+    /// workspace id
+    ///
+    /// If this event is emitted, it will be send immediately after the
+    /// ext_workspace_handle_v1 is created or when an id is assigned to
+    /// a workspace (at most once during it's lifetime).
+    ///
+    /// An id will never change during the lifetime of the `ext_workspace_handle_v1`
+    /// and is guaranteed to be unique during it's lifetime.
+    ///
+    /// Ids are not human-readable and shouldn't be displayed, use `name` for that purpose.
+    ///
+    /// Compositors are expected to only send ids for workspaces likely stable across multiple
+    /// sessions and can be used by clients to store preferences for workspaces. Workspaces without
+    /// ids should be considered temporary and any data associated with them should be deleted once
+    /// the respective object is lost.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn id(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        let _ = id;
+    }
+
+    /// workspace name changed
+    ///
+    /// This event is emitted immediately after the ext_workspace_handle_v1 is
+    /// created and whenever the name of the workspace changes.
+    ///
+    /// A name is meant to be human-readable and can be displayed to a user.
+    /// Unlike the id it is neither stable nor unique.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`:
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// workspace coordinates changed
+    ///
+    /// This event is used to organize workspaces into an N-dimensional grid
+    /// within a workspace group, and if supported, is emitted immediately after
+    /// the ext_workspace_handle_v1 is created and whenever the coordinates of
+    /// the workspace change. Compositors may not send this event if they do not
+    /// conceptually arrange workspaces in this way. If compositors simply
+    /// number workspaces, without any geometric interpretation, they may send
+    /// 1D coordinates, which clients should not interpret as implying any
+    /// geometry. Sending an empty array means that the compositor no longer
+    /// orders the workspace geometrically.
+    ///
+    /// Coordinates have an arbitrary number of dimensions N with an uint32
+    /// position along each dimension. By convention if N > 1, the first
+    /// dimension is X, the second Y, the third Z, and so on. The compositor may
+    /// chose to utilize these events for a more novel workspace layout
+    /// convention, however. No guarantee is made about the grid being filled or
+    /// bounded; there may be a workspace at coordinate 1 and another at
+    /// coordinate 1000 and none in between. Within a workspace group, however,
+    /// workspaces must have unique coordinates of equal dimensionality.
+    ///
+    /// # Arguments
+    ///
+    /// - `coordinates`:
+    #[inline]
+    fn coordinates(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        let _ = coordinates;
+    }
+
+    /// the state of the workspace changed
+    ///
+    /// This event is emitted immediately after the ext_workspace_handle_v1 is
+    /// created and each time the workspace state changes, either because of a
+    /// compositor action or because of a request in this protocol.
+    ///
+    /// Missing states convey the opposite meaning, e.g. an unset active bit
+    /// means the workspace is currently inactive.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`:
+    #[inline]
+    fn state(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        state: ExtWorkspaceHandleV1State,
+    ) {
+        let _ = state;
+    }
+
+    /// compositor capabilities
+    ///
+    /// This event advertises the capabilities supported by the compositor. If
+    /// a capability isn't supported, clients should hide or disable the UI
+    /// elements that expose this functionality. For instance, if the
+    /// compositor doesn't advertise support for removing workspaces, a button
+    /// triggering the remove request should not be displayed.
+    ///
+    /// The compositor will ignore requests it doesn't support. For instance,
+    /// a compositor which doesn't advertise support for remove will ignore
+    /// remove requests.
+    ///
+    /// Compositors must send this event once after creation of an
+    /// ext_workspace_handle_v1 . When the capabilities change, compositors
+    /// must send this event again.
+    ///
+    /// # Arguments
+    ///
+    /// - `capabilities`: capabilities
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// this workspace has been removed
+    ///
+    /// This event is send when the workspace associated with the ext_workspace_handle_v1
+    /// has been removed. After sending this request, the compositor will immediately consider
+    /// the object inert. Any requests will be ignored except the destroy request.
+    ///
+    /// It is guaranteed there won't be any more events referencing this
+    /// ext_workspace_handle_v1.
+    ///
+    /// The compositor must only remove a workspaces not currently belonging to any
+    /// workspace_group.
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {}
+}
+
+/// A mutable event handler for [ExtWorkspaceHandleV1] proxies.
+///
+/// This trait is the same as [ExtWorkspaceHandleV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtWorkspaceHandleV1EventHandlerMut {
+    /// See [ExtWorkspaceHandleV1EventHandler::id].
+    #[inline]
+    fn id(&mut self, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        let _ = id;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::name].
+    #[inline]
+    fn name(&mut self, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::coordinates].
+    #[inline]
+    fn coordinates(&mut self, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        let _ = coordinates;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::state].
+    #[inline]
+    fn state(&mut self, _slf: &ExtWorkspaceHandleV1Ref, state: ExtWorkspaceHandleV1State) {
+        let _ = state;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::capabilities].
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _slf: &ExtWorkspaceHandleV1Ref) {}
+}
+
+/// A mutable event handler for [ExtWorkspaceHandleV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtWorkspaceHandleV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtWorkspaceHandleV1EventHandlerMutWithData<T> {
+    /// See [ExtWorkspaceHandleV1EventHandler::id].
+    #[inline]
+    fn id(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        let _ = id;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::name].
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::coordinates].
+    #[inline]
+    fn coordinates(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        let _ = coordinates;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::state].
+    #[inline]
+    fn state(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        state: ExtWorkspaceHandleV1State,
+    ) {
+        let _ = state;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::capabilities].
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        let _ = capabilities;
+    }
+
+    /// See [ExtWorkspaceHandleV1EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {}
+}
+
+impl<H> ExtWorkspaceHandleV1EventHandler for MutEventHandler<H>
+where
+    H: ExtWorkspaceHandleV1EventHandlerMut,
+{
+    #[inline]
+    fn id(&self, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        self.with(|h| h.id(_slf, id))
+    }
+
+    #[inline]
+    fn name(&self, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        self.with(|h| h.name(_slf, name))
+    }
+
+    #[inline]
+    fn coordinates(&self, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        self.with(|h| h.coordinates(_slf, coordinates))
+    }
+
+    #[inline]
+    fn state(&self, _slf: &ExtWorkspaceHandleV1Ref, state: ExtWorkspaceHandleV1State) {
+        self.with(|h| h.state(_slf, state))
+    }
+
+    #[inline]
+    fn capabilities(
+        &self,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        self.with(|h| h.capabilities(_slf, capabilities))
+    }
+
+    #[inline]
+    fn removed(&self, _slf: &ExtWorkspaceHandleV1Ref) {
+        self.with(|h| h.removed(_slf))
+    }
+}
+
+impl<T, H> ExtWorkspaceHandleV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtWorkspaceHandleV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn id(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        self.with(|h| h.id(_data, _slf, id))
+    }
+
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        self.with(|h| h.name(_data, _slf, name))
+    }
+
+    #[inline]
+    fn coordinates(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        self.with(|h| h.coordinates(_data, _slf, coordinates))
+    }
+
+    #[inline]
+    fn state(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        state: ExtWorkspaceHandleV1State,
+    ) {
+        self.with(|h| h.state(_data, _slf, state))
+    }
+
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        self.with(|h| h.capabilities(_data, _slf, capabilities))
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {
+        self.with(|h| h.removed(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtWorkspaceHandleV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtWorkspaceHandleV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn id(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        let _data = self.project(_data);
+        self.handler().id(_data, _slf, id)
+    }
+
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn coordinates(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().coordinates(_data, _slf, coordinates)
+    }
+
+    #[inline]
+    fn state(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        state: ExtWorkspaceHandleV1State,
+    ) {
+        let _data = self.project(_data);
+        self.handler().state(_data, _slf, state)
+    }
+
+    #[inline]
+    fn capabilities(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        let _data = self.project(_data);
+        self.handler().capabilities(_data, _slf, capabilities)
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler().removed(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtWorkspaceHandleV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn id(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().id(_data, _slf, id)
+    }
+
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn coordinates(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+        let _data = self.project(_data);
+        self.handler_mut().coordinates(_data, _slf, coordinates)
+    }
+
+    #[inline]
+    fn state(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        state: ExtWorkspaceHandleV1State,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().state(_data, _slf, state)
+    }
+
+    #[inline]
+    fn capabilities(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceHandleV1Ref,
+        capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().capabilities(_data, _slf, capabilities)
+    }
+
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().removed(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtWorkspaceHandleV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtWorkspaceHandleV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("ext_workspace_handle_v1", "id", args[0].s) };
+                self.0.id(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("ext_workspace_handle_v1", "name", args[0].s) };
+                self.0.name(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.coordinates(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtWorkspaceHandleV1State(args[0].u) };
+                self.0.state(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtWorkspaceHandleV1WorkspaceCapabilities(args[0].u) };
+                self.0.capabilities(slf, arg0);
+            }
+            5 => {
+                self.0.removed(slf);
+            }
+            _ => {
+                invalid_opcode("ext_workspace_handle_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtWorkspaceHandleV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("ext_workspace_handle_v1", "id", args[0].s) };
+                self.0.id(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("ext_workspace_handle_v1", "name", args[0].s) };
+                self.0.name(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.coordinates(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtWorkspaceHandleV1State(args[0].u) };
+                self.0.state(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ExtWorkspaceHandleV1WorkspaceCapabilities(args[0].u) };
+                self.0.capabilities(data, slf, arg0);
+            }
+            5 => {
+                self.0.removed(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_workspace_handle_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtWorkspaceHandleV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ExtWorkspaceHandleV1 {
+    /// Since when the state.active enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_ACTIVE__SINCE: u32 = 1;
+    /// Since when the state.urgent enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_URGENT__SINCE: u32 = 1;
+    /// Since when the state.hidden enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_HIDDEN__SINCE: u32 = 1;
+
+    /// Since when the workspace_capabilities.activate enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__WORKSPACE_CAPABILITIES_ACTIVATE__SINCE: u32 = 1;
+    /// Since when the workspace_capabilities.deactivate enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__WORKSPACE_CAPABILITIES_DEACTIVATE__SINCE: u32 = 1;
+    /// Since when the workspace_capabilities.remove enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__WORKSPACE_CAPABILITIES_REMOVE__SINCE: u32 = 1;
+    /// Since when the workspace_capabilities.assign enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__WORKSPACE_CAPABILITIES_ASSIGN__SINCE: u32 = 1;
+}
+
+/// types of states on the workspace
+///
+/// The different states that a workspace can have.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ExtWorkspaceHandleV1State(pub u32);
+
+/// An iterator over the set bits in a [ExtWorkspaceHandleV1State].
+///
+/// You can construct this with the `IntoIterator` implementation of `ExtWorkspaceHandleV1State`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ExtWorkspaceHandleV1StateIter(pub u32);
+
+impl ExtWorkspaceHandleV1State {
+    /// the workspace is active
+    #[allow(dead_code)]
+    pub const ACTIVE: Self = Self(1);
+
+    /// the workspace requests attention
+    #[allow(dead_code)]
+    pub const URGENT: Self = Self(2);
+
+    /// the workspace is not visible
+    ///
+    /// The workspace is not visible in its workspace group, and clients
+    /// attempting to visualize the compositor workspace state should not
+    /// display such workspaces.
+    #[allow(dead_code)]
+    pub const HIDDEN: Self = Self(4);
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceHandleV1State {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1 | 2 | 4)
+    }
+}
+
+impl Iterator for ExtWorkspaceHandleV1StateIter {
+    type Item = ExtWorkspaceHandleV1State;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ExtWorkspaceHandleV1State(bit))
+    }
+}
+
+impl IntoIterator for ExtWorkspaceHandleV1State {
+    type Item = ExtWorkspaceHandleV1State;
+    type IntoIter = ExtWorkspaceHandleV1StateIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ExtWorkspaceHandleV1StateIter(self.0)
+    }
+}
+
+impl BitAnd for ExtWorkspaceHandleV1State {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ExtWorkspaceHandleV1State {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ExtWorkspaceHandleV1State {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ExtWorkspaceHandleV1State {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ExtWorkspaceHandleV1State {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ExtWorkspaceHandleV1State {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ExtWorkspaceHandleV1State {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ExtWorkspaceHandleV1State {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ExtWorkspaceHandleV1State {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ExtWorkspaceHandleV1State {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("ACTIVE")?;
+        }
+        if v & 2 == 2 {
+            v &= !2;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("URGENT")?;
+        }
+        if v & 4 == 4 {
+            v &= !4;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("HIDDEN")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ExtWorkspaceHandleV1WorkspaceCapabilities(pub u32);
+
+/// An iterator over the set bits in a [ExtWorkspaceHandleV1WorkspaceCapabilities].
+///
+/// You can construct this with the `IntoIterator` implementation of `ExtWorkspaceHandleV1WorkspaceCapabilities`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ExtWorkspaceHandleV1WorkspaceCapabilitiesIter(pub u32);
+
+impl ExtWorkspaceHandleV1WorkspaceCapabilities {
+    /// activate request is available
+    #[allow(dead_code)]
+    pub const ACTIVATE: Self = Self(1);
+
+    /// deactivate request is available
+    #[allow(dead_code)]
+    pub const DEACTIVATE: Self = Self(2);
+
+    /// remove request is available
+    #[allow(dead_code)]
+    pub const REMOVE: Self = Self(4);
+
+    /// assign request is available
+    #[allow(dead_code)]
+    pub const ASSIGN: Self = Self(8);
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceHandleV1WorkspaceCapabilities {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1 | 2 | 4 | 8)
+    }
+}
+
+impl Iterator for ExtWorkspaceHandleV1WorkspaceCapabilitiesIter {
+    type Item = ExtWorkspaceHandleV1WorkspaceCapabilities;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ExtWorkspaceHandleV1WorkspaceCapabilities(bit))
+    }
+}
+
+impl IntoIterator for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    type Item = ExtWorkspaceHandleV1WorkspaceCapabilities;
+    type IntoIter = ExtWorkspaceHandleV1WorkspaceCapabilitiesIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ExtWorkspaceHandleV1WorkspaceCapabilitiesIter(self.0)
+    }
+}
+
+impl BitAnd for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ExtWorkspaceHandleV1WorkspaceCapabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("ACTIVATE")?;
+        }
+        if v & 2 == 2 {
+            v &= !2;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("DEACTIVATE")?;
+        }
+        if v & 4 == 4 {
+            v &= !4;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("REMOVE")?;
+        }
+        if v & 8 == 8 {
+            v &= !8;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("ASSIGN")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for id events.
+    pub struct Id<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandler for Id<F>
+    where
+        F: Fn(&ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn id(&self, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerWithData<T> for Id<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn id(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Event handler for name events.
+    pub struct Name<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandler for Name<F>
+    where
+        F: Fn(&ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerWithData<T> for Name<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Event handler for coordinates events.
+    pub struct Coordinates<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandler for Coordinates<F>
+    where
+        F: Fn(&ExtWorkspaceHandleV1Ref, &[u8]),
+    {
+        #[inline]
+        fn coordinates(&self, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+            self.0(_slf, coordinates)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerWithData<T> for Coordinates<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &[u8]),
+    {
+        #[inline]
+        fn coordinates(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+            self.0(_data, _slf, coordinates)
+        }
+    }
+
+    /// Event handler for state events.
+    pub struct State<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandler for State<F>
+    where
+        F: Fn(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+    {
+        #[inline]
+        fn state(&self, _slf: &ExtWorkspaceHandleV1Ref, state: ExtWorkspaceHandleV1State) {
+            self.0(_slf, state)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerWithData<T> for State<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+    {
+        #[inline]
+        fn state(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            state: ExtWorkspaceHandleV1State,
+        ) {
+            self.0(_data, _slf, state)
+        }
+    }
+
+    /// Event handler for capabilities events.
+    pub struct Capabilities<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandler for Capabilities<F>
+    where
+        F: Fn(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerWithData<T> for Capabilities<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
+    /// Event handler for removed events.
+    pub struct Removed<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandler for Removed<F>
+    where
+        F: Fn(&ExtWorkspaceHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&self, _slf: &ExtWorkspaceHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerWithData<T> for Removed<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for id events.
+    pub struct IdMut<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandlerMut for IdMut<F>
+    where
+        F: FnMut(&ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn id(&mut self, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for IdMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn id(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for name events.
+    pub struct NameMut<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandlerMut for NameMut<F>
+    where
+        F: FnMut(&ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for NameMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Mutable event handler for coordinates events.
+    pub struct CoordinatesMut<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandlerMut for CoordinatesMut<F>
+    where
+        F: FnMut(&ExtWorkspaceHandleV1Ref, &[u8]),
+    {
+        #[inline]
+        fn coordinates(&mut self, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+            self.0(_slf, coordinates)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for CoordinatesMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, &[u8]),
+    {
+        #[inline]
+        fn coordinates(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            coordinates: &[u8],
+        ) {
+            self.0(_data, _slf, coordinates)
+        }
+    }
+
+    /// Mutable event handler for state events.
+    pub struct StateMut<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandlerMut for StateMut<F>
+    where
+        F: FnMut(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+    {
+        #[inline]
+        fn state(&mut self, _slf: &ExtWorkspaceHandleV1Ref, state: ExtWorkspaceHandleV1State) {
+            self.0(_slf, state)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for StateMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+    {
+        #[inline]
+        fn state(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            state: ExtWorkspaceHandleV1State,
+        ) {
+            self.0(_data, _slf, state)
+        }
+    }
+
+    /// Mutable event handler for capabilities events.
+    pub struct CapabilitiesMut<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandlerMut for CapabilitiesMut<F>
+    where
+        F: FnMut(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for CapabilitiesMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
+    /// Mutable event handler for removed events.
+    pub struct RemovedMut<F>(F);
+    impl<F> ExtWorkspaceHandleV1EventHandlerMut for RemovedMut<F>
+    where
+        F: FnMut(&ExtWorkspaceHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _slf: &ExtWorkspaceHandleV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceHandleV1EventHandlerMutWithData<T> for RemovedMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtWorkspaceHandleV1 {
+        /// Creates an event handler for id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_id<F>(f: F) -> Id<F>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, &str),
+        {
+            Id(f)
+        }
+
+        /// Creates an event handler with mutable data for id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_id_with_data<T, F>(f: F) -> Id<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+        {
+            Id(f)
+        }
+
+        /// Creates an event handler for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(f: F) -> Name<F>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates an event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(f: F) -> Name<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates an event handler for coordinates events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_coordinates<F>(f: F) -> Coordinates<F>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, &[u8]),
+        {
+            Coordinates(f)
+        }
+
+        /// Creates an event handler with mutable data for coordinates events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_coordinates_with_data<T, F>(f: F) -> Coordinates<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &[u8]),
+        {
+            Coordinates(f)
+        }
+
+        /// Creates an event handler for state events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_state<F>(f: F) -> State<F>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+        {
+            State(f)
+        }
+
+        /// Creates an event handler with mutable data for state events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_state_with_data<T, F>(f: F) -> State<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+        {
+            State(f)
+        }
+
+        /// Creates an event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates an event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates an event handler for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(f: F) -> Removed<F>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates an event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(f: F) -> Removed<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates a mutable event handler for id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_id_mut<F>(f: F) -> IdMut<F>
+        where
+            F: FnMut(&ExtWorkspaceHandleV1Ref, &str),
+        {
+            IdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_id_mut_with_data<T, F>(f: F) -> IdMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+        {
+            IdMut(f)
+        }
+
+        /// Creates a mutable event handler for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut<F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&ExtWorkspaceHandleV1Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut_with_data<T, F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler for coordinates events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_coordinates_mut<F>(f: F) -> CoordinatesMut<F>
+        where
+            F: FnMut(&ExtWorkspaceHandleV1Ref, &[u8]),
+        {
+            CoordinatesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for coordinates events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_coordinates_mut_with_data<T, F>(f: F) -> CoordinatesMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, &[u8]),
+        {
+            CoordinatesMut(f)
+        }
+
+        /// Creates a mutable event handler for state events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_state_mut<F>(f: F) -> StateMut<F>
+        where
+            F: FnMut(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+        {
+            StateMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for state events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_state_mut_with_data<T, F>(f: F) -> StateMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+        {
+            StateMut(f)
+        }
+
+        /// Creates a mutable event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut<F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut_with_data<T, F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut<F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&ExtWorkspaceHandleV1Ref),
+        {
+            RemovedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut_with_data<T, F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceHandleV1Ref),
+        {
+            RemovedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                id: Ignore,
+                name: Ignore,
+                coordinates: Ignore,
+                state: Ignore,
+                capabilities: Ignore,
+                removed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtWorkspaceHandleV1EventHandler for Ignore {}
+    impl<T> ExtWorkspaceHandleV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtWorkspaceHandleV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        id: H0,
+        name: H1,
+        coordinates: H2,
+        state: H3,
+        capabilities: H4,
+        removed: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> ExtWorkspaceHandleV1EventHandler for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ExtWorkspaceHandleV1EventHandler,
+        H1: ExtWorkspaceHandleV1EventHandler,
+        H2: ExtWorkspaceHandleV1EventHandler,
+        H3: ExtWorkspaceHandleV1EventHandler,
+        H4: ExtWorkspaceHandleV1EventHandler,
+        H5: ExtWorkspaceHandleV1EventHandler,
+    {
+        #[inline]
+        fn id(&self, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+            self.id.id(_slf, id)
+        }
+
+        #[inline]
+        fn name(&self, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+            self.name.name(_slf, name)
+        }
+
+        #[inline]
+        fn coordinates(&self, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+            self.coordinates.coordinates(_slf, coordinates)
+        }
+
+        #[inline]
+        fn state(&self, _slf: &ExtWorkspaceHandleV1Ref, state: ExtWorkspaceHandleV1State) {
+            self.state.state(_slf, state)
+        }
+
+        #[inline]
+        fn capabilities(
+            &self,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ) {
+            self.capabilities.capabilities(_slf, capabilities)
+        }
+
+        #[inline]
+        fn removed(&self, _slf: &ExtWorkspaceHandleV1Ref) {
+            self.removed.removed(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> ExtWorkspaceHandleV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+        H1: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+        H2: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+        H3: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+        H4: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+        H5: ExtWorkspaceHandleV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn id(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, id: &str) {
+            self.id.id(_data, _slf, id)
+        }
+
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, name: &str) {
+            self.name.name(_data, _slf, name)
+        }
+
+        #[inline]
+        fn coordinates(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref, coordinates: &[u8]) {
+            self.coordinates.coordinates(_data, _slf, coordinates)
+        }
+
+        #[inline]
+        fn state(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            state: ExtWorkspaceHandleV1State,
+        ) {
+            self.state.state(_data, _slf, state)
+        }
+
+        #[inline]
+        fn capabilities(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceHandleV1Ref,
+            capabilities: ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ) {
+            self.capabilities.capabilities(_data, _slf, capabilities)
+        }
+
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ExtWorkspaceHandleV1Ref) {
+            self.removed.removed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for id events.
+        #[allow(dead_code)]
+        pub fn on_id<F>(self, f: F) -> Handler<Id<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, &str),
+        {
+            Handler {
+                id: Id(f),
+                name: self.name,
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for id events.
+        #[allow(dead_code)]
+        pub fn on_id_with_data<T, F>(self, f: F) -> Handler<Id<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+        {
+            Handler {
+                id: Id(f),
+                name: self.name,
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for name events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(self, f: F) -> Handler<H0, Name<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, &str),
+        {
+            Handler {
+                id: self.id,
+                name: Name(f),
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for name events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(self, f: F) -> Handler<H0, Name<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &str),
+        {
+            Handler {
+                id: self.id,
+                name: Name(f),
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for coordinates events.
+        #[allow(dead_code)]
+        pub fn on_coordinates<F>(self, f: F) -> Handler<H0, H1, Coordinates<F>, H3, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, &[u8]),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: Coordinates(f),
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for coordinates events.
+        #[allow(dead_code)]
+        pub fn on_coordinates_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, Coordinates<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, &[u8]),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: Coordinates(f),
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for state events.
+        #[allow(dead_code)]
+        pub fn on_state<F>(self, f: F) -> Handler<H0, H1, H2, State<F>, H4, H5>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: self.coordinates,
+                state: State(f),
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for state events.
+        #[allow(dead_code)]
+        pub fn on_state_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, State<F>, H4, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1State),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: self.coordinates,
+                state: State(f),
+                capabilities: self.capabilities,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(self, f: F) -> Handler<H0, H1, H2, H3, Capabilities<F>, H5>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: Capabilities(f),
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, Capabilities<F>, H5>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref, ExtWorkspaceHandleV1WorkspaceCapabilities),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: Capabilities(f),
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Removed<F>>
+        where
+            F: Fn(&ExtWorkspaceHandleV1Ref),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: Removed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Removed<F>>
+        where
+            F: Fn(&mut T, &ExtWorkspaceHandleV1Ref),
+        {
+            Handler {
+                id: self.id,
+                name: self.name,
+                coordinates: self.coordinates,
+                state: self.state,
+                capabilities: self.capabilities,
+                removed: Removed(f),
+            }
+        }
+    }
+}
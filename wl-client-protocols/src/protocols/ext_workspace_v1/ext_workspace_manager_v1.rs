@@ -0,0 +1,1455 @@
+//! list and control workspaces
+//!
+//! Workspaces, also called virtual desktops, are groups of surfaces. A
+//! compositor with a concept of workspaces may only show some such groups of
+//! surfaces (those of 'active' workspaces) at a time. 'Activating' a
+//! workspace is a request for the compositor to display that workspace's
+//! surfaces as normal, whereas the compositor may hide or otherwise
+//! de-emphasise surfaces that are associated only with 'inactive' workspaces.
+//! Workspaces are grouped by which sets of outputs they correspond to, and
+//! may contain surfaces only from those outputs. In this way, it is possible
+//! for each output to have its own set of workspaces, or for all outputs (or
+//! any other arbitrary grouping) to share workspaces. Compositors may
+//! optionally conceptually arrange each group of workspaces in an
+//! N-dimensional grid.
+//!
+//! The purpose of this protocol is to enable the creation of taskbars and
+//! docks by providing them with a list of workspaces and their properties,
+//! and allowing them to activate and deactivate workspaces.
+//!
+//! After a client binds the ext_workspace_manager_v1, each workspace will be
+//! sent via the workspace event.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"ext_workspace_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"commit".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"stop".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 4,
+    events: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"workspace_group".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtWorkspaceGroupHandleV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"workspace".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ExtWorkspaceHandleV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"finished".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the ext_workspace_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "ext_workspace_manager_v1",
+    protocol: "ext_workspace_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "commit",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "stop",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "workspace_group",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "workspace_group",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_workspace_group_handle_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "workspace",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "workspace",
+                ty: info::ArgType::NewId,
+                interface: Some("ext_workspace_handle_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "finished",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned ext_workspace_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtWorkspaceManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed ext_workspace_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ExtWorkspaceManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ExtWorkspaceManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ExtWorkspaceManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ExtWorkspaceManagerV1 {
+    const INTERFACE: &'static str = "ext_workspace_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ExtWorkspaceManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ExtWorkspaceManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ExtWorkspaceManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ExtWorkspaceManagerV1Ref {
+    type Owned = ExtWorkspaceManagerV1;
+}
+
+impl Deref for ExtWorkspaceManagerV1 {
+    type Target = ExtWorkspaceManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ExtWorkspaceManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_workspace_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ExtWorkspaceManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ext_workspace_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ExtWorkspaceManagerV1Ref> for ExtWorkspaceManagerV1 {
+    fn eq(&self, other: &ExtWorkspaceManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ExtWorkspaceManagerV1> for ExtWorkspaceManagerV1Ref {
+    fn eq(&self, other: &ExtWorkspaceManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ExtWorkspaceManagerV1Ref {
+    /// all requests about the workspaces have been sent
+    ///
+    /// The client must send this request after it has finished sending other
+    /// requests. The compositor must process a series of requests preceding a
+    /// commit request atomically.
+    ///
+    /// This allows changes to the workspace properties to be seen as atomic,
+    /// even if they happen via multiple events, and even if they involve
+    /// multiple ext_workspace_handle_v1 objects, for example, deactivating one
+    /// workspace and activating another.
+    #[inline]
+    pub fn commit(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+
+    /// stop sending events
+    ///
+    /// Indicates the client no longer wishes to receive events for new
+    /// workspace groups. However the compositor may emit further workspace
+    /// events, until the finished event is emitted. The compositor is expected
+    /// to send the finished event eventually once the stop request has been processed.
+    ///
+    /// The client must not send any requests after this one, doing so will raise a wl_display
+    /// invalid_object error.
+    #[inline]
+    pub fn stop(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+impl ExtWorkspaceManagerV1 {
+    /// Since when the workspace_group event is available.
+    #[allow(dead_code)]
+    pub const EVT__WORKSPACE_GROUP__SINCE: u32 = 1;
+
+    /// Since when the workspace event is available.
+    #[allow(dead_code)]
+    pub const EVT__WORKSPACE__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the finished event is available.
+    #[allow(dead_code)]
+    pub const EVT__FINISHED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ExtWorkspaceManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ExtWorkspaceManagerV1EventHandler {
+    /// a workspace group has been created
+    ///
+    /// This event is emitted whenever a new workspace group has been created.
+    ///
+    /// All initial details of the workspace group (outputs) will be
+    /// sent immediately after this event via the corresponding events in
+    /// ext_workspace_group_handle_v1 and ext_workspace_handle_v1.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace_group`:
+    #[inline]
+    fn workspace_group(
+        &self,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        let _ = workspace_group;
+    }
+
+    /// workspace has been created
+    ///
+    /// This event is emitted whenever a new workspace has been created.
+    ///
+    /// All initial details of the workspace (name, coordinates, state) will
+    /// be sent immediately after this event via the corresponding events in
+    /// ext_workspace_handle_v1.
+    ///
+    /// Workspaces start off unassigned to any workspace group.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    #[inline]
+    fn workspace(&self, _slf: &ExtWorkspaceManagerV1Ref, workspace: ExtWorkspaceHandleV1) {
+        let _ = workspace;
+    }
+
+    /// all information about the workspaces and workspace groups has been sent
+    ///
+    /// This event is sent after all changes in all workspaces and workspace groups have been
+    /// sent.
+    ///
+    /// This allows changes to one or more ext_workspace_group_handle_v1
+    /// properties and ext_workspace_handle_v1 properties
+    /// to be seen as atomic, even if they happen via multiple events.
+    /// In particular, an output moving from one workspace group to
+    /// another sends an output_enter event and an output_leave event to the two
+    /// ext_workspace_group_handle_v1 objects in question. The compositor sends
+    /// the done event only after updating the output information in both
+    /// workspace groups.
+    #[inline]
+    fn done(&self, _slf: &ExtWorkspaceManagerV1Ref) {}
+
+    /// the compositor has finished with the workspace_manager
+    ///
+    /// This event indicates that the compositor is done sending events to the
+    /// ext_workspace_manager_v1. The server will destroy the object
+    /// immediately after sending this request.
+    #[inline]
+    fn finished(&self, _slf: &ExtWorkspaceManagerV1Ref) {}
+}
+
+impl ExtWorkspaceManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ExtWorkspaceManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtWorkspaceManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ExtWorkspaceManagerV1EventHandlerWithData<T> {
+    /// a workspace group has been created
+    ///
+    /// This event is emitted whenever a new workspace group has been created.
+    ///
+    /// All initial details of the workspace group (outputs) will be
+    /// sent immediately after this event via the corresponding events in
+    /// ext_workspace_group_handle_v1 and ext_workspace_handle_v1.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace_group`:
+    #[inline]
+    fn workspace_group(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        let _ = workspace_group;
+    }
+
+    /// workspace has been created
+    ///
+    /// This event is emitted whenever a new workspace has been created.
+    ///
+    /// All initial details of the workspace (name, coordinates, state) will
+    /// be sent immediately after this event via the corresponding events in
+    /// ext_workspace_handle_v1.
+    ///
+    /// Workspaces start off unassigned to any workspace group.
+    ///
+    /// # Arguments
+    ///
+    /// - `workspace`:
+    #[inline]
+    fn workspace(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace: ExtWorkspaceHandleV1,
+    ) {
+        let _ = workspace;
+    }
+
+    /// all information about the workspaces and workspace groups has been sent
+    ///
+    /// This event is sent after all changes in all workspaces and workspace groups have been
+    /// sent.
+    ///
+    /// This allows changes to one or more ext_workspace_group_handle_v1
+    /// properties and ext_workspace_handle_v1 properties
+    /// to be seen as atomic, even if they happen via multiple events.
+    /// In particular, an output moving from one workspace group to
+    /// another sends an output_enter event and an output_leave event to the two
+    /// ext_workspace_group_handle_v1 objects in question. The compositor sends
+    /// the done event only after updating the output information in both
+    /// workspace groups.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {}
+
+    /// the compositor has finished with the workspace_manager
+    ///
+    /// This event indicates that the compositor is done sending events to the
+    /// ext_workspace_manager_v1. The server will destroy the object
+    /// immediately after sending this request.
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {}
+}
+
+/// A mutable event handler for [ExtWorkspaceManagerV1] proxies.
+///
+/// This trait is the same as [ExtWorkspaceManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtWorkspaceManagerV1EventHandlerMut {
+    /// See [ExtWorkspaceManagerV1EventHandler::workspace_group].
+    #[inline]
+    fn workspace_group(
+        &mut self,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        let _ = workspace_group;
+    }
+
+    /// See [ExtWorkspaceManagerV1EventHandler::workspace].
+    #[inline]
+    fn workspace(&mut self, _slf: &ExtWorkspaceManagerV1Ref, workspace: ExtWorkspaceHandleV1) {
+        let _ = workspace;
+    }
+
+    /// See [ExtWorkspaceManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ExtWorkspaceManagerV1Ref) {}
+
+    /// See [ExtWorkspaceManagerV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _slf: &ExtWorkspaceManagerV1Ref) {}
+}
+
+/// A mutable event handler for [ExtWorkspaceManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ExtWorkspaceManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ExtWorkspaceManagerV1EventHandlerMutWithData<T> {
+    /// See [ExtWorkspaceManagerV1EventHandler::workspace_group].
+    #[inline]
+    fn workspace_group(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        let _ = workspace_group;
+    }
+
+    /// See [ExtWorkspaceManagerV1EventHandler::workspace].
+    #[inline]
+    fn workspace(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace: ExtWorkspaceHandleV1,
+    ) {
+        let _ = workspace;
+    }
+
+    /// See [ExtWorkspaceManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {}
+
+    /// See [ExtWorkspaceManagerV1EventHandler::finished].
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {}
+}
+
+impl<H> ExtWorkspaceManagerV1EventHandler for MutEventHandler<H>
+where
+    H: ExtWorkspaceManagerV1EventHandlerMut,
+{
+    #[inline]
+    fn workspace_group(
+        &self,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        self.with(|h| h.workspace_group(_slf, workspace_group))
+    }
+
+    #[inline]
+    fn workspace(&self, _slf: &ExtWorkspaceManagerV1Ref, workspace: ExtWorkspaceHandleV1) {
+        self.with(|h| h.workspace(_slf, workspace))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ExtWorkspaceManagerV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn finished(&self, _slf: &ExtWorkspaceManagerV1Ref) {
+        self.with(|h| h.finished(_slf))
+    }
+}
+
+impl<T, H> ExtWorkspaceManagerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ExtWorkspaceManagerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn workspace_group(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        self.with(|h| h.workspace_group(_data, _slf, workspace_group))
+    }
+
+    #[inline]
+    fn workspace(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace: ExtWorkspaceHandleV1,
+    ) {
+        self.with(|h| h.workspace(_data, _slf, workspace))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+        self.with(|h| h.finished(_data, _slf))
+    }
+}
+
+impl<T, U, H> ExtWorkspaceManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtWorkspaceManagerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn workspace_group(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler().workspace_group(_data, _slf, workspace_group)
+    }
+
+    #[inline]
+    fn workspace(
+        &self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace: ExtWorkspaceHandleV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler().workspace(_data, _slf, workspace)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn finished(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().finished(_data, _slf)
+    }
+}
+
+impl<T, U, H> ExtWorkspaceManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ExtWorkspaceManagerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn workspace_group(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace_group: ExtWorkspaceGroupHandleV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .workspace_group(_data, _slf, workspace_group)
+    }
+
+    #[inline]
+    fn workspace(
+        &mut self,
+        _data: &mut T,
+        _slf: &ExtWorkspaceManagerV1Ref,
+        workspace: ExtWorkspaceHandleV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().workspace(_data, _slf, workspace)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn finished(&mut self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().finished(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ExtWorkspaceManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtWorkspaceManagerV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtWorkspaceGroupHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtWorkspaceGroupHandleV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceGroupHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ExtWorkspaceGroupHandleV1>(arg0)
+                };
+                self.0.workspace_group(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtWorkspaceHandleV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ExtWorkspaceHandleV1>(arg0) };
+                self.0.workspace(slf, arg0);
+            }
+            2 => {
+                self.0.done(slf);
+            }
+            3 => {
+                self.0.finished(slf);
+            }
+            _ => {
+                invalid_opcode("ext_workspace_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ExtWorkspaceManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ExtWorkspaceManagerV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtWorkspaceGroupHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtWorkspaceGroupHandleV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceGroupHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ExtWorkspaceGroupHandleV1>(arg0)
+                };
+                self.0.workspace_group(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ExtWorkspaceHandleV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ExtWorkspaceHandleV1::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ExtWorkspaceHandleV1>(arg0) };
+                self.0.workspace(data, slf, arg0);
+            }
+            2 => {
+                self.0.done(data, slf);
+            }
+            3 => {
+                self.0.finished(data, slf);
+            }
+            _ => {
+                invalid_opcode("ext_workspace_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ExtWorkspaceManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ExtWorkspaceManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for workspace_group events.
+    pub struct WorkspaceGroup<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandler for WorkspaceGroup<F>
+    where
+        F: Fn(&ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+    {
+        #[inline]
+        fn workspace_group(
+            &self,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace_group: ExtWorkspaceGroupHandleV1,
+        ) {
+            self.0(_slf, workspace_group)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerWithData<T> for WorkspaceGroup<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+    {
+        #[inline]
+        fn workspace_group(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace_group: ExtWorkspaceGroupHandleV1,
+        ) {
+            self.0(_data, _slf, workspace_group)
+        }
+    }
+
+    /// Event handler for workspace events.
+    pub struct Workspace<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandler for Workspace<F>
+    where
+        F: Fn(&ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+    {
+        #[inline]
+        fn workspace(&self, _slf: &ExtWorkspaceManagerV1Ref, workspace: ExtWorkspaceHandleV1) {
+            self.0(_slf, workspace)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerWithData<T> for Workspace<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+    {
+        #[inline]
+        fn workspace(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace: ExtWorkspaceHandleV1,
+        ) {
+            self.0(_data, _slf, workspace)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandler for Done<F>
+    where
+        F: Fn(&ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for finished events.
+    pub struct Finished<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandler for Finished<F>
+    where
+        F: Fn(&ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerWithData<T> for Finished<F>
+    where
+        F: Fn(&mut T, &ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for workspace_group events.
+    pub struct WorkspaceGroupMut<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandlerMut for WorkspaceGroupMut<F>
+    where
+        F: FnMut(&ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+    {
+        #[inline]
+        fn workspace_group(
+            &mut self,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace_group: ExtWorkspaceGroupHandleV1,
+        ) {
+            self.0(_slf, workspace_group)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerMutWithData<T> for WorkspaceGroupMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+    {
+        #[inline]
+        fn workspace_group(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace_group: ExtWorkspaceGroupHandleV1,
+        ) {
+            self.0(_data, _slf, workspace_group)
+        }
+    }
+
+    /// Mutable event handler for workspace events.
+    pub struct WorkspaceMut<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandlerMut for WorkspaceMut<F>
+    where
+        F: FnMut(&ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+    {
+        #[inline]
+        fn workspace(&mut self, _slf: &ExtWorkspaceManagerV1Ref, workspace: ExtWorkspaceHandleV1) {
+            self.0(_slf, workspace)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerMutWithData<T> for WorkspaceMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+    {
+        #[inline]
+        fn workspace(
+            &mut self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace: ExtWorkspaceHandleV1,
+        ) {
+            self.0(_data, _slf, workspace)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for finished events.
+    pub struct FinishedMut<F>(F);
+    impl<F> ExtWorkspaceManagerV1EventHandlerMut for FinishedMut<F>
+    where
+        F: FnMut(&ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ExtWorkspaceManagerV1EventHandlerMutWithData<T> for FinishedMut<F>
+    where
+        F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref),
+    {
+        #[inline]
+        fn finished(&mut self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ExtWorkspaceManagerV1 {
+        /// Creates an event handler for workspace_group events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_group<F>(f: F) -> WorkspaceGroup<F>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+        {
+            WorkspaceGroup(f)
+        }
+
+        /// Creates an event handler with mutable data for workspace_group events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_group_with_data<T, F>(f: F) -> WorkspaceGroup<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+        {
+            WorkspaceGroup(f)
+        }
+
+        /// Creates an event handler for workspace events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace<F>(f: F) -> Workspace<F>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+        {
+            Workspace(f)
+        }
+
+        /// Creates an event handler with mutable data for workspace events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_workspace_with_data<T, F>(f: F) -> Workspace<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+        {
+            Workspace(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(f: F) -> Finished<F>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates an event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(f: F) -> Finished<F>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref),
+        {
+            Finished(f)
+        }
+
+        /// Creates a mutable event handler for workspace_group events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_group_mut<F>(f: F) -> WorkspaceGroupMut<F>
+        where
+            F: FnMut(&ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+        {
+            WorkspaceGroupMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for workspace_group events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_group_mut_with_data<T, F>(f: F) -> WorkspaceGroupMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+        {
+            WorkspaceGroupMut(f)
+        }
+
+        /// Creates a mutable event handler for workspace events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_mut<F>(f: F) -> WorkspaceMut<F>
+        where
+            F: FnMut(&ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+        {
+            WorkspaceMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for workspace events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_workspace_mut_with_data<T, F>(f: F) -> WorkspaceMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+        {
+            WorkspaceMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ExtWorkspaceManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut<F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&ExtWorkspaceManagerV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_finished_mut_with_data<T, F>(f: F) -> FinishedMut<F>
+        where
+            F: FnMut(&mut T, &ExtWorkspaceManagerV1Ref),
+        {
+            FinishedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                workspace_group: Ignore,
+                workspace: Ignore,
+                done: Ignore,
+                finished: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ExtWorkspaceManagerV1EventHandler for Ignore {}
+    impl<T> ExtWorkspaceManagerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ExtWorkspaceManagerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        workspace_group: H0,
+        workspace: H1,
+        done: H2,
+        finished: H3,
+    }
+
+    impl<H0, H1, H2, H3> ExtWorkspaceManagerV1EventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: ExtWorkspaceManagerV1EventHandler,
+        H1: ExtWorkspaceManagerV1EventHandler,
+        H2: ExtWorkspaceManagerV1EventHandler,
+        H3: ExtWorkspaceManagerV1EventHandler,
+    {
+        #[inline]
+        fn workspace_group(
+            &self,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace_group: ExtWorkspaceGroupHandleV1,
+        ) {
+            self.workspace_group.workspace_group(_slf, workspace_group)
+        }
+
+        #[inline]
+        fn workspace(&self, _slf: &ExtWorkspaceManagerV1Ref, workspace: ExtWorkspaceHandleV1) {
+            self.workspace.workspace(_slf, workspace)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn finished(&self, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.finished.finished(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> ExtWorkspaceManagerV1EventHandlerWithData<T> for Handler<H0, H1, H2, H3>
+    where
+        H0: ExtWorkspaceManagerV1EventHandlerWithData<T>,
+        H1: ExtWorkspaceManagerV1EventHandlerWithData<T>,
+        H2: ExtWorkspaceManagerV1EventHandlerWithData<T>,
+        H3: ExtWorkspaceManagerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn workspace_group(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace_group: ExtWorkspaceGroupHandleV1,
+        ) {
+            self.workspace_group
+                .workspace_group(_data, _slf, workspace_group)
+        }
+
+        #[inline]
+        fn workspace(
+            &self,
+            _data: &mut T,
+            _slf: &ExtWorkspaceManagerV1Ref,
+            workspace: ExtWorkspaceHandleV1,
+        ) {
+            self.workspace.workspace(_data, _slf, workspace)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn finished(&self, _data: &mut T, _slf: &ExtWorkspaceManagerV1Ref) {
+            self.finished.finished(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for workspace_group events.
+        #[allow(dead_code)]
+        pub fn on_workspace_group<F>(self, f: F) -> Handler<WorkspaceGroup<F>, H1, H2, H3>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+        {
+            Handler {
+                workspace_group: WorkspaceGroup(f),
+                workspace: self.workspace,
+                done: self.done,
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for workspace_group events.
+        #[allow(dead_code)]
+        pub fn on_workspace_group_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<WorkspaceGroup<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceGroupHandleV1),
+        {
+            Handler {
+                workspace_group: WorkspaceGroup(f),
+                workspace: self.workspace,
+                done: self.done,
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure for workspace events.
+        #[allow(dead_code)]
+        pub fn on_workspace<F>(self, f: F) -> Handler<H0, Workspace<F>, H2, H3>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+        {
+            Handler {
+                workspace_group: self.workspace_group,
+                workspace: Workspace(f),
+                done: self.done,
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for workspace events.
+        #[allow(dead_code)]
+        pub fn on_workspace_with_data<T, F>(self, f: F) -> Handler<H0, Workspace<F>, H2, H3>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref, ExtWorkspaceHandleV1),
+        {
+            Handler {
+                workspace_group: self.workspace_group,
+                workspace: Workspace(f),
+                done: self.done,
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, Done<F>, H3>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref),
+        {
+            Handler {
+                workspace_group: self.workspace_group,
+                workspace: self.workspace,
+                done: Done(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, Done<F>, H3>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref),
+        {
+            Handler {
+                workspace_group: self.workspace_group,
+                workspace: self.workspace,
+                done: Done(f),
+                finished: self.finished,
+            }
+        }
+
+        /// Sets the closure for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished<F>(self, f: F) -> Handler<H0, H1, H2, Finished<F>>
+        where
+            F: Fn(&ExtWorkspaceManagerV1Ref),
+        {
+            Handler {
+                workspace_group: self.workspace_group,
+                workspace: self.workspace,
+                done: self.done,
+                finished: Finished(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for finished events.
+        #[allow(dead_code)]
+        pub fn on_finished_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Finished<F>>
+        where
+            F: Fn(&mut T, &ExtWorkspaceManagerV1Ref),
+        {
+            Handler {
+                workspace_group: self.workspace_group,
+                workspace: self.workspace,
+                done: self.done,
+                finished: Finished(f),
+            }
+        }
+    }
+}
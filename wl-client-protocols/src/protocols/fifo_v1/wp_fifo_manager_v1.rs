@@ -0,0 +1,441 @@
+//! protocol for fifo constraints
+//!
+//! When a Wayland compositor considers applying a content update,
+//! it must ensure all the update's readiness constraints (fences, etc)
+//! are met.
+//!
+//! This protocol provides a way to use the completion of a display refresh
+//! cycle as an additional readiness constraint.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_fifo_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_fifo".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(WpFifoV1::WL_INTERFACE), Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_fifo_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_fifo_manager_v1",
+    protocol: "fifo_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_fifo",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_fifo_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "already_exists",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_fifo_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpFifoManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_fifo_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpFifoManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpFifoManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpFifoManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpFifoManagerV1 {
+    const INTERFACE: &'static str = "wp_fifo_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpFifoManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpFifoManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpFifoManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpFifoManagerV1Ref {
+    type Owned = WpFifoManagerV1;
+}
+
+impl Deref for WpFifoManagerV1 {
+    type Target = WpFifoManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpFifoManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_fifo_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpFifoManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_fifo_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpFifoManagerV1Ref> for WpFifoManagerV1 {
+    fn eq(&self, other: &WpFifoManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpFifoManagerV1> for WpFifoManagerV1Ref {
+    fn eq(&self, other: &WpFifoManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpFifoManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// unbind from the manager interface
+    ///
+    /// Informs the server that the client will no longer be using
+    /// this protocol object. Existing objects created by this object
+    /// are not affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_fifo request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_FIFO__SINCE: u32 = 1;
+
+    /// request fifo interface for surface
+    ///
+    /// Establish a fifo object for a surface that may be used to add
+    /// display refresh constraints to content updates.
+    ///
+    /// Only one such object may exist for a surface and attempting
+    /// to create more than one will result in an already_exists
+    /// protocol error. If a surface is acted on by multiple software
+    /// components, general best practice is that only the component
+    /// performing wl_surface.attach operations should use this protocol.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_fifo(&self, surface: &WlSurfaceRef) -> WpFifoV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, WpFifoV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpFifoV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpFifoManagerV1Ref {
+    /// request fifo interface for surface
+    ///
+    /// Establish a fifo object for a surface that may be used to add
+    /// display refresh constraints to content updates.
+    ///
+    /// Only one such object may exist for a surface and attempting
+    /// to create more than one will result in an already_exists
+    /// protocol error. If a surface is acted on by multiple software
+    /// components, general best practice is that only the component
+    /// performing wl_surface.attach operations should use this protocol.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_fifo(&self, _queue: &Queue, surface: &WlSurfaceRef) -> WpFifoV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, WpFifoV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WpFifoV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpFifoManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpFifoManagerV1EventHandler {}
+
+impl WpFifoManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpFifoManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpFifoManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpFifoManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpFifoManagerV1] proxies.
+///
+/// This trait is the same as [WpFifoManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpFifoManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpFifoManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpFifoManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpFifoManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpFifoManagerV1EventHandler for MutEventHandler<H> where H: WpFifoManagerV1EventHandlerMut {}
+
+impl<T, H> WpFifoManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpFifoManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpFifoManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpFifoManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpFifoManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpFifoManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpFifoManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_fifo_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpFifoManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_fifo_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpFifoManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpFifoManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpFifoManagerV1 {
+    /// Since when the error.already_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_EXISTS__SINCE: u32 = 1;
+}
+
+/// fatal presentation error
+///
+/// These fatal protocol errors may be emitted in response to
+/// illegal requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpFifoManagerV1Error(pub u32);
+
+impl WpFifoManagerV1Error {
+    /// fifo manager already exists for surface
+    #[allow(dead_code)]
+    pub const ALREADY_EXISTS: Self = Self(0);
+}
+
+impl Debug for WpFifoManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_EXISTS => "ALREADY_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpFifoManagerV1 {}
+}
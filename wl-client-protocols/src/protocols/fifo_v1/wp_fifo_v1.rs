@@ -0,0 +1,424 @@
+//! fifo interface
+//!
+//! A fifo object for a surface that may be used to add
+//! display refresh constraints to content updates.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_fifo_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"set_barrier".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"wait_barrier".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_fifo_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_fifo_v1",
+    protocol: "fifo_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_barrier",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "wait_barrier",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "surface_destroyed",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_fifo_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpFifoV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_fifo_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpFifoV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpFifoV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpFifoV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpFifoV1 {
+    const INTERFACE: &'static str = "wp_fifo_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpFifoV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpFifoV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpFifoV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpFifoV1Ref {
+    type Owned = WpFifoV1;
+}
+
+impl Deref for WpFifoV1 {
+    type Target = WpFifoV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpFifoV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_fifo_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpFifoV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_fifo_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpFifoV1Ref> for WpFifoV1 {
+    fn eq(&self, other: &WpFifoV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpFifoV1> for WpFifoV1Ref {
+    fn eq(&self, other: &WpFifoV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpFifoV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the fifo interface
+    ///
+    /// Informs the server that the client will no longer be using
+    /// this protocol object.
+    ///
+    /// Surface state changes previously made by this protocol are
+    /// unaffected by this object's destruction.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(2, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpFifoV1Ref {
+    /// sets the start point for a fifo constraint
+    ///
+    /// When the content update containing the "set_barrier" is applied,
+    /// it sets a "fifo_barrier" condition on the surface associated with
+    /// the fifo object. The condition is cleared immediately after the
+    /// following latching deadline for non-tearing presentation.
+    ///
+    /// The compositor may clear the condition early if it must do so to
+    /// ensure client forward progress assumptions.
+    ///
+    /// To wait for this condition to clear, use the "wait_barrier" request.
+    ///
+    /// "set_barrier" is double-buffered state, see wl_surface.commit.
+    ///
+    /// Requesting set_barrier after the fifo object's surface is
+    /// destroyed will generate a "surface_destroyed" error.
+    #[inline]
+    pub fn set_barrier(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+
+    /// adds a fifo constraint to a content update
+    ///
+    /// Indicate that this content update is not ready while a
+    /// "fifo_barrier" condition is present on the surface.
+    ///
+    /// This means that when the content update containing "set_barrier"
+    /// was made active at a latching deadline, it will be active for
+    /// at least one refresh cycle. A content update which is allowed to
+    /// tear might become active after a latching deadline if no content
+    /// update became active at the deadline.
+    ///
+    /// The constraint must be ignored if the surface is a subsurface in
+    /// synchronized mode. If the surface is not being updated by the
+    /// compositor (off-screen, occluded) the compositor may ignore the
+    /// constraint. Clients must use an additional mechanism such as
+    /// frame callbacks or timestamps to ensure throttling occurs under
+    /// all conditions.
+    ///
+    /// "wait_barrier" is double-buffered state, see wl_surface.commit.
+    ///
+    /// Requesting "wait_barrier" after the fifo object's surface is
+    /// destroyed will generate a "surface_destroyed" error.
+    #[inline]
+    pub fn wait_barrier(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpFifoV1] proxies.
+#[allow(dead_code)]
+pub trait WpFifoV1EventHandler {}
+
+impl WpFifoV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpFifoV1] proxies with mutable data.
+///
+/// This trait is the same as [WpFifoV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpFifoV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpFifoV1] proxies.
+///
+/// This trait is the same as [WpFifoV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpFifoV1EventHandlerMut {}
+
+/// A mutable event handler for [WpFifoV1] proxies with mutable data.
+///
+/// This trait is the same as [WpFifoV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpFifoV1EventHandlerMutWithData<T> {}
+
+impl<H> WpFifoV1EventHandler for MutEventHandler<H> where H: WpFifoV1EventHandlerMut {}
+
+impl<T, H> WpFifoV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpFifoV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpFifoV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpFifoV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpFifoV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpFifoV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpFifoV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_fifo_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpFifoV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_fifo_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpFifoV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpFifoV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpFifoV1 {
+    /// Since when the error.surface_destroyed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SURFACE_DESTROYED__SINCE: u32 = 1;
+}
+
+/// fatal error
+///
+/// These fatal protocol errors may be emitted in response to
+/// illegal requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpFifoV1Error(pub u32);
+
+impl WpFifoV1Error {
+    /// the associated surface no longer exists
+    #[allow(dead_code)]
+    pub const SURFACE_DESTROYED: Self = Self(0);
+}
+
+impl Debug for WpFifoV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::SURFACE_DESTROYED => "SURFACE_DESTROYED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpFifoV1 {}
+}
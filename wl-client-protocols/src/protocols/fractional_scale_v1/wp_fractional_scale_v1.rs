@@ -0,0 +1,569 @@
+//! fractional scale interface to a wl_surface
+//!
+//! An additional interface to a wl_surface object which allows the compositor
+//! to inform the client of the preferred scale.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_fractional_scale_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"preferred_scale".as_ptr(),
+            signature: c"u".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_fractional_scale_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_fractional_scale_v1",
+    protocol: "fractional_scale_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[info::MessageInfo {
+        name: "preferred_scale",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "scale",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[],
+};
+
+/// An owned wp_fractional_scale_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpFractionalScaleV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_fractional_scale_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpFractionalScaleV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpFractionalScaleV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpFractionalScaleV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpFractionalScaleV1 {
+    const INTERFACE: &'static str = "wp_fractional_scale_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpFractionalScaleV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpFractionalScaleV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpFractionalScaleV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpFractionalScaleV1Ref {
+    type Owned = WpFractionalScaleV1;
+}
+
+impl Deref for WpFractionalScaleV1 {
+    type Target = WpFractionalScaleV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpFractionalScaleV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_fractional_scale_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpFractionalScaleV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_fractional_scale_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpFractionalScaleV1Ref> for WpFractionalScaleV1 {
+    fn eq(&self, other: &WpFractionalScaleV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpFractionalScaleV1> for WpFractionalScaleV1Ref {
+    fn eq(&self, other: &WpFractionalScaleV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpFractionalScaleV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// remove surface scale information for surface
+    ///
+    /// Destroy the fractional scale object. When this object is destroyed,
+    /// preferred_scale events will no longer be sent.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl WpFractionalScaleV1 {
+    /// Since when the preferred_scale event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREFERRED_SCALE__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpFractionalScaleV1] proxies.
+#[allow(dead_code)]
+pub trait WpFractionalScaleV1EventHandler {
+    /// notify of new preferred scale
+    ///
+    /// Notification of a new preferred scale for this surface that the
+    /// compositor suggests that the client should use.
+    ///
+    /// The sent scale is the numerator of a fraction with a denominator of 120.
+    ///
+    /// # Arguments
+    ///
+    /// - `scale`: the new preferred scale
+    #[inline]
+    fn preferred_scale(&self, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        let _ = scale;
+    }
+}
+
+impl WpFractionalScaleV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpFractionalScaleV1] proxies with mutable data.
+///
+/// This trait is the same as [WpFractionalScaleV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpFractionalScaleV1EventHandlerWithData<T> {
+    /// notify of new preferred scale
+    ///
+    /// Notification of a new preferred scale for this surface that the
+    /// compositor suggests that the client should use.
+    ///
+    /// The sent scale is the numerator of a fraction with a denominator of 120.
+    ///
+    /// # Arguments
+    ///
+    /// - `scale`: the new preferred scale
+    #[inline]
+    fn preferred_scale(&self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        let _ = scale;
+    }
+}
+
+/// A mutable event handler for [WpFractionalScaleV1] proxies.
+///
+/// This trait is the same as [WpFractionalScaleV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpFractionalScaleV1EventHandlerMut {
+    /// See [WpFractionalScaleV1EventHandler::preferred_scale].
+    #[inline]
+    fn preferred_scale(&mut self, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        let _ = scale;
+    }
+}
+
+/// A mutable event handler for [WpFractionalScaleV1] proxies with mutable data.
+///
+/// This trait is the same as [WpFractionalScaleV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpFractionalScaleV1EventHandlerMutWithData<T> {
+    /// See [WpFractionalScaleV1EventHandler::preferred_scale].
+    #[inline]
+    fn preferred_scale(&mut self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        let _ = scale;
+    }
+}
+
+impl<H> WpFractionalScaleV1EventHandler for MutEventHandler<H>
+where
+    H: WpFractionalScaleV1EventHandlerMut,
+{
+    #[inline]
+    fn preferred_scale(&self, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        self.with(|h| h.preferred_scale(_slf, scale))
+    }
+}
+
+impl<T, H> WpFractionalScaleV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpFractionalScaleV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn preferred_scale(&self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        self.with(|h| h.preferred_scale(_data, _slf, scale))
+    }
+}
+
+impl<T, U, H> WpFractionalScaleV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpFractionalScaleV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn preferred_scale(&self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        let _data = self.project(_data);
+        self.handler().preferred_scale(_data, _slf, scale)
+    }
+}
+
+impl<T, U, H> WpFractionalScaleV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpFractionalScaleV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn preferred_scale(&mut self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().preferred_scale(_data, _slf, scale)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpFractionalScaleV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpFractionalScaleV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.preferred_scale(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wp_fractional_scale_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpFractionalScaleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpFractionalScaleV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.preferred_scale(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wp_fractional_scale_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpFractionalScaleV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpFractionalScaleV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for preferred_scale events.
+    pub struct PreferredScale<F>(F);
+    impl<F> WpFractionalScaleV1EventHandler for PreferredScale<F>
+    where
+        F: Fn(&WpFractionalScaleV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_scale(&self, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+            self.0(_slf, scale)
+        }
+    }
+    impl<T, F> WpFractionalScaleV1EventHandlerWithData<T> for PreferredScale<F>
+    where
+        F: Fn(&mut T, &WpFractionalScaleV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_scale(&self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+            self.0(_data, _slf, scale)
+        }
+    }
+
+    /// Mutable event handler for preferred_scale events.
+    pub struct PreferredScaleMut<F>(F);
+    impl<F> WpFractionalScaleV1EventHandlerMut for PreferredScaleMut<F>
+    where
+        F: FnMut(&WpFractionalScaleV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_scale(&mut self, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+            self.0(_slf, scale)
+        }
+    }
+    impl<T, F> WpFractionalScaleV1EventHandlerMutWithData<T> for PreferredScaleMut<F>
+    where
+        F: FnMut(&mut T, &WpFractionalScaleV1Ref, u32),
+    {
+        #[inline]
+        fn preferred_scale(&mut self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+            self.0(_data, _slf, scale)
+        }
+    }
+
+    impl WpFractionalScaleV1 {
+        /// Creates an event handler for preferred_scale events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_scale<F>(f: F) -> PreferredScale<F>
+        where
+            F: Fn(&WpFractionalScaleV1Ref, u32),
+        {
+            PreferredScale(f)
+        }
+
+        /// Creates an event handler with mutable data for preferred_scale events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_scale_with_data<T, F>(f: F) -> PreferredScale<F>
+        where
+            F: Fn(&mut T, &WpFractionalScaleV1Ref, u32),
+        {
+            PreferredScale(f)
+        }
+
+        /// Creates a mutable event handler for preferred_scale events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_scale_mut<F>(f: F) -> PreferredScaleMut<F>
+        where
+            F: FnMut(&WpFractionalScaleV1Ref, u32),
+        {
+            PreferredScaleMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preferred_scale events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_scale_mut_with_data<T, F>(f: F) -> PreferredScaleMut<F>
+        where
+            F: FnMut(&mut T, &WpFractionalScaleV1Ref, u32),
+        {
+            PreferredScaleMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                preferred_scale: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpFractionalScaleV1EventHandler for Ignore {}
+    impl<T> WpFractionalScaleV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpFractionalScaleV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        preferred_scale: H0,
+    }
+
+    impl<H0> WpFractionalScaleV1EventHandler for Handler<H0>
+    where
+        H0: WpFractionalScaleV1EventHandler,
+    {
+        #[inline]
+        fn preferred_scale(&self, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+            self.preferred_scale.preferred_scale(_slf, scale)
+        }
+    }
+
+    impl<T, H0> WpFractionalScaleV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WpFractionalScaleV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn preferred_scale(&self, _data: &mut T, _slf: &WpFractionalScaleV1Ref, scale: u32) {
+            self.preferred_scale.preferred_scale(_data, _slf, scale)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for preferred_scale events.
+        #[allow(dead_code)]
+        pub fn on_preferred_scale<F>(self, f: F) -> Handler<PreferredScale<F>>
+        where
+            F: Fn(&WpFractionalScaleV1Ref, u32),
+        {
+            Handler {
+                preferred_scale: PreferredScale(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preferred_scale events.
+        #[allow(dead_code)]
+        pub fn on_preferred_scale_with_data<T, F>(self, f: F) -> Handler<PreferredScale<F>>
+        where
+            F: Fn(&mut T, &WpFractionalScaleV1Ref, u32),
+        {
+            Handler {
+                preferred_scale: PreferredScale(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,935 @@
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_fullscreen_shell_mode_feedback_v1".as_ptr(),
+    version: 1,
+    method_count: 0,
+    methods: ptr::null(),
+    event_count: 3,
+    events: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"mode_successful".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"mode_failed".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"present_cancelled".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_fullscreen_shell_mode_feedback_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_fullscreen_shell_mode_feedback_v1",
+    protocol: "fullscreen_shell_unstable_v1",
+    version: 1,
+    requests: &[],
+    events: &[
+        info::MessageInfo {
+            name: "mode_successful",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "mode_failed",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "present_cancelled",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_fullscreen_shell_mode_feedback_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpFullscreenShellModeFeedbackV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_fullscreen_shell_mode_feedback_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpFullscreenShellModeFeedbackV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpFullscreenShellModeFeedbackV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpFullscreenShellModeFeedbackV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpFullscreenShellModeFeedbackV1 {
+    const INTERFACE: &'static str = "zwp_fullscreen_shell_mode_feedback_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpFullscreenShellModeFeedbackV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpFullscreenShellModeFeedbackV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpFullscreenShellModeFeedbackV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpFullscreenShellModeFeedbackV1Ref {
+    type Owned = ZwpFullscreenShellModeFeedbackV1;
+}
+
+impl Deref for ZwpFullscreenShellModeFeedbackV1 {
+    type Target = ZwpFullscreenShellModeFeedbackV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpFullscreenShellModeFeedbackV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_fullscreen_shell_mode_feedback_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ZwpFullscreenShellModeFeedbackV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_fullscreen_shell_mode_feedback_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ZwpFullscreenShellModeFeedbackV1Ref> for ZwpFullscreenShellModeFeedbackV1 {
+    fn eq(&self, other: &ZwpFullscreenShellModeFeedbackV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpFullscreenShellModeFeedbackV1> for ZwpFullscreenShellModeFeedbackV1Ref {
+    fn eq(&self, other: &ZwpFullscreenShellModeFeedbackV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl ZwpFullscreenShellModeFeedbackV1 {
+    /// Since when the mode_successful event is available.
+    #[allow(dead_code)]
+    pub const EVT__MODE_SUCCESSFUL__SINCE: u32 = 1;
+
+    /// Since when the mode_failed event is available.
+    #[allow(dead_code)]
+    pub const EVT__MODE_FAILED__SINCE: u32 = 1;
+
+    /// Since when the present_cancelled event is available.
+    #[allow(dead_code)]
+    pub const EVT__PRESENT_CANCELLED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpFullscreenShellModeFeedbackV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellModeFeedbackV1EventHandler {
+    /// mode switch succeeded
+    ///
+    /// This event indicates that the attempted mode switch operation was
+    /// successful.  A surface of the size requested in the mode switch
+    /// will fill the output without scaling.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// wl_fullscreen_shell_mode_feedback object.
+    #[inline]
+    fn mode_successful(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// mode switch failed
+    ///
+    /// This event indicates that the attempted mode switch operation
+    /// failed.  This may be because the requested output mode is not
+    /// possible or it may mean that the compositor does not want to allow it.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// wl_fullscreen_shell_mode_feedback object.
+    #[inline]
+    fn mode_failed(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// mode switch cancelled
+    ///
+    /// This event indicates that the attempted mode switch operation was
+    /// cancelled.  Most likely this is because the client requested a
+    /// second mode switch before the first one completed.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// wl_fullscreen_shell_mode_feedback object.
+    #[inline]
+    fn present_cancelled(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+}
+
+impl ZwpFullscreenShellModeFeedbackV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpFullscreenShellModeFeedbackV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpFullscreenShellModeFeedbackV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> {
+    /// mode switch succeeded
+    ///
+    /// This event indicates that the attempted mode switch operation was
+    /// successful.  A surface of the size requested in the mode switch
+    /// will fill the output without scaling.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// wl_fullscreen_shell_mode_feedback object.
+    #[inline]
+    fn mode_successful(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// mode switch failed
+    ///
+    /// This event indicates that the attempted mode switch operation
+    /// failed.  This may be because the requested output mode is not
+    /// possible or it may mean that the compositor does not want to allow it.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// wl_fullscreen_shell_mode_feedback object.
+    #[inline]
+    fn mode_failed(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// mode switch cancelled
+    ///
+    /// This event indicates that the attempted mode switch operation was
+    /// cancelled.  Most likely this is because the client requested a
+    /// second mode switch before the first one completed.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// wl_fullscreen_shell_mode_feedback object.
+    #[inline]
+    fn present_cancelled(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpFullscreenShellModeFeedbackV1] proxies.
+///
+/// This trait is the same as [ZwpFullscreenShellModeFeedbackV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellModeFeedbackV1EventHandlerMut {
+    /// See [ZwpFullscreenShellModeFeedbackV1EventHandler::mode_successful].
+    #[inline]
+    fn mode_successful(&mut self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// See [ZwpFullscreenShellModeFeedbackV1EventHandler::mode_failed].
+    #[inline]
+    fn mode_failed(&mut self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// See [ZwpFullscreenShellModeFeedbackV1EventHandler::present_cancelled].
+    #[inline]
+    fn present_cancelled(&mut self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpFullscreenShellModeFeedbackV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpFullscreenShellModeFeedbackV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<T> {
+    /// See [ZwpFullscreenShellModeFeedbackV1EventHandler::mode_successful].
+    #[inline]
+    fn mode_successful(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// See [ZwpFullscreenShellModeFeedbackV1EventHandler::mode_failed].
+    #[inline]
+    fn mode_failed(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+
+    /// See [ZwpFullscreenShellModeFeedbackV1EventHandler::present_cancelled].
+    #[inline]
+    fn present_cancelled(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {}
+}
+
+impl<H> ZwpFullscreenShellModeFeedbackV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandlerMut,
+{
+    #[inline]
+    fn mode_successful(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        self.with(|h| h.mode_successful(_slf))
+    }
+
+    #[inline]
+    fn mode_failed(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        self.with(|h| h.mode_failed(_slf))
+    }
+
+    #[inline]
+    fn present_cancelled(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        self.with(|h| h.present_cancelled(_slf))
+    }
+}
+
+impl<T, H> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn mode_successful(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        self.with(|h| h.mode_successful(_data, _slf))
+    }
+
+    #[inline]
+    fn mode_failed(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        self.with(|h| h.mode_failed(_data, _slf))
+    }
+
+    #[inline]
+    fn present_cancelled(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        self.with(|h| h.present_cancelled(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn mode_successful(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler().mode_successful(_data, _slf)
+    }
+
+    #[inline]
+    fn mode_failed(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler().mode_failed(_data, _slf)
+    }
+
+    #[inline]
+    fn present_cancelled(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler().present_cancelled(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn mode_successful(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().mode_successful(_data, _slf)
+    }
+
+    #[inline]
+    fn mode_failed(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().mode_failed(_data, _slf)
+    }
+
+    #[inline]
+    fn present_cancelled(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().present_cancelled(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpFullscreenShellModeFeedbackV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                self.0.mode_successful(slf);
+            }
+            1 => {
+                self.0.mode_failed(slf);
+            }
+            2 => {
+                self.0.present_cancelled(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_fullscreen_shell_mode_feedback_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpFullscreenShellModeFeedbackV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.mode_successful(data, slf);
+            }
+            1 => {
+                self.0.mode_failed(data, slf);
+            }
+            2 => {
+                self.0.present_cancelled(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_fullscreen_shell_mode_feedback_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for mode_successful events.
+    pub struct ModeSuccessful<F>(F);
+    impl<F> ZwpFullscreenShellModeFeedbackV1EventHandler for ModeSuccessful<F>
+    where
+        F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_successful(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> for ModeSuccessful<F>
+    where
+        F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_successful(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for mode_failed events.
+    pub struct ModeFailed<F>(F);
+    impl<F> ZwpFullscreenShellModeFeedbackV1EventHandler for ModeFailed<F>
+    where
+        F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_failed(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> for ModeFailed<F>
+    where
+        F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_failed(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for present_cancelled events.
+    pub struct PresentCancelled<F>(F);
+    impl<F> ZwpFullscreenShellModeFeedbackV1EventHandler for PresentCancelled<F>
+    where
+        F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn present_cancelled(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> for PresentCancelled<F>
+    where
+        F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn present_cancelled(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for mode_successful events.
+    pub struct ModeSuccessfulMut<F>(F);
+    impl<F> ZwpFullscreenShellModeFeedbackV1EventHandlerMut for ModeSuccessfulMut<F>
+    where
+        F: FnMut(&ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_successful(&mut self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<T> for ModeSuccessfulMut<F>
+    where
+        F: FnMut(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_successful(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for mode_failed events.
+    pub struct ModeFailedMut<F>(F);
+    impl<F> ZwpFullscreenShellModeFeedbackV1EventHandlerMut for ModeFailedMut<F>
+    where
+        F: FnMut(&ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_failed(&mut self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<T> for ModeFailedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn mode_failed(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for present_cancelled events.
+    pub struct PresentCancelledMut<F>(F);
+    impl<F> ZwpFullscreenShellModeFeedbackV1EventHandlerMut for PresentCancelledMut<F>
+    where
+        F: FnMut(&ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn present_cancelled(&mut self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellModeFeedbackV1EventHandlerMutWithData<T> for PresentCancelledMut<F>
+    where
+        F: FnMut(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+    {
+        #[inline]
+        fn present_cancelled(&mut self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpFullscreenShellModeFeedbackV1 {
+        /// Creates an event handler for mode_successful events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_mode_successful<F>(f: F) -> ModeSuccessful<F>
+        where
+            F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeSuccessful(f)
+        }
+
+        /// Creates an event handler with mutable data for mode_successful events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_mode_successful_with_data<T, F>(f: F) -> ModeSuccessful<F>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeSuccessful(f)
+        }
+
+        /// Creates an event handler for mode_failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_mode_failed<F>(f: F) -> ModeFailed<F>
+        where
+            F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeFailed(f)
+        }
+
+        /// Creates an event handler with mutable data for mode_failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_mode_failed_with_data<T, F>(f: F) -> ModeFailed<F>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeFailed(f)
+        }
+
+        /// Creates an event handler for present_cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_present_cancelled<F>(f: F) -> PresentCancelled<F>
+        where
+            F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            PresentCancelled(f)
+        }
+
+        /// Creates an event handler with mutable data for present_cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_present_cancelled_with_data<T, F>(f: F) -> PresentCancelled<F>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            PresentCancelled(f)
+        }
+
+        /// Creates a mutable event handler for mode_successful events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_mode_successful_mut<F>(f: F) -> ModeSuccessfulMut<F>
+        where
+            F: FnMut(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeSuccessfulMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for mode_successful events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_mode_successful_mut_with_data<T, F>(f: F) -> ModeSuccessfulMut<F>
+        where
+            F: FnMut(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeSuccessfulMut(f)
+        }
+
+        /// Creates a mutable event handler for mode_failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_mode_failed_mut<F>(f: F) -> ModeFailedMut<F>
+        where
+            F: FnMut(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeFailedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for mode_failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_mode_failed_mut_with_data<T, F>(f: F) -> ModeFailedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            ModeFailedMut(f)
+        }
+
+        /// Creates a mutable event handler for present_cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_present_cancelled_mut<F>(f: F) -> PresentCancelledMut<F>
+        where
+            F: FnMut(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            PresentCancelledMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for present_cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_present_cancelled_mut_with_data<T, F>(f: F) -> PresentCancelledMut<F>
+        where
+            F: FnMut(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            PresentCancelledMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                mode_successful: Ignore,
+                mode_failed: Ignore,
+                present_cancelled: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpFullscreenShellModeFeedbackV1EventHandler for Ignore {}
+    impl<T> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpFullscreenShellModeFeedbackV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        mode_successful: H0,
+        mode_failed: H1,
+        present_cancelled: H2,
+    }
+
+    impl<H0, H1, H2> ZwpFullscreenShellModeFeedbackV1EventHandler for Handler<H0, H1, H2>
+    where
+        H0: ZwpFullscreenShellModeFeedbackV1EventHandler,
+        H1: ZwpFullscreenShellModeFeedbackV1EventHandler,
+        H2: ZwpFullscreenShellModeFeedbackV1EventHandler,
+    {
+        #[inline]
+        fn mode_successful(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.mode_successful.mode_successful(_slf)
+        }
+
+        #[inline]
+        fn mode_failed(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.mode_failed.mode_failed(_slf)
+        }
+
+        #[inline]
+        fn present_cancelled(&self, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.present_cancelled.present_cancelled(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2> ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T>,
+        H1: ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T>,
+        H2: ZwpFullscreenShellModeFeedbackV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn mode_successful(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.mode_successful.mode_successful(_data, _slf)
+        }
+
+        #[inline]
+        fn mode_failed(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.mode_failed.mode_failed(_data, _slf)
+        }
+
+        #[inline]
+        fn present_cancelled(&self, _data: &mut T, _slf: &ZwpFullscreenShellModeFeedbackV1Ref) {
+            self.present_cancelled.present_cancelled(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for mode_successful events.
+        #[allow(dead_code)]
+        pub fn on_mode_successful<F>(self, f: F) -> Handler<ModeSuccessful<F>, H1, H2>
+        where
+            F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            Handler {
+                mode_successful: ModeSuccessful(f),
+                mode_failed: self.mode_failed,
+                present_cancelled: self.present_cancelled,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for mode_successful events.
+        #[allow(dead_code)]
+        pub fn on_mode_successful_with_data<T, F>(self, f: F) -> Handler<ModeSuccessful<F>, H1, H2>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            Handler {
+                mode_successful: ModeSuccessful(f),
+                mode_failed: self.mode_failed,
+                present_cancelled: self.present_cancelled,
+            }
+        }
+
+        /// Sets the closure for mode_failed events.
+        #[allow(dead_code)]
+        pub fn on_mode_failed<F>(self, f: F) -> Handler<H0, ModeFailed<F>, H2>
+        where
+            F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            Handler {
+                mode_successful: self.mode_successful,
+                mode_failed: ModeFailed(f),
+                present_cancelled: self.present_cancelled,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for mode_failed events.
+        #[allow(dead_code)]
+        pub fn on_mode_failed_with_data<T, F>(self, f: F) -> Handler<H0, ModeFailed<F>, H2>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            Handler {
+                mode_successful: self.mode_successful,
+                mode_failed: ModeFailed(f),
+                present_cancelled: self.present_cancelled,
+            }
+        }
+
+        /// Sets the closure for present_cancelled events.
+        #[allow(dead_code)]
+        pub fn on_present_cancelled<F>(self, f: F) -> Handler<H0, H1, PresentCancelled<F>>
+        where
+            F: Fn(&ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            Handler {
+                mode_successful: self.mode_successful,
+                mode_failed: self.mode_failed,
+                present_cancelled: PresentCancelled(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for present_cancelled events.
+        #[allow(dead_code)]
+        pub fn on_present_cancelled_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, PresentCancelled<F>>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellModeFeedbackV1Ref),
+        {
+            Handler {
+                mode_successful: self.mode_successful,
+                mode_failed: self.mode_failed,
+                present_cancelled: PresentCancelled(f),
+            }
+        }
+    }
+}
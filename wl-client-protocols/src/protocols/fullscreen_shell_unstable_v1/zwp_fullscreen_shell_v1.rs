@@ -0,0 +1,1232 @@
+//! displays a single surface per output
+//!
+//! Displays a single surface per output.
+//!
+//! This interface provides a mechanism for a single client to display
+//! simple full-screen surfaces.  While there technically may be multiple
+//! clients bound to this interface, only one of those clients should be
+//! shown at a time.
+//!
+//! To present a surface, the client uses either the present_surface or
+//! present_surface_for_mode requests.  Presenting a surface takes effect
+//! on the next wl_surface.commit.  See the individual requests for
+//! details about scaling and mode switches.
+//!
+//! The client can have at most one surface per output at any time.
+//! Requesting a surface to be presented on an output that already has a
+//! surface replaces the previously presented surface.  Presenting a null
+//! surface removes its content and effectively disables the output.
+//! Exactly what happens when an output is "disabled" is
+//! compositor-specific.  The same surface may be presented on multiple
+//! outputs simultaneously.
+//!
+//! Once a surface is presented on an output, it stays on that output
+//! until either the client removes it or the compositor destroys the
+//! output.  This way, the client can update the output's contents by
+//! simply attaching a new buffer.
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_fullscreen_shell_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"release".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"present_surface".as_ptr(),
+                signature: c"?ou?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(WlSurface::WL_INTERFACE),
+                        None,
+                        Some(WlOutput::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"present_surface_for_mode".as_ptr(),
+                signature: c"ooin".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlOutput::WL_INTERFACE),
+                        None,
+                        Some(ZwpFullscreenShellModeFeedbackV1::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"capability".as_ptr(),
+            signature: c"u".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_fullscreen_shell_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_fullscreen_shell_v1",
+    protocol: "fullscreen_shell_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "release",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "present_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "method",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("present_method"),
+                },
+                info::ArgInfo {
+                    name: "output",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_output"),
+                    allow_null: true,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "present_surface_for_mode",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "output",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_output"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "framerate",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "feedback",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_fullscreen_shell_mode_feedback_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "capability",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "capability",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: Some("capability"),
+        }],
+    }],
+    enums: &[
+        info::EnumInfo {
+            name: "capability",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "arbitrary_modes",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "cursor_plane",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "present_method",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "default",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "center",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "zoom",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "zoom_crop",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "stretch",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "invalid_method",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "role",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned zwp_fullscreen_shell_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpFullscreenShellV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_fullscreen_shell_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpFullscreenShellV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpFullscreenShellV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpFullscreenShellV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpFullscreenShellV1 {
+    const INTERFACE: &'static str = "zwp_fullscreen_shell_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpFullscreenShellV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpFullscreenShellV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpFullscreenShellV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpFullscreenShellV1Ref {
+    type Owned = ZwpFullscreenShellV1;
+}
+
+impl Deref for ZwpFullscreenShellV1 {
+    type Target = ZwpFullscreenShellV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpFullscreenShellV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_fullscreen_shell_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpFullscreenShellV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_fullscreen_shell_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpFullscreenShellV1Ref> for ZwpFullscreenShellV1 {
+    fn eq(&self, other: &ZwpFullscreenShellV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpFullscreenShellV1> for ZwpFullscreenShellV1Ref {
+    fn eq(&self, other: &ZwpFullscreenShellV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpFullscreenShellV1 {
+    /// Since when the release request is available.
+    #[allow(dead_code)]
+    pub const REQ__RELEASE__SINCE: u32 = 1;
+
+    /// release the wl_fullscreen_shell interface
+    ///
+    /// Release the binding from the wl_fullscreen_shell interface.
+    ///
+    /// This destroys the server-side object and frees this binding.  If
+    /// the client binds to wl_fullscreen_shell multiple times, it may wish
+    /// to free some of those bindings.
+    #[inline]
+    pub fn release(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the present_surface_for_mode request is available.
+    #[allow(dead_code)]
+    pub const REQ__PRESENT_SURFACE_FOR_MODE__SINCE: u32 = 1;
+
+    /// present surface for display at a particular mode
+    ///
+    /// Presents a surface on the given output for a particular mode.
+    ///
+    /// If the current size of the output differs from that of the surface,
+    /// the compositor will attempt to change the size of the output to
+    /// match the surface.  The result of the mode-switch operation will be
+    /// returned via the provided wl_fullscreen_shell_mode_feedback object.
+    ///
+    /// If the current output mode matches the one requested or if the
+    /// compositor successfully switches the mode to match the surface,
+    /// then the mode_successful event will be sent and the output will
+    /// contain the contents of the given surface.  If the compositor
+    /// cannot match the output size to the surface size, the mode_failed
+    /// will be sent and the output will contain the contents of the
+    /// previously presented surface (if any).  If another surface is
+    /// presented on the given output before either of these has a chance
+    /// to happen, the present_cancelled event will be sent.
+    ///
+    /// Due to race conditions and other issues unknown to the client, no
+    /// mode-switch operation is guaranteed to succeed.  However, if the
+    /// mode is one advertised by wl_output.mode or if the compositor
+    /// advertises the ARBITRARY_MODES capability, then the client should
+    /// expect that the mode-switch operation will usually succeed.
+    ///
+    /// If the size of the presented surface changes, the resulting output
+    /// is undefined.  The compositor may attempt to change the output mode
+    /// to compensate.  However, there is no guarantee that a suitable mode
+    /// will be found and the client has no way to be notified of success
+    /// or failure.
+    ///
+    /// The framerate parameter specifies the desired framerate for the
+    /// output in mHz.  The compositor is free to ignore this parameter.  A
+    /// value of 0 indicates that the client has no preference.
+    ///
+    /// If the value of wl_output.scale differs from wl_surface.buffer_scale,
+    /// then the compositor may choose a mode that matches either the buffer
+    /// size or the surface size.  In either case, the surface will fill the
+    /// output.
+    ///
+    /// This request gives the surface the role of a fullscreen shell surface.
+    /// If the surface already has another role, it raises a role protocol
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    /// - `output`:
+    /// - `framerate`:
+    #[inline]
+    pub fn present_surface_for_mode(
+        &self,
+        surface: &WlSurfaceRef,
+        output: &WlOutputRef,
+        framerate: i32,
+    ) -> ZwpFullscreenShellModeFeedbackV1 {
+        let (arg0, arg1, arg2) = (surface, output, framerate);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("output", obj1_lock.wl_proxy());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { o: obj1 },
+            wl_argument { i: arg2 },
+            wl_argument { n: 0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `ooin`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                ZwpFullscreenShellModeFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpFullscreenShellModeFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpFullscreenShellV1Ref {
+    /// present surface for display
+    ///
+    /// Present a surface on the given output.
+    ///
+    /// If the output is null, the compositor will present the surface on
+    /// whatever display (or displays) it thinks best.  In particular, this
+    /// may replace any or all surfaces currently presented so it should
+    /// not be used in combination with placing surfaces on specific
+    /// outputs.
+    ///
+    /// The method parameter is a hint to the compositor for how the surface
+    /// is to be presented.  In particular, it tells the compositor how to
+    /// handle a size mismatch between the presented surface and the
+    /// output.  The compositor is free to ignore this parameter.
+    ///
+    /// The "zoom", "zoom_crop", and "stretch" methods imply a scaling
+    /// operation on the surface.  This will override any kind of output
+    /// scaling, so the buffer_scale property of the surface is effectively
+    /// ignored.
+    ///
+    /// This request gives the surface the role of a fullscreen shell surface.
+    /// If the surface already has another role, it raises a role protocol
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    /// - `method`:
+    /// - `output`:
+    #[inline]
+    pub fn present_surface(
+        &self,
+        surface: Option<&WlSurfaceRef>,
+        method: ZwpFullscreenShellV1PresentMethod,
+        output: Option<&WlOutputRef>,
+    ) {
+        let (arg0, arg1, arg2) = (surface, method, output);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("surface", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let obj2_lock = arg2.map(|arg2| proxy::lock(arg2));
+        let obj2 = obj2_lock
+            .map(|obj2_lock| check_argument_proxy("output", obj2_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { u: arg1.0 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `?ou?o`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// present surface for display at a particular mode
+    ///
+    /// Presents a surface on the given output for a particular mode.
+    ///
+    /// If the current size of the output differs from that of the surface,
+    /// the compositor will attempt to change the size of the output to
+    /// match the surface.  The result of the mode-switch operation will be
+    /// returned via the provided wl_fullscreen_shell_mode_feedback object.
+    ///
+    /// If the current output mode matches the one requested or if the
+    /// compositor successfully switches the mode to match the surface,
+    /// then the mode_successful event will be sent and the output will
+    /// contain the contents of the given surface.  If the compositor
+    /// cannot match the output size to the surface size, the mode_failed
+    /// will be sent and the output will contain the contents of the
+    /// previously presented surface (if any).  If another surface is
+    /// presented on the given output before either of these has a chance
+    /// to happen, the present_cancelled event will be sent.
+    ///
+    /// Due to race conditions and other issues unknown to the client, no
+    /// mode-switch operation is guaranteed to succeed.  However, if the
+    /// mode is one advertised by wl_output.mode or if the compositor
+    /// advertises the ARBITRARY_MODES capability, then the client should
+    /// expect that the mode-switch operation will usually succeed.
+    ///
+    /// If the size of the presented surface changes, the resulting output
+    /// is undefined.  The compositor may attempt to change the output mode
+    /// to compensate.  However, there is no guarantee that a suitable mode
+    /// will be found and the client has no way to be notified of success
+    /// or failure.
+    ///
+    /// The framerate parameter specifies the desired framerate for the
+    /// output in mHz.  The compositor is free to ignore this parameter.  A
+    /// value of 0 indicates that the client has no preference.
+    ///
+    /// If the value of wl_output.scale differs from wl_surface.buffer_scale,
+    /// then the compositor may choose a mode that matches either the buffer
+    /// size or the surface size.  In either case, the surface will fill the
+    /// output.
+    ///
+    /// This request gives the surface the role of a fullscreen shell surface.
+    /// If the surface already has another role, it raises a role protocol
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    /// - `output`:
+    /// - `framerate`:
+    #[inline]
+    pub fn present_surface_for_mode(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+        output: &WlOutputRef,
+        framerate: i32,
+    ) -> ZwpFullscreenShellModeFeedbackV1 {
+        let (arg0, arg1, arg2) = (surface, output, framerate);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("output", obj1_lock.wl_proxy());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { o: obj1 },
+            wl_argument { i: arg2 },
+            wl_argument { n: 0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `ooin`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                ZwpFullscreenShellModeFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpFullscreenShellModeFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ZwpFullscreenShellV1 {
+    /// Since when the capability event is available.
+    #[allow(dead_code)]
+    pub const EVT__CAPABILITY__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpFullscreenShellV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellV1EventHandler {
+    /// advertises a capability of the compositor
+    ///
+    /// Advertises a single capability of the compositor.
+    ///
+    /// When the wl_fullscreen_shell interface is bound, this event is emitted
+    /// once for each capability advertised.  Valid capabilities are given by
+    /// the wl_fullscreen_shell.capability enum.  If clients want to take
+    /// advantage of any of these capabilities, they should use a
+    /// wl_display.sync request immediately after binding to ensure that they
+    /// receive all the capability events.
+    ///
+    /// # Arguments
+    ///
+    /// - `capability`:
+    #[inline]
+    fn capability(
+        &self,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        let _ = capability;
+    }
+}
+
+impl ZwpFullscreenShellV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpFullscreenShellV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpFullscreenShellV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellV1EventHandlerWithData<T> {
+    /// advertises a capability of the compositor
+    ///
+    /// Advertises a single capability of the compositor.
+    ///
+    /// When the wl_fullscreen_shell interface is bound, this event is emitted
+    /// once for each capability advertised.  Valid capabilities are given by
+    /// the wl_fullscreen_shell.capability enum.  If clients want to take
+    /// advantage of any of these capabilities, they should use a
+    /// wl_display.sync request immediately after binding to ensure that they
+    /// receive all the capability events.
+    ///
+    /// # Arguments
+    ///
+    /// - `capability`:
+    #[inline]
+    fn capability(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        let _ = capability;
+    }
+}
+
+/// A mutable event handler for [ZwpFullscreenShellV1] proxies.
+///
+/// This trait is the same as [ZwpFullscreenShellV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellV1EventHandlerMut {
+    /// See [ZwpFullscreenShellV1EventHandler::capability].
+    #[inline]
+    fn capability(
+        &mut self,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        let _ = capability;
+    }
+}
+
+/// A mutable event handler for [ZwpFullscreenShellV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpFullscreenShellV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpFullscreenShellV1EventHandlerMutWithData<T> {
+    /// See [ZwpFullscreenShellV1EventHandler::capability].
+    #[inline]
+    fn capability(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        let _ = capability;
+    }
+}
+
+impl<H> ZwpFullscreenShellV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpFullscreenShellV1EventHandlerMut,
+{
+    #[inline]
+    fn capability(
+        &self,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        self.with(|h| h.capability(_slf, capability))
+    }
+}
+
+impl<T, H> ZwpFullscreenShellV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpFullscreenShellV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn capability(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        self.with(|h| h.capability(_data, _slf, capability))
+    }
+}
+
+impl<T, U, H> ZwpFullscreenShellV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpFullscreenShellV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn capability(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        let _data = self.project(_data);
+        self.handler().capability(_data, _slf, capability)
+    }
+}
+
+impl<T, U, H> ZwpFullscreenShellV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpFullscreenShellV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn capability(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpFullscreenShellV1Ref,
+        capability: ZwpFullscreenShellV1Capability,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().capability(_data, _slf, capability)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpFullscreenShellV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpFullscreenShellV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpFullscreenShellV1Capability(args[0].u) };
+                self.0.capability(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_fullscreen_shell_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpFullscreenShellV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpFullscreenShellV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpFullscreenShellV1Capability(args[0].u) };
+                self.0.capability(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_fullscreen_shell_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpFullscreenShellV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpFullscreenShellV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpFullscreenShellV1 {
+    /// Since when the capability.arbitrary_modes enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_ARBITRARY_MODES__SINCE: u32 = 1;
+    /// Since when the capability.cursor_plane enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_CURSOR_PLANE__SINCE: u32 = 1;
+
+    /// Since when the present_method.default enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENT_METHOD_DEFAULT__SINCE: u32 = 1;
+    /// Since when the present_method.center enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENT_METHOD_CENTER__SINCE: u32 = 1;
+    /// Since when the present_method.zoom enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENT_METHOD_ZOOM__SINCE: u32 = 1;
+    /// Since when the present_method.zoom_crop enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENT_METHOD_ZOOM_CROP__SINCE: u32 = 1;
+    /// Since when the present_method.stretch enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENT_METHOD_STRETCH__SINCE: u32 = 1;
+
+    /// Since when the error.invalid_method enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_METHOD__SINCE: u32 = 1;
+    /// Since when the error.role enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
+}
+
+/// capabilities advertised by the compositor
+///
+/// Various capabilities that can be advertised by the compositor.  They
+/// are advertised one-at-a-time when the wl_fullscreen_shell interface is
+/// bound.  See the wl_fullscreen_shell.capability event for more details.
+///
+/// ARBITRARY_MODES:
+/// This is a hint to the client that indicates that the compositor is
+/// capable of setting practically any mode on its outputs.  If this
+/// capability is provided, wl_fullscreen_shell.present_surface_for_mode
+/// will almost never fail and clients should feel free to set whatever
+/// mode they like.  If the compositor does not advertise this, it may
+/// still support some modes that are not advertised through wl_global.mode
+/// but it is less likely.
+///
+/// CURSOR_PLANE:
+/// This is a hint to the client that indicates that the compositor can
+/// handle a cursor surface from the client without actually compositing.
+/// This may be because of a hardware cursor plane or some other mechanism.
+/// If the compositor does not advertise this capability then setting
+/// wl_pointer.cursor may degrade performance or be ignored entirely.  If
+/// CURSOR_PLANE is not advertised, it is recommended that the client draw
+/// its own cursor and set wl_pointer.cursor(NULL).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpFullscreenShellV1Capability(pub u32);
+
+impl ZwpFullscreenShellV1Capability {
+    /// compositor is capable of almost any output mode
+    #[allow(dead_code)]
+    pub const ARBITRARY_MODES: Self = Self(1);
+
+    /// compositor has a separate cursor plane
+    #[allow(dead_code)]
+    pub const CURSOR_PLANE: Self = Self(2);
+}
+
+impl Debug for ZwpFullscreenShellV1Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ARBITRARY_MODES => "ARBITRARY_MODES",
+            Self::CURSOR_PLANE => "CURSOR_PLANE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// different method to set the surface fullscreen
+///
+/// Hints to indicate to the compositor how to deal with a conflict
+/// between the dimensions of the surface and the dimensions of the
+/// output. The compositor is free to ignore this parameter.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpFullscreenShellV1PresentMethod(pub u32);
+
+impl ZwpFullscreenShellV1PresentMethod {
+    /// no preference, apply default policy
+    #[allow(dead_code)]
+    pub const DEFAULT: Self = Self(0);
+
+    /// center the surface on the output
+    #[allow(dead_code)]
+    pub const CENTER: Self = Self(1);
+
+    /// scale the surface, preserving aspect ratio, to the largest size that will fit on the output
+    #[allow(dead_code)]
+    pub const ZOOM: Self = Self(2);
+
+    /// scale the surface, preserving aspect ratio, to fully fill the output cropping if needed
+    #[allow(dead_code)]
+    pub const ZOOM_CROP: Self = Self(3);
+
+    /// scale the surface to the size of the output ignoring aspect ratio
+    #[allow(dead_code)]
+    pub const STRETCH: Self = Self(4);
+}
+
+impl Debug for ZwpFullscreenShellV1PresentMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::DEFAULT => "DEFAULT",
+            Self::CENTER => "CENTER",
+            Self::ZOOM => "ZOOM",
+            Self::ZOOM_CROP => "ZOOM_CROP",
+            Self::STRETCH => "STRETCH",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// wl_fullscreen_shell error values
+///
+/// These errors can be emitted in response to wl_fullscreen_shell requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpFullscreenShellV1Error(pub u32);
+
+impl ZwpFullscreenShellV1Error {
+    /// present_method is not known
+    #[allow(dead_code)]
+    pub const INVALID_METHOD: Self = Self(0);
+
+    /// given wl_surface has another role
+    #[allow(dead_code)]
+    pub const ROLE: Self = Self(1);
+}
+
+impl Debug for ZwpFullscreenShellV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_METHOD => "INVALID_METHOD",
+            Self::ROLE => "ROLE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for capability events.
+    pub struct Capability<F>(F);
+    impl<F> ZwpFullscreenShellV1EventHandler for Capability<F>
+    where
+        F: Fn(&ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+    {
+        #[inline]
+        fn capability(
+            &self,
+            _slf: &ZwpFullscreenShellV1Ref,
+            capability: ZwpFullscreenShellV1Capability,
+        ) {
+            self.0(_slf, capability)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellV1EventHandlerWithData<T> for Capability<F>
+    where
+        F: Fn(&mut T, &ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+    {
+        #[inline]
+        fn capability(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpFullscreenShellV1Ref,
+            capability: ZwpFullscreenShellV1Capability,
+        ) {
+            self.0(_data, _slf, capability)
+        }
+    }
+
+    /// Mutable event handler for capability events.
+    pub struct CapabilityMut<F>(F);
+    impl<F> ZwpFullscreenShellV1EventHandlerMut for CapabilityMut<F>
+    where
+        F: FnMut(&ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+    {
+        #[inline]
+        fn capability(
+            &mut self,
+            _slf: &ZwpFullscreenShellV1Ref,
+            capability: ZwpFullscreenShellV1Capability,
+        ) {
+            self.0(_slf, capability)
+        }
+    }
+    impl<T, F> ZwpFullscreenShellV1EventHandlerMutWithData<T> for CapabilityMut<F>
+    where
+        F: FnMut(&mut T, &ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+    {
+        #[inline]
+        fn capability(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpFullscreenShellV1Ref,
+            capability: ZwpFullscreenShellV1Capability,
+        ) {
+            self.0(_data, _slf, capability)
+        }
+    }
+
+    impl ZwpFullscreenShellV1 {
+        /// Creates an event handler for capability events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capability<F>(f: F) -> Capability<F>
+        where
+            F: Fn(&ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+        {
+            Capability(f)
+        }
+
+        /// Creates an event handler with mutable data for capability events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capability_with_data<T, F>(f: F) -> Capability<F>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+        {
+            Capability(f)
+        }
+
+        /// Creates a mutable event handler for capability events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capability_mut<F>(f: F) -> CapabilityMut<F>
+        where
+            F: FnMut(&ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+        {
+            CapabilityMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capability events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capability_mut_with_data<T, F>(f: F) -> CapabilityMut<F>
+        where
+            F: FnMut(&mut T, &ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+        {
+            CapabilityMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { capability: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpFullscreenShellV1EventHandler for Ignore {}
+    impl<T> ZwpFullscreenShellV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpFullscreenShellV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        capability: H0,
+    }
+
+    impl<H0> ZwpFullscreenShellV1EventHandler for Handler<H0>
+    where
+        H0: ZwpFullscreenShellV1EventHandler,
+    {
+        #[inline]
+        fn capability(
+            &self,
+            _slf: &ZwpFullscreenShellV1Ref,
+            capability: ZwpFullscreenShellV1Capability,
+        ) {
+            self.capability.capability(_slf, capability)
+        }
+    }
+
+    impl<T, H0> ZwpFullscreenShellV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZwpFullscreenShellV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn capability(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpFullscreenShellV1Ref,
+            capability: ZwpFullscreenShellV1Capability,
+        ) {
+            self.capability.capability(_data, _slf, capability)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for capability events.
+        #[allow(dead_code)]
+        pub fn on_capability<F>(self, f: F) -> Handler<Capability<F>>
+        where
+            F: Fn(&ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+        {
+            Handler {
+                capability: Capability(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for capability events.
+        #[allow(dead_code)]
+        pub fn on_capability_with_data<T, F>(self, f: F) -> Handler<Capability<F>>
+        where
+            F: Fn(&mut T, &ZwpFullscreenShellV1Ref, ZwpFullscreenShellV1Capability),
+        {
+            Handler {
+                capability: Capability(f),
+            }
+        }
+    }
+}
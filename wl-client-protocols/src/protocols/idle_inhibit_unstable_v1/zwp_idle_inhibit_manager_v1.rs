@@ -0,0 +1,400 @@
+//! control behavior when display idles
+//!
+//! This interface permits inhibiting the idle behavior such as screen
+//! blanking, locking, and screensaving.  The client binds the idle manager
+//! globally, then creates idle-inhibitor objects for each surface.
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_idle_inhibit_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_inhibitor".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpIdleInhibitorV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_idle_inhibit_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_idle_inhibit_manager_v1",
+    protocol: "idle_inhibit_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "create_inhibitor",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_idle_inhibitor_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_idle_inhibit_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpIdleInhibitManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_idle_inhibit_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpIdleInhibitManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpIdleInhibitManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpIdleInhibitManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpIdleInhibitManagerV1 {
+    const INTERFACE: &'static str = "zwp_idle_inhibit_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpIdleInhibitManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpIdleInhibitManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpIdleInhibitManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpIdleInhibitManagerV1Ref {
+    type Owned = ZwpIdleInhibitManagerV1;
+}
+
+impl Deref for ZwpIdleInhibitManagerV1 {
+    type Target = ZwpIdleInhibitManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpIdleInhibitManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_idle_inhibit_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpIdleInhibitManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_idle_inhibit_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpIdleInhibitManagerV1Ref> for ZwpIdleInhibitManagerV1 {
+    fn eq(&self, other: &ZwpIdleInhibitManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpIdleInhibitManagerV1> for ZwpIdleInhibitManagerV1Ref {
+    fn eq(&self, other: &ZwpIdleInhibitManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpIdleInhibitManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the idle inhibitor object
+    ///
+    /// Destroy the inhibit manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the create_inhibitor request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_INHIBITOR__SINCE: u32 = 1;
+
+    /// create a new inhibitor object
+    ///
+    /// Create a new inhibitor object associated with the given surface.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface that inhibits the idle behavior
+    #[inline]
+    pub fn create_inhibitor(&self, surface: &WlSurfaceRef) -> ZwpIdleInhibitorV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpIdleInhibitorV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpIdleInhibitorV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpIdleInhibitManagerV1Ref {
+    /// create a new inhibitor object
+    ///
+    /// Create a new inhibitor object associated with the given surface.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface that inhibits the idle behavior
+    #[inline]
+    pub fn create_inhibitor(&self, _queue: &Queue, surface: &WlSurfaceRef) -> ZwpIdleInhibitorV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpIdleInhibitorV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpIdleInhibitorV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpIdleInhibitManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpIdleInhibitManagerV1EventHandler {}
+
+impl ZwpIdleInhibitManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpIdleInhibitManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpIdleInhibitManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpIdleInhibitManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpIdleInhibitManagerV1] proxies.
+///
+/// This trait is the same as [ZwpIdleInhibitManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpIdleInhibitManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpIdleInhibitManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpIdleInhibitManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpIdleInhibitManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpIdleInhibitManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpIdleInhibitManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpIdleInhibitManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpIdleInhibitManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpIdleInhibitManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpIdleInhibitManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpIdleInhibitManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpIdleInhibitManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpIdleInhibitManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_idle_inhibit_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpIdleInhibitManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_idle_inhibit_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpIdleInhibitManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpIdleInhibitManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpIdleInhibitManagerV1 {}
+}
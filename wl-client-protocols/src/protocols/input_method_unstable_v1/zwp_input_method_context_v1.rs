@@ -0,0 +1,2848 @@
+//! input method context
+//!
+//! Corresponds to a text input on the input method side. An input method context
+//! is created on text input activation on the input method side. It allows
+//! receiving information about the text input from the application via events.
+//! Input method contexts do not keep state after deactivation and should be
+//! destroyed after deactivation is handled.
+//!
+//! Text is generally UTF-8 encoded, indices and lengths are in bytes.
+//!
+//! Serials are used to synchronize the state between the text input and
+//! an input method. New serials are sent by the text input in the
+//! commit_state request and are used by the input method to indicate
+//! the known text input state in events like preedit_string, commit_string,
+//! and keysym. The text input can then ignore events from the input method
+//! which are based on an outdated state (for example after a reset).
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_input_method_context_v1".as_ptr(),
+    version: 1,
+    method_count: 14,
+    methods: {
+        static MESSAGES: [wl_message; 14] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit_string".as_ptr(),
+                signature: c"us".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_string".as_ptr(),
+                signature: c"uss".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_styling".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_cursor".as_ptr(),
+                signature: c"i".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"delete_surrounding_text".as_ptr(),
+                signature: c"iu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"cursor_position".as_ptr(),
+                signature: c"ii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"modifiers_map".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"keysym".as_ptr(),
+                signature: c"uuuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] =
+                        [None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"grab_keyboard".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlKeyboard::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"key".as_ptr(),
+                signature: c"uuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"modifiers".as_ptr(),
+                signature: c"uuuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] =
+                        [None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"language".as_ptr(),
+                signature: c"us".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"text_direction".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 6,
+    events: {
+        static MESSAGES: [wl_message; 6] = [
+            wl_message {
+                name: c"surrounding_text".as_ptr(),
+                signature: c"suu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"reset".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"content_type".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"invoke_action".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit_state".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preferred_language".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_input_method_context_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_input_method_context_v1",
+    protocol: "input_method_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "commit_string",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "preedit_string",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "commit",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "preedit_styling",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "style",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "preedit_cursor",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "index",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "delete_surrounding_text",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "cursor_position",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "anchor",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "modifiers_map",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "map",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "keysym",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "sym",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "state",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifiers",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "grab_keyboard",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "keyboard",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_keyboard"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "key",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "key",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "state",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "modifiers",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mods_depressed",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mods_latched",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mods_locked",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "group",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "language",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "language",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "text_direction",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "direction",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "surrounding_text",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cursor",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "anchor",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "reset",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "content_type",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "hint",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "purpose",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "invoke_action",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "button",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "commit_state",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "preferred_language",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "language",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_input_method_context_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputMethodContextV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_input_method_context_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputMethodContextV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpInputMethodContextV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpInputMethodContextV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpInputMethodContextV1 {
+    const INTERFACE: &'static str = "zwp_input_method_context_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpInputMethodContextV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpInputMethodContextV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpInputMethodContextV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpInputMethodContextV1Ref {
+    type Owned = ZwpInputMethodContextV1;
+}
+
+impl Deref for ZwpInputMethodContextV1 {
+    type Target = ZwpInputMethodContextV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpInputMethodContextV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_method_context_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpInputMethodContextV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_method_context_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpInputMethodContextV1Ref> for ZwpInputMethodContextV1 {
+    fn eq(&self, other: &ZwpInputMethodContextV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpInputMethodContextV1> for ZwpInputMethodContextV1Ref {
+    fn eq(&self, other: &ZwpInputMethodContextV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputMethodContextV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 14
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the grab_keyboard request is available.
+    #[allow(dead_code)]
+    pub const REQ__GRAB_KEYBOARD__SINCE: u32 = 1;
+
+    /// grab hardware keyboard
+    ///
+    /// Allow an input method to receive hardware keyboard input and process
+    /// key events to generate text events (with pre-edit) over the wire. This
+    /// allows input methods which compose multiple key events for inputting
+    /// text like it is done for CJK languages.
+    #[inline]
+    pub fn grab_keyboard(&self) -> WlKeyboard {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 9 < INTERFACE.method_count = 14
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(9, &mut args, WlKeyboard::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WlKeyboard::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputMethodContextV1Ref {
+    /// commit string
+    ///
+    /// Send the commit string text for insertion to the application.
+    ///
+    /// The text to commit could be either just a single character after a key
+    /// press or the result of some composing (pre-edit). It could be also an
+    /// empty text when some text should be removed (see
+    /// delete_surrounding_text) or when the input cursor should be moved (see
+    /// cursor_position).
+    ///
+    /// Any previously set composing text will be removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `text`:
+    #[inline]
+    pub fn commit_string(&self, serial: u32, text: &str) {
+        let (arg0, arg1) = (serial, text);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let mut args = [wl_argument { u: arg0 }, wl_argument { s: str1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 14
+            //         - the request signature is `us`
+            unsafe {
+                self.proxy.send_request(1, &mut args);
+            }
+        })
+    }
+
+    /// pre-edit string
+    ///
+    /// Send the pre-edit string text to the application text input.
+    ///
+    /// The commit text can be used to replace the pre-edit text on reset (for
+    /// example on unfocus).
+    ///
+    /// Previously sent preedit_style and preedit_cursor requests are also
+    /// processed by the text_input.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `text`:
+    /// - `commit`:
+    #[inline]
+    pub fn preedit_string(&self, serial: u32, text: &str, commit: &str) {
+        let (arg0, arg1, arg2) = (serial, text, commit);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str2_offset = cache.len();
+            cache.extend_from_slice(arg2.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let str2 = cache[str2_offset..].as_ptr().cast();
+            let mut args = [
+                wl_argument { u: arg0 },
+                wl_argument { s: str1 },
+                wl_argument { s: str2 },
+            ];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 2 < INTERFACE.method_count = 14
+            //         - the request signature is `uss`
+            unsafe {
+                self.proxy.send_request(2, &mut args);
+            }
+        })
+    }
+
+    /// pre-edit styling
+    ///
+    /// Set the styling information on composing text. The style is applied for
+    /// length in bytes from index relative to the beginning of
+    /// the composing text (as byte offset). Multiple styles can
+    /// be applied to a composing text.
+    ///
+    /// This request should be sent before sending a preedit_string request.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `length`:
+    /// - `style`:
+    #[inline]
+    pub fn preedit_styling(&self, index: u32, length: u32, style: u32) {
+        let (arg0, arg1, arg2) = (index, length, style);
+        let mut args = [
+            wl_argument { u: arg0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 14
+        //         - the request signature is `uuu`
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+
+    /// pre-edit cursor
+    ///
+    /// Set the cursor position inside the composing text (as byte offset)
+    /// relative to the start of the composing text.
+    ///
+    /// When index is negative no cursor should be displayed.
+    ///
+    /// This request should be sent before sending a preedit_string request.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    #[inline]
+    pub fn preedit_cursor(&self, index: i32) {
+        let (arg0,) = (index,);
+        let mut args = [wl_argument { i: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 14
+        //         - the request signature is `i`
+        unsafe {
+            self.proxy.send_request(4, &mut args);
+        }
+    }
+
+    /// delete text
+    ///
+    /// Remove the surrounding text.
+    ///
+    /// This request will be handled on the text_input side directly following
+    /// a commit_string request.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `length`:
+    #[inline]
+    pub fn delete_surrounding_text(&self, index: i32, length: u32) {
+        let (arg0, arg1) = (index, length);
+        let mut args = [wl_argument { i: arg0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 5 < INTERFACE.method_count = 14
+        //         - the request signature is `iu`
+        unsafe {
+            self.proxy.send_request(5, &mut args);
+        }
+    }
+
+    /// set cursor to a new position
+    ///
+    /// Set the cursor and anchor to a new position. Index is the new cursor
+    /// position in bytes (when >= 0 this is relative to the end of the inserted text,
+    /// otherwise it is relative to the beginning of the inserted text). Anchor is
+    /// the new anchor position in bytes (when >= 0 this is relative to the end of the
+    /// inserted text, otherwise it is relative to the beginning of the inserted
+    /// text). When there should be no selected text, anchor should be the same
+    /// as index.
+    ///
+    /// This request will be handled on the text_input side directly following
+    /// a commit_string request.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `anchor`:
+    #[inline]
+    pub fn cursor_position(&self, index: i32, anchor: i32) {
+        let (arg0, arg1) = (index, anchor);
+        let mut args = [wl_argument { i: arg0 }, wl_argument { i: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 6 < INTERFACE.method_count = 14
+        //         - the request signature is `ii`
+        unsafe {
+            self.proxy.send_request(6, &mut args);
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// - `map`:
+    #[inline]
+    pub fn modifiers_map(&self, map: &[u8]) {
+        let (arg0,) = (map,);
+        let mut arr0 = wl_array {
+            size: arg0.len(),
+            alloc: arg0.len(),
+            data: arg0.as_ptr().cast_mut().cast(),
+        };
+        let mut args = [wl_argument { a: &mut arr0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 7 < INTERFACE.method_count = 14
+        //         - the request signature is `a`
+        unsafe {
+            self.proxy.send_request(7, &mut args);
+        }
+    }
+
+    /// keysym
+    ///
+    /// Notify when a key event was sent. Key events should not be used for
+    /// normal text input operations, which should be done with commit_string,
+    /// delete_surrounding_text, etc. The key event follows the wl_keyboard key
+    /// event convention. Sym is an XKB keysym, state is a wl_keyboard key_state.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `time`:
+    /// - `sym`:
+    /// - `state`:
+    /// - `modifiers`:
+    #[inline]
+    pub fn keysym(&self, serial: u32, time: u32, sym: u32, state: u32, modifiers: u32) {
+        let (arg0, arg1, arg2, arg3, arg4) = (serial, time, sym, state, modifiers);
+        let mut args = [
+            wl_argument { u: arg0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 8 < INTERFACE.method_count = 14
+        //         - the request signature is `uuuuu`
+        unsafe {
+            self.proxy.send_request(8, &mut args);
+        }
+    }
+
+    /// grab hardware keyboard
+    ///
+    /// Allow an input method to receive hardware keyboard input and process
+    /// key events to generate text events (with pre-edit) over the wire. This
+    /// allows input methods which compose multiple key events for inputting
+    /// text like it is done for CJK languages.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn grab_keyboard(&self, _queue: &Queue) -> WlKeyboard {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 9 < INTERFACE.method_count = 14
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 9, &mut args, WlKeyboard::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WlKeyboard::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// forward key event
+    ///
+    /// Forward a wl_keyboard::key event to the client that was not processed
+    /// by the input method itself. Should be used when filtering key events
+    /// with grab_keyboard.  The arguments should be the ones from the
+    /// wl_keyboard::key event.
+    ///
+    /// For generating custom key events use the keysym request instead.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial from wl_keyboard::key
+    /// - `time`: time from wl_keyboard::key
+    /// - `key`: key from wl_keyboard::key
+    /// - `state`: state from wl_keyboard::key
+    #[inline]
+    pub fn key(&self, serial: u32, time: u32, key: u32, state: u32) {
+        let (arg0, arg1, arg2, arg3) = (serial, time, key, state);
+        let mut args = [
+            wl_argument { u: arg0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 10 < INTERFACE.method_count = 14
+        //         - the request signature is `uuuu`
+        unsafe {
+            self.proxy.send_request(10, &mut args);
+        }
+    }
+
+    /// forward modifiers event
+    ///
+    /// Forward a wl_keyboard::modifiers event to the client that was not
+    /// processed by the input method itself.  Should be used when filtering
+    /// key events with grab_keyboard. The arguments should be the ones
+    /// from the wl_keyboard::modifiers event.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial from wl_keyboard::modifiers
+    /// - `mods_depressed`: mods_depressed from wl_keyboard::modifiers
+    /// - `mods_latched`: mods_latched from wl_keyboard::modifiers
+    /// - `mods_locked`: mods_locked from wl_keyboard::modifiers
+    /// - `group`: group from wl_keyboard::modifiers
+    #[inline]
+    pub fn modifiers(
+        &self,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let (arg0, arg1, arg2, arg3, arg4) =
+            (serial, mods_depressed, mods_latched, mods_locked, group);
+        let mut args = [
+            wl_argument { u: arg0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 11 < INTERFACE.method_count = 14
+        //         - the request signature is `uuuuu`
+        unsafe {
+            self.proxy.send_request(11, &mut args);
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `language`:
+    #[inline]
+    pub fn language(&self, serial: u32, language: &str) {
+        let (arg0, arg1) = (serial, language);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let mut args = [wl_argument { u: arg0 }, wl_argument { s: str1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 12 < INTERFACE.method_count = 14
+            //         - the request signature is `us`
+            unsafe {
+                self.proxy.send_request(12, &mut args);
+            }
+        })
+    }
+
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `direction`:
+    #[inline]
+    pub fn text_direction(&self, serial: u32, direction: u32) {
+        let (arg0, arg1) = (serial, direction);
+        let mut args = [wl_argument { u: arg0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 13 < INTERFACE.method_count = 14
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(13, &mut args);
+        }
+    }
+}
+
+impl ZwpInputMethodContextV1 {
+    /// Since when the surrounding_text event is available.
+    #[allow(dead_code)]
+    pub const EVT__SURROUNDING_TEXT__SINCE: u32 = 1;
+
+    /// Since when the reset event is available.
+    #[allow(dead_code)]
+    pub const EVT__RESET__SINCE: u32 = 1;
+
+    /// Since when the content_type event is available.
+    #[allow(dead_code)]
+    pub const EVT__CONTENT_TYPE__SINCE: u32 = 1;
+
+    /// Since when the invoke_action event is available.
+    #[allow(dead_code)]
+    pub const EVT__INVOKE_ACTION__SINCE: u32 = 1;
+
+    /// Since when the commit_state event is available.
+    #[allow(dead_code)]
+    pub const EVT__COMMIT_STATE__SINCE: u32 = 1;
+
+    /// Since when the preferred_language event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREFERRED_LANGUAGE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpInputMethodContextV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpInputMethodContextV1EventHandler {
+    /// surrounding text event
+    ///
+    /// The plain surrounding text around the input position. Cursor is the
+    /// position in bytes within the surrounding text relative to the beginning
+    /// of the text. Anchor is the position in bytes of the selection anchor
+    /// within the surrounding text relative to the beginning of the text. If
+    /// there is no selected text then anchor is the same as cursor.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    /// - `cursor`:
+    /// - `anchor`:
+    #[inline]
+    fn surrounding_text(
+        &self,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        let _ = text;
+        let _ = cursor;
+        let _ = anchor;
+    }
+
+    #[inline]
+    fn reset(&self, _slf: &ZwpInputMethodContextV1Ref) {}
+
+    /// # Arguments
+    ///
+    /// - `hint`:
+    /// - `purpose`:
+    #[inline]
+    fn content_type(&self, _slf: &ZwpInputMethodContextV1Ref, hint: u32, purpose: u32) {
+        let _ = hint;
+        let _ = purpose;
+    }
+
+    /// # Arguments
+    ///
+    /// - `button`:
+    /// - `index`:
+    #[inline]
+    fn invoke_action(&self, _slf: &ZwpInputMethodContextV1Ref, button: u32, index: u32) {
+        let _ = button;
+        let _ = index;
+    }
+
+    /// # Arguments
+    ///
+    /// - `serial`: serial of text input state
+    #[inline]
+    fn commit_state(&self, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// # Arguments
+    ///
+    /// - `language`:
+    #[inline]
+    fn preferred_language(&self, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+        let _ = language;
+    }
+}
+
+impl ZwpInputMethodContextV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpInputMethodContextV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputMethodContextV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpInputMethodContextV1EventHandlerWithData<T> {
+    /// surrounding text event
+    ///
+    /// The plain surrounding text around the input position. Cursor is the
+    /// position in bytes within the surrounding text relative to the beginning
+    /// of the text. Anchor is the position in bytes of the selection anchor
+    /// within the surrounding text relative to the beginning of the text. If
+    /// there is no selected text then anchor is the same as cursor.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    /// - `cursor`:
+    /// - `anchor`:
+    #[inline]
+    fn surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        let _ = text;
+        let _ = cursor;
+        let _ = anchor;
+    }
+
+    #[inline]
+    fn reset(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {}
+
+    /// # Arguments
+    ///
+    /// - `hint`:
+    /// - `purpose`:
+    #[inline]
+    fn content_type(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        hint: u32,
+        purpose: u32,
+    ) {
+        let _ = hint;
+        let _ = purpose;
+    }
+
+    /// # Arguments
+    ///
+    /// - `button`:
+    /// - `index`:
+    #[inline]
+    fn invoke_action(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        button: u32,
+        index: u32,
+    ) {
+        let _ = button;
+        let _ = index;
+    }
+
+    /// # Arguments
+    ///
+    /// - `serial`: serial of text input state
+    #[inline]
+    fn commit_state(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// # Arguments
+    ///
+    /// - `language`:
+    #[inline]
+    fn preferred_language(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+        let _ = language;
+    }
+}
+
+/// A mutable event handler for [ZwpInputMethodContextV1] proxies.
+///
+/// This trait is the same as [ZwpInputMethodContextV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputMethodContextV1EventHandlerMut {
+    /// See [ZwpInputMethodContextV1EventHandler::surrounding_text].
+    #[inline]
+    fn surrounding_text(
+        &mut self,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        let _ = text;
+        let _ = cursor;
+        let _ = anchor;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::reset].
+    #[inline]
+    fn reset(&mut self, _slf: &ZwpInputMethodContextV1Ref) {}
+
+    /// See [ZwpInputMethodContextV1EventHandler::content_type].
+    #[inline]
+    fn content_type(&mut self, _slf: &ZwpInputMethodContextV1Ref, hint: u32, purpose: u32) {
+        let _ = hint;
+        let _ = purpose;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::invoke_action].
+    #[inline]
+    fn invoke_action(&mut self, _slf: &ZwpInputMethodContextV1Ref, button: u32, index: u32) {
+        let _ = button;
+        let _ = index;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::commit_state].
+    #[inline]
+    fn commit_state(&mut self, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::preferred_language].
+    #[inline]
+    fn preferred_language(&mut self, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+        let _ = language;
+    }
+}
+
+/// A mutable event handler for [ZwpInputMethodContextV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputMethodContextV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputMethodContextV1EventHandlerMutWithData<T> {
+    /// See [ZwpInputMethodContextV1EventHandler::surrounding_text].
+    #[inline]
+    fn surrounding_text(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        let _ = text;
+        let _ = cursor;
+        let _ = anchor;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::reset].
+    #[inline]
+    fn reset(&mut self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {}
+
+    /// See [ZwpInputMethodContextV1EventHandler::content_type].
+    #[inline]
+    fn content_type(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        hint: u32,
+        purpose: u32,
+    ) {
+        let _ = hint;
+        let _ = purpose;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::invoke_action].
+    #[inline]
+    fn invoke_action(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        button: u32,
+        index: u32,
+    ) {
+        let _ = button;
+        let _ = index;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::commit_state].
+    #[inline]
+    fn commit_state(&mut self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// See [ZwpInputMethodContextV1EventHandler::preferred_language].
+    #[inline]
+    fn preferred_language(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        language: &str,
+    ) {
+        let _ = language;
+    }
+}
+
+impl<H> ZwpInputMethodContextV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpInputMethodContextV1EventHandlerMut,
+{
+    #[inline]
+    fn surrounding_text(
+        &self,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        self.with(|h| h.surrounding_text(_slf, text, cursor, anchor))
+    }
+
+    #[inline]
+    fn reset(&self, _slf: &ZwpInputMethodContextV1Ref) {
+        self.with(|h| h.reset(_slf))
+    }
+
+    #[inline]
+    fn content_type(&self, _slf: &ZwpInputMethodContextV1Ref, hint: u32, purpose: u32) {
+        self.with(|h| h.content_type(_slf, hint, purpose))
+    }
+
+    #[inline]
+    fn invoke_action(&self, _slf: &ZwpInputMethodContextV1Ref, button: u32, index: u32) {
+        self.with(|h| h.invoke_action(_slf, button, index))
+    }
+
+    #[inline]
+    fn commit_state(&self, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        self.with(|h| h.commit_state(_slf, serial))
+    }
+
+    #[inline]
+    fn preferred_language(&self, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+        self.with(|h| h.preferred_language(_slf, language))
+    }
+}
+
+impl<T, H> ZwpInputMethodContextV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpInputMethodContextV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        self.with(|h| h.surrounding_text(_data, _slf, text, cursor, anchor))
+    }
+
+    #[inline]
+    fn reset(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {
+        self.with(|h| h.reset(_data, _slf))
+    }
+
+    #[inline]
+    fn content_type(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        hint: u32,
+        purpose: u32,
+    ) {
+        self.with(|h| h.content_type(_data, _slf, hint, purpose))
+    }
+
+    #[inline]
+    fn invoke_action(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        button: u32,
+        index: u32,
+    ) {
+        self.with(|h| h.invoke_action(_data, _slf, button, index))
+    }
+
+    #[inline]
+    fn commit_state(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        self.with(|h| h.commit_state(_data, _slf, serial))
+    }
+
+    #[inline]
+    fn preferred_language(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+        self.with(|h| h.preferred_language(_data, _slf, language))
+    }
+}
+
+impl<T, U, H> ZwpInputMethodContextV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputMethodContextV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .surrounding_text(_data, _slf, text, cursor, anchor)
+    }
+
+    #[inline]
+    fn reset(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {
+        let _data = self.project(_data);
+        self.handler().reset(_data, _slf)
+    }
+
+    #[inline]
+    fn content_type(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        hint: u32,
+        purpose: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().content_type(_data, _slf, hint, purpose)
+    }
+
+    #[inline]
+    fn invoke_action(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        button: u32,
+        index: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().invoke_action(_data, _slf, button, index)
+    }
+
+    #[inline]
+    fn commit_state(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        let _data = self.project(_data);
+        self.handler().commit_state(_data, _slf, serial)
+    }
+
+    #[inline]
+    fn preferred_language(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+        let _data = self.project(_data);
+        self.handler().preferred_language(_data, _slf, language)
+    }
+}
+
+impl<T, U, H> ZwpInputMethodContextV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputMethodContextV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn surrounding_text(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        text: &str,
+        cursor: u32,
+        anchor: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .surrounding_text(_data, _slf, text, cursor, anchor)
+    }
+
+    #[inline]
+    fn reset(&mut self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().reset(_data, _slf)
+    }
+
+    #[inline]
+    fn content_type(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        hint: u32,
+        purpose: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().content_type(_data, _slf, hint, purpose)
+    }
+
+    #[inline]
+    fn invoke_action(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        button: u32,
+        index: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().invoke_action(_data, _slf, button, index)
+    }
+
+    #[inline]
+    fn commit_state(&mut self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().commit_state(_data, _slf, serial)
+    }
+
+    #[inline]
+    fn preferred_language(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodContextV1Ref,
+        language: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().preferred_language(_data, _slf, language)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpInputMethodContextV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpInputMethodContextV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("zwp_input_method_context_v1", "text", args[0].s) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.surrounding_text(slf, arg0, arg1, arg2);
+            }
+            1 => {
+                self.0.reset(slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.content_type(slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.invoke_action(slf, arg0, arg1);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.commit_state(slf, arg0);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("zwp_input_method_context_v1", "language", args[0].s)
+                };
+                self.0.preferred_language(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_input_method_context_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpInputMethodContextV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpInputMethodContextV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("zwp_input_method_context_v1", "text", args[0].s) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.surrounding_text(data, slf, arg0, arg1, arg2);
+            }
+            1 => {
+                self.0.reset(data, slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.content_type(data, slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.invoke_action(data, slf, arg0, arg1);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.commit_state(data, slf, arg0);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("zwp_input_method_context_v1", "language", args[0].s)
+                };
+                self.0.preferred_language(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_input_method_context_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpInputMethodContextV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpInputMethodContextV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for surrounding_text events.
+    pub struct SurroundingText<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandler for SurroundingText<F>
+    where
+        F: Fn(&ZwpInputMethodContextV1Ref, &str, u32, u32),
+    {
+        #[inline]
+        fn surrounding_text(
+            &self,
+            _slf: &ZwpInputMethodContextV1Ref,
+            text: &str,
+            cursor: u32,
+            anchor: u32,
+        ) {
+            self.0(_slf, text, cursor, anchor)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerWithData<T> for SurroundingText<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodContextV1Ref, &str, u32, u32),
+    {
+        #[inline]
+        fn surrounding_text(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            text: &str,
+            cursor: u32,
+            anchor: u32,
+        ) {
+            self.0(_data, _slf, text, cursor, anchor)
+        }
+    }
+
+    /// Event handler for reset events.
+    pub struct Reset<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandler for Reset<F>
+    where
+        F: Fn(&ZwpInputMethodContextV1Ref),
+    {
+        #[inline]
+        fn reset(&self, _slf: &ZwpInputMethodContextV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerWithData<T> for Reset<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodContextV1Ref),
+    {
+        #[inline]
+        fn reset(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for content_type events.
+    pub struct ContentType<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandler for ContentType<F>
+    where
+        F: Fn(&ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn content_type(&self, _slf: &ZwpInputMethodContextV1Ref, hint: u32, purpose: u32) {
+            self.0(_slf, hint, purpose)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerWithData<T> for ContentType<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn content_type(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            hint: u32,
+            purpose: u32,
+        ) {
+            self.0(_data, _slf, hint, purpose)
+        }
+    }
+
+    /// Event handler for invoke_action events.
+    pub struct InvokeAction<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandler for InvokeAction<F>
+    where
+        F: Fn(&ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn invoke_action(&self, _slf: &ZwpInputMethodContextV1Ref, button: u32, index: u32) {
+            self.0(_slf, button, index)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerWithData<T> for InvokeAction<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn invoke_action(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            button: u32,
+            index: u32,
+        ) {
+            self.0(_data, _slf, button, index)
+        }
+    }
+
+    /// Event handler for commit_state events.
+    pub struct CommitState<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandler for CommitState<F>
+    where
+        F: Fn(&ZwpInputMethodContextV1Ref, u32),
+    {
+        #[inline]
+        fn commit_state(&self, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerWithData<T> for CommitState<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32),
+    {
+        #[inline]
+        fn commit_state(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Event handler for preferred_language events.
+    pub struct PreferredLanguage<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandler for PreferredLanguage<F>
+    where
+        F: Fn(&ZwpInputMethodContextV1Ref, &str),
+    {
+        #[inline]
+        fn preferred_language(&self, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+            self.0(_slf, language)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerWithData<T> for PreferredLanguage<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodContextV1Ref, &str),
+    {
+        #[inline]
+        fn preferred_language(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            language: &str,
+        ) {
+            self.0(_data, _slf, language)
+        }
+    }
+
+    /// Mutable event handler for surrounding_text events.
+    pub struct SurroundingTextMut<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandlerMut for SurroundingTextMut<F>
+    where
+        F: FnMut(&ZwpInputMethodContextV1Ref, &str, u32, u32),
+    {
+        #[inline]
+        fn surrounding_text(
+            &mut self,
+            _slf: &ZwpInputMethodContextV1Ref,
+            text: &str,
+            cursor: u32,
+            anchor: u32,
+        ) {
+            self.0(_slf, text, cursor, anchor)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerMutWithData<T> for SurroundingTextMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, &str, u32, u32),
+    {
+        #[inline]
+        fn surrounding_text(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            text: &str,
+            cursor: u32,
+            anchor: u32,
+        ) {
+            self.0(_data, _slf, text, cursor, anchor)
+        }
+    }
+
+    /// Mutable event handler for reset events.
+    pub struct ResetMut<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandlerMut for ResetMut<F>
+    where
+        F: FnMut(&ZwpInputMethodContextV1Ref),
+    {
+        #[inline]
+        fn reset(&mut self, _slf: &ZwpInputMethodContextV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerMutWithData<T> for ResetMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodContextV1Ref),
+    {
+        #[inline]
+        fn reset(&mut self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for content_type events.
+    pub struct ContentTypeMut<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandlerMut for ContentTypeMut<F>
+    where
+        F: FnMut(&ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn content_type(&mut self, _slf: &ZwpInputMethodContextV1Ref, hint: u32, purpose: u32) {
+            self.0(_slf, hint, purpose)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerMutWithData<T> for ContentTypeMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn content_type(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            hint: u32,
+            purpose: u32,
+        ) {
+            self.0(_data, _slf, hint, purpose)
+        }
+    }
+
+    /// Mutable event handler for invoke_action events.
+    pub struct InvokeActionMut<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandlerMut for InvokeActionMut<F>
+    where
+        F: FnMut(&ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn invoke_action(&mut self, _slf: &ZwpInputMethodContextV1Ref, button: u32, index: u32) {
+            self.0(_slf, button, index)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerMutWithData<T> for InvokeActionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+    {
+        #[inline]
+        fn invoke_action(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            button: u32,
+            index: u32,
+        ) {
+            self.0(_data, _slf, button, index)
+        }
+    }
+
+    /// Mutable event handler for commit_state events.
+    pub struct CommitStateMut<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandlerMut for CommitStateMut<F>
+    where
+        F: FnMut(&ZwpInputMethodContextV1Ref, u32),
+    {
+        #[inline]
+        fn commit_state(&mut self, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerMutWithData<T> for CommitStateMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, u32),
+    {
+        #[inline]
+        fn commit_state(&mut self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Mutable event handler for preferred_language events.
+    pub struct PreferredLanguageMut<F>(F);
+    impl<F> ZwpInputMethodContextV1EventHandlerMut for PreferredLanguageMut<F>
+    where
+        F: FnMut(&ZwpInputMethodContextV1Ref, &str),
+    {
+        #[inline]
+        fn preferred_language(&mut self, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+            self.0(_slf, language)
+        }
+    }
+    impl<T, F> ZwpInputMethodContextV1EventHandlerMutWithData<T> for PreferredLanguageMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, &str),
+    {
+        #[inline]
+        fn preferred_language(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            language: &str,
+        ) {
+            self.0(_data, _slf, language)
+        }
+    }
+
+    impl ZwpInputMethodContextV1 {
+        /// Creates an event handler for surrounding_text events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_surrounding_text<F>(f: F) -> SurroundingText<F>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, &str, u32, u32),
+        {
+            SurroundingText(f)
+        }
+
+        /// Creates an event handler with mutable data for surrounding_text events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_surrounding_text_with_data<T, F>(f: F) -> SurroundingText<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, &str, u32, u32),
+        {
+            SurroundingText(f)
+        }
+
+        /// Creates an event handler for reset events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_reset<F>(f: F) -> Reset<F>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref),
+        {
+            Reset(f)
+        }
+
+        /// Creates an event handler with mutable data for reset events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_reset_with_data<T, F>(f: F) -> Reset<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref),
+        {
+            Reset(f)
+        }
+
+        /// Creates an event handler for content_type events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_content_type<F>(f: F) -> ContentType<F>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            ContentType(f)
+        }
+
+        /// Creates an event handler with mutable data for content_type events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_content_type_with_data<T, F>(f: F) -> ContentType<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            ContentType(f)
+        }
+
+        /// Creates an event handler for invoke_action events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_invoke_action<F>(f: F) -> InvokeAction<F>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            InvokeAction(f)
+        }
+
+        /// Creates an event handler with mutable data for invoke_action events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_invoke_action_with_data<T, F>(f: F) -> InvokeAction<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            InvokeAction(f)
+        }
+
+        /// Creates an event handler for commit_state events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_commit_state<F>(f: F) -> CommitState<F>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, u32),
+        {
+            CommitState(f)
+        }
+
+        /// Creates an event handler with mutable data for commit_state events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_commit_state_with_data<T, F>(f: F) -> CommitState<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32),
+        {
+            CommitState(f)
+        }
+
+        /// Creates an event handler for preferred_language events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_language<F>(f: F) -> PreferredLanguage<F>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, &str),
+        {
+            PreferredLanguage(f)
+        }
+
+        /// Creates an event handler with mutable data for preferred_language events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_language_with_data<T, F>(f: F) -> PreferredLanguage<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, &str),
+        {
+            PreferredLanguage(f)
+        }
+
+        /// Creates a mutable event handler for surrounding_text events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_surrounding_text_mut<F>(f: F) -> SurroundingTextMut<F>
+        where
+            F: FnMut(&ZwpInputMethodContextV1Ref, &str, u32, u32),
+        {
+            SurroundingTextMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for surrounding_text events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_surrounding_text_mut_with_data<T, F>(f: F) -> SurroundingTextMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, &str, u32, u32),
+        {
+            SurroundingTextMut(f)
+        }
+
+        /// Creates a mutable event handler for reset events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_reset_mut<F>(f: F) -> ResetMut<F>
+        where
+            F: FnMut(&ZwpInputMethodContextV1Ref),
+        {
+            ResetMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for reset events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_reset_mut_with_data<T, F>(f: F) -> ResetMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodContextV1Ref),
+        {
+            ResetMut(f)
+        }
+
+        /// Creates a mutable event handler for content_type events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_content_type_mut<F>(f: F) -> ContentTypeMut<F>
+        where
+            F: FnMut(&ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            ContentTypeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for content_type events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_content_type_mut_with_data<T, F>(f: F) -> ContentTypeMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            ContentTypeMut(f)
+        }
+
+        /// Creates a mutable event handler for invoke_action events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_invoke_action_mut<F>(f: F) -> InvokeActionMut<F>
+        where
+            F: FnMut(&ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            InvokeActionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for invoke_action events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_invoke_action_mut_with_data<T, F>(f: F) -> InvokeActionMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            InvokeActionMut(f)
+        }
+
+        /// Creates a mutable event handler for commit_state events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_commit_state_mut<F>(f: F) -> CommitStateMut<F>
+        where
+            F: FnMut(&ZwpInputMethodContextV1Ref, u32),
+        {
+            CommitStateMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for commit_state events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_commit_state_mut_with_data<T, F>(f: F) -> CommitStateMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, u32),
+        {
+            CommitStateMut(f)
+        }
+
+        /// Creates a mutable event handler for preferred_language events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_language_mut<F>(f: F) -> PreferredLanguageMut<F>
+        where
+            F: FnMut(&ZwpInputMethodContextV1Ref, &str),
+        {
+            PreferredLanguageMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preferred_language events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_language_mut_with_data<T, F>(f: F) -> PreferredLanguageMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodContextV1Ref, &str),
+        {
+            PreferredLanguageMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                surrounding_text: Ignore,
+                reset: Ignore,
+                content_type: Ignore,
+                invoke_action: Ignore,
+                commit_state: Ignore,
+                preferred_language: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpInputMethodContextV1EventHandler for Ignore {}
+    impl<T> ZwpInputMethodContextV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpInputMethodContextV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        surrounding_text: H0,
+        reset: H1,
+        content_type: H2,
+        invoke_action: H3,
+        commit_state: H4,
+        preferred_language: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> ZwpInputMethodContextV1EventHandler for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ZwpInputMethodContextV1EventHandler,
+        H1: ZwpInputMethodContextV1EventHandler,
+        H2: ZwpInputMethodContextV1EventHandler,
+        H3: ZwpInputMethodContextV1EventHandler,
+        H4: ZwpInputMethodContextV1EventHandler,
+        H5: ZwpInputMethodContextV1EventHandler,
+    {
+        #[inline]
+        fn surrounding_text(
+            &self,
+            _slf: &ZwpInputMethodContextV1Ref,
+            text: &str,
+            cursor: u32,
+            anchor: u32,
+        ) {
+            self.surrounding_text
+                .surrounding_text(_slf, text, cursor, anchor)
+        }
+
+        #[inline]
+        fn reset(&self, _slf: &ZwpInputMethodContextV1Ref) {
+            self.reset.reset(_slf)
+        }
+
+        #[inline]
+        fn content_type(&self, _slf: &ZwpInputMethodContextV1Ref, hint: u32, purpose: u32) {
+            self.content_type.content_type(_slf, hint, purpose)
+        }
+
+        #[inline]
+        fn invoke_action(&self, _slf: &ZwpInputMethodContextV1Ref, button: u32, index: u32) {
+            self.invoke_action.invoke_action(_slf, button, index)
+        }
+
+        #[inline]
+        fn commit_state(&self, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+            self.commit_state.commit_state(_slf, serial)
+        }
+
+        #[inline]
+        fn preferred_language(&self, _slf: &ZwpInputMethodContextV1Ref, language: &str) {
+            self.preferred_language.preferred_language(_slf, language)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> ZwpInputMethodContextV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ZwpInputMethodContextV1EventHandlerWithData<T>,
+        H1: ZwpInputMethodContextV1EventHandlerWithData<T>,
+        H2: ZwpInputMethodContextV1EventHandlerWithData<T>,
+        H3: ZwpInputMethodContextV1EventHandlerWithData<T>,
+        H4: ZwpInputMethodContextV1EventHandlerWithData<T>,
+        H5: ZwpInputMethodContextV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn surrounding_text(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            text: &str,
+            cursor: u32,
+            anchor: u32,
+        ) {
+            self.surrounding_text
+                .surrounding_text(_data, _slf, text, cursor, anchor)
+        }
+
+        #[inline]
+        fn reset(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref) {
+            self.reset.reset(_data, _slf)
+        }
+
+        #[inline]
+        fn content_type(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            hint: u32,
+            purpose: u32,
+        ) {
+            self.content_type.content_type(_data, _slf, hint, purpose)
+        }
+
+        #[inline]
+        fn invoke_action(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            button: u32,
+            index: u32,
+        ) {
+            self.invoke_action.invoke_action(_data, _slf, button, index)
+        }
+
+        #[inline]
+        fn commit_state(&self, _data: &mut T, _slf: &ZwpInputMethodContextV1Ref, serial: u32) {
+            self.commit_state.commit_state(_data, _slf, serial)
+        }
+
+        #[inline]
+        fn preferred_language(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodContextV1Ref,
+            language: &str,
+        ) {
+            self.preferred_language
+                .preferred_language(_data, _slf, language)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for surrounding_text events.
+        #[allow(dead_code)]
+        pub fn on_surrounding_text<F>(self, f: F) -> Handler<SurroundingText<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, &str, u32, u32),
+        {
+            Handler {
+                surrounding_text: SurroundingText(f),
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for surrounding_text events.
+        #[allow(dead_code)]
+        pub fn on_surrounding_text_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<SurroundingText<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, &str, u32, u32),
+        {
+            Handler {
+                surrounding_text: SurroundingText(f),
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure for reset events.
+        #[allow(dead_code)]
+        pub fn on_reset<F>(self, f: F) -> Handler<H0, Reset<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: Reset(f),
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for reset events.
+        #[allow(dead_code)]
+        pub fn on_reset_with_data<T, F>(self, f: F) -> Handler<H0, Reset<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: Reset(f),
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure for content_type events.
+        #[allow(dead_code)]
+        pub fn on_content_type<F>(self, f: F) -> Handler<H0, H1, ContentType<F>, H3, H4, H5>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: ContentType(f),
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for content_type events.
+        #[allow(dead_code)]
+        pub fn on_content_type_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, ContentType<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: ContentType(f),
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure for invoke_action events.
+        #[allow(dead_code)]
+        pub fn on_invoke_action<F>(self, f: F) -> Handler<H0, H1, H2, InvokeAction<F>, H4, H5>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: InvokeAction(f),
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for invoke_action events.
+        #[allow(dead_code)]
+        pub fn on_invoke_action_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, InvokeAction<F>, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32, u32),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: InvokeAction(f),
+                commit_state: self.commit_state,
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure for commit_state events.
+        #[allow(dead_code)]
+        pub fn on_commit_state<F>(self, f: F) -> Handler<H0, H1, H2, H3, CommitState<F>, H5>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, u32),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: CommitState(f),
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for commit_state events.
+        #[allow(dead_code)]
+        pub fn on_commit_state_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, CommitState<F>, H5>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, u32),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: CommitState(f),
+                preferred_language: self.preferred_language,
+            }
+        }
+
+        /// Sets the closure for preferred_language events.
+        #[allow(dead_code)]
+        pub fn on_preferred_language<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, PreferredLanguage<F>>
+        where
+            F: Fn(&ZwpInputMethodContextV1Ref, &str),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: PreferredLanguage(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preferred_language events.
+        #[allow(dead_code)]
+        pub fn on_preferred_language_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, PreferredLanguage<F>>
+        where
+            F: Fn(&mut T, &ZwpInputMethodContextV1Ref, &str),
+        {
+            Handler {
+                surrounding_text: self.surrounding_text,
+                reset: self.reset,
+                content_type: self.content_type,
+                invoke_action: self.invoke_action,
+                commit_state: self.commit_state,
+                preferred_language: PreferredLanguage(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,875 @@
+//! input method
+//!
+//! An input method object is responsible for composing text in response to
+//! input from hardware or virtual keyboards. There is one input method
+//! object per seat. On activate there is a new input method context object
+//! created which allows the input method to communicate with the text input.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_input_method_v1".as_ptr(),
+    version: 1,
+    method_count: 0,
+    methods: ptr::null(),
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"activate".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpInputMethodContextV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"deactivate".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpInputMethodContextV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_input_method_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_input_method_v1",
+    protocol: "input_method_unstable_v1",
+    version: 1,
+    requests: &[],
+    events: &[
+        info::MessageInfo {
+            name: "activate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_input_method_context_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "deactivate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "context",
+                ty: info::ArgType::Object,
+                interface: Some("zwp_input_method_context_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_input_method_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputMethodV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_input_method_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputMethodV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpInputMethodV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpInputMethodV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpInputMethodV1 {
+    const INTERFACE: &'static str = "zwp_input_method_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpInputMethodV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpInputMethodV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpInputMethodV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpInputMethodV1Ref {
+    type Owned = ZwpInputMethodV1;
+}
+
+impl Deref for ZwpInputMethodV1 {
+    type Target = ZwpInputMethodV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpInputMethodV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_method_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpInputMethodV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_method_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpInputMethodV1Ref> for ZwpInputMethodV1 {
+    fn eq(&self, other: &ZwpInputMethodV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpInputMethodV1> for ZwpInputMethodV1Ref {
+    fn eq(&self, other: &ZwpInputMethodV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl ZwpInputMethodV1 {
+    /// Since when the activate event is available.
+    #[allow(dead_code)]
+    pub const EVT__ACTIVATE__SINCE: u32 = 1;
+
+    /// Since when the deactivate event is available.
+    #[allow(dead_code)]
+    pub const EVT__DEACTIVATE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpInputMethodV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpInputMethodV1EventHandler {
+    /// activate event
+    ///
+    /// A text input was activated. Creates an input method context object
+    /// which allows communication with the text input.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn activate(&self, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        let _ = id;
+    }
+
+    /// deactivate event
+    ///
+    /// The text input corresponding to the context argument was deactivated.
+    /// The input method context should be destroyed after deactivation is
+    /// handled.
+    ///
+    /// # Arguments
+    ///
+    /// - `context`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn deactivate(&self, _slf: &ZwpInputMethodV1Ref, context: Option<&ZwpInputMethodContextV1Ref>) {
+        let _ = context;
+    }
+}
+
+impl ZwpInputMethodV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpInputMethodV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputMethodV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpInputMethodV1EventHandlerWithData<T> {
+    /// activate event
+    ///
+    /// A text input was activated. Creates an input method context object
+    /// which allows communication with the text input.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn activate(&self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        let _ = id;
+    }
+
+    /// deactivate event
+    ///
+    /// The text input corresponding to the context argument was deactivated.
+    /// The input method context should be destroyed after deactivation is
+    /// handled.
+    ///
+    /// # Arguments
+    ///
+    /// - `context`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn deactivate(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodV1Ref,
+        context: Option<&ZwpInputMethodContextV1Ref>,
+    ) {
+        let _ = context;
+    }
+}
+
+/// A mutable event handler for [ZwpInputMethodV1] proxies.
+///
+/// This trait is the same as [ZwpInputMethodV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputMethodV1EventHandlerMut {
+    /// See [ZwpInputMethodV1EventHandler::activate].
+    #[inline]
+    fn activate(&mut self, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        let _ = id;
+    }
+
+    /// See [ZwpInputMethodV1EventHandler::deactivate].
+    #[inline]
+    fn deactivate(
+        &mut self,
+        _slf: &ZwpInputMethodV1Ref,
+        context: Option<&ZwpInputMethodContextV1Ref>,
+    ) {
+        let _ = context;
+    }
+}
+
+/// A mutable event handler for [ZwpInputMethodV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputMethodV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputMethodV1EventHandlerMutWithData<T> {
+    /// See [ZwpInputMethodV1EventHandler::activate].
+    #[inline]
+    fn activate(&mut self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        let _ = id;
+    }
+
+    /// See [ZwpInputMethodV1EventHandler::deactivate].
+    #[inline]
+    fn deactivate(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodV1Ref,
+        context: Option<&ZwpInputMethodContextV1Ref>,
+    ) {
+        let _ = context;
+    }
+}
+
+impl<H> ZwpInputMethodV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpInputMethodV1EventHandlerMut,
+{
+    #[inline]
+    fn activate(&self, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        self.with(|h| h.activate(_slf, id))
+    }
+
+    #[inline]
+    fn deactivate(&self, _slf: &ZwpInputMethodV1Ref, context: Option<&ZwpInputMethodContextV1Ref>) {
+        self.with(|h| h.deactivate(_slf, context))
+    }
+}
+
+impl<T, H> ZwpInputMethodV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpInputMethodV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn activate(&self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        self.with(|h| h.activate(_data, _slf, id))
+    }
+
+    #[inline]
+    fn deactivate(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodV1Ref,
+        context: Option<&ZwpInputMethodContextV1Ref>,
+    ) {
+        self.with(|h| h.deactivate(_data, _slf, context))
+    }
+}
+
+impl<T, U, H> ZwpInputMethodV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputMethodV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn activate(&self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        let _data = self.project(_data);
+        self.handler().activate(_data, _slf, id)
+    }
+
+    #[inline]
+    fn deactivate(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodV1Ref,
+        context: Option<&ZwpInputMethodContextV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().deactivate(_data, _slf, context)
+    }
+}
+
+impl<T, U, H> ZwpInputMethodV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputMethodV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn activate(&mut self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+        let _data = self.project(_data);
+        self.handler_mut().activate(_data, _slf, id)
+    }
+
+    #[inline]
+    fn deactivate(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputMethodV1Ref,
+        context: Option<&ZwpInputMethodContextV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().deactivate(_data, _slf, context)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpInputMethodV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpInputMethodV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpInputMethodContextV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpInputMethodContextV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpInputMethodContextV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ZwpInputMethodContextV1>(arg0)
+                };
+                self.0.activate(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpInputMethodContextV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ZwpInputMethodContextV1Ref>(arg0)
+                });
+                self.0.deactivate(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_input_method_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpInputMethodV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpInputMethodV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpInputMethodContextV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpInputMethodContextV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpInputMethodContextV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ZwpInputMethodContextV1>(arg0)
+                };
+                self.0.activate(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpInputMethodContextV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ZwpInputMethodContextV1Ref>(arg0)
+                });
+                self.0.deactivate(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_input_method_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpInputMethodV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpInputMethodV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for activate events.
+    pub struct Activate<F>(F);
+    impl<F> ZwpInputMethodV1EventHandler for Activate<F>
+    where
+        F: Fn(&ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+    {
+        #[inline]
+        fn activate(&self, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpInputMethodV1EventHandlerWithData<T> for Activate<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+    {
+        #[inline]
+        fn activate(&self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Event handler for deactivate events.
+    pub struct Deactivate<F>(F);
+    impl<F> ZwpInputMethodV1EventHandler for Deactivate<F>
+    where
+        F: Fn(&ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+    {
+        #[inline]
+        fn deactivate(
+            &self,
+            _slf: &ZwpInputMethodV1Ref,
+            context: Option<&ZwpInputMethodContextV1Ref>,
+        ) {
+            self.0(_slf, context)
+        }
+    }
+    impl<T, F> ZwpInputMethodV1EventHandlerWithData<T> for Deactivate<F>
+    where
+        F: Fn(&mut T, &ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+    {
+        #[inline]
+        fn deactivate(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodV1Ref,
+            context: Option<&ZwpInputMethodContextV1Ref>,
+        ) {
+            self.0(_data, _slf, context)
+        }
+    }
+
+    /// Mutable event handler for activate events.
+    pub struct ActivateMut<F>(F);
+    impl<F> ZwpInputMethodV1EventHandlerMut for ActivateMut<F>
+    where
+        F: FnMut(&ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+    {
+        #[inline]
+        fn activate(&mut self, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpInputMethodV1EventHandlerMutWithData<T> for ActivateMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+    {
+        #[inline]
+        fn activate(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodV1Ref,
+            id: ZwpInputMethodContextV1,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for deactivate events.
+    pub struct DeactivateMut<F>(F);
+    impl<F> ZwpInputMethodV1EventHandlerMut for DeactivateMut<F>
+    where
+        F: FnMut(&ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+    {
+        #[inline]
+        fn deactivate(
+            &mut self,
+            _slf: &ZwpInputMethodV1Ref,
+            context: Option<&ZwpInputMethodContextV1Ref>,
+        ) {
+            self.0(_slf, context)
+        }
+    }
+    impl<T, F> ZwpInputMethodV1EventHandlerMutWithData<T> for DeactivateMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+    {
+        #[inline]
+        fn deactivate(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodV1Ref,
+            context: Option<&ZwpInputMethodContextV1Ref>,
+        ) {
+            self.0(_data, _slf, context)
+        }
+    }
+
+    impl ZwpInputMethodV1 {
+        /// Creates an event handler for activate events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_activate<F>(f: F) -> Activate<F>
+        where
+            F: Fn(&ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+        {
+            Activate(f)
+        }
+
+        /// Creates an event handler with mutable data for activate events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_activate_with_data<T, F>(f: F) -> Activate<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+        {
+            Activate(f)
+        }
+
+        /// Creates an event handler for deactivate events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_deactivate<F>(f: F) -> Deactivate<F>
+        where
+            F: Fn(&ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+        {
+            Deactivate(f)
+        }
+
+        /// Creates an event handler with mutable data for deactivate events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_deactivate_with_data<T, F>(f: F) -> Deactivate<F>
+        where
+            F: Fn(&mut T, &ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+        {
+            Deactivate(f)
+        }
+
+        /// Creates a mutable event handler for activate events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_activate_mut<F>(f: F) -> ActivateMut<F>
+        where
+            F: FnMut(&ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+        {
+            ActivateMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for activate events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_activate_mut_with_data<T, F>(f: F) -> ActivateMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+        {
+            ActivateMut(f)
+        }
+
+        /// Creates a mutable event handler for deactivate events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_deactivate_mut<F>(f: F) -> DeactivateMut<F>
+        where
+            F: FnMut(&ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+        {
+            DeactivateMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for deactivate events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_deactivate_mut_with_data<T, F>(f: F) -> DeactivateMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+        {
+            DeactivateMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                activate: Ignore,
+                deactivate: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpInputMethodV1EventHandler for Ignore {}
+    impl<T> ZwpInputMethodV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpInputMethodV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        activate: H0,
+        deactivate: H1,
+    }
+
+    impl<H0, H1> ZwpInputMethodV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpInputMethodV1EventHandler,
+        H1: ZwpInputMethodV1EventHandler,
+    {
+        #[inline]
+        fn activate(&self, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+            self.activate.activate(_slf, id)
+        }
+
+        #[inline]
+        fn deactivate(
+            &self,
+            _slf: &ZwpInputMethodV1Ref,
+            context: Option<&ZwpInputMethodContextV1Ref>,
+        ) {
+            self.deactivate.deactivate(_slf, context)
+        }
+    }
+
+    impl<T, H0, H1> ZwpInputMethodV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpInputMethodV1EventHandlerWithData<T>,
+        H1: ZwpInputMethodV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn activate(&self, _data: &mut T, _slf: &ZwpInputMethodV1Ref, id: ZwpInputMethodContextV1) {
+            self.activate.activate(_data, _slf, id)
+        }
+
+        #[inline]
+        fn deactivate(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputMethodV1Ref,
+            context: Option<&ZwpInputMethodContextV1Ref>,
+        ) {
+            self.deactivate.deactivate(_data, _slf, context)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for activate events.
+        #[allow(dead_code)]
+        pub fn on_activate<F>(self, f: F) -> Handler<Activate<F>, H1>
+        where
+            F: Fn(&ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+        {
+            Handler {
+                activate: Activate(f),
+                deactivate: self.deactivate,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for activate events.
+        #[allow(dead_code)]
+        pub fn on_activate_with_data<T, F>(self, f: F) -> Handler<Activate<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpInputMethodV1Ref, ZwpInputMethodContextV1),
+        {
+            Handler {
+                activate: Activate(f),
+                deactivate: self.deactivate,
+            }
+        }
+
+        /// Sets the closure for deactivate events.
+        #[allow(dead_code)]
+        pub fn on_deactivate<F>(self, f: F) -> Handler<H0, Deactivate<F>>
+        where
+            F: Fn(&ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+        {
+            Handler {
+                activate: self.activate,
+                deactivate: Deactivate(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for deactivate events.
+        #[allow(dead_code)]
+        pub fn on_deactivate_with_data<T, F>(self, f: F) -> Handler<H0, Deactivate<F>>
+        where
+            F: Fn(&mut T, &ZwpInputMethodV1Ref, Option<&ZwpInputMethodContextV1Ref>),
+        {
+            Handler {
+                activate: self.activate,
+                deactivate: Deactivate(f),
+            }
+        }
+    }
+}
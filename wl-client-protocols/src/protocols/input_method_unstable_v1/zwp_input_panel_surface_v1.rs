@@ -0,0 +1,374 @@
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_input_panel_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"set_toplevel".as_ptr(),
+                signature: c"ou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(WlOutput::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_overlay_panel".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_input_panel_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_input_panel_surface_v1",
+    protocol: "input_method_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_toplevel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "output",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_output"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "position",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_overlay_panel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "position",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "center_bottom",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned zwp_input_panel_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputPanelSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_input_panel_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputPanelSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpInputPanelSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpInputPanelSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpInputPanelSurfaceV1 {
+    const INTERFACE: &'static str = "zwp_input_panel_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpInputPanelSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpInputPanelSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpInputPanelSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpInputPanelSurfaceV1Ref {
+    type Owned = ZwpInputPanelSurfaceV1;
+}
+
+impl Deref for ZwpInputPanelSurfaceV1 {
+    type Target = ZwpInputPanelSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpInputPanelSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_panel_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpInputPanelSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_panel_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpInputPanelSurfaceV1Ref> for ZwpInputPanelSurfaceV1 {
+    fn eq(&self, other: &ZwpInputPanelSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpInputPanelSurfaceV1> for ZwpInputPanelSurfaceV1Ref {
+    fn eq(&self, other: &ZwpInputPanelSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputPanelSurfaceV1Ref {
+    /// set the surface type as a keyboard
+    ///
+    /// Set the input_panel_surface type to keyboard.
+    ///
+    /// A keyboard surface is only shown when a text input is active.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`:
+    /// - `position`:
+    #[inline]
+    pub fn set_toplevel(&self, output: &WlOutputRef, position: u32) {
+        let (arg0, arg1) = (output, position);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("output", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `ou`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+
+    /// set the surface type as an overlay panel
+    ///
+    /// Set the input_panel_surface to be an overlay panel.
+    ///
+    /// This is shown near the input cursor above the application window when
+    /// a text input is active.
+    #[inline]
+    pub fn set_overlay_panel(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [ZwpInputPanelSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpInputPanelSurfaceV1EventHandler {}
+
+impl ZwpInputPanelSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpInputPanelSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputPanelSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpInputPanelSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpInputPanelSurfaceV1] proxies.
+///
+/// This trait is the same as [ZwpInputPanelSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputPanelSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpInputPanelSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputPanelSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputPanelSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpInputPanelSurfaceV1EventHandler for MutEventHandler<H> where
+    H: ZwpInputPanelSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpInputPanelSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpInputPanelSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpInputPanelSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpInputPanelSurfaceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpInputPanelSurfaceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpInputPanelSurfaceV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpInputPanelSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_input_panel_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpInputPanelSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_input_panel_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpInputPanelSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpInputPanelSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpInputPanelSurfaceV1 {
+    /// Since when the position.center_bottom enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__POSITION_CENTER_BOTTOM__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpInputPanelSurfaceV1Position(pub u32);
+
+impl ZwpInputPanelSurfaceV1Position {
+    #[allow(dead_code)]
+    pub const CENTER_BOTTOM: Self = Self(0);
+}
+
+impl Debug for ZwpInputPanelSurfaceV1Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::CENTER_BOTTOM => "CENTER_BOTTOM",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpInputPanelSurfaceV1 {}
+}
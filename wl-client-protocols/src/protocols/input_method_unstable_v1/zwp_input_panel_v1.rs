@@ -0,0 +1,345 @@
+//! interface for implementing keyboards
+//!
+//! Only one client can bind this interface at a time.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_input_panel_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"get_input_panel_surface".as_ptr(),
+            signature: c"no".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 2] = [
+                    Some(ZwpInputPanelSurfaceV1::WL_INTERFACE),
+                    Some(WlSurface::WL_INTERFACE),
+                ];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_input_panel_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_input_panel_v1",
+    protocol: "input_method_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "get_input_panel_surface",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[
+            info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_input_panel_surface_v1"),
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            },
+        ],
+    }],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_input_panel_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputPanelV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_input_panel_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputPanelV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpInputPanelV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpInputPanelV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpInputPanelV1 {
+    const INTERFACE: &'static str = "zwp_input_panel_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpInputPanelV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpInputPanelV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpInputPanelV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpInputPanelV1Ref {
+    type Owned = ZwpInputPanelV1;
+}
+
+impl Deref for ZwpInputPanelV1 {
+    type Target = ZwpInputPanelV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpInputPanelV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_panel_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpInputPanelV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_panel_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpInputPanelV1Ref> for ZwpInputPanelV1 {
+    fn eq(&self, other: &ZwpInputPanelV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpInputPanelV1> for ZwpInputPanelV1Ref {
+    fn eq(&self, other: &ZwpInputPanelV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputPanelV1 {
+    /// Since when the get_input_panel_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_INPUT_PANEL_SURFACE__SINCE: u32 = 1;
+
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_input_panel_surface(&self, surface: &WlSurfaceRef) -> ZwpInputPanelSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ZwpInputPanelSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputPanelSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputPanelV1Ref {
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_input_panel_surface(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> ZwpInputPanelSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ZwpInputPanelSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputPanelSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpInputPanelV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpInputPanelV1EventHandler {}
+
+impl ZwpInputPanelV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpInputPanelV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputPanelV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpInputPanelV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpInputPanelV1] proxies.
+///
+/// This trait is the same as [ZwpInputPanelV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputPanelV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpInputPanelV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputPanelV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputPanelV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpInputPanelV1EventHandler for MutEventHandler<H> where H: ZwpInputPanelV1EventHandlerMut {}
+
+impl<T, H> ZwpInputPanelV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpInputPanelV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpInputPanelV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpInputPanelV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpInputPanelV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpInputPanelV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpInputPanelV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_input_panel_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpInputPanelV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_input_panel_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpInputPanelV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpInputPanelV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpInputPanelV1 {}
+}
@@ -0,0 +1,638 @@
+//! context object for high-resolution input timestamps
+//!
+//! A global interface used for requesting high-resolution timestamps
+//! for input events.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_input_timestamps_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 4,
+    methods: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_keyboard_timestamps".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpInputTimestampsV1::WL_INTERFACE),
+                        Some(WlKeyboard::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_pointer_timestamps".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpInputTimestampsV1::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_touch_timestamps".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpInputTimestampsV1::WL_INTERFACE),
+                        Some(WlTouch::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_input_timestamps_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_input_timestamps_manager_v1",
+    protocol: "input_timestamps_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_keyboard_timestamps",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_input_timestamps_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "keyboard",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_keyboard"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_pointer_timestamps",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_input_timestamps_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_touch_timestamps",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_input_timestamps_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "touch",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_touch"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_input_timestamps_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputTimestampsManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_input_timestamps_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputTimestampsManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpInputTimestampsManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpInputTimestampsManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpInputTimestampsManagerV1 {
+    const INTERFACE: &'static str = "zwp_input_timestamps_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpInputTimestampsManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpInputTimestampsManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpInputTimestampsManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpInputTimestampsManagerV1Ref {
+    type Owned = ZwpInputTimestampsManagerV1;
+}
+
+impl Deref for ZwpInputTimestampsManagerV1 {
+    type Target = ZwpInputTimestampsManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpInputTimestampsManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_timestamps_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpInputTimestampsManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_timestamps_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpInputTimestampsManagerV1Ref> for ZwpInputTimestampsManagerV1 {
+    fn eq(&self, other: &ZwpInputTimestampsManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpInputTimestampsManagerV1> for ZwpInputTimestampsManagerV1Ref {
+    fn eq(&self, other: &ZwpInputTimestampsManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputTimestampsManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the input timestamps manager object
+    ///
+    /// Informs the server that the client will no longer be using this
+    /// protocol object. Existing objects created by this object are not
+    /// affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_keyboard_timestamps request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_KEYBOARD_TIMESTAMPS__SINCE: u32 = 1;
+
+    /// subscribe to high-resolution keyboard timestamp events
+    ///
+    /// Creates a new input timestamps object that represents a subscription
+    /// to high-resolution timestamp events for all wl_keyboard events that
+    /// carry a timestamp.
+    ///
+    /// If the associated wl_keyboard object is invalidated, either through
+    /// client action (e.g. release) or server-side changes, the input
+    /// timestamps object becomes inert and the client should destroy it
+    /// by calling zwp_input_timestamps_v1.destroy.
+    ///
+    /// # Arguments
+    ///
+    /// - `keyboard`: the wl_keyboard object for which to get timestamp events
+    #[inline]
+    pub fn get_keyboard_timestamps(&self, keyboard: &WlKeyboardRef) -> ZwpInputTimestampsV1 {
+        let (arg1,) = (keyboard,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("keyboard", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpInputTimestampsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputTimestampsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_pointer_timestamps request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_POINTER_TIMESTAMPS__SINCE: u32 = 1;
+
+    /// subscribe to high-resolution pointer timestamp events
+    ///
+    /// Creates a new input timestamps object that represents a subscription
+    /// to high-resolution timestamp events for all wl_pointer events that
+    /// carry a timestamp.
+    ///
+    /// If the associated wl_pointer object is invalidated, either through
+    /// client action (e.g. release) or server-side changes, the input
+    /// timestamps object becomes inert and the client should destroy it
+    /// by calling zwp_input_timestamps_v1.destroy.
+    ///
+    /// # Arguments
+    ///
+    /// - `pointer`: the wl_pointer object for which to get timestamp events
+    #[inline]
+    pub fn get_pointer_timestamps(&self, pointer: &WlPointerRef) -> ZwpInputTimestampsV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                ZwpInputTimestampsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputTimestampsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_touch_timestamps request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_TOUCH_TIMESTAMPS__SINCE: u32 = 1;
+
+    /// subscribe to high-resolution touch timestamp events
+    ///
+    /// Creates a new input timestamps object that represents a subscription
+    /// to high-resolution timestamp events for all wl_touch events that
+    /// carry a timestamp.
+    ///
+    /// If the associated wl_touch object becomes invalid, either through
+    /// client action (e.g. release) or server-side changes, the input
+    /// timestamps object becomes inert and the client should destroy it
+    /// by calling zwp_input_timestamps_v1.destroy.
+    ///
+    /// # Arguments
+    ///
+    /// - `touch`: the wl_touch object for which to get timestamp events
+    #[inline]
+    pub fn get_touch_timestamps(&self, touch: &WlTouchRef) -> ZwpInputTimestampsV1 {
+        let (arg1,) = (touch,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("touch", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                3,
+                &mut args,
+                ZwpInputTimestampsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputTimestampsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputTimestampsManagerV1Ref {
+    /// subscribe to high-resolution keyboard timestamp events
+    ///
+    /// Creates a new input timestamps object that represents a subscription
+    /// to high-resolution timestamp events for all wl_keyboard events that
+    /// carry a timestamp.
+    ///
+    /// If the associated wl_keyboard object is invalidated, either through
+    /// client action (e.g. release) or server-side changes, the input
+    /// timestamps object becomes inert and the client should destroy it
+    /// by calling zwp_input_timestamps_v1.destroy.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `keyboard`: the wl_keyboard object for which to get timestamp events
+    #[inline]
+    pub fn get_keyboard_timestamps(
+        &self,
+        _queue: &Queue,
+        keyboard: &WlKeyboardRef,
+    ) -> ZwpInputTimestampsV1 {
+        let (arg1,) = (keyboard,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("keyboard", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpInputTimestampsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputTimestampsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// subscribe to high-resolution pointer timestamp events
+    ///
+    /// Creates a new input timestamps object that represents a subscription
+    /// to high-resolution timestamp events for all wl_pointer events that
+    /// carry a timestamp.
+    ///
+    /// If the associated wl_pointer object is invalidated, either through
+    /// client action (e.g. release) or server-side changes, the input
+    /// timestamps object becomes inert and the client should destroy it
+    /// by calling zwp_input_timestamps_v1.destroy.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `pointer`: the wl_pointer object for which to get timestamp events
+    #[inline]
+    pub fn get_pointer_timestamps(
+        &self,
+        _queue: &Queue,
+        pointer: &WlPointerRef,
+    ) -> ZwpInputTimestampsV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                ZwpInputTimestampsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputTimestampsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// subscribe to high-resolution touch timestamp events
+    ///
+    /// Creates a new input timestamps object that represents a subscription
+    /// to high-resolution timestamp events for all wl_touch events that
+    /// carry a timestamp.
+    ///
+    /// If the associated wl_touch object becomes invalid, either through
+    /// client action (e.g. release) or server-side changes, the input
+    /// timestamps object becomes inert and the client should destroy it
+    /// by calling zwp_input_timestamps_v1.destroy.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `touch`: the wl_touch object for which to get timestamp events
+    #[inline]
+    pub fn get_touch_timestamps(&self, _queue: &Queue, touch: &WlTouchRef) -> ZwpInputTimestampsV1 {
+        let (arg1,) = (touch,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("touch", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                3,
+                &mut args,
+                ZwpInputTimestampsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpInputTimestampsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpInputTimestampsManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsManagerV1EventHandler {}
+
+impl ZwpInputTimestampsManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpInputTimestampsManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputTimestampsManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpInputTimestampsManagerV1] proxies.
+///
+/// This trait is the same as [ZwpInputTimestampsManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpInputTimestampsManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputTimestampsManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpInputTimestampsManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpInputTimestampsManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpInputTimestampsManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpInputTimestampsManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpInputTimestampsManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpInputTimestampsManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpInputTimestampsManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputTimestampsManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpInputTimestampsManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_input_timestamps_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpInputTimestampsManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_input_timestamps_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpInputTimestampsManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpInputTimestampsManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpInputTimestampsManagerV1 {}
+}
@@ -0,0 +1,723 @@
+//! context object for input timestamps
+//!
+//! Provides high-resolution timestamp events for a set of subscribed input
+//! events. The set of subscribed input events is determined by the
+//! zwp_input_timestamps_manager_v1 request used to create this object.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_input_timestamps_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"timestamp".as_ptr(),
+            signature: c"uuu".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_input_timestamps_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_input_timestamps_v1",
+    protocol: "input_timestamps_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[info::MessageInfo {
+        name: "timestamp",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[
+            info::ArgInfo {
+                name: "tv_sec_hi",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "tv_sec_lo",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "tv_nsec",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+        ],
+    }],
+    enums: &[],
+};
+
+/// An owned zwp_input_timestamps_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputTimestampsV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_input_timestamps_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpInputTimestampsV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpInputTimestampsV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpInputTimestampsV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpInputTimestampsV1 {
+    const INTERFACE: &'static str = "zwp_input_timestamps_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpInputTimestampsV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpInputTimestampsV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpInputTimestampsV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpInputTimestampsV1Ref {
+    type Owned = ZwpInputTimestampsV1;
+}
+
+impl Deref for ZwpInputTimestampsV1 {
+    type Target = ZwpInputTimestampsV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpInputTimestampsV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_timestamps_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpInputTimestampsV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_input_timestamps_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpInputTimestampsV1Ref> for ZwpInputTimestampsV1 {
+    fn eq(&self, other: &ZwpInputTimestampsV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpInputTimestampsV1> for ZwpInputTimestampsV1Ref {
+    fn eq(&self, other: &ZwpInputTimestampsV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpInputTimestampsV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the input timestamps object
+    ///
+    /// Informs the server that the client will no longer be using this
+    /// protocol object. After the server processes the request, no more
+    /// timestamp events will be emitted.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpInputTimestampsV1 {
+    /// Since when the timestamp event is available.
+    #[allow(dead_code)]
+    pub const EVT__TIMESTAMP__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpInputTimestampsV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsV1EventHandler {
+    /// high-resolution timestamp event
+    ///
+    /// The timestamp event is associated with the first subsequent input event
+    /// carrying a timestamp which belongs to the set of input events this
+    /// object is subscribed to.
+    ///
+    /// The timestamp provided by this event is a high-resolution version of
+    /// the timestamp argument of the associated input event. The provided
+    /// timestamp is in the same clock domain and is at least as accurate as
+    /// the associated input event timestamp.
+    ///
+    /// The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+    /// each component being an unsigned 32-bit value. Whole seconds are in
+    /// tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+    /// and the additional fractional part in tv_nsec as nanoseconds. Hence,
+    /// for valid timestamps tv_nsec must be in [0, 999999999].
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of the timestamp
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of the timestamp
+    /// - `tv_nsec`: nanoseconds part of the timestamp
+    #[inline]
+    fn timestamp(
+        &self,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+}
+
+impl ZwpInputTimestampsV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpInputTimestampsV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputTimestampsV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsV1EventHandlerWithData<T> {
+    /// high-resolution timestamp event
+    ///
+    /// The timestamp event is associated with the first subsequent input event
+    /// carrying a timestamp which belongs to the set of input events this
+    /// object is subscribed to.
+    ///
+    /// The timestamp provided by this event is a high-resolution version of
+    /// the timestamp argument of the associated input event. The provided
+    /// timestamp is in the same clock domain and is at least as accurate as
+    /// the associated input event timestamp.
+    ///
+    /// The timestamp is expressed as tv_sec_hi, tv_sec_lo, tv_nsec triples,
+    /// each component being an unsigned 32-bit value. Whole seconds are in
+    /// tv_sec which is a 64-bit value combined from tv_sec_hi and tv_sec_lo,
+    /// and the additional fractional part in tv_nsec as nanoseconds. Hence,
+    /// for valid timestamps tv_nsec must be in [0, 999999999].
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of the timestamp
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of the timestamp
+    /// - `tv_nsec`: nanoseconds part of the timestamp
+    #[inline]
+    fn timestamp(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+}
+
+/// A mutable event handler for [ZwpInputTimestampsV1] proxies.
+///
+/// This trait is the same as [ZwpInputTimestampsV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsV1EventHandlerMut {
+    /// See [ZwpInputTimestampsV1EventHandler::timestamp].
+    #[inline]
+    fn timestamp(
+        &mut self,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+}
+
+/// A mutable event handler for [ZwpInputTimestampsV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpInputTimestampsV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpInputTimestampsV1EventHandlerMutWithData<T> {
+    /// See [ZwpInputTimestampsV1EventHandler::timestamp].
+    #[inline]
+    fn timestamp(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+    }
+}
+
+impl<H> ZwpInputTimestampsV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpInputTimestampsV1EventHandlerMut,
+{
+    #[inline]
+    fn timestamp(
+        &self,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        self.with(|h| h.timestamp(_slf, tv_sec_hi, tv_sec_lo, tv_nsec))
+    }
+}
+
+impl<T, H> ZwpInputTimestampsV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpInputTimestampsV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn timestamp(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        self.with(|h| h.timestamp(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec))
+    }
+}
+
+impl<T, U, H> ZwpInputTimestampsV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputTimestampsV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn timestamp(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .timestamp(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+    }
+}
+
+impl<T, U, H> ZwpInputTimestampsV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpInputTimestampsV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn timestamp(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpInputTimestampsV1Ref,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .timestamp(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpInputTimestampsV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpInputTimestampsV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.timestamp(slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_input_timestamps_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpInputTimestampsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpInputTimestampsV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.timestamp(data, slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_input_timestamps_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpInputTimestampsV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpInputTimestampsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for timestamp events.
+    pub struct Timestamp<F>(F);
+    impl<F> ZwpInputTimestampsV1EventHandler for Timestamp<F>
+    where
+        F: Fn(&ZwpInputTimestampsV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn timestamp(
+            &self,
+            _slf: &ZwpInputTimestampsV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+    impl<T, F> ZwpInputTimestampsV1EventHandlerWithData<T> for Timestamp<F>
+    where
+        F: Fn(&mut T, &ZwpInputTimestampsV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn timestamp(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputTimestampsV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+
+    /// Mutable event handler for timestamp events.
+    pub struct TimestampMut<F>(F);
+    impl<F> ZwpInputTimestampsV1EventHandlerMut for TimestampMut<F>
+    where
+        F: FnMut(&ZwpInputTimestampsV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn timestamp(
+            &mut self,
+            _slf: &ZwpInputTimestampsV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+    impl<T, F> ZwpInputTimestampsV1EventHandlerMutWithData<T> for TimestampMut<F>
+    where
+        F: FnMut(&mut T, &ZwpInputTimestampsV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn timestamp(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpInputTimestampsV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.0(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+
+    impl ZwpInputTimestampsV1 {
+        /// Creates an event handler for timestamp events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_timestamp<F>(f: F) -> Timestamp<F>
+        where
+            F: Fn(&ZwpInputTimestampsV1Ref, u32, u32, u32),
+        {
+            Timestamp(f)
+        }
+
+        /// Creates an event handler with mutable data for timestamp events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_timestamp_with_data<T, F>(f: F) -> Timestamp<F>
+        where
+            F: Fn(&mut T, &ZwpInputTimestampsV1Ref, u32, u32, u32),
+        {
+            Timestamp(f)
+        }
+
+        /// Creates a mutable event handler for timestamp events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_timestamp_mut<F>(f: F) -> TimestampMut<F>
+        where
+            F: FnMut(&ZwpInputTimestampsV1Ref, u32, u32, u32),
+        {
+            TimestampMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for timestamp events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_timestamp_mut_with_data<T, F>(f: F) -> TimestampMut<F>
+        where
+            F: FnMut(&mut T, &ZwpInputTimestampsV1Ref, u32, u32, u32),
+        {
+            TimestampMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { timestamp: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpInputTimestampsV1EventHandler for Ignore {}
+    impl<T> ZwpInputTimestampsV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpInputTimestampsV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        timestamp: H0,
+    }
+
+    impl<H0> ZwpInputTimestampsV1EventHandler for Handler<H0>
+    where
+        H0: ZwpInputTimestampsV1EventHandler,
+    {
+        #[inline]
+        fn timestamp(
+            &self,
+            _slf: &ZwpInputTimestampsV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.timestamp
+                .timestamp(_slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+
+    impl<T, H0> ZwpInputTimestampsV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZwpInputTimestampsV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn timestamp(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpInputTimestampsV1Ref,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+        ) {
+            self.timestamp
+                .timestamp(_data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for timestamp events.
+        #[allow(dead_code)]
+        pub fn on_timestamp<F>(self, f: F) -> Handler<Timestamp<F>>
+        where
+            F: Fn(&ZwpInputTimestampsV1Ref, u32, u32, u32),
+        {
+            Handler {
+                timestamp: Timestamp(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for timestamp events.
+        #[allow(dead_code)]
+        pub fn on_timestamp_with_data<T, F>(self, f: F) -> Handler<Timestamp<F>>
+        where
+            F: Fn(&mut T, &ZwpInputTimestampsV1Ref, u32, u32, u32),
+        {
+            Handler {
+                timestamp: Timestamp(f),
+            }
+        }
+    }
+}
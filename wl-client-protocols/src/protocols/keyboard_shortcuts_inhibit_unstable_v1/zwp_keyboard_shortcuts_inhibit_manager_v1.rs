@@ -0,0 +1,476 @@
+//! context object for keyboard grab_manager
+//!
+//! A global interface used for inhibiting the compositor keyboard shortcuts.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_keyboard_shortcuts_inhibit_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"inhibit_shortcuts".as_ptr(),
+                signature: c"noo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ZwpKeyboardShortcutsInhibitorV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_keyboard_shortcuts_inhibit_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_keyboard_shortcuts_inhibit_manager_v1",
+    protocol: "keyboard_shortcuts_inhibit_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "inhibit_shortcuts",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_keyboard_shortcuts_inhibitor_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "already_inhibited",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned zwp_keyboard_shortcuts_inhibit_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpKeyboardShortcutsInhibitManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_keyboard_shortcuts_inhibit_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpKeyboardShortcutsInhibitManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpKeyboardShortcutsInhibitManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpKeyboardShortcutsInhibitManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpKeyboardShortcutsInhibitManagerV1 {
+    const INTERFACE: &'static str = "zwp_keyboard_shortcuts_inhibit_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpKeyboardShortcutsInhibitManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpKeyboardShortcutsInhibitManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpKeyboardShortcutsInhibitManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpKeyboardShortcutsInhibitManagerV1Ref {
+    type Owned = ZwpKeyboardShortcutsInhibitManagerV1;
+}
+
+impl Deref for ZwpKeyboardShortcutsInhibitManagerV1 {
+    type Target = ZwpKeyboardShortcutsInhibitManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpKeyboardShortcutsInhibitManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_keyboard_shortcuts_inhibit_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ZwpKeyboardShortcutsInhibitManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_keyboard_shortcuts_inhibit_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ZwpKeyboardShortcutsInhibitManagerV1Ref> for ZwpKeyboardShortcutsInhibitManagerV1 {
+    fn eq(&self, other: &ZwpKeyboardShortcutsInhibitManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpKeyboardShortcutsInhibitManagerV1> for ZwpKeyboardShortcutsInhibitManagerV1Ref {
+    fn eq(&self, other: &ZwpKeyboardShortcutsInhibitManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpKeyboardShortcutsInhibitManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the keyboard shortcuts inhibitor object
+    ///
+    /// Destroy the keyboard shortcuts inhibitor manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the inhibit_shortcuts request is available.
+    #[allow(dead_code)]
+    pub const REQ__INHIBIT_SHORTCUTS__SINCE: u32 = 1;
+
+    /// create a new keyboard shortcuts inhibitor object
+    ///
+    /// Create a new keyboard shortcuts inhibitor object associated with
+    /// the given surface for the given seat.
+    ///
+    /// If shortcuts are already inhibited for the specified seat and surface,
+    /// a protocol error "already_inhibited" is raised by the compositor.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface that inhibits the keyboard shortcuts behavior
+    /// - `seat`: the wl_seat for which keyboard shortcuts should be disabled
+    #[inline]
+    pub fn inhibit_shortcuts(
+        &self,
+        surface: &WlSurfaceRef,
+        seat: &WlSeatRef,
+    ) -> ZwpKeyboardShortcutsInhibitorV1 {
+        let (arg1, arg2) = (surface, seat);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpKeyboardShortcutsInhibitorV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpKeyboardShortcutsInhibitorV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpKeyboardShortcutsInhibitManagerV1Ref {
+    /// create a new keyboard shortcuts inhibitor object
+    ///
+    /// Create a new keyboard shortcuts inhibitor object associated with
+    /// the given surface for the given seat.
+    ///
+    /// If shortcuts are already inhibited for the specified seat and surface,
+    /// a protocol error "already_inhibited" is raised by the compositor.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface that inhibits the keyboard shortcuts behavior
+    /// - `seat`: the wl_seat for which keyboard shortcuts should be disabled
+    #[inline]
+    pub fn inhibit_shortcuts(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+        seat: &WlSeatRef,
+    ) -> ZwpKeyboardShortcutsInhibitorV1 {
+        let (arg1, arg2) = (surface, seat);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpKeyboardShortcutsInhibitorV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpKeyboardShortcutsInhibitorV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpKeyboardShortcutsInhibitManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitManagerV1EventHandler {}
+
+impl ZwpKeyboardShortcutsInhibitManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpKeyboardShortcutsInhibitManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpKeyboardShortcutsInhibitManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpKeyboardShortcutsInhibitManagerV1] proxies.
+///
+/// This trait is the same as [ZwpKeyboardShortcutsInhibitManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpKeyboardShortcutsInhibitManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpKeyboardShortcutsInhibitManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> ZwpKeyboardShortcutsInhibitManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_keyboard_shortcuts_inhibit_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_keyboard_shortcuts_inhibit_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpKeyboardShortcutsInhibitManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpKeyboardShortcutsInhibitManagerV1 {
+    /// Since when the error.already_inhibited enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_INHIBITED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpKeyboardShortcutsInhibitManagerV1Error(pub u32);
+
+impl ZwpKeyboardShortcutsInhibitManagerV1Error {
+    /// the shortcuts are already inhibited for this surface
+    #[allow(dead_code)]
+    pub const ALREADY_INHIBITED: Self = Self(0);
+}
+
+impl Debug for ZwpKeyboardShortcutsInhibitManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_INHIBITED => "ALREADY_INHIBITED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpKeyboardShortcutsInhibitManagerV1 {}
+}
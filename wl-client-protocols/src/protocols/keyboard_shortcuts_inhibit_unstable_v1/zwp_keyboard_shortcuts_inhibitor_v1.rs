@@ -0,0 +1,790 @@
+//! context object for keyboard shortcuts inhibitor
+//!
+//! A keyboard shortcuts inhibitor instructs the compositor to ignore
+//! its own keyboard shortcuts when the associated surface has keyboard
+//! focus. As a result, when the surface has keyboard focus on the given
+//! seat, it will receive all key events originating from the specified
+//! seat, even those which would normally be caught by the compositor for
+//! its own shortcuts.
+//!
+//! The Wayland compositor is however under no obligation to disable
+//! all of its shortcuts, and may keep some special key combo for its own
+//! use, including but not limited to one allowing the user to forcibly
+//! restore normal keyboard events routing in the case of an unwilling
+//! client. The compositor may also use the same key combo to reactivate
+//! an existing shortcut inhibitor that was previously deactivated on
+//! user request.
+//!
+//! When the compositor restores its own keyboard shortcuts, an
+//! "inactive" event is emitted to notify the client that the keyboard
+//! shortcuts inhibitor is not effectively active for the surface and
+//! seat any more, and the client should not expect to receive all
+//! keyboard events.
+//!
+//! When the keyboard shortcuts inhibitor is inactive, the client has
+//! no way to forcibly reactivate the keyboard shortcuts inhibitor.
+//!
+//! The user can chose to re-enable a previously deactivated keyboard
+//! shortcuts inhibitor using any mechanism the compositor may offer,
+//! in which case the compositor will send an "active" event to notify
+//! the client.
+//!
+//! If the surface is destroyed, unmapped, or loses the seat's keyboard
+//! focus, the keyboard shortcuts inhibitor becomes irrelevant and the
+//! compositor will restore its own keyboard shortcuts but no "inactive"
+//! event is emitted in this case.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_keyboard_shortcuts_inhibitor_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"active".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"inactive".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_keyboard_shortcuts_inhibitor_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_keyboard_shortcuts_inhibitor_v1",
+    protocol: "keyboard_shortcuts_inhibit_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "active",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "inactive",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_keyboard_shortcuts_inhibitor_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpKeyboardShortcutsInhibitorV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_keyboard_shortcuts_inhibitor_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpKeyboardShortcutsInhibitorV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpKeyboardShortcutsInhibitorV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpKeyboardShortcutsInhibitorV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpKeyboardShortcutsInhibitorV1 {
+    const INTERFACE: &'static str = "zwp_keyboard_shortcuts_inhibitor_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpKeyboardShortcutsInhibitorV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpKeyboardShortcutsInhibitorV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpKeyboardShortcutsInhibitorV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpKeyboardShortcutsInhibitorV1Ref {
+    type Owned = ZwpKeyboardShortcutsInhibitorV1;
+}
+
+impl Deref for ZwpKeyboardShortcutsInhibitorV1 {
+    type Target = ZwpKeyboardShortcutsInhibitorV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpKeyboardShortcutsInhibitorV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_keyboard_shortcuts_inhibitor_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpKeyboardShortcutsInhibitorV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_keyboard_shortcuts_inhibitor_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpKeyboardShortcutsInhibitorV1Ref> for ZwpKeyboardShortcutsInhibitorV1 {
+    fn eq(&self, other: &ZwpKeyboardShortcutsInhibitorV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpKeyboardShortcutsInhibitorV1> for ZwpKeyboardShortcutsInhibitorV1Ref {
+    fn eq(&self, other: &ZwpKeyboardShortcutsInhibitorV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpKeyboardShortcutsInhibitorV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the keyboard shortcuts inhibitor object
+    ///
+    /// Remove the keyboard shortcuts inhibitor from the associated wl_surface.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpKeyboardShortcutsInhibitorV1 {
+    /// Since when the active event is available.
+    #[allow(dead_code)]
+    pub const EVT__ACTIVE__SINCE: u32 = 1;
+
+    /// Since when the inactive event is available.
+    #[allow(dead_code)]
+    pub const EVT__INACTIVE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpKeyboardShortcutsInhibitorV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitorV1EventHandler {
+    /// shortcuts are inhibited
+    ///
+    /// This event indicates that the shortcut inhibitor is active.
+    ///
+    /// The compositor sends this event every time compositor shortcuts
+    /// are inhibited on behalf of the surface. When active, the client
+    /// may receive input events normally reserved by the compositor
+    /// (see zwp_keyboard_shortcuts_inhibitor_v1).
+    ///
+    /// This occurs typically when the initial request "inhibit_shortcuts"
+    /// first becomes active or when the user instructs the compositor to
+    /// re-enable and existing shortcuts inhibitor using any mechanism
+    /// offered by the compositor.
+    #[inline]
+    fn active(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+
+    /// shortcuts are restored
+    ///
+    /// This event indicates that the shortcuts inhibitor is inactive,
+    /// normal shortcuts processing is restored by the compositor.
+    #[inline]
+    fn inactive(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+}
+
+impl ZwpKeyboardShortcutsInhibitorV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpKeyboardShortcutsInhibitorV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpKeyboardShortcutsInhibitorV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T> {
+    /// shortcuts are inhibited
+    ///
+    /// This event indicates that the shortcut inhibitor is active.
+    ///
+    /// The compositor sends this event every time compositor shortcuts
+    /// are inhibited on behalf of the surface. When active, the client
+    /// may receive input events normally reserved by the compositor
+    /// (see zwp_keyboard_shortcuts_inhibitor_v1).
+    ///
+    /// This occurs typically when the initial request "inhibit_shortcuts"
+    /// first becomes active or when the user instructs the compositor to
+    /// re-enable and existing shortcuts inhibitor using any mechanism
+    /// offered by the compositor.
+    #[inline]
+    fn active(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+
+    /// shortcuts are restored
+    ///
+    /// This event indicates that the shortcuts inhibitor is inactive,
+    /// normal shortcuts processing is restored by the compositor.
+    #[inline]
+    fn inactive(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpKeyboardShortcutsInhibitorV1] proxies.
+///
+/// This trait is the same as [ZwpKeyboardShortcutsInhibitorV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitorV1EventHandlerMut {
+    /// See [ZwpKeyboardShortcutsInhibitorV1EventHandler::active].
+    #[inline]
+    fn active(&mut self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+
+    /// See [ZwpKeyboardShortcutsInhibitorV1EventHandler::inactive].
+    #[inline]
+    fn inactive(&mut self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpKeyboardShortcutsInhibitorV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpKeyboardShortcutsInhibitorV1EventHandlerMutWithData<T> {
+    /// See [ZwpKeyboardShortcutsInhibitorV1EventHandler::active].
+    #[inline]
+    fn active(&mut self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+
+    /// See [ZwpKeyboardShortcutsInhibitorV1EventHandler::inactive].
+    #[inline]
+    fn inactive(&mut self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {}
+}
+
+impl<H> ZwpKeyboardShortcutsInhibitorV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandlerMut,
+{
+    #[inline]
+    fn active(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        self.with(|h| h.active(_slf))
+    }
+
+    #[inline]
+    fn inactive(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        self.with(|h| h.inactive(_slf))
+    }
+}
+
+impl<T, H> ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn active(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        self.with(|h| h.active(_data, _slf))
+    }
+
+    #[inline]
+    fn inactive(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        self.with(|h| h.inactive(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn active(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        let _data = self.project(_data);
+        self.handler().active(_data, _slf)
+    }
+
+    #[inline]
+    fn inactive(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        let _data = self.project(_data);
+        self.handler().inactive(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpKeyboardShortcutsInhibitorV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn active(&mut self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().active(_data, _slf)
+    }
+
+    #[inline]
+    fn inactive(&mut self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().inactive(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpKeyboardShortcutsInhibitorV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                self.0.active(slf);
+            }
+            1 => {
+                self.0.inactive(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_keyboard_shortcuts_inhibitor_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpKeyboardShortcutsInhibitorV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.active(data, slf);
+            }
+            1 => {
+                self.0.inactive(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_keyboard_shortcuts_inhibitor_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for active events.
+    pub struct Active<F>(F);
+    impl<F> ZwpKeyboardShortcutsInhibitorV1EventHandler for Active<F>
+    where
+        F: Fn(&ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn active(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T> for Active<F>
+    where
+        F: Fn(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn active(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for inactive events.
+    pub struct Inactive<F>(F);
+    impl<F> ZwpKeyboardShortcutsInhibitorV1EventHandler for Inactive<F>
+    where
+        F: Fn(&ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn inactive(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T> for Inactive<F>
+    where
+        F: Fn(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn inactive(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for active events.
+    pub struct ActiveMut<F>(F);
+    impl<F> ZwpKeyboardShortcutsInhibitorV1EventHandlerMut for ActiveMut<F>
+    where
+        F: FnMut(&ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn active(&mut self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpKeyboardShortcutsInhibitorV1EventHandlerMutWithData<T> for ActiveMut<F>
+    where
+        F: FnMut(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn active(&mut self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for inactive events.
+    pub struct InactiveMut<F>(F);
+    impl<F> ZwpKeyboardShortcutsInhibitorV1EventHandlerMut for InactiveMut<F>
+    where
+        F: FnMut(&ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn inactive(&mut self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpKeyboardShortcutsInhibitorV1EventHandlerMutWithData<T> for InactiveMut<F>
+    where
+        F: FnMut(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+    {
+        #[inline]
+        fn inactive(&mut self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpKeyboardShortcutsInhibitorV1 {
+        /// Creates an event handler for active events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_active<F>(f: F) -> Active<F>
+        where
+            F: Fn(&ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Active(f)
+        }
+
+        /// Creates an event handler with mutable data for active events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_active_with_data<T, F>(f: F) -> Active<F>
+        where
+            F: Fn(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Active(f)
+        }
+
+        /// Creates an event handler for inactive events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_inactive<F>(f: F) -> Inactive<F>
+        where
+            F: Fn(&ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Inactive(f)
+        }
+
+        /// Creates an event handler with mutable data for inactive events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_inactive_with_data<T, F>(f: F) -> Inactive<F>
+        where
+            F: Fn(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Inactive(f)
+        }
+
+        /// Creates a mutable event handler for active events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_active_mut<F>(f: F) -> ActiveMut<F>
+        where
+            F: FnMut(&ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            ActiveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for active events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_active_mut_with_data<T, F>(f: F) -> ActiveMut<F>
+        where
+            F: FnMut(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            ActiveMut(f)
+        }
+
+        /// Creates a mutable event handler for inactive events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_inactive_mut<F>(f: F) -> InactiveMut<F>
+        where
+            F: FnMut(&ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            InactiveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for inactive events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_inactive_mut_with_data<T, F>(f: F) -> InactiveMut<F>
+        where
+            F: FnMut(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            InactiveMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                active: Ignore,
+                inactive: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpKeyboardShortcutsInhibitorV1EventHandler for Ignore {}
+    impl<T> ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpKeyboardShortcutsInhibitorV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        active: H0,
+        inactive: H1,
+    }
+
+    impl<H0, H1> ZwpKeyboardShortcutsInhibitorV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpKeyboardShortcutsInhibitorV1EventHandler,
+        H1: ZwpKeyboardShortcutsInhibitorV1EventHandler,
+    {
+        #[inline]
+        fn active(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.active.active(_slf)
+        }
+
+        #[inline]
+        fn inactive(&self, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.inactive.inactive(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T>,
+        H1: ZwpKeyboardShortcutsInhibitorV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn active(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.active.active(_data, _slf)
+        }
+
+        #[inline]
+        fn inactive(&self, _data: &mut T, _slf: &ZwpKeyboardShortcutsInhibitorV1Ref) {
+            self.inactive.inactive(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for active events.
+        #[allow(dead_code)]
+        pub fn on_active<F>(self, f: F) -> Handler<Active<F>, H1>
+        where
+            F: Fn(&ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Handler {
+                active: Active(f),
+                inactive: self.inactive,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for active events.
+        #[allow(dead_code)]
+        pub fn on_active_with_data<T, F>(self, f: F) -> Handler<Active<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Handler {
+                active: Active(f),
+                inactive: self.inactive,
+            }
+        }
+
+        /// Sets the closure for inactive events.
+        #[allow(dead_code)]
+        pub fn on_inactive<F>(self, f: F) -> Handler<H0, Inactive<F>>
+        where
+            F: Fn(&ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Handler {
+                active: self.active,
+                inactive: Inactive(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for inactive events.
+        #[allow(dead_code)]
+        pub fn on_inactive_with_data<T, F>(self, f: F) -> Handler<H0, Inactive<F>>
+        where
+            F: Fn(&mut T, &ZwpKeyboardShortcutsInhibitorV1Ref),
+        {
+            Handler {
+                active: self.active,
+                inactive: Inactive(f),
+            }
+        }
+    }
+}
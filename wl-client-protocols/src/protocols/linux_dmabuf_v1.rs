@@ -0,0 +1,24 @@
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod zwp_linux_buffer_params_v1;
+pub mod zwp_linux_dmabuf_feedback_v1;
+pub mod zwp_linux_dmabuf_v1;
+
+/// Metadata about the linux_dmabuf_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "linux_dmabuf_v1",
+    interfaces: &[
+        &zwp_linux_dmabuf_v1::INFO,
+        &zwp_linux_buffer_params_v1::INFO,
+        &zwp_linux_dmabuf_feedback_v1::INFO,
+    ],
+};
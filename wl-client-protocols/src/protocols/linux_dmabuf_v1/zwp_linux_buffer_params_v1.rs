@@ -0,0 +1,1669 @@
+//! parameters for creating a dmabuf-based wl_buffer
+//!
+//! This temporary object is a collection of dmabufs and other
+//! parameters that together form a single logical buffer. The temporary
+//! object may eventually create one wl_buffer unless cancelled by
+//! destroying it before requesting 'create'.
+//!
+//! Single-planar formats only require one dmabuf, however
+//! multi-planar formats may require more than one dmabuf. For all
+//! formats, an 'add' request must be called once per plane (even if the
+//! underlying dmabuf fd is identical).
+//!
+//! You must use consecutive plane indices ('plane_idx' argument for 'add')
+//! from zero to the number of planes used by the drm_fourcc format code.
+//! All planes required by the format must be given exactly once, but can
+//! be given in any order. Each plane index can only be set once; subsequent
+//! calls with a plane index which has already been set will result in a
+//! plane_set error being generated.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_linux_buffer_params_v1".as_ptr(),
+    version: 5,
+    method_count: 4,
+    methods: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"add".as_ptr(),
+                signature: c"huuuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 6] =
+                        [None, None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create".as_ptr(),
+                signature: c"iiuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_immed".as_ptr(),
+                signature: c"niiuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] =
+                        [Some(WlBuffer::WL_INTERFACE), None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"created".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlBuffer::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"failed".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_linux_buffer_params_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_linux_buffer_params_v1",
+    protocol: "linux_dmabuf_v1",
+    version: 5,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "add",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "plane_idx",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "offset",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "stride",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifier_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifier_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "create",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "format",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "flags",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("flags"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "create_immed",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "buffer_id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_buffer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "format",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "flags",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("flags"),
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "created",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "buffer",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_buffer"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "failed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "already_used",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "plane_idx",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "plane_set",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "incomplete",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_format",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_dimensions",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "out_of_bounds",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_wl_buffer",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "flags",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "y_invert",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "interlaced",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom_first",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned zwp_linux_buffer_params_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxBufferParamsV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_linux_buffer_params_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxBufferParamsV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLinuxBufferParamsV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLinuxBufferParamsV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLinuxBufferParamsV1 {
+    const INTERFACE: &'static str = "zwp_linux_buffer_params_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 5;
+
+    type Borrowed = ZwpLinuxBufferParamsV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLinuxBufferParamsV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLinuxBufferParamsV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLinuxBufferParamsV1Ref {
+    type Owned = ZwpLinuxBufferParamsV1;
+}
+
+impl Deref for ZwpLinuxBufferParamsV1 {
+    type Target = ZwpLinuxBufferParamsV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLinuxBufferParamsV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_buffer_params_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpLinuxBufferParamsV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_buffer_params_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpLinuxBufferParamsV1Ref> for ZwpLinuxBufferParamsV1 {
+    fn eq(&self, other: &ZwpLinuxBufferParamsV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLinuxBufferParamsV1> for ZwpLinuxBufferParamsV1Ref {
+    fn eq(&self, other: &ZwpLinuxBufferParamsV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxBufferParamsV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// delete this object, used or not
+    ///
+    /// Cleans up the temporary data sent to the server for dmabuf-based
+    /// wl_buffer creation.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the create_immed request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_IMMED__SINCE: u32 = 2;
+
+    /// immediately create a wl_buffer from the given
+    ///                      dmabufs
+    ///
+    /// This asks for immediate creation of a wl_buffer by importing the
+    /// added dmabufs.
+    ///
+    /// In case of import success, no event is sent from the server, and the
+    /// wl_buffer is ready to be used by the client.
+    ///
+    /// Upon import failure, either of the following may happen, as seen fit
+    /// by the implementation:
+    /// - the client is terminated with one of the following fatal protocol
+    ///   errors:
+    ///   - INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS, OUT_OF_BOUNDS,
+    ///     in case of argument errors such as mismatch between the number
+    ///     of planes and the format, bad format, non-positive width or
+    ///     height, or bad offset or stride.
+    ///   - INVALID_WL_BUFFER, in case the cause for failure is unknown or
+    ///     platform specific.
+    /// - the server creates an invalid wl_buffer, marks it as failed and
+    ///   sends a 'failed' event to the client. The result of using this
+    ///   invalid wl_buffer as an argument in any request by the client is
+    ///   defined by the compositor implementation.
+    ///
+    /// This takes the same arguments as a 'create' request, and obeys the
+    /// same restrictions.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: base plane width in pixels
+    /// - `height`: base plane height in pixels
+    /// - `format`: DRM_FORMAT code
+    /// - `flags`: see enum flags
+    #[inline]
+    pub fn create_immed(
+        &self,
+        width: i32,
+        height: i32,
+        format: u32,
+        flags: ZwpLinuxBufferParamsV1Flags,
+    ) -> WlBuffer {
+        let (arg1, arg2, arg3, arg4) = (width, height, format, flags);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `niiuu`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(3, &mut args, WlBuffer::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WlBuffer::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxBufferParamsV1Ref {
+    /// add a dmabuf to the temporary set
+    ///
+    /// This request adds one dmabuf to the set in this
+    /// zwp_linux_buffer_params_v1.
+    ///
+    /// The 64-bit unsigned value combined from modifier_hi and modifier_lo
+    /// is the dmabuf layout modifier. DRM AddFB2 ioctl calls this the
+    /// fb modifier, which is defined in drm_mode.h of Linux UAPI.
+    /// This is an opaque token. Drivers use this token to express tiling,
+    /// compression, etc. driver-specific modifications to the base format
+    /// defined by the DRM fourcc code.
+    ///
+    /// Starting from version 4, the invalid_format protocol error is sent if
+    /// the format + modifier pair was not advertised as supported.
+    ///
+    /// Starting from version 5, the invalid_format protocol error is sent if
+    /// all planes don't use the same modifier.
+    ///
+    /// This request raises the PLANE_IDX error if plane_idx is too large.
+    /// The error PLANE_SET is raised if attempting to set a plane that
+    /// was already set.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: dmabuf fd
+    /// - `plane_idx`: plane index
+    /// - `offset`: offset in bytes
+    /// - `stride`: stride in bytes
+    /// - `modifier_hi`: high 32 bits of layout modifier
+    /// - `modifier_lo`: low 32 bits of layout modifier
+    #[inline]
+    pub fn add(
+        &self,
+        fd: BorrowedFd<'_>,
+        plane_idx: u32,
+        offset: u32,
+        stride: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let (arg0, arg1, arg2, arg3, arg4, arg5) =
+            (fd, plane_idx, offset, stride, modifier_hi, modifier_lo);
+        let mut args = [
+            wl_argument {
+                h: arg0.as_raw_fd(),
+            },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4 },
+            wl_argument { u: arg5 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `huuuuu`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// create a wl_buffer from the given dmabufs
+    ///
+    /// This asks for creation of a wl_buffer from the added dmabuf
+    /// buffers. The wl_buffer is not created immediately but returned via
+    /// the 'created' event if the dmabuf sharing succeeds. The sharing
+    /// may fail at runtime for reasons a client cannot predict, in
+    /// which case the 'failed' event is triggered.
+    ///
+    /// The 'format' argument is a DRM_FORMAT code, as defined by the
+    /// libdrm's drm_fourcc.h. The Linux kernel's DRM sub-system is the
+    /// authoritative source on how the format codes should work.
+    ///
+    /// The 'flags' is a bitfield of the flags defined in enum "flags".
+    /// 'y_invert' means the that the image needs to be y-flipped.
+    ///
+    /// Flag 'interlaced' means that the frame in the buffer is not
+    /// progressive as usual, but interlaced. An interlaced buffer as
+    /// supported here must always contain both top and bottom fields.
+    /// The top field always begins on the first pixel row. The temporal
+    /// ordering between the two fields is top field first, unless
+    /// 'bottom_first' is specified. It is undefined whether 'bottom_first'
+    /// is ignored if 'interlaced' is not set.
+    ///
+    /// This protocol does not convey any information about field rate,
+    /// duration, or timing, other than the relative ordering between the
+    /// two fields in one buffer. A compositor may have to estimate the
+    /// intended field rate from the incoming buffer rate. It is undefined
+    /// whether the time of receiving wl_surface.commit with a new buffer
+    /// attached, applying the wl_surface state, wl_surface.frame callback
+    /// trigger, presentation, or any other point in the compositor cycle
+    /// is used to measure the frame or field times. There is no support
+    /// for detecting missed or late frames/fields/buffers either, and
+    /// there is no support whatsoever for cooperating with interlaced
+    /// compositor output.
+    ///
+    /// The composited image quality resulting from the use of interlaced
+    /// buffers is explicitly undefined. A compositor may use elaborate
+    /// hardware features or software to deinterlace and create progressive
+    /// output frames from a sequence of interlaced input buffers, or it
+    /// may produce substandard image quality. However, compositors that
+    /// cannot guarantee reasonable image quality in all cases are recommended
+    /// to just reject all interlaced buffers.
+    ///
+    /// Any argument errors, including non-positive width or height,
+    /// mismatch between the number of planes and the format, bad
+    /// format, bad offset or stride, may be indicated by fatal protocol
+    /// errors: INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS,
+    /// OUT_OF_BOUNDS.
+    ///
+    /// Dmabuf import errors in the server that are not obvious client
+    /// bugs are returned via the 'failed' event as non-fatal. This
+    /// allows attempting dmabuf sharing and falling back in the client
+    /// if it fails.
+    ///
+    /// This request can be sent only once in the object's lifetime, after
+    /// which the only legal request is destroy. This object should be
+    /// destroyed after issuing a 'create' request. Attempting to use this
+    /// object after issuing 'create' raises ALREADY_USED protocol error.
+    ///
+    /// It is not mandatory to issue 'create'. If a client wants to
+    /// cancel the buffer creation, it can just destroy this object.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: base plane width in pixels
+    /// - `height`: base plane height in pixels
+    /// - `format`: DRM_FORMAT code
+    /// - `flags`: see enum flags
+    #[inline]
+    pub fn create(&self, width: i32, height: i32, format: u32, flags: ZwpLinuxBufferParamsV1Flags) {
+        let (arg0, arg1, arg2, arg3) = (width, height, format, flags);
+        let mut args = [
+            wl_argument { i: arg0 },
+            wl_argument { i: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `iiuu`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// immediately create a wl_buffer from the given
+    ///                      dmabufs
+    ///
+    /// This asks for immediate creation of a wl_buffer by importing the
+    /// added dmabufs.
+    ///
+    /// In case of import success, no event is sent from the server, and the
+    /// wl_buffer is ready to be used by the client.
+    ///
+    /// Upon import failure, either of the following may happen, as seen fit
+    /// by the implementation:
+    /// - the client is terminated with one of the following fatal protocol
+    ///   errors:
+    ///   - INCOMPLETE, INVALID_FORMAT, INVALID_DIMENSIONS, OUT_OF_BOUNDS,
+    ///     in case of argument errors such as mismatch between the number
+    ///     of planes and the format, bad format, non-positive width or
+    ///     height, or bad offset or stride.
+    ///   - INVALID_WL_BUFFER, in case the cause for failure is unknown or
+    ///     platform specific.
+    /// - the server creates an invalid wl_buffer, marks it as failed and
+    ///   sends a 'failed' event to the client. The result of using this
+    ///   invalid wl_buffer as an argument in any request by the client is
+    ///   defined by the compositor implementation.
+    ///
+    /// This takes the same arguments as a 'create' request, and obeys the
+    /// same restrictions.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `width`: base plane width in pixels
+    /// - `height`: base plane height in pixels
+    /// - `format`: DRM_FORMAT code
+    /// - `flags`: see enum flags
+    #[inline]
+    pub fn create_immed(
+        &self,
+        _queue: &Queue,
+        width: i32,
+        height: i32,
+        format: u32,
+        flags: ZwpLinuxBufferParamsV1Flags,
+    ) -> WlBuffer {
+        let (arg1, arg2, arg3, arg4) = (width, height, format, flags);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `niiuu`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 3, &mut args, WlBuffer::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WlBuffer::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ZwpLinuxBufferParamsV1 {
+    /// Since when the created event is available.
+    #[allow(dead_code)]
+    pub const EVT__CREATED__SINCE: u32 = 1;
+
+    /// Since when the failed event is available.
+    #[allow(dead_code)]
+    pub const EVT__FAILED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpLinuxBufferParamsV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferParamsV1EventHandler {
+    /// buffer creation succeeded
+    ///
+    /// This event indicates that the attempted buffer creation was
+    /// successful. It provides the new wl_buffer referencing the dmabuf(s).
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// zwp_linux_buffer_params_v1 object.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer`: the newly created wl_buffer
+    #[inline]
+    fn created(&self, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        let _ = buffer;
+    }
+
+    /// buffer creation failed
+    ///
+    /// This event indicates that the attempted buffer creation has
+    /// failed. It usually means that one of the dmabuf constraints
+    /// has not been fulfilled.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// zwp_linux_buffer_params_v1 object.
+    #[inline]
+    fn failed(&self, _slf: &ZwpLinuxBufferParamsV1Ref) {}
+}
+
+impl ZwpLinuxBufferParamsV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLinuxBufferParamsV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxBufferParamsV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferParamsV1EventHandlerWithData<T> {
+    /// buffer creation succeeded
+    ///
+    /// This event indicates that the attempted buffer creation was
+    /// successful. It provides the new wl_buffer referencing the dmabuf(s).
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// zwp_linux_buffer_params_v1 object.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer`: the newly created wl_buffer
+    #[inline]
+    fn created(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        let _ = buffer;
+    }
+
+    /// buffer creation failed
+    ///
+    /// This event indicates that the attempted buffer creation has
+    /// failed. It usually means that one of the dmabuf constraints
+    /// has not been fulfilled.
+    ///
+    /// Upon receiving this event, the client should destroy the
+    /// zwp_linux_buffer_params_v1 object.
+    #[inline]
+    fn failed(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpLinuxBufferParamsV1] proxies.
+///
+/// This trait is the same as [ZwpLinuxBufferParamsV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferParamsV1EventHandlerMut {
+    /// See [ZwpLinuxBufferParamsV1EventHandler::created].
+    #[inline]
+    fn created(&mut self, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        let _ = buffer;
+    }
+
+    /// See [ZwpLinuxBufferParamsV1EventHandler::failed].
+    #[inline]
+    fn failed(&mut self, _slf: &ZwpLinuxBufferParamsV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpLinuxBufferParamsV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxBufferParamsV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferParamsV1EventHandlerMutWithData<T> {
+    /// See [ZwpLinuxBufferParamsV1EventHandler::created].
+    #[inline]
+    fn created(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        let _ = buffer;
+    }
+
+    /// See [ZwpLinuxBufferParamsV1EventHandler::failed].
+    #[inline]
+    fn failed(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {}
+}
+
+impl<H> ZwpLinuxBufferParamsV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpLinuxBufferParamsV1EventHandlerMut,
+{
+    #[inline]
+    fn created(&self, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        self.with(|h| h.created(_slf, buffer))
+    }
+
+    #[inline]
+    fn failed(&self, _slf: &ZwpLinuxBufferParamsV1Ref) {
+        self.with(|h| h.failed(_slf))
+    }
+}
+
+impl<T, H> ZwpLinuxBufferParamsV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpLinuxBufferParamsV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn created(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        self.with(|h| h.created(_data, _slf, buffer))
+    }
+
+    #[inline]
+    fn failed(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {
+        self.with(|h| h.failed(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpLinuxBufferParamsV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxBufferParamsV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn created(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        let _data = self.project(_data);
+        self.handler().created(_data, _slf, buffer)
+    }
+
+    #[inline]
+    fn failed(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {
+        let _data = self.project(_data);
+        self.handler().failed(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpLinuxBufferParamsV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxBufferParamsV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn created(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+        let _data = self.project(_data);
+        self.handler_mut().created(_data, _slf, buffer)
+    }
+
+    #[inline]
+    fn failed(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().failed(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLinuxBufferParamsV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxBufferParamsV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface WlBuffer::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        WlBuffer::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlBuffer::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<WlBuffer>(arg0) };
+                self.0.created(slf, arg0);
+            }
+            1 => {
+                self.0.failed(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_buffer_params_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLinuxBufferParamsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxBufferParamsV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface WlBuffer::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        WlBuffer::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlBuffer::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<WlBuffer>(arg0) };
+                self.0.created(data, slf, arg0);
+            }
+            1 => {
+                self.0.failed(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_buffer_params_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLinuxBufferParamsV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLinuxBufferParamsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpLinuxBufferParamsV1 {
+    /// Since when the error.already_used enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_USED__SINCE: u32 = 1;
+    /// Since when the error.plane_idx enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_PLANE_IDX__SINCE: u32 = 1;
+    /// Since when the error.plane_set enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_PLANE_SET__SINCE: u32 = 1;
+    /// Since when the error.incomplete enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INCOMPLETE__SINCE: u32 = 1;
+    /// Since when the error.invalid_format enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_FORMAT__SINCE: u32 = 1;
+    /// Since when the error.invalid_dimensions enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_DIMENSIONS__SINCE: u32 = 1;
+    /// Since when the error.out_of_bounds enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_OUT_OF_BOUNDS__SINCE: u32 = 1;
+    /// Since when the error.invalid_wl_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_WL_BUFFER__SINCE: u32 = 1;
+
+    /// Since when the flags.y_invert enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FLAGS_Y_INVERT__SINCE: u32 = 1;
+    /// Since when the flags.interlaced enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FLAGS_INTERLACED__SINCE: u32 = 1;
+    /// Since when the flags.bottom_first enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__FLAGS_BOTTOM_FIRST__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpLinuxBufferParamsV1Error(pub u32);
+
+impl ZwpLinuxBufferParamsV1Error {
+    /// the dmabuf_batch object has already been used to create a wl_buffer
+    #[allow(dead_code)]
+    pub const ALREADY_USED: Self = Self(0);
+
+    /// plane index out of bounds
+    #[allow(dead_code)]
+    pub const PLANE_IDX: Self = Self(1);
+
+    /// the plane index was already set
+    #[allow(dead_code)]
+    pub const PLANE_SET: Self = Self(2);
+
+    /// missing or too many planes to create a buffer
+    #[allow(dead_code)]
+    pub const INCOMPLETE: Self = Self(3);
+
+    /// format not supported
+    #[allow(dead_code)]
+    pub const INVALID_FORMAT: Self = Self(4);
+
+    /// invalid width or height
+    #[allow(dead_code)]
+    pub const INVALID_DIMENSIONS: Self = Self(5);
+
+    /// offset + stride * height goes out of dmabuf bounds
+    #[allow(dead_code)]
+    pub const OUT_OF_BOUNDS: Self = Self(6);
+
+    /// invalid wl_buffer resulted from importing dmabufs via
+    ///                the create_immed request on given buffer_params
+    #[allow(dead_code)]
+    pub const INVALID_WL_BUFFER: Self = Self(7);
+}
+
+impl Debug for ZwpLinuxBufferParamsV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_USED => "ALREADY_USED",
+            Self::PLANE_IDX => "PLANE_IDX",
+            Self::PLANE_SET => "PLANE_SET",
+            Self::INCOMPLETE => "INCOMPLETE",
+            Self::INVALID_FORMAT => "INVALID_FORMAT",
+            Self::INVALID_DIMENSIONS => "INVALID_DIMENSIONS",
+            Self::OUT_OF_BOUNDS => "OUT_OF_BOUNDS",
+            Self::INVALID_WL_BUFFER => "INVALID_WL_BUFFER",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ZwpLinuxBufferParamsV1Flags(pub u32);
+
+/// An iterator over the set bits in a [ZwpLinuxBufferParamsV1Flags].
+///
+/// You can construct this with the `IntoIterator` implementation of `ZwpLinuxBufferParamsV1Flags`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ZwpLinuxBufferParamsV1FlagsIter(pub u32);
+
+impl ZwpLinuxBufferParamsV1Flags {
+    /// contents are y-inverted
+    #[allow(dead_code)]
+    pub const Y_INVERT: Self = Self(1);
+
+    /// content is interlaced
+    #[allow(dead_code)]
+    pub const INTERLACED: Self = Self(2);
+
+    /// bottom field first
+    #[allow(dead_code)]
+    pub const BOTTOM_FIRST: Self = Self(4);
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxBufferParamsV1Flags {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1 | 2 | 4)
+    }
+}
+
+impl Iterator for ZwpLinuxBufferParamsV1FlagsIter {
+    type Item = ZwpLinuxBufferParamsV1Flags;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ZwpLinuxBufferParamsV1Flags(bit))
+    }
+}
+
+impl IntoIterator for ZwpLinuxBufferParamsV1Flags {
+    type Item = ZwpLinuxBufferParamsV1Flags;
+    type IntoIter = ZwpLinuxBufferParamsV1FlagsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ZwpLinuxBufferParamsV1FlagsIter(self.0)
+    }
+}
+
+impl BitAnd for ZwpLinuxBufferParamsV1Flags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ZwpLinuxBufferParamsV1Flags {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ZwpLinuxBufferParamsV1Flags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ZwpLinuxBufferParamsV1Flags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ZwpLinuxBufferParamsV1Flags {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ZwpLinuxBufferParamsV1Flags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ZwpLinuxBufferParamsV1Flags {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ZwpLinuxBufferParamsV1Flags {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ZwpLinuxBufferParamsV1Flags {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ZwpLinuxBufferParamsV1Flags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("Y_INVERT")?;
+        }
+        if v & 2 == 2 {
+            v &= !2;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("INTERLACED")?;
+        }
+        if v & 4 == 4 {
+            v &= !4;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("BOTTOM_FIRST")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for created events.
+    pub struct Created<F>(F);
+    impl<F> ZwpLinuxBufferParamsV1EventHandler for Created<F>
+    where
+        F: Fn(&ZwpLinuxBufferParamsV1Ref, WlBuffer),
+    {
+        #[inline]
+        fn created(&self, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+            self.0(_slf, buffer)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferParamsV1EventHandlerWithData<T> for Created<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxBufferParamsV1Ref, WlBuffer),
+    {
+        #[inline]
+        fn created(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+            self.0(_data, _slf, buffer)
+        }
+    }
+
+    /// Event handler for failed events.
+    pub struct Failed<F>(F);
+    impl<F> ZwpLinuxBufferParamsV1EventHandler for Failed<F>
+    where
+        F: Fn(&ZwpLinuxBufferParamsV1Ref),
+    {
+        #[inline]
+        fn failed(&self, _slf: &ZwpLinuxBufferParamsV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferParamsV1EventHandlerWithData<T> for Failed<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxBufferParamsV1Ref),
+    {
+        #[inline]
+        fn failed(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for created events.
+    pub struct CreatedMut<F>(F);
+    impl<F> ZwpLinuxBufferParamsV1EventHandlerMut for CreatedMut<F>
+    where
+        F: FnMut(&ZwpLinuxBufferParamsV1Ref, WlBuffer),
+    {
+        #[inline]
+        fn created(&mut self, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+            self.0(_slf, buffer)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferParamsV1EventHandlerMutWithData<T> for CreatedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxBufferParamsV1Ref, WlBuffer),
+    {
+        #[inline]
+        fn created(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+            self.0(_data, _slf, buffer)
+        }
+    }
+
+    /// Mutable event handler for failed events.
+    pub struct FailedMut<F>(F);
+    impl<F> ZwpLinuxBufferParamsV1EventHandlerMut for FailedMut<F>
+    where
+        F: FnMut(&ZwpLinuxBufferParamsV1Ref),
+    {
+        #[inline]
+        fn failed(&mut self, _slf: &ZwpLinuxBufferParamsV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferParamsV1EventHandlerMutWithData<T> for FailedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxBufferParamsV1Ref),
+    {
+        #[inline]
+        fn failed(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpLinuxBufferParamsV1 {
+        /// Creates an event handler for created events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_created<F>(f: F) -> Created<F>
+        where
+            F: Fn(&ZwpLinuxBufferParamsV1Ref, WlBuffer),
+        {
+            Created(f)
+        }
+
+        /// Creates an event handler with mutable data for created events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_created_with_data<T, F>(f: F) -> Created<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferParamsV1Ref, WlBuffer),
+        {
+            Created(f)
+        }
+
+        /// Creates an event handler for failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_failed<F>(f: F) -> Failed<F>
+        where
+            F: Fn(&ZwpLinuxBufferParamsV1Ref),
+        {
+            Failed(f)
+        }
+
+        /// Creates an event handler with mutable data for failed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_failed_with_data<T, F>(f: F) -> Failed<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferParamsV1Ref),
+        {
+            Failed(f)
+        }
+
+        /// Creates a mutable event handler for created events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_created_mut<F>(f: F) -> CreatedMut<F>
+        where
+            F: FnMut(&ZwpLinuxBufferParamsV1Ref, WlBuffer),
+        {
+            CreatedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for created events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_created_mut_with_data<T, F>(f: F) -> CreatedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxBufferParamsV1Ref, WlBuffer),
+        {
+            CreatedMut(f)
+        }
+
+        /// Creates a mutable event handler for failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_failed_mut<F>(f: F) -> FailedMut<F>
+        where
+            F: FnMut(&ZwpLinuxBufferParamsV1Ref),
+        {
+            FailedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for failed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_failed_mut_with_data<T, F>(f: F) -> FailedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxBufferParamsV1Ref),
+        {
+            FailedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                created: Ignore,
+                failed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpLinuxBufferParamsV1EventHandler for Ignore {}
+    impl<T> ZwpLinuxBufferParamsV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpLinuxBufferParamsV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        created: H0,
+        failed: H1,
+    }
+
+    impl<H0, H1> ZwpLinuxBufferParamsV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpLinuxBufferParamsV1EventHandler,
+        H1: ZwpLinuxBufferParamsV1EventHandler,
+    {
+        #[inline]
+        fn created(&self, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+            self.created.created(_slf, buffer)
+        }
+
+        #[inline]
+        fn failed(&self, _slf: &ZwpLinuxBufferParamsV1Ref) {
+            self.failed.failed(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ZwpLinuxBufferParamsV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpLinuxBufferParamsV1EventHandlerWithData<T>,
+        H1: ZwpLinuxBufferParamsV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn created(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref, buffer: WlBuffer) {
+            self.created.created(_data, _slf, buffer)
+        }
+
+        #[inline]
+        fn failed(&self, _data: &mut T, _slf: &ZwpLinuxBufferParamsV1Ref) {
+            self.failed.failed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for created events.
+        #[allow(dead_code)]
+        pub fn on_created<F>(self, f: F) -> Handler<Created<F>, H1>
+        where
+            F: Fn(&ZwpLinuxBufferParamsV1Ref, WlBuffer),
+        {
+            Handler {
+                created: Created(f),
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for created events.
+        #[allow(dead_code)]
+        pub fn on_created_with_data<T, F>(self, f: F) -> Handler<Created<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferParamsV1Ref, WlBuffer),
+        {
+            Handler {
+                created: Created(f),
+                failed: self.failed,
+            }
+        }
+
+        /// Sets the closure for failed events.
+        #[allow(dead_code)]
+        pub fn on_failed<F>(self, f: F) -> Handler<H0, Failed<F>>
+        where
+            F: Fn(&ZwpLinuxBufferParamsV1Ref),
+        {
+            Handler {
+                created: self.created,
+                failed: Failed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for failed events.
+        #[allow(dead_code)]
+        pub fn on_failed_with_data<T, F>(self, f: F) -> Handler<H0, Failed<F>>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferParamsV1Ref),
+        {
+            Handler {
+                created: self.created,
+                failed: Failed(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,2625 @@
+//! dmabuf feedback
+//!
+//! This object advertises dmabuf parameters feedback. This includes the
+//! preferred devices and the supported formats/modifiers.
+//!
+//! The parameters are sent once when this object is created and whenever they
+//! change. The done event is always sent once after all parameters have been
+//! sent. When a single parameter changes, all parameters are re-sent by the
+//! compositor.
+//!
+//! Compositors can re-send the parameters when the current client buffer
+//! allocations are sub-optimal. Compositors should not re-send the
+//! parameters if re-allocating the buffers would not result in a more optimal
+//! configuration. In particular, compositors should avoid sending the exact
+//! same parameters multiple times in a row.
+//!
+//! The tranche_target_device and tranche_formats events are grouped by
+//! tranches of preference. For each tranche, a tranche_target_device, one
+//! tranche_flags and one or more tranche_formats events are sent, followed
+//! by a tranche_done event finishing the list. The tranches are sent in
+//! descending order of preference. All formats and modifiers in the same
+//! tranche have the same preference.
+//!
+//! To send parameters, the compositor sends one main_device event, tranches
+//! (each consisting of one tranche_target_device event, one tranche_flags
+//! event, tranche_formats events and then a tranche_done event), then one
+//! done event.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_linux_dmabuf_feedback_v1".as_ptr(),
+    version: 5,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 7,
+    events: {
+        static MESSAGES: [wl_message; 7] = [
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"format_table".as_ptr(),
+                signature: c"hu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"main_device".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tranche_done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tranche_target_device".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tranche_formats".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tranche_flags".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_linux_dmabuf_feedback_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_linux_dmabuf_feedback_v1",
+    protocol: "linux_dmabuf_v1",
+    version: 5,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "format_table",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "size",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "main_device",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "device",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tranche_done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "tranche_target_device",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "device",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tranche_formats",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "indices",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tranche_flags",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "flags",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("tranche_flags"),
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "tranche_flags",
+        since: 1,
+        bitfield: true,
+        entries: &[info::EntryInfo {
+            name: "scanout",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned zwp_linux_dmabuf_feedback_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxDmabufFeedbackV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_linux_dmabuf_feedback_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxDmabufFeedbackV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLinuxDmabufFeedbackV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLinuxDmabufFeedbackV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLinuxDmabufFeedbackV1 {
+    const INTERFACE: &'static str = "zwp_linux_dmabuf_feedback_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 5;
+
+    type Borrowed = ZwpLinuxDmabufFeedbackV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLinuxDmabufFeedbackV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLinuxDmabufFeedbackV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLinuxDmabufFeedbackV1Ref {
+    type Owned = ZwpLinuxDmabufFeedbackV1;
+}
+
+impl Deref for ZwpLinuxDmabufFeedbackV1 {
+    type Target = ZwpLinuxDmabufFeedbackV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLinuxDmabufFeedbackV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_dmabuf_feedback_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpLinuxDmabufFeedbackV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_dmabuf_feedback_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpLinuxDmabufFeedbackV1Ref> for ZwpLinuxDmabufFeedbackV1 {
+    fn eq(&self, other: &ZwpLinuxDmabufFeedbackV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLinuxDmabufFeedbackV1> for ZwpLinuxDmabufFeedbackV1Ref {
+    fn eq(&self, other: &ZwpLinuxDmabufFeedbackV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxDmabufFeedbackV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the feedback object
+    ///
+    /// Using this request a client can tell the server that it is not going to
+    /// use the wp_linux_dmabuf_feedback object anymore.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpLinuxDmabufFeedbackV1 {
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the format_table event is available.
+    #[allow(dead_code)]
+    pub const EVT__FORMAT_TABLE__SINCE: u32 = 1;
+
+    /// Since when the main_device event is available.
+    #[allow(dead_code)]
+    pub const EVT__MAIN_DEVICE__SINCE: u32 = 1;
+
+    /// Since when the tranche_done event is available.
+    #[allow(dead_code)]
+    pub const EVT__TRANCHE_DONE__SINCE: u32 = 1;
+
+    /// Since when the tranche_target_device event is available.
+    #[allow(dead_code)]
+    pub const EVT__TRANCHE_TARGET_DEVICE__SINCE: u32 = 1;
+
+    /// Since when the tranche_formats event is available.
+    #[allow(dead_code)]
+    pub const EVT__TRANCHE_FORMATS__SINCE: u32 = 1;
+
+    /// Since when the tranche_flags event is available.
+    #[allow(dead_code)]
+    pub const EVT__TRANCHE_FLAGS__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpLinuxDmabufFeedbackV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufFeedbackV1EventHandler {
+    /// all feedback has been sent
+    ///
+    /// This event is sent after all parameters of a wp_linux_dmabuf_feedback
+    /// object have been sent.
+    ///
+    /// This allows changes to the wp_linux_dmabuf_feedback parameters to be
+    /// seen as atomic, even if they happen via multiple events.
+    #[inline]
+    fn done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// format and modifier table
+    ///
+    /// This event provides a file descriptor which can be memory-mapped to
+    /// access the format and modifier table.
+    ///
+    /// The table contains a tightly packed array of consecutive format +
+    /// modifier pairs. Each pair is 16 bytes wide. It contains a format as a
+    /// 32-bit unsigned integer, followed by 4 bytes of unused padding, and a
+    /// modifier as a 64-bit unsigned integer. The native endianness is used.
+    ///
+    /// The client must map the file descriptor in read-only private mode.
+    ///
+    /// Compositors are not allowed to mutate the table file contents once this
+    /// event has been sent. Instead, compositors must create a new, separate
+    /// table file and re-send feedback parameters. Compositors are allowed to
+    /// store duplicate format + modifier pairs in the table.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: table file descriptor
+    /// - `size`: table size, in bytes
+    #[inline]
+    fn format_table(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, fd: OwnedFd, size: u32) {
+        let _ = fd;
+        let _ = size;
+    }
+
+    /// preferred main device
+    ///
+    /// This event advertises the main device that the server prefers to use
+    /// when direct scan-out to the target device isn't possible. The
+    /// advertised main device may be different for each
+    /// wp_linux_dmabuf_feedback object, and may change over time.
+    ///
+    /// There is exactly one main device. The compositor must send at least
+    /// one preference tranche with tranche_target_device equal to main_device.
+    ///
+    /// Clients need to create buffers that the main device can import and
+    /// read from, otherwise creating the dmabuf wl_buffer will fail (see the
+    /// wp_linux_buffer_params.create and create_immed requests for details).
+    /// The main device will also likely be kept active by the compositor,
+    /// so clients can use it instead of waking up another device for power
+    /// savings.
+    ///
+    /// In general the device is a DRM node. The DRM node type (primary vs.
+    /// render) is unspecified. Clients must not rely on the compositor sending
+    /// a particular node type. Clients cannot check two devices for equality
+    /// by comparing the dev_t value.
+    ///
+    /// If explicit modifiers are not supported and the client performs buffer
+    /// allocations on a different device than the main device, then the client
+    /// must force the buffer to have a linear layout.
+    ///
+    /// # Arguments
+    ///
+    /// - `device`: device dev_t value
+    #[inline]
+    fn main_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// a preference tranche has been sent
+    ///
+    /// This event splits tranche_target_device and tranche_formats events in
+    /// preference tranches. It is sent after a set of tranche_target_device
+    /// and tranche_formats events; it represents the end of a tranche. The
+    /// next tranche will have a lower preference.
+    #[inline]
+    fn tranche_done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// target device
+    ///
+    /// This event advertises the target device that the server prefers to use
+    /// for a buffer created given this tranche. The advertised target device
+    /// may be different for each preference tranche, and may change over time.
+    ///
+    /// There is exactly one target device per tranche.
+    ///
+    /// The target device may be a scan-out device, for example if the
+    /// compositor prefers to directly scan-out a buffer created given this
+    /// tranche. The target device may be a rendering device, for example if
+    /// the compositor prefers to texture from said buffer.
+    ///
+    /// The client can use this hint to allocate the buffer in a way that makes
+    /// it accessible from the target device, ideally directly. The buffer must
+    /// still be accessible from the main device, either through direct import
+    /// or through a potentially more expensive fallback path. If the buffer
+    /// can't be directly imported from the main device then clients must be
+    /// prepared for the compositor changing the tranche priority or making
+    /// wl_buffer creation fail (see the wp_linux_buffer_params.create and
+    /// create_immed requests for details).
+    ///
+    /// If the device is a DRM node, the DRM node type (primary vs. render) is
+    /// unspecified. Clients must not rely on the compositor sending a
+    /// particular node type. Clients cannot check two devices for equality by
+    /// comparing the dev_t value.
+    ///
+    /// This event is tied to a preference tranche, see the tranche_done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `device`: device dev_t value
+    #[inline]
+    fn tranche_target_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// supported buffer format modifier
+    ///
+    /// This event advertises the format + modifier combinations that the
+    /// compositor supports.
+    ///
+    /// It carries an array of indices, each referring to a format + modifier
+    /// pair in the last received format table (see the format_table event).
+    /// Each index is a 16-bit unsigned integer in native endianness.
+    ///
+    /// For legacy support, DRM_FORMAT_MOD_INVALID is an allowed modifier.
+    /// It indicates that the server can support the format with an implicit
+    /// modifier. When a buffer has DRM_FORMAT_MOD_INVALID as its modifier, it
+    /// is as if no explicit modifier is specified. The effective modifier
+    /// will be derived from the dmabuf.
+    ///
+    /// A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for
+    /// a given format supports both explicit modifiers and implicit modifiers.
+    ///
+    /// Compositors must not send duplicate format + modifier pairs within the
+    /// same tranche or across two different tranches with the same target
+    /// device and flags.
+    ///
+    /// This event is tied to a preference tranche, see the tranche_done event.
+    ///
+    /// For the definition of the format and modifier codes, see the
+    /// wp_linux_buffer_params.create request.
+    ///
+    /// # Arguments
+    ///
+    /// - `indices`: array of 16-bit indexes
+    #[inline]
+    fn tranche_formats(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+        let _ = indices;
+    }
+
+    /// tranche flags
+    ///
+    /// This event sets tranche-specific flags.
+    ///
+    /// The scanout flag is a hint that direct scan-out may be attempted by the
+    /// compositor on the target device if the client appropriately allocates a
+    /// buffer. How to allocate a buffer that can be scanned out on the target
+    /// device is implementation-defined.
+    ///
+    /// This event is tied to a preference tranche, see the tranche_done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `flags`: tranche flags
+    #[inline]
+    fn tranche_flags(
+        &self,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        let _ = flags;
+    }
+}
+
+impl ZwpLinuxDmabufFeedbackV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLinuxDmabufFeedbackV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxDmabufFeedbackV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> {
+    /// all feedback has been sent
+    ///
+    /// This event is sent after all parameters of a wp_linux_dmabuf_feedback
+    /// object have been sent.
+    ///
+    /// This allows changes to the wp_linux_dmabuf_feedback parameters to be
+    /// seen as atomic, even if they happen via multiple events.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// format and modifier table
+    ///
+    /// This event provides a file descriptor which can be memory-mapped to
+    /// access the format and modifier table.
+    ///
+    /// The table contains a tightly packed array of consecutive format +
+    /// modifier pairs. Each pair is 16 bytes wide. It contains a format as a
+    /// 32-bit unsigned integer, followed by 4 bytes of unused padding, and a
+    /// modifier as a 64-bit unsigned integer. The native endianness is used.
+    ///
+    /// The client must map the file descriptor in read-only private mode.
+    ///
+    /// Compositors are not allowed to mutate the table file contents once this
+    /// event has been sent. Instead, compositors must create a new, separate
+    /// table file and re-send feedback parameters. Compositors are allowed to
+    /// store duplicate format + modifier pairs in the table.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: table file descriptor
+    /// - `size`: table size, in bytes
+    #[inline]
+    fn format_table(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        fd: OwnedFd,
+        size: u32,
+    ) {
+        let _ = fd;
+        let _ = size;
+    }
+
+    /// preferred main device
+    ///
+    /// This event advertises the main device that the server prefers to use
+    /// when direct scan-out to the target device isn't possible. The
+    /// advertised main device may be different for each
+    /// wp_linux_dmabuf_feedback object, and may change over time.
+    ///
+    /// There is exactly one main device. The compositor must send at least
+    /// one preference tranche with tranche_target_device equal to main_device.
+    ///
+    /// Clients need to create buffers that the main device can import and
+    /// read from, otherwise creating the dmabuf wl_buffer will fail (see the
+    /// wp_linux_buffer_params.create and create_immed requests for details).
+    /// The main device will also likely be kept active by the compositor,
+    /// so clients can use it instead of waking up another device for power
+    /// savings.
+    ///
+    /// In general the device is a DRM node. The DRM node type (primary vs.
+    /// render) is unspecified. Clients must not rely on the compositor sending
+    /// a particular node type. Clients cannot check two devices for equality
+    /// by comparing the dev_t value.
+    ///
+    /// If explicit modifiers are not supported and the client performs buffer
+    /// allocations on a different device than the main device, then the client
+    /// must force the buffer to have a linear layout.
+    ///
+    /// # Arguments
+    ///
+    /// - `device`: device dev_t value
+    #[inline]
+    fn main_device(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// a preference tranche has been sent
+    ///
+    /// This event splits tranche_target_device and tranche_formats events in
+    /// preference tranches. It is sent after a set of tranche_target_device
+    /// and tranche_formats events; it represents the end of a tranche. The
+    /// next tranche will have a lower preference.
+    #[inline]
+    fn tranche_done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// target device
+    ///
+    /// This event advertises the target device that the server prefers to use
+    /// for a buffer created given this tranche. The advertised target device
+    /// may be different for each preference tranche, and may change over time.
+    ///
+    /// There is exactly one target device per tranche.
+    ///
+    /// The target device may be a scan-out device, for example if the
+    /// compositor prefers to directly scan-out a buffer created given this
+    /// tranche. The target device may be a rendering device, for example if
+    /// the compositor prefers to texture from said buffer.
+    ///
+    /// The client can use this hint to allocate the buffer in a way that makes
+    /// it accessible from the target device, ideally directly. The buffer must
+    /// still be accessible from the main device, either through direct import
+    /// or through a potentially more expensive fallback path. If the buffer
+    /// can't be directly imported from the main device then clients must be
+    /// prepared for the compositor changing the tranche priority or making
+    /// wl_buffer creation fail (see the wp_linux_buffer_params.create and
+    /// create_immed requests for details).
+    ///
+    /// If the device is a DRM node, the DRM node type (primary vs. render) is
+    /// unspecified. Clients must not rely on the compositor sending a
+    /// particular node type. Clients cannot check two devices for equality by
+    /// comparing the dev_t value.
+    ///
+    /// This event is tied to a preference tranche, see the tranche_done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `device`: device dev_t value
+    #[inline]
+    fn tranche_target_device(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        device: &[u8],
+    ) {
+        let _ = device;
+    }
+
+    /// supported buffer format modifier
+    ///
+    /// This event advertises the format + modifier combinations that the
+    /// compositor supports.
+    ///
+    /// It carries an array of indices, each referring to a format + modifier
+    /// pair in the last received format table (see the format_table event).
+    /// Each index is a 16-bit unsigned integer in native endianness.
+    ///
+    /// For legacy support, DRM_FORMAT_MOD_INVALID is an allowed modifier.
+    /// It indicates that the server can support the format with an implicit
+    /// modifier. When a buffer has DRM_FORMAT_MOD_INVALID as its modifier, it
+    /// is as if no explicit modifier is specified. The effective modifier
+    /// will be derived from the dmabuf.
+    ///
+    /// A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for
+    /// a given format supports both explicit modifiers and implicit modifiers.
+    ///
+    /// Compositors must not send duplicate format + modifier pairs within the
+    /// same tranche or across two different tranches with the same target
+    /// device and flags.
+    ///
+    /// This event is tied to a preference tranche, see the tranche_done event.
+    ///
+    /// For the definition of the format and modifier codes, see the
+    /// wp_linux_buffer_params.create request.
+    ///
+    /// # Arguments
+    ///
+    /// - `indices`: array of 16-bit indexes
+    #[inline]
+    fn tranche_formats(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+        let _ = indices;
+    }
+
+    /// tranche flags
+    ///
+    /// This event sets tranche-specific flags.
+    ///
+    /// The scanout flag is a hint that direct scan-out may be attempted by the
+    /// compositor on the target device if the client appropriately allocates a
+    /// buffer. How to allocate a buffer that can be scanned out on the target
+    /// device is implementation-defined.
+    ///
+    /// This event is tied to a preference tranche, see the tranche_done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `flags`: tranche flags
+    #[inline]
+    fn tranche_flags(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        let _ = flags;
+    }
+}
+
+/// A mutable event handler for [ZwpLinuxDmabufFeedbackV1] proxies.
+///
+/// This trait is the same as [ZwpLinuxDmabufFeedbackV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufFeedbackV1EventHandlerMut {
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::format_table].
+    #[inline]
+    fn format_table(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, fd: OwnedFd, size: u32) {
+        let _ = fd;
+        let _ = size;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::main_device].
+    #[inline]
+    fn main_device(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_done].
+    #[inline]
+    fn tranche_done(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_target_device].
+    #[inline]
+    fn tranche_target_device(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_formats].
+    #[inline]
+    fn tranche_formats(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+        let _ = indices;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_flags].
+    #[inline]
+    fn tranche_flags(
+        &mut self,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        let _ = flags;
+    }
+}
+
+/// A mutable event handler for [ZwpLinuxDmabufFeedbackV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxDmabufFeedbackV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> {
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::format_table].
+    #[inline]
+    fn format_table(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        fd: OwnedFd,
+        size: u32,
+    ) {
+        let _ = fd;
+        let _ = size;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::main_device].
+    #[inline]
+    fn main_device(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _ = device;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_done].
+    #[inline]
+    fn tranche_done(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {}
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_target_device].
+    #[inline]
+    fn tranche_target_device(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        device: &[u8],
+    ) {
+        let _ = device;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_formats].
+    #[inline]
+    fn tranche_formats(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        indices: &[u8],
+    ) {
+        let _ = indices;
+    }
+
+    /// See [ZwpLinuxDmabufFeedbackV1EventHandler::tranche_flags].
+    #[inline]
+    fn tranche_flags(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        let _ = flags;
+    }
+}
+
+impl<H> ZwpLinuxDmabufFeedbackV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandlerMut,
+{
+    #[inline]
+    fn done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn format_table(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, fd: OwnedFd, size: u32) {
+        self.with(|h| h.format_table(_slf, fd, size))
+    }
+
+    #[inline]
+    fn main_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        self.with(|h| h.main_device(_slf, device))
+    }
+
+    #[inline]
+    fn tranche_done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        self.with(|h| h.tranche_done(_slf))
+    }
+
+    #[inline]
+    fn tranche_target_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        self.with(|h| h.tranche_target_device(_slf, device))
+    }
+
+    #[inline]
+    fn tranche_formats(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+        self.with(|h| h.tranche_formats(_slf, indices))
+    }
+
+    #[inline]
+    fn tranche_flags(
+        &self,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        self.with(|h| h.tranche_flags(_slf, flags))
+    }
+}
+
+impl<T, H> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn format_table(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        fd: OwnedFd,
+        size: u32,
+    ) {
+        self.with(|h| h.format_table(_data, _slf, fd, size))
+    }
+
+    #[inline]
+    fn main_device(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        self.with(|h| h.main_device(_data, _slf, device))
+    }
+
+    #[inline]
+    fn tranche_done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        self.with(|h| h.tranche_done(_data, _slf))
+    }
+
+    #[inline]
+    fn tranche_target_device(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        device: &[u8],
+    ) {
+        self.with(|h| h.tranche_target_device(_data, _slf, device))
+    }
+
+    #[inline]
+    fn tranche_formats(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+        self.with(|h| h.tranche_formats(_data, _slf, indices))
+    }
+
+    #[inline]
+    fn tranche_flags(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        self.with(|h| h.tranche_flags(_data, _slf, flags))
+    }
+}
+
+impl<T, U, H> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn format_table(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        fd: OwnedFd,
+        size: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().format_table(_data, _slf, fd, size)
+    }
+
+    #[inline]
+    fn main_device(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().main_device(_data, _slf, device)
+    }
+
+    #[inline]
+    fn tranche_done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler().tranche_done(_data, _slf)
+    }
+
+    #[inline]
+    fn tranche_target_device(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        device: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler().tranche_target_device(_data, _slf, device)
+    }
+
+    #[inline]
+    fn tranche_formats(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().tranche_formats(_data, _slf, indices)
+    }
+
+    #[inline]
+    fn tranche_flags(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        let _data = self.project(_data);
+        self.handler().tranche_flags(_data, _slf, flags)
+    }
+}
+
+impl<T, U, H> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn format_table(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        fd: OwnedFd,
+        size: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().format_table(_data, _slf, fd, size)
+    }
+
+    #[inline]
+    fn main_device(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+        let _data = self.project(_data);
+        self.handler_mut().main_device(_data, _slf, device)
+    }
+
+    #[inline]
+    fn tranche_done(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().tranche_done(_data, _slf)
+    }
+
+    #[inline]
+    fn tranche_target_device(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        device: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .tranche_target_device(_data, _slf, device)
+    }
+
+    #[inline]
+    fn tranche_formats(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        indices: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().tranche_formats(_data, _slf, indices)
+    }
+
+    #[inline]
+    fn tranche_flags(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+        flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().tranche_flags(_data, _slf, flags)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxDmabufFeedbackV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.done(slf);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.format_table(slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.main_device(slf, arg0);
+            }
+            3 => {
+                self.0.tranche_done(slf);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.tranche_target_device(slf, arg0);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.tranche_formats(slf, arg0);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpLinuxDmabufFeedbackV1TrancheFlags(args[0].u) };
+                self.0.tranche_flags(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_dmabuf_feedback_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxDmabufFeedbackV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.done(data, slf);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.format_table(data, slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.main_device(data, slf, arg0);
+            }
+            3 => {
+                self.0.tranche_done(data, slf);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.tranche_target_device(data, slf, arg0);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.tranche_formats(data, slf, arg0);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpLinuxDmabufFeedbackV1TrancheFlags(args[0].u) };
+                self.0.tranche_flags(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_dmabuf_feedback_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpLinuxDmabufFeedbackV1 {
+    /// Since when the tranche_flags.scanout enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TRANCHE_FLAGS_SCANOUT__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ZwpLinuxDmabufFeedbackV1TrancheFlags(pub u32);
+
+/// An iterator over the set bits in a [ZwpLinuxDmabufFeedbackV1TrancheFlags].
+///
+/// You can construct this with the `IntoIterator` implementation of `ZwpLinuxDmabufFeedbackV1TrancheFlags`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ZwpLinuxDmabufFeedbackV1TrancheFlagsIter(pub u32);
+
+impl ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    /// direct scan-out tranche
+    #[allow(dead_code)]
+    pub const SCANOUT: Self = Self(1);
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 1)
+    }
+}
+
+impl Iterator for ZwpLinuxDmabufFeedbackV1TrancheFlagsIter {
+    type Item = ZwpLinuxDmabufFeedbackV1TrancheFlags;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ZwpLinuxDmabufFeedbackV1TrancheFlags(bit))
+    }
+}
+
+impl IntoIterator for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    type Item = ZwpLinuxDmabufFeedbackV1TrancheFlags;
+    type IntoIter = ZwpLinuxDmabufFeedbackV1TrancheFlagsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ZwpLinuxDmabufFeedbackV1TrancheFlagsIter(self.0)
+    }
+}
+
+impl BitAnd for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ZwpLinuxDmabufFeedbackV1TrancheFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 1 == 1 {
+            v &= !1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("SCANOUT")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for Done<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for format_table events.
+    pub struct FormatTable<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for FormatTable<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn format_table(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, fd: OwnedFd, size: u32) {
+            self.0(_slf, fd, size)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for FormatTable<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn format_table(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            fd: OwnedFd,
+            size: u32,
+        ) {
+            self.0(_data, _slf, fd, size)
+        }
+    }
+
+    /// Event handler for main_device events.
+    pub struct MainDevice<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for MainDevice<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn main_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.0(_slf, device)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for MainDevice<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn main_device(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.0(_data, _slf, device)
+        }
+    }
+
+    /// Event handler for tranche_done events.
+    pub struct TrancheDone<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for TrancheDone<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn tranche_done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for TrancheDone<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn tranche_done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for tranche_target_device events.
+    pub struct TrancheTargetDevice<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for TrancheTargetDevice<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_target_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.0(_slf, device)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for TrancheTargetDevice<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_target_device(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            device: &[u8],
+        ) {
+            self.0(_data, _slf, device)
+        }
+    }
+
+    /// Event handler for tranche_formats events.
+    pub struct TrancheFormats<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for TrancheFormats<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_formats(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+            self.0(_slf, indices)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for TrancheFormats<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_formats(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            indices: &[u8],
+        ) {
+            self.0(_data, _slf, indices)
+        }
+    }
+
+    /// Event handler for tranche_flags events.
+    pub struct TrancheFlags<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandler for TrancheFlags<F>
+    where
+        F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+    {
+        #[inline]
+        fn tranche_flags(
+            &self,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        ) {
+            self.0(_slf, flags)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for TrancheFlags<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+    {
+        #[inline]
+        fn tranche_flags(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        ) {
+            self.0(_data, _slf, flags)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for format_table events.
+    pub struct FormatTableMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for FormatTableMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn format_table(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, fd: OwnedFd, size: u32) {
+            self.0(_slf, fd, size)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for FormatTableMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+    {
+        #[inline]
+        fn format_table(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            fd: OwnedFd,
+            size: u32,
+        ) {
+            self.0(_data, _slf, fd, size)
+        }
+    }
+
+    /// Mutable event handler for main_device events.
+    pub struct MainDeviceMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for MainDeviceMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn main_device(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.0(_slf, device)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for MainDeviceMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn main_device(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            device: &[u8],
+        ) {
+            self.0(_data, _slf, device)
+        }
+    }
+
+    /// Mutable event handler for tranche_done events.
+    pub struct TrancheDoneMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for TrancheDoneMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn tranche_done(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for TrancheDoneMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+    {
+        #[inline]
+        fn tranche_done(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for tranche_target_device events.
+    pub struct TrancheTargetDeviceMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for TrancheTargetDeviceMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_target_device(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.0(_slf, device)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for TrancheTargetDeviceMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_target_device(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            device: &[u8],
+        ) {
+            self.0(_data, _slf, device)
+        }
+    }
+
+    /// Mutable event handler for tranche_formats events.
+    pub struct TrancheFormatsMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for TrancheFormatsMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_formats(&mut self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+            self.0(_slf, indices)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for TrancheFormatsMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+    {
+        #[inline]
+        fn tranche_formats(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            indices: &[u8],
+        ) {
+            self.0(_data, _slf, indices)
+        }
+    }
+
+    /// Mutable event handler for tranche_flags events.
+    pub struct TrancheFlagsMut<F>(F);
+    impl<F> ZwpLinuxDmabufFeedbackV1EventHandlerMut for TrancheFlagsMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+    {
+        #[inline]
+        fn tranche_flags(
+            &mut self,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        ) {
+            self.0(_slf, flags)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufFeedbackV1EventHandlerMutWithData<T> for TrancheFlagsMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+    {
+        #[inline]
+        fn tranche_flags(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        ) {
+            self.0(_data, _slf, flags)
+        }
+    }
+
+    impl ZwpLinuxDmabufFeedbackV1 {
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for format_table events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_format_table<F>(f: F) -> FormatTable<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+        {
+            FormatTable(f)
+        }
+
+        /// Creates an event handler with mutable data for format_table events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_format_table_with_data<T, F>(f: F) -> FormatTable<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+        {
+            FormatTable(f)
+        }
+
+        /// Creates an event handler for main_device events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_main_device<F>(f: F) -> MainDevice<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            MainDevice(f)
+        }
+
+        /// Creates an event handler with mutable data for main_device events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_main_device_with_data<T, F>(f: F) -> MainDevice<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            MainDevice(f)
+        }
+
+        /// Creates an event handler for tranche_done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_done<F>(f: F) -> TrancheDone<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            TrancheDone(f)
+        }
+
+        /// Creates an event handler with mutable data for tranche_done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_done_with_data<T, F>(f: F) -> TrancheDone<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            TrancheDone(f)
+        }
+
+        /// Creates an event handler for tranche_target_device events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_target_device<F>(f: F) -> TrancheTargetDevice<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheTargetDevice(f)
+        }
+
+        /// Creates an event handler with mutable data for tranche_target_device events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_target_device_with_data<T, F>(f: F) -> TrancheTargetDevice<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheTargetDevice(f)
+        }
+
+        /// Creates an event handler for tranche_formats events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_formats<F>(f: F) -> TrancheFormats<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheFormats(f)
+        }
+
+        /// Creates an event handler with mutable data for tranche_formats events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_formats_with_data<T, F>(f: F) -> TrancheFormats<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheFormats(f)
+        }
+
+        /// Creates an event handler for tranche_flags events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_flags<F>(f: F) -> TrancheFlags<F>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+        {
+            TrancheFlags(f)
+        }
+
+        /// Creates an event handler with mutable data for tranche_flags events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tranche_flags_with_data<T, F>(f: F) -> TrancheFlags<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+        {
+            TrancheFlags(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for format_table events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_format_table_mut<F>(f: F) -> FormatTableMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+        {
+            FormatTableMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for format_table events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_format_table_mut_with_data<T, F>(f: F) -> FormatTableMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+        {
+            FormatTableMut(f)
+        }
+
+        /// Creates a mutable event handler for main_device events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_main_device_mut<F>(f: F) -> MainDeviceMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            MainDeviceMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for main_device events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_main_device_mut_with_data<T, F>(f: F) -> MainDeviceMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            MainDeviceMut(f)
+        }
+
+        /// Creates a mutable event handler for tranche_done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_done_mut<F>(f: F) -> TrancheDoneMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            TrancheDoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tranche_done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_done_mut_with_data<T, F>(f: F) -> TrancheDoneMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            TrancheDoneMut(f)
+        }
+
+        /// Creates a mutable event handler for tranche_target_device events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_target_device_mut<F>(f: F) -> TrancheTargetDeviceMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheTargetDeviceMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tranche_target_device events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_target_device_mut_with_data<T, F>(f: F) -> TrancheTargetDeviceMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheTargetDeviceMut(f)
+        }
+
+        /// Creates a mutable event handler for tranche_formats events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_formats_mut<F>(f: F) -> TrancheFormatsMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheFormatsMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tranche_formats events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_formats_mut_with_data<T, F>(f: F) -> TrancheFormatsMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            TrancheFormatsMut(f)
+        }
+
+        /// Creates a mutable event handler for tranche_flags events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_flags_mut<F>(f: F) -> TrancheFlagsMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+        {
+            TrancheFlagsMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tranche_flags events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tranche_flags_mut_with_data<T, F>(f: F) -> TrancheFlagsMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+        {
+            TrancheFlagsMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                done: Ignore,
+                format_table: Ignore,
+                main_device: Ignore,
+                tranche_done: Ignore,
+                tranche_target_device: Ignore,
+                tranche_formats: Ignore,
+                tranche_flags: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpLinuxDmabufFeedbackV1EventHandler for Ignore {}
+    impl<T> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpLinuxDmabufFeedbackV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<
+        H0 = Ignore,
+        H1 = Ignore,
+        H2 = Ignore,
+        H3 = Ignore,
+        H4 = Ignore,
+        H5 = Ignore,
+        H6 = Ignore,
+    > {
+        done: H0,
+        format_table: H1,
+        main_device: H2,
+        tranche_done: H3,
+        tranche_target_device: H4,
+        tranche_formats: H5,
+        tranche_flags: H6,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5, H6> ZwpLinuxDmabufFeedbackV1EventHandler
+        for Handler<H0, H1, H2, H3, H4, H5, H6>
+    where
+        H0: ZwpLinuxDmabufFeedbackV1EventHandler,
+        H1: ZwpLinuxDmabufFeedbackV1EventHandler,
+        H2: ZwpLinuxDmabufFeedbackV1EventHandler,
+        H3: ZwpLinuxDmabufFeedbackV1EventHandler,
+        H4: ZwpLinuxDmabufFeedbackV1EventHandler,
+        H5: ZwpLinuxDmabufFeedbackV1EventHandler,
+        H6: ZwpLinuxDmabufFeedbackV1EventHandler,
+    {
+        #[inline]
+        fn done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn format_table(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, fd: OwnedFd, size: u32) {
+            self.format_table.format_table(_slf, fd, size)
+        }
+
+        #[inline]
+        fn main_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.main_device.main_device(_slf, device)
+        }
+
+        #[inline]
+        fn tranche_done(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.tranche_done.tranche_done(_slf)
+        }
+
+        #[inline]
+        fn tranche_target_device(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.tranche_target_device
+                .tranche_target_device(_slf, device)
+        }
+
+        #[inline]
+        fn tranche_formats(&self, _slf: &ZwpLinuxDmabufFeedbackV1Ref, indices: &[u8]) {
+            self.tranche_formats.tranche_formats(_slf, indices)
+        }
+
+        #[inline]
+        fn tranche_flags(
+            &self,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        ) {
+            self.tranche_flags.tranche_flags(_slf, flags)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5, H6> ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5, H6>
+    where
+        H0: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+        H1: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+        H2: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+        H3: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+        H4: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+        H5: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+        H6: ZwpLinuxDmabufFeedbackV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn format_table(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            fd: OwnedFd,
+            size: u32,
+        ) {
+            self.format_table.format_table(_data, _slf, fd, size)
+        }
+
+        #[inline]
+        fn main_device(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref, device: &[u8]) {
+            self.main_device.main_device(_data, _slf, device)
+        }
+
+        #[inline]
+        fn tranche_done(&self, _data: &mut T, _slf: &ZwpLinuxDmabufFeedbackV1Ref) {
+            self.tranche_done.tranche_done(_data, _slf)
+        }
+
+        #[inline]
+        fn tranche_target_device(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            device: &[u8],
+        ) {
+            self.tranche_target_device
+                .tranche_target_device(_data, _slf, device)
+        }
+
+        #[inline]
+        fn tranche_formats(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            indices: &[u8],
+        ) {
+            self.tranche_formats.tranche_formats(_data, _slf, indices)
+        }
+
+        #[inline]
+        fn tranche_flags(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufFeedbackV1Ref,
+            flags: ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        ) {
+            self.tranche_flags.tranche_flags(_data, _slf, flags)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5, H6> Handler<H0, H1, H2, H3, H4, H5, H6> {
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<Done<F>, H1, H2, H3, H4, H5, H6>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            Handler {
+                done: Done(f),
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<Done<F>, H1, H2, H3, H4, H5, H6>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            Handler {
+                done: Done(f),
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure for format_table events.
+        #[allow(dead_code)]
+        pub fn on_format_table<F>(self, f: F) -> Handler<H0, FormatTable<F>, H2, H3, H4, H5, H6>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+        {
+            Handler {
+                done: self.done,
+                format_table: FormatTable(f),
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for format_table events.
+        #[allow(dead_code)]
+        pub fn on_format_table_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, FormatTable<F>, H2, H3, H4, H5, H6>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, OwnedFd, u32),
+        {
+            Handler {
+                done: self.done,
+                format_table: FormatTable(f),
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure for main_device events.
+        #[allow(dead_code)]
+        pub fn on_main_device<F>(self, f: F) -> Handler<H0, H1, MainDevice<F>, H3, H4, H5, H6>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: MainDevice(f),
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for main_device events.
+        #[allow(dead_code)]
+        pub fn on_main_device_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, MainDevice<F>, H3, H4, H5, H6>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: MainDevice(f),
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure for tranche_done events.
+        #[allow(dead_code)]
+        pub fn on_tranche_done<F>(self, f: F) -> Handler<H0, H1, H2, TrancheDone<F>, H4, H5, H6>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: TrancheDone(f),
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tranche_done events.
+        #[allow(dead_code)]
+        pub fn on_tranche_done_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, TrancheDone<F>, H4, H5, H6>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: TrancheDone(f),
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure for tranche_target_device events.
+        #[allow(dead_code)]
+        pub fn on_tranche_target_device<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, TrancheTargetDevice<F>, H5, H6>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: TrancheTargetDevice(f),
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tranche_target_device events.
+        #[allow(dead_code)]
+        pub fn on_tranche_target_device_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, TrancheTargetDevice<F>, H5, H6>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: TrancheTargetDevice(f),
+                tranche_formats: self.tranche_formats,
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure for tranche_formats events.
+        #[allow(dead_code)]
+        pub fn on_tranche_formats<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, TrancheFormats<F>, H6>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: TrancheFormats(f),
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tranche_formats events.
+        #[allow(dead_code)]
+        pub fn on_tranche_formats_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, TrancheFormats<F>, H6>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, &[u8]),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: TrancheFormats(f),
+                tranche_flags: self.tranche_flags,
+            }
+        }
+
+        /// Sets the closure for tranche_flags events.
+        #[allow(dead_code)]
+        pub fn on_tranche_flags<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, H5, TrancheFlags<F>>
+        where
+            F: Fn(&ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: TrancheFlags(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tranche_flags events.
+        #[allow(dead_code)]
+        pub fn on_tranche_flags_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, TrancheFlags<F>>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufFeedbackV1Ref, ZwpLinuxDmabufFeedbackV1TrancheFlags),
+        {
+            Handler {
+                done: self.done,
+                format_table: self.format_table,
+                main_device: self.main_device,
+                tranche_done: self.tranche_done,
+                tranche_target_device: self.tranche_target_device,
+                tranche_formats: self.tranche_formats,
+                tranche_flags: TrancheFlags(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1336 @@
+//! factory for creating dmabuf-based wl_buffers
+//!
+//! This interface offers ways to create generic dmabuf-based wl_buffers.
+//!
+//! For more information about dmabuf, see:
+//! https://www.kernel.org/doc/html/next/userspace-api/dma-buf-alloc-exchange.html
+//!
+//! Clients can use the get_surface_feedback request to get dmabuf feedback
+//! for a particular surface. If the client wants to retrieve feedback not
+//! tied to a surface, they can use the get_default_feedback request.
+//!
+//! The following are required from clients:
+//!
+//! - Clients must ensure that either all data in the dma-buf is
+//!   coherent for all subsequent read access or that coherency is
+//!   correctly handled by the underlying kernel-side dma-buf
+//!   implementation.
+//!
+//! - Don't make any more attachments after sending the buffer to the
+//!   compositor. Making more attachments later increases the risk of
+//!   the compositor not being able to use (re-import) an existing
+//!   dmabuf-based wl_buffer.
+//!
+//! The underlying graphics stack must ensure the following:
+//!
+//! - The dmabuf file descriptors relayed to the server will stay valid
+//!   for the whole lifetime of the wl_buffer. This means the server may
+//!   at any time use those fds to import the dmabuf into any kernel
+//!   sub-system that might accept it.
+//!
+//! However, when the underlying graphics stack fails to deliver the
+//! promise, because of e.g. a device hot-unplug which raises internal
+//! errors, after the wl_buffer has been successfully created the
+//! compositor must not raise protocol errors to the client when dmabuf
+//! import later fails.
+//!
+//! To create a wl_buffer from one or more dmabufs, a client creates a
+//! zwp_linux_dmabuf_params_v1 object with a zwp_linux_dmabuf_v1.create_params
+//! request. All planes required by the intended format are added with
+//! the 'add' request. Finally, a 'create' or 'create_immed' request is
+//! issued, which has the following outcome depending on the import success.
+//!
+//! The 'create' request,
+//! - on success, triggers a 'created' event which provides the final
+//!   wl_buffer to the client.
+//! - on failure, triggers a 'failed' event to convey that the server
+//!   cannot use the dmabufs received from the client.
+//!
+//! For the 'create_immed' request,
+//! - on success, the server immediately imports the added dmabufs to
+//!   create a wl_buffer. No event is sent from the server in this case.
+//! - on failure, the server can choose to either:
+//!   - terminate the client by raising a fatal error.
+//!   - mark the wl_buffer as failed, and send a 'failed' event to the
+//!     client. If the client uses a failed wl_buffer as an argument to any
+//!     request, the behaviour is compositor implementation-defined.
+//!
+//! For all DRM formats and unless specified in another protocol extension,
+//! pre-multiplied alpha is used for pixel values.
+//!
+//! Unless specified otherwise in another protocol extension, implicit
+//! synchronization is used. In other words, compositors and clients must
+//! wait and signal fences implicitly passed via the DMA-BUF's reservation
+//! mechanism.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_linux_dmabuf_v1".as_ptr(),
+    version: 5,
+    method_count: 4,
+    methods: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_params".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpLinuxBufferParamsV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_default_feedback".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpLinuxDmabufFeedbackV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface_feedback".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpLinuxDmabufFeedbackV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"format".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"modifier".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_linux_dmabuf_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_linux_dmabuf_v1",
+    protocol: "linux_dmabuf_v1",
+    version: 5,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "create_params",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "params_id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_linux_buffer_params_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_default_feedback",
+            since: 4,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_surface_feedback",
+            since: 4,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_linux_dmabuf_feedback_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "format",
+            since: 1,
+            deprecated_since: Some(4),
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "format",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "modifier",
+            since: 3,
+            deprecated_since: Some(4),
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "format",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifier_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifier_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_linux_dmabuf_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxDmabufV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_linux_dmabuf_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxDmabufV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLinuxDmabufV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLinuxDmabufV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLinuxDmabufV1 {
+    const INTERFACE: &'static str = "zwp_linux_dmabuf_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 5;
+
+    type Borrowed = ZwpLinuxDmabufV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLinuxDmabufV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLinuxDmabufV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLinuxDmabufV1Ref {
+    type Owned = ZwpLinuxDmabufV1;
+}
+
+impl Deref for ZwpLinuxDmabufV1 {
+    type Target = ZwpLinuxDmabufV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLinuxDmabufV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_dmabuf_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpLinuxDmabufV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_dmabuf_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpLinuxDmabufV1Ref> for ZwpLinuxDmabufV1 {
+    fn eq(&self, other: &ZwpLinuxDmabufV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLinuxDmabufV1> for ZwpLinuxDmabufV1Ref {
+    fn eq(&self, other: &ZwpLinuxDmabufV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxDmabufV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// unbind the factory
+    ///
+    /// Objects created through this interface, especially wl_buffers, will
+    /// remain valid.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the create_params request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_PARAMS__SINCE: u32 = 1;
+
+    /// create a temporary object for buffer parameters
+    ///
+    /// This temporary object is used to collect multiple dmabuf handles into
+    /// a single batch to create a wl_buffer. It can only be used once and
+    /// should be destroyed after a 'created' or 'failed' event has been
+    /// received.
+    #[inline]
+    pub fn create_params(&self) -> ZwpLinuxBufferParamsV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpLinuxBufferParamsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxBufferParamsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_default_feedback request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_DEFAULT_FEEDBACK__SINCE: u32 = 4;
+
+    /// get default feedback
+    ///
+    /// This request creates a new wp_linux_dmabuf_feedback object not bound
+    /// to a particular surface. This object will deliver feedback about dmabuf
+    /// parameters to use if the client doesn't support per-surface feedback
+    /// (see get_surface_feedback).
+    #[inline]
+    pub fn get_default_feedback(&self) -> ZwpLinuxDmabufFeedbackV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                ZwpLinuxDmabufFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxDmabufFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_surface_feedback request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE_FEEDBACK__SINCE: u32 = 4;
+
+    /// get feedback for a surface
+    ///
+    /// This request creates a new wp_linux_dmabuf_feedback object for the
+    /// specified wl_surface. This object will deliver feedback about dmabuf
+    /// parameters to use for buffers attached to this surface.
+    ///
+    /// If the surface is destroyed before the wp_linux_dmabuf_feedback object,
+    /// the feedback object becomes inert.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface_feedback(&self, surface: &WlSurfaceRef) -> ZwpLinuxDmabufFeedbackV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                3,
+                &mut args,
+                ZwpLinuxDmabufFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxDmabufFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxDmabufV1Ref {
+    /// create a temporary object for buffer parameters
+    ///
+    /// This temporary object is used to collect multiple dmabuf handles into
+    /// a single batch to create a wl_buffer. It can only be used once and
+    /// should be destroyed after a 'created' or 'failed' event has been
+    /// received.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_params(&self, _queue: &Queue) -> ZwpLinuxBufferParamsV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpLinuxBufferParamsV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxBufferParamsV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// get default feedback
+    ///
+    /// This request creates a new wp_linux_dmabuf_feedback object not bound
+    /// to a particular surface. This object will deliver feedback about dmabuf
+    /// parameters to use if the client doesn't support per-surface feedback
+    /// (see get_surface_feedback).
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_default_feedback(&self, _queue: &Queue) -> ZwpLinuxDmabufFeedbackV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                ZwpLinuxDmabufFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxDmabufFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// get feedback for a surface
+    ///
+    /// This request creates a new wp_linux_dmabuf_feedback object for the
+    /// specified wl_surface. This object will deliver feedback about dmabuf
+    /// parameters to use for buffers attached to this surface.
+    ///
+    /// If the surface is destroyed before the wp_linux_dmabuf_feedback object,
+    /// the feedback object becomes inert.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_surface_feedback(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> ZwpLinuxDmabufFeedbackV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                3,
+                &mut args,
+                ZwpLinuxDmabufFeedbackV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxDmabufFeedbackV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl ZwpLinuxDmabufV1 {
+    /// Since when the format event is available.
+    #[allow(dead_code)]
+    pub const EVT__FORMAT__SINCE: u32 = 1;
+
+    /// Since when the format event is deprecated.
+    #[allow(dead_code)]
+    pub const EVT__FORMAT__DEPRECATED_SINCE: u32 = 4;
+
+    /// Since when the modifier event is available.
+    #[allow(dead_code)]
+    pub const EVT__MODIFIER__SINCE: u32 = 3;
+
+    /// Since when the modifier event is deprecated.
+    #[allow(dead_code)]
+    pub const EVT__MODIFIER__DEPRECATED_SINCE: u32 = 4;
+}
+
+/// An event handler for [ZwpLinuxDmabufV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufV1EventHandler {
+    /// supported buffer format
+    ///
+    /// This event advertises one buffer format that the server supports.
+    /// All the supported formats are advertised once when the client
+    /// binds to this interface. A roundtrip after binding guarantees
+    /// that the client has received all supported formats.
+    ///
+    /// For the definition of the format codes, see the
+    /// zwp_linux_buffer_params_v1::create request.
+    ///
+    /// Starting version 4, the format event is deprecated and must not be
+    /// sent by compositors. Instead, use get_default_feedback or
+    /// get_surface_feedback.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: DRM_FORMAT code
+    #[inline]
+    fn format(&self, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        let _ = format;
+    }
+
+    /// supported buffer format modifier
+    ///
+    /// This event advertises the formats that the server supports, along with
+    /// the modifiers supported for each format. All the supported modifiers
+    /// for all the supported formats are advertised once when the client
+    /// binds to this interface. A roundtrip after binding guarantees that
+    /// the client has received all supported format-modifier pairs.
+    ///
+    /// For legacy support, DRM_FORMAT_MOD_INVALID (that is, modifier_hi ==
+    /// 0x00ffffff and modifier_lo == 0xffffffff) is allowed in this event.
+    /// It indicates that the server can support the format with an implicit
+    /// modifier. When a plane has DRM_FORMAT_MOD_INVALID as its modifier, it
+    /// is as if no explicit modifier is specified. The effective modifier
+    /// will be derived from the dmabuf.
+    ///
+    /// A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for
+    /// a given format supports both explicit modifiers and implicit modifiers.
+    ///
+    /// For the definition of the format and modifier codes, see the
+    /// zwp_linux_buffer_params_v1::create and zwp_linux_buffer_params_v1::add
+    /// requests.
+    ///
+    /// Starting version 4, the modifier event is deprecated and must not be
+    /// sent by compositors. Instead, use get_default_feedback or
+    /// get_surface_feedback.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: DRM_FORMAT code
+    /// - `modifier_hi`: high 32 bits of layout modifier
+    /// - `modifier_lo`: low 32 bits of layout modifier
+    #[inline]
+    fn modifier(
+        &self,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let _ = format;
+        let _ = modifier_hi;
+        let _ = modifier_lo;
+    }
+}
+
+impl ZwpLinuxDmabufV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLinuxDmabufV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxDmabufV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufV1EventHandlerWithData<T> {
+    /// supported buffer format
+    ///
+    /// This event advertises one buffer format that the server supports.
+    /// All the supported formats are advertised once when the client
+    /// binds to this interface. A roundtrip after binding guarantees
+    /// that the client has received all supported formats.
+    ///
+    /// For the definition of the format codes, see the
+    /// zwp_linux_buffer_params_v1::create request.
+    ///
+    /// Starting version 4, the format event is deprecated and must not be
+    /// sent by compositors. Instead, use get_default_feedback or
+    /// get_surface_feedback.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: DRM_FORMAT code
+    #[inline]
+    fn format(&self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        let _ = format;
+    }
+
+    /// supported buffer format modifier
+    ///
+    /// This event advertises the formats that the server supports, along with
+    /// the modifiers supported for each format. All the supported modifiers
+    /// for all the supported formats are advertised once when the client
+    /// binds to this interface. A roundtrip after binding guarantees that
+    /// the client has received all supported format-modifier pairs.
+    ///
+    /// For legacy support, DRM_FORMAT_MOD_INVALID (that is, modifier_hi ==
+    /// 0x00ffffff and modifier_lo == 0xffffffff) is allowed in this event.
+    /// It indicates that the server can support the format with an implicit
+    /// modifier. When a plane has DRM_FORMAT_MOD_INVALID as its modifier, it
+    /// is as if no explicit modifier is specified. The effective modifier
+    /// will be derived from the dmabuf.
+    ///
+    /// A compositor that sends valid modifiers and DRM_FORMAT_MOD_INVALID for
+    /// a given format supports both explicit modifiers and implicit modifiers.
+    ///
+    /// For the definition of the format and modifier codes, see the
+    /// zwp_linux_buffer_params_v1::create and zwp_linux_buffer_params_v1::add
+    /// requests.
+    ///
+    /// Starting version 4, the modifier event is deprecated and must not be
+    /// sent by compositors. Instead, use get_default_feedback or
+    /// get_surface_feedback.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: DRM_FORMAT code
+    /// - `modifier_hi`: high 32 bits of layout modifier
+    /// - `modifier_lo`: low 32 bits of layout modifier
+    #[inline]
+    fn modifier(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let _ = format;
+        let _ = modifier_hi;
+        let _ = modifier_lo;
+    }
+}
+
+/// A mutable event handler for [ZwpLinuxDmabufV1] proxies.
+///
+/// This trait is the same as [ZwpLinuxDmabufV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufV1EventHandlerMut {
+    /// See [ZwpLinuxDmabufV1EventHandler::format].
+    #[inline]
+    fn format(&mut self, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        let _ = format;
+    }
+
+    /// See [ZwpLinuxDmabufV1EventHandler::modifier].
+    #[inline]
+    fn modifier(
+        &mut self,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let _ = format;
+        let _ = modifier_hi;
+        let _ = modifier_lo;
+    }
+}
+
+/// A mutable event handler for [ZwpLinuxDmabufV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxDmabufV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxDmabufV1EventHandlerMutWithData<T> {
+    /// See [ZwpLinuxDmabufV1EventHandler::format].
+    #[inline]
+    fn format(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        let _ = format;
+    }
+
+    /// See [ZwpLinuxDmabufV1EventHandler::modifier].
+    #[inline]
+    fn modifier(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let _ = format;
+        let _ = modifier_hi;
+        let _ = modifier_lo;
+    }
+}
+
+impl<H> ZwpLinuxDmabufV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpLinuxDmabufV1EventHandlerMut,
+{
+    #[inline]
+    fn format(&self, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        self.with(|h| h.format(_slf, format))
+    }
+
+    #[inline]
+    fn modifier(
+        &self,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        self.with(|h| h.modifier(_slf, format, modifier_hi, modifier_lo))
+    }
+}
+
+impl<T, H> ZwpLinuxDmabufV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpLinuxDmabufV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn format(&self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        self.with(|h| h.format(_data, _slf, format))
+    }
+
+    #[inline]
+    fn modifier(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        self.with(|h| h.modifier(_data, _slf, format, modifier_hi, modifier_lo))
+    }
+}
+
+impl<T, U, H> ZwpLinuxDmabufV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxDmabufV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn format(&self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        let _data = self.project(_data);
+        self.handler().format(_data, _slf, format)
+    }
+
+    #[inline]
+    fn modifier(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .modifier(_data, _slf, format, modifier_hi, modifier_lo)
+    }
+}
+
+impl<T, U, H> ZwpLinuxDmabufV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxDmabufV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn format(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().format(_data, _slf, format)
+    }
+
+    #[inline]
+    fn modifier(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpLinuxDmabufV1Ref,
+        format: u32,
+        modifier_hi: u32,
+        modifier_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .modifier(_data, _slf, format, modifier_hi, modifier_lo)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLinuxDmabufV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxDmabufV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.format(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.modifier(slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_dmabuf_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLinuxDmabufV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxDmabufV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.format(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.modifier(data, slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_dmabuf_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLinuxDmabufV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLinuxDmabufV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for format events.
+    pub struct Format<F>(F);
+    impl<F> ZwpLinuxDmabufV1EventHandler for Format<F>
+    where
+        F: Fn(&ZwpLinuxDmabufV1Ref, u32),
+    {
+        #[inline]
+        fn format(&self, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+            self.0(_slf, format)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufV1EventHandlerWithData<T> for Format<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufV1Ref, u32),
+    {
+        #[inline]
+        fn format(&self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+            self.0(_data, _slf, format)
+        }
+    }
+
+    /// Event handler for modifier events.
+    pub struct Modifier<F>(F);
+    impl<F> ZwpLinuxDmabufV1EventHandler for Modifier<F>
+    where
+        F: Fn(&ZwpLinuxDmabufV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn modifier(
+            &self,
+            _slf: &ZwpLinuxDmabufV1Ref,
+            format: u32,
+            modifier_hi: u32,
+            modifier_lo: u32,
+        ) {
+            self.0(_slf, format, modifier_hi, modifier_lo)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufV1EventHandlerWithData<T> for Modifier<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxDmabufV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn modifier(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufV1Ref,
+            format: u32,
+            modifier_hi: u32,
+            modifier_lo: u32,
+        ) {
+            self.0(_data, _slf, format, modifier_hi, modifier_lo)
+        }
+    }
+
+    /// Mutable event handler for format events.
+    pub struct FormatMut<F>(F);
+    impl<F> ZwpLinuxDmabufV1EventHandlerMut for FormatMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufV1Ref, u32),
+    {
+        #[inline]
+        fn format(&mut self, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+            self.0(_slf, format)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufV1EventHandlerMutWithData<T> for FormatMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufV1Ref, u32),
+    {
+        #[inline]
+        fn format(&mut self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+            self.0(_data, _slf, format)
+        }
+    }
+
+    /// Mutable event handler for modifier events.
+    pub struct ModifierMut<F>(F);
+    impl<F> ZwpLinuxDmabufV1EventHandlerMut for ModifierMut<F>
+    where
+        F: FnMut(&ZwpLinuxDmabufV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn modifier(
+            &mut self,
+            _slf: &ZwpLinuxDmabufV1Ref,
+            format: u32,
+            modifier_hi: u32,
+            modifier_lo: u32,
+        ) {
+            self.0(_slf, format, modifier_hi, modifier_lo)
+        }
+    }
+    impl<T, F> ZwpLinuxDmabufV1EventHandlerMutWithData<T> for ModifierMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxDmabufV1Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn modifier(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufV1Ref,
+            format: u32,
+            modifier_hi: u32,
+            modifier_lo: u32,
+        ) {
+            self.0(_data, _slf, format, modifier_hi, modifier_lo)
+        }
+    }
+
+    impl ZwpLinuxDmabufV1 {
+        /// Creates an event handler for format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_format<F>(f: F) -> Format<F>
+        where
+            F: Fn(&ZwpLinuxDmabufV1Ref, u32),
+        {
+            Format(f)
+        }
+
+        /// Creates an event handler with mutable data for format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_format_with_data<T, F>(f: F) -> Format<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufV1Ref, u32),
+        {
+            Format(f)
+        }
+
+        /// Creates an event handler for modifier events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_modifier<F>(f: F) -> Modifier<F>
+        where
+            F: Fn(&ZwpLinuxDmabufV1Ref, u32, u32, u32),
+        {
+            Modifier(f)
+        }
+
+        /// Creates an event handler with mutable data for modifier events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_modifier_with_data<T, F>(f: F) -> Modifier<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufV1Ref, u32, u32, u32),
+        {
+            Modifier(f)
+        }
+
+        /// Creates a mutable event handler for format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_format_mut<F>(f: F) -> FormatMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufV1Ref, u32),
+        {
+            FormatMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_format_mut_with_data<T, F>(f: F) -> FormatMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufV1Ref, u32),
+        {
+            FormatMut(f)
+        }
+
+        /// Creates a mutable event handler for modifier events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modifier_mut<F>(f: F) -> ModifierMut<F>
+        where
+            F: FnMut(&ZwpLinuxDmabufV1Ref, u32, u32, u32),
+        {
+            ModifierMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for modifier events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modifier_mut_with_data<T, F>(f: F) -> ModifierMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxDmabufV1Ref, u32, u32, u32),
+        {
+            ModifierMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                format: Ignore,
+                modifier: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpLinuxDmabufV1EventHandler for Ignore {}
+    impl<T> ZwpLinuxDmabufV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpLinuxDmabufV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        format: H0,
+        modifier: H1,
+    }
+
+    impl<H0, H1> ZwpLinuxDmabufV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpLinuxDmabufV1EventHandler,
+        H1: ZwpLinuxDmabufV1EventHandler,
+    {
+        #[inline]
+        fn format(&self, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+            self.format.format(_slf, format)
+        }
+
+        #[inline]
+        fn modifier(
+            &self,
+            _slf: &ZwpLinuxDmabufV1Ref,
+            format: u32,
+            modifier_hi: u32,
+            modifier_lo: u32,
+        ) {
+            self.modifier
+                .modifier(_slf, format, modifier_hi, modifier_lo)
+        }
+    }
+
+    impl<T, H0, H1> ZwpLinuxDmabufV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpLinuxDmabufV1EventHandlerWithData<T>,
+        H1: ZwpLinuxDmabufV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn format(&self, _data: &mut T, _slf: &ZwpLinuxDmabufV1Ref, format: u32) {
+            self.format.format(_data, _slf, format)
+        }
+
+        #[inline]
+        fn modifier(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpLinuxDmabufV1Ref,
+            format: u32,
+            modifier_hi: u32,
+            modifier_lo: u32,
+        ) {
+            self.modifier
+                .modifier(_data, _slf, format, modifier_hi, modifier_lo)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for format events.
+        #[allow(dead_code)]
+        pub fn on_format<F>(self, f: F) -> Handler<Format<F>, H1>
+        where
+            F: Fn(&ZwpLinuxDmabufV1Ref, u32),
+        {
+            Handler {
+                format: Format(f),
+                modifier: self.modifier,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for format events.
+        #[allow(dead_code)]
+        pub fn on_format_with_data<T, F>(self, f: F) -> Handler<Format<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufV1Ref, u32),
+        {
+            Handler {
+                format: Format(f),
+                modifier: self.modifier,
+            }
+        }
+
+        /// Sets the closure for modifier events.
+        #[allow(dead_code)]
+        pub fn on_modifier<F>(self, f: F) -> Handler<H0, Modifier<F>>
+        where
+            F: Fn(&ZwpLinuxDmabufV1Ref, u32, u32, u32),
+        {
+            Handler {
+                format: self.format,
+                modifier: Modifier(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for modifier events.
+        #[allow(dead_code)]
+        pub fn on_modifier_with_data<T, F>(self, f: F) -> Handler<H0, Modifier<F>>
+        where
+            F: Fn(&mut T, &ZwpLinuxDmabufV1Ref, u32, u32, u32),
+        {
+            Handler {
+                format: self.format,
+                modifier: Modifier(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,580 @@
+//! global for providing explicit synchronization
+//!
+//! This global is a factory interface, allowing clients to request
+//! explicit synchronization for buffers on a per-surface basis.
+//!
+//! See wp_linux_drm_syncobj_surface_v1 for more information.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_linux_drm_syncobj_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_surface".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpLinuxDrmSyncobjSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"import_timeline".as_ptr(),
+                signature: c"nh".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_linux_drm_syncobj_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_linux_drm_syncobj_manager_v1",
+    protocol: "linux_drm_syncobj_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_linux_drm_syncobj_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "import_timeline",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_linux_drm_syncobj_timeline_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "surface_exists",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_timeline",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_linux_drm_syncobj_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpLinuxDrmSyncobjManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_linux_drm_syncobj_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpLinuxDrmSyncobjManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpLinuxDrmSyncobjManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpLinuxDrmSyncobjManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpLinuxDrmSyncobjManagerV1 {
+    const INTERFACE: &'static str = "wp_linux_drm_syncobj_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpLinuxDrmSyncobjManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpLinuxDrmSyncobjManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpLinuxDrmSyncobjManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpLinuxDrmSyncobjManagerV1Ref {
+    type Owned = WpLinuxDrmSyncobjManagerV1;
+}
+
+impl Deref for WpLinuxDrmSyncobjManagerV1 {
+    type Target = WpLinuxDrmSyncobjManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpLinuxDrmSyncobjManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_linux_drm_syncobj_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpLinuxDrmSyncobjManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_linux_drm_syncobj_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpLinuxDrmSyncobjManagerV1Ref> for WpLinuxDrmSyncobjManagerV1 {
+    fn eq(&self, other: &WpLinuxDrmSyncobjManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpLinuxDrmSyncobjManagerV1> for WpLinuxDrmSyncobjManagerV1Ref {
+    fn eq(&self, other: &WpLinuxDrmSyncobjManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpLinuxDrmSyncobjManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy explicit synchronization factory object
+    ///
+    /// Destroy this explicit synchronization factory object. Other objects
+    /// shall not be affected by this request.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SURFACE__SINCE: u32 = 1;
+
+    /// extend surface interface for explicit synchronization
+    ///
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the surface_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a surface_exists protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface
+    #[inline]
+    pub fn get_surface(&self, surface: &WlSurfaceRef) -> WpLinuxDrmSyncobjSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpLinuxDrmSyncobjSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpLinuxDrmSyncobjSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the import_timeline request is available.
+    #[allow(dead_code)]
+    pub const REQ__IMPORT_TIMELINE__SINCE: u32 = 1;
+
+    /// import a DRM syncobj timeline
+    ///
+    /// Import a DRM synchronization object timeline.
+    ///
+    /// If the FD cannot be imported, the invalid_timeline error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: drm_syncobj file descriptor
+    #[inline]
+    pub fn import_timeline(&self, fd: BorrowedFd<'_>) -> WpLinuxDrmSyncobjTimelineV1 {
+        let (arg1,) = (fd,);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument {
+                h: arg1.as_raw_fd(),
+            },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `nh`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpLinuxDrmSyncobjManagerV1Ref {
+    /// extend surface interface for explicit synchronization
+    ///
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the surface_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a surface_exists protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface
+    #[inline]
+    pub fn get_surface(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> WpLinuxDrmSyncobjSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpLinuxDrmSyncobjSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpLinuxDrmSyncobjSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// import a DRM syncobj timeline
+    ///
+    /// Import a DRM synchronization object timeline.
+    ///
+    /// If the FD cannot be imported, the invalid_timeline error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `fd`: drm_syncobj file descriptor
+    #[inline]
+    pub fn import_timeline(
+        &self,
+        _queue: &Queue,
+        fd: BorrowedFd<'_>,
+    ) -> WpLinuxDrmSyncobjTimelineV1 {
+        let (arg1,) = (fd,);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument {
+                h: arg1.as_raw_fd(),
+            },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `nh`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpLinuxDrmSyncobjManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjManagerV1EventHandler {}
+
+impl WpLinuxDrmSyncobjManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpLinuxDrmSyncobjManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpLinuxDrmSyncobjManagerV1] proxies.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpLinuxDrmSyncobjManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpLinuxDrmSyncobjManagerV1EventHandler for MutEventHandler<H> where
+    H: WpLinuxDrmSyncobjManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> WpLinuxDrmSyncobjManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpLinuxDrmSyncobjManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpLinuxDrmSyncobjManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpLinuxDrmSyncobjManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpLinuxDrmSyncobjManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpLinuxDrmSyncobjManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpLinuxDrmSyncobjManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_linux_drm_syncobj_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpLinuxDrmSyncobjManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_linux_drm_syncobj_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpLinuxDrmSyncobjManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpLinuxDrmSyncobjManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpLinuxDrmSyncobjManagerV1 {
+    /// Since when the error.surface_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SURFACE_EXISTS__SINCE: u32 = 1;
+    /// Since when the error.invalid_timeline enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_TIMELINE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpLinuxDrmSyncobjManagerV1Error(pub u32);
+
+impl WpLinuxDrmSyncobjManagerV1Error {
+    /// the surface already has a synchronization object associated
+    #[allow(dead_code)]
+    pub const SURFACE_EXISTS: Self = Self(0);
+
+    /// the timeline object could not be imported
+    #[allow(dead_code)]
+    pub const INVALID_TIMELINE: Self = Self(1);
+}
+
+impl Debug for WpLinuxDrmSyncobjManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::SURFACE_EXISTS => "SURFACE_EXISTS",
+            Self::INVALID_TIMELINE => "INVALID_TIMELINE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpLinuxDrmSyncobjManagerV1 {}
+}
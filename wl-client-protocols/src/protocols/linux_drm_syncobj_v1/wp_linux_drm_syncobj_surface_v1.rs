@@ -0,0 +1,634 @@
+//! per-surface explicit synchronization
+//!
+//! This object is an add-on interface for wl_surface to enable explicit
+//! synchronization.
+//!
+//! Each surface can be associated with only one object of this interface at
+//! any time.
+//!
+//! Explicit synchronization is guaranteed to be supported for buffers
+//! created with any version of the linux-dmabuf protocol. Compositors are
+//! free to support explicit synchronization for additional buffer types.
+//! If at surface commit time the attached buffer does not support explicit
+//! synchronization, an unsupported_buffer error is raised.
+//!
+//! As long as the wp_linux_drm_syncobj_surface_v1 object is alive, the
+//! compositor may ignore implicit synchronization for buffers attached and
+//! committed to the wl_surface. The delivery of wl_buffer.release events
+//! for buffers attached to the surface becomes undefined.
+//!
+//! Clients must set both acquire and release points if and only if a
+//! non-null buffer is attached in the same surface commit. See the
+//! no_buffer, no_acquire_point and no_release_point protocol errors.
+//!
+//! If at surface commit time the acquire and release DRM syncobj timelines
+//! are identical, the acquire point value must be strictly less than the
+//! release point value, or else the conflicting_points protocol error is
+//! raised.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_linux_drm_syncobj_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_acquire_point".as_ptr(),
+                signature: c"ouu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] =
+                        [Some(WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE), None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_release_point".as_ptr(),
+                signature: c"ouu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] =
+                        [Some(WpLinuxDrmSyncobjTimelineV1::WL_INTERFACE), None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_linux_drm_syncobj_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_linux_drm_syncobj_surface_v1",
+    protocol: "linux_drm_syncobj_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_acquire_point",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "timeline",
+                    ty: info::ArgType::Object,
+                    interface: Some("wp_linux_drm_syncobj_timeline_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "point_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "point_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_release_point",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "timeline",
+                    ty: info::ArgType::Object,
+                    interface: Some("wp_linux_drm_syncobj_timeline_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "point_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "point_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "no_surface",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "unsupported_buffer",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_buffer",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_acquire_point",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_release_point",
+                value: 5,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "conflicting_points",
+                value: 6,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_linux_drm_syncobj_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpLinuxDrmSyncobjSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_linux_drm_syncobj_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpLinuxDrmSyncobjSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpLinuxDrmSyncobjSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpLinuxDrmSyncobjSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpLinuxDrmSyncobjSurfaceV1 {
+    const INTERFACE: &'static str = "wp_linux_drm_syncobj_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpLinuxDrmSyncobjSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpLinuxDrmSyncobjSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpLinuxDrmSyncobjSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpLinuxDrmSyncobjSurfaceV1Ref {
+    type Owned = WpLinuxDrmSyncobjSurfaceV1;
+}
+
+impl Deref for WpLinuxDrmSyncobjSurfaceV1 {
+    type Target = WpLinuxDrmSyncobjSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpLinuxDrmSyncobjSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_linux_drm_syncobj_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpLinuxDrmSyncobjSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_linux_drm_syncobj_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpLinuxDrmSyncobjSurfaceV1Ref> for WpLinuxDrmSyncobjSurfaceV1 {
+    fn eq(&self, other: &WpLinuxDrmSyncobjSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpLinuxDrmSyncobjSurfaceV1> for WpLinuxDrmSyncobjSurfaceV1Ref {
+    fn eq(&self, other: &WpLinuxDrmSyncobjSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpLinuxDrmSyncobjSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the surface synchronization object
+    ///
+    /// Destroy this surface synchronization object.
+    ///
+    /// Any timeline point set by this object with set_acquire_point or
+    /// set_release_point since the last commit may be discarded by the
+    /// compositor. Any timeline point set by this object before the last
+    /// commit will not be affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpLinuxDrmSyncobjSurfaceV1Ref {
+    /// set the acquire timeline point
+    ///
+    /// Set the timeline point that must be signalled before the compositor may
+    /// sample from the buffer attached with wl_surface.attach.
+    ///
+    /// The 64-bit unsigned value combined from point_hi and point_lo is the
+    /// point value.
+    ///
+    /// The acquire point is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If an acquire point has already been attached during the same commit
+    /// cycle, the new point replaces the old one.
+    ///
+    /// If the associated wl_surface was destroyed, a no_surface error is
+    /// raised.
+    ///
+    /// If at surface commit time there is a pending acquire timeline point set
+    /// but no pending buffer attached, a no_buffer error is raised. If at
+    /// surface commit time there is a pending buffer attached but no pending
+    /// acquire timeline point set, the no_acquire_point protocol error is
+    /// raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeline`:
+    /// - `point_hi`: high 32 bits of the point value
+    /// - `point_lo`: low 32 bits of the point value
+    #[inline]
+    pub fn set_acquire_point(
+        &self,
+        timeline: &WpLinuxDrmSyncobjTimelineV1Ref,
+        point_hi: u32,
+        point_lo: u32,
+    ) {
+        let (arg0, arg1, arg2) = (timeline, point_hi, point_lo);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("timeline", obj0_lock.wl_proxy());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `ouu`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// set the release timeline point
+    ///
+    /// Set the timeline point that must be signalled by the compositor when it
+    /// has finished its usage of the buffer attached with wl_surface.attach
+    /// for the relevant commit.
+    ///
+    /// Once the timeline point is signaled, and assuming the associated buffer
+    /// is not pending release from other wl_surface.commit requests, no
+    /// additional explicit or implicit synchronization with the compositor is
+    /// required to safely re-use the buffer.
+    ///
+    /// Note that clients cannot rely on the release point being always
+    /// signaled after the acquire point: compositors may release buffers
+    /// without ever reading from them. In addition, the compositor may use
+    /// different presentation paths for different commits, which may have
+    /// different release behavior. As a result, the compositor may signal the
+    /// release points in a different order than the client committed them.
+    ///
+    /// Because signaling a timeline point also signals every previous point,
+    /// it is generally not safe to use the same timeline object for the
+    /// release points of multiple buffers. The out-of-order signaling
+    /// described above may lead to a release point being signaled before the
+    /// compositor has finished reading. To avoid this, it is strongly
+    /// recommended that each buffer should use a separate timeline for its
+    /// release points.
+    ///
+    /// The 64-bit unsigned value combined from point_hi and point_lo is the
+    /// point value.
+    ///
+    /// The release point is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If a release point has already been attached during the same commit
+    /// cycle, the new point replaces the old one.
+    ///
+    /// If the associated wl_surface was destroyed, a no_surface error is
+    /// raised.
+    ///
+    /// If at surface commit time there is a pending release timeline point set
+    /// but no pending buffer attached, a no_buffer error is raised. If at
+    /// surface commit time there is a pending buffer attached but no pending
+    /// release timeline point set, the no_release_point protocol error is
+    /// raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `timeline`:
+    /// - `point_hi`: high 32 bits of the point value
+    /// - `point_lo`: low 32 bits of the point value
+    #[inline]
+    pub fn set_release_point(
+        &self,
+        timeline: &WpLinuxDrmSyncobjTimelineV1Ref,
+        point_hi: u32,
+        point_lo: u32,
+    ) {
+        let (arg0, arg1, arg2) = (timeline, point_hi, point_lo);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("timeline", obj0_lock.wl_proxy());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `ouu`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpLinuxDrmSyncobjSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjSurfaceV1EventHandler {}
+
+impl WpLinuxDrmSyncobjSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpLinuxDrmSyncobjSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpLinuxDrmSyncobjSurfaceV1] proxies.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [WpLinuxDrmSyncobjSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> WpLinuxDrmSyncobjSurfaceV1EventHandler for MutEventHandler<H> where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpLinuxDrmSyncobjSurfaceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_linux_drm_syncobj_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_linux_drm_syncobj_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpLinuxDrmSyncobjSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpLinuxDrmSyncobjSurfaceV1 {
+    /// Since when the error.no_surface enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_SURFACE__SINCE: u32 = 1;
+    /// Since when the error.unsupported_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_UNSUPPORTED_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.no_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.no_acquire_point enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_ACQUIRE_POINT__SINCE: u32 = 1;
+    /// Since when the error.no_release_point enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_RELEASE_POINT__SINCE: u32 = 1;
+    /// Since when the error.conflicting_points enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_CONFLICTING_POINTS__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpLinuxDrmSyncobjSurfaceV1Error(pub u32);
+
+impl WpLinuxDrmSyncobjSurfaceV1Error {
+    /// the associated wl_surface was destroyed
+    #[allow(dead_code)]
+    pub const NO_SURFACE: Self = Self(1);
+
+    /// the buffer does not support explicit synchronization
+    #[allow(dead_code)]
+    pub const UNSUPPORTED_BUFFER: Self = Self(2);
+
+    /// no buffer was attached
+    #[allow(dead_code)]
+    pub const NO_BUFFER: Self = Self(3);
+
+    /// no acquire timeline point was set
+    #[allow(dead_code)]
+    pub const NO_ACQUIRE_POINT: Self = Self(4);
+
+    /// no release timeline point was set
+    #[allow(dead_code)]
+    pub const NO_RELEASE_POINT: Self = Self(5);
+
+    /// acquire and release timeline points are in conflict
+    #[allow(dead_code)]
+    pub const CONFLICTING_POINTS: Self = Self(6);
+}
+
+impl Debug for WpLinuxDrmSyncobjSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NO_SURFACE => "NO_SURFACE",
+            Self::UNSUPPORTED_BUFFER => "UNSUPPORTED_BUFFER",
+            Self::NO_BUFFER => "NO_BUFFER",
+            Self::NO_ACQUIRE_POINT => "NO_ACQUIRE_POINT",
+            Self::NO_RELEASE_POINT => "NO_RELEASE_POINT",
+            Self::CONFLICTING_POINTS => "CONFLICTING_POINTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpLinuxDrmSyncobjSurfaceV1 {}
+}
@@ -0,0 +1,290 @@
+//! synchronization object timeline
+//!
+//! This object represents an explicit synchronization object timeline
+//! imported by the client to the compositor.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_linux_drm_syncobj_timeline_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_linux_drm_syncobj_timeline_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_linux_drm_syncobj_timeline_v1",
+    protocol: "linux_drm_syncobj_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned wp_linux_drm_syncobj_timeline_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpLinuxDrmSyncobjTimelineV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_linux_drm_syncobj_timeline_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpLinuxDrmSyncobjTimelineV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpLinuxDrmSyncobjTimelineV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpLinuxDrmSyncobjTimelineV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpLinuxDrmSyncobjTimelineV1 {
+    const INTERFACE: &'static str = "wp_linux_drm_syncobj_timeline_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpLinuxDrmSyncobjTimelineV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpLinuxDrmSyncobjTimelineV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpLinuxDrmSyncobjTimelineV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpLinuxDrmSyncobjTimelineV1Ref {
+    type Owned = WpLinuxDrmSyncobjTimelineV1;
+}
+
+impl Deref for WpLinuxDrmSyncobjTimelineV1 {
+    type Target = WpLinuxDrmSyncobjTimelineV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpLinuxDrmSyncobjTimelineV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_linux_drm_syncobj_timeline_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpLinuxDrmSyncobjTimelineV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_linux_drm_syncobj_timeline_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpLinuxDrmSyncobjTimelineV1Ref> for WpLinuxDrmSyncobjTimelineV1 {
+    fn eq(&self, other: &WpLinuxDrmSyncobjTimelineV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpLinuxDrmSyncobjTimelineV1> for WpLinuxDrmSyncobjTimelineV1Ref {
+    fn eq(&self, other: &WpLinuxDrmSyncobjTimelineV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpLinuxDrmSyncobjTimelineV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the timeline
+    ///
+    /// Destroy the synchronization object timeline. Other objects are not
+    /// affected by this request, in particular timeline points set by
+    /// set_acquire_point and set_release_point are not unset.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpLinuxDrmSyncobjTimelineV1] proxies.
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjTimelineV1EventHandler {}
+
+impl WpLinuxDrmSyncobjTimelineV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpLinuxDrmSyncobjTimelineV1] proxies with mutable data.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjTimelineV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjTimelineV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpLinuxDrmSyncobjTimelineV1] proxies.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjTimelineV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjTimelineV1EventHandlerMut {}
+
+/// A mutable event handler for [WpLinuxDrmSyncobjTimelineV1] proxies with mutable data.
+///
+/// This trait is the same as [WpLinuxDrmSyncobjTimelineV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpLinuxDrmSyncobjTimelineV1EventHandlerMutWithData<T> {}
+
+impl<H> WpLinuxDrmSyncobjTimelineV1EventHandler for MutEventHandler<H> where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandlerMut
+{
+}
+
+impl<T, H> WpLinuxDrmSyncobjTimelineV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpLinuxDrmSyncobjTimelineV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpLinuxDrmSyncobjTimelineV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_linux_drm_syncobj_timeline_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_linux_drm_syncobj_timeline_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpLinuxDrmSyncobjTimelineV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpLinuxDrmSyncobjTimelineV1 {}
+}
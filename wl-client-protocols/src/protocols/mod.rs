@@ -0,0 +1,913 @@
+#[cfg(feature = "alpha_modifier_v1")]
+pub mod alpha_modifier_v1;
+#[cfg(feature = "color_management_v1")]
+pub mod color_management_v1;
+#[cfg(feature = "color_representation_v1")]
+pub mod color_representation_v1;
+#[cfg(feature = "commit_timing_v1")]
+pub mod commit_timing_v1;
+#[cfg(feature = "content_type_v1")]
+pub mod content_type_v1;
+#[cfg(feature = "cursor_shape_v1")]
+pub mod cursor_shape_v1;
+#[cfg(feature = "drm_lease_v1")]
+pub mod drm_lease_v1;
+#[cfg(feature = "ext_background_effect_v1")]
+pub mod ext_background_effect_v1;
+#[cfg(feature = "ext_data_control_v1")]
+pub mod ext_data_control_v1;
+#[cfg(feature = "ext_foreign_toplevel_list_v1")]
+pub mod ext_foreign_toplevel_list_v1;
+#[cfg(feature = "ext_idle_notify_v1")]
+pub mod ext_idle_notify_v1;
+#[cfg(feature = "ext_image_capture_source_v1")]
+pub mod ext_image_capture_source_v1;
+#[cfg(feature = "ext_image_copy_capture_v1")]
+pub mod ext_image_copy_capture_v1;
+#[cfg(feature = "ext_session_lock_v1")]
+pub mod ext_session_lock_v1;
+#[cfg(feature = "ext_transient_seat_v1")]
+pub mod ext_transient_seat_v1;
+#[cfg(feature = "ext_workspace_v1")]
+pub mod ext_workspace_v1;
+#[cfg(feature = "fifo_v1")]
+pub mod fifo_v1;
+#[cfg(feature = "fractional_scale_v1")]
+pub mod fractional_scale_v1;
+#[cfg(feature = "fullscreen_shell_unstable_v1")]
+pub mod fullscreen_shell_unstable_v1;
+#[cfg(feature = "idle_inhibit_unstable_v1")]
+pub mod idle_inhibit_unstable_v1;
+#[cfg(feature = "input_method_unstable_v1")]
+pub mod input_method_unstable_v1;
+#[cfg(feature = "input_timestamps_unstable_v1")]
+pub mod input_timestamps_unstable_v1;
+#[cfg(feature = "keyboard_shortcuts_inhibit_unstable_v1")]
+pub mod keyboard_shortcuts_inhibit_unstable_v1;
+#[cfg(feature = "linux_dmabuf_v1")]
+pub mod linux_dmabuf_v1;
+#[cfg(feature = "linux_drm_syncobj_v1")]
+pub mod linux_drm_syncobj_v1;
+#[cfg(feature = "pointer_constraints_unstable_v1")]
+pub mod pointer_constraints_unstable_v1;
+#[cfg(feature = "pointer_gestures_unstable_v1")]
+pub mod pointer_gestures_unstable_v1;
+#[cfg(feature = "pointer_warp_v1")]
+pub mod pointer_warp_v1;
+#[cfg(feature = "presentation_time")]
+pub mod presentation_time;
+#[cfg(feature = "relative_pointer_unstable_v1")]
+pub mod relative_pointer_unstable_v1;
+#[cfg(feature = "security_context_v1")]
+pub mod security_context_v1;
+#[cfg(feature = "single_pixel_buffer_v1")]
+pub mod single_pixel_buffer_v1;
+#[cfg(feature = "tablet_unstable_v1")]
+pub mod tablet_unstable_v1;
+#[cfg(feature = "tablet_v2")]
+pub mod tablet_v2;
+#[cfg(feature = "tearing_control_v1")]
+pub mod tearing_control_v1;
+#[cfg(feature = "text_input_unstable_v1")]
+pub mod text_input_unstable_v1;
+#[cfg(feature = "text_input_unstable_v3")]
+pub mod text_input_unstable_v3;
+#[cfg(feature = "viewporter")]
+pub mod viewporter;
+#[cfg(feature = "wayland")]
+pub mod wayland;
+#[cfg(feature = "wp_primary_selection_unstable_v1")]
+pub mod wp_primary_selection_unstable_v1;
+#[cfg(feature = "xdg_activation_v1")]
+pub mod xdg_activation_v1;
+#[cfg(feature = "xdg_decoration_unstable_v1")]
+pub mod xdg_decoration_unstable_v1;
+#[cfg(feature = "xdg_dialog_v1")]
+pub mod xdg_dialog_v1;
+#[cfg(feature = "xdg_foreign_unstable_v1")]
+pub mod xdg_foreign_unstable_v1;
+#[cfg(feature = "xdg_foreign_unstable_v2")]
+pub mod xdg_foreign_unstable_v2;
+#[cfg(feature = "xdg_output_unstable_v1")]
+pub mod xdg_output_unstable_v1;
+#[cfg(feature = "xdg_shell")]
+pub mod xdg_shell;
+#[cfg(feature = "xdg_system_bell_v1")]
+pub mod xdg_system_bell_v1;
+#[cfg(feature = "xdg_toplevel_drag_v1")]
+pub mod xdg_toplevel_drag_v1;
+#[cfg(feature = "xdg_toplevel_icon_v1")]
+pub mod xdg_toplevel_icon_v1;
+#[cfg(feature = "xdg_toplevel_tag_v1")]
+pub mod xdg_toplevel_tag_v1;
+#[cfg(feature = "xwayland_keyboard_grab_unstable_v1")]
+pub mod xwayland_keyboard_grab_unstable_v1;
+#[cfg(feature = "xwayland_shell_v1")]
+pub mod xwayland_shell_v1;
+#[cfg(feature = "zwp_linux_explicit_synchronization_unstable_v1")]
+pub mod zwp_linux_explicit_synchronization_unstable_v1;
+
+/// Metadata about all protocols in this module.
+#[allow(dead_code)]
+pub static PROTOCOLS: &[&::wl_client::info::ProtocolInfo] = &[
+    #[cfg(feature = "linux_drm_syncobj_v1")]
+    &linux_drm_syncobj_v1::PROTOCOL,
+    #[cfg(feature = "zwp_linux_explicit_synchronization_unstable_v1")]
+    &zwp_linux_explicit_synchronization_unstable_v1::PROTOCOL,
+    #[cfg(feature = "idle_inhibit_unstable_v1")]
+    &idle_inhibit_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xdg_dialog_v1")]
+    &xdg_dialog_v1::PROTOCOL,
+    #[cfg(feature = "xdg_foreign_unstable_v2")]
+    &xdg_foreign_unstable_v2::PROTOCOL,
+    #[cfg(feature = "ext_transient_seat_v1")]
+    &ext_transient_seat_v1::PROTOCOL,
+    #[cfg(feature = "commit_timing_v1")]
+    &commit_timing_v1::PROTOCOL,
+    #[cfg(feature = "linux_dmabuf_v1")]
+    &linux_dmabuf_v1::PROTOCOL,
+    #[cfg(feature = "keyboard_shortcuts_inhibit_unstable_v1")]
+    &keyboard_shortcuts_inhibit_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xdg_toplevel_drag_v1")]
+    &xdg_toplevel_drag_v1::PROTOCOL,
+    #[cfg(feature = "cursor_shape_v1")]
+    &cursor_shape_v1::PROTOCOL,
+    #[cfg(feature = "xdg_foreign_unstable_v1")]
+    &xdg_foreign_unstable_v1::PROTOCOL,
+    #[cfg(feature = "alpha_modifier_v1")]
+    &alpha_modifier_v1::PROTOCOL,
+    #[cfg(feature = "drm_lease_v1")]
+    &drm_lease_v1::PROTOCOL,
+    #[cfg(feature = "presentation_time")]
+    &presentation_time::PROTOCOL,
+    #[cfg(feature = "ext_idle_notify_v1")]
+    &ext_idle_notify_v1::PROTOCOL,
+    #[cfg(feature = "xdg_toplevel_tag_v1")]
+    &xdg_toplevel_tag_v1::PROTOCOL,
+    #[cfg(feature = "fullscreen_shell_unstable_v1")]
+    &fullscreen_shell_unstable_v1::PROTOCOL,
+    #[cfg(feature = "viewporter")]
+    &viewporter::PROTOCOL,
+    #[cfg(feature = "ext_data_control_v1")]
+    &ext_data_control_v1::PROTOCOL,
+    #[cfg(feature = "input_timestamps_unstable_v1")]
+    &input_timestamps_unstable_v1::PROTOCOL,
+    #[cfg(feature = "text_input_unstable_v3")]
+    &text_input_unstable_v3::PROTOCOL,
+    #[cfg(feature = "content_type_v1")]
+    &content_type_v1::PROTOCOL,
+    #[cfg(feature = "fractional_scale_v1")]
+    &fractional_scale_v1::PROTOCOL,
+    #[cfg(feature = "relative_pointer_unstable_v1")]
+    &relative_pointer_unstable_v1::PROTOCOL,
+    #[cfg(feature = "fifo_v1")]
+    &fifo_v1::PROTOCOL,
+    #[cfg(feature = "pointer_warp_v1")]
+    &pointer_warp_v1::PROTOCOL,
+    #[cfg(feature = "ext_session_lock_v1")]
+    &ext_session_lock_v1::PROTOCOL,
+    #[cfg(feature = "tablet_unstable_v1")]
+    &tablet_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xwayland_keyboard_grab_unstable_v1")]
+    &xwayland_keyboard_grab_unstable_v1::PROTOCOL,
+    #[cfg(feature = "wayland")]
+    &wayland::PROTOCOL,
+    #[cfg(feature = "text_input_unstable_v1")]
+    &text_input_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xdg_output_unstable_v1")]
+    &xdg_output_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xdg_decoration_unstable_v1")]
+    &xdg_decoration_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xwayland_shell_v1")]
+    &xwayland_shell_v1::PROTOCOL,
+    #[cfg(feature = "pointer_gestures_unstable_v1")]
+    &pointer_gestures_unstable_v1::PROTOCOL,
+    #[cfg(feature = "ext_workspace_v1")]
+    &ext_workspace_v1::PROTOCOL,
+    #[cfg(feature = "single_pixel_buffer_v1")]
+    &single_pixel_buffer_v1::PROTOCOL,
+    #[cfg(feature = "ext_image_copy_capture_v1")]
+    &ext_image_copy_capture_v1::PROTOCOL,
+    #[cfg(feature = "color_representation_v1")]
+    &color_representation_v1::PROTOCOL,
+    #[cfg(feature = "security_context_v1")]
+    &security_context_v1::PROTOCOL,
+    #[cfg(feature = "pointer_constraints_unstable_v1")]
+    &pointer_constraints_unstable_v1::PROTOCOL,
+    #[cfg(feature = "ext_background_effect_v1")]
+    &ext_background_effect_v1::PROTOCOL,
+    #[cfg(feature = "color_management_v1")]
+    &color_management_v1::PROTOCOL,
+    #[cfg(feature = "tearing_control_v1")]
+    &tearing_control_v1::PROTOCOL,
+    #[cfg(feature = "input_method_unstable_v1")]
+    &input_method_unstable_v1::PROTOCOL,
+    #[cfg(feature = "wp_primary_selection_unstable_v1")]
+    &wp_primary_selection_unstable_v1::PROTOCOL,
+    #[cfg(feature = "xdg_shell")]
+    &xdg_shell::PROTOCOL,
+    #[cfg(feature = "tablet_v2")]
+    &tablet_v2::PROTOCOL,
+    #[cfg(feature = "ext_foreign_toplevel_list_v1")]
+    &ext_foreign_toplevel_list_v1::PROTOCOL,
+    #[cfg(feature = "ext_image_capture_source_v1")]
+    &ext_image_capture_source_v1::PROTOCOL,
+    #[cfg(feature = "xdg_system_bell_v1")]
+    &xdg_system_bell_v1::PROTOCOL,
+    #[cfg(feature = "xdg_toplevel_icon_v1")]
+    &xdg_toplevel_icon_v1::PROTOCOL,
+    #[cfg(feature = "xdg_activation_v1")]
+    &xdg_activation_v1::PROTOCOL,
+];
+
+/// Returns the metadata of the interface with the given name.
+///
+/// If multiple protocols define an interface with this name, the metadata of the
+/// preferred protocol is returned. If no protocol is preferred, `None` is returned
+/// and the interface can only be found via [`PROTOCOLS`].
+#[allow(dead_code, unreachable_code, clippy::match_single_binding)]
+pub fn interface_info(name: &str) -> Option<&'static ::wl_client::info::InterfaceInfo> {
+    let info = match name {
+        #[cfg(feature = "linux_drm_syncobj_v1")] "wp_linux_drm_syncobj_manager_v1" => &linux_drm_syncobj_v1::wp_linux_drm_syncobj_manager_v1::INFO,
+        #[cfg(feature = "linux_drm_syncobj_v1")] "wp_linux_drm_syncobj_timeline_v1" => &linux_drm_syncobj_v1::wp_linux_drm_syncobj_timeline_v1::INFO,
+        #[cfg(feature = "linux_drm_syncobj_v1")] "wp_linux_drm_syncobj_surface_v1" => &linux_drm_syncobj_v1::wp_linux_drm_syncobj_surface_v1::INFO,
+        #[cfg(feature = "zwp_linux_explicit_synchronization_unstable_v1")] "zwp_linux_explicit_synchronization_v1" => &zwp_linux_explicit_synchronization_unstable_v1::zwp_linux_explicit_synchronization_v1::INFO,
+        #[cfg(feature = "zwp_linux_explicit_synchronization_unstable_v1")] "zwp_linux_surface_synchronization_v1" => &zwp_linux_explicit_synchronization_unstable_v1::zwp_linux_surface_synchronization_v1::INFO,
+        #[cfg(feature = "zwp_linux_explicit_synchronization_unstable_v1")] "zwp_linux_buffer_release_v1" => &zwp_linux_explicit_synchronization_unstable_v1::zwp_linux_buffer_release_v1::INFO,
+        #[cfg(feature = "idle_inhibit_unstable_v1")] "zwp_idle_inhibit_manager_v1" => &idle_inhibit_unstable_v1::zwp_idle_inhibit_manager_v1::INFO,
+        #[cfg(feature = "idle_inhibit_unstable_v1")] "zwp_idle_inhibitor_v1" => &idle_inhibit_unstable_v1::zwp_idle_inhibitor_v1::INFO,
+        #[cfg(feature = "xdg_dialog_v1")] "xdg_wm_dialog_v1" => &xdg_dialog_v1::xdg_wm_dialog_v1::INFO,
+        #[cfg(feature = "xdg_dialog_v1")] "xdg_dialog_v1" => &xdg_dialog_v1::xdg_dialog_v1::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v2")] "zxdg_exporter_v2" => &xdg_foreign_unstable_v2::zxdg_exporter_v2::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v2")] "zxdg_importer_v2" => &xdg_foreign_unstable_v2::zxdg_importer_v2::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v2")] "zxdg_exported_v2" => &xdg_foreign_unstable_v2::zxdg_exported_v2::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v2")] "zxdg_imported_v2" => &xdg_foreign_unstable_v2::zxdg_imported_v2::INFO,
+        #[cfg(feature = "ext_transient_seat_v1")] "ext_transient_seat_manager_v1" => &ext_transient_seat_v1::ext_transient_seat_manager_v1::INFO,
+        #[cfg(feature = "ext_transient_seat_v1")] "ext_transient_seat_v1" => &ext_transient_seat_v1::ext_transient_seat_v1::INFO,
+        #[cfg(feature = "commit_timing_v1")] "wp_commit_timing_manager_v1" => &commit_timing_v1::wp_commit_timing_manager_v1::INFO,
+        #[cfg(feature = "commit_timing_v1")] "wp_commit_timer_v1" => &commit_timing_v1::wp_commit_timer_v1::INFO,
+        #[cfg(feature = "linux_dmabuf_v1")] "zwp_linux_dmabuf_v1" => &linux_dmabuf_v1::zwp_linux_dmabuf_v1::INFO,
+        #[cfg(feature = "linux_dmabuf_v1")] "zwp_linux_buffer_params_v1" => &linux_dmabuf_v1::zwp_linux_buffer_params_v1::INFO,
+        #[cfg(feature = "linux_dmabuf_v1")] "zwp_linux_dmabuf_feedback_v1" => &linux_dmabuf_v1::zwp_linux_dmabuf_feedback_v1::INFO,
+        #[cfg(feature = "keyboard_shortcuts_inhibit_unstable_v1")] "zwp_keyboard_shortcuts_inhibit_manager_v1" => &keyboard_shortcuts_inhibit_unstable_v1::zwp_keyboard_shortcuts_inhibit_manager_v1::INFO,
+        #[cfg(feature = "keyboard_shortcuts_inhibit_unstable_v1")] "zwp_keyboard_shortcuts_inhibitor_v1" => &keyboard_shortcuts_inhibit_unstable_v1::zwp_keyboard_shortcuts_inhibitor_v1::INFO,
+        #[cfg(feature = "xdg_toplevel_drag_v1")] "xdg_toplevel_drag_manager_v1" => &xdg_toplevel_drag_v1::xdg_toplevel_drag_manager_v1::INFO,
+        #[cfg(feature = "xdg_toplevel_drag_v1")] "xdg_toplevel_drag_v1" => &xdg_toplevel_drag_v1::xdg_toplevel_drag_v1::INFO,
+        #[cfg(feature = "cursor_shape_v1")] "wp_cursor_shape_manager_v1" => &cursor_shape_v1::wp_cursor_shape_manager_v1::INFO,
+        #[cfg(feature = "cursor_shape_v1")] "wp_cursor_shape_device_v1" => &cursor_shape_v1::wp_cursor_shape_device_v1::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v1")] "zxdg_exporter_v1" => &xdg_foreign_unstable_v1::zxdg_exporter_v1::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v1")] "zxdg_importer_v1" => &xdg_foreign_unstable_v1::zxdg_importer_v1::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v1")] "zxdg_exported_v1" => &xdg_foreign_unstable_v1::zxdg_exported_v1::INFO,
+        #[cfg(feature = "xdg_foreign_unstable_v1")] "zxdg_imported_v1" => &xdg_foreign_unstable_v1::zxdg_imported_v1::INFO,
+        #[cfg(feature = "alpha_modifier_v1")] "wp_alpha_modifier_v1" => &alpha_modifier_v1::wp_alpha_modifier_v1::INFO,
+        #[cfg(feature = "alpha_modifier_v1")] "wp_alpha_modifier_surface_v1" => &alpha_modifier_v1::wp_alpha_modifier_surface_v1::INFO,
+        #[cfg(feature = "drm_lease_v1")] "wp_drm_lease_device_v1" => &drm_lease_v1::wp_drm_lease_device_v1::INFO,
+        #[cfg(feature = "drm_lease_v1")] "wp_drm_lease_connector_v1" => &drm_lease_v1::wp_drm_lease_connector_v1::INFO,
+        #[cfg(feature = "drm_lease_v1")] "wp_drm_lease_request_v1" => &drm_lease_v1::wp_drm_lease_request_v1::INFO,
+        #[cfg(feature = "drm_lease_v1")] "wp_drm_lease_v1" => &drm_lease_v1::wp_drm_lease_v1::INFO,
+        #[cfg(feature = "presentation_time")] "wp_presentation" => &presentation_time::wp_presentation::INFO,
+        #[cfg(feature = "presentation_time")] "wp_presentation_feedback" => &presentation_time::wp_presentation_feedback::INFO,
+        #[cfg(feature = "ext_idle_notify_v1")] "ext_idle_notifier_v1" => &ext_idle_notify_v1::ext_idle_notifier_v1::INFO,
+        #[cfg(feature = "ext_idle_notify_v1")] "ext_idle_notification_v1" => &ext_idle_notify_v1::ext_idle_notification_v1::INFO,
+        #[cfg(feature = "xdg_toplevel_tag_v1")] "xdg_toplevel_tag_manager_v1" => &xdg_toplevel_tag_v1::xdg_toplevel_tag_manager_v1::INFO,
+        #[cfg(feature = "fullscreen_shell_unstable_v1")] "zwp_fullscreen_shell_v1" => &fullscreen_shell_unstable_v1::zwp_fullscreen_shell_v1::INFO,
+        #[cfg(feature = "fullscreen_shell_unstable_v1")] "zwp_fullscreen_shell_mode_feedback_v1" => &fullscreen_shell_unstable_v1::zwp_fullscreen_shell_mode_feedback_v1::INFO,
+        #[cfg(feature = "viewporter")] "wp_viewporter" => &viewporter::wp_viewporter::INFO,
+        #[cfg(feature = "viewporter")] "wp_viewport" => &viewporter::wp_viewport::INFO,
+        #[cfg(feature = "ext_data_control_v1")] "ext_data_control_manager_v1" => &ext_data_control_v1::ext_data_control_manager_v1::INFO,
+        #[cfg(feature = "ext_data_control_v1")] "ext_data_control_device_v1" => &ext_data_control_v1::ext_data_control_device_v1::INFO,
+        #[cfg(feature = "ext_data_control_v1")] "ext_data_control_source_v1" => &ext_data_control_v1::ext_data_control_source_v1::INFO,
+        #[cfg(feature = "ext_data_control_v1")] "ext_data_control_offer_v1" => &ext_data_control_v1::ext_data_control_offer_v1::INFO,
+        #[cfg(feature = "input_timestamps_unstable_v1")] "zwp_input_timestamps_manager_v1" => &input_timestamps_unstable_v1::zwp_input_timestamps_manager_v1::INFO,
+        #[cfg(feature = "input_timestamps_unstable_v1")] "zwp_input_timestamps_v1" => &input_timestamps_unstable_v1::zwp_input_timestamps_v1::INFO,
+        #[cfg(feature = "text_input_unstable_v3")] "zwp_text_input_v3" => &text_input_unstable_v3::zwp_text_input_v3::INFO,
+        #[cfg(feature = "text_input_unstable_v3")] "zwp_text_input_manager_v3" => &text_input_unstable_v3::zwp_text_input_manager_v3::INFO,
+        #[cfg(feature = "content_type_v1")] "wp_content_type_manager_v1" => &content_type_v1::wp_content_type_manager_v1::INFO,
+        #[cfg(feature = "content_type_v1")] "wp_content_type_v1" => &content_type_v1::wp_content_type_v1::INFO,
+        #[cfg(feature = "fractional_scale_v1")] "wp_fractional_scale_manager_v1" => &fractional_scale_v1::wp_fractional_scale_manager_v1::INFO,
+        #[cfg(feature = "fractional_scale_v1")] "wp_fractional_scale_v1" => &fractional_scale_v1::wp_fractional_scale_v1::INFO,
+        #[cfg(feature = "relative_pointer_unstable_v1")] "zwp_relative_pointer_manager_v1" => &relative_pointer_unstable_v1::zwp_relative_pointer_manager_v1::INFO,
+        #[cfg(feature = "relative_pointer_unstable_v1")] "zwp_relative_pointer_v1" => &relative_pointer_unstable_v1::zwp_relative_pointer_v1::INFO,
+        #[cfg(feature = "fifo_v1")] "wp_fifo_manager_v1" => &fifo_v1::wp_fifo_manager_v1::INFO,
+        #[cfg(feature = "fifo_v1")] "wp_fifo_v1" => &fifo_v1::wp_fifo_v1::INFO,
+        #[cfg(feature = "pointer_warp_v1")] "wp_pointer_warp_v1" => &pointer_warp_v1::wp_pointer_warp_v1::INFO,
+        #[cfg(feature = "ext_session_lock_v1")] "ext_session_lock_manager_v1" => &ext_session_lock_v1::ext_session_lock_manager_v1::INFO,
+        #[cfg(feature = "ext_session_lock_v1")] "ext_session_lock_v1" => &ext_session_lock_v1::ext_session_lock_v1::INFO,
+        #[cfg(feature = "ext_session_lock_v1")] "ext_session_lock_surface_v1" => &ext_session_lock_v1::ext_session_lock_surface_v1::INFO,
+        #[cfg(feature = "tablet_unstable_v1")] "zwp_tablet_manager_v1" => &tablet_unstable_v1::zwp_tablet_manager_v1::INFO,
+        #[cfg(feature = "tablet_unstable_v1")] "zwp_tablet_seat_v1" => &tablet_unstable_v1::zwp_tablet_seat_v1::INFO,
+        #[cfg(feature = "tablet_unstable_v1")] "zwp_tablet_tool_v1" => &tablet_unstable_v1::zwp_tablet_tool_v1::INFO,
+        #[cfg(feature = "tablet_unstable_v1")] "zwp_tablet_v1" => &tablet_unstable_v1::zwp_tablet_v1::INFO,
+        #[cfg(feature = "xwayland_keyboard_grab_unstable_v1")] "zwp_xwayland_keyboard_grab_manager_v1" => &xwayland_keyboard_grab_unstable_v1::zwp_xwayland_keyboard_grab_manager_v1::INFO,
+        #[cfg(feature = "xwayland_keyboard_grab_unstable_v1")] "zwp_xwayland_keyboard_grab_v1" => &xwayland_keyboard_grab_unstable_v1::zwp_xwayland_keyboard_grab_v1::INFO,
+        #[cfg(feature = "wayland")] "wl_display" => &wayland::wl_display::INFO,
+        #[cfg(feature = "wayland")] "wl_registry" => &wayland::wl_registry::INFO,
+        #[cfg(feature = "wayland")] "wl_callback" => &wayland::wl_callback::INFO,
+        #[cfg(feature = "wayland")] "wl_compositor" => &wayland::wl_compositor::INFO,
+        #[cfg(feature = "wayland")] "wl_shm_pool" => &wayland::wl_shm_pool::INFO,
+        #[cfg(feature = "wayland")] "wl_shm" => &wayland::wl_shm::INFO,
+        #[cfg(feature = "wayland")] "wl_buffer" => &wayland::wl_buffer::INFO,
+        #[cfg(feature = "wayland")] "wl_data_offer" => &wayland::wl_data_offer::INFO,
+        #[cfg(feature = "wayland")] "wl_data_source" => &wayland::wl_data_source::INFO,
+        #[cfg(feature = "wayland")] "wl_data_device" => &wayland::wl_data_device::INFO,
+        #[cfg(feature = "wayland")] "wl_data_device_manager" => &wayland::wl_data_device_manager::INFO,
+        #[cfg(feature = "wayland")] "wl_shell" => &wayland::wl_shell::INFO,
+        #[cfg(feature = "wayland")] "wl_shell_surface" => &wayland::wl_shell_surface::INFO,
+        #[cfg(feature = "wayland")] "wl_surface" => &wayland::wl_surface::INFO,
+        #[cfg(feature = "wayland")] "wl_seat" => &wayland::wl_seat::INFO,
+        #[cfg(feature = "wayland")] "wl_pointer" => &wayland::wl_pointer::INFO,
+        #[cfg(feature = "wayland")] "wl_keyboard" => &wayland::wl_keyboard::INFO,
+        #[cfg(feature = "wayland")] "wl_touch" => &wayland::wl_touch::INFO,
+        #[cfg(feature = "wayland")] "wl_output" => &wayland::wl_output::INFO,
+        #[cfg(feature = "wayland")] "wl_region" => &wayland::wl_region::INFO,
+        #[cfg(feature = "wayland")] "wl_subcompositor" => &wayland::wl_subcompositor::INFO,
+        #[cfg(feature = "wayland")] "wl_subsurface" => &wayland::wl_subsurface::INFO,
+        #[cfg(feature = "wayland")] "wl_fixes" => &wayland::wl_fixes::INFO,
+        #[cfg(feature = "text_input_unstable_v1")] "zwp_text_input_v1" => &text_input_unstable_v1::zwp_text_input_v1::INFO,
+        #[cfg(feature = "text_input_unstable_v1")] "zwp_text_input_manager_v1" => &text_input_unstable_v1::zwp_text_input_manager_v1::INFO,
+        #[cfg(feature = "xdg_output_unstable_v1")] "zxdg_output_manager_v1" => &xdg_output_unstable_v1::zxdg_output_manager_v1::INFO,
+        #[cfg(feature = "xdg_output_unstable_v1")] "zxdg_output_v1" => &xdg_output_unstable_v1::zxdg_output_v1::INFO,
+        #[cfg(feature = "xdg_decoration_unstable_v1")] "zxdg_decoration_manager_v1" => &xdg_decoration_unstable_v1::zxdg_decoration_manager_v1::INFO,
+        #[cfg(feature = "xdg_decoration_unstable_v1")] "zxdg_toplevel_decoration_v1" => &xdg_decoration_unstable_v1::zxdg_toplevel_decoration_v1::INFO,
+        #[cfg(feature = "xwayland_shell_v1")] "xwayland_shell_v1" => &xwayland_shell_v1::xwayland_shell_v1::INFO,
+        #[cfg(feature = "xwayland_shell_v1")] "xwayland_surface_v1" => &xwayland_shell_v1::xwayland_surface_v1::INFO,
+        #[cfg(feature = "pointer_gestures_unstable_v1")] "zwp_pointer_gestures_v1" => &pointer_gestures_unstable_v1::zwp_pointer_gestures_v1::INFO,
+        #[cfg(feature = "pointer_gestures_unstable_v1")] "zwp_pointer_gesture_swipe_v1" => &pointer_gestures_unstable_v1::zwp_pointer_gesture_swipe_v1::INFO,
+        #[cfg(feature = "pointer_gestures_unstable_v1")] "zwp_pointer_gesture_pinch_v1" => &pointer_gestures_unstable_v1::zwp_pointer_gesture_pinch_v1::INFO,
+        #[cfg(feature = "pointer_gestures_unstable_v1")] "zwp_pointer_gesture_hold_v1" => &pointer_gestures_unstable_v1::zwp_pointer_gesture_hold_v1::INFO,
+        #[cfg(feature = "ext_workspace_v1")] "ext_workspace_manager_v1" => &ext_workspace_v1::ext_workspace_manager_v1::INFO,
+        #[cfg(feature = "ext_workspace_v1")] "ext_workspace_group_handle_v1" => &ext_workspace_v1::ext_workspace_group_handle_v1::INFO,
+        #[cfg(feature = "ext_workspace_v1")] "ext_workspace_handle_v1" => &ext_workspace_v1::ext_workspace_handle_v1::INFO,
+        #[cfg(feature = "single_pixel_buffer_v1")] "wp_single_pixel_buffer_manager_v1" => &single_pixel_buffer_v1::wp_single_pixel_buffer_manager_v1::INFO,
+        #[cfg(feature = "ext_image_copy_capture_v1")] "ext_image_copy_capture_manager_v1" => &ext_image_copy_capture_v1::ext_image_copy_capture_manager_v1::INFO,
+        #[cfg(feature = "ext_image_copy_capture_v1")] "ext_image_copy_capture_session_v1" => &ext_image_copy_capture_v1::ext_image_copy_capture_session_v1::INFO,
+        #[cfg(feature = "ext_image_copy_capture_v1")] "ext_image_copy_capture_frame_v1" => &ext_image_copy_capture_v1::ext_image_copy_capture_frame_v1::INFO,
+        #[cfg(feature = "ext_image_copy_capture_v1")] "ext_image_copy_capture_cursor_session_v1" => &ext_image_copy_capture_v1::ext_image_copy_capture_cursor_session_v1::INFO,
+        #[cfg(feature = "color_representation_v1")] "wp_color_representation_manager_v1" => &color_representation_v1::wp_color_representation_manager_v1::INFO,
+        #[cfg(feature = "color_representation_v1")] "wp_color_representation_surface_v1" => &color_representation_v1::wp_color_representation_surface_v1::INFO,
+        #[cfg(feature = "security_context_v1")] "wp_security_context_manager_v1" => &security_context_v1::wp_security_context_manager_v1::INFO,
+        #[cfg(feature = "security_context_v1")] "wp_security_context_v1" => &security_context_v1::wp_security_context_v1::INFO,
+        #[cfg(feature = "pointer_constraints_unstable_v1")] "zwp_pointer_constraints_v1" => &pointer_constraints_unstable_v1::zwp_pointer_constraints_v1::INFO,
+        #[cfg(feature = "pointer_constraints_unstable_v1")] "zwp_locked_pointer_v1" => &pointer_constraints_unstable_v1::zwp_locked_pointer_v1::INFO,
+        #[cfg(feature = "pointer_constraints_unstable_v1")] "zwp_confined_pointer_v1" => &pointer_constraints_unstable_v1::zwp_confined_pointer_v1::INFO,
+        #[cfg(feature = "ext_background_effect_v1")] "ext_background_effect_manager_v1" => &ext_background_effect_v1::ext_background_effect_manager_v1::INFO,
+        #[cfg(feature = "ext_background_effect_v1")] "ext_background_effect_surface_v1" => &ext_background_effect_v1::ext_background_effect_surface_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_color_manager_v1" => &color_management_v1::wp_color_manager_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_color_management_output_v1" => &color_management_v1::wp_color_management_output_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_color_management_surface_v1" => &color_management_v1::wp_color_management_surface_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_color_management_surface_feedback_v1" => &color_management_v1::wp_color_management_surface_feedback_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_image_description_creator_icc_v1" => &color_management_v1::wp_image_description_creator_icc_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_image_description_creator_params_v1" => &color_management_v1::wp_image_description_creator_params_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_image_description_v1" => &color_management_v1::wp_image_description_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_image_description_info_v1" => &color_management_v1::wp_image_description_info_v1::INFO,
+        #[cfg(feature = "color_management_v1")] "wp_image_description_reference_v1" => &color_management_v1::wp_image_description_reference_v1::INFO,
+        #[cfg(feature = "tearing_control_v1")] "wp_tearing_control_manager_v1" => &tearing_control_v1::wp_tearing_control_manager_v1::INFO,
+        #[cfg(feature = "tearing_control_v1")] "wp_tearing_control_v1" => &tearing_control_v1::wp_tearing_control_v1::INFO,
+        #[cfg(feature = "input_method_unstable_v1")] "zwp_input_method_context_v1" => &input_method_unstable_v1::zwp_input_method_context_v1::INFO,
+        #[cfg(feature = "input_method_unstable_v1")] "zwp_input_method_v1" => &input_method_unstable_v1::zwp_input_method_v1::INFO,
+        #[cfg(feature = "input_method_unstable_v1")] "zwp_input_panel_v1" => &input_method_unstable_v1::zwp_input_panel_v1::INFO,
+        #[cfg(feature = "input_method_unstable_v1")] "zwp_input_panel_surface_v1" => &input_method_unstable_v1::zwp_input_panel_surface_v1::INFO,
+        #[cfg(feature = "wp_primary_selection_unstable_v1")] "zwp_primary_selection_device_manager_v1" => &wp_primary_selection_unstable_v1::zwp_primary_selection_device_manager_v1::INFO,
+        #[cfg(feature = "wp_primary_selection_unstable_v1")] "zwp_primary_selection_device_v1" => &wp_primary_selection_unstable_v1::zwp_primary_selection_device_v1::INFO,
+        #[cfg(feature = "wp_primary_selection_unstable_v1")] "zwp_primary_selection_offer_v1" => &wp_primary_selection_unstable_v1::zwp_primary_selection_offer_v1::INFO,
+        #[cfg(feature = "wp_primary_selection_unstable_v1")] "zwp_primary_selection_source_v1" => &wp_primary_selection_unstable_v1::zwp_primary_selection_source_v1::INFO,
+        #[cfg(feature = "xdg_shell")] "xdg_wm_base" => &xdg_shell::xdg_wm_base::INFO,
+        #[cfg(feature = "xdg_shell")] "xdg_positioner" => &xdg_shell::xdg_positioner::INFO,
+        #[cfg(feature = "xdg_shell")] "xdg_surface" => &xdg_shell::xdg_surface::INFO,
+        #[cfg(feature = "xdg_shell")] "xdg_toplevel" => &xdg_shell::xdg_toplevel::INFO,
+        #[cfg(feature = "xdg_shell")] "xdg_popup" => &xdg_shell::xdg_popup::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_manager_v2" => &tablet_v2::zwp_tablet_manager_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_seat_v2" => &tablet_v2::zwp_tablet_seat_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_tool_v2" => &tablet_v2::zwp_tablet_tool_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_v2" => &tablet_v2::zwp_tablet_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_pad_ring_v2" => &tablet_v2::zwp_tablet_pad_ring_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_pad_strip_v2" => &tablet_v2::zwp_tablet_pad_strip_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_pad_group_v2" => &tablet_v2::zwp_tablet_pad_group_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_pad_v2" => &tablet_v2::zwp_tablet_pad_v2::INFO,
+        #[cfg(feature = "tablet_v2")] "zwp_tablet_pad_dial_v2" => &tablet_v2::zwp_tablet_pad_dial_v2::INFO,
+        #[cfg(feature = "ext_foreign_toplevel_list_v1")] "ext_foreign_toplevel_list_v1" => &ext_foreign_toplevel_list_v1::ext_foreign_toplevel_list_v1::INFO,
+        #[cfg(feature = "ext_foreign_toplevel_list_v1")] "ext_foreign_toplevel_handle_v1" => &ext_foreign_toplevel_list_v1::ext_foreign_toplevel_handle_v1::INFO,
+        #[cfg(feature = "ext_image_capture_source_v1")] "ext_image_capture_source_v1" => &ext_image_capture_source_v1::ext_image_capture_source_v1::INFO,
+        #[cfg(feature = "ext_image_capture_source_v1")] "ext_output_image_capture_source_manager_v1" => &ext_image_capture_source_v1::ext_output_image_capture_source_manager_v1::INFO,
+        #[cfg(feature = "ext_image_capture_source_v1")] "ext_foreign_toplevel_image_capture_source_manager_v1" => &ext_image_capture_source_v1::ext_foreign_toplevel_image_capture_source_manager_v1::INFO,
+        #[cfg(feature = "xdg_system_bell_v1")] "xdg_system_bell_v1" => &xdg_system_bell_v1::xdg_system_bell_v1::INFO,
+        #[cfg(feature = "xdg_toplevel_icon_v1")] "xdg_toplevel_icon_manager_v1" => &xdg_toplevel_icon_v1::xdg_toplevel_icon_manager_v1::INFO,
+        #[cfg(feature = "xdg_toplevel_icon_v1")] "xdg_toplevel_icon_v1" => &xdg_toplevel_icon_v1::xdg_toplevel_icon_v1::INFO,
+        #[cfg(feature = "xdg_activation_v1")] "xdg_activation_v1" => &xdg_activation_v1::xdg_activation_v1::INFO,
+        #[cfg(feature = "xdg_activation_v1")] "xdg_activation_token_v1" => &xdg_activation_v1::xdg_activation_token_v1::INFO,
+        _ => return None,
+    };
+    Some(info)
+}
+
+#[allow(unused_imports)]
+mod all_types {
+    #[cfg(feature = "alpha_modifier_v1")]
+    pub(super) use super::alpha_modifier_v1::{
+        wp_alpha_modifier_surface_v1::WpAlphaModifierSurfaceV1,
+        wp_alpha_modifier_surface_v1::WpAlphaModifierSurfaceV1Error,
+        wp_alpha_modifier_surface_v1::WpAlphaModifierSurfaceV1Ref,
+        wp_alpha_modifier_v1::WpAlphaModifierV1, wp_alpha_modifier_v1::WpAlphaModifierV1Error,
+        wp_alpha_modifier_v1::WpAlphaModifierV1Ref,
+    };
+    #[cfg(feature = "color_management_v1")]
+    pub(super) use super::color_management_v1::{
+        wp_color_management_output_v1::WpColorManagementOutputV1,
+        wp_color_management_output_v1::WpColorManagementOutputV1Ref,
+        wp_color_management_surface_feedback_v1::WpColorManagementSurfaceFeedbackV1,
+        wp_color_management_surface_feedback_v1::WpColorManagementSurfaceFeedbackV1Error,
+        wp_color_management_surface_feedback_v1::WpColorManagementSurfaceFeedbackV1Ref,
+        wp_color_management_surface_v1::WpColorManagementSurfaceV1,
+        wp_color_management_surface_v1::WpColorManagementSurfaceV1Error,
+        wp_color_management_surface_v1::WpColorManagementSurfaceV1Ref,
+        wp_color_manager_v1::WpColorManagerV1, wp_color_manager_v1::WpColorManagerV1Error,
+        wp_color_manager_v1::WpColorManagerV1Feature,
+        wp_color_manager_v1::WpColorManagerV1Primaries, wp_color_manager_v1::WpColorManagerV1Ref,
+        wp_color_manager_v1::WpColorManagerV1RenderIntent,
+        wp_color_manager_v1::WpColorManagerV1TransferFunction,
+        wp_image_description_creator_icc_v1::WpImageDescriptionCreatorIccV1,
+        wp_image_description_creator_icc_v1::WpImageDescriptionCreatorIccV1Error,
+        wp_image_description_creator_icc_v1::WpImageDescriptionCreatorIccV1Ref,
+        wp_image_description_creator_params_v1::WpImageDescriptionCreatorParamsV1,
+        wp_image_description_creator_params_v1::WpImageDescriptionCreatorParamsV1Error,
+        wp_image_description_creator_params_v1::WpImageDescriptionCreatorParamsV1Ref,
+        wp_image_description_info_v1::WpImageDescriptionInfoV1,
+        wp_image_description_info_v1::WpImageDescriptionInfoV1Ref,
+        wp_image_description_reference_v1::WpImageDescriptionReferenceV1,
+        wp_image_description_reference_v1::WpImageDescriptionReferenceV1Ref,
+        wp_image_description_v1::WpImageDescriptionV1,
+        wp_image_description_v1::WpImageDescriptionV1Cause,
+        wp_image_description_v1::WpImageDescriptionV1Error,
+        wp_image_description_v1::WpImageDescriptionV1Ref,
+    };
+    #[cfg(feature = "color_representation_v1")]
+    pub(super) use super::color_representation_v1::{
+        wp_color_representation_manager_v1::WpColorRepresentationManagerV1,
+        wp_color_representation_manager_v1::WpColorRepresentationManagerV1Error,
+        wp_color_representation_manager_v1::WpColorRepresentationManagerV1Ref,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1AlphaMode,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1ChromaLocation,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1Coefficients,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1Error,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1Range,
+        wp_color_representation_surface_v1::WpColorRepresentationSurfaceV1Ref,
+    };
+    #[cfg(feature = "commit_timing_v1")]
+    pub(super) use super::commit_timing_v1::{
+        wp_commit_timer_v1::WpCommitTimerV1, wp_commit_timer_v1::WpCommitTimerV1Error,
+        wp_commit_timer_v1::WpCommitTimerV1Ref,
+        wp_commit_timing_manager_v1::WpCommitTimingManagerV1,
+        wp_commit_timing_manager_v1::WpCommitTimingManagerV1Error,
+        wp_commit_timing_manager_v1::WpCommitTimingManagerV1Ref,
+    };
+    #[cfg(feature = "content_type_v1")]
+    pub(super) use super::content_type_v1::{
+        wp_content_type_manager_v1::WpContentTypeManagerV1,
+        wp_content_type_manager_v1::WpContentTypeManagerV1Error,
+        wp_content_type_manager_v1::WpContentTypeManagerV1Ref, wp_content_type_v1::WpContentTypeV1,
+        wp_content_type_v1::WpContentTypeV1Ref, wp_content_type_v1::WpContentTypeV1Type,
+    };
+    #[cfg(feature = "cursor_shape_v1")]
+    pub(super) use super::cursor_shape_v1::{
+        wp_cursor_shape_device_v1::WpCursorShapeDeviceV1,
+        wp_cursor_shape_device_v1::WpCursorShapeDeviceV1Error,
+        wp_cursor_shape_device_v1::WpCursorShapeDeviceV1Ref,
+        wp_cursor_shape_device_v1::WpCursorShapeDeviceV1Shape,
+        wp_cursor_shape_manager_v1::WpCursorShapeManagerV1,
+        wp_cursor_shape_manager_v1::WpCursorShapeManagerV1Ref,
+    };
+    #[cfg(feature = "drm_lease_v1")]
+    pub(super) use super::drm_lease_v1::{
+        wp_drm_lease_connector_v1::WpDrmLeaseConnectorV1,
+        wp_drm_lease_connector_v1::WpDrmLeaseConnectorV1Ref,
+        wp_drm_lease_device_v1::WpDrmLeaseDeviceV1, wp_drm_lease_device_v1::WpDrmLeaseDeviceV1Ref,
+        wp_drm_lease_request_v1::WpDrmLeaseRequestV1,
+        wp_drm_lease_request_v1::WpDrmLeaseRequestV1Error,
+        wp_drm_lease_request_v1::WpDrmLeaseRequestV1Ref, wp_drm_lease_v1::WpDrmLeaseV1,
+        wp_drm_lease_v1::WpDrmLeaseV1Ref,
+    };
+    #[cfg(feature = "ext_background_effect_v1")]
+    pub(super) use super::ext_background_effect_v1::{
+        ext_background_effect_manager_v1::ExtBackgroundEffectManagerV1,
+        ext_background_effect_manager_v1::ExtBackgroundEffectManagerV1Capability,
+        ext_background_effect_manager_v1::ExtBackgroundEffectManagerV1Error,
+        ext_background_effect_manager_v1::ExtBackgroundEffectManagerV1Ref,
+        ext_background_effect_surface_v1::ExtBackgroundEffectSurfaceV1,
+        ext_background_effect_surface_v1::ExtBackgroundEffectSurfaceV1Error,
+        ext_background_effect_surface_v1::ExtBackgroundEffectSurfaceV1Ref,
+    };
+    #[cfg(feature = "ext_data_control_v1")]
+    pub(super) use super::ext_data_control_v1::{
+        ext_data_control_device_v1::ExtDataControlDeviceV1,
+        ext_data_control_device_v1::ExtDataControlDeviceV1Error,
+        ext_data_control_device_v1::ExtDataControlDeviceV1Ref,
+        ext_data_control_manager_v1::ExtDataControlManagerV1,
+        ext_data_control_manager_v1::ExtDataControlManagerV1Ref,
+        ext_data_control_offer_v1::ExtDataControlOfferV1,
+        ext_data_control_offer_v1::ExtDataControlOfferV1Ref,
+        ext_data_control_source_v1::ExtDataControlSourceV1,
+        ext_data_control_source_v1::ExtDataControlSourceV1Error,
+        ext_data_control_source_v1::ExtDataControlSourceV1Ref,
+    };
+    #[cfg(feature = "ext_foreign_toplevel_list_v1")]
+    pub(super) use super::ext_foreign_toplevel_list_v1::{
+        ext_foreign_toplevel_handle_v1::ExtForeignToplevelHandleV1,
+        ext_foreign_toplevel_handle_v1::ExtForeignToplevelHandleV1Ref,
+        ext_foreign_toplevel_list_v1::ExtForeignToplevelListV1,
+        ext_foreign_toplevel_list_v1::ExtForeignToplevelListV1Ref,
+    };
+    #[cfg(feature = "ext_idle_notify_v1")]
+    pub(super) use super::ext_idle_notify_v1::{
+        ext_idle_notification_v1::ExtIdleNotificationV1,
+        ext_idle_notification_v1::ExtIdleNotificationV1Ref,
+        ext_idle_notifier_v1::ExtIdleNotifierV1, ext_idle_notifier_v1::ExtIdleNotifierV1Ref,
+    };
+    #[cfg(feature = "ext_image_capture_source_v1")]
+    pub(super) use super::ext_image_capture_source_v1::{
+        ext_foreign_toplevel_image_capture_source_manager_v1::ExtForeignToplevelImageCaptureSourceManagerV1,
+        ext_foreign_toplevel_image_capture_source_manager_v1::ExtForeignToplevelImageCaptureSourceManagerV1Ref,
+        ext_image_capture_source_v1::ExtImageCaptureSourceV1,
+        ext_image_capture_source_v1::ExtImageCaptureSourceV1Ref,
+        ext_output_image_capture_source_manager_v1::ExtOutputImageCaptureSourceManagerV1,
+        ext_output_image_capture_source_manager_v1::ExtOutputImageCaptureSourceManagerV1Ref,
+    };
+    #[cfg(feature = "ext_image_copy_capture_v1")]
+    pub(super) use super::ext_image_copy_capture_v1::{
+        ext_image_copy_capture_cursor_session_v1::ExtImageCopyCaptureCursorSessionV1,
+        ext_image_copy_capture_cursor_session_v1::ExtImageCopyCaptureCursorSessionV1Error,
+        ext_image_copy_capture_cursor_session_v1::ExtImageCopyCaptureCursorSessionV1Ref,
+        ext_image_copy_capture_frame_v1::ExtImageCopyCaptureFrameV1,
+        ext_image_copy_capture_frame_v1::ExtImageCopyCaptureFrameV1Error,
+        ext_image_copy_capture_frame_v1::ExtImageCopyCaptureFrameV1FailureReason,
+        ext_image_copy_capture_frame_v1::ExtImageCopyCaptureFrameV1Ref,
+        ext_image_copy_capture_manager_v1::ExtImageCopyCaptureManagerV1,
+        ext_image_copy_capture_manager_v1::ExtImageCopyCaptureManagerV1Error,
+        ext_image_copy_capture_manager_v1::ExtImageCopyCaptureManagerV1Options,
+        ext_image_copy_capture_manager_v1::ExtImageCopyCaptureManagerV1Ref,
+        ext_image_copy_capture_session_v1::ExtImageCopyCaptureSessionV1,
+        ext_image_copy_capture_session_v1::ExtImageCopyCaptureSessionV1Error,
+        ext_image_copy_capture_session_v1::ExtImageCopyCaptureSessionV1Ref,
+    };
+    #[cfg(feature = "ext_session_lock_v1")]
+    pub(super) use super::ext_session_lock_v1::{
+        ext_session_lock_manager_v1::ExtSessionLockManagerV1,
+        ext_session_lock_manager_v1::ExtSessionLockManagerV1Ref,
+        ext_session_lock_surface_v1::ExtSessionLockSurfaceV1,
+        ext_session_lock_surface_v1::ExtSessionLockSurfaceV1Error,
+        ext_session_lock_surface_v1::ExtSessionLockSurfaceV1Ref,
+        ext_session_lock_v1::ExtSessionLockV1, ext_session_lock_v1::ExtSessionLockV1Error,
+        ext_session_lock_v1::ExtSessionLockV1Ref,
+    };
+    #[cfg(feature = "ext_transient_seat_v1")]
+    pub(super) use super::ext_transient_seat_v1::{
+        ext_transient_seat_manager_v1::ExtTransientSeatManagerV1,
+        ext_transient_seat_manager_v1::ExtTransientSeatManagerV1Ref,
+        ext_transient_seat_v1::ExtTransientSeatV1, ext_transient_seat_v1::ExtTransientSeatV1Ref,
+    };
+    #[cfg(feature = "ext_workspace_v1")]
+    pub(super) use super::ext_workspace_v1::{
+        ext_workspace_group_handle_v1::ExtWorkspaceGroupHandleV1,
+        ext_workspace_group_handle_v1::ExtWorkspaceGroupHandleV1GroupCapabilities,
+        ext_workspace_group_handle_v1::ExtWorkspaceGroupHandleV1Ref,
+        ext_workspace_handle_v1::ExtWorkspaceHandleV1,
+        ext_workspace_handle_v1::ExtWorkspaceHandleV1Ref,
+        ext_workspace_handle_v1::ExtWorkspaceHandleV1State,
+        ext_workspace_handle_v1::ExtWorkspaceHandleV1WorkspaceCapabilities,
+        ext_workspace_manager_v1::ExtWorkspaceManagerV1,
+        ext_workspace_manager_v1::ExtWorkspaceManagerV1Ref,
+    };
+    #[cfg(feature = "fifo_v1")]
+    pub(super) use super::fifo_v1::{
+        wp_fifo_manager_v1::WpFifoManagerV1, wp_fifo_manager_v1::WpFifoManagerV1Error,
+        wp_fifo_manager_v1::WpFifoManagerV1Ref, wp_fifo_v1::WpFifoV1, wp_fifo_v1::WpFifoV1Error,
+        wp_fifo_v1::WpFifoV1Ref,
+    };
+    #[cfg(feature = "fractional_scale_v1")]
+    pub(super) use super::fractional_scale_v1::{
+        wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1,
+        wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1Error,
+        wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1Ref,
+        wp_fractional_scale_v1::WpFractionalScaleV1,
+        wp_fractional_scale_v1::WpFractionalScaleV1Ref,
+    };
+    #[cfg(feature = "fullscreen_shell_unstable_v1")]
+    pub(super) use super::fullscreen_shell_unstable_v1::{
+        zwp_fullscreen_shell_mode_feedback_v1::ZwpFullscreenShellModeFeedbackV1,
+        zwp_fullscreen_shell_mode_feedback_v1::ZwpFullscreenShellModeFeedbackV1Ref,
+        zwp_fullscreen_shell_v1::ZwpFullscreenShellV1,
+        zwp_fullscreen_shell_v1::ZwpFullscreenShellV1Capability,
+        zwp_fullscreen_shell_v1::ZwpFullscreenShellV1Error,
+        zwp_fullscreen_shell_v1::ZwpFullscreenShellV1PresentMethod,
+        zwp_fullscreen_shell_v1::ZwpFullscreenShellV1Ref,
+    };
+    #[cfg(feature = "idle_inhibit_unstable_v1")]
+    pub(super) use super::idle_inhibit_unstable_v1::{
+        zwp_idle_inhibit_manager_v1::ZwpIdleInhibitManagerV1,
+        zwp_idle_inhibit_manager_v1::ZwpIdleInhibitManagerV1Ref,
+        zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1, zwp_idle_inhibitor_v1::ZwpIdleInhibitorV1Ref,
+    };
+    #[cfg(feature = "input_method_unstable_v1")]
+    pub(super) use super::input_method_unstable_v1::{
+        zwp_input_method_context_v1::ZwpInputMethodContextV1,
+        zwp_input_method_context_v1::ZwpInputMethodContextV1Ref,
+        zwp_input_method_v1::ZwpInputMethodV1, zwp_input_method_v1::ZwpInputMethodV1Ref,
+        zwp_input_panel_surface_v1::ZwpInputPanelSurfaceV1,
+        zwp_input_panel_surface_v1::ZwpInputPanelSurfaceV1Position,
+        zwp_input_panel_surface_v1::ZwpInputPanelSurfaceV1Ref, zwp_input_panel_v1::ZwpInputPanelV1,
+        zwp_input_panel_v1::ZwpInputPanelV1Ref,
+    };
+    #[cfg(feature = "input_timestamps_unstable_v1")]
+    pub(super) use super::input_timestamps_unstable_v1::{
+        zwp_input_timestamps_manager_v1::ZwpInputTimestampsManagerV1,
+        zwp_input_timestamps_manager_v1::ZwpInputTimestampsManagerV1Ref,
+        zwp_input_timestamps_v1::ZwpInputTimestampsV1,
+        zwp_input_timestamps_v1::ZwpInputTimestampsV1Ref,
+    };
+    #[cfg(feature = "keyboard_shortcuts_inhibit_unstable_v1")]
+    pub(super) use super::keyboard_shortcuts_inhibit_unstable_v1::{
+        zwp_keyboard_shortcuts_inhibit_manager_v1::ZwpKeyboardShortcutsInhibitManagerV1,
+        zwp_keyboard_shortcuts_inhibit_manager_v1::ZwpKeyboardShortcutsInhibitManagerV1Error,
+        zwp_keyboard_shortcuts_inhibit_manager_v1::ZwpKeyboardShortcutsInhibitManagerV1Ref,
+        zwp_keyboard_shortcuts_inhibitor_v1::ZwpKeyboardShortcutsInhibitorV1,
+        zwp_keyboard_shortcuts_inhibitor_v1::ZwpKeyboardShortcutsInhibitorV1Ref,
+    };
+    #[cfg(feature = "linux_dmabuf_v1")]
+    pub(super) use super::linux_dmabuf_v1::{
+        zwp_linux_buffer_params_v1::ZwpLinuxBufferParamsV1,
+        zwp_linux_buffer_params_v1::ZwpLinuxBufferParamsV1Error,
+        zwp_linux_buffer_params_v1::ZwpLinuxBufferParamsV1Flags,
+        zwp_linux_buffer_params_v1::ZwpLinuxBufferParamsV1Ref,
+        zwp_linux_dmabuf_feedback_v1::ZwpLinuxDmabufFeedbackV1,
+        zwp_linux_dmabuf_feedback_v1::ZwpLinuxDmabufFeedbackV1Ref,
+        zwp_linux_dmabuf_feedback_v1::ZwpLinuxDmabufFeedbackV1TrancheFlags,
+        zwp_linux_dmabuf_v1::ZwpLinuxDmabufV1, zwp_linux_dmabuf_v1::ZwpLinuxDmabufV1Ref,
+    };
+    #[cfg(feature = "linux_drm_syncobj_v1")]
+    pub(super) use super::linux_drm_syncobj_v1::{
+        wp_linux_drm_syncobj_manager_v1::WpLinuxDrmSyncobjManagerV1,
+        wp_linux_drm_syncobj_manager_v1::WpLinuxDrmSyncobjManagerV1Error,
+        wp_linux_drm_syncobj_manager_v1::WpLinuxDrmSyncobjManagerV1Ref,
+        wp_linux_drm_syncobj_surface_v1::WpLinuxDrmSyncobjSurfaceV1,
+        wp_linux_drm_syncobj_surface_v1::WpLinuxDrmSyncobjSurfaceV1Error,
+        wp_linux_drm_syncobj_surface_v1::WpLinuxDrmSyncobjSurfaceV1Ref,
+        wp_linux_drm_syncobj_timeline_v1::WpLinuxDrmSyncobjTimelineV1,
+        wp_linux_drm_syncobj_timeline_v1::WpLinuxDrmSyncobjTimelineV1Ref,
+    };
+    #[cfg(feature = "pointer_constraints_unstable_v1")]
+    pub(super) use super::pointer_constraints_unstable_v1::{
+        zwp_confined_pointer_v1::ZwpConfinedPointerV1,
+        zwp_confined_pointer_v1::ZwpConfinedPointerV1Ref,
+        zwp_locked_pointer_v1::ZwpLockedPointerV1, zwp_locked_pointer_v1::ZwpLockedPointerV1Ref,
+        zwp_pointer_constraints_v1::ZwpPointerConstraintsV1,
+        zwp_pointer_constraints_v1::ZwpPointerConstraintsV1Error,
+        zwp_pointer_constraints_v1::ZwpPointerConstraintsV1Lifetime,
+        zwp_pointer_constraints_v1::ZwpPointerConstraintsV1Ref,
+    };
+    #[cfg(feature = "pointer_gestures_unstable_v1")]
+    pub(super) use super::pointer_gestures_unstable_v1::{
+        zwp_pointer_gesture_hold_v1::ZwpPointerGestureHoldV1,
+        zwp_pointer_gesture_hold_v1::ZwpPointerGestureHoldV1Ref,
+        zwp_pointer_gesture_pinch_v1::ZwpPointerGesturePinchV1,
+        zwp_pointer_gesture_pinch_v1::ZwpPointerGesturePinchV1Ref,
+        zwp_pointer_gesture_swipe_v1::ZwpPointerGestureSwipeV1,
+        zwp_pointer_gesture_swipe_v1::ZwpPointerGestureSwipeV1Ref,
+        zwp_pointer_gestures_v1::ZwpPointerGesturesV1,
+        zwp_pointer_gestures_v1::ZwpPointerGesturesV1Ref,
+    };
+    #[cfg(feature = "pointer_warp_v1")]
+    pub(super) use super::pointer_warp_v1::{
+        wp_pointer_warp_v1::WpPointerWarpV1, wp_pointer_warp_v1::WpPointerWarpV1Ref,
+    };
+    #[cfg(feature = "presentation_time")]
+    pub(super) use super::presentation_time::{
+        wp_presentation::WpPresentation, wp_presentation::WpPresentationError,
+        wp_presentation::WpPresentationRef, wp_presentation_feedback::WpPresentationFeedback,
+        wp_presentation_feedback::WpPresentationFeedbackKind,
+        wp_presentation_feedback::WpPresentationFeedbackRef,
+    };
+    #[cfg(feature = "relative_pointer_unstable_v1")]
+    pub(super) use super::relative_pointer_unstable_v1::{
+        zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1,
+        zwp_relative_pointer_manager_v1::ZwpRelativePointerManagerV1Ref,
+        zwp_relative_pointer_v1::ZwpRelativePointerV1,
+        zwp_relative_pointer_v1::ZwpRelativePointerV1Ref,
+    };
+    #[cfg(feature = "security_context_v1")]
+    pub(super) use super::security_context_v1::{
+        wp_security_context_manager_v1::WpSecurityContextManagerV1,
+        wp_security_context_manager_v1::WpSecurityContextManagerV1Error,
+        wp_security_context_manager_v1::WpSecurityContextManagerV1Ref,
+        wp_security_context_v1::WpSecurityContextV1,
+        wp_security_context_v1::WpSecurityContextV1Error,
+        wp_security_context_v1::WpSecurityContextV1Ref,
+    };
+    #[cfg(feature = "single_pixel_buffer_v1")]
+    pub(super) use super::single_pixel_buffer_v1::{
+        wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1,
+        wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1Ref,
+    };
+    #[cfg(feature = "tablet_unstable_v1")]
+    pub(super) use super::tablet_unstable_v1::{
+        zwp_tablet_manager_v1::ZwpTabletManagerV1, zwp_tablet_manager_v1::ZwpTabletManagerV1Ref,
+        zwp_tablet_seat_v1::ZwpTabletSeatV1, zwp_tablet_seat_v1::ZwpTabletSeatV1Ref,
+        zwp_tablet_tool_v1::ZwpTabletToolV1, zwp_tablet_tool_v1::ZwpTabletToolV1ButtonState,
+        zwp_tablet_tool_v1::ZwpTabletToolV1Capability, zwp_tablet_tool_v1::ZwpTabletToolV1Error,
+        zwp_tablet_tool_v1::ZwpTabletToolV1Ref, zwp_tablet_tool_v1::ZwpTabletToolV1Type,
+        zwp_tablet_v1::ZwpTabletV1, zwp_tablet_v1::ZwpTabletV1Ref,
+    };
+    #[cfg(feature = "tablet_v2")]
+    pub(super) use super::tablet_v2::{
+        zwp_tablet_manager_v2::ZwpTabletManagerV2, zwp_tablet_manager_v2::ZwpTabletManagerV2Ref,
+        zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2, zwp_tablet_pad_dial_v2::ZwpTabletPadDialV2Ref,
+        zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2,
+        zwp_tablet_pad_group_v2::ZwpTabletPadGroupV2Ref,
+        zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2, zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2Ref,
+        zwp_tablet_pad_ring_v2::ZwpTabletPadRingV2Source,
+        zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2,
+        zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2Ref,
+        zwp_tablet_pad_strip_v2::ZwpTabletPadStripV2Source, zwp_tablet_pad_v2::ZwpTabletPadV2,
+        zwp_tablet_pad_v2::ZwpTabletPadV2ButtonState, zwp_tablet_pad_v2::ZwpTabletPadV2Ref,
+        zwp_tablet_seat_v2::ZwpTabletSeatV2, zwp_tablet_seat_v2::ZwpTabletSeatV2Ref,
+        zwp_tablet_tool_v2::ZwpTabletToolV2, zwp_tablet_tool_v2::ZwpTabletToolV2ButtonState,
+        zwp_tablet_tool_v2::ZwpTabletToolV2Capability, zwp_tablet_tool_v2::ZwpTabletToolV2Error,
+        zwp_tablet_tool_v2::ZwpTabletToolV2Ref, zwp_tablet_tool_v2::ZwpTabletToolV2Type,
+        zwp_tablet_v2::ZwpTabletV2, zwp_tablet_v2::ZwpTabletV2Bustype,
+        zwp_tablet_v2::ZwpTabletV2Ref,
+    };
+    #[cfg(feature = "tearing_control_v1")]
+    pub(super) use super::tearing_control_v1::{
+        wp_tearing_control_manager_v1::WpTearingControlManagerV1,
+        wp_tearing_control_manager_v1::WpTearingControlManagerV1Error,
+        wp_tearing_control_manager_v1::WpTearingControlManagerV1Ref,
+        wp_tearing_control_v1::WpTearingControlV1,
+        wp_tearing_control_v1::WpTearingControlV1PresentationHint,
+        wp_tearing_control_v1::WpTearingControlV1Ref,
+    };
+    #[cfg(feature = "text_input_unstable_v1")]
+    pub(super) use super::text_input_unstable_v1::{
+        zwp_text_input_manager_v1::ZwpTextInputManagerV1,
+        zwp_text_input_manager_v1::ZwpTextInputManagerV1Ref, zwp_text_input_v1::ZwpTextInputV1,
+        zwp_text_input_v1::ZwpTextInputV1ContentHint,
+        zwp_text_input_v1::ZwpTextInputV1ContentPurpose,
+        zwp_text_input_v1::ZwpTextInputV1PreeditStyle, zwp_text_input_v1::ZwpTextInputV1Ref,
+        zwp_text_input_v1::ZwpTextInputV1TextDirection,
+    };
+    #[cfg(feature = "text_input_unstable_v3")]
+    pub(super) use super::text_input_unstable_v3::{
+        zwp_text_input_manager_v3::ZwpTextInputManagerV3,
+        zwp_text_input_manager_v3::ZwpTextInputManagerV3Ref, zwp_text_input_v3::ZwpTextInputV3,
+        zwp_text_input_v3::ZwpTextInputV3ChangeCause, zwp_text_input_v3::ZwpTextInputV3ContentHint,
+        zwp_text_input_v3::ZwpTextInputV3ContentPurpose, zwp_text_input_v3::ZwpTextInputV3Ref,
+    };
+    #[cfg(feature = "viewporter")]
+    pub(super) use super::viewporter::{
+        wp_viewport::WpViewport, wp_viewport::WpViewportError, wp_viewport::WpViewportRef,
+        wp_viewporter::WpViewporter, wp_viewporter::WpViewporterError,
+        wp_viewporter::WpViewporterRef,
+    };
+    #[cfg(feature = "wayland")]
+    pub(super) use super::wayland::{
+        wl_buffer::WlBuffer, wl_buffer::WlBufferRef, wl_callback::WlCallback,
+        wl_callback::WlCallbackRef, wl_compositor::WlCompositor, wl_compositor::WlCompositorRef,
+        wl_data_device::WlDataDevice, wl_data_device::WlDataDeviceError,
+        wl_data_device::WlDataDeviceRef, wl_data_device_manager::WlDataDeviceManager,
+        wl_data_device_manager::WlDataDeviceManagerDndAction,
+        wl_data_device_manager::WlDataDeviceManagerRef, wl_data_offer::WlDataOffer,
+        wl_data_offer::WlDataOfferError, wl_data_offer::WlDataOfferRef,
+        wl_data_source::WlDataSource, wl_data_source::WlDataSourceError,
+        wl_data_source::WlDataSourceRef, wl_display::WlDisplay, wl_display::WlDisplayError,
+        wl_display::WlDisplayRef, wl_fixes::WlFixes, wl_fixes::WlFixesRef, wl_keyboard::WlKeyboard,
+        wl_keyboard::WlKeyboardKeyState, wl_keyboard::WlKeyboardKeymapFormat,
+        wl_keyboard::WlKeyboardRef, wl_output::WlOutput, wl_output::WlOutputMode,
+        wl_output::WlOutputRef, wl_output::WlOutputSubpixel, wl_output::WlOutputTransform,
+        wl_pointer::WlPointer, wl_pointer::WlPointerAxis,
+        wl_pointer::WlPointerAxisRelativeDirection, wl_pointer::WlPointerAxisSource,
+        wl_pointer::WlPointerButtonState, wl_pointer::WlPointerError, wl_pointer::WlPointerRef,
+        wl_region::WlRegion, wl_region::WlRegionRef, wl_registry::WlRegistry,
+        wl_registry::WlRegistryRef, wl_seat::WlSeat, wl_seat::WlSeatCapability,
+        wl_seat::WlSeatError, wl_seat::WlSeatRef, wl_shell::WlShell, wl_shell::WlShellError,
+        wl_shell::WlShellRef, wl_shell_surface::WlShellSurface,
+        wl_shell_surface::WlShellSurfaceFullscreenMethod, wl_shell_surface::WlShellSurfaceRef,
+        wl_shell_surface::WlShellSurfaceResize, wl_shell_surface::WlShellSurfaceTransient,
+        wl_shm::WlShm, wl_shm::WlShmError, wl_shm::WlShmFormat, wl_shm::WlShmRef,
+        wl_shm_pool::WlShmPool, wl_shm_pool::WlShmPoolRef, wl_subcompositor::WlSubcompositor,
+        wl_subcompositor::WlSubcompositorError, wl_subcompositor::WlSubcompositorRef,
+        wl_subsurface::WlSubsurface, wl_subsurface::WlSubsurfaceError,
+        wl_subsurface::WlSubsurfaceRef, wl_surface::WlSurface, wl_surface::WlSurfaceError,
+        wl_surface::WlSurfaceRef, wl_touch::WlTouch, wl_touch::WlTouchRef,
+    };
+    #[cfg(feature = "wp_primary_selection_unstable_v1")]
+    pub(super) use super::wp_primary_selection_unstable_v1::{
+        zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1,
+        zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1Ref,
+        zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1,
+        zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1Ref,
+        zwp_primary_selection_offer_v1::ZwpPrimarySelectionOfferV1,
+        zwp_primary_selection_offer_v1::ZwpPrimarySelectionOfferV1Ref,
+        zwp_primary_selection_source_v1::ZwpPrimarySelectionSourceV1,
+        zwp_primary_selection_source_v1::ZwpPrimarySelectionSourceV1Ref,
+    };
+    #[cfg(feature = "xdg_activation_v1")]
+    pub(super) use super::xdg_activation_v1::{
+        xdg_activation_token_v1::XdgActivationTokenV1,
+        xdg_activation_token_v1::XdgActivationTokenV1Error,
+        xdg_activation_token_v1::XdgActivationTokenV1Ref, xdg_activation_v1::XdgActivationV1,
+        xdg_activation_v1::XdgActivationV1Ref,
+    };
+    #[cfg(feature = "xdg_decoration_unstable_v1")]
+    pub(super) use super::xdg_decoration_unstable_v1::{
+        zxdg_decoration_manager_v1::ZxdgDecorationManagerV1,
+        zxdg_decoration_manager_v1::ZxdgDecorationManagerV1Ref,
+        zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1,
+        zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1Error,
+        zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1Mode,
+        zxdg_toplevel_decoration_v1::ZxdgToplevelDecorationV1Ref,
+    };
+    #[cfg(feature = "xdg_dialog_v1")]
+    pub(super) use super::xdg_dialog_v1::{
+        xdg_dialog_v1::XdgDialogV1, xdg_dialog_v1::XdgDialogV1Ref, xdg_wm_dialog_v1::XdgWmDialogV1,
+        xdg_wm_dialog_v1::XdgWmDialogV1Error, xdg_wm_dialog_v1::XdgWmDialogV1Ref,
+    };
+    #[cfg(feature = "xdg_foreign_unstable_v1")]
+    pub(super) use super::xdg_foreign_unstable_v1::{
+        zxdg_exported_v1::ZxdgExportedV1, zxdg_exported_v1::ZxdgExportedV1Ref,
+        zxdg_exporter_v1::ZxdgExporterV1, zxdg_exporter_v1::ZxdgExporterV1Ref,
+        zxdg_imported_v1::ZxdgImportedV1, zxdg_imported_v1::ZxdgImportedV1Ref,
+        zxdg_importer_v1::ZxdgImporterV1, zxdg_importer_v1::ZxdgImporterV1Ref,
+    };
+    #[cfg(feature = "xdg_foreign_unstable_v2")]
+    pub(super) use super::xdg_foreign_unstable_v2::{
+        zxdg_exported_v2::ZxdgExportedV2, zxdg_exported_v2::ZxdgExportedV2Ref,
+        zxdg_exporter_v2::ZxdgExporterV2, zxdg_exporter_v2::ZxdgExporterV2Error,
+        zxdg_exporter_v2::ZxdgExporterV2Ref, zxdg_imported_v2::ZxdgImportedV2,
+        zxdg_imported_v2::ZxdgImportedV2Error, zxdg_imported_v2::ZxdgImportedV2Ref,
+        zxdg_importer_v2::ZxdgImporterV2, zxdg_importer_v2::ZxdgImporterV2Ref,
+    };
+    #[cfg(feature = "xdg_output_unstable_v1")]
+    pub(super) use super::xdg_output_unstable_v1::{
+        zxdg_output_manager_v1::ZxdgOutputManagerV1,
+        zxdg_output_manager_v1::ZxdgOutputManagerV1Ref, zxdg_output_v1::ZxdgOutputV1,
+        zxdg_output_v1::ZxdgOutputV1Ref,
+    };
+    #[cfg(feature = "xdg_shell")]
+    pub(super) use super::xdg_shell::{
+        xdg_popup::XdgPopup, xdg_popup::XdgPopupError, xdg_popup::XdgPopupRef,
+        xdg_positioner::XdgPositioner, xdg_positioner::XdgPositionerAnchor,
+        xdg_positioner::XdgPositionerConstraintAdjustment, xdg_positioner::XdgPositionerError,
+        xdg_positioner::XdgPositionerGravity, xdg_positioner::XdgPositionerRef,
+        xdg_surface::XdgSurface, xdg_surface::XdgSurfaceError, xdg_surface::XdgSurfaceRef,
+        xdg_toplevel::XdgToplevel, xdg_toplevel::XdgToplevelError, xdg_toplevel::XdgToplevelRef,
+        xdg_toplevel::XdgToplevelResizeEdge, xdg_toplevel::XdgToplevelState,
+        xdg_toplevel::XdgToplevelWmCapabilities, xdg_wm_base::XdgWmBase,
+        xdg_wm_base::XdgWmBaseError, xdg_wm_base::XdgWmBaseRef,
+    };
+    #[cfg(feature = "xdg_system_bell_v1")]
+    pub(super) use super::xdg_system_bell_v1::{
+        xdg_system_bell_v1::XdgSystemBellV1, xdg_system_bell_v1::XdgSystemBellV1Ref,
+    };
+    #[cfg(feature = "xdg_toplevel_drag_v1")]
+    pub(super) use super::xdg_toplevel_drag_v1::{
+        xdg_toplevel_drag_manager_v1::XdgToplevelDragManagerV1,
+        xdg_toplevel_drag_manager_v1::XdgToplevelDragManagerV1Error,
+        xdg_toplevel_drag_manager_v1::XdgToplevelDragManagerV1Ref,
+        xdg_toplevel_drag_v1::XdgToplevelDragV1, xdg_toplevel_drag_v1::XdgToplevelDragV1Error,
+        xdg_toplevel_drag_v1::XdgToplevelDragV1Ref,
+    };
+    #[cfg(feature = "xdg_toplevel_icon_v1")]
+    pub(super) use super::xdg_toplevel_icon_v1::{
+        xdg_toplevel_icon_manager_v1::XdgToplevelIconManagerV1,
+        xdg_toplevel_icon_manager_v1::XdgToplevelIconManagerV1Ref,
+        xdg_toplevel_icon_v1::XdgToplevelIconV1, xdg_toplevel_icon_v1::XdgToplevelIconV1Error,
+        xdg_toplevel_icon_v1::XdgToplevelIconV1Ref,
+    };
+    #[cfg(feature = "xdg_toplevel_tag_v1")]
+    pub(super) use super::xdg_toplevel_tag_v1::{
+        xdg_toplevel_tag_manager_v1::XdgToplevelTagManagerV1,
+        xdg_toplevel_tag_manager_v1::XdgToplevelTagManagerV1Ref,
+    };
+    #[cfg(feature = "xwayland_keyboard_grab_unstable_v1")]
+    pub(super) use super::xwayland_keyboard_grab_unstable_v1::{
+        zwp_xwayland_keyboard_grab_manager_v1::ZwpXwaylandKeyboardGrabManagerV1,
+        zwp_xwayland_keyboard_grab_manager_v1::ZwpXwaylandKeyboardGrabManagerV1Ref,
+        zwp_xwayland_keyboard_grab_v1::ZwpXwaylandKeyboardGrabV1,
+        zwp_xwayland_keyboard_grab_v1::ZwpXwaylandKeyboardGrabV1Ref,
+    };
+    #[cfg(feature = "xwayland_shell_v1")]
+    pub(super) use super::xwayland_shell_v1::{
+        xwayland_shell_v1::XwaylandShellV1, xwayland_shell_v1::XwaylandShellV1Error,
+        xwayland_shell_v1::XwaylandShellV1Ref, xwayland_surface_v1::XwaylandSurfaceV1,
+        xwayland_surface_v1::XwaylandSurfaceV1Error, xwayland_surface_v1::XwaylandSurfaceV1Ref,
+    };
+    #[cfg(feature = "zwp_linux_explicit_synchronization_unstable_v1")]
+    pub(super) use super::zwp_linux_explicit_synchronization_unstable_v1::{
+        zwp_linux_buffer_release_v1::ZwpLinuxBufferReleaseV1,
+        zwp_linux_buffer_release_v1::ZwpLinuxBufferReleaseV1Ref,
+        zwp_linux_explicit_synchronization_v1::ZwpLinuxExplicitSynchronizationV1,
+        zwp_linux_explicit_synchronization_v1::ZwpLinuxExplicitSynchronizationV1Error,
+        zwp_linux_explicit_synchronization_v1::ZwpLinuxExplicitSynchronizationV1Ref,
+        zwp_linux_surface_synchronization_v1::ZwpLinuxSurfaceSynchronizationV1,
+        zwp_linux_surface_synchronization_v1::ZwpLinuxSurfaceSynchronizationV1Error,
+        zwp_linux_surface_synchronization_v1::ZwpLinuxSurfaceSynchronizationV1Ref,
+    };
+}
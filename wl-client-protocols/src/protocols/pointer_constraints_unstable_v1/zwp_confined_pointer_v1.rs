@@ -0,0 +1,825 @@
+//! confined pointer object
+//!
+//! The wp_confined_pointer interface represents a confined pointer state.
+//!
+//! This object will send the event 'confined' when the confinement is
+//! activated. Whenever the confinement is activated, it is guaranteed that
+//! the surface the pointer is confined to will already have received pointer
+//! focus and that the pointer will be within the region passed to the request
+//! creating this object. It is up to the compositor to decide whether this
+//! requires some user interaction and if the pointer will warp to within the
+//! passed region if outside.
+//!
+//! To unconfine the pointer, send the destroy request. This will also destroy
+//! the wp_confined_pointer object.
+//!
+//! If the compositor decides to unconfine the pointer the unconfined event is
+//! sent. The wp_confined_pointer object is at this point defunct and should
+//! be destroyed.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_confined_pointer_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_region".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlRegion::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"confined".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"unconfined".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_confined_pointer_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_confined_pointer_v1",
+    protocol: "pointer_constraints_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_region",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "region",
+                ty: info::ArgType::Object,
+                interface: Some("wl_region"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "confined",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "unconfined",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_confined_pointer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpConfinedPointerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_confined_pointer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpConfinedPointerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpConfinedPointerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpConfinedPointerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpConfinedPointerV1 {
+    const INTERFACE: &'static str = "zwp_confined_pointer_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpConfinedPointerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpConfinedPointerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpConfinedPointerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpConfinedPointerV1Ref {
+    type Owned = ZwpConfinedPointerV1;
+}
+
+impl Deref for ZwpConfinedPointerV1 {
+    type Target = ZwpConfinedPointerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpConfinedPointerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_confined_pointer_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpConfinedPointerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_confined_pointer_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpConfinedPointerV1Ref> for ZwpConfinedPointerV1 {
+    fn eq(&self, other: &ZwpConfinedPointerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpConfinedPointerV1> for ZwpConfinedPointerV1Ref {
+    fn eq(&self, other: &ZwpConfinedPointerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpConfinedPointerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the confined pointer object
+    ///
+    /// Destroy the confined pointer object. If applicable, the compositor will
+    /// unconfine the pointer.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpConfinedPointerV1Ref {
+    /// set a new confine region
+    ///
+    /// Set a new region used to confine the pointer.
+    ///
+    /// The new confine region is double-buffered, see wl_surface.commit.
+    ///
+    /// If the confinement is active when the new confinement region is applied
+    /// and the pointer ends up outside of newly applied region, the pointer may
+    /// warped to a position within the new confinement region. If warped, a
+    /// wl_pointer.motion event will be emitted, but no
+    /// wp_relative_pointer.relative_motion event.
+    ///
+    /// The compositor may also, instead of using the new region, unconfine the
+    /// pointer.
+    ///
+    /// For details about the confine region, see wp_confined_pointer.
+    ///
+    /// # Arguments
+    ///
+    /// - `region`: region of surface
+    #[inline]
+    pub fn set_region(&self, region: Option<&WlRegionRef>) {
+        let (arg0,) = (region,);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("region", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `?o`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+impl ZwpConfinedPointerV1 {
+    /// Since when the confined event is available.
+    #[allow(dead_code)]
+    pub const EVT__CONFINED__SINCE: u32 = 1;
+
+    /// Since when the unconfined event is available.
+    #[allow(dead_code)]
+    pub const EVT__UNCONFINED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpConfinedPointerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpConfinedPointerV1EventHandler {
+    /// pointer confined
+    ///
+    /// Notification that the pointer confinement of the seat's pointer is
+    /// activated.
+    #[inline]
+    fn confined(&self, _slf: &ZwpConfinedPointerV1Ref) {}
+
+    /// pointer unconfined
+    ///
+    /// Notification that the pointer confinement of the seat's pointer is no
+    /// longer active. If this is a oneshot pointer confinement (see
+    /// wp_pointer_constraints.lifetime) this object is now defunct and should
+    /// be destroyed. If this is a persistent pointer confinement (see
+    /// wp_pointer_constraints.lifetime) this pointer confinement may again
+    /// reactivate in the future.
+    #[inline]
+    fn unconfined(&self, _slf: &ZwpConfinedPointerV1Ref) {}
+}
+
+impl ZwpConfinedPointerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpConfinedPointerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpConfinedPointerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpConfinedPointerV1EventHandlerWithData<T> {
+    /// pointer confined
+    ///
+    /// Notification that the pointer confinement of the seat's pointer is
+    /// activated.
+    #[inline]
+    fn confined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {}
+
+    /// pointer unconfined
+    ///
+    /// Notification that the pointer confinement of the seat's pointer is no
+    /// longer active. If this is a oneshot pointer confinement (see
+    /// wp_pointer_constraints.lifetime) this object is now defunct and should
+    /// be destroyed. If this is a persistent pointer confinement (see
+    /// wp_pointer_constraints.lifetime) this pointer confinement may again
+    /// reactivate in the future.
+    #[inline]
+    fn unconfined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpConfinedPointerV1] proxies.
+///
+/// This trait is the same as [ZwpConfinedPointerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpConfinedPointerV1EventHandlerMut {
+    /// See [ZwpConfinedPointerV1EventHandler::confined].
+    #[inline]
+    fn confined(&mut self, _slf: &ZwpConfinedPointerV1Ref) {}
+
+    /// See [ZwpConfinedPointerV1EventHandler::unconfined].
+    #[inline]
+    fn unconfined(&mut self, _slf: &ZwpConfinedPointerV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpConfinedPointerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpConfinedPointerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpConfinedPointerV1EventHandlerMutWithData<T> {
+    /// See [ZwpConfinedPointerV1EventHandler::confined].
+    #[inline]
+    fn confined(&mut self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {}
+
+    /// See [ZwpConfinedPointerV1EventHandler::unconfined].
+    #[inline]
+    fn unconfined(&mut self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {}
+}
+
+impl<H> ZwpConfinedPointerV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpConfinedPointerV1EventHandlerMut,
+{
+    #[inline]
+    fn confined(&self, _slf: &ZwpConfinedPointerV1Ref) {
+        self.with(|h| h.confined(_slf))
+    }
+
+    #[inline]
+    fn unconfined(&self, _slf: &ZwpConfinedPointerV1Ref) {
+        self.with(|h| h.unconfined(_slf))
+    }
+}
+
+impl<T, H> ZwpConfinedPointerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpConfinedPointerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn confined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+        self.with(|h| h.confined(_data, _slf))
+    }
+
+    #[inline]
+    fn unconfined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+        self.with(|h| h.unconfined(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpConfinedPointerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpConfinedPointerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn confined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().confined(_data, _slf)
+    }
+
+    #[inline]
+    fn unconfined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().unconfined(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpConfinedPointerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpConfinedPointerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn confined(&mut self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().confined(_data, _slf)
+    }
+
+    #[inline]
+    fn unconfined(&mut self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().unconfined(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpConfinedPointerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpConfinedPointerV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.confined(slf);
+            }
+            1 => {
+                self.0.unconfined(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_confined_pointer_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpConfinedPointerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpConfinedPointerV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.confined(data, slf);
+            }
+            1 => {
+                self.0.unconfined(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_confined_pointer_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpConfinedPointerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpConfinedPointerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for confined events.
+    pub struct Confined<F>(F);
+    impl<F> ZwpConfinedPointerV1EventHandler for Confined<F>
+    where
+        F: Fn(&ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn confined(&self, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpConfinedPointerV1EventHandlerWithData<T> for Confined<F>
+    where
+        F: Fn(&mut T, &ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn confined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for unconfined events.
+    pub struct Unconfined<F>(F);
+    impl<F> ZwpConfinedPointerV1EventHandler for Unconfined<F>
+    where
+        F: Fn(&ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn unconfined(&self, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpConfinedPointerV1EventHandlerWithData<T> for Unconfined<F>
+    where
+        F: Fn(&mut T, &ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn unconfined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for confined events.
+    pub struct ConfinedMut<F>(F);
+    impl<F> ZwpConfinedPointerV1EventHandlerMut for ConfinedMut<F>
+    where
+        F: FnMut(&ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn confined(&mut self, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpConfinedPointerV1EventHandlerMutWithData<T> for ConfinedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn confined(&mut self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for unconfined events.
+    pub struct UnconfinedMut<F>(F);
+    impl<F> ZwpConfinedPointerV1EventHandlerMut for UnconfinedMut<F>
+    where
+        F: FnMut(&ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn unconfined(&mut self, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpConfinedPointerV1EventHandlerMutWithData<T> for UnconfinedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpConfinedPointerV1Ref),
+    {
+        #[inline]
+        fn unconfined(&mut self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpConfinedPointerV1 {
+        /// Creates an event handler for confined events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_confined<F>(f: F) -> Confined<F>
+        where
+            F: Fn(&ZwpConfinedPointerV1Ref),
+        {
+            Confined(f)
+        }
+
+        /// Creates an event handler with mutable data for confined events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_confined_with_data<T, F>(f: F) -> Confined<F>
+        where
+            F: Fn(&mut T, &ZwpConfinedPointerV1Ref),
+        {
+            Confined(f)
+        }
+
+        /// Creates an event handler for unconfined events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_unconfined<F>(f: F) -> Unconfined<F>
+        where
+            F: Fn(&ZwpConfinedPointerV1Ref),
+        {
+            Unconfined(f)
+        }
+
+        /// Creates an event handler with mutable data for unconfined events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_unconfined_with_data<T, F>(f: F) -> Unconfined<F>
+        where
+            F: Fn(&mut T, &ZwpConfinedPointerV1Ref),
+        {
+            Unconfined(f)
+        }
+
+        /// Creates a mutable event handler for confined events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_confined_mut<F>(f: F) -> ConfinedMut<F>
+        where
+            F: FnMut(&ZwpConfinedPointerV1Ref),
+        {
+            ConfinedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for confined events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_confined_mut_with_data<T, F>(f: F) -> ConfinedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpConfinedPointerV1Ref),
+        {
+            ConfinedMut(f)
+        }
+
+        /// Creates a mutable event handler for unconfined events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_unconfined_mut<F>(f: F) -> UnconfinedMut<F>
+        where
+            F: FnMut(&ZwpConfinedPointerV1Ref),
+        {
+            UnconfinedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for unconfined events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_unconfined_mut_with_data<T, F>(f: F) -> UnconfinedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpConfinedPointerV1Ref),
+        {
+            UnconfinedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                confined: Ignore,
+                unconfined: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpConfinedPointerV1EventHandler for Ignore {}
+    impl<T> ZwpConfinedPointerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpConfinedPointerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        confined: H0,
+        unconfined: H1,
+    }
+
+    impl<H0, H1> ZwpConfinedPointerV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpConfinedPointerV1EventHandler,
+        H1: ZwpConfinedPointerV1EventHandler,
+    {
+        #[inline]
+        fn confined(&self, _slf: &ZwpConfinedPointerV1Ref) {
+            self.confined.confined(_slf)
+        }
+
+        #[inline]
+        fn unconfined(&self, _slf: &ZwpConfinedPointerV1Ref) {
+            self.unconfined.unconfined(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ZwpConfinedPointerV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpConfinedPointerV1EventHandlerWithData<T>,
+        H1: ZwpConfinedPointerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn confined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+            self.confined.confined(_data, _slf)
+        }
+
+        #[inline]
+        fn unconfined(&self, _data: &mut T, _slf: &ZwpConfinedPointerV1Ref) {
+            self.unconfined.unconfined(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for confined events.
+        #[allow(dead_code)]
+        pub fn on_confined<F>(self, f: F) -> Handler<Confined<F>, H1>
+        where
+            F: Fn(&ZwpConfinedPointerV1Ref),
+        {
+            Handler {
+                confined: Confined(f),
+                unconfined: self.unconfined,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for confined events.
+        #[allow(dead_code)]
+        pub fn on_confined_with_data<T, F>(self, f: F) -> Handler<Confined<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpConfinedPointerV1Ref),
+        {
+            Handler {
+                confined: Confined(f),
+                unconfined: self.unconfined,
+            }
+        }
+
+        /// Sets the closure for unconfined events.
+        #[allow(dead_code)]
+        pub fn on_unconfined<F>(self, f: F) -> Handler<H0, Unconfined<F>>
+        where
+            F: Fn(&ZwpConfinedPointerV1Ref),
+        {
+            Handler {
+                confined: self.confined,
+                unconfined: Unconfined(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for unconfined events.
+        #[allow(dead_code)]
+        pub fn on_unconfined_with_data<T, F>(self, f: F) -> Handler<H0, Unconfined<F>>
+        where
+            F: Fn(&mut T, &ZwpConfinedPointerV1Ref),
+        {
+            Handler {
+                confined: self.confined,
+                unconfined: Unconfined(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,881 @@
+//! receive relative pointer motion events
+//!
+//! The wp_locked_pointer interface represents a locked pointer state.
+//!
+//! While the lock of this object is active, the wl_pointer objects of the
+//! associated seat will not emit any wl_pointer.motion events.
+//!
+//! This object will send the event 'locked' when the lock is activated.
+//! Whenever the lock is activated, it is guaranteed that the locked surface
+//! will already have received pointer focus and that the pointer will be
+//! within the region passed to the request creating this object.
+//!
+//! To unlock the pointer, send the destroy request. This will also destroy
+//! the wp_locked_pointer object.
+//!
+//! If the compositor decides to unlock the pointer the unlocked event is
+//! sent. See wp_locked_pointer.unlock for details.
+//!
+//! When unlocking, the compositor may warp the cursor position to the set
+//! cursor position hint. If it does, it will not result in any relative
+//! motion events emitted via wp_relative_pointer.
+//!
+//! If the surface the lock was requested on is destroyed and the lock is not
+//! yet activated, the wp_locked_pointer object is now defunct and must be
+//! destroyed.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_locked_pointer_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_cursor_position_hint".as_ptr(),
+                signature: c"ff".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_region".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlRegion::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"locked".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"unlocked".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_locked_pointer_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_locked_pointer_v1",
+    protocol: "pointer_constraints_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_cursor_position_hint",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "surface_x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface_y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_region",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "region",
+                ty: info::ArgType::Object,
+                interface: Some("wl_region"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "locked",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "unlocked",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_locked_pointer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLockedPointerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_locked_pointer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLockedPointerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLockedPointerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLockedPointerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLockedPointerV1 {
+    const INTERFACE: &'static str = "zwp_locked_pointer_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpLockedPointerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLockedPointerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLockedPointerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLockedPointerV1Ref {
+    type Owned = ZwpLockedPointerV1;
+}
+
+impl Deref for ZwpLockedPointerV1 {
+    type Target = ZwpLockedPointerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLockedPointerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_locked_pointer_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpLockedPointerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_locked_pointer_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpLockedPointerV1Ref> for ZwpLockedPointerV1 {
+    fn eq(&self, other: &ZwpLockedPointerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLockedPointerV1> for ZwpLockedPointerV1Ref {
+    fn eq(&self, other: &ZwpLockedPointerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLockedPointerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the locked pointer object
+    ///
+    /// Destroy the locked pointer object. If applicable, the compositor will
+    /// unlock the pointer.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLockedPointerV1Ref {
+    /// set the pointer cursor position hint
+    ///
+    /// Set the cursor position hint relative to the top left corner of the
+    /// surface.
+    ///
+    /// If the client is drawing its own cursor, it should update the position
+    /// hint to the position of its own cursor. A compositor may use this
+    /// information to warp the pointer upon unlock in order to avoid pointer
+    /// jumps.
+    ///
+    /// The cursor position hint is double-buffered state, see
+    /// wl_surface.commit.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface_x`: surface-local x coordinate
+    /// - `surface_y`: surface-local y coordinate
+    #[inline]
+    pub fn set_cursor_position_hint(&self, surface_x: Fixed, surface_y: Fixed) {
+        let (arg0, arg1) = (surface_x, surface_y);
+        let mut args = [
+            wl_argument { f: arg0.to_wire() },
+            wl_argument { f: arg1.to_wire() },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `ff`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// set a new lock region
+    ///
+    /// Set a new region used to lock the pointer.
+    ///
+    /// The new lock region is double-buffered, see wl_surface.commit.
+    ///
+    /// For details about the lock region, see wp_locked_pointer.
+    ///
+    /// # Arguments
+    ///
+    /// - `region`: region of surface
+    #[inline]
+    pub fn set_region(&self, region: Option<&WlRegionRef>) {
+        let (arg0,) = (region,);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("region", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `?o`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+impl ZwpLockedPointerV1 {
+    /// Since when the locked event is available.
+    #[allow(dead_code)]
+    pub const EVT__LOCKED__SINCE: u32 = 1;
+
+    /// Since when the unlocked event is available.
+    #[allow(dead_code)]
+    pub const EVT__UNLOCKED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpLockedPointerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLockedPointerV1EventHandler {
+    /// lock activation event
+    ///
+    /// Notification that the pointer lock of the seat's pointer is activated.
+    #[inline]
+    fn locked(&self, _slf: &ZwpLockedPointerV1Ref) {}
+
+    /// lock deactivation event
+    ///
+    /// Notification that the pointer lock of the seat's pointer is no longer
+    /// active. If this is a oneshot pointer lock (see
+    /// wp_pointer_constraints.lifetime) this object is now defunct and should
+    /// be destroyed. If this is a persistent pointer lock (see
+    /// wp_pointer_constraints.lifetime) this pointer lock may again
+    /// reactivate in the future.
+    #[inline]
+    fn unlocked(&self, _slf: &ZwpLockedPointerV1Ref) {}
+}
+
+impl ZwpLockedPointerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLockedPointerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLockedPointerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLockedPointerV1EventHandlerWithData<T> {
+    /// lock activation event
+    ///
+    /// Notification that the pointer lock of the seat's pointer is activated.
+    #[inline]
+    fn locked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {}
+
+    /// lock deactivation event
+    ///
+    /// Notification that the pointer lock of the seat's pointer is no longer
+    /// active. If this is a oneshot pointer lock (see
+    /// wp_pointer_constraints.lifetime) this object is now defunct and should
+    /// be destroyed. If this is a persistent pointer lock (see
+    /// wp_pointer_constraints.lifetime) this pointer lock may again
+    /// reactivate in the future.
+    #[inline]
+    fn unlocked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpLockedPointerV1] proxies.
+///
+/// This trait is the same as [ZwpLockedPointerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLockedPointerV1EventHandlerMut {
+    /// See [ZwpLockedPointerV1EventHandler::locked].
+    #[inline]
+    fn locked(&mut self, _slf: &ZwpLockedPointerV1Ref) {}
+
+    /// See [ZwpLockedPointerV1EventHandler::unlocked].
+    #[inline]
+    fn unlocked(&mut self, _slf: &ZwpLockedPointerV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpLockedPointerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLockedPointerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLockedPointerV1EventHandlerMutWithData<T> {
+    /// See [ZwpLockedPointerV1EventHandler::locked].
+    #[inline]
+    fn locked(&mut self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {}
+
+    /// See [ZwpLockedPointerV1EventHandler::unlocked].
+    #[inline]
+    fn unlocked(&mut self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {}
+}
+
+impl<H> ZwpLockedPointerV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpLockedPointerV1EventHandlerMut,
+{
+    #[inline]
+    fn locked(&self, _slf: &ZwpLockedPointerV1Ref) {
+        self.with(|h| h.locked(_slf))
+    }
+
+    #[inline]
+    fn unlocked(&self, _slf: &ZwpLockedPointerV1Ref) {
+        self.with(|h| h.unlocked(_slf))
+    }
+}
+
+impl<T, H> ZwpLockedPointerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpLockedPointerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn locked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+        self.with(|h| h.locked(_data, _slf))
+    }
+
+    #[inline]
+    fn unlocked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+        self.with(|h| h.unlocked(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpLockedPointerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLockedPointerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn locked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().locked(_data, _slf)
+    }
+
+    #[inline]
+    fn unlocked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().unlocked(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpLockedPointerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLockedPointerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn locked(&mut self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().locked(_data, _slf)
+    }
+
+    #[inline]
+    fn unlocked(&mut self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().unlocked(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLockedPointerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLockedPointerV1Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.locked(slf);
+            }
+            1 => {
+                self.0.unlocked(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_locked_pointer_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLockedPointerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLockedPointerV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.locked(data, slf);
+            }
+            1 => {
+                self.0.unlocked(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_locked_pointer_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLockedPointerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLockedPointerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for locked events.
+    pub struct Locked<F>(F);
+    impl<F> ZwpLockedPointerV1EventHandler for Locked<F>
+    where
+        F: Fn(&ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn locked(&self, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLockedPointerV1EventHandlerWithData<T> for Locked<F>
+    where
+        F: Fn(&mut T, &ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn locked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for unlocked events.
+    pub struct Unlocked<F>(F);
+    impl<F> ZwpLockedPointerV1EventHandler for Unlocked<F>
+    where
+        F: Fn(&ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn unlocked(&self, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLockedPointerV1EventHandlerWithData<T> for Unlocked<F>
+    where
+        F: Fn(&mut T, &ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn unlocked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for locked events.
+    pub struct LockedMut<F>(F);
+    impl<F> ZwpLockedPointerV1EventHandlerMut for LockedMut<F>
+    where
+        F: FnMut(&ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn locked(&mut self, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLockedPointerV1EventHandlerMutWithData<T> for LockedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn locked(&mut self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for unlocked events.
+    pub struct UnlockedMut<F>(F);
+    impl<F> ZwpLockedPointerV1EventHandlerMut for UnlockedMut<F>
+    where
+        F: FnMut(&ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn unlocked(&mut self, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLockedPointerV1EventHandlerMutWithData<T> for UnlockedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLockedPointerV1Ref),
+    {
+        #[inline]
+        fn unlocked(&mut self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpLockedPointerV1 {
+        /// Creates an event handler for locked events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_locked<F>(f: F) -> Locked<F>
+        where
+            F: Fn(&ZwpLockedPointerV1Ref),
+        {
+            Locked(f)
+        }
+
+        /// Creates an event handler with mutable data for locked events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_locked_with_data<T, F>(f: F) -> Locked<F>
+        where
+            F: Fn(&mut T, &ZwpLockedPointerV1Ref),
+        {
+            Locked(f)
+        }
+
+        /// Creates an event handler for unlocked events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_unlocked<F>(f: F) -> Unlocked<F>
+        where
+            F: Fn(&ZwpLockedPointerV1Ref),
+        {
+            Unlocked(f)
+        }
+
+        /// Creates an event handler with mutable data for unlocked events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_unlocked_with_data<T, F>(f: F) -> Unlocked<F>
+        where
+            F: Fn(&mut T, &ZwpLockedPointerV1Ref),
+        {
+            Unlocked(f)
+        }
+
+        /// Creates a mutable event handler for locked events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_locked_mut<F>(f: F) -> LockedMut<F>
+        where
+            F: FnMut(&ZwpLockedPointerV1Ref),
+        {
+            LockedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for locked events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_locked_mut_with_data<T, F>(f: F) -> LockedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLockedPointerV1Ref),
+        {
+            LockedMut(f)
+        }
+
+        /// Creates a mutable event handler for unlocked events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_unlocked_mut<F>(f: F) -> UnlockedMut<F>
+        where
+            F: FnMut(&ZwpLockedPointerV1Ref),
+        {
+            UnlockedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for unlocked events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_unlocked_mut_with_data<T, F>(f: F) -> UnlockedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLockedPointerV1Ref),
+        {
+            UnlockedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                locked: Ignore,
+                unlocked: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpLockedPointerV1EventHandler for Ignore {}
+    impl<T> ZwpLockedPointerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpLockedPointerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        locked: H0,
+        unlocked: H1,
+    }
+
+    impl<H0, H1> ZwpLockedPointerV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpLockedPointerV1EventHandler,
+        H1: ZwpLockedPointerV1EventHandler,
+    {
+        #[inline]
+        fn locked(&self, _slf: &ZwpLockedPointerV1Ref) {
+            self.locked.locked(_slf)
+        }
+
+        #[inline]
+        fn unlocked(&self, _slf: &ZwpLockedPointerV1Ref) {
+            self.unlocked.unlocked(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ZwpLockedPointerV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpLockedPointerV1EventHandlerWithData<T>,
+        H1: ZwpLockedPointerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn locked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+            self.locked.locked(_data, _slf)
+        }
+
+        #[inline]
+        fn unlocked(&self, _data: &mut T, _slf: &ZwpLockedPointerV1Ref) {
+            self.unlocked.unlocked(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for locked events.
+        #[allow(dead_code)]
+        pub fn on_locked<F>(self, f: F) -> Handler<Locked<F>, H1>
+        where
+            F: Fn(&ZwpLockedPointerV1Ref),
+        {
+            Handler {
+                locked: Locked(f),
+                unlocked: self.unlocked,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for locked events.
+        #[allow(dead_code)]
+        pub fn on_locked_with_data<T, F>(self, f: F) -> Handler<Locked<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpLockedPointerV1Ref),
+        {
+            Handler {
+                locked: Locked(f),
+                unlocked: self.unlocked,
+            }
+        }
+
+        /// Sets the closure for unlocked events.
+        #[allow(dead_code)]
+        pub fn on_unlocked<F>(self, f: F) -> Handler<H0, Unlocked<F>>
+        where
+            F: Fn(&ZwpLockedPointerV1Ref),
+        {
+            Handler {
+                locked: self.locked,
+                unlocked: Unlocked(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for unlocked events.
+        #[allow(dead_code)]
+        pub fn on_unlocked_with_data<T, F>(self, f: F) -> Handler<H0, Unlocked<F>>
+        where
+            F: Fn(&mut T, &ZwpLockedPointerV1Ref),
+        {
+            Handler {
+                locked: self.locked,
+                unlocked: Unlocked(f),
+            }
+        }
+    }
+}
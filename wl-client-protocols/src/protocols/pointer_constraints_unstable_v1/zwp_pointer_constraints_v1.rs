@@ -0,0 +1,839 @@
+//! constrain the movement of a pointer
+//!
+//! The global interface exposing pointer constraining functionality. It
+//! exposes two requests: lock_pointer for locking the pointer to its
+//! position, and confine_pointer for locking the pointer to a region.
+//!
+//! The lock_pointer and confine_pointer requests create the objects
+//! wp_locked_pointer and wp_confined_pointer respectively, and the client can
+//! use these objects to interact with the lock.
+//!
+//! For any surface, only one lock or confinement may be active across all
+//! wl_pointer objects of the same seat. If a lock or confinement is requested
+//! when another lock or confinement is active or requested on the same surface
+//! and with any of the wl_pointer objects of the same seat, an
+//! 'already_constrained' error will be raised.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_pointer_constraints_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"lock_pointer".as_ptr(),
+                signature: c"noo?ou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] = [
+                        Some(ZwpLockedPointerV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                        Some(WlRegion::WL_INTERFACE),
+                        None,
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"confine_pointer".as_ptr(),
+                signature: c"noo?ou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] = [
+                        Some(ZwpConfinedPointerV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                        Some(WlRegion::WL_INTERFACE),
+                        None,
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_pointer_constraints_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_pointer_constraints_v1",
+    protocol: "pointer_constraints_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "lock_pointer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_locked_pointer_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "region",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_region"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "lifetime",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("lifetime"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "confine_pointer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_confined_pointer_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "region",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_region"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "lifetime",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("lifetime"),
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "already_constrained",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+        info::EnumInfo {
+            name: "lifetime",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "oneshot",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "persistent",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned zwp_pointer_constraints_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerConstraintsV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_pointer_constraints_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerConstraintsV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPointerConstraintsV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPointerConstraintsV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPointerConstraintsV1 {
+    const INTERFACE: &'static str = "zwp_pointer_constraints_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpPointerConstraintsV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPointerConstraintsV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPointerConstraintsV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPointerConstraintsV1Ref {
+    type Owned = ZwpPointerConstraintsV1;
+}
+
+impl Deref for ZwpPointerConstraintsV1 {
+    type Target = ZwpPointerConstraintsV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPointerConstraintsV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_constraints_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPointerConstraintsV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_constraints_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPointerConstraintsV1Ref> for ZwpPointerConstraintsV1 {
+    fn eq(&self, other: &ZwpPointerConstraintsV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPointerConstraintsV1> for ZwpPointerConstraintsV1Ref {
+    fn eq(&self, other: &ZwpPointerConstraintsV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPointerConstraintsV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the pointer constraints manager object
+    ///
+    /// Used by the client to notify the server that it will no longer use this
+    /// pointer constraints object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the lock_pointer request is available.
+    #[allow(dead_code)]
+    pub const REQ__LOCK_POINTER__SINCE: u32 = 1;
+
+    /// lock pointer to a position
+    ///
+    /// The lock_pointer request lets the client request to disable movements of
+    /// the virtual pointer (i.e. the cursor), effectively locking the pointer
+    /// to a position. This request may not take effect immediately; in the
+    /// future, when the compositor deems implementation-specific constraints
+    /// are satisfied, the pointer lock will be activated and the compositor
+    /// sends a locked event.
+    ///
+    /// The protocol provides no guarantee that the constraints are ever
+    /// satisfied, and does not require the compositor to send an error if the
+    /// constraints cannot ever be satisfied. It is thus possible to request a
+    /// lock that will never activate.
+    ///
+    /// There may not be another pointer constraint of any kind requested or
+    /// active on the surface for any of the wl_pointer objects of the seat of
+    /// the passed pointer when requesting a lock. If there is, an error will be
+    /// raised. See general pointer lock documentation for more details.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the lock to activate. It is up to the compositor whether to
+    /// warp the pointer or require some kind of user interaction for the lock
+    /// to activate. If the region is null the surface input region is used.
+    ///
+    /// A surface may receive pointer focus without the lock being activated.
+    ///
+    /// The request creates a new object wp_locked_pointer which is used to
+    /// interact with the lock as well as receive updates about its state. See
+    /// the the description of wp_locked_pointer for further information.
+    ///
+    /// Note that while a pointer is locked, the wl_pointer objects of the
+    /// corresponding seat will not emit any wl_pointer.motion events, but
+    /// relative motion events will still be emitted via wp_relative_pointer
+    /// objects of the same seat. wl_pointer.axis and wl_pointer.button events
+    /// are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: surface to lock pointer to
+    /// - `pointer`: the pointer that should be locked
+    /// - `region`: region of surface
+    /// - `lifetime`: lock lifetime
+    #[inline]
+    pub fn lock_pointer(
+        &self,
+        surface: &WlSurfaceRef,
+        pointer: &WlPointerRef,
+        region: Option<&WlRegionRef>,
+        lifetime: ZwpPointerConstraintsV1Lifetime,
+    ) -> ZwpLockedPointerV1 {
+        let (arg1, arg2, arg3, arg4) = (surface, pointer, region, lifetime);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("pointer", obj2_lock.wl_proxy());
+        let obj3_lock = arg3.map(|arg3| proxy::lock(arg3));
+        let obj3 = obj3_lock
+            .map(|obj3_lock| check_argument_proxy("region", obj3_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+            wl_argument { o: obj3 },
+            wl_argument { u: arg4.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `noo?ou`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpLockedPointerV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLockedPointerV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the confine_pointer request is available.
+    #[allow(dead_code)]
+    pub const REQ__CONFINE_POINTER__SINCE: u32 = 1;
+
+    /// confine pointer to a region
+    ///
+    /// The confine_pointer request lets the client request to confine the
+    /// pointer cursor to a given region. This request may not take effect
+    /// immediately; in the future, when the compositor deems implementation-
+    /// specific constraints are satisfied, the pointer confinement will be
+    /// activated and the compositor sends a confined event.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the confinement to activate. It is up to the compositor
+    /// whether to warp the pointer or require some kind of user interaction for
+    /// the confinement to activate. If the region is null the surface input
+    /// region is used.
+    ///
+    /// The request will create a new object wp_confined_pointer which is used
+    /// to interact with the confinement as well as receive updates about its
+    /// state. See the the description of wp_confined_pointer for further
+    /// information.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: surface to lock pointer to
+    /// - `pointer`: the pointer that should be confined
+    /// - `region`: region of surface
+    /// - `lifetime`: confinement lifetime
+    #[inline]
+    pub fn confine_pointer(
+        &self,
+        surface: &WlSurfaceRef,
+        pointer: &WlPointerRef,
+        region: Option<&WlRegionRef>,
+        lifetime: ZwpPointerConstraintsV1Lifetime,
+    ) -> ZwpConfinedPointerV1 {
+        let (arg1, arg2, arg3, arg4) = (surface, pointer, region, lifetime);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("pointer", obj2_lock.wl_proxy());
+        let obj3_lock = arg3.map(|arg3| proxy::lock(arg3));
+        let obj3 = obj3_lock
+            .map(|obj3_lock| check_argument_proxy("region", obj3_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+            wl_argument { o: obj3 },
+            wl_argument { u: arg4.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `noo?ou`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                ZwpConfinedPointerV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpConfinedPointerV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPointerConstraintsV1Ref {
+    /// lock pointer to a position
+    ///
+    /// The lock_pointer request lets the client request to disable movements of
+    /// the virtual pointer (i.e. the cursor), effectively locking the pointer
+    /// to a position. This request may not take effect immediately; in the
+    /// future, when the compositor deems implementation-specific constraints
+    /// are satisfied, the pointer lock will be activated and the compositor
+    /// sends a locked event.
+    ///
+    /// The protocol provides no guarantee that the constraints are ever
+    /// satisfied, and does not require the compositor to send an error if the
+    /// constraints cannot ever be satisfied. It is thus possible to request a
+    /// lock that will never activate.
+    ///
+    /// There may not be another pointer constraint of any kind requested or
+    /// active on the surface for any of the wl_pointer objects of the seat of
+    /// the passed pointer when requesting a lock. If there is, an error will be
+    /// raised. See general pointer lock documentation for more details.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the lock to activate. It is up to the compositor whether to
+    /// warp the pointer or require some kind of user interaction for the lock
+    /// to activate. If the region is null the surface input region is used.
+    ///
+    /// A surface may receive pointer focus without the lock being activated.
+    ///
+    /// The request creates a new object wp_locked_pointer which is used to
+    /// interact with the lock as well as receive updates about its state. See
+    /// the the description of wp_locked_pointer for further information.
+    ///
+    /// Note that while a pointer is locked, the wl_pointer objects of the
+    /// corresponding seat will not emit any wl_pointer.motion events, but
+    /// relative motion events will still be emitted via wp_relative_pointer
+    /// objects of the same seat. wl_pointer.axis and wl_pointer.button events
+    /// are unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: surface to lock pointer to
+    /// - `pointer`: the pointer that should be locked
+    /// - `region`: region of surface
+    /// - `lifetime`: lock lifetime
+    #[inline]
+    pub fn lock_pointer(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+        pointer: &WlPointerRef,
+        region: Option<&WlRegionRef>,
+        lifetime: ZwpPointerConstraintsV1Lifetime,
+    ) -> ZwpLockedPointerV1 {
+        let (arg1, arg2, arg3, arg4) = (surface, pointer, region, lifetime);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("pointer", obj2_lock.wl_proxy());
+        let obj3_lock = arg3.map(|arg3| proxy::lock(arg3));
+        let obj3 = obj3_lock
+            .map(|obj3_lock| check_argument_proxy("region", obj3_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+            wl_argument { o: obj3 },
+            wl_argument { u: arg4.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `noo?ou`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpLockedPointerV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLockedPointerV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// confine pointer to a region
+    ///
+    /// The confine_pointer request lets the client request to confine the
+    /// pointer cursor to a given region. This request may not take effect
+    /// immediately; in the future, when the compositor deems implementation-
+    /// specific constraints are satisfied, the pointer confinement will be
+    /// activated and the compositor sends a confined event.
+    ///
+    /// The intersection of the region passed with this request and the input
+    /// region of the surface is used to determine where the pointer must be
+    /// in order for the confinement to activate. It is up to the compositor
+    /// whether to warp the pointer or require some kind of user interaction for
+    /// the confinement to activate. If the region is null the surface input
+    /// region is used.
+    ///
+    /// The request will create a new object wp_confined_pointer which is used
+    /// to interact with the confinement as well as receive updates about its
+    /// state. See the the description of wp_confined_pointer for further
+    /// information.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: surface to lock pointer to
+    /// - `pointer`: the pointer that should be confined
+    /// - `region`: region of surface
+    /// - `lifetime`: confinement lifetime
+    #[inline]
+    pub fn confine_pointer(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+        pointer: &WlPointerRef,
+        region: Option<&WlRegionRef>,
+        lifetime: ZwpPointerConstraintsV1Lifetime,
+    ) -> ZwpConfinedPointerV1 {
+        let (arg1, arg2, arg3, arg4) = (surface, pointer, region, lifetime);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("pointer", obj2_lock.wl_proxy());
+        let obj3_lock = arg3.map(|arg3| proxy::lock(arg3));
+        let obj3 = obj3_lock
+            .map(|obj3_lock| check_argument_proxy("region", obj3_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+            wl_argument { o: obj3 },
+            wl_argument { u: arg4.0 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `noo?ou`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                ZwpConfinedPointerV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpConfinedPointerV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpPointerConstraintsV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPointerConstraintsV1EventHandler {}
+
+impl ZwpPointerConstraintsV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPointerConstraintsV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerConstraintsV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPointerConstraintsV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpPointerConstraintsV1] proxies.
+///
+/// This trait is the same as [ZwpPointerConstraintsV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerConstraintsV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpPointerConstraintsV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerConstraintsV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerConstraintsV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpPointerConstraintsV1EventHandler for MutEventHandler<H> where
+    H: ZwpPointerConstraintsV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpPointerConstraintsV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpPointerConstraintsV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpPointerConstraintsV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpPointerConstraintsV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpPointerConstraintsV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpPointerConstraintsV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPointerConstraintsV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_pointer_constraints_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPointerConstraintsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_pointer_constraints_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPointerConstraintsV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPointerConstraintsV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpPointerConstraintsV1 {
+    /// Since when the error.already_constrained enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_CONSTRAINED__SINCE: u32 = 1;
+
+    /// Since when the lifetime.oneshot enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__LIFETIME_ONESHOT__SINCE: u32 = 1;
+    /// Since when the lifetime.persistent enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__LIFETIME_PERSISTENT__SINCE: u32 = 1;
+}
+
+/// wp_pointer_constraints error values
+///
+/// These errors can be emitted in response to wp_pointer_constraints
+/// requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpPointerConstraintsV1Error(pub u32);
+
+impl ZwpPointerConstraintsV1Error {
+    /// pointer constraint already requested on that surface
+    #[allow(dead_code)]
+    pub const ALREADY_CONSTRAINED: Self = Self(1);
+}
+
+impl Debug for ZwpPointerConstraintsV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_CONSTRAINED => "ALREADY_CONSTRAINED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// constraint lifetime
+///
+/// These values represent different lifetime semantics. They are passed
+/// as arguments to the factory requests to specify how the constraint
+/// lifetimes should be managed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpPointerConstraintsV1Lifetime(pub u32);
+
+impl ZwpPointerConstraintsV1Lifetime {
+    /// the pointer constraint is defunct once deactivated
+    ///
+    /// A oneshot pointer constraint will never reactivate once it has been
+    /// deactivated. See the corresponding deactivation event
+    /// (wp_locked_pointer.unlocked and wp_confined_pointer.unconfined) for
+    /// details.
+    #[allow(dead_code)]
+    pub const ONESHOT: Self = Self(1);
+
+    /// the pointer constraint may reactivate
+    ///
+    /// A persistent pointer constraint may again reactivate once it has
+    /// been deactivated. See the corresponding deactivation event
+    /// (wp_locked_pointer.unlocked and wp_confined_pointer.unconfined) for
+    /// details.
+    #[allow(dead_code)]
+    pub const PERSISTENT: Self = Self(2);
+}
+
+impl Debug for ZwpPointerConstraintsV1Lifetime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ONESHOT => "ONESHOT",
+            Self::PERSISTENT => "PERSISTENT",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpPointerConstraintsV1 {}
+}
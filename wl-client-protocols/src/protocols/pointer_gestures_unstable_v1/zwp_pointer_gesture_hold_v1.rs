@@ -0,0 +1,1109 @@
+//! a hold gesture object
+//!
+//! A hold gesture object notifies a client about a single- or
+//! multi-finger hold gesture detected on an indirect input device such as
+//! a touchpad. The gesture is usually initiated by one or more fingers
+//! being held down without significant movement. The precise conditions
+//! of when such a gesture is detected are implementation-dependent.
+//!
+//! In particular, this gesture may be used to cancel kinetic scrolling.
+//!
+//! A hold gesture consists of two stages: begin and end. Unlike pinch and
+//! swipe there is no update stage.
+//! There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+//! same pointer/seat, how compositors prevent these situations is
+//! implementation-dependent.
+//!
+//! A gesture may be cancelled by the compositor or the hardware.
+//! Clients should not consider performing permanent or irreversible
+//! actions until the end of a gesture has been received.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_pointer_gesture_hold_v1".as_ptr(),
+    version: 3,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"begin".as_ptr(),
+                signature: c"uuou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] =
+                        [None, None, Some(WlSurface::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"end".as_ptr(),
+                signature: c"uui".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_pointer_gesture_hold_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_pointer_gesture_hold_v1",
+    protocol: "pointer_gestures_unstable_v1",
+    version: 3,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 3,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "begin",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fingers",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "end",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cancelled",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_pointer_gesture_hold_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerGestureHoldV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_pointer_gesture_hold_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerGestureHoldV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPointerGestureHoldV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPointerGestureHoldV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPointerGestureHoldV1 {
+    const INTERFACE: &'static str = "zwp_pointer_gesture_hold_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 3;
+
+    type Borrowed = ZwpPointerGestureHoldV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPointerGestureHoldV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPointerGestureHoldV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPointerGestureHoldV1Ref {
+    type Owned = ZwpPointerGestureHoldV1;
+}
+
+impl Deref for ZwpPointerGestureHoldV1 {
+    type Target = ZwpPointerGestureHoldV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPointerGestureHoldV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_gesture_hold_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPointerGestureHoldV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_gesture_hold_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPointerGestureHoldV1Ref> for ZwpPointerGestureHoldV1 {
+    fn eq(&self, other: &ZwpPointerGestureHoldV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPointerGestureHoldV1> for ZwpPointerGestureHoldV1Ref {
+    fn eq(&self, other: &ZwpPointerGestureHoldV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPointerGestureHoldV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 3;
+
+    /// destroy the hold gesture object
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpPointerGestureHoldV1 {
+    /// Since when the begin event is available.
+    #[allow(dead_code)]
+    pub const EVT__BEGIN__SINCE: u32 = 3;
+
+    /// Since when the end event is available.
+    #[allow(dead_code)]
+    pub const EVT__END__SINCE: u32 = 3;
+}
+
+/// An event handler for [ZwpPointerGestureHoldV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPointerGestureHoldV1EventHandler {
+    /// multi-finger hold begin
+    ///
+    /// This event is sent when a hold gesture is detected on the device.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `surface`:
+    /// - `fingers`: number of fingers
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn begin(
+        &self,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// multi-finger hold end
+    ///
+    /// This event is sent when a hold gesture ceases to
+    /// be valid. This may happen when the holding fingers are lifted or
+    /// the gesture is cancelled, for example if the fingers move past an
+    /// implementation-defined threshold, the finger count changes or the hold
+    /// gesture changes into a different type of gesture.
+    ///
+    /// When a gesture is cancelled, the client may need to undo state changes
+    /// caused by this gesture. What causes a gesture to be cancelled is
+    /// implementation-dependent.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `cancelled`: 1 if the gesture was cancelled, 0 otherwise
+    #[inline]
+    fn end(&self, _slf: &ZwpPointerGestureHoldV1Ref, serial: u32, time: u32, cancelled: i32) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+impl ZwpPointerGestureHoldV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPointerGestureHoldV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerGestureHoldV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPointerGestureHoldV1EventHandlerWithData<T> {
+    /// multi-finger hold begin
+    ///
+    /// This event is sent when a hold gesture is detected on the device.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `surface`:
+    /// - `fingers`: number of fingers
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn begin(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// multi-finger hold end
+    ///
+    /// This event is sent when a hold gesture ceases to
+    /// be valid. This may happen when the holding fingers are lifted or
+    /// the gesture is cancelled, for example if the fingers move past an
+    /// implementation-defined threshold, the finger count changes or the hold
+    /// gesture changes into a different type of gesture.
+    ///
+    /// When a gesture is cancelled, the client may need to undo state changes
+    /// caused by this gesture. What causes a gesture to be cancelled is
+    /// implementation-dependent.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `cancelled`: 1 if the gesture was cancelled, 0 otherwise
+    #[inline]
+    fn end(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+/// A mutable event handler for [ZwpPointerGestureHoldV1] proxies.
+///
+/// This trait is the same as [ZwpPointerGestureHoldV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerGestureHoldV1EventHandlerMut {
+    /// See [ZwpPointerGestureHoldV1EventHandler::begin].
+    #[inline]
+    fn begin(
+        &mut self,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// See [ZwpPointerGestureHoldV1EventHandler::end].
+    #[inline]
+    fn end(&mut self, _slf: &ZwpPointerGestureHoldV1Ref, serial: u32, time: u32, cancelled: i32) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+/// A mutable event handler for [ZwpPointerGestureHoldV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerGestureHoldV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerGestureHoldV1EventHandlerMutWithData<T> {
+    /// See [ZwpPointerGestureHoldV1EventHandler::begin].
+    #[inline]
+    fn begin(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// See [ZwpPointerGestureHoldV1EventHandler::end].
+    #[inline]
+    fn end(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+impl<H> ZwpPointerGestureHoldV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpPointerGestureHoldV1EventHandlerMut,
+{
+    #[inline]
+    fn begin(
+        &self,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        self.with(|h| h.begin(_slf, serial, time, surface, fingers))
+    }
+
+    #[inline]
+    fn end(&self, _slf: &ZwpPointerGestureHoldV1Ref, serial: u32, time: u32, cancelled: i32) {
+        self.with(|h| h.end(_slf, serial, time, cancelled))
+    }
+}
+
+impl<T, H> ZwpPointerGestureHoldV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpPointerGestureHoldV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn begin(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        self.with(|h| h.begin(_data, _slf, serial, time, surface, fingers))
+    }
+
+    #[inline]
+    fn end(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        self.with(|h| h.end(_data, _slf, serial, time, cancelled))
+    }
+}
+
+impl<T, U, H> ZwpPointerGestureHoldV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPointerGestureHoldV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn begin(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .begin(_data, _slf, serial, time, surface, fingers)
+    }
+
+    #[inline]
+    fn end(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().end(_data, _slf, serial, time, cancelled)
+    }
+}
+
+impl<T, U, H> ZwpPointerGestureHoldV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPointerGestureHoldV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn begin(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .begin(_data, _slf, serial, time, surface, fingers)
+    }
+
+    #[inline]
+    fn end(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGestureHoldV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().end(_data, _slf, serial, time, cancelled)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPointerGestureHoldV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpPointerGestureHoldV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an object
+                let arg2 = unsafe {
+                    if let Some(p) = NonNull::new(args[2].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg2 = arg2.as_ref().map(|arg2| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
+                });
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                self.0.begin(slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.end(slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_pointer_gesture_hold_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPointerGestureHoldV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpPointerGestureHoldV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an object
+                let arg2 = unsafe {
+                    if let Some(p) = NonNull::new(args[2].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg2 = arg2.as_ref().map(|arg2| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
+                });
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                self.0.begin(data, slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.end(data, slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_pointer_gesture_hold_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPointerGestureHoldV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPointerGestureHoldV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for begin events.
+    pub struct Begin<F>(F);
+    impl<F> ZwpPointerGestureHoldV1EventHandler for Begin<F>
+    where
+        F: Fn(&ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_slf, serial, time, surface, fingers)
+        }
+    }
+    impl<T, F> ZwpPointerGestureHoldV1EventHandlerWithData<T> for Begin<F>
+    where
+        F: Fn(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_data, _slf, serial, time, surface, fingers)
+        }
+    }
+
+    /// Event handler for end events.
+    pub struct End<F>(F);
+    impl<F> ZwpPointerGestureHoldV1EventHandler for End<F>
+    where
+        F: Fn(&ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(&self, _slf: &ZwpPointerGestureHoldV1Ref, serial: u32, time: u32, cancelled: i32) {
+            self.0(_slf, serial, time, cancelled)
+        }
+    }
+    impl<T, F> ZwpPointerGestureHoldV1EventHandlerWithData<T> for End<F>
+    where
+        F: Fn(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.0(_data, _slf, serial, time, cancelled)
+        }
+    }
+
+    /// Mutable event handler for begin events.
+    pub struct BeginMut<F>(F);
+    impl<F> ZwpPointerGestureHoldV1EventHandlerMut for BeginMut<F>
+    where
+        F: FnMut(&ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &mut self,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_slf, serial, time, surface, fingers)
+        }
+    }
+    impl<T, F> ZwpPointerGestureHoldV1EventHandlerMutWithData<T> for BeginMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_data, _slf, serial, time, surface, fingers)
+        }
+    }
+
+    /// Mutable event handler for end events.
+    pub struct EndMut<F>(F);
+    impl<F> ZwpPointerGestureHoldV1EventHandlerMut for EndMut<F>
+    where
+        F: FnMut(&ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(
+            &mut self,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.0(_slf, serial, time, cancelled)
+        }
+    }
+    impl<T, F> ZwpPointerGestureHoldV1EventHandlerMutWithData<T> for EndMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.0(_data, _slf, serial, time, cancelled)
+        }
+    }
+
+    impl ZwpPointerGestureHoldV1 {
+        /// Creates an event handler for begin events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_begin<F>(f: F) -> Begin<F>
+        where
+            F: Fn(&ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Begin(f)
+        }
+
+        /// Creates an event handler with mutable data for begin events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_begin_with_data<T, F>(f: F) -> Begin<F>
+        where
+            F: Fn(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Begin(f)
+        }
+
+        /// Creates an event handler for end events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_end<F>(f: F) -> End<F>
+        where
+            F: Fn(&ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+        {
+            End(f)
+        }
+
+        /// Creates an event handler with mutable data for end events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_end_with_data<T, F>(f: F) -> End<F>
+        where
+            F: Fn(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+        {
+            End(f)
+        }
+
+        /// Creates a mutable event handler for begin events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_begin_mut<F>(f: F) -> BeginMut<F>
+        where
+            F: FnMut(&ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            BeginMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for begin events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_begin_mut_with_data<T, F>(f: F) -> BeginMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            BeginMut(f)
+        }
+
+        /// Creates a mutable event handler for end events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_end_mut<F>(f: F) -> EndMut<F>
+        where
+            F: FnMut(&ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+        {
+            EndMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for end events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_end_mut_with_data<T, F>(f: F) -> EndMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+        {
+            EndMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                begin: Ignore,
+                end: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpPointerGestureHoldV1EventHandler for Ignore {}
+    impl<T> ZwpPointerGestureHoldV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpPointerGestureHoldV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        begin: H0,
+        end: H1,
+    }
+
+    impl<H0, H1> ZwpPointerGestureHoldV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpPointerGestureHoldV1EventHandler,
+        H1: ZwpPointerGestureHoldV1EventHandler,
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.begin.begin(_slf, serial, time, surface, fingers)
+        }
+
+        #[inline]
+        fn end(&self, _slf: &ZwpPointerGestureHoldV1Ref, serial: u32, time: u32, cancelled: i32) {
+            self.end.end(_slf, serial, time, cancelled)
+        }
+    }
+
+    impl<T, H0, H1> ZwpPointerGestureHoldV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpPointerGestureHoldV1EventHandlerWithData<T>,
+        H1: ZwpPointerGestureHoldV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.begin
+                .begin(_data, _slf, serial, time, surface, fingers)
+        }
+
+        #[inline]
+        fn end(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGestureHoldV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.end.end(_data, _slf, serial, time, cancelled)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for begin events.
+        #[allow(dead_code)]
+        pub fn on_begin<F>(self, f: F) -> Handler<Begin<F>, H1>
+        where
+            F: Fn(&ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Handler {
+                begin: Begin(f),
+                end: self.end,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for begin events.
+        #[allow(dead_code)]
+        pub fn on_begin_with_data<T, F>(self, f: F) -> Handler<Begin<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Handler {
+                begin: Begin(f),
+                end: self.end,
+            }
+        }
+
+        /// Sets the closure for end events.
+        #[allow(dead_code)]
+        pub fn on_end<F>(self, f: F) -> Handler<H0, End<F>>
+        where
+            F: Fn(&ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+        {
+            Handler {
+                begin: self.begin,
+                end: End(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for end events.
+        #[allow(dead_code)]
+        pub fn on_end_with_data<T, F>(self, f: F) -> Handler<H0, End<F>>
+        where
+            F: Fn(&mut T, &ZwpPointerGestureHoldV1Ref, u32, u32, i32),
+        {
+            Handler {
+                begin: self.begin,
+                end: End(f),
+            }
+        }
+    }
+}
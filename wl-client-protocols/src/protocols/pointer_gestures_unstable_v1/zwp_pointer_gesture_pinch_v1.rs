@@ -0,0 +1,1550 @@
+//! a pinch gesture object
+//!
+//! A pinch gesture object notifies a client about a multi-finger pinch
+//! gesture detected on an indirect input device such as a touchpad.
+//! The gesture is usually initiated by multiple fingers moving towards
+//! each other or away from each other, or by two or more fingers rotating
+//! around a logical center of gravity. The precise conditions of when
+//! such a gesture is detected are implementation-dependent.
+//!
+//! A gesture consists of three stages: begin, update (optional) and end.
+//! There cannot be multiple simultaneous hold, pinch or swipe gestures on a
+//! same pointer/seat, how compositors prevent these situations is
+//! implementation-dependent.
+//!
+//! A gesture may be cancelled by the compositor or the hardware.
+//! Clients should not consider performing permanent or irreversible
+//! actions until the end of a gesture has been received.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_pointer_gesture_pinch_v1".as_ptr(),
+    version: 2,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 3,
+    events: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"begin".as_ptr(),
+                signature: c"uuou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] =
+                        [None, None, Some(WlSurface::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"update".as_ptr(),
+                signature: c"uffff".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] =
+                        [None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"end".as_ptr(),
+                signature: c"uui".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_pointer_gesture_pinch_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_pointer_gesture_pinch_v1",
+    protocol: "pointer_gestures_unstable_v1",
+    version: 2,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "begin",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fingers",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "update",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "dx",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "dy",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "scale",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "rotation",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "end",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cancelled",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_pointer_gesture_pinch_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerGesturePinchV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_pointer_gesture_pinch_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerGesturePinchV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPointerGesturePinchV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPointerGesturePinchV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPointerGesturePinchV1 {
+    const INTERFACE: &'static str = "zwp_pointer_gesture_pinch_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = ZwpPointerGesturePinchV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPointerGesturePinchV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPointerGesturePinchV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPointerGesturePinchV1Ref {
+    type Owned = ZwpPointerGesturePinchV1;
+}
+
+impl Deref for ZwpPointerGesturePinchV1 {
+    type Target = ZwpPointerGesturePinchV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPointerGesturePinchV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_gesture_pinch_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPointerGesturePinchV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_gesture_pinch_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPointerGesturePinchV1Ref> for ZwpPointerGesturePinchV1 {
+    fn eq(&self, other: &ZwpPointerGesturePinchV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPointerGesturePinchV1> for ZwpPointerGesturePinchV1Ref {
+    fn eq(&self, other: &ZwpPointerGesturePinchV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPointerGesturePinchV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the pinch gesture object
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpPointerGesturePinchV1 {
+    /// Since when the begin event is available.
+    #[allow(dead_code)]
+    pub const EVT__BEGIN__SINCE: u32 = 1;
+
+    /// Since when the update event is available.
+    #[allow(dead_code)]
+    pub const EVT__UPDATE__SINCE: u32 = 1;
+
+    /// Since when the end event is available.
+    #[allow(dead_code)]
+    pub const EVT__END__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpPointerGesturePinchV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPointerGesturePinchV1EventHandler {
+    /// multi-finger pinch begin
+    ///
+    /// This event is sent when a multi-finger pinch gesture is detected
+    /// on the device.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `surface`:
+    /// - `fingers`: number of fingers
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn begin(
+        &self,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// multi-finger pinch motion
+    ///
+    /// This event is sent when a multi-finger pinch gesture changes the
+    /// position of the logical center, the rotation or the relative scale.
+    ///
+    /// The dx and dy coordinates are relative coordinates in the
+    /// surface coordinate space of the logical center of the gesture.
+    ///
+    /// The scale factor is an absolute scale compared to the
+    /// pointer_gesture_pinch.begin event, e.g. a scale of 2 means the fingers
+    /// are now twice as far apart as on pointer_gesture_pinch.begin.
+    ///
+    /// The rotation is the relative angle in degrees clockwise compared to the previous
+    /// pointer_gesture_pinch.begin or pointer_gesture_pinch.update event.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    /// - `dx`: delta x coordinate in surface coordinate space
+    /// - `dy`: delta y coordinate in surface coordinate space
+    /// - `scale`: scale relative to the initial finger position
+    /// - `rotation`: angle in degrees cw relative to the previous event
+    #[inline]
+    fn update(
+        &self,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        let _ = time;
+        let _ = dx;
+        let _ = dy;
+        let _ = scale;
+        let _ = rotation;
+    }
+
+    /// multi-finger pinch end
+    ///
+    /// This event is sent when a multi-finger pinch gesture ceases to
+    /// be valid. This may happen when one or more fingers are lifted or
+    /// the gesture is cancelled.
+    ///
+    /// When a gesture is cancelled, the client should undo state changes
+    /// caused by this gesture. What causes a gesture to be cancelled is
+    /// implementation-dependent.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `cancelled`: 1 if the gesture was cancelled, 0 otherwise
+    #[inline]
+    fn end(&self, _slf: &ZwpPointerGesturePinchV1Ref, serial: u32, time: u32, cancelled: i32) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+impl ZwpPointerGesturePinchV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPointerGesturePinchV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerGesturePinchV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPointerGesturePinchV1EventHandlerWithData<T> {
+    /// multi-finger pinch begin
+    ///
+    /// This event is sent when a multi-finger pinch gesture is detected
+    /// on the device.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `surface`:
+    /// - `fingers`: number of fingers
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn begin(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// multi-finger pinch motion
+    ///
+    /// This event is sent when a multi-finger pinch gesture changes the
+    /// position of the logical center, the rotation or the relative scale.
+    ///
+    /// The dx and dy coordinates are relative coordinates in the
+    /// surface coordinate space of the logical center of the gesture.
+    ///
+    /// The scale factor is an absolute scale compared to the
+    /// pointer_gesture_pinch.begin event, e.g. a scale of 2 means the fingers
+    /// are now twice as far apart as on pointer_gesture_pinch.begin.
+    ///
+    /// The rotation is the relative angle in degrees clockwise compared to the previous
+    /// pointer_gesture_pinch.begin or pointer_gesture_pinch.update event.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    /// - `dx`: delta x coordinate in surface coordinate space
+    /// - `dy`: delta y coordinate in surface coordinate space
+    /// - `scale`: scale relative to the initial finger position
+    /// - `rotation`: angle in degrees cw relative to the previous event
+    #[inline]
+    fn update(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        let _ = time;
+        let _ = dx;
+        let _ = dy;
+        let _ = scale;
+        let _ = rotation;
+    }
+
+    /// multi-finger pinch end
+    ///
+    /// This event is sent when a multi-finger pinch gesture ceases to
+    /// be valid. This may happen when one or more fingers are lifted or
+    /// the gesture is cancelled.
+    ///
+    /// When a gesture is cancelled, the client should undo state changes
+    /// caused by this gesture. What causes a gesture to be cancelled is
+    /// implementation-dependent.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`: timestamp with millisecond granularity
+    /// - `cancelled`: 1 if the gesture was cancelled, 0 otherwise
+    #[inline]
+    fn end(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+/// A mutable event handler for [ZwpPointerGesturePinchV1] proxies.
+///
+/// This trait is the same as [ZwpPointerGesturePinchV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerGesturePinchV1EventHandlerMut {
+    /// See [ZwpPointerGesturePinchV1EventHandler::begin].
+    #[inline]
+    fn begin(
+        &mut self,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// See [ZwpPointerGesturePinchV1EventHandler::update].
+    #[inline]
+    fn update(
+        &mut self,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        let _ = time;
+        let _ = dx;
+        let _ = dy;
+        let _ = scale;
+        let _ = rotation;
+    }
+
+    /// See [ZwpPointerGesturePinchV1EventHandler::end].
+    #[inline]
+    fn end(&mut self, _slf: &ZwpPointerGesturePinchV1Ref, serial: u32, time: u32, cancelled: i32) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+/// A mutable event handler for [ZwpPointerGesturePinchV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerGesturePinchV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerGesturePinchV1EventHandlerMutWithData<T> {
+    /// See [ZwpPointerGesturePinchV1EventHandler::begin].
+    #[inline]
+    fn begin(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = surface;
+        let _ = fingers;
+    }
+
+    /// See [ZwpPointerGesturePinchV1EventHandler::update].
+    #[inline]
+    fn update(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        let _ = time;
+        let _ = dx;
+        let _ = dy;
+        let _ = scale;
+        let _ = rotation;
+    }
+
+    /// See [ZwpPointerGesturePinchV1EventHandler::end].
+    #[inline]
+    fn end(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = cancelled;
+    }
+}
+
+impl<H> ZwpPointerGesturePinchV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpPointerGesturePinchV1EventHandlerMut,
+{
+    #[inline]
+    fn begin(
+        &self,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        self.with(|h| h.begin(_slf, serial, time, surface, fingers))
+    }
+
+    #[inline]
+    fn update(
+        &self,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        self.with(|h| h.update(_slf, time, dx, dy, scale, rotation))
+    }
+
+    #[inline]
+    fn end(&self, _slf: &ZwpPointerGesturePinchV1Ref, serial: u32, time: u32, cancelled: i32) {
+        self.with(|h| h.end(_slf, serial, time, cancelled))
+    }
+}
+
+impl<T, H> ZwpPointerGesturePinchV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpPointerGesturePinchV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn begin(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        self.with(|h| h.begin(_data, _slf, serial, time, surface, fingers))
+    }
+
+    #[inline]
+    fn update(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        self.with(|h| h.update(_data, _slf, time, dx, dy, scale, rotation))
+    }
+
+    #[inline]
+    fn end(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        self.with(|h| h.end(_data, _slf, serial, time, cancelled))
+    }
+}
+
+impl<T, U, H> ZwpPointerGesturePinchV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPointerGesturePinchV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn begin(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .begin(_data, _slf, serial, time, surface, fingers)
+    }
+
+    #[inline]
+    fn update(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .update(_data, _slf, time, dx, dy, scale, rotation)
+    }
+
+    #[inline]
+    fn end(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().end(_data, _slf, serial, time, cancelled)
+    }
+}
+
+impl<T, U, H> ZwpPointerGesturePinchV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPointerGesturePinchV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn begin(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        surface: Option<&WlSurfaceRef>,
+        fingers: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .begin(_data, _slf, serial, time, surface, fingers)
+    }
+
+    #[inline]
+    fn update(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        time: u32,
+        dx: Fixed,
+        dy: Fixed,
+        scale: Fixed,
+        rotation: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .update(_data, _slf, time, dx, dy, scale, rotation)
+    }
+
+    #[inline]
+    fn end(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPointerGesturePinchV1Ref,
+        serial: u32,
+        time: u32,
+        cancelled: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().end(_data, _slf, serial, time, cancelled)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPointerGesturePinchV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpPointerGesturePinchV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an object
+                let arg2 = unsafe {
+                    if let Some(p) = NonNull::new(args[2].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg2 = arg2.as_ref().map(|arg2| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
+                });
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                self.0.begin(slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 5 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 5]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                // SAFETY: - INTERFACE requires that args[3] contains a fixed
+                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
+                // SAFETY: - INTERFACE requires that args[4] contains a fixed
+                let arg4 = unsafe { Fixed::from_wire(args[4].f) };
+                self.0.update(slf, arg0, arg1, arg2, arg3, arg4);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.end(slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_pointer_gesture_pinch_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPointerGesturePinchV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpPointerGesturePinchV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an object
+                let arg2 = unsafe {
+                    if let Some(p) = NonNull::new(args[2].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg2 = arg2.as_ref().map(|arg2| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
+                });
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                self.0.begin(data, slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 5 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 5]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                // SAFETY: - INTERFACE requires that args[3] contains a fixed
+                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
+                // SAFETY: - INTERFACE requires that args[4] contains a fixed
+                let arg4 = unsafe { Fixed::from_wire(args[4].f) };
+                self.0.update(data, slf, arg0, arg1, arg2, arg3, arg4);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.end(data, slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_pointer_gesture_pinch_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPointerGesturePinchV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPointerGesturePinchV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for begin events.
+    pub struct Begin<F>(F);
+    impl<F> ZwpPointerGesturePinchV1EventHandler for Begin<F>
+    where
+        F: Fn(&ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_slf, serial, time, surface, fingers)
+        }
+    }
+    impl<T, F> ZwpPointerGesturePinchV1EventHandlerWithData<T> for Begin<F>
+    where
+        F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_data, _slf, serial, time, surface, fingers)
+        }
+    }
+
+    /// Event handler for update events.
+    pub struct Update<F>(F);
+    impl<F> ZwpPointerGesturePinchV1EventHandler for Update<F>
+    where
+        F: Fn(&ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn update(
+            &self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            time: u32,
+            dx: Fixed,
+            dy: Fixed,
+            scale: Fixed,
+            rotation: Fixed,
+        ) {
+            self.0(_slf, time, dx, dy, scale, rotation)
+        }
+    }
+    impl<T, F> ZwpPointerGesturePinchV1EventHandlerWithData<T> for Update<F>
+    where
+        F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn update(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            time: u32,
+            dx: Fixed,
+            dy: Fixed,
+            scale: Fixed,
+            rotation: Fixed,
+        ) {
+            self.0(_data, _slf, time, dx, dy, scale, rotation)
+        }
+    }
+
+    /// Event handler for end events.
+    pub struct End<F>(F);
+    impl<F> ZwpPointerGesturePinchV1EventHandler for End<F>
+    where
+        F: Fn(&ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(&self, _slf: &ZwpPointerGesturePinchV1Ref, serial: u32, time: u32, cancelled: i32) {
+            self.0(_slf, serial, time, cancelled)
+        }
+    }
+    impl<T, F> ZwpPointerGesturePinchV1EventHandlerWithData<T> for End<F>
+    where
+        F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.0(_data, _slf, serial, time, cancelled)
+        }
+    }
+
+    /// Mutable event handler for begin events.
+    pub struct BeginMut<F>(F);
+    impl<F> ZwpPointerGesturePinchV1EventHandlerMut for BeginMut<F>
+    where
+        F: FnMut(&ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &mut self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_slf, serial, time, surface, fingers)
+        }
+    }
+    impl<T, F> ZwpPointerGesturePinchV1EventHandlerMutWithData<T> for BeginMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+    {
+        #[inline]
+        fn begin(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.0(_data, _slf, serial, time, surface, fingers)
+        }
+    }
+
+    /// Mutable event handler for update events.
+    pub struct UpdateMut<F>(F);
+    impl<F> ZwpPointerGesturePinchV1EventHandlerMut for UpdateMut<F>
+    where
+        F: FnMut(&ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn update(
+            &mut self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            time: u32,
+            dx: Fixed,
+            dy: Fixed,
+            scale: Fixed,
+            rotation: Fixed,
+        ) {
+            self.0(_slf, time, dx, dy, scale, rotation)
+        }
+    }
+    impl<T, F> ZwpPointerGesturePinchV1EventHandlerMutWithData<T> for UpdateMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn update(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            time: u32,
+            dx: Fixed,
+            dy: Fixed,
+            scale: Fixed,
+            rotation: Fixed,
+        ) {
+            self.0(_data, _slf, time, dx, dy, scale, rotation)
+        }
+    }
+
+    /// Mutable event handler for end events.
+    pub struct EndMut<F>(F);
+    impl<F> ZwpPointerGesturePinchV1EventHandlerMut for EndMut<F>
+    where
+        F: FnMut(&ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(
+            &mut self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.0(_slf, serial, time, cancelled)
+        }
+    }
+    impl<T, F> ZwpPointerGesturePinchV1EventHandlerMutWithData<T> for EndMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+    {
+        #[inline]
+        fn end(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.0(_data, _slf, serial, time, cancelled)
+        }
+    }
+
+    impl ZwpPointerGesturePinchV1 {
+        /// Creates an event handler for begin events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_begin<F>(f: F) -> Begin<F>
+        where
+            F: Fn(&ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Begin(f)
+        }
+
+        /// Creates an event handler with mutable data for begin events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_begin_with_data<T, F>(f: F) -> Begin<F>
+        where
+            F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Begin(f)
+        }
+
+        /// Creates an event handler for update events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_update<F>(f: F) -> Update<F>
+        where
+            F: Fn(&ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            Update(f)
+        }
+
+        /// Creates an event handler with mutable data for update events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_update_with_data<T, F>(f: F) -> Update<F>
+        where
+            F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            Update(f)
+        }
+
+        /// Creates an event handler for end events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_end<F>(f: F) -> End<F>
+        where
+            F: Fn(&ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+        {
+            End(f)
+        }
+
+        /// Creates an event handler with mutable data for end events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_end_with_data<T, F>(f: F) -> End<F>
+        where
+            F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+        {
+            End(f)
+        }
+
+        /// Creates a mutable event handler for begin events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_begin_mut<F>(f: F) -> BeginMut<F>
+        where
+            F: FnMut(&ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            BeginMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for begin events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_begin_mut_with_data<T, F>(f: F) -> BeginMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            BeginMut(f)
+        }
+
+        /// Creates a mutable event handler for update events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_update_mut<F>(f: F) -> UpdateMut<F>
+        where
+            F: FnMut(&ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            UpdateMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for update events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_update_mut_with_data<T, F>(f: F) -> UpdateMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            UpdateMut(f)
+        }
+
+        /// Creates a mutable event handler for end events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_end_mut<F>(f: F) -> EndMut<F>
+        where
+            F: FnMut(&ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+        {
+            EndMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for end events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_end_mut_with_data<T, F>(f: F) -> EndMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+        {
+            EndMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                begin: Ignore,
+                update: Ignore,
+                end: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpPointerGesturePinchV1EventHandler for Ignore {}
+    impl<T> ZwpPointerGesturePinchV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpPointerGesturePinchV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        begin: H0,
+        update: H1,
+        end: H2,
+    }
+
+    impl<H0, H1, H2> ZwpPointerGesturePinchV1EventHandler for Handler<H0, H1, H2>
+    where
+        H0: ZwpPointerGesturePinchV1EventHandler,
+        H1: ZwpPointerGesturePinchV1EventHandler,
+        H2: ZwpPointerGesturePinchV1EventHandler,
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.begin.begin(_slf, serial, time, surface, fingers)
+        }
+
+        #[inline]
+        fn update(
+            &self,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            time: u32,
+            dx: Fixed,
+            dy: Fixed,
+            scale: Fixed,
+            rotation: Fixed,
+        ) {
+            self.update.update(_slf, time, dx, dy, scale, rotation)
+        }
+
+        #[inline]
+        fn end(&self, _slf: &ZwpPointerGesturePinchV1Ref, serial: u32, time: u32, cancelled: i32) {
+            self.end.end(_slf, serial, time, cancelled)
+        }
+    }
+
+    impl<T, H0, H1, H2> ZwpPointerGesturePinchV1EventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: ZwpPointerGesturePinchV1EventHandlerWithData<T>,
+        H1: ZwpPointerGesturePinchV1EventHandlerWithData<T>,
+        H2: ZwpPointerGesturePinchV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn begin(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            surface: Option<&WlSurfaceRef>,
+            fingers: u32,
+        ) {
+            self.begin
+                .begin(_data, _slf, serial, time, surface, fingers)
+        }
+
+        #[inline]
+        fn update(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            time: u32,
+            dx: Fixed,
+            dy: Fixed,
+            scale: Fixed,
+            rotation: Fixed,
+        ) {
+            self.update
+                .update(_data, _slf, time, dx, dy, scale, rotation)
+        }
+
+        #[inline]
+        fn end(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPointerGesturePinchV1Ref,
+            serial: u32,
+            time: u32,
+            cancelled: i32,
+        ) {
+            self.end.end(_data, _slf, serial, time, cancelled)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for begin events.
+        #[allow(dead_code)]
+        pub fn on_begin<F>(self, f: F) -> Handler<Begin<F>, H1, H2>
+        where
+            F: Fn(&ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Handler {
+                begin: Begin(f),
+                update: self.update,
+                end: self.end,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for begin events.
+        #[allow(dead_code)]
+        pub fn on_begin_with_data<T, F>(self, f: F) -> Handler<Begin<F>, H1, H2>
+        where
+            F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, Option<&WlSurfaceRef>, u32),
+        {
+            Handler {
+                begin: Begin(f),
+                update: self.update,
+                end: self.end,
+            }
+        }
+
+        /// Sets the closure for update events.
+        #[allow(dead_code)]
+        pub fn on_update<F>(self, f: F) -> Handler<H0, Update<F>, H2>
+        where
+            F: Fn(&ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            Handler {
+                begin: self.begin,
+                update: Update(f),
+                end: self.end,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for update events.
+        #[allow(dead_code)]
+        pub fn on_update_with_data<T, F>(self, f: F) -> Handler<H0, Update<F>, H2>
+        where
+            F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            Handler {
+                begin: self.begin,
+                update: Update(f),
+                end: self.end,
+            }
+        }
+
+        /// Sets the closure for end events.
+        #[allow(dead_code)]
+        pub fn on_end<F>(self, f: F) -> Handler<H0, H1, End<F>>
+        where
+            F: Fn(&ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+        {
+            Handler {
+                begin: self.begin,
+                update: self.update,
+                end: End(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for end events.
+        #[allow(dead_code)]
+        pub fn on_end_with_data<T, F>(self, f: F) -> Handler<H0, H1, End<F>>
+        where
+            F: Fn(&mut T, &ZwpPointerGesturePinchV1Ref, u32, u32, i32),
+        {
+            Handler {
+                begin: self.begin,
+                update: self.update,
+                end: End(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,617 @@
+//! touchpad gestures
+//!
+//! A global interface to provide semantic touchpad gestures for a given
+//! pointer.
+//!
+//! Three gestures are currently supported: swipe, pinch, and hold.
+//! Pinch and swipe gestures follow a three-stage cycle: begin, update,
+//! end. Hold gestures follow a two-stage cycle: begin and end. All
+//! gestures are identified by a unique id.
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_pointer_gestures_v1".as_ptr(),
+    version: 3,
+    method_count: 4,
+    methods: {
+        static MESSAGES: [wl_message; 4] = [
+            wl_message {
+                name: c"get_swipe_gesture".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpPointerGestureSwipeV1::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_pinch_gesture".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpPointerGesturePinchV1::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"release".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_hold_gesture".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpPointerGestureHoldV1::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_pointer_gestures_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_pointer_gestures_v1",
+    protocol: "pointer_gestures_unstable_v1",
+    version: 3,
+    requests: &[
+        info::MessageInfo {
+            name: "get_swipe_gesture",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_pointer_gesture_swipe_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "get_pinch_gesture",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_pointer_gesture_pinch_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "release",
+            since: 2,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_hold_gesture",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_pointer_gesture_hold_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_pointer_gestures_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerGesturesV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_pointer_gestures_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPointerGesturesV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPointerGesturesV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPointerGesturesV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPointerGesturesV1 {
+    const INTERFACE: &'static str = "zwp_pointer_gestures_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 3;
+
+    type Borrowed = ZwpPointerGesturesV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPointerGesturesV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPointerGesturesV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPointerGesturesV1Ref {
+    type Owned = ZwpPointerGesturesV1;
+}
+
+impl Deref for ZwpPointerGesturesV1 {
+    type Target = ZwpPointerGesturesV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPointerGesturesV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_gestures_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPointerGesturesV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_pointer_gestures_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPointerGesturesV1Ref> for ZwpPointerGesturesV1 {
+    fn eq(&self, other: &ZwpPointerGesturesV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPointerGesturesV1> for ZwpPointerGesturesV1Ref {
+    fn eq(&self, other: &ZwpPointerGesturesV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPointerGesturesV1 {
+    /// Since when the get_swipe_gesture request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SWIPE_GESTURE__SINCE: u32 = 1;
+
+    /// get swipe gesture
+    ///
+    /// Create a swipe gesture object. See the
+    /// wl_pointer_gesture_swipe interface for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `pointer`:
+    #[inline]
+    pub fn get_swipe_gesture(&self, pointer: &WlPointerRef) -> ZwpPointerGestureSwipeV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ZwpPointerGestureSwipeV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPointerGestureSwipeV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_pinch_gesture request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_PINCH_GESTURE__SINCE: u32 = 1;
+
+    /// get pinch gesture
+    ///
+    /// Create a pinch gesture object. See the
+    /// wl_pointer_gesture_pinch interface for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `pointer`:
+    #[inline]
+    pub fn get_pinch_gesture(&self, pointer: &WlPointerRef) -> ZwpPointerGesturePinchV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpPointerGesturePinchV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPointerGesturePinchV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the release request is available.
+    #[allow(dead_code)]
+    pub const REQ__RELEASE__SINCE: u32 = 2;
+
+    /// destroy the pointer gesture object
+    ///
+    /// Destroy the pointer gesture object. Swipe, pinch and hold objects
+    /// created via this gesture object remain valid.
+    #[inline]
+    pub fn release(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 4
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(2, &mut args);
+        }
+    }
+
+    /// Since when the get_hold_gesture request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_HOLD_GESTURE__SINCE: u32 = 3;
+
+    /// get hold gesture
+    ///
+    /// Create a hold gesture object. See the
+    /// wl_pointer_gesture_hold interface for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `pointer`:
+    #[inline]
+    pub fn get_hold_gesture(&self, pointer: &WlPointerRef) -> ZwpPointerGestureHoldV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                3,
+                &mut args,
+                ZwpPointerGestureHoldV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPointerGestureHoldV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPointerGesturesV1Ref {
+    /// get swipe gesture
+    ///
+    /// Create a swipe gesture object. See the
+    /// wl_pointer_gesture_swipe interface for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `pointer`:
+    #[inline]
+    pub fn get_swipe_gesture(
+        &self,
+        _queue: &Queue,
+        pointer: &WlPointerRef,
+    ) -> ZwpPointerGestureSwipeV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ZwpPointerGestureSwipeV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPointerGestureSwipeV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// get pinch gesture
+    ///
+    /// Create a pinch gesture object. See the
+    /// wl_pointer_gesture_pinch interface for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `pointer`:
+    #[inline]
+    pub fn get_pinch_gesture(
+        &self,
+        _queue: &Queue,
+        pointer: &WlPointerRef,
+    ) -> ZwpPointerGesturePinchV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpPointerGesturePinchV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPointerGesturePinchV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// get hold gesture
+    ///
+    /// Create a hold gesture object. See the
+    /// wl_pointer_gesture_hold interface for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `pointer`:
+    #[inline]
+    pub fn get_hold_gesture(
+        &self,
+        _queue: &Queue,
+        pointer: &WlPointerRef,
+    ) -> ZwpPointerGestureHoldV1 {
+        let (arg1,) = (pointer,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 4
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                3,
+                &mut args,
+                ZwpPointerGestureHoldV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPointerGestureHoldV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpPointerGesturesV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPointerGesturesV1EventHandler {}
+
+impl ZwpPointerGesturesV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPointerGesturesV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerGesturesV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPointerGesturesV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpPointerGesturesV1] proxies.
+///
+/// This trait is the same as [ZwpPointerGesturesV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerGesturesV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpPointerGesturesV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPointerGesturesV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPointerGesturesV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpPointerGesturesV1EventHandler for MutEventHandler<H> where
+    H: ZwpPointerGesturesV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpPointerGesturesV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpPointerGesturesV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpPointerGesturesV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpPointerGesturesV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpPointerGesturesV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpPointerGesturesV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPointerGesturesV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_pointer_gestures_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPointerGesturesV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_pointer_gestures_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPointerGesturesV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPointerGesturesV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpPointerGesturesV1 {}
+}
@@ -8,12 +8,11 @@
 #![allow(rustdoc::bare_urls)]
 #![allow(rustdoc::invalid_rust_codeblocks)]
 
-pub mod wl_callback;
-pub mod wl_display;
-pub mod wl_dummy;
-pub mod wl_keyboard;
-pub mod wl_registry;
-pub mod wl_root;
-pub mod wl_seat;
-pub mod wl_string;
-pub mod wl_surface;
+pub mod wp_pointer_warp_v1;
+
+/// Metadata about the pointer_warp_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "pointer_warp_v1",
+    interfaces: &[&wp_pointer_warp_v1::INFO],
+};
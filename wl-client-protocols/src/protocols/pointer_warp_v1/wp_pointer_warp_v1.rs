@@ -0,0 +1,406 @@
+//! reposition the pointer to a location on a surface
+//!
+//! This global interface allows applications to request the pointer to be
+//! moved to a position relative to a wl_surface.
+//!
+//! Note that if the desired behavior is to constrain the pointer to an area
+//! or lock it to a position, this protocol does not provide a reliable way
+//! to do that. The pointer constraint and pointer lock protocols should be
+//! used for those use cases instead.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_pointer_warp_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"warp_pointer".as_ptr(),
+                signature: c"ooffu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] = [
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlPointer::WL_INTERFACE),
+                        None,
+                        None,
+                        None,
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_pointer_warp_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_pointer_warp_v1",
+    protocol: "pointer_warp_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "warp_pointer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pointer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_pointer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned wp_pointer_warp_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpPointerWarpV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_pointer_warp_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpPointerWarpV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpPointerWarpV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpPointerWarpV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpPointerWarpV1 {
+    const INTERFACE: &'static str = "wp_pointer_warp_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpPointerWarpV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpPointerWarpV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpPointerWarpV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpPointerWarpV1Ref {
+    type Owned = WpPointerWarpV1;
+}
+
+impl Deref for WpPointerWarpV1 {
+    type Target = WpPointerWarpV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpPointerWarpV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_pointer_warp_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpPointerWarpV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_pointer_warp_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpPointerWarpV1Ref> for WpPointerWarpV1 {
+    fn eq(&self, other: &WpPointerWarpV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpPointerWarpV1> for WpPointerWarpV1Ref {
+    fn eq(&self, other: &WpPointerWarpV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpPointerWarpV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the warp manager
+    ///
+    /// Destroy the pointer warp manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpPointerWarpV1Ref {
+    /// reposition the pointer
+    ///
+    /// Request the compositor to move the pointer to a surface-local position.
+    /// Whether or not the compositor honors the request is implementation defined,
+    /// but it should
+    /// - honor it if the surface has pointer focus, including
+    ///   when it has an implicit pointer grab
+    /// - reject it if the enter serial is incorrect
+    /// - reject it if the requested position is outside of the surface
+    ///
+    /// Note that the enter serial is valid for any surface of the client,
+    /// and does not have to be from the surface the pointer is warped to.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: surface to position the pointer on
+    /// - `pointer`: the pointer that should be repositioned
+    /// - `x`:
+    /// - `y`:
+    /// - `serial`: serial number of the enter event
+    #[inline]
+    pub fn warp_pointer(
+        &self,
+        surface: &WlSurfaceRef,
+        pointer: &WlPointerRef,
+        x: Fixed,
+        y: Fixed,
+        serial: u32,
+    ) {
+        let (arg0, arg1, arg2, arg3, arg4) = (surface, pointer, x, y, serial);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("pointer", obj1_lock.wl_proxy());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { o: obj1 },
+            wl_argument { f: arg2.to_wire() },
+            wl_argument { f: arg3.to_wire() },
+            wl_argument { u: arg4 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `ooffu`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpPointerWarpV1] proxies.
+#[allow(dead_code)]
+pub trait WpPointerWarpV1EventHandler {}
+
+impl WpPointerWarpV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpPointerWarpV1] proxies with mutable data.
+///
+/// This trait is the same as [WpPointerWarpV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpPointerWarpV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpPointerWarpV1] proxies.
+///
+/// This trait is the same as [WpPointerWarpV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpPointerWarpV1EventHandlerMut {}
+
+/// A mutable event handler for [WpPointerWarpV1] proxies with mutable data.
+///
+/// This trait is the same as [WpPointerWarpV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpPointerWarpV1EventHandlerMutWithData<T> {}
+
+impl<H> WpPointerWarpV1EventHandler for MutEventHandler<H> where H: WpPointerWarpV1EventHandlerMut {}
+
+impl<T, H> WpPointerWarpV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpPointerWarpV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpPointerWarpV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpPointerWarpV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpPointerWarpV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpPointerWarpV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpPointerWarpV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_pointer_warp_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpPointerWarpV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_pointer_warp_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpPointerWarpV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpPointerWarpV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpPointerWarpV1 {}
+}
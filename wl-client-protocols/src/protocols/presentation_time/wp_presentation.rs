@@ -0,0 +1,807 @@
+//! timed presentation related wl_surface requests
+//!
+//! The main feature of this interface is accurate presentation
+//! timing feedback to ensure smooth video playback while maintaining
+//! audio/video synchronization. Some features use the concept of a
+//! presentation clock, which is defined in the
+//! presentation.clock_id event.
+//!
+//! A content update for a wl_surface is submitted by a
+//! wl_surface.commit request. Request 'feedback' associates with
+//! the wl_surface.commit and provides feedback on the content
+//! update, particularly the final realized presentation time.
+//!
+//!
+//!
+//! When the final realized presentation time is available, e.g.
+//! after a framebuffer flip completes, the requested
+//! presentation_feedback.presented events are sent. The final
+//! presentation time can differ from the compositor's predicted
+//! display update time and the update's target time, especially
+//! when the compositor misses its target vertical blanking period.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_presentation".as_ptr(),
+    version: 2,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"feedback".as_ptr(),
+                signature: c"on".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WpPresentationFeedback::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"clock_id".as_ptr(),
+            signature: c"u".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_presentation interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_presentation",
+    protocol: "presentation_time",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "feedback",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "callback",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_presentation_feedback"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "clock_id",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "clk_id",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_timestamp",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_flag",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_presentation proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpPresentation {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_presentation proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpPresentationRef {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpPresentation is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpPresentation {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpPresentation {
+    const INTERFACE: &'static str = "wp_presentation";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpPresentationRef;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpPresentationRef is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpPresentationRef {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpPresentationRef {
+    type Owned = WpPresentation;
+}
+
+impl Deref for WpPresentation {
+    type Target = WpPresentationRef;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpPresentation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_presentation#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpPresentationRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_presentation#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpPresentationRef> for WpPresentation {
+    fn eq(&self, other: &WpPresentationRef) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpPresentation> for WpPresentationRef {
+    fn eq(&self, other: &WpPresentation) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpPresentation {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// unbind from the presentation interface
+    ///
+    /// Informs the server that the client will no longer be using
+    /// this protocol object. Existing objects created by this object
+    /// are not affected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the feedback request is available.
+    #[allow(dead_code)]
+    pub const REQ__FEEDBACK__SINCE: u32 = 1;
+
+    /// request presentation feedback information
+    ///
+    /// Request presentation feedback for the current content submission
+    /// on the given surface. This creates a new presentation_feedback
+    /// object, which will deliver the feedback information once. If
+    /// multiple presentation_feedback objects are created for the same
+    /// submission, they will all deliver the same information.
+    ///
+    /// For details on what information is returned, see the
+    /// presentation_feedback interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: target surface
+    #[inline]
+    pub fn feedback(&self, surface: &WlSurfaceRef) -> WpPresentationFeedback {
+        let (arg0,) = (surface,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `on`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpPresentationFeedback::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpPresentationFeedback::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpPresentationRef {
+    /// request presentation feedback information
+    ///
+    /// Request presentation feedback for the current content submission
+    /// on the given surface. This creates a new presentation_feedback
+    /// object, which will deliver the feedback information once. If
+    /// multiple presentation_feedback objects are created for the same
+    /// submission, they will all deliver the same information.
+    ///
+    /// For details on what information is returned, see the
+    /// presentation_feedback interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: target surface
+    #[inline]
+    pub fn feedback(&self, _queue: &Queue, surface: &WlSurfaceRef) -> WpPresentationFeedback {
+        let (arg0,) = (surface,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `on`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpPresentationFeedback::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpPresentationFeedback::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+impl WpPresentation {
+    /// Since when the clock_id event is available.
+    #[allow(dead_code)]
+    pub const EVT__CLOCK_ID__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpPresentation] proxies.
+#[allow(dead_code)]
+pub trait WpPresentationEventHandler {
+    /// clock ID for timestamps
+    ///
+    /// This event tells the client in which clock domain the
+    /// compositor interprets the timestamps used by the presentation
+    /// extension. This clock is called the presentation clock.
+    ///
+    /// The compositor sends this event when the client binds to the
+    /// presentation interface. The presentation clock does not change
+    /// during the lifetime of the client connection.
+    ///
+    /// The clock identifier is platform dependent. On POSIX platforms, the
+    /// identifier value is one of the clockid_t values accepted by
+    /// clock_gettime(). clock_gettime() is defined by POSIX.1-2001.
+    ///
+    /// Timestamps in this clock domain are expressed as tv_sec_hi,
+    /// tv_sec_lo, tv_nsec triples, each component being an unsigned
+    /// 32-bit value. Whole seconds are in tv_sec which is a 64-bit
+    /// value combined from tv_sec_hi and tv_sec_lo, and the
+    /// additional fractional part in tv_nsec as nanoseconds. Hence,
+    /// for valid timestamps tv_nsec must be in [0, 999999999].
+    ///
+    /// Note that clock_id applies only to the presentation clock,
+    /// and implies nothing about e.g. the timestamps used in the
+    /// Wayland core protocol input events.
+    ///
+    /// Compositors should prefer a clock which does not jump and is
+    /// not slewed e.g. by NTP. The absolute value of the clock is
+    /// irrelevant. Precision of one millisecond or better is
+    /// recommended. Clients must be able to query the current clock
+    /// value directly, not by asking the compositor.
+    ///
+    /// # Arguments
+    ///
+    /// - `clk_id`: platform clock identifier
+    #[inline]
+    fn clock_id(&self, _slf: &WpPresentationRef, clk_id: u32) {
+        let _ = clk_id;
+    }
+}
+
+impl WpPresentationEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpPresentation] proxies with mutable data.
+///
+/// This trait is the same as [WpPresentationEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpPresentationEventHandlerWithData<T> {
+    /// clock ID for timestamps
+    ///
+    /// This event tells the client in which clock domain the
+    /// compositor interprets the timestamps used by the presentation
+    /// extension. This clock is called the presentation clock.
+    ///
+    /// The compositor sends this event when the client binds to the
+    /// presentation interface. The presentation clock does not change
+    /// during the lifetime of the client connection.
+    ///
+    /// The clock identifier is platform dependent. On POSIX platforms, the
+    /// identifier value is one of the clockid_t values accepted by
+    /// clock_gettime(). clock_gettime() is defined by POSIX.1-2001.
+    ///
+    /// Timestamps in this clock domain are expressed as tv_sec_hi,
+    /// tv_sec_lo, tv_nsec triples, each component being an unsigned
+    /// 32-bit value. Whole seconds are in tv_sec which is a 64-bit
+    /// value combined from tv_sec_hi and tv_sec_lo, and the
+    /// additional fractional part in tv_nsec as nanoseconds. Hence,
+    /// for valid timestamps tv_nsec must be in [0, 999999999].
+    ///
+    /// Note that clock_id applies only to the presentation clock,
+    /// and implies nothing about e.g. the timestamps used in the
+    /// Wayland core protocol input events.
+    ///
+    /// Compositors should prefer a clock which does not jump and is
+    /// not slewed e.g. by NTP. The absolute value of the clock is
+    /// irrelevant. Precision of one millisecond or better is
+    /// recommended. Clients must be able to query the current clock
+    /// value directly, not by asking the compositor.
+    ///
+    /// # Arguments
+    ///
+    /// - `clk_id`: platform clock identifier
+    #[inline]
+    fn clock_id(&self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+        let _ = clk_id;
+    }
+}
+
+/// A mutable event handler for [WpPresentation] proxies.
+///
+/// This trait is the same as [WpPresentationEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpPresentationEventHandlerMut {
+    /// See [WpPresentationEventHandler::clock_id].
+    #[inline]
+    fn clock_id(&mut self, _slf: &WpPresentationRef, clk_id: u32) {
+        let _ = clk_id;
+    }
+}
+
+/// A mutable event handler for [WpPresentation] proxies with mutable data.
+///
+/// This trait is the same as [WpPresentationEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpPresentationEventHandlerMutWithData<T> {
+    /// See [WpPresentationEventHandler::clock_id].
+    #[inline]
+    fn clock_id(&mut self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+        let _ = clk_id;
+    }
+}
+
+impl<H> WpPresentationEventHandler for MutEventHandler<H>
+where
+    H: WpPresentationEventHandlerMut,
+{
+    #[inline]
+    fn clock_id(&self, _slf: &WpPresentationRef, clk_id: u32) {
+        self.with(|h| h.clock_id(_slf, clk_id))
+    }
+}
+
+impl<T, H> WpPresentationEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpPresentationEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn clock_id(&self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+        self.with(|h| h.clock_id(_data, _slf, clk_id))
+    }
+}
+
+impl<T, U, H> WpPresentationEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpPresentationEventHandlerWithData<U>,
+{
+    #[inline]
+    fn clock_id(&self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+        let _data = self.project(_data);
+        self.handler().clock_id(_data, _slf, clk_id)
+    }
+}
+
+impl<T, U, H> WpPresentationEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpPresentationEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn clock_id(&mut self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().clock_id(_data, _slf, clk_id)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpPresentationEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpPresentationRef>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.clock_id(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wp_presentation", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpPresentationEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WpPresentationRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.clock_id(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wp_presentation", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpPresentationEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpPresentationEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpPresentation {
+    /// Since when the error.invalid_timestamp enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_TIMESTAMP__SINCE: u32 = 1;
+    /// Since when the error.invalid_flag enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_FLAG__SINCE: u32 = 1;
+}
+
+/// fatal presentation errors
+///
+/// These fatal protocol errors may be emitted in response to
+/// illegal presentation requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpPresentationError(pub u32);
+
+impl WpPresentationError {
+    /// invalid value in tv_nsec
+    #[allow(dead_code)]
+    pub const INVALID_TIMESTAMP: Self = Self(0);
+
+    /// invalid flag
+    #[allow(dead_code)]
+    pub const INVALID_FLAG: Self = Self(1);
+}
+
+impl Debug for WpPresentationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_TIMESTAMP => "INVALID_TIMESTAMP",
+            Self::INVALID_FLAG => "INVALID_FLAG",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for clock_id events.
+    pub struct ClockId<F>(F);
+    impl<F> WpPresentationEventHandler for ClockId<F>
+    where
+        F: Fn(&WpPresentationRef, u32),
+    {
+        #[inline]
+        fn clock_id(&self, _slf: &WpPresentationRef, clk_id: u32) {
+            self.0(_slf, clk_id)
+        }
+    }
+    impl<T, F> WpPresentationEventHandlerWithData<T> for ClockId<F>
+    where
+        F: Fn(&mut T, &WpPresentationRef, u32),
+    {
+        #[inline]
+        fn clock_id(&self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+            self.0(_data, _slf, clk_id)
+        }
+    }
+
+    /// Mutable event handler for clock_id events.
+    pub struct ClockIdMut<F>(F);
+    impl<F> WpPresentationEventHandlerMut for ClockIdMut<F>
+    where
+        F: FnMut(&WpPresentationRef, u32),
+    {
+        #[inline]
+        fn clock_id(&mut self, _slf: &WpPresentationRef, clk_id: u32) {
+            self.0(_slf, clk_id)
+        }
+    }
+    impl<T, F> WpPresentationEventHandlerMutWithData<T> for ClockIdMut<F>
+    where
+        F: FnMut(&mut T, &WpPresentationRef, u32),
+    {
+        #[inline]
+        fn clock_id(&mut self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+            self.0(_data, _slf, clk_id)
+        }
+    }
+
+    impl WpPresentation {
+        /// Creates an event handler for clock_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_clock_id<F>(f: F) -> ClockId<F>
+        where
+            F: Fn(&WpPresentationRef, u32),
+        {
+            ClockId(f)
+        }
+
+        /// Creates an event handler with mutable data for clock_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_clock_id_with_data<T, F>(f: F) -> ClockId<F>
+        where
+            F: Fn(&mut T, &WpPresentationRef, u32),
+        {
+            ClockId(f)
+        }
+
+        /// Creates a mutable event handler for clock_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_clock_id_mut<F>(f: F) -> ClockIdMut<F>
+        where
+            F: FnMut(&WpPresentationRef, u32),
+        {
+            ClockIdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for clock_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_clock_id_mut_with_data<T, F>(f: F) -> ClockIdMut<F>
+        where
+            F: FnMut(&mut T, &WpPresentationRef, u32),
+        {
+            ClockIdMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { clock_id: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpPresentationEventHandler for Ignore {}
+    impl<T> WpPresentationEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpPresentation::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        clock_id: H0,
+    }
+
+    impl<H0> WpPresentationEventHandler for Handler<H0>
+    where
+        H0: WpPresentationEventHandler,
+    {
+        #[inline]
+        fn clock_id(&self, _slf: &WpPresentationRef, clk_id: u32) {
+            self.clock_id.clock_id(_slf, clk_id)
+        }
+    }
+
+    impl<T, H0> WpPresentationEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WpPresentationEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn clock_id(&self, _data: &mut T, _slf: &WpPresentationRef, clk_id: u32) {
+            self.clock_id.clock_id(_data, _slf, clk_id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for clock_id events.
+        #[allow(dead_code)]
+        pub fn on_clock_id<F>(self, f: F) -> Handler<ClockId<F>>
+        where
+            F: Fn(&WpPresentationRef, u32),
+        {
+            Handler {
+                clock_id: ClockId(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for clock_id events.
+        #[allow(dead_code)]
+        pub fn on_clock_id_with_data<T, F>(self, f: F) -> Handler<ClockId<F>>
+        where
+            F: Fn(&mut T, &WpPresentationRef, u32),
+        {
+            Handler {
+                clock_id: ClockId(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,1826 @@
+//! presentation time feedback event
+//!
+//! A presentation_feedback object returns an indication that a
+//! wl_surface content update has become visible to the user.
+//! One object corresponds to one content update submission
+//! (wl_surface.commit). There are two possible outcomes: the
+//! content update is presented to the user, and a presentation
+//! timestamp delivered; or, the user did not see the content
+//! update because it was superseded or its surface destroyed,
+//! and the content update is discarded.
+//!
+//! Once a presentation_feedback object has delivered a 'presented'
+//! or 'discarded' event it is automatically destroyed.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_presentation_feedback".as_ptr(),
+    version: 2,
+    method_count: 0,
+    methods: ptr::null(),
+    event_count: 3,
+    events: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"sync_output".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlOutput::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"presented".as_ptr(),
+                signature: c"uuuuuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 7] =
+                        [None, None, None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"discarded".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wp_presentation_feedback interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_presentation_feedback",
+    protocol: "presentation_time",
+    version: 2,
+    requests: &[],
+    events: &[
+        info::MessageInfo {
+            name: "sync_output",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "presented",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[
+                info::ArgInfo {
+                    name: "tv_sec_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tv_sec_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tv_nsec",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "refresh",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seq_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seq_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "flags",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("kind"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "discarded",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "kind",
+        since: 1,
+        bitfield: true,
+        entries: &[
+            info::EntryInfo {
+                name: "vsync",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "hw_clock",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "hw_completion",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "zero_copy",
+                value: 8,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_presentation_feedback proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpPresentationFeedback {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_presentation_feedback proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpPresentationFeedbackRef {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpPresentationFeedback is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpPresentationFeedback {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpPresentationFeedback {
+    const INTERFACE: &'static str = "wp_presentation_feedback";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = WpPresentationFeedbackRef;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpPresentationFeedbackRef is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpPresentationFeedbackRef {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpPresentationFeedbackRef {
+    type Owned = WpPresentationFeedback;
+}
+
+impl Deref for WpPresentationFeedback {
+    type Target = WpPresentationFeedbackRef;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpPresentationFeedback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_presentation_feedback#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpPresentationFeedbackRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_presentation_feedback#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpPresentationFeedbackRef> for WpPresentationFeedback {
+    fn eq(&self, other: &WpPresentationFeedbackRef) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpPresentationFeedback> for WpPresentationFeedbackRef {
+    fn eq(&self, other: &WpPresentationFeedback) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl WpPresentationFeedback {
+    /// Since when the sync_output event is available.
+    #[allow(dead_code)]
+    pub const EVT__SYNC_OUTPUT__SINCE: u32 = 1;
+
+    /// Since when the presented event is available.
+    #[allow(dead_code)]
+    pub const EVT__PRESENTED__SINCE: u32 = 1;
+
+    /// Since when the discarded event is available.
+    #[allow(dead_code)]
+    pub const EVT__DISCARDED__SINCE: u32 = 1;
+}
+
+/// An event handler for [WpPresentationFeedback] proxies.
+#[allow(dead_code)]
+pub trait WpPresentationFeedbackEventHandler {
+    /// presentation synchronized to this output
+    ///
+    /// As presentation can be synchronized to only one output at a
+    /// time, this event tells which output it was. This event is only
+    /// sent prior to the presented event.
+    ///
+    /// As clients may bind to the same global wl_output multiple
+    /// times, this event is sent for each bound instance that matches
+    /// the synchronized output. If a client has not bound to the
+    /// right wl_output global at all, this event is not sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`: presentation output
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn sync_output(&self, _slf: &WpPresentationFeedbackRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// the content update was displayed
+    ///
+    /// The associated content update was displayed to the user at the
+    /// indicated time (tv_sec_hi/lo, tv_nsec). For the interpretation of
+    /// the timestamp, see presentation.clock_id event.
+    ///
+    /// The timestamp corresponds to the time when the content update
+    /// turned into light the first time on the surface's main output.
+    /// Compositors may approximate this from the framebuffer flip
+    /// completion events from the system, and the latency of the
+    /// physical display path if known.
+    ///
+    /// This event is preceded by all related sync_output events
+    /// telling which output's refresh cycle the feedback corresponds
+    /// to, i.e. the main output for the surface. Compositors are
+    /// recommended to choose the output containing the largest part
+    /// of the wl_surface, or keeping the output they previously
+    /// chose. Having a stable presentation output association helps
+    /// clients predict future output refreshes (vblank).
+    ///
+    /// The 'refresh' argument gives the compositor's prediction of how
+    /// many nanoseconds after tv_sec, tv_nsec the very next output
+    /// refresh may occur. This is to further aid clients in
+    /// predicting future refreshes, i.e., estimating the timestamps
+    /// targeting the next few vblanks. If such prediction cannot
+    /// usefully be done, the argument is zero.
+    ///
+    /// For version 2 and later, if the output does not have a constant
+    /// refresh rate, explicit video mode switches excluded, then the
+    /// refresh argument must be either an appropriate rate picked by the
+    /// compositor (e.g. fastest rate), or 0 if no such rate exists.
+    /// For version 1, if the output does not have a constant refresh rate,
+    /// the refresh argument must be zero.
+    ///
+    /// The 64-bit value combined from seq_hi and seq_lo is the value
+    /// of the output's vertical retrace counter when the content
+    /// update was first scanned out to the display. This value must
+    /// be compatible with the definition of MSC in
+    /// GLX_OML_sync_control specification. Note, that if the display
+    /// path has a non-zero latency, the time instant specified by
+    /// this counter may differ from the timestamp's.
+    ///
+    /// If the output does not have a concept of vertical retrace or a
+    /// refresh cycle, or the output device is self-refreshing without
+    /// a way to query the refresh count, then the arguments seq_hi
+    /// and seq_lo must be zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of the presentation timestamp
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of the presentation timestamp
+    /// - `tv_nsec`: nanoseconds part of the presentation timestamp
+    /// - `refresh`: nanoseconds till next refresh
+    /// - `seq_hi`: high 32 bits of refresh counter
+    /// - `seq_lo`: low 32 bits of refresh counter
+    /// - `flags`: combination of 'kind' values
+    #[inline]
+    fn presented(
+        &self,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+        let _ = refresh;
+        let _ = seq_hi;
+        let _ = seq_lo;
+        let _ = flags;
+    }
+
+    /// the content update was not displayed
+    ///
+    /// The content update was never displayed to the user.
+    #[inline]
+    fn discarded(&self, _slf: &WpPresentationFeedbackRef) {}
+}
+
+impl WpPresentationFeedbackEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpPresentationFeedback] proxies with mutable data.
+///
+/// This trait is the same as [WpPresentationFeedbackEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpPresentationFeedbackEventHandlerWithData<T> {
+    /// presentation synchronized to this output
+    ///
+    /// As presentation can be synchronized to only one output at a
+    /// time, this event tells which output it was. This event is only
+    /// sent prior to the presented event.
+    ///
+    /// As clients may bind to the same global wl_output multiple
+    /// times, this event is sent for each bound instance that matches
+    /// the synchronized output. If a client has not bound to the
+    /// right wl_output global at all, this event is not sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`: presentation output
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn sync_output(
+        &self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _ = output;
+    }
+
+    /// the content update was displayed
+    ///
+    /// The associated content update was displayed to the user at the
+    /// indicated time (tv_sec_hi/lo, tv_nsec). For the interpretation of
+    /// the timestamp, see presentation.clock_id event.
+    ///
+    /// The timestamp corresponds to the time when the content update
+    /// turned into light the first time on the surface's main output.
+    /// Compositors may approximate this from the framebuffer flip
+    /// completion events from the system, and the latency of the
+    /// physical display path if known.
+    ///
+    /// This event is preceded by all related sync_output events
+    /// telling which output's refresh cycle the feedback corresponds
+    /// to, i.e. the main output for the surface. Compositors are
+    /// recommended to choose the output containing the largest part
+    /// of the wl_surface, or keeping the output they previously
+    /// chose. Having a stable presentation output association helps
+    /// clients predict future output refreshes (vblank).
+    ///
+    /// The 'refresh' argument gives the compositor's prediction of how
+    /// many nanoseconds after tv_sec, tv_nsec the very next output
+    /// refresh may occur. This is to further aid clients in
+    /// predicting future refreshes, i.e., estimating the timestamps
+    /// targeting the next few vblanks. If such prediction cannot
+    /// usefully be done, the argument is zero.
+    ///
+    /// For version 2 and later, if the output does not have a constant
+    /// refresh rate, explicit video mode switches excluded, then the
+    /// refresh argument must be either an appropriate rate picked by the
+    /// compositor (e.g. fastest rate), or 0 if no such rate exists.
+    /// For version 1, if the output does not have a constant refresh rate,
+    /// the refresh argument must be zero.
+    ///
+    /// The 64-bit value combined from seq_hi and seq_lo is the value
+    /// of the output's vertical retrace counter when the content
+    /// update was first scanned out to the display. This value must
+    /// be compatible with the definition of MSC in
+    /// GLX_OML_sync_control specification. Note, that if the display
+    /// path has a non-zero latency, the time instant specified by
+    /// this counter may differ from the timestamp's.
+    ///
+    /// If the output does not have a concept of vertical retrace or a
+    /// refresh cycle, or the output device is self-refreshing without
+    /// a way to query the refresh count, then the arguments seq_hi
+    /// and seq_lo must be zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `tv_sec_hi`: high 32 bits of the seconds part of the presentation timestamp
+    /// - `tv_sec_lo`: low 32 bits of the seconds part of the presentation timestamp
+    /// - `tv_nsec`: nanoseconds part of the presentation timestamp
+    /// - `refresh`: nanoseconds till next refresh
+    /// - `seq_hi`: high 32 bits of refresh counter
+    /// - `seq_lo`: low 32 bits of refresh counter
+    /// - `flags`: combination of 'kind' values
+    #[inline]
+    fn presented(
+        &self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+        let _ = refresh;
+        let _ = seq_hi;
+        let _ = seq_lo;
+        let _ = flags;
+    }
+
+    /// the content update was not displayed
+    ///
+    /// The content update was never displayed to the user.
+    #[inline]
+    fn discarded(&self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {}
+}
+
+/// A mutable event handler for [WpPresentationFeedback] proxies.
+///
+/// This trait is the same as [WpPresentationFeedbackEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpPresentationFeedbackEventHandlerMut {
+    /// See [WpPresentationFeedbackEventHandler::sync_output].
+    #[inline]
+    fn sync_output(&mut self, _slf: &WpPresentationFeedbackRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [WpPresentationFeedbackEventHandler::presented].
+    #[inline]
+    fn presented(
+        &mut self,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+        let _ = refresh;
+        let _ = seq_hi;
+        let _ = seq_lo;
+        let _ = flags;
+    }
+
+    /// See [WpPresentationFeedbackEventHandler::discarded].
+    #[inline]
+    fn discarded(&mut self, _slf: &WpPresentationFeedbackRef) {}
+}
+
+/// A mutable event handler for [WpPresentationFeedback] proxies with mutable data.
+///
+/// This trait is the same as [WpPresentationFeedbackEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpPresentationFeedbackEventHandlerMutWithData<T> {
+    /// See [WpPresentationFeedbackEventHandler::sync_output].
+    #[inline]
+    fn sync_output(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _ = output;
+    }
+
+    /// See [WpPresentationFeedbackEventHandler::presented].
+    #[inline]
+    fn presented(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        let _ = tv_sec_hi;
+        let _ = tv_sec_lo;
+        let _ = tv_nsec;
+        let _ = refresh;
+        let _ = seq_hi;
+        let _ = seq_lo;
+        let _ = flags;
+    }
+
+    /// See [WpPresentationFeedbackEventHandler::discarded].
+    #[inline]
+    fn discarded(&mut self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {}
+}
+
+impl<H> WpPresentationFeedbackEventHandler for MutEventHandler<H>
+where
+    H: WpPresentationFeedbackEventHandlerMut,
+{
+    #[inline]
+    fn sync_output(&self, _slf: &WpPresentationFeedbackRef, output: Option<&WlOutputRef>) {
+        self.with(|h| h.sync_output(_slf, output))
+    }
+
+    #[inline]
+    fn presented(
+        &self,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        self.with(|h| {
+            h.presented(
+                _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        })
+    }
+
+    #[inline]
+    fn discarded(&self, _slf: &WpPresentationFeedbackRef) {
+        self.with(|h| h.discarded(_slf))
+    }
+}
+
+impl<T, H> WpPresentationFeedbackEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WpPresentationFeedbackEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn sync_output(
+        &self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        output: Option<&WlOutputRef>,
+    ) {
+        self.with(|h| h.sync_output(_data, _slf, output))
+    }
+
+    #[inline]
+    fn presented(
+        &self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        self.with(|h| {
+            h.presented(
+                _data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        })
+    }
+
+    #[inline]
+    fn discarded(&self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {
+        self.with(|h| h.discarded(_data, _slf))
+    }
+}
+
+impl<T, U, H> WpPresentationFeedbackEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpPresentationFeedbackEventHandlerWithData<U>,
+{
+    #[inline]
+    fn sync_output(
+        &self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().sync_output(_data, _slf, output)
+    }
+
+    #[inline]
+    fn presented(
+        &self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        let _data = self.project(_data);
+        self.handler().presented(
+            _data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+        )
+    }
+
+    #[inline]
+    fn discarded(&self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {
+        let _data = self.project(_data);
+        self.handler().discarded(_data, _slf)
+    }
+}
+
+impl<T, U, H> WpPresentationFeedbackEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WpPresentationFeedbackEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn sync_output(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        output: Option<&WlOutputRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().sync_output(_data, _slf, output)
+    }
+
+    #[inline]
+    fn presented(
+        &mut self,
+        _data: &mut T,
+        _slf: &WpPresentationFeedbackRef,
+        tv_sec_hi: u32,
+        tv_sec_lo: u32,
+        tv_nsec: u32,
+        refresh: u32,
+        seq_hi: u32,
+        seq_lo: u32,
+        flags: WpPresentationFeedbackKind,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().presented(
+            _data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+        )
+    }
+
+    #[inline]
+    fn discarded(&mut self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {
+        let _data = self.project(_data);
+        self.handler_mut().discarded(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpPresentationFeedbackEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpPresentationFeedbackRef>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.sync_output(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 7 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 7]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                // SAFETY: - INTERFACE requires that args[4] contains a uint
+                let arg4 = unsafe { args[4].u };
+                // SAFETY: - INTERFACE requires that args[5] contains a uint
+                let arg5 = unsafe { args[5].u };
+                // SAFETY: - INTERFACE requires that args[6] contains a uint
+                let arg6 = unsafe { WpPresentationFeedbackKind(args[6].u) };
+                self.0
+                    .presented(slf, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            }
+            2 => {
+                self.0.discarded(slf);
+            }
+            _ => {
+                invalid_opcode("wp_presentation_feedback", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpPresentationFeedbackEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<WpPresentationFeedbackRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.sync_output(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 7 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 7]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                // SAFETY: - INTERFACE requires that args[4] contains a uint
+                let arg4 = unsafe { args[4].u };
+                // SAFETY: - INTERFACE requires that args[5] contains a uint
+                let arg5 = unsafe { args[5].u };
+                // SAFETY: - INTERFACE requires that args[6] contains a uint
+                let arg6 = unsafe { WpPresentationFeedbackKind(args[6].u) };
+                self.0
+                    .presented(data, slf, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            }
+            2 => {
+                self.0.discarded(data, slf);
+            }
+            _ => {
+                invalid_opcode("wp_presentation_feedback", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpPresentationFeedbackEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpPresentationFeedbackEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpPresentationFeedback {
+    /// Since when the kind.vsync enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__KIND_VSYNC__SINCE: u32 = 1;
+    /// Since when the kind.hw_clock enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__KIND_HW_CLOCK__SINCE: u32 = 1;
+    /// Since when the kind.hw_completion enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__KIND_HW_COMPLETION__SINCE: u32 = 1;
+    /// Since when the kind.zero_copy enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__KIND_ZERO_COPY__SINCE: u32 = 1;
+}
+
+/// bitmask of flags in presented event
+///
+/// These flags provide information about how the presentation of
+/// the related content update was done. The intent is to help
+/// clients assess the reliability of the feedback and the visual
+/// quality with respect to possible tearing and timings.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct WpPresentationFeedbackKind(pub u32);
+
+/// An iterator over the set bits in a [WpPresentationFeedbackKind].
+///
+/// You can construct this with the `IntoIterator` implementation of `WpPresentationFeedbackKind`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct WpPresentationFeedbackKindIter(pub u32);
+
+impl WpPresentationFeedbackKind {
+    /// presentation was vsync'd
+    ///
+    /// The presentation was synchronized to the "vertical retrace" by
+    /// the display hardware such that tearing does not happen.
+    /// Relying on software scheduling is not acceptable for this
+    /// flag. If presentation is done by a copy to the active
+    /// frontbuffer, then it must guarantee that tearing cannot
+    /// happen.
+    #[allow(dead_code)]
+    pub const VSYNC: Self = Self(0x1);
+
+    /// hardware provided the presentation timestamp
+    ///
+    /// The display hardware provided measurements that the hardware
+    /// driver converted into a presentation timestamp. Sampling a
+    /// clock in software is not acceptable for this flag.
+    #[allow(dead_code)]
+    pub const HW_CLOCK: Self = Self(0x2);
+
+    /// hardware signalled the start of the presentation
+    ///
+    /// The display hardware signalled that it started using the new
+    /// image content. The opposite of this is e.g. a timer being used
+    /// to guess when the display hardware has switched to the new
+    /// image content.
+    #[allow(dead_code)]
+    pub const HW_COMPLETION: Self = Self(0x4);
+
+    /// presentation was done zero-copy
+    ///
+    /// The presentation of this update was done zero-copy. This means
+    /// the buffer from the client was given to display hardware as
+    /// is, without copying it. Compositing with OpenGL counts as
+    /// copying, even if textured directly from the client buffer.
+    /// Possible zero-copy cases include direct scanout of a
+    /// fullscreen surface and a surface on a hardware overlay.
+    #[allow(dead_code)]
+    pub const ZERO_COPY: Self = Self(0x8);
+}
+
+#[allow(dead_code)]
+impl WpPresentationFeedbackKind {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 0x1 | 0x2 | 0x4 | 0x8)
+    }
+}
+
+impl Iterator for WpPresentationFeedbackKindIter {
+    type Item = WpPresentationFeedbackKind;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(WpPresentationFeedbackKind(bit))
+    }
+}
+
+impl IntoIterator for WpPresentationFeedbackKind {
+    type Item = WpPresentationFeedbackKind;
+    type IntoIter = WpPresentationFeedbackKindIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        WpPresentationFeedbackKindIter(self.0)
+    }
+}
+
+impl BitAnd for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for WpPresentationFeedbackKind {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for WpPresentationFeedbackKind {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for WpPresentationFeedbackKind {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for WpPresentationFeedbackKind {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for WpPresentationFeedbackKind {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for WpPresentationFeedbackKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 0x1 == 0x1 {
+            v &= !0x1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("VSYNC")?;
+        }
+        if v & 0x2 == 0x2 {
+            v &= !0x2;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("HW_CLOCK")?;
+        }
+        if v & 0x4 == 0x4 {
+            v &= !0x4;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("HW_COMPLETION")?;
+        }
+        if v & 0x8 == 0x8 {
+            v &= !0x8;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("ZERO_COPY")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("0")?;
+        }
+        Ok(())
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for sync_output events.
+    pub struct SyncOutput<F>(F);
+    impl<F> WpPresentationFeedbackEventHandler for SyncOutput<F>
+    where
+        F: Fn(&WpPresentationFeedbackRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn sync_output(&self, _slf: &WpPresentationFeedbackRef, output: Option<&WlOutputRef>) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> WpPresentationFeedbackEventHandlerWithData<T> for SyncOutput<F>
+    where
+        F: Fn(&mut T, &WpPresentationFeedbackRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn sync_output(
+            &self,
+            _data: &mut T,
+            _slf: &WpPresentationFeedbackRef,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Event handler for presented events.
+    pub struct Presented<F>(F);
+    impl<F> WpPresentationFeedbackEventHandler for Presented<F>
+    where
+        F: Fn(&WpPresentationFeedbackRef, u32, u32, u32, u32, u32, u32, WpPresentationFeedbackKind),
+    {
+        #[inline]
+        fn presented(
+            &self,
+            _slf: &WpPresentationFeedbackRef,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+            refresh: u32,
+            seq_hi: u32,
+            seq_lo: u32,
+            flags: WpPresentationFeedbackKind,
+        ) {
+            self.0(
+                _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        }
+    }
+    impl<T, F> WpPresentationFeedbackEventHandlerWithData<T> for Presented<F>
+    where
+        F: Fn(
+            &mut T,
+            &WpPresentationFeedbackRef,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            WpPresentationFeedbackKind,
+        ),
+    {
+        #[inline]
+        fn presented(
+            &self,
+            _data: &mut T,
+            _slf: &WpPresentationFeedbackRef,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+            refresh: u32,
+            seq_hi: u32,
+            seq_lo: u32,
+            flags: WpPresentationFeedbackKind,
+        ) {
+            self.0(
+                _data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        }
+    }
+
+    /// Event handler for discarded events.
+    pub struct Discarded<F>(F);
+    impl<F> WpPresentationFeedbackEventHandler for Discarded<F>
+    where
+        F: Fn(&WpPresentationFeedbackRef),
+    {
+        #[inline]
+        fn discarded(&self, _slf: &WpPresentationFeedbackRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpPresentationFeedbackEventHandlerWithData<T> for Discarded<F>
+    where
+        F: Fn(&mut T, &WpPresentationFeedbackRef),
+    {
+        #[inline]
+        fn discarded(&self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for sync_output events.
+    pub struct SyncOutputMut<F>(F);
+    impl<F> WpPresentationFeedbackEventHandlerMut for SyncOutputMut<F>
+    where
+        F: FnMut(&WpPresentationFeedbackRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn sync_output(&mut self, _slf: &WpPresentationFeedbackRef, output: Option<&WlOutputRef>) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> WpPresentationFeedbackEventHandlerMutWithData<T> for SyncOutputMut<F>
+    where
+        F: FnMut(&mut T, &WpPresentationFeedbackRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn sync_output(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpPresentationFeedbackRef,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Mutable event handler for presented events.
+    pub struct PresentedMut<F>(F);
+    impl<F> WpPresentationFeedbackEventHandlerMut for PresentedMut<F>
+    where
+        F: FnMut(
+            &WpPresentationFeedbackRef,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            WpPresentationFeedbackKind,
+        ),
+    {
+        #[inline]
+        fn presented(
+            &mut self,
+            _slf: &WpPresentationFeedbackRef,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+            refresh: u32,
+            seq_hi: u32,
+            seq_lo: u32,
+            flags: WpPresentationFeedbackKind,
+        ) {
+            self.0(
+                _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        }
+    }
+    impl<T, F> WpPresentationFeedbackEventHandlerMutWithData<T> for PresentedMut<F>
+    where
+        F: FnMut(
+            &mut T,
+            &WpPresentationFeedbackRef,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+            WpPresentationFeedbackKind,
+        ),
+    {
+        #[inline]
+        fn presented(
+            &mut self,
+            _data: &mut T,
+            _slf: &WpPresentationFeedbackRef,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+            refresh: u32,
+            seq_hi: u32,
+            seq_lo: u32,
+            flags: WpPresentationFeedbackKind,
+        ) {
+            self.0(
+                _data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        }
+    }
+
+    /// Mutable event handler for discarded events.
+    pub struct DiscardedMut<F>(F);
+    impl<F> WpPresentationFeedbackEventHandlerMut for DiscardedMut<F>
+    where
+        F: FnMut(&WpPresentationFeedbackRef),
+    {
+        #[inline]
+        fn discarded(&mut self, _slf: &WpPresentationFeedbackRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WpPresentationFeedbackEventHandlerMutWithData<T> for DiscardedMut<F>
+    where
+        F: FnMut(&mut T, &WpPresentationFeedbackRef),
+    {
+        #[inline]
+        fn discarded(&mut self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WpPresentationFeedback {
+        /// Creates an event handler for sync_output events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_sync_output<F>(f: F) -> SyncOutput<F>
+        where
+            F: Fn(&WpPresentationFeedbackRef, Option<&WlOutputRef>),
+        {
+            SyncOutput(f)
+        }
+
+        /// Creates an event handler with mutable data for sync_output events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_sync_output_with_data<T, F>(f: F) -> SyncOutput<F>
+        where
+            F: Fn(&mut T, &WpPresentationFeedbackRef, Option<&WlOutputRef>),
+        {
+            SyncOutput(f)
+        }
+
+        /// Creates an event handler for presented events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_presented<F>(f: F) -> Presented<F>
+        where
+            F: Fn(
+                &WpPresentationFeedbackRef,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                WpPresentationFeedbackKind,
+            ),
+        {
+            Presented(f)
+        }
+
+        /// Creates an event handler with mutable data for presented events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_presented_with_data<T, F>(f: F) -> Presented<F>
+        where
+            F: Fn(
+                &mut T,
+                &WpPresentationFeedbackRef,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                WpPresentationFeedbackKind,
+            ),
+        {
+            Presented(f)
+        }
+
+        /// Creates an event handler for discarded events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_discarded<F>(f: F) -> Discarded<F>
+        where
+            F: Fn(&WpPresentationFeedbackRef),
+        {
+            Discarded(f)
+        }
+
+        /// Creates an event handler with mutable data for discarded events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_discarded_with_data<T, F>(f: F) -> Discarded<F>
+        where
+            F: Fn(&mut T, &WpPresentationFeedbackRef),
+        {
+            Discarded(f)
+        }
+
+        /// Creates a mutable event handler for sync_output events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_sync_output_mut<F>(f: F) -> SyncOutputMut<F>
+        where
+            F: FnMut(&WpPresentationFeedbackRef, Option<&WlOutputRef>),
+        {
+            SyncOutputMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for sync_output events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_sync_output_mut_with_data<T, F>(f: F) -> SyncOutputMut<F>
+        where
+            F: FnMut(&mut T, &WpPresentationFeedbackRef, Option<&WlOutputRef>),
+        {
+            SyncOutputMut(f)
+        }
+
+        /// Creates a mutable event handler for presented events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_presented_mut<F>(f: F) -> PresentedMut<F>
+        where
+            F: FnMut(
+                &WpPresentationFeedbackRef,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                WpPresentationFeedbackKind,
+            ),
+        {
+            PresentedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for presented events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_presented_mut_with_data<T, F>(f: F) -> PresentedMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &WpPresentationFeedbackRef,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                WpPresentationFeedbackKind,
+            ),
+        {
+            PresentedMut(f)
+        }
+
+        /// Creates a mutable event handler for discarded events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_discarded_mut<F>(f: F) -> DiscardedMut<F>
+        where
+            F: FnMut(&WpPresentationFeedbackRef),
+        {
+            DiscardedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for discarded events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_discarded_mut_with_data<T, F>(f: F) -> DiscardedMut<F>
+        where
+            F: FnMut(&mut T, &WpPresentationFeedbackRef),
+        {
+            DiscardedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                sync_output: Ignore,
+                presented: Ignore,
+                discarded: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WpPresentationFeedbackEventHandler for Ignore {}
+    impl<T> WpPresentationFeedbackEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WpPresentationFeedback::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        sync_output: H0,
+        presented: H1,
+        discarded: H2,
+    }
+
+    impl<H0, H1, H2> WpPresentationFeedbackEventHandler for Handler<H0, H1, H2>
+    where
+        H0: WpPresentationFeedbackEventHandler,
+        H1: WpPresentationFeedbackEventHandler,
+        H2: WpPresentationFeedbackEventHandler,
+    {
+        #[inline]
+        fn sync_output(&self, _slf: &WpPresentationFeedbackRef, output: Option<&WlOutputRef>) {
+            self.sync_output.sync_output(_slf, output)
+        }
+
+        #[inline]
+        fn presented(
+            &self,
+            _slf: &WpPresentationFeedbackRef,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+            refresh: u32,
+            seq_hi: u32,
+            seq_lo: u32,
+            flags: WpPresentationFeedbackKind,
+        ) {
+            self.presented.presented(
+                _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        }
+
+        #[inline]
+        fn discarded(&self, _slf: &WpPresentationFeedbackRef) {
+            self.discarded.discarded(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2> WpPresentationFeedbackEventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: WpPresentationFeedbackEventHandlerWithData<T>,
+        H1: WpPresentationFeedbackEventHandlerWithData<T>,
+        H2: WpPresentationFeedbackEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn sync_output(
+            &self,
+            _data: &mut T,
+            _slf: &WpPresentationFeedbackRef,
+            output: Option<&WlOutputRef>,
+        ) {
+            self.sync_output.sync_output(_data, _slf, output)
+        }
+
+        #[inline]
+        fn presented(
+            &self,
+            _data: &mut T,
+            _slf: &WpPresentationFeedbackRef,
+            tv_sec_hi: u32,
+            tv_sec_lo: u32,
+            tv_nsec: u32,
+            refresh: u32,
+            seq_hi: u32,
+            seq_lo: u32,
+            flags: WpPresentationFeedbackKind,
+        ) {
+            self.presented.presented(
+                _data, _slf, tv_sec_hi, tv_sec_lo, tv_nsec, refresh, seq_hi, seq_lo, flags,
+            )
+        }
+
+        #[inline]
+        fn discarded(&self, _data: &mut T, _slf: &WpPresentationFeedbackRef) {
+            self.discarded.discarded(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for sync_output events.
+        #[allow(dead_code)]
+        pub fn on_sync_output<F>(self, f: F) -> Handler<SyncOutput<F>, H1, H2>
+        where
+            F: Fn(&WpPresentationFeedbackRef, Option<&WlOutputRef>),
+        {
+            Handler {
+                sync_output: SyncOutput(f),
+                presented: self.presented,
+                discarded: self.discarded,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for sync_output events.
+        #[allow(dead_code)]
+        pub fn on_sync_output_with_data<T, F>(self, f: F) -> Handler<SyncOutput<F>, H1, H2>
+        where
+            F: Fn(&mut T, &WpPresentationFeedbackRef, Option<&WlOutputRef>),
+        {
+            Handler {
+                sync_output: SyncOutput(f),
+                presented: self.presented,
+                discarded: self.discarded,
+            }
+        }
+
+        /// Sets the closure for presented events.
+        #[allow(dead_code)]
+        pub fn on_presented<F>(self, f: F) -> Handler<H0, Presented<F>, H2>
+        where
+            F: Fn(
+                &WpPresentationFeedbackRef,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                WpPresentationFeedbackKind,
+            ),
+        {
+            Handler {
+                sync_output: self.sync_output,
+                presented: Presented(f),
+                discarded: self.discarded,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for presented events.
+        #[allow(dead_code)]
+        pub fn on_presented_with_data<T, F>(self, f: F) -> Handler<H0, Presented<F>, H2>
+        where
+            F: Fn(
+                &mut T,
+                &WpPresentationFeedbackRef,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                u32,
+                WpPresentationFeedbackKind,
+            ),
+        {
+            Handler {
+                sync_output: self.sync_output,
+                presented: Presented(f),
+                discarded: self.discarded,
+            }
+        }
+
+        /// Sets the closure for discarded events.
+        #[allow(dead_code)]
+        pub fn on_discarded<F>(self, f: F) -> Handler<H0, H1, Discarded<F>>
+        where
+            F: Fn(&WpPresentationFeedbackRef),
+        {
+            Handler {
+                sync_output: self.sync_output,
+                presented: self.presented,
+                discarded: Discarded(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for discarded events.
+        #[allow(dead_code)]
+        pub fn on_discarded_with_data<T, F>(self, f: F) -> Handler<H0, H1, Discarded<F>>
+        where
+            F: Fn(&mut T, &WpPresentationFeedbackRef),
+        {
+            Handler {
+                sync_output: self.sync_output,
+                presented: self.presented,
+                discarded: Discarded(f),
+            }
+        }
+    }
+}
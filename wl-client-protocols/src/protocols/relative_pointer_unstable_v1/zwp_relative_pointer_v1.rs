@@ -0,0 +1,861 @@
+//! relative pointer object
+//!
+//! A wp_relative_pointer object is an extension to the wl_pointer interface
+//! used for emitting relative pointer events. It shares the same focus as
+//! wl_pointer objects of the same seat and will only emit events when it has
+//! focus.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_relative_pointer_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"relative_motion".as_ptr(),
+            signature: c"uuffff".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 6] =
+                    [None, None, None, None, None, None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_relative_pointer_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_relative_pointer_v1",
+    protocol: "relative_pointer_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[info::MessageInfo {
+        name: "relative_motion",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[
+            info::ArgInfo {
+                name: "utime_hi",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "utime_lo",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "dx",
+                ty: info::ArgType::Fixed,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "dy",
+                ty: info::ArgType::Fixed,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "dx_unaccel",
+                ty: info::ArgType::Fixed,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "dy_unaccel",
+                ty: info::ArgType::Fixed,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+        ],
+    }],
+    enums: &[],
+};
+
+/// An owned zwp_relative_pointer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpRelativePointerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_relative_pointer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpRelativePointerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpRelativePointerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpRelativePointerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpRelativePointerV1 {
+    const INTERFACE: &'static str = "zwp_relative_pointer_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpRelativePointerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpRelativePointerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpRelativePointerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpRelativePointerV1Ref {
+    type Owned = ZwpRelativePointerV1;
+}
+
+impl Deref for ZwpRelativePointerV1 {
+    type Target = ZwpRelativePointerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpRelativePointerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_relative_pointer_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpRelativePointerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_relative_pointer_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpRelativePointerV1Ref> for ZwpRelativePointerV1 {
+    fn eq(&self, other: &ZwpRelativePointerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpRelativePointerV1> for ZwpRelativePointerV1Ref {
+    fn eq(&self, other: &ZwpRelativePointerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpRelativePointerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// release the relative pointer object
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpRelativePointerV1 {
+    /// Since when the relative_motion event is available.
+    #[allow(dead_code)]
+    pub const EVT__RELATIVE_MOTION__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpRelativePointerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpRelativePointerV1EventHandler {
+    /// relative pointer motion
+    ///
+    /// Relative x/y pointer motion from the pointer of the seat associated with
+    /// this object.
+    ///
+    /// A relative motion is in the same dimension as regular wl_pointer motion
+    /// events, except they do not represent an absolute position. For example,
+    /// moving a pointer from (x, y) to (x', y') would have the equivalent
+    /// relative motion (x' - x, y' - y). If a pointer motion caused the
+    /// absolute pointer position to be clipped by for example the edge of the
+    /// monitor, the relative motion is unaffected by the clipping and will
+    /// represent the unclipped motion.
+    ///
+    /// This event also contains non-accelerated motion deltas. The
+    /// non-accelerated delta is, when applicable, the regular pointer motion
+    /// delta as it was before having applied motion acceleration and other
+    /// transformations such as normalization.
+    ///
+    /// Note that the non-accelerated delta does not represent 'raw' events as
+    /// they were read from some device. Pointer motion acceleration is device-
+    /// and configuration-specific and non-accelerated deltas and accelerated
+    /// deltas may have the same value on some devices.
+    ///
+    /// Relative motions are not coupled to wl_pointer.motion events, and can be
+    /// sent in combination with such events, but also independently. There may
+    /// also be scenarios where wl_pointer.motion is sent, but there is no
+    /// relative motion. The order of an absolute and relative motion event
+    /// originating from the same physical motion is not guaranteed.
+    ///
+    /// If the client needs button events or focus state, it can receive them
+    /// from a wl_pointer object of the same seat that the wp_relative_pointer
+    /// object is associated with.
+    ///
+    /// # Arguments
+    ///
+    /// - `utime_hi`: high 32 bits of a 64 bit timestamp with microsecond granularity
+    /// - `utime_lo`: low 32 bits of a 64 bit timestamp with microsecond granularity
+    /// - `dx`: the x component of the motion vector
+    /// - `dy`: the y component of the motion vector
+    /// - `dx_unaccel`: the x component of the unaccelerated motion vector
+    /// - `dy_unaccel`: the y component of the unaccelerated motion vector
+    #[inline]
+    fn relative_motion(
+        &self,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        let _ = utime_hi;
+        let _ = utime_lo;
+        let _ = dx;
+        let _ = dy;
+        let _ = dx_unaccel;
+        let _ = dy_unaccel;
+    }
+}
+
+impl ZwpRelativePointerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpRelativePointerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpRelativePointerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpRelativePointerV1EventHandlerWithData<T> {
+    /// relative pointer motion
+    ///
+    /// Relative x/y pointer motion from the pointer of the seat associated with
+    /// this object.
+    ///
+    /// A relative motion is in the same dimension as regular wl_pointer motion
+    /// events, except they do not represent an absolute position. For example,
+    /// moving a pointer from (x, y) to (x', y') would have the equivalent
+    /// relative motion (x' - x, y' - y). If a pointer motion caused the
+    /// absolute pointer position to be clipped by for example the edge of the
+    /// monitor, the relative motion is unaffected by the clipping and will
+    /// represent the unclipped motion.
+    ///
+    /// This event also contains non-accelerated motion deltas. The
+    /// non-accelerated delta is, when applicable, the regular pointer motion
+    /// delta as it was before having applied motion acceleration and other
+    /// transformations such as normalization.
+    ///
+    /// Note that the non-accelerated delta does not represent 'raw' events as
+    /// they were read from some device. Pointer motion acceleration is device-
+    /// and configuration-specific and non-accelerated deltas and accelerated
+    /// deltas may have the same value on some devices.
+    ///
+    /// Relative motions are not coupled to wl_pointer.motion events, and can be
+    /// sent in combination with such events, but also independently. There may
+    /// also be scenarios where wl_pointer.motion is sent, but there is no
+    /// relative motion. The order of an absolute and relative motion event
+    /// originating from the same physical motion is not guaranteed.
+    ///
+    /// If the client needs button events or focus state, it can receive them
+    /// from a wl_pointer object of the same seat that the wp_relative_pointer
+    /// object is associated with.
+    ///
+    /// # Arguments
+    ///
+    /// - `utime_hi`: high 32 bits of a 64 bit timestamp with microsecond granularity
+    /// - `utime_lo`: low 32 bits of a 64 bit timestamp with microsecond granularity
+    /// - `dx`: the x component of the motion vector
+    /// - `dy`: the y component of the motion vector
+    /// - `dx_unaccel`: the x component of the unaccelerated motion vector
+    /// - `dy_unaccel`: the y component of the unaccelerated motion vector
+    #[inline]
+    fn relative_motion(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        let _ = utime_hi;
+        let _ = utime_lo;
+        let _ = dx;
+        let _ = dy;
+        let _ = dx_unaccel;
+        let _ = dy_unaccel;
+    }
+}
+
+/// A mutable event handler for [ZwpRelativePointerV1] proxies.
+///
+/// This trait is the same as [ZwpRelativePointerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpRelativePointerV1EventHandlerMut {
+    /// See [ZwpRelativePointerV1EventHandler::relative_motion].
+    #[inline]
+    fn relative_motion(
+        &mut self,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        let _ = utime_hi;
+        let _ = utime_lo;
+        let _ = dx;
+        let _ = dy;
+        let _ = dx_unaccel;
+        let _ = dy_unaccel;
+    }
+}
+
+/// A mutable event handler for [ZwpRelativePointerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpRelativePointerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpRelativePointerV1EventHandlerMutWithData<T> {
+    /// See [ZwpRelativePointerV1EventHandler::relative_motion].
+    #[inline]
+    fn relative_motion(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        let _ = utime_hi;
+        let _ = utime_lo;
+        let _ = dx;
+        let _ = dy;
+        let _ = dx_unaccel;
+        let _ = dy_unaccel;
+    }
+}
+
+impl<H> ZwpRelativePointerV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpRelativePointerV1EventHandlerMut,
+{
+    #[inline]
+    fn relative_motion(
+        &self,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        self.with(|h| h.relative_motion(_slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel))
+    }
+}
+
+impl<T, H> ZwpRelativePointerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpRelativePointerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn relative_motion(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        self.with(|h| {
+            h.relative_motion(
+                _data, _slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel,
+            )
+        })
+    }
+}
+
+impl<T, U, H> ZwpRelativePointerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpRelativePointerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn relative_motion(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler().relative_motion(
+            _data, _slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel,
+        )
+    }
+}
+
+impl<T, U, H> ZwpRelativePointerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpRelativePointerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn relative_motion(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpRelativePointerV1Ref,
+        utime_hi: u32,
+        utime_lo: u32,
+        dx: Fixed,
+        dy: Fixed,
+        dx_unaccel: Fixed,
+        dy_unaccel: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().relative_motion(
+            _data, _slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel,
+        )
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpRelativePointerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpRelativePointerV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 6 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 6]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                // SAFETY: - INTERFACE requires that args[3] contains a fixed
+                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
+                // SAFETY: - INTERFACE requires that args[4] contains a fixed
+                let arg4 = unsafe { Fixed::from_wire(args[4].f) };
+                // SAFETY: - INTERFACE requires that args[5] contains a fixed
+                let arg5 = unsafe { Fixed::from_wire(args[5].f) };
+                self.0
+                    .relative_motion(slf, arg0, arg1, arg2, arg3, arg4, arg5);
+            }
+            _ => {
+                invalid_opcode("zwp_relative_pointer_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpRelativePointerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpRelativePointerV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 6 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 6]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                // SAFETY: - INTERFACE requires that args[3] contains a fixed
+                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
+                // SAFETY: - INTERFACE requires that args[4] contains a fixed
+                let arg4 = unsafe { Fixed::from_wire(args[4].f) };
+                // SAFETY: - INTERFACE requires that args[5] contains a fixed
+                let arg5 = unsafe { Fixed::from_wire(args[5].f) };
+                self.0
+                    .relative_motion(data, slf, arg0, arg1, arg2, arg3, arg4, arg5);
+            }
+            _ => {
+                invalid_opcode("zwp_relative_pointer_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpRelativePointerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpRelativePointerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for relative_motion events.
+    pub struct RelativeMotion<F>(F);
+    impl<F> ZwpRelativePointerV1EventHandler for RelativeMotion<F>
+    where
+        F: Fn(&ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn relative_motion(
+            &self,
+            _slf: &ZwpRelativePointerV1Ref,
+            utime_hi: u32,
+            utime_lo: u32,
+            dx: Fixed,
+            dy: Fixed,
+            dx_unaccel: Fixed,
+            dy_unaccel: Fixed,
+        ) {
+            self.0(_slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel)
+        }
+    }
+    impl<T, F> ZwpRelativePointerV1EventHandlerWithData<T> for RelativeMotion<F>
+    where
+        F: Fn(&mut T, &ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn relative_motion(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpRelativePointerV1Ref,
+            utime_hi: u32,
+            utime_lo: u32,
+            dx: Fixed,
+            dy: Fixed,
+            dx_unaccel: Fixed,
+            dy_unaccel: Fixed,
+        ) {
+            self.0(
+                _data, _slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel,
+            )
+        }
+    }
+
+    /// Mutable event handler for relative_motion events.
+    pub struct RelativeMotionMut<F>(F);
+    impl<F> ZwpRelativePointerV1EventHandlerMut for RelativeMotionMut<F>
+    where
+        F: FnMut(&ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn relative_motion(
+            &mut self,
+            _slf: &ZwpRelativePointerV1Ref,
+            utime_hi: u32,
+            utime_lo: u32,
+            dx: Fixed,
+            dy: Fixed,
+            dx_unaccel: Fixed,
+            dy_unaccel: Fixed,
+        ) {
+            self.0(_slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel)
+        }
+    }
+    impl<T, F> ZwpRelativePointerV1EventHandlerMutWithData<T> for RelativeMotionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+    {
+        #[inline]
+        fn relative_motion(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpRelativePointerV1Ref,
+            utime_hi: u32,
+            utime_lo: u32,
+            dx: Fixed,
+            dy: Fixed,
+            dx_unaccel: Fixed,
+            dy_unaccel: Fixed,
+        ) {
+            self.0(
+                _data, _slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel,
+            )
+        }
+    }
+
+    impl ZwpRelativePointerV1 {
+        /// Creates an event handler for relative_motion events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_relative_motion<F>(f: F) -> RelativeMotion<F>
+        where
+            F: Fn(&ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            RelativeMotion(f)
+        }
+
+        /// Creates an event handler with mutable data for relative_motion events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_relative_motion_with_data<T, F>(f: F) -> RelativeMotion<F>
+        where
+            F: Fn(&mut T, &ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            RelativeMotion(f)
+        }
+
+        /// Creates a mutable event handler for relative_motion events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_relative_motion_mut<F>(f: F) -> RelativeMotionMut<F>
+        where
+            F: FnMut(&ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            RelativeMotionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for relative_motion events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_relative_motion_mut_with_data<T, F>(f: F) -> RelativeMotionMut<F>
+        where
+            F: FnMut(&mut T, &ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            RelativeMotionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                relative_motion: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpRelativePointerV1EventHandler for Ignore {}
+    impl<T> ZwpRelativePointerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpRelativePointerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        relative_motion: H0,
+    }
+
+    impl<H0> ZwpRelativePointerV1EventHandler for Handler<H0>
+    where
+        H0: ZwpRelativePointerV1EventHandler,
+    {
+        #[inline]
+        fn relative_motion(
+            &self,
+            _slf: &ZwpRelativePointerV1Ref,
+            utime_hi: u32,
+            utime_lo: u32,
+            dx: Fixed,
+            dy: Fixed,
+            dx_unaccel: Fixed,
+            dy_unaccel: Fixed,
+        ) {
+            self.relative_motion
+                .relative_motion(_slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel)
+        }
+    }
+
+    impl<T, H0> ZwpRelativePointerV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZwpRelativePointerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn relative_motion(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpRelativePointerV1Ref,
+            utime_hi: u32,
+            utime_lo: u32,
+            dx: Fixed,
+            dy: Fixed,
+            dx_unaccel: Fixed,
+            dy_unaccel: Fixed,
+        ) {
+            self.relative_motion.relative_motion(
+                _data, _slf, utime_hi, utime_lo, dx, dy, dx_unaccel, dy_unaccel,
+            )
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for relative_motion events.
+        #[allow(dead_code)]
+        pub fn on_relative_motion<F>(self, f: F) -> Handler<RelativeMotion<F>>
+        where
+            F: Fn(&ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            Handler {
+                relative_motion: RelativeMotion(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for relative_motion events.
+        #[allow(dead_code)]
+        pub fn on_relative_motion_with_data<T, F>(self, f: F) -> Handler<RelativeMotion<F>>
+        where
+            F: Fn(&mut T, &ZwpRelativePointerV1Ref, u32, u32, Fixed, Fixed, Fixed, Fixed),
+        {
+            Handler {
+                relative_motion: RelativeMotion(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,517 @@
+//! client security context manager
+//!
+//! This interface allows a client to register a new Wayland connection to
+//! the compositor and attach a security context to it.
+//!
+//! This is intended to be used by sandboxes. Sandbox engines attach a
+//! security context to all connections coming from inside the sandbox. The
+//! compositor can then restrict the features that the sandboxed connections
+//! can use.
+//!
+//! Compositors should forbid nesting multiple security contexts by not
+//! exposing wp_security_context_manager_v1 global to clients with a security
+//! context attached, or by sending the nested protocol error. Nested
+//! security contexts are dangerous because they can potentially allow
+//! privilege escalation of a sandboxed client.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_security_context_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_listener".as_ptr(),
+                signature: c"nhh".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] =
+                        [Some(WpSecurityContextV1::WL_INTERFACE), None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_security_context_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_security_context_manager_v1",
+    protocol: "security_context_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "create_listener",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_security_context_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "listen_fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "close_fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_listen_fd",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "nested",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_security_context_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpSecurityContextManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_security_context_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpSecurityContextManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpSecurityContextManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpSecurityContextManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpSecurityContextManagerV1 {
+    const INTERFACE: &'static str = "wp_security_context_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpSecurityContextManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpSecurityContextManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpSecurityContextManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpSecurityContextManagerV1Ref {
+    type Owned = WpSecurityContextManagerV1;
+}
+
+impl Deref for WpSecurityContextManagerV1 {
+    type Target = WpSecurityContextManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpSecurityContextManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_security_context_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpSecurityContextManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_security_context_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpSecurityContextManagerV1Ref> for WpSecurityContextManagerV1 {
+    fn eq(&self, other: &WpSecurityContextManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpSecurityContextManagerV1> for WpSecurityContextManagerV1Ref {
+    fn eq(&self, other: &WpSecurityContextManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpSecurityContextManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager object
+    ///
+    /// Destroy the manager. This doesn't destroy objects created with the
+    /// manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the create_listener request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_LISTENER__SINCE: u32 = 1;
+
+    /// create a new security context
+    ///
+    /// Creates a new security context with a socket listening FD.
+    ///
+    /// The compositor will accept new client connections on listen_fd.
+    /// listen_fd must be ready to accept new connections when this request is
+    /// sent by the client. In other words, the client must call bind(2) and
+    /// listen(2) before sending the FD.
+    ///
+    /// close_fd is a FD that will signal hangup when the compositor should stop
+    /// accepting new connections on listen_fd.
+    ///
+    /// The compositor must continue to accept connections on listen_fd when
+    /// the Wayland client which created the security context disconnects.
+    ///
+    /// After sending this request, closing listen_fd and close_fd remains the
+    /// only valid operation on them.
+    ///
+    /// # Arguments
+    ///
+    /// - `listen_fd`: listening socket FD
+    /// - `close_fd`: FD signaling when done
+    #[inline]
+    pub fn create_listener(
+        &self,
+        listen_fd: BorrowedFd<'_>,
+        close_fd: BorrowedFd<'_>,
+    ) -> WpSecurityContextV1 {
+        let (arg1, arg2) = (listen_fd, close_fd);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument {
+                h: arg1.as_raw_fd(),
+            },
+            wl_argument {
+                h: arg2.as_raw_fd(),
+            },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `nhh`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpSecurityContextV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpSecurityContextV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpSecurityContextManagerV1Ref {
+    /// create a new security context
+    ///
+    /// Creates a new security context with a socket listening FD.
+    ///
+    /// The compositor will accept new client connections on listen_fd.
+    /// listen_fd must be ready to accept new connections when this request is
+    /// sent by the client. In other words, the client must call bind(2) and
+    /// listen(2) before sending the FD.
+    ///
+    /// close_fd is a FD that will signal hangup when the compositor should stop
+    /// accepting new connections on listen_fd.
+    ///
+    /// The compositor must continue to accept connections on listen_fd when
+    /// the Wayland client which created the security context disconnects.
+    ///
+    /// After sending this request, closing listen_fd and close_fd remains the
+    /// only valid operation on them.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `listen_fd`: listening socket FD
+    /// - `close_fd`: FD signaling when done
+    #[inline]
+    pub fn create_listener(
+        &self,
+        _queue: &Queue,
+        listen_fd: BorrowedFd<'_>,
+        close_fd: BorrowedFd<'_>,
+    ) -> WpSecurityContextV1 {
+        let (arg1, arg2) = (listen_fd, close_fd);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument {
+                h: arg1.as_raw_fd(),
+            },
+            wl_argument {
+                h: arg2.as_raw_fd(),
+            },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `nhh`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpSecurityContextV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpSecurityContextV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpSecurityContextManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpSecurityContextManagerV1EventHandler {}
+
+impl WpSecurityContextManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpSecurityContextManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpSecurityContextManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpSecurityContextManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpSecurityContextManagerV1] proxies.
+///
+/// This trait is the same as [WpSecurityContextManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpSecurityContextManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpSecurityContextManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpSecurityContextManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpSecurityContextManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpSecurityContextManagerV1EventHandler for MutEventHandler<H> where
+    H: WpSecurityContextManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> WpSecurityContextManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpSecurityContextManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpSecurityContextManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpSecurityContextManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpSecurityContextManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpSecurityContextManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpSecurityContextManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_security_context_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpSecurityContextManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_security_context_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpSecurityContextManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpSecurityContextManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpSecurityContextManagerV1 {
+    /// Since when the error.invalid_listen_fd enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_LISTEN_FD__SINCE: u32 = 1;
+    /// Since when the error.nested enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NESTED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpSecurityContextManagerV1Error(pub u32);
+
+impl WpSecurityContextManagerV1Error {
+    /// listening socket FD is invalid
+    #[allow(dead_code)]
+    pub const INVALID_LISTEN_FD: Self = Self(1);
+
+    /// nested security contexts are forbidden
+    #[allow(dead_code)]
+    pub const NESTED: Self = Self(2);
+}
+
+impl Debug for WpSecurityContextManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_LISTEN_FD => "INVALID_LISTEN_FD",
+            Self::NESTED => "NESTED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpSecurityContextManagerV1 {}
+}
@@ -0,0 +1,572 @@
+//! client security context
+//!
+//! The security context allows a client to register a new client and attach
+//! security context metadata to the connections.
+//!
+//! When both are set, the combination of the application ID and the sandbox
+//! engine must uniquely identify an application. The same application ID
+//! will be used across instances (e.g. if the application is restarted, or
+//! if the application is started multiple times).
+//!
+//! When both are set, the combination of the instance ID and the sandbox
+//! engine must uniquely identify a running instance of an application.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_security_context_v1".as_ptr(),
+    version: 1,
+    method_count: 5,
+    methods: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_sandbox_engine".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_app_id".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_instance_id".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_security_context_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_security_context_v1",
+    protocol: "security_context_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_sandbox_engine",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_app_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "app_id",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_instance_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "instance_id",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "commit",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "already_used",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "already_set",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_metadata",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_security_context_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpSecurityContextV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_security_context_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpSecurityContextV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpSecurityContextV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpSecurityContextV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpSecurityContextV1 {
+    const INTERFACE: &'static str = "wp_security_context_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpSecurityContextV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpSecurityContextV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpSecurityContextV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpSecurityContextV1Ref {
+    type Owned = WpSecurityContextV1;
+}
+
+impl Deref for WpSecurityContextV1 {
+    type Target = WpSecurityContextV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpSecurityContextV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_security_context_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpSecurityContextV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_security_context_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpSecurityContextV1Ref> for WpSecurityContextV1 {
+    fn eq(&self, other: &WpSecurityContextV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpSecurityContextV1> for WpSecurityContextV1Ref {
+    fn eq(&self, other: &WpSecurityContextV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpSecurityContextV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the security context object
+    ///
+    /// Destroy the security context object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpSecurityContextV1Ref {
+    /// set the sandbox engine
+    ///
+    /// Attach a unique sandbox engine name to the security context. The name
+    /// should follow the reverse-DNS style (e.g. "org.flatpak").
+    ///
+    /// A list of well-known engines is maintained at:
+    /// https://gitlab.freedesktop.org/wayland/wayland-protocols/-/blob/main/staging/security-context/engines.md
+    ///
+    /// It is a protocol error to call this request twice. The already_set
+    /// error is sent in this case.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: the sandbox engine name
+    #[inline]
+    pub fn set_sandbox_engine(&self, name: &str) {
+        let (arg0,) = (name,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 5
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(1, &mut args);
+            }
+        })
+    }
+
+    /// set the application ID
+    ///
+    /// Attach an application ID to the security context.
+    ///
+    /// The application ID is an opaque, sandbox-specific identifier for an
+    /// application. See the well-known engines document for more details:
+    /// https://gitlab.freedesktop.org/wayland/wayland-protocols/-/blob/main/staging/security-context/engines.md
+    ///
+    /// The compositor may use the application ID to group clients belonging to
+    /// the same security context application.
+    ///
+    /// Whether this request is optional or not depends on the sandbox engine used.
+    ///
+    /// It is a protocol error to call this request twice. The already_set
+    /// error is sent in this case.
+    ///
+    /// # Arguments
+    ///
+    /// - `app_id`: the application ID
+    #[inline]
+    pub fn set_app_id(&self, app_id: &str) {
+        let (arg0,) = (app_id,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 2 < INTERFACE.method_count = 5
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(2, &mut args);
+            }
+        })
+    }
+
+    /// set the instance ID
+    ///
+    /// Attach an instance ID to the security context.
+    ///
+    /// The instance ID is an opaque, sandbox-specific identifier for a running
+    /// instance of an application. See the well-known engines document for
+    /// more details:
+    /// https://gitlab.freedesktop.org/wayland/wayland-protocols/-/blob/main/staging/security-context/engines.md
+    ///
+    /// Whether this request is optional or not depends on the sandbox engine used.
+    ///
+    /// It is a protocol error to call this request twice. The already_set
+    /// error is sent in this case.
+    ///
+    /// # Arguments
+    ///
+    /// - `instance_id`: the instance ID
+    #[inline]
+    pub fn set_instance_id(&self, instance_id: &str) {
+        let (arg0,) = (instance_id,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 3 < INTERFACE.method_count = 5
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(3, &mut args);
+            }
+        })
+    }
+
+    /// register the security context
+    ///
+    /// Atomically register the new client and attach the security context
+    /// metadata.
+    ///
+    /// If the provided metadata is inconsistent or does not match with out of
+    /// band metadata (see
+    /// https://gitlab.freedesktop.org/wayland/wayland-protocols/-/blob/main/staging/security-context/engines.md),
+    /// the invalid_metadata error may be sent eventually.
+    ///
+    /// It's a protocol error to send any request other than "destroy" after
+    /// this request. In this case, the already_used error is sent.
+    #[inline]
+    pub fn commit(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(4, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpSecurityContextV1] proxies.
+#[allow(dead_code)]
+pub trait WpSecurityContextV1EventHandler {}
+
+impl WpSecurityContextV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpSecurityContextV1] proxies with mutable data.
+///
+/// This trait is the same as [WpSecurityContextV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpSecurityContextV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpSecurityContextV1] proxies.
+///
+/// This trait is the same as [WpSecurityContextV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpSecurityContextV1EventHandlerMut {}
+
+/// A mutable event handler for [WpSecurityContextV1] proxies with mutable data.
+///
+/// This trait is the same as [WpSecurityContextV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpSecurityContextV1EventHandlerMutWithData<T> {}
+
+impl<H> WpSecurityContextV1EventHandler for MutEventHandler<H> where
+    H: WpSecurityContextV1EventHandlerMut
+{
+}
+
+impl<T, H> WpSecurityContextV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpSecurityContextV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpSecurityContextV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpSecurityContextV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpSecurityContextV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpSecurityContextV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpSecurityContextV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_security_context_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpSecurityContextV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_security_context_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpSecurityContextV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpSecurityContextV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpSecurityContextV1 {
+    /// Since when the error.already_used enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_USED__SINCE: u32 = 1;
+    /// Since when the error.already_set enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_SET__SINCE: u32 = 1;
+    /// Since when the error.invalid_metadata enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_METADATA__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpSecurityContextV1Error(pub u32);
+
+impl WpSecurityContextV1Error {
+    /// security context has already been committed
+    #[allow(dead_code)]
+    pub const ALREADY_USED: Self = Self(1);
+
+    /// metadata has already been set
+    #[allow(dead_code)]
+    pub const ALREADY_SET: Self = Self(2);
+
+    /// metadata is invalid
+    #[allow(dead_code)]
+    pub const INVALID_METADATA: Self = Self(3);
+}
+
+impl Debug for WpSecurityContextV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_USED => "ALREADY_USED",
+            Self::ALREADY_SET => "ALREADY_SET",
+            Self::INVALID_METADATA => "INVALID_METADATA",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpSecurityContextV1 {}
+}
@@ -0,0 +1,31 @@
+//! single pixel buffer factory
+//!
+//! This protocol extension allows clients to create single-pixel buffers.
+//!
+//! Compositors supporting this protocol extension should also support the
+//! viewporter protocol extension. Clients may use viewporter to scale a
+//! single-pixel buffer to a desired size.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod wp_single_pixel_buffer_manager_v1;
+
+/// Metadata about the single_pixel_buffer_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "single_pixel_buffer_v1",
+    interfaces: &[&wp_single_pixel_buffer_manager_v1::INFO],
+};
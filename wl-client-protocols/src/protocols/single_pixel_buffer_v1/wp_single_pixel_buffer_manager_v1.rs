@@ -0,0 +1,449 @@
+//! global factory for single-pixel buffers
+//!
+//! The wp_single_pixel_buffer_manager_v1 interface is a factory for
+//! single-pixel buffers.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_single_pixel_buffer_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_u32_rgba_buffer".as_ptr(),
+                signature: c"nuuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] =
+                        [Some(WlBuffer::WL_INTERFACE), None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_single_pixel_buffer_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_single_pixel_buffer_manager_v1",
+    protocol: "single_pixel_buffer_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "create_u32_rgba_buffer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_buffer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "r",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "g",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "b",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "a",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned wp_single_pixel_buffer_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpSinglePixelBufferManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_single_pixel_buffer_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpSinglePixelBufferManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpSinglePixelBufferManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpSinglePixelBufferManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpSinglePixelBufferManagerV1 {
+    const INTERFACE: &'static str = "wp_single_pixel_buffer_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpSinglePixelBufferManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpSinglePixelBufferManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpSinglePixelBufferManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpSinglePixelBufferManagerV1Ref {
+    type Owned = WpSinglePixelBufferManagerV1;
+}
+
+impl Deref for WpSinglePixelBufferManagerV1 {
+    type Target = WpSinglePixelBufferManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpSinglePixelBufferManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_single_pixel_buffer_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpSinglePixelBufferManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_single_pixel_buffer_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpSinglePixelBufferManagerV1Ref> for WpSinglePixelBufferManagerV1 {
+    fn eq(&self, other: &WpSinglePixelBufferManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpSinglePixelBufferManagerV1> for WpSinglePixelBufferManagerV1Ref {
+    fn eq(&self, other: &WpSinglePixelBufferManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpSinglePixelBufferManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the manager
+    ///
+    /// Destroy the wp_single_pixel_buffer_manager_v1 object.
+    ///
+    /// The child objects created via this interface are unaffected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the create_u32_rgba_buffer request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_U32_RGBA_BUFFER__SINCE: u32 = 1;
+
+    /// create a 1×1 buffer from 32-bit RGBA values
+    ///
+    /// Create a single-pixel buffer from four 32-bit RGBA values.
+    ///
+    /// Unless specified in another protocol extension, the RGBA values use
+    /// pre-multiplied alpha.
+    ///
+    /// The width and height of the buffer are 1.
+    ///
+    /// The r, g, b and a arguments valid range is from UINT32_MIN (0)
+    /// to UINT32_MAX (0xffffffff).
+    ///
+    /// These arguments should be interpreted as a percentage, i.e.
+    /// - UINT32_MIN = 0% of the given color component
+    /// - UINT32_MAX = 100% of the given color component
+    ///
+    /// # Arguments
+    ///
+    /// - `r`: value of the buffer's red channel
+    /// - `g`: value of the buffer's green channel
+    /// - `b`: value of the buffer's blue channel
+    /// - `a`: value of the buffer's alpha channel
+    #[inline]
+    pub fn create_u32_rgba_buffer(&self, r: u32, g: u32, b: u32, a: u32) -> WlBuffer {
+        let (arg1, arg2, arg3, arg4) = (r, g, b, a);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `nuuuu`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, WlBuffer::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WlBuffer::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpSinglePixelBufferManagerV1Ref {
+    /// create a 1×1 buffer from 32-bit RGBA values
+    ///
+    /// Create a single-pixel buffer from four 32-bit RGBA values.
+    ///
+    /// Unless specified in another protocol extension, the RGBA values use
+    /// pre-multiplied alpha.
+    ///
+    /// The width and height of the buffer are 1.
+    ///
+    /// The r, g, b and a arguments valid range is from UINT32_MIN (0)
+    /// to UINT32_MAX (0xffffffff).
+    ///
+    /// These arguments should be interpreted as a percentage, i.e.
+    /// - UINT32_MIN = 0% of the given color component
+    /// - UINT32_MAX = 100% of the given color component
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `r`: value of the buffer's red channel
+    /// - `g`: value of the buffer's green channel
+    /// - `b`: value of the buffer's blue channel
+    /// - `a`: value of the buffer's alpha channel
+    #[inline]
+    pub fn create_u32_rgba_buffer(
+        &self,
+        _queue: &Queue,
+        r: u32,
+        g: u32,
+        b: u32,
+        a: u32,
+    ) -> WlBuffer {
+        let (arg1, arg2, arg3, arg4) = (r, g, b, a);
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { u: arg1 },
+            wl_argument { u: arg2 },
+            wl_argument { u: arg3 },
+            wl_argument { u: arg4 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `nuuuu`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, WlBuffer::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface WlBuffer::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpSinglePixelBufferManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpSinglePixelBufferManagerV1EventHandler {}
+
+impl WpSinglePixelBufferManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpSinglePixelBufferManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpSinglePixelBufferManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpSinglePixelBufferManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpSinglePixelBufferManagerV1] proxies.
+///
+/// This trait is the same as [WpSinglePixelBufferManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpSinglePixelBufferManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpSinglePixelBufferManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpSinglePixelBufferManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpSinglePixelBufferManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpSinglePixelBufferManagerV1EventHandler for MutEventHandler<H> where
+    H: WpSinglePixelBufferManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> WpSinglePixelBufferManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpSinglePixelBufferManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpSinglePixelBufferManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpSinglePixelBufferManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpSinglePixelBufferManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: WpSinglePixelBufferManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpSinglePixelBufferManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_single_pixel_buffer_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpSinglePixelBufferManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_single_pixel_buffer_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpSinglePixelBufferManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpSinglePixelBufferManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpSinglePixelBufferManagerV1 {}
+}
@@ -0,0 +1,385 @@
+//! controller object for graphic tablet devices
+//!
+//! An object that provides access to the graphics tablets available on this
+//! system. All tablets are associated with a seat, to get access to the
+//! actual tablets, use wp_tablet_manager.get_tablet_seat.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_tablet_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"get_tablet_seat".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpTabletSeatV1::WL_INTERFACE),
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_tablet_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_manager_v1",
+    protocol: "tablet_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "get_tablet_seat",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "tablet_seat",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_tablet_seat_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_tablet_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTabletManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_tablet_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTabletManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTabletManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTabletManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTabletManagerV1 {
+    const INTERFACE: &'static str = "zwp_tablet_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpTabletManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTabletManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTabletManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTabletManagerV1Ref {
+    type Owned = ZwpTabletManagerV1;
+}
+
+impl Deref for ZwpTabletManagerV1 {
+    type Target = ZwpTabletManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTabletManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_tablet_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTabletManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_tablet_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTabletManagerV1Ref> for ZwpTabletManagerV1 {
+    fn eq(&self, other: &ZwpTabletManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTabletManagerV1> for ZwpTabletManagerV1Ref {
+    fn eq(&self, other: &ZwpTabletManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTabletManagerV1 {
+    /// Since when the get_tablet_seat request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_TABLET_SEAT__SINCE: u32 = 1;
+
+    /// get the tablet seat
+    ///
+    /// Get the wp_tablet_seat object for the given seat. This object
+    /// provides access to all graphics tablets in this seat.
+    ///
+    /// # Arguments
+    ///
+    /// - `seat`: The wl_seat object to retrieve the tablets for
+    #[inline]
+    pub fn get_tablet_seat(&self, seat: &WlSeatRef) -> ZwpTabletSeatV1 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(0, &mut args, ZwpTabletSeatV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZwpTabletSeatV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// release the memory for the tablet manager object
+    ///
+    /// Destroy the wp_tablet_manager object. Objects created from this
+    /// object are unaffected and should be destroyed separately.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTabletManagerV1Ref {
+    /// get the tablet seat
+    ///
+    /// Get the wp_tablet_seat object for the given seat. This object
+    /// provides access to all graphics tablets in this seat.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `seat`: The wl_seat object to retrieve the tablets for
+    #[inline]
+    pub fn get_tablet_seat(&self, _queue: &Queue, seat: &WlSeatRef) -> ZwpTabletSeatV1 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 0, &mut args, ZwpTabletSeatV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZwpTabletSeatV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpTabletManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV1EventHandler {}
+
+impl ZwpTabletManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTabletManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpTabletManagerV1] proxies.
+///
+/// This trait is the same as [ZwpTabletManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpTabletManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpTabletManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpTabletManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpTabletManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpTabletManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpTabletManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTabletManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpTabletManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTabletManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTabletManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_tablet_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_tablet_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTabletManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpTabletManagerV1 {}
+}
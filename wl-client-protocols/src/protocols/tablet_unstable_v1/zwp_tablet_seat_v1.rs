@@ -0,0 +1,847 @@
+//! controller object for graphic tablet devices of a seat
+//!
+//! An object that provides access to the graphics tablets available on this
+//! seat. After binding to this interface, the compositor sends a set of
+//! wp_tablet_seat.tablet_added and wp_tablet_seat.tool_added events.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_tablet_seat_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"tablet_added".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpTabletV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"tool_added".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpTabletToolV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_tablet_seat_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_seat_v1",
+    protocol: "tablet_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "tablet_added",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tool_added",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_tool_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_tablet_seat_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTabletSeatV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_tablet_seat_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTabletSeatV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTabletSeatV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTabletSeatV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTabletSeatV1 {
+    const INTERFACE: &'static str = "zwp_tablet_seat_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpTabletSeatV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTabletSeatV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTabletSeatV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTabletSeatV1Ref {
+    type Owned = ZwpTabletSeatV1;
+}
+
+impl Deref for ZwpTabletSeatV1 {
+    type Target = ZwpTabletSeatV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTabletSeatV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_tablet_seat_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTabletSeatV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_tablet_seat_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTabletSeatV1Ref> for ZwpTabletSeatV1 {
+    fn eq(&self, other: &ZwpTabletSeatV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTabletSeatV1> for ZwpTabletSeatV1Ref {
+    fn eq(&self, other: &ZwpTabletSeatV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTabletSeatV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// release the memory for the tablet seat object
+    ///
+    /// Destroy the wp_tablet_seat object. Objects created from this
+    /// object are unaffected and should be destroyed separately.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZwpTabletSeatV1 {
+    /// Since when the tablet_added event is available.
+    #[allow(dead_code)]
+    pub const EVT__TABLET_ADDED__SINCE: u32 = 1;
+
+    /// Since when the tool_added event is available.
+    #[allow(dead_code)]
+    pub const EVT__TOOL_ADDED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpTabletSeatV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV1EventHandler {
+    /// new device notification
+    ///
+    /// This event is sent whenever a new tablet becomes available on this
+    /// seat. This event only provides the object id of the tablet, any
+    /// static information about the tablet (device name, vid/pid, etc.) is
+    /// sent through the wp_tablet interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added graphics tablet
+    #[inline]
+    fn tablet_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        let _ = id;
+    }
+
+    /// a new tool has been used with a tablet
+    ///
+    /// This event is sent whenever a tool that has not previously been used
+    /// with a tablet comes into use. This event only provides the object id
+    /// of the tool; any static information about the tool (capabilities,
+    /// type, etc.) is sent through the wp_tablet_tool interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added tablet tool
+    #[inline]
+    fn tool_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        let _ = id;
+    }
+}
+
+impl ZwpTabletSeatV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTabletSeatV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletSeatV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV1EventHandlerWithData<T> {
+    /// new device notification
+    ///
+    /// This event is sent whenever a new tablet becomes available on this
+    /// seat. This event only provides the object id of the tablet, any
+    /// static information about the tablet (device name, vid/pid, etc.) is
+    /// sent through the wp_tablet interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added graphics tablet
+    #[inline]
+    fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        let _ = id;
+    }
+
+    /// a new tool has been used with a tablet
+    ///
+    /// This event is sent whenever a tool that has not previously been used
+    /// with a tablet comes into use. This event only provides the object id
+    /// of the tool; any static information about the tool (capabilities,
+    /// type, etc.) is sent through the wp_tablet_tool interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added tablet tool
+    #[inline]
+    fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletSeatV1] proxies.
+///
+/// This trait is the same as [ZwpTabletSeatV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV1EventHandlerMut {
+    /// See [ZwpTabletSeatV1EventHandler::tablet_added].
+    #[inline]
+    fn tablet_added(&mut self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        let _ = id;
+    }
+
+    /// See [ZwpTabletSeatV1EventHandler::tool_added].
+    #[inline]
+    fn tool_added(&mut self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletSeatV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletSeatV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV1EventHandlerMutWithData<T> {
+    /// See [ZwpTabletSeatV1EventHandler::tablet_added].
+    #[inline]
+    fn tablet_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        let _ = id;
+    }
+
+    /// See [ZwpTabletSeatV1EventHandler::tool_added].
+    #[inline]
+    fn tool_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        let _ = id;
+    }
+}
+
+impl<H> ZwpTabletSeatV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletSeatV1EventHandlerMut,
+{
+    #[inline]
+    fn tablet_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        self.with(|h| h.tablet_added(_slf, id))
+    }
+
+    #[inline]
+    fn tool_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        self.with(|h| h.tool_added(_slf, id))
+    }
+}
+
+impl<T, H> ZwpTabletSeatV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletSeatV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        self.with(|h| h.tablet_added(_data, _slf, id))
+    }
+
+    #[inline]
+    fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        self.with(|h| h.tool_added(_data, _slf, id))
+    }
+}
+
+impl<T, U, H> ZwpTabletSeatV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletSeatV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        let _data = self.project(_data);
+        self.handler().tablet_added(_data, _slf, id)
+    }
+
+    #[inline]
+    fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        let _data = self.project(_data);
+        self.handler().tool_added(_data, _slf, id)
+    }
+}
+
+impl<T, U, H> ZwpTabletSeatV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletSeatV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn tablet_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+        let _data = self.project(_data);
+        self.handler_mut().tablet_added(_data, _slf, id)
+    }
+
+    #[inline]
+    fn tool_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+        let _data = self.project(_data);
+        self.handler_mut().tool_added(_data, _slf, id)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTabletSeatV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletSeatV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletV1::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletV1>(arg0) };
+                self.0.tablet_added(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletToolV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletToolV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletToolV1::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletToolV1>(arg0) };
+                self.0.tool_added(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_seat_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletSeatV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletSeatV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletV1::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletV1>(arg0) };
+                self.0.tablet_added(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletToolV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletToolV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletToolV1::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletToolV1>(arg0) };
+                self.0.tool_added(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_seat_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTabletSeatV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletSeatV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for tablet_added events.
+    pub struct TabletAdded<F>(F);
+    impl<F> ZwpTabletSeatV1EventHandler for TabletAdded<F>
+    where
+        F: Fn(&ZwpTabletSeatV1Ref, ZwpTabletV1),
+    {
+        #[inline]
+        fn tablet_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV1EventHandlerWithData<T> for TabletAdded<F>
+    where
+        F: Fn(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletV1),
+    {
+        #[inline]
+        fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Event handler for tool_added events.
+    pub struct ToolAdded<F>(F);
+    impl<F> ZwpTabletSeatV1EventHandler for ToolAdded<F>
+    where
+        F: Fn(&ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+    {
+        #[inline]
+        fn tool_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV1EventHandlerWithData<T> for ToolAdded<F>
+    where
+        F: Fn(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+    {
+        #[inline]
+        fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for tablet_added events.
+    pub struct TabletAddedMut<F>(F);
+    impl<F> ZwpTabletSeatV1EventHandlerMut for TabletAddedMut<F>
+    where
+        F: FnMut(&ZwpTabletSeatV1Ref, ZwpTabletV1),
+    {
+        #[inline]
+        fn tablet_added(&mut self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV1EventHandlerMutWithData<T> for TabletAddedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletV1),
+    {
+        #[inline]
+        fn tablet_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for tool_added events.
+    pub struct ToolAddedMut<F>(F);
+    impl<F> ZwpTabletSeatV1EventHandlerMut for ToolAddedMut<F>
+    where
+        F: FnMut(&ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+    {
+        #[inline]
+        fn tool_added(&mut self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV1EventHandlerMutWithData<T> for ToolAddedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+    {
+        #[inline]
+        fn tool_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    impl ZwpTabletSeatV1 {
+        /// Creates an event handler for tablet_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added<F>(f: F) -> TabletAdded<F>
+        where
+            F: Fn(&ZwpTabletSeatV1Ref, ZwpTabletV1),
+        {
+            TabletAdded(f)
+        }
+
+        /// Creates an event handler with mutable data for tablet_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added_with_data<T, F>(f: F) -> TabletAdded<F>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletV1),
+        {
+            TabletAdded(f)
+        }
+
+        /// Creates an event handler for tool_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tool_added<F>(f: F) -> ToolAdded<F>
+        where
+            F: Fn(&ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+        {
+            ToolAdded(f)
+        }
+
+        /// Creates an event handler with mutable data for tool_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tool_added_with_data<T, F>(f: F) -> ToolAdded<F>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+        {
+            ToolAdded(f)
+        }
+
+        /// Creates a mutable event handler for tablet_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tablet_added_mut<F>(f: F) -> TabletAddedMut<F>
+        where
+            F: FnMut(&ZwpTabletSeatV1Ref, ZwpTabletV1),
+        {
+            TabletAddedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tablet_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tablet_added_mut_with_data<T, F>(f: F) -> TabletAddedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletV1),
+        {
+            TabletAddedMut(f)
+        }
+
+        /// Creates a mutable event handler for tool_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tool_added_mut<F>(f: F) -> ToolAddedMut<F>
+        where
+            F: FnMut(&ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+        {
+            ToolAddedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tool_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tool_added_mut_with_data<T, F>(f: F) -> ToolAddedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+        {
+            ToolAddedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                tablet_added: Ignore,
+                tool_added: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletSeatV1EventHandler for Ignore {}
+    impl<T> ZwpTabletSeatV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletSeatV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        tablet_added: H0,
+        tool_added: H1,
+    }
+
+    impl<H0, H1> ZwpTabletSeatV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpTabletSeatV1EventHandler,
+        H1: ZwpTabletSeatV1EventHandler,
+    {
+        #[inline]
+        fn tablet_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+            self.tablet_added.tablet_added(_slf, id)
+        }
+
+        #[inline]
+        fn tool_added(&self, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+            self.tool_added.tool_added(_slf, id)
+        }
+    }
+
+    impl<T, H0, H1> ZwpTabletSeatV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpTabletSeatV1EventHandlerWithData<T>,
+        H1: ZwpTabletSeatV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletV1) {
+            self.tablet_added.tablet_added(_data, _slf, id)
+        }
+
+        #[inline]
+        fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV1Ref, id: ZwpTabletToolV1) {
+            self.tool_added.tool_added(_data, _slf, id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for tablet_added events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added<F>(self, f: F) -> Handler<TabletAdded<F>, H1>
+        where
+            F: Fn(&ZwpTabletSeatV1Ref, ZwpTabletV1),
+        {
+            Handler {
+                tablet_added: TabletAdded(f),
+                tool_added: self.tool_added,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tablet_added events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added_with_data<T, F>(self, f: F) -> Handler<TabletAdded<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletV1),
+        {
+            Handler {
+                tablet_added: TabletAdded(f),
+                tool_added: self.tool_added,
+            }
+        }
+
+        /// Sets the closure for tool_added events.
+        #[allow(dead_code)]
+        pub fn on_tool_added<F>(self, f: F) -> Handler<H0, ToolAdded<F>>
+        where
+            F: Fn(&ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+        {
+            Handler {
+                tablet_added: self.tablet_added,
+                tool_added: ToolAdded(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tool_added events.
+        #[allow(dead_code)]
+        pub fn on_tool_added_with_data<T, F>(self, f: F) -> Handler<H0, ToolAdded<F>>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV1Ref, ZwpTabletToolV1),
+        {
+            Handler {
+                tablet_added: self.tablet_added,
+                tool_added: ToolAdded(f),
+            }
+        }
+    }
+}
@@ -7,19 +7,19 @@
 //! More than one tablet may exist, and device-specifics matter. Tablets are
 //! not represented by a single virtual device like wl_pointer. A client
 //! binds to the tablet manager object which is just a proxy object. From
-//! that, the client requests wp_tablet_manager.get_tablet_seat(wl_seat)
+//! that, the client requests zwp_tablet_manager_v2.get_tablet_seat(wl_seat)
 //! and that returns the actual interface that has all the tablets. With
-//! this indirection, we can avoid merging wp_tablet into the actual Wayland
+//! this indirection, we can avoid merging zwp_tablet_v2 into the actual Wayland
 //! protocol, a long-term benefit.
 //!
-//! The wp_tablet_seat sends a "tablet added" event for each tablet
+//! The zwp_tablet_seat_v2 sends a "tablet added" event for each tablet
 //! connected. That event is followed by descriptive events about the
 //! hardware; currently that includes events for name, vid/pid and
-//! a wp_tablet.path event that describes a local path. This path can be
+//! a zwp_tablet_v2.path event that describes a local path. This path can be
 //! used to uniquely identify a tablet or get more information through
 //! libwacom. Emulated or nested tablets can skip any of those, e.g. a
 //! virtual tablet may not have a vid/pid. The sequence of descriptive
-//! events is terminated by a wp_tablet.done event to signal that a client
+//! events is terminated by a zwp_tablet_v2.done event to signal that a client
 //! may now finalize any initialization for that tablet.
 //!
 //! Events from tablets require a tool in proximity. Tools are also managed
@@ -27,15 +27,15 @@
 //! to the compositor. That event is followed by a number of descriptive
 //! events about the hardware; currently that includes capabilities,
 //! hardware id and serial number, and tool type. Similar to the tablet
-//! interface, a wp_tablet_tool.done event is sent to terminate that initial
+//! interface, a zwp_tablet_tool_v2.done event is sent to terminate that initial
 //! sequence.
 //!
-//! Any event from a tool happens on the wp_tablet_tool interface. When the
+//! Any event from a tool happens on the zwp_tablet_tool_v2 interface. When the
 //! tool gets into proximity of the tablet, a proximity_in event is sent on
-//! the wp_tablet_tool interface, listing the tablet and the surface. That
+//! the zwp_tablet_tool_v2 interface, listing the tablet and the surface. That
 //! event is followed by a motion event with the coordinates. After that,
 //! it's the usual motion, axis, button, etc. events. The protocol's
-//! serialisation means events are grouped by wp_tablet_tool.frame events.
+//! serialisation means events are grouped by zwp_tablet_tool_v2.frame events.
 //!
 //! Two special events (that don't exist in X) are down and up. They signal
 //! "tip touching the surface". For tablets without real proximity
@@ -67,7 +67,7 @@
 //!
 //! Since tablets work independently of the pointer controlled by the mouse,
 //! the focus handling is independent too and controlled by proximity.
-//! The wp_tablet_tool.set_cursor request sets a tool-specific cursor.
+//! The zwp_tablet_tool_v2.set_cursor request sets a tool-specific cursor.
 //! This cursor surface may be the same as the mouse cursor, and it may be
 //! the same across tools but it is possible to be more fine-grained. For
 //! example, a client may set different cursors for the pen and eraser.
@@ -88,6 +88,7 @@
 #![allow(rustdoc::invalid_rust_codeblocks)]
 
 pub mod zwp_tablet_manager_v2;
+pub mod zwp_tablet_pad_dial_v2;
 pub mod zwp_tablet_pad_group_v2;
 pub mod zwp_tablet_pad_ring_v2;
 pub mod zwp_tablet_pad_strip_v2;
@@ -95,3 +96,20 @@ pub mod zwp_tablet_pad_v2;
 pub mod zwp_tablet_seat_v2;
 pub mod zwp_tablet_tool_v2;
 pub mod zwp_tablet_v2;
+
+/// Metadata about the tablet_v2 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "tablet_v2",
+    interfaces: &[
+        &zwp_tablet_manager_v2::INFO,
+        &zwp_tablet_seat_v2::INFO,
+        &zwp_tablet_tool_v2::INFO,
+        &zwp_tablet_v2::INFO,
+        &zwp_tablet_pad_ring_v2::INFO,
+        &zwp_tablet_pad_strip_v2::INFO,
+        &zwp_tablet_pad_group_v2::INFO,
+        &zwp_tablet_pad_v2::INFO,
+        &zwp_tablet_pad_dial_v2::INFO,
+    ],
+};
@@ -0,0 +1,912 @@
+//! pad dial
+//!
+//! A rotary control, e.g. a dial or a wheel.
+//!
+//! Events on a dial are logically grouped by the zwp_tablet_pad_dial_v2.frame
+//! event.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_tablet_pad_dial_v2".as_ptr(),
+    version: 2,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"set_feedback".as_ptr(),
+                signature: c"su".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"delta".as_ptr(),
+                signature: c"i".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"frame".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_tablet_pad_dial_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_pad_dial_v2",
+    protocol: "tablet_v2",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "set_feedback",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "description",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "delta",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "value120",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "frame",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "time",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_tablet_pad_dial_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTabletPadDialV2 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_tablet_pad_dial_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTabletPadDialV2Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTabletPadDialV2 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTabletPadDialV2 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTabletPadDialV2 {
+    const INTERFACE: &'static str = "zwp_tablet_pad_dial_v2";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = ZwpTabletPadDialV2Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTabletPadDialV2Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTabletPadDialV2Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTabletPadDialV2Ref {
+    type Owned = ZwpTabletPadDialV2;
+}
+
+impl Deref for ZwpTabletPadDialV2 {
+    type Target = ZwpTabletPadDialV2Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTabletPadDialV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_tablet_pad_dial_v2#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTabletPadDialV2Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_tablet_pad_dial_v2#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTabletPadDialV2Ref> for ZwpTabletPadDialV2 {
+    fn eq(&self, other: &ZwpTabletPadDialV2Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTabletPadDialV2> for ZwpTabletPadDialV2Ref {
+    fn eq(&self, other: &ZwpTabletPadDialV2) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTabletPadDialV2 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the dial object
+    ///
+    /// This destroys the client's resource for this dial object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTabletPadDialV2Ref {
+    /// set compositor feedback
+    ///
+    /// Requests the compositor to use the provided feedback string
+    /// associated with this dial. This request should be issued immediately
+    /// after a zwp_tablet_pad_group_v2.mode_switch event from the corresponding
+    /// group is received, or whenever the dial is mapped to a different
+    /// action. See zwp_tablet_pad_group_v2.mode_switch for more details.
+    ///
+    /// Clients are encouraged to provide context-aware descriptions for
+    /// the actions associated with the dial, and compositors may use this
+    /// information to offer visual feedback about the button layout
+    /// (eg. on-screen displays).
+    ///
+    /// The provided string 'description' is a UTF-8 encoded string to be
+    /// associated with this ring, and is considered user-visible; general
+    /// internationalization rules apply.
+    ///
+    /// The serial argument will be that of the last
+    /// zwp_tablet_pad_group_v2.mode_switch event received for the group of this
+    /// dial. Requests providing other serials than the most recent one will be
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: dial description
+    /// - `serial`: serial of the mode switch event
+    #[inline]
+    pub fn set_feedback(&self, description: &str, serial: u32) {
+        let (arg0, arg1) = (description, serial);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }, wl_argument { u: arg1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 0 < INTERFACE.method_count = 2
+            //         - the request signature is `su`
+            unsafe {
+                self.proxy.send_request(0, &mut args);
+            }
+        })
+    }
+}
+
+impl ZwpTabletPadDialV2 {
+    /// Since when the delta event is available.
+    #[allow(dead_code)]
+    pub const EVT__DELTA__SINCE: u32 = 1;
+
+    /// Since when the frame event is available.
+    #[allow(dead_code)]
+    pub const EVT__FRAME__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpTabletPadDialV2] proxies.
+#[allow(dead_code)]
+pub trait ZwpTabletPadDialV2EventHandler {
+    /// delta movement
+    ///
+    /// Sent whenever the position on a dial changes.
+    ///
+    /// This event carries the wheel delta as multiples or fractions
+    /// of 120 with each multiple of 120 representing one logical wheel detent.
+    /// For example, an axis_value120 of 30 is one quarter of
+    /// a logical wheel step in the positive direction, a value120 of
+    /// -240 are two logical wheel steps in the negative direction within the
+    /// same hardware event. See the wl_pointer.axis_value120 for more details.
+    ///
+    /// The value120 must not be zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `value120`: rotation distance as fraction of 120
+    #[inline]
+    fn delta(&self, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        let _ = value120;
+    }
+
+    /// end of a dial event sequence
+    ///
+    /// Indicates the end of a set of events that represent one logical
+    /// hardware dial event. A client is expected to accumulate the data
+    /// in all events within the frame before proceeding.
+    ///
+    /// All zwp_tablet_pad_dial_v2 events before a zwp_tablet_pad_dial_v2.frame event belong
+    /// logically together.
+    ///
+    /// A zwp_tablet_pad_dial_v2.frame event is sent for every logical event
+    /// group, even if the group only contains a single zwp_tablet_pad_dial_v2
+    /// event. Specifically, a client may get a sequence: delta, frame,
+    /// delta, frame, etc.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    #[inline]
+    fn frame(&self, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+impl ZwpTabletPadDialV2EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTabletPadDialV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletPadDialV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletPadDialV2EventHandlerWithData<T> {
+    /// delta movement
+    ///
+    /// Sent whenever the position on a dial changes.
+    ///
+    /// This event carries the wheel delta as multiples or fractions
+    /// of 120 with each multiple of 120 representing one logical wheel detent.
+    /// For example, an axis_value120 of 30 is one quarter of
+    /// a logical wheel step in the positive direction, a value120 of
+    /// -240 are two logical wheel steps in the negative direction within the
+    /// same hardware event. See the wl_pointer.axis_value120 for more details.
+    ///
+    /// The value120 must not be zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `value120`: rotation distance as fraction of 120
+    #[inline]
+    fn delta(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        let _ = value120;
+    }
+
+    /// end of a dial event sequence
+    ///
+    /// Indicates the end of a set of events that represent one logical
+    /// hardware dial event. A client is expected to accumulate the data
+    /// in all events within the frame before proceeding.
+    ///
+    /// All zwp_tablet_pad_dial_v2 events before a zwp_tablet_pad_dial_v2.frame event belong
+    /// logically together.
+    ///
+    /// A zwp_tablet_pad_dial_v2.frame event is sent for every logical event
+    /// group, even if the group only contains a single zwp_tablet_pad_dial_v2
+    /// event. Specifically, a client may get a sequence: delta, frame,
+    /// delta, frame, etc.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletPadDialV2] proxies.
+///
+/// This trait is the same as [ZwpTabletPadDialV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletPadDialV2EventHandlerMut {
+    /// See [ZwpTabletPadDialV2EventHandler::delta].
+    #[inline]
+    fn delta(&mut self, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        let _ = value120;
+    }
+
+    /// See [ZwpTabletPadDialV2EventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletPadDialV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletPadDialV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletPadDialV2EventHandlerMutWithData<T> {
+    /// See [ZwpTabletPadDialV2EventHandler::delta].
+    #[inline]
+    fn delta(&mut self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        let _ = value120;
+    }
+
+    /// See [ZwpTabletPadDialV2EventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+impl<H> ZwpTabletPadDialV2EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletPadDialV2EventHandlerMut,
+{
+    #[inline]
+    fn delta(&self, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        self.with(|h| h.delta(_slf, value120))
+    }
+
+    #[inline]
+    fn frame(&self, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        self.with(|h| h.frame(_slf, time))
+    }
+}
+
+impl<T, H> ZwpTabletPadDialV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletPadDialV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn delta(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        self.with(|h| h.delta(_data, _slf, value120))
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        self.with(|h| h.frame(_data, _slf, time))
+    }
+}
+
+impl<T, U, H> ZwpTabletPadDialV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletPadDialV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn delta(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        let _data = self.project(_data);
+        self.handler().delta(_data, _slf, value120)
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        let _data = self.project(_data);
+        self.handler().frame(_data, _slf, time)
+    }
+}
+
+impl<T, U, H> ZwpTabletPadDialV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletPadDialV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn delta(&mut self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().delta(_data, _slf, value120)
+    }
+
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().frame(_data, _slf, time)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTabletPadDialV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletPadDialV2Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.delta(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.frame(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_pad_dial_v2", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletPadDialV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletPadDialV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.delta(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.frame(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_pad_dial_v2", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTabletPadDialV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletPadDialV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for delta events.
+    pub struct Delta<F>(F);
+    impl<F> ZwpTabletPadDialV2EventHandler for Delta<F>
+    where
+        F: Fn(&ZwpTabletPadDialV2Ref, i32),
+    {
+        #[inline]
+        fn delta(&self, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+            self.0(_slf, value120)
+        }
+    }
+    impl<T, F> ZwpTabletPadDialV2EventHandlerWithData<T> for Delta<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadDialV2Ref, i32),
+    {
+        #[inline]
+        fn delta(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+            self.0(_data, _slf, value120)
+        }
+    }
+
+    /// Event handler for frame events.
+    pub struct Frame<F>(F);
+    impl<F> ZwpTabletPadDialV2EventHandler for Frame<F>
+    where
+        F: Fn(&ZwpTabletPadDialV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&self, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+            self.0(_slf, time)
+        }
+    }
+    impl<T, F> ZwpTabletPadDialV2EventHandlerWithData<T> for Frame<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadDialV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+            self.0(_data, _slf, time)
+        }
+    }
+
+    /// Mutable event handler for delta events.
+    pub struct DeltaMut<F>(F);
+    impl<F> ZwpTabletPadDialV2EventHandlerMut for DeltaMut<F>
+    where
+        F: FnMut(&ZwpTabletPadDialV2Ref, i32),
+    {
+        #[inline]
+        fn delta(&mut self, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+            self.0(_slf, value120)
+        }
+    }
+    impl<T, F> ZwpTabletPadDialV2EventHandlerMutWithData<T> for DeltaMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadDialV2Ref, i32),
+    {
+        #[inline]
+        fn delta(&mut self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+            self.0(_data, _slf, value120)
+        }
+    }
+
+    /// Mutable event handler for frame events.
+    pub struct FrameMut<F>(F);
+    impl<F> ZwpTabletPadDialV2EventHandlerMut for FrameMut<F>
+    where
+        F: FnMut(&ZwpTabletPadDialV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&mut self, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+            self.0(_slf, time)
+        }
+    }
+    impl<T, F> ZwpTabletPadDialV2EventHandlerMutWithData<T> for FrameMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadDialV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+            self.0(_data, _slf, time)
+        }
+    }
+
+    impl ZwpTabletPadDialV2 {
+        /// Creates an event handler for delta events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delta<F>(f: F) -> Delta<F>
+        where
+            F: Fn(&ZwpTabletPadDialV2Ref, i32),
+        {
+            Delta(f)
+        }
+
+        /// Creates an event handler with mutable data for delta events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delta_with_data<T, F>(f: F) -> Delta<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadDialV2Ref, i32),
+        {
+            Delta(f)
+        }
+
+        /// Creates an event handler for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(f: F) -> Frame<F>
+        where
+            F: Fn(&ZwpTabletPadDialV2Ref, u32),
+        {
+            Frame(f)
+        }
+
+        /// Creates an event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(f: F) -> Frame<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadDialV2Ref, u32),
+        {
+            Frame(f)
+        }
+
+        /// Creates a mutable event handler for delta events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delta_mut<F>(f: F) -> DeltaMut<F>
+        where
+            F: FnMut(&ZwpTabletPadDialV2Ref, i32),
+        {
+            DeltaMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for delta events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delta_mut_with_data<T, F>(f: F) -> DeltaMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadDialV2Ref, i32),
+        {
+            DeltaMut(f)
+        }
+
+        /// Creates a mutable event handler for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut<F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&ZwpTabletPadDialV2Ref, u32),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut_with_data<T, F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadDialV2Ref, u32),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                delta: Ignore,
+                frame: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletPadDialV2EventHandler for Ignore {}
+    impl<T> ZwpTabletPadDialV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletPadDialV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        delta: H0,
+        frame: H1,
+    }
+
+    impl<H0, H1> ZwpTabletPadDialV2EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpTabletPadDialV2EventHandler,
+        H1: ZwpTabletPadDialV2EventHandler,
+    {
+        #[inline]
+        fn delta(&self, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+            self.delta.delta(_slf, value120)
+        }
+
+        #[inline]
+        fn frame(&self, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+            self.frame.frame(_slf, time)
+        }
+    }
+
+    impl<T, H0, H1> ZwpTabletPadDialV2EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpTabletPadDialV2EventHandlerWithData<T>,
+        H1: ZwpTabletPadDialV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn delta(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, value120: i32) {
+            self.delta.delta(_data, _slf, value120)
+        }
+
+        #[inline]
+        fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadDialV2Ref, time: u32) {
+            self.frame.frame(_data, _slf, time)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for delta events.
+        #[allow(dead_code)]
+        pub fn on_delta<F>(self, f: F) -> Handler<Delta<F>, H1>
+        where
+            F: Fn(&ZwpTabletPadDialV2Ref, i32),
+        {
+            Handler {
+                delta: Delta(f),
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for delta events.
+        #[allow(dead_code)]
+        pub fn on_delta_with_data<T, F>(self, f: F) -> Handler<Delta<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpTabletPadDialV2Ref, i32),
+        {
+            Handler {
+                delta: Delta(f),
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(self, f: F) -> Handler<H0, Frame<F>>
+        where
+            F: Fn(&ZwpTabletPadDialV2Ref, u32),
+        {
+            Handler {
+                delta: self.delta,
+                frame: Frame(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(self, f: F) -> Handler<H0, Frame<F>>
+        where
+            F: Fn(&mut T, &ZwpTabletPadDialV2Ref, u32),
+        {
+            Handler {
+                delta: self.delta,
+                frame: Frame(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,453 @@
+//! protocol for tearing control
+//!
+//! For some use cases like games or drawing tablets it can make sense to
+//! reduce latency by accepting tearing with the use of asynchronous page
+//! flips. This global is a factory interface, allowing clients to inform
+//! which type of presentation the content of their surfaces is suitable for.
+//!
+//! Graphics APIs like EGL or Vulkan, that manage the buffer queue and commits
+//! of a wl_surface themselves, are likely to be using this extension
+//! internally. If a client is using such an API for a wl_surface, it should
+//! not directly use this extension on that surface, to avoid raising a
+//! tearing_control_exists protocol error.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_tearing_control_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_tearing_control".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(WpTearingControlV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_tearing_control_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_tearing_control_manager_v1",
+    protocol: "tearing_control_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_tearing_control",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_tearing_control_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "tearing_control_exists",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned wp_tearing_control_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpTearingControlManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_tearing_control_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpTearingControlManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpTearingControlManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpTearingControlManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpTearingControlManagerV1 {
+    const INTERFACE: &'static str = "wp_tearing_control_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpTearingControlManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpTearingControlManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpTearingControlManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpTearingControlManagerV1Ref {
+    type Owned = WpTearingControlManagerV1;
+}
+
+impl Deref for WpTearingControlManagerV1 {
+    type Target = WpTearingControlManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpTearingControlManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_tearing_control_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpTearingControlManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_tearing_control_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpTearingControlManagerV1Ref> for WpTearingControlManagerV1 {
+    fn eq(&self, other: &WpTearingControlManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpTearingControlManagerV1> for WpTearingControlManagerV1Ref {
+    fn eq(&self, other: &WpTearingControlManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpTearingControlManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy tearing control factory object
+    ///
+    /// Destroy this tearing control factory object. Other objects, including
+    /// wp_tearing_control_v1 objects created by this factory, are not affected
+    /// by this request.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_tearing_control request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_TEARING_CONTROL__SINCE: u32 = 1;
+
+    /// extend surface interface for tearing control
+    ///
+    /// Instantiate an interface extension for the given wl_surface to request
+    /// asynchronous page flips for presentation.
+    ///
+    /// If the given wl_surface already has a wp_tearing_control_v1 object
+    /// associated, the tearing_control_exists protocol error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_tearing_control(&self, surface: &WlSurfaceRef) -> WpTearingControlV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                WpTearingControlV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpTearingControlV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl WpTearingControlManagerV1Ref {
+    /// extend surface interface for tearing control
+    ///
+    /// Instantiate an interface extension for the given wl_surface to request
+    /// asynchronous page flips for presentation.
+    ///
+    /// If the given wl_surface already has a wp_tearing_control_v1 object
+    /// associated, the tearing_control_exists protocol error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_tearing_control(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> WpTearingControlV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                WpTearingControlV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface WpTearingControlV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [WpTearingControlManagerV1] proxies.
+#[allow(dead_code)]
+pub trait WpTearingControlManagerV1EventHandler {}
+
+impl WpTearingControlManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpTearingControlManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpTearingControlManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpTearingControlManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpTearingControlManagerV1] proxies.
+///
+/// This trait is the same as [WpTearingControlManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpTearingControlManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [WpTearingControlManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [WpTearingControlManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpTearingControlManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> WpTearingControlManagerV1EventHandler for MutEventHandler<H> where
+    H: WpTearingControlManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> WpTearingControlManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpTearingControlManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpTearingControlManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpTearingControlManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpTearingControlManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpTearingControlManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpTearingControlManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_tearing_control_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpTearingControlManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_tearing_control_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpTearingControlManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpTearingControlManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpTearingControlManagerV1 {
+    /// Since when the error.tearing_control_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_TEARING_CONTROL_EXISTS__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpTearingControlManagerV1Error(pub u32);
+
+impl WpTearingControlManagerV1Error {
+    /// the surface already has a tearing object associated
+    #[allow(dead_code)]
+    pub const TEARING_CONTROL_EXISTS: Self = Self(0);
+}
+
+impl Debug for WpTearingControlManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::TEARING_CONTROL_EXISTS => "TEARING_CONTROL_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpTearingControlManagerV1 {}
+}
@@ -0,0 +1,408 @@
+//! per-surface tearing control interface
+//!
+//! An additional interface to a wl_surface object, which allows the client
+//! to hint to the compositor if the content on the surface is suitable for
+//! presentation with tearing.
+//! The default presentation hint is vsync. See presentation_hint for more
+//! details.
+//!
+//! If the associated wl_surface is destroyed, this object becomes inert and
+//! should be destroyed.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wp_tearing_control_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"set_presentation_hint".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the wp_tearing_control_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_tearing_control_v1",
+    protocol: "tearing_control_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_presentation_hint",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "hint",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("presentation_hint"),
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "presentation_hint",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "vsync",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "async",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned wp_tearing_control_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpTearingControlV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wp_tearing_control_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WpTearingControlV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WpTearingControlV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WpTearingControlV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WpTearingControlV1 {
+    const INTERFACE: &'static str = "wp_tearing_control_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WpTearingControlV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WpTearingControlV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WpTearingControlV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WpTearingControlV1Ref {
+    type Owned = WpTearingControlV1;
+}
+
+impl Deref for WpTearingControlV1 {
+    type Target = WpTearingControlV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WpTearingControlV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_tearing_control_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WpTearingControlV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wp_tearing_control_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WpTearingControlV1Ref> for WpTearingControlV1 {
+    fn eq(&self, other: &WpTearingControlV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WpTearingControlV1> for WpTearingControlV1Ref {
+    fn eq(&self, other: &WpTearingControlV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WpTearingControlV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy tearing control object
+    ///
+    /// Destroy this surface tearing object and revert the presentation hint to
+    /// vsync. The change will be applied on the next wl_surface.commit.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl WpTearingControlV1Ref {
+    /// set presentation hint
+    ///
+    /// Set the presentation hint for the associated wl_surface. This state is
+    /// double-buffered, see wl_surface.commit.
+    ///
+    /// The compositor is free to dynamically respect or ignore this hint based
+    /// on various conditions like hardware capabilities, surface state and
+    /// user preferences.
+    ///
+    /// # Arguments
+    ///
+    /// - `hint`:
+    #[inline]
+    pub fn set_presentation_hint(&self, hint: WpTearingControlV1PresentationHint) {
+        let (arg0,) = (hint,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [WpTearingControlV1] proxies.
+#[allow(dead_code)]
+pub trait WpTearingControlV1EventHandler {}
+
+impl WpTearingControlV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpTearingControlV1] proxies with mutable data.
+///
+/// This trait is the same as [WpTearingControlV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpTearingControlV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpTearingControlV1] proxies.
+///
+/// This trait is the same as [WpTearingControlV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpTearingControlV1EventHandlerMut {}
+
+/// A mutable event handler for [WpTearingControlV1] proxies with mutable data.
+///
+/// This trait is the same as [WpTearingControlV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpTearingControlV1EventHandlerMutWithData<T> {}
+
+impl<H> WpTearingControlV1EventHandler for MutEventHandler<H> where
+    H: WpTearingControlV1EventHandlerMut
+{
+}
+
+impl<T, H> WpTearingControlV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpTearingControlV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpTearingControlV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpTearingControlV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpTearingControlV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpTearingControlV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WpTearingControlV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_tearing_control_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpTearingControlV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_tearing_control_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WpTearingControlV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpTearingControlV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WpTearingControlV1 {
+    /// Since when the presentation_hint.vsync enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENTATION_HINT_VSYNC__SINCE: u32 = 1;
+    /// Since when the presentation_hint.async enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PRESENTATION_HINT_ASYNC__SINCE: u32 = 1;
+}
+
+/// presentation hint values
+///
+/// This enum provides information for if submitted frames from the client
+/// may be presented with tearing.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WpTearingControlV1PresentationHint(pub u32);
+
+impl WpTearingControlV1PresentationHint {
+    /// tearing-free presentation
+    ///
+    /// The content of this surface is meant to be synchronized to the
+    /// vertical blanking period. This should not result in visible tearing
+    /// and may result in a delay before a surface commit is presented.
+    #[allow(dead_code)]
+    pub const VSYNC: Self = Self(0);
+
+    /// asynchronous presentation
+    ///
+    /// The content of this surface is meant to be presented with minimal
+    /// latency and tearing is acceptable.
+    #[allow(dead_code)]
+    pub const ASYNC: Self = Self(1);
+}
+
+impl Debug for WpTearingControlV1PresentationHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::VSYNC => "VSYNC",
+            Self::ASYNC => "ASYNC",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl WpTearingControlV1 {}
+}
@@ -0,0 +1,321 @@
+//! text input manager
+//!
+//! A factory for text_input objects. This object is a global singleton.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_text_input_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"create_text_input".as_ptr(),
+            signature: c"n".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] =
+                    [Some(ZwpTextInputV1::WL_INTERFACE)];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_text_input_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_text_input_manager_v1",
+    protocol: "text_input_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "create_text_input",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "id",
+            ty: info::ArgType::NewId,
+            interface: Some("zwp_text_input_v1"),
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_text_input_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_text_input_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTextInputManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTextInputManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTextInputManagerV1 {
+    const INTERFACE: &'static str = "zwp_text_input_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpTextInputManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTextInputManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTextInputManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTextInputManagerV1Ref {
+    type Owned = ZwpTextInputManagerV1;
+}
+
+impl Deref for ZwpTextInputManagerV1 {
+    type Target = ZwpTextInputManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTextInputManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTextInputManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTextInputManagerV1Ref> for ZwpTextInputManagerV1 {
+    fn eq(&self, other: &ZwpTextInputManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTextInputManagerV1> for ZwpTextInputManagerV1Ref {
+    fn eq(&self, other: &ZwpTextInputManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputManagerV1 {
+    /// Since when the create_text_input request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_TEXT_INPUT__SINCE: u32 = 1;
+
+    /// create text input
+    ///
+    /// Creates a new text_input object.
+    #[inline]
+    pub fn create_text_input(&self) -> ZwpTextInputV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(0, &mut args, ZwpTextInputV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZwpTextInputV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputManagerV1Ref {
+    /// create text input
+    ///
+    /// Creates a new text_input object.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_text_input(&self, _queue: &Queue) -> ZwpTextInputV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 0, &mut args, ZwpTextInputV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZwpTextInputV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpTextInputManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV1EventHandler {}
+
+impl ZwpTextInputManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTextInputManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpTextInputManagerV1] proxies.
+///
+/// This trait is the same as [ZwpTextInputManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpTextInputManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpTextInputManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpTextInputManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpTextInputManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpTextInputManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpTextInputManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTextInputManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpTextInputManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTextInputManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTextInputManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_text_input_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTextInputManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_text_input_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTextInputManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTextInputManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpTextInputManagerV1 {}
+}
@@ -0,0 +1,5885 @@
+//! text input
+//!
+//! An object used for text input. Adds support for text input and input
+//! methods to applications. A text_input object is created from a
+//! wl_text_input_manager and corresponds typically to a text entry in an
+//! application.
+//!
+//! Requests are used to activate/deactivate the text_input object and set
+//! state information like surrounding and selected text or the content type.
+//! The information about entered text is sent to the text_input object via
+//! the pre-edit and commit events. Using this interface removes the need
+//! for applications to directly process hardware key events and compose text
+//! out of them.
+//!
+//! Text is generally UTF-8 encoded, indices and lengths are in bytes.
+//!
+//! Serials are used to synchronize the state between the text input and
+//! an input method. New serials are sent by the text input in the
+//! commit_state request and are used by the input method to indicate
+//! the known text input state in events like preedit_string, commit_string,
+//! and keysym. The text input can then ignore events from the input method
+//! which are based on an outdated state (for example after a reset).
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_text_input_v1".as_ptr(),
+    version: 1,
+    method_count: 11,
+    methods: {
+        static MESSAGES: [wl_message; 11] = [
+            wl_message {
+                name: c"activate".as_ptr(),
+                signature: c"oo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(WlSeat::WL_INTERFACE), Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"deactivate".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [Some(WlSeat::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"show_input_panel".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"hide_input_panel".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"reset".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_surrounding_text".as_ptr(),
+                signature: c"suu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_content_type".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_cursor_rectangle".as_ptr(),
+                signature: c"iiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_preferred_language".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit_state".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"invoke_action".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 13,
+    events: {
+        static MESSAGES: [wl_message; 13] = [
+            wl_message {
+                name: c"enter".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"leave".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"modifiers_map".as_ptr(),
+                signature: c"a".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"input_panel_state".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_string".as_ptr(),
+                signature: c"uss".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_styling".as_ptr(),
+                signature: c"uuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_cursor".as_ptr(),
+                signature: c"i".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit_string".as_ptr(),
+                signature: c"us".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"cursor_position".as_ptr(),
+                signature: c"ii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"delete_surrounding_text".as_ptr(),
+                signature: c"iu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"keysym".as_ptr(),
+                signature: c"uuuuu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 5] =
+                        [None, None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"language".as_ptr(),
+                signature: c"us".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"text_direction".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_text_input_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_text_input_v1",
+    protocol: "text_input_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "activate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "deactivate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "seat",
+                ty: info::ArgType::Object,
+                interface: Some("wl_seat"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "show_input_panel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "hide_input_panel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "reset",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_surrounding_text",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cursor",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "anchor",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_content_type",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "hint",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("content_hint"),
+                },
+                info::ArgInfo {
+                    name: "purpose",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("content_purpose"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_cursor_rectangle",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_preferred_language",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "language",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "commit_state",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "invoke_action",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "button",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "modifiers_map",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "map",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "input_panel_state",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "state",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "preedit_string",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "commit",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "preedit_styling",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "style",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("preedit_style"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "preedit_cursor",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "index",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "commit_string",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "cursor_position",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "anchor",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "delete_surrounding_text",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "index",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "keysym",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "sym",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "state",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "modifiers",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "language",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "language",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "text_direction",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "direction",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("text_direction"),
+                },
+            ],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "content_hint",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "none",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "default",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "password",
+                    value: 192,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "auto_completion",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "auto_correction",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "auto_capitalization",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "lowercase",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "uppercase",
+                    value: 16,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "titlecase",
+                    value: 32,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "hidden_text",
+                    value: 64,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "sensitive_data",
+                    value: 128,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "latin",
+                    value: 256,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "multiline",
+                    value: 512,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "content_purpose",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "normal",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "alpha",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "digits",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "number",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "phone",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "url",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "email",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "name",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "password",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "date",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "time",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "datetime",
+                    value: 11,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "terminal",
+                    value: 12,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "preedit_style",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "default",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "none",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "active",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "inactive",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "highlight",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "underline",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "selection",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "incorrect",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "text_direction",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "auto",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ltr",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rtl",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned zwp_text_input_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_text_input_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTextInputV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTextInputV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTextInputV1 {
+    const INTERFACE: &'static str = "zwp_text_input_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpTextInputV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTextInputV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTextInputV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTextInputV1Ref {
+    type Owned = ZwpTextInputV1;
+}
+
+impl Deref for ZwpTextInputV1 {
+    type Target = ZwpTextInputV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTextInputV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTextInputV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTextInputV1Ref> for ZwpTextInputV1 {
+    fn eq(&self, other: &ZwpTextInputV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTextInputV1> for ZwpTextInputV1Ref {
+    fn eq(&self, other: &ZwpTextInputV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputV1Ref {
+    /// request activation
+    ///
+    /// Requests the text_input object to be activated (typically when the
+    /// text entry gets focus).
+    ///
+    /// The seat argument is a wl_seat which maintains the focus for this
+    /// activation. The surface argument is a wl_surface assigned to the
+    /// text_input object and tracked for focus lost. The enter event
+    /// is emitted on successful activation.
+    ///
+    /// # Arguments
+    ///
+    /// - `seat`:
+    /// - `surface`:
+    #[inline]
+    pub fn activate(&self, seat: &WlSeatRef, surface: &WlSurfaceRef) {
+        let (arg0, arg1) = (seat, surface);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("seat", obj0_lock.wl_proxy());
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 11
+        //         - the request signature is `oo`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+
+    /// request deactivation
+    ///
+    /// Requests the text_input object to be deactivated (typically when the
+    /// text entry lost focus). The seat argument is a wl_seat which was used
+    /// for activation.
+    ///
+    /// # Arguments
+    ///
+    /// - `seat`:
+    #[inline]
+    pub fn deactivate(&self, seat: &WlSeatRef) {
+        let (arg0,) = (seat,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("seat", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 11
+        //         - the request signature is `o`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// show input panels
+    ///
+    /// Requests input panels (virtual keyboard) to show.
+    #[inline]
+    pub fn show_input_panel(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 11
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// hide input panels
+    ///
+    /// Requests input panels (virtual keyboard) to hide.
+    #[inline]
+    pub fn hide_input_panel(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 11
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+
+    /// reset
+    ///
+    /// Should be called by an editor widget when the input state should be
+    /// reset, for example after the text was changed outside of the normal
+    /// input method flow.
+    #[inline]
+    pub fn reset(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 11
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(4, &mut args);
+        }
+    }
+
+    /// sets the surrounding text
+    ///
+    /// Sets the plain surrounding text around the input position. Text is
+    /// UTF-8 encoded. Cursor is the byte offset within the
+    /// surrounding text. Anchor is the byte offset of the
+    /// selection anchor within the surrounding text. If there is no selected
+    /// text anchor, then it is the same as cursor.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    /// - `cursor`:
+    /// - `anchor`:
+    #[inline]
+    pub fn set_surrounding_text(&self, text: &str, cursor: u32, anchor: u32) {
+        let (arg0, arg1, arg2) = (text, cursor, anchor);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [
+                wl_argument { s: str0 },
+                wl_argument { u: arg1 },
+                wl_argument { u: arg2 },
+            ];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 5 < INTERFACE.method_count = 11
+            //         - the request signature is `suu`
+            unsafe {
+                self.proxy.send_request(5, &mut args);
+            }
+        })
+    }
+
+    /// set content purpose and hint
+    ///
+    /// Sets the content purpose and content hint. While the purpose is the
+    /// basic purpose of an input field, the hint flags allow to modify some
+    /// of the behavior.
+    ///
+    /// When no content type is explicitly set, a normal content purpose with
+    /// default hints (auto completion, auto correction, auto capitalization)
+    /// should be assumed.
+    ///
+    /// # Arguments
+    ///
+    /// - `hint`:
+    /// - `purpose`:
+    #[inline]
+    pub fn set_content_type(
+        &self,
+        hint: ZwpTextInputV1ContentHint,
+        purpose: ZwpTextInputV1ContentPurpose,
+    ) {
+        let (arg0, arg1) = (hint, purpose);
+        let mut args = [wl_argument { u: arg0.0 }, wl_argument { u: arg1.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 6 < INTERFACE.method_count = 11
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(6, &mut args);
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// - `x`:
+    /// - `y`:
+    /// - `width`:
+    /// - `height`:
+    #[inline]
+    pub fn set_cursor_rectangle(&self, x: i32, y: i32, width: i32, height: i32) {
+        let (arg0, arg1, arg2, arg3) = (x, y, width, height);
+        let mut args = [
+            wl_argument { i: arg0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { i: arg3 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 7 < INTERFACE.method_count = 11
+        //         - the request signature is `iiii`
+        unsafe {
+            self.proxy.send_request(7, &mut args);
+        }
+    }
+
+    /// sets preferred language
+    ///
+    /// Sets a specific language. This allows for example a virtual keyboard to
+    /// show a language specific layout. The "language" argument is an RFC-3066
+    /// format language tag.
+    ///
+    /// It could be used for example in a word processor to indicate the
+    /// language of the currently edited document or in an instant message
+    /// application which tracks languages of contacts.
+    ///
+    /// # Arguments
+    ///
+    /// - `language`:
+    #[inline]
+    pub fn set_preferred_language(&self, language: &str) {
+        let (arg0,) = (language,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 8 < INTERFACE.method_count = 11
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(8, &mut args);
+            }
+        })
+    }
+
+    /// # Arguments
+    ///
+    /// - `serial`: used to identify the known state
+    #[inline]
+    pub fn commit_state(&self, serial: u32) {
+        let (arg0,) = (serial,);
+        let mut args = [wl_argument { u: arg0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 9 < INTERFACE.method_count = 11
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(9, &mut args);
+        }
+    }
+
+    /// # Arguments
+    ///
+    /// - `button`:
+    /// - `index`:
+    #[inline]
+    pub fn invoke_action(&self, button: u32, index: u32) {
+        let (arg0, arg1) = (button, index);
+        let mut args = [wl_argument { u: arg0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 10 < INTERFACE.method_count = 11
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(10, &mut args);
+        }
+    }
+}
+
+impl ZwpTextInputV1 {
+    /// Since when the enter event is available.
+    #[allow(dead_code)]
+    pub const EVT__ENTER__SINCE: u32 = 1;
+
+    /// Since when the leave event is available.
+    #[allow(dead_code)]
+    pub const EVT__LEAVE__SINCE: u32 = 1;
+
+    /// Since when the modifiers_map event is available.
+    #[allow(dead_code)]
+    pub const EVT__MODIFIERS_MAP__SINCE: u32 = 1;
+
+    /// Since when the input_panel_state event is available.
+    #[allow(dead_code)]
+    pub const EVT__INPUT_PANEL_STATE__SINCE: u32 = 1;
+
+    /// Since when the preedit_string event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREEDIT_STRING__SINCE: u32 = 1;
+
+    /// Since when the preedit_styling event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREEDIT_STYLING__SINCE: u32 = 1;
+
+    /// Since when the preedit_cursor event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREEDIT_CURSOR__SINCE: u32 = 1;
+
+    /// Since when the commit_string event is available.
+    #[allow(dead_code)]
+    pub const EVT__COMMIT_STRING__SINCE: u32 = 1;
+
+    /// Since when the cursor_position event is available.
+    #[allow(dead_code)]
+    pub const EVT__CURSOR_POSITION__SINCE: u32 = 1;
+
+    /// Since when the delete_surrounding_text event is available.
+    #[allow(dead_code)]
+    pub const EVT__DELETE_SURROUNDING_TEXT__SINCE: u32 = 1;
+
+    /// Since when the keysym event is available.
+    #[allow(dead_code)]
+    pub const EVT__KEYSYM__SINCE: u32 = 1;
+
+    /// Since when the language event is available.
+    #[allow(dead_code)]
+    pub const EVT__LANGUAGE__SINCE: u32 = 1;
+
+    /// Since when the text_direction event is available.
+    #[allow(dead_code)]
+    pub const EVT__TEXT_DIRECTION__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpTextInputV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpTextInputV1EventHandler {
+    /// enter event
+    ///
+    /// Notify the text_input object when it received focus. Typically in
+    /// response to an activate request.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn enter(&self, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// leave event
+    ///
+    /// Notify the text_input object when it lost focus. Either in response
+    /// to a deactivate request or when the assigned surface lost focus or was
+    /// destroyed.
+    #[inline]
+    fn leave(&self, _slf: &ZwpTextInputV1Ref) {}
+
+    /// modifiers map
+    ///
+    /// Transfer an array of 0-terminated modifier names. The position in
+    /// the array is the index of the modifier as used in the modifiers
+    /// bitmask in the keysym event.
+    ///
+    /// # Arguments
+    ///
+    /// - `map`:
+    #[inline]
+    fn modifiers_map(&self, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        let _ = map;
+    }
+
+    /// state of the input panel
+    ///
+    /// Notify when the visibility state of the input panel changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`:
+    #[inline]
+    fn input_panel_state(&self, _slf: &ZwpTextInputV1Ref, state: u32) {
+        let _ = state;
+    }
+
+    /// pre-edit
+    ///
+    /// Notify when a new composing text (pre-edit) should be set around the
+    /// current cursor position. Any previously set composing text should
+    /// be removed.
+    ///
+    /// The commit text can be used to replace the preedit text on reset
+    /// (for example on unfocus).
+    ///
+    /// The text input should also handle all preedit_style and preedit_cursor
+    /// events occurring directly before preedit_string.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `text`:
+    /// - `commit`:
+    #[inline]
+    fn preedit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str, commit: &str) {
+        let _ = serial;
+        let _ = text;
+        let _ = commit;
+    }
+
+    /// pre-edit styling
+    ///
+    /// Sets styling information on composing text. The style is applied for
+    /// length bytes from index relative to the beginning of the composing
+    /// text (as byte offset). Multiple styles can
+    /// be applied to a composing text by sending multiple preedit_styling
+    /// events.
+    ///
+    /// This event is handled as part of a following preedit_string event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `length`:
+    /// - `style`:
+    #[inline]
+    fn preedit_styling(
+        &self,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        let _ = index;
+        let _ = length;
+        let _ = style;
+    }
+
+    /// pre-edit cursor
+    ///
+    /// Sets the cursor position inside the composing text (as byte
+    /// offset) relative to the start of the composing text. When index is a
+    /// negative number no cursor is shown.
+    ///
+    /// This event is handled as part of a following preedit_string event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    #[inline]
+    fn preedit_cursor(&self, _slf: &ZwpTextInputV1Ref, index: i32) {
+        let _ = index;
+    }
+
+    /// commit
+    ///
+    /// Notify when text should be inserted into the editor widget. The text to
+    /// commit could be either just a single character after a key press or the
+    /// result of some composing (pre-edit). It could also be an empty text
+    /// when some text should be removed (see delete_surrounding_text) or when
+    /// the input cursor should be moved (see cursor_position).
+    ///
+    /// Any previously set composing text should be removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `text`:
+    #[inline]
+    fn commit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        let _ = serial;
+        let _ = text;
+    }
+
+    /// set cursor to new position
+    ///
+    /// Notify when the cursor or anchor position should be modified.
+    ///
+    /// This event should be handled as part of a following commit_string
+    /// event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `anchor`:
+    #[inline]
+    fn cursor_position(&self, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+        let _ = index;
+        let _ = anchor;
+    }
+
+    /// delete surrounding text
+    ///
+    /// Notify when the text around the current cursor position should be
+    /// deleted.
+    ///
+    /// Index is relative to the current cursor (in bytes).
+    /// Length is the length of deleted text (in bytes).
+    ///
+    /// This event should be handled as part of a following commit_string
+    /// event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `length`:
+    #[inline]
+    fn delete_surrounding_text(&self, _slf: &ZwpTextInputV1Ref, index: i32, length: u32) {
+        let _ = index;
+        let _ = length;
+    }
+
+    /// keysym
+    ///
+    /// Notify when a key event was sent. Key events should not be used
+    /// for normal text input operations, which should be done with
+    /// commit_string, delete_surrounding_text, etc. The key event follows
+    /// the wl_keyboard key event convention. Sym is an XKB keysym, state a
+    /// wl_keyboard key_state. Modifiers are a mask for effective modifiers
+    /// (where the modifier indices are set by the modifiers_map event)
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `time`:
+    /// - `sym`:
+    /// - `state`:
+    /// - `modifiers`:
+    #[inline]
+    fn keysym(
+        &self,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = sym;
+        let _ = state;
+        let _ = modifiers;
+    }
+
+    /// language
+    ///
+    /// Sets the language of the input text. The "language" argument is an
+    /// RFC-3066 format language tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `language`:
+    #[inline]
+    fn language(&self, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        let _ = serial;
+        let _ = language;
+    }
+
+    /// text direction
+    ///
+    /// Sets the text direction of input text.
+    ///
+    /// It is mainly needed for showing an input cursor on the correct side of
+    /// the editor when there is no input done yet and making sure neutral
+    /// direction text is laid out properly.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `direction`:
+    #[inline]
+    fn text_direction(
+        &self,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        let _ = serial;
+        let _ = direction;
+    }
+}
+
+impl ZwpTextInputV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTextInputV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTextInputV1EventHandlerWithData<T> {
+    /// enter event
+    ///
+    /// Notify the text_input object when it received focus. Typically in
+    /// response to an activate request.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// leave event
+    ///
+    /// Notify the text_input object when it lost focus. Either in response
+    /// to a deactivate request or when the assigned surface lost focus or was
+    /// destroyed.
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {}
+
+    /// modifiers map
+    ///
+    /// Transfer an array of 0-terminated modifier names. The position in
+    /// the array is the index of the modifier as used in the modifiers
+    /// bitmask in the keysym event.
+    ///
+    /// # Arguments
+    ///
+    /// - `map`:
+    #[inline]
+    fn modifiers_map(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        let _ = map;
+    }
+
+    /// state of the input panel
+    ///
+    /// Notify when the visibility state of the input panel changed.
+    ///
+    /// # Arguments
+    ///
+    /// - `state`:
+    #[inline]
+    fn input_panel_state(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+        let _ = state;
+    }
+
+    /// pre-edit
+    ///
+    /// Notify when a new composing text (pre-edit) should be set around the
+    /// current cursor position. Any previously set composing text should
+    /// be removed.
+    ///
+    /// The commit text can be used to replace the preedit text on reset
+    /// (for example on unfocus).
+    ///
+    /// The text input should also handle all preedit_style and preedit_cursor
+    /// events occurring directly before preedit_string.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `text`:
+    /// - `commit`:
+    #[inline]
+    fn preedit_string(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        text: &str,
+        commit: &str,
+    ) {
+        let _ = serial;
+        let _ = text;
+        let _ = commit;
+    }
+
+    /// pre-edit styling
+    ///
+    /// Sets styling information on composing text. The style is applied for
+    /// length bytes from index relative to the beginning of the composing
+    /// text (as byte offset). Multiple styles can
+    /// be applied to a composing text by sending multiple preedit_styling
+    /// events.
+    ///
+    /// This event is handled as part of a following preedit_string event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `length`:
+    /// - `style`:
+    #[inline]
+    fn preedit_styling(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        let _ = index;
+        let _ = length;
+        let _ = style;
+    }
+
+    /// pre-edit cursor
+    ///
+    /// Sets the cursor position inside the composing text (as byte
+    /// offset) relative to the start of the composing text. When index is a
+    /// negative number no cursor is shown.
+    ///
+    /// This event is handled as part of a following preedit_string event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    #[inline]
+    fn preedit_cursor(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+        let _ = index;
+    }
+
+    /// commit
+    ///
+    /// Notify when text should be inserted into the editor widget. The text to
+    /// commit could be either just a single character after a key press or the
+    /// result of some composing (pre-edit). It could also be an empty text
+    /// when some text should be removed (see delete_surrounding_text) or when
+    /// the input cursor should be moved (see cursor_position).
+    ///
+    /// Any previously set composing text should be removed.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `text`:
+    #[inline]
+    fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        let _ = serial;
+        let _ = text;
+    }
+
+    /// set cursor to new position
+    ///
+    /// Notify when the cursor or anchor position should be modified.
+    ///
+    /// This event should be handled as part of a following commit_string
+    /// event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `anchor`:
+    #[inline]
+    fn cursor_position(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+        let _ = index;
+        let _ = anchor;
+    }
+
+    /// delete surrounding text
+    ///
+    /// Notify when the text around the current cursor position should be
+    /// deleted.
+    ///
+    /// Index is relative to the current cursor (in bytes).
+    /// Length is the length of deleted text (in bytes).
+    ///
+    /// This event should be handled as part of a following commit_string
+    /// event.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`:
+    /// - `length`:
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        length: u32,
+    ) {
+        let _ = index;
+        let _ = length;
+    }
+
+    /// keysym
+    ///
+    /// Notify when a key event was sent. Key events should not be used
+    /// for normal text input operations, which should be done with
+    /// commit_string, delete_surrounding_text, etc. The key event follows
+    /// the wl_keyboard key event convention. Sym is an XKB keysym, state a
+    /// wl_keyboard key_state. Modifiers are a mask for effective modifiers
+    /// (where the modifier indices are set by the modifiers_map event)
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `time`:
+    /// - `sym`:
+    /// - `state`:
+    /// - `modifiers`:
+    #[inline]
+    fn keysym(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = sym;
+        let _ = state;
+        let _ = modifiers;
+    }
+
+    /// language
+    ///
+    /// Sets the language of the input text. The "language" argument is an
+    /// RFC-3066 format language tag.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `language`:
+    #[inline]
+    fn language(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        let _ = serial;
+        let _ = language;
+    }
+
+    /// text direction
+    ///
+    /// Sets the text direction of input text.
+    ///
+    /// It is mainly needed for showing an input cursor on the correct side of
+    /// the editor when there is no input done yet and making sure neutral
+    /// direction text is laid out properly.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the latest known text input state
+    /// - `direction`:
+    #[inline]
+    fn text_direction(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        let _ = serial;
+        let _ = direction;
+    }
+}
+
+/// A mutable event handler for [ZwpTextInputV1] proxies.
+///
+/// This trait is the same as [ZwpTextInputV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputV1EventHandlerMut {
+    /// See [ZwpTextInputV1EventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _slf: &ZwpTextInputV1Ref) {}
+
+    /// See [ZwpTextInputV1EventHandler::modifiers_map].
+    #[inline]
+    fn modifiers_map(&mut self, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        let _ = map;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::input_panel_state].
+    #[inline]
+    fn input_panel_state(&mut self, _slf: &ZwpTextInputV1Ref, state: u32) {
+        let _ = state;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::preedit_string].
+    #[inline]
+    fn preedit_string(&mut self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str, commit: &str) {
+        let _ = serial;
+        let _ = text;
+        let _ = commit;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::preedit_styling].
+    #[inline]
+    fn preedit_styling(
+        &mut self,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        let _ = index;
+        let _ = length;
+        let _ = style;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::preedit_cursor].
+    #[inline]
+    fn preedit_cursor(&mut self, _slf: &ZwpTextInputV1Ref, index: i32) {
+        let _ = index;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::commit_string].
+    #[inline]
+    fn commit_string(&mut self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        let _ = serial;
+        let _ = text;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::cursor_position].
+    #[inline]
+    fn cursor_position(&mut self, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+        let _ = index;
+        let _ = anchor;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::delete_surrounding_text].
+    #[inline]
+    fn delete_surrounding_text(&mut self, _slf: &ZwpTextInputV1Ref, index: i32, length: u32) {
+        let _ = index;
+        let _ = length;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::keysym].
+    #[inline]
+    fn keysym(
+        &mut self,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = sym;
+        let _ = state;
+        let _ = modifiers;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::language].
+    #[inline]
+    fn language(&mut self, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        let _ = serial;
+        let _ = language;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::text_direction].
+    #[inline]
+    fn text_direction(
+        &mut self,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        let _ = serial;
+        let _ = direction;
+    }
+}
+
+/// A mutable event handler for [ZwpTextInputV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputV1EventHandlerMutWithData<T> {
+    /// See [ZwpTextInputV1EventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {}
+
+    /// See [ZwpTextInputV1EventHandler::modifiers_map].
+    #[inline]
+    fn modifiers_map(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        let _ = map;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::input_panel_state].
+    #[inline]
+    fn input_panel_state(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+        let _ = state;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::preedit_string].
+    #[inline]
+    fn preedit_string(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        text: &str,
+        commit: &str,
+    ) {
+        let _ = serial;
+        let _ = text;
+        let _ = commit;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::preedit_styling].
+    #[inline]
+    fn preedit_styling(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        let _ = index;
+        let _ = length;
+        let _ = style;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::preedit_cursor].
+    #[inline]
+    fn preedit_cursor(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+        let _ = index;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::commit_string].
+    #[inline]
+    fn commit_string(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        let _ = serial;
+        let _ = text;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::cursor_position].
+    #[inline]
+    fn cursor_position(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        anchor: i32,
+    ) {
+        let _ = index;
+        let _ = anchor;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::delete_surrounding_text].
+    #[inline]
+    fn delete_surrounding_text(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        length: u32,
+    ) {
+        let _ = index;
+        let _ = length;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::keysym].
+    #[inline]
+    fn keysym(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = sym;
+        let _ = state;
+        let _ = modifiers;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::language].
+    #[inline]
+    fn language(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        let _ = serial;
+        let _ = language;
+    }
+
+    /// See [ZwpTextInputV1EventHandler::text_direction].
+    #[inline]
+    fn text_direction(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        let _ = serial;
+        let _ = direction;
+    }
+}
+
+impl<H> ZwpTextInputV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpTextInputV1EventHandlerMut,
+{
+    #[inline]
+    fn enter(&self, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.enter(_slf, surface))
+    }
+
+    #[inline]
+    fn leave(&self, _slf: &ZwpTextInputV1Ref) {
+        self.with(|h| h.leave(_slf))
+    }
+
+    #[inline]
+    fn modifiers_map(&self, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        self.with(|h| h.modifiers_map(_slf, map))
+    }
+
+    #[inline]
+    fn input_panel_state(&self, _slf: &ZwpTextInputV1Ref, state: u32) {
+        self.with(|h| h.input_panel_state(_slf, state))
+    }
+
+    #[inline]
+    fn preedit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str, commit: &str) {
+        self.with(|h| h.preedit_string(_slf, serial, text, commit))
+    }
+
+    #[inline]
+    fn preedit_styling(
+        &self,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        self.with(|h| h.preedit_styling(_slf, index, length, style))
+    }
+
+    #[inline]
+    fn preedit_cursor(&self, _slf: &ZwpTextInputV1Ref, index: i32) {
+        self.with(|h| h.preedit_cursor(_slf, index))
+    }
+
+    #[inline]
+    fn commit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        self.with(|h| h.commit_string(_slf, serial, text))
+    }
+
+    #[inline]
+    fn cursor_position(&self, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+        self.with(|h| h.cursor_position(_slf, index, anchor))
+    }
+
+    #[inline]
+    fn delete_surrounding_text(&self, _slf: &ZwpTextInputV1Ref, index: i32, length: u32) {
+        self.with(|h| h.delete_surrounding_text(_slf, index, length))
+    }
+
+    #[inline]
+    fn keysym(
+        &self,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        self.with(|h| h.keysym(_slf, serial, time, sym, state, modifiers))
+    }
+
+    #[inline]
+    fn language(&self, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        self.with(|h| h.language(_slf, serial, language))
+    }
+
+    #[inline]
+    fn text_direction(
+        &self,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        self.with(|h| h.text_direction(_slf, serial, direction))
+    }
+}
+
+impl<T, H> ZwpTextInputV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTextInputV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.enter(_data, _slf, surface))
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {
+        self.with(|h| h.leave(_data, _slf))
+    }
+
+    #[inline]
+    fn modifiers_map(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        self.with(|h| h.modifiers_map(_data, _slf, map))
+    }
+
+    #[inline]
+    fn input_panel_state(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+        self.with(|h| h.input_panel_state(_data, _slf, state))
+    }
+
+    #[inline]
+    fn preedit_string(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        text: &str,
+        commit: &str,
+    ) {
+        self.with(|h| h.preedit_string(_data, _slf, serial, text, commit))
+    }
+
+    #[inline]
+    fn preedit_styling(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        self.with(|h| h.preedit_styling(_data, _slf, index, length, style))
+    }
+
+    #[inline]
+    fn preedit_cursor(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+        self.with(|h| h.preedit_cursor(_data, _slf, index))
+    }
+
+    #[inline]
+    fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        self.with(|h| h.commit_string(_data, _slf, serial, text))
+    }
+
+    #[inline]
+    fn cursor_position(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+        self.with(|h| h.cursor_position(_data, _slf, index, anchor))
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        length: u32,
+    ) {
+        self.with(|h| h.delete_surrounding_text(_data, _slf, index, length))
+    }
+
+    #[inline]
+    fn keysym(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        self.with(|h| h.keysym(_data, _slf, serial, time, sym, state, modifiers))
+    }
+
+    #[inline]
+    fn language(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        self.with(|h| h.language(_data, _slf, serial, language))
+    }
+
+    #[inline]
+    fn text_direction(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        self.with(|h| h.text_direction(_data, _slf, serial, direction))
+    }
+}
+
+impl<T, U, H> ZwpTextInputV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTextInputV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        let _data = self.project(_data);
+        self.handler().enter(_data, _slf, surface)
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {
+        let _data = self.project(_data);
+        self.handler().leave(_data, _slf)
+    }
+
+    #[inline]
+    fn modifiers_map(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().modifiers_map(_data, _slf, map)
+    }
+
+    #[inline]
+    fn input_panel_state(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+        let _data = self.project(_data);
+        self.handler().input_panel_state(_data, _slf, state)
+    }
+
+    #[inline]
+    fn preedit_string(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        text: &str,
+        commit: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .preedit_string(_data, _slf, serial, text, commit)
+    }
+
+    #[inline]
+    fn preedit_styling(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .preedit_styling(_data, _slf, index, length, style)
+    }
+
+    #[inline]
+    fn preedit_cursor(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+        let _data = self.project(_data);
+        self.handler().preedit_cursor(_data, _slf, index)
+    }
+
+    #[inline]
+    fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        let _data = self.project(_data);
+        self.handler().commit_string(_data, _slf, serial, text)
+    }
+
+    #[inline]
+    fn cursor_position(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+        let _data = self.project(_data);
+        self.handler().cursor_position(_data, _slf, index, anchor)
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        length: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .delete_surrounding_text(_data, _slf, index, length)
+    }
+
+    #[inline]
+    fn keysym(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .keysym(_data, _slf, serial, time, sym, state, modifiers)
+    }
+
+    #[inline]
+    fn language(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        let _data = self.project(_data);
+        self.handler().language(_data, _slf, serial, language)
+    }
+
+    #[inline]
+    fn text_direction(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .text_direction(_data, _slf, serial, direction)
+    }
+}
+
+impl<T, U, H> ZwpTextInputV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTextInputV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+        let _data = self.project(_data);
+        self.handler_mut().enter(_data, _slf, surface)
+    }
+
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().leave(_data, _slf)
+    }
+
+    #[inline]
+    fn modifiers_map(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+        let _data = self.project(_data);
+        self.handler_mut().modifiers_map(_data, _slf, map)
+    }
+
+    #[inline]
+    fn input_panel_state(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().input_panel_state(_data, _slf, state)
+    }
+
+    #[inline]
+    fn preedit_string(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        text: &str,
+        commit: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .preedit_string(_data, _slf, serial, text, commit)
+    }
+
+    #[inline]
+    fn preedit_styling(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: u32,
+        length: u32,
+        style: ZwpTextInputV1PreeditStyle,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .preedit_styling(_data, _slf, index, length, style)
+    }
+
+    #[inline]
+    fn preedit_cursor(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().preedit_cursor(_data, _slf, index)
+    }
+
+    #[inline]
+    fn commit_string(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().commit_string(_data, _slf, serial, text)
+    }
+
+    #[inline]
+    fn cursor_position(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        anchor: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .cursor_position(_data, _slf, index, anchor)
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        index: i32,
+        length: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .delete_surrounding_text(_data, _slf, index, length)
+    }
+
+    #[inline]
+    fn keysym(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        time: u32,
+        sym: u32,
+        state: u32,
+        modifiers: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .keysym(_data, _slf, serial, time, sym, state, modifiers)
+    }
+
+    #[inline]
+    fn language(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().language(_data, _slf, serial, language)
+    }
+
+    #[inline]
+    fn text_direction(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV1Ref,
+        serial: u32,
+        direction: ZwpTextInputV1TextDirection,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .text_direction(_data, _slf, serial, direction)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTextInputV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTextInputV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg0)
+                });
+                self.0.enter(slf, arg0);
+            }
+            1 => {
+                self.0.leave(slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.modifiers_map(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.input_panel_state(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 = unsafe { convert_string_arg("zwp_text_input_v1", "text", args[1].s) };
+                // SAFETY: - INTERFACE requires that args[2] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg2 = unsafe { convert_string_arg("zwp_text_input_v1", "commit", args[2].s) };
+                self.0.preedit_string(slf, arg0, arg1, arg2);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { ZwpTextInputV1PreeditStyle(args[2].u) };
+                self.0.preedit_styling(slf, arg0, arg1, arg2);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.preedit_cursor(slf, arg0);
+            }
+            7 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 = unsafe { convert_string_arg("zwp_text_input_v1", "text", args[1].s) };
+                self.0.commit_string(slf, arg0, arg1);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.cursor_position(slf, arg0, arg1);
+            }
+            9 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.delete_surrounding_text(slf, arg0, arg1);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 5 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 5]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                // SAFETY: - INTERFACE requires that args[4] contains a uint
+                let arg4 = unsafe { args[4].u };
+                self.0.keysym(slf, arg0, arg1, arg2, arg3, arg4);
+            }
+            11 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 =
+                    unsafe { convert_string_arg("zwp_text_input_v1", "language", args[1].s) };
+                self.0.language(slf, arg0, arg1);
+            }
+            12 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { ZwpTextInputV1TextDirection(args[1].u) };
+                self.0.text_direction(slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("zwp_text_input_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTextInputV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTextInputV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg0)
+                });
+                self.0.enter(data, slf, arg0);
+            }
+            1 => {
+                self.0.leave(data, slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.modifiers_map(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.input_panel_state(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 = unsafe { convert_string_arg("zwp_text_input_v1", "text", args[1].s) };
+                // SAFETY: - INTERFACE requires that args[2] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg2 = unsafe { convert_string_arg("zwp_text_input_v1", "commit", args[2].s) };
+                self.0.preedit_string(data, slf, arg0, arg1, arg2);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { ZwpTextInputV1PreeditStyle(args[2].u) };
+                self.0.preedit_styling(data, slf, arg0, arg1, arg2);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.preedit_cursor(data, slf, arg0);
+            }
+            7 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 = unsafe { convert_string_arg("zwp_text_input_v1", "text", args[1].s) };
+                self.0.commit_string(data, slf, arg0, arg1);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.cursor_position(data, slf, arg0, arg1);
+            }
+            9 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.delete_surrounding_text(data, slf, arg0, arg1);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 5 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 5]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                // SAFETY: - INTERFACE requires that args[4] contains a uint
+                let arg4 = unsafe { args[4].u };
+                self.0.keysym(data, slf, arg0, arg1, arg2, arg3, arg4);
+            }
+            11 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 =
+                    unsafe { convert_string_arg("zwp_text_input_v1", "language", args[1].s) };
+                self.0.language(data, slf, arg0, arg1);
+            }
+            12 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { ZwpTextInputV1TextDirection(args[1].u) };
+                self.0.text_direction(data, slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("zwp_text_input_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTextInputV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTextInputV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpTextInputV1 {
+    /// Since when the content_hint.none enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_NONE__SINCE: u32 = 1;
+    /// Since when the content_hint.default enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_DEFAULT__SINCE: u32 = 1;
+    /// Since when the content_hint.password enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_PASSWORD__SINCE: u32 = 1;
+    /// Since when the content_hint.auto_completion enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_AUTO_COMPLETION__SINCE: u32 = 1;
+    /// Since when the content_hint.auto_correction enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_AUTO_CORRECTION__SINCE: u32 = 1;
+    /// Since when the content_hint.auto_capitalization enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_AUTO_CAPITALIZATION__SINCE: u32 = 1;
+    /// Since when the content_hint.lowercase enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_LOWERCASE__SINCE: u32 = 1;
+    /// Since when the content_hint.uppercase enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_UPPERCASE__SINCE: u32 = 1;
+    /// Since when the content_hint.titlecase enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_TITLECASE__SINCE: u32 = 1;
+    /// Since when the content_hint.hidden_text enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_HIDDEN_TEXT__SINCE: u32 = 1;
+    /// Since when the content_hint.sensitive_data enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_SENSITIVE_DATA__SINCE: u32 = 1;
+    /// Since when the content_hint.latin enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_LATIN__SINCE: u32 = 1;
+    /// Since when the content_hint.multiline enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_MULTILINE__SINCE: u32 = 1;
+
+    /// Since when the content_purpose.normal enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_NORMAL__SINCE: u32 = 1;
+    /// Since when the content_purpose.alpha enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_ALPHA__SINCE: u32 = 1;
+    /// Since when the content_purpose.digits enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_DIGITS__SINCE: u32 = 1;
+    /// Since when the content_purpose.number enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_NUMBER__SINCE: u32 = 1;
+    /// Since when the content_purpose.phone enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_PHONE__SINCE: u32 = 1;
+    /// Since when the content_purpose.url enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_URL__SINCE: u32 = 1;
+    /// Since when the content_purpose.email enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_EMAIL__SINCE: u32 = 1;
+    /// Since when the content_purpose.name enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_NAME__SINCE: u32 = 1;
+    /// Since when the content_purpose.password enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_PASSWORD__SINCE: u32 = 1;
+    /// Since when the content_purpose.date enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_DATE__SINCE: u32 = 1;
+    /// Since when the content_purpose.time enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_TIME__SINCE: u32 = 1;
+    /// Since when the content_purpose.datetime enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_DATETIME__SINCE: u32 = 1;
+    /// Since when the content_purpose.terminal enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_TERMINAL__SINCE: u32 = 1;
+
+    /// Since when the preedit_style.default enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_DEFAULT__SINCE: u32 = 1;
+    /// Since when the preedit_style.none enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_NONE__SINCE: u32 = 1;
+    /// Since when the preedit_style.active enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_ACTIVE__SINCE: u32 = 1;
+    /// Since when the preedit_style.inactive enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_INACTIVE__SINCE: u32 = 1;
+    /// Since when the preedit_style.highlight enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_HIGHLIGHT__SINCE: u32 = 1;
+    /// Since when the preedit_style.underline enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_UNDERLINE__SINCE: u32 = 1;
+    /// Since when the preedit_style.selection enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_SELECTION__SINCE: u32 = 1;
+    /// Since when the preedit_style.incorrect enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__PREEDIT_STYLE_INCORRECT__SINCE: u32 = 1;
+
+    /// Since when the text_direction.auto enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TEXT_DIRECTION_AUTO__SINCE: u32 = 1;
+    /// Since when the text_direction.ltr enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TEXT_DIRECTION_LTR__SINCE: u32 = 1;
+    /// Since when the text_direction.rtl enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TEXT_DIRECTION_RTL__SINCE: u32 = 1;
+}
+
+/// content hint
+///
+/// Content hint is a bitmask to allow to modify the behavior of the text
+/// input.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV1ContentHint(pub u32);
+
+/// An iterator over the set bits in a [ZwpTextInputV1ContentHint].
+///
+/// You can construct this with the `IntoIterator` implementation of `ZwpTextInputV1ContentHint`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV1ContentHintIter(pub u32);
+
+impl ZwpTextInputV1ContentHint {
+    /// no special behaviour
+    #[allow(dead_code)]
+    pub const NONE: Self = Self(0x0);
+
+    /// auto completion, correction and capitalization
+    #[allow(dead_code)]
+    pub const DEFAULT: Self = Self(0x7);
+
+    /// hidden and sensitive text
+    #[allow(dead_code)]
+    pub const PASSWORD: Self = Self(0xc0);
+
+    /// suggest word completions
+    #[allow(dead_code)]
+    pub const AUTO_COMPLETION: Self = Self(0x1);
+
+    /// suggest word corrections
+    #[allow(dead_code)]
+    pub const AUTO_CORRECTION: Self = Self(0x2);
+
+    /// switch to uppercase letters at the start of a sentence
+    #[allow(dead_code)]
+    pub const AUTO_CAPITALIZATION: Self = Self(0x4);
+
+    /// prefer lowercase letters
+    #[allow(dead_code)]
+    pub const LOWERCASE: Self = Self(0x8);
+
+    /// prefer uppercase letters
+    #[allow(dead_code)]
+    pub const UPPERCASE: Self = Self(0x10);
+
+    /// prefer casing for titles and headings (can be language dependent)
+    #[allow(dead_code)]
+    pub const TITLECASE: Self = Self(0x20);
+
+    /// characters should be hidden
+    #[allow(dead_code)]
+    pub const HIDDEN_TEXT: Self = Self(0x40);
+
+    /// typed text should not be stored
+    #[allow(dead_code)]
+    pub const SENSITIVE_DATA: Self = Self(0x80);
+
+    /// just latin characters should be entered
+    #[allow(dead_code)]
+    pub const LATIN: Self = Self(0x100);
+
+    /// the text input is multiline
+    #[allow(dead_code)]
+    pub const MULTILINE: Self = Self(0x200);
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputV1ContentHint {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(
+            0 | 0x0
+                | 0x7
+                | 0xc0
+                | 0x1
+                | 0x2
+                | 0x4
+                | 0x8
+                | 0x10
+                | 0x20
+                | 0x40
+                | 0x80
+                | 0x100
+                | 0x200,
+        )
+    }
+}
+
+impl Iterator for ZwpTextInputV1ContentHintIter {
+    type Item = ZwpTextInputV1ContentHint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ZwpTextInputV1ContentHint(bit))
+    }
+}
+
+impl IntoIterator for ZwpTextInputV1ContentHint {
+    type Item = ZwpTextInputV1ContentHint;
+    type IntoIter = ZwpTextInputV1ContentHintIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ZwpTextInputV1ContentHintIter(self.0)
+    }
+}
+
+impl BitAnd for ZwpTextInputV1ContentHint {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ZwpTextInputV1ContentHint {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ZwpTextInputV1ContentHint {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ZwpTextInputV1ContentHint {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ZwpTextInputV1ContentHint {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ZwpTextInputV1ContentHint {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ZwpTextInputV1ContentHint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ZwpTextInputV1ContentHint {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ZwpTextInputV1ContentHint {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ZwpTextInputV1ContentHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 0x7 == 0x7 {
+            v &= !0x7;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("DEFAULT")?;
+        }
+        if v & 0xc0 == 0xc0 {
+            v &= !0xc0;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("PASSWORD")?;
+        }
+        if v & 0x1 == 0x1 {
+            v &= !0x1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("AUTO_COMPLETION")?;
+        }
+        if v & 0x2 == 0x2 {
+            v &= !0x2;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("AUTO_CORRECTION")?;
+        }
+        if v & 0x4 == 0x4 {
+            v &= !0x4;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("AUTO_CAPITALIZATION")?;
+        }
+        if v & 0x8 == 0x8 {
+            v &= !0x8;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("LOWERCASE")?;
+        }
+        if v & 0x10 == 0x10 {
+            v &= !0x10;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("UPPERCASE")?;
+        }
+        if v & 0x20 == 0x20 {
+            v &= !0x20;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("TITLECASE")?;
+        }
+        if v & 0x40 == 0x40 {
+            v &= !0x40;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("HIDDEN_TEXT")?;
+        }
+        if v & 0x80 == 0x80 {
+            v &= !0x80;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("SENSITIVE_DATA")?;
+        }
+        if v & 0x100 == 0x100 {
+            v &= !0x100;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("LATIN")?;
+        }
+        if v & 0x200 == 0x200 {
+            v &= !0x200;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("MULTILINE")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("NONE")?;
+        }
+        Ok(())
+    }
+}
+
+/// content purpose
+///
+/// The content purpose allows to specify the primary purpose of a text
+/// input.
+///
+/// This allows an input method to show special purpose input panels with
+/// extra characters or to disallow some characters.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV1ContentPurpose(pub u32);
+
+impl ZwpTextInputV1ContentPurpose {
+    /// default input, allowing all characters
+    #[allow(dead_code)]
+    pub const NORMAL: Self = Self(0);
+
+    /// allow only alphabetic characters
+    #[allow(dead_code)]
+    pub const ALPHA: Self = Self(1);
+
+    /// allow only digits
+    #[allow(dead_code)]
+    pub const DIGITS: Self = Self(2);
+
+    /// input a number (including decimal separator and sign)
+    #[allow(dead_code)]
+    pub const NUMBER: Self = Self(3);
+
+    /// input a phone number
+    #[allow(dead_code)]
+    pub const PHONE: Self = Self(4);
+
+    /// input an URL
+    #[allow(dead_code)]
+    pub const URL: Self = Self(5);
+
+    /// input an email address
+    #[allow(dead_code)]
+    pub const EMAIL: Self = Self(6);
+
+    /// input a name of a person
+    #[allow(dead_code)]
+    pub const NAME: Self = Self(7);
+
+    /// input a password (combine with password or sensitive_data hint)
+    #[allow(dead_code)]
+    pub const PASSWORD: Self = Self(8);
+
+    /// input a date
+    #[allow(dead_code)]
+    pub const DATE: Self = Self(9);
+
+    /// input a time
+    #[allow(dead_code)]
+    pub const TIME: Self = Self(10);
+
+    /// input a date and time
+    #[allow(dead_code)]
+    pub const DATETIME: Self = Self(11);
+
+    /// input for a terminal
+    #[allow(dead_code)]
+    pub const TERMINAL: Self = Self(12);
+}
+
+impl Debug for ZwpTextInputV1ContentPurpose {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NORMAL => "NORMAL",
+            Self::ALPHA => "ALPHA",
+            Self::DIGITS => "DIGITS",
+            Self::NUMBER => "NUMBER",
+            Self::PHONE => "PHONE",
+            Self::URL => "URL",
+            Self::EMAIL => "EMAIL",
+            Self::NAME => "NAME",
+            Self::PASSWORD => "PASSWORD",
+            Self::DATE => "DATE",
+            Self::TIME => "TIME",
+            Self::DATETIME => "DATETIME",
+            Self::TERMINAL => "TERMINAL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV1PreeditStyle(pub u32);
+
+impl ZwpTextInputV1PreeditStyle {
+    /// default style for composing text
+    #[allow(dead_code)]
+    pub const DEFAULT: Self = Self(0);
+
+    /// style should be the same as in non-composing text
+    #[allow(dead_code)]
+    pub const NONE: Self = Self(1);
+
+    #[allow(dead_code)]
+    pub const ACTIVE: Self = Self(2);
+
+    #[allow(dead_code)]
+    pub const INACTIVE: Self = Self(3);
+
+    #[allow(dead_code)]
+    pub const HIGHLIGHT: Self = Self(4);
+
+    #[allow(dead_code)]
+    pub const UNDERLINE: Self = Self(5);
+
+    #[allow(dead_code)]
+    pub const SELECTION: Self = Self(6);
+
+    #[allow(dead_code)]
+    pub const INCORRECT: Self = Self(7);
+}
+
+impl Debug for ZwpTextInputV1PreeditStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::DEFAULT => "DEFAULT",
+            Self::NONE => "NONE",
+            Self::ACTIVE => "ACTIVE",
+            Self::INACTIVE => "INACTIVE",
+            Self::HIGHLIGHT => "HIGHLIGHT",
+            Self::UNDERLINE => "UNDERLINE",
+            Self::SELECTION => "SELECTION",
+            Self::INCORRECT => "INCORRECT",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV1TextDirection(pub u32);
+
+impl ZwpTextInputV1TextDirection {
+    /// automatic text direction based on text and language
+    #[allow(dead_code)]
+    pub const AUTO: Self = Self(0);
+
+    /// left-to-right
+    #[allow(dead_code)]
+    pub const LTR: Self = Self(1);
+
+    /// right-to-left
+    #[allow(dead_code)]
+    pub const RTL: Self = Self(2);
+}
+
+impl Debug for ZwpTextInputV1TextDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::AUTO => "AUTO",
+            Self::LTR => "LTR",
+            Self::RTL => "RTL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for enter events.
+    pub struct Enter<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for Enter<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(&self, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, surface)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for Enter<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_data, _slf, surface)
+        }
+    }
+
+    /// Event handler for leave events.
+    pub struct Leave<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for Leave<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref),
+    {
+        #[inline]
+        fn leave(&self, _slf: &ZwpTextInputV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for Leave<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref),
+    {
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for modifiers_map events.
+    pub struct ModifiersMap<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for ModifiersMap<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, &[u8]),
+    {
+        #[inline]
+        fn modifiers_map(&self, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+            self.0(_slf, map)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for ModifiersMap<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, &[u8]),
+    {
+        #[inline]
+        fn modifiers_map(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+            self.0(_data, _slf, map)
+        }
+    }
+
+    /// Event handler for input_panel_state events.
+    pub struct InputPanelState<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for InputPanelState<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32),
+    {
+        #[inline]
+        fn input_panel_state(&self, _slf: &ZwpTextInputV1Ref, state: u32) {
+            self.0(_slf, state)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for InputPanelState<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32),
+    {
+        #[inline]
+        fn input_panel_state(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+            self.0(_data, _slf, state)
+        }
+    }
+
+    /// Event handler for preedit_string events.
+    pub struct PreeditString<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for PreeditString<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32, &str, &str),
+    {
+        #[inline]
+        fn preedit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str, commit: &str) {
+            self.0(_slf, serial, text, commit)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for PreeditString<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str, &str),
+    {
+        #[inline]
+        fn preedit_string(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            text: &str,
+            commit: &str,
+        ) {
+            self.0(_data, _slf, serial, text, commit)
+        }
+    }
+
+    /// Event handler for preedit_styling events.
+    pub struct PreeditStyling<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for PreeditStyling<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+    {
+        #[inline]
+        fn preedit_styling(
+            &self,
+            _slf: &ZwpTextInputV1Ref,
+            index: u32,
+            length: u32,
+            style: ZwpTextInputV1PreeditStyle,
+        ) {
+            self.0(_slf, index, length, style)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for PreeditStyling<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+    {
+        #[inline]
+        fn preedit_styling(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: u32,
+            length: u32,
+            style: ZwpTextInputV1PreeditStyle,
+        ) {
+            self.0(_data, _slf, index, length, style)
+        }
+    }
+
+    /// Event handler for preedit_cursor events.
+    pub struct PreeditCursor<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for PreeditCursor<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, i32),
+    {
+        #[inline]
+        fn preedit_cursor(&self, _slf: &ZwpTextInputV1Ref, index: i32) {
+            self.0(_slf, index)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for PreeditCursor<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, i32),
+    {
+        #[inline]
+        fn preedit_cursor(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+            self.0(_data, _slf, index)
+        }
+    }
+
+    /// Event handler for commit_string events.
+    pub struct CommitString<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for CommitString<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn commit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+            self.0(_slf, serial, text)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for CommitString<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+            self.0(_data, _slf, serial, text)
+        }
+    }
+
+    /// Event handler for cursor_position events.
+    pub struct CursorPosition<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for CursorPosition<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn cursor_position(&self, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+            self.0(_slf, index, anchor)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for CursorPosition<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn cursor_position(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: i32,
+            anchor: i32,
+        ) {
+            self.0(_data, _slf, index, anchor)
+        }
+    }
+
+    /// Event handler for delete_surrounding_text events.
+    pub struct DeleteSurroundingText<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for DeleteSurroundingText<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, i32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(&self, _slf: &ZwpTextInputV1Ref, index: i32, length: u32) {
+            self.0(_slf, index, length)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for DeleteSurroundingText<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, i32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: i32,
+            length: u32,
+        ) {
+            self.0(_data, _slf, index, length)
+        }
+    }
+
+    /// Event handler for keysym events.
+    pub struct Keysym<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for Keysym<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn keysym(
+            &self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            time: u32,
+            sym: u32,
+            state: u32,
+            modifiers: u32,
+        ) {
+            self.0(_slf, serial, time, sym, state, modifiers)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for Keysym<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn keysym(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            time: u32,
+            sym: u32,
+            state: u32,
+            modifiers: u32,
+        ) {
+            self.0(_data, _slf, serial, time, sym, state, modifiers)
+        }
+    }
+
+    /// Event handler for language events.
+    pub struct Language<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for Language<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn language(&self, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+            self.0(_slf, serial, language)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for Language<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn language(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+            self.0(_data, _slf, serial, language)
+        }
+    }
+
+    /// Event handler for text_direction events.
+    pub struct TextDirection<F>(F);
+    impl<F> ZwpTextInputV1EventHandler for TextDirection<F>
+    where
+        F: Fn(&ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+    {
+        #[inline]
+        fn text_direction(
+            &self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            direction: ZwpTextInputV1TextDirection,
+        ) {
+            self.0(_slf, serial, direction)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerWithData<T> for TextDirection<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+    {
+        #[inline]
+        fn text_direction(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            direction: ZwpTextInputV1TextDirection,
+        ) {
+            self.0(_data, _slf, serial, direction)
+        }
+    }
+
+    /// Mutable event handler for enter events.
+    pub struct EnterMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for EnterMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(&mut self, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, surface)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for EnterMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, surface)
+        }
+    }
+
+    /// Mutable event handler for leave events.
+    pub struct LeaveMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for LeaveMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref),
+    {
+        #[inline]
+        fn leave(&mut self, _slf: &ZwpTextInputV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for LeaveMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref),
+    {
+        #[inline]
+        fn leave(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for modifiers_map events.
+    pub struct ModifiersMapMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for ModifiersMapMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, &[u8]),
+    {
+        #[inline]
+        fn modifiers_map(&mut self, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+            self.0(_slf, map)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for ModifiersMapMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, &[u8]),
+    {
+        #[inline]
+        fn modifiers_map(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+            self.0(_data, _slf, map)
+        }
+    }
+
+    /// Mutable event handler for input_panel_state events.
+    pub struct InputPanelStateMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for InputPanelStateMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32),
+    {
+        #[inline]
+        fn input_panel_state(&mut self, _slf: &ZwpTextInputV1Ref, state: u32) {
+            self.0(_slf, state)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for InputPanelStateMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32),
+    {
+        #[inline]
+        fn input_panel_state(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+            self.0(_data, _slf, state)
+        }
+    }
+
+    /// Mutable event handler for preedit_string events.
+    pub struct PreeditStringMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for PreeditStringMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32, &str, &str),
+    {
+        #[inline]
+        fn preedit_string(
+            &mut self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            text: &str,
+            commit: &str,
+        ) {
+            self.0(_slf, serial, text, commit)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for PreeditStringMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, &str, &str),
+    {
+        #[inline]
+        fn preedit_string(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            text: &str,
+            commit: &str,
+        ) {
+            self.0(_data, _slf, serial, text, commit)
+        }
+    }
+
+    /// Mutable event handler for preedit_styling events.
+    pub struct PreeditStylingMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for PreeditStylingMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+    {
+        #[inline]
+        fn preedit_styling(
+            &mut self,
+            _slf: &ZwpTextInputV1Ref,
+            index: u32,
+            length: u32,
+            style: ZwpTextInputV1PreeditStyle,
+        ) {
+            self.0(_slf, index, length, style)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for PreeditStylingMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+    {
+        #[inline]
+        fn preedit_styling(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: u32,
+            length: u32,
+            style: ZwpTextInputV1PreeditStyle,
+        ) {
+            self.0(_data, _slf, index, length, style)
+        }
+    }
+
+    /// Mutable event handler for preedit_cursor events.
+    pub struct PreeditCursorMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for PreeditCursorMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, i32),
+    {
+        #[inline]
+        fn preedit_cursor(&mut self, _slf: &ZwpTextInputV1Ref, index: i32) {
+            self.0(_slf, index)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for PreeditCursorMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, i32),
+    {
+        #[inline]
+        fn preedit_cursor(&mut self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+            self.0(_data, _slf, index)
+        }
+    }
+
+    /// Mutable event handler for commit_string events.
+    pub struct CommitStringMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for CommitStringMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn commit_string(&mut self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+            self.0(_slf, serial, text)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for CommitStringMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn commit_string(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            text: &str,
+        ) {
+            self.0(_data, _slf, serial, text)
+        }
+    }
+
+    /// Mutable event handler for cursor_position events.
+    pub struct CursorPositionMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for CursorPositionMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn cursor_position(&mut self, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+            self.0(_slf, index, anchor)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for CursorPositionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn cursor_position(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: i32,
+            anchor: i32,
+        ) {
+            self.0(_data, _slf, index, anchor)
+        }
+    }
+
+    /// Mutable event handler for delete_surrounding_text events.
+    pub struct DeleteSurroundingTextMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for DeleteSurroundingTextMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, i32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(&mut self, _slf: &ZwpTextInputV1Ref, index: i32, length: u32) {
+            self.0(_slf, index, length)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for DeleteSurroundingTextMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, i32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: i32,
+            length: u32,
+        ) {
+            self.0(_data, _slf, index, length)
+        }
+    }
+
+    /// Mutable event handler for keysym events.
+    pub struct KeysymMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for KeysymMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn keysym(
+            &mut self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            time: u32,
+            sym: u32,
+            state: u32,
+            modifiers: u32,
+        ) {
+            self.0(_slf, serial, time, sym, state, modifiers)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for KeysymMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn keysym(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            time: u32,
+            sym: u32,
+            state: u32,
+            modifiers: u32,
+        ) {
+            self.0(_data, _slf, serial, time, sym, state, modifiers)
+        }
+    }
+
+    /// Mutable event handler for language events.
+    pub struct LanguageMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for LanguageMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn language(&mut self, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+            self.0(_slf, serial, language)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for LanguageMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, &str),
+    {
+        #[inline]
+        fn language(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            language: &str,
+        ) {
+            self.0(_data, _slf, serial, language)
+        }
+    }
+
+    /// Mutable event handler for text_direction events.
+    pub struct TextDirectionMut<F>(F);
+    impl<F> ZwpTextInputV1EventHandlerMut for TextDirectionMut<F>
+    where
+        F: FnMut(&ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+    {
+        #[inline]
+        fn text_direction(
+            &mut self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            direction: ZwpTextInputV1TextDirection,
+        ) {
+            self.0(_slf, serial, direction)
+        }
+    }
+    impl<T, F> ZwpTextInputV1EventHandlerMutWithData<T> for TextDirectionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+    {
+        #[inline]
+        fn text_direction(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            direction: ZwpTextInputV1TextDirection,
+        ) {
+            self.0(_data, _slf, serial, direction)
+        }
+    }
+
+    impl ZwpTextInputV1 {
+        /// Creates an event handler for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(f: F) -> Enter<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(f: F) -> Enter<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(f: F) -> Leave<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(f: F) -> Leave<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler for modifiers_map events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_modifiers_map<F>(f: F) -> ModifiersMap<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, &[u8]),
+        {
+            ModifiersMap(f)
+        }
+
+        /// Creates an event handler with mutable data for modifiers_map events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_modifiers_map_with_data<T, F>(f: F) -> ModifiersMap<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, &[u8]),
+        {
+            ModifiersMap(f)
+        }
+
+        /// Creates an event handler for input_panel_state events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_input_panel_state<F>(f: F) -> InputPanelState<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32),
+        {
+            InputPanelState(f)
+        }
+
+        /// Creates an event handler with mutable data for input_panel_state events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_input_panel_state_with_data<T, F>(f: F) -> InputPanelState<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32),
+        {
+            InputPanelState(f)
+        }
+
+        /// Creates an event handler for preedit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string<F>(f: F) -> PreeditString<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, &str, &str),
+        {
+            PreeditString(f)
+        }
+
+        /// Creates an event handler with mutable data for preedit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string_with_data<T, F>(f: F) -> PreeditString<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str, &str),
+        {
+            PreeditString(f)
+        }
+
+        /// Creates an event handler for preedit_styling events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_styling<F>(f: F) -> PreeditStyling<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+        {
+            PreeditStyling(f)
+        }
+
+        /// Creates an event handler with mutable data for preedit_styling events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_styling_with_data<T, F>(f: F) -> PreeditStyling<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+        {
+            PreeditStyling(f)
+        }
+
+        /// Creates an event handler for preedit_cursor events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_cursor<F>(f: F) -> PreeditCursor<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, i32),
+        {
+            PreeditCursor(f)
+        }
+
+        /// Creates an event handler with mutable data for preedit_cursor events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_cursor_with_data<T, F>(f: F) -> PreeditCursor<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, i32),
+        {
+            PreeditCursor(f)
+        }
+
+        /// Creates an event handler for commit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_commit_string<F>(f: F) -> CommitString<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, &str),
+        {
+            CommitString(f)
+        }
+
+        /// Creates an event handler with mutable data for commit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_commit_string_with_data<T, F>(f: F) -> CommitString<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str),
+        {
+            CommitString(f)
+        }
+
+        /// Creates an event handler for cursor_position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cursor_position<F>(f: F) -> CursorPosition<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, i32, i32),
+        {
+            CursorPosition(f)
+        }
+
+        /// Creates an event handler with mutable data for cursor_position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cursor_position_with_data<T, F>(f: F) -> CursorPosition<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, i32, i32),
+        {
+            CursorPosition(f)
+        }
+
+        /// Creates an event handler for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text<F>(f: F) -> DeleteSurroundingText<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, i32, u32),
+        {
+            DeleteSurroundingText(f)
+        }
+
+        /// Creates an event handler with mutable data for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_with_data<T, F>(f: F) -> DeleteSurroundingText<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, i32, u32),
+        {
+            DeleteSurroundingText(f)
+        }
+
+        /// Creates an event handler for keysym events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_keysym<F>(f: F) -> Keysym<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+        {
+            Keysym(f)
+        }
+
+        /// Creates an event handler with mutable data for keysym events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_keysym_with_data<T, F>(f: F) -> Keysym<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+        {
+            Keysym(f)
+        }
+
+        /// Creates an event handler for language events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_language<F>(f: F) -> Language<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, &str),
+        {
+            Language(f)
+        }
+
+        /// Creates an event handler with mutable data for language events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_language_with_data<T, F>(f: F) -> Language<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str),
+        {
+            Language(f)
+        }
+
+        /// Creates an event handler for text_direction events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_text_direction<F>(f: F) -> TextDirection<F>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+        {
+            TextDirection(f)
+        }
+
+        /// Creates an event handler with mutable data for text_direction events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_text_direction_with_data<T, F>(f: F) -> TextDirection<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+        {
+            TextDirection(f)
+        }
+
+        /// Creates a mutable event handler for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut<F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut_with_data<T, F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut<F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut_with_data<T, F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for modifiers_map events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modifiers_map_mut<F>(f: F) -> ModifiersMapMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, &[u8]),
+        {
+            ModifiersMapMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for modifiers_map events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modifiers_map_mut_with_data<T, F>(f: F) -> ModifiersMapMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, &[u8]),
+        {
+            ModifiersMapMut(f)
+        }
+
+        /// Creates a mutable event handler for input_panel_state events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_input_panel_state_mut<F>(f: F) -> InputPanelStateMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32),
+        {
+            InputPanelStateMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for input_panel_state events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_input_panel_state_mut_with_data<T, F>(f: F) -> InputPanelStateMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32),
+        {
+            InputPanelStateMut(f)
+        }
+
+        /// Creates a mutable event handler for preedit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_string_mut<F>(f: F) -> PreeditStringMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32, &str, &str),
+        {
+            PreeditStringMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preedit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_string_mut_with_data<T, F>(f: F) -> PreeditStringMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, &str, &str),
+        {
+            PreeditStringMut(f)
+        }
+
+        /// Creates a mutable event handler for preedit_styling events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_styling_mut<F>(f: F) -> PreeditStylingMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+        {
+            PreeditStylingMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preedit_styling events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_styling_mut_with_data<T, F>(f: F) -> PreeditStylingMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+        {
+            PreeditStylingMut(f)
+        }
+
+        /// Creates a mutable event handler for preedit_cursor events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_cursor_mut<F>(f: F) -> PreeditCursorMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, i32),
+        {
+            PreeditCursorMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preedit_cursor events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_cursor_mut_with_data<T, F>(f: F) -> PreeditCursorMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, i32),
+        {
+            PreeditCursorMut(f)
+        }
+
+        /// Creates a mutable event handler for commit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_commit_string_mut<F>(f: F) -> CommitStringMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32, &str),
+        {
+            CommitStringMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for commit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_commit_string_mut_with_data<T, F>(f: F) -> CommitStringMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, &str),
+        {
+            CommitStringMut(f)
+        }
+
+        /// Creates a mutable event handler for cursor_position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cursor_position_mut<F>(f: F) -> CursorPositionMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, i32, i32),
+        {
+            CursorPositionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for cursor_position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cursor_position_mut_with_data<T, F>(f: F) -> CursorPositionMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, i32, i32),
+        {
+            CursorPositionMut(f)
+        }
+
+        /// Creates a mutable event handler for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_mut<F>(f: F) -> DeleteSurroundingTextMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, i32, u32),
+        {
+            DeleteSurroundingTextMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_mut_with_data<T, F>(f: F) -> DeleteSurroundingTextMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, i32, u32),
+        {
+            DeleteSurroundingTextMut(f)
+        }
+
+        /// Creates a mutable event handler for keysym events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_keysym_mut<F>(f: F) -> KeysymMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+        {
+            KeysymMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for keysym events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_keysym_mut_with_data<T, F>(f: F) -> KeysymMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+        {
+            KeysymMut(f)
+        }
+
+        /// Creates a mutable event handler for language events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_language_mut<F>(f: F) -> LanguageMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32, &str),
+        {
+            LanguageMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for language events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_language_mut_with_data<T, F>(f: F) -> LanguageMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, &str),
+        {
+            LanguageMut(f)
+        }
+
+        /// Creates a mutable event handler for text_direction events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_text_direction_mut<F>(f: F) -> TextDirectionMut<F>
+        where
+            F: FnMut(&ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+        {
+            TextDirectionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for text_direction events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_text_direction_mut_with_data<T, F>(f: F) -> TextDirectionMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+        {
+            TextDirectionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                enter: Ignore,
+                leave: Ignore,
+                modifiers_map: Ignore,
+                input_panel_state: Ignore,
+                preedit_string: Ignore,
+                preedit_styling: Ignore,
+                preedit_cursor: Ignore,
+                commit_string: Ignore,
+                cursor_position: Ignore,
+                delete_surrounding_text: Ignore,
+                keysym: Ignore,
+                language: Ignore,
+                text_direction: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTextInputV1EventHandler for Ignore {}
+    impl<T> ZwpTextInputV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTextInputV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<
+        H0 = Ignore,
+        H1 = Ignore,
+        H2 = Ignore,
+        H3 = Ignore,
+        H4 = Ignore,
+        H5 = Ignore,
+        H6 = Ignore,
+        H7 = Ignore,
+        H8 = Ignore,
+        H9 = Ignore,
+        H10 = Ignore,
+        H11 = Ignore,
+        H12 = Ignore,
+    > {
+        enter: H0,
+        leave: H1,
+        modifiers_map: H2,
+        input_panel_state: H3,
+        preedit_string: H4,
+        preedit_styling: H5,
+        preedit_cursor: H6,
+        commit_string: H7,
+        cursor_position: H8,
+        delete_surrounding_text: H9,
+        keysym: H10,
+        language: H11,
+        text_direction: H12,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12> ZwpTextInputV1EventHandler
+        for Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+    where
+        H0: ZwpTextInputV1EventHandler,
+        H1: ZwpTextInputV1EventHandler,
+        H2: ZwpTextInputV1EventHandler,
+        H3: ZwpTextInputV1EventHandler,
+        H4: ZwpTextInputV1EventHandler,
+        H5: ZwpTextInputV1EventHandler,
+        H6: ZwpTextInputV1EventHandler,
+        H7: ZwpTextInputV1EventHandler,
+        H8: ZwpTextInputV1EventHandler,
+        H9: ZwpTextInputV1EventHandler,
+        H10: ZwpTextInputV1EventHandler,
+        H11: ZwpTextInputV1EventHandler,
+        H12: ZwpTextInputV1EventHandler,
+    {
+        #[inline]
+        fn enter(&self, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+            self.enter.enter(_slf, surface)
+        }
+
+        #[inline]
+        fn leave(&self, _slf: &ZwpTextInputV1Ref) {
+            self.leave.leave(_slf)
+        }
+
+        #[inline]
+        fn modifiers_map(&self, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+            self.modifiers_map.modifiers_map(_slf, map)
+        }
+
+        #[inline]
+        fn input_panel_state(&self, _slf: &ZwpTextInputV1Ref, state: u32) {
+            self.input_panel_state.input_panel_state(_slf, state)
+        }
+
+        #[inline]
+        fn preedit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str, commit: &str) {
+            self.preedit_string
+                .preedit_string(_slf, serial, text, commit)
+        }
+
+        #[inline]
+        fn preedit_styling(
+            &self,
+            _slf: &ZwpTextInputV1Ref,
+            index: u32,
+            length: u32,
+            style: ZwpTextInputV1PreeditStyle,
+        ) {
+            self.preedit_styling
+                .preedit_styling(_slf, index, length, style)
+        }
+
+        #[inline]
+        fn preedit_cursor(&self, _slf: &ZwpTextInputV1Ref, index: i32) {
+            self.preedit_cursor.preedit_cursor(_slf, index)
+        }
+
+        #[inline]
+        fn commit_string(&self, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+            self.commit_string.commit_string(_slf, serial, text)
+        }
+
+        #[inline]
+        fn cursor_position(&self, _slf: &ZwpTextInputV1Ref, index: i32, anchor: i32) {
+            self.cursor_position.cursor_position(_slf, index, anchor)
+        }
+
+        #[inline]
+        fn delete_surrounding_text(&self, _slf: &ZwpTextInputV1Ref, index: i32, length: u32) {
+            self.delete_surrounding_text
+                .delete_surrounding_text(_slf, index, length)
+        }
+
+        #[inline]
+        fn keysym(
+            &self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            time: u32,
+            sym: u32,
+            state: u32,
+            modifiers: u32,
+        ) {
+            self.keysym
+                .keysym(_slf, serial, time, sym, state, modifiers)
+        }
+
+        #[inline]
+        fn language(&self, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+            self.language.language(_slf, serial, language)
+        }
+
+        #[inline]
+        fn text_direction(
+            &self,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            direction: ZwpTextInputV1TextDirection,
+        ) {
+            self.text_direction.text_direction(_slf, serial, direction)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        ZwpTextInputV1EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+    where
+        H0: ZwpTextInputV1EventHandlerWithData<T>,
+        H1: ZwpTextInputV1EventHandlerWithData<T>,
+        H2: ZwpTextInputV1EventHandlerWithData<T>,
+        H3: ZwpTextInputV1EventHandlerWithData<T>,
+        H4: ZwpTextInputV1EventHandlerWithData<T>,
+        H5: ZwpTextInputV1EventHandlerWithData<T>,
+        H6: ZwpTextInputV1EventHandlerWithData<T>,
+        H7: ZwpTextInputV1EventHandlerWithData<T>,
+        H8: ZwpTextInputV1EventHandlerWithData<T>,
+        H9: ZwpTextInputV1EventHandlerWithData<T>,
+        H10: ZwpTextInputV1EventHandlerWithData<T>,
+        H11: ZwpTextInputV1EventHandlerWithData<T>,
+        H12: ZwpTextInputV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, surface: Option<&WlSurfaceRef>) {
+            self.enter.enter(_data, _slf, surface)
+        }
+
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref) {
+            self.leave.leave(_data, _slf)
+        }
+
+        #[inline]
+        fn modifiers_map(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, map: &[u8]) {
+            self.modifiers_map.modifiers_map(_data, _slf, map)
+        }
+
+        #[inline]
+        fn input_panel_state(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, state: u32) {
+            self.input_panel_state.input_panel_state(_data, _slf, state)
+        }
+
+        #[inline]
+        fn preedit_string(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            text: &str,
+            commit: &str,
+        ) {
+            self.preedit_string
+                .preedit_string(_data, _slf, serial, text, commit)
+        }
+
+        #[inline]
+        fn preedit_styling(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: u32,
+            length: u32,
+            style: ZwpTextInputV1PreeditStyle,
+        ) {
+            self.preedit_styling
+                .preedit_styling(_data, _slf, index, length, style)
+        }
+
+        #[inline]
+        fn preedit_cursor(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, index: i32) {
+            self.preedit_cursor.preedit_cursor(_data, _slf, index)
+        }
+
+        #[inline]
+        fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, text: &str) {
+            self.commit_string.commit_string(_data, _slf, serial, text)
+        }
+
+        #[inline]
+        fn cursor_position(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: i32,
+            anchor: i32,
+        ) {
+            self.cursor_position
+                .cursor_position(_data, _slf, index, anchor)
+        }
+
+        #[inline]
+        fn delete_surrounding_text(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            index: i32,
+            length: u32,
+        ) {
+            self.delete_surrounding_text
+                .delete_surrounding_text(_data, _slf, index, length)
+        }
+
+        #[inline]
+        fn keysym(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            time: u32,
+            sym: u32,
+            state: u32,
+            modifiers: u32,
+        ) {
+            self.keysym
+                .keysym(_data, _slf, serial, time, sym, state, modifiers)
+        }
+
+        #[inline]
+        fn language(&self, _data: &mut T, _slf: &ZwpTextInputV1Ref, serial: u32, language: &str) {
+            self.language.language(_data, _slf, serial, language)
+        }
+
+        #[inline]
+        fn text_direction(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV1Ref,
+            serial: u32,
+            direction: ZwpTextInputV1TextDirection,
+        ) {
+            self.text_direction
+                .text_direction(_data, _slf, serial, direction)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+    {
+        /// Sets the closure for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(
+            self,
+            f: F,
+        ) -> Handler<Enter<F>, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<Enter<F>, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, Leave<F>, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, Leave<F>, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for modifiers_map events.
+        #[allow(dead_code)]
+        pub fn on_modifiers_map<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, ModifiersMap<F>, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, &[u8]),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: ModifiersMap(f),
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for modifiers_map events.
+        #[allow(dead_code)]
+        pub fn on_modifiers_map_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, ModifiersMap<F>, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, &[u8]),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: ModifiersMap(f),
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for input_panel_state events.
+        #[allow(dead_code)]
+        pub fn on_input_panel_state<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, InputPanelState<F>, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: InputPanelState(f),
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for input_panel_state events.
+        #[allow(dead_code)]
+        pub fn on_input_panel_state_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, InputPanelState<F>, H4, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: InputPanelState(f),
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for preedit_string events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, PreeditString<F>, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, &str, &str),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: PreeditString(f),
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preedit_string events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, PreeditString<F>, H5, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str, &str),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: PreeditString(f),
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for preedit_styling events.
+        #[allow(dead_code)]
+        pub fn on_preedit_styling<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, PreeditStyling<F>, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: PreeditStyling(f),
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preedit_styling events.
+        #[allow(dead_code)]
+        pub fn on_preedit_styling_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, PreeditStyling<F>, H6, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, u32, ZwpTextInputV1PreeditStyle),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: PreeditStyling(f),
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for preedit_cursor events.
+        #[allow(dead_code)]
+        pub fn on_preedit_cursor<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, PreeditCursor<F>, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: PreeditCursor(f),
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preedit_cursor events.
+        #[allow(dead_code)]
+        pub fn on_preedit_cursor_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, PreeditCursor<F>, H7, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: PreeditCursor(f),
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for commit_string events.
+        #[allow(dead_code)]
+        pub fn on_commit_string<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, CommitString<F>, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, &str),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: CommitString(f),
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for commit_string events.
+        #[allow(dead_code)]
+        pub fn on_commit_string_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, CommitString<F>, H8, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: CommitString(f),
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for cursor_position events.
+        #[allow(dead_code)]
+        pub fn on_cursor_position<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, CursorPosition<F>, H9, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: CursorPosition(f),
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for cursor_position events.
+        #[allow(dead_code)]
+        pub fn on_cursor_position_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, CursorPosition<F>, H9, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: CursorPosition(f),
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for delete_surrounding_text events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, DeleteSurroundingText<F>, H10, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, i32, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: DeleteSurroundingText(f),
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for delete_surrounding_text events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, DeleteSurroundingText<F>, H10, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, i32, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: DeleteSurroundingText(f),
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for keysym events.
+        #[allow(dead_code)]
+        pub fn on_keysym<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, Keysym<F>, H11, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: Keysym(f),
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for keysym events.
+        #[allow(dead_code)]
+        pub fn on_keysym_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, Keysym<F>, H11, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, u32, u32, u32, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: Keysym(f),
+                language: self.language,
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for language events.
+        #[allow(dead_code)]
+        pub fn on_language<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, Language<F>, H12>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, &str),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: Language(f),
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for language events.
+        #[allow(dead_code)]
+        pub fn on_language_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, Language<F>, H12>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, &str),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: Language(f),
+                text_direction: self.text_direction,
+            }
+        }
+
+        /// Sets the closure for text_direction events.
+        #[allow(dead_code)]
+        pub fn on_text_direction<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, TextDirection<F>>
+        where
+            F: Fn(&ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: TextDirection(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for text_direction events.
+        #[allow(dead_code)]
+        pub fn on_text_direction_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, TextDirection<F>>
+        where
+            F: Fn(&mut T, &ZwpTextInputV1Ref, u32, ZwpTextInputV1TextDirection),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                modifiers_map: self.modifiers_map,
+                input_panel_state: self.input_panel_state,
+                preedit_string: self.preedit_string,
+                preedit_styling: self.preedit_styling,
+                preedit_cursor: self.preedit_cursor,
+                commit_string: self.commit_string,
+                cursor_position: self.cursor_position,
+                delete_surrounding_text: self.delete_surrounding_text,
+                keysym: self.keysym,
+                language: self.language,
+                text_direction: TextDirection(f),
+            }
+        }
+    }
+}
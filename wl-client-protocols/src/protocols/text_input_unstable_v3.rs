@@ -0,0 +1,37 @@
+//! Protocol for composing text
+//!
+//! This protocol allows compositors to act as input methods and to send text
+//! to applications. A text input object is used to manage state of what are
+//! typically text entry fields in the application.
+//!
+//! This document adheres to the RFC 2119 when using words like "must",
+//! "should", "may", etc.
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod zwp_text_input_manager_v3;
+pub mod zwp_text_input_v3;
+
+/// Metadata about the text_input_unstable_v3 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "text_input_unstable_v3",
+    interfaces: &[&zwp_text_input_v3::INFO, &zwp_text_input_manager_v3::INFO],
+};
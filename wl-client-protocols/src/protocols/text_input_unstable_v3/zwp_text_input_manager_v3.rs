@@ -0,0 +1,380 @@
+//! text input manager
+//!
+//! A factory for text-input objects. This object is a global singleton.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_text_input_manager_v3".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_text_input".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpTextInputV3::WL_INTERFACE),
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_text_input_manager_v3 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_text_input_manager_v3",
+    protocol: "text_input_unstable_v3",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_text_input",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_text_input_v3"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_text_input_manager_v3 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputManagerV3 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_text_input_manager_v3 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputManagerV3Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTextInputManagerV3 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTextInputManagerV3 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTextInputManagerV3 {
+    const INTERFACE: &'static str = "zwp_text_input_manager_v3";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpTextInputManagerV3Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTextInputManagerV3Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTextInputManagerV3Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTextInputManagerV3Ref {
+    type Owned = ZwpTextInputManagerV3;
+}
+
+impl Deref for ZwpTextInputManagerV3 {
+    type Target = ZwpTextInputManagerV3Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTextInputManagerV3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_manager_v3#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTextInputManagerV3Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_manager_v3#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTextInputManagerV3Ref> for ZwpTextInputManagerV3 {
+    fn eq(&self, other: &ZwpTextInputManagerV3Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTextInputManagerV3> for ZwpTextInputManagerV3Ref {
+    fn eq(&self, other: &ZwpTextInputManagerV3) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputManagerV3 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// Destroy the wp_text_input_manager
+    ///
+    /// Destroy the wp_text_input_manager object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_text_input request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_TEXT_INPUT__SINCE: u32 = 1;
+
+    /// create a new text input object
+    ///
+    /// Creates a new text-input object for a given seat.
+    ///
+    /// # Arguments
+    ///
+    /// - `seat`:
+    #[inline]
+    pub fn get_text_input(&self, seat: &WlSeatRef) -> ZwpTextInputV3 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, ZwpTextInputV3::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZwpTextInputV3::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputManagerV3Ref {
+    /// create a new text input object
+    ///
+    /// Creates a new text-input object for a given seat.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `seat`:
+    #[inline]
+    pub fn get_text_input(&self, _queue: &Queue, seat: &WlSeatRef) -> ZwpTextInputV3 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, ZwpTextInputV3::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZwpTextInputV3::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpTextInputManagerV3] proxies.
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV3EventHandler {}
+
+impl ZwpTextInputManagerV3EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTextInputManagerV3] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputManagerV3EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV3EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpTextInputManagerV3] proxies.
+///
+/// This trait is the same as [ZwpTextInputManagerV3EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV3EventHandlerMut {}
+
+/// A mutable event handler for [ZwpTextInputManagerV3] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputManagerV3EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputManagerV3EventHandlerMutWithData<T> {}
+
+impl<H> ZwpTextInputManagerV3EventHandler for MutEventHandler<H> where
+    H: ZwpTextInputManagerV3EventHandlerMut
+{
+}
+
+impl<T, H> ZwpTextInputManagerV3EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpTextInputManagerV3EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpTextInputManagerV3EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTextInputManagerV3EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpTextInputManagerV3EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTextInputManagerV3EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTextInputManagerV3EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_text_input_manager_v3", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTextInputManagerV3EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_text_input_manager_v3", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTextInputManagerV3EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTextInputManagerV3EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpTextInputManagerV3 {}
+}
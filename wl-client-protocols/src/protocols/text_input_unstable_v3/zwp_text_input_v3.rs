@@ -0,0 +1,3419 @@
+//! text input
+//!
+//! The zwp_text_input_v3 interface represents text input and input methods
+//! associated with a seat. It provides enter/leave events to follow the
+//! text input focus for a seat.
+//!
+//! Requests are used to enable/disable the text-input object and set
+//! state information like surrounding and selected text or the content type.
+//! The information about the entered text is sent to the text-input object
+//! via the preedit_string and commit_string events.
+//!
+//! Text is valid UTF-8 encoded, indices and lengths are in bytes. Indices
+//! must not point to middle bytes inside a code point: they must either
+//! point to the first byte of a code point or to the end of the buffer.
+//! Lengths must be measured between two valid indices.
+//!
+//! Focus moving throughout surfaces will result in the emission of
+//! zwp_text_input_v3.enter and zwp_text_input_v3.leave events. The focused
+//! surface must commit zwp_text_input_v3.enable and
+//! zwp_text_input_v3.disable requests as the keyboard focus moves across
+//! editable and non-editable elements of the UI. Those two requests are not
+//! expected to be paired with each other, the compositor must be able to
+//! handle consecutive series of the same request.
+//!
+//! State is sent by the state requests (set_surrounding_text,
+//! set_content_type and set_cursor_rectangle) and a commit request. After an
+//! enter event or disable request all state information is invalidated and
+//! needs to be resent by the client.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_text_input_v3".as_ptr(),
+    version: 1,
+    method_count: 8,
+    methods: {
+        static MESSAGES: [wl_message; 8] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"enable".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"disable".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_surrounding_text".as_ptr(),
+                signature: c"sii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_text_change_cause".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_content_type".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_cursor_rectangle".as_ptr(),
+                signature: c"iiii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 4] = [None, None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 6,
+    events: {
+        static MESSAGES: [wl_message; 6] = [
+            wl_message {
+                name: c"enter".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"leave".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"preedit_string".as_ptr(),
+                signature: c"?sii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit_string".as_ptr(),
+                signature: c"?s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"delete_surrounding_text".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_text_input_v3 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_text_input_v3",
+    protocol: "text_input_unstable_v3",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "enable",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "disable",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_surrounding_text",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cursor",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "anchor",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_text_change_cause",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "cause",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("change_cause"),
+            }],
+        },
+        info::MessageInfo {
+            name: "set_content_type",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "hint",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("content_hint"),
+                },
+                info::ArgInfo {
+                    name: "purpose",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("content_purpose"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_cursor_rectangle",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "commit",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "preedit_string",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "text",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cursor_begin",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "cursor_end",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "commit_string",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "text",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "delete_surrounding_text",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "before_length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "after_length",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "change_cause",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "input_method",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "other",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "content_hint",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "none",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "completion",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "spellcheck",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "auto_capitalization",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "lowercase",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "uppercase",
+                    value: 16,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "titlecase",
+                    value: 32,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "hidden_text",
+                    value: 64,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "sensitive_data",
+                    value: 128,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "latin",
+                    value: 256,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "multiline",
+                    value: 512,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "content_purpose",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "normal",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "alpha",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "digits",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "number",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "phone",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "url",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "email",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "name",
+                    value: 7,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "password",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pin",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "date",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "time",
+                    value: 11,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "datetime",
+                    value: 12,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "terminal",
+                    value: 13,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned zwp_text_input_v3 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputV3 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_text_input_v3 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpTextInputV3Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpTextInputV3 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpTextInputV3 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpTextInputV3 {
+    const INTERFACE: &'static str = "zwp_text_input_v3";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpTextInputV3Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpTextInputV3Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpTextInputV3Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpTextInputV3Ref {
+    type Owned = ZwpTextInputV3;
+}
+
+impl Deref for ZwpTextInputV3 {
+    type Target = ZwpTextInputV3Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpTextInputV3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_v3#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpTextInputV3Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_text_input_v3#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpTextInputV3Ref> for ZwpTextInputV3 {
+    fn eq(&self, other: &ZwpTextInputV3Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpTextInputV3> for ZwpTextInputV3Ref {
+    fn eq(&self, other: &ZwpTextInputV3) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputV3 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// Destroy the wp_text_input
+    ///
+    /// Destroy the wp_text_input object. Also disables all surfaces enabled
+    /// through this wp_text_input object.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 8
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputV3Ref {
+    /// Request text input to be enabled
+    ///
+    /// Requests text input on the surface previously obtained from the enter
+    /// event.
+    ///
+    /// This request must be issued every time the focused text input changes
+    /// to a new one, including within the current surface. Use
+    /// zwp_text_input_v3.disable when there is no longer any input focus on
+    /// the current surface.
+    ///
+    /// Clients must not enable more than one text input on the single seat
+    /// and should disable the current text input before enabling the new one.
+    /// Requests to enable a text input when another text input is enabled
+    /// on the same seat must be ignored by compositor.
+    ///
+    /// This request resets all state associated with previous enable, disable,
+    /// set_surrounding_text, set_text_change_cause, set_content_type, and
+    /// set_cursor_rectangle requests, as well as the state associated with
+    /// preedit_string, commit_string, and delete_surrounding_text events.
+    ///
+    /// The set_surrounding_text, set_content_type and set_cursor_rectangle
+    /// requests must follow if the text input supports the necessary
+    /// functionality.
+    ///
+    /// State set with this request is double-buffered. It will get applied on
+    /// the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The changes must be applied by the compositor after issuing a
+    /// zwp_text_input_v3.commit request.
+    #[inline]
+    pub fn enable(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 8
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// Disable text input on a surface
+    ///
+    /// Explicitly disable text input on the current surface (typically when
+    /// there is no focus on any text entry inside the surface).
+    ///
+    /// State set with this request is double-buffered. It will get applied on
+    /// the next zwp_text_input_v3.commit request.
+    #[inline]
+    pub fn disable(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 8
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// sets the surrounding text
+    ///
+    /// Sets the surrounding plain text around the input, excluding the preedit
+    /// text.
+    ///
+    /// The client should notify the compositor of any changes in any of the
+    /// values carried with this request, including changes caused by handling
+    /// incoming text-input events as well as changes caused by other
+    /// mechanisms like keyboard typing.
+    ///
+    /// If the client is unaware of the text around the cursor, it should not
+    /// issue this request, to signify lack of support to the compositor.
+    ///
+    /// Text is UTF-8 encoded, and should include the cursor position, the
+    /// complete selection and additional characters before and after them.
+    /// There is a maximum length of wayland messages, so text can not be
+    /// longer than 4000 bytes.
+    ///
+    /// Cursor is the byte offset of the cursor within text buffer.
+    ///
+    /// Anchor is the byte offset of the selection anchor within text buffer.
+    /// If there is no selected text, anchor is the same as cursor.
+    ///
+    /// If any preedit text is present, it is replaced with a cursor for the
+    /// purpose of this event.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The initial state for affected fields is empty, meaning that the text
+    /// input does not support sending surrounding text. If the empty values
+    /// get applied, subsequent attempts to change them may have no effect.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    /// - `cursor`:
+    /// - `anchor`:
+    #[inline]
+    pub fn set_surrounding_text(&self, text: &str, cursor: i32, anchor: i32) {
+        let (arg0, arg1, arg2) = (text, cursor, anchor);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [
+                wl_argument { s: str0 },
+                wl_argument { i: arg1 },
+                wl_argument { i: arg2 },
+            ];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 3 < INTERFACE.method_count = 8
+            //         - the request signature is `sii`
+            unsafe {
+                self.proxy.send_request(3, &mut args);
+            }
+        })
+    }
+
+    /// indicates the cause of surrounding text change
+    ///
+    /// Tells the compositor why the text surrounding the cursor changed.
+    ///
+    /// Whenever the client detects an external change in text, cursor, or
+    /// anchor posision, it must issue this request to the compositor. This
+    /// request is intended to give the input method a chance to update the
+    /// preedit text in an appropriate way, e.g. by removing it when the user
+    /// starts typing with a keyboard.
+    ///
+    /// cause describes the source of the change.
+    ///
+    /// The value set with this request is double-buffered. It must be applied
+    /// and reset to initial at the next zwp_text_input_v3.commit request.
+    ///
+    /// The initial value of cause is input_method.
+    ///
+    /// # Arguments
+    ///
+    /// - `cause`:
+    #[inline]
+    pub fn set_text_change_cause(&self, cause: ZwpTextInputV3ChangeCause) {
+        let (arg0,) = (cause,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 8
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(4, &mut args);
+        }
+    }
+
+    /// set content purpose and hint
+    ///
+    /// Sets the content purpose and content hint. While the purpose is the
+    /// basic purpose of an input field, the hint flags allow to modify some of
+    /// the behavior.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request.
+    /// Subsequent attempts to update them may have no effect. The values
+    /// remain valid until the next committed enable or disable request.
+    ///
+    /// The initial value for hint is none, and the initial value for purpose
+    /// is normal.
+    ///
+    /// # Arguments
+    ///
+    /// - `hint`:
+    /// - `purpose`:
+    #[inline]
+    pub fn set_content_type(
+        &self,
+        hint: ZwpTextInputV3ContentHint,
+        purpose: ZwpTextInputV3ContentPurpose,
+    ) {
+        let (arg0, arg1) = (hint, purpose);
+        let mut args = [wl_argument { u: arg0.0 }, wl_argument { u: arg1.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 5 < INTERFACE.method_count = 8
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(5, &mut args);
+        }
+    }
+
+    /// set cursor position
+    ///
+    /// Marks an area around the cursor as a x, y, width, height rectangle in
+    /// surface local coordinates.
+    ///
+    /// Allows the compositor to put a window with word suggestions near the
+    /// cursor, without obstructing the text being input.
+    ///
+    /// If the client is unaware of the position of edited text, it should not
+    /// issue this request, to signify lack of support to the compositor.
+    ///
+    /// Values set with this request are double-buffered. They will get applied
+    /// on the next zwp_text_input_v3.commit request, and stay valid until the
+    /// next committed enable or disable request.
+    ///
+    /// The initial values describing a cursor rectangle are empty. That means
+    /// the text input does not support describing the cursor area. If the
+    /// empty values get applied, subsequent attempts to change them may have
+    /// no effect.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`:
+    /// - `y`:
+    /// - `width`:
+    /// - `height`:
+    #[inline]
+    pub fn set_cursor_rectangle(&self, x: i32, y: i32, width: i32, height: i32) {
+        let (arg0, arg1, arg2, arg3) = (x, y, width, height);
+        let mut args = [
+            wl_argument { i: arg0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+            wl_argument { i: arg3 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 6 < INTERFACE.method_count = 8
+        //         - the request signature is `iiii`
+        unsafe {
+            self.proxy.send_request(6, &mut args);
+        }
+    }
+
+    /// commit state
+    ///
+    /// Atomically applies state changes recently sent to the compositor.
+    ///
+    /// The commit request establishes and updates the state of the client, and
+    /// must be issued after any changes to apply them.
+    ///
+    /// Text input state (enabled status, content purpose, content hint,
+    /// surrounding text and change cause, cursor rectangle) is conceptually
+    /// double-buffered within the context of a text input, i.e. between a
+    /// committed enable request and the following committed enable or disable
+    /// request.
+    ///
+    /// Protocol requests modify the pending state, as opposed to the current
+    /// state in use by the input method. A commit request atomically applies
+    /// all pending state, replacing the current state. After commit, the new
+    /// pending state is as documented for each related request.
+    ///
+    /// Requests are applied in the order of arrival.
+    ///
+    /// Neither current nor pending state are modified unless noted otherwise.
+    ///
+    /// The compositor must count the number of commit requests coming from
+    /// each zwp_text_input_v3 object and use the count as the serial in done
+    /// events.
+    #[inline]
+    pub fn commit(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 7 < INTERFACE.method_count = 8
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(7, &mut args);
+        }
+    }
+}
+
+impl ZwpTextInputV3 {
+    /// Since when the enter event is available.
+    #[allow(dead_code)]
+    pub const EVT__ENTER__SINCE: u32 = 1;
+
+    /// Since when the leave event is available.
+    #[allow(dead_code)]
+    pub const EVT__LEAVE__SINCE: u32 = 1;
+
+    /// Since when the preedit_string event is available.
+    #[allow(dead_code)]
+    pub const EVT__PREEDIT_STRING__SINCE: u32 = 1;
+
+    /// Since when the commit_string event is available.
+    #[allow(dead_code)]
+    pub const EVT__COMMIT_STRING__SINCE: u32 = 1;
+
+    /// Since when the delete_surrounding_text event is available.
+    #[allow(dead_code)]
+    pub const EVT__DELETE_SURROUNDING_TEXT__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpTextInputV3] proxies.
+#[allow(dead_code)]
+pub trait ZwpTextInputV3EventHandler {
+    /// enter event
+    ///
+    /// Notification that this seat's text-input focus is on a certain surface.
+    ///
+    /// If client has created multiple text input objects, compositor must send
+    /// this event to all of them.
+    ///
+    /// When the seat has the keyboard capability the text-input focus follows
+    /// the keyboard focus. This event sets the current surface for the
+    /// text-input object.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn enter(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// leave event
+    ///
+    /// Notification that this seat's text-input focus is no longer on a
+    /// certain surface. The client should reset any preedit string previously
+    /// set.
+    ///
+    /// The leave notification clears the current surface. It is sent before
+    /// the enter notification for the new focus. After leave event, compositor
+    /// must ignore requests from any text input instances until next enter
+    /// event.
+    ///
+    /// When the seat has the keyboard capability the text-input focus follows
+    /// the keyboard focus.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn leave(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// pre-edit
+    ///
+    /// Notify when a new composing text (pre-edit) should be set at the
+    /// current cursor position. Any previously set composing text must be
+    /// removed. Any previously existing selected text must be removed.
+    ///
+    /// The argument text contains the pre-edit string buffer.
+    ///
+    /// The parameters cursor_begin and cursor_end are counted in bytes
+    /// relative to the beginning of the submitted text buffer. Cursor should
+    /// be hidden when both are equal to -1.
+    ///
+    /// They could be represented by the client as a line if both values are
+    /// the same, or as a text highlight otherwise.
+    ///
+    /// Values set with this event are double-buffered. They must be applied
+    /// and reset to initial on the next zwp_text_input_v3.done event.
+    ///
+    /// The initial value of text is an empty string, and cursor_begin,
+    /// cursor_end and cursor_hidden are all 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    /// - `cursor_begin`:
+    /// - `cursor_end`:
+    #[inline]
+    fn preedit_string(
+        &self,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        let _ = text;
+        let _ = cursor_begin;
+        let _ = cursor_end;
+    }
+
+    /// text commit
+    ///
+    /// Notify when text should be inserted into the editor widget. The text to
+    /// commit could be either just a single character after a key press or the
+    /// result of some composing (pre-edit).
+    ///
+    /// Values set with this event are double-buffered. They must be applied
+    /// and reset to initial on the next zwp_text_input_v3.done event.
+    ///
+    /// The initial value of text is an empty string.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    #[inline]
+    fn commit_string(&self, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        let _ = text;
+    }
+
+    /// delete surrounding text
+    ///
+    /// Notify when the text around the current cursor position should be
+    /// deleted.
+    ///
+    /// Before_length and after_length are the number of bytes before and after
+    /// the current cursor index (excluding the selection) to delete.
+    ///
+    /// If a preedit text is present, in effect before_length is counted from
+    /// the beginning of it, and after_length from its end (see done event
+    /// sequence).
+    ///
+    /// Values set with this event are double-buffered. They must be applied
+    /// and reset to initial on the next zwp_text_input_v3.done event.
+    ///
+    /// The initial values of both before_length and after_length are 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `before_length`: length of text before current cursor position
+    /// - `after_length`: length of text after current cursor position
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        let _ = before_length;
+        let _ = after_length;
+    }
+
+    /// apply changes
+    ///
+    /// Instruct the application to apply changes to state requested by the
+    /// preedit_string, commit_string and delete_surrounding_text events. The
+    /// state relating to these events is double-buffered, and each one
+    /// modifies the pending state. This event replaces the current state with
+    /// the pending state.
+    ///
+    /// The application must proceed by evaluating the changes in the following
+    /// order:
+    ///
+    /// 1. Replace existing preedit string with the cursor.
+    /// 2. Delete requested surrounding text.
+    /// 3. Insert commit string with the cursor at its end.
+    /// 4. Calculate surrounding text to send.
+    /// 5. Insert new preedit text in cursor position.
+    /// 6. Place cursor inside preedit text.
+    ///
+    /// The serial number reflects the last state of the zwp_text_input_v3
+    /// object known to the compositor. The value of the serial argument must
+    /// be equal to the number of commit requests already issued on that object.
+    ///
+    /// When the client receives a done event with a serial different than the
+    /// number of past commit requests, it must proceed with evaluating and
+    /// applying the changes as normal, except it should not change the current
+    /// state of the zwp_text_input_v3 object. All pending state requests
+    /// (set_surrounding_text, set_content_type and set_cursor_rectangle) on
+    /// the zwp_text_input_v3 object should be sent and committed after
+    /// receiving a zwp_text_input_v3.done event with a matching serial.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    #[inline]
+    fn done(&self, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        let _ = serial;
+    }
+}
+
+impl ZwpTextInputV3EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpTextInputV3] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputV3EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTextInputV3EventHandlerWithData<T> {
+    /// enter event
+    ///
+    /// Notification that this seat's text-input focus is on a certain surface.
+    ///
+    /// If client has created multiple text input objects, compositor must send
+    /// this event to all of them.
+    ///
+    /// When the seat has the keyboard capability the text-input focus follows
+    /// the keyboard focus. This event sets the current surface for the
+    /// text-input object.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// leave event
+    ///
+    /// Notification that this seat's text-input focus is no longer on a
+    /// certain surface. The client should reset any preedit string previously
+    /// set.
+    ///
+    /// The leave notification clears the current surface. It is sent before
+    /// the enter notification for the new focus. After leave event, compositor
+    /// must ignore requests from any text input instances until next enter
+    /// event.
+    ///
+    /// When the seat has the keyboard capability the text-input focus follows
+    /// the keyboard focus.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// pre-edit
+    ///
+    /// Notify when a new composing text (pre-edit) should be set at the
+    /// current cursor position. Any previously set composing text must be
+    /// removed. Any previously existing selected text must be removed.
+    ///
+    /// The argument text contains the pre-edit string buffer.
+    ///
+    /// The parameters cursor_begin and cursor_end are counted in bytes
+    /// relative to the beginning of the submitted text buffer. Cursor should
+    /// be hidden when both are equal to -1.
+    ///
+    /// They could be represented by the client as a line if both values are
+    /// the same, or as a text highlight otherwise.
+    ///
+    /// Values set with this event are double-buffered. They must be applied
+    /// and reset to initial on the next zwp_text_input_v3.done event.
+    ///
+    /// The initial value of text is an empty string, and cursor_begin,
+    /// cursor_end and cursor_hidden are all 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    /// - `cursor_begin`:
+    /// - `cursor_end`:
+    #[inline]
+    fn preedit_string(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        let _ = text;
+        let _ = cursor_begin;
+        let _ = cursor_end;
+    }
+
+    /// text commit
+    ///
+    /// Notify when text should be inserted into the editor widget. The text to
+    /// commit could be either just a single character after a key press or the
+    /// result of some composing (pre-edit).
+    ///
+    /// Values set with this event are double-buffered. They must be applied
+    /// and reset to initial on the next zwp_text_input_v3.done event.
+    ///
+    /// The initial value of text is an empty string.
+    ///
+    /// # Arguments
+    ///
+    /// - `text`:
+    #[inline]
+    fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        let _ = text;
+    }
+
+    /// delete surrounding text
+    ///
+    /// Notify when the text around the current cursor position should be
+    /// deleted.
+    ///
+    /// Before_length and after_length are the number of bytes before and after
+    /// the current cursor index (excluding the selection) to delete.
+    ///
+    /// If a preedit text is present, in effect before_length is counted from
+    /// the beginning of it, and after_length from its end (see done event
+    /// sequence).
+    ///
+    /// Values set with this event are double-buffered. They must be applied
+    /// and reset to initial on the next zwp_text_input_v3.done event.
+    ///
+    /// The initial values of both before_length and after_length are 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `before_length`: length of text before current cursor position
+    /// - `after_length`: length of text after current cursor position
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        let _ = before_length;
+        let _ = after_length;
+    }
+
+    /// apply changes
+    ///
+    /// Instruct the application to apply changes to state requested by the
+    /// preedit_string, commit_string and delete_surrounding_text events. The
+    /// state relating to these events is double-buffered, and each one
+    /// modifies the pending state. This event replaces the current state with
+    /// the pending state.
+    ///
+    /// The application must proceed by evaluating the changes in the following
+    /// order:
+    ///
+    /// 1. Replace existing preedit string with the cursor.
+    /// 2. Delete requested surrounding text.
+    /// 3. Insert commit string with the cursor at its end.
+    /// 4. Calculate surrounding text to send.
+    /// 5. Insert new preedit text in cursor position.
+    /// 6. Place cursor inside preedit text.
+    ///
+    /// The serial number reflects the last state of the zwp_text_input_v3
+    /// object known to the compositor. The value of the serial argument must
+    /// be equal to the number of commit requests already issued on that object.
+    ///
+    /// When the client receives a done event with a serial different than the
+    /// number of past commit requests, it must proceed with evaluating and
+    /// applying the changes as normal, except it should not change the current
+    /// state of the zwp_text_input_v3 object. All pending state requests
+    /// (set_surrounding_text, set_content_type and set_cursor_rectangle) on
+    /// the zwp_text_input_v3 object should be sent and committed after
+    /// receiving a zwp_text_input_v3.done event with a matching serial.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        let _ = serial;
+    }
+}
+
+/// A mutable event handler for [ZwpTextInputV3] proxies.
+///
+/// This trait is the same as [ZwpTextInputV3EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputV3EventHandlerMut {
+    /// See [ZwpTextInputV3EventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::preedit_string].
+    #[inline]
+    fn preedit_string(
+        &mut self,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        let _ = text;
+        let _ = cursor_begin;
+        let _ = cursor_end;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::commit_string].
+    #[inline]
+    fn commit_string(&mut self, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        let _ = text;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::delete_surrounding_text].
+    #[inline]
+    fn delete_surrounding_text(
+        &mut self,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        let _ = before_length;
+        let _ = after_length;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        let _ = serial;
+    }
+}
+
+/// A mutable event handler for [ZwpTextInputV3] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTextInputV3EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTextInputV3EventHandlerMutWithData<T> {
+    /// See [ZwpTextInputV3EventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _ = surface;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::preedit_string].
+    #[inline]
+    fn preedit_string(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        let _ = text;
+        let _ = cursor_begin;
+        let _ = cursor_end;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::commit_string].
+    #[inline]
+    fn commit_string(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        let _ = text;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::delete_surrounding_text].
+    #[inline]
+    fn delete_surrounding_text(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        let _ = before_length;
+        let _ = after_length;
+    }
+
+    /// See [ZwpTextInputV3EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        let _ = serial;
+    }
+}
+
+impl<H> ZwpTextInputV3EventHandler for MutEventHandler<H>
+where
+    H: ZwpTextInputV3EventHandlerMut,
+{
+    #[inline]
+    fn enter(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.enter(_slf, surface))
+    }
+
+    #[inline]
+    fn leave(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.leave(_slf, surface))
+    }
+
+    #[inline]
+    fn preedit_string(
+        &self,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        self.with(|h| h.preedit_string(_slf, text, cursor_begin, cursor_end))
+    }
+
+    #[inline]
+    fn commit_string(&self, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        self.with(|h| h.commit_string(_slf, text))
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        self.with(|h| h.delete_surrounding_text(_slf, before_length, after_length))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        self.with(|h| h.done(_slf, serial))
+    }
+}
+
+impl<T, H> ZwpTextInputV3EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTextInputV3EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.enter(_data, _slf, surface))
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.leave(_data, _slf, surface))
+    }
+
+    #[inline]
+    fn preedit_string(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        self.with(|h| h.preedit_string(_data, _slf, text, cursor_begin, cursor_end))
+    }
+
+    #[inline]
+    fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        self.with(|h| h.commit_string(_data, _slf, text))
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        self.with(|h| h.delete_surrounding_text(_data, _slf, before_length, after_length))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        self.with(|h| h.done(_data, _slf, serial))
+    }
+}
+
+impl<T, U, H> ZwpTextInputV3EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTextInputV3EventHandlerWithData<U>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _data = self.project(_data);
+        self.handler().enter(_data, _slf, surface)
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _data = self.project(_data);
+        self.handler().leave(_data, _slf, surface)
+    }
+
+    #[inline]
+    fn preedit_string(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .preedit_string(_data, _slf, text, cursor_begin, cursor_end)
+    }
+
+    #[inline]
+    fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        let _data = self.project(_data);
+        self.handler().commit_string(_data, _slf, text)
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .delete_surrounding_text(_data, _slf, before_length, after_length)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf, serial)
+    }
+}
+
+impl<T, U, H> ZwpTextInputV3EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTextInputV3EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _data = self.project(_data);
+        self.handler_mut().enter(_data, _slf, surface)
+    }
+
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+        let _data = self.project(_data);
+        self.handler_mut().leave(_data, _slf, surface)
+    }
+
+    #[inline]
+    fn preedit_string(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        text: Option<&str>,
+        cursor_begin: i32,
+        cursor_end: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .preedit_string(_data, _slf, text, cursor_begin, cursor_end)
+    }
+
+    #[inline]
+    fn commit_string(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+        let _data = self.project(_data);
+        self.handler_mut().commit_string(_data, _slf, text)
+    }
+
+    #[inline]
+    fn delete_surrounding_text(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTextInputV3Ref,
+        before_length: u32,
+        after_length: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .delete_surrounding_text(_data, _slf, before_length, after_length)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf, serial)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTextInputV3EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTextInputV3Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg0)
+                });
+                self.0.enter(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg0)
+                });
+                self.0.leave(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_optional_string_arg("zwp_text_input_v3", "text", args[0].s) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.preedit_string(slf, arg0, arg1, arg2);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_optional_string_arg("zwp_text_input_v3", "text", args[0].s) };
+                self.0.commit_string(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.delete_surrounding_text(slf, arg0, arg1);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.done(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_text_input_v3", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTextInputV3EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTextInputV3Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg0)
+                });
+                self.0.enter(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg0)
+                });
+                self.0.leave(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_optional_string_arg("zwp_text_input_v3", "text", args[0].s) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.preedit_string(data, slf, arg0, arg1, arg2);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_optional_string_arg("zwp_text_input_v3", "text", args[0].s) };
+                self.0.commit_string(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.delete_surrounding_text(data, slf, arg0, arg1);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.done(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_text_input_v3", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTextInputV3EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTextInputV3EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpTextInputV3 {
+    /// Since when the change_cause.input_method enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHANGE_CAUSE_INPUT_METHOD__SINCE: u32 = 1;
+    /// Since when the change_cause.other enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CHANGE_CAUSE_OTHER__SINCE: u32 = 1;
+
+    /// Since when the content_hint.none enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_NONE__SINCE: u32 = 1;
+    /// Since when the content_hint.completion enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_COMPLETION__SINCE: u32 = 1;
+    /// Since when the content_hint.spellcheck enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_SPELLCHECK__SINCE: u32 = 1;
+    /// Since when the content_hint.auto_capitalization enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_AUTO_CAPITALIZATION__SINCE: u32 = 1;
+    /// Since when the content_hint.lowercase enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_LOWERCASE__SINCE: u32 = 1;
+    /// Since when the content_hint.uppercase enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_UPPERCASE__SINCE: u32 = 1;
+    /// Since when the content_hint.titlecase enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_TITLECASE__SINCE: u32 = 1;
+    /// Since when the content_hint.hidden_text enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_HIDDEN_TEXT__SINCE: u32 = 1;
+    /// Since when the content_hint.sensitive_data enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_SENSITIVE_DATA__SINCE: u32 = 1;
+    /// Since when the content_hint.latin enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_LATIN__SINCE: u32 = 1;
+    /// Since when the content_hint.multiline enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_HINT_MULTILINE__SINCE: u32 = 1;
+
+    /// Since when the content_purpose.normal enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_NORMAL__SINCE: u32 = 1;
+    /// Since when the content_purpose.alpha enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_ALPHA__SINCE: u32 = 1;
+    /// Since when the content_purpose.digits enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_DIGITS__SINCE: u32 = 1;
+    /// Since when the content_purpose.number enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_NUMBER__SINCE: u32 = 1;
+    /// Since when the content_purpose.phone enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_PHONE__SINCE: u32 = 1;
+    /// Since when the content_purpose.url enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_URL__SINCE: u32 = 1;
+    /// Since when the content_purpose.email enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_EMAIL__SINCE: u32 = 1;
+    /// Since when the content_purpose.name enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_NAME__SINCE: u32 = 1;
+    /// Since when the content_purpose.password enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_PASSWORD__SINCE: u32 = 1;
+    /// Since when the content_purpose.pin enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_PIN__SINCE: u32 = 1;
+    /// Since when the content_purpose.date enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_DATE__SINCE: u32 = 1;
+    /// Since when the content_purpose.time enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_TIME__SINCE: u32 = 1;
+    /// Since when the content_purpose.datetime enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_DATETIME__SINCE: u32 = 1;
+    /// Since when the content_purpose.terminal enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CONTENT_PURPOSE_TERMINAL__SINCE: u32 = 1;
+}
+
+/// text change reason
+///
+/// Reason for the change of surrounding text or cursor posision.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV3ChangeCause(pub u32);
+
+impl ZwpTextInputV3ChangeCause {
+    /// input method caused the change
+    #[allow(dead_code)]
+    pub const INPUT_METHOD: Self = Self(0);
+
+    /// something else than the input method caused the change
+    #[allow(dead_code)]
+    pub const OTHER: Self = Self(1);
+}
+
+impl Debug for ZwpTextInputV3ChangeCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INPUT_METHOD => "INPUT_METHOD",
+            Self::OTHER => "OTHER",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// content hint
+///
+/// Content hint is a bitmask to allow to modify the behavior of the text
+/// input.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV3ContentHint(pub u32);
+
+/// An iterator over the set bits in a [ZwpTextInputV3ContentHint].
+///
+/// You can construct this with the `IntoIterator` implementation of `ZwpTextInputV3ContentHint`.
+#[derive(Clone, Debug)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV3ContentHintIter(pub u32);
+
+impl ZwpTextInputV3ContentHint {
+    /// no special behavior
+    #[allow(dead_code)]
+    pub const NONE: Self = Self(0x0);
+
+    /// suggest word completions
+    #[allow(dead_code)]
+    pub const COMPLETION: Self = Self(0x1);
+
+    /// suggest word corrections
+    #[allow(dead_code)]
+    pub const SPELLCHECK: Self = Self(0x2);
+
+    /// switch to uppercase letters at the start of a sentence
+    #[allow(dead_code)]
+    pub const AUTO_CAPITALIZATION: Self = Self(0x4);
+
+    /// prefer lowercase letters
+    #[allow(dead_code)]
+    pub const LOWERCASE: Self = Self(0x8);
+
+    /// prefer uppercase letters
+    #[allow(dead_code)]
+    pub const UPPERCASE: Self = Self(0x10);
+
+    /// prefer casing for titles and headings (can be language dependent)
+    #[allow(dead_code)]
+    pub const TITLECASE: Self = Self(0x20);
+
+    /// characters should be hidden
+    #[allow(dead_code)]
+    pub const HIDDEN_TEXT: Self = Self(0x40);
+
+    /// typed text should not be stored
+    #[allow(dead_code)]
+    pub const SENSITIVE_DATA: Self = Self(0x80);
+
+    /// just Latin characters should be entered
+    #[allow(dead_code)]
+    pub const LATIN: Self = Self(0x100);
+
+    /// the text input is multiline
+    #[allow(dead_code)]
+    pub const MULTILINE: Self = Self(0x200);
+}
+
+#[allow(dead_code)]
+impl ZwpTextInputV3ContentHint {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn insert(&mut self, other: Self) {
+        *self = self.union(other);
+    }
+
+    #[inline]
+    pub const fn remove(&mut self, other: Self) {
+        *self = self.difference(other);
+    }
+
+    #[inline]
+    pub const fn toggle(&mut self, other: Self) {
+        *self = self.symmetric_difference(other);
+    }
+
+    #[inline]
+    pub const fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn complement(self) -> Self {
+        Self(!self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    #[inline]
+    pub const fn all_known() -> Self {
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        Self(0 | 0x0 | 0x1 | 0x2 | 0x4 | 0x8 | 0x10 | 0x20 | 0x40 | 0x80 | 0x100 | 0x200)
+    }
+}
+
+impl Iterator for ZwpTextInputV3ContentHintIter {
+    type Item = ZwpTextInputV3ContentHint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = 1 << self.0.trailing_zeros();
+        self.0 &= !bit;
+        Some(ZwpTextInputV3ContentHint(bit))
+    }
+}
+
+impl IntoIterator for ZwpTextInputV3ContentHint {
+    type Item = ZwpTextInputV3ContentHint;
+    type IntoIter = ZwpTextInputV3ContentHintIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ZwpTextInputV3ContentHintIter(self.0)
+    }
+}
+
+impl BitAnd for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ZwpTextInputV3ContentHint {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl BitOr for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ZwpTextInputV3ContentHint {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitXor for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ZwpTextInputV3ContentHint {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Sub for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ZwpTextInputV3ContentHint {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl Not for ZwpTextInputV3ContentHint {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl Debug for ZwpTextInputV3ContentHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut v = self.0;
+        let mut first = true;
+        if v & 0x1 == 0x1 {
+            v &= !0x1;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("COMPLETION")?;
+        }
+        if v & 0x2 == 0x2 {
+            v &= !0x2;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("SPELLCHECK")?;
+        }
+        if v & 0x4 == 0x4 {
+            v &= !0x4;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("AUTO_CAPITALIZATION")?;
+        }
+        if v & 0x8 == 0x8 {
+            v &= !0x8;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("LOWERCASE")?;
+        }
+        if v & 0x10 == 0x10 {
+            v &= !0x10;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("UPPERCASE")?;
+        }
+        if v & 0x20 == 0x20 {
+            v &= !0x20;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("TITLECASE")?;
+        }
+        if v & 0x40 == 0x40 {
+            v &= !0x40;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("HIDDEN_TEXT")?;
+        }
+        if v & 0x80 == 0x80 {
+            v &= !0x80;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("SENSITIVE_DATA")?;
+        }
+        if v & 0x100 == 0x100 {
+            v &= !0x100;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("LATIN")?;
+        }
+        if v & 0x200 == 0x200 {
+            v &= !0x200;
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            f.write_str("MULTILINE")?;
+        }
+        if v != 0 {
+            if first {
+                first = false;
+            } else {
+                f.write_str(" | ")?;
+            }
+            write!(f, "0x{v:032x}")?;
+        }
+        if first {
+            f.write_str("NONE")?;
+        }
+        Ok(())
+    }
+}
+
+/// content purpose
+///
+/// The content purpose allows to specify the primary purpose of a text
+/// input.
+///
+/// This allows an input method to show special purpose input panels with
+/// extra characters or to disallow some characters.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTextInputV3ContentPurpose(pub u32);
+
+impl ZwpTextInputV3ContentPurpose {
+    /// default input, allowing all characters
+    #[allow(dead_code)]
+    pub const NORMAL: Self = Self(0);
+
+    /// allow only alphabetic characters
+    #[allow(dead_code)]
+    pub const ALPHA: Self = Self(1);
+
+    /// allow only digits
+    #[allow(dead_code)]
+    pub const DIGITS: Self = Self(2);
+
+    /// input a number (including decimal separator and sign)
+    #[allow(dead_code)]
+    pub const NUMBER: Self = Self(3);
+
+    /// input a phone number
+    #[allow(dead_code)]
+    pub const PHONE: Self = Self(4);
+
+    /// input an URL
+    #[allow(dead_code)]
+    pub const URL: Self = Self(5);
+
+    /// input an email address
+    #[allow(dead_code)]
+    pub const EMAIL: Self = Self(6);
+
+    /// input a name of a person
+    #[allow(dead_code)]
+    pub const NAME: Self = Self(7);
+
+    /// input a password (combine with sensitive_data hint)
+    #[allow(dead_code)]
+    pub const PASSWORD: Self = Self(8);
+
+    /// input is a numeric password (combine with sensitive_data hint)
+    #[allow(dead_code)]
+    pub const PIN: Self = Self(9);
+
+    /// input a date
+    #[allow(dead_code)]
+    pub const DATE: Self = Self(10);
+
+    /// input a time
+    #[allow(dead_code)]
+    pub const TIME: Self = Self(11);
+
+    /// input a date and time
+    #[allow(dead_code)]
+    pub const DATETIME: Self = Self(12);
+
+    /// input for a terminal
+    #[allow(dead_code)]
+    pub const TERMINAL: Self = Self(13);
+}
+
+impl Debug for ZwpTextInputV3ContentPurpose {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::NORMAL => "NORMAL",
+            Self::ALPHA => "ALPHA",
+            Self::DIGITS => "DIGITS",
+            Self::NUMBER => "NUMBER",
+            Self::PHONE => "PHONE",
+            Self::URL => "URL",
+            Self::EMAIL => "EMAIL",
+            Self::NAME => "NAME",
+            Self::PASSWORD => "PASSWORD",
+            Self::PIN => "PIN",
+            Self::DATE => "DATE",
+            Self::TIME => "TIME",
+            Self::DATETIME => "DATETIME",
+            Self::TERMINAL => "TERMINAL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for enter events.
+    pub struct Enter<F>(F);
+    impl<F> ZwpTextInputV3EventHandler for Enter<F>
+    where
+        F: Fn(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, surface)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerWithData<T> for Enter<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_data, _slf, surface)
+        }
+    }
+
+    /// Event handler for leave events.
+    pub struct Leave<F>(F);
+    impl<F> ZwpTextInputV3EventHandler for Leave<F>
+    where
+        F: Fn(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, surface)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerWithData<T> for Leave<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_data, _slf, surface)
+        }
+    }
+
+    /// Event handler for preedit_string events.
+    pub struct PreeditString<F>(F);
+    impl<F> ZwpTextInputV3EventHandler for PreeditString<F>
+    where
+        F: Fn(&ZwpTextInputV3Ref, Option<&str>, i32, i32),
+    {
+        #[inline]
+        fn preedit_string(
+            &self,
+            _slf: &ZwpTextInputV3Ref,
+            text: Option<&str>,
+            cursor_begin: i32,
+            cursor_end: i32,
+        ) {
+            self.0(_slf, text, cursor_begin, cursor_end)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerWithData<T> for PreeditString<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&str>, i32, i32),
+    {
+        #[inline]
+        fn preedit_string(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            text: Option<&str>,
+            cursor_begin: i32,
+            cursor_end: i32,
+        ) {
+            self.0(_data, _slf, text, cursor_begin, cursor_end)
+        }
+    }
+
+    /// Event handler for commit_string events.
+    pub struct CommitString<F>(F);
+    impl<F> ZwpTextInputV3EventHandler for CommitString<F>
+    where
+        F: Fn(&ZwpTextInputV3Ref, Option<&str>),
+    {
+        #[inline]
+        fn commit_string(&self, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+            self.0(_slf, text)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerWithData<T> for CommitString<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&str>),
+    {
+        #[inline]
+        fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+            self.0(_data, _slf, text)
+        }
+    }
+
+    /// Event handler for delete_surrounding_text events.
+    pub struct DeleteSurroundingText<F>(F);
+    impl<F> ZwpTextInputV3EventHandler for DeleteSurroundingText<F>
+    where
+        F: Fn(&ZwpTextInputV3Ref, u32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(
+            &self,
+            _slf: &ZwpTextInputV3Ref,
+            before_length: u32,
+            after_length: u32,
+        ) {
+            self.0(_slf, before_length, after_length)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerWithData<T> for DeleteSurroundingText<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV3Ref, u32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            before_length: u32,
+            after_length: u32,
+        ) {
+            self.0(_data, _slf, before_length, after_length)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ZwpTextInputV3EventHandler for Done<F>
+    where
+        F: Fn(&ZwpTextInputV3Ref, u32),
+    {
+        #[inline]
+        fn done(&self, _slf: &ZwpTextInputV3Ref, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ZwpTextInputV3Ref, u32),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Mutable event handler for enter events.
+    pub struct EnterMut<F>(F);
+    impl<F> ZwpTextInputV3EventHandlerMut for EnterMut<F>
+    where
+        F: FnMut(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(&mut self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, surface)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerMutWithData<T> for EnterMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn enter(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, surface)
+        }
+    }
+
+    /// Mutable event handler for leave events.
+    pub struct LeaveMut<F>(F);
+    impl<F> ZwpTextInputV3EventHandlerMut for LeaveMut<F>
+    where
+        F: FnMut(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(&mut self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, surface)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerMutWithData<T> for LeaveMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, surface)
+        }
+    }
+
+    /// Mutable event handler for preedit_string events.
+    pub struct PreeditStringMut<F>(F);
+    impl<F> ZwpTextInputV3EventHandlerMut for PreeditStringMut<F>
+    where
+        F: FnMut(&ZwpTextInputV3Ref, Option<&str>, i32, i32),
+    {
+        #[inline]
+        fn preedit_string(
+            &mut self,
+            _slf: &ZwpTextInputV3Ref,
+            text: Option<&str>,
+            cursor_begin: i32,
+            cursor_end: i32,
+        ) {
+            self.0(_slf, text, cursor_begin, cursor_end)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerMutWithData<T> for PreeditStringMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&str>, i32, i32),
+    {
+        #[inline]
+        fn preedit_string(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            text: Option<&str>,
+            cursor_begin: i32,
+            cursor_end: i32,
+        ) {
+            self.0(_data, _slf, text, cursor_begin, cursor_end)
+        }
+    }
+
+    /// Mutable event handler for commit_string events.
+    pub struct CommitStringMut<F>(F);
+    impl<F> ZwpTextInputV3EventHandlerMut for CommitStringMut<F>
+    where
+        F: FnMut(&ZwpTextInputV3Ref, Option<&str>),
+    {
+        #[inline]
+        fn commit_string(&mut self, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+            self.0(_slf, text)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerMutWithData<T> for CommitStringMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&str>),
+    {
+        #[inline]
+        fn commit_string(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+            self.0(_data, _slf, text)
+        }
+    }
+
+    /// Mutable event handler for delete_surrounding_text events.
+    pub struct DeleteSurroundingTextMut<F>(F);
+    impl<F> ZwpTextInputV3EventHandlerMut for DeleteSurroundingTextMut<F>
+    where
+        F: FnMut(&ZwpTextInputV3Ref, u32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(
+            &mut self,
+            _slf: &ZwpTextInputV3Ref,
+            before_length: u32,
+            after_length: u32,
+        ) {
+            self.0(_slf, before_length, after_length)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerMutWithData<T> for DeleteSurroundingTextMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV3Ref, u32, u32),
+    {
+        #[inline]
+        fn delete_surrounding_text(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            before_length: u32,
+            after_length: u32,
+        ) {
+            self.0(_data, _slf, before_length, after_length)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ZwpTextInputV3EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ZwpTextInputV3Ref, u32),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ZwpTextInputV3Ref, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> ZwpTextInputV3EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTextInputV3Ref, u32),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    impl ZwpTextInputV3 {
+        /// Creates an event handler for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(f: F) -> Enter<F>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(f: F) -> Enter<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(f: F) -> Leave<F>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(f: F) -> Leave<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler for preedit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string<F>(f: F) -> PreeditString<F>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&str>, i32, i32),
+        {
+            PreeditString(f)
+        }
+
+        /// Creates an event handler with mutable data for preedit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string_with_data<T, F>(f: F) -> PreeditString<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&str>, i32, i32),
+        {
+            PreeditString(f)
+        }
+
+        /// Creates an event handler for commit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_commit_string<F>(f: F) -> CommitString<F>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&str>),
+        {
+            CommitString(f)
+        }
+
+        /// Creates an event handler with mutable data for commit_string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_commit_string_with_data<T, F>(f: F) -> CommitString<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&str>),
+        {
+            CommitString(f)
+        }
+
+        /// Creates an event handler for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text<F>(f: F) -> DeleteSurroundingText<F>
+        where
+            F: Fn(&ZwpTextInputV3Ref, u32, u32),
+        {
+            DeleteSurroundingText(f)
+        }
+
+        /// Creates an event handler with mutable data for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_with_data<T, F>(f: F) -> DeleteSurroundingText<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, u32, u32),
+        {
+            DeleteSurroundingText(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ZwpTextInputV3Ref, u32),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, u32),
+        {
+            Done(f)
+        }
+
+        /// Creates a mutable event handler for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut<F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut_with_data<T, F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut<F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut_with_data<T, F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for preedit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_string_mut<F>(f: F) -> PreeditStringMut<F>
+        where
+            F: FnMut(&ZwpTextInputV3Ref, Option<&str>, i32, i32),
+        {
+            PreeditStringMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preedit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preedit_string_mut_with_data<T, F>(f: F) -> PreeditStringMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&str>, i32, i32),
+        {
+            PreeditStringMut(f)
+        }
+
+        /// Creates a mutable event handler for commit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_commit_string_mut<F>(f: F) -> CommitStringMut<F>
+        where
+            F: FnMut(&ZwpTextInputV3Ref, Option<&str>),
+        {
+            CommitStringMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for commit_string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_commit_string_mut_with_data<T, F>(f: F) -> CommitStringMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV3Ref, Option<&str>),
+        {
+            CommitStringMut(f)
+        }
+
+        /// Creates a mutable event handler for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_mut<F>(f: F) -> DeleteSurroundingTextMut<F>
+        where
+            F: FnMut(&ZwpTextInputV3Ref, u32, u32),
+        {
+            DeleteSurroundingTextMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for delete_surrounding_text events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_mut_with_data<T, F>(f: F) -> DeleteSurroundingTextMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV3Ref, u32, u32),
+        {
+            DeleteSurroundingTextMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ZwpTextInputV3Ref, u32),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTextInputV3Ref, u32),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                enter: Ignore,
+                leave: Ignore,
+                preedit_string: Ignore,
+                commit_string: Ignore,
+                delete_surrounding_text: Ignore,
+                done: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTextInputV3EventHandler for Ignore {}
+    impl<T> ZwpTextInputV3EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTextInputV3::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        enter: H0,
+        leave: H1,
+        preedit_string: H2,
+        commit_string: H3,
+        delete_surrounding_text: H4,
+        done: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> ZwpTextInputV3EventHandler for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ZwpTextInputV3EventHandler,
+        H1: ZwpTextInputV3EventHandler,
+        H2: ZwpTextInputV3EventHandler,
+        H3: ZwpTextInputV3EventHandler,
+        H4: ZwpTextInputV3EventHandler,
+        H5: ZwpTextInputV3EventHandler,
+    {
+        #[inline]
+        fn enter(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.enter.enter(_slf, surface)
+        }
+
+        #[inline]
+        fn leave(&self, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.leave.leave(_slf, surface)
+        }
+
+        #[inline]
+        fn preedit_string(
+            &self,
+            _slf: &ZwpTextInputV3Ref,
+            text: Option<&str>,
+            cursor_begin: i32,
+            cursor_end: i32,
+        ) {
+            self.preedit_string
+                .preedit_string(_slf, text, cursor_begin, cursor_end)
+        }
+
+        #[inline]
+        fn commit_string(&self, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+            self.commit_string.commit_string(_slf, text)
+        }
+
+        #[inline]
+        fn delete_surrounding_text(
+            &self,
+            _slf: &ZwpTextInputV3Ref,
+            before_length: u32,
+            after_length: u32,
+        ) {
+            self.delete_surrounding_text
+                .delete_surrounding_text(_slf, before_length, after_length)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ZwpTextInputV3Ref, serial: u32) {
+            self.done.done(_slf, serial)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> ZwpTextInputV3EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ZwpTextInputV3EventHandlerWithData<T>,
+        H1: ZwpTextInputV3EventHandlerWithData<T>,
+        H2: ZwpTextInputV3EventHandlerWithData<T>,
+        H3: ZwpTextInputV3EventHandlerWithData<T>,
+        H4: ZwpTextInputV3EventHandlerWithData<T>,
+        H5: ZwpTextInputV3EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.enter.enter(_data, _slf, surface)
+        }
+
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, surface: Option<&WlSurfaceRef>) {
+            self.leave.leave(_data, _slf, surface)
+        }
+
+        #[inline]
+        fn preedit_string(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            text: Option<&str>,
+            cursor_begin: i32,
+            cursor_end: i32,
+        ) {
+            self.preedit_string
+                .preedit_string(_data, _slf, text, cursor_begin, cursor_end)
+        }
+
+        #[inline]
+        fn commit_string(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, text: Option<&str>) {
+            self.commit_string.commit_string(_data, _slf, text)
+        }
+
+        #[inline]
+        fn delete_surrounding_text(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTextInputV3Ref,
+            before_length: u32,
+            after_length: u32,
+        ) {
+            self.delete_surrounding_text.delete_surrounding_text(
+                _data,
+                _slf,
+                before_length,
+                after_length,
+            )
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTextInputV3Ref, serial: u32) {
+            self.done.done(_data, _slf, serial)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for preedit_string events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string<F>(self, f: F) -> Handler<H0, H1, PreeditString<F>, H3, H4, H5>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&str>, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: PreeditString(f),
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preedit_string events.
+        #[allow(dead_code)]
+        pub fn on_preedit_string_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, PreeditString<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&str>, i32, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: PreeditString(f),
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for commit_string events.
+        #[allow(dead_code)]
+        pub fn on_commit_string<F>(self, f: F) -> Handler<H0, H1, H2, CommitString<F>, H4, H5>
+        where
+            F: Fn(&ZwpTextInputV3Ref, Option<&str>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: CommitString(f),
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for commit_string events.
+        #[allow(dead_code)]
+        pub fn on_commit_string_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, CommitString<F>, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, Option<&str>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: CommitString(f),
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for delete_surrounding_text events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, DeleteSurroundingText<F>, H5>
+        where
+            F: Fn(&ZwpTextInputV3Ref, u32, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: DeleteSurroundingText(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for delete_surrounding_text events.
+        #[allow(dead_code)]
+        pub fn on_delete_surrounding_text_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, DeleteSurroundingText<F>, H5>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, u32, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: DeleteSurroundingText(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Done<F>>
+        where
+            F: Fn(&ZwpTextInputV3Ref, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: Done(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Done<F>>
+        where
+            F: Fn(&mut T, &ZwpTextInputV3Ref, u32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preedit_string: self.preedit_string,
+                commit_string: self.commit_string,
+                delete_surrounding_text: self.delete_surrounding_text,
+                done: Done(f),
+            }
+        }
+    }
+}
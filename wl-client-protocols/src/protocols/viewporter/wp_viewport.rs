@@ -94,6 +94,113 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wp_viewport interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_viewport",
+    protocol: "viewporter",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_source",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_destination",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "bad_value",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "bad_size",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "out_of_buffer",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_surface",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wp_viewport proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -148,11 +255,16 @@ impl Deref for WpViewport {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -273,26 +385,84 @@ impl WpViewportRef {
 
 /// An event handler for [WpViewport] proxies.
 #[allow(dead_code)]
-pub trait WpViewportEventHandler {
-    type Data: 'static;
+pub trait WpViewportEventHandler {}
+
+impl WpViewportEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WpViewport] proxies with mutable data.
+///
+/// This trait is the same as [WpViewportEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpViewportEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpViewport] proxies.
+///
+/// This trait is the same as [WpViewportEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpViewportEventHandlerMut {}
+
+/// A mutable event handler for [WpViewport] proxies with mutable data.
+///
+/// This trait is the same as [WpViewportEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpViewportEventHandlerMutWithData<T> {}
+
+impl<H> WpViewportEventHandler for MutEventHandler<H> where H: WpViewportEventHandlerMut {}
+
+impl<T, H> WpViewportEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpViewportEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpViewportEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpViewportEventHandlerWithData<U>
+{
 }
 
-impl WpViewportEventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, U, H> WpViewportEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpViewportEventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WpViewportEventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_viewport", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpViewportEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -321,6 +491,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpViewportEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WpViewport {
     /// Since when the error.bad_value enum variant is available.
     #[allow(dead_code)]
@@ -0,0 +1,64 @@
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod wl_buffer;
+pub mod wl_callback;
+pub mod wl_compositor;
+pub mod wl_data_device;
+pub mod wl_data_device_manager;
+pub mod wl_data_offer;
+pub mod wl_data_source;
+pub mod wl_display;
+pub mod wl_fixes;
+pub mod wl_keyboard;
+pub mod wl_output;
+pub mod wl_pointer;
+pub mod wl_region;
+pub mod wl_registry;
+pub mod wl_seat;
+pub mod wl_shell;
+pub mod wl_shell_surface;
+pub mod wl_shm;
+pub mod wl_shm_pool;
+pub mod wl_subcompositor;
+pub mod wl_subsurface;
+pub mod wl_surface;
+pub mod wl_touch;
+
+/// Metadata about the wayland protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "wayland",
+    interfaces: &[
+        &wl_display::INFO,
+        &wl_registry::INFO,
+        &wl_callback::INFO,
+        &wl_compositor::INFO,
+        &wl_shm_pool::INFO,
+        &wl_shm::INFO,
+        &wl_buffer::INFO,
+        &wl_data_offer::INFO,
+        &wl_data_source::INFO,
+        &wl_data_device::INFO,
+        &wl_data_device_manager::INFO,
+        &wl_shell::INFO,
+        &wl_shell_surface::INFO,
+        &wl_surface::INFO,
+        &wl_seat::INFO,
+        &wl_pointer::INFO,
+        &wl_keyboard::INFO,
+        &wl_touch::INFO,
+        &wl_output::INFO,
+        &wl_region::INFO,
+        &wl_subcompositor::INFO,
+        &wl_subsurface::INFO,
+        &wl_fixes::INFO,
+    ],
+};
@@ -0,0 +1,568 @@
+//! content for a wl_surface
+//!
+//! A buffer provides the content for a wl_surface. Buffers are
+//! created through factory interfaces such as wl_shm, wp_linux_buffer_params
+//! (from the linux-dmabuf protocol extension) or similar. It has a width and
+//! a height and can be attached to a wl_surface, but the mechanism by which a
+//! client provides and updates the contents is defined by the buffer factory
+//! interface.
+//!
+//! Color channels are assumed to be electrical rather than optical (in other
+//! words, encoded with a transfer function) unless otherwise specified. If
+//! the buffer uses a format that has an alpha channel, the alpha channel is
+//! assumed to be premultiplied into the electrical color channel values
+//! (after transfer function encoding) unless otherwise specified.
+//!
+//! Note, because wl_buffer objects are created from multiple independent
+//! factory interfaces, the wl_buffer interface is frozen at version 1.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"wl_buffer".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"release".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the wl_buffer interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_buffer",
+    protocol: "wayland",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[info::MessageInfo {
+        name: "release",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[],
+    }],
+    enums: &[],
+};
+
+/// An owned wl_buffer proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WlBuffer {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed wl_buffer proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct WlBufferRef {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: WlBuffer is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for WlBuffer {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for WlBuffer {
+    const INTERFACE: &'static str = "wl_buffer";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = WlBufferRef;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: WlBufferRef is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for WlBufferRef {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for WlBufferRef {
+    type Owned = WlBuffer;
+}
+
+impl Deref for WlBuffer {
+    type Target = WlBufferRef;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for WlBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wl_buffer#{}", self.proxy.id())
+    }
+}
+
+impl Debug for WlBufferRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "wl_buffer#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<WlBufferRef> for WlBuffer {
+    fn eq(&self, other: &WlBufferRef) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<WlBuffer> for WlBufferRef {
+    fn eq(&self, other: &WlBuffer) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl WlBuffer {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy a buffer
+    ///
+    /// Destroy a buffer. If and how you need to release the backing
+    /// storage is defined by the buffer factory interface.
+    ///
+    /// For possible side-effects to a surface, see wl_surface.attach.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl WlBuffer {
+    /// Since when the release event is available.
+    #[allow(dead_code)]
+    pub const EVT__RELEASE__SINCE: u32 = 1;
+}
+
+/// An event handler for [WlBuffer] proxies.
+#[allow(dead_code)]
+pub trait WlBufferEventHandler {
+    /// compositor releases buffer
+    ///
+    /// Sent when this wl_buffer is no longer used by the compositor.
+    /// The client is now free to reuse or destroy this buffer and its
+    /// backing storage.
+    ///
+    /// If a client receives a release event before the frame callback
+    /// requested in the same wl_surface.commit that attaches this
+    /// wl_buffer to a surface, then the client is immediately free to
+    /// reuse the buffer and its backing storage, and does not need a
+    /// second buffer for the next surface content update. Typically
+    /// this is possible, when the compositor maintains a copy of the
+    /// wl_surface contents, e.g. as a GL texture. This is an important
+    /// optimization for GL(ES) compositors with wl_shm clients.
+    #[inline]
+    fn release(&self, _slf: &WlBufferRef) {}
+}
+
+impl WlBufferEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WlBuffer] proxies with mutable data.
+///
+/// This trait is the same as [WlBufferEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlBufferEventHandlerWithData<T> {
+    /// compositor releases buffer
+    ///
+    /// Sent when this wl_buffer is no longer used by the compositor.
+    /// The client is now free to reuse or destroy this buffer and its
+    /// backing storage.
+    ///
+    /// If a client receives a release event before the frame callback
+    /// requested in the same wl_surface.commit that attaches this
+    /// wl_buffer to a surface, then the client is immediately free to
+    /// reuse the buffer and its backing storage, and does not need a
+    /// second buffer for the next surface content update. Typically
+    /// this is possible, when the compositor maintains a copy of the
+    /// wl_surface contents, e.g. as a GL texture. This is an important
+    /// optimization for GL(ES) compositors with wl_shm clients.
+    #[inline]
+    fn release(&self, _data: &mut T, _slf: &WlBufferRef) {}
+}
+
+/// A mutable event handler for [WlBuffer] proxies.
+///
+/// This trait is the same as [WlBufferEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlBufferEventHandlerMut {
+    /// See [WlBufferEventHandler::release].
+    #[inline]
+    fn release(&mut self, _slf: &WlBufferRef) {}
+}
+
+/// A mutable event handler for [WlBuffer] proxies with mutable data.
+///
+/// This trait is the same as [WlBufferEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlBufferEventHandlerMutWithData<T> {
+    /// See [WlBufferEventHandler::release].
+    #[inline]
+    fn release(&mut self, _data: &mut T, _slf: &WlBufferRef) {}
+}
+
+impl<H> WlBufferEventHandler for MutEventHandler<H>
+where
+    H: WlBufferEventHandlerMut,
+{
+    #[inline]
+    fn release(&self, _slf: &WlBufferRef) {
+        self.with(|h| h.release(_slf))
+    }
+}
+
+impl<T, H> WlBufferEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlBufferEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+        self.with(|h| h.release(_data, _slf))
+    }
+}
+
+impl<T, U, H> WlBufferEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlBufferEventHandlerWithData<U>,
+{
+    #[inline]
+    fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+        let _data = self.project(_data);
+        self.handler().release(_data, _slf)
+    }
+}
+
+impl<T, U, H> WlBufferEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlBufferEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn release(&mut self, _data: &mut T, _slf: &WlBufferRef) {
+        let _data = self.project(_data);
+        self.handler_mut().release(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WlBufferEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlBufferRef>(slf) };
+        match opcode {
+            0 => {
+                self.0.release(slf);
+            }
+            _ => {
+                invalid_opcode("wl_buffer", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlBufferEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlBufferRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.release(data, slf);
+            }
+            _ => {
+                invalid_opcode("wl_buffer", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WlBufferEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlBufferEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for release events.
+    pub struct Release<F>(F);
+    impl<F> WlBufferEventHandler for Release<F>
+    where
+        F: Fn(&WlBufferRef),
+    {
+        #[inline]
+        fn release(&self, _slf: &WlBufferRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlBufferEventHandlerWithData<T> for Release<F>
+    where
+        F: Fn(&mut T, &WlBufferRef),
+    {
+        #[inline]
+        fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for release events.
+    pub struct ReleaseMut<F>(F);
+    impl<F> WlBufferEventHandlerMut for ReleaseMut<F>
+    where
+        F: FnMut(&WlBufferRef),
+    {
+        #[inline]
+        fn release(&mut self, _slf: &WlBufferRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlBufferEventHandlerMutWithData<T> for ReleaseMut<F>
+    where
+        F: FnMut(&mut T, &WlBufferRef),
+    {
+        #[inline]
+        fn release(&mut self, _data: &mut T, _slf: &WlBufferRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl WlBuffer {
+        /// Creates an event handler for release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_release<F>(f: F) -> Release<F>
+        where
+            F: Fn(&WlBufferRef),
+        {
+            Release(f)
+        }
+
+        /// Creates an event handler with mutable data for release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_release_with_data<T, F>(f: F) -> Release<F>
+        where
+            F: Fn(&mut T, &WlBufferRef),
+        {
+            Release(f)
+        }
+
+        /// Creates a mutable event handler for release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_release_mut<F>(f: F) -> ReleaseMut<F>
+        where
+            F: FnMut(&WlBufferRef),
+        {
+            ReleaseMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_release_mut_with_data<T, F>(f: F) -> ReleaseMut<F>
+        where
+            F: FnMut(&mut T, &WlBufferRef),
+        {
+            ReleaseMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { release: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlBufferEventHandler for Ignore {}
+    impl<T> WlBufferEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlBuffer::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        release: H0,
+    }
+
+    impl<H0> WlBufferEventHandler for Handler<H0>
+    where
+        H0: WlBufferEventHandler,
+    {
+        #[inline]
+        fn release(&self, _slf: &WlBufferRef) {
+            self.release.release(_slf)
+        }
+    }
+
+    impl<T, H0> WlBufferEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WlBufferEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+            self.release.release(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for release events.
+        #[allow(dead_code)]
+        pub fn on_release<F>(self, f: F) -> Handler<Release<F>>
+        where
+            F: Fn(&WlBufferRef),
+        {
+            Handler {
+                release: Release(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for release events.
+        #[allow(dead_code)]
+        pub fn on_release_with_data<T, F>(self, f: F) -> Handler<Release<F>>
+        where
+            F: Fn(&mut T, &WlBufferRef),
+        {
+            Handler {
+                release: Release(f),
+            }
+        }
+    }
+}
@@ -37,6 +37,44 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_compositor interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_compositor",
+    protocol: "wayland",
+    version: 6,
+    requests: &[
+        info::MessageInfo {
+            name: "create_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "create_region",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_region"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
 /// An owned wl_compositor proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -91,11 +129,16 @@ impl Deref for WlCompositor {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -220,26 +263,84 @@ impl WlCompositorRef {
 
 /// An event handler for [WlCompositor] proxies.
 #[allow(dead_code)]
-pub trait WlCompositorEventHandler {
-    type Data: 'static;
+pub trait WlCompositorEventHandler {}
+
+impl WlCompositorEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WlCompositor] proxies with mutable data.
+///
+/// This trait is the same as [WlCompositorEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlCompositorEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlCompositor] proxies.
+///
+/// This trait is the same as [WlCompositorEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlCompositorEventHandlerMut {}
+
+/// A mutable event handler for [WlCompositor] proxies with mutable data.
+///
+/// This trait is the same as [WlCompositorEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlCompositorEventHandlerMutWithData<T> {}
+
+impl<H> WlCompositorEventHandler for MutEventHandler<H> where H: WlCompositorEventHandlerMut {}
+
+impl<T, H> WlCompositorEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlCompositorEventHandlerMutWithData<T>
+{
 }
 
-impl WlCompositorEventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, U, H> WlCompositorEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlCompositorEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WlCompositorEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlCompositorEventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WlCompositorEventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_compositor", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlCompositorEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -268,6 +369,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlCompositorEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
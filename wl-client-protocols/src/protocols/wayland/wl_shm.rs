@@ -52,6 +52,837 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_shm interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_shm",
+    protocol: "wayland",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "create_pool",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_shm_pool"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "size",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "release",
+            since: 2,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "format",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "format",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: Some("format"),
+        }],
+    }],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "invalid_format",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_stride",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_fd",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "format",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "argb8888",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb8888",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "c8",
+                    value: 538982467,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgb332",
+                    value: 943867730,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgr233",
+                    value: 944916290,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb4444",
+                    value: 842093144,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr4444",
+                    value: 842089048,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgbx4444",
+                    value: 842094674,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgrx4444",
+                    value: 842094658,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "argb4444",
+                    value: 842093121,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "abgr4444",
+                    value: 842089025,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgba4444",
+                    value: 842088786,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgra4444",
+                    value: 842088770,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb1555",
+                    value: 892424792,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr1555",
+                    value: 892420696,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgbx5551",
+                    value: 892426322,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgrx5551",
+                    value: 892426306,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "argb1555",
+                    value: 892424769,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "abgr1555",
+                    value: 892420673,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgba5551",
+                    value: 892420434,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgra5551",
+                    value: 892420418,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgb565",
+                    value: 909199186,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgr565",
+                    value: 909199170,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgb888",
+                    value: 875710290,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgr888",
+                    value: 875710274,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr8888",
+                    value: 875709016,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgbx8888",
+                    value: 875714642,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgrx8888",
+                    value: 875714626,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "abgr8888",
+                    value: 875708993,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgba8888",
+                    value: 875708754,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgra8888",
+                    value: 875708738,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb2101010",
+                    value: 808669784,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr2101010",
+                    value: 808665688,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgbx1010102",
+                    value: 808671314,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgrx1010102",
+                    value: 808671298,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "argb2101010",
+                    value: 808669761,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "abgr2101010",
+                    value: 808665665,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgba1010102",
+                    value: 808665426,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgra1010102",
+                    value: 808665410,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuyv",
+                    value: 1448695129,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yvyu",
+                    value: 1431918169,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "uyvy",
+                    value: 1498831189,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "vyuy",
+                    value: 1498765654,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "ayuv",
+                    value: 1448433985,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv12",
+                    value: 842094158,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv21",
+                    value: 825382478,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv16",
+                    value: 909203022,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv61",
+                    value: 825644622,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv410",
+                    value: 961959257,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yvu410",
+                    value: 961893977,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv411",
+                    value: 825316697,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yvu411",
+                    value: 825316953,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv420",
+                    value: 842093913,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yvu420",
+                    value: 842094169,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv422",
+                    value: 909202777,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yvu422",
+                    value: 909203033,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv444",
+                    value: 875713881,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yvu444",
+                    value: 875714137,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r8",
+                    value: 538982482,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r16",
+                    value: 540422482,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rg88",
+                    value: 943212370,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "gr88",
+                    value: 943215175,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rg1616",
+                    value: 842221394,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "gr1616",
+                    value: 842224199,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb16161616f",
+                    value: 1211388504,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr16161616f",
+                    value: 1211384408,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "argb16161616f",
+                    value: 1211388481,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "abgr16161616f",
+                    value: 1211384385,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xyuv8888",
+                    value: 1448434008,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "vuy888",
+                    value: 875713878,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "vuy101010",
+                    value: 808670550,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y210",
+                    value: 808530521,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y212",
+                    value: 842084953,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y216",
+                    value: 909193817,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y410",
+                    value: 808531033,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y412",
+                    value: 842085465,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y416",
+                    value: 909194329,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xvyu2101010",
+                    value: 808670808,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xvyu12_16161616",
+                    value: 909334104,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xvyu16161616",
+                    value: 942954072,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y0l0",
+                    value: 810299481,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "x0l0",
+                    value: 810299480,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "y0l2",
+                    value: 843853913,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "x0l2",
+                    value: 843853912,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv420_8bit",
+                    value: 942691673,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "yuv420_10bit",
+                    value: 808539481,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb8888_a8",
+                    value: 943805016,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr8888_a8",
+                    value: 943800920,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgbx8888_a8",
+                    value: 943806546,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgrx8888_a8",
+                    value: 943806530,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgb888_a8",
+                    value: 943798354,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgr888_a8",
+                    value: 943798338,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rgb565_a8",
+                    value: 943797586,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bgr565_a8",
+                    value: 943797570,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv24",
+                    value: 875714126,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv42",
+                    value: 842290766,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "p210",
+                    value: 808530512,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "p010",
+                    value: 808530000,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "p012",
+                    value: 842084432,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "p016",
+                    value: 909193296,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "axbxgxrx106106106106",
+                    value: 808534593,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "nv15",
+                    value: 892425806,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "q410",
+                    value: 808531025,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "q401",
+                    value: 825242705,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xrgb16161616",
+                    value: 942953048,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xbgr16161616",
+                    value: 942948952,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "argb16161616",
+                    value: 942953025,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "abgr16161616",
+                    value: 942948929,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "c1",
+                    value: 538980675,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "c2",
+                    value: 538980931,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "c4",
+                    value: 538981443,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "d1",
+                    value: 538980676,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "d2",
+                    value: 538980932,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "d4",
+                    value: 538981444,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "d8",
+                    value: 538982468,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r1",
+                    value: 538980690,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r2",
+                    value: 538980946,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r4",
+                    value: 538981458,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r10",
+                    value: 540029266,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "r12",
+                    value: 540160338,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "avuy8888",
+                    value: 1498764865,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "xvuy8888",
+                    value: 1498764888,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "p030",
+                    value: 808661072,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
 /// An owned wl_shm proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -106,11 +937,16 @@ impl Deref for WlShm {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -248,8 +1084,30 @@ impl WlShm {
 /// An event handler for [WlShm] proxies.
 #[allow(dead_code)]
 pub trait WlShmEventHandler {
-    type Data: 'static;
+    /// pixel format description
+    ///
+    /// Informs the client about a valid pixel format that
+    /// can be used for buffers. Known formats include
+    /// argb8888 and xrgb8888.
+    ///
+    /// # Arguments
+    ///
+    /// - `format`: buffer pixel format
+    #[inline]
+    fn format(&self, _slf: &WlShmRef, format: WlShmFormat) {
+        let _ = format;
+    }
+}
+
+impl WlShmEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlShm] proxies with mutable data.
+///
+/// This trait is the same as [WlShmEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlShmEventHandlerWithData<T> {
     /// pixel format description
     ///
     /// Informs the client about a valid pixel format that
@@ -260,27 +1118,127 @@ pub trait WlShmEventHandler {
     ///
     /// - `format`: buffer pixel format
     #[inline]
-    fn format(&self, _data: &mut Self::Data, _slf: &WlShmRef, format: WlShmFormat) {
+    fn format(&self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+        let _ = format;
+    }
+}
+
+/// A mutable event handler for [WlShm] proxies.
+///
+/// This trait is the same as [WlShmEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShmEventHandlerMut {
+    /// See [WlShmEventHandler::format].
+    #[inline]
+    fn format(&mut self, _slf: &WlShmRef, format: WlShmFormat) {
         let _ = format;
     }
 }
 
-impl WlShmEventHandler for private::NoOpEventHandler {
-    type Data = ();
+/// A mutable event handler for [WlShm] proxies with mutable data.
+///
+/// This trait is the same as [WlShmEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShmEventHandlerMutWithData<T> {
+    /// See [WlShmEventHandler::format].
+    #[inline]
+    fn format(&mut self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+        let _ = format;
+    }
+}
+
+impl<H> WlShmEventHandler for MutEventHandler<H>
+where
+    H: WlShmEventHandlerMut,
+{
+    #[inline]
+    fn format(&self, _slf: &WlShmRef, format: WlShmFormat) {
+        self.with(|h| h.format(_slf, format))
+    }
+}
+
+impl<T, H> WlShmEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlShmEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn format(&self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+        self.with(|h| h.format(_data, _slf, format))
+    }
+}
+
+impl<T, U, H> WlShmEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlShmEventHandlerWithData<U>,
+{
+    #[inline]
+    fn format(&self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+        let _data = self.project(_data);
+        self.handler().format(_data, _slf, format)
+    }
+}
+
+impl<T, U, H> WlShmEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlShmEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn format(&mut self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+        let _data = self.project(_data);
+        self.handler_mut().format(_data, _slf, format)
+    }
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WlShmEventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlShmRef>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlShmFormat(args[0].u) };
+                self.0.format(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_shm", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlShmEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -296,9 +1254,8 @@ where
         // SAFETY: This function requires that slf has the interface INTERFACE
         let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlShmRef>(slf) };
         // SAFETY: This function requires that data is `&mut T` where `T`
-        //         has the type id returned by `Self::mutable_type`, i.e.,
-        //         `T = H::Data`.
-        let data: &mut H::Data = unsafe { &mut *data.cast() };
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
         match opcode {
             0 => {
                 // SAFETY: INTERFACE requires that there are 1 arguments
@@ -326,6 +1283,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlShmEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlShm {
     /// Since when the error.invalid_format enum variant is available.
     #[allow(dead_code)]
@@ -1378,31 +2348,159 @@ pub mod event_handlers {
     use super::*;
 
     /// Event handler for format events.
-    pub struct Format<T, F>(F, PhantomData<fn(&mut T)>);
-    impl<T, F> WlShmEventHandler for Format<T, F>
+    pub struct Format<F>(F);
+    impl<F> WlShmEventHandler for Format<F>
+    where
+        F: Fn(&WlShmRef, WlShmFormat),
+    {
+        #[inline]
+        fn format(&self, _slf: &WlShmRef, format: WlShmFormat) {
+            self.0(_slf, format)
+        }
+    }
+    impl<T, F> WlShmEventHandlerWithData<T> for Format<F>
     where
-        T: 'static,
         F: Fn(&mut T, &WlShmRef, WlShmFormat),
     {
-        type Data = T;
-
         #[inline]
         fn format(&self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
             self.0(_data, _slf, format)
         }
     }
 
+    /// Mutable event handler for format events.
+    pub struct FormatMut<F>(F);
+    impl<F> WlShmEventHandlerMut for FormatMut<F>
+    where
+        F: FnMut(&WlShmRef, WlShmFormat),
+    {
+        #[inline]
+        fn format(&mut self, _slf: &WlShmRef, format: WlShmFormat) {
+            self.0(_slf, format)
+        }
+    }
+    impl<T, F> WlShmEventHandlerMutWithData<T> for FormatMut<F>
+    where
+        F: FnMut(&mut T, &WlShmRef, WlShmFormat),
+    {
+        #[inline]
+        fn format(&mut self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+            self.0(_data, _slf, format)
+        }
+    }
+
     impl WlShm {
         /// Creates an event handler for format events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_format<T, F>(f: F) -> Format<T, F>
+        pub fn on_format<F>(f: F) -> Format<F>
+        where
+            F: Fn(&WlShmRef, WlShmFormat),
+        {
+            Format(f)
+        }
+
+        /// Creates an event handler with mutable data for format events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_format_with_data<T, F>(f: F) -> Format<F>
+        where
+            F: Fn(&mut T, &WlShmRef, WlShmFormat),
+        {
+            Format(f)
+        }
+
+        /// Creates a mutable event handler for format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_format_mut<F>(f: F) -> FormatMut<F>
+        where
+            F: FnMut(&WlShmRef, WlShmFormat),
+        {
+            FormatMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for format events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_format_mut_with_data<T, F>(f: F) -> FormatMut<F>
+        where
+            F: FnMut(&mut T, &WlShmRef, WlShmFormat),
+        {
+            FormatMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { format: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlShmEventHandler for Ignore {}
+    impl<T> WlShmEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlShm::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        format: H0,
+    }
+
+    impl<H0> WlShmEventHandler for Handler<H0>
+    where
+        H0: WlShmEventHandler,
+    {
+        #[inline]
+        fn format(&self, _slf: &WlShmRef, format: WlShmFormat) {
+            self.format.format(_slf, format)
+        }
+    }
+
+    impl<T, H0> WlShmEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WlShmEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn format(&self, _data: &mut T, _slf: &WlShmRef, format: WlShmFormat) {
+            self.format.format(_data, _slf, format)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for format events.
+        #[allow(dead_code)]
+        pub fn on_format<F>(self, f: F) -> Handler<Format<F>>
+        where
+            F: Fn(&WlShmRef, WlShmFormat),
+        {
+            Handler { format: Format(f) }
+        }
+
+        /// Sets the closure that takes mutable data for format events.
+        #[allow(dead_code)]
+        pub fn on_format_with_data<T, F>(self, f: F) -> Handler<Format<F>>
         where
-            T: 'static,
             F: Fn(&mut T, &WlShmRef, WlShmFormat),
         {
-            Format(f, PhantomData)
+            Handler { format: Format(f) }
         }
     }
 }
@@ -48,6 +48,88 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_shm_pool interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_shm_pool",
+    protocol: "wayland",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "create_buffer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_buffer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "offset",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "stride",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "format",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("wl_shm.format"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "resize",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "size",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
 /// An owned wl_shm_pool proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -102,11 +184,16 @@ impl Deref for WlShmPool {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -301,26 +388,84 @@ impl WlShmPoolRef {
 
 /// An event handler for [WlShmPool] proxies.
 #[allow(dead_code)]
-pub trait WlShmPoolEventHandler {
-    type Data: 'static;
+pub trait WlShmPoolEventHandler {}
+
+impl WlShmPoolEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WlShmPool] proxies with mutable data.
+///
+/// This trait is the same as [WlShmPoolEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlShmPoolEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlShmPool] proxies.
+///
+/// This trait is the same as [WlShmPoolEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShmPoolEventHandlerMut {}
+
+/// A mutable event handler for [WlShmPool] proxies with mutable data.
+///
+/// This trait is the same as [WlShmPoolEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShmPoolEventHandlerMutWithData<T> {}
+
+impl<H> WlShmPoolEventHandler for MutEventHandler<H> where H: WlShmPoolEventHandlerMut {}
+
+impl<T, H> WlShmPoolEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlShmPoolEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WlShmPoolEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlShmPoolEventHandlerWithData<U>
+{
 }
 
-impl WlShmPoolEventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, U, H> WlShmPoolEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlShmPoolEventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WlShmPoolEventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_shm_pool", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlShmPoolEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -349,6 +494,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlShmPoolEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
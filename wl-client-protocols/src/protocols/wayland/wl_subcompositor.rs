@@ -55,6 +55,72 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_subcompositor interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_subcompositor",
+    protocol: "wayland",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_subsurface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_subsurface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "parent",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "bad_surface",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "bad_parent",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wl_subcompositor proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -109,11 +175,16 @@ impl Deref for WlSubcompositor {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -280,26 +351,84 @@ impl WlSubcompositorRef {
 
 /// An event handler for [WlSubcompositor] proxies.
 #[allow(dead_code)]
-pub trait WlSubcompositorEventHandler {
-    type Data: 'static;
+pub trait WlSubcompositorEventHandler {}
+
+impl WlSubcompositorEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WlSubcompositor] proxies with mutable data.
+///
+/// This trait is the same as [WlSubcompositorEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlSubcompositorEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlSubcompositor] proxies.
+///
+/// This trait is the same as [WlSubcompositorEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSubcompositorEventHandlerMut {}
+
+/// A mutable event handler for [WlSubcompositor] proxies with mutable data.
+///
+/// This trait is the same as [WlSubcompositorEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSubcompositorEventHandlerMutWithData<T> {}
+
+impl<H> WlSubcompositorEventHandler for MutEventHandler<H> where H: WlSubcompositorEventHandlerMut {}
+
+impl<T, H> WlSubcompositorEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlSubcompositorEventHandlerMutWithData<T>
+{
 }
 
-impl WlSubcompositorEventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, U, H> WlSubcompositorEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlSubcompositorEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WlSubcompositorEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlSubcompositorEventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WlSubcompositorEventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_subcompositor", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlSubcompositorEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -328,6 +457,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlSubcompositorEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlSubcompositor {
     /// Since when the error.bad_surface enum variant is available.
     #[allow(dead_code)]
@@ -118,6 +118,97 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_subsurface interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_subsurface",
+    protocol: "wayland",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_position",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "place_above",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "sibling",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "place_below",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "sibling",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_sync",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_desync",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "bad_surface",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
 /// An owned wl_subsurface proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -172,11 +263,16 @@ impl Deref for WlSubsurface {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -380,26 +476,84 @@ impl WlSubsurfaceRef {
 
 /// An event handler for [WlSubsurface] proxies.
 #[allow(dead_code)]
-pub trait WlSubsurfaceEventHandler {
-    type Data: 'static;
+pub trait WlSubsurfaceEventHandler {}
+
+impl WlSubsurfaceEventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [WlSubsurface] proxies with mutable data.
+///
+/// This trait is the same as [WlSubsurfaceEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlSubsurfaceEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlSubsurface] proxies.
+///
+/// This trait is the same as [WlSubsurfaceEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSubsurfaceEventHandlerMut {}
+
+/// A mutable event handler for [WlSubsurface] proxies with mutable data.
+///
+/// This trait is the same as [WlSubsurfaceEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSubsurfaceEventHandlerMutWithData<T> {}
+
+impl<H> WlSubsurfaceEventHandler for MutEventHandler<H> where H: WlSubsurfaceEventHandlerMut {}
+
+impl<T, H> WlSubsurfaceEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlSubsurfaceEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WlSubsurfaceEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlSubsurfaceEventHandlerWithData<U>
+{
 }
 
-impl WlSubsurfaceEventHandler for private::NoOpEventHandler {
-    type Data = ();
+impl<T, U, H> WlSubsurfaceEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlSubsurfaceEventHandlerMutWithData<U>
+{
 }
 
 // SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
     H: WlSubsurfaceEventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_subsurface", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlSubsurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
         Some((id, name))
     }
 
@@ -428,6 +582,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlSubsurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlSubsurface {
     /// Since when the error.bad_surface enum variant is available.
     #[allow(dead_code)]
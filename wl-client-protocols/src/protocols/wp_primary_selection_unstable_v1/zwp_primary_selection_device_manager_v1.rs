@@ -0,0 +1,479 @@
+//! X primary selection emulation
+//!
+//! The primary selection device manager is a singleton global object that
+//! provides access to the primary selection. It allows to create
+//! wp_primary_selection_source objects, as well as retrieving the per-seat
+//! wp_primary_selection_device objects.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_primary_selection_device_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"create_source".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpPrimarySelectionSourceV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_device".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpPrimarySelectionDeviceV1::WL_INTERFACE),
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_primary_selection_device_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_primary_selection_device_manager_v1",
+    protocol: "wp_primary_selection_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_source",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_primary_selection_source_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_device",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_primary_selection_device_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_primary_selection_device_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionDeviceManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_primary_selection_device_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionDeviceManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPrimarySelectionDeviceManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPrimarySelectionDeviceManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPrimarySelectionDeviceManagerV1 {
+    const INTERFACE: &'static str = "zwp_primary_selection_device_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpPrimarySelectionDeviceManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPrimarySelectionDeviceManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPrimarySelectionDeviceManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPrimarySelectionDeviceManagerV1Ref {
+    type Owned = ZwpPrimarySelectionDeviceManagerV1;
+}
+
+impl Deref for ZwpPrimarySelectionDeviceManagerV1 {
+    type Target = ZwpPrimarySelectionDeviceManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPrimarySelectionDeviceManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_primary_selection_device_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ZwpPrimarySelectionDeviceManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_primary_selection_device_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionDeviceManagerV1Ref> for ZwpPrimarySelectionDeviceManagerV1 {
+    fn eq(&self, other: &ZwpPrimarySelectionDeviceManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionDeviceManagerV1> for ZwpPrimarySelectionDeviceManagerV1Ref {
+    fn eq(&self, other: &ZwpPrimarySelectionDeviceManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionDeviceManagerV1 {
+    /// Since when the create_source request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_SOURCE__SINCE: u32 = 1;
+
+    /// create a new primary selection source
+    ///
+    /// Create a new primary selection source.
+    #[inline]
+    pub fn create_source(&self) -> ZwpPrimarySelectionSourceV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                0,
+                &mut args,
+                ZwpPrimarySelectionSourceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPrimarySelectionSourceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the get_device request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_DEVICE__SINCE: u32 = 1;
+
+    /// create a new primary selection device
+    ///
+    /// Create a new data device for a given seat.
+    ///
+    /// # Arguments
+    ///
+    /// - `seat`:
+    #[inline]
+    pub fn get_device(&self, seat: &WlSeatRef) -> ZwpPrimarySelectionDeviceV1 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpPrimarySelectionDeviceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPrimarySelectionDeviceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the primary selection device manager
+    ///
+    /// Destroy the primary selection device manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(2, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionDeviceManagerV1Ref {
+    /// create a new primary selection source
+    ///
+    /// Create a new primary selection source.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_source(&self, _queue: &Queue) -> ZwpPrimarySelectionSourceV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                0,
+                &mut args,
+                ZwpPrimarySelectionSourceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPrimarySelectionSourceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// create a new primary selection device
+    ///
+    /// Create a new data device for a given seat.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `seat`:
+    #[inline]
+    pub fn get_device(&self, _queue: &Queue, seat: &WlSeatRef) -> ZwpPrimarySelectionDeviceV1 {
+        let (arg1,) = (seat,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpPrimarySelectionDeviceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpPrimarySelectionDeviceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpPrimarySelectionDeviceManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceManagerV1EventHandler {}
+
+impl ZwpPrimarySelectionDeviceManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPrimarySelectionDeviceManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionDeviceManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpPrimarySelectionDeviceManagerV1] proxies.
+///
+/// This trait is the same as [ZwpPrimarySelectionDeviceManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpPrimarySelectionDeviceManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpPrimarySelectionDeviceManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> ZwpPrimarySelectionDeviceManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_primary_selection_device_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_primary_selection_device_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionDeviceManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpPrimarySelectionDeviceManagerV1 {}
+}
@@ -0,0 +1,1056 @@
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_primary_selection_device_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"set_selection".as_ptr(),
+                signature: c"?ou".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(ZwpPrimarySelectionSourceV1::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"data_offer".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpPrimarySelectionOfferV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"selection".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpPrimarySelectionOfferV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_primary_selection_device_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_primary_selection_device_v1",
+    protocol: "wp_primary_selection_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_selection",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "source",
+                    ty: info::ArgType::Object,
+                    interface: Some("zwp_primary_selection_source_v1"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "data_offer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "offer",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_primary_selection_offer_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "selection",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::Object,
+                interface: Some("zwp_primary_selection_offer_v1"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_primary_selection_device_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionDeviceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_primary_selection_device_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionDeviceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPrimarySelectionDeviceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPrimarySelectionDeviceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPrimarySelectionDeviceV1 {
+    const INTERFACE: &'static str = "zwp_primary_selection_device_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpPrimarySelectionDeviceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPrimarySelectionDeviceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPrimarySelectionDeviceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPrimarySelectionDeviceV1Ref {
+    type Owned = ZwpPrimarySelectionDeviceV1;
+}
+
+impl Deref for ZwpPrimarySelectionDeviceV1 {
+    type Target = ZwpPrimarySelectionDeviceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPrimarySelectionDeviceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_primary_selection_device_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPrimarySelectionDeviceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_primary_selection_device_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionDeviceV1Ref> for ZwpPrimarySelectionDeviceV1 {
+    fn eq(&self, other: &ZwpPrimarySelectionDeviceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionDeviceV1> for ZwpPrimarySelectionDeviceV1Ref {
+    fn eq(&self, other: &ZwpPrimarySelectionDeviceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionDeviceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the primary selection device
+    ///
+    /// Destroy the primary selection device.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionDeviceV1Ref {
+    /// set the primary selection
+    ///
+    /// Replaces the current selection. The previous owner of the primary
+    /// selection will receive a wp_primary_selection_source.cancelled event.
+    ///
+    /// To unset the selection, set the source to NULL.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`:
+    /// - `serial`: serial of the event that triggered this request
+    #[inline]
+    pub fn set_selection(&self, source: Option<&ZwpPrimarySelectionSourceV1Ref>, serial: u32) {
+        let (arg0, arg1) = (source, serial);
+        let obj0_lock = arg0.map(|arg0| proxy::lock(arg0));
+        let obj0 = obj0_lock
+            .map(|obj0_lock| check_argument_proxy("source", obj0_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `?ou`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+}
+
+impl ZwpPrimarySelectionDeviceV1 {
+    /// Since when the data_offer event is available.
+    #[allow(dead_code)]
+    pub const EVT__DATA_OFFER__SINCE: u32 = 1;
+
+    /// Since when the selection event is available.
+    #[allow(dead_code)]
+    pub const EVT__SELECTION__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpPrimarySelectionDeviceV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceV1EventHandler {
+    /// introduce a new wp_primary_selection_offer
+    ///
+    /// Introduces a new wp_primary_selection_offer object that may be used
+    /// to receive the current primary selection. Immediately following this
+    /// event, the new wp_primary_selection_offer object will send
+    /// wp_primary_selection_offer.offer events to describe the offered mime
+    /// types.
+    ///
+    /// # Arguments
+    ///
+    /// - `offer`:
+    #[inline]
+    fn data_offer(&self, _slf: &ZwpPrimarySelectionDeviceV1Ref, offer: ZwpPrimarySelectionOfferV1) {
+        let _ = offer;
+    }
+
+    /// advertise a new primary selection
+    ///
+    /// The wp_primary_selection_device.selection event is sent to notify the
+    /// client of a new primary selection. This event is sent after the
+    /// wp_primary_selection.data_offer event introducing this object, and after
+    /// the offer has announced its mimetypes through
+    /// wp_primary_selection_offer.offer.
+    ///
+    /// The data_offer is valid until a new offer or NULL is received
+    /// or until the client loses keyboard focus. The client must destroy the
+    /// previous selection data_offer, if any, upon receiving this event.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn selection(
+        &self,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+impl ZwpPrimarySelectionDeviceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPrimarySelectionDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionDeviceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> {
+    /// introduce a new wp_primary_selection_offer
+    ///
+    /// Introduces a new wp_primary_selection_offer object that may be used
+    /// to receive the current primary selection. Immediately following this
+    /// event, the new wp_primary_selection_offer object will send
+    /// wp_primary_selection_offer.offer events to describe the offered mime
+    /// types.
+    ///
+    /// # Arguments
+    ///
+    /// - `offer`:
+    #[inline]
+    fn data_offer(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        offer: ZwpPrimarySelectionOfferV1,
+    ) {
+        let _ = offer;
+    }
+
+    /// advertise a new primary selection
+    ///
+    /// The wp_primary_selection_device.selection event is sent to notify the
+    /// client of a new primary selection. This event is sent after the
+    /// wp_primary_selection.data_offer event introducing this object, and after
+    /// the offer has announced its mimetypes through
+    /// wp_primary_selection_offer.offer.
+    ///
+    /// The data_offer is valid until a new offer or NULL is received
+    /// or until the client loses keyboard focus. The client must destroy the
+    /// previous selection data_offer, if any, upon receiving this event.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn selection(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ZwpPrimarySelectionDeviceV1] proxies.
+///
+/// This trait is the same as [ZwpPrimarySelectionDeviceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceV1EventHandlerMut {
+    /// See [ZwpPrimarySelectionDeviceV1EventHandler::data_offer].
+    #[inline]
+    fn data_offer(
+        &mut self,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        offer: ZwpPrimarySelectionOfferV1,
+    ) {
+        let _ = offer;
+    }
+
+    /// See [ZwpPrimarySelectionDeviceV1EventHandler::selection].
+    #[inline]
+    fn selection(
+        &mut self,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ZwpPrimarySelectionDeviceV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionDeviceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionDeviceV1EventHandlerMutWithData<T> {
+    /// See [ZwpPrimarySelectionDeviceV1EventHandler::data_offer].
+    #[inline]
+    fn data_offer(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        offer: ZwpPrimarySelectionOfferV1,
+    ) {
+        let _ = offer;
+    }
+
+    /// See [ZwpPrimarySelectionDeviceV1EventHandler::selection].
+    #[inline]
+    fn selection(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        let _ = id;
+    }
+}
+
+impl<H> ZwpPrimarySelectionDeviceV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandlerMut,
+{
+    #[inline]
+    fn data_offer(&self, _slf: &ZwpPrimarySelectionDeviceV1Ref, offer: ZwpPrimarySelectionOfferV1) {
+        self.with(|h| h.data_offer(_slf, offer))
+    }
+
+    #[inline]
+    fn selection(
+        &self,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        self.with(|h| h.selection(_slf, id))
+    }
+}
+
+impl<T, H> ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn data_offer(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        offer: ZwpPrimarySelectionOfferV1,
+    ) {
+        self.with(|h| h.data_offer(_data, _slf, offer))
+    }
+
+    #[inline]
+    fn selection(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        self.with(|h| h.selection(_data, _slf, id))
+    }
+}
+
+impl<T, U, H> ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn data_offer(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        offer: ZwpPrimarySelectionOfferV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler().data_offer(_data, _slf, offer)
+    }
+
+    #[inline]
+    fn selection(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().selection(_data, _slf, id)
+    }
+}
+
+impl<T, U, H> ZwpPrimarySelectionDeviceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn data_offer(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        offer: ZwpPrimarySelectionOfferV1,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().data_offer(_data, _slf, offer)
+    }
+
+    #[inline]
+    fn selection(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionDeviceV1Ref,
+        id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().selection(_data, _slf, id)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionDeviceV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpPrimarySelectionOfferV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpPrimarySelectionOfferV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpPrimarySelectionOfferV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ZwpPrimarySelectionOfferV1>(arg0)
+                };
+                self.0.data_offer(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpPrimarySelectionOfferV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionOfferV1Ref>(arg0)
+                });
+                self.0.selection(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_primary_selection_device_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionDeviceV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpPrimarySelectionOfferV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpPrimarySelectionOfferV1::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpPrimarySelectionOfferV1::WL_INTERFACE
+                let arg0 = unsafe {
+                    proxy::low_level::from_untyped_owned::<ZwpPrimarySelectionOfferV1>(arg0)
+                };
+                self.0.data_offer(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpPrimarySelectionOfferV1::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionOfferV1Ref>(arg0)
+                });
+                self.0.selection(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_primary_selection_device_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionDeviceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for data_offer events.
+    pub struct DataOffer<F>(F);
+    impl<F> ZwpPrimarySelectionDeviceV1EventHandler for DataOffer<F>
+    where
+        F: Fn(&ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+    {
+        #[inline]
+        fn data_offer(
+            &self,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            offer: ZwpPrimarySelectionOfferV1,
+        ) {
+            self.0(_slf, offer)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> for DataOffer<F>
+    where
+        F: Fn(&mut T, &ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+    {
+        #[inline]
+        fn data_offer(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            offer: ZwpPrimarySelectionOfferV1,
+        ) {
+            self.0(_data, _slf, offer)
+        }
+    }
+
+    /// Event handler for selection events.
+    pub struct Selection<F>(F);
+    impl<F> ZwpPrimarySelectionDeviceV1EventHandler for Selection<F>
+    where
+        F: Fn(&ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &self,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+        ) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> for Selection<F>
+    where
+        F: Fn(&mut T, &ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for data_offer events.
+    pub struct DataOfferMut<F>(F);
+    impl<F> ZwpPrimarySelectionDeviceV1EventHandlerMut for DataOfferMut<F>
+    where
+        F: FnMut(&ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+    {
+        #[inline]
+        fn data_offer(
+            &mut self,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            offer: ZwpPrimarySelectionOfferV1,
+        ) {
+            self.0(_slf, offer)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionDeviceV1EventHandlerMutWithData<T> for DataOfferMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+    {
+        #[inline]
+        fn data_offer(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            offer: ZwpPrimarySelectionOfferV1,
+        ) {
+            self.0(_data, _slf, offer)
+        }
+    }
+
+    /// Mutable event handler for selection events.
+    pub struct SelectionMut<F>(F);
+    impl<F> ZwpPrimarySelectionDeviceV1EventHandlerMut for SelectionMut<F>
+    where
+        F: FnMut(&ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &mut self,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+        ) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionDeviceV1EventHandlerMutWithData<T> for SelectionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+    {
+        #[inline]
+        fn selection(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+        ) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    impl ZwpPrimarySelectionDeviceV1 {
+        /// Creates an event handler for data_offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_data_offer<F>(f: F) -> DataOffer<F>
+        where
+            F: Fn(&ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+        {
+            DataOffer(f)
+        }
+
+        /// Creates an event handler with mutable data for data_offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_data_offer_with_data<T, F>(f: F) -> DataOffer<F>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+        {
+            DataOffer(f)
+        }
+
+        /// Creates an event handler for selection events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_selection<F>(f: F) -> Selection<F>
+        where
+            F: Fn(&ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+        {
+            Selection(f)
+        }
+
+        /// Creates an event handler with mutable data for selection events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_selection_with_data<T, F>(f: F) -> Selection<F>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+        {
+            Selection(f)
+        }
+
+        /// Creates a mutable event handler for data_offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_data_offer_mut<F>(f: F) -> DataOfferMut<F>
+        where
+            F: FnMut(&ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+        {
+            DataOfferMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for data_offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_data_offer_mut_with_data<T, F>(f: F) -> DataOfferMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+        {
+            DataOfferMut(f)
+        }
+
+        /// Creates a mutable event handler for selection events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_selection_mut<F>(f: F) -> SelectionMut<F>
+        where
+            F: FnMut(&ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+        {
+            SelectionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for selection events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_selection_mut_with_data<T, F>(f: F) -> SelectionMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &ZwpPrimarySelectionDeviceV1Ref,
+                Option<&ZwpPrimarySelectionOfferV1Ref>,
+            ),
+        {
+            SelectionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                data_offer: Ignore,
+                selection: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpPrimarySelectionDeviceV1EventHandler for Ignore {}
+    impl<T> ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpPrimarySelectionDeviceV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        data_offer: H0,
+        selection: H1,
+    }
+
+    impl<H0, H1> ZwpPrimarySelectionDeviceV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpPrimarySelectionDeviceV1EventHandler,
+        H1: ZwpPrimarySelectionDeviceV1EventHandler,
+    {
+        #[inline]
+        fn data_offer(
+            &self,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            offer: ZwpPrimarySelectionOfferV1,
+        ) {
+            self.data_offer.data_offer(_slf, offer)
+        }
+
+        #[inline]
+        fn selection(
+            &self,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+        ) {
+            self.selection.selection(_slf, id)
+        }
+    }
+
+    impl<T, H0, H1> ZwpPrimarySelectionDeviceV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpPrimarySelectionDeviceV1EventHandlerWithData<T>,
+        H1: ZwpPrimarySelectionDeviceV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn data_offer(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            offer: ZwpPrimarySelectionOfferV1,
+        ) {
+            self.data_offer.data_offer(_data, _slf, offer)
+        }
+
+        #[inline]
+        fn selection(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionDeviceV1Ref,
+            id: Option<&ZwpPrimarySelectionOfferV1Ref>,
+        ) {
+            self.selection.selection(_data, _slf, id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for data_offer events.
+        #[allow(dead_code)]
+        pub fn on_data_offer<F>(self, f: F) -> Handler<DataOffer<F>, H1>
+        where
+            F: Fn(&ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+        {
+            Handler {
+                data_offer: DataOffer(f),
+                selection: self.selection,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for data_offer events.
+        #[allow(dead_code)]
+        pub fn on_data_offer_with_data<T, F>(self, f: F) -> Handler<DataOffer<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionDeviceV1Ref, ZwpPrimarySelectionOfferV1),
+        {
+            Handler {
+                data_offer: DataOffer(f),
+                selection: self.selection,
+            }
+        }
+
+        /// Sets the closure for selection events.
+        #[allow(dead_code)]
+        pub fn on_selection<F>(self, f: F) -> Handler<H0, Selection<F>>
+        where
+            F: Fn(&ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: Selection(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for selection events.
+        #[allow(dead_code)]
+        pub fn on_selection_with_data<T, F>(self, f: F) -> Handler<H0, Selection<F>>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionDeviceV1Ref, Option<&ZwpPrimarySelectionOfferV1Ref>),
+        {
+            Handler {
+                data_offer: self.data_offer,
+                selection: Selection(f),
+            }
+        }
+    }
+}
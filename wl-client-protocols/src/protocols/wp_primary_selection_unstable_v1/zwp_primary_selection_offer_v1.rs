@@ -0,0 +1,652 @@
+//! offer to transfer primary selection contents
+//!
+//! A wp_primary_selection_offer represents an offer to transfer the contents
+//! of the primary selection clipboard to the client. Similar to
+//! wl_data_offer, the offer also describes the mime types that the data can
+//! be converted to and provides the mechanisms for transferring the data
+//! directly to the client.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_primary_selection_offer_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"receive".as_ptr(),
+                signature: c"sh".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"offer".as_ptr(),
+            signature: c"s".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_primary_selection_offer_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_primary_selection_offer_v1",
+    protocol: "wp_primary_selection_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "receive",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "mime_type",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "offer",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "mime_type",
+            ty: info::ArgType::String,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[],
+};
+
+/// An owned zwp_primary_selection_offer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionOfferV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_primary_selection_offer_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionOfferV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPrimarySelectionOfferV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPrimarySelectionOfferV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPrimarySelectionOfferV1 {
+    const INTERFACE: &'static str = "zwp_primary_selection_offer_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpPrimarySelectionOfferV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPrimarySelectionOfferV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPrimarySelectionOfferV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPrimarySelectionOfferV1Ref {
+    type Owned = ZwpPrimarySelectionOfferV1;
+}
+
+impl Deref for ZwpPrimarySelectionOfferV1 {
+    type Target = ZwpPrimarySelectionOfferV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPrimarySelectionOfferV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_primary_selection_offer_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPrimarySelectionOfferV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_primary_selection_offer_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionOfferV1Ref> for ZwpPrimarySelectionOfferV1 {
+    fn eq(&self, other: &ZwpPrimarySelectionOfferV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionOfferV1> for ZwpPrimarySelectionOfferV1Ref {
+    fn eq(&self, other: &ZwpPrimarySelectionOfferV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionOfferV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the primary selection offer
+    ///
+    /// Destroy the primary selection offer.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionOfferV1Ref {
+    /// request that the data is transferred
+    ///
+    /// To transfer the contents of the primary selection clipboard, the client
+    /// issues this request and indicates the mime type that it wants to
+    /// receive. The transfer happens through the passed file descriptor
+    /// (typically created with the pipe system call). The source client writes
+    /// the data in the mime type representation requested and then closes the
+    /// file descriptor.
+    ///
+    /// The receiving client reads from the read end of the pipe until EOF and
+    /// closes its end, at which point the transfer is complete.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`:
+    /// - `fd`:
+    #[inline]
+    pub fn receive(&self, mime_type: &str, fd: BorrowedFd<'_>) {
+        let (arg0, arg1) = (mime_type, fd);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [
+                wl_argument { s: str0 },
+                wl_argument {
+                    h: arg1.as_raw_fd(),
+                },
+            ];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 0 < INTERFACE.method_count = 2
+            //         - the request signature is `sh`
+            unsafe {
+                self.proxy.send_request(0, &mut args);
+            }
+        })
+    }
+}
+
+impl ZwpPrimarySelectionOfferV1 {
+    /// Since when the offer event is available.
+    #[allow(dead_code)]
+    pub const EVT__OFFER__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpPrimarySelectionOfferV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionOfferV1EventHandler {
+    /// advertise offered mime type
+    ///
+    /// Sent immediately after creating announcing the
+    /// wp_primary_selection_offer through
+    /// wp_primary_selection_device.data_offer. One event is sent per offered
+    /// mime type.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`:
+    #[inline]
+    fn offer(&self, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        let _ = mime_type;
+    }
+}
+
+impl ZwpPrimarySelectionOfferV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPrimarySelectionOfferV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionOfferV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionOfferV1EventHandlerWithData<T> {
+    /// advertise offered mime type
+    ///
+    /// Sent immediately after creating announcing the
+    /// wp_primary_selection_offer through
+    /// wp_primary_selection_device.data_offer. One event is sent per offered
+    /// mime type.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`:
+    #[inline]
+    fn offer(&self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        let _ = mime_type;
+    }
+}
+
+/// A mutable event handler for [ZwpPrimarySelectionOfferV1] proxies.
+///
+/// This trait is the same as [ZwpPrimarySelectionOfferV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionOfferV1EventHandlerMut {
+    /// See [ZwpPrimarySelectionOfferV1EventHandler::offer].
+    #[inline]
+    fn offer(&mut self, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        let _ = mime_type;
+    }
+}
+
+/// A mutable event handler for [ZwpPrimarySelectionOfferV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionOfferV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionOfferV1EventHandlerMutWithData<T> {
+    /// See [ZwpPrimarySelectionOfferV1EventHandler::offer].
+    #[inline]
+    fn offer(&mut self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        let _ = mime_type;
+    }
+}
+
+impl<H> ZwpPrimarySelectionOfferV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpPrimarySelectionOfferV1EventHandlerMut,
+{
+    #[inline]
+    fn offer(&self, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        self.with(|h| h.offer(_slf, mime_type))
+    }
+}
+
+impl<T, H> ZwpPrimarySelectionOfferV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpPrimarySelectionOfferV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn offer(&self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        self.with(|h| h.offer(_data, _slf, mime_type))
+    }
+}
+
+impl<T, U, H> ZwpPrimarySelectionOfferV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionOfferV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn offer(&self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        let _data = self.project(_data);
+        self.handler().offer(_data, _slf, mime_type)
+    }
+}
+
+impl<T, U, H> ZwpPrimarySelectionOfferV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionOfferV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn offer(&mut self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().offer(_data, _slf, mime_type)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPrimarySelectionOfferV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionOfferV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("zwp_primary_selection_offer_v1", "mime_type", args[0].s)
+                };
+                self.0.offer(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_primary_selection_offer_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPrimarySelectionOfferV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionOfferV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("zwp_primary_selection_offer_v1", "mime_type", args[0].s)
+                };
+                self.0.offer(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_primary_selection_offer_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionOfferV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionOfferV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for offer events.
+    pub struct Offer<F>(F);
+    impl<F> ZwpPrimarySelectionOfferV1EventHandler for Offer<F>
+    where
+        F: Fn(&ZwpPrimarySelectionOfferV1Ref, &str),
+    {
+        #[inline]
+        fn offer(&self, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+            self.0(_slf, mime_type)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionOfferV1EventHandlerWithData<T> for Offer<F>
+    where
+        F: Fn(&mut T, &ZwpPrimarySelectionOfferV1Ref, &str),
+    {
+        #[inline]
+        fn offer(&self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+            self.0(_data, _slf, mime_type)
+        }
+    }
+
+    /// Mutable event handler for offer events.
+    pub struct OfferMut<F>(F);
+    impl<F> ZwpPrimarySelectionOfferV1EventHandlerMut for OfferMut<F>
+    where
+        F: FnMut(&ZwpPrimarySelectionOfferV1Ref, &str),
+    {
+        #[inline]
+        fn offer(&mut self, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+            self.0(_slf, mime_type)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionOfferV1EventHandlerMutWithData<T> for OfferMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPrimarySelectionOfferV1Ref, &str),
+    {
+        #[inline]
+        fn offer(&mut self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+            self.0(_data, _slf, mime_type)
+        }
+    }
+
+    impl ZwpPrimarySelectionOfferV1 {
+        /// Creates an event handler for offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_offer<F>(f: F) -> Offer<F>
+        where
+            F: Fn(&ZwpPrimarySelectionOfferV1Ref, &str),
+        {
+            Offer(f)
+        }
+
+        /// Creates an event handler with mutable data for offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_offer_with_data<T, F>(f: F) -> Offer<F>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionOfferV1Ref, &str),
+        {
+            Offer(f)
+        }
+
+        /// Creates a mutable event handler for offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_offer_mut<F>(f: F) -> OfferMut<F>
+        where
+            F: FnMut(&ZwpPrimarySelectionOfferV1Ref, &str),
+        {
+            OfferMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_offer_mut_with_data<T, F>(f: F) -> OfferMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPrimarySelectionOfferV1Ref, &str),
+        {
+            OfferMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { offer: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpPrimarySelectionOfferV1EventHandler for Ignore {}
+    impl<T> ZwpPrimarySelectionOfferV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpPrimarySelectionOfferV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        offer: H0,
+    }
+
+    impl<H0> ZwpPrimarySelectionOfferV1EventHandler for Handler<H0>
+    where
+        H0: ZwpPrimarySelectionOfferV1EventHandler,
+    {
+        #[inline]
+        fn offer(&self, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+            self.offer.offer(_slf, mime_type)
+        }
+    }
+
+    impl<T, H0> ZwpPrimarySelectionOfferV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZwpPrimarySelectionOfferV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn offer(&self, _data: &mut T, _slf: &ZwpPrimarySelectionOfferV1Ref, mime_type: &str) {
+            self.offer.offer(_data, _slf, mime_type)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for offer events.
+        #[allow(dead_code)]
+        pub fn on_offer<F>(self, f: F) -> Handler<Offer<F>>
+        where
+            F: Fn(&ZwpPrimarySelectionOfferV1Ref, &str),
+        {
+            Handler { offer: Offer(f) }
+        }
+
+        /// Sets the closure that takes mutable data for offer events.
+        #[allow(dead_code)]
+        pub fn on_offer_with_data<T, F>(self, f: F) -> Handler<Offer<F>>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionOfferV1Ref, &str),
+        {
+            Handler { offer: Offer(f) }
+        }
+    }
+}
@@ -0,0 +1,900 @@
+//! offer to replace the contents of the primary selection
+//!
+//! The source side of a wp_primary_selection_offer, it provides a way to
+//! describe the offered data and respond to requests to transfer the
+//! requested contents of the primary selection clipboard.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_primary_selection_source_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"offer".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"send".as_ptr(),
+                signature: c"sh".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"cancelled".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_primary_selection_source_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_primary_selection_source_v1",
+    protocol: "wp_primary_selection_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "offer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "mime_type",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "send",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "mime_type",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "cancelled",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_primary_selection_source_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionSourceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_primary_selection_source_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpPrimarySelectionSourceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpPrimarySelectionSourceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpPrimarySelectionSourceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpPrimarySelectionSourceV1 {
+    const INTERFACE: &'static str = "zwp_primary_selection_source_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpPrimarySelectionSourceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpPrimarySelectionSourceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpPrimarySelectionSourceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpPrimarySelectionSourceV1Ref {
+    type Owned = ZwpPrimarySelectionSourceV1;
+}
+
+impl Deref for ZwpPrimarySelectionSourceV1 {
+    type Target = ZwpPrimarySelectionSourceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpPrimarySelectionSourceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_primary_selection_source_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpPrimarySelectionSourceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_primary_selection_source_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionSourceV1Ref> for ZwpPrimarySelectionSourceV1 {
+    fn eq(&self, other: &ZwpPrimarySelectionSourceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpPrimarySelectionSourceV1> for ZwpPrimarySelectionSourceV1Ref {
+    fn eq(&self, other: &ZwpPrimarySelectionSourceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionSourceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the primary selection source
+    ///
+    /// Destroy the primary selection source.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpPrimarySelectionSourceV1Ref {
+    /// add an offered mime type
+    ///
+    /// This request adds a mime type to the set of mime types advertised to
+    /// targets. Can be called several times to offer multiple types.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`:
+    #[inline]
+    pub fn offer(&self, mime_type: &str) {
+        let (arg0,) = (mime_type,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 0 < INTERFACE.method_count = 2
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(0, &mut args);
+            }
+        })
+    }
+}
+
+impl ZwpPrimarySelectionSourceV1 {
+    /// Since when the send event is available.
+    #[allow(dead_code)]
+    pub const EVT__SEND__SINCE: u32 = 1;
+
+    /// Since when the cancelled event is available.
+    #[allow(dead_code)]
+    pub const EVT__CANCELLED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpPrimarySelectionSourceV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionSourceV1EventHandler {
+    /// send the primary selection contents
+    ///
+    /// Request for the current primary selection contents from the client.
+    /// Send the specified mime type over the passed file descriptor, then
+    /// close it.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`:
+    /// - `fd`:
+    #[inline]
+    fn send(&self, _slf: &ZwpPrimarySelectionSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// request for primary selection contents was canceled
+    ///
+    /// This primary selection source is no longer valid. The client should
+    /// clean up and destroy this primary selection source.
+    #[inline]
+    fn cancelled(&self, _slf: &ZwpPrimarySelectionSourceV1Ref) {}
+}
+
+impl ZwpPrimarySelectionSourceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpPrimarySelectionSourceV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionSourceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionSourceV1EventHandlerWithData<T> {
+    /// send the primary selection contents
+    ///
+    /// Request for the current primary selection contents from the client.
+    /// Send the specified mime type over the passed file descriptor, then
+    /// close it.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`:
+    /// - `fd`:
+    #[inline]
+    fn send(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// request for primary selection contents was canceled
+    ///
+    /// This primary selection source is no longer valid. The client should
+    /// clean up and destroy this primary selection source.
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpPrimarySelectionSourceV1] proxies.
+///
+/// This trait is the same as [ZwpPrimarySelectionSourceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionSourceV1EventHandlerMut {
+    /// See [ZwpPrimarySelectionSourceV1EventHandler::send].
+    #[inline]
+    fn send(&mut self, _slf: &ZwpPrimarySelectionSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// See [ZwpPrimarySelectionSourceV1EventHandler::cancelled].
+    #[inline]
+    fn cancelled(&mut self, _slf: &ZwpPrimarySelectionSourceV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpPrimarySelectionSourceV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpPrimarySelectionSourceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpPrimarySelectionSourceV1EventHandlerMutWithData<T> {
+    /// See [ZwpPrimarySelectionSourceV1EventHandler::send].
+    #[inline]
+    fn send(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// See [ZwpPrimarySelectionSourceV1EventHandler::cancelled].
+    #[inline]
+    fn cancelled(&mut self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {}
+}
+
+impl<H> ZwpPrimarySelectionSourceV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpPrimarySelectionSourceV1EventHandlerMut,
+{
+    #[inline]
+    fn send(&self, _slf: &ZwpPrimarySelectionSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+        self.with(|h| h.send(_slf, mime_type, fd))
+    }
+
+    #[inline]
+    fn cancelled(&self, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+        self.with(|h| h.cancelled(_slf))
+    }
+}
+
+impl<T, H> ZwpPrimarySelectionSourceV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpPrimarySelectionSourceV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn send(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        self.with(|h| h.send(_data, _slf, mime_type, fd))
+    }
+
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+        self.with(|h| h.cancelled(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpPrimarySelectionSourceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionSourceV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn send(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        let _data = self.project(_data);
+        self.handler().send(_data, _slf, mime_type, fd)
+    }
+
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+        let _data = self.project(_data);
+        self.handler().cancelled(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpPrimarySelectionSourceV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpPrimarySelectionSourceV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn send(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpPrimarySelectionSourceV1Ref,
+        mime_type: &str,
+        fd: OwnedFd,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().send(_data, _slf, mime_type, fd)
+    }
+
+    #[inline]
+    fn cancelled(&mut self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().cancelled(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpPrimarySelectionSourceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionSourceV1Ref>(slf)
+        };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("zwp_primary_selection_source_v1", "mime_type", args[0].s)
+                };
+                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
+                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
+                self.0.send(slf, arg0, arg1);
+            }
+            1 => {
+                self.0.cancelled(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_primary_selection_source_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpPrimarySelectionSourceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe {
+            proxy::low_level::from_untyped_borrowed::<ZwpPrimarySelectionSourceV1Ref>(slf)
+        };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_string_arg("zwp_primary_selection_source_v1", "mime_type", args[0].s)
+                };
+                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
+                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
+                self.0.send(data, slf, arg0, arg1);
+            }
+            1 => {
+                self.0.cancelled(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_primary_selection_source_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionSourceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpPrimarySelectionSourceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for send events.
+    pub struct Send<F>(F);
+    impl<F> ZwpPrimarySelectionSourceV1EventHandler for Send<F>
+    where
+        F: Fn(&ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&self, _slf: &ZwpPrimarySelectionSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+            self.0(_slf, mime_type, fd)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionSourceV1EventHandlerWithData<T> for Send<F>
+    where
+        F: Fn(&mut T, &ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionSourceV1Ref,
+            mime_type: &str,
+            fd: OwnedFd,
+        ) {
+            self.0(_data, _slf, mime_type, fd)
+        }
+    }
+
+    /// Event handler for cancelled events.
+    pub struct Cancelled<F>(F);
+    impl<F> ZwpPrimarySelectionSourceV1EventHandler for Cancelled<F>
+    where
+        F: Fn(&ZwpPrimarySelectionSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&self, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionSourceV1EventHandlerWithData<T> for Cancelled<F>
+    where
+        F: Fn(&mut T, &ZwpPrimarySelectionSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for send events.
+    pub struct SendMut<F>(F);
+    impl<F> ZwpPrimarySelectionSourceV1EventHandlerMut for SendMut<F>
+    where
+        F: FnMut(&ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&mut self, _slf: &ZwpPrimarySelectionSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+            self.0(_slf, mime_type, fd)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionSourceV1EventHandlerMutWithData<T> for SendMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionSourceV1Ref,
+            mime_type: &str,
+            fd: OwnedFd,
+        ) {
+            self.0(_data, _slf, mime_type, fd)
+        }
+    }
+
+    /// Mutable event handler for cancelled events.
+    pub struct CancelledMut<F>(F);
+    impl<F> ZwpPrimarySelectionSourceV1EventHandlerMut for CancelledMut<F>
+    where
+        F: FnMut(&ZwpPrimarySelectionSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&mut self, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpPrimarySelectionSourceV1EventHandlerMutWithData<T> for CancelledMut<F>
+    where
+        F: FnMut(&mut T, &ZwpPrimarySelectionSourceV1Ref),
+    {
+        #[inline]
+        fn cancelled(&mut self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpPrimarySelectionSourceV1 {
+        /// Creates an event handler for send events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_send<F>(f: F) -> Send<F>
+        where
+            F: Fn(&ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+        {
+            Send(f)
+        }
+
+        /// Creates an event handler with mutable data for send events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_send_with_data<T, F>(f: F) -> Send<F>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+        {
+            Send(f)
+        }
+
+        /// Creates an event handler for cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cancelled<F>(f: F) -> Cancelled<F>
+        where
+            F: Fn(&ZwpPrimarySelectionSourceV1Ref),
+        {
+            Cancelled(f)
+        }
+
+        /// Creates an event handler with mutable data for cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cancelled_with_data<T, F>(f: F) -> Cancelled<F>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionSourceV1Ref),
+        {
+            Cancelled(f)
+        }
+
+        /// Creates a mutable event handler for send events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_send_mut<F>(f: F) -> SendMut<F>
+        where
+            F: FnMut(&ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+        {
+            SendMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for send events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_send_mut_with_data<T, F>(f: F) -> SendMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+        {
+            SendMut(f)
+        }
+
+        /// Creates a mutable event handler for cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cancelled_mut<F>(f: F) -> CancelledMut<F>
+        where
+            F: FnMut(&ZwpPrimarySelectionSourceV1Ref),
+        {
+            CancelledMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cancelled_mut_with_data<T, F>(f: F) -> CancelledMut<F>
+        where
+            F: FnMut(&mut T, &ZwpPrimarySelectionSourceV1Ref),
+        {
+            CancelledMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                send: Ignore,
+                cancelled: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpPrimarySelectionSourceV1EventHandler for Ignore {}
+    impl<T> ZwpPrimarySelectionSourceV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpPrimarySelectionSourceV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        send: H0,
+        cancelled: H1,
+    }
+
+    impl<H0, H1> ZwpPrimarySelectionSourceV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpPrimarySelectionSourceV1EventHandler,
+        H1: ZwpPrimarySelectionSourceV1EventHandler,
+    {
+        #[inline]
+        fn send(&self, _slf: &ZwpPrimarySelectionSourceV1Ref, mime_type: &str, fd: OwnedFd) {
+            self.send.send(_slf, mime_type, fd)
+        }
+
+        #[inline]
+        fn cancelled(&self, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+            self.cancelled.cancelled(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ZwpPrimarySelectionSourceV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpPrimarySelectionSourceV1EventHandlerWithData<T>,
+        H1: ZwpPrimarySelectionSourceV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn send(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpPrimarySelectionSourceV1Ref,
+            mime_type: &str,
+            fd: OwnedFd,
+        ) {
+            self.send.send(_data, _slf, mime_type, fd)
+        }
+
+        #[inline]
+        fn cancelled(&self, _data: &mut T, _slf: &ZwpPrimarySelectionSourceV1Ref) {
+            self.cancelled.cancelled(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for send events.
+        #[allow(dead_code)]
+        pub fn on_send<F>(self, f: F) -> Handler<Send<F>, H1>
+        where
+            F: Fn(&ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+        {
+            Handler {
+                send: Send(f),
+                cancelled: self.cancelled,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for send events.
+        #[allow(dead_code)]
+        pub fn on_send_with_data<T, F>(self, f: F) -> Handler<Send<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionSourceV1Ref, &str, OwnedFd),
+        {
+            Handler {
+                send: Send(f),
+                cancelled: self.cancelled,
+            }
+        }
+
+        /// Sets the closure for cancelled events.
+        #[allow(dead_code)]
+        pub fn on_cancelled<F>(self, f: F) -> Handler<H0, Cancelled<F>>
+        where
+            F: Fn(&ZwpPrimarySelectionSourceV1Ref),
+        {
+            Handler {
+                send: self.send,
+                cancelled: Cancelled(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for cancelled events.
+        #[allow(dead_code)]
+        pub fn on_cancelled_with_data<T, F>(self, f: F) -> Handler<H0, Cancelled<F>>
+        where
+            F: Fn(&mut T, &ZwpPrimarySelectionSourceV1Ref),
+        {
+            Handler {
+                send: self.send,
+                cancelled: Cancelled(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,806 @@
+//! an exported activation handle
+//!
+//! An object for setting up a token and receiving a token handle that can
+//! be passed as an activation token to another client.
+//!
+//! The object is created using the xdg_activation_v1.get_activation_token
+//! request. This object should then be populated with the app_id, surface
+//! and serial information and committed. The compositor shall then issue a
+//! done event with the token. In case the request's parameters are invalid,
+//! the compositor will provide an invalid token.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_activation_token_v1".as_ptr(),
+    version: 1,
+    method_count: 5,
+    methods: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"set_serial".as_ptr(),
+                signature: c"uo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [None, Some(WlSeat::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_app_id".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_surface".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"commit".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"done".as_ptr(),
+            signature: c"s".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the xdg_activation_token_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_activation_token_v1",
+    protocol: "xdg_activation_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_serial",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_app_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "app_id",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "commit",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "done",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "token",
+            ty: info::ArgType::String,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "already_used",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned xdg_activation_token_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgActivationTokenV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_activation_token_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgActivationTokenV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgActivationTokenV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgActivationTokenV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgActivationTokenV1 {
+    const INTERFACE: &'static str = "xdg_activation_token_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgActivationTokenV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgActivationTokenV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgActivationTokenV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgActivationTokenV1Ref {
+    type Owned = XdgActivationTokenV1;
+}
+
+impl Deref for XdgActivationTokenV1 {
+    type Target = XdgActivationTokenV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgActivationTokenV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_activation_token_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgActivationTokenV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_activation_token_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgActivationTokenV1Ref> for XdgActivationTokenV1 {
+    fn eq(&self, other: &XdgActivationTokenV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgActivationTokenV1> for XdgActivationTokenV1Ref {
+    fn eq(&self, other: &XdgActivationTokenV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgActivationTokenV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_activation_token_v1 object
+    ///
+    /// Notify the compositor that the xdg_activation_token_v1 object will no
+    /// longer be used. The received token stays valid.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 4 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(4, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgActivationTokenV1Ref {
+    /// specifies the seat and serial of the activating event
+    ///
+    /// Provides information about the seat and serial event that requested the
+    /// token.
+    ///
+    /// The serial can come from an input or focus event. For instance, if a
+    /// click triggers the launch of a third-party client, the launcher client
+    /// should send a set_serial request with the serial and seat from the
+    /// wl_pointer.button event.
+    ///
+    /// Some compositors might refuse to activate toplevels when the token
+    /// doesn't have a valid and recent enough event serial.
+    ///
+    /// Must be sent before commit. This information is optional.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: the serial of the event that triggered the activation
+    /// - `seat`: the wl_seat of the event
+    #[inline]
+    pub fn set_serial(&self, serial: u32, seat: &WlSeatRef) {
+        let (arg0, arg1) = (serial, seat);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("seat", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { u: arg0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 5
+        //         - the request signature is `uo`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+
+    /// specifies the application being activated
+    ///
+    /// The requesting client can specify an app_id to associate the token
+    /// being created with it.
+    ///
+    /// Must be sent before commit. This information is optional.
+    ///
+    /// # Arguments
+    ///
+    /// - `app_id`: the application id of the client being activated.
+    #[inline]
+    pub fn set_app_id(&self, app_id: &str) {
+        let (arg0,) = (app_id,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 5
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(1, &mut args);
+            }
+        })
+    }
+
+    /// specifies the surface requesting activation
+    ///
+    /// This request sets the surface requesting the activation. Note, this is
+    /// different from the surface that will be activated.
+    ///
+    /// Some compositors might refuse to activate toplevels when the token
+    /// doesn't have a requesting surface.
+    ///
+    /// Must be sent before commit. This information is optional.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the requesting surface
+    #[inline]
+    pub fn set_surface(&self, surface: &WlSurfaceRef) {
+        let (arg0,) = (surface,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 5
+        //         - the request signature is `o`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+
+    /// issues the token request
+    ///
+    /// Requests an activation token based on the different parameters that
+    /// have been offered through set_serial, set_surface and set_app_id.
+    #[inline]
+    pub fn commit(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 3 < INTERFACE.method_count = 5
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(3, &mut args);
+        }
+    }
+}
+
+impl XdgActivationTokenV1 {
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+}
+
+/// An event handler for [XdgActivationTokenV1] proxies.
+#[allow(dead_code)]
+pub trait XdgActivationTokenV1EventHandler {
+    /// the exported activation token
+    ///
+    /// The 'done' event contains the unique token of this activation request
+    /// and notifies that the provider is done.
+    ///
+    /// # Arguments
+    ///
+    /// - `token`: the exported activation token
+    #[inline]
+    fn done(&self, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        let _ = token;
+    }
+}
+
+impl XdgActivationTokenV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgActivationTokenV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgActivationTokenV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgActivationTokenV1EventHandlerWithData<T> {
+    /// the exported activation token
+    ///
+    /// The 'done' event contains the unique token of this activation request
+    /// and notifies that the provider is done.
+    ///
+    /// # Arguments
+    ///
+    /// - `token`: the exported activation token
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        let _ = token;
+    }
+}
+
+/// A mutable event handler for [XdgActivationTokenV1] proxies.
+///
+/// This trait is the same as [XdgActivationTokenV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgActivationTokenV1EventHandlerMut {
+    /// See [XdgActivationTokenV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        let _ = token;
+    }
+}
+
+/// A mutable event handler for [XdgActivationTokenV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgActivationTokenV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgActivationTokenV1EventHandlerMutWithData<T> {
+    /// See [XdgActivationTokenV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        let _ = token;
+    }
+}
+
+impl<H> XdgActivationTokenV1EventHandler for MutEventHandler<H>
+where
+    H: XdgActivationTokenV1EventHandlerMut,
+{
+    #[inline]
+    fn done(&self, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        self.with(|h| h.done(_slf, token))
+    }
+}
+
+impl<T, H> XdgActivationTokenV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: XdgActivationTokenV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        self.with(|h| h.done(_data, _slf, token))
+    }
+}
+
+impl<T, U, H> XdgActivationTokenV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgActivationTokenV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf, token)
+    }
+}
+
+impl<T, U, H> XdgActivationTokenV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgActivationTokenV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf, token)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgActivationTokenV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<XdgActivationTokenV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("xdg_activation_token_v1", "token", args[0].s) };
+                self.0.done(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_activation_token_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgActivationTokenV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<XdgActivationTokenV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("xdg_activation_token_v1", "token", args[0].s) };
+                self.0.done(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_activation_token_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgActivationTokenV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgActivationTokenV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XdgActivationTokenV1 {
+    /// Since when the error.already_used enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_USED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XdgActivationTokenV1Error(pub u32);
+
+impl XdgActivationTokenV1Error {
+    /// The token has already been used previously
+    #[allow(dead_code)]
+    pub const ALREADY_USED: Self = Self(0);
+}
+
+impl Debug for XdgActivationTokenV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_USED => "ALREADY_USED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> XdgActivationTokenV1EventHandler for Done<F>
+    where
+        F: Fn(&XdgActivationTokenV1Ref, &str),
+    {
+        #[inline]
+        fn done(&self, _slf: &XdgActivationTokenV1Ref, token: &str) {
+            self.0(_slf, token)
+        }
+    }
+    impl<T, F> XdgActivationTokenV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &XdgActivationTokenV1Ref, &str),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+            self.0(_data, _slf, token)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> XdgActivationTokenV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&XdgActivationTokenV1Ref, &str),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &XdgActivationTokenV1Ref, token: &str) {
+            self.0(_slf, token)
+        }
+    }
+    impl<T, F> XdgActivationTokenV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &XdgActivationTokenV1Ref, &str),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+            self.0(_data, _slf, token)
+        }
+    }
+
+    impl XdgActivationTokenV1 {
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&XdgActivationTokenV1Ref, &str),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &XdgActivationTokenV1Ref, &str),
+        {
+            Done(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&XdgActivationTokenV1Ref, &str),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &XdgActivationTokenV1Ref, &str),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { done: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl XdgActivationTokenV1EventHandler for Ignore {}
+    impl<T> XdgActivationTokenV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`XdgActivationTokenV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        done: H0,
+    }
+
+    impl<H0> XdgActivationTokenV1EventHandler for Handler<H0>
+    where
+        H0: XdgActivationTokenV1EventHandler,
+    {
+        #[inline]
+        fn done(&self, _slf: &XdgActivationTokenV1Ref, token: &str) {
+            self.done.done(_slf, token)
+        }
+    }
+
+    impl<T, H0> XdgActivationTokenV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: XdgActivationTokenV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &XdgActivationTokenV1Ref, token: &str) {
+            self.done.done(_data, _slf, token)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<Done<F>>
+        where
+            F: Fn(&XdgActivationTokenV1Ref, &str),
+        {
+            Handler { done: Done(f) }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<Done<F>>
+        where
+            F: Fn(&mut T, &XdgActivationTokenV1Ref, &str),
+        {
+            Handler { done: Done(f) }
+        }
+    }
+}
@@ -0,0 +1,442 @@
+//! interface for activating surfaces
+//!
+//! A global interface used for informing the compositor about applications
+//! being activated or started, or for applications to request to be
+//! activated.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_activation_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_activation_token".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(XdgActivationTokenV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"activate".as_ptr(),
+                signature: c"so".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [None, Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_activation_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_activation_v1",
+    protocol: "xdg_activation_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_activation_token",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("xdg_activation_token_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "activate",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "token",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned xdg_activation_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgActivationV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_activation_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgActivationV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgActivationV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgActivationV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgActivationV1 {
+    const INTERFACE: &'static str = "xdg_activation_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgActivationV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgActivationV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgActivationV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgActivationV1Ref {
+    type Owned = XdgActivationV1;
+}
+
+impl Deref for XdgActivationV1 {
+    type Target = XdgActivationV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgActivationV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_activation_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgActivationV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_activation_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgActivationV1Ref> for XdgActivationV1 {
+    fn eq(&self, other: &XdgActivationV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgActivationV1> for XdgActivationV1Ref {
+    fn eq(&self, other: &XdgActivationV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgActivationV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_activation object
+    ///
+    /// Notify the compositor that the xdg_activation object will no longer be
+    /// used.
+    ///
+    /// The child objects created via this interface are unaffected and should
+    /// be destroyed separately.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_activation_token request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_ACTIVATION_TOKEN__SINCE: u32 = 1;
+
+    /// requests a token
+    ///
+    /// Creates an xdg_activation_token_v1 object that will provide
+    /// the initiating client with a unique token for this activation. This
+    /// token should be offered to the clients to be activated.
+    #[inline]
+    pub fn get_activation_token(&self) -> XdgActivationTokenV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                XdgActivationTokenV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface XdgActivationTokenV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgActivationV1Ref {
+    /// requests a token
+    ///
+    /// Creates an xdg_activation_token_v1 object that will provide
+    /// the initiating client with a unique token for this activation. This
+    /// token should be offered to the clients to be activated.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_activation_token(&self, _queue: &Queue) -> XdgActivationTokenV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                XdgActivationTokenV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface XdgActivationTokenV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// notify new interaction being available
+    ///
+    /// Requests surface activation. It's up to the compositor to display
+    /// this information as desired, for example by placing the surface above
+    /// the rest.
+    ///
+    /// The compositor may know who requested this by checking the activation
+    /// token and might decide not to follow through with the activation if it's
+    /// considered unwanted.
+    ///
+    /// Compositors can ignore unknown activation tokens when an invalid
+    /// token is passed.
+    ///
+    /// # Arguments
+    ///
+    /// - `token`: the activation token of the initiating client
+    /// - `surface`: the wl_surface to activate
+    #[inline]
+    pub fn activate(&self, token: &str, surface: &WlSurfaceRef) {
+        let (arg0, arg1) = (token, surface);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let obj1_lock = proxy::lock(arg1);
+            let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+            let mut args = [wl_argument { s: str0 }, wl_argument { o: obj1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 2 < INTERFACE.method_count = 3
+            //         - the request signature is `so`
+            unsafe {
+                self.proxy.send_request(2, &mut args);
+            }
+        })
+    }
+}
+
+/// An event handler for [XdgActivationV1] proxies.
+#[allow(dead_code)]
+pub trait XdgActivationV1EventHandler {}
+
+impl XdgActivationV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgActivationV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgActivationV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgActivationV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgActivationV1] proxies.
+///
+/// This trait is the same as [XdgActivationV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgActivationV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgActivationV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgActivationV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgActivationV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgActivationV1EventHandler for MutEventHandler<H> where H: XdgActivationV1EventHandlerMut {}
+
+impl<T, H> XdgActivationV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgActivationV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgActivationV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgActivationV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgActivationV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgActivationV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgActivationV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_activation_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgActivationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_activation_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgActivationV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgActivationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgActivationV1 {}
+}
@@ -0,0 +1,427 @@
+//! window decoration manager
+//!
+//! This interface allows a compositor to announce support for server-side
+//! decorations.
+//!
+//! A window decoration is a set of window controls as deemed appropriate by
+//! the party managing them, such as user interface components used to move,
+//! resize and change a window's state.
+//!
+//! A client can use this protocol to request being decorated by a supporting
+//! compositor.
+//!
+//! If compositor and client do not negotiate the use of a server-side
+//! decoration using this protocol, clients continue to self-decorate as they
+//! see fit.
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_decoration_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_toplevel_decoration".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZxdgToplevelDecorationV1::WL_INTERFACE),
+                        Some(XdgToplevel::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zxdg_decoration_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_decoration_manager_v1",
+    protocol: "xdg_decoration_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_toplevel_decoration",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zxdg_toplevel_decoration_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "toplevel",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zxdg_decoration_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgDecorationManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_decoration_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgDecorationManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgDecorationManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgDecorationManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgDecorationManagerV1 {
+    const INTERFACE: &'static str = "zxdg_decoration_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgDecorationManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgDecorationManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgDecorationManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgDecorationManagerV1Ref {
+    type Owned = ZxdgDecorationManagerV1;
+}
+
+impl Deref for ZxdgDecorationManagerV1 {
+    type Target = ZxdgDecorationManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgDecorationManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_decoration_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgDecorationManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_decoration_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgDecorationManagerV1Ref> for ZxdgDecorationManagerV1 {
+    fn eq(&self, other: &ZxdgDecorationManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgDecorationManagerV1> for ZxdgDecorationManagerV1Ref {
+    fn eq(&self, other: &ZxdgDecorationManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgDecorationManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the decoration manager object
+    ///
+    /// Destroy the decoration manager. This doesn't destroy objects created
+    /// with the manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_toplevel_decoration request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_TOPLEVEL_DECORATION__SINCE: u32 = 1;
+
+    /// create a new toplevel decoration object
+    ///
+    /// Create a new decoration object associated with the given toplevel.
+    ///
+    /// Creating an xdg_toplevel_decoration from an xdg_toplevel which has a
+    /// buffer attached or committed is a client error, and any attempts by a
+    /// client to attach or manipulate a buffer prior to the first
+    /// xdg_toplevel_decoration.configure event must also be treated as
+    /// errors.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    #[inline]
+    pub fn get_toplevel_decoration(&self, toplevel: &XdgToplevelRef) -> ZxdgToplevelDecorationV1 {
+        let (arg1,) = (toplevel,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("toplevel", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZxdgToplevelDecorationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZxdgToplevelDecorationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgDecorationManagerV1Ref {
+    /// create a new toplevel decoration object
+    ///
+    /// Create a new decoration object associated with the given toplevel.
+    ///
+    /// Creating an xdg_toplevel_decoration from an xdg_toplevel which has a
+    /// buffer attached or committed is a client error, and any attempts by a
+    /// client to attach or manipulate a buffer prior to the first
+    /// xdg_toplevel_decoration.configure event must also be treated as
+    /// errors.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `toplevel`:
+    #[inline]
+    pub fn get_toplevel_decoration(
+        &self,
+        _queue: &Queue,
+        toplevel: &XdgToplevelRef,
+    ) -> ZxdgToplevelDecorationV1 {
+        let (arg1,) = (toplevel,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("toplevel", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZxdgToplevelDecorationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZxdgToplevelDecorationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZxdgDecorationManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZxdgDecorationManagerV1EventHandler {}
+
+impl ZxdgDecorationManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgDecorationManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgDecorationManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgDecorationManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZxdgDecorationManagerV1] proxies.
+///
+/// This trait is the same as [ZxdgDecorationManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgDecorationManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZxdgDecorationManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgDecorationManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgDecorationManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZxdgDecorationManagerV1EventHandler for MutEventHandler<H> where
+    H: ZxdgDecorationManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZxdgDecorationManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZxdgDecorationManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZxdgDecorationManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgDecorationManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZxdgDecorationManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgDecorationManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgDecorationManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_decoration_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgDecorationManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_decoration_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgDecorationManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgDecorationManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZxdgDecorationManagerV1 {}
+}
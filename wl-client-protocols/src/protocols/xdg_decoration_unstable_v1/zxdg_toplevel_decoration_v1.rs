@@ -0,0 +1,870 @@
+//! decoration object for a toplevel surface
+//!
+//! The decoration object allows the compositor to toggle server-side window
+//! decorations for a toplevel surface. The client can request to switch to
+//! another mode.
+//!
+//! The xdg_toplevel_decoration object must be destroyed before its
+//! xdg_toplevel.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_toplevel_decoration_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_mode".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"unset_mode".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"configure".as_ptr(),
+            signature: c"u".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zxdg_toplevel_decoration_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_toplevel_decoration_v1",
+    protocol: "xdg_decoration_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_mode",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "mode",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("mode"),
+            }],
+        },
+        info::MessageInfo {
+            name: "unset_mode",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "configure",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "mode",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: Some("mode"),
+        }],
+    }],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "unconfigured_buffer",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "already_constructed",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "orphaned",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_mode",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "mode",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "client_side",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "server_side",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
+/// An owned zxdg_toplevel_decoration_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgToplevelDecorationV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_toplevel_decoration_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgToplevelDecorationV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgToplevelDecorationV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgToplevelDecorationV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgToplevelDecorationV1 {
+    const INTERFACE: &'static str = "zxdg_toplevel_decoration_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgToplevelDecorationV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgToplevelDecorationV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgToplevelDecorationV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgToplevelDecorationV1Ref {
+    type Owned = ZxdgToplevelDecorationV1;
+}
+
+impl Deref for ZxdgToplevelDecorationV1 {
+    type Target = ZxdgToplevelDecorationV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgToplevelDecorationV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_toplevel_decoration_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgToplevelDecorationV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_toplevel_decoration_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgToplevelDecorationV1Ref> for ZxdgToplevelDecorationV1 {
+    fn eq(&self, other: &ZxdgToplevelDecorationV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgToplevelDecorationV1> for ZxdgToplevelDecorationV1Ref {
+    fn eq(&self, other: &ZxdgToplevelDecorationV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgToplevelDecorationV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the decoration object
+    ///
+    /// Switch back to a mode without any server-side decorations at the next
+    /// commit.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgToplevelDecorationV1Ref {
+    /// set the decoration mode
+    ///
+    /// Set the toplevel surface decoration mode. This informs the compositor
+    /// that the client prefers the provided decoration mode.
+    ///
+    /// After requesting a decoration mode, the compositor will respond by
+    /// emitting an xdg_surface.configure event. The client should then update
+    /// its content, drawing it without decorations if the received mode is
+    /// server-side decorations. The client must also acknowledge the configure
+    /// when committing the new content (see xdg_surface.ack_configure).
+    ///
+    /// The compositor can decide not to use the client's mode and enforce a
+    /// different mode instead.
+    ///
+    /// Clients whose decoration mode depend on the xdg_toplevel state may send
+    /// a set_mode request in response to an xdg_surface.configure event and wait
+    /// for the next xdg_surface.configure event to prevent unwanted state.
+    /// Such clients are responsible for preventing configure loops and must
+    /// make sure not to send multiple successive set_mode requests with the
+    /// same decoration mode.
+    ///
+    /// If an invalid mode is supplied by the client, the invalid_mode protocol
+    /// error is raised by the compositor.
+    ///
+    /// # Arguments
+    ///
+    /// - `mode`: the decoration mode
+    #[inline]
+    pub fn set_mode(&self, mode: ZxdgToplevelDecorationV1Mode) {
+        let (arg0,) = (mode,);
+        let mut args = [wl_argument { u: arg0.0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `u`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// unset the decoration mode
+    ///
+    /// Unset the toplevel surface decoration mode. This informs the compositor
+    /// that the client doesn't prefer a particular decoration mode.
+    ///
+    /// This request has the same semantics as set_mode.
+    #[inline]
+    pub fn unset_mode(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+impl ZxdgToplevelDecorationV1 {
+    /// Since when the configure event is available.
+    #[allow(dead_code)]
+    pub const EVT__CONFIGURE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZxdgToplevelDecorationV1] proxies.
+#[allow(dead_code)]
+pub trait ZxdgToplevelDecorationV1EventHandler {
+    /// notify a decoration mode change
+    ///
+    /// The configure event configures the effective decoration mode. The
+    /// configured state should not be applied immediately. Clients must send an
+    /// ack_configure in response to this event. See xdg_surface.configure and
+    /// xdg_surface.ack_configure for details.
+    ///
+    /// A configure event can be sent at any time. The specified mode must be
+    /// obeyed by the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `mode`: the decoration mode
+    #[inline]
+    fn configure(&self, _slf: &ZxdgToplevelDecorationV1Ref, mode: ZxdgToplevelDecorationV1Mode) {
+        let _ = mode;
+    }
+}
+
+impl ZxdgToplevelDecorationV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgToplevelDecorationV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgToplevelDecorationV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgToplevelDecorationV1EventHandlerWithData<T> {
+    /// notify a decoration mode change
+    ///
+    /// The configure event configures the effective decoration mode. The
+    /// configured state should not be applied immediately. Clients must send an
+    /// ack_configure in response to this event. See xdg_surface.configure and
+    /// xdg_surface.ack_configure for details.
+    ///
+    /// A configure event can be sent at any time. The specified mode must be
+    /// obeyed by the client.
+    ///
+    /// # Arguments
+    ///
+    /// - `mode`: the decoration mode
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &ZxdgToplevelDecorationV1Ref,
+        mode: ZxdgToplevelDecorationV1Mode,
+    ) {
+        let _ = mode;
+    }
+}
+
+/// A mutable event handler for [ZxdgToplevelDecorationV1] proxies.
+///
+/// This trait is the same as [ZxdgToplevelDecorationV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgToplevelDecorationV1EventHandlerMut {
+    /// See [ZxdgToplevelDecorationV1EventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _slf: &ZxdgToplevelDecorationV1Ref,
+        mode: ZxdgToplevelDecorationV1Mode,
+    ) {
+        let _ = mode;
+    }
+}
+
+/// A mutable event handler for [ZxdgToplevelDecorationV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgToplevelDecorationV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgToplevelDecorationV1EventHandlerMutWithData<T> {
+    /// See [ZxdgToplevelDecorationV1EventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZxdgToplevelDecorationV1Ref,
+        mode: ZxdgToplevelDecorationV1Mode,
+    ) {
+        let _ = mode;
+    }
+}
+
+impl<H> ZxdgToplevelDecorationV1EventHandler for MutEventHandler<H>
+where
+    H: ZxdgToplevelDecorationV1EventHandlerMut,
+{
+    #[inline]
+    fn configure(&self, _slf: &ZxdgToplevelDecorationV1Ref, mode: ZxdgToplevelDecorationV1Mode) {
+        self.with(|h| h.configure(_slf, mode))
+    }
+}
+
+impl<T, H> ZxdgToplevelDecorationV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZxdgToplevelDecorationV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &ZxdgToplevelDecorationV1Ref,
+        mode: ZxdgToplevelDecorationV1Mode,
+    ) {
+        self.with(|h| h.configure(_data, _slf, mode))
+    }
+}
+
+impl<T, U, H> ZxdgToplevelDecorationV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgToplevelDecorationV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &ZxdgToplevelDecorationV1Ref,
+        mode: ZxdgToplevelDecorationV1Mode,
+    ) {
+        let _data = self.project(_data);
+        self.handler().configure(_data, _slf, mode)
+    }
+}
+
+impl<T, U, H> ZxdgToplevelDecorationV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgToplevelDecorationV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZxdgToplevelDecorationV1Ref,
+        mode: ZxdgToplevelDecorationV1Mode,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().configure(_data, _slf, mode)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgToplevelDecorationV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgToplevelDecorationV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZxdgToplevelDecorationV1Mode(args[0].u) };
+                self.0.configure(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zxdg_toplevel_decoration_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgToplevelDecorationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgToplevelDecorationV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZxdgToplevelDecorationV1Mode(args[0].u) };
+                self.0.configure(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zxdg_toplevel_decoration_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgToplevelDecorationV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgToplevelDecorationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZxdgToplevelDecorationV1 {
+    /// Since when the error.unconfigured_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_UNCONFIGURED_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.already_constructed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_CONSTRUCTED__SINCE: u32 = 1;
+    /// Since when the error.orphaned enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ORPHANED__SINCE: u32 = 1;
+    /// Since when the error.invalid_mode enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_MODE__SINCE: u32 = 1;
+
+    /// Since when the mode.client_side enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__MODE_CLIENT_SIDE__SINCE: u32 = 1;
+    /// Since when the mode.server_side enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__MODE_SERVER_SIDE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZxdgToplevelDecorationV1Error(pub u32);
+
+impl ZxdgToplevelDecorationV1Error {
+    /// xdg_toplevel has a buffer attached before configure
+    #[allow(dead_code)]
+    pub const UNCONFIGURED_BUFFER: Self = Self(0);
+
+    /// xdg_toplevel already has a decoration object
+    #[allow(dead_code)]
+    pub const ALREADY_CONSTRUCTED: Self = Self(1);
+
+    /// xdg_toplevel destroyed before the decoration object
+    #[allow(dead_code)]
+    pub const ORPHANED: Self = Self(2);
+
+    /// invalid mode
+    #[allow(dead_code)]
+    pub const INVALID_MODE: Self = Self(3);
+}
+
+impl Debug for ZxdgToplevelDecorationV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::UNCONFIGURED_BUFFER => "UNCONFIGURED_BUFFER",
+            Self::ALREADY_CONSTRUCTED => "ALREADY_CONSTRUCTED",
+            Self::ORPHANED => "ORPHANED",
+            Self::INVALID_MODE => "INVALID_MODE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// window decoration modes
+///
+/// These values describe window decoration modes.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZxdgToplevelDecorationV1Mode(pub u32);
+
+impl ZxdgToplevelDecorationV1Mode {
+    /// no server-side window decoration
+    #[allow(dead_code)]
+    pub const CLIENT_SIDE: Self = Self(1);
+
+    /// server-side window decoration
+    #[allow(dead_code)]
+    pub const SERVER_SIDE: Self = Self(2);
+}
+
+impl Debug for ZxdgToplevelDecorationV1Mode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::CLIENT_SIDE => "CLIENT_SIDE",
+            Self::SERVER_SIDE => "SERVER_SIDE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for configure events.
+    pub struct Configure<F>(F);
+    impl<F> ZxdgToplevelDecorationV1EventHandler for Configure<F>
+    where
+        F: Fn(&ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _slf: &ZxdgToplevelDecorationV1Ref,
+            mode: ZxdgToplevelDecorationV1Mode,
+        ) {
+            self.0(_slf, mode)
+        }
+    }
+    impl<T, F> ZxdgToplevelDecorationV1EventHandlerWithData<T> for Configure<F>
+    where
+        F: Fn(&mut T, &ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &ZxdgToplevelDecorationV1Ref,
+            mode: ZxdgToplevelDecorationV1Mode,
+        ) {
+            self.0(_data, _slf, mode)
+        }
+    }
+
+    /// Mutable event handler for configure events.
+    pub struct ConfigureMut<F>(F);
+    impl<F> ZxdgToplevelDecorationV1EventHandlerMut for ConfigureMut<F>
+    where
+        F: FnMut(&ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _slf: &ZxdgToplevelDecorationV1Ref,
+            mode: ZxdgToplevelDecorationV1Mode,
+        ) {
+            self.0(_slf, mode)
+        }
+    }
+    impl<T, F> ZxdgToplevelDecorationV1EventHandlerMutWithData<T> for ConfigureMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZxdgToplevelDecorationV1Ref,
+            mode: ZxdgToplevelDecorationV1Mode,
+        ) {
+            self.0(_data, _slf, mode)
+        }
+    }
+
+    impl ZxdgToplevelDecorationV1 {
+        /// Creates an event handler for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(f: F) -> Configure<F>
+        where
+            F: Fn(&ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+        {
+            Configure(f)
+        }
+
+        /// Creates an event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(f: F) -> Configure<F>
+        where
+            F: Fn(&mut T, &ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+        {
+            Configure(f)
+        }
+
+        /// Creates a mutable event handler for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut<F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut_with_data<T, F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { configure: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZxdgToplevelDecorationV1EventHandler for Ignore {}
+    impl<T> ZxdgToplevelDecorationV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZxdgToplevelDecorationV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        configure: H0,
+    }
+
+    impl<H0> ZxdgToplevelDecorationV1EventHandler for Handler<H0>
+    where
+        H0: ZxdgToplevelDecorationV1EventHandler,
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _slf: &ZxdgToplevelDecorationV1Ref,
+            mode: ZxdgToplevelDecorationV1Mode,
+        ) {
+            self.configure.configure(_slf, mode)
+        }
+    }
+
+    impl<T, H0> ZxdgToplevelDecorationV1EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZxdgToplevelDecorationV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &ZxdgToplevelDecorationV1Ref,
+            mode: ZxdgToplevelDecorationV1Mode,
+        ) {
+            self.configure.configure(_data, _slf, mode)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(self, f: F) -> Handler<Configure<F>>
+        where
+            F: Fn(&ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+        {
+            Handler {
+                configure: Configure(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(self, f: F) -> Handler<Configure<F>>
+        where
+            F: Fn(&mut T, &ZxdgToplevelDecorationV1Ref, ZxdgToplevelDecorationV1Mode),
+        {
+            Handler {
+                configure: Configure(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! dialog object
+//!
+//! A xdg_dialog_v1 object is an ancillary object tied to a xdg_toplevel. Its
+//! purpose is hinting the compositor that the toplevel is a "dialog" (e.g. a
+//! temporary window) relative to another toplevel (see
+//! xdg_toplevel.set_parent). If the xdg_toplevel is destroyed, the xdg_dialog_v1
+//! becomes inert.
+//!
+//! Through this object, the client may provide additional hints about
+//! the purpose of the secondary toplevel. This interface has no effect
+//! on toplevels that are not attached to a parent toplevel.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_dialog_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_modal".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"unset_modal".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_dialog_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_dialog_v1",
+    protocol: "xdg_dialog_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_modal",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "unset_modal",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned xdg_dialog_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgDialogV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_dialog_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgDialogV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgDialogV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgDialogV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgDialogV1 {
+    const INTERFACE: &'static str = "xdg_dialog_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgDialogV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgDialogV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgDialogV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgDialogV1Ref {
+    type Owned = XdgDialogV1;
+}
+
+impl Deref for XdgDialogV1 {
+    type Target = XdgDialogV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgDialogV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_dialog_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgDialogV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_dialog_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgDialogV1Ref> for XdgDialogV1 {
+    fn eq(&self, other: &XdgDialogV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgDialogV1> for XdgDialogV1Ref {
+    fn eq(&self, other: &XdgDialogV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgDialogV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the dialog object
+    ///
+    /// Destroys the xdg_dialog_v1 object. If this object is destroyed
+    /// before the related xdg_toplevel, the compositor should unapply its
+    /// effects.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgDialogV1Ref {
+    /// mark dialog as modal
+    ///
+    /// Hints that the dialog has "modal" behavior. Modal dialogs typically
+    /// require to be fully addressed by the user (i.e. closed) before resuming
+    /// interaction with the parent toplevel, and may require a distinct
+    /// presentation.
+    ///
+    /// Clients must implement the logic to filter events in the parent
+    /// toplevel on their own.
+    ///
+    /// Compositors may choose any policy in event delivery to the parent
+    /// toplevel, from delivering all events unfiltered to using them for
+    /// internal consumption.
+    #[inline]
+    pub fn set_modal(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// mark dialog as not modal
+    ///
+    /// Drops the hint that this dialog has "modal" behavior. See
+    /// xdg_dialog_v1.set_modal for more details.
+    #[inline]
+    pub fn unset_modal(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+/// An event handler for [XdgDialogV1] proxies.
+#[allow(dead_code)]
+pub trait XdgDialogV1EventHandler {}
+
+impl XdgDialogV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgDialogV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgDialogV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgDialogV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgDialogV1] proxies.
+///
+/// This trait is the same as [XdgDialogV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgDialogV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgDialogV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgDialogV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgDialogV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgDialogV1EventHandler for MutEventHandler<H> where H: XdgDialogV1EventHandlerMut {}
+
+impl<T, H> XdgDialogV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgDialogV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgDialogV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgDialogV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgDialogV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgDialogV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgDialogV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_dialog_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgDialogV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_dialog_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgDialogV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgDialogV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgDialogV1 {}
+}
@@ -0,0 +1,432 @@
+//! create dialogs related to other toplevels
+//!
+//! The xdg_wm_dialog_v1 interface is exposed as a global object allowing
+//! to register surfaces with a xdg_toplevel role as "dialogs" relative to
+//! another toplevel.
+//!
+//! The compositor may let this relation influence how the surface is
+//! placed, displayed or interacted with.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_wm_dialog_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_xdg_dialog".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(XdgDialogV1::WL_INTERFACE),
+                        Some(XdgToplevel::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_wm_dialog_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_wm_dialog_v1",
+    protocol: "xdg_dialog_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_xdg_dialog",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("xdg_dialog_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "toplevel",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "already_used",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned xdg_wm_dialog_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgWmDialogV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_wm_dialog_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgWmDialogV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgWmDialogV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgWmDialogV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgWmDialogV1 {
+    const INTERFACE: &'static str = "xdg_wm_dialog_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgWmDialogV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgWmDialogV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgWmDialogV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgWmDialogV1Ref {
+    type Owned = XdgWmDialogV1;
+}
+
+impl Deref for XdgWmDialogV1 {
+    type Target = XdgWmDialogV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgWmDialogV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_wm_dialog_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgWmDialogV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_wm_dialog_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgWmDialogV1Ref> for XdgWmDialogV1 {
+    fn eq(&self, other: &XdgWmDialogV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgWmDialogV1> for XdgWmDialogV1Ref {
+    fn eq(&self, other: &XdgWmDialogV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgWmDialogV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the dialog manager object
+    ///
+    /// Destroys the xdg_wm_dialog_v1 object. This does not affect
+    /// the xdg_dialog_v1 objects generated through it.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_xdg_dialog request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_XDG_DIALOG__SINCE: u32 = 1;
+
+    /// create a dialog object
+    ///
+    /// Creates a xdg_dialog_v1 object for the given toplevel. See the interface
+    /// description for more details.
+    ///
+    /// 	Compositors must raise an already_used error if clients attempt to
+    /// 	create multiple xdg_dialog_v1 objects for the same xdg_toplevel.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    #[inline]
+    pub fn get_xdg_dialog(&self, toplevel: &XdgToplevelRef) -> XdgDialogV1 {
+        let (arg1,) = (toplevel,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("toplevel", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, XdgDialogV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface XdgDialogV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgWmDialogV1Ref {
+    /// create a dialog object
+    ///
+    /// Creates a xdg_dialog_v1 object for the given toplevel. See the interface
+    /// description for more details.
+    ///
+    /// 	Compositors must raise an already_used error if clients attempt to
+    /// 	create multiple xdg_dialog_v1 objects for the same xdg_toplevel.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `toplevel`:
+    #[inline]
+    pub fn get_xdg_dialog(&self, _queue: &Queue, toplevel: &XdgToplevelRef) -> XdgDialogV1 {
+        let (arg1,) = (toplevel,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("toplevel", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, XdgDialogV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface XdgDialogV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [XdgWmDialogV1] proxies.
+#[allow(dead_code)]
+pub trait XdgWmDialogV1EventHandler {}
+
+impl XdgWmDialogV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgWmDialogV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgWmDialogV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgWmDialogV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgWmDialogV1] proxies.
+///
+/// This trait is the same as [XdgWmDialogV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgWmDialogV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgWmDialogV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgWmDialogV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgWmDialogV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgWmDialogV1EventHandler for MutEventHandler<H> where H: XdgWmDialogV1EventHandlerMut {}
+
+impl<T, H> XdgWmDialogV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgWmDialogV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgWmDialogV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgWmDialogV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgWmDialogV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgWmDialogV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgWmDialogV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_wm_dialog_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgWmDialogV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_wm_dialog_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgWmDialogV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgWmDialogV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XdgWmDialogV1 {
+    /// Since when the error.already_used enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_USED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XdgWmDialogV1Error(pub u32);
+
+impl XdgWmDialogV1Error {
+    /// the xdg_toplevel object has already been used to create a xdg_dialog_v1
+    #[allow(dead_code)]
+    pub const ALREADY_USED: Self = Self(0);
+}
+
+impl Debug for XdgWmDialogV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_USED => "ALREADY_USED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgWmDialogV1 {}
+}
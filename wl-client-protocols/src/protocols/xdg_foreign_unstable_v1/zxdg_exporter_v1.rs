@@ -0,0 +1,393 @@
+//! interface for exporting surfaces
+//!
+//! A global interface used for exporting surfaces that can later be imported
+//! using xdg_importer.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_exporter_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"export".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZxdgExportedV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zxdg_exporter_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_exporter_v1",
+    protocol: "xdg_foreign_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "export",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zxdg_exported_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zxdg_exporter_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgExporterV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_exporter_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgExporterV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgExporterV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgExporterV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgExporterV1 {
+    const INTERFACE: &'static str = "zxdg_exporter_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgExporterV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgExporterV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgExporterV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgExporterV1Ref {
+    type Owned = ZxdgExporterV1;
+}
+
+impl Deref for ZxdgExporterV1 {
+    type Target = ZxdgExporterV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgExporterV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_exporter_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgExporterV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_exporter_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgExporterV1Ref> for ZxdgExporterV1 {
+    fn eq(&self, other: &ZxdgExporterV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgExporterV1> for ZxdgExporterV1Ref {
+    fn eq(&self, other: &ZxdgExporterV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgExporterV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_exporter object
+    ///
+    /// Notify the compositor that the xdg_exporter object will no longer be
+    /// used.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the export request is available.
+    #[allow(dead_code)]
+    pub const REQ__EXPORT__SINCE: u32 = 1;
+
+    /// export a surface
+    ///
+    /// The export request exports the passed surface so that it can later be
+    /// imported via xdg_importer. When called, a new xdg_exported object will
+    /// be created and xdg_exported.handle will be sent immediately. See the
+    /// corresponding interface and event for details.
+    ///
+    /// A surface may be exported multiple times, and each exported handle may
+    /// be used to create an xdg_imported multiple times. Only xdg_surface
+    /// surfaces may be exported.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface to export
+    #[inline]
+    pub fn export(&self, surface: &WlSurfaceRef) -> ZxdgExportedV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, ZxdgExportedV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZxdgExportedV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgExporterV1Ref {
+    /// export a surface
+    ///
+    /// The export request exports the passed surface so that it can later be
+    /// imported via xdg_importer. When called, a new xdg_exported object will
+    /// be created and xdg_exported.handle will be sent immediately. See the
+    /// corresponding interface and event for details.
+    ///
+    /// A surface may be exported multiple times, and each exported handle may
+    /// be used to create an xdg_imported multiple times. Only xdg_surface
+    /// surfaces may be exported.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface to export
+    #[inline]
+    pub fn export(&self, _queue: &Queue, surface: &WlSurfaceRef) -> ZxdgExportedV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, ZxdgExportedV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZxdgExportedV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZxdgExporterV1] proxies.
+#[allow(dead_code)]
+pub trait ZxdgExporterV1EventHandler {}
+
+impl ZxdgExporterV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgExporterV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgExporterV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgExporterV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZxdgExporterV1] proxies.
+///
+/// This trait is the same as [ZxdgExporterV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgExporterV1EventHandlerMut {}
+
+/// A mutable event handler for [ZxdgExporterV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgExporterV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgExporterV1EventHandlerMutWithData<T> {}
+
+impl<H> ZxdgExporterV1EventHandler for MutEventHandler<H> where H: ZxdgExporterV1EventHandlerMut {}
+
+impl<T, H> ZxdgExporterV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZxdgExporterV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZxdgExporterV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgExporterV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZxdgExporterV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgExporterV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgExporterV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_exporter_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgExporterV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_exporter_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgExporterV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgExporterV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZxdgExporterV1 {}
+}
@@ -0,0 +1,568 @@
+//! an exported surface handle
+//!
+//! An xdg_exported object represents an exported reference to a surface. The
+//! exported surface may be referenced as long as the xdg_exported object not
+//! destroyed. Destroying the xdg_exported invalidates any relationship the
+//! importer may have established using xdg_imported.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_exported_v2".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"handle".as_ptr(),
+            signature: c"s".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zxdg_exported_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_exported_v2",
+    protocol: "xdg_foreign_unstable_v2",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[info::MessageInfo {
+        name: "handle",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "handle",
+            ty: info::ArgType::String,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[],
+};
+
+/// An owned zxdg_exported_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgExportedV2 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_exported_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgExportedV2Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgExportedV2 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgExportedV2 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgExportedV2 {
+    const INTERFACE: &'static str = "zxdg_exported_v2";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgExportedV2Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgExportedV2Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgExportedV2Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgExportedV2Ref {
+    type Owned = ZxdgExportedV2;
+}
+
+impl Deref for ZxdgExportedV2 {
+    type Target = ZxdgExportedV2Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgExportedV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_exported_v2#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgExportedV2Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_exported_v2#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgExportedV2Ref> for ZxdgExportedV2 {
+    fn eq(&self, other: &ZxdgExportedV2Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgExportedV2> for ZxdgExportedV2Ref {
+    fn eq(&self, other: &ZxdgExportedV2) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgExportedV2 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// unexport the exported surface
+    ///
+    /// Revoke the previously exported surface. This invalidates any
+    /// relationship the importer may have set up using the xdg_imported created
+    /// given the handle sent via xdg_exported.handle.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZxdgExportedV2 {
+    /// Since when the handle event is available.
+    #[allow(dead_code)]
+    pub const EVT__HANDLE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZxdgExportedV2] proxies.
+#[allow(dead_code)]
+pub trait ZxdgExportedV2EventHandler {
+    /// the exported surface handle
+    ///
+    /// The handle event contains the unique handle of this exported surface
+    /// reference. It may be shared with any client, which then can use it to
+    /// import the surface by calling xdg_importer.import_toplevel. A handle
+    /// may be used to import the surface multiple times.
+    ///
+    /// # Arguments
+    ///
+    /// - `handle`: the exported surface handle
+    #[inline]
+    fn handle(&self, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        let _ = handle;
+    }
+}
+
+impl ZxdgExportedV2EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgExportedV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgExportedV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgExportedV2EventHandlerWithData<T> {
+    /// the exported surface handle
+    ///
+    /// The handle event contains the unique handle of this exported surface
+    /// reference. It may be shared with any client, which then can use it to
+    /// import the surface by calling xdg_importer.import_toplevel. A handle
+    /// may be used to import the surface multiple times.
+    ///
+    /// # Arguments
+    ///
+    /// - `handle`: the exported surface handle
+    #[inline]
+    fn handle(&self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        let _ = handle;
+    }
+}
+
+/// A mutable event handler for [ZxdgExportedV2] proxies.
+///
+/// This trait is the same as [ZxdgExportedV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgExportedV2EventHandlerMut {
+    /// See [ZxdgExportedV2EventHandler::handle].
+    #[inline]
+    fn handle(&mut self, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        let _ = handle;
+    }
+}
+
+/// A mutable event handler for [ZxdgExportedV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgExportedV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgExportedV2EventHandlerMutWithData<T> {
+    /// See [ZxdgExportedV2EventHandler::handle].
+    #[inline]
+    fn handle(&mut self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        let _ = handle;
+    }
+}
+
+impl<H> ZxdgExportedV2EventHandler for MutEventHandler<H>
+where
+    H: ZxdgExportedV2EventHandlerMut,
+{
+    #[inline]
+    fn handle(&self, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        self.with(|h| h.handle(_slf, handle))
+    }
+}
+
+impl<T, H> ZxdgExportedV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZxdgExportedV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn handle(&self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        self.with(|h| h.handle(_data, _slf, handle))
+    }
+}
+
+impl<T, U, H> ZxdgExportedV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgExportedV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn handle(&self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        let _data = self.project(_data);
+        self.handler().handle(_data, _slf, handle)
+    }
+}
+
+impl<T, U, H> ZxdgExportedV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgExportedV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn handle(&mut self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().handle(_data, _slf, handle)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgExportedV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgExportedV2Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("zxdg_exported_v2", "handle", args[0].s) };
+                self.0.handle(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zxdg_exported_v2", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgExportedV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgExportedV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("zxdg_exported_v2", "handle", args[0].s) };
+                self.0.handle(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zxdg_exported_v2", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgExportedV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgExportedV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for handle events.
+    pub struct Handle<F>(F);
+    impl<F> ZxdgExportedV2EventHandler for Handle<F>
+    where
+        F: Fn(&ZxdgExportedV2Ref, &str),
+    {
+        #[inline]
+        fn handle(&self, _slf: &ZxdgExportedV2Ref, handle: &str) {
+            self.0(_slf, handle)
+        }
+    }
+    impl<T, F> ZxdgExportedV2EventHandlerWithData<T> for Handle<F>
+    where
+        F: Fn(&mut T, &ZxdgExportedV2Ref, &str),
+    {
+        #[inline]
+        fn handle(&self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+            self.0(_data, _slf, handle)
+        }
+    }
+
+    /// Mutable event handler for handle events.
+    pub struct HandleMut<F>(F);
+    impl<F> ZxdgExportedV2EventHandlerMut for HandleMut<F>
+    where
+        F: FnMut(&ZxdgExportedV2Ref, &str),
+    {
+        #[inline]
+        fn handle(&mut self, _slf: &ZxdgExportedV2Ref, handle: &str) {
+            self.0(_slf, handle)
+        }
+    }
+    impl<T, F> ZxdgExportedV2EventHandlerMutWithData<T> for HandleMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgExportedV2Ref, &str),
+    {
+        #[inline]
+        fn handle(&mut self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+            self.0(_data, _slf, handle)
+        }
+    }
+
+    impl ZxdgExportedV2 {
+        /// Creates an event handler for handle events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_handle<F>(f: F) -> Handle<F>
+        where
+            F: Fn(&ZxdgExportedV2Ref, &str),
+        {
+            Handle(f)
+        }
+
+        /// Creates an event handler with mutable data for handle events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_handle_with_data<T, F>(f: F) -> Handle<F>
+        where
+            F: Fn(&mut T, &ZxdgExportedV2Ref, &str),
+        {
+            Handle(f)
+        }
+
+        /// Creates a mutable event handler for handle events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_handle_mut<F>(f: F) -> HandleMut<F>
+        where
+            F: FnMut(&ZxdgExportedV2Ref, &str),
+        {
+            HandleMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for handle events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_handle_mut_with_data<T, F>(f: F) -> HandleMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgExportedV2Ref, &str),
+        {
+            HandleMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { handle: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZxdgExportedV2EventHandler for Ignore {}
+    impl<T> ZxdgExportedV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZxdgExportedV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        handle: H0,
+    }
+
+    impl<H0> ZxdgExportedV2EventHandler for Handler<H0>
+    where
+        H0: ZxdgExportedV2EventHandler,
+    {
+        #[inline]
+        fn handle(&self, _slf: &ZxdgExportedV2Ref, handle: &str) {
+            self.handle.handle(_slf, handle)
+        }
+    }
+
+    impl<T, H0> ZxdgExportedV2EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZxdgExportedV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn handle(&self, _data: &mut T, _slf: &ZxdgExportedV2Ref, handle: &str) {
+            self.handle.handle(_data, _slf, handle)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for handle events.
+        #[allow(dead_code)]
+        pub fn on_handle<F>(self, f: F) -> Handler<Handle<F>>
+        where
+            F: Fn(&ZxdgExportedV2Ref, &str),
+        {
+            Handler { handle: Handle(f) }
+        }
+
+        /// Sets the closure that takes mutable data for handle events.
+        #[allow(dead_code)]
+        pub fn on_handle_with_data<T, F>(self, f: F) -> Handler<Handle<F>>
+        where
+            F: Fn(&mut T, &ZxdgExportedV2Ref, &str),
+        {
+            Handler { handle: Handle(f) }
+        }
+    }
+}
@@ -0,0 +1,435 @@
+//! interface for exporting surfaces
+//!
+//! A global interface used for exporting surfaces that can later be imported
+//! using xdg_importer.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_exporter_v2".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"export_toplevel".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZxdgExportedV2::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zxdg_exporter_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_exporter_v2",
+    protocol: "xdg_foreign_unstable_v2",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "export_toplevel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zxdg_exported_v2"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "invalid_surface",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned zxdg_exporter_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgExporterV2 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_exporter_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgExporterV2Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgExporterV2 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgExporterV2 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgExporterV2 {
+    const INTERFACE: &'static str = "zxdg_exporter_v2";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgExporterV2Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgExporterV2Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgExporterV2Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgExporterV2Ref {
+    type Owned = ZxdgExporterV2;
+}
+
+impl Deref for ZxdgExporterV2 {
+    type Target = ZxdgExporterV2Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgExporterV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_exporter_v2#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgExporterV2Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_exporter_v2#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgExporterV2Ref> for ZxdgExporterV2 {
+    fn eq(&self, other: &ZxdgExporterV2Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgExporterV2> for ZxdgExporterV2Ref {
+    fn eq(&self, other: &ZxdgExporterV2) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgExporterV2 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_exporter object
+    ///
+    /// Notify the compositor that the xdg_exporter object will no longer be
+    /// used.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the export_toplevel request is available.
+    #[allow(dead_code)]
+    pub const REQ__EXPORT_TOPLEVEL__SINCE: u32 = 1;
+
+    /// export a toplevel surface
+    ///
+    /// The export_toplevel request exports the passed surface so that it can later be
+    /// imported via xdg_importer. When called, a new xdg_exported object will
+    /// be created and xdg_exported.handle will be sent immediately. See the
+    /// corresponding interface and event for details.
+    ///
+    /// A surface may be exported multiple times, and each exported handle may
+    /// be used to create an xdg_imported multiple times. Only xdg_toplevel
+    ///         equivalent surfaces may be exported, otherwise an invalid_surface
+    ///         protocol error is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface to export
+    #[inline]
+    pub fn export_toplevel(&self, surface: &WlSurfaceRef) -> ZxdgExportedV2 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor::<false>(1, &mut args, ZxdgExportedV2::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZxdgExportedV2::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgExporterV2Ref {
+    /// export a toplevel surface
+    ///
+    /// The export_toplevel request exports the passed surface so that it can later be
+    /// imported via xdg_importer. When called, a new xdg_exported object will
+    /// be created and xdg_exported.handle will be sent immediately. See the
+    /// corresponding interface and event for details.
+    ///
+    /// A surface may be exported multiple times, and each exported handle may
+    /// be used to create an xdg_imported multiple times. Only xdg_toplevel
+    ///         equivalent surfaces may be exported, otherwise an invalid_surface
+    ///         protocol error is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface to export
+    #[inline]
+    pub fn export_toplevel(&self, _queue: &Queue, surface: &WlSurfaceRef) -> ZxdgExportedV2 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, ZxdgExportedV2::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface ZxdgExportedV2::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZxdgExporterV2] proxies.
+#[allow(dead_code)]
+pub trait ZxdgExporterV2EventHandler {}
+
+impl ZxdgExporterV2EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgExporterV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgExporterV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgExporterV2EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZxdgExporterV2] proxies.
+///
+/// This trait is the same as [ZxdgExporterV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgExporterV2EventHandlerMut {}
+
+/// A mutable event handler for [ZxdgExporterV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgExporterV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgExporterV2EventHandlerMutWithData<T> {}
+
+impl<H> ZxdgExporterV2EventHandler for MutEventHandler<H> where H: ZxdgExporterV2EventHandlerMut {}
+
+impl<T, H> ZxdgExporterV2EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZxdgExporterV2EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZxdgExporterV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgExporterV2EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZxdgExporterV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgExporterV2EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgExporterV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_exporter_v2", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgExporterV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_exporter_v2", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgExporterV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgExporterV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZxdgExporterV2 {
+    /// Since when the error.invalid_surface enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SURFACE__SINCE: u32 = 1;
+}
+
+/// error values
+///
+/// These errors can be emitted in response to invalid xdg_exporter
+/// requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZxdgExporterV2Error(pub u32);
+
+impl ZxdgExporterV2Error {
+    /// surface is not an xdg_toplevel
+    #[allow(dead_code)]
+    pub const INVALID_SURFACE: Self = Self(0);
+}
+
+impl Debug for ZxdgExporterV2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_SURFACE => "INVALID_SURFACE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZxdgExporterV2 {}
+}
@@ -0,0 +1,633 @@
+//! an imported surface handle
+//!
+//! An xdg_imported object represents an imported reference to surface exported
+//! by some client. A client can use this interface to manipulate
+//! relationships between its own surfaces and the imported surface.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_imported_v2".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_parent_of".as_ptr(),
+                signature: c"o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(WlSurface::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 1,
+    events: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroyed".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zxdg_imported_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_imported_v2",
+    protocol: "xdg_foreign_unstable_v2",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_parent_of",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "destroyed",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[],
+    }],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "invalid_surface",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned zxdg_imported_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgImportedV2 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_imported_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgImportedV2Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgImportedV2 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgImportedV2 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgImportedV2 {
+    const INTERFACE: &'static str = "zxdg_imported_v2";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgImportedV2Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgImportedV2Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgImportedV2Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgImportedV2Ref {
+    type Owned = ZxdgImportedV2;
+}
+
+impl Deref for ZxdgImportedV2 {
+    type Target = ZxdgImportedV2Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgImportedV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_imported_v2#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgImportedV2Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_imported_v2#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgImportedV2Ref> for ZxdgImportedV2 {
+    fn eq(&self, other: &ZxdgImportedV2Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgImportedV2> for ZxdgImportedV2Ref {
+    fn eq(&self, other: &ZxdgImportedV2) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgImportedV2 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_imported object
+    ///
+    /// Notify the compositor that it will no longer use the xdg_imported
+    /// object. Any relationship that may have been set up will at this point
+    /// be invalidated.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgImportedV2Ref {
+    /// set as the parent of some surface
+    ///
+    /// Set the imported surface as the parent of some surface of the client.
+    /// The passed surface must be an xdg_toplevel equivalent, otherwise an
+    /// invalid_surface protocol error is sent. Calling this function sets up
+    /// a surface to surface relation with the same stacking and positioning
+    /// semantics as xdg_toplevel.set_parent.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the child surface
+    #[inline]
+    pub fn set_parent_of(&self, surface: &WlSurfaceRef) {
+        let (arg0,) = (surface,);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("surface", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `o`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+impl ZxdgImportedV2 {
+    /// Since when the destroyed event is available.
+    #[allow(dead_code)]
+    pub const EVT__DESTROYED__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZxdgImportedV2] proxies.
+#[allow(dead_code)]
+pub trait ZxdgImportedV2EventHandler {
+    /// the imported surface handle has been destroyed
+    ///
+    /// The imported surface handle has been destroyed and any relationship set
+    /// up has been invalidated. This may happen for various reasons, for
+    /// example if the exported surface or the exported surface handle has been
+    /// destroyed, if the handle used for importing was invalid.
+    #[inline]
+    fn destroyed(&self, _slf: &ZxdgImportedV2Ref) {}
+}
+
+impl ZxdgImportedV2EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgImportedV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgImportedV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgImportedV2EventHandlerWithData<T> {
+    /// the imported surface handle has been destroyed
+    ///
+    /// The imported surface handle has been destroyed and any relationship set
+    /// up has been invalidated. This may happen for various reasons, for
+    /// example if the exported surface or the exported surface handle has been
+    /// destroyed, if the handle used for importing was invalid.
+    #[inline]
+    fn destroyed(&self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {}
+}
+
+/// A mutable event handler for [ZxdgImportedV2] proxies.
+///
+/// This trait is the same as [ZxdgImportedV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgImportedV2EventHandlerMut {
+    /// See [ZxdgImportedV2EventHandler::destroyed].
+    #[inline]
+    fn destroyed(&mut self, _slf: &ZxdgImportedV2Ref) {}
+}
+
+/// A mutable event handler for [ZxdgImportedV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgImportedV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgImportedV2EventHandlerMutWithData<T> {
+    /// See [ZxdgImportedV2EventHandler::destroyed].
+    #[inline]
+    fn destroyed(&mut self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {}
+}
+
+impl<H> ZxdgImportedV2EventHandler for MutEventHandler<H>
+where
+    H: ZxdgImportedV2EventHandlerMut,
+{
+    #[inline]
+    fn destroyed(&self, _slf: &ZxdgImportedV2Ref) {
+        self.with(|h| h.destroyed(_slf))
+    }
+}
+
+impl<T, H> ZxdgImportedV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZxdgImportedV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn destroyed(&self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {
+        self.with(|h| h.destroyed(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZxdgImportedV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgImportedV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn destroyed(&self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {
+        let _data = self.project(_data);
+        self.handler().destroyed(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZxdgImportedV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgImportedV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn destroyed(&mut self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().destroyed(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgImportedV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgImportedV2Ref>(slf) };
+        match opcode {
+            0 => {
+                self.0.destroyed(slf);
+            }
+            _ => {
+                invalid_opcode("zxdg_imported_v2", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgImportedV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgImportedV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.destroyed(data, slf);
+            }
+            _ => {
+                invalid_opcode("zxdg_imported_v2", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgImportedV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgImportedV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZxdgImportedV2 {
+    /// Since when the error.invalid_surface enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SURFACE__SINCE: u32 = 1;
+}
+
+/// error values
+///
+/// These errors can be emitted in response to invalid xdg_imported
+/// requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZxdgImportedV2Error(pub u32);
+
+impl ZxdgImportedV2Error {
+    /// surface is not an xdg_toplevel
+    #[allow(dead_code)]
+    pub const INVALID_SURFACE: Self = Self(0);
+}
+
+impl Debug for ZxdgImportedV2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_SURFACE => "INVALID_SURFACE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for destroyed events.
+    pub struct Destroyed<F>(F);
+    impl<F> ZxdgImportedV2EventHandler for Destroyed<F>
+    where
+        F: Fn(&ZxdgImportedV2Ref),
+    {
+        #[inline]
+        fn destroyed(&self, _slf: &ZxdgImportedV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZxdgImportedV2EventHandlerWithData<T> for Destroyed<F>
+    where
+        F: Fn(&mut T, &ZxdgImportedV2Ref),
+    {
+        #[inline]
+        fn destroyed(&self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for destroyed events.
+    pub struct DestroyedMut<F>(F);
+    impl<F> ZxdgImportedV2EventHandlerMut for DestroyedMut<F>
+    where
+        F: FnMut(&ZxdgImportedV2Ref),
+    {
+        #[inline]
+        fn destroyed(&mut self, _slf: &ZxdgImportedV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZxdgImportedV2EventHandlerMutWithData<T> for DestroyedMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgImportedV2Ref),
+    {
+        #[inline]
+        fn destroyed(&mut self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZxdgImportedV2 {
+        /// Creates an event handler for destroyed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_destroyed<F>(f: F) -> Destroyed<F>
+        where
+            F: Fn(&ZxdgImportedV2Ref),
+        {
+            Destroyed(f)
+        }
+
+        /// Creates an event handler with mutable data for destroyed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_destroyed_with_data<T, F>(f: F) -> Destroyed<F>
+        where
+            F: Fn(&mut T, &ZxdgImportedV2Ref),
+        {
+            Destroyed(f)
+        }
+
+        /// Creates a mutable event handler for destroyed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_destroyed_mut<F>(f: F) -> DestroyedMut<F>
+        where
+            F: FnMut(&ZxdgImportedV2Ref),
+        {
+            DestroyedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for destroyed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_destroyed_mut_with_data<T, F>(f: F) -> DestroyedMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgImportedV2Ref),
+        {
+            DestroyedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { destroyed: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZxdgImportedV2EventHandler for Ignore {}
+    impl<T> ZxdgImportedV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZxdgImportedV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        destroyed: H0,
+    }
+
+    impl<H0> ZxdgImportedV2EventHandler for Handler<H0>
+    where
+        H0: ZxdgImportedV2EventHandler,
+    {
+        #[inline]
+        fn destroyed(&self, _slf: &ZxdgImportedV2Ref) {
+            self.destroyed.destroyed(_slf)
+        }
+    }
+
+    impl<T, H0> ZxdgImportedV2EventHandlerWithData<T> for Handler<H0>
+    where
+        H0: ZxdgImportedV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn destroyed(&self, _data: &mut T, _slf: &ZxdgImportedV2Ref) {
+            self.destroyed.destroyed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for destroyed events.
+        #[allow(dead_code)]
+        pub fn on_destroyed<F>(self, f: F) -> Handler<Destroyed<F>>
+        where
+            F: Fn(&ZxdgImportedV2Ref),
+        {
+            Handler {
+                destroyed: Destroyed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for destroyed events.
+        #[allow(dead_code)]
+        pub fn on_destroyed_with_data<T, F>(self, f: F) -> Handler<Destroyed<F>>
+        where
+            F: Fn(&mut T, &ZxdgImportedV2Ref),
+        {
+            Handler {
+                destroyed: Destroyed(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! interface for importing surfaces
+//!
+//! A global interface used for importing surfaces exported by xdg_exporter.
+//! With this interface, a client can create a reference to a surface of
+//! another client.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_importer_v2".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"import_toplevel".as_ptr(),
+                signature: c"ns".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(ZxdgImportedV2::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zxdg_importer_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_importer_v2",
+    protocol: "xdg_foreign_unstable_v2",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "import_toplevel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zxdg_imported_v2"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "handle",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zxdg_importer_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgImporterV2 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_importer_v2 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgImporterV2Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgImporterV2 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgImporterV2 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgImporterV2 {
+    const INTERFACE: &'static str = "zxdg_importer_v2";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZxdgImporterV2Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgImporterV2Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgImporterV2Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgImporterV2Ref {
+    type Owned = ZxdgImporterV2;
+}
+
+impl Deref for ZxdgImporterV2 {
+    type Target = ZxdgImporterV2Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgImporterV2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_importer_v2#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgImporterV2Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_importer_v2#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgImporterV2Ref> for ZxdgImporterV2 {
+    fn eq(&self, other: &ZxdgImporterV2Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgImporterV2> for ZxdgImporterV2Ref {
+    fn eq(&self, other: &ZxdgImporterV2) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgImporterV2 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_importer object
+    ///
+    /// Notify the compositor that the xdg_importer object will no longer be
+    /// used.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the import_toplevel request is available.
+    #[allow(dead_code)]
+    pub const REQ__IMPORT_TOPLEVEL__SINCE: u32 = 1;
+
+    /// import a toplevel surface
+    ///
+    /// The import_toplevel request imports a surface from any client given a handle
+    /// retrieved by exporting said surface using xdg_exporter.export_toplevel.
+    /// When called, a new xdg_imported object will be created. This new object
+    /// represents the imported surface, and the importing client can
+    /// manipulate its relationship using it. See xdg_imported for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `handle`: the exported surface handle
+    #[inline]
+    pub fn import_toplevel(&self, handle: &str) -> ZxdgImportedV2 {
+        let (arg1,) = (handle,);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let mut args = [wl_argument { n: 0 }, wl_argument { s: str1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 2
+            //         - the request signature is `ns`
+            //         - OwnedProxy::WL_INTERFACE is always a valid interface
+            let data = unsafe {
+                self.proxy.send_constructor::<false>(
+                    1,
+                    &mut args,
+                    ZxdgImportedV2::WL_INTERFACE,
+                    None,
+                )
+            };
+            // SAFETY: data has the interface ZxdgImportedV2::WL_INTERFACE
+            unsafe { proxy::low_level::from_untyped_owned(data) }
+        })
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgImporterV2Ref {
+    /// import a toplevel surface
+    ///
+    /// The import_toplevel request imports a surface from any client given a handle
+    /// retrieved by exporting said surface using xdg_exporter.export_toplevel.
+    /// When called, a new xdg_imported object will be created. This new object
+    /// represents the imported surface, and the importing client can
+    /// manipulate its relationship using it. See xdg_imported for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `handle`: the exported surface handle
+    #[inline]
+    pub fn import_toplevel(&self, _queue: &Queue, handle: &str) -> ZxdgImportedV2 {
+        let (arg1,) = (handle,);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let mut args = [wl_argument { n: 0 }, wl_argument { s: str1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 2
+            //         - the request signature is `ns`
+            //         - OwnedProxy::WL_INTERFACE is always a valid interface
+            let data = unsafe {
+                self.proxy.send_constructor(
+                    _queue,
+                    1,
+                    &mut args,
+                    ZxdgImportedV2::WL_INTERFACE,
+                    None,
+                )
+            };
+            // SAFETY: data has the interface ZxdgImportedV2::WL_INTERFACE
+            unsafe { proxy::low_level::from_untyped_owned(data) }
+        })
+    }
+}
+
+/// An event handler for [ZxdgImporterV2] proxies.
+#[allow(dead_code)]
+pub trait ZxdgImporterV2EventHandler {}
+
+impl ZxdgImporterV2EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgImporterV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgImporterV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgImporterV2EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZxdgImporterV2] proxies.
+///
+/// This trait is the same as [ZxdgImporterV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgImporterV2EventHandlerMut {}
+
+/// A mutable event handler for [ZxdgImporterV2] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgImporterV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgImporterV2EventHandlerMutWithData<T> {}
+
+impl<H> ZxdgImporterV2EventHandler for MutEventHandler<H> where H: ZxdgImporterV2EventHandlerMut {}
+
+impl<T, H> ZxdgImporterV2EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZxdgImporterV2EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZxdgImporterV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgImporterV2EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZxdgImporterV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZxdgImporterV2EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgImporterV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_importer_v2", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgImporterV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zxdg_importer_v2", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgImporterV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgImporterV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZxdgImporterV2 {}
+}
@@ -0,0 +1,1680 @@
+//! compositor logical output region
+//!
+//! An xdg_output describes part of the compositor geometry.
+//!
+//! This typically corresponds to a monitor that displays part of the
+//! compositor space.
+//!
+//! For objects version 3 onwards, after all xdg_output properties have been
+//! sent (when the object is created and when properties are updated), a
+//! wl_output.done event is sent. This allows changes to the output
+//! properties to be seen as atomic, even if they happen via multiple events.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zxdg_output_v1".as_ptr(),
+    version: 3,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 5,
+    events: {
+        static MESSAGES: [wl_message; 5] = [
+            wl_message {
+                name: c"logical_position".as_ptr(),
+                signature: c"ii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"logical_size".as_ptr(),
+                signature: c"ii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"name".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"description".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zxdg_output_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zxdg_output_v1",
+    protocol: "xdg_output_unstable_v1",
+    version: 3,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "logical_position",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "logical_size",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: Some(3),
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "name",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "description",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "description",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zxdg_output_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgOutputV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zxdg_output_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZxdgOutputV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZxdgOutputV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZxdgOutputV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZxdgOutputV1 {
+    const INTERFACE: &'static str = "zxdg_output_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 3;
+
+    type Borrowed = ZxdgOutputV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZxdgOutputV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZxdgOutputV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZxdgOutputV1Ref {
+    type Owned = ZxdgOutputV1;
+}
+
+impl Deref for ZxdgOutputV1 {
+    type Target = ZxdgOutputV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZxdgOutputV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_output_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZxdgOutputV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zxdg_output_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZxdgOutputV1Ref> for ZxdgOutputV1 {
+    fn eq(&self, other: &ZxdgOutputV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZxdgOutputV1> for ZxdgOutputV1Ref {
+    fn eq(&self, other: &ZxdgOutputV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZxdgOutputV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_output object
+    ///
+    /// Using this request a client can tell the server that it is not
+    /// going to use the xdg_output object anymore.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+impl ZxdgOutputV1 {
+    /// Since when the logical_position event is available.
+    #[allow(dead_code)]
+    pub const EVT__LOGICAL_POSITION__SINCE: u32 = 1;
+
+    /// Since when the logical_size event is available.
+    #[allow(dead_code)]
+    pub const EVT__LOGICAL_SIZE__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+
+    /// Since when the done event is deprecated.
+    #[allow(dead_code)]
+    pub const EVT__DONE__DEPRECATED_SINCE: u32 = 3;
+
+    /// Since when the name event is available.
+    #[allow(dead_code)]
+    pub const EVT__NAME__SINCE: u32 = 2;
+
+    /// Since when the description event is available.
+    #[allow(dead_code)]
+    pub const EVT__DESCRIPTION__SINCE: u32 = 2;
+}
+
+/// An event handler for [ZxdgOutputV1] proxies.
+#[allow(dead_code)]
+pub trait ZxdgOutputV1EventHandler {
+    /// position of the output within the global compositor space
+    ///
+    /// The position event describes the location of the wl_output within
+    /// the global compositor space.
+    ///
+    /// The logical_position event is sent after creating an xdg_output
+    /// (see xdg_output_manager.get_xdg_output) and whenever the location
+    /// of the output changes within the global compositor space.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: x position within the global compositor space
+    /// - `y`: y position within the global compositor space
+    #[inline]
+    fn logical_position(&self, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// size of the output in the global compositor space
+    ///
+    /// The logical_size event describes the size of the output in the
+    /// global compositor space.
+    ///
+    /// Most regular Wayland clients should not pay attention to the
+    /// logical size and would rather rely on xdg_shell interfaces.
+    ///
+    /// Some clients such as Xwayland, however, need this to configure
+    /// their surfaces in the global compositor space as the compositor
+    /// may apply a different scale from what is advertised by the output
+    /// scaling property (to achieve fractional scaling, for example).
+    ///
+    /// For example, for a wl_output mode 3840×2160 and a scale factor 2:
+    ///
+    /// - A compositor not scaling the monitor viewport in its compositing space
+    ///   will advertise a logical size of 3840×2160,
+    ///
+    /// - A compositor scaling the monitor viewport with scale factor 2 will
+    ///   advertise a logical size of 1920×1080,
+    ///
+    /// - A compositor scaling the monitor viewport using a fractional scale of
+    ///   1.5 will advertise a logical size of 2560×1440.
+    ///
+    /// For example, for a wl_output mode 1920×1080 and a 90 degree rotation,
+    /// the compositor will advertise a logical size of 1080x1920.
+    ///
+    /// The logical_size event is sent after creating an xdg_output
+    /// (see xdg_output_manager.get_xdg_output) and whenever the logical
+    /// size of the output changes, either as a result of a change in the
+    /// applied scale or because of a change in the corresponding output
+    /// mode(see wl_output.mode) or transform (see wl_output.transform).
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: width in global compositor space
+    /// - `height`: height in global compositor space
+    #[inline]
+    fn logical_size(&self, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// all information about the output have been sent
+    ///
+    /// This event is sent after all other properties of an xdg_output
+    /// have been sent.
+    ///
+    /// This allows changes to the xdg_output properties to be seen as
+    /// atomic, even if they happen via multiple events.
+    ///
+    /// For objects version 3 onwards, this event is deprecated. Compositors
+    /// are not required to send it anymore and must send wl_output.done
+    /// instead.
+    #[inline]
+    fn done(&self, _slf: &ZxdgOutputV1Ref) {}
+
+    /// name of this output
+    ///
+    /// Many compositors will assign names to their outputs, show them to the
+    /// user, allow them to be configured by name, etc. The client may wish to
+    /// know this name as well to offer the user similar behaviors.
+    ///
+    /// The naming convention is compositor defined, but limited to
+    /// alphanumeric characters and dashes (-). Each name is unique among all
+    /// wl_output globals, but if a wl_output global is destroyed the same name
+    /// may be reused later. The names will also remain consistent across
+    /// sessions with the same hardware and software configuration.
+    ///
+    /// Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+    /// not assume that the name is a reflection of an underlying DRM
+    /// connector, X11 connection, etc.
+    ///
+    /// The name event is sent after creating an xdg_output (see
+    /// xdg_output_manager.get_xdg_output). This event is only sent once per
+    /// xdg_output, and the name does not change over the lifetime of the
+    /// wl_output global.
+    ///
+    ///         This event is deprecated, instead clients should use wl_output.name.
+    ///         Compositors must still support this event.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: output name
+    #[inline]
+    fn name(&self, _slf: &ZxdgOutputV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// human-readable description of this output
+    ///
+    /// Many compositors can produce human-readable descriptions of their
+    /// outputs.  The client may wish to know this description as well, to
+    /// communicate the user for various purposes.
+    ///
+    /// The description is a UTF-8 string with no convention defined for its
+    /// contents. Examples might include 'Foocorp 11" Display' or 'Virtual X11
+    /// output via :1'.
+    ///
+    /// The description event is sent after creating an xdg_output (see
+    /// xdg_output_manager.get_xdg_output) and whenever the description
+    /// changes. The description is optional, and may not be sent at all.
+    ///
+    /// For objects of version 2 and lower, this event is only sent once per
+    /// xdg_output, and the description does not change over the lifetime of
+    /// the wl_output global.
+    ///
+    /// This event is deprecated, instead clients should use
+    /// wl_output.description. Compositors must still support this event.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: output description
+    #[inline]
+    fn description(&self, _slf: &ZxdgOutputV1Ref, description: &str) {
+        let _ = description;
+    }
+}
+
+impl ZxdgOutputV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZxdgOutputV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgOutputV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZxdgOutputV1EventHandlerWithData<T> {
+    /// position of the output within the global compositor space
+    ///
+    /// The position event describes the location of the wl_output within
+    /// the global compositor space.
+    ///
+    /// The logical_position event is sent after creating an xdg_output
+    /// (see xdg_output_manager.get_xdg_output) and whenever the location
+    /// of the output changes within the global compositor space.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: x position within the global compositor space
+    /// - `y`: y position within the global compositor space
+    #[inline]
+    fn logical_position(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// size of the output in the global compositor space
+    ///
+    /// The logical_size event describes the size of the output in the
+    /// global compositor space.
+    ///
+    /// Most regular Wayland clients should not pay attention to the
+    /// logical size and would rather rely on xdg_shell interfaces.
+    ///
+    /// Some clients such as Xwayland, however, need this to configure
+    /// their surfaces in the global compositor space as the compositor
+    /// may apply a different scale from what is advertised by the output
+    /// scaling property (to achieve fractional scaling, for example).
+    ///
+    /// For example, for a wl_output mode 3840×2160 and a scale factor 2:
+    ///
+    /// - A compositor not scaling the monitor viewport in its compositing space
+    ///   will advertise a logical size of 3840×2160,
+    ///
+    /// - A compositor scaling the monitor viewport with scale factor 2 will
+    ///   advertise a logical size of 1920×1080,
+    ///
+    /// - A compositor scaling the monitor viewport using a fractional scale of
+    ///   1.5 will advertise a logical size of 2560×1440.
+    ///
+    /// For example, for a wl_output mode 1920×1080 and a 90 degree rotation,
+    /// the compositor will advertise a logical size of 1080x1920.
+    ///
+    /// The logical_size event is sent after creating an xdg_output
+    /// (see xdg_output_manager.get_xdg_output) and whenever the logical
+    /// size of the output changes, either as a result of a change in the
+    /// applied scale or because of a change in the corresponding output
+    /// mode(see wl_output.mode) or transform (see wl_output.transform).
+    ///
+    /// # Arguments
+    ///
+    /// - `width`: width in global compositor space
+    /// - `height`: height in global compositor space
+    #[inline]
+    fn logical_size(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// all information about the output have been sent
+    ///
+    /// This event is sent after all other properties of an xdg_output
+    /// have been sent.
+    ///
+    /// This allows changes to the xdg_output properties to be seen as
+    /// atomic, even if they happen via multiple events.
+    ///
+    /// For objects version 3 onwards, this event is deprecated. Compositors
+    /// are not required to send it anymore and must send wl_output.done
+    /// instead.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {}
+
+    /// name of this output
+    ///
+    /// Many compositors will assign names to their outputs, show them to the
+    /// user, allow them to be configured by name, etc. The client may wish to
+    /// know this name as well to offer the user similar behaviors.
+    ///
+    /// The naming convention is compositor defined, but limited to
+    /// alphanumeric characters and dashes (-). Each name is unique among all
+    /// wl_output globals, but if a wl_output global is destroyed the same name
+    /// may be reused later. The names will also remain consistent across
+    /// sessions with the same hardware and software configuration.
+    ///
+    /// Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+    /// not assume that the name is a reflection of an underlying DRM
+    /// connector, X11 connection, etc.
+    ///
+    /// The name event is sent after creating an xdg_output (see
+    /// xdg_output_manager.get_xdg_output). This event is only sent once per
+    /// xdg_output, and the name does not change over the lifetime of the
+    /// wl_output global.
+    ///
+    ///         This event is deprecated, instead clients should use wl_output.name.
+    ///         Compositors must still support this event.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: output name
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// human-readable description of this output
+    ///
+    /// Many compositors can produce human-readable descriptions of their
+    /// outputs.  The client may wish to know this description as well, to
+    /// communicate the user for various purposes.
+    ///
+    /// The description is a UTF-8 string with no convention defined for its
+    /// contents. Examples might include 'Foocorp 11" Display' or 'Virtual X11
+    /// output via :1'.
+    ///
+    /// The description event is sent after creating an xdg_output (see
+    /// xdg_output_manager.get_xdg_output) and whenever the description
+    /// changes. The description is optional, and may not be sent at all.
+    ///
+    /// For objects of version 2 and lower, this event is only sent once per
+    /// xdg_output, and the description does not change over the lifetime of
+    /// the wl_output global.
+    ///
+    /// This event is deprecated, instead clients should use
+    /// wl_output.description. Compositors must still support this event.
+    ///
+    /// # Arguments
+    ///
+    /// - `description`: output description
+    #[inline]
+    fn description(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+        let _ = description;
+    }
+}
+
+/// A mutable event handler for [ZxdgOutputV1] proxies.
+///
+/// This trait is the same as [ZxdgOutputV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgOutputV1EventHandlerMut {
+    /// See [ZxdgOutputV1EventHandler::logical_position].
+    #[inline]
+    fn logical_position(&mut self, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// See [ZxdgOutputV1EventHandler::logical_size].
+    #[inline]
+    fn logical_size(&mut self, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [ZxdgOutputV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ZxdgOutputV1Ref) {}
+
+    /// See [ZxdgOutputV1EventHandler::name].
+    #[inline]
+    fn name(&mut self, _slf: &ZxdgOutputV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [ZxdgOutputV1EventHandler::description].
+    #[inline]
+    fn description(&mut self, _slf: &ZxdgOutputV1Ref, description: &str) {
+        let _ = description;
+    }
+}
+
+/// A mutable event handler for [ZxdgOutputV1] proxies with mutable data.
+///
+/// This trait is the same as [ZxdgOutputV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZxdgOutputV1EventHandlerMutWithData<T> {
+    /// See [ZxdgOutputV1EventHandler::logical_position].
+    #[inline]
+    fn logical_position(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// See [ZxdgOutputV1EventHandler::logical_size].
+    #[inline]
+    fn logical_size(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [ZxdgOutputV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {}
+
+    /// See [ZxdgOutputV1EventHandler::name].
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [ZxdgOutputV1EventHandler::description].
+    #[inline]
+    fn description(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+        let _ = description;
+    }
+}
+
+impl<H> ZxdgOutputV1EventHandler for MutEventHandler<H>
+where
+    H: ZxdgOutputV1EventHandlerMut,
+{
+    #[inline]
+    fn logical_position(&self, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        self.with(|h| h.logical_position(_slf, x, y))
+    }
+
+    #[inline]
+    fn logical_size(&self, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        self.with(|h| h.logical_size(_slf, width, height))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ZxdgOutputV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn name(&self, _slf: &ZxdgOutputV1Ref, name: &str) {
+        self.with(|h| h.name(_slf, name))
+    }
+
+    #[inline]
+    fn description(&self, _slf: &ZxdgOutputV1Ref, description: &str) {
+        self.with(|h| h.description(_slf, description))
+    }
+}
+
+impl<T, H> ZxdgOutputV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZxdgOutputV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn logical_position(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        self.with(|h| h.logical_position(_data, _slf, x, y))
+    }
+
+    #[inline]
+    fn logical_size(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        self.with(|h| h.logical_size(_data, _slf, width, height))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+        self.with(|h| h.name(_data, _slf, name))
+    }
+
+    #[inline]
+    fn description(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+        self.with(|h| h.description(_data, _slf, description))
+    }
+}
+
+impl<T, U, H> ZxdgOutputV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgOutputV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn logical_position(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        let _data = self.project(_data);
+        self.handler().logical_position(_data, _slf, x, y)
+    }
+
+    #[inline]
+    fn logical_size(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        let _data = self.project(_data);
+        self.handler().logical_size(_data, _slf, width, height)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn description(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+        let _data = self.project(_data);
+        self.handler().description(_data, _slf, description)
+    }
+}
+
+impl<T, U, H> ZxdgOutputV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZxdgOutputV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn logical_position(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().logical_position(_data, _slf, x, y)
+    }
+
+    #[inline]
+    fn logical_size(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().logical_size(_data, _slf, width, height)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn description(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().description(_data, _slf, description)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZxdgOutputV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgOutputV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.logical_position(slf, arg0, arg1);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.logical_size(slf, arg0, arg1);
+            }
+            2 => {
+                self.0.done(slf);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("zxdg_output_v1", "name", args[0].s) };
+                self.0.name(slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("zxdg_output_v1", "description", args[0].s) };
+                self.0.description(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zxdg_output_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZxdgOutputV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZxdgOutputV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.logical_position(data, slf, arg0, arg1);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.logical_size(data, slf, arg0, arg1);
+            }
+            2 => {
+                self.0.done(data, slf);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("zxdg_output_v1", "name", args[0].s) };
+                self.0.name(data, slf, arg0);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 =
+                    unsafe { convert_string_arg("zxdg_output_v1", "description", args[0].s) };
+                self.0.description(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zxdg_output_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZxdgOutputV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZxdgOutputV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for logical_position events.
+    pub struct LogicalPosition<F>(F);
+    impl<F> ZxdgOutputV1EventHandler for LogicalPosition<F>
+    where
+        F: Fn(&ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_position(&self, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerWithData<T> for LogicalPosition<F>
+    where
+        F: Fn(&mut T, &ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_position(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Event handler for logical_size events.
+    pub struct LogicalSize<F>(F);
+    impl<F> ZxdgOutputV1EventHandler for LogicalSize<F>
+    where
+        F: Fn(&ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_size(&self, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+            self.0(_slf, width, height)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerWithData<T> for LogicalSize<F>
+    where
+        F: Fn(&mut T, &ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_size(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+            self.0(_data, _slf, width, height)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ZxdgOutputV1EventHandler for Done<F>
+    where
+        F: Fn(&ZxdgOutputV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &ZxdgOutputV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ZxdgOutputV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for name events.
+    pub struct Name<F>(F);
+    impl<F> ZxdgOutputV1EventHandler for Name<F>
+    where
+        F: Fn(&ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _slf: &ZxdgOutputV1Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerWithData<T> for Name<F>
+    where
+        F: Fn(&mut T, &ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Event handler for description events.
+    pub struct Description<F>(F);
+    impl<F> ZxdgOutputV1EventHandler for Description<F>
+    where
+        F: Fn(&ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn description(&self, _slf: &ZxdgOutputV1Ref, description: &str) {
+            self.0(_slf, description)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerWithData<T> for Description<F>
+    where
+        F: Fn(&mut T, &ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn description(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+            self.0(_data, _slf, description)
+        }
+    }
+
+    /// Mutable event handler for logical_position events.
+    pub struct LogicalPositionMut<F>(F);
+    impl<F> ZxdgOutputV1EventHandlerMut for LogicalPositionMut<F>
+    where
+        F: FnMut(&ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_position(&mut self, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerMutWithData<T> for LogicalPositionMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_position(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Mutable event handler for logical_size events.
+    pub struct LogicalSizeMut<F>(F);
+    impl<F> ZxdgOutputV1EventHandlerMut for LogicalSizeMut<F>
+    where
+        F: FnMut(&ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_size(&mut self, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+            self.0(_slf, width, height)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerMutWithData<T> for LogicalSizeMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgOutputV1Ref, i32, i32),
+    {
+        #[inline]
+        fn logical_size(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+            self.0(_data, _slf, width, height)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ZxdgOutputV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ZxdgOutputV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ZxdgOutputV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgOutputV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for name events.
+    pub struct NameMut<F>(F);
+    impl<F> ZxdgOutputV1EventHandlerMut for NameMut<F>
+    where
+        F: FnMut(&ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _slf: &ZxdgOutputV1Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerMutWithData<T> for NameMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Mutable event handler for description events.
+    pub struct DescriptionMut<F>(F);
+    impl<F> ZxdgOutputV1EventHandlerMut for DescriptionMut<F>
+    where
+        F: FnMut(&ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn description(&mut self, _slf: &ZxdgOutputV1Ref, description: &str) {
+            self.0(_slf, description)
+        }
+    }
+    impl<T, F> ZxdgOutputV1EventHandlerMutWithData<T> for DescriptionMut<F>
+    where
+        F: FnMut(&mut T, &ZxdgOutputV1Ref, &str),
+    {
+        #[inline]
+        fn description(&mut self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+            self.0(_data, _slf, description)
+        }
+    }
+
+    impl ZxdgOutputV1 {
+        /// Creates an event handler for logical_position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_logical_position<F>(f: F) -> LogicalPosition<F>
+        where
+            F: Fn(&ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalPosition(f)
+        }
+
+        /// Creates an event handler with mutable data for logical_position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_logical_position_with_data<T, F>(f: F) -> LogicalPosition<F>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalPosition(f)
+        }
+
+        /// Creates an event handler for logical_size events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_logical_size<F>(f: F) -> LogicalSize<F>
+        where
+            F: Fn(&ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalSize(f)
+        }
+
+        /// Creates an event handler with mutable data for logical_size events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_logical_size_with_data<T, F>(f: F) -> LogicalSize<F>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalSize(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ZxdgOutputV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(f: F) -> Name<F>
+        where
+            F: Fn(&ZxdgOutputV1Ref, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates an event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(f: F) -> Name<F>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates an event handler for description events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_description<F>(f: F) -> Description<F>
+        where
+            F: Fn(&ZxdgOutputV1Ref, &str),
+        {
+            Description(f)
+        }
+
+        /// Creates an event handler with mutable data for description events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_description_with_data<T, F>(f: F) -> Description<F>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, &str),
+        {
+            Description(f)
+        }
+
+        /// Creates a mutable event handler for logical_position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_logical_position_mut<F>(f: F) -> LogicalPositionMut<F>
+        where
+            F: FnMut(&ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalPositionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for logical_position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_logical_position_mut_with_data<T, F>(f: F) -> LogicalPositionMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalPositionMut(f)
+        }
+
+        /// Creates a mutable event handler for logical_size events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_logical_size_mut<F>(f: F) -> LogicalSizeMut<F>
+        where
+            F: FnMut(&ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalSizeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for logical_size events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_logical_size_mut_with_data<T, F>(f: F) -> LogicalSizeMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgOutputV1Ref, i32, i32),
+        {
+            LogicalSizeMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ZxdgOutputV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgOutputV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut<F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&ZxdgOutputV1Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut_with_data<T, F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgOutputV1Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler for description events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_description_mut<F>(f: F) -> DescriptionMut<F>
+        where
+            F: FnMut(&ZxdgOutputV1Ref, &str),
+        {
+            DescriptionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for description events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_description_mut_with_data<T, F>(f: F) -> DescriptionMut<F>
+        where
+            F: FnMut(&mut T, &ZxdgOutputV1Ref, &str),
+        {
+            DescriptionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                logical_position: Ignore,
+                logical_size: Ignore,
+                done: Ignore,
+                name: Ignore,
+                description: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZxdgOutputV1EventHandler for Ignore {}
+    impl<T> ZxdgOutputV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZxdgOutputV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore> {
+        logical_position: H0,
+        logical_size: H1,
+        done: H2,
+        name: H3,
+        description: H4,
+    }
+
+    impl<H0, H1, H2, H3, H4> ZxdgOutputV1EventHandler for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ZxdgOutputV1EventHandler,
+        H1: ZxdgOutputV1EventHandler,
+        H2: ZxdgOutputV1EventHandler,
+        H3: ZxdgOutputV1EventHandler,
+        H4: ZxdgOutputV1EventHandler,
+    {
+        #[inline]
+        fn logical_position(&self, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+            self.logical_position.logical_position(_slf, x, y)
+        }
+
+        #[inline]
+        fn logical_size(&self, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+            self.logical_size.logical_size(_slf, width, height)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ZxdgOutputV1Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn name(&self, _slf: &ZxdgOutputV1Ref, name: &str) {
+            self.name.name(_slf, name)
+        }
+
+        #[inline]
+        fn description(&self, _slf: &ZxdgOutputV1Ref, description: &str) {
+            self.description.description(_slf, description)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4> ZxdgOutputV1EventHandlerWithData<T> for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ZxdgOutputV1EventHandlerWithData<T>,
+        H1: ZxdgOutputV1EventHandlerWithData<T>,
+        H2: ZxdgOutputV1EventHandlerWithData<T>,
+        H3: ZxdgOutputV1EventHandlerWithData<T>,
+        H4: ZxdgOutputV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn logical_position(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, x: i32, y: i32) {
+            self.logical_position.logical_position(_data, _slf, x, y)
+        }
+
+        #[inline]
+        fn logical_size(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, width: i32, height: i32) {
+            self.logical_size.logical_size(_data, _slf, width, height)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, name: &str) {
+            self.name.name(_data, _slf, name)
+        }
+
+        #[inline]
+        fn description(&self, _data: &mut T, _slf: &ZxdgOutputV1Ref, description: &str) {
+            self.description.description(_data, _slf, description)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4> Handler<H0, H1, H2, H3, H4> {
+        /// Sets the closure for logical_position events.
+        #[allow(dead_code)]
+        pub fn on_logical_position<F>(self, f: F) -> Handler<LogicalPosition<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&ZxdgOutputV1Ref, i32, i32),
+        {
+            Handler {
+                logical_position: LogicalPosition(f),
+                logical_size: self.logical_size,
+                done: self.done,
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for logical_position events.
+        #[allow(dead_code)]
+        pub fn on_logical_position_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<LogicalPosition<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, i32, i32),
+        {
+            Handler {
+                logical_position: LogicalPosition(f),
+                logical_size: self.logical_size,
+                done: self.done,
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure for logical_size events.
+        #[allow(dead_code)]
+        pub fn on_logical_size<F>(self, f: F) -> Handler<H0, LogicalSize<F>, H2, H3, H4>
+        where
+            F: Fn(&ZxdgOutputV1Ref, i32, i32),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: LogicalSize(f),
+                done: self.done,
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for logical_size events.
+        #[allow(dead_code)]
+        pub fn on_logical_size_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, LogicalSize<F>, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, i32, i32),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: LogicalSize(f),
+                done: self.done,
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, Done<F>, H3, H4>
+        where
+            F: Fn(&ZxdgOutputV1Ref),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: self.logical_size,
+                done: Done(f),
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, Done<F>, H3, H4>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: self.logical_size,
+                done: Done(f),
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure for name events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(self, f: F) -> Handler<H0, H1, H2, Name<F>, H4>
+        where
+            F: Fn(&ZxdgOutputV1Ref, &str),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: self.logical_size,
+                done: self.done,
+                name: Name(f),
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for name events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Name<F>, H4>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, &str),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: self.logical_size,
+                done: self.done,
+                name: Name(f),
+                description: self.description,
+            }
+        }
+
+        /// Sets the closure for description events.
+        #[allow(dead_code)]
+        pub fn on_description<F>(self, f: F) -> Handler<H0, H1, H2, H3, Description<F>>
+        where
+            F: Fn(&ZxdgOutputV1Ref, &str),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: self.logical_size,
+                done: self.done,
+                name: self.name,
+                description: Description(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for description events.
+        #[allow(dead_code)]
+        pub fn on_description_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Description<F>>
+        where
+            F: Fn(&mut T, &ZxdgOutputV1Ref, &str),
+        {
+            Handler {
+                logical_position: self.logical_position,
+                logical_size: self.logical_size,
+                done: self.done,
+                name: self.name,
+                description: Description(f),
+            }
+        }
+    }
+}
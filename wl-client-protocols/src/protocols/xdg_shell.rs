@@ -13,3 +13,16 @@ pub mod xdg_positioner;
 pub mod xdg_surface;
 pub mod xdg_toplevel;
 pub mod xdg_wm_base;
+
+/// Metadata about the xdg_shell protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "xdg_shell",
+    interfaces: &[
+        &xdg_wm_base::INFO,
+        &xdg_positioner::INFO,
+        &xdg_surface::INFO,
+        &xdg_toplevel::INFO,
+        &xdg_popup::INFO,
+    ],
+};
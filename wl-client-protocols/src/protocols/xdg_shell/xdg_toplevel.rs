@@ -26,7 +26,7 @@ use {super::super::all_types::*, ::wl_client::builder::prelude::*};
 
 static INTERFACE: wl_interface = wl_interface {
     name: c"xdg_toplevel".as_ptr(),
-    version: 6,
+    version: 7,
     method_count: 14,
     methods: {
         static MESSAGES: [wl_message; 14] = [
@@ -190,6 +190,511 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the xdg_toplevel interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_toplevel",
+    protocol: "xdg_shell",
+    version: 7,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_parent",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "parent",
+                ty: info::ArgType::Object,
+                interface: Some("xdg_toplevel"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_title",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "title",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_app_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "app_id",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "show_window_menu",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "move",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "resize",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "edges",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("resize_edge"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_max_size",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_min_size",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_maximized",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "unset_maximized",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_fullscreen",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "unset_fullscreen",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_minimized",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "configure",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "states",
+                    ty: info::ArgType::Array,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "close",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "configure_bounds",
+            since: 4,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "wm_capabilities",
+            since: 5,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "capabilities",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "invalid_resize_edge",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_parent",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "invalid_size",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "resize_edge",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "none",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "top",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "left",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "top_left",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom_left",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "right",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "top_right",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom_right",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "state",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "maximized",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "fullscreen",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "resizing",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "activated",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "tiled_left",
+                    value: 5,
+                    since: 2,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "tiled_right",
+                    value: 6,
+                    since: 2,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "tiled_top",
+                    value: 7,
+                    since: 2,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "tiled_bottom",
+                    value: 8,
+                    since: 2,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "suspended",
+                    value: 9,
+                    since: 6,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "constrained_left",
+                    value: 10,
+                    since: 7,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "constrained_right",
+                    value: 11,
+                    since: 7,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "constrained_top",
+                    value: 12,
+                    since: 7,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "constrained_bottom",
+                    value: 13,
+                    since: 7,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "wm_capabilities",
+            since: 5,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "window_menu",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "maximize",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "fullscreen",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "minimize",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
 /// An owned xdg_toplevel proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -220,7 +725,7 @@ unsafe impl OwnedProxy for XdgToplevel {
     const WL_INTERFACE: &'static wl_interface = &INTERFACE;
     const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
         private::EventHandler(private::NoOpEventHandler);
-    const MAX_VERSION: u32 = 6;
+    const MAX_VERSION: u32 = 7;
 
     type Borrowed = XdgToplevelRef;
     type Api = private::ProxyApi;
@@ -244,11 +749,16 @@ impl Deref for XdgToplevel {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -843,8 +1353,122 @@ impl XdgToplevel {
 /// An event handler for [XdgToplevel] proxies.
 #[allow(dead_code)]
 pub trait XdgToplevelEventHandler {
-    type Data: 'static;
+    /// suggest a surface change
+    ///
+    /// This configure event asks the client to resize its toplevel surface or
+    /// to change its state. The configured state should not be applied
+    /// immediately. See xdg_surface.configure for details.
+    ///
+    /// The width and height arguments specify a hint to the window
+    /// about how its surface should be resized in window geometry
+    /// coordinates. See set_window_geometry.
+    ///
+    /// If the width or height arguments are zero, it means the client
+    /// should decide its own window dimension. This may happen when the
+    /// compositor needs to configure the state of the surface but doesn't
+    /// have any information about any previous or expected dimension.
+    ///
+    /// The states listed in the event specify how the width/height
+    /// arguments should be interpreted, and possibly how it should be
+    /// drawn.
+    ///
+    /// Clients must send an ack_configure in response to this event. See
+    /// xdg_surface.configure and xdg_surface.ack_configure for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`:
+    /// - `height`:
+    /// - `states`:
+    #[inline]
+    fn configure(&self, _slf: &XdgToplevelRef, width: i32, height: i32, states: &[u8]) {
+        let _ = width;
+        let _ = height;
+        let _ = states;
+    }
+
+    /// surface wants to be closed
+    ///
+    /// The close event is sent by the compositor when the user
+    /// wants the surface to be closed. This should be equivalent to
+    /// the user clicking the close button in client-side decorations,
+    /// if your application has any.
+    ///
+    /// This is only a request that the user intends to close the
+    /// window. The client may choose to ignore this request, or show
+    /// a dialog to ask the user to save their data, etc.
+    #[inline]
+    fn close(&self, _slf: &XdgToplevelRef) {}
+
+    /// recommended window geometry bounds
+    ///
+    /// The configure_bounds event may be sent prior to a xdg_toplevel.configure
+    /// event to communicate the bounds a window geometry size is recommended
+    /// to constrain to.
+    ///
+    /// The passed width and height are in surface coordinate space. If width
+    /// and height are 0, it means bounds is unknown and equivalent to as if no
+    /// configure_bounds event was ever sent for this surface.
+    ///
+    /// The bounds can for example correspond to the size of a monitor excluding
+    /// any panels or other shell components, so that a surface isn't created in
+    /// a way that it cannot fit.
+    ///
+    /// The bounds may change at any point, and in such a case, a new
+    /// xdg_toplevel.configure_bounds will be sent, followed by
+    /// xdg_toplevel.configure and xdg_surface.configure.
+    ///
+    /// # Arguments
+    ///
+    /// - `width`:
+    /// - `height`:
+    #[inline]
+    fn configure_bounds(&self, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// compositor capabilities
+    ///
+    /// This event advertises the capabilities supported by the compositor. If
+    /// a capability isn't supported, clients should hide or disable the UI
+    /// elements that expose this functionality. For instance, if the
+    /// compositor doesn't advertise support for minimized toplevels, a button
+    /// triggering the set_minimized request should not be displayed.
+    ///
+    /// The compositor will ignore requests it doesn't support. For instance,
+    /// a compositor which doesn't advertise support for minimized will ignore
+    /// set_minimized requests.
+    ///
+    /// Compositors must send this event once before the first
+    /// xdg_surface.configure event. When the capabilities change, compositors
+    /// must send this event again and then send an xdg_surface.configure
+    /// event.
+    ///
+    /// The configured state should not be applied immediately. See
+    /// xdg_surface.configure for details.
+    ///
+    /// The capabilities are sent as an array of 32-bit unsigned integers in
+    /// native endianness.
+    ///
+    /// # Arguments
+    ///
+    /// - `capabilities`: array of 32-bit capabilities
+    #[inline]
+    fn wm_capabilities(&self, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        let _ = capabilities;
+    }
+}
+
+impl XdgToplevelEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [XdgToplevel] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgToplevelEventHandlerWithData<T> {
     /// suggest a surface change
     ///
     /// This configure event asks the client to resize its toplevel surface or
@@ -875,7 +1499,7 @@ pub trait XdgToplevelEventHandler {
     #[inline]
     fn configure(
         &self,
-        _data: &mut Self::Data,
+        _data: &mut T,
         _slf: &XdgToplevelRef,
         width: i32,
         height: i32,
@@ -897,7 +1521,7 @@ pub trait XdgToplevelEventHandler {
     /// window. The client may choose to ignore this request, or show
     /// a dialog to ask the user to save their data, etc.
     #[inline]
-    fn close(&self, _data: &mut Self::Data, _slf: &XdgToplevelRef) {}
+    fn close(&self, _data: &mut T, _slf: &XdgToplevelRef) {}
 
     /// recommended window geometry bounds
     ///
@@ -922,13 +1546,7 @@ pub trait XdgToplevelEventHandler {
     /// - `width`:
     /// - `height`:
     #[inline]
-    fn configure_bounds(
-        &self,
-        _data: &mut Self::Data,
-        _slf: &XdgToplevelRef,
-        width: i32,
-        height: i32,
-    ) {
+    fn configure_bounds(&self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
         let _ = width;
         let _ = height;
     }
@@ -960,94 +1578,357 @@ pub trait XdgToplevelEventHandler {
     ///
     /// - `capabilities`: array of 32-bit capabilities
     #[inline]
-    fn wm_capabilities(&self, _data: &mut Self::Data, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+    fn wm_capabilities(&self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
         let _ = capabilities;
     }
 }
 
-impl XdgToplevelEventHandler for private::NoOpEventHandler {
-    type Data = ();
-}
+/// A mutable event handler for [XdgToplevel] proxies.
+///
+/// This trait is the same as [XdgToplevelEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelEventHandlerMut {
+    /// See [XdgToplevelEventHandler::configure].
+    #[inline]
+    fn configure(&mut self, _slf: &XdgToplevelRef, width: i32, height: i32, states: &[u8]) {
+        let _ = width;
+        let _ = height;
+        let _ = states;
+    }
 
-// SAFETY: - INTERFACE is a valid wl_interface
-//         - mutable_type always returns the same value
-unsafe impl<H> EventHandler for private::EventHandler<H>
-where
-    H: XdgToplevelEventHandler,
-{
-    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    /// See [XdgToplevelEventHandler::close].
+    #[inline]
+    fn close(&mut self, _slf: &XdgToplevelRef) {}
 
+    /// See [XdgToplevelEventHandler::configure_bounds].
     #[inline]
-    fn mutable_type() -> Option<(TypeId, &'static str)> {
-        let id = TypeId::of::<H::Data>();
-        let name = std::any::type_name::<H::Data>();
-        Some((id, name))
+    fn configure_bounds(&mut self, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
     }
 
-    #[allow(unused_variables)]
-    unsafe fn handle_event(
-        &self,
-        queue: &Queue,
-        data: *mut u8,
-        slf: &UntypedBorrowedProxy,
-        opcode: u32,
-        args: *mut wl_argument,
-    ) {
-        // SAFETY: This function requires that slf has the interface INTERFACE
-        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<XdgToplevelRef>(slf) };
-        // SAFETY: This function requires that data is `&mut T` where `T`
-        //         has the type id returned by `Self::mutable_type`, i.e.,
-        //         `T = H::Data`.
-        let data: &mut H::Data = unsafe { &mut *data.cast() };
-        match opcode {
-            0 => {
-                // SAFETY: INTERFACE requires that there are 3 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an int
-                let arg0 = unsafe { args[0].i };
-                // SAFETY: - INTERFACE requires that args[1] contains an int
-                let arg1 = unsafe { args[1].i };
-                // SAFETY: - INTERFACE requires that args[2] contains an array
-                let arg2 = unsafe {
-                    let a = &*args[2].a;
-                    std::slice::from_raw_parts(a.data.cast(), a.size)
-                };
-                self.0.configure(data, slf, arg0, arg1, arg2);
-            }
-            1 => {
-                self.0.close(data, slf);
-            }
-            2 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an int
-                let arg0 = unsafe { args[0].i };
-                // SAFETY: - INTERFACE requires that args[1] contains an int
-                let arg1 = unsafe { args[1].i };
-                self.0.configure_bounds(data, slf, arg0, arg1);
-            }
-            3 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an array
-                let arg0 = unsafe {
-                    let a = &*args[0].a;
-                    std::slice::from_raw_parts(a.data.cast(), a.size)
-                };
-                self.0.wm_capabilities(data, slf, arg0);
-            }
-            _ => {
-                invalid_opcode("xdg_toplevel", opcode);
-            }
-        }
+    /// See [XdgToplevelEventHandler::wm_capabilities].
+    #[inline]
+    fn wm_capabilities(&mut self, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        let _ = capabilities;
     }
 }
 
-impl<H> CreateEventHandler<H> for private::ProxyApi
-where
-    H: XdgToplevelEventHandler,
-{
-    type EventHandler = private::EventHandler<H>;
+/// A mutable event handler for [XdgToplevel] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelEventHandlerMutWithData<T> {
+    /// See [XdgToplevelEventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &XdgToplevelRef,
+        width: i32,
+        height: i32,
+        states: &[u8],
+    ) {
+        let _ = width;
+        let _ = height;
+        let _ = states;
+    }
+
+    /// See [XdgToplevelEventHandler::close].
+    #[inline]
+    fn close(&mut self, _data: &mut T, _slf: &XdgToplevelRef) {}
+
+    /// See [XdgToplevelEventHandler::configure_bounds].
+    #[inline]
+    fn configure_bounds(&mut self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [XdgToplevelEventHandler::wm_capabilities].
+    #[inline]
+    fn wm_capabilities(&mut self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        let _ = capabilities;
+    }
+}
+
+impl<H> XdgToplevelEventHandler for MutEventHandler<H>
+where
+    H: XdgToplevelEventHandlerMut,
+{
+    #[inline]
+    fn configure(&self, _slf: &XdgToplevelRef, width: i32, height: i32, states: &[u8]) {
+        self.with(|h| h.configure(_slf, width, height, states))
+    }
+
+    #[inline]
+    fn close(&self, _slf: &XdgToplevelRef) {
+        self.with(|h| h.close(_slf))
+    }
+
+    #[inline]
+    fn configure_bounds(&self, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        self.with(|h| h.configure_bounds(_slf, width, height))
+    }
+
+    #[inline]
+    fn wm_capabilities(&self, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        self.with(|h| h.wm_capabilities(_slf, capabilities))
+    }
+}
+
+impl<T, H> XdgToplevelEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: XdgToplevelEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &XdgToplevelRef,
+        width: i32,
+        height: i32,
+        states: &[u8],
+    ) {
+        self.with(|h| h.configure(_data, _slf, width, height, states))
+    }
+
+    #[inline]
+    fn close(&self, _data: &mut T, _slf: &XdgToplevelRef) {
+        self.with(|h| h.close(_data, _slf))
+    }
+
+    #[inline]
+    fn configure_bounds(&self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        self.with(|h| h.configure_bounds(_data, _slf, width, height))
+    }
+
+    #[inline]
+    fn wm_capabilities(&self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        self.with(|h| h.wm_capabilities(_data, _slf, capabilities))
+    }
+}
+
+impl<T, U, H> XdgToplevelEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgToplevelEventHandlerWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &XdgToplevelRef,
+        width: i32,
+        height: i32,
+        states: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler().configure(_data, _slf, width, height, states)
+    }
+
+    #[inline]
+    fn close(&self, _data: &mut T, _slf: &XdgToplevelRef) {
+        let _data = self.project(_data);
+        self.handler().close(_data, _slf)
+    }
+
+    #[inline]
+    fn configure_bounds(&self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        let _data = self.project(_data);
+        self.handler().configure_bounds(_data, _slf, width, height)
+    }
+
+    #[inline]
+    fn wm_capabilities(&self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().wm_capabilities(_data, _slf, capabilities)
+    }
+}
+
+impl<T, U, H> XdgToplevelEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgToplevelEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &XdgToplevelRef,
+        width: i32,
+        height: i32,
+        states: &[u8],
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .configure(_data, _slf, width, height, states)
+    }
+
+    #[inline]
+    fn close(&mut self, _data: &mut T, _slf: &XdgToplevelRef) {
+        let _data = self.project(_data);
+        self.handler_mut().close(_data, _slf)
+    }
+
+    #[inline]
+    fn configure_bounds(&mut self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .configure_bounds(_data, _slf, width, height)
+    }
+
+    #[inline]
+    fn wm_capabilities(&mut self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .wm_capabilities(_data, _slf, capabilities)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgToplevelEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<XdgToplevelRef>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an array
+                let arg2 = unsafe {
+                    let a = &*args[2].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.configure(slf, arg0, arg1, arg2);
+            }
+            1 => {
+                self.0.close(slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.configure_bounds(slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.wm_capabilities(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_toplevel", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgToplevelEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<XdgToplevelRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an array
+                let arg2 = unsafe {
+                    let a = &*args[2].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.configure(data, slf, arg0, arg1, arg2);
+            }
+            1 => {
+                self.0.close(data, slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.configure_bounds(data, slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.wm_capabilities(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_toplevel", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgToplevelEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
 
     #[inline]
     fn create_event_handler(handler: H) -> Self::EventHandler {
@@ -1055,6 +1936,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgToplevelEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl XdgToplevel {
     /// Since when the error.invalid_resize_edge enum variant is available.
     #[allow(dead_code)]
@@ -1121,6 +2015,18 @@ impl XdgToplevel {
     /// Since when the state.suspended enum variant is available.
     #[allow(dead_code)]
     pub const ENM__STATE_SUSPENDED__SINCE: u32 = 6;
+    /// Since when the state.constrained_left enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_CONSTRAINED_LEFT__SINCE: u32 = 7;
+    /// Since when the state.constrained_right enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_CONSTRAINED_RIGHT__SINCE: u32 = 7;
+    /// Since when the state.constrained_top enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_CONSTRAINED_TOP__SINCE: u32 = 7;
+    /// Since when the state.constrained_bottom enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__STATE_CONSTRAINED_BOTTOM__SINCE: u32 = 7;
 
     /// Since when the wm_capabilities.window_menu enum variant is available.
     #[allow(dead_code)]
@@ -1328,6 +2234,42 @@ impl XdgToplevelState {
     /// outputs are switched off due to screen locking.
     #[allow(dead_code)]
     pub const SUSPENDED: Self = Self(9);
+
+    /// the surface’s left edge is constrained
+    ///
+    /// The left edge of the window is currently constrained, meaning it
+    /// shouldn't attempt to resize from that edge. It can for example mean
+    /// it's tiled next to a monitor edge on the constrained side of the
+    /// window.
+    #[allow(dead_code)]
+    pub const CONSTRAINED_LEFT: Self = Self(10);
+
+    /// the surface’s right edge is constrained
+    ///
+    /// The right edge of the window is currently constrained, meaning it
+    /// shouldn't attempt to resize from that edge. It can for example mean
+    /// it's tiled next to a monitor edge on the constrained side of the
+    /// window.
+    #[allow(dead_code)]
+    pub const CONSTRAINED_RIGHT: Self = Self(11);
+
+    /// the surface’s top edge is constrained
+    ///
+    /// The top edge of the window is currently constrained, meaning it
+    /// shouldn't attempt to resize from that edge. It can for example mean
+    /// it's tiled next to a monitor edge on the constrained side of the
+    /// window.
+    #[allow(dead_code)]
+    pub const CONSTRAINED_TOP: Self = Self(12);
+
+    /// the surface’s bottom edge is constrained
+    ///
+    /// The bottom edge of the window is currently constrained, meaning it
+    /// shouldn't attempt to resize from that edge. It can for example mean
+    /// it's tiled next to a monitor edge on the constrained side of the
+    /// window.
+    #[allow(dead_code)]
+    pub const CONSTRAINED_BOTTOM: Self = Self(13);
 }
 
 impl Debug for XdgToplevelState {
@@ -1342,6 +2284,10 @@ impl Debug for XdgToplevelState {
             Self::TILED_TOP => "TILED_TOP",
             Self::TILED_BOTTOM => "TILED_BOTTOM",
             Self::SUSPENDED => "SUSPENDED",
+            Self::CONSTRAINED_LEFT => "CONSTRAINED_LEFT",
+            Self::CONSTRAINED_RIGHT => "CONSTRAINED_RIGHT",
+            Self::CONSTRAINED_TOP => "CONSTRAINED_TOP",
+            Self::CONSTRAINED_BOTTOM => "CONSTRAINED_BOTTOM",
             _ => return Debug::fmt(&self.0, f),
         };
         f.write_str(name)
@@ -1388,14 +2334,20 @@ pub mod event_handlers {
     use super::*;
 
     /// Event handler for configure events.
-    pub struct Configure<T, F>(F, PhantomData<fn(&mut T)>);
-    impl<T, F> XdgToplevelEventHandler for Configure<T, F>
+    pub struct Configure<F>(F);
+    impl<F> XdgToplevelEventHandler for Configure<F>
+    where
+        F: Fn(&XdgToplevelRef, i32, i32, &[u8]),
+    {
+        #[inline]
+        fn configure(&self, _slf: &XdgToplevelRef, width: i32, height: i32, states: &[u8]) {
+            self.0(_slf, width, height, states)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerWithData<T> for Configure<F>
     where
-        T: 'static,
         F: Fn(&mut T, &XdgToplevelRef, i32, i32, &[u8]),
     {
-        type Data = T;
-
         #[inline]
         fn configure(
             &self,
@@ -1410,14 +2362,20 @@ pub mod event_handlers {
     }
 
     /// Event handler for close events.
-    pub struct Close<T, F>(F, PhantomData<fn(&mut T)>);
-    impl<T, F> XdgToplevelEventHandler for Close<T, F>
+    pub struct Close<F>(F);
+    impl<F> XdgToplevelEventHandler for Close<F>
+    where
+        F: Fn(&XdgToplevelRef),
+    {
+        #[inline]
+        fn close(&self, _slf: &XdgToplevelRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerWithData<T> for Close<F>
     where
-        T: 'static,
         F: Fn(&mut T, &XdgToplevelRef),
     {
-        type Data = T;
-
         #[inline]
         fn close(&self, _data: &mut T, _slf: &XdgToplevelRef) {
             self.0(_data, _slf)
@@ -1425,14 +2383,20 @@ pub mod event_handlers {
     }
 
     /// Event handler for configure_bounds events.
-    pub struct ConfigureBounds<T, F>(F, PhantomData<fn(&mut T)>);
-    impl<T, F> XdgToplevelEventHandler for ConfigureBounds<T, F>
+    pub struct ConfigureBounds<F>(F);
+    impl<F> XdgToplevelEventHandler for ConfigureBounds<F>
+    where
+        F: Fn(&XdgToplevelRef, i32, i32),
+    {
+        #[inline]
+        fn configure_bounds(&self, _slf: &XdgToplevelRef, width: i32, height: i32) {
+            self.0(_slf, width, height)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerWithData<T> for ConfigureBounds<F>
     where
-        T: 'static,
         F: Fn(&mut T, &XdgToplevelRef, i32, i32),
     {
-        type Data = T;
-
         #[inline]
         fn configure_bounds(&self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
             self.0(_data, _slf, width, height)
@@ -1440,67 +2404,526 @@ pub mod event_handlers {
     }
 
     /// Event handler for wm_capabilities events.
-    pub struct WmCapabilities<T, F>(F, PhantomData<fn(&mut T)>);
-    impl<T, F> XdgToplevelEventHandler for WmCapabilities<T, F>
+    pub struct WmCapabilities<F>(F);
+    impl<F> XdgToplevelEventHandler for WmCapabilities<F>
+    where
+        F: Fn(&XdgToplevelRef, &[u8]),
+    {
+        #[inline]
+        fn wm_capabilities(&self, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerWithData<T> for WmCapabilities<F>
     where
-        T: 'static,
         F: Fn(&mut T, &XdgToplevelRef, &[u8]),
     {
-        type Data = T;
-
         #[inline]
         fn wm_capabilities(&self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
             self.0(_data, _slf, capabilities)
         }
     }
 
+    /// Mutable event handler for configure events.
+    pub struct ConfigureMut<F>(F);
+    impl<F> XdgToplevelEventHandlerMut for ConfigureMut<F>
+    where
+        F: FnMut(&XdgToplevelRef, i32, i32, &[u8]),
+    {
+        #[inline]
+        fn configure(&mut self, _slf: &XdgToplevelRef, width: i32, height: i32, states: &[u8]) {
+            self.0(_slf, width, height, states)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerMutWithData<T> for ConfigureMut<F>
+    where
+        F: FnMut(&mut T, &XdgToplevelRef, i32, i32, &[u8]),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _data: &mut T,
+            _slf: &XdgToplevelRef,
+            width: i32,
+            height: i32,
+            states: &[u8],
+        ) {
+            self.0(_data, _slf, width, height, states)
+        }
+    }
+
+    /// Mutable event handler for close events.
+    pub struct CloseMut<F>(F);
+    impl<F> XdgToplevelEventHandlerMut for CloseMut<F>
+    where
+        F: FnMut(&XdgToplevelRef),
+    {
+        #[inline]
+        fn close(&mut self, _slf: &XdgToplevelRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerMutWithData<T> for CloseMut<F>
+    where
+        F: FnMut(&mut T, &XdgToplevelRef),
+    {
+        #[inline]
+        fn close(&mut self, _data: &mut T, _slf: &XdgToplevelRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for configure_bounds events.
+    pub struct ConfigureBoundsMut<F>(F);
+    impl<F> XdgToplevelEventHandlerMut for ConfigureBoundsMut<F>
+    where
+        F: FnMut(&XdgToplevelRef, i32, i32),
+    {
+        #[inline]
+        fn configure_bounds(&mut self, _slf: &XdgToplevelRef, width: i32, height: i32) {
+            self.0(_slf, width, height)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerMutWithData<T> for ConfigureBoundsMut<F>
+    where
+        F: FnMut(&mut T, &XdgToplevelRef, i32, i32),
+    {
+        #[inline]
+        fn configure_bounds(
+            &mut self,
+            _data: &mut T,
+            _slf: &XdgToplevelRef,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, width, height)
+        }
+    }
+
+    /// Mutable event handler for wm_capabilities events.
+    pub struct WmCapabilitiesMut<F>(F);
+    impl<F> XdgToplevelEventHandlerMut for WmCapabilitiesMut<F>
+    where
+        F: FnMut(&XdgToplevelRef, &[u8]),
+    {
+        #[inline]
+        fn wm_capabilities(&mut self, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> XdgToplevelEventHandlerMutWithData<T> for WmCapabilitiesMut<F>
+    where
+        F: FnMut(&mut T, &XdgToplevelRef, &[u8]),
+    {
+        #[inline]
+        fn wm_capabilities(&mut self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
     impl XdgToplevel {
         /// Creates an event handler for configure events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_configure<T, F>(f: F) -> Configure<T, F>
+        pub fn on_configure<F>(f: F) -> Configure<F>
+        where
+            F: Fn(&XdgToplevelRef, i32, i32, &[u8]),
+        {
+            Configure(f)
+        }
+
+        /// Creates an event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(f: F) -> Configure<F>
         where
-            T: 'static,
             F: Fn(&mut T, &XdgToplevelRef, i32, i32, &[u8]),
         {
-            Configure(f, PhantomData)
+            Configure(f)
         }
 
         /// Creates an event handler for close events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_close<T, F>(f: F) -> Close<T, F>
+        pub fn on_close<F>(f: F) -> Close<F>
+        where
+            F: Fn(&XdgToplevelRef),
+        {
+            Close(f)
+        }
+
+        /// Creates an event handler with mutable data for close events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_close_with_data<T, F>(f: F) -> Close<F>
         where
-            T: 'static,
             F: Fn(&mut T, &XdgToplevelRef),
         {
-            Close(f, PhantomData)
+            Close(f)
         }
 
         /// Creates an event handler for configure_bounds events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_configure_bounds<T, F>(f: F) -> ConfigureBounds<T, F>
+        pub fn on_configure_bounds<F>(f: F) -> ConfigureBounds<F>
+        where
+            F: Fn(&XdgToplevelRef, i32, i32),
+        {
+            ConfigureBounds(f)
+        }
+
+        /// Creates an event handler with mutable data for configure_bounds events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_bounds_with_data<T, F>(f: F) -> ConfigureBounds<F>
         where
-            T: 'static,
             F: Fn(&mut T, &XdgToplevelRef, i32, i32),
         {
-            ConfigureBounds(f, PhantomData)
+            ConfigureBounds(f)
         }
 
         /// Creates an event handler for wm_capabilities events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_wm_capabilities<T, F>(f: F) -> WmCapabilities<T, F>
+        pub fn on_wm_capabilities<F>(f: F) -> WmCapabilities<F>
+        where
+            F: Fn(&XdgToplevelRef, &[u8]),
+        {
+            WmCapabilities(f)
+        }
+
+        /// Creates an event handler with mutable data for wm_capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_wm_capabilities_with_data<T, F>(f: F) -> WmCapabilities<F>
+        where
+            F: Fn(&mut T, &XdgToplevelRef, &[u8]),
+        {
+            WmCapabilities(f)
+        }
+
+        /// Creates a mutable event handler for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut<F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&XdgToplevelRef, i32, i32, &[u8]),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut_with_data<T, F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&mut T, &XdgToplevelRef, i32, i32, &[u8]),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler for close events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_close_mut<F>(f: F) -> CloseMut<F>
+        where
+            F: FnMut(&XdgToplevelRef),
+        {
+            CloseMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for close events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_close_mut_with_data<T, F>(f: F) -> CloseMut<F>
+        where
+            F: FnMut(&mut T, &XdgToplevelRef),
+        {
+            CloseMut(f)
+        }
+
+        /// Creates a mutable event handler for configure_bounds events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_bounds_mut<F>(f: F) -> ConfigureBoundsMut<F>
+        where
+            F: FnMut(&XdgToplevelRef, i32, i32),
+        {
+            ConfigureBoundsMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure_bounds events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_bounds_mut_with_data<T, F>(f: F) -> ConfigureBoundsMut<F>
+        where
+            F: FnMut(&mut T, &XdgToplevelRef, i32, i32),
+        {
+            ConfigureBoundsMut(f)
+        }
+
+        /// Creates a mutable event handler for wm_capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_wm_capabilities_mut<F>(f: F) -> WmCapabilitiesMut<F>
+        where
+            F: FnMut(&XdgToplevelRef, &[u8]),
+        {
+            WmCapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for wm_capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_wm_capabilities_mut_with_data<T, F>(f: F) -> WmCapabilitiesMut<F>
+        where
+            F: FnMut(&mut T, &XdgToplevelRef, &[u8]),
+        {
+            WmCapabilitiesMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                configure: Ignore,
+                close: Ignore,
+                configure_bounds: Ignore,
+                wm_capabilities: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl XdgToplevelEventHandler for Ignore {}
+    impl<T> XdgToplevelEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`XdgToplevel::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        configure: H0,
+        close: H1,
+        configure_bounds: H2,
+        wm_capabilities: H3,
+    }
+
+    impl<H0, H1, H2, H3> XdgToplevelEventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: XdgToplevelEventHandler,
+        H1: XdgToplevelEventHandler,
+        H2: XdgToplevelEventHandler,
+        H3: XdgToplevelEventHandler,
+    {
+        #[inline]
+        fn configure(&self, _slf: &XdgToplevelRef, width: i32, height: i32, states: &[u8]) {
+            self.configure.configure(_slf, width, height, states)
+        }
+
+        #[inline]
+        fn close(&self, _slf: &XdgToplevelRef) {
+            self.close.close(_slf)
+        }
+
+        #[inline]
+        fn configure_bounds(&self, _slf: &XdgToplevelRef, width: i32, height: i32) {
+            self.configure_bounds.configure_bounds(_slf, width, height)
+        }
+
+        #[inline]
+        fn wm_capabilities(&self, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+            self.wm_capabilities.wm_capabilities(_slf, capabilities)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> XdgToplevelEventHandlerWithData<T> for Handler<H0, H1, H2, H3>
+    where
+        H0: XdgToplevelEventHandlerWithData<T>,
+        H1: XdgToplevelEventHandlerWithData<T>,
+        H2: XdgToplevelEventHandlerWithData<T>,
+        H3: XdgToplevelEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &XdgToplevelRef,
+            width: i32,
+            height: i32,
+            states: &[u8],
+        ) {
+            self.configure.configure(_data, _slf, width, height, states)
+        }
+
+        #[inline]
+        fn close(&self, _data: &mut T, _slf: &XdgToplevelRef) {
+            self.close.close(_data, _slf)
+        }
+
+        #[inline]
+        fn configure_bounds(&self, _data: &mut T, _slf: &XdgToplevelRef, width: i32, height: i32) {
+            self.configure_bounds
+                .configure_bounds(_data, _slf, width, height)
+        }
+
+        #[inline]
+        fn wm_capabilities(&self, _data: &mut T, _slf: &XdgToplevelRef, capabilities: &[u8]) {
+            self.wm_capabilities
+                .wm_capabilities(_data, _slf, capabilities)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(self, f: F) -> Handler<Configure<F>, H1, H2, H3>
+        where
+            F: Fn(&XdgToplevelRef, i32, i32, &[u8]),
+        {
+            Handler {
+                configure: Configure(f),
+                close: self.close,
+                configure_bounds: self.configure_bounds,
+                wm_capabilities: self.wm_capabilities,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(self, f: F) -> Handler<Configure<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &XdgToplevelRef, i32, i32, &[u8]),
+        {
+            Handler {
+                configure: Configure(f),
+                close: self.close,
+                configure_bounds: self.configure_bounds,
+                wm_capabilities: self.wm_capabilities,
+            }
+        }
+
+        /// Sets the closure for close events.
+        #[allow(dead_code)]
+        pub fn on_close<F>(self, f: F) -> Handler<H0, Close<F>, H2, H3>
+        where
+            F: Fn(&XdgToplevelRef),
+        {
+            Handler {
+                configure: self.configure,
+                close: Close(f),
+                configure_bounds: self.configure_bounds,
+                wm_capabilities: self.wm_capabilities,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for close events.
+        #[allow(dead_code)]
+        pub fn on_close_with_data<T, F>(self, f: F) -> Handler<H0, Close<F>, H2, H3>
+        where
+            F: Fn(&mut T, &XdgToplevelRef),
+        {
+            Handler {
+                configure: self.configure,
+                close: Close(f),
+                configure_bounds: self.configure_bounds,
+                wm_capabilities: self.wm_capabilities,
+            }
+        }
+
+        /// Sets the closure for configure_bounds events.
+        #[allow(dead_code)]
+        pub fn on_configure_bounds<F>(self, f: F) -> Handler<H0, H1, ConfigureBounds<F>, H3>
+        where
+            F: Fn(&XdgToplevelRef, i32, i32),
+        {
+            Handler {
+                configure: self.configure,
+                close: self.close,
+                configure_bounds: ConfigureBounds(f),
+                wm_capabilities: self.wm_capabilities,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure_bounds events.
+        #[allow(dead_code)]
+        pub fn on_configure_bounds_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, ConfigureBounds<F>, H3>
+        where
+            F: Fn(&mut T, &XdgToplevelRef, i32, i32),
+        {
+            Handler {
+                configure: self.configure,
+                close: self.close,
+                configure_bounds: ConfigureBounds(f),
+                wm_capabilities: self.wm_capabilities,
+            }
+        }
+
+        /// Sets the closure for wm_capabilities events.
+        #[allow(dead_code)]
+        pub fn on_wm_capabilities<F>(self, f: F) -> Handler<H0, H1, H2, WmCapabilities<F>>
+        where
+            F: Fn(&XdgToplevelRef, &[u8]),
+        {
+            Handler {
+                configure: self.configure,
+                close: self.close,
+                configure_bounds: self.configure_bounds,
+                wm_capabilities: WmCapabilities(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for wm_capabilities events.
+        #[allow(dead_code)]
+        pub fn on_wm_capabilities_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, WmCapabilities<F>>
         where
-            T: 'static,
             F: Fn(&mut T, &XdgToplevelRef, &[u8]),
         {
-            WmCapabilities(f, PhantomData)
+            Handler {
+                configure: self.configure,
+                close: self.close,
+                configure_bounds: self.configure_bounds,
+                wm_capabilities: WmCapabilities(f),
+            }
         }
     }
 }
@@ -0,0 +1,476 @@
+//! Move a window during a drag
+//!
+//! This protocol enhances normal drag and drop with the ability to move a
+//! window at the same time. This allows having detachable parts of a window
+//! that when dragged out of it become a new window and can be dragged over
+//! an existing window to be reattached.
+//!
+//! A typical workflow would be when the user starts dragging on top of a
+//! detachable part of a window, the client would create a wl_data_source and
+//! a xdg_toplevel_drag_v1 object and start the drag as normal via
+//! wl_data_device.start_drag. Once the client determines that the detachable
+//! window contents should be detached from the originating window, it creates
+//! a new xdg_toplevel with these contents and issues a
+//! xdg_toplevel_drag_v1.attach request before mapping it. From now on the new
+//! window is moved by the compositor during the drag as if the client called
+//! xdg_toplevel.move.
+//!
+//! Dragging an existing window is similar. The client creates a
+//! xdg_toplevel_drag_v1 object and attaches the existing toplevel before
+//! starting the drag.
+//!
+//! Clients use the existing drag and drop mechanism to detect when a window
+//! can be docked or undocked. If the client wants to snap a window into a
+//! parent window it should delete or unmap the dragged top-level. If the
+//! contents should be detached again it attaches a new toplevel as described
+//! above. If a drag operation is cancelled without being dropped, clients
+//! should revert to the previous state, deleting any newly created windows
+//! as appropriate. When a drag operation ends as indicated by
+//! wl_data_source.dnd_drop_performed the dragged toplevel window's final
+//! position is determined as if a xdg_toplevel_move operation ended.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_toplevel_drag_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_xdg_toplevel_drag".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(XdgToplevelDragV1::WL_INTERFACE),
+                        Some(WlDataSource::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_toplevel_drag_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_toplevel_drag_manager_v1",
+    protocol: "xdg_toplevel_drag_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_xdg_toplevel_drag",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("xdg_toplevel_drag_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "data_source",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_data_source"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "invalid_source",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned xdg_toplevel_drag_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelDragManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_toplevel_drag_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelDragManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgToplevelDragManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgToplevelDragManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgToplevelDragManagerV1 {
+    const INTERFACE: &'static str = "xdg_toplevel_drag_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgToplevelDragManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgToplevelDragManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgToplevelDragManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgToplevelDragManagerV1Ref {
+    type Owned = XdgToplevelDragManagerV1;
+}
+
+impl Deref for XdgToplevelDragManagerV1 {
+    type Target = XdgToplevelDragManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgToplevelDragManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_drag_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgToplevelDragManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_drag_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgToplevelDragManagerV1Ref> for XdgToplevelDragManagerV1 {
+    fn eq(&self, other: &XdgToplevelDragManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgToplevelDragManagerV1> for XdgToplevelDragManagerV1Ref {
+    fn eq(&self, other: &XdgToplevelDragManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelDragManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the xdg_toplevel_drag_manager_v1 object
+    ///
+    /// Destroy this xdg_toplevel_drag_manager_v1 object. Other objects,
+    /// including xdg_toplevel_drag_v1 objects created by this factory, are not
+    /// affected by this request.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_xdg_toplevel_drag request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_XDG_TOPLEVEL_DRAG__SINCE: u32 = 1;
+
+    /// get an xdg_toplevel_drag for a wl_data_source
+    ///
+    /// Create an xdg_toplevel_drag for a drag and drop operation that is going
+    /// to be started with data_source.
+    ///
+    /// This request can only be made on sources used in drag-and-drop, so it
+    /// must be performed before wl_data_device.start_drag. Attempting to use
+    /// the source other than for drag-and-drop such as in
+    /// wl_data_device.set_selection will raise an invalid_source error.
+    ///
+    /// Destroying data_source while a toplevel is attached to the
+    /// xdg_toplevel_drag is undefined.
+    ///
+    /// # Arguments
+    ///
+    /// - `data_source`:
+    #[inline]
+    pub fn get_xdg_toplevel_drag(&self, data_source: &WlDataSourceRef) -> XdgToplevelDragV1 {
+        let (arg1,) = (data_source,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("data_source", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                XdgToplevelDragV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface XdgToplevelDragV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelDragManagerV1Ref {
+    /// get an xdg_toplevel_drag for a wl_data_source
+    ///
+    /// Create an xdg_toplevel_drag for a drag and drop operation that is going
+    /// to be started with data_source.
+    ///
+    /// This request can only be made on sources used in drag-and-drop, so it
+    /// must be performed before wl_data_device.start_drag. Attempting to use
+    /// the source other than for drag-and-drop such as in
+    /// wl_data_device.set_selection will raise an invalid_source error.
+    ///
+    /// Destroying data_source while a toplevel is attached to the
+    /// xdg_toplevel_drag is undefined.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `data_source`:
+    #[inline]
+    pub fn get_xdg_toplevel_drag(
+        &self,
+        _queue: &Queue,
+        data_source: &WlDataSourceRef,
+    ) -> XdgToplevelDragV1 {
+        let (arg1,) = (data_source,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("data_source", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, XdgToplevelDragV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface XdgToplevelDragV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [XdgToplevelDragManagerV1] proxies.
+#[allow(dead_code)]
+pub trait XdgToplevelDragManagerV1EventHandler {}
+
+impl XdgToplevelDragManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgToplevelDragManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelDragManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgToplevelDragManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgToplevelDragManagerV1] proxies.
+///
+/// This trait is the same as [XdgToplevelDragManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelDragManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgToplevelDragManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelDragManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelDragManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgToplevelDragManagerV1EventHandler for MutEventHandler<H> where
+    H: XdgToplevelDragManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> XdgToplevelDragManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgToplevelDragManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgToplevelDragManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelDragManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgToplevelDragManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelDragManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgToplevelDragManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_drag_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgToplevelDragManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_drag_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgToplevelDragManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgToplevelDragManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XdgToplevelDragManagerV1 {
+    /// Since when the error.invalid_source enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SOURCE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XdgToplevelDragManagerV1Error(pub u32);
+
+impl XdgToplevelDragManagerV1Error {
+    /// data_source already used for toplevel drag
+    #[allow(dead_code)]
+    pub const INVALID_SOURCE: Self = Self(0);
+}
+
+impl Debug for XdgToplevelDragManagerV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_SOURCE => "INVALID_SOURCE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgToplevelDragManagerV1 {}
+}
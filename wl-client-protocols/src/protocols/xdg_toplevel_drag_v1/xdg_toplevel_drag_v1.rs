@@ -0,0 +1,424 @@
+//! Object representing a toplevel move during a drag
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_toplevel_drag_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"attach".as_ptr(),
+                signature: c"oii".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] =
+                        [Some(XdgToplevel::WL_INTERFACE), None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_toplevel_drag_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_toplevel_drag_v1",
+    protocol: "xdg_toplevel_drag_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "attach",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "toplevel",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "x_offset",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y_offset",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "toplevel_attached",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "ongoing_drag",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned xdg_toplevel_drag_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelDragV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_toplevel_drag_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelDragV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgToplevelDragV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgToplevelDragV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgToplevelDragV1 {
+    const INTERFACE: &'static str = "xdg_toplevel_drag_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgToplevelDragV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgToplevelDragV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgToplevelDragV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgToplevelDragV1Ref {
+    type Owned = XdgToplevelDragV1;
+}
+
+impl Deref for XdgToplevelDragV1 {
+    type Target = XdgToplevelDragV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgToplevelDragV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_drag_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgToplevelDragV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_drag_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgToplevelDragV1Ref> for XdgToplevelDragV1 {
+    fn eq(&self, other: &XdgToplevelDragV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgToplevelDragV1> for XdgToplevelDragV1Ref {
+    fn eq(&self, other: &XdgToplevelDragV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelDragV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy an xdg_toplevel_drag_v1 object
+    ///
+    /// Destroy this xdg_toplevel_drag_v1 object. This request must only be
+    /// called after the underlying wl_data_source drag has ended, as indicated
+    /// by the dnd_drop_performed or cancelled events. In any other case an
+    /// ongoing_drag error is raised.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelDragV1Ref {
+    /// Move a toplevel with the drag operation
+    ///
+    /// Request that the window will be moved with the cursor during the drag
+    /// operation. The offset is a hint to the compositor how the toplevel
+    /// should be positioned relative to the cursor hotspot in surface local
+    /// coordinates and relative to the geometry of the toplevel being attached.
+    /// See xdg_surface.set_window_geometry. For example it might only
+    /// be used when an unmapped window is attached. The attached window
+    /// does not participate in the selection of the drag target.
+    ///
+    /// If the toplevel is unmapped while it is attached, it is automatically
+    /// detached from the drag. In this case this request has to be called again
+    /// if the window should be attached after it is remapped.
+    ///
+    /// This request can be called multiple times but issuing it while a
+    /// toplevel with an active role is attached raises a toplevel_attached
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    /// - `x_offset`: dragged surface x offset
+    /// - `y_offset`: dragged surface y offset
+    #[inline]
+    pub fn attach(&self, toplevel: &XdgToplevelRef, x_offset: i32, y_offset: i32) {
+        let (arg0, arg1, arg2) = (toplevel, x_offset, y_offset);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("toplevel", obj0_lock.wl_proxy());
+        let mut args = [
+            wl_argument { o: obj0 },
+            wl_argument { i: arg1 },
+            wl_argument { i: arg2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `oii`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+}
+
+/// An event handler for [XdgToplevelDragV1] proxies.
+#[allow(dead_code)]
+pub trait XdgToplevelDragV1EventHandler {}
+
+impl XdgToplevelDragV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgToplevelDragV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelDragV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgToplevelDragV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgToplevelDragV1] proxies.
+///
+/// This trait is the same as [XdgToplevelDragV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelDragV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgToplevelDragV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelDragV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelDragV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgToplevelDragV1EventHandler for MutEventHandler<H> where
+    H: XdgToplevelDragV1EventHandlerMut
+{
+}
+
+impl<T, H> XdgToplevelDragV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgToplevelDragV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgToplevelDragV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelDragV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgToplevelDragV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelDragV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgToplevelDragV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_drag_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgToplevelDragV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_drag_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgToplevelDragV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgToplevelDragV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XdgToplevelDragV1 {
+    /// Since when the error.toplevel_attached enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_TOPLEVEL_ATTACHED__SINCE: u32 = 1;
+    /// Since when the error.ongoing_drag enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ONGOING_DRAG__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XdgToplevelDragV1Error(pub u32);
+
+impl XdgToplevelDragV1Error {
+    /// valid toplevel already attached
+    #[allow(dead_code)]
+    pub const TOPLEVEL_ATTACHED: Self = Self(0);
+
+    /// drag has not ended
+    #[allow(dead_code)]
+    pub const ONGOING_DRAG: Self = Self(1);
+}
+
+impl Debug for XdgToplevelDragV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::TOPLEVEL_ATTACHED => "TOPLEVEL_ATTACHED",
+            Self::ONGOING_DRAG => "ONGOING_DRAG",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgToplevelDragV1 {}
+}
@@ -0,0 +1,943 @@
+//! interface to manage toplevel icons
+//!
+//! This interface allows clients to create toplevel window icons and set
+//! them on toplevel windows to be displayed to the user.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_toplevel_icon_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"create_icon".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(XdgToplevelIconV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_icon".as_ptr(),
+                signature: c"o?o".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(XdgToplevel::WL_INTERFACE),
+                        Some(XdgToplevelIconV1::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"icon_size".as_ptr(),
+                signature: c"i".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the xdg_toplevel_icon_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_toplevel_icon_manager_v1",
+    protocol: "xdg_toplevel_icon_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "create_icon",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("xdg_toplevel_icon_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_icon",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "toplevel",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "icon",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel_icon_v1"),
+                    allow_null: true,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "icon_size",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "size",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned xdg_toplevel_icon_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelIconManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_toplevel_icon_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelIconManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgToplevelIconManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgToplevelIconManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgToplevelIconManagerV1 {
+    const INTERFACE: &'static str = "xdg_toplevel_icon_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgToplevelIconManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgToplevelIconManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgToplevelIconManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgToplevelIconManagerV1Ref {
+    type Owned = XdgToplevelIconManagerV1;
+}
+
+impl Deref for XdgToplevelIconManagerV1 {
+    type Target = XdgToplevelIconManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgToplevelIconManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_icon_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgToplevelIconManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_icon_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgToplevelIconManagerV1Ref> for XdgToplevelIconManagerV1 {
+    fn eq(&self, other: &XdgToplevelIconManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgToplevelIconManagerV1> for XdgToplevelIconManagerV1Ref {
+    fn eq(&self, other: &XdgToplevelIconManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelIconManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the toplevel icon manager
+    ///
+    /// Destroy the toplevel icon manager.
+    /// This does not destroy objects created with the manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the create_icon request is available.
+    #[allow(dead_code)]
+    pub const REQ__CREATE_ICON__SINCE: u32 = 1;
+
+    /// create a new icon instance
+    ///
+    /// Creates a new icon object. This icon can then be attached to a
+    /// xdg_toplevel via the 'set_icon' request.
+    #[inline]
+    pub fn create_icon(&self) -> XdgToplevelIconV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                XdgToplevelIconV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface XdgToplevelIconV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelIconManagerV1Ref {
+    /// create a new icon instance
+    ///
+    /// Creates a new icon object. This icon can then be attached to a
+    /// xdg_toplevel via the 'set_icon' request.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn create_icon(&self, _queue: &Queue) -> XdgToplevelIconV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, XdgToplevelIconV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface XdgToplevelIconV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+
+    /// set an icon on a toplevel window
+    ///
+    /// This request assigns the icon 'icon' to 'toplevel', or clears the
+    /// toplevel icon if 'icon' was null.
+    /// This state is double-buffered and is applied on the next
+    /// wl_surface.commit of the toplevel.
+    ///
+    /// After making this call, the xdg_toplevel_icon_v1 provided as 'icon'
+    /// can be destroyed by the client without 'toplevel' losing its icon.
+    /// The xdg_toplevel_icon_v1 is immutable from this point, and any
+    /// future attempts to change it must raise the
+    /// 'xdg_toplevel_icon_v1.immutable' protocol error.
+    ///
+    /// The compositor must set the toplevel icon from either the pixel data
+    /// the icon provides, or by loading a stock icon using the icon name.
+    /// See the description of 'xdg_toplevel_icon_v1' for details.
+    ///
+    /// If 'icon' is set to null, the icon of the respective toplevel is reset
+    /// to its default icon (usually the icon of the application, derived from
+    /// its desktop-entry file, or a placeholder icon).
+    /// If this request is passed an icon with no pixel buffers or icon name
+    /// assigned, the icon must be reset just like if 'icon' was null.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`: the toplevel to act on
+    /// - `icon`:
+    #[inline]
+    pub fn set_icon(&self, toplevel: &XdgToplevelRef, icon: Option<&XdgToplevelIconV1Ref>) {
+        let (arg0, arg1) = (toplevel, icon);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("toplevel", obj0_lock.wl_proxy());
+        let obj1_lock = arg1.map(|arg1| proxy::lock(arg1));
+        let obj1 = obj1_lock
+            .map(|obj1_lock| check_argument_proxy("icon", obj1_lock.wl_proxy()))
+            .unwrap_or(ptr::null_mut());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `o?o`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+impl XdgToplevelIconManagerV1 {
+    /// Since when the icon_size event is available.
+    #[allow(dead_code)]
+    pub const EVT__ICON_SIZE__SINCE: u32 = 1;
+
+    /// Since when the done event is available.
+    #[allow(dead_code)]
+    pub const EVT__DONE__SINCE: u32 = 1;
+}
+
+/// An event handler for [XdgToplevelIconManagerV1] proxies.
+#[allow(dead_code)]
+pub trait XdgToplevelIconManagerV1EventHandler {
+    /// describes a supported & preferred icon size
+    ///
+    /// This event indicates an icon size the compositor prefers to be
+    /// available if the client has scalable icons and can render to any size.
+    ///
+    /// When the 'xdg_toplevel_icon_manager_v1' object is created, the
+    /// compositor may send one or more 'icon_size' events to describe the list
+    /// of preferred icon sizes. If the compositor has no size preference, it
+    /// may not send any 'icon_size' event, and it is up to the client to
+    /// decide a suitable icon size.
+    ///
+    /// A sequence of 'icon_size' events must be finished with a 'done' event.
+    /// If the compositor has no size preferences, it must still send the
+    /// 'done' event, without any preceding 'icon_size' events.
+    ///
+    /// # Arguments
+    ///
+    /// - `size`: the edge size of the square icon in surface-local coordinates, e.g. 64
+    #[inline]
+    fn icon_size(&self, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        let _ = size;
+    }
+
+    /// all information has been sent
+    ///
+    /// This event is sent after all 'icon_size' events have been sent.
+    #[inline]
+    fn done(&self, _slf: &XdgToplevelIconManagerV1Ref) {}
+}
+
+impl XdgToplevelIconManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgToplevelIconManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelIconManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgToplevelIconManagerV1EventHandlerWithData<T> {
+    /// describes a supported & preferred icon size
+    ///
+    /// This event indicates an icon size the compositor prefers to be
+    /// available if the client has scalable icons and can render to any size.
+    ///
+    /// When the 'xdg_toplevel_icon_manager_v1' object is created, the
+    /// compositor may send one or more 'icon_size' events to describe the list
+    /// of preferred icon sizes. If the compositor has no size preference, it
+    /// may not send any 'icon_size' event, and it is up to the client to
+    /// decide a suitable icon size.
+    ///
+    /// A sequence of 'icon_size' events must be finished with a 'done' event.
+    /// If the compositor has no size preferences, it must still send the
+    /// 'done' event, without any preceding 'icon_size' events.
+    ///
+    /// # Arguments
+    ///
+    /// - `size`: the edge size of the square icon in surface-local coordinates, e.g. 64
+    #[inline]
+    fn icon_size(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        let _ = size;
+    }
+
+    /// all information has been sent
+    ///
+    /// This event is sent after all 'icon_size' events have been sent.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {}
+}
+
+/// A mutable event handler for [XdgToplevelIconManagerV1] proxies.
+///
+/// This trait is the same as [XdgToplevelIconManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelIconManagerV1EventHandlerMut {
+    /// See [XdgToplevelIconManagerV1EventHandler::icon_size].
+    #[inline]
+    fn icon_size(&mut self, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        let _ = size;
+    }
+
+    /// See [XdgToplevelIconManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &XdgToplevelIconManagerV1Ref) {}
+}
+
+/// A mutable event handler for [XdgToplevelIconManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelIconManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelIconManagerV1EventHandlerMutWithData<T> {
+    /// See [XdgToplevelIconManagerV1EventHandler::icon_size].
+    #[inline]
+    fn icon_size(&mut self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        let _ = size;
+    }
+
+    /// See [XdgToplevelIconManagerV1EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {}
+}
+
+impl<H> XdgToplevelIconManagerV1EventHandler for MutEventHandler<H>
+where
+    H: XdgToplevelIconManagerV1EventHandlerMut,
+{
+    #[inline]
+    fn icon_size(&self, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        self.with(|h| h.icon_size(_slf, size))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &XdgToplevelIconManagerV1Ref) {
+        self.with(|h| h.done(_slf))
+    }
+}
+
+impl<T, H> XdgToplevelIconManagerV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: XdgToplevelIconManagerV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn icon_size(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        self.with(|h| h.icon_size(_data, _slf, size))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+}
+
+impl<T, U, H> XdgToplevelIconManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgToplevelIconManagerV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn icon_size(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        let _data = self.project(_data);
+        self.handler().icon_size(_data, _slf, size)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+}
+
+impl<T, U, H> XdgToplevelIconManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgToplevelIconManagerV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn icon_size(&mut self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().icon_size(_data, _slf, size)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgToplevelIconManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<XdgToplevelIconManagerV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.icon_size(slf, arg0);
+            }
+            1 => {
+                self.0.done(slf);
+            }
+            _ => {
+                invalid_opcode("xdg_toplevel_icon_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgToplevelIconManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<XdgToplevelIconManagerV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.icon_size(data, slf, arg0);
+            }
+            1 => {
+                self.0.done(data, slf);
+            }
+            _ => {
+                invalid_opcode("xdg_toplevel_icon_manager_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgToplevelIconManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgToplevelIconManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for icon_size events.
+    pub struct IconSize<F>(F);
+    impl<F> XdgToplevelIconManagerV1EventHandler for IconSize<F>
+    where
+        F: Fn(&XdgToplevelIconManagerV1Ref, i32),
+    {
+        #[inline]
+        fn icon_size(&self, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+            self.0(_slf, size)
+        }
+    }
+    impl<T, F> XdgToplevelIconManagerV1EventHandlerWithData<T> for IconSize<F>
+    where
+        F: Fn(&mut T, &XdgToplevelIconManagerV1Ref, i32),
+    {
+        #[inline]
+        fn icon_size(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+            self.0(_data, _slf, size)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> XdgToplevelIconManagerV1EventHandler for Done<F>
+    where
+        F: Fn(&XdgToplevelIconManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &XdgToplevelIconManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> XdgToplevelIconManagerV1EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &XdgToplevelIconManagerV1Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for icon_size events.
+    pub struct IconSizeMut<F>(F);
+    impl<F> XdgToplevelIconManagerV1EventHandlerMut for IconSizeMut<F>
+    where
+        F: FnMut(&XdgToplevelIconManagerV1Ref, i32),
+    {
+        #[inline]
+        fn icon_size(&mut self, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+            self.0(_slf, size)
+        }
+    }
+    impl<T, F> XdgToplevelIconManagerV1EventHandlerMutWithData<T> for IconSizeMut<F>
+    where
+        F: FnMut(&mut T, &XdgToplevelIconManagerV1Ref, i32),
+    {
+        #[inline]
+        fn icon_size(&mut self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+            self.0(_data, _slf, size)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> XdgToplevelIconManagerV1EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&XdgToplevelIconManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &XdgToplevelIconManagerV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> XdgToplevelIconManagerV1EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &XdgToplevelIconManagerV1Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl XdgToplevelIconManagerV1 {
+        /// Creates an event handler for icon_size events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_icon_size<F>(f: F) -> IconSize<F>
+        where
+            F: Fn(&XdgToplevelIconManagerV1Ref, i32),
+        {
+            IconSize(f)
+        }
+
+        /// Creates an event handler with mutable data for icon_size events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_icon_size_with_data<T, F>(f: F) -> IconSize<F>
+        where
+            F: Fn(&mut T, &XdgToplevelIconManagerV1Ref, i32),
+        {
+            IconSize(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&XdgToplevelIconManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &XdgToplevelIconManagerV1Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates a mutable event handler for icon_size events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_icon_size_mut<F>(f: F) -> IconSizeMut<F>
+        where
+            F: FnMut(&XdgToplevelIconManagerV1Ref, i32),
+        {
+            IconSizeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for icon_size events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_icon_size_mut_with_data<T, F>(f: F) -> IconSizeMut<F>
+        where
+            F: FnMut(&mut T, &XdgToplevelIconManagerV1Ref, i32),
+        {
+            IconSizeMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&XdgToplevelIconManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &XdgToplevelIconManagerV1Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                icon_size: Ignore,
+                done: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl XdgToplevelIconManagerV1EventHandler for Ignore {}
+    impl<T> XdgToplevelIconManagerV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`XdgToplevelIconManagerV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        icon_size: H0,
+        done: H1,
+    }
+
+    impl<H0, H1> XdgToplevelIconManagerV1EventHandler for Handler<H0, H1>
+    where
+        H0: XdgToplevelIconManagerV1EventHandler,
+        H1: XdgToplevelIconManagerV1EventHandler,
+    {
+        #[inline]
+        fn icon_size(&self, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+            self.icon_size.icon_size(_slf, size)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &XdgToplevelIconManagerV1Ref) {
+            self.done.done(_slf)
+        }
+    }
+
+    impl<T, H0, H1> XdgToplevelIconManagerV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: XdgToplevelIconManagerV1EventHandlerWithData<T>,
+        H1: XdgToplevelIconManagerV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn icon_size(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref, size: i32) {
+            self.icon_size.icon_size(_data, _slf, size)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &XdgToplevelIconManagerV1Ref) {
+            self.done.done(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for icon_size events.
+        #[allow(dead_code)]
+        pub fn on_icon_size<F>(self, f: F) -> Handler<IconSize<F>, H1>
+        where
+            F: Fn(&XdgToplevelIconManagerV1Ref, i32),
+        {
+            Handler {
+                icon_size: IconSize(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for icon_size events.
+        #[allow(dead_code)]
+        pub fn on_icon_size_with_data<T, F>(self, f: F) -> Handler<IconSize<F>, H1>
+        where
+            F: Fn(&mut T, &XdgToplevelIconManagerV1Ref, i32),
+        {
+            Handler {
+                icon_size: IconSize(f),
+                done: self.done,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, Done<F>>
+        where
+            F: Fn(&XdgToplevelIconManagerV1Ref),
+        {
+            Handler {
+                icon_size: self.icon_size,
+                done: Done(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, Done<F>>
+        where
+            F: Fn(&mut T, &XdgToplevelIconManagerV1Ref),
+        {
+            Handler {
+                icon_size: self.icon_size,
+                done: Done(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,503 @@
+//! a toplevel window icon
+//!
+//! This interface defines a toplevel icon.
+//! An icon can have a name, and multiple buffers.
+//! In order to be applied, the icon must have either a name, or at least
+//! one buffer assigned. Applying an empty icon (with no buffer or name) to
+//! a toplevel should reset its icon to the default icon.
+//!
+//! It is up to compositor policy whether to prefer using a buffer or loading
+//! an icon via its name. See 'set_name' and 'add_buffer' for details.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_toplevel_icon_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_name".as_ptr(),
+                signature: c"s".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"add_buffer".as_ptr(),
+                signature: c"oi".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(WlBuffer::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_toplevel_icon_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_toplevel_icon_v1",
+    protocol: "xdg_toplevel_icon_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_name",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "icon_name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "add_buffer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "buffer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_buffer"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "scale",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_buffer",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "immutable",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_buffer",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned xdg_toplevel_icon_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelIconV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_toplevel_icon_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelIconV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgToplevelIconV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgToplevelIconV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgToplevelIconV1 {
+    const INTERFACE: &'static str = "xdg_toplevel_icon_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgToplevelIconV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgToplevelIconV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgToplevelIconV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgToplevelIconV1Ref {
+    type Owned = XdgToplevelIconV1;
+}
+
+impl Deref for XdgToplevelIconV1 {
+    type Target = XdgToplevelIconV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgToplevelIconV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_icon_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgToplevelIconV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_icon_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgToplevelIconV1Ref> for XdgToplevelIconV1 {
+    fn eq(&self, other: &XdgToplevelIconV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgToplevelIconV1> for XdgToplevelIconV1Ref {
+    fn eq(&self, other: &XdgToplevelIconV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelIconV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the icon object
+    ///
+    /// Destroys the 'xdg_toplevel_icon_v1' object.
+    /// The icon must still remain set on every toplevel it was assigned to,
+    /// until the toplevel icon is reset explicitly.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelIconV1Ref {
+    /// set an icon name
+    ///
+    /// This request assigns an icon name to this icon.
+    /// Any previously set name is overridden.
+    ///
+    /// The compositor must resolve 'icon_name' according to the lookup rules
+    /// described in the XDG icon theme specification[1] using the
+    /// environment's current icon theme.
+    ///
+    /// If the compositor does not support icon names or cannot resolve
+    /// 'icon_name' according to the XDG icon theme specification it must
+    /// fall back to using pixel buffer data instead.
+    ///
+    /// If this request is made after the icon has been assigned to a toplevel
+    /// via 'set_icon', a 'immutable' error must be raised.
+    ///
+    /// [1]: https://specifications.freedesktop.org/icon-theme-spec/icon-theme-spec-latest.html
+    ///
+    /// # Arguments
+    ///
+    /// - `icon_name`:
+    #[inline]
+    pub fn set_name(&self, icon_name: &str) {
+        let (arg0,) = (icon_name,);
+        with_cstr_cache(|cache| {
+            let str0_offset = cache.len();
+            cache.extend_from_slice(arg0.as_bytes());
+            cache.push(0);
+            let str0 = cache[str0_offset..].as_ptr().cast();
+            let mut args = [wl_argument { s: str0 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 3
+            //         - the request signature is `s`
+            unsafe {
+                self.proxy.send_request(1, &mut args);
+            }
+        })
+    }
+
+    /// add icon data from a pixel buffer
+    ///
+    /// This request adds pixel data supplied as wl_buffer to the icon.
+    ///
+    /// The client should add pixel data for all icon sizes and scales that
+    /// it can provide, or which are explicitly requested by the compositor
+    /// via 'icon_size' events on xdg_toplevel_icon_manager_v1.
+    ///
+    /// The wl_buffer supplying pixel data as 'buffer' must be backed by wl_shm
+    /// and must be a square (width and height being equal).
+    /// If any of these buffer requirements are not fulfilled, a 'invalid_buffer'
+    /// error must be raised.
+    ///
+    /// If this icon instance already has a buffer of the same size and scale
+    /// from a previous 'add_buffer' request, data from the last request
+    /// overrides the preexisting pixel data.
+    ///
+    /// The wl_buffer must be kept alive for as long as the xdg_toplevel_icon
+    /// it is associated with is not destroyed, otherwise a 'no_buffer' error
+    /// is raised. The buffer contents must not be modified after it was
+    /// assigned to the icon. As a result, the region of the wl_shm_pool's
+    /// backing storage used for the wl_buffer must not be modified after this
+    /// request is sent. The wl_buffer.release event is unused.
+    ///
+    /// If this request is made after the icon has been assigned to a toplevel
+    /// via 'set_icon', a 'immutable' error must be raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer`:
+    /// - `scale`: the scaling factor of the icon, e.g. 1
+    #[inline]
+    pub fn add_buffer(&self, buffer: &WlBufferRef, scale: i32) {
+        let (arg0, arg1) = (buffer, scale);
+        let obj0_lock = proxy::lock(arg0);
+        let obj0 = check_argument_proxy("buffer", obj0_lock.wl_proxy());
+        let mut args = [wl_argument { o: obj0 }, wl_argument { i: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `oi`
+        unsafe {
+            self.proxy.send_request(2, &mut args);
+        }
+    }
+}
+
+/// An event handler for [XdgToplevelIconV1] proxies.
+#[allow(dead_code)]
+pub trait XdgToplevelIconV1EventHandler {}
+
+impl XdgToplevelIconV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgToplevelIconV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelIconV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgToplevelIconV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgToplevelIconV1] proxies.
+///
+/// This trait is the same as [XdgToplevelIconV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelIconV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgToplevelIconV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelIconV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelIconV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgToplevelIconV1EventHandler for MutEventHandler<H> where
+    H: XdgToplevelIconV1EventHandlerMut
+{
+}
+
+impl<T, H> XdgToplevelIconV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgToplevelIconV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgToplevelIconV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelIconV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgToplevelIconV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelIconV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgToplevelIconV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_icon_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgToplevelIconV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_icon_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgToplevelIconV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgToplevelIconV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XdgToplevelIconV1 {
+    /// Since when the error.invalid_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.immutable enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_IMMUTABLE__SINCE: u32 = 1;
+    /// Since when the error.no_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_BUFFER__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XdgToplevelIconV1Error(pub u32);
+
+impl XdgToplevelIconV1Error {
+    /// the provided buffer does not satisfy requirements
+    #[allow(dead_code)]
+    pub const INVALID_BUFFER: Self = Self(1);
+
+    /// the icon has already been assigned to a toplevel and must not be changed
+    #[allow(dead_code)]
+    pub const IMMUTABLE: Self = Self(2);
+
+    /// the provided buffer has been destroyed before the toplevel icon
+    #[allow(dead_code)]
+    pub const NO_BUFFER: Self = Self(3);
+}
+
+impl Debug for XdgToplevelIconV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_BUFFER => "INVALID_BUFFER",
+            Self::IMMUTABLE => "IMMUTABLE",
+            Self::NO_BUFFER => "NO_BUFFER",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgToplevelIconV1 {}
+}
@@ -0,0 +1,441 @@
+//! protocol for setting toplevel tags
+//!
+//! In order to make some window properties like position, size,
+//! "always on top" or user defined rules for window behavior persistent, the
+//! compositor needs some way to identify windows even after the application
+//! has been restarted.
+//! This protocol allows clients to make this possible by setting a tag for
+//! toplevels.
+//!
+//! Warning! The protocol described in this file is currently in the testing
+//! phase. Backward compatible changes may be added together with the
+//! corresponding interface version bump. Backward incompatible changes can
+//! only be done by creating a new major version of the extension.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xdg_toplevel_tag_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_toplevel_tag".as_ptr(),
+                signature: c"os".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(XdgToplevel::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_toplevel_description".as_ptr(),
+                signature: c"os".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] =
+                        [Some(XdgToplevel::WL_INTERFACE), None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xdg_toplevel_tag_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_toplevel_tag_manager_v1",
+    protocol: "xdg_toplevel_tag_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_toplevel_tag",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "toplevel",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tag",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_toplevel_description",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "toplevel",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_toplevel"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "description",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned xdg_toplevel_tag_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelTagManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xdg_toplevel_tag_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XdgToplevelTagManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XdgToplevelTagManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XdgToplevelTagManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XdgToplevelTagManagerV1 {
+    const INTERFACE: &'static str = "xdg_toplevel_tag_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XdgToplevelTagManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XdgToplevelTagManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XdgToplevelTagManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XdgToplevelTagManagerV1Ref {
+    type Owned = XdgToplevelTagManagerV1;
+}
+
+impl Deref for XdgToplevelTagManagerV1 {
+    type Target = XdgToplevelTagManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XdgToplevelTagManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_tag_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XdgToplevelTagManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xdg_toplevel_tag_manager_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XdgToplevelTagManagerV1Ref> for XdgToplevelTagManagerV1 {
+    fn eq(&self, other: &XdgToplevelTagManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XdgToplevelTagManagerV1> for XdgToplevelTagManagerV1Ref {
+    fn eq(&self, other: &XdgToplevelTagManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelTagManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy toplevel tag object
+    ///
+    /// Destroy this toplevel tag manager object. This request has no other
+    /// effects.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl XdgToplevelTagManagerV1Ref {
+    /// set tag
+    ///
+    /// Set a tag for a toplevel. The tag may be shown to the user in UI, so
+    /// it's preferable for it to be human readable, but it must be suitable
+    /// for configuration files and should not be translated.
+    /// Suitable tags would for example be "main window", "settings",
+    /// "e-mail composer" or similar.
+    ///
+    /// The tag does not need to be unique across applications, and the client
+    /// may set the same tag for multiple windows, for example if the user has
+    /// opened the same UI twice. How the potentially resulting conflicts are
+    /// handled is compositor policy.
+    ///
+    /// The client should set the tag as part of the initial commit on the
+    /// associated toplevel, but it may set it at any time afterwards as well,
+    /// for example if the purpose of the toplevel changes.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    /// - `tag`: untranslated tag
+    #[inline]
+    pub fn set_toplevel_tag(&self, toplevel: &XdgToplevelRef, tag: &str) {
+        let (arg0, arg1) = (toplevel, tag);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let obj0_lock = proxy::lock(arg0);
+            let obj0 = check_argument_proxy("toplevel", obj0_lock.wl_proxy());
+            let mut args = [wl_argument { o: obj0 }, wl_argument { s: str1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 1 < INTERFACE.method_count = 3
+            //         - the request signature is `os`
+            unsafe {
+                self.proxy.send_request(1, &mut args);
+            }
+        })
+    }
+
+    /// set description
+    ///
+    /// Set a description for a toplevel. This description may be shown to the
+    /// user in UI or read by a screen reader for accessibility purposes, and
+    /// should be translated.
+    /// It is recommended to make the description the translation of the tag.
+    ///
+    /// The client should set the description as part of the initial commit on
+    /// the associated toplevel, but it may set it at any time afterwards as
+    /// well, for example if the purpose of the toplevel changes.
+    ///
+    /// # Arguments
+    ///
+    /// - `toplevel`:
+    /// - `description`: translated description
+    #[inline]
+    pub fn set_toplevel_description(&self, toplevel: &XdgToplevelRef, description: &str) {
+        let (arg0, arg1) = (toplevel, description);
+        with_cstr_cache(|cache| {
+            let str1_offset = cache.len();
+            cache.extend_from_slice(arg1.as_bytes());
+            cache.push(0);
+            let str1 = cache[str1_offset..].as_ptr().cast();
+            let obj0_lock = proxy::lock(arg0);
+            let obj0 = check_argument_proxy("toplevel", obj0_lock.wl_proxy());
+            let mut args = [wl_argument { o: obj0 }, wl_argument { s: str1 }];
+            // SAFETY: - self.proxy has the interface INTERFACE
+            //         - 2 < INTERFACE.method_count = 3
+            //         - the request signature is `os`
+            unsafe {
+                self.proxy.send_request(2, &mut args);
+            }
+        })
+    }
+}
+
+/// An event handler for [XdgToplevelTagManagerV1] proxies.
+#[allow(dead_code)]
+pub trait XdgToplevelTagManagerV1EventHandler {}
+
+impl XdgToplevelTagManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XdgToplevelTagManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelTagManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgToplevelTagManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XdgToplevelTagManagerV1] proxies.
+///
+/// This trait is the same as [XdgToplevelTagManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelTagManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [XdgToplevelTagManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [XdgToplevelTagManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgToplevelTagManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> XdgToplevelTagManagerV1EventHandler for MutEventHandler<H> where
+    H: XdgToplevelTagManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> XdgToplevelTagManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XdgToplevelTagManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XdgToplevelTagManagerV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelTagManagerV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XdgToplevelTagManagerV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XdgToplevelTagManagerV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XdgToplevelTagManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_tag_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgToplevelTagManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xdg_toplevel_tag_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XdgToplevelTagManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgToplevelTagManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XdgToplevelTagManagerV1 {}
+}
@@ -0,0 +1,468 @@
+//! context object for keyboard grab manager
+//!
+//! A global interface used for grabbing the keyboard.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_xwayland_keyboard_grab_manager_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"grab_keyboard".as_ptr(),
+                signature: c"noo".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [
+                        Some(ZwpXwaylandKeyboardGrabV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                        Some(WlSeat::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_xwayland_keyboard_grab_manager_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_xwayland_keyboard_grab_manager_v1",
+    protocol: "xwayland_keyboard_grab_unstable_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "grab_keyboard",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_xwayland_keyboard_grab_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_xwayland_keyboard_grab_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpXwaylandKeyboardGrabManagerV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_xwayland_keyboard_grab_manager_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpXwaylandKeyboardGrabManagerV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpXwaylandKeyboardGrabManagerV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpXwaylandKeyboardGrabManagerV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpXwaylandKeyboardGrabManagerV1 {
+    const INTERFACE: &'static str = "zwp_xwayland_keyboard_grab_manager_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpXwaylandKeyboardGrabManagerV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpXwaylandKeyboardGrabManagerV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpXwaylandKeyboardGrabManagerV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpXwaylandKeyboardGrabManagerV1Ref {
+    type Owned = ZwpXwaylandKeyboardGrabManagerV1;
+}
+
+impl Deref for ZwpXwaylandKeyboardGrabManagerV1 {
+    type Target = ZwpXwaylandKeyboardGrabManagerV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpXwaylandKeyboardGrabManagerV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_xwayland_keyboard_grab_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ZwpXwaylandKeyboardGrabManagerV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_xwayland_keyboard_grab_manager_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ZwpXwaylandKeyboardGrabManagerV1Ref> for ZwpXwaylandKeyboardGrabManagerV1 {
+    fn eq(&self, other: &ZwpXwaylandKeyboardGrabManagerV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpXwaylandKeyboardGrabManagerV1> for ZwpXwaylandKeyboardGrabManagerV1Ref {
+    fn eq(&self, other: &ZwpXwaylandKeyboardGrabManagerV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpXwaylandKeyboardGrabManagerV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the keyboard grab manager
+    ///
+    /// Destroy the keyboard grab manager.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the grab_keyboard request is available.
+    #[allow(dead_code)]
+    pub const REQ__GRAB_KEYBOARD__SINCE: u32 = 1;
+
+    /// grab the keyboard to a surface
+    ///
+    /// The grab_keyboard request asks for a grab of the keyboard, forcing
+    /// the keyboard focus for the given seat upon the given surface.
+    ///
+    /// The protocol provides no guarantee that the grab is ever satisfied,
+    /// and does not require the compositor to send an error if the grab
+    /// cannot ever be satisfied. It is thus possible to request a keyboard
+    /// grab that will never be effective.
+    ///
+    /// The protocol:
+    ///
+    /// * does not guarantee that the grab itself is applied for a surface,
+    ///   the grab request may be silently ignored by the compositor,
+    /// * does not guarantee that any events are sent to this client even
+    ///   if the grab is applied to a surface,
+    /// * does not guarantee that events sent to this client are exhaustive,
+    ///   a compositor may filter some events for its own consumption,
+    /// * does not guarantee that events sent to this client are continuous,
+    ///   a compositor may change and reroute keyboard events while the grab
+    ///   is nominally active.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: surface to report keyboard events to
+    /// - `seat`: the seat for which the keyboard should be grabbed
+    #[inline]
+    pub fn grab_keyboard(
+        &self,
+        surface: &WlSurfaceRef,
+        seat: &WlSeatRef,
+    ) -> ZwpXwaylandKeyboardGrabV1 {
+        let (arg1, arg2) = (surface, seat);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpXwaylandKeyboardGrabV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpXwaylandKeyboardGrabV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpXwaylandKeyboardGrabManagerV1Ref {
+    /// grab the keyboard to a surface
+    ///
+    /// The grab_keyboard request asks for a grab of the keyboard, forcing
+    /// the keyboard focus for the given seat upon the given surface.
+    ///
+    /// The protocol provides no guarantee that the grab is ever satisfied,
+    /// and does not require the compositor to send an error if the grab
+    /// cannot ever be satisfied. It is thus possible to request a keyboard
+    /// grab that will never be effective.
+    ///
+    /// The protocol:
+    ///
+    /// * does not guarantee that the grab itself is applied for a surface,
+    ///   the grab request may be silently ignored by the compositor,
+    /// * does not guarantee that any events are sent to this client even
+    ///   if the grab is applied to a surface,
+    /// * does not guarantee that events sent to this client are exhaustive,
+    ///   a compositor may filter some events for its own consumption,
+    /// * does not guarantee that events sent to this client are continuous,
+    ///   a compositor may change and reroute keyboard events while the grab
+    ///   is nominally active.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: surface to report keyboard events to
+    /// - `seat`: the seat for which the keyboard should be grabbed
+    #[inline]
+    pub fn grab_keyboard(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+        seat: &WlSeatRef,
+    ) -> ZwpXwaylandKeyboardGrabV1 {
+        let (arg1, arg2) = (surface, seat);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let obj2_lock = proxy::lock(arg2);
+        let obj2 = check_argument_proxy("seat", obj2_lock.wl_proxy());
+        let mut args = [
+            wl_argument { n: 0 },
+            wl_argument { o: obj1 },
+            wl_argument { o: obj2 },
+        ];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `noo`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpXwaylandKeyboardGrabV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpXwaylandKeyboardGrabV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpXwaylandKeyboardGrabManagerV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabManagerV1EventHandler {}
+
+impl ZwpXwaylandKeyboardGrabManagerV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpXwaylandKeyboardGrabManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpXwaylandKeyboardGrabManagerV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpXwaylandKeyboardGrabManagerV1] proxies.
+///
+/// This trait is the same as [ZwpXwaylandKeyboardGrabManagerV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabManagerV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpXwaylandKeyboardGrabManagerV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabManagerV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpXwaylandKeyboardGrabManagerV1EventHandler for MutEventHandler<H> where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> ZwpXwaylandKeyboardGrabManagerV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_xwayland_keyboard_grab_manager_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_xwayland_keyboard_grab_manager_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpXwaylandKeyboardGrabManagerV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpXwaylandKeyboardGrabManagerV1 {}
+}
@@ -0,0 +1,286 @@
+//! interface for grabbing the keyboard
+//!
+//! A global interface used for grabbing the keyboard.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_xwayland_keyboard_grab_v1".as_ptr(),
+    version: 1,
+    method_count: 1,
+    methods: {
+        static MESSAGES: [wl_message; 1] = [wl_message {
+            name: c"destroy".as_ptr(),
+            signature: c"".as_ptr(),
+            types: {
+                static TYPES: [Option<&'static wl_interface>; 0] = [];
+                TYPES.as_ptr().cast()
+            },
+        }];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_xwayland_keyboard_grab_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_xwayland_keyboard_grab_v1",
+    protocol: "xwayland_keyboard_grab_unstable_v1",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[],
+    enums: &[],
+};
+
+/// An owned zwp_xwayland_keyboard_grab_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpXwaylandKeyboardGrabV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_xwayland_keyboard_grab_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpXwaylandKeyboardGrabV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpXwaylandKeyboardGrabV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpXwaylandKeyboardGrabV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpXwaylandKeyboardGrabV1 {
+    const INTERFACE: &'static str = "zwp_xwayland_keyboard_grab_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpXwaylandKeyboardGrabV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpXwaylandKeyboardGrabV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpXwaylandKeyboardGrabV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpXwaylandKeyboardGrabV1Ref {
+    type Owned = ZwpXwaylandKeyboardGrabV1;
+}
+
+impl Deref for ZwpXwaylandKeyboardGrabV1 {
+    type Target = ZwpXwaylandKeyboardGrabV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpXwaylandKeyboardGrabV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_xwayland_keyboard_grab_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpXwaylandKeyboardGrabV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_xwayland_keyboard_grab_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpXwaylandKeyboardGrabV1Ref> for ZwpXwaylandKeyboardGrabV1 {
+    fn eq(&self, other: &ZwpXwaylandKeyboardGrabV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpXwaylandKeyboardGrabV1> for ZwpXwaylandKeyboardGrabV1Ref {
+    fn eq(&self, other: &ZwpXwaylandKeyboardGrabV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpXwaylandKeyboardGrabV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the grabbed keyboard object
+    ///
+    /// Destroy the grabbed keyboard object. If applicable, the compositor
+    /// will ungrab the keyboard.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 1
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [ZwpXwaylandKeyboardGrabV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabV1EventHandler {}
+
+impl ZwpXwaylandKeyboardGrabV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpXwaylandKeyboardGrabV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpXwaylandKeyboardGrabV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpXwaylandKeyboardGrabV1] proxies.
+///
+/// This trait is the same as [ZwpXwaylandKeyboardGrabV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpXwaylandKeyboardGrabV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpXwaylandKeyboardGrabV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpXwaylandKeyboardGrabV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpXwaylandKeyboardGrabV1EventHandler for MutEventHandler<H> where
+    H: ZwpXwaylandKeyboardGrabV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpXwaylandKeyboardGrabV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpXwaylandKeyboardGrabV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpXwaylandKeyboardGrabV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpXwaylandKeyboardGrabV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpXwaylandKeyboardGrabV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpXwaylandKeyboardGrabV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpXwaylandKeyboardGrabV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_xwayland_keyboard_grab_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpXwaylandKeyboardGrabV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_xwayland_keyboard_grab_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpXwaylandKeyboardGrabV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpXwaylandKeyboardGrabV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpXwaylandKeyboardGrabV1 {}
+}
@@ -0,0 +1,453 @@
+//! context object for Xwayland shell
+//!
+//! xwayland_shell_v1 is a singleton global object that
+//! provides the ability to create a xwayland_surface_v1 object
+//! for a given wl_surface.
+//!
+//! This interface is intended to be bound by the Xwayland server.
+//!
+//! A compositor must not allow clients other than Xwayland to
+//! bind to this interface. A compositor should hide this global
+//! from other clients' wl_registry.
+//! A client the compositor does not consider to be an Xwayland
+//! server attempting to bind this interface will result in
+//! an implementation-defined error.
+//!
+//! An Xwayland server that has bound this interface must not
+//! set the `WL_SURFACE_ID` atom on a window.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xwayland_shell_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_xwayland_surface".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(XwaylandSurfaceV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xwayland_shell_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xwayland_shell_v1",
+    protocol: "xwayland_shell_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_xwayland_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("xwayland_surface_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "role",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned xwayland_shell_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XwaylandShellV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xwayland_shell_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XwaylandShellV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XwaylandShellV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XwaylandShellV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XwaylandShellV1 {
+    const INTERFACE: &'static str = "xwayland_shell_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XwaylandShellV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XwaylandShellV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XwaylandShellV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XwaylandShellV1Ref {
+    type Owned = XwaylandShellV1;
+}
+
+impl Deref for XwaylandShellV1 {
+    type Target = XwaylandShellV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XwaylandShellV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xwayland_shell_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XwaylandShellV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xwayland_shell_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XwaylandShellV1Ref> for XwaylandShellV1 {
+    fn eq(&self, other: &XwaylandShellV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XwaylandShellV1> for XwaylandShellV1Ref {
+    fn eq(&self, other: &XwaylandShellV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XwaylandShellV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the Xwayland shell object
+    ///
+    /// Destroy the xwayland_shell_v1 object.
+    ///
+    /// The child objects created via this interface are unaffected.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_xwayland_surface request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_XWAYLAND_SURFACE__SINCE: u32 = 1;
+
+    /// assign the xwayland_surface surface role
+    ///
+    /// Create an xwayland_surface_v1 interface for a given wl_surface
+    /// object and gives it the xwayland_surface role.
+    ///
+    /// It is illegal to create an xwayland_surface_v1 for a wl_surface
+    /// which already has an assigned role and this will result in the
+    /// `role` protocol error.
+    ///
+    /// See the documentation of xwayland_surface_v1 for more details
+    /// about what an xwayland_surface_v1 is and how it is used.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`:
+    #[inline]
+    pub fn get_xwayland_surface(&self, surface: &WlSurfaceRef) -> XwaylandSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                XwaylandSurfaceV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface XwaylandSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl XwaylandShellV1Ref {
+    /// assign the xwayland_surface surface role
+    ///
+    /// Create an xwayland_surface_v1 interface for a given wl_surface
+    /// object and gives it the xwayland_surface role.
+    ///
+    /// It is illegal to create an xwayland_surface_v1 for a wl_surface
+    /// which already has an assigned role and this will result in the
+    /// `role` protocol error.
+    ///
+    /// See the documentation of xwayland_surface_v1 for more details
+    /// about what an xwayland_surface_v1 is and how it is used.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`:
+    #[inline]
+    pub fn get_xwayland_surface(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> XwaylandSurfaceV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy
+                .send_constructor(_queue, 1, &mut args, XwaylandSurfaceV1::WL_INTERFACE, None)
+        };
+        // SAFETY: data has the interface XwaylandSurfaceV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [XwaylandShellV1] proxies.
+#[allow(dead_code)]
+pub trait XwaylandShellV1EventHandler {}
+
+impl XwaylandShellV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XwaylandShellV1] proxies with mutable data.
+///
+/// This trait is the same as [XwaylandShellV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XwaylandShellV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XwaylandShellV1] proxies.
+///
+/// This trait is the same as [XwaylandShellV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XwaylandShellV1EventHandlerMut {}
+
+/// A mutable event handler for [XwaylandShellV1] proxies with mutable data.
+///
+/// This trait is the same as [XwaylandShellV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XwaylandShellV1EventHandlerMutWithData<T> {}
+
+impl<H> XwaylandShellV1EventHandler for MutEventHandler<H> where H: XwaylandShellV1EventHandlerMut {}
+
+impl<T, H> XwaylandShellV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XwaylandShellV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XwaylandShellV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XwaylandShellV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XwaylandShellV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XwaylandShellV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XwaylandShellV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xwayland_shell_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XwaylandShellV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xwayland_shell_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XwaylandShellV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XwaylandShellV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XwaylandShellV1 {
+    /// Since when the error.role enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XwaylandShellV1Error(pub u32);
+
+impl XwaylandShellV1Error {
+    /// given wl_surface has another role
+    #[allow(dead_code)]
+    pub const ROLE: Self = Self(0);
+}
+
+impl Debug for XwaylandShellV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ROLE => "ROLE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XwaylandShellV1 {}
+}
@@ -0,0 +1,423 @@
+//! interface for associating Xwayland windows to wl_surfaces
+//!
+//! An Xwayland surface is a surface managed by an Xwayland server.
+//! It is used for associating surfaces to Xwayland windows.
+//!
+//! The Xwayland server associated with actions in this interface is
+//! determined by the Wayland client making the request.
+//!
+//! The client must call wl_surface.commit on the corresponding wl_surface
+//! for the xwayland_surface_v1 state to take effect.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"xwayland_surface_v1".as_ptr(),
+    version: 1,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"set_serial".as_ptr(),
+                signature: c"uu".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [None, None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the xwayland_surface_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xwayland_surface_v1",
+    protocol: "xwayland_shell_v1",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_serial",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "already_associated",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_serial",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned xwayland_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XwaylandSurfaceV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed xwayland_surface_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct XwaylandSurfaceV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: XwaylandSurfaceV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for XwaylandSurfaceV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for XwaylandSurfaceV1 {
+    const INTERFACE: &'static str = "xwayland_surface_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = XwaylandSurfaceV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: XwaylandSurfaceV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for XwaylandSurfaceV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for XwaylandSurfaceV1Ref {
+    type Owned = XwaylandSurfaceV1;
+}
+
+impl Deref for XwaylandSurfaceV1 {
+    type Target = XwaylandSurfaceV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for XwaylandSurfaceV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xwayland_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for XwaylandSurfaceV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "xwayland_surface_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<XwaylandSurfaceV1Ref> for XwaylandSurfaceV1 {
+    fn eq(&self, other: &XwaylandSurfaceV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<XwaylandSurfaceV1> for XwaylandSurfaceV1Ref {
+    fn eq(&self, other: &XwaylandSurfaceV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl XwaylandSurfaceV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy the Xwayland surface object
+    ///
+    /// Destroy the xwayland_surface_v1 object.
+    ///
+    /// Any already existing associations are unaffected by this action.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(1, &mut args);
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl XwaylandSurfaceV1Ref {
+    /// associates a Xwayland window to a wl_surface
+    ///
+    /// Associates an Xwayland window to a wl_surface.
+    /// The association state is double-buffered, see wl_surface.commit.
+    ///
+    /// The `serial_lo` and `serial_hi` parameters specify a non-zero
+    /// monotonic serial number which is entirely unique and provided by the
+    /// Xwayland server equal to the serial value provided by a client message
+    /// with a message type of the `WL_SURFACE_SERIAL` atom on the X11 window
+    /// for this surface to be associated to.
+    ///
+    /// The serial value in the `WL_SURFACE_SERIAL` client message is specified
+    /// as having the lo-bits specified in `l[0]` and the hi-bits specified
+    /// in `l[1]`.
+    ///
+    /// If the serial value provided by `serial_lo` and `serial_hi` is not
+    /// valid, the `invalid_serial` protocol error will be raised.
+    ///
+    /// An X11 window may be associated with multiple surfaces throughout its
+    /// lifespan. (eg. unmapping and remapping a window).
+    ///
+    /// For each wl_surface, this state must not be committed more than once,
+    /// otherwise the `already_associated` protocol error will be raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial_lo`: The lower 32-bits of the serial number associated with the X11 window
+    /// - `serial_hi`: The upper 32-bits of the serial number associated with the X11 window
+    #[inline]
+    pub fn set_serial(&self, serial_lo: u32, serial_hi: u32) {
+        let (arg0, arg1) = (serial_lo, serial_hi);
+        let mut args = [wl_argument { u: arg0 }, wl_argument { u: arg1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is `uu`
+        unsafe {
+            self.proxy.send_request(0, &mut args);
+        }
+    }
+}
+
+/// An event handler for [XwaylandSurfaceV1] proxies.
+#[allow(dead_code)]
+pub trait XwaylandSurfaceV1EventHandler {}
+
+impl XwaylandSurfaceV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [XwaylandSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [XwaylandSurfaceV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XwaylandSurfaceV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [XwaylandSurfaceV1] proxies.
+///
+/// This trait is the same as [XwaylandSurfaceV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XwaylandSurfaceV1EventHandlerMut {}
+
+/// A mutable event handler for [XwaylandSurfaceV1] proxies with mutable data.
+///
+/// This trait is the same as [XwaylandSurfaceV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XwaylandSurfaceV1EventHandlerMutWithData<T> {}
+
+impl<H> XwaylandSurfaceV1EventHandler for MutEventHandler<H> where
+    H: XwaylandSurfaceV1EventHandlerMut
+{
+}
+
+impl<T, H> XwaylandSurfaceV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: XwaylandSurfaceV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> XwaylandSurfaceV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XwaylandSurfaceV1EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> XwaylandSurfaceV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: XwaylandSurfaceV1EventHandlerMutWithData<U>
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: XwaylandSurfaceV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xwayland_surface_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XwaylandSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("xwayland_surface_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: XwaylandSurfaceV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XwaylandSurfaceV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl XwaylandSurfaceV1 {
+    /// Since when the error.already_associated enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ALREADY_ASSOCIATED__SINCE: u32 = 1;
+    /// Since when the error.invalid_serial enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SERIAL__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct XwaylandSurfaceV1Error(pub u32);
+
+impl XwaylandSurfaceV1Error {
+    /// given wl_surface is already associated with an X11 window
+    #[allow(dead_code)]
+    pub const ALREADY_ASSOCIATED: Self = Self(0);
+
+    /// serial was not valid
+    #[allow(dead_code)]
+    pub const INVALID_SERIAL: Self = Self(1);
+}
+
+impl Debug for XwaylandSurfaceV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ALREADY_ASSOCIATED => "ALREADY_ASSOCIATED",
+            Self::INVALID_SERIAL => "INVALID_SERIAL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl XwaylandSurfaceV1 {}
+}
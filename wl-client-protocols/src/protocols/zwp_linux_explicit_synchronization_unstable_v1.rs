@@ -0,0 +1,24 @@
+#![allow(clippy::tabs_in_doc_comments)]
+#![allow(clippy::doc_lazy_continuation)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::module_inception)]
+#![allow(unused_imports)]
+#![allow(rustdoc::broken_intra_doc_links)]
+#![allow(rustdoc::bare_urls)]
+#![allow(rustdoc::invalid_rust_codeblocks)]
+
+pub mod zwp_linux_buffer_release_v1;
+pub mod zwp_linux_explicit_synchronization_v1;
+pub mod zwp_linux_surface_synchronization_v1;
+
+/// Metadata about the zwp_linux_explicit_synchronization_unstable_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "zwp_linux_explicit_synchronization_unstable_v1",
+    interfaces: &[
+        &zwp_linux_explicit_synchronization_v1::INFO,
+        &zwp_linux_surface_synchronization_v1::INFO,
+        &zwp_linux_buffer_release_v1::INFO,
+    ],
+};
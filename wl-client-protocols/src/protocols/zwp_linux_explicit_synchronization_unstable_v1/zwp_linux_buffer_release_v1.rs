@@ -0,0 +1,786 @@
+//! buffer release explicit synchronization
+//!
+//! This object is instantiated in response to a
+//! zwp_linux_surface_synchronization_v1.get_release request.
+//!
+//! It provides an alternative to wl_buffer.release events, providing a
+//! unique release from a single wl_surface.commit request. The release event
+//! also supports explicit synchronization, providing a fence FD for the
+//! client to synchronize against.
+//!
+//! Exactly one event, either a fenced_release or an immediate_release, will
+//! be emitted for the wl_surface.commit request. The compositor can choose
+//! release by release which event it uses.
+//!
+//! This event does not replace wl_buffer.release events; servers are still
+//! required to send those events.
+//!
+//! Once a buffer release object has delivered a 'fenced_release' or an
+//! 'immediate_release' event it is automatically destroyed.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_linux_buffer_release_v1".as_ptr(),
+    version: 1,
+    method_count: 0,
+    methods: ptr::null(),
+    event_count: 2,
+    events: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"fenced_release".as_ptr(),
+                signature: c"h".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"immediate_release".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+};
+
+/// Metadata about the zwp_linux_buffer_release_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_linux_buffer_release_v1",
+    protocol: "zwp_linux_explicit_synchronization_unstable_v1",
+    version: 1,
+    requests: &[],
+    events: &[
+        info::MessageInfo {
+            name: "fenced_release",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[info::ArgInfo {
+                name: "fence",
+                ty: info::ArgType::Fd,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "immediate_release",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
+/// An owned zwp_linux_buffer_release_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxBufferReleaseV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_linux_buffer_release_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxBufferReleaseV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLinuxBufferReleaseV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLinuxBufferReleaseV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLinuxBufferReleaseV1 {
+    const INTERFACE: &'static str = "zwp_linux_buffer_release_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 1;
+
+    type Borrowed = ZwpLinuxBufferReleaseV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLinuxBufferReleaseV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLinuxBufferReleaseV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLinuxBufferReleaseV1Ref {
+    type Owned = ZwpLinuxBufferReleaseV1;
+}
+
+impl Deref for ZwpLinuxBufferReleaseV1 {
+    type Target = ZwpLinuxBufferReleaseV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLinuxBufferReleaseV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_buffer_release_v1#{}", self.proxy.id())
+    }
+}
+
+impl Debug for ZwpLinuxBufferReleaseV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zwp_linux_buffer_release_v1#{}", self.proxy.id())
+    }
+}
+
+impl PartialEq<ZwpLinuxBufferReleaseV1Ref> for ZwpLinuxBufferReleaseV1 {
+    fn eq(&self, other: &ZwpLinuxBufferReleaseV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLinuxBufferReleaseV1> for ZwpLinuxBufferReleaseV1Ref {
+    fn eq(&self, other: &ZwpLinuxBufferReleaseV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl ZwpLinuxBufferReleaseV1 {
+    /// Since when the fenced_release event is available.
+    #[allow(dead_code)]
+    pub const EVT__FENCED_RELEASE__SINCE: u32 = 1;
+
+    /// Since when the immediate_release event is available.
+    #[allow(dead_code)]
+    pub const EVT__IMMEDIATE_RELEASE__SINCE: u32 = 1;
+}
+
+/// An event handler for [ZwpLinuxBufferReleaseV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferReleaseV1EventHandler {
+    /// release buffer with fence
+    ///
+    /// Sent when the compositor has finalised its usage of the associated
+    /// buffer for the relevant commit, providing a dma_fence which will be
+    /// signaled when all operations by the compositor on that buffer for that
+    /// commit have finished.
+    ///
+    /// Once the fence has signaled, and assuming the associated buffer is not
+    /// pending release from other wl_surface.commit requests, no additional
+    /// explicit or implicit synchronization is required to safely reuse or
+    /// destroy the buffer.
+    ///
+    /// This event destroys the zwp_linux_buffer_release_v1 object.
+    ///
+    /// # Arguments
+    ///
+    /// - `fence`: fence for last operation on buffer
+    #[inline]
+    fn fenced_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        let _ = fence;
+    }
+
+    /// release buffer immediately
+    ///
+    /// Sent when the compositor has finalised its usage of the associated
+    /// buffer for the relevant commit, and either performed no operations
+    /// using it, or has a guarantee that all its operations on that buffer for
+    /// that commit have finished.
+    ///
+    /// Once this event is received, and assuming the associated buffer is not
+    /// pending release from other wl_surface.commit requests, no additional
+    /// explicit or implicit synchronization is required to safely reuse or
+    /// destroy the buffer.
+    ///
+    /// This event destroys the zwp_linux_buffer_release_v1 object.
+    #[inline]
+    fn immediate_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref) {}
+}
+
+impl ZwpLinuxBufferReleaseV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLinuxBufferReleaseV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxBufferReleaseV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferReleaseV1EventHandlerWithData<T> {
+    /// release buffer with fence
+    ///
+    /// Sent when the compositor has finalised its usage of the associated
+    /// buffer for the relevant commit, providing a dma_fence which will be
+    /// signaled when all operations by the compositor on that buffer for that
+    /// commit have finished.
+    ///
+    /// Once the fence has signaled, and assuming the associated buffer is not
+    /// pending release from other wl_surface.commit requests, no additional
+    /// explicit or implicit synchronization is required to safely reuse or
+    /// destroy the buffer.
+    ///
+    /// This event destroys the zwp_linux_buffer_release_v1 object.
+    ///
+    /// # Arguments
+    ///
+    /// - `fence`: fence for last operation on buffer
+    #[inline]
+    fn fenced_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        let _ = fence;
+    }
+
+    /// release buffer immediately
+    ///
+    /// Sent when the compositor has finalised its usage of the associated
+    /// buffer for the relevant commit, and either performed no operations
+    /// using it, or has a guarantee that all its operations on that buffer for
+    /// that commit have finished.
+    ///
+    /// Once this event is received, and assuming the associated buffer is not
+    /// pending release from other wl_surface.commit requests, no additional
+    /// explicit or implicit synchronization is required to safely reuse or
+    /// destroy the buffer.
+    ///
+    /// This event destroys the zwp_linux_buffer_release_v1 object.
+    #[inline]
+    fn immediate_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpLinuxBufferReleaseV1] proxies.
+///
+/// This trait is the same as [ZwpLinuxBufferReleaseV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferReleaseV1EventHandlerMut {
+    /// See [ZwpLinuxBufferReleaseV1EventHandler::fenced_release].
+    #[inline]
+    fn fenced_release(&mut self, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        let _ = fence;
+    }
+
+    /// See [ZwpLinuxBufferReleaseV1EventHandler::immediate_release].
+    #[inline]
+    fn immediate_release(&mut self, _slf: &ZwpLinuxBufferReleaseV1Ref) {}
+}
+
+/// A mutable event handler for [ZwpLinuxBufferReleaseV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxBufferReleaseV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxBufferReleaseV1EventHandlerMutWithData<T> {
+    /// See [ZwpLinuxBufferReleaseV1EventHandler::fenced_release].
+    #[inline]
+    fn fenced_release(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        let _ = fence;
+    }
+
+    /// See [ZwpLinuxBufferReleaseV1EventHandler::immediate_release].
+    #[inline]
+    fn immediate_release(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {}
+}
+
+impl<H> ZwpLinuxBufferReleaseV1EventHandler for MutEventHandler<H>
+where
+    H: ZwpLinuxBufferReleaseV1EventHandlerMut,
+{
+    #[inline]
+    fn fenced_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        self.with(|h| h.fenced_release(_slf, fence))
+    }
+
+    #[inline]
+    fn immediate_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+        self.with(|h| h.immediate_release(_slf))
+    }
+}
+
+impl<T, H> ZwpLinuxBufferReleaseV1EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpLinuxBufferReleaseV1EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn fenced_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        self.with(|h| h.fenced_release(_data, _slf, fence))
+    }
+
+    #[inline]
+    fn immediate_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+        self.with(|h| h.immediate_release(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpLinuxBufferReleaseV1EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxBufferReleaseV1EventHandlerWithData<U>,
+{
+    #[inline]
+    fn fenced_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler().fenced_release(_data, _slf, fence)
+    }
+
+    #[inline]
+    fn immediate_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+        let _data = self.project(_data);
+        self.handler().immediate_release(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpLinuxBufferReleaseV1EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxBufferReleaseV1EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn fenced_release(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler_mut().fenced_release(_data, _slf, fence)
+    }
+
+    #[inline]
+    fn immediate_release(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().immediate_release(_data, _slf)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLinuxBufferReleaseV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxBufferReleaseV1Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                self.0.fenced_release(slf, arg0);
+            }
+            1 => {
+                self.0.immediate_release(slf);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_buffer_release_v1", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLinuxBufferReleaseV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf =
+            unsafe { proxy::low_level::from_untyped_borrowed::<ZwpLinuxBufferReleaseV1Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a file descriptor
+                let arg0 = unsafe { OwnedFd::from_raw_fd(args[0].h) };
+                self.0.fenced_release(data, slf, arg0);
+            }
+            1 => {
+                self.0.immediate_release(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_linux_buffer_release_v1", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLinuxBufferReleaseV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLinuxBufferReleaseV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for fenced_release events.
+    pub struct FencedRelease<F>(F);
+    impl<F> ZwpLinuxBufferReleaseV1EventHandler for FencedRelease<F>
+    where
+        F: Fn(&ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn fenced_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+            self.0(_slf, fence)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferReleaseV1EventHandlerWithData<T> for FencedRelease<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn fenced_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+            self.0(_data, _slf, fence)
+        }
+    }
+
+    /// Event handler for immediate_release events.
+    pub struct ImmediateRelease<F>(F);
+    impl<F> ZwpLinuxBufferReleaseV1EventHandler for ImmediateRelease<F>
+    where
+        F: Fn(&ZwpLinuxBufferReleaseV1Ref),
+    {
+        #[inline]
+        fn immediate_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferReleaseV1EventHandlerWithData<T> for ImmediateRelease<F>
+    where
+        F: Fn(&mut T, &ZwpLinuxBufferReleaseV1Ref),
+    {
+        #[inline]
+        fn immediate_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for fenced_release events.
+    pub struct FencedReleaseMut<F>(F);
+    impl<F> ZwpLinuxBufferReleaseV1EventHandlerMut for FencedReleaseMut<F>
+    where
+        F: FnMut(&ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn fenced_release(&mut self, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+            self.0(_slf, fence)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferReleaseV1EventHandlerMutWithData<T> for FencedReleaseMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+    {
+        #[inline]
+        fn fenced_release(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpLinuxBufferReleaseV1Ref,
+            fence: OwnedFd,
+        ) {
+            self.0(_data, _slf, fence)
+        }
+    }
+
+    /// Mutable event handler for immediate_release events.
+    pub struct ImmediateReleaseMut<F>(F);
+    impl<F> ZwpLinuxBufferReleaseV1EventHandlerMut for ImmediateReleaseMut<F>
+    where
+        F: FnMut(&ZwpLinuxBufferReleaseV1Ref),
+    {
+        #[inline]
+        fn immediate_release(&mut self, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpLinuxBufferReleaseV1EventHandlerMutWithData<T> for ImmediateReleaseMut<F>
+    where
+        F: FnMut(&mut T, &ZwpLinuxBufferReleaseV1Ref),
+    {
+        #[inline]
+        fn immediate_release(&mut self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    impl ZwpLinuxBufferReleaseV1 {
+        /// Creates an event handler for fenced_release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_fenced_release<F>(f: F) -> FencedRelease<F>
+        where
+            F: Fn(&ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+        {
+            FencedRelease(f)
+        }
+
+        /// Creates an event handler with mutable data for fenced_release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_fenced_release_with_data<T, F>(f: F) -> FencedRelease<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+        {
+            FencedRelease(f)
+        }
+
+        /// Creates an event handler for immediate_release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_immediate_release<F>(f: F) -> ImmediateRelease<F>
+        where
+            F: Fn(&ZwpLinuxBufferReleaseV1Ref),
+        {
+            ImmediateRelease(f)
+        }
+
+        /// Creates an event handler with mutable data for immediate_release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_immediate_release_with_data<T, F>(f: F) -> ImmediateRelease<F>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferReleaseV1Ref),
+        {
+            ImmediateRelease(f)
+        }
+
+        /// Creates a mutable event handler for fenced_release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_fenced_release_mut<F>(f: F) -> FencedReleaseMut<F>
+        where
+            F: FnMut(&ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+        {
+            FencedReleaseMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for fenced_release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_fenced_release_mut_with_data<T, F>(f: F) -> FencedReleaseMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+        {
+            FencedReleaseMut(f)
+        }
+
+        /// Creates a mutable event handler for immediate_release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_immediate_release_mut<F>(f: F) -> ImmediateReleaseMut<F>
+        where
+            F: FnMut(&ZwpLinuxBufferReleaseV1Ref),
+        {
+            ImmediateReleaseMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for immediate_release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_immediate_release_mut_with_data<T, F>(f: F) -> ImmediateReleaseMut<F>
+        where
+            F: FnMut(&mut T, &ZwpLinuxBufferReleaseV1Ref),
+        {
+            ImmediateReleaseMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                fenced_release: Ignore,
+                immediate_release: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpLinuxBufferReleaseV1EventHandler for Ignore {}
+    impl<T> ZwpLinuxBufferReleaseV1EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpLinuxBufferReleaseV1::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        fenced_release: H0,
+        immediate_release: H1,
+    }
+
+    impl<H0, H1> ZwpLinuxBufferReleaseV1EventHandler for Handler<H0, H1>
+    where
+        H0: ZwpLinuxBufferReleaseV1EventHandler,
+        H1: ZwpLinuxBufferReleaseV1EventHandler,
+    {
+        #[inline]
+        fn fenced_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+            self.fenced_release.fenced_release(_slf, fence)
+        }
+
+        #[inline]
+        fn immediate_release(&self, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+            self.immediate_release.immediate_release(_slf)
+        }
+    }
+
+    impl<T, H0, H1> ZwpLinuxBufferReleaseV1EventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: ZwpLinuxBufferReleaseV1EventHandlerWithData<T>,
+        H1: ZwpLinuxBufferReleaseV1EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn fenced_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref, fence: OwnedFd) {
+            self.fenced_release.fenced_release(_data, _slf, fence)
+        }
+
+        #[inline]
+        fn immediate_release(&self, _data: &mut T, _slf: &ZwpLinuxBufferReleaseV1Ref) {
+            self.immediate_release.immediate_release(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for fenced_release events.
+        #[allow(dead_code)]
+        pub fn on_fenced_release<F>(self, f: F) -> Handler<FencedRelease<F>, H1>
+        where
+            F: Fn(&ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+        {
+            Handler {
+                fenced_release: FencedRelease(f),
+                immediate_release: self.immediate_release,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for fenced_release events.
+        #[allow(dead_code)]
+        pub fn on_fenced_release_with_data<T, F>(self, f: F) -> Handler<FencedRelease<F>, H1>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferReleaseV1Ref, OwnedFd),
+        {
+            Handler {
+                fenced_release: FencedRelease(f),
+                immediate_release: self.immediate_release,
+            }
+        }
+
+        /// Sets the closure for immediate_release events.
+        #[allow(dead_code)]
+        pub fn on_immediate_release<F>(self, f: F) -> Handler<H0, ImmediateRelease<F>>
+        where
+            F: Fn(&ZwpLinuxBufferReleaseV1Ref),
+        {
+            Handler {
+                fenced_release: self.fenced_release,
+                immediate_release: ImmediateRelease(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for immediate_release events.
+        #[allow(dead_code)]
+        pub fn on_immediate_release_with_data<T, F>(self, f: F) -> Handler<H0, ImmediateRelease<F>>
+        where
+            F: Fn(&mut T, &ZwpLinuxBufferReleaseV1Ref),
+        {
+            Handler {
+                fenced_release: self.fenced_release,
+                immediate_release: ImmediateRelease(f),
+            }
+        }
+    }
+}
@@ -0,0 +1,480 @@
+//! protocol for providing explicit synchronization
+//!
+//! This global is a factory interface, allowing clients to request
+//! explicit synchronization for buffers on a per-surface basis.
+//!
+//! See zwp_linux_surface_synchronization_v1 for more information.
+//!
+//! This interface is derived from Chromium's
+//! zcr_linux_explicit_synchronization_v1.
+//!
+//! Note: this protocol is superseded by linux-drm-syncobj.
+//!
+//! Warning! The protocol described in this file is experimental and
+//! backward incompatible changes may be made. Backward compatible changes
+//! may be added together with the corresponding interface version bump.
+//! Backward incompatible changes are done by bumping the version number in
+//! the protocol and interface names and resetting the interface version.
+//! Once the protocol is to be declared stable, the 'z' prefix and the
+//! version number in the protocol and interface names are removed and the
+//! interface version number is reset.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_linux_explicit_synchronization_v1".as_ptr(),
+    version: 2,
+    method_count: 2,
+    methods: {
+        static MESSAGES: [wl_message; 2] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_synchronization".as_ptr(),
+                signature: c"no".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 2] = [
+                        Some(ZwpLinuxSurfaceSynchronizationV1::WL_INTERFACE),
+                        Some(WlSurface::WL_INTERFACE),
+                    ];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_linux_explicit_synchronization_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_linux_explicit_synchronization_v1",
+    protocol: "zwp_linux_explicit_synchronization_unstable_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_synchronization",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_linux_surface_synchronization_v1"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "synchronization_exists",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
+/// An owned zwp_linux_explicit_synchronization_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxExplicitSynchronizationV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_linux_explicit_synchronization_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxExplicitSynchronizationV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLinuxExplicitSynchronizationV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLinuxExplicitSynchronizationV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLinuxExplicitSynchronizationV1 {
+    const INTERFACE: &'static str = "zwp_linux_explicit_synchronization_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = ZwpLinuxExplicitSynchronizationV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLinuxExplicitSynchronizationV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLinuxExplicitSynchronizationV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLinuxExplicitSynchronizationV1Ref {
+    type Owned = ZwpLinuxExplicitSynchronizationV1;
+}
+
+impl Deref for ZwpLinuxExplicitSynchronizationV1 {
+    type Target = ZwpLinuxExplicitSynchronizationV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLinuxExplicitSynchronizationV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_linux_explicit_synchronization_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ZwpLinuxExplicitSynchronizationV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_linux_explicit_synchronization_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ZwpLinuxExplicitSynchronizationV1Ref> for ZwpLinuxExplicitSynchronizationV1 {
+    fn eq(&self, other: &ZwpLinuxExplicitSynchronizationV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLinuxExplicitSynchronizationV1> for ZwpLinuxExplicitSynchronizationV1Ref {
+    fn eq(&self, other: &ZwpLinuxExplicitSynchronizationV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxExplicitSynchronizationV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy explicit synchronization factory object
+    ///
+    /// Destroy this explicit synchronization factory object. Other objects,
+    /// including zwp_linux_surface_synchronization_v1 objects created by this
+    /// factory, shall not be affected by this request.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 2
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_synchronization request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_SYNCHRONIZATION__SINCE: u32 = 1;
+
+    /// extend surface interface for explicit synchronization
+    ///
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the synchronization_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a synchronization_exists protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `surface`: the surface
+    #[inline]
+    pub fn get_synchronization(&self, surface: &WlSurfaceRef) -> ZwpLinuxSurfaceSynchronizationV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                1,
+                &mut args,
+                ZwpLinuxSurfaceSynchronizationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxSurfaceSynchronizationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxExplicitSynchronizationV1Ref {
+    /// extend surface interface for explicit synchronization
+    ///
+    /// Instantiate an interface extension for the given wl_surface to provide
+    /// explicit synchronization.
+    ///
+    /// If the given wl_surface already has an explicit synchronization object
+    /// associated, the synchronization_exists protocol error is raised.
+    ///
+    /// Graphics APIs, like EGL or Vulkan, that manage the buffer queue and
+    /// commits of a wl_surface themselves, are likely to be using this
+    /// extension internally. If a client is using such an API for a
+    /// wl_surface, it should not directly use this extension on that surface,
+    /// to avoid raising a synchronization_exists protocol error.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    /// - `surface`: the surface
+    #[inline]
+    pub fn get_synchronization(
+        &self,
+        _queue: &Queue,
+        surface: &WlSurfaceRef,
+    ) -> ZwpLinuxSurfaceSynchronizationV1 {
+        let (arg1,) = (surface,);
+        let obj1_lock = proxy::lock(arg1);
+        let obj1 = check_argument_proxy("surface", obj1_lock.wl_proxy());
+        let mut args = [wl_argument { n: 0 }, wl_argument { o: obj1 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 2
+        //         - the request signature is `no`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                1,
+                &mut args,
+                ZwpLinuxSurfaceSynchronizationV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxSurfaceSynchronizationV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpLinuxExplicitSynchronizationV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLinuxExplicitSynchronizationV1EventHandler {}
+
+impl ZwpLinuxExplicitSynchronizationV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLinuxExplicitSynchronizationV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxExplicitSynchronizationV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLinuxExplicitSynchronizationV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpLinuxExplicitSynchronizationV1] proxies.
+///
+/// This trait is the same as [ZwpLinuxExplicitSynchronizationV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxExplicitSynchronizationV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpLinuxExplicitSynchronizationV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxExplicitSynchronizationV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxExplicitSynchronizationV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpLinuxExplicitSynchronizationV1EventHandler for MutEventHandler<H> where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpLinuxExplicitSynchronizationV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpLinuxExplicitSynchronizationV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> ZwpLinuxExplicitSynchronizationV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_linux_explicit_synchronization_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_linux_explicit_synchronization_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLinuxExplicitSynchronizationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpLinuxExplicitSynchronizationV1 {
+    /// Since when the error.synchronization_exists enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_SYNCHRONIZATION_EXISTS__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpLinuxExplicitSynchronizationV1Error(pub u32);
+
+impl ZwpLinuxExplicitSynchronizationV1Error {
+    /// the surface already has a synchronization object associated
+    #[allow(dead_code)]
+    pub const SYNCHRONIZATION_EXISTS: Self = Self(0);
+}
+
+impl Debug for ZwpLinuxExplicitSynchronizationV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::SYNCHRONIZATION_EXISTS => "SYNCHRONIZATION_EXISTS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpLinuxExplicitSynchronizationV1 {}
+}
@@ -0,0 +1,619 @@
+//! per-surface explicit synchronization support
+//!
+//! This object implements per-surface explicit synchronization.
+//!
+//! Synchronization refers to co-ordination of pipelined operations performed
+//! on buffers. Most GPU clients will schedule an asynchronous operation to
+//! render to the buffer, then immediately send the buffer to the compositor
+//! to be attached to a surface.
+//!
+//! In implicit synchronization, ensuring that the rendering operation is
+//! complete before the compositor displays the buffer is an implementation
+//! detail handled by either the kernel or userspace graphics driver.
+//!
+//! By contrast, in explicit synchronization, dma_fence objects mark when the
+//! asynchronous operations are complete. When submitting a buffer, the
+//! client provides an acquire fence which will be waited on before the
+//! compositor accesses the buffer. The Wayland server, through a
+//! zwp_linux_buffer_release_v1 object, will inform the client with an event
+//! which may be accompanied by a release fence, when the compositor will no
+//! longer access the buffer contents due to the specific commit that
+//! requested the release event.
+//!
+//! Each surface can be associated with only one object of this interface at
+//! any time.
+//!
+//! In version 1 of this interface, explicit synchronization is only
+//! guaranteed to be supported for buffers created with any version of the
+//! wp_linux_dmabuf buffer factory. Version 2 additionally guarantees
+//! explicit synchronization support for opaque EGL buffers, which is a type
+//! of platform specific buffers described in the EGL_WL_bind_wayland_display
+//! extension. Compositors are free to support explicit synchronization for
+//! additional buffer types.
+
+use {super::super::all_types::*, ::wl_client::builder::prelude::*};
+
+static INTERFACE: wl_interface = wl_interface {
+    name: c"zwp_linux_surface_synchronization_v1".as_ptr(),
+    version: 2,
+    method_count: 3,
+    methods: {
+        static MESSAGES: [wl_message; 3] = [
+            wl_message {
+                name: c"destroy".as_ptr(),
+                signature: c"".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 0] = [];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"set_acquire_fence".as_ptr(),
+                signature: c"h".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] = [None];
+                    TYPES.as_ptr().cast()
+                },
+            },
+            wl_message {
+                name: c"get_release".as_ptr(),
+                signature: c"n".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 1] =
+                        [Some(ZwpLinuxBufferReleaseV1::WL_INTERFACE)];
+                    TYPES.as_ptr().cast()
+                },
+            },
+        ];
+        MESSAGES.as_ptr()
+    },
+    event_count: 0,
+    events: ptr::null(),
+};
+
+/// Metadata about the zwp_linux_surface_synchronization_v1 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_linux_surface_synchronization_v1",
+    protocol: "zwp_linux_explicit_synchronization_unstable_v1",
+    version: 2,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_acquire_fence",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "fd",
+                ty: info::ArgType::Fd,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_release",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "release",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_linux_buffer_release_v1"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_fence",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "duplicate_fence",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "duplicate_release",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_surface",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "unsupported_buffer",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "no_buffer",
+                value: 5,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
+/// An owned zwp_linux_surface_synchronization_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Clone, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxSurfaceSynchronizationV1 {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedOwnedProxy,
+}
+
+/// A borrowed zwp_linux_surface_synchronization_v1 proxy.
+///
+/// See the documentation of [the module][self] for the interface description.
+#[derive(Eq, PartialEq)]
+#[repr(transparent)]
+pub struct ZwpLinuxSurfaceSynchronizationV1Ref {
+    /// This proxy has the interface INTERFACE.
+    proxy: UntypedBorrowedProxy,
+}
+
+// SAFETY: ZwpLinuxSurfaceSynchronizationV1 is a transparent wrapper around UntypedOwnedProxy
+unsafe impl UntypedOwnedProxyWrapper for ZwpLinuxSurfaceSynchronizationV1 {}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - The only invariant is that self.proxy has a compatible interface
+unsafe impl OwnedProxy for ZwpLinuxSurfaceSynchronizationV1 {
+    const INTERFACE: &'static str = "zwp_linux_surface_synchronization_v1";
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+    const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler =
+        private::EventHandler(private::NoOpEventHandler);
+    const MAX_VERSION: u32 = 2;
+
+    type Borrowed = ZwpLinuxSurfaceSynchronizationV1Ref;
+    type Api = private::ProxyApi;
+    type NoOpEventHandler = private::EventHandler<private::NoOpEventHandler>;
+}
+
+// SAFETY: ZwpLinuxSurfaceSynchronizationV1Ref is a transparent wrapper around UntypedBorrowedProxy
+unsafe impl UntypedBorrowedProxyWrapper for ZwpLinuxSurfaceSynchronizationV1Ref {}
+
+// SAFETY: - The only invariant is that self.proxy has a compatible interface
+unsafe impl BorrowedProxy for ZwpLinuxSurfaceSynchronizationV1Ref {
+    type Owned = ZwpLinuxSurfaceSynchronizationV1;
+}
+
+impl Deref for ZwpLinuxSurfaceSynchronizationV1 {
+    type Target = ZwpLinuxSurfaceSynchronizationV1Ref;
+
+    fn deref(&self) -> &Self::Target {
+        proxy::low_level::deref(self)
+    }
+}
+
+mod private {
+    use std::marker::PhantomData;
+
+    pub struct ProxyApi;
+
+    #[allow(dead_code)]
+    pub struct EventHandler<H>(pub(super) H);
+
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
+    #[allow(dead_code)]
+    pub struct NoOpEventHandler;
+}
+
+impl Debug for ZwpLinuxSurfaceSynchronizationV1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_linux_surface_synchronization_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl Debug for ZwpLinuxSurfaceSynchronizationV1Ref {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "zwp_linux_surface_synchronization_v1#{}",
+            self.proxy.id()
+        )
+    }
+}
+
+impl PartialEq<ZwpLinuxSurfaceSynchronizationV1Ref> for ZwpLinuxSurfaceSynchronizationV1 {
+    fn eq(&self, other: &ZwpLinuxSurfaceSynchronizationV1Ref) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+impl PartialEq<ZwpLinuxSurfaceSynchronizationV1> for ZwpLinuxSurfaceSynchronizationV1Ref {
+    fn eq(&self, other: &ZwpLinuxSurfaceSynchronizationV1) -> bool {
+        self.proxy == other.proxy
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxSurfaceSynchronizationV1 {
+    /// Since when the destroy request is available.
+    #[allow(dead_code)]
+    pub const REQ__DESTROY__SINCE: u32 = 1;
+
+    /// destroy synchronization object
+    ///
+    /// Destroy this explicit synchronization object.
+    ///
+    /// Any fence set by this object with set_acquire_fence since the last
+    /// commit will be discarded by the server. Any fences set by this object
+    /// before the last commit are not affected.
+    ///
+    /// zwp_linux_buffer_release_v1 objects created by this object are not
+    /// affected by this request.
+    #[inline]
+    pub fn destroy(&self) {
+        let mut args = [];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 0 < INTERFACE.method_count = 3
+        //         - the request signature is ``
+        unsafe {
+            self.proxy.send_destructor(0, &mut args);
+        }
+    }
+
+    /// Since when the get_release request is available.
+    #[allow(dead_code)]
+    pub const REQ__GET_RELEASE__SINCE: u32 = 1;
+
+    /// release fence for last-attached buffer
+    ///
+    /// Create a listener for the release of the buffer attached by the
+    /// client with wl_surface.attach. See zwp_linux_buffer_release_v1
+    /// documentation for more information.
+    ///
+    /// The release object is double-buffered state, and will be associated
+    /// with the buffer that is attached to the surface at wl_surface.commit
+    /// time.
+    ///
+    /// If a zwp_linux_buffer_release_v1 object has already been requested for
+    /// the surface in the same commit cycle, a DUPLICATE_RELEASE error is
+    /// raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error
+    /// is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    #[inline]
+    pub fn get_release(&self) -> ZwpLinuxBufferReleaseV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor::<false>(
+                2,
+                &mut args,
+                ZwpLinuxBufferReleaseV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxBufferReleaseV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+#[allow(dead_code)]
+impl ZwpLinuxSurfaceSynchronizationV1Ref {
+    /// set the acquire fence
+    ///
+    /// Set the acquire fence that must be signaled before the compositor
+    /// may sample from the buffer attached with wl_surface.attach. The fence
+    /// is a dma_fence kernel object.
+    ///
+    /// The acquire fence is double-buffered state, and will be applied on the
+    /// next wl_surface.commit request for the associated surface. Thus, it
+    /// applies only to the buffer that is attached to the surface at commit
+    /// time.
+    ///
+    /// If the provided fd is not a valid dma_fence fd, then an INVALID_FENCE
+    /// error is raised.
+    ///
+    /// If a fence has already been attached during the same commit cycle, a
+    /// DUPLICATE_FENCE error is raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error is
+    /// raised.
+    ///
+    /// If at surface commit time the attached buffer does not support explicit
+    /// synchronization, an UNSUPPORTED_BUFFER error is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `fd`: acquire fence fd
+    #[inline]
+    pub fn set_acquire_fence(&self, fd: BorrowedFd<'_>) {
+        let (arg0,) = (fd,);
+        let mut args = [wl_argument {
+            h: arg0.as_raw_fd(),
+        }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 1 < INTERFACE.method_count = 3
+        //         - the request signature is `h`
+        unsafe {
+            self.proxy.send_request(1, &mut args);
+        }
+    }
+
+    /// release fence for last-attached buffer
+    ///
+    /// Create a listener for the release of the buffer attached by the
+    /// client with wl_surface.attach. See zwp_linux_buffer_release_v1
+    /// documentation for more information.
+    ///
+    /// The release object is double-buffered state, and will be associated
+    /// with the buffer that is attached to the surface at wl_surface.commit
+    /// time.
+    ///
+    /// If a zwp_linux_buffer_release_v1 object has already been requested for
+    /// the surface in the same commit cycle, a DUPLICATE_RELEASE error is
+    /// raised.
+    ///
+    /// If the associated wl_surface was destroyed, a NO_SURFACE error
+    /// is raised.
+    ///
+    /// If at surface commit time there is no buffer attached, a NO_BUFFER
+    /// error is raised.
+    ///
+    /// # Arguments
+    ///
+    /// - `_queue`: The queue that the returned proxy is assigned to.
+    #[inline]
+    pub fn get_release(&self, _queue: &Queue) -> ZwpLinuxBufferReleaseV1 {
+        let mut args = [wl_argument { n: 0 }];
+        // SAFETY: - self.proxy has the interface INTERFACE
+        //         - 2 < INTERFACE.method_count = 3
+        //         - the request signature is `n`
+        //         - OwnedProxy::WL_INTERFACE is always a valid interface
+        let data = unsafe {
+            self.proxy.send_constructor(
+                _queue,
+                2,
+                &mut args,
+                ZwpLinuxBufferReleaseV1::WL_INTERFACE,
+                None,
+            )
+        };
+        // SAFETY: data has the interface ZwpLinuxBufferReleaseV1::WL_INTERFACE
+        unsafe { proxy::low_level::from_untyped_owned(data) }
+    }
+}
+
+/// An event handler for [ZwpLinuxSurfaceSynchronizationV1] proxies.
+#[allow(dead_code)]
+pub trait ZwpLinuxSurfaceSynchronizationV1EventHandler {}
+
+impl ZwpLinuxSurfaceSynchronizationV1EventHandler for private::NoOpEventHandler {}
+
+/// An event handler for [ZwpLinuxSurfaceSynchronizationV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxSurfaceSynchronizationV1EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpLinuxSurfaceSynchronizationV1] proxies.
+///
+/// This trait is the same as [ZwpLinuxSurfaceSynchronizationV1EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxSurfaceSynchronizationV1EventHandlerMut {}
+
+/// A mutable event handler for [ZwpLinuxSurfaceSynchronizationV1] proxies with mutable data.
+///
+/// This trait is the same as [ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpLinuxSurfaceSynchronizationV1EventHandlerMutWithData<T> {}
+
+impl<H> ZwpLinuxSurfaceSynchronizationV1EventHandler for MutEventHandler<H> where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandlerMut
+{
+}
+
+impl<T, H> ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData<U>,
+{
+}
+
+impl<T, U, H> ZwpLinuxSurfaceSynchronizationV1EventHandlerMutWithData<T>
+    for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandlerMutWithData<U>,
+{
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_linux_surface_synchronization_v1", opcode);
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_linux_surface_synchronization_v1", opcode);
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpLinuxSurfaceSynchronizationV1EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpLinuxSurfaceSynchronizationV1 {
+    /// Since when the error.invalid_fence enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_FENCE__SINCE: u32 = 1;
+    /// Since when the error.duplicate_fence enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DUPLICATE_FENCE__SINCE: u32 = 1;
+    /// Since when the error.duplicate_release enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DUPLICATE_RELEASE__SINCE: u32 = 1;
+    /// Since when the error.no_surface enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_SURFACE__SINCE: u32 = 1;
+    /// Since when the error.unsupported_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_UNSUPPORTED_BUFFER__SINCE: u32 = 1;
+    /// Since when the error.no_buffer enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_NO_BUFFER__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpLinuxSurfaceSynchronizationV1Error(pub u32);
+
+impl ZwpLinuxSurfaceSynchronizationV1Error {
+    /// the fence specified by the client could not be imported
+    #[allow(dead_code)]
+    pub const INVALID_FENCE: Self = Self(0);
+
+    /// multiple fences added for a single surface commit
+    #[allow(dead_code)]
+    pub const DUPLICATE_FENCE: Self = Self(1);
+
+    /// multiple releases added for a single surface commit
+    #[allow(dead_code)]
+    pub const DUPLICATE_RELEASE: Self = Self(2);
+
+    /// the associated wl_surface was destroyed
+    #[allow(dead_code)]
+    pub const NO_SURFACE: Self = Self(3);
+
+    /// the buffer does not support explicit synchronization
+    #[allow(dead_code)]
+    pub const UNSUPPORTED_BUFFER: Self = Self(4);
+
+    /// no buffer was attached
+    #[allow(dead_code)]
+    pub const NO_BUFFER: Self = Self(5);
+}
+
+impl Debug for ZwpLinuxSurfaceSynchronizationV1Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_FENCE => "INVALID_FENCE",
+            Self::DUPLICATE_FENCE => "DUPLICATE_FENCE",
+            Self::DUPLICATE_RELEASE => "DUPLICATE_RELEASE",
+            Self::NO_SURFACE => "NO_SURFACE",
+            Self::UNSUPPORTED_BUFFER => "UNSUPPORTED_BUFFER",
+            Self::NO_BUFFER => "NO_BUFFER",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    impl ZwpLinuxSurfaceSynchronizationV1 {}
+}
@@ -0,0 +1,20 @@
+#!/bin/bash
+
+# Copies the XML files of the wayland and wayland-protocols submodules into
+# all_protocols. Run generate.sh afterwards to update the wl-client-protocols crate.
+
+set -ex
+
+cd "$(dirname "$0")"
+
+if [ ! -f ../wayland/protocol/wayland.xml ]; then
+  echo "The submodules are not checked out. Run git submodule update --init first." >&2
+  exit 1
+fi
+
+rm -rf all_protocols
+mkdir all_protocols
+cp ../wayland/protocol/wayland.xml all_protocols/
+for dir in stable staging unstable; do
+  find ../wayland-protocols/$dir -name '*.xml' -exec cp {} all_protocols/ \;
+done
@@ -2,6 +2,5 @@
 
 /// We can't use build.rs for examples so we have to generate the protocols ahead of time.
 pub mod protocols;
-pub mod protocols_data;
 pub mod simple_window;
 pub mod singletons;
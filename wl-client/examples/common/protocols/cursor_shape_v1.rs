@@ -10,3 +10,13 @@
 
 pub mod wp_cursor_shape_device_v1;
 pub mod wp_cursor_shape_manager_v1;
+
+/// Metadata about the cursor_shape_v1 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "cursor_shape_v1",
+    interfaces: &[
+        &wp_cursor_shape_manager_v1::INFO,
+        &wp_cursor_shape_device_v1::INFO,
+    ],
+};
@@ -4,6 +4,69 @@ pub mod viewporter;
 pub mod wayland;
 pub mod xdg_shell;
 
+/// Metadata about all protocols in this module.
+#[allow(dead_code)]
+pub static PROTOCOLS: &[&::wl_client::info::ProtocolInfo] = &[
+    &cursor_shape_v1::PROTOCOL,
+    &viewporter::PROTOCOL,
+    &wayland::PROTOCOL,
+    &xdg_shell::PROTOCOL,
+    &tablet_v2::PROTOCOL,
+];
+
+/// Returns the metadata of the interface with the given name.
+///
+/// If multiple protocols define an interface with this name, the metadata of the
+/// preferred protocol is returned. If no protocol is preferred, `None` is returned
+/// and the interface can only be found via [`PROTOCOLS`].
+#[allow(dead_code, unreachable_code, clippy::match_single_binding)]
+pub fn interface_info(name: &str) -> Option<&'static ::wl_client::info::InterfaceInfo> {
+    let info = match name {
+        "wp_cursor_shape_manager_v1" => &cursor_shape_v1::wp_cursor_shape_manager_v1::INFO,
+        "wp_cursor_shape_device_v1" => &cursor_shape_v1::wp_cursor_shape_device_v1::INFO,
+        "wp_viewporter" => &viewporter::wp_viewporter::INFO,
+        "wp_viewport" => &viewporter::wp_viewport::INFO,
+        "wl_display" => &wayland::wl_display::INFO,
+        "wl_registry" => &wayland::wl_registry::INFO,
+        "wl_callback" => &wayland::wl_callback::INFO,
+        "wl_compositor" => &wayland::wl_compositor::INFO,
+        "wl_shm_pool" => &wayland::wl_shm_pool::INFO,
+        "wl_shm" => &wayland::wl_shm::INFO,
+        "wl_buffer" => &wayland::wl_buffer::INFO,
+        "wl_data_offer" => &wayland::wl_data_offer::INFO,
+        "wl_data_source" => &wayland::wl_data_source::INFO,
+        "wl_data_device" => &wayland::wl_data_device::INFO,
+        "wl_data_device_manager" => &wayland::wl_data_device_manager::INFO,
+        "wl_shell" => &wayland::wl_shell::INFO,
+        "wl_shell_surface" => &wayland::wl_shell_surface::INFO,
+        "wl_surface" => &wayland::wl_surface::INFO,
+        "wl_seat" => &wayland::wl_seat::INFO,
+        "wl_pointer" => &wayland::wl_pointer::INFO,
+        "wl_keyboard" => &wayland::wl_keyboard::INFO,
+        "wl_touch" => &wayland::wl_touch::INFO,
+        "wl_output" => &wayland::wl_output::INFO,
+        "wl_region" => &wayland::wl_region::INFO,
+        "wl_subcompositor" => &wayland::wl_subcompositor::INFO,
+        "wl_subsurface" => &wayland::wl_subsurface::INFO,
+        "wl_fixes" => &wayland::wl_fixes::INFO,
+        "xdg_wm_base" => &xdg_shell::xdg_wm_base::INFO,
+        "xdg_positioner" => &xdg_shell::xdg_positioner::INFO,
+        "xdg_surface" => &xdg_shell::xdg_surface::INFO,
+        "xdg_toplevel" => &xdg_shell::xdg_toplevel::INFO,
+        "xdg_popup" => &xdg_shell::xdg_popup::INFO,
+        "zwp_tablet_manager_v2" => &tablet_v2::zwp_tablet_manager_v2::INFO,
+        "zwp_tablet_seat_v2" => &tablet_v2::zwp_tablet_seat_v2::INFO,
+        "zwp_tablet_tool_v2" => &tablet_v2::zwp_tablet_tool_v2::INFO,
+        "zwp_tablet_v2" => &tablet_v2::zwp_tablet_v2::INFO,
+        "zwp_tablet_pad_ring_v2" => &tablet_v2::zwp_tablet_pad_ring_v2::INFO,
+        "zwp_tablet_pad_strip_v2" => &tablet_v2::zwp_tablet_pad_strip_v2::INFO,
+        "zwp_tablet_pad_group_v2" => &tablet_v2::zwp_tablet_pad_group_v2::INFO,
+        "zwp_tablet_pad_v2" => &tablet_v2::zwp_tablet_pad_v2::INFO,
+        _ => return None,
+    };
+    Some(info)
+}
+
 #[allow(unused_imports)]
 mod all_types {
     pub(super) use super::{
@@ -95,3 +95,19 @@ pub mod zwp_tablet_pad_v2;
 pub mod zwp_tablet_seat_v2;
 pub mod zwp_tablet_tool_v2;
 pub mod zwp_tablet_v2;
+
+/// Metadata about the tablet_v2 protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "tablet_v2",
+    interfaces: &[
+        &zwp_tablet_manager_v2::INFO,
+        &zwp_tablet_seat_v2::INFO,
+        &zwp_tablet_tool_v2::INFO,
+        &zwp_tablet_v2::INFO,
+        &zwp_tablet_pad_ring_v2::INFO,
+        &zwp_tablet_pad_strip_v2::INFO,
+        &zwp_tablet_pad_group_v2::INFO,
+        &zwp_tablet_pad_v2::INFO,
+    ],
+};
@@ -38,6 +38,47 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the zwp_tablet_manager_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_manager_v2",
+    protocol: "tablet_v2",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "get_tablet_seat",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "tablet_seat",
+                    ty: info::ArgType::NewId,
+                    interface: Some("zwp_tablet_seat_v2"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
 /// An owned zwp_tablet_manager_v2 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -92,11 +133,16 @@ impl Deref for ZwpTabletManagerV2 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -213,6 +259,50 @@ pub trait ZwpTabletManagerV2EventHandler {}
 
 impl ZwpTabletManagerV2EventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [ZwpTabletManagerV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletManagerV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV2EventHandlerWithData<T> {}
+
+/// A mutable event handler for [ZwpTabletManagerV2] proxies.
+///
+/// This trait is the same as [ZwpTabletManagerV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV2EventHandlerMut {}
+
+/// A mutable event handler for [ZwpTabletManagerV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletManagerV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletManagerV2EventHandlerMutWithData<T> {}
+
+impl<H> ZwpTabletManagerV2EventHandler for MutEventHandler<H> where
+    H: ZwpTabletManagerV2EventHandlerMut
+{
+}
+
+impl<T, H> ZwpTabletManagerV2EventHandlerWithData<T> for MutEventHandler<H> where
+    H: ZwpTabletManagerV2EventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> ZwpTabletManagerV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTabletManagerV2EventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> ZwpTabletManagerV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: ZwpTabletManagerV2EventHandlerMutWithData<U>
+{
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -233,6 +323,35 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletManagerV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("zwp_tablet_manager_v2", opcode);
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: ZwpTabletManagerV2EventHandler,
@@ -245,6 +364,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletManagerV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -97,6 +97,112 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the zwp_tablet_pad_group_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_pad_group_v2",
+    protocol: "tablet_v2",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "buttons",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "buttons",
+                ty: info::ArgType::Array,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "ring",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "ring",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_pad_ring_v2"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "strip",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "strip",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_pad_strip_v2"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "modes",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "modes",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "mode_switch",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mode",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[],
+};
+
 /// An owned zwp_tablet_pad_group_v2 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -151,11 +257,16 @@ impl Deref for ZwpTabletPadGroupV2 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -367,138 +478,687 @@ pub trait ZwpTabletPadGroupV2EventHandler {
 
 impl ZwpTabletPadGroupV2EventHandler for private::NoOpEventHandler {}
 
-// SAFETY: - INTERFACE is a valid wl_interface
-unsafe impl<H> EventHandler for private::EventHandler<H>
-where
-    H: ZwpTabletPadGroupV2EventHandler,
-{
-    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+/// An event handler for [ZwpTabletPadGroupV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletPadGroupV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletPadGroupV2EventHandlerWithData<T> {
+    /// buttons announced
+    ///
+    /// Sent on wp_tablet_pad_group initialization to announce the available
+    /// buttons in the group. Button indices start at 0, a button may only be
+    /// in one group at a time.
+    ///
+    /// This event is first sent in the initial burst of events before the
+    /// wp_tablet_pad_group.done event.
+    ///
+    /// Some buttons are reserved by the compositor. These buttons may not be
+    /// assigned to any wp_tablet_pad_group. Compositors may broadcast this
+    /// event in the case of changes to the mapping of these reserved buttons.
+    /// If the compositor happens to reserve all buttons in a group, this event
+    /// will be sent with an empty array.
+    ///
+    /// # Arguments
+    ///
+    /// - `buttons`: buttons in this group
+    #[inline]
+    fn buttons(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        let _ = buttons;
+    }
 
-    #[allow(unused_variables)]
-    unsafe fn handle_event(
+    /// ring announced
+    ///
+    /// Sent on wp_tablet_pad_group initialization to announce available rings.
+    /// One event is sent for each ring available on this pad group.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_pad_group.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `ring`:
+    #[inline]
+    fn ring(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        let _ = ring;
+    }
+
+    /// strip announced
+    ///
+    /// Sent on wp_tablet_pad initialization to announce available strips.
+    /// One event is sent for each strip available on this pad group.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_pad_group.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `strip`:
+    #[inline]
+    fn strip(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        let _ = strip;
+    }
+
+    /// mode-switch ability announced
+    ///
+    /// Sent on wp_tablet_pad_group initialization to announce that the pad
+    /// group may switch between modes. A client may use a mode to store a
+    /// specific configuration for buttons, rings and strips and use the
+    /// wl_tablet_pad_group.mode_switch event to toggle between these
+    /// configurations. Mode indices start at 0.
+    ///
+    /// Switching modes is compositor-dependent. See the
+    /// wp_tablet_pad_group.mode_switch event for more details.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_pad_group.done event. This event is only sent when more than
+    /// more than one mode is available.
+    ///
+    /// # Arguments
+    ///
+    /// - `modes`: the number of modes
+    #[inline]
+    fn modes(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        let _ = modes;
+    }
+
+    /// tablet group description events sequence complete
+    ///
+    /// This event is sent immediately to signal the end of the initial
+    /// burst of descriptive events. A client may consider the static
+    /// description of the tablet to be complete and finalize initialization
+    /// of the tablet group.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {}
+
+    /// mode switch event
+    ///
+    /// Notification that the mode was switched.
+    ///
+    /// A mode applies to all buttons, rings and strips in a group
+    /// simultaneously, but a client is not required to assign different actions
+    /// for each mode. For example, a client may have mode-specific button
+    /// mappings but map the ring to vertical scrolling in all modes. Mode
+    /// indices start at 0.
+    ///
+    /// Switching modes is compositor-dependent. The compositor may provide
+    /// visual cues to the user about the mode, e.g. by toggling LEDs on
+    /// the tablet device. Mode-switching may be software-controlled or
+    /// controlled by one or more physical buttons. For example, on a Wacom
+    /// Intuos Pro, the button inside the ring may be assigned to switch
+    /// between modes.
+    ///
+    /// The compositor will also send this event after wp_tablet_pad.enter on
+    /// each group in order to notify of the current mode. Groups that only
+    /// feature one mode will use mode=0 when emitting this event.
+    ///
+    /// If a button action in the new mode differs from the action in the
+    /// previous mode, the client should immediately issue a
+    /// wp_tablet_pad.set_feedback request for each changed button.
+    ///
+    /// If a ring or strip action in the new mode differs from the action
+    /// in the previous mode, the client should immediately issue a
+    /// wp_tablet_ring.set_feedback or wp_tablet_strip.set_feedback request
+    /// for each changed ring or strip.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: the time of the event with millisecond granularity
+    /// - `serial`:
+    /// - `mode`: the new mode of the pad
+    #[inline]
+    fn mode_switch(
         &self,
-        queue: &Queue,
-        data: *mut u8,
-        slf: &UntypedBorrowedProxy,
-        opcode: u32,
-        args: *mut wl_argument,
+        _data: &mut T,
+        _slf: &ZwpTabletPadGroupV2Ref,
+        time: u32,
+        serial: u32,
+        mode: u32,
     ) {
-        // SAFETY: This function requires that slf has the interface INTERFACE
-        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletPadGroupV2Ref>(slf) };
-        match opcode {
-            0 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an array
-                let arg0 = unsafe {
-                    let a = &*args[0].a;
-                    std::slice::from_raw_parts(a.data.cast(), a.size)
-                };
-                self.0.buttons(slf, arg0);
-            }
-            1 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an object
-                //         - ownership is transferred to this function
-                //         - INTERFACE requires that the object has the interface ZwpTabletPadRingV2::WL_INTERFACE
-                let arg0 = unsafe {
-                    UntypedOwnedProxy::from_plain_wl_proxy(
-                        queue,
-                        NonNull::new_unchecked(args[0].o.cast()),
-                        ZwpTabletPadRingV2::WL_INTERFACE,
-                    )
-                };
-                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadRingV2::WL_INTERFACE
-                let arg0 =
-                    unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadRingV2>(arg0) };
-                self.0.ring(slf, arg0);
-            }
-            2 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an object
-                //         - ownership is transferred to this function
-                //         - INTERFACE requires that the object has the interface ZwpTabletPadStripV2::WL_INTERFACE
-                let arg0 = unsafe {
-                    UntypedOwnedProxy::from_plain_wl_proxy(
-                        queue,
-                        NonNull::new_unchecked(args[0].o.cast()),
-                        ZwpTabletPadStripV2::WL_INTERFACE,
-                    )
-                };
-                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadStripV2::WL_INTERFACE
-                let arg0 =
-                    unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadStripV2>(arg0) };
-                self.0.strip(slf, arg0);
-            }
-            3 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                self.0.modes(slf, arg0);
-            }
-            4 => {
-                self.0.done(slf);
-            }
-            5 => {
-                // SAFETY: INTERFACE requires that there are 3 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { args[1].u };
-                // SAFETY: - INTERFACE requires that args[2] contains a uint
-                let arg2 = unsafe { args[2].u };
-                self.0.mode_switch(slf, arg0, arg1, arg2);
-            }
-            _ => {
-                invalid_opcode("zwp_tablet_pad_group_v2", opcode);
-            }
-        }
+        let _ = time;
+        let _ = serial;
+        let _ = mode;
     }
 }
 
-impl<H> CreateEventHandler<H> for private::ProxyApi
-where
-    H: ZwpTabletPadGroupV2EventHandler,
-{
-    type EventHandler = private::EventHandler<H>;
+/// A mutable event handler for [ZwpTabletPadGroupV2] proxies.
+///
+/// This trait is the same as [ZwpTabletPadGroupV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletPadGroupV2EventHandlerMut {
+    /// See [ZwpTabletPadGroupV2EventHandler::buttons].
+    #[inline]
+    fn buttons(&mut self, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        let _ = buttons;
+    }
 
+    /// See [ZwpTabletPadGroupV2EventHandler::ring].
     #[inline]
-    fn create_event_handler(handler: H) -> Self::EventHandler {
-        private::EventHandler(handler)
+    fn ring(&mut self, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        let _ = ring;
     }
-}
 
-/// Functional event handlers.
-pub mod event_handlers {
-    use super::*;
+    /// See [ZwpTabletPadGroupV2EventHandler::strip].
+    #[inline]
+    fn strip(&mut self, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        let _ = strip;
+    }
 
-    /// Event handler for buttons events.
-    pub struct Buttons<F>(F);
-    impl<F> ZwpTabletPadGroupV2EventHandler for Buttons<F>
-    where
-        F: Fn(&ZwpTabletPadGroupV2Ref, &[u8]),
-    {
-        #[inline]
-        fn buttons(&self, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
-            self.0(_slf, buttons)
-        }
+    /// See [ZwpTabletPadGroupV2EventHandler::modes].
+    #[inline]
+    fn modes(&mut self, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        let _ = modes;
     }
 
-    /// Event handler for ring events.
-    pub struct Ring<F>(F);
-    impl<F> ZwpTabletPadGroupV2EventHandler for Ring<F>
-    where
-        F: Fn(&ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+    /// See [ZwpTabletPadGroupV2EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ZwpTabletPadGroupV2Ref) {}
+
+    /// See [ZwpTabletPadGroupV2EventHandler::mode_switch].
+    #[inline]
+    fn mode_switch(&mut self, _slf: &ZwpTabletPadGroupV2Ref, time: u32, serial: u32, mode: u32) {
+        let _ = time;
+        let _ = serial;
+        let _ = mode;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletPadGroupV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletPadGroupV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletPadGroupV2EventHandlerMutWithData<T> {
+    /// See [ZwpTabletPadGroupV2EventHandler::buttons].
+    #[inline]
+    fn buttons(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        let _ = buttons;
+    }
+
+    /// See [ZwpTabletPadGroupV2EventHandler::ring].
+    #[inline]
+    fn ring(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        let _ = ring;
+    }
+
+    /// See [ZwpTabletPadGroupV2EventHandler::strip].
+    #[inline]
+    fn strip(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        let _ = strip;
+    }
+
+    /// See [ZwpTabletPadGroupV2EventHandler::modes].
+    #[inline]
+    fn modes(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        let _ = modes;
+    }
+
+    /// See [ZwpTabletPadGroupV2EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {}
+
+    /// See [ZwpTabletPadGroupV2EventHandler::mode_switch].
+    #[inline]
+    fn mode_switch(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadGroupV2Ref,
+        time: u32,
+        serial: u32,
+        mode: u32,
+    ) {
+        let _ = time;
+        let _ = serial;
+        let _ = mode;
+    }
+}
+
+impl<H> ZwpTabletPadGroupV2EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletPadGroupV2EventHandlerMut,
+{
+    #[inline]
+    fn buttons(&self, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        self.with(|h| h.buttons(_slf, buttons))
+    }
+
+    #[inline]
+    fn ring(&self, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        self.with(|h| h.ring(_slf, ring))
+    }
+
+    #[inline]
+    fn strip(&self, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        self.with(|h| h.strip(_slf, strip))
+    }
+
+    #[inline]
+    fn modes(&self, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        self.with(|h| h.modes(_slf, modes))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ZwpTabletPadGroupV2Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn mode_switch(&self, _slf: &ZwpTabletPadGroupV2Ref, time: u32, serial: u32, mode: u32) {
+        self.with(|h| h.mode_switch(_slf, time, serial, mode))
+    }
+}
+
+impl<T, H> ZwpTabletPadGroupV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletPadGroupV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn buttons(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        self.with(|h| h.buttons(_data, _slf, buttons))
+    }
+
+    #[inline]
+    fn ring(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        self.with(|h| h.ring(_data, _slf, ring))
+    }
+
+    #[inline]
+    fn strip(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        self.with(|h| h.strip(_data, _slf, strip))
+    }
+
+    #[inline]
+    fn modes(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        self.with(|h| h.modes(_data, _slf, modes))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn mode_switch(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadGroupV2Ref,
+        time: u32,
+        serial: u32,
+        mode: u32,
+    ) {
+        self.with(|h| h.mode_switch(_data, _slf, time, serial, mode))
+    }
+}
+
+impl<T, U, H> ZwpTabletPadGroupV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletPadGroupV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn buttons(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        let _data = self.project(_data);
+        self.handler().buttons(_data, _slf, buttons)
+    }
+
+    #[inline]
+    fn ring(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        let _data = self.project(_data);
+        self.handler().ring(_data, _slf, ring)
+    }
+
+    #[inline]
+    fn strip(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        let _data = self.project(_data);
+        self.handler().strip(_data, _slf, strip)
+    }
+
+    #[inline]
+    fn modes(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        let _data = self.project(_data);
+        self.handler().modes(_data, _slf, modes)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn mode_switch(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadGroupV2Ref,
+        time: u32,
+        serial: u32,
+        mode: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().mode_switch(_data, _slf, time, serial, mode)
+    }
+}
+
+impl<T, U, H> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletPadGroupV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn buttons(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+        let _data = self.project(_data);
+        self.handler_mut().buttons(_data, _slf, buttons)
+    }
+
+    #[inline]
+    fn ring(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+        let _data = self.project(_data);
+        self.handler_mut().ring(_data, _slf, ring)
+    }
+
+    #[inline]
+    fn strip(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+        let _data = self.project(_data);
+        self.handler_mut().strip(_data, _slf, strip)
+    }
+
+    #[inline]
+    fn modes(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().modes(_data, _slf, modes)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn mode_switch(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadGroupV2Ref,
+        time: u32,
+        serial: u32,
+        mode: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .mode_switch(_data, _slf, time, serial, mode)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTabletPadGroupV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletPadGroupV2Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.buttons(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletPadRingV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletPadRingV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadRingV2::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadRingV2>(arg0) };
+                self.0.ring(slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletPadStripV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletPadStripV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadStripV2::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadStripV2>(arg0) };
+                self.0.strip(slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.modes(slf, arg0);
+            }
+            4 => {
+                self.0.done(slf);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.mode_switch(slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_pad_group_v2", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletPadGroupV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an array
+                let arg0 = unsafe {
+                    let a = &*args[0].a;
+                    std::slice::from_raw_parts(a.data.cast(), a.size)
+                };
+                self.0.buttons(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletPadRingV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletPadRingV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadRingV2::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadRingV2>(arg0) };
+                self.0.ring(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletPadStripV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletPadStripV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadStripV2::WL_INTERFACE
+                let arg0 =
+                    unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadStripV2>(arg0) };
+                self.0.strip(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.modes(data, slf, arg0);
+            }
+            4 => {
+                self.0.done(data, slf);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.mode_switch(data, slf, arg0, arg1, arg2);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_pad_group_v2", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTabletPadGroupV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for buttons events.
+    pub struct Buttons<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandler for Buttons<F>
+    where
+        F: Fn(&ZwpTabletPadGroupV2Ref, &[u8]),
+    {
+        #[inline]
+        fn buttons(&self, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+            self.0(_slf, buttons)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerWithData<T> for Buttons<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, &[u8]),
+    {
+        #[inline]
+        fn buttons(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+            self.0(_data, _slf, buttons)
+        }
+    }
+
+    /// Event handler for ring events.
+    pub struct Ring<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandler for Ring<F>
+    where
+        F: Fn(&ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
     {
         #[inline]
         fn ring(&self, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
             self.0(_slf, ring)
         }
     }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerWithData<T> for Ring<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+    {
+        #[inline]
+        fn ring(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+            self.0(_data, _slf, ring)
+        }
+    }
 
     /// Event handler for strip events.
     pub struct Strip<F>(F);
@@ -511,6 +1171,15 @@ pub mod event_handlers {
             self.0(_slf, strip)
         }
     }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerWithData<T> for Strip<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+    {
+        #[inline]
+        fn strip(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+            self.0(_data, _slf, strip)
+        }
+    }
 
     /// Event handler for modes events.
     pub struct Modes<F>(F);
@@ -523,6 +1192,15 @@ pub mod event_handlers {
             self.0(_slf, modes)
         }
     }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerWithData<T> for Modes<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, u32),
+    {
+        #[inline]
+        fn modes(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+            self.0(_data, _slf, modes)
+        }
+    }
 
     /// Event handler for done events.
     pub struct Done<F>(F);
@@ -535,6 +1213,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadGroupV2Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for mode_switch events.
     pub struct ModeSwitch<F>(F);
@@ -546,16 +1233,187 @@ pub mod event_handlers {
         fn mode_switch(&self, _slf: &ZwpTabletPadGroupV2Ref, time: u32, serial: u32, mode: u32) {
             self.0(_slf, time, serial, mode)
         }
-    }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerWithData<T> for ModeSwitch<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn mode_switch(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadGroupV2Ref,
+            time: u32,
+            serial: u32,
+            mode: u32,
+        ) {
+            self.0(_data, _slf, time, serial, mode)
+        }
+    }
+
+    /// Mutable event handler for buttons events.
+    pub struct ButtonsMut<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandlerMut for ButtonsMut<F>
+    where
+        F: FnMut(&ZwpTabletPadGroupV2Ref, &[u8]),
+    {
+        #[inline]
+        fn buttons(&mut self, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+            self.0(_slf, buttons)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for ButtonsMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, &[u8]),
+    {
+        #[inline]
+        fn buttons(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+            self.0(_data, _slf, buttons)
+        }
+    }
+
+    /// Mutable event handler for ring events.
+    pub struct RingMut<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandlerMut for RingMut<F>
+    where
+        F: FnMut(&ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+    {
+        #[inline]
+        fn ring(&mut self, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+            self.0(_slf, ring)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for RingMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+    {
+        #[inline]
+        fn ring(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+            self.0(_data, _slf, ring)
+        }
+    }
+
+    /// Mutable event handler for strip events.
+    pub struct StripMut<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandlerMut for StripMut<F>
+    where
+        F: FnMut(&ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+    {
+        #[inline]
+        fn strip(&mut self, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+            self.0(_slf, strip)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for StripMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+    {
+        #[inline]
+        fn strip(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadGroupV2Ref,
+            strip: ZwpTabletPadStripV2,
+        ) {
+            self.0(_data, _slf, strip)
+        }
+    }
+
+    /// Mutable event handler for modes events.
+    pub struct ModesMut<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandlerMut for ModesMut<F>
+    where
+        F: FnMut(&ZwpTabletPadGroupV2Ref, u32),
+    {
+        #[inline]
+        fn modes(&mut self, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+            self.0(_slf, modes)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for ModesMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, u32),
+    {
+        #[inline]
+        fn modes(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+            self.0(_data, _slf, modes)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ZwpTabletPadGroupV2Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ZwpTabletPadGroupV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for mode_switch events.
+    pub struct ModeSwitchMut<F>(F);
+    impl<F> ZwpTabletPadGroupV2EventHandlerMut for ModeSwitchMut<F>
+    where
+        F: FnMut(&ZwpTabletPadGroupV2Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn mode_switch(
+            &mut self,
+            _slf: &ZwpTabletPadGroupV2Ref,
+            time: u32,
+            serial: u32,
+            mode: u32,
+        ) {
+            self.0(_slf, time, serial, mode)
+        }
+    }
+    impl<T, F> ZwpTabletPadGroupV2EventHandlerMutWithData<T> for ModeSwitchMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, u32, u32, u32),
+    {
+        #[inline]
+        fn mode_switch(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadGroupV2Ref,
+            time: u32,
+            serial: u32,
+            mode: u32,
+        ) {
+            self.0(_data, _slf, time, serial, mode)
+        }
+    }
+
+    impl ZwpTabletPadGroupV2 {
+        /// Creates an event handler for buttons events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_buttons<F>(f: F) -> Buttons<F>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref, &[u8]),
+        {
+            Buttons(f)
+        }
 
-    impl ZwpTabletPadGroupV2 {
-        /// Creates an event handler for buttons events.
+        /// Creates an event handler with mutable data for buttons events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_buttons<F>(f: F) -> Buttons<F>
+        pub fn on_buttons_with_data<T, F>(f: F) -> Buttons<F>
         where
-            F: Fn(&ZwpTabletPadGroupV2Ref, &[u8]),
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, &[u8]),
         {
             Buttons(f)
         }
@@ -571,6 +1429,17 @@ pub mod event_handlers {
             Ring(f)
         }
 
+        /// Creates an event handler with mutable data for ring events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ring_with_data<T, F>(f: F) -> Ring<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+        {
+            Ring(f)
+        }
+
         /// Creates an event handler for strip events.
         ///
         /// The event handler ignores all other events.
@@ -582,6 +1451,17 @@ pub mod event_handlers {
             Strip(f)
         }
 
+        /// Creates an event handler with mutable data for strip events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_strip_with_data<T, F>(f: F) -> Strip<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+        {
+            Strip(f)
+        }
+
         /// Creates an event handler for modes events.
         ///
         /// The event handler ignores all other events.
@@ -593,6 +1473,17 @@ pub mod event_handlers {
             Modes(f)
         }
 
+        /// Creates an event handler with mutable data for modes events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_modes_with_data<T, F>(f: F) -> Modes<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, u32),
+        {
+            Modes(f)
+        }
+
         /// Creates an event handler for done events.
         ///
         /// The event handler ignores all other events.
@@ -604,6 +1495,17 @@ pub mod event_handlers {
             Done(f)
         }
 
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref),
+        {
+            Done(f)
+        }
+
         /// Creates an event handler for mode_switch events.
         ///
         /// The event handler ignores all other events.
@@ -614,5 +1516,485 @@ pub mod event_handlers {
         {
             ModeSwitch(f)
         }
+
+        /// Creates an event handler with mutable data for mode_switch events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_mode_switch_with_data<T, F>(f: F) -> ModeSwitch<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, u32, u32, u32),
+        {
+            ModeSwitch(f)
+        }
+
+        /// Creates a mutable event handler for buttons events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_buttons_mut<F>(f: F) -> ButtonsMut<F>
+        where
+            F: FnMut(&ZwpTabletPadGroupV2Ref, &[u8]),
+        {
+            ButtonsMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for buttons events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_buttons_mut_with_data<T, F>(f: F) -> ButtonsMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, &[u8]),
+        {
+            ButtonsMut(f)
+        }
+
+        /// Creates a mutable event handler for ring events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ring_mut<F>(f: F) -> RingMut<F>
+        where
+            F: FnMut(&ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+        {
+            RingMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ring events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ring_mut_with_data<T, F>(f: F) -> RingMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+        {
+            RingMut(f)
+        }
+
+        /// Creates a mutable event handler for strip events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_strip_mut<F>(f: F) -> StripMut<F>
+        where
+            F: FnMut(&ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+        {
+            StripMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for strip events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_strip_mut_with_data<T, F>(f: F) -> StripMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+        {
+            StripMut(f)
+        }
+
+        /// Creates a mutable event handler for modes events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modes_mut<F>(f: F) -> ModesMut<F>
+        where
+            F: FnMut(&ZwpTabletPadGroupV2Ref, u32),
+        {
+            ModesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for modes events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modes_mut_with_data<T, F>(f: F) -> ModesMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, u32),
+        {
+            ModesMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ZwpTabletPadGroupV2Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for mode_switch events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_mode_switch_mut<F>(f: F) -> ModeSwitchMut<F>
+        where
+            F: FnMut(&ZwpTabletPadGroupV2Ref, u32, u32, u32),
+        {
+            ModeSwitchMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for mode_switch events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_mode_switch_mut_with_data<T, F>(f: F) -> ModeSwitchMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadGroupV2Ref, u32, u32, u32),
+        {
+            ModeSwitchMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                buttons: Ignore,
+                ring: Ignore,
+                strip: Ignore,
+                modes: Ignore,
+                done: Ignore,
+                mode_switch: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletPadGroupV2EventHandler for Ignore {}
+    impl<T> ZwpTabletPadGroupV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletPadGroupV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        buttons: H0,
+        ring: H1,
+        strip: H2,
+        modes: H3,
+        done: H4,
+        mode_switch: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> ZwpTabletPadGroupV2EventHandler for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ZwpTabletPadGroupV2EventHandler,
+        H1: ZwpTabletPadGroupV2EventHandler,
+        H2: ZwpTabletPadGroupV2EventHandler,
+        H3: ZwpTabletPadGroupV2EventHandler,
+        H4: ZwpTabletPadGroupV2EventHandler,
+        H5: ZwpTabletPadGroupV2EventHandler,
+    {
+        #[inline]
+        fn buttons(&self, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+            self.buttons.buttons(_slf, buttons)
+        }
+
+        #[inline]
+        fn ring(&self, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+            self.ring.ring(_slf, ring)
+        }
+
+        #[inline]
+        fn strip(&self, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+            self.strip.strip(_slf, strip)
+        }
+
+        #[inline]
+        fn modes(&self, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+            self.modes.modes(_slf, modes)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ZwpTabletPadGroupV2Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn mode_switch(&self, _slf: &ZwpTabletPadGroupV2Ref, time: u32, serial: u32, mode: u32) {
+            self.mode_switch.mode_switch(_slf, time, serial, mode)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> ZwpTabletPadGroupV2EventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+        H1: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+        H2: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+        H3: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+        H4: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+        H5: ZwpTabletPadGroupV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn buttons(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, buttons: &[u8]) {
+            self.buttons.buttons(_data, _slf, buttons)
+        }
+
+        #[inline]
+        fn ring(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, ring: ZwpTabletPadRingV2) {
+            self.ring.ring(_data, _slf, ring)
+        }
+
+        #[inline]
+        fn strip(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, strip: ZwpTabletPadStripV2) {
+            self.strip.strip(_data, _slf, strip)
+        }
+
+        #[inline]
+        fn modes(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref, modes: u32) {
+            self.modes.modes(_data, _slf, modes)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTabletPadGroupV2Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn mode_switch(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadGroupV2Ref,
+            time: u32,
+            serial: u32,
+            mode: u32,
+        ) {
+            self.mode_switch
+                .mode_switch(_data, _slf, time, serial, mode)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for buttons events.
+        #[allow(dead_code)]
+        pub fn on_buttons<F>(self, f: F) -> Handler<Buttons<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref, &[u8]),
+        {
+            Handler {
+                buttons: Buttons(f),
+                ring: self.ring,
+                strip: self.strip,
+                modes: self.modes,
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for buttons events.
+        #[allow(dead_code)]
+        pub fn on_buttons_with_data<T, F>(self, f: F) -> Handler<Buttons<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, &[u8]),
+        {
+            Handler {
+                buttons: Buttons(f),
+                ring: self.ring,
+                strip: self.strip,
+                modes: self.modes,
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure for ring events.
+        #[allow(dead_code)]
+        pub fn on_ring<F>(self, f: F) -> Handler<H0, Ring<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: Ring(f),
+                strip: self.strip,
+                modes: self.modes,
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for ring events.
+        #[allow(dead_code)]
+        pub fn on_ring_with_data<T, F>(self, f: F) -> Handler<H0, Ring<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadRingV2),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: Ring(f),
+                strip: self.strip,
+                modes: self.modes,
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure for strip events.
+        #[allow(dead_code)]
+        pub fn on_strip<F>(self, f: F) -> Handler<H0, H1, Strip<F>, H3, H4, H5>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: Strip(f),
+                modes: self.modes,
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for strip events.
+        #[allow(dead_code)]
+        pub fn on_strip_with_data<T, F>(self, f: F) -> Handler<H0, H1, Strip<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, ZwpTabletPadStripV2),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: Strip(f),
+                modes: self.modes,
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure for modes events.
+        #[allow(dead_code)]
+        pub fn on_modes<F>(self, f: F) -> Handler<H0, H1, H2, Modes<F>, H4, H5>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref, u32),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: self.strip,
+                modes: Modes(f),
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for modes events.
+        #[allow(dead_code)]
+        pub fn on_modes_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Modes<F>, H4, H5>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, u32),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: self.strip,
+                modes: Modes(f),
+                done: self.done,
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, H2, H3, Done<F>, H5>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: self.strip,
+                modes: self.modes,
+                done: Done(f),
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Done<F>, H5>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: self.strip,
+                modes: self.modes,
+                done: Done(f),
+                mode_switch: self.mode_switch,
+            }
+        }
+
+        /// Sets the closure for mode_switch events.
+        #[allow(dead_code)]
+        pub fn on_mode_switch<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, ModeSwitch<F>>
+        where
+            F: Fn(&ZwpTabletPadGroupV2Ref, u32, u32, u32),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: self.strip,
+                modes: self.modes,
+                done: self.done,
+                mode_switch: ModeSwitch(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for mode_switch events.
+        #[allow(dead_code)]
+        pub fn on_mode_switch_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, ModeSwitch<F>>
+        where
+            F: Fn(&mut T, &ZwpTabletPadGroupV2Ref, u32, u32, u32),
+        {
+            Handler {
+                buttons: self.buttons,
+                ring: self.ring,
+                strip: self.strip,
+                modes: self.modes,
+                done: self.done,
+                mode_switch: ModeSwitch(f),
+            }
+        }
     }
 }
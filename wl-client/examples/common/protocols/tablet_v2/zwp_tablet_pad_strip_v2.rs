@@ -73,6 +73,104 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the zwp_tablet_pad_strip_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_pad_strip_v2",
+    protocol: "tablet_v2",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_feedback",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "description",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "source",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "source",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("source"),
+            }],
+        },
+        info::MessageInfo {
+            name: "position",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "position",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "stop",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "frame",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "time",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "source",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "finger",
+            value: 1,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
 /// An owned zwp_tablet_pad_strip_v2 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -127,11 +225,16 @@ impl Deref for ZwpTabletPadStripV2 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -331,6 +434,286 @@ pub trait ZwpTabletPadStripV2EventHandler {
 
 impl ZwpTabletPadStripV2EventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [ZwpTabletPadStripV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletPadStripV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletPadStripV2EventHandlerWithData<T> {
+    /// strip event source
+    ///
+    /// Source information for strip events.
+    ///
+    /// This event does not occur on its own. It is sent before a
+    /// wp_tablet_pad_strip.frame event and carries the source information
+    /// for all events within that frame.
+    ///
+    /// The source specifies how this event was generated. If the source is
+    /// wp_tablet_pad_strip.source.finger, a wp_tablet_pad_strip.stop event
+    /// will be sent when the user lifts their finger off the device.
+    ///
+    /// This event is optional. If the source is unknown for an interaction,
+    /// no event is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `source`: the event source
+    #[inline]
+    fn source(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadStripV2Ref,
+        source: ZwpTabletPadStripV2Source,
+    ) {
+        let _ = source;
+    }
+
+    /// position changed
+    ///
+    /// Sent whenever the position on a strip changes.
+    ///
+    /// The position is normalized to a range of [0, 65535], the 0-value
+    /// represents the top-most and/or left-most position of the strip in
+    /// the pad's current rotation.
+    ///
+    /// # Arguments
+    ///
+    /// - `position`: the current position
+    #[inline]
+    fn position(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        let _ = position;
+    }
+
+    /// interaction stopped
+    ///
+    /// Stop notification for strip events.
+    ///
+    /// For some wp_tablet_pad_strip.source types, a wp_tablet_pad_strip.stop
+    /// event is sent to notify a client that the interaction with the strip
+    /// has terminated. This enables the client to implement kinetic
+    /// scrolling. See the wp_tablet_pad_strip.source documentation for
+    /// information on when this event may be generated.
+    ///
+    /// Any wp_tablet_pad_strip.position events with the same source after this
+    /// event should be considered as the start of a new interaction.
+    #[inline]
+    fn stop(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {}
+
+    /// end of a strip event sequence
+    ///
+    /// Indicates the end of a set of events that represent one logical
+    /// hardware strip event. A client is expected to accumulate the data
+    /// in all events within the frame before proceeding.
+    ///
+    /// All wp_tablet_pad_strip events before a wp_tablet_pad_strip.frame event belong
+    /// logically together. For example, on termination of a finger interaction
+    /// on a strip the compositor will send a wp_tablet_pad_strip.source event,
+    /// a wp_tablet_pad_strip.stop event and a wp_tablet_pad_strip.frame
+    /// event.
+    ///
+    /// A wp_tablet_pad_strip.frame event is sent for every logical event
+    /// group, even if the group only contains a single wp_tablet_pad_strip
+    /// event. Specifically, a client may get a sequence: position, frame,
+    /// position, frame, etc.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletPadStripV2] proxies.
+///
+/// This trait is the same as [ZwpTabletPadStripV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletPadStripV2EventHandlerMut {
+    /// See [ZwpTabletPadStripV2EventHandler::source].
+    #[inline]
+    fn source(&mut self, _slf: &ZwpTabletPadStripV2Ref, source: ZwpTabletPadStripV2Source) {
+        let _ = source;
+    }
+
+    /// See [ZwpTabletPadStripV2EventHandler::position].
+    #[inline]
+    fn position(&mut self, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        let _ = position;
+    }
+
+    /// See [ZwpTabletPadStripV2EventHandler::stop].
+    #[inline]
+    fn stop(&mut self, _slf: &ZwpTabletPadStripV2Ref) {}
+
+    /// See [ZwpTabletPadStripV2EventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletPadStripV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletPadStripV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletPadStripV2EventHandlerMutWithData<T> {
+    /// See [ZwpTabletPadStripV2EventHandler::source].
+    #[inline]
+    fn source(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadStripV2Ref,
+        source: ZwpTabletPadStripV2Source,
+    ) {
+        let _ = source;
+    }
+
+    /// See [ZwpTabletPadStripV2EventHandler::position].
+    #[inline]
+    fn position(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        let _ = position;
+    }
+
+    /// See [ZwpTabletPadStripV2EventHandler::stop].
+    #[inline]
+    fn stop(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {}
+
+    /// See [ZwpTabletPadStripV2EventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+impl<H> ZwpTabletPadStripV2EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletPadStripV2EventHandlerMut,
+{
+    #[inline]
+    fn source(&self, _slf: &ZwpTabletPadStripV2Ref, source: ZwpTabletPadStripV2Source) {
+        self.with(|h| h.source(_slf, source))
+    }
+
+    #[inline]
+    fn position(&self, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        self.with(|h| h.position(_slf, position))
+    }
+
+    #[inline]
+    fn stop(&self, _slf: &ZwpTabletPadStripV2Ref) {
+        self.with(|h| h.stop(_slf))
+    }
+
+    #[inline]
+    fn frame(&self, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        self.with(|h| h.frame(_slf, time))
+    }
+}
+
+impl<T, H> ZwpTabletPadStripV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletPadStripV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn source(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadStripV2Ref,
+        source: ZwpTabletPadStripV2Source,
+    ) {
+        self.with(|h| h.source(_data, _slf, source))
+    }
+
+    #[inline]
+    fn position(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        self.with(|h| h.position(_data, _slf, position))
+    }
+
+    #[inline]
+    fn stop(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {
+        self.with(|h| h.stop(_data, _slf))
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        self.with(|h| h.frame(_data, _slf, time))
+    }
+}
+
+impl<T, U, H> ZwpTabletPadStripV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletPadStripV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn source(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadStripV2Ref,
+        source: ZwpTabletPadStripV2Source,
+    ) {
+        let _data = self.project(_data);
+        self.handler().source(_data, _slf, source)
+    }
+
+    #[inline]
+    fn position(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        let _data = self.project(_data);
+        self.handler().position(_data, _slf, position)
+    }
+
+    #[inline]
+    fn stop(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {
+        let _data = self.project(_data);
+        self.handler().stop(_data, _slf)
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        let _data = self.project(_data);
+        self.handler().frame(_data, _slf, time)
+    }
+}
+
+impl<T, U, H> ZwpTabletPadStripV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletPadStripV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn source(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletPadStripV2Ref,
+        source: ZwpTabletPadStripV2Source,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().source(_data, _slf, source)
+    }
+
+    #[inline]
+    fn position(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().position(_data, _slf, position)
+    }
+
+    #[inline]
+    fn stop(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().stop(_data, _slf)
+    }
+
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().frame(_data, _slf, time)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -381,6 +764,68 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletPadStripV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletPadStripV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpTabletPadStripV2Source(args[0].u) };
+                self.0.source(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.position(data, slf, arg0);
+            }
+            2 => {
+                self.0.stop(data, slf);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.frame(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_pad_strip_v2", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: ZwpTabletPadStripV2EventHandler,
@@ -393,6 +838,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletPadStripV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl ZwpTabletPadStripV2 {
     /// Since when the source.finger enum variant is available.
     #[allow(dead_code)]
@@ -440,6 +898,20 @@ pub mod event_handlers {
             self.0(_slf, source)
         }
     }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerWithData<T> for Source<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+    {
+        #[inline]
+        fn source(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadStripV2Ref,
+            source: ZwpTabletPadStripV2Source,
+        ) {
+            self.0(_data, _slf, source)
+        }
+    }
 
     /// Event handler for position events.
     pub struct Position<F>(F);
@@ -452,6 +924,15 @@ pub mod event_handlers {
             self.0(_slf, position)
         }
     }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerWithData<T> for Position<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadStripV2Ref, u32),
+    {
+        #[inline]
+        fn position(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+            self.0(_data, _slf, position)
+        }
+    }
 
     /// Event handler for stop events.
     pub struct Stop<F>(F);
@@ -464,6 +945,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerWithData<T> for Stop<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadStripV2Ref),
+    {
+        #[inline]
+        fn stop(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for frame events.
     pub struct Frame<F>(F);
@@ -476,6 +966,104 @@ pub mod event_handlers {
             self.0(_slf, time)
         }
     }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerWithData<T> for Frame<F>
+    where
+        F: Fn(&mut T, &ZwpTabletPadStripV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+            self.0(_data, _slf, time)
+        }
+    }
+
+    /// Mutable event handler for source events.
+    pub struct SourceMut<F>(F);
+    impl<F> ZwpTabletPadStripV2EventHandlerMut for SourceMut<F>
+    where
+        F: FnMut(&ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+    {
+        #[inline]
+        fn source(&mut self, _slf: &ZwpTabletPadStripV2Ref, source: ZwpTabletPadStripV2Source) {
+            self.0(_slf, source)
+        }
+    }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerMutWithData<T> for SourceMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+    {
+        #[inline]
+        fn source(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadStripV2Ref,
+            source: ZwpTabletPadStripV2Source,
+        ) {
+            self.0(_data, _slf, source)
+        }
+    }
+
+    /// Mutable event handler for position events.
+    pub struct PositionMut<F>(F);
+    impl<F> ZwpTabletPadStripV2EventHandlerMut for PositionMut<F>
+    where
+        F: FnMut(&ZwpTabletPadStripV2Ref, u32),
+    {
+        #[inline]
+        fn position(&mut self, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+            self.0(_slf, position)
+        }
+    }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerMutWithData<T> for PositionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadStripV2Ref, u32),
+    {
+        #[inline]
+        fn position(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+            self.0(_data, _slf, position)
+        }
+    }
+
+    /// Mutable event handler for stop events.
+    pub struct StopMut<F>(F);
+    impl<F> ZwpTabletPadStripV2EventHandlerMut for StopMut<F>
+    where
+        F: FnMut(&ZwpTabletPadStripV2Ref),
+    {
+        #[inline]
+        fn stop(&mut self, _slf: &ZwpTabletPadStripV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerMutWithData<T> for StopMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadStripV2Ref),
+    {
+        #[inline]
+        fn stop(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for frame events.
+    pub struct FrameMut<F>(F);
+    impl<F> ZwpTabletPadStripV2EventHandlerMut for FrameMut<F>
+    where
+        F: FnMut(&ZwpTabletPadStripV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&mut self, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+            self.0(_slf, time)
+        }
+    }
+    impl<T, F> ZwpTabletPadStripV2EventHandlerMutWithData<T> for FrameMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletPadStripV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+            self.0(_data, _slf, time)
+        }
+    }
 
     impl ZwpTabletPadStripV2 {
         /// Creates an event handler for source events.
@@ -489,6 +1077,17 @@ pub mod event_handlers {
             Source(f)
         }
 
+        /// Creates an event handler with mutable data for source events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_source_with_data<T, F>(f: F) -> Source<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+        {
+            Source(f)
+        }
+
         /// Creates an event handler for position events.
         ///
         /// The event handler ignores all other events.
@@ -500,6 +1099,17 @@ pub mod event_handlers {
             Position(f)
         }
 
+        /// Creates an event handler with mutable data for position events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_position_with_data<T, F>(f: F) -> Position<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref, u32),
+        {
+            Position(f)
+        }
+
         /// Creates an event handler for stop events.
         ///
         /// The event handler ignores all other events.
@@ -511,6 +1121,17 @@ pub mod event_handlers {
             Stop(f)
         }
 
+        /// Creates an event handler with mutable data for stop events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_stop_with_data<T, F>(f: F) -> Stop<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref),
+        {
+            Stop(f)
+        }
+
         /// Creates an event handler for frame events.
         ///
         /// The event handler ignores all other events.
@@ -521,5 +1142,322 @@ pub mod event_handlers {
         {
             Frame(f)
         }
+
+        /// Creates an event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(f: F) -> Frame<F>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref, u32),
+        {
+            Frame(f)
+        }
+
+        /// Creates a mutable event handler for source events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_source_mut<F>(f: F) -> SourceMut<F>
+        where
+            F: FnMut(&ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+        {
+            SourceMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for source events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_source_mut_with_data<T, F>(f: F) -> SourceMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+        {
+            SourceMut(f)
+        }
+
+        /// Creates a mutable event handler for position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_position_mut<F>(f: F) -> PositionMut<F>
+        where
+            F: FnMut(&ZwpTabletPadStripV2Ref, u32),
+        {
+            PositionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for position events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_position_mut_with_data<T, F>(f: F) -> PositionMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadStripV2Ref, u32),
+        {
+            PositionMut(f)
+        }
+
+        /// Creates a mutable event handler for stop events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_stop_mut<F>(f: F) -> StopMut<F>
+        where
+            F: FnMut(&ZwpTabletPadStripV2Ref),
+        {
+            StopMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for stop events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_stop_mut_with_data<T, F>(f: F) -> StopMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadStripV2Ref),
+        {
+            StopMut(f)
+        }
+
+        /// Creates a mutable event handler for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut<F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&ZwpTabletPadStripV2Ref, u32),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut_with_data<T, F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletPadStripV2Ref, u32),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                source: Ignore,
+                position: Ignore,
+                stop: Ignore,
+                frame: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletPadStripV2EventHandler for Ignore {}
+    impl<T> ZwpTabletPadStripV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletPadStripV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        source: H0,
+        position: H1,
+        stop: H2,
+        frame: H3,
+    }
+
+    impl<H0, H1, H2, H3> ZwpTabletPadStripV2EventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: ZwpTabletPadStripV2EventHandler,
+        H1: ZwpTabletPadStripV2EventHandler,
+        H2: ZwpTabletPadStripV2EventHandler,
+        H3: ZwpTabletPadStripV2EventHandler,
+    {
+        #[inline]
+        fn source(&self, _slf: &ZwpTabletPadStripV2Ref, source: ZwpTabletPadStripV2Source) {
+            self.source.source(_slf, source)
+        }
+
+        #[inline]
+        fn position(&self, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+            self.position.position(_slf, position)
+        }
+
+        #[inline]
+        fn stop(&self, _slf: &ZwpTabletPadStripV2Ref) {
+            self.stop.stop(_slf)
+        }
+
+        #[inline]
+        fn frame(&self, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+            self.frame.frame(_slf, time)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> ZwpTabletPadStripV2EventHandlerWithData<T> for Handler<H0, H1, H2, H3>
+    where
+        H0: ZwpTabletPadStripV2EventHandlerWithData<T>,
+        H1: ZwpTabletPadStripV2EventHandlerWithData<T>,
+        H2: ZwpTabletPadStripV2EventHandlerWithData<T>,
+        H3: ZwpTabletPadStripV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn source(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletPadStripV2Ref,
+            source: ZwpTabletPadStripV2Source,
+        ) {
+            self.source.source(_data, _slf, source)
+        }
+
+        #[inline]
+        fn position(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, position: u32) {
+            self.position.position(_data, _slf, position)
+        }
+
+        #[inline]
+        fn stop(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref) {
+            self.stop.stop(_data, _slf)
+        }
+
+        #[inline]
+        fn frame(&self, _data: &mut T, _slf: &ZwpTabletPadStripV2Ref, time: u32) {
+            self.frame.frame(_data, _slf, time)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for source events.
+        #[allow(dead_code)]
+        pub fn on_source<F>(self, f: F) -> Handler<Source<F>, H1, H2, H3>
+        where
+            F: Fn(&ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+        {
+            Handler {
+                source: Source(f),
+                position: self.position,
+                stop: self.stop,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for source events.
+        #[allow(dead_code)]
+        pub fn on_source_with_data<T, F>(self, f: F) -> Handler<Source<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref, ZwpTabletPadStripV2Source),
+        {
+            Handler {
+                source: Source(f),
+                position: self.position,
+                stop: self.stop,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for position events.
+        #[allow(dead_code)]
+        pub fn on_position<F>(self, f: F) -> Handler<H0, Position<F>, H2, H3>
+        where
+            F: Fn(&ZwpTabletPadStripV2Ref, u32),
+        {
+            Handler {
+                source: self.source,
+                position: Position(f),
+                stop: self.stop,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for position events.
+        #[allow(dead_code)]
+        pub fn on_position_with_data<T, F>(self, f: F) -> Handler<H0, Position<F>, H2, H3>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref, u32),
+        {
+            Handler {
+                source: self.source,
+                position: Position(f),
+                stop: self.stop,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for stop events.
+        #[allow(dead_code)]
+        pub fn on_stop<F>(self, f: F) -> Handler<H0, H1, Stop<F>, H3>
+        where
+            F: Fn(&ZwpTabletPadStripV2Ref),
+        {
+            Handler {
+                source: self.source,
+                position: self.position,
+                stop: Stop(f),
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for stop events.
+        #[allow(dead_code)]
+        pub fn on_stop_with_data<T, F>(self, f: F) -> Handler<H0, H1, Stop<F>, H3>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref),
+        {
+            Handler {
+                source: self.source,
+                position: self.position,
+                stop: Stop(f),
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(self, f: F) -> Handler<H0, H1, H2, Frame<F>>
+        where
+            F: Fn(&ZwpTabletPadStripV2Ref, u32),
+        {
+            Handler {
+                source: self.source,
+                position: self.position,
+                stop: self.stop,
+                frame: Frame(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Frame<F>>
+        where
+            F: Fn(&mut T, &ZwpTabletPadStripV2Ref, u32),
+        {
+            Handler {
+                source: self.source,
+                position: self.position,
+                stop: self.stop,
+                frame: Frame(f),
+            }
+        }
     }
 }
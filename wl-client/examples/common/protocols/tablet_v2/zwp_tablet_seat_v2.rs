@@ -56,6 +56,63 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the zwp_tablet_seat_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_seat_v2",
+    protocol: "tablet_v2",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "tablet_added",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_v2"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tool_added",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_tool_v2"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "pad_added",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("zwp_tablet_pad_v2"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
 /// An owned zwp_tablet_seat_v2 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -110,11 +167,16 @@ impl Deref for ZwpTabletSeatV2 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -236,6 +298,203 @@ pub trait ZwpTabletSeatV2EventHandler {
 
 impl ZwpTabletSeatV2EventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [ZwpTabletSeatV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletSeatV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV2EventHandlerWithData<T> {
+    /// new device notification
+    ///
+    /// This event is sent whenever a new tablet becomes available on this
+    /// seat. This event only provides the object id of the tablet, any
+    /// static information about the tablet (device name, vid/pid, etc.) is
+    /// sent through the wp_tablet interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added graphics tablet
+    #[inline]
+    fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        let _ = id;
+    }
+
+    /// a new tool has been used with a tablet
+    ///
+    /// This event is sent whenever a tool that has not previously been used
+    /// with a tablet comes into use. This event only provides the object id
+    /// of the tool; any static information about the tool (capabilities,
+    /// type, etc.) is sent through the wp_tablet_tool interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added tablet tool
+    #[inline]
+    fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        let _ = id;
+    }
+
+    /// new pad notification
+    ///
+    /// This event is sent whenever a new pad is known to the system. Typically,
+    /// pads are physically attached to tablets and a pad_added event is
+    /// sent immediately after the wp_tablet_seat.tablet_added.
+    /// However, some standalone pad devices logically attach to tablets at
+    /// runtime, and the client must wait for wp_tablet_pad.enter to know
+    /// the tablet a pad is attached to.
+    ///
+    /// This event only provides the object id of the pad. All further
+    /// features (buttons, strips, rings) are sent through the wp_tablet_pad
+    /// interface.
+    ///
+    /// # Arguments
+    ///
+    /// - `id`: the newly added pad
+    #[inline]
+    fn pad_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletSeatV2] proxies.
+///
+/// This trait is the same as [ZwpTabletSeatV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV2EventHandlerMut {
+    /// See [ZwpTabletSeatV2EventHandler::tablet_added].
+    #[inline]
+    fn tablet_added(&mut self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        let _ = id;
+    }
+
+    /// See [ZwpTabletSeatV2EventHandler::tool_added].
+    #[inline]
+    fn tool_added(&mut self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        let _ = id;
+    }
+
+    /// See [ZwpTabletSeatV2EventHandler::pad_added].
+    #[inline]
+    fn pad_added(&mut self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletSeatV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletSeatV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletSeatV2EventHandlerMutWithData<T> {
+    /// See [ZwpTabletSeatV2EventHandler::tablet_added].
+    #[inline]
+    fn tablet_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        let _ = id;
+    }
+
+    /// See [ZwpTabletSeatV2EventHandler::tool_added].
+    #[inline]
+    fn tool_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        let _ = id;
+    }
+
+    /// See [ZwpTabletSeatV2EventHandler::pad_added].
+    #[inline]
+    fn pad_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        let _ = id;
+    }
+}
+
+impl<H> ZwpTabletSeatV2EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletSeatV2EventHandlerMut,
+{
+    #[inline]
+    fn tablet_added(&self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        self.with(|h| h.tablet_added(_slf, id))
+    }
+
+    #[inline]
+    fn tool_added(&self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        self.with(|h| h.tool_added(_slf, id))
+    }
+
+    #[inline]
+    fn pad_added(&self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        self.with(|h| h.pad_added(_slf, id))
+    }
+}
+
+impl<T, H> ZwpTabletSeatV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletSeatV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        self.with(|h| h.tablet_added(_data, _slf, id))
+    }
+
+    #[inline]
+    fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        self.with(|h| h.tool_added(_data, _slf, id))
+    }
+
+    #[inline]
+    fn pad_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        self.with(|h| h.pad_added(_data, _slf, id))
+    }
+}
+
+impl<T, U, H> ZwpTabletSeatV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletSeatV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        let _data = self.project(_data);
+        self.handler().tablet_added(_data, _slf, id)
+    }
+
+    #[inline]
+    fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        let _data = self.project(_data);
+        self.handler().tool_added(_data, _slf, id)
+    }
+
+    #[inline]
+    fn pad_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        let _data = self.project(_data);
+        self.handler().pad_added(_data, _slf, id)
+    }
+}
+
+impl<T, U, H> ZwpTabletSeatV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletSeatV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn tablet_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+        let _data = self.project(_data);
+        self.handler_mut().tablet_added(_data, _slf, id)
+    }
+
+    #[inline]
+    fn tool_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+        let _data = self.project(_data);
+        self.handler_mut().tool_added(_data, _slf, id)
+    }
+
+    #[inline]
+    fn pad_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+        let _data = self.project(_data);
+        self.handler_mut().pad_added(_data, _slf, id)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -313,6 +572,95 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletSeatV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletSeatV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletV2::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletV2>(arg0) };
+                self.0.tablet_added(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletToolV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletToolV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletToolV2::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletToolV2>(arg0) };
+                self.0.tool_added(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface ZwpTabletPadV2::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        ZwpTabletPadV2::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletPadV2::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<ZwpTabletPadV2>(arg0) };
+                self.0.pad_added(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_seat_v2", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: ZwpTabletSeatV2EventHandler,
@@ -325,6 +673,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletSeatV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -340,6 +701,15 @@ pub mod event_handlers {
             self.0(_slf, id)
         }
     }
+    impl<T, F> ZwpTabletSeatV2EventHandlerWithData<T> for TabletAdded<F>
+    where
+        F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletV2),
+    {
+        #[inline]
+        fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+            self.0(_data, _slf, id)
+        }
+    }
 
     /// Event handler for tool_added events.
     pub struct ToolAdded<F>(F);
@@ -352,6 +722,15 @@ pub mod event_handlers {
             self.0(_slf, id)
         }
     }
+    impl<T, F> ZwpTabletSeatV2EventHandlerWithData<T> for ToolAdded<F>
+    where
+        F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+    {
+        #[inline]
+        fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+            self.0(_data, _slf, id)
+        }
+    }
 
     /// Event handler for pad_added events.
     pub struct PadAdded<F>(F);
@@ -364,6 +743,78 @@ pub mod event_handlers {
             self.0(_slf, id)
         }
     }
+    impl<T, F> ZwpTabletSeatV2EventHandlerWithData<T> for PadAdded<F>
+    where
+        F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+    {
+        #[inline]
+        fn pad_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for tablet_added events.
+    pub struct TabletAddedMut<F>(F);
+    impl<F> ZwpTabletSeatV2EventHandlerMut for TabletAddedMut<F>
+    where
+        F: FnMut(&ZwpTabletSeatV2Ref, ZwpTabletV2),
+    {
+        #[inline]
+        fn tablet_added(&mut self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV2EventHandlerMutWithData<T> for TabletAddedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletV2),
+    {
+        #[inline]
+        fn tablet_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for tool_added events.
+    pub struct ToolAddedMut<F>(F);
+    impl<F> ZwpTabletSeatV2EventHandlerMut for ToolAddedMut<F>
+    where
+        F: FnMut(&ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+    {
+        #[inline]
+        fn tool_added(&mut self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV2EventHandlerMutWithData<T> for ToolAddedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+    {
+        #[inline]
+        fn tool_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for pad_added events.
+    pub struct PadAddedMut<F>(F);
+    impl<F> ZwpTabletSeatV2EventHandlerMut for PadAddedMut<F>
+    where
+        F: FnMut(&ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+    {
+        #[inline]
+        fn pad_added(&mut self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> ZwpTabletSeatV2EventHandlerMutWithData<T> for PadAddedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+    {
+        #[inline]
+        fn pad_added(&mut self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+            self.0(_data, _slf, id)
+        }
+    }
 
     impl ZwpTabletSeatV2 {
         /// Creates an event handler for tablet_added events.
@@ -377,6 +828,17 @@ pub mod event_handlers {
             TabletAdded(f)
         }
 
+        /// Creates an event handler with mutable data for tablet_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added_with_data<T, F>(f: F) -> TabletAdded<F>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletV2),
+        {
+            TabletAdded(f)
+        }
+
         /// Creates an event handler for tool_added events.
         ///
         /// The event handler ignores all other events.
@@ -388,6 +850,17 @@ pub mod event_handlers {
             ToolAdded(f)
         }
 
+        /// Creates an event handler with mutable data for tool_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tool_added_with_data<T, F>(f: F) -> ToolAdded<F>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+        {
+            ToolAdded(f)
+        }
+
         /// Creates an event handler for pad_added events.
         ///
         /// The event handler ignores all other events.
@@ -398,5 +871,245 @@ pub mod event_handlers {
         {
             PadAdded(f)
         }
+
+        /// Creates an event handler with mutable data for pad_added events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_pad_added_with_data<T, F>(f: F) -> PadAdded<F>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+        {
+            PadAdded(f)
+        }
+
+        /// Creates a mutable event handler for tablet_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tablet_added_mut<F>(f: F) -> TabletAddedMut<F>
+        where
+            F: FnMut(&ZwpTabletSeatV2Ref, ZwpTabletV2),
+        {
+            TabletAddedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tablet_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tablet_added_mut_with_data<T, F>(f: F) -> TabletAddedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletV2),
+        {
+            TabletAddedMut(f)
+        }
+
+        /// Creates a mutable event handler for tool_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tool_added_mut<F>(f: F) -> ToolAddedMut<F>
+        where
+            F: FnMut(&ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+        {
+            ToolAddedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for tool_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tool_added_mut_with_data<T, F>(f: F) -> ToolAddedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+        {
+            ToolAddedMut(f)
+        }
+
+        /// Creates a mutable event handler for pad_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_pad_added_mut<F>(f: F) -> PadAddedMut<F>
+        where
+            F: FnMut(&ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+        {
+            PadAddedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for pad_added events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_pad_added_mut_with_data<T, F>(f: F) -> PadAddedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+        {
+            PadAddedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                tablet_added: Ignore,
+                tool_added: Ignore,
+                pad_added: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletSeatV2EventHandler for Ignore {}
+    impl<T> ZwpTabletSeatV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletSeatV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        tablet_added: H0,
+        tool_added: H1,
+        pad_added: H2,
+    }
+
+    impl<H0, H1, H2> ZwpTabletSeatV2EventHandler for Handler<H0, H1, H2>
+    where
+        H0: ZwpTabletSeatV2EventHandler,
+        H1: ZwpTabletSeatV2EventHandler,
+        H2: ZwpTabletSeatV2EventHandler,
+    {
+        #[inline]
+        fn tablet_added(&self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+            self.tablet_added.tablet_added(_slf, id)
+        }
+
+        #[inline]
+        fn tool_added(&self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+            self.tool_added.tool_added(_slf, id)
+        }
+
+        #[inline]
+        fn pad_added(&self, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+            self.pad_added.pad_added(_slf, id)
+        }
+    }
+
+    impl<T, H0, H1, H2> ZwpTabletSeatV2EventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: ZwpTabletSeatV2EventHandlerWithData<T>,
+        H1: ZwpTabletSeatV2EventHandlerWithData<T>,
+        H2: ZwpTabletSeatV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn tablet_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletV2) {
+            self.tablet_added.tablet_added(_data, _slf, id)
+        }
+
+        #[inline]
+        fn tool_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletToolV2) {
+            self.tool_added.tool_added(_data, _slf, id)
+        }
+
+        #[inline]
+        fn pad_added(&self, _data: &mut T, _slf: &ZwpTabletSeatV2Ref, id: ZwpTabletPadV2) {
+            self.pad_added.pad_added(_data, _slf, id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for tablet_added events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added<F>(self, f: F) -> Handler<TabletAdded<F>, H1, H2>
+        where
+            F: Fn(&ZwpTabletSeatV2Ref, ZwpTabletV2),
+        {
+            Handler {
+                tablet_added: TabletAdded(f),
+                tool_added: self.tool_added,
+                pad_added: self.pad_added,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tablet_added events.
+        #[allow(dead_code)]
+        pub fn on_tablet_added_with_data<T, F>(self, f: F) -> Handler<TabletAdded<F>, H1, H2>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletV2),
+        {
+            Handler {
+                tablet_added: TabletAdded(f),
+                tool_added: self.tool_added,
+                pad_added: self.pad_added,
+            }
+        }
+
+        /// Sets the closure for tool_added events.
+        #[allow(dead_code)]
+        pub fn on_tool_added<F>(self, f: F) -> Handler<H0, ToolAdded<F>, H2>
+        where
+            F: Fn(&ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+        {
+            Handler {
+                tablet_added: self.tablet_added,
+                tool_added: ToolAdded(f),
+                pad_added: self.pad_added,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for tool_added events.
+        #[allow(dead_code)]
+        pub fn on_tool_added_with_data<T, F>(self, f: F) -> Handler<H0, ToolAdded<F>, H2>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletToolV2),
+        {
+            Handler {
+                tablet_added: self.tablet_added,
+                tool_added: ToolAdded(f),
+                pad_added: self.pad_added,
+            }
+        }
+
+        /// Sets the closure for pad_added events.
+        #[allow(dead_code)]
+        pub fn on_pad_added<F>(self, f: F) -> Handler<H0, H1, PadAdded<F>>
+        where
+            F: Fn(&ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+        {
+            Handler {
+                tablet_added: self.tablet_added,
+                tool_added: self.tool_added,
+                pad_added: PadAdded(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for pad_added events.
+        #[allow(dead_code)]
+        pub fn on_pad_added_with_data<T, F>(self, f: F) -> Handler<H0, H1, PadAdded<F>>
+        where
+            F: Fn(&mut T, &ZwpTabletSeatV2Ref, ZwpTabletPadV2),
+        {
+            Handler {
+                tablet_added: self.tablet_added,
+                tool_added: self.tool_added,
+                pad_added: PadAdded(f),
+            }
+        }
     }
 }
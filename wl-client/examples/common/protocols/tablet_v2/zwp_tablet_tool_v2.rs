@@ -213,6 +213,491 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the zwp_tablet_tool_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_tool_v2",
+    protocol: "tablet_v2",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "set_cursor",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "hotspot_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "hotspot_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "type",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "tool_type",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("type"),
+            }],
+        },
+        info::MessageInfo {
+            name: "hardware_serial",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "hardware_serial_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "hardware_serial_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "hardware_id_wacom",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "hardware_id_hi",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "hardware_id_lo",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "capability",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "capability",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("capability"),
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "removed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "proximity_in",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tablet",
+                    ty: info::ArgType::Object,
+                    interface: Some("zwp_tablet_v2"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "proximity_out",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "down",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "up",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "motion",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "pressure",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "pressure",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "distance",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "distance",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "tilt",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "tilt_x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "tilt_y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "rotation",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "degrees",
+                ty: info::ArgType::Fixed,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "slider",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "position",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "wheel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "degrees",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "clicks",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "button",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "button",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "state",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("button_state"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "frame",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "time",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "type",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "pen",
+                    value: 320,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "eraser",
+                    value: 321,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "brush",
+                    value: 322,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pencil",
+                    value: 323,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "airbrush",
+                    value: 324,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "finger",
+                    value: 325,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "mouse",
+                    value: 326,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "lens",
+                    value: 327,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "capability",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "tilt",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pressure",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "distance",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "rotation",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "slider",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "wheel",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "button_state",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "released",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pressed",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "role",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+    ],
+};
+
 /// An owned zwp_tablet_tool_v2 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -267,11 +752,16 @@ impl Deref for ZwpTabletToolV2 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -850,443 +1340,3807 @@ pub trait ZwpTabletToolV2EventHandler {
 
 impl ZwpTabletToolV2EventHandler for private::NoOpEventHandler {}
 
-// SAFETY: - INTERFACE is a valid wl_interface
-unsafe impl<H> EventHandler for private::EventHandler<H>
-where
-    H: ZwpTabletToolV2EventHandler,
-{
-    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
-
-    #[allow(unused_variables)]
-    unsafe fn handle_event(
-        &self,
-        queue: &Queue,
-        data: *mut u8,
-        slf: &UntypedBorrowedProxy,
-        opcode: u32,
-        args: *mut wl_argument,
-    ) {
-        // SAFETY: This function requires that slf has the interface INTERFACE
-        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletToolV2Ref>(slf) };
-        match opcode {
-            0 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { ZwpTabletToolV2Type(args[0].u) };
-                self.0.r#type(slf, arg0);
-            }
-            1 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { args[1].u };
-                self.0.hardware_serial(slf, arg0, arg1);
-            }
-            2 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { args[1].u };
-                self.0.hardware_id_wacom(slf, arg0, arg1);
-            }
-            3 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { ZwpTabletToolV2Capability(args[0].u) };
-                self.0.capability(slf, arg0);
-            }
-            4 => {
-                self.0.done(slf);
-            }
-            5 => {
-                self.0.removed(slf);
-            }
-            6 => {
-                // SAFETY: INTERFACE requires that there are 3 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains an object
-                let arg1 = unsafe {
-                    if let Some(p) = NonNull::new(args[1].o.cast()) {
-                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
-                    } else {
-                        None
-                    }
-                };
-                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletV2::WL_INTERFACE
-                let arg1 = arg1.as_ref().map(|arg1| unsafe {
-                    proxy::low_level::from_untyped_borrowed::<ZwpTabletV2Ref>(arg1)
-                });
-                // SAFETY: - INTERFACE requires that args[2] contains an object
-                let arg2 = unsafe {
-                    if let Some(p) = NonNull::new(args[2].o.cast()) {
-                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
-                    } else {
-                        None
-                    }
-                };
-                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
-                let arg2 = arg2.as_ref().map(|arg2| unsafe {
-                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
-                });
-                self.0.proximity_in(slf, arg0, arg1, arg2);
-            }
-            7 => {
-                self.0.proximity_out(slf);
-            }
-            8 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                self.0.down(slf, arg0);
-            }
-            9 => {
-                self.0.up(slf);
-            }
-            10 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a fixed
-                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
-                // SAFETY: - INTERFACE requires that args[1] contains a fixed
-                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
-                self.0.motion(slf, arg0, arg1);
-            }
-            11 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                self.0.pressure(slf, arg0);
-            }
-            12 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                self.0.distance(slf, arg0);
-            }
-            13 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a fixed
-                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
-                // SAFETY: - INTERFACE requires that args[1] contains a fixed
-                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
-                self.0.tilt(slf, arg0, arg1);
-            }
-            14 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a fixed
-                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
-                self.0.rotation(slf, arg0);
-            }
-            15 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains an int
-                let arg0 = unsafe { args[0].i };
-                self.0.slider(slf, arg0);
-            }
-            16 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a fixed
-                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
-                // SAFETY: - INTERFACE requires that args[1] contains an int
-                let arg1 = unsafe { args[1].i };
-                self.0.wheel(slf, arg0, arg1);
-            }
-            17 => {
-                // SAFETY: INTERFACE requires that there are 3 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { args[1].u };
-                // SAFETY: - INTERFACE requires that args[2] contains a uint
-                let arg2 = unsafe { ZwpTabletToolV2ButtonState(args[2].u) };
-                self.0.button(slf, arg0, arg1, arg2);
-            }
-            18 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                self.0.frame(slf, arg0);
-            }
-            _ => {
-                invalid_opcode("zwp_tablet_tool_v2", opcode);
-            }
-        }
-    }
-}
-
-impl<H> CreateEventHandler<H> for private::ProxyApi
-where
-    H: ZwpTabletToolV2EventHandler,
-{
-    type EventHandler = private::EventHandler<H>;
-
+/// An event handler for [ZwpTabletToolV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletToolV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletToolV2EventHandlerWithData<T> {
+    /// tool type
+    ///
+    /// The tool type is the high-level type of the tool and usually decides
+    /// the interaction expected from this tool.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_tool.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `tool_type`: the physical tool type
     #[inline]
-    fn create_event_handler(handler: H) -> Self::EventHandler {
-        private::EventHandler(handler)
+    fn r#type(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        let _ = tool_type;
     }
-}
 
-impl ZwpTabletToolV2 {
-    /// Since when the type.pen enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__TYPE_PEN__SINCE: u32 = 1;
-    /// Since when the type.eraser enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__TYPE_ERASER__SINCE: u32 = 1;
-    /// Since when the type.brush enum variant is available.
-    #[allow(dead_code)]
+    /// unique hardware serial number of the tool
+    ///
+    /// If the physical tool can be identified by a unique 64-bit serial
+    /// number, this event notifies the client of this serial number.
+    ///
+    /// If multiple tablets are available in the same seat and the tool is
+    /// uniquely identifiable by the serial number, that tool may move
+    /// between tablets.
+    ///
+    /// Otherwise, if the tool has no serial number and this event is
+    /// missing, the tool is tied to the tablet it first comes into
+    /// proximity with. Even if the physical tool is used on multiple
+    /// tablets, separate wp_tablet_tool objects will be created, one per
+    /// tablet.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_tool.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `hardware_serial_hi`: the unique serial number of the tool, most significant bits
+    /// - `hardware_serial_lo`: the unique serial number of the tool, least significant bits
+    #[inline]
+    fn hardware_serial(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        let _ = hardware_serial_hi;
+        let _ = hardware_serial_lo;
+    }
+
+    /// hardware id notification in Wacom's format
+    ///
+    /// This event notifies the client of a hardware id available on this tool.
+    ///
+    /// The hardware id is a device-specific 64-bit id that provides extra
+    /// information about the tool in use, beyond the wl_tool.type
+    /// enumeration. The format of the id is specific to tablets made by
+    /// Wacom Inc. For example, the hardware id of a Wacom Grip
+    /// Pen (a stylus) is 0x802.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_tool.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `hardware_id_hi`: the hardware id, most significant bits
+    /// - `hardware_id_lo`: the hardware id, least significant bits
+    #[inline]
+    fn hardware_id_wacom(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        let _ = hardware_id_hi;
+        let _ = hardware_id_lo;
+    }
+
+    /// tool capability notification
+    ///
+    /// This event notifies the client of any capabilities of this tool,
+    /// beyond the main set of x/y axes and tip up/down detection.
+    ///
+    /// One event is sent for each extra capability available on this tool.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet_tool.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `capability`: the capability
+    #[inline]
+    fn capability(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        capability: ZwpTabletToolV2Capability,
+    ) {
+        let _ = capability;
+    }
+
+    /// tool description events sequence complete
+    ///
+    /// This event signals the end of the initial burst of descriptive
+    /// events. A client may consider the static description of the tool to
+    /// be complete and finalize initialization of the tool.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// tool removed
+    ///
+    /// This event is sent when the tool is removed from the system and will
+    /// send no further events. Should the physical tool come back into
+    /// proximity later, a new wp_tablet_tool object will be created.
+    ///
+    /// It is compositor-dependent when a tool is removed. A compositor may
+    /// remove a tool on proximity out, tablet removal or any other reason.
+    /// A compositor may also keep a tool alive until shutdown.
+    ///
+    /// If the tool is currently in proximity, a proximity_out event will be
+    /// sent before the removed event. See wp_tablet_tool.proximity_out for
+    /// the handling of any buttons logically down.
+    ///
+    /// When this event is received, the client must wp_tablet_tool.destroy
+    /// the object.
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// proximity in event
+    ///
+    /// Notification that this tool is focused on a certain surface.
+    ///
+    /// This event can be received when the tool has moved from one surface to
+    /// another, or when the tool has come back into proximity above the
+    /// surface.
+    ///
+    /// If any button is logically down when the tool comes into proximity,
+    /// the respective button event is sent after the proximity_in event but
+    /// within the same frame as the proximity_in event.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `tablet`: The tablet the tool is in proximity of
+    /// - `surface`: The current surface the tablet tool is over
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn proximity_in(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _ = serial;
+        let _ = tablet;
+        let _ = surface;
+    }
+
+    /// proximity out event
+    ///
+    /// Notification that this tool has either left proximity, or is no
+    /// longer focused on a certain surface.
+    ///
+    /// When the tablet tool leaves proximity of the tablet, button release
+    /// events are sent for each button that was held down at the time of
+    /// leaving proximity. These events are sent before the proximity_out
+    /// event but within the same wp_tablet.frame.
+    ///
+    /// If the tool stays within proximity of the tablet, but the focus
+    /// changes from one surface to another, a button release event may not
+    /// be sent until the button is actually released or the tool leaves the
+    /// proximity of the tablet.
+    #[inline]
+    fn proximity_out(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// tablet tool is making contact
+    ///
+    /// Sent whenever the tablet tool comes in contact with the surface of the
+    /// tablet.
+    ///
+    /// If the tool is already in contact with the tablet when entering the
+    /// input region, the client owning said region will receive a
+    /// wp_tablet.proximity_in event, followed by a wp_tablet.down
+    /// event and a wp_tablet.frame event.
+    ///
+    /// Note that this event describes logical contact, not physical
+    /// contact. On some devices, a compositor may not consider a tool in
+    /// logical contact until a minimum physical pressure threshold is
+    /// exceeded.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    #[inline]
+    fn down(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// tablet tool is no longer making contact
+    ///
+    /// Sent whenever the tablet tool stops making contact with the surface of
+    /// the tablet, or when the tablet tool moves out of the input region
+    /// and the compositor grab (if any) is dismissed.
+    ///
+    /// If the tablet tool moves out of the input region while in contact
+    /// with the surface of the tablet and the compositor does not have an
+    /// ongoing grab on the surface, the client owning said region will
+    /// receive a wp_tablet.up event, followed by a wp_tablet.proximity_out
+    /// event and a wp_tablet.frame event. If the compositor has an ongoing
+    /// grab on this device, this event sequence is sent whenever the grab
+    /// is dismissed in the future.
+    ///
+    /// Note that this event describes logical contact, not physical
+    /// contact. On some devices, a compositor may not consider a tool out
+    /// of logical contact until physical pressure falls below a specific
+    /// threshold.
+    #[inline]
+    fn up(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// motion event
+    ///
+    /// Sent whenever a tablet tool moves.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: surface-local x coordinate
+    /// - `y`: surface-local y coordinate
+    #[inline]
+    fn motion(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// pressure change event
+    ///
+    /// Sent whenever the pressure axis on a tool changes. The value of this
+    /// event is normalized to a value between 0 and 65535.
+    ///
+    /// Note that pressure may be nonzero even when a tool is not in logical
+    /// contact. See the down and up events for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `pressure`: The current pressure value
+    #[inline]
+    fn pressure(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        let _ = pressure;
+    }
+
+    /// distance change event
+    ///
+    /// Sent whenever the distance axis on a tool changes. The value of this
+    /// event is normalized to a value between 0 and 65535.
+    ///
+    /// Note that distance may be nonzero even when a tool is not in logical
+    /// contact. See the down and up events for more details.
+    ///
+    /// # Arguments
+    ///
+    /// - `distance`: The current distance value
+    #[inline]
+    fn distance(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        let _ = distance;
+    }
+
+    /// tilt change event
+    ///
+    /// Sent whenever one or both of the tilt axes on a tool change. Each tilt
+    /// value is in degrees, relative to the z-axis of the tablet.
+    /// The angle is positive when the top of a tool tilts along the
+    /// positive x or y axis.
+    ///
+    /// # Arguments
+    ///
+    /// - `tilt_x`: The current value of the X tilt axis
+    /// - `tilt_y`: The current value of the Y tilt axis
+    #[inline]
+    fn tilt(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        let _ = tilt_x;
+        let _ = tilt_y;
+    }
+
+    /// z-rotation change event
+    ///
+    /// Sent whenever the z-rotation axis on the tool changes. The
+    /// rotation value is in degrees clockwise from the tool's
+    /// logical neutral position.
+    ///
+    /// # Arguments
+    ///
+    /// - `degrees`: The current rotation of the Z axis
+    #[inline]
+    fn rotation(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        let _ = degrees;
+    }
+
+    /// Slider position change event
+    ///
+    /// Sent whenever the slider position on the tool changes. The
+    /// value is normalized between -65535 and 65535, with 0 as the logical
+    /// neutral position of the slider.
+    ///
+    /// The slider is available on e.g. the Wacom Airbrush tool.
+    ///
+    /// # Arguments
+    ///
+    /// - `position`: The current position of slider
+    #[inline]
+    fn slider(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        let _ = position;
+    }
+
+    /// Wheel delta event
+    ///
+    /// Sent whenever the wheel on the tool emits an event. This event
+    /// contains two values for the same axis change. The degrees value is
+    /// in the same orientation as the wl_pointer.vertical_scroll axis. The
+    /// clicks value is in discrete logical clicks of the mouse wheel. This
+    /// value may be zero if the movement of the wheel was less
+    /// than one logical click.
+    ///
+    /// Clients should choose either value and avoid mixing degrees and
+    /// clicks. The compositor may accumulate values smaller than a logical
+    /// click and emulate click events when a certain threshold is met.
+    /// Thus, wl_tablet_tool.wheel events with non-zero clicks values may
+    /// have different degrees values.
+    ///
+    /// # Arguments
+    ///
+    /// - `degrees`: The wheel delta in degrees
+    /// - `clicks`: The wheel delta in discrete clicks
+    #[inline]
+    fn wheel(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        let _ = degrees;
+        let _ = clicks;
+    }
+
+    /// button event
+    ///
+    /// Sent whenever a button on the tool is pressed or released.
+    ///
+    /// If a button is held down when the tool moves in or out of proximity,
+    /// button events are generated by the compositor. See
+    /// wp_tablet_tool.proximity_in and wp_tablet_tool.proximity_out for
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `button`: The button whose state has changed
+    /// - `state`: Whether the button was pressed or released
+    #[inline]
+    fn button(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        let _ = serial;
+        let _ = button;
+        let _ = state;
+    }
+
+    /// frame event
+    ///
+    /// Marks the end of a series of axis and/or button updates from the
+    /// tablet. The Wayland protocol requires axis updates to be sent
+    /// sequentially, however all events within a frame should be considered
+    /// one hardware event.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: The time of the event with millisecond granularity
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletToolV2] proxies.
+///
+/// This trait is the same as [ZwpTabletToolV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletToolV2EventHandlerMut {
+    /// See [ZwpTabletToolV2EventHandler::r#type].
+    #[inline]
+    fn r#type(&mut self, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        let _ = tool_type;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::hardware_serial].
+    #[inline]
+    fn hardware_serial(
+        &mut self,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        let _ = hardware_serial_hi;
+        let _ = hardware_serial_lo;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::hardware_id_wacom].
+    #[inline]
+    fn hardware_id_wacom(
+        &mut self,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        let _ = hardware_id_hi;
+        let _ = hardware_id_lo;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::capability].
+    #[inline]
+    fn capability(&mut self, _slf: &ZwpTabletToolV2Ref, capability: ZwpTabletToolV2Capability) {
+        let _ = capability;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::proximity_in].
+    #[inline]
+    fn proximity_in(
+        &mut self,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _ = serial;
+        let _ = tablet;
+        let _ = surface;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::proximity_out].
+    #[inline]
+    fn proximity_out(&mut self, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::down].
+    #[inline]
+    fn down(&mut self, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::up].
+    #[inline]
+    fn up(&mut self, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::motion].
+    #[inline]
+    fn motion(&mut self, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::pressure].
+    #[inline]
+    fn pressure(&mut self, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        let _ = pressure;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::distance].
+    #[inline]
+    fn distance(&mut self, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        let _ = distance;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::tilt].
+    #[inline]
+    fn tilt(&mut self, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        let _ = tilt_x;
+        let _ = tilt_y;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::rotation].
+    #[inline]
+    fn rotation(&mut self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        let _ = degrees;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::slider].
+    #[inline]
+    fn slider(&mut self, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        let _ = position;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::wheel].
+    #[inline]
+    fn wheel(&mut self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        let _ = degrees;
+        let _ = clicks;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::button].
+    #[inline]
+    fn button(
+        &mut self,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        let _ = serial;
+        let _ = button;
+        let _ = state;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+/// A mutable event handler for [ZwpTabletToolV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletToolV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletToolV2EventHandlerMutWithData<T> {
+    /// See [ZwpTabletToolV2EventHandler::r#type].
+    #[inline]
+    fn r#type(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        let _ = tool_type;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::hardware_serial].
+    #[inline]
+    fn hardware_serial(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        let _ = hardware_serial_hi;
+        let _ = hardware_serial_lo;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::hardware_id_wacom].
+    #[inline]
+    fn hardware_id_wacom(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        let _ = hardware_id_hi;
+        let _ = hardware_id_lo;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::capability].
+    #[inline]
+    fn capability(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        capability: ZwpTabletToolV2Capability,
+    ) {
+        let _ = capability;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::proximity_in].
+    #[inline]
+    fn proximity_in(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _ = serial;
+        let _ = tablet;
+        let _ = surface;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::proximity_out].
+    #[inline]
+    fn proximity_out(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::down].
+    #[inline]
+    fn down(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        let _ = serial;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::up].
+    #[inline]
+    fn up(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {}
+
+    /// See [ZwpTabletToolV2EventHandler::motion].
+    #[inline]
+    fn motion(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        let _ = x;
+        let _ = y;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::pressure].
+    #[inline]
+    fn pressure(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        let _ = pressure;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::distance].
+    #[inline]
+    fn distance(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        let _ = distance;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::tilt].
+    #[inline]
+    fn tilt(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        let _ = tilt_x;
+        let _ = tilt_y;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::rotation].
+    #[inline]
+    fn rotation(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        let _ = degrees;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::slider].
+    #[inline]
+    fn slider(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        let _ = position;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::wheel].
+    #[inline]
+    fn wheel(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        let _ = degrees;
+        let _ = clicks;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::button].
+    #[inline]
+    fn button(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        let _ = serial;
+        let _ = button;
+        let _ = state;
+    }
+
+    /// See [ZwpTabletToolV2EventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        let _ = time;
+    }
+}
+
+impl<H> ZwpTabletToolV2EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletToolV2EventHandlerMut,
+{
+    #[inline]
+    fn r#type(&self, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        self.with(|h| h.r#type(_slf, tool_type))
+    }
+
+    #[inline]
+    fn hardware_serial(
+        &self,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        self.with(|h| h.hardware_serial(_slf, hardware_serial_hi, hardware_serial_lo))
+    }
+
+    #[inline]
+    fn hardware_id_wacom(
+        &self,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        self.with(|h| h.hardware_id_wacom(_slf, hardware_id_hi, hardware_id_lo))
+    }
+
+    #[inline]
+    fn capability(&self, _slf: &ZwpTabletToolV2Ref, capability: ZwpTabletToolV2Capability) {
+        self.with(|h| h.capability(_slf, capability))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn removed(&self, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.removed(_slf))
+    }
+
+    #[inline]
+    fn proximity_in(
+        &self,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        self.with(|h| h.proximity_in(_slf, serial, tablet, surface))
+    }
+
+    #[inline]
+    fn proximity_out(&self, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.proximity_out(_slf))
+    }
+
+    #[inline]
+    fn down(&self, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        self.with(|h| h.down(_slf, serial))
+    }
+
+    #[inline]
+    fn up(&self, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.up(_slf))
+    }
+
+    #[inline]
+    fn motion(&self, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        self.with(|h| h.motion(_slf, x, y))
+    }
+
+    #[inline]
+    fn pressure(&self, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        self.with(|h| h.pressure(_slf, pressure))
+    }
+
+    #[inline]
+    fn distance(&self, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        self.with(|h| h.distance(_slf, distance))
+    }
+
+    #[inline]
+    fn tilt(&self, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        self.with(|h| h.tilt(_slf, tilt_x, tilt_y))
+    }
+
+    #[inline]
+    fn rotation(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        self.with(|h| h.rotation(_slf, degrees))
+    }
+
+    #[inline]
+    fn slider(&self, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        self.with(|h| h.slider(_slf, position))
+    }
+
+    #[inline]
+    fn wheel(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        self.with(|h| h.wheel(_slf, degrees, clicks))
+    }
+
+    #[inline]
+    fn button(
+        &self,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        self.with(|h| h.button(_slf, serial, button, state))
+    }
+
+    #[inline]
+    fn frame(&self, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        self.with(|h| h.frame(_slf, time))
+    }
+}
+
+impl<T, H> ZwpTabletToolV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletToolV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn r#type(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        self.with(|h| h.r#type(_data, _slf, tool_type))
+    }
+
+    #[inline]
+    fn hardware_serial(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        self.with(|h| h.hardware_serial(_data, _slf, hardware_serial_hi, hardware_serial_lo))
+    }
+
+    #[inline]
+    fn hardware_id_wacom(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        self.with(|h| h.hardware_id_wacom(_data, _slf, hardware_id_hi, hardware_id_lo))
+    }
+
+    #[inline]
+    fn capability(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        capability: ZwpTabletToolV2Capability,
+    ) {
+        self.with(|h| h.capability(_data, _slf, capability))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.removed(_data, _slf))
+    }
+
+    #[inline]
+    fn proximity_in(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        self.with(|h| h.proximity_in(_data, _slf, serial, tablet, surface))
+    }
+
+    #[inline]
+    fn proximity_out(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.proximity_out(_data, _slf))
+    }
+
+    #[inline]
+    fn down(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        self.with(|h| h.down(_data, _slf, serial))
+    }
+
+    #[inline]
+    fn up(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        self.with(|h| h.up(_data, _slf))
+    }
+
+    #[inline]
+    fn motion(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        self.with(|h| h.motion(_data, _slf, x, y))
+    }
+
+    #[inline]
+    fn pressure(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        self.with(|h| h.pressure(_data, _slf, pressure))
+    }
+
+    #[inline]
+    fn distance(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        self.with(|h| h.distance(_data, _slf, distance))
+    }
+
+    #[inline]
+    fn tilt(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        self.with(|h| h.tilt(_data, _slf, tilt_x, tilt_y))
+    }
+
+    #[inline]
+    fn rotation(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        self.with(|h| h.rotation(_data, _slf, degrees))
+    }
+
+    #[inline]
+    fn slider(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        self.with(|h| h.slider(_data, _slf, position))
+    }
+
+    #[inline]
+    fn wheel(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        self.with(|h| h.wheel(_data, _slf, degrees, clicks))
+    }
+
+    #[inline]
+    fn button(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        self.with(|h| h.button(_data, _slf, serial, button, state))
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        self.with(|h| h.frame(_data, _slf, time))
+    }
+}
+
+impl<T, U, H> ZwpTabletToolV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletToolV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn r#type(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        let _data = self.project(_data);
+        self.handler().r#type(_data, _slf, tool_type)
+    }
+
+    #[inline]
+    fn hardware_serial(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .hardware_serial(_data, _slf, hardware_serial_hi, hardware_serial_lo)
+    }
+
+    #[inline]
+    fn hardware_id_wacom(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .hardware_id_wacom(_data, _slf, hardware_id_hi, hardware_id_lo)
+    }
+
+    #[inline]
+    fn capability(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        capability: ZwpTabletToolV2Capability,
+    ) {
+        let _data = self.project(_data);
+        self.handler().capability(_data, _slf, capability)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler().removed(_data, _slf)
+    }
+
+    #[inline]
+    fn proximity_in(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .proximity_in(_data, _slf, serial, tablet, surface)
+    }
+
+    #[inline]
+    fn proximity_out(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler().proximity_out(_data, _slf)
+    }
+
+    #[inline]
+    fn down(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        let _data = self.project(_data);
+        self.handler().down(_data, _slf, serial)
+    }
+
+    #[inline]
+    fn up(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler().up(_data, _slf)
+    }
+
+    #[inline]
+    fn motion(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        let _data = self.project(_data);
+        self.handler().motion(_data, _slf, x, y)
+    }
+
+    #[inline]
+    fn pressure(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        let _data = self.project(_data);
+        self.handler().pressure(_data, _slf, pressure)
+    }
+
+    #[inline]
+    fn distance(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        let _data = self.project(_data);
+        self.handler().distance(_data, _slf, distance)
+    }
+
+    #[inline]
+    fn tilt(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        let _data = self.project(_data);
+        self.handler().tilt(_data, _slf, tilt_x, tilt_y)
+    }
+
+    #[inline]
+    fn rotation(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        let _data = self.project(_data);
+        self.handler().rotation(_data, _slf, degrees)
+    }
+
+    #[inline]
+    fn slider(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        let _data = self.project(_data);
+        self.handler().slider(_data, _slf, position)
+    }
+
+    #[inline]
+    fn wheel(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        let _data = self.project(_data);
+        self.handler().wheel(_data, _slf, degrees, clicks)
+    }
+
+    #[inline]
+    fn button(
+        &self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        let _data = self.project(_data);
+        self.handler().button(_data, _slf, serial, button, state)
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        let _data = self.project(_data);
+        self.handler().frame(_data, _slf, time)
+    }
+}
+
+impl<T, U, H> ZwpTabletToolV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletToolV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn r#type(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+        let _data = self.project(_data);
+        self.handler_mut().r#type(_data, _slf, tool_type)
+    }
+
+    #[inline]
+    fn hardware_serial(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_serial_hi: u32,
+        hardware_serial_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .hardware_serial(_data, _slf, hardware_serial_hi, hardware_serial_lo)
+    }
+
+    #[inline]
+    fn hardware_id_wacom(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        hardware_id_hi: u32,
+        hardware_id_lo: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .hardware_id_wacom(_data, _slf, hardware_id_hi, hardware_id_lo)
+    }
+
+    #[inline]
+    fn capability(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        capability: ZwpTabletToolV2Capability,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().capability(_data, _slf, capability)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().removed(_data, _slf)
+    }
+
+    #[inline]
+    fn proximity_in(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        tablet: Option<&ZwpTabletV2Ref>,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .proximity_in(_data, _slf, serial, tablet, surface)
+    }
+
+    #[inline]
+    fn proximity_out(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().proximity_out(_data, _slf)
+    }
+
+    #[inline]
+    fn down(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().down(_data, _slf, serial)
+    }
+
+    #[inline]
+    fn up(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().up(_data, _slf)
+    }
+
+    #[inline]
+    fn motion(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+        let _data = self.project(_data);
+        self.handler_mut().motion(_data, _slf, x, y)
+    }
+
+    #[inline]
+    fn pressure(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().pressure(_data, _slf, pressure)
+    }
+
+    #[inline]
+    fn distance(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().distance(_data, _slf, distance)
+    }
+
+    #[inline]
+    fn tilt(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+        let _data = self.project(_data);
+        self.handler_mut().tilt(_data, _slf, tilt_x, tilt_y)
+    }
+
+    #[inline]
+    fn rotation(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+        let _data = self.project(_data);
+        self.handler_mut().rotation(_data, _slf, degrees)
+    }
+
+    #[inline]
+    fn slider(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().slider(_data, _slf, position)
+    }
+
+    #[inline]
+    fn wheel(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+        let _data = self.project(_data);
+        self.handler_mut().wheel(_data, _slf, degrees, clicks)
+    }
+
+    #[inline]
+    fn button(
+        &mut self,
+        _data: &mut T,
+        _slf: &ZwpTabletToolV2Ref,
+        serial: u32,
+        button: u32,
+        state: ZwpTabletToolV2ButtonState,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .button(_data, _slf, serial, button, state)
+    }
+
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().frame(_data, _slf, time)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: ZwpTabletToolV2EventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletToolV2Ref>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpTabletToolV2Type(args[0].u) };
+                self.0.r#type(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.hardware_serial(slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.hardware_id_wacom(slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpTabletToolV2Capability(args[0].u) };
+                self.0.capability(slf, arg0);
+            }
+            4 => {
+                self.0.done(slf);
+            }
+            5 => {
+                self.0.removed(slf);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an object
+                let arg1 = unsafe {
+                    if let Some(p) = NonNull::new(args[1].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletV2::WL_INTERFACE
+                let arg1 = arg1.as_ref().map(|arg1| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ZwpTabletV2Ref>(arg1)
+                });
+                // SAFETY: - INTERFACE requires that args[2] contains an object
+                let arg2 = unsafe {
+                    if let Some(p) = NonNull::new(args[2].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg2 = arg2.as_ref().map(|arg2| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
+                });
+                self.0.proximity_in(slf, arg0, arg1, arg2);
+            }
+            7 => {
+                self.0.proximity_out(slf);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.down(slf, arg0);
+            }
+            9 => {
+                self.0.up(slf);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                self.0.motion(slf, arg0, arg1);
+            }
+            11 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.pressure(slf, arg0);
+            }
+            12 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.distance(slf, arg0);
+            }
+            13 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                self.0.tilt(slf, arg0, arg1);
+            }
+            14 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                self.0.rotation(slf, arg0);
+            }
+            15 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.slider(slf, arg0);
+            }
+            16 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.wheel(slf, arg0, arg1);
+            }
+            17 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { ZwpTabletToolV2ButtonState(args[2].u) };
+                self.0.button(slf, arg0, arg1, arg2);
+            }
+            18 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.frame(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_tool_v2", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletToolV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletToolV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpTabletToolV2Type(args[0].u) };
+                self.0.r#type(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.hardware_serial(data, slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.hardware_id_wacom(data, slf, arg0, arg1);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { ZwpTabletToolV2Capability(args[0].u) };
+                self.0.capability(data, slf, arg0);
+            }
+            4 => {
+                self.0.done(data, slf);
+            }
+            5 => {
+                self.0.removed(data, slf);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an object
+                let arg1 = unsafe {
+                    if let Some(p) = NonNull::new(args[1].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface ZwpTabletV2::WL_INTERFACE
+                let arg1 = arg1.as_ref().map(|arg1| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<ZwpTabletV2Ref>(arg1)
+                });
+                // SAFETY: - INTERFACE requires that args[2] contains an object
+                let arg2 = unsafe {
+                    if let Some(p) = NonNull::new(args[2].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg2 = arg2.as_ref().map(|arg2| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg2)
+                });
+                self.0.proximity_in(data, slf, arg0, arg1, arg2);
+            }
+            7 => {
+                self.0.proximity_out(data, slf);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.down(data, slf, arg0);
+            }
+            9 => {
+                self.0.up(data, slf);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                self.0.motion(data, slf, arg0, arg1);
+            }
+            11 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.pressure(data, slf, arg0);
+            }
+            12 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.distance(data, slf, arg0);
+            }
+            13 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                self.0.tilt(data, slf, arg0, arg1);
+            }
+            14 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                self.0.rotation(data, slf, arg0);
+            }
+            15 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.slider(data, slf, arg0);
+            }
+            16 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a fixed
+                let arg0 = unsafe { Fixed::from_wire(args[0].f) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.wheel(data, slf, arg0, arg1);
+            }
+            17 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { ZwpTabletToolV2ButtonState(args[2].u) };
+                self.0.button(data, slf, arg0, arg1, arg2);
+            }
+            18 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.frame(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_tool_v2", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: ZwpTabletToolV2EventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletToolV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl ZwpTabletToolV2 {
+    /// Since when the type.pen enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_PEN__SINCE: u32 = 1;
+    /// Since when the type.eraser enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_ERASER__SINCE: u32 = 1;
+    /// Since when the type.brush enum variant is available.
+    #[allow(dead_code)]
     pub const ENM__TYPE_BRUSH__SINCE: u32 = 1;
     /// Since when the type.pencil enum variant is available.
     #[allow(dead_code)]
-    pub const ENM__TYPE_PENCIL__SINCE: u32 = 1;
-    /// Since when the type.airbrush enum variant is available.
+    pub const ENM__TYPE_PENCIL__SINCE: u32 = 1;
+    /// Since when the type.airbrush enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_AIRBRUSH__SINCE: u32 = 1;
+    /// Since when the type.finger enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_FINGER__SINCE: u32 = 1;
+    /// Since when the type.mouse enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_MOUSE__SINCE: u32 = 1;
+    /// Since when the type.lens enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__TYPE_LENS__SINCE: u32 = 1;
+
+    /// Since when the capability.tilt enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_TILT__SINCE: u32 = 1;
+    /// Since when the capability.pressure enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_PRESSURE__SINCE: u32 = 1;
+    /// Since when the capability.distance enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_DISTANCE__SINCE: u32 = 1;
+    /// Since when the capability.rotation enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_ROTATION__SINCE: u32 = 1;
+    /// Since when the capability.slider enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_SLIDER__SINCE: u32 = 1;
+    /// Since when the capability.wheel enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__CAPABILITY_WHEEL__SINCE: u32 = 1;
+
+    /// Since when the button_state.released enum variant is available.
     #[allow(dead_code)]
-    pub const ENM__TYPE_AIRBRUSH__SINCE: u32 = 1;
-    /// Since when the type.finger enum variant is available.
+    pub const ENM__BUTTON_STATE_RELEASED__SINCE: u32 = 1;
+    /// Since when the button_state.pressed enum variant is available.
     #[allow(dead_code)]
-    pub const ENM__TYPE_FINGER__SINCE: u32 = 1;
-    /// Since when the type.mouse enum variant is available.
+    pub const ENM__BUTTON_STATE_PRESSED__SINCE: u32 = 1;
+
+    /// Since when the error.role enum variant is available.
     #[allow(dead_code)]
-    pub const ENM__TYPE_MOUSE__SINCE: u32 = 1;
-    /// Since when the type.lens enum variant is available.
+    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
+}
+
+/// a physical tool type
+///
+/// Describes the physical type of a tool. The physical type of a tool
+/// generally defines its base usage.
+///
+/// The mouse tool represents a mouse-shaped tool that is not a relative
+/// device but bound to the tablet's surface, providing absolute
+/// coordinates.
+///
+/// The lens tool is a mouse-shaped tool with an attached lens to
+/// provide precision focus.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTabletToolV2Type(pub u32);
+
+impl ZwpTabletToolV2Type {
+    /// Pen
     #[allow(dead_code)]
-    pub const ENM__TYPE_LENS__SINCE: u32 = 1;
+    pub const PEN: Self = Self(0x140);
+
+    /// Eraser
+    #[allow(dead_code)]
+    pub const ERASER: Self = Self(0x141);
+
+    /// Brush
+    #[allow(dead_code)]
+    pub const BRUSH: Self = Self(0x142);
+
+    /// Pencil
+    #[allow(dead_code)]
+    pub const PENCIL: Self = Self(0x143);
+
+    /// Airbrush
+    #[allow(dead_code)]
+    pub const AIRBRUSH: Self = Self(0x144);
+
+    /// Finger
+    #[allow(dead_code)]
+    pub const FINGER: Self = Self(0x145);
+
+    /// Mouse
+    #[allow(dead_code)]
+    pub const MOUSE: Self = Self(0x146);
+
+    /// Lens
+    #[allow(dead_code)]
+    pub const LENS: Self = Self(0x147);
+}
+
+impl Debug for ZwpTabletToolV2Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::PEN => "PEN",
+            Self::ERASER => "ERASER",
+            Self::BRUSH => "BRUSH",
+            Self::PENCIL => "PENCIL",
+            Self::AIRBRUSH => "AIRBRUSH",
+            Self::FINGER => "FINGER",
+            Self::MOUSE => "MOUSE",
+            Self::LENS => "LENS",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// capability flags for a tool
+///
+/// Describes extra capabilities on a tablet.
+///
+/// Any tool must provide x and y values, extra axes are
+/// device-specific.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTabletToolV2Capability(pub u32);
+
+impl ZwpTabletToolV2Capability {
+    /// Tilt axes
+    #[allow(dead_code)]
+    pub const TILT: Self = Self(1);
+
+    /// Pressure axis
+    #[allow(dead_code)]
+    pub const PRESSURE: Self = Self(2);
+
+    /// Distance axis
+    #[allow(dead_code)]
+    pub const DISTANCE: Self = Self(3);
+
+    /// Z-rotation axis
+    #[allow(dead_code)]
+    pub const ROTATION: Self = Self(4);
+
+    /// Slider axis
+    #[allow(dead_code)]
+    pub const SLIDER: Self = Self(5);
+
+    /// Wheel axis
+    #[allow(dead_code)]
+    pub const WHEEL: Self = Self(6);
+}
+
+impl Debug for ZwpTabletToolV2Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::TILT => "TILT",
+            Self::PRESSURE => "PRESSURE",
+            Self::DISTANCE => "DISTANCE",
+            Self::ROTATION => "ROTATION",
+            Self::SLIDER => "SLIDER",
+            Self::WHEEL => "WHEEL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// physical button state
+///
+/// Describes the physical state of a button that produced the button event.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTabletToolV2ButtonState(pub u32);
+
+impl ZwpTabletToolV2ButtonState {
+    /// button is not pressed
+    #[allow(dead_code)]
+    pub const RELEASED: Self = Self(0);
+
+    /// button is pressed
+    #[allow(dead_code)]
+    pub const PRESSED: Self = Self(1);
+}
+
+impl Debug for ZwpTabletToolV2ButtonState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::RELEASED => "RELEASED",
+            Self::PRESSED => "PRESSED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct ZwpTabletToolV2Error(pub u32);
+
+impl ZwpTabletToolV2Error {
+    /// given wl_surface has another role
+    #[allow(dead_code)]
+    pub const ROLE: Self = Self(0);
+}
+
+impl Debug for ZwpTabletToolV2Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ROLE => "ROLE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for type events.
+    pub struct Type<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Type<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+    {
+        #[inline]
+        fn r#type(&self, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+            self.0(_slf, tool_type)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Type<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+    {
+        #[inline]
+        fn r#type(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+            self.0(_data, _slf, tool_type)
+        }
+    }
+
+    /// Event handler for hardware_serial events.
+    pub struct HardwareSerial<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for HardwareSerial<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_serial(
+            &self,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_serial_hi: u32,
+            hardware_serial_lo: u32,
+        ) {
+            self.0(_slf, hardware_serial_hi, hardware_serial_lo)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for HardwareSerial<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_serial(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_serial_hi: u32,
+            hardware_serial_lo: u32,
+        ) {
+            self.0(_data, _slf, hardware_serial_hi, hardware_serial_lo)
+        }
+    }
+
+    /// Event handler for hardware_id_wacom events.
+    pub struct HardwareIdWacom<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for HardwareIdWacom<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_id_wacom(
+            &self,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_id_hi: u32,
+            hardware_id_lo: u32,
+        ) {
+            self.0(_slf, hardware_id_hi, hardware_id_lo)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for HardwareIdWacom<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_id_wacom(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_id_hi: u32,
+            hardware_id_lo: u32,
+        ) {
+            self.0(_data, _slf, hardware_id_hi, hardware_id_lo)
+        }
+    }
+
+    /// Event handler for capability events.
+    pub struct Capability<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Capability<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+    {
+        #[inline]
+        fn capability(&self, _slf: &ZwpTabletToolV2Ref, capability: ZwpTabletToolV2Capability) {
+            self.0(_slf, capability)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Capability<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+    {
+        #[inline]
+        fn capability(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            capability: ZwpTabletToolV2Capability,
+        ) {
+            self.0(_data, _slf, capability)
+        }
+    }
+
+    /// Event handler for done events.
+    pub struct Done<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Done<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn done(&self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for removed events.
+    pub struct Removed<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Removed<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn removed(&self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Removed<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for proximity_in events.
+    pub struct ProximityIn<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for ProximityIn<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn proximity_in(
+            &self,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            tablet: Option<&ZwpTabletV2Ref>,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_slf, serial, tablet, surface)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for ProximityIn<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn proximity_in(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            tablet: Option<&ZwpTabletV2Ref>,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, serial, tablet, surface)
+        }
+    }
+
+    /// Event handler for proximity_out events.
+    pub struct ProximityOut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for ProximityOut<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn proximity_out(&self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for ProximityOut<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn proximity_out(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for down events.
+    pub struct Down<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Down<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn down(&self, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Down<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn down(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Event handler for up events.
+    pub struct Up<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Up<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn up(&self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Up<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn up(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for motion events.
+    pub struct Motion<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Motion<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(&self, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Motion<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Event handler for pressure events.
+    pub struct Pressure<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Pressure<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn pressure(&self, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+            self.0(_slf, pressure)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Pressure<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn pressure(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+            self.0(_data, _slf, pressure)
+        }
+    }
+
+    /// Event handler for distance events.
+    pub struct Distance<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Distance<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn distance(&self, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+            self.0(_slf, distance)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Distance<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn distance(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+            self.0(_data, _slf, distance)
+        }
+    }
+
+    /// Event handler for tilt events.
+    pub struct Tilt<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Tilt<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn tilt(&self, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+            self.0(_slf, tilt_x, tilt_y)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Tilt<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn tilt(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+            self.0(_data, _slf, tilt_x, tilt_y)
+        }
+    }
+
+    /// Event handler for rotation events.
+    pub struct Rotation<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Rotation<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, Fixed),
+    {
+        #[inline]
+        fn rotation(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+            self.0(_slf, degrees)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Rotation<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed),
+    {
+        #[inline]
+        fn rotation(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+            self.0(_data, _slf, degrees)
+        }
+    }
+
+    /// Event handler for slider events.
+    pub struct Slider<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Slider<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, i32),
+    {
+        #[inline]
+        fn slider(&self, _slf: &ZwpTabletToolV2Ref, position: i32) {
+            self.0(_slf, position)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Slider<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, i32),
+    {
+        #[inline]
+        fn slider(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+            self.0(_data, _slf, position)
+        }
+    }
+
+    /// Event handler for wheel events.
+    pub struct Wheel<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Wheel<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, Fixed, i32),
+    {
+        #[inline]
+        fn wheel(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+            self.0(_slf, degrees, clicks)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Wheel<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, i32),
+    {
+        #[inline]
+        fn wheel(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+            self.0(_data, _slf, degrees, clicks)
+        }
+    }
+
+    /// Event handler for button events.
+    pub struct Button<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Button<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+    {
+        #[inline]
+        fn button(
+            &self,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            button: u32,
+            state: ZwpTabletToolV2ButtonState,
+        ) {
+            self.0(_slf, serial, button, state)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Button<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+    {
+        #[inline]
+        fn button(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            button: u32,
+            state: ZwpTabletToolV2ButtonState,
+        ) {
+            self.0(_data, _slf, serial, button, state)
+        }
+    }
+
+    /// Event handler for frame events.
+    pub struct Frame<F>(F);
+    impl<F> ZwpTabletToolV2EventHandler for Frame<F>
+    where
+        F: Fn(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&self, _slf: &ZwpTabletToolV2Ref, time: u32) {
+            self.0(_slf, time)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerWithData<T> for Frame<F>
+    where
+        F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+            self.0(_data, _slf, time)
+        }
+    }
+
+    /// Mutable event handler for type events.
+    pub struct TypeMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for TypeMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+    {
+        #[inline]
+        fn r#type(&mut self, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+            self.0(_slf, tool_type)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for TypeMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+    {
+        #[inline]
+        fn r#type(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            tool_type: ZwpTabletToolV2Type,
+        ) {
+            self.0(_data, _slf, tool_type)
+        }
+    }
+
+    /// Mutable event handler for hardware_serial events.
+    pub struct HardwareSerialMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for HardwareSerialMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_serial(
+            &mut self,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_serial_hi: u32,
+            hardware_serial_lo: u32,
+        ) {
+            self.0(_slf, hardware_serial_hi, hardware_serial_lo)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for HardwareSerialMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_serial(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_serial_hi: u32,
+            hardware_serial_lo: u32,
+        ) {
+            self.0(_data, _slf, hardware_serial_hi, hardware_serial_lo)
+        }
+    }
+
+    /// Mutable event handler for hardware_id_wacom events.
+    pub struct HardwareIdWacomMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for HardwareIdWacomMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_id_wacom(
+            &mut self,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_id_hi: u32,
+            hardware_id_lo: u32,
+        ) {
+            self.0(_slf, hardware_id_hi, hardware_id_lo)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for HardwareIdWacomMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+    {
+        #[inline]
+        fn hardware_id_wacom(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_id_hi: u32,
+            hardware_id_lo: u32,
+        ) {
+            self.0(_data, _slf, hardware_id_hi, hardware_id_lo)
+        }
+    }
+
+    /// Mutable event handler for capability events.
+    pub struct CapabilityMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for CapabilityMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+    {
+        #[inline]
+        fn capability(&mut self, _slf: &ZwpTabletToolV2Ref, capability: ZwpTabletToolV2Capability) {
+            self.0(_slf, capability)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for CapabilityMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+    {
+        #[inline]
+        fn capability(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            capability: ZwpTabletToolV2Capability,
+        ) {
+            self.0(_data, _slf, capability)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for removed events.
+    pub struct RemovedMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for RemovedMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for RemovedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for proximity_in events.
+    pub struct ProximityInMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for ProximityInMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn proximity_in(
+            &mut self,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            tablet: Option<&ZwpTabletV2Ref>,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_slf, serial, tablet, surface)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for ProximityInMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn proximity_in(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            tablet: Option<&ZwpTabletV2Ref>,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, serial, tablet, surface)
+        }
+    }
+
+    /// Mutable event handler for proximity_out events.
+    pub struct ProximityOutMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for ProximityOutMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn proximity_out(&mut self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for ProximityOutMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn proximity_out(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for down events.
+    pub struct DownMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for DownMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn down(&mut self, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for DownMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn down(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Mutable event handler for up events.
+    pub struct UpMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for UpMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn up(&mut self, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for UpMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+    {
+        #[inline]
+        fn up(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for motion events.
+    pub struct MotionMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for MotionMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(&mut self, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+            self.0(_slf, x, y)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for MotionMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+            self.0(_data, _slf, x, y)
+        }
+    }
+
+    /// Mutable event handler for pressure events.
+    pub struct PressureMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for PressureMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn pressure(&mut self, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+            self.0(_slf, pressure)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for PressureMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn pressure(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+            self.0(_data, _slf, pressure)
+        }
+    }
+
+    /// Mutable event handler for distance events.
+    pub struct DistanceMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for DistanceMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn distance(&mut self, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+            self.0(_slf, distance)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for DistanceMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn distance(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+            self.0(_data, _slf, distance)
+        }
+    }
+
+    /// Mutable event handler for tilt events.
+    pub struct TiltMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for TiltMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn tilt(&mut self, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+            self.0(_slf, tilt_x, tilt_y)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for TiltMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+    {
+        #[inline]
+        fn tilt(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+            self.0(_data, _slf, tilt_x, tilt_y)
+        }
+    }
+
+    /// Mutable event handler for rotation events.
+    pub struct RotationMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for RotationMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, Fixed),
+    {
+        #[inline]
+        fn rotation(&mut self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+            self.0(_slf, degrees)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for RotationMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed),
+    {
+        #[inline]
+        fn rotation(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+            self.0(_data, _slf, degrees)
+        }
+    }
+
+    /// Mutable event handler for slider events.
+    pub struct SliderMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for SliderMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, i32),
+    {
+        #[inline]
+        fn slider(&mut self, _slf: &ZwpTabletToolV2Ref, position: i32) {
+            self.0(_slf, position)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for SliderMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, i32),
+    {
+        #[inline]
+        fn slider(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+            self.0(_data, _slf, position)
+        }
+    }
+
+    /// Mutable event handler for wheel events.
+    pub struct WheelMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for WheelMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, Fixed, i32),
+    {
+        #[inline]
+        fn wheel(&mut self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+            self.0(_slf, degrees, clicks)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for WheelMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed, i32),
+    {
+        #[inline]
+        fn wheel(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+            self.0(_data, _slf, degrees, clicks)
+        }
+    }
+
+    /// Mutable event handler for button events.
+    pub struct ButtonMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for ButtonMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+    {
+        #[inline]
+        fn button(
+            &mut self,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            button: u32,
+            state: ZwpTabletToolV2ButtonState,
+        ) {
+            self.0(_slf, serial, button, state)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for ButtonMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+    {
+        #[inline]
+        fn button(
+            &mut self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            button: u32,
+            state: ZwpTabletToolV2ButtonState,
+        ) {
+            self.0(_data, _slf, serial, button, state)
+        }
+    }
+
+    /// Mutable event handler for frame events.
+    pub struct FrameMut<F>(F);
+    impl<F> ZwpTabletToolV2EventHandlerMut for FrameMut<F>
+    where
+        F: FnMut(&ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&mut self, _slf: &ZwpTabletToolV2Ref, time: u32) {
+            self.0(_slf, time)
+        }
+    }
+    impl<T, F> ZwpTabletToolV2EventHandlerMutWithData<T> for FrameMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+    {
+        #[inline]
+        fn frame(&mut self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+            self.0(_data, _slf, time)
+        }
+    }
+
+    impl ZwpTabletToolV2 {
+        /// Creates an event handler for type events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_type<F>(f: F) -> Type<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+        {
+            Type(f)
+        }
+
+        /// Creates an event handler with mutable data for type events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_type_with_data<T, F>(f: F) -> Type<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+        {
+            Type(f)
+        }
+
+        /// Creates an event handler for hardware_serial events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_hardware_serial<F>(f: F) -> HardwareSerial<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareSerial(f)
+        }
+
+        /// Creates an event handler with mutable data for hardware_serial events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_hardware_serial_with_data<T, F>(f: F) -> HardwareSerial<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareSerial(f)
+        }
+
+        /// Creates an event handler for hardware_id_wacom events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_hardware_id_wacom<F>(f: F) -> HardwareIdWacom<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareIdWacom(f)
+        }
+
+        /// Creates an event handler with mutable data for hardware_id_wacom events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_hardware_id_wacom_with_data<T, F>(f: F) -> HardwareIdWacom<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareIdWacom(f)
+        }
+
+        /// Creates an event handler for capability events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capability<F>(f: F) -> Capability<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+        {
+            Capability(f)
+        }
+
+        /// Creates an event handler with mutable data for capability events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capability_with_data<T, F>(f: F) -> Capability<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+        {
+            Capability(f)
+        }
+
+        /// Creates an event handler for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(f: F) -> Done<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Done(f)
+        }
+
+        /// Creates an event handler for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(f: F) -> Removed<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates an event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(f: F) -> Removed<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates an event handler for proximity_in events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_proximity_in<F>(f: F) -> ProximityIn<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+        {
+            ProximityIn(f)
+        }
+
+        /// Creates an event handler with mutable data for proximity_in events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_proximity_in_with_data<T, F>(f: F) -> ProximityIn<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+        {
+            ProximityIn(f)
+        }
+
+        /// Creates an event handler for proximity_out events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_proximity_out<F>(f: F) -> ProximityOut<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref),
+        {
+            ProximityOut(f)
+        }
+
+        /// Creates an event handler with mutable data for proximity_out events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_proximity_out_with_data<T, F>(f: F) -> ProximityOut<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            ProximityOut(f)
+        }
+
+        /// Creates an event handler for down events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_down<F>(f: F) -> Down<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32),
+        {
+            Down(f)
+        }
+
+        /// Creates an event handler with mutable data for down events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_down_with_data<T, F>(f: F) -> Down<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Down(f)
+        }
+
+        /// Creates an event handler for up events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_up<F>(f: F) -> Up<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref),
+        {
+            Up(f)
+        }
+
+        /// Creates an event handler with mutable data for up events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_up_with_data<T, F>(f: F) -> Up<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Up(f)
+        }
 
-    /// Since when the capability.tilt enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__CAPABILITY_TILT__SINCE: u32 = 1;
-    /// Since when the capability.pressure enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__CAPABILITY_PRESSURE__SINCE: u32 = 1;
-    /// Since when the capability.distance enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__CAPABILITY_DISTANCE__SINCE: u32 = 1;
-    /// Since when the capability.rotation enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__CAPABILITY_ROTATION__SINCE: u32 = 1;
-    /// Since when the capability.slider enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__CAPABILITY_SLIDER__SINCE: u32 = 1;
-    /// Since when the capability.wheel enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__CAPABILITY_WHEEL__SINCE: u32 = 1;
+        /// Creates an event handler for motion events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_motion<F>(f: F) -> Motion<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            Motion(f)
+        }
+
+        /// Creates an event handler with mutable data for motion events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_motion_with_data<T, F>(f: F) -> Motion<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            Motion(f)
+        }
+
+        /// Creates an event handler for pressure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_pressure<F>(f: F) -> Pressure<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32),
+        {
+            Pressure(f)
+        }
+
+        /// Creates an event handler with mutable data for pressure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_pressure_with_data<T, F>(f: F) -> Pressure<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Pressure(f)
+        }
+
+        /// Creates an event handler for distance events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_distance<F>(f: F) -> Distance<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32),
+        {
+            Distance(f)
+        }
+
+        /// Creates an event handler with mutable data for distance events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_distance_with_data<T, F>(f: F) -> Distance<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Distance(f)
+        }
+
+        /// Creates an event handler for tilt events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tilt<F>(f: F) -> Tilt<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            Tilt(f)
+        }
+
+        /// Creates an event handler with mutable data for tilt events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_tilt_with_data<T, F>(f: F) -> Tilt<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            Tilt(f)
+        }
+
+        /// Creates an event handler for rotation events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_rotation<F>(f: F) -> Rotation<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, Fixed),
+        {
+            Rotation(f)
+        }
+
+        /// Creates an event handler with mutable data for rotation events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_rotation_with_data<T, F>(f: F) -> Rotation<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed),
+        {
+            Rotation(f)
+        }
+
+        /// Creates an event handler for slider events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_slider<F>(f: F) -> Slider<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, i32),
+        {
+            Slider(f)
+        }
+
+        /// Creates an event handler with mutable data for slider events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_slider_with_data<T, F>(f: F) -> Slider<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, i32),
+        {
+            Slider(f)
+        }
+
+        /// Creates an event handler for wheel events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_wheel<F>(f: F) -> Wheel<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, Fixed, i32),
+        {
+            Wheel(f)
+        }
+
+        /// Creates an event handler with mutable data for wheel events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_wheel_with_data<T, F>(f: F) -> Wheel<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, i32),
+        {
+            Wheel(f)
+        }
+
+        /// Creates an event handler for button events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_button<F>(f: F) -> Button<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+        {
+            Button(f)
+        }
+
+        /// Creates an event handler with mutable data for button events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_button_with_data<T, F>(f: F) -> Button<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+        {
+            Button(f)
+        }
+
+        /// Creates an event handler for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(f: F) -> Frame<F>
+        where
+            F: Fn(&ZwpTabletToolV2Ref, u32),
+        {
+            Frame(f)
+        }
+
+        /// Creates an event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(f: F) -> Frame<F>
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Frame(f)
+        }
+
+        /// Creates a mutable event handler for type events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_type_mut<F>(f: F) -> TypeMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+        {
+            TypeMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for type events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_type_mut_with_data<T, F>(f: F) -> TypeMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+        {
+            TypeMut(f)
+        }
+
+        /// Creates a mutable event handler for hardware_serial events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_hardware_serial_mut<F>(f: F) -> HardwareSerialMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareSerialMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for hardware_serial events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_hardware_serial_mut_with_data<T, F>(f: F) -> HardwareSerialMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareSerialMut(f)
+        }
+
+        /// Creates a mutable event handler for hardware_id_wacom events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_hardware_id_wacom_mut<F>(f: F) -> HardwareIdWacomMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareIdWacomMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for hardware_id_wacom events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_hardware_id_wacom_mut_with_data<T, F>(f: F) -> HardwareIdWacomMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+        {
+            HardwareIdWacomMut(f)
+        }
+
+        /// Creates a mutable event handler for capability events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capability_mut<F>(f: F) -> CapabilityMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+        {
+            CapabilityMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capability events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capability_mut_with_data<T, F>(f: F) -> CapabilityMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+        {
+            CapabilityMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut<F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref),
+        {
+            RemovedMut(f)
+        }
 
-    /// Since when the button_state.released enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__BUTTON_STATE_RELEASED__SINCE: u32 = 1;
-    /// Since when the button_state.pressed enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__BUTTON_STATE_PRESSED__SINCE: u32 = 1;
+        /// Creates a mutable event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut_with_data<T, F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+        {
+            RemovedMut(f)
+        }
 
-    /// Since when the error.role enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
-}
+        /// Creates a mutable event handler for proximity_in events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_proximity_in_mut<F>(f: F) -> ProximityInMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+        {
+            ProximityInMut(f)
+        }
 
-/// a physical tool type
-///
-/// Describes the physical type of a tool. The physical type of a tool
-/// generally defines its base usage.
-///
-/// The mouse tool represents a mouse-shaped tool that is not a relative
-/// device but bound to the tablet's surface, providing absolute
-/// coordinates.
-///
-/// The lens tool is a mouse-shaped tool with an attached lens to
-/// provide precision focus.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct ZwpTabletToolV2Type(pub u32);
+        /// Creates a mutable event handler with mutable data for proximity_in events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_proximity_in_mut_with_data<T, F>(f: F) -> ProximityInMut<F>
+        where
+            F: FnMut(
+                &mut T,
+                &ZwpTabletToolV2Ref,
+                u32,
+                Option<&ZwpTabletV2Ref>,
+                Option<&WlSurfaceRef>,
+            ),
+        {
+            ProximityInMut(f)
+        }
 
-impl ZwpTabletToolV2Type {
-    /// Pen
-    #[allow(dead_code)]
-    pub const PEN: Self = Self(0x140);
+        /// Creates a mutable event handler for proximity_out events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_proximity_out_mut<F>(f: F) -> ProximityOutMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref),
+        {
+            ProximityOutMut(f)
+        }
 
-    /// Eraser
-    #[allow(dead_code)]
-    pub const ERASER: Self = Self(0x141);
+        /// Creates a mutable event handler with mutable data for proximity_out events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_proximity_out_mut_with_data<T, F>(f: F) -> ProximityOutMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+        {
+            ProximityOutMut(f)
+        }
 
-    /// Brush
-    #[allow(dead_code)]
-    pub const BRUSH: Self = Self(0x142);
+        /// Creates a mutable event handler for down events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_down_mut<F>(f: F) -> DownMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32),
+        {
+            DownMut(f)
+        }
 
-    /// Pencil
-    #[allow(dead_code)]
-    pub const PENCIL: Self = Self(0x143);
+        /// Creates a mutable event handler with mutable data for down events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_down_mut_with_data<T, F>(f: F) -> DownMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            DownMut(f)
+        }
 
-    /// Airbrush
-    #[allow(dead_code)]
-    pub const AIRBRUSH: Self = Self(0x144);
+        /// Creates a mutable event handler for up events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_up_mut<F>(f: F) -> UpMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref),
+        {
+            UpMut(f)
+        }
 
-    /// Finger
-    #[allow(dead_code)]
-    pub const FINGER: Self = Self(0x145);
+        /// Creates a mutable event handler with mutable data for up events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_up_mut_with_data<T, F>(f: F) -> UpMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref),
+        {
+            UpMut(f)
+        }
 
-    /// Mouse
-    #[allow(dead_code)]
-    pub const MOUSE: Self = Self(0x146);
+        /// Creates a mutable event handler for motion events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_motion_mut<F>(f: F) -> MotionMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            MotionMut(f)
+        }
 
-    /// Lens
-    #[allow(dead_code)]
-    pub const LENS: Self = Self(0x147);
-}
+        /// Creates a mutable event handler with mutable data for motion events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_motion_mut_with_data<T, F>(f: F) -> MotionMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            MotionMut(f)
+        }
 
-impl Debug for ZwpTabletToolV2Type {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::PEN => "PEN",
-            Self::ERASER => "ERASER",
-            Self::BRUSH => "BRUSH",
-            Self::PENCIL => "PENCIL",
-            Self::AIRBRUSH => "AIRBRUSH",
-            Self::FINGER => "FINGER",
-            Self::MOUSE => "MOUSE",
-            Self::LENS => "LENS",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        /// Creates a mutable event handler for pressure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_pressure_mut<F>(f: F) -> PressureMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32),
+        {
+            PressureMut(f)
+        }
 
-/// capability flags for a tool
-///
-/// Describes extra capabilities on a tablet.
-///
-/// Any tool must provide x and y values, extra axes are
-/// device-specific.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct ZwpTabletToolV2Capability(pub u32);
+        /// Creates a mutable event handler with mutable data for pressure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_pressure_mut_with_data<T, F>(f: F) -> PressureMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            PressureMut(f)
+        }
 
-impl ZwpTabletToolV2Capability {
-    /// Tilt axes
-    #[allow(dead_code)]
-    pub const TILT: Self = Self(1);
+        /// Creates a mutable event handler for distance events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_distance_mut<F>(f: F) -> DistanceMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32),
+        {
+            DistanceMut(f)
+        }
 
-    /// Pressure axis
-    #[allow(dead_code)]
-    pub const PRESSURE: Self = Self(2);
+        /// Creates a mutable event handler with mutable data for distance events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_distance_mut_with_data<T, F>(f: F) -> DistanceMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            DistanceMut(f)
+        }
 
-    /// Distance axis
-    #[allow(dead_code)]
-    pub const DISTANCE: Self = Self(3);
+        /// Creates a mutable event handler for tilt events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tilt_mut<F>(f: F) -> TiltMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            TiltMut(f)
+        }
 
-    /// Z-rotation axis
-    #[allow(dead_code)]
-    pub const ROTATION: Self = Self(4);
+        /// Creates a mutable event handler with mutable data for tilt events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_tilt_mut_with_data<T, F>(f: F) -> TiltMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            TiltMut(f)
+        }
 
-    /// Slider axis
-    #[allow(dead_code)]
-    pub const SLIDER: Self = Self(5);
+        /// Creates a mutable event handler for rotation events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_rotation_mut<F>(f: F) -> RotationMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, Fixed),
+        {
+            RotationMut(f)
+        }
 
-    /// Wheel axis
-    #[allow(dead_code)]
-    pub const WHEEL: Self = Self(6);
-}
+        /// Creates a mutable event handler with mutable data for rotation events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_rotation_mut_with_data<T, F>(f: F) -> RotationMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed),
+        {
+            RotationMut(f)
+        }
 
-impl Debug for ZwpTabletToolV2Capability {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::TILT => "TILT",
-            Self::PRESSURE => "PRESSURE",
-            Self::DISTANCE => "DISTANCE",
-            Self::ROTATION => "ROTATION",
-            Self::SLIDER => "SLIDER",
-            Self::WHEEL => "WHEEL",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        /// Creates a mutable event handler for slider events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_slider_mut<F>(f: F) -> SliderMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, i32),
+        {
+            SliderMut(f)
+        }
 
-/// physical button state
-///
-/// Describes the physical state of a button that produced the button event.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct ZwpTabletToolV2ButtonState(pub u32);
+        /// Creates a mutable event handler with mutable data for slider events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_slider_mut_with_data<T, F>(f: F) -> SliderMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, i32),
+        {
+            SliderMut(f)
+        }
 
-impl ZwpTabletToolV2ButtonState {
-    /// button is not pressed
-    #[allow(dead_code)]
-    pub const RELEASED: Self = Self(0);
+        /// Creates a mutable event handler for wheel events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_wheel_mut<F>(f: F) -> WheelMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, Fixed, i32),
+        {
+            WheelMut(f)
+        }
 
-    /// button is pressed
-    #[allow(dead_code)]
-    pub const PRESSED: Self = Self(1);
-}
+        /// Creates a mutable event handler with mutable data for wheel events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_wheel_mut_with_data<T, F>(f: F) -> WheelMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, Fixed, i32),
+        {
+            WheelMut(f)
+        }
 
-impl Debug for ZwpTabletToolV2ButtonState {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::RELEASED => "RELEASED",
-            Self::PRESSED => "PRESSED",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        /// Creates a mutable event handler for button events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_button_mut<F>(f: F) -> ButtonMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+        {
+            ButtonMut(f)
+        }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct ZwpTabletToolV2Error(pub u32);
+        /// Creates a mutable event handler with mutable data for button events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_button_mut_with_data<T, F>(f: F) -> ButtonMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+        {
+            ButtonMut(f)
+        }
 
-impl ZwpTabletToolV2Error {
-    /// given wl_surface has another role
-    #[allow(dead_code)]
-    pub const ROLE: Self = Self(0);
-}
+        /// Creates a mutable event handler for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut<F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&ZwpTabletToolV2Ref, u32),
+        {
+            FrameMut(f)
+        }
 
-impl Debug for ZwpTabletToolV2Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::ROLE => "ROLE",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
+        /// Creates a mutable event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut_with_data<T, F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                r#type: Ignore,
+                hardware_serial: Ignore,
+                hardware_id_wacom: Ignore,
+                capability: Ignore,
+                done: Ignore,
+                removed: Ignore,
+                proximity_in: Ignore,
+                proximity_out: Ignore,
+                down: Ignore,
+                up: Ignore,
+                motion: Ignore,
+                pressure: Ignore,
+                distance: Ignore,
+                tilt: Ignore,
+                rotation: Ignore,
+                slider: Ignore,
+                wheel: Ignore,
+                button: Ignore,
+                frame: Ignore,
+            }
+        }
     }
-}
 
-/// Functional event handlers.
-pub mod event_handlers {
-    use super::*;
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletToolV2EventHandler for Ignore {}
+    impl<T> ZwpTabletToolV2EventHandlerWithData<T> for Ignore {}
 
-    /// Event handler for type events.
-    pub struct Type<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Type<F>
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletToolV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<
+        H0 = Ignore,
+        H1 = Ignore,
+        H2 = Ignore,
+        H3 = Ignore,
+        H4 = Ignore,
+        H5 = Ignore,
+        H6 = Ignore,
+        H7 = Ignore,
+        H8 = Ignore,
+        H9 = Ignore,
+        H10 = Ignore,
+        H11 = Ignore,
+        H12 = Ignore,
+        H13 = Ignore,
+        H14 = Ignore,
+        H15 = Ignore,
+        H16 = Ignore,
+        H17 = Ignore,
+        H18 = Ignore,
+    > {
+        r#type: H0,
+        hardware_serial: H1,
+        hardware_id_wacom: H2,
+        capability: H3,
+        done: H4,
+        removed: H5,
+        proximity_in: H6,
+        proximity_out: H7,
+        down: H8,
+        up: H9,
+        motion: H10,
+        pressure: H11,
+        distance: H12,
+        tilt: H13,
+        rotation: H14,
+        slider: H15,
+        wheel: H16,
+        button: H17,
+        frame: H18,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12, H13, H14, H15, H16, H17, H18>
+        ZwpTabletToolV2EventHandler
+        for Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
     where
-        F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+        H0: ZwpTabletToolV2EventHandler,
+        H1: ZwpTabletToolV2EventHandler,
+        H2: ZwpTabletToolV2EventHandler,
+        H3: ZwpTabletToolV2EventHandler,
+        H4: ZwpTabletToolV2EventHandler,
+        H5: ZwpTabletToolV2EventHandler,
+        H6: ZwpTabletToolV2EventHandler,
+        H7: ZwpTabletToolV2EventHandler,
+        H8: ZwpTabletToolV2EventHandler,
+        H9: ZwpTabletToolV2EventHandler,
+        H10: ZwpTabletToolV2EventHandler,
+        H11: ZwpTabletToolV2EventHandler,
+        H12: ZwpTabletToolV2EventHandler,
+        H13: ZwpTabletToolV2EventHandler,
+        H14: ZwpTabletToolV2EventHandler,
+        H15: ZwpTabletToolV2EventHandler,
+        H16: ZwpTabletToolV2EventHandler,
+        H17: ZwpTabletToolV2EventHandler,
+        H18: ZwpTabletToolV2EventHandler,
     {
         #[inline]
         fn r#type(&self, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
-            self.0(_slf, tool_type)
+            self.r#type.r#type(_slf, tool_type)
         }
-    }
 
-    /// Event handler for hardware_serial events.
-    pub struct HardwareSerial<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for HardwareSerial<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32, u32),
-    {
         #[inline]
         fn hardware_serial(
             &self,
@@ -1294,16 +5148,10 @@ pub mod event_handlers {
             hardware_serial_hi: u32,
             hardware_serial_lo: u32,
         ) {
-            self.0(_slf, hardware_serial_hi, hardware_serial_lo)
+            self.hardware_serial
+                .hardware_serial(_slf, hardware_serial_hi, hardware_serial_lo)
         }
-    }
 
-    /// Event handler for hardware_id_wacom events.
-    pub struct HardwareIdWacom<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for HardwareIdWacom<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32, u32),
-    {
         #[inline]
         fn hardware_id_wacom(
             &self,
@@ -1311,52 +5159,25 @@ pub mod event_handlers {
             hardware_id_hi: u32,
             hardware_id_lo: u32,
         ) {
-            self.0(_slf, hardware_id_hi, hardware_id_lo)
+            self.hardware_id_wacom
+                .hardware_id_wacom(_slf, hardware_id_hi, hardware_id_lo)
         }
-    }
 
-    /// Event handler for capability events.
-    pub struct Capability<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Capability<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
-    {
         #[inline]
         fn capability(&self, _slf: &ZwpTabletToolV2Ref, capability: ZwpTabletToolV2Capability) {
-            self.0(_slf, capability)
+            self.capability.capability(_slf, capability)
         }
-    }
 
-    /// Event handler for done events.
-    pub struct Done<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Done<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref),
-    {
         #[inline]
         fn done(&self, _slf: &ZwpTabletToolV2Ref) {
-            self.0(_slf)
+            self.done.done(_slf)
         }
-    }
 
-    /// Event handler for removed events.
-    pub struct Removed<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Removed<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref),
-    {
         #[inline]
         fn removed(&self, _slf: &ZwpTabletToolV2Ref) {
-            self.0(_slf)
+            self.removed.removed(_slf)
         }
-    }
 
-    /// Event handler for proximity_in events.
-    pub struct ProximityIn<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for ProximityIn<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
-    {
         #[inline]
         fn proximity_in(
             &self,
@@ -1365,368 +5186,2233 @@ pub mod event_handlers {
             tablet: Option<&ZwpTabletV2Ref>,
             surface: Option<&WlSurfaceRef>,
         ) {
-            self.0(_slf, serial, tablet, surface)
+            self.proximity_in
+                .proximity_in(_slf, serial, tablet, surface)
         }
-    }
 
-    /// Event handler for proximity_out events.
-    pub struct ProximityOut<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for ProximityOut<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref),
-    {
         #[inline]
         fn proximity_out(&self, _slf: &ZwpTabletToolV2Ref) {
-            self.0(_slf)
+            self.proximity_out.proximity_out(_slf)
         }
-    }
 
-    /// Event handler for down events.
-    pub struct Down<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Down<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32),
-    {
         #[inline]
         fn down(&self, _slf: &ZwpTabletToolV2Ref, serial: u32) {
-            self.0(_slf, serial)
+            self.down.down(_slf, serial)
         }
-    }
 
-    /// Event handler for up events.
-    pub struct Up<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Up<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref),
-    {
         #[inline]
         fn up(&self, _slf: &ZwpTabletToolV2Ref) {
-            self.0(_slf)
+            self.up.up(_slf)
         }
-    }
 
-    /// Event handler for motion events.
-    pub struct Motion<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Motion<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
-    {
         #[inline]
         fn motion(&self, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
-            self.0(_slf, x, y)
+            self.motion.motion(_slf, x, y)
         }
-    }
 
-    /// Event handler for pressure events.
-    pub struct Pressure<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Pressure<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32),
-    {
         #[inline]
         fn pressure(&self, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
-            self.0(_slf, pressure)
+            self.pressure.pressure(_slf, pressure)
         }
-    }
 
-    /// Event handler for distance events.
-    pub struct Distance<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Distance<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32),
-    {
         #[inline]
         fn distance(&self, _slf: &ZwpTabletToolV2Ref, distance: u32) {
-            self.0(_slf, distance)
+            self.distance.distance(_slf, distance)
         }
-    }
 
-    /// Event handler for tilt events.
-    pub struct Tilt<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Tilt<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
-    {
         #[inline]
         fn tilt(&self, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
-            self.0(_slf, tilt_x, tilt_y)
+            self.tilt.tilt(_slf, tilt_x, tilt_y)
         }
-    }
 
-    /// Event handler for rotation events.
-    pub struct Rotation<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Rotation<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, Fixed),
-    {
         #[inline]
         fn rotation(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
-            self.0(_slf, degrees)
+            self.rotation.rotation(_slf, degrees)
+        }
+
+        #[inline]
+        fn slider(&self, _slf: &ZwpTabletToolV2Ref, position: i32) {
+            self.slider.slider(_slf, position)
+        }
+
+        #[inline]
+        fn wheel(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+            self.wheel.wheel(_slf, degrees, clicks)
+        }
+
+        #[inline]
+        fn button(
+            &self,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            button: u32,
+            state: ZwpTabletToolV2ButtonState,
+        ) {
+            self.button.button(_slf, serial, button, state)
+        }
+
+        #[inline]
+        fn frame(&self, _slf: &ZwpTabletToolV2Ref, time: u32) {
+            self.frame.frame(_slf, time)
         }
     }
 
-    /// Event handler for slider events.
-    pub struct Slider<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Slider<F>
+    impl<T, H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12, H13, H14, H15, H16, H17, H18>
+        ZwpTabletToolV2EventHandlerWithData<T>
+        for Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
     where
-        F: Fn(&ZwpTabletToolV2Ref, i32),
+        H0: ZwpTabletToolV2EventHandlerWithData<T>,
+        H1: ZwpTabletToolV2EventHandlerWithData<T>,
+        H2: ZwpTabletToolV2EventHandlerWithData<T>,
+        H3: ZwpTabletToolV2EventHandlerWithData<T>,
+        H4: ZwpTabletToolV2EventHandlerWithData<T>,
+        H5: ZwpTabletToolV2EventHandlerWithData<T>,
+        H6: ZwpTabletToolV2EventHandlerWithData<T>,
+        H7: ZwpTabletToolV2EventHandlerWithData<T>,
+        H8: ZwpTabletToolV2EventHandlerWithData<T>,
+        H9: ZwpTabletToolV2EventHandlerWithData<T>,
+        H10: ZwpTabletToolV2EventHandlerWithData<T>,
+        H11: ZwpTabletToolV2EventHandlerWithData<T>,
+        H12: ZwpTabletToolV2EventHandlerWithData<T>,
+        H13: ZwpTabletToolV2EventHandlerWithData<T>,
+        H14: ZwpTabletToolV2EventHandlerWithData<T>,
+        H15: ZwpTabletToolV2EventHandlerWithData<T>,
+        H16: ZwpTabletToolV2EventHandlerWithData<T>,
+        H17: ZwpTabletToolV2EventHandlerWithData<T>,
+        H18: ZwpTabletToolV2EventHandlerWithData<T>,
     {
         #[inline]
-        fn slider(&self, _slf: &ZwpTabletToolV2Ref, position: i32) {
-            self.0(_slf, position)
+        fn r#type(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tool_type: ZwpTabletToolV2Type) {
+            self.r#type.r#type(_data, _slf, tool_type)
+        }
+
+        #[inline]
+        fn hardware_serial(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_serial_hi: u32,
+            hardware_serial_lo: u32,
+        ) {
+            self.hardware_serial.hardware_serial(
+                _data,
+                _slf,
+                hardware_serial_hi,
+                hardware_serial_lo,
+            )
+        }
+
+        #[inline]
+        fn hardware_id_wacom(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            hardware_id_hi: u32,
+            hardware_id_lo: u32,
+        ) {
+            self.hardware_id_wacom
+                .hardware_id_wacom(_data, _slf, hardware_id_hi, hardware_id_lo)
+        }
+
+        #[inline]
+        fn capability(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            capability: ZwpTabletToolV2Capability,
+        ) {
+            self.capability.capability(_data, _slf, capability)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.removed.removed(_data, _slf)
+        }
+
+        #[inline]
+        fn proximity_in(
+            &self,
+            _data: &mut T,
+            _slf: &ZwpTabletToolV2Ref,
+            serial: u32,
+            tablet: Option<&ZwpTabletV2Ref>,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.proximity_in
+                .proximity_in(_data, _slf, serial, tablet, surface)
+        }
+
+        #[inline]
+        fn proximity_out(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.proximity_out.proximity_out(_data, _slf)
+        }
+
+        #[inline]
+        fn down(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, serial: u32) {
+            self.down.down(_data, _slf, serial)
+        }
+
+        #[inline]
+        fn up(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref) {
+            self.up.up(_data, _slf)
+        }
+
+        #[inline]
+        fn motion(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, x: Fixed, y: Fixed) {
+            self.motion.motion(_data, _slf, x, y)
+        }
+
+        #[inline]
+        fn pressure(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, pressure: u32) {
+            self.pressure.pressure(_data, _slf, pressure)
+        }
+
+        #[inline]
+        fn distance(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, distance: u32) {
+            self.distance.distance(_data, _slf, distance)
+        }
+
+        #[inline]
+        fn tilt(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, tilt_x: Fixed, tilt_y: Fixed) {
+            self.tilt.tilt(_data, _slf, tilt_x, tilt_y)
+        }
+
+        #[inline]
+        fn rotation(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed) {
+            self.rotation.rotation(_data, _slf, degrees)
+        }
+
+        #[inline]
+        fn slider(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, position: i32) {
+            self.slider.slider(_data, _slf, position)
         }
-    }
 
-    /// Event handler for wheel events.
-    pub struct Wheel<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Wheel<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, Fixed, i32),
-    {
         #[inline]
-        fn wheel(&self, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
-            self.0(_slf, degrees, clicks)
+        fn wheel(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, degrees: Fixed, clicks: i32) {
+            self.wheel.wheel(_data, _slf, degrees, clicks)
         }
-    }
 
-    /// Event handler for button events.
-    pub struct Button<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Button<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
-    {
         #[inline]
         fn button(
             &self,
+            _data: &mut T,
             _slf: &ZwpTabletToolV2Ref,
             serial: u32,
             button: u32,
             state: ZwpTabletToolV2ButtonState,
         ) {
-            self.0(_slf, serial, button, state)
+            self.button.button(_data, _slf, serial, button, state)
         }
-    }
 
-    /// Event handler for frame events.
-    pub struct Frame<F>(F);
-    impl<F> ZwpTabletToolV2EventHandler for Frame<F>
-    where
-        F: Fn(&ZwpTabletToolV2Ref, u32),
-    {
         #[inline]
-        fn frame(&self, _slf: &ZwpTabletToolV2Ref, time: u32) {
-            self.0(_slf, time)
+        fn frame(&self, _data: &mut T, _slf: &ZwpTabletToolV2Ref, time: u32) {
+            self.frame.frame(_data, _slf, time)
         }
     }
 
-    impl ZwpTabletToolV2 {
-        /// Creates an event handler for type events.
-        ///
-        /// The event handler ignores all other events.
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12, H13, H14, H15, H16, H17, H18>
+        Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10, H11, H12, H13, H14, H15, H16, H17, H18>
+    {
+        /// Sets the closure for type events.
         #[allow(dead_code)]
-        pub fn on_type<F>(f: F) -> Type<F>
+        pub fn on_type<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            Type<F>,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
         {
-            Type(f)
+            Handler {
+                r#type: Type(f),
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for hardware_serial events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for type events.
         #[allow(dead_code)]
-        pub fn on_hardware_serial<F>(f: F) -> HardwareSerial<F>
+        pub fn on_type_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            Type<F>,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Type),
+        {
+            Handler {
+                r#type: Type(f),
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for hardware_serial events.
+        #[allow(dead_code)]
+        pub fn on_hardware_serial<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            HardwareSerial<F>,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32, u32),
         {
-            HardwareSerial(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: HardwareSerial(f),
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for hardware_id_wacom events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for hardware_serial events.
         #[allow(dead_code)]
-        pub fn on_hardware_id_wacom<F>(f: F) -> HardwareIdWacom<F>
+        pub fn on_hardware_serial_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            HardwareSerial<F>,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: HardwareSerial(f),
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for hardware_id_wacom events.
+        #[allow(dead_code)]
+        pub fn on_hardware_id_wacom<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            HardwareIdWacom<F>,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32, u32),
         {
-            HardwareIdWacom(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: HardwareIdWacom(f),
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for capability events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for hardware_id_wacom events.
         #[allow(dead_code)]
-        pub fn on_capability<F>(f: F) -> Capability<F>
+        pub fn on_hardware_id_wacom_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            HardwareIdWacom<F>,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: HardwareIdWacom(f),
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for capability events.
+        #[allow(dead_code)]
+        pub fn on_capability<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            Capability<F>,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
         {
-            Capability(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: Capability(f),
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for done events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for capability events.
         #[allow(dead_code)]
-        pub fn on_done<F>(f: F) -> Done<F>
+        pub fn on_capability_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            Capability<F>,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, ZwpTabletToolV2Capability),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: Capability(f),
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            Done<F>,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref),
         {
-            Done(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: Done(f),
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for removed events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for done events.
         #[allow(dead_code)]
-        pub fn on_removed<F>(f: F) -> Removed<F>
+        pub fn on_done_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            Done<F>,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: Done(f),
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            Removed<F>,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref),
         {
-            Removed(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: Removed(f),
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for proximity_in events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for removed events.
         #[allow(dead_code)]
-        pub fn on_proximity_in<F>(f: F) -> ProximityIn<F>
+        pub fn on_removed_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            Removed<F>,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: Removed(f),
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for proximity_in events.
+        #[allow(dead_code)]
+        pub fn on_proximity_in<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            ProximityIn<F>,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
         {
-            ProximityIn(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: ProximityIn(f),
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for proximity_out events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for proximity_in events.
         #[allow(dead_code)]
-        pub fn on_proximity_out<F>(f: F) -> ProximityOut<F>
+        pub fn on_proximity_in_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            ProximityIn<F>,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, Option<&ZwpTabletV2Ref>, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: ProximityIn(f),
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for proximity_out events.
+        #[allow(dead_code)]
+        pub fn on_proximity_out<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            ProximityOut<F>,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref),
         {
-            ProximityOut(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: ProximityOut(f),
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for down events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for proximity_out events.
         #[allow(dead_code)]
-        pub fn on_down<F>(f: F) -> Down<F>
+        pub fn on_proximity_out_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            ProximityOut<F>,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: ProximityOut(f),
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for down events.
+        #[allow(dead_code)]
+        pub fn on_down<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            Down<F>,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32),
         {
-            Down(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: Down(f),
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for up events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for down events.
         #[allow(dead_code)]
-        pub fn on_up<F>(f: F) -> Up<F>
+        pub fn on_down_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            Down<F>,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: Down(f),
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for up events.
+        #[allow(dead_code)]
+        pub fn on_up<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            Up<F>,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref),
         {
-            Up(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: Up(f),
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for motion events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for up events.
         #[allow(dead_code)]
-        pub fn on_motion<F>(f: F) -> Motion<F>
+        pub fn on_up_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            Up<F>,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: Up(f),
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for motion events.
+        #[allow(dead_code)]
+        pub fn on_motion<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            Motion<F>,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
         {
-            Motion(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: Motion(f),
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for pressure events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for motion events.
         #[allow(dead_code)]
-        pub fn on_pressure<F>(f: F) -> Pressure<F>
+        pub fn on_motion_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            Motion<F>,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: Motion(f),
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for pressure events.
+        #[allow(dead_code)]
+        pub fn on_pressure<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            Pressure<F>,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32),
         {
-            Pressure(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: Pressure(f),
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for distance events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for pressure events.
         #[allow(dead_code)]
-        pub fn on_distance<F>(f: F) -> Distance<F>
+        pub fn on_pressure_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            Pressure<F>,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: Pressure(f),
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for distance events.
+        #[allow(dead_code)]
+        pub fn on_distance<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            Distance<F>,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32),
         {
-            Distance(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: Distance(f),
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for tilt events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for distance events.
         #[allow(dead_code)]
-        pub fn on_tilt<F>(f: F) -> Tilt<F>
+        pub fn on_distance_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            Distance<F>,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: Distance(f),
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for tilt events.
+        #[allow(dead_code)]
+        pub fn on_tilt<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            Tilt<F>,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, Fixed, Fixed),
         {
-            Tilt(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: Tilt(f),
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for rotation events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for tilt events.
         #[allow(dead_code)]
-        pub fn on_rotation<F>(f: F) -> Rotation<F>
+        pub fn on_tilt_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            Tilt<F>,
+            H14,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, Fixed),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: Tilt(f),
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for rotation events.
+        #[allow(dead_code)]
+        pub fn on_rotation<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            Rotation<F>,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, Fixed),
         {
-            Rotation(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: Rotation(f),
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for slider events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for rotation events.
         #[allow(dead_code)]
-        pub fn on_slider<F>(f: F) -> Slider<F>
+        pub fn on_rotation_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            Rotation<F>,
+            H15,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: Rotation(f),
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for slider events.
+        #[allow(dead_code)]
+        pub fn on_slider<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            Slider<F>,
+            H16,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, i32),
         {
-            Slider(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: Slider(f),
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for wheel events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for slider events.
         #[allow(dead_code)]
-        pub fn on_wheel<F>(f: F) -> Wheel<F>
+        pub fn on_slider_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            Slider<F>,
+            H16,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, i32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: Slider(f),
+                wheel: self.wheel,
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for wheel events.
+        #[allow(dead_code)]
+        pub fn on_wheel<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            Wheel<F>,
+            H17,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, Fixed, i32),
         {
-            Wheel(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: Wheel(f),
+                button: self.button,
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for button events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for wheel events.
         #[allow(dead_code)]
-        pub fn on_button<F>(f: F) -> Button<F>
+        pub fn on_wheel_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            Wheel<F>,
+            H17,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, Fixed, i32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: Wheel(f),
+                button: self.button,
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for button events.
+        #[allow(dead_code)]
+        pub fn on_button<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            Button<F>,
+            H18,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
         {
-            Button(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: Button(f),
+                frame: self.frame,
+            }
         }
 
-        /// Creates an event handler for frame events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for button events.
         #[allow(dead_code)]
-        pub fn on_frame<F>(f: F) -> Frame<F>
+        pub fn on_button_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            Button<F>,
+            H18,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32, u32, ZwpTabletToolV2ButtonState),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: Button(f),
+                frame: self.frame,
+            }
+        }
+
+        /// Sets the closure for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            Frame<F>,
+        >
         where
             F: Fn(&ZwpTabletToolV2Ref, u32),
         {
-            Frame(f)
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: Frame(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<
+            H0,
+            H1,
+            H2,
+            H3,
+            H4,
+            H5,
+            H6,
+            H7,
+            H8,
+            H9,
+            H10,
+            H11,
+            H12,
+            H13,
+            H14,
+            H15,
+            H16,
+            H17,
+            Frame<F>,
+        >
+        where
+            F: Fn(&mut T, &ZwpTabletToolV2Ref, u32),
+        {
+            Handler {
+                r#type: self.r#type,
+                hardware_serial: self.hardware_serial,
+                hardware_id_wacom: self.hardware_id_wacom,
+                capability: self.capability,
+                done: self.done,
+                removed: self.removed,
+                proximity_in: self.proximity_in,
+                proximity_out: self.proximity_out,
+                down: self.down,
+                up: self.up,
+                motion: self.motion,
+                pressure: self.pressure,
+                distance: self.distance,
+                tilt: self.tilt,
+                rotation: self.rotation,
+                slider: self.slider,
+                wheel: self.wheel,
+                button: self.button,
+                frame: Frame(f),
+            }
         }
     }
 }
@@ -74,6 +74,86 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the zwp_tablet_v2 interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "zwp_tablet_v2",
+    protocol: "tablet_v2",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "name",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "vid",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "pid",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "path",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "path",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "removed",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[],
+};
+
 /// An owned zwp_tablet_v2 proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -128,11 +208,16 @@ impl Deref for ZwpTabletV2 {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -291,6 +376,295 @@ pub trait ZwpTabletV2EventHandler {
 
 impl ZwpTabletV2EventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [ZwpTabletV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletV2EventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait ZwpTabletV2EventHandlerWithData<T> {
+    /// tablet device name
+    ///
+    /// A descriptive name for the tablet device.
+    ///
+    /// 	If the device has no descriptive name, this event is not sent.
+    ///
+    /// 	This event is sent in the initial burst of events before the
+    /// wp_tablet.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: the device name
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// tablet device USB vendor/product id
+    ///
+    /// The USB vendor and product IDs for the tablet device.
+    ///
+    /// If the device has no USB vendor/product ID, this event is not sent.
+    /// This can happen for virtual devices or non-USB devices, for instance.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `vid`: USB vendor id
+    /// - `pid`: USB product id
+    #[inline]
+    fn id(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        let _ = vid;
+        let _ = pid;
+    }
+
+    /// path to the device
+    ///
+    /// A system-specific device path that indicates which device is behind
+    /// this wp_tablet. This information may be used to gather additional
+    /// information about the device, e.g. through libwacom.
+    ///
+    /// A device may have more than one device path. If so, multiple
+    /// wp_tablet.path events are sent. A device may be emulated and not
+    /// have a device path, and in that case this event will not be sent.
+    ///
+    /// The format of the path is unspecified, it may be a device node, a
+    /// sysfs path, or some other identifier. It is up to the client to
+    /// identify the string provided.
+    ///
+    /// This event is sent in the initial burst of events before the
+    /// wp_tablet.done event.
+    ///
+    /// # Arguments
+    ///
+    /// - `path`: path to local device
+    #[inline]
+    fn path(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+        let _ = path;
+    }
+
+    /// tablet description events sequence complete
+    ///
+    /// This event is sent immediately to signal the end of the initial
+    /// burst of descriptive events. A client may consider the static
+    /// description of the tablet to be complete and finalize initialization
+    /// of the tablet.
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {}
+
+    /// tablet removed event
+    ///
+    /// Sent when the tablet has been removed from the system. When a tablet
+    /// is removed, some tools may be removed.
+    ///
+    /// When this event is received, the client must wp_tablet.destroy
+    /// the object.
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {}
+}
+
+/// A mutable event handler for [ZwpTabletV2] proxies.
+///
+/// This trait is the same as [ZwpTabletV2EventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletV2EventHandlerMut {
+    /// See [ZwpTabletV2EventHandler::name].
+    #[inline]
+    fn name(&mut self, _slf: &ZwpTabletV2Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [ZwpTabletV2EventHandler::id].
+    #[inline]
+    fn id(&mut self, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        let _ = vid;
+        let _ = pid;
+    }
+
+    /// See [ZwpTabletV2EventHandler::path].
+    #[inline]
+    fn path(&mut self, _slf: &ZwpTabletV2Ref, path: &str) {
+        let _ = path;
+    }
+
+    /// See [ZwpTabletV2EventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &ZwpTabletV2Ref) {}
+
+    /// See [ZwpTabletV2EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _slf: &ZwpTabletV2Ref) {}
+}
+
+/// A mutable event handler for [ZwpTabletV2] proxies with mutable data.
+///
+/// This trait is the same as [ZwpTabletV2EventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait ZwpTabletV2EventHandlerMutWithData<T> {
+    /// See [ZwpTabletV2EventHandler::name].
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+        let _ = name;
+    }
+
+    /// See [ZwpTabletV2EventHandler::id].
+    #[inline]
+    fn id(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        let _ = vid;
+        let _ = pid;
+    }
+
+    /// See [ZwpTabletV2EventHandler::path].
+    #[inline]
+    fn path(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+        let _ = path;
+    }
+
+    /// See [ZwpTabletV2EventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref) {}
+
+    /// See [ZwpTabletV2EventHandler::removed].
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref) {}
+}
+
+impl<H> ZwpTabletV2EventHandler for MutEventHandler<H>
+where
+    H: ZwpTabletV2EventHandlerMut,
+{
+    #[inline]
+    fn name(&self, _slf: &ZwpTabletV2Ref, name: &str) {
+        self.with(|h| h.name(_slf, name))
+    }
+
+    #[inline]
+    fn id(&self, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        self.with(|h| h.id(_slf, vid, pid))
+    }
+
+    #[inline]
+    fn path(&self, _slf: &ZwpTabletV2Ref, path: &str) {
+        self.with(|h| h.path(_slf, path))
+    }
+
+    #[inline]
+    fn done(&self, _slf: &ZwpTabletV2Ref) {
+        self.with(|h| h.done(_slf))
+    }
+
+    #[inline]
+    fn removed(&self, _slf: &ZwpTabletV2Ref) {
+        self.with(|h| h.removed(_slf))
+    }
+}
+
+impl<T, H> ZwpTabletV2EventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: ZwpTabletV2EventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+        self.with(|h| h.name(_data, _slf, name))
+    }
+
+    #[inline]
+    fn id(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        self.with(|h| h.id(_data, _slf, vid, pid))
+    }
+
+    #[inline]
+    fn path(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+        self.with(|h| h.path(_data, _slf, path))
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+        self.with(|h| h.done(_data, _slf))
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+        self.with(|h| h.removed(_data, _slf))
+    }
+}
+
+impl<T, U, H> ZwpTabletV2EventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletV2EventHandlerWithData<U>,
+{
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn id(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        let _data = self.project(_data);
+        self.handler().id(_data, _slf, vid, pid)
+    }
+
+    #[inline]
+    fn path(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+        let _data = self.project(_data);
+        self.handler().path(_data, _slf, path)
+    }
+
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf)
+    }
+
+    #[inline]
+    fn removed(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+        let _data = self.project(_data);
+        self.handler().removed(_data, _slf)
+    }
+}
+
+impl<T, U, H> ZwpTabletV2EventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: ZwpTabletV2EventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().name(_data, _slf, name)
+    }
+
+    #[inline]
+    fn id(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().id(_data, _slf, vid, pid)
+    }
+
+    #[inline]
+    fn path(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().path(_data, _slf, path)
+    }
+
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf)
+    }
+
+    #[inline]
+    fn removed(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+        let _data = self.project(_data);
+        self.handler_mut().removed(_data, _slf)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -348,6 +722,75 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: ZwpTabletV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<ZwpTabletV2Ref>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("zwp_tablet_v2", "name", args[0].s) };
+                self.0.name(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                self.0.id(data, slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("zwp_tablet_v2", "path", args[0].s) };
+                self.0.path(data, slf, arg0);
+            }
+            3 => {
+                self.0.done(data, slf);
+            }
+            4 => {
+                self.0.removed(data, slf);
+            }
+            _ => {
+                invalid_opcode("zwp_tablet_v2", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: ZwpTabletV2EventHandler,
@@ -360,6 +803,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: ZwpTabletV2EventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -375,6 +831,15 @@ pub mod event_handlers {
             self.0(_slf, name)
         }
     }
+    impl<T, F> ZwpTabletV2EventHandlerWithData<T> for Name<F>
+    where
+        F: Fn(&mut T, &ZwpTabletV2Ref, &str),
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
 
     /// Event handler for id events.
     pub struct Id<F>(F);
@@ -387,6 +852,15 @@ pub mod event_handlers {
             self.0(_slf, vid, pid)
         }
     }
+    impl<T, F> ZwpTabletV2EventHandlerWithData<T> for Id<F>
+    where
+        F: Fn(&mut T, &ZwpTabletV2Ref, u32, u32),
+    {
+        #[inline]
+        fn id(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+            self.0(_data, _slf, vid, pid)
+        }
+    }
 
     /// Event handler for path events.
     pub struct Path<F>(F);
@@ -399,6 +873,15 @@ pub mod event_handlers {
             self.0(_slf, path)
         }
     }
+    impl<T, F> ZwpTabletV2EventHandlerWithData<T> for Path<F>
+    where
+        F: Fn(&mut T, &ZwpTabletV2Ref, &str),
+    {
+        #[inline]
+        fn path(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+            self.0(_data, _slf, path)
+        }
+    }
 
     /// Event handler for done events.
     pub struct Done<F>(F);
@@ -411,6 +894,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> ZwpTabletV2EventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &ZwpTabletV2Ref),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for removed events.
     pub struct Removed<F>(F);
@@ -423,6 +915,120 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> ZwpTabletV2EventHandlerWithData<T> for Removed<F>
+    where
+        F: Fn(&mut T, &ZwpTabletV2Ref),
+    {
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for name events.
+    pub struct NameMut<F>(F);
+    impl<F> ZwpTabletV2EventHandlerMut for NameMut<F>
+    where
+        F: FnMut(&ZwpTabletV2Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _slf: &ZwpTabletV2Ref, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> ZwpTabletV2EventHandlerMutWithData<T> for NameMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletV2Ref, &str),
+    {
+        #[inline]
+        fn name(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Mutable event handler for id events.
+    pub struct IdMut<F>(F);
+    impl<F> ZwpTabletV2EventHandlerMut for IdMut<F>
+    where
+        F: FnMut(&ZwpTabletV2Ref, u32, u32),
+    {
+        #[inline]
+        fn id(&mut self, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+            self.0(_slf, vid, pid)
+        }
+    }
+    impl<T, F> ZwpTabletV2EventHandlerMutWithData<T> for IdMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletV2Ref, u32, u32),
+    {
+        #[inline]
+        fn id(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+            self.0(_data, _slf, vid, pid)
+        }
+    }
+
+    /// Mutable event handler for path events.
+    pub struct PathMut<F>(F);
+    impl<F> ZwpTabletV2EventHandlerMut for PathMut<F>
+    where
+        F: FnMut(&ZwpTabletV2Ref, &str),
+    {
+        #[inline]
+        fn path(&mut self, _slf: &ZwpTabletV2Ref, path: &str) {
+            self.0(_slf, path)
+        }
+    }
+    impl<T, F> ZwpTabletV2EventHandlerMutWithData<T> for PathMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletV2Ref, &str),
+    {
+        #[inline]
+        fn path(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+            self.0(_data, _slf, path)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> ZwpTabletV2EventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&ZwpTabletV2Ref),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &ZwpTabletV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletV2EventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletV2Ref),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for removed events.
+    pub struct RemovedMut<F>(F);
+    impl<F> ZwpTabletV2EventHandlerMut for RemovedMut<F>
+    where
+        F: FnMut(&ZwpTabletV2Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _slf: &ZwpTabletV2Ref) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> ZwpTabletV2EventHandlerMutWithData<T> for RemovedMut<F>
+    where
+        F: FnMut(&mut T, &ZwpTabletV2Ref),
+    {
+        #[inline]
+        fn removed(&mut self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+            self.0(_data, _slf)
+        }
+    }
 
     impl ZwpTabletV2 {
         /// Creates an event handler for name events.
@@ -436,6 +1042,17 @@ pub mod event_handlers {
             Name(f)
         }
 
+        /// Creates an event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(f: F) -> Name<F>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref, &str),
+        {
+            Name(f)
+        }
+
         /// Creates an event handler for id events.
         ///
         /// The event handler ignores all other events.
@@ -447,6 +1064,17 @@ pub mod event_handlers {
             Id(f)
         }
 
+        /// Creates an event handler with mutable data for id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_id_with_data<T, F>(f: F) -> Id<F>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref, u32, u32),
+        {
+            Id(f)
+        }
+
         /// Creates an event handler for path events.
         ///
         /// The event handler ignores all other events.
@@ -458,6 +1086,17 @@ pub mod event_handlers {
             Path(f)
         }
 
+        /// Creates an event handler with mutable data for path events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_path_with_data<T, F>(f: F) -> Path<F>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref, &str),
+        {
+            Path(f)
+        }
+
         /// Creates an event handler for done events.
         ///
         /// The event handler ignores all other events.
@@ -469,6 +1108,17 @@ pub mod event_handlers {
             Done(f)
         }
 
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref),
+        {
+            Done(f)
+        }
+
         /// Creates an event handler for removed events.
         ///
         /// The event handler ignores all other events.
@@ -479,5 +1129,393 @@ pub mod event_handlers {
         {
             Removed(f)
         }
+
+        /// Creates an event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(f: F) -> Removed<F>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref),
+        {
+            Removed(f)
+        }
+
+        /// Creates a mutable event handler for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut<F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&ZwpTabletV2Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut_with_data<T, F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletV2Ref, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler for id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_id_mut<F>(f: F) -> IdMut<F>
+        where
+            F: FnMut(&ZwpTabletV2Ref, u32, u32),
+        {
+            IdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_id_mut_with_data<T, F>(f: F) -> IdMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletV2Ref, u32, u32),
+        {
+            IdMut(f)
+        }
+
+        /// Creates a mutable event handler for path events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_path_mut<F>(f: F) -> PathMut<F>
+        where
+            F: FnMut(&ZwpTabletV2Ref, &str),
+        {
+            PathMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for path events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_path_mut_with_data<T, F>(f: F) -> PathMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletV2Ref, &str),
+        {
+            PathMut(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&ZwpTabletV2Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletV2Ref),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut<F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&ZwpTabletV2Ref),
+        {
+            RemovedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for removed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_removed_mut_with_data<T, F>(f: F) -> RemovedMut<F>
+        where
+            F: FnMut(&mut T, &ZwpTabletV2Ref),
+        {
+            RemovedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                name: Ignore,
+                id: Ignore,
+                path: Ignore,
+                done: Ignore,
+                removed: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl ZwpTabletV2EventHandler for Ignore {}
+    impl<T> ZwpTabletV2EventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`ZwpTabletV2::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore> {
+        name: H0,
+        id: H1,
+        path: H2,
+        done: H3,
+        removed: H4,
+    }
+
+    impl<H0, H1, H2, H3, H4> ZwpTabletV2EventHandler for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ZwpTabletV2EventHandler,
+        H1: ZwpTabletV2EventHandler,
+        H2: ZwpTabletV2EventHandler,
+        H3: ZwpTabletV2EventHandler,
+        H4: ZwpTabletV2EventHandler,
+    {
+        #[inline]
+        fn name(&self, _slf: &ZwpTabletV2Ref, name: &str) {
+            self.name.name(_slf, name)
+        }
+
+        #[inline]
+        fn id(&self, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+            self.id.id(_slf, vid, pid)
+        }
+
+        #[inline]
+        fn path(&self, _slf: &ZwpTabletV2Ref, path: &str) {
+            self.path.path(_slf, path)
+        }
+
+        #[inline]
+        fn done(&self, _slf: &ZwpTabletV2Ref) {
+            self.done.done(_slf)
+        }
+
+        #[inline]
+        fn removed(&self, _slf: &ZwpTabletV2Ref) {
+            self.removed.removed(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4> ZwpTabletV2EventHandlerWithData<T> for Handler<H0, H1, H2, H3, H4>
+    where
+        H0: ZwpTabletV2EventHandlerWithData<T>,
+        H1: ZwpTabletV2EventHandlerWithData<T>,
+        H2: ZwpTabletV2EventHandlerWithData<T>,
+        H3: ZwpTabletV2EventHandlerWithData<T>,
+        H4: ZwpTabletV2EventHandlerWithData<T>,
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, name: &str) {
+            self.name.name(_data, _slf, name)
+        }
+
+        #[inline]
+        fn id(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, vid: u32, pid: u32) {
+            self.id.id(_data, _slf, vid, pid)
+        }
+
+        #[inline]
+        fn path(&self, _data: &mut T, _slf: &ZwpTabletV2Ref, path: &str) {
+            self.path.path(_data, _slf, path)
+        }
+
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+            self.done.done(_data, _slf)
+        }
+
+        #[inline]
+        fn removed(&self, _data: &mut T, _slf: &ZwpTabletV2Ref) {
+            self.removed.removed(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4> Handler<H0, H1, H2, H3, H4> {
+        /// Sets the closure for name events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(self, f: F) -> Handler<Name<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&ZwpTabletV2Ref, &str),
+        {
+            Handler {
+                name: Name(f),
+                id: self.id,
+                path: self.path,
+                done: self.done,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for name events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(self, f: F) -> Handler<Name<F>, H1, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref, &str),
+        {
+            Handler {
+                name: Name(f),
+                id: self.id,
+                path: self.path,
+                done: self.done,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for id events.
+        #[allow(dead_code)]
+        pub fn on_id<F>(self, f: F) -> Handler<H0, Id<F>, H2, H3, H4>
+        where
+            F: Fn(&ZwpTabletV2Ref, u32, u32),
+        {
+            Handler {
+                name: self.name,
+                id: Id(f),
+                path: self.path,
+                done: self.done,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for id events.
+        #[allow(dead_code)]
+        pub fn on_id_with_data<T, F>(self, f: F) -> Handler<H0, Id<F>, H2, H3, H4>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref, u32, u32),
+        {
+            Handler {
+                name: self.name,
+                id: Id(f),
+                path: self.path,
+                done: self.done,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for path events.
+        #[allow(dead_code)]
+        pub fn on_path<F>(self, f: F) -> Handler<H0, H1, Path<F>, H3, H4>
+        where
+            F: Fn(&ZwpTabletV2Ref, &str),
+        {
+            Handler {
+                name: self.name,
+                id: self.id,
+                path: Path(f),
+                done: self.done,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for path events.
+        #[allow(dead_code)]
+        pub fn on_path_with_data<T, F>(self, f: F) -> Handler<H0, H1, Path<F>, H3, H4>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref, &str),
+        {
+            Handler {
+                name: self.name,
+                id: self.id,
+                path: Path(f),
+                done: self.done,
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<H0, H1, H2, Done<F>, H4>
+        where
+            F: Fn(&ZwpTabletV2Ref),
+        {
+            Handler {
+                name: self.name,
+                id: self.id,
+                path: self.path,
+                done: Done(f),
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, Done<F>, H4>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref),
+        {
+            Handler {
+                name: self.name,
+                id: self.id,
+                path: self.path,
+                done: Done(f),
+                removed: self.removed,
+            }
+        }
+
+        /// Sets the closure for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed<F>(self, f: F) -> Handler<H0, H1, H2, H3, Removed<F>>
+        where
+            F: Fn(&ZwpTabletV2Ref),
+        {
+            Handler {
+                name: self.name,
+                id: self.id,
+                path: self.path,
+                done: self.done,
+                removed: Removed(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for removed events.
+        #[allow(dead_code)]
+        pub fn on_removed_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, Removed<F>>
+        where
+            F: Fn(&mut T, &ZwpTabletV2Ref),
+        {
+            Handler {
+                name: self.name,
+                id: self.id,
+                path: self.path,
+                done: self.done,
+                removed: Removed(f),
+            }
+        }
     }
 }
@@ -10,3 +10,10 @@
 
 pub mod wp_viewport;
 pub mod wp_viewporter;
+
+/// Metadata about the viewporter protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "viewporter",
+    interfaces: &[&wp_viewporter::INFO, &wp_viewport::INFO],
+};
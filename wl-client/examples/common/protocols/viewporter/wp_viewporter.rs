@@ -41,6 +41,57 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wp_viewporter interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wp_viewporter",
+    protocol: "viewporter",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_viewport",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wp_viewport"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "viewport_exists",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
 /// An owned wp_viewporter proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -95,11 +146,16 @@ impl Deref for WpViewporter {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -221,6 +277,47 @@ pub trait WpViewporterEventHandler {}
 
 impl WpViewporterEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WpViewporter] proxies with mutable data.
+///
+/// This trait is the same as [WpViewporterEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WpViewporterEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WpViewporter] proxies.
+///
+/// This trait is the same as [WpViewporterEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpViewporterEventHandlerMut {}
+
+/// A mutable event handler for [WpViewporter] proxies with mutable data.
+///
+/// This trait is the same as [WpViewporterEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WpViewporterEventHandlerMutWithData<T> {}
+
+impl<H> WpViewporterEventHandler for MutEventHandler<H> where H: WpViewporterEventHandlerMut {}
+
+impl<T, H> WpViewporterEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WpViewporterEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WpViewporterEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpViewporterEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WpViewporterEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WpViewporterEventHandlerMutWithData<U>
+{
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -241,6 +338,35 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WpViewporterEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wp_viewporter", opcode);
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WpViewporterEventHandler,
@@ -253,6 +379,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WpViewporterEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WpViewporter {
     /// Since when the error.viewport_exists enum variant is available.
     #[allow(dead_code)]
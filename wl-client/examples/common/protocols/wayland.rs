@@ -31,3 +31,34 @@ pub mod wl_subcompositor;
 pub mod wl_subsurface;
 pub mod wl_surface;
 pub mod wl_touch;
+
+/// Metadata about the wayland protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: ::wl_client::info::ProtocolInfo = ::wl_client::info::ProtocolInfo {
+    name: "wayland",
+    interfaces: &[
+        &wl_display::INFO,
+        &wl_registry::INFO,
+        &wl_callback::INFO,
+        &wl_compositor::INFO,
+        &wl_shm_pool::INFO,
+        &wl_shm::INFO,
+        &wl_buffer::INFO,
+        &wl_data_offer::INFO,
+        &wl_data_source::INFO,
+        &wl_data_device::INFO,
+        &wl_data_device_manager::INFO,
+        &wl_shell::INFO,
+        &wl_shell_surface::INFO,
+        &wl_surface::INFO,
+        &wl_seat::INFO,
+        &wl_pointer::INFO,
+        &wl_keyboard::INFO,
+        &wl_touch::INFO,
+        &wl_output::INFO,
+        &wl_region::INFO,
+        &wl_subcompositor::INFO,
+        &wl_subsurface::INFO,
+        &wl_fixes::INFO,
+    ],
+};
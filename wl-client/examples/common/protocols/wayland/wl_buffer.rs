@@ -47,6 +47,29 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_buffer interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_buffer",
+    protocol: "wayland",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "destroy",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[info::MessageInfo {
+        name: "release",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[],
+    }],
+    enums: &[],
+};
+
 /// An owned wl_buffer proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -101,11 +124,16 @@ impl Deref for WlBuffer {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -187,6 +215,97 @@ pub trait WlBufferEventHandler {
 
 impl WlBufferEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlBuffer] proxies with mutable data.
+///
+/// This trait is the same as [WlBufferEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlBufferEventHandlerWithData<T> {
+    /// compositor releases buffer
+    ///
+    /// Sent when this wl_buffer is no longer used by the compositor.
+    /// The client is now free to reuse or destroy this buffer and its
+    /// backing storage.
+    ///
+    /// If a client receives a release event before the frame callback
+    /// requested in the same wl_surface.commit that attaches this
+    /// wl_buffer to a surface, then the client is immediately free to
+    /// reuse the buffer and its backing storage, and does not need a
+    /// second buffer for the next surface content update. Typically
+    /// this is possible, when the compositor maintains a copy of the
+    /// wl_surface contents, e.g. as a GL texture. This is an important
+    /// optimization for GL(ES) compositors with wl_shm clients.
+    #[inline]
+    fn release(&self, _data: &mut T, _slf: &WlBufferRef) {}
+}
+
+/// A mutable event handler for [WlBuffer] proxies.
+///
+/// This trait is the same as [WlBufferEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlBufferEventHandlerMut {
+    /// See [WlBufferEventHandler::release].
+    #[inline]
+    fn release(&mut self, _slf: &WlBufferRef) {}
+}
+
+/// A mutable event handler for [WlBuffer] proxies with mutable data.
+///
+/// This trait is the same as [WlBufferEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlBufferEventHandlerMutWithData<T> {
+    /// See [WlBufferEventHandler::release].
+    #[inline]
+    fn release(&mut self, _data: &mut T, _slf: &WlBufferRef) {}
+}
+
+impl<H> WlBufferEventHandler for MutEventHandler<H>
+where
+    H: WlBufferEventHandlerMut,
+{
+    #[inline]
+    fn release(&self, _slf: &WlBufferRef) {
+        self.with(|h| h.release(_slf))
+    }
+}
+
+impl<T, H> WlBufferEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlBufferEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+        self.with(|h| h.release(_data, _slf))
+    }
+}
+
+impl<T, U, H> WlBufferEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlBufferEventHandlerWithData<U>,
+{
+    #[inline]
+    fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+        let _data = self.project(_data);
+        self.handler().release(_data, _slf)
+    }
+}
+
+impl<T, U, H> WlBufferEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlBufferEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn release(&mut self, _data: &mut T, _slf: &WlBufferRef) {
+        let _data = self.project(_data);
+        self.handler_mut().release(_data, _slf)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -216,6 +335,47 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlBufferEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlBufferRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                self.0.release(data, slf);
+            }
+            _ => {
+                invalid_opcode("wl_buffer", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlBufferEventHandler,
@@ -228,6 +388,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlBufferEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -243,6 +416,36 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> WlBufferEventHandlerWithData<T> for Release<F>
+    where
+        F: Fn(&mut T, &WlBufferRef),
+    {
+        #[inline]
+        fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for release events.
+    pub struct ReleaseMut<F>(F);
+    impl<F> WlBufferEventHandlerMut for ReleaseMut<F>
+    where
+        F: FnMut(&WlBufferRef),
+    {
+        #[inline]
+        fn release(&mut self, _slf: &WlBufferRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlBufferEventHandlerMutWithData<T> for ReleaseMut<F>
+    where
+        F: FnMut(&mut T, &WlBufferRef),
+    {
+        #[inline]
+        fn release(&mut self, _data: &mut T, _slf: &WlBufferRef) {
+            self.0(_data, _slf)
+        }
+    }
 
     impl WlBuffer {
         /// Creates an event handler for release events.
@@ -255,5 +458,111 @@ pub mod event_handlers {
         {
             Release(f)
         }
+
+        /// Creates an event handler with mutable data for release events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_release_with_data<T, F>(f: F) -> Release<F>
+        where
+            F: Fn(&mut T, &WlBufferRef),
+        {
+            Release(f)
+        }
+
+        /// Creates a mutable event handler for release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_release_mut<F>(f: F) -> ReleaseMut<F>
+        where
+            F: FnMut(&WlBufferRef),
+        {
+            ReleaseMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for release events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_release_mut_with_data<T, F>(f: F) -> ReleaseMut<F>
+        where
+            F: FnMut(&mut T, &WlBufferRef),
+        {
+            ReleaseMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { release: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlBufferEventHandler for Ignore {}
+    impl<T> WlBufferEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlBuffer::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        release: H0,
+    }
+
+    impl<H0> WlBufferEventHandler for Handler<H0>
+    where
+        H0: WlBufferEventHandler,
+    {
+        #[inline]
+        fn release(&self, _slf: &WlBufferRef) {
+            self.release.release(_slf)
+        }
+    }
+
+    impl<T, H0> WlBufferEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WlBufferEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn release(&self, _data: &mut T, _slf: &WlBufferRef) {
+            self.release.release(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for release events.
+        #[allow(dead_code)]
+        pub fn on_release<F>(self, f: F) -> Handler<Release<F>>
+        where
+            F: Fn(&WlBufferRef),
+        {
+            Handler {
+                release: Release(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for release events.
+        #[allow(dead_code)]
+        pub fn on_release_with_data<T, F>(self, f: F) -> Handler<Release<F>>
+        where
+            F: Fn(&mut T, &WlBufferRef),
+        {
+            Handler {
+                release: Release(f),
+            }
+        }
     }
 }
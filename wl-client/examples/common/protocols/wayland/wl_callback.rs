@@ -27,6 +27,29 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_callback interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_callback",
+    protocol: "wayland",
+    version: 1,
+    requests: &[],
+    events: &[info::MessageInfo {
+        name: "done",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[info::ArgInfo {
+            name: "callback_data",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[],
+};
+
 /// An owned wl_callback proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -81,11 +104,16 @@ impl Deref for WlCallback {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -138,6 +166,96 @@ pub trait WlCallbackEventHandler {
 
 impl WlCallbackEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlCallback] proxies with mutable data.
+///
+/// This trait is the same as [WlCallbackEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlCallbackEventHandlerWithData<T> {
+    /// done event
+    ///
+    /// Notify the client when the related request is done.
+    ///
+    /// # Arguments
+    ///
+    /// - `callback_data`: request-specific data for the callback
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+        let _ = callback_data;
+    }
+}
+
+/// A mutable event handler for [WlCallback] proxies.
+///
+/// This trait is the same as [WlCallbackEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlCallbackEventHandlerMut {
+    /// See [WlCallbackEventHandler::done].
+    #[inline]
+    fn done(&mut self, _slf: &WlCallbackRef, callback_data: u32) {
+        let _ = callback_data;
+    }
+}
+
+/// A mutable event handler for [WlCallback] proxies with mutable data.
+///
+/// This trait is the same as [WlCallbackEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlCallbackEventHandlerMutWithData<T> {
+    /// See [WlCallbackEventHandler::done].
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+        let _ = callback_data;
+    }
+}
+
+impl<H> WlCallbackEventHandler for MutEventHandler<H>
+where
+    H: WlCallbackEventHandlerMut,
+{
+    #[inline]
+    fn done(&self, _slf: &WlCallbackRef, callback_data: u32) {
+        self.with(|h| h.done(_slf, callback_data))
+    }
+}
+
+impl<T, H> WlCallbackEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlCallbackEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+        self.with(|h| h.done(_data, _slf, callback_data))
+    }
+}
+
+impl<T, U, H> WlCallbackEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlCallbackEventHandlerWithData<U>,
+{
+    #[inline]
+    fn done(&self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+        let _data = self.project(_data);
+        self.handler().done(_data, _slf, callback_data)
+    }
+}
+
+impl<T, U, H> WlCallbackEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlCallbackEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn done(&mut self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().done(_data, _slf, callback_data)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -171,6 +289,51 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlCallbackEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlCallbackRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.done(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_callback", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlCallbackEventHandler,
@@ -183,6 +346,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlCallbackEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -198,6 +374,36 @@ pub mod event_handlers {
             self.0(_slf, callback_data)
         }
     }
+    impl<T, F> WlCallbackEventHandlerWithData<T> for Done<F>
+    where
+        F: Fn(&mut T, &WlCallbackRef, u32),
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+            self.0(_data, _slf, callback_data)
+        }
+    }
+
+    /// Mutable event handler for done events.
+    pub struct DoneMut<F>(F);
+    impl<F> WlCallbackEventHandlerMut for DoneMut<F>
+    where
+        F: FnMut(&WlCallbackRef, u32),
+    {
+        #[inline]
+        fn done(&mut self, _slf: &WlCallbackRef, callback_data: u32) {
+            self.0(_slf, callback_data)
+        }
+    }
+    impl<T, F> WlCallbackEventHandlerMutWithData<T> for DoneMut<F>
+    where
+        F: FnMut(&mut T, &WlCallbackRef, u32),
+    {
+        #[inline]
+        fn done(&mut self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+            self.0(_data, _slf, callback_data)
+        }
+    }
 
     impl WlCallback {
         /// Creates an event handler for done events.
@@ -210,5 +416,107 @@ pub mod event_handlers {
         {
             Done(f)
         }
+
+        /// Creates an event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(f: F) -> Done<F>
+        where
+            F: Fn(&mut T, &WlCallbackRef, u32),
+        {
+            Done(f)
+        }
+
+        /// Creates a mutable event handler for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut<F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&WlCallbackRef, u32),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_done_mut_with_data<T, F>(f: F) -> DoneMut<F>
+        where
+            F: FnMut(&mut T, &WlCallbackRef, u32),
+        {
+            DoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { done: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlCallbackEventHandler for Ignore {}
+    impl<T> WlCallbackEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlCallback::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        done: H0,
+    }
+
+    impl<H0> WlCallbackEventHandler for Handler<H0>
+    where
+        H0: WlCallbackEventHandler,
+    {
+        #[inline]
+        fn done(&self, _slf: &WlCallbackRef, callback_data: u32) {
+            self.done.done(_slf, callback_data)
+        }
+    }
+
+    impl<T, H0> WlCallbackEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WlCallbackEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn done(&self, _data: &mut T, _slf: &WlCallbackRef, callback_data: u32) {
+            self.done.done(_data, _slf, callback_data)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for done events.
+        #[allow(dead_code)]
+        pub fn on_done<F>(self, f: F) -> Handler<Done<F>>
+        where
+            F: Fn(&WlCallbackRef, u32),
+        {
+            Handler { done: Done(f) }
+        }
+
+        /// Sets the closure that takes mutable data for done events.
+        #[allow(dead_code)]
+        pub fn on_done_with_data<T, F>(self, f: F) -> Handler<Done<F>>
+        where
+            F: Fn(&mut T, &WlCallbackRef, u32),
+        {
+            Handler { done: Done(f) }
+        }
     }
 }
@@ -44,6 +44,83 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_data_device_manager interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_data_device_manager",
+    protocol: "wayland",
+    version: 3,
+    requests: &[
+        info::MessageInfo {
+            name: "create_data_source",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_data_source"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_data_device",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_data_device"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "dnd_action",
+        since: 3,
+        bitfield: true,
+        entries: &[
+            info::EntryInfo {
+                name: "none",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "copy",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "move",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "ask",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wl_data_device_manager proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -98,11 +175,16 @@ impl Deref for WlDataDeviceManager {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -242,6 +324,50 @@ pub trait WlDataDeviceManagerEventHandler {}
 
 impl WlDataDeviceManagerEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlDataDeviceManager] proxies with mutable data.
+///
+/// This trait is the same as [WlDataDeviceManagerEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlDataDeviceManagerEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlDataDeviceManager] proxies.
+///
+/// This trait is the same as [WlDataDeviceManagerEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDataDeviceManagerEventHandlerMut {}
+
+/// A mutable event handler for [WlDataDeviceManager] proxies with mutable data.
+///
+/// This trait is the same as [WlDataDeviceManagerEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDataDeviceManagerEventHandlerMutWithData<T> {}
+
+impl<H> WlDataDeviceManagerEventHandler for MutEventHandler<H> where
+    H: WlDataDeviceManagerEventHandlerMut
+{
+}
+
+impl<T, H> WlDataDeviceManagerEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlDataDeviceManagerEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WlDataDeviceManagerEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlDataDeviceManagerEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WlDataDeviceManagerEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlDataDeviceManagerEventHandlerMutWithData<U>
+{
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -262,6 +388,35 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlDataDeviceManagerEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_data_device_manager", opcode);
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlDataDeviceManagerEventHandler,
@@ -274,6 +429,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlDataDeviceManagerEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlDataDeviceManager {
     /// Since when the dnd_action.none enum variant is available.
     #[allow(dead_code)]
@@ -90,6 +90,168 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_data_offer interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_data_offer",
+    protocol: "wayland",
+    version: 3,
+    requests: &[
+        info::MessageInfo {
+            name: "accept",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mime_type",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: true,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "receive",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "mime_type",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "finish",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_actions",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "dnd_actions",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("wl_data_device_manager.dnd_action"),
+                },
+                info::ArgInfo {
+                    name: "preferred_action",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("wl_data_device_manager.dnd_action"),
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "offer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "mime_type",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "source_actions",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "source_actions",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_data_device_manager.dnd_action"),
+            }],
+        },
+        info::MessageInfo {
+            name: "action",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "dnd_action",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_data_device_manager.dnd_action"),
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_finish",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_action_mask",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_action",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_offer",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wl_data_offer proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -144,11 +306,16 @@ impl Deref for WlDataOffer {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -465,6 +632,281 @@ pub trait WlDataOfferEventHandler {
 
 impl WlDataOfferEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlDataOffer] proxies with mutable data.
+///
+/// This trait is the same as [WlDataOfferEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlDataOfferEventHandlerWithData<T> {
+    /// advertise offered mime type
+    ///
+    /// Sent immediately after creating the wl_data_offer object.  One
+    /// event per offered mime type.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`: offered mime type
+    #[inline]
+    fn offer(&self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+        let _ = mime_type;
+    }
+
+    /// notify the source-side available actions
+    ///
+    /// This event indicates the actions offered by the data source. It
+    /// will be sent immediately after creating the wl_data_offer object,
+    /// or anytime the source side changes its offered actions through
+    /// wl_data_source.set_actions.
+    ///
+    /// # Arguments
+    ///
+    /// - `source_actions`: actions offered by the data source
+    #[inline]
+    fn source_actions(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        source_actions: WlDataDeviceManagerDndAction,
+    ) {
+        let _ = source_actions;
+    }
+
+    /// notify the selected action
+    ///
+    /// This event indicates the action selected by the compositor after
+    /// matching the source/destination side actions. Only one action (or
+    /// none) will be offered here.
+    ///
+    /// This event can be emitted multiple times during the drag-and-drop
+    /// operation in response to destination side action changes through
+    /// wl_data_offer.set_actions.
+    ///
+    /// This event will no longer be emitted after wl_data_device.drop
+    /// happened on the drag-and-drop destination, the client must
+    /// honor the last action received, or the last preferred one set
+    /// through wl_data_offer.set_actions when handling an "ask" action.
+    ///
+    /// Compositors may also change the selected action on the fly, mainly
+    /// in response to keyboard modifier changes during the drag-and-drop
+    /// operation.
+    ///
+    /// The most recent action received is always the valid one. Prior to
+    /// receiving wl_data_device.drop, the chosen action may change (e.g.
+    /// due to keyboard modifiers being pressed). At the time of receiving
+    /// wl_data_device.drop the drag-and-drop destination must honor the
+    /// last action received.
+    ///
+    /// Action changes may still happen after wl_data_device.drop,
+    /// especially on "ask" actions, where the drag-and-drop destination
+    /// may choose another action afterwards. Action changes happening
+    /// at this stage are always the result of inter-client negotiation, the
+    /// compositor shall no longer be able to induce a different action.
+    ///
+    /// Upon "ask" actions, it is expected that the drag-and-drop destination
+    /// may potentially choose a different action and/or mime type,
+    /// based on wl_data_offer.source_actions and finally chosen by the
+    /// user (e.g. popping up a menu with the available options). The
+    /// final wl_data_offer.set_actions and wl_data_offer.accept requests
+    /// must happen before the call to wl_data_offer.finish.
+    ///
+    /// # Arguments
+    ///
+    /// - `dnd_action`: action selected by the compositor
+    #[inline]
+    fn action(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _ = dnd_action;
+    }
+}
+
+/// A mutable event handler for [WlDataOffer] proxies.
+///
+/// This trait is the same as [WlDataOfferEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDataOfferEventHandlerMut {
+    /// See [WlDataOfferEventHandler::offer].
+    #[inline]
+    fn offer(&mut self, _slf: &WlDataOfferRef, mime_type: &str) {
+        let _ = mime_type;
+    }
+
+    /// See [WlDataOfferEventHandler::source_actions].
+    #[inline]
+    fn source_actions(
+        &mut self,
+        _slf: &WlDataOfferRef,
+        source_actions: WlDataDeviceManagerDndAction,
+    ) {
+        let _ = source_actions;
+    }
+
+    /// See [WlDataOfferEventHandler::action].
+    #[inline]
+    fn action(&mut self, _slf: &WlDataOfferRef, dnd_action: WlDataDeviceManagerDndAction) {
+        let _ = dnd_action;
+    }
+}
+
+/// A mutable event handler for [WlDataOffer] proxies with mutable data.
+///
+/// This trait is the same as [WlDataOfferEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDataOfferEventHandlerMutWithData<T> {
+    /// See [WlDataOfferEventHandler::offer].
+    #[inline]
+    fn offer(&mut self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+        let _ = mime_type;
+    }
+
+    /// See [WlDataOfferEventHandler::source_actions].
+    #[inline]
+    fn source_actions(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        source_actions: WlDataDeviceManagerDndAction,
+    ) {
+        let _ = source_actions;
+    }
+
+    /// See [WlDataOfferEventHandler::action].
+    #[inline]
+    fn action(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _ = dnd_action;
+    }
+}
+
+impl<H> WlDataOfferEventHandler for MutEventHandler<H>
+where
+    H: WlDataOfferEventHandlerMut,
+{
+    #[inline]
+    fn offer(&self, _slf: &WlDataOfferRef, mime_type: &str) {
+        self.with(|h| h.offer(_slf, mime_type))
+    }
+
+    #[inline]
+    fn source_actions(&self, _slf: &WlDataOfferRef, source_actions: WlDataDeviceManagerDndAction) {
+        self.with(|h| h.source_actions(_slf, source_actions))
+    }
+
+    #[inline]
+    fn action(&self, _slf: &WlDataOfferRef, dnd_action: WlDataDeviceManagerDndAction) {
+        self.with(|h| h.action(_slf, dnd_action))
+    }
+}
+
+impl<T, H> WlDataOfferEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlDataOfferEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn offer(&self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+        self.with(|h| h.offer(_data, _slf, mime_type))
+    }
+
+    #[inline]
+    fn source_actions(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        source_actions: WlDataDeviceManagerDndAction,
+    ) {
+        self.with(|h| h.source_actions(_data, _slf, source_actions))
+    }
+
+    #[inline]
+    fn action(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        self.with(|h| h.action(_data, _slf, dnd_action))
+    }
+}
+
+impl<T, U, H> WlDataOfferEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlDataOfferEventHandlerWithData<U>,
+{
+    #[inline]
+    fn offer(&self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+        let _data = self.project(_data);
+        self.handler().offer(_data, _slf, mime_type)
+    }
+
+    #[inline]
+    fn source_actions(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        source_actions: WlDataDeviceManagerDndAction,
+    ) {
+        let _data = self.project(_data);
+        self.handler().source_actions(_data, _slf, source_actions)
+    }
+
+    #[inline]
+    fn action(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _data = self.project(_data);
+        self.handler().action(_data, _slf, dnd_action)
+    }
+}
+
+impl<T, U, H> WlDataOfferEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlDataOfferEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn offer(&mut self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().offer(_data, _slf, mime_type)
+    }
+
+    #[inline]
+    fn source_actions(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        source_actions: WlDataDeviceManagerDndAction,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .source_actions(_data, _slf, source_actions)
+    }
+
+    #[inline]
+    fn action(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDataOfferRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().action(_data, _slf, dnd_action)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -513,6 +955,66 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlDataOfferEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlDataOfferRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("wl_data_offer", "mime_type", args[0].s) };
+                self.0.offer(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlDataDeviceManagerDndAction(args[0].u) };
+                self.0.source_actions(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlDataDeviceManagerDndAction(args[0].u) };
+                self.0.action(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_data_offer", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlDataOfferEventHandler,
@@ -525,6 +1027,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlDataOfferEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlDataOffer {
     /// Since when the error.invalid_finish enum variant is available.
     #[allow(dead_code)]
@@ -590,6 +1105,15 @@ pub mod event_handlers {
             self.0(_slf, mime_type)
         }
     }
+    impl<T, F> WlDataOfferEventHandlerWithData<T> for Offer<F>
+    where
+        F: Fn(&mut T, &WlDataOfferRef, &str),
+    {
+        #[inline]
+        fn offer(&self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+            self.0(_data, _slf, mime_type)
+        }
+    }
 
     /// Event handler for source_actions events.
     pub struct SourceActions<F>(F);
@@ -606,6 +1130,20 @@ pub mod event_handlers {
             self.0(_slf, source_actions)
         }
     }
+    impl<T, F> WlDataOfferEventHandlerWithData<T> for SourceActions<F>
+    where
+        F: Fn(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn source_actions(
+            &self,
+            _data: &mut T,
+            _slf: &WlDataOfferRef,
+            source_actions: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_data, _slf, source_actions)
+        }
+    }
 
     /// Event handler for action events.
     pub struct Action<F>(F);
@@ -618,6 +1156,97 @@ pub mod event_handlers {
             self.0(_slf, dnd_action)
         }
     }
+    impl<T, F> WlDataOfferEventHandlerWithData<T> for Action<F>
+    where
+        F: Fn(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn action(
+            &self,
+            _data: &mut T,
+            _slf: &WlDataOfferRef,
+            dnd_action: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_data, _slf, dnd_action)
+        }
+    }
+
+    /// Mutable event handler for offer events.
+    pub struct OfferMut<F>(F);
+    impl<F> WlDataOfferEventHandlerMut for OfferMut<F>
+    where
+        F: FnMut(&WlDataOfferRef, &str),
+    {
+        #[inline]
+        fn offer(&mut self, _slf: &WlDataOfferRef, mime_type: &str) {
+            self.0(_slf, mime_type)
+        }
+    }
+    impl<T, F> WlDataOfferEventHandlerMutWithData<T> for OfferMut<F>
+    where
+        F: FnMut(&mut T, &WlDataOfferRef, &str),
+    {
+        #[inline]
+        fn offer(&mut self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+            self.0(_data, _slf, mime_type)
+        }
+    }
+
+    /// Mutable event handler for source_actions events.
+    pub struct SourceActionsMut<F>(F);
+    impl<F> WlDataOfferEventHandlerMut for SourceActionsMut<F>
+    where
+        F: FnMut(&WlDataOfferRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn source_actions(
+            &mut self,
+            _slf: &WlDataOfferRef,
+            source_actions: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_slf, source_actions)
+        }
+    }
+    impl<T, F> WlDataOfferEventHandlerMutWithData<T> for SourceActionsMut<F>
+    where
+        F: FnMut(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn source_actions(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlDataOfferRef,
+            source_actions: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_data, _slf, source_actions)
+        }
+    }
+
+    /// Mutable event handler for action events.
+    pub struct ActionMut<F>(F);
+    impl<F> WlDataOfferEventHandlerMut for ActionMut<F>
+    where
+        F: FnMut(&WlDataOfferRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn action(&mut self, _slf: &WlDataOfferRef, dnd_action: WlDataDeviceManagerDndAction) {
+            self.0(_slf, dnd_action)
+        }
+    }
+    impl<T, F> WlDataOfferEventHandlerMutWithData<T> for ActionMut<F>
+    where
+        F: FnMut(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn action(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlDataOfferRef,
+            dnd_action: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_data, _slf, dnd_action)
+        }
+    }
 
     impl WlDataOffer {
         /// Creates an event handler for offer events.
@@ -631,6 +1260,17 @@ pub mod event_handlers {
             Offer(f)
         }
 
+        /// Creates an event handler with mutable data for offer events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_offer_with_data<T, F>(f: F) -> Offer<F>
+        where
+            F: Fn(&mut T, &WlDataOfferRef, &str),
+        {
+            Offer(f)
+        }
+
         /// Creates an event handler for source_actions events.
         ///
         /// The event handler ignores all other events.
@@ -642,6 +1282,17 @@ pub mod event_handlers {
             SourceActions(f)
         }
 
+        /// Creates an event handler with mutable data for source_actions events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_source_actions_with_data<T, F>(f: F) -> SourceActions<F>
+        where
+            F: Fn(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            SourceActions(f)
+        }
+
         /// Creates an event handler for action events.
         ///
         /// The event handler ignores all other events.
@@ -652,5 +1303,260 @@ pub mod event_handlers {
         {
             Action(f)
         }
+
+        /// Creates an event handler with mutable data for action events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_action_with_data<T, F>(f: F) -> Action<F>
+        where
+            F: Fn(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            Action(f)
+        }
+
+        /// Creates a mutable event handler for offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_offer_mut<F>(f: F) -> OfferMut<F>
+        where
+            F: FnMut(&WlDataOfferRef, &str),
+        {
+            OfferMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for offer events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_offer_mut_with_data<T, F>(f: F) -> OfferMut<F>
+        where
+            F: FnMut(&mut T, &WlDataOfferRef, &str),
+        {
+            OfferMut(f)
+        }
+
+        /// Creates a mutable event handler for source_actions events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_source_actions_mut<F>(f: F) -> SourceActionsMut<F>
+        where
+            F: FnMut(&WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            SourceActionsMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for source_actions events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_source_actions_mut_with_data<T, F>(f: F) -> SourceActionsMut<F>
+        where
+            F: FnMut(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            SourceActionsMut(f)
+        }
+
+        /// Creates a mutable event handler for action events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_action_mut<F>(f: F) -> ActionMut<F>
+        where
+            F: FnMut(&WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            ActionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for action events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_action_mut_with_data<T, F>(f: F) -> ActionMut<F>
+        where
+            F: FnMut(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            ActionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                offer: Ignore,
+                source_actions: Ignore,
+                action: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlDataOfferEventHandler for Ignore {}
+    impl<T> WlDataOfferEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlDataOffer::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        offer: H0,
+        source_actions: H1,
+        action: H2,
+    }
+
+    impl<H0, H1, H2> WlDataOfferEventHandler for Handler<H0, H1, H2>
+    where
+        H0: WlDataOfferEventHandler,
+        H1: WlDataOfferEventHandler,
+        H2: WlDataOfferEventHandler,
+    {
+        #[inline]
+        fn offer(&self, _slf: &WlDataOfferRef, mime_type: &str) {
+            self.offer.offer(_slf, mime_type)
+        }
+
+        #[inline]
+        fn source_actions(
+            &self,
+            _slf: &WlDataOfferRef,
+            source_actions: WlDataDeviceManagerDndAction,
+        ) {
+            self.source_actions.source_actions(_slf, source_actions)
+        }
+
+        #[inline]
+        fn action(&self, _slf: &WlDataOfferRef, dnd_action: WlDataDeviceManagerDndAction) {
+            self.action.action(_slf, dnd_action)
+        }
+    }
+
+    impl<T, H0, H1, H2> WlDataOfferEventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: WlDataOfferEventHandlerWithData<T>,
+        H1: WlDataOfferEventHandlerWithData<T>,
+        H2: WlDataOfferEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn offer(&self, _data: &mut T, _slf: &WlDataOfferRef, mime_type: &str) {
+            self.offer.offer(_data, _slf, mime_type)
+        }
+
+        #[inline]
+        fn source_actions(
+            &self,
+            _data: &mut T,
+            _slf: &WlDataOfferRef,
+            source_actions: WlDataDeviceManagerDndAction,
+        ) {
+            self.source_actions
+                .source_actions(_data, _slf, source_actions)
+        }
+
+        #[inline]
+        fn action(
+            &self,
+            _data: &mut T,
+            _slf: &WlDataOfferRef,
+            dnd_action: WlDataDeviceManagerDndAction,
+        ) {
+            self.action.action(_data, _slf, dnd_action)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for offer events.
+        #[allow(dead_code)]
+        pub fn on_offer<F>(self, f: F) -> Handler<Offer<F>, H1, H2>
+        where
+            F: Fn(&WlDataOfferRef, &str),
+        {
+            Handler {
+                offer: Offer(f),
+                source_actions: self.source_actions,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for offer events.
+        #[allow(dead_code)]
+        pub fn on_offer_with_data<T, F>(self, f: F) -> Handler<Offer<F>, H1, H2>
+        where
+            F: Fn(&mut T, &WlDataOfferRef, &str),
+        {
+            Handler {
+                offer: Offer(f),
+                source_actions: self.source_actions,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for source_actions events.
+        #[allow(dead_code)]
+        pub fn on_source_actions<F>(self, f: F) -> Handler<H0, SourceActions<F>, H2>
+        where
+            F: Fn(&WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            Handler {
+                offer: self.offer,
+                source_actions: SourceActions(f),
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for source_actions events.
+        #[allow(dead_code)]
+        pub fn on_source_actions_with_data<T, F>(self, f: F) -> Handler<H0, SourceActions<F>, H2>
+        where
+            F: Fn(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            Handler {
+                offer: self.offer,
+                source_actions: SourceActions(f),
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for action events.
+        #[allow(dead_code)]
+        pub fn on_action<F>(self, f: F) -> Handler<H0, H1, Action<F>>
+        where
+            F: Fn(&WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            Handler {
+                offer: self.offer,
+                source_actions: self.source_actions,
+                action: Action(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for action events.
+        #[allow(dead_code)]
+        pub fn on_action_with_data<T, F>(self, f: F) -> Handler<H0, H1, Action<F>>
+        where
+            F: Fn(&mut T, &WlDataOfferRef, WlDataDeviceManagerDndAction),
+        {
+            Handler {
+                offer: self.offer,
+                source_actions: self.source_actions,
+                action: Action(f),
+            }
+        }
     }
 }
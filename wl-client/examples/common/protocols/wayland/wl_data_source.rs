@@ -96,6 +96,139 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_data_source interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_data_source",
+    protocol: "wayland",
+    version: 3,
+    requests: &[
+        info::MessageInfo {
+            name: "offer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "mime_type",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_actions",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "dnd_actions",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_data_device_manager.dnd_action"),
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "target",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "mime_type",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "send",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "mime_type",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "fd",
+                    ty: info::ArgType::Fd,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "cancelled",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "dnd_drop_performed",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "dnd_finished",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "action",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "dnd_action",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_data_device_manager.dnd_action"),
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_action_mask",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_source",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wl_data_source proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -150,11 +283,16 @@ impl Deref for WlDataSource {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -414,140 +552,625 @@ pub trait WlDataSourceEventHandler {
 
 impl WlDataSourceEventHandler for private::NoOpEventHandler {}
 
-// SAFETY: - INTERFACE is a valid wl_interface
-unsafe impl<H> EventHandler for private::EventHandler<H>
-where
-    H: WlDataSourceEventHandler,
-{
-    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+/// An event handler for [WlDataSource] proxies with mutable data.
+///
+/// This trait is the same as [WlDataSourceEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlDataSourceEventHandlerWithData<T> {
+    /// a target accepts an offered mime type
+    ///
+    /// Sent when a target accepts pointer_focus or motion events.  If
+    /// a target does not accept any of the offered types, type is NULL.
+    ///
+    /// Used for feedback during drag-and-drop.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`: mime type accepted by the target
+    #[inline]
+    fn target(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        let _ = mime_type;
+    }
 
-    #[allow(unused_variables)]
-    unsafe fn handle_event(
+    /// send the data
+    ///
+    /// Request for data from the client.  Send the data as the
+    /// specified mime type over the passed file descriptor, then
+    /// close it.
+    ///
+    /// # Arguments
+    ///
+    /// - `mime_type`: mime type for the data
+    /// - `fd`: file descriptor for the data
+    #[inline]
+    fn send(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// selection was cancelled
+    ///
+    /// This data source is no longer valid. There are several reasons why
+    /// this could happen:
+    ///
+    /// - The data source has been replaced by another data source.
+    /// - The drag-and-drop operation was performed, but the drop destination
+    ///   did not accept any of the mime types offered through
+    ///   wl_data_source.target.
+    /// - The drag-and-drop operation was performed, but the drop destination
+    ///   did not select any of the actions present in the mask offered through
+    ///   wl_data_source.action.
+    /// - The drag-and-drop operation was performed but didn't happen over a
+    ///   surface.
+    /// - The compositor cancelled the drag-and-drop operation (e.g. compositor
+    ///   dependent timeouts to avoid stale drag-and-drop transfers).
+    ///
+    /// The client should clean up and destroy this data source.
+    ///
+    /// For objects of version 2 or older, wl_data_source.cancelled will
+    /// only be emitted if the data source was replaced by another data
+    /// source.
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &WlDataSourceRef) {}
+
+    /// the drag-and-drop operation physically finished
+    ///
+    /// The user performed the drop action. This event does not indicate
+    /// acceptance, wl_data_source.cancelled may still be emitted afterwards
+    /// if the drop destination does not accept any mime type.
+    ///
+    /// However, this event might however not be received if the compositor
+    /// cancelled the drag-and-drop operation before this event could happen.
+    ///
+    /// Note that the data_source may still be used in the future and should
+    /// not be destroyed here.
+    #[inline]
+    fn dnd_drop_performed(&self, _data: &mut T, _slf: &WlDataSourceRef) {}
+
+    /// the drag-and-drop operation concluded
+    ///
+    /// The drop destination finished interoperating with this data
+    /// source, so the client is now free to destroy this data source and
+    /// free all associated data.
+    ///
+    /// If the action used to perform the operation was "move", the
+    /// source can now delete the transferred data.
+    #[inline]
+    fn dnd_finished(&self, _data: &mut T, _slf: &WlDataSourceRef) {}
+
+    /// notify the selected action
+    ///
+    /// This event indicates the action selected by the compositor after
+    /// matching the source/destination side actions. Only one action (or
+    /// none) will be offered here.
+    ///
+    /// This event can be emitted multiple times during the drag-and-drop
+    /// operation, mainly in response to destination side changes through
+    /// wl_data_offer.set_actions, and as the data device enters/leaves
+    /// surfaces.
+    ///
+    /// It is only possible to receive this event after
+    /// wl_data_source.dnd_drop_performed if the drag-and-drop operation
+    /// ended in an "ask" action, in which case the final wl_data_source.action
+    /// event will happen immediately before wl_data_source.dnd_finished.
+    ///
+    /// Compositors may also change the selected action on the fly, mainly
+    /// in response to keyboard modifier changes during the drag-and-drop
+    /// operation.
+    ///
+    /// The most recent action received is always the valid one. The chosen
+    /// action may change alongside negotiation (e.g. an "ask" action can turn
+    /// into a "move" operation), so the effects of the final action must
+    /// always be applied in wl_data_offer.dnd_finished.
+    ///
+    /// Clients can trigger cursor surface changes from this point, so
+    /// they reflect the current action.
+    ///
+    /// # Arguments
+    ///
+    /// - `dnd_action`: action selected by the compositor
+    #[inline]
+    fn action(
         &self,
-        queue: &Queue,
-        data: *mut u8,
-        slf: &UntypedBorrowedProxy,
-        opcode: u32,
-        args: *mut wl_argument,
+        _data: &mut T,
+        _slf: &WlDataSourceRef,
+        dnd_action: WlDataDeviceManagerDndAction,
     ) {
-        // SAFETY: This function requires that slf has the interface INTERFACE
-        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlDataSourceRef>(slf) };
-        match opcode {
-            0 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a string
-                //         - if the pointer is not null, then it is a c string
-                let arg0 = unsafe {
-                    convert_optional_string_arg("wl_data_source", "mime_type", args[0].s)
-                };
-                self.0.target(slf, arg0);
-            }
-            1 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a string
-                //         - if the pointer is not null, then it is a c string
-                let arg0 = unsafe { convert_string_arg("wl_data_source", "mime_type", args[0].s) };
-                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
-                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
-                self.0.send(slf, arg0, arg1);
-            }
-            2 => {
-                self.0.cancelled(slf);
-            }
-            3 => {
-                self.0.dnd_drop_performed(slf);
-            }
-            4 => {
-                self.0.dnd_finished(slf);
-            }
-            5 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { WlDataDeviceManagerDndAction(args[0].u) };
-                self.0.action(slf, arg0);
-            }
-            _ => {
-                invalid_opcode("wl_data_source", opcode);
-            }
-        }
+        let _ = dnd_action;
     }
 }
 
-impl<H> CreateEventHandler<H> for private::ProxyApi
-where
-    H: WlDataSourceEventHandler,
-{
-    type EventHandler = private::EventHandler<H>;
-
+/// A mutable event handler for [WlDataSource] proxies.
+///
+/// This trait is the same as [WlDataSourceEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDataSourceEventHandlerMut {
+    /// See [WlDataSourceEventHandler::target].
     #[inline]
-    fn create_event_handler(handler: H) -> Self::EventHandler {
-        private::EventHandler(handler)
+    fn target(&mut self, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        let _ = mime_type;
     }
-}
 
-impl WlDataSource {
-    /// Since when the error.invalid_action_mask enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_INVALID_ACTION_MASK__SINCE: u32 = 1;
-    /// Since when the error.invalid_source enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_INVALID_SOURCE__SINCE: u32 = 1;
-}
+    /// See [WlDataSourceEventHandler::send].
+    #[inline]
+    fn send(&mut self, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlDataSourceError(pub u32);
+    /// See [WlDataSourceEventHandler::cancelled].
+    #[inline]
+    fn cancelled(&mut self, _slf: &WlDataSourceRef) {}
 
-impl WlDataSourceError {
-    /// action mask contains invalid values
-    #[allow(dead_code)]
-    pub const INVALID_ACTION_MASK: Self = Self(0);
+    /// See [WlDataSourceEventHandler::dnd_drop_performed].
+    #[inline]
+    fn dnd_drop_performed(&mut self, _slf: &WlDataSourceRef) {}
 
-    /// source doesn't accept this request
-    #[allow(dead_code)]
-    pub const INVALID_SOURCE: Self = Self(1);
-}
+    /// See [WlDataSourceEventHandler::dnd_finished].
+    #[inline]
+    fn dnd_finished(&mut self, _slf: &WlDataSourceRef) {}
 
-impl Debug for WlDataSourceError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::INVALID_ACTION_MASK => "INVALID_ACTION_MASK",
-            Self::INVALID_SOURCE => "INVALID_SOURCE",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
+    /// See [WlDataSourceEventHandler::action].
+    #[inline]
+    fn action(&mut self, _slf: &WlDataSourceRef, dnd_action: WlDataDeviceManagerDndAction) {
+        let _ = dnd_action;
     }
 }
 
-/// Functional event handlers.
-pub mod event_handlers {
-    use super::*;
-
-    /// Event handler for target events.
-    pub struct Target<F>(F);
-    impl<F> WlDataSourceEventHandler for Target<F>
-    where
-        F: Fn(&WlDataSourceRef, Option<&str>),
-    {
-        #[inline]
-        fn target(&self, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
-            self.0(_slf, mime_type)
-        }
+/// A mutable event handler for [WlDataSource] proxies with mutable data.
+///
+/// This trait is the same as [WlDataSourceEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDataSourceEventHandlerMutWithData<T> {
+    /// See [WlDataSourceEventHandler::target].
+    #[inline]
+    fn target(&mut self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        let _ = mime_type;
     }
 
-    /// Event handler for send events.
-    pub struct Send<F>(F);
-    impl<F> WlDataSourceEventHandler for Send<F>
-    where
-        F: Fn(&WlDataSourceRef, &str, OwnedFd),
-    {
-        #[inline]
-        fn send(&self, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
-            self.0(_slf, mime_type, fd)
-        }
+    /// See [WlDataSourceEventHandler::send].
+    #[inline]
+    fn send(&mut self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        let _ = mime_type;
+        let _ = fd;
+    }
+
+    /// See [WlDataSourceEventHandler::cancelled].
+    #[inline]
+    fn cancelled(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {}
+
+    /// See [WlDataSourceEventHandler::dnd_drop_performed].
+    #[inline]
+    fn dnd_drop_performed(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {}
+
+    /// See [WlDataSourceEventHandler::dnd_finished].
+    #[inline]
+    fn dnd_finished(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {}
+
+    /// See [WlDataSourceEventHandler::action].
+    #[inline]
+    fn action(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDataSourceRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _ = dnd_action;
+    }
+}
+
+impl<H> WlDataSourceEventHandler for MutEventHandler<H>
+where
+    H: WlDataSourceEventHandlerMut,
+{
+    #[inline]
+    fn target(&self, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        self.with(|h| h.target(_slf, mime_type))
+    }
+
+    #[inline]
+    fn send(&self, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        self.with(|h| h.send(_slf, mime_type, fd))
+    }
+
+    #[inline]
+    fn cancelled(&self, _slf: &WlDataSourceRef) {
+        self.with(|h| h.cancelled(_slf))
+    }
+
+    #[inline]
+    fn dnd_drop_performed(&self, _slf: &WlDataSourceRef) {
+        self.with(|h| h.dnd_drop_performed(_slf))
+    }
+
+    #[inline]
+    fn dnd_finished(&self, _slf: &WlDataSourceRef) {
+        self.with(|h| h.dnd_finished(_slf))
+    }
+
+    #[inline]
+    fn action(&self, _slf: &WlDataSourceRef, dnd_action: WlDataDeviceManagerDndAction) {
+        self.with(|h| h.action(_slf, dnd_action))
+    }
+}
+
+impl<T, H> WlDataSourceEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlDataSourceEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn target(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        self.with(|h| h.target(_data, _slf, mime_type))
+    }
+
+    #[inline]
+    fn send(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        self.with(|h| h.send(_data, _slf, mime_type, fd))
+    }
+
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+        self.with(|h| h.cancelled(_data, _slf))
+    }
+
+    #[inline]
+    fn dnd_drop_performed(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+        self.with(|h| h.dnd_drop_performed(_data, _slf))
+    }
+
+    #[inline]
+    fn dnd_finished(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+        self.with(|h| h.dnd_finished(_data, _slf))
+    }
+
+    #[inline]
+    fn action(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataSourceRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        self.with(|h| h.action(_data, _slf, dnd_action))
+    }
+}
+
+impl<T, U, H> WlDataSourceEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlDataSourceEventHandlerWithData<U>,
+{
+    #[inline]
+    fn target(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        let _data = self.project(_data);
+        self.handler().target(_data, _slf, mime_type)
+    }
+
+    #[inline]
+    fn send(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler().send(_data, _slf, mime_type, fd)
+    }
+
+    #[inline]
+    fn cancelled(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+        let _data = self.project(_data);
+        self.handler().cancelled(_data, _slf)
+    }
+
+    #[inline]
+    fn dnd_drop_performed(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+        let _data = self.project(_data);
+        self.handler().dnd_drop_performed(_data, _slf)
+    }
+
+    #[inline]
+    fn dnd_finished(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+        let _data = self.project(_data);
+        self.handler().dnd_finished(_data, _slf)
+    }
+
+    #[inline]
+    fn action(
+        &self,
+        _data: &mut T,
+        _slf: &WlDataSourceRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _data = self.project(_data);
+        self.handler().action(_data, _slf, dnd_action)
+    }
+}
+
+impl<T, U, H> WlDataSourceEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlDataSourceEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn target(&mut self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+        let _data = self.project(_data);
+        self.handler_mut().target(_data, _slf, mime_type)
+    }
+
+    #[inline]
+    fn send(&mut self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+        let _data = self.project(_data);
+        self.handler_mut().send(_data, _slf, mime_type, fd)
+    }
+
+    #[inline]
+    fn cancelled(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {
+        let _data = self.project(_data);
+        self.handler_mut().cancelled(_data, _slf)
+    }
+
+    #[inline]
+    fn dnd_drop_performed(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {
+        let _data = self.project(_data);
+        self.handler_mut().dnd_drop_performed(_data, _slf)
+    }
+
+    #[inline]
+    fn dnd_finished(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {
+        let _data = self.project(_data);
+        self.handler_mut().dnd_finished(_data, _slf)
+    }
+
+    #[inline]
+    fn action(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDataSourceRef,
+        dnd_action: WlDataDeviceManagerDndAction,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().action(_data, _slf, dnd_action)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WlDataSourceEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlDataSourceRef>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_optional_string_arg("wl_data_source", "mime_type", args[0].s)
+                };
+                self.0.target(slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("wl_data_source", "mime_type", args[0].s) };
+                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
+                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
+                self.0.send(slf, arg0, arg1);
+            }
+            2 => {
+                self.0.cancelled(slf);
+            }
+            3 => {
+                self.0.dnd_drop_performed(slf);
+            }
+            4 => {
+                self.0.dnd_finished(slf);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlDataDeviceManagerDndAction(args[0].u) };
+                self.0.action(slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_data_source", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlDataSourceEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlDataSourceRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe {
+                    convert_optional_string_arg("wl_data_source", "mime_type", args[0].s)
+                };
+                self.0.target(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("wl_data_source", "mime_type", args[0].s) };
+                // SAFETY: - INTERFACE requires that args[1] contains a file descriptor
+                let arg1 = unsafe { OwnedFd::from_raw_fd(args[1].h) };
+                self.0.send(data, slf, arg0, arg1);
+            }
+            2 => {
+                self.0.cancelled(data, slf);
+            }
+            3 => {
+                self.0.dnd_drop_performed(data, slf);
+            }
+            4 => {
+                self.0.dnd_finished(data, slf);
+            }
+            5 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlDataDeviceManagerDndAction(args[0].u) };
+                self.0.action(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_data_source", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WlDataSourceEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlDataSourceEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WlDataSource {
+    /// Since when the error.invalid_action_mask enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_ACTION_MASK__SINCE: u32 = 1;
+    /// Since when the error.invalid_source enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SOURCE__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlDataSourceError(pub u32);
+
+impl WlDataSourceError {
+    /// action mask contains invalid values
+    #[allow(dead_code)]
+    pub const INVALID_ACTION_MASK: Self = Self(0);
+
+    /// source doesn't accept this request
+    #[allow(dead_code)]
+    pub const INVALID_SOURCE: Self = Self(1);
+}
+
+impl Debug for WlDataSourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::INVALID_ACTION_MASK => "INVALID_ACTION_MASK",
+            Self::INVALID_SOURCE => "INVALID_SOURCE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for target events.
+    pub struct Target<F>(F);
+    impl<F> WlDataSourceEventHandler for Target<F>
+    where
+        F: Fn(&WlDataSourceRef, Option<&str>),
+    {
+        #[inline]
+        fn target(&self, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+            self.0(_slf, mime_type)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerWithData<T> for Target<F>
+    where
+        F: Fn(&mut T, &WlDataSourceRef, Option<&str>),
+    {
+        #[inline]
+        fn target(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+            self.0(_data, _slf, mime_type)
+        }
+    }
+
+    /// Event handler for send events.
+    pub struct Send<F>(F);
+    impl<F> WlDataSourceEventHandler for Send<F>
+    where
+        F: Fn(&WlDataSourceRef, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&self, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+            self.0(_slf, mime_type, fd)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerWithData<T> for Send<F>
+    where
+        F: Fn(&mut T, &WlDataSourceRef, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+            self.0(_data, _slf, mime_type, fd)
+        }
     }
 
     /// Event handler for cancelled events.
@@ -561,6 +1184,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> WlDataSourceEventHandlerWithData<T> for Cancelled<F>
+    where
+        F: Fn(&mut T, &WlDataSourceRef),
+    {
+        #[inline]
+        fn cancelled(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for dnd_drop_performed events.
     pub struct DndDropPerformed<F>(F);
@@ -573,6 +1205,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> WlDataSourceEventHandlerWithData<T> for DndDropPerformed<F>
+    where
+        F: Fn(&mut T, &WlDataSourceRef),
+    {
+        #[inline]
+        fn dnd_drop_performed(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for dnd_finished events.
     pub struct DndFinished<F>(F);
@@ -585,6 +1226,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> WlDataSourceEventHandlerWithData<T> for DndFinished<F>
+    where
+        F: Fn(&mut T, &WlDataSourceRef),
+    {
+        #[inline]
+        fn dnd_finished(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for action events.
     pub struct Action<F>(F);
@@ -596,16 +1246,172 @@ pub mod event_handlers {
         fn action(&self, _slf: &WlDataSourceRef, dnd_action: WlDataDeviceManagerDndAction) {
             self.0(_slf, dnd_action)
         }
-    }
+    }
+    impl<T, F> WlDataSourceEventHandlerWithData<T> for Action<F>
+    where
+        F: Fn(&mut T, &WlDataSourceRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn action(
+            &self,
+            _data: &mut T,
+            _slf: &WlDataSourceRef,
+            dnd_action: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_data, _slf, dnd_action)
+        }
+    }
+
+    /// Mutable event handler for target events.
+    pub struct TargetMut<F>(F);
+    impl<F> WlDataSourceEventHandlerMut for TargetMut<F>
+    where
+        F: FnMut(&WlDataSourceRef, Option<&str>),
+    {
+        #[inline]
+        fn target(&mut self, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+            self.0(_slf, mime_type)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerMutWithData<T> for TargetMut<F>
+    where
+        F: FnMut(&mut T, &WlDataSourceRef, Option<&str>),
+    {
+        #[inline]
+        fn target(&mut self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+            self.0(_data, _slf, mime_type)
+        }
+    }
+
+    /// Mutable event handler for send events.
+    pub struct SendMut<F>(F);
+    impl<F> WlDataSourceEventHandlerMut for SendMut<F>
+    where
+        F: FnMut(&WlDataSourceRef, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&mut self, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+            self.0(_slf, mime_type, fd)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerMutWithData<T> for SendMut<F>
+    where
+        F: FnMut(&mut T, &WlDataSourceRef, &str, OwnedFd),
+    {
+        #[inline]
+        fn send(&mut self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+            self.0(_data, _slf, mime_type, fd)
+        }
+    }
+
+    /// Mutable event handler for cancelled events.
+    pub struct CancelledMut<F>(F);
+    impl<F> WlDataSourceEventHandlerMut for CancelledMut<F>
+    where
+        F: FnMut(&WlDataSourceRef),
+    {
+        #[inline]
+        fn cancelled(&mut self, _slf: &WlDataSourceRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerMutWithData<T> for CancelledMut<F>
+    where
+        F: FnMut(&mut T, &WlDataSourceRef),
+    {
+        #[inline]
+        fn cancelled(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for dnd_drop_performed events.
+    pub struct DndDropPerformedMut<F>(F);
+    impl<F> WlDataSourceEventHandlerMut for DndDropPerformedMut<F>
+    where
+        F: FnMut(&WlDataSourceRef),
+    {
+        #[inline]
+        fn dnd_drop_performed(&mut self, _slf: &WlDataSourceRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerMutWithData<T> for DndDropPerformedMut<F>
+    where
+        F: FnMut(&mut T, &WlDataSourceRef),
+    {
+        #[inline]
+        fn dnd_drop_performed(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for dnd_finished events.
+    pub struct DndFinishedMut<F>(F);
+    impl<F> WlDataSourceEventHandlerMut for DndFinishedMut<F>
+    where
+        F: FnMut(&WlDataSourceRef),
+    {
+        #[inline]
+        fn dnd_finished(&mut self, _slf: &WlDataSourceRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerMutWithData<T> for DndFinishedMut<F>
+    where
+        F: FnMut(&mut T, &WlDataSourceRef),
+    {
+        #[inline]
+        fn dnd_finished(&mut self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for action events.
+    pub struct ActionMut<F>(F);
+    impl<F> WlDataSourceEventHandlerMut for ActionMut<F>
+    where
+        F: FnMut(&WlDataSourceRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn action(&mut self, _slf: &WlDataSourceRef, dnd_action: WlDataDeviceManagerDndAction) {
+            self.0(_slf, dnd_action)
+        }
+    }
+    impl<T, F> WlDataSourceEventHandlerMutWithData<T> for ActionMut<F>
+    where
+        F: FnMut(&mut T, &WlDataSourceRef, WlDataDeviceManagerDndAction),
+    {
+        #[inline]
+        fn action(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlDataSourceRef,
+            dnd_action: WlDataDeviceManagerDndAction,
+        ) {
+            self.0(_data, _slf, dnd_action)
+        }
+    }
+
+    impl WlDataSource {
+        /// Creates an event handler for target events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_target<F>(f: F) -> Target<F>
+        where
+            F: Fn(&WlDataSourceRef, Option<&str>),
+        {
+            Target(f)
+        }
 
-    impl WlDataSource {
-        /// Creates an event handler for target events.
+        /// Creates an event handler with mutable data for target events.
         ///
         /// The event handler ignores all other events.
         #[allow(dead_code)]
-        pub fn on_target<F>(f: F) -> Target<F>
+        pub fn on_target_with_data<T, F>(f: F) -> Target<F>
         where
-            F: Fn(&WlDataSourceRef, Option<&str>),
+            F: Fn(&mut T, &WlDataSourceRef, Option<&str>),
         {
             Target(f)
         }
@@ -621,6 +1427,17 @@ pub mod event_handlers {
             Send(f)
         }
 
+        /// Creates an event handler with mutable data for send events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_send_with_data<T, F>(f: F) -> Send<F>
+        where
+            F: Fn(&mut T, &WlDataSourceRef, &str, OwnedFd),
+        {
+            Send(f)
+        }
+
         /// Creates an event handler for cancelled events.
         ///
         /// The event handler ignores all other events.
@@ -632,6 +1449,17 @@ pub mod event_handlers {
             Cancelled(f)
         }
 
+        /// Creates an event handler with mutable data for cancelled events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_cancelled_with_data<T, F>(f: F) -> Cancelled<F>
+        where
+            F: Fn(&mut T, &WlDataSourceRef),
+        {
+            Cancelled(f)
+        }
+
         /// Creates an event handler for dnd_drop_performed events.
         ///
         /// The event handler ignores all other events.
@@ -643,6 +1471,17 @@ pub mod event_handlers {
             DndDropPerformed(f)
         }
 
+        /// Creates an event handler with mutable data for dnd_drop_performed events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_dnd_drop_performed_with_data<T, F>(f: F) -> DndDropPerformed<F>
+        where
+            F: Fn(&mut T, &WlDataSourceRef),
+        {
+            DndDropPerformed(f)
+        }
+
         /// Creates an event handler for dnd_finished events.
         ///
         /// The event handler ignores all other events.
@@ -654,6 +1493,17 @@ pub mod event_handlers {
             DndFinished(f)
         }
 
+        /// Creates an event handler with mutable data for dnd_finished events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_dnd_finished_with_data<T, F>(f: F) -> DndFinished<F>
+        where
+            F: Fn(&mut T, &WlDataSourceRef),
+        {
+            DndFinished(f)
+        }
+
         /// Creates an event handler for action events.
         ///
         /// The event handler ignores all other events.
@@ -664,5 +1514,488 @@ pub mod event_handlers {
         {
             Action(f)
         }
+
+        /// Creates an event handler with mutable data for action events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_action_with_data<T, F>(f: F) -> Action<F>
+        where
+            F: Fn(&mut T, &WlDataSourceRef, WlDataDeviceManagerDndAction),
+        {
+            Action(f)
+        }
+
+        /// Creates a mutable event handler for target events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_mut<F>(f: F) -> TargetMut<F>
+        where
+            F: FnMut(&WlDataSourceRef, Option<&str>),
+        {
+            TargetMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for target events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_target_mut_with_data<T, F>(f: F) -> TargetMut<F>
+        where
+            F: FnMut(&mut T, &WlDataSourceRef, Option<&str>),
+        {
+            TargetMut(f)
+        }
+
+        /// Creates a mutable event handler for send events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_send_mut<F>(f: F) -> SendMut<F>
+        where
+            F: FnMut(&WlDataSourceRef, &str, OwnedFd),
+        {
+            SendMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for send events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_send_mut_with_data<T, F>(f: F) -> SendMut<F>
+        where
+            F: FnMut(&mut T, &WlDataSourceRef, &str, OwnedFd),
+        {
+            SendMut(f)
+        }
+
+        /// Creates a mutable event handler for cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cancelled_mut<F>(f: F) -> CancelledMut<F>
+        where
+            F: FnMut(&WlDataSourceRef),
+        {
+            CancelledMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for cancelled events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_cancelled_mut_with_data<T, F>(f: F) -> CancelledMut<F>
+        where
+            F: FnMut(&mut T, &WlDataSourceRef),
+        {
+            CancelledMut(f)
+        }
+
+        /// Creates a mutable event handler for dnd_drop_performed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dnd_drop_performed_mut<F>(f: F) -> DndDropPerformedMut<F>
+        where
+            F: FnMut(&WlDataSourceRef),
+        {
+            DndDropPerformedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for dnd_drop_performed events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dnd_drop_performed_mut_with_data<T, F>(f: F) -> DndDropPerformedMut<F>
+        where
+            F: FnMut(&mut T, &WlDataSourceRef),
+        {
+            DndDropPerformedMut(f)
+        }
+
+        /// Creates a mutable event handler for dnd_finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dnd_finished_mut<F>(f: F) -> DndFinishedMut<F>
+        where
+            F: FnMut(&WlDataSourceRef),
+        {
+            DndFinishedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for dnd_finished events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_dnd_finished_mut_with_data<T, F>(f: F) -> DndFinishedMut<F>
+        where
+            F: FnMut(&mut T, &WlDataSourceRef),
+        {
+            DndFinishedMut(f)
+        }
+
+        /// Creates a mutable event handler for action events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_action_mut<F>(f: F) -> ActionMut<F>
+        where
+            F: FnMut(&WlDataSourceRef, WlDataDeviceManagerDndAction),
+        {
+            ActionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for action events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_action_mut_with_data<T, F>(f: F) -> ActionMut<F>
+        where
+            F: FnMut(&mut T, &WlDataSourceRef, WlDataDeviceManagerDndAction),
+        {
+            ActionMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                target: Ignore,
+                send: Ignore,
+                cancelled: Ignore,
+                dnd_drop_performed: Ignore,
+                dnd_finished: Ignore,
+                action: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlDataSourceEventHandler for Ignore {}
+    impl<T> WlDataSourceEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlDataSource::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore, H4 = Ignore, H5 = Ignore> {
+        target: H0,
+        send: H1,
+        cancelled: H2,
+        dnd_drop_performed: H3,
+        dnd_finished: H4,
+        action: H5,
+    }
+
+    impl<H0, H1, H2, H3, H4, H5> WlDataSourceEventHandler for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: WlDataSourceEventHandler,
+        H1: WlDataSourceEventHandler,
+        H2: WlDataSourceEventHandler,
+        H3: WlDataSourceEventHandler,
+        H4: WlDataSourceEventHandler,
+        H5: WlDataSourceEventHandler,
+    {
+        #[inline]
+        fn target(&self, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+            self.target.target(_slf, mime_type)
+        }
+
+        #[inline]
+        fn send(&self, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+            self.send.send(_slf, mime_type, fd)
+        }
+
+        #[inline]
+        fn cancelled(&self, _slf: &WlDataSourceRef) {
+            self.cancelled.cancelled(_slf)
+        }
+
+        #[inline]
+        fn dnd_drop_performed(&self, _slf: &WlDataSourceRef) {
+            self.dnd_drop_performed.dnd_drop_performed(_slf)
+        }
+
+        #[inline]
+        fn dnd_finished(&self, _slf: &WlDataSourceRef) {
+            self.dnd_finished.dnd_finished(_slf)
+        }
+
+        #[inline]
+        fn action(&self, _slf: &WlDataSourceRef, dnd_action: WlDataDeviceManagerDndAction) {
+            self.action.action(_slf, dnd_action)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3, H4, H5> WlDataSourceEventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5>
+    where
+        H0: WlDataSourceEventHandlerWithData<T>,
+        H1: WlDataSourceEventHandlerWithData<T>,
+        H2: WlDataSourceEventHandlerWithData<T>,
+        H3: WlDataSourceEventHandlerWithData<T>,
+        H4: WlDataSourceEventHandlerWithData<T>,
+        H5: WlDataSourceEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn target(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: Option<&str>) {
+            self.target.target(_data, _slf, mime_type)
+        }
+
+        #[inline]
+        fn send(&self, _data: &mut T, _slf: &WlDataSourceRef, mime_type: &str, fd: OwnedFd) {
+            self.send.send(_data, _slf, mime_type, fd)
+        }
+
+        #[inline]
+        fn cancelled(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.cancelled.cancelled(_data, _slf)
+        }
+
+        #[inline]
+        fn dnd_drop_performed(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.dnd_drop_performed.dnd_drop_performed(_data, _slf)
+        }
+
+        #[inline]
+        fn dnd_finished(&self, _data: &mut T, _slf: &WlDataSourceRef) {
+            self.dnd_finished.dnd_finished(_data, _slf)
+        }
+
+        #[inline]
+        fn action(
+            &self,
+            _data: &mut T,
+            _slf: &WlDataSourceRef,
+            dnd_action: WlDataDeviceManagerDndAction,
+        ) {
+            self.action.action(_data, _slf, dnd_action)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5> Handler<H0, H1, H2, H3, H4, H5> {
+        /// Sets the closure for target events.
+        #[allow(dead_code)]
+        pub fn on_target<F>(self, f: F) -> Handler<Target<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&WlDataSourceRef, Option<&str>),
+        {
+            Handler {
+                target: Target(f),
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for target events.
+        #[allow(dead_code)]
+        pub fn on_target_with_data<T, F>(self, f: F) -> Handler<Target<F>, H1, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &WlDataSourceRef, Option<&str>),
+        {
+            Handler {
+                target: Target(f),
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for send events.
+        #[allow(dead_code)]
+        pub fn on_send<F>(self, f: F) -> Handler<H0, Send<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&WlDataSourceRef, &str, OwnedFd),
+        {
+            Handler {
+                target: self.target,
+                send: Send(f),
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for send events.
+        #[allow(dead_code)]
+        pub fn on_send_with_data<T, F>(self, f: F) -> Handler<H0, Send<F>, H2, H3, H4, H5>
+        where
+            F: Fn(&mut T, &WlDataSourceRef, &str, OwnedFd),
+        {
+            Handler {
+                target: self.target,
+                send: Send(f),
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for cancelled events.
+        #[allow(dead_code)]
+        pub fn on_cancelled<F>(self, f: F) -> Handler<H0, H1, Cancelled<F>, H3, H4, H5>
+        where
+            F: Fn(&WlDataSourceRef),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: Cancelled(f),
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for cancelled events.
+        #[allow(dead_code)]
+        pub fn on_cancelled_with_data<T, F>(self, f: F) -> Handler<H0, H1, Cancelled<F>, H3, H4, H5>
+        where
+            F: Fn(&mut T, &WlDataSourceRef),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: Cancelled(f),
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for dnd_drop_performed events.
+        #[allow(dead_code)]
+        pub fn on_dnd_drop_performed<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, DndDropPerformed<F>, H4, H5>
+        where
+            F: Fn(&WlDataSourceRef),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: DndDropPerformed(f),
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for dnd_drop_performed events.
+        #[allow(dead_code)]
+        pub fn on_dnd_drop_performed_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, DndDropPerformed<F>, H4, H5>
+        where
+            F: Fn(&mut T, &WlDataSourceRef),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: DndDropPerformed(f),
+                dnd_finished: self.dnd_finished,
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for dnd_finished events.
+        #[allow(dead_code)]
+        pub fn on_dnd_finished<F>(self, f: F) -> Handler<H0, H1, H2, H3, DndFinished<F>, H5>
+        where
+            F: Fn(&WlDataSourceRef),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: DndFinished(f),
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for dnd_finished events.
+        #[allow(dead_code)]
+        pub fn on_dnd_finished_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, DndFinished<F>, H5>
+        where
+            F: Fn(&mut T, &WlDataSourceRef),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: DndFinished(f),
+                action: self.action,
+            }
+        }
+
+        /// Sets the closure for action events.
+        #[allow(dead_code)]
+        pub fn on_action<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Action<F>>
+        where
+            F: Fn(&WlDataSourceRef, WlDataDeviceManagerDndAction),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: Action(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for action events.
+        #[allow(dead_code)]
+        pub fn on_action_with_data<T, F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Action<F>>
+        where
+            F: Fn(&mut T, &WlDataSourceRef, WlDataDeviceManagerDndAction),
+        {
+            Handler {
+                target: self.target,
+                send: self.send,
+                cancelled: self.cancelled,
+                dnd_drop_performed: self.dnd_drop_performed,
+                dnd_finished: self.dnd_finished,
+                action: Action(f),
+            }
+        }
     }
 }
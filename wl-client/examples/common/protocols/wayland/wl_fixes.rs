@@ -35,6 +35,38 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_fixes interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_fixes",
+    protocol: "wayland",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "destroy_registry",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "registry",
+                ty: info::ArgType::Object,
+                interface: Some("wl_registry"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[],
+    enums: &[],
+};
+
 /// An owned wl_fixes proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -89,11 +121,16 @@ impl Deref for WlFixes {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -179,6 +216,47 @@ pub trait WlFixesEventHandler {}
 
 impl WlFixesEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlFixes] proxies with mutable data.
+///
+/// This trait is the same as [WlFixesEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlFixesEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlFixes] proxies.
+///
+/// This trait is the same as [WlFixesEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlFixesEventHandlerMut {}
+
+/// A mutable event handler for [WlFixes] proxies with mutable data.
+///
+/// This trait is the same as [WlFixesEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlFixesEventHandlerMutWithData<T> {}
+
+impl<H> WlFixesEventHandler for MutEventHandler<H> where H: WlFixesEventHandlerMut {}
+
+impl<T, H> WlFixesEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlFixesEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WlFixesEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlFixesEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WlFixesEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlFixesEventHandlerMutWithData<U>
+{
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -199,6 +277,35 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlFixesEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_fixes", opcode);
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlFixesEventHandler,
@@ -211,6 +318,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlFixesEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
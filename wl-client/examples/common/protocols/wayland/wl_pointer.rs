@@ -135,6 +135,422 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_pointer interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_pointer",
+    protocol: "wayland",
+    version: 10,
+    requests: &[
+        info::MessageInfo {
+            name: "set_cursor",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "hotspot_x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "hotspot_y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "release",
+            since: 3,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface_x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface_y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "motion",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface_x",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface_y",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "button",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "button",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "state",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("button_state"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "axis",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "axis",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("axis"),
+                },
+                info::ArgInfo {
+                    name: "value",
+                    ty: info::ArgType::Fixed,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "frame",
+            since: 5,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "axis_source",
+            since: 5,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "axis_source",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("axis_source"),
+            }],
+        },
+        info::MessageInfo {
+            name: "axis_stop",
+            since: 5,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "axis",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("axis"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "axis_discrete",
+            since: 5,
+            deprecated_since: Some(8),
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "axis",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("axis"),
+                },
+                info::ArgInfo {
+                    name: "discrete",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "axis_value120",
+            since: 8,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "axis",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("axis"),
+                },
+                info::ArgInfo {
+                    name: "value120",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "axis_relative_direction",
+            since: 9,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "axis",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("axis"),
+                },
+                info::ArgInfo {
+                    name: "direction",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("axis_relative_direction"),
+                },
+            ],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "role",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+        info::EnumInfo {
+            name: "button_state",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "released",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "pressed",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "axis",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "vertical_scroll",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "horizontal_scroll",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "axis_source",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "wheel",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "finger",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "continuous",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "wheel_tilt",
+                    value: 3,
+                    since: 6,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "axis_relative_direction",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "identical",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "inverted",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
 /// An owned wl_pointer proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -189,11 +605,16 @@ impl Deref for WlPointer {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -748,641 +1169,3606 @@ pub trait WlPointerEventHandler {
 
 impl WlPointerEventHandler for private::NoOpEventHandler {}
 
-// SAFETY: - INTERFACE is a valid wl_interface
-unsafe impl<H> EventHandler for private::EventHandler<H>
-where
-    H: WlPointerEventHandler,
-{
-    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
-
-    #[allow(unused_variables)]
-    unsafe fn handle_event(
+/// An event handler for [WlPointer] proxies with mutable data.
+///
+/// This trait is the same as [WlPointerEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlPointerEventHandlerWithData<T> {
+    /// enter event
+    ///
+    /// Notification that this seat's pointer is focused on a certain
+    /// surface.
+    ///
+    /// When a seat's focus enters a surface, the pointer image
+    /// is undefined and a client should respond to this event by setting
+    /// an appropriate pointer image with the set_cursor request.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial number of the enter event
+    /// - `surface`: surface entered by the pointer
+    /// - `surface_x`: surface-local x coordinate
+    /// - `surface_y`: surface-local y coordinate
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn enter(
         &self,
-        queue: &Queue,
-        data: *mut u8,
-        slf: &UntypedBorrowedProxy,
-        opcode: u32,
-        args: *mut wl_argument,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
     ) {
-        // SAFETY: This function requires that slf has the interface INTERFACE
-        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlPointerRef>(slf) };
-        match opcode {
-            0 => {
-                // SAFETY: INTERFACE requires that there are 4 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains an object
-                let arg1 = unsafe {
-                    if let Some(p) = NonNull::new(args[1].o.cast()) {
-                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
-                    } else {
-                        None
-                    }
-                };
-                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
-                let arg1 = arg1.as_ref().map(|arg1| unsafe {
-                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg1)
-                });
-                // SAFETY: - INTERFACE requires that args[2] contains a fixed
-                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
-                // SAFETY: - INTERFACE requires that args[3] contains a fixed
-                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
-                self.0.enter(slf, arg0, arg1, arg2, arg3);
-            }
-            1 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains an object
-                let arg1 = unsafe {
-                    if let Some(p) = NonNull::new(args[1].o.cast()) {
-                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
-                    } else {
-                        None
-                    }
-                };
-                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
-                let arg1 = arg1.as_ref().map(|arg1| unsafe {
-                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg1)
-                });
-                self.0.leave(slf, arg0, arg1);
-            }
-            2 => {
-                // SAFETY: INTERFACE requires that there are 3 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a fixed
-                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
-                // SAFETY: - INTERFACE requires that args[2] contains a fixed
-                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
-                self.0.motion(slf, arg0, arg1, arg2);
-            }
-            3 => {
-                // SAFETY: INTERFACE requires that there are 4 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { args[1].u };
-                // SAFETY: - INTERFACE requires that args[2] contains a uint
-                let arg2 = unsafe { args[2].u };
-                // SAFETY: - INTERFACE requires that args[3] contains a uint
-                let arg3 = unsafe { WlPointerButtonState(args[3].u) };
-                self.0.button(slf, arg0, arg1, arg2, arg3);
-            }
-            4 => {
-                // SAFETY: INTERFACE requires that there are 3 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { WlPointerAxis(args[1].u) };
-                // SAFETY: - INTERFACE requires that args[2] contains a fixed
-                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
-                self.0.axis(slf, arg0, arg1, arg2);
-            }
-            5 => {
-                self.0.frame(slf);
-            }
-            6 => {
-                // SAFETY: INTERFACE requires that there are 1 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { WlPointerAxisSource(args[0].u) };
-                self.0.axis_source(slf, arg0);
-            }
-            7 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { args[0].u };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { WlPointerAxis(args[1].u) };
-                self.0.axis_stop(slf, arg0, arg1);
-            }
-            8 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { WlPointerAxis(args[0].u) };
-                // SAFETY: - INTERFACE requires that args[1] contains an int
-                let arg1 = unsafe { args[1].i };
-                self.0.axis_discrete(slf, arg0, arg1);
-            }
-            9 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { WlPointerAxis(args[0].u) };
-                // SAFETY: - INTERFACE requires that args[1] contains an int
-                let arg1 = unsafe { args[1].i };
-                self.0.axis_value120(slf, arg0, arg1);
-            }
-            10 => {
-                // SAFETY: INTERFACE requires that there are 2 arguments
-                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
-                // SAFETY: - INTERFACE requires that args[0] contains a uint
-                let arg0 = unsafe { WlPointerAxis(args[0].u) };
-                // SAFETY: - INTERFACE requires that args[1] contains a uint
-                let arg1 = unsafe { WlPointerAxisRelativeDirection(args[1].u) };
-                self.0.axis_relative_direction(slf, arg0, arg1);
-            }
-            _ => {
-                invalid_opcode("wl_pointer", opcode);
-            }
-        }
+        let _ = serial;
+        let _ = surface;
+        let _ = surface_x;
+        let _ = surface_y;
     }
-}
-
-impl<H> CreateEventHandler<H> for private::ProxyApi
-where
-    H: WlPointerEventHandler,
-{
-    type EventHandler = private::EventHandler<H>;
 
+    /// leave event
+    ///
+    /// Notification that this seat's pointer is no longer focused on
+    /// a certain surface.
+    ///
+    /// The leave notification is sent before the enter notification
+    /// for the new focus.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial number of the leave event
+    /// - `surface`: surface left by the pointer
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
     #[inline]
-    fn create_event_handler(handler: H) -> Self::EventHandler {
-        private::EventHandler(handler)
+    fn leave(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _ = serial;
+        let _ = surface;
     }
-}
 
-impl WlPointer {
-    /// Since when the error.role enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
+    /// pointer motion event
+    ///
+    /// Notification of pointer location change. The arguments
+    /// surface_x and surface_y are the location relative to the
+    /// focused surface.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    /// - `surface_x`: surface-local x coordinate
+    /// - `surface_y`: surface-local y coordinate
+    #[inline]
+    fn motion(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _ = time;
+        let _ = surface_x;
+        let _ = surface_y;
+    }
+
+    /// pointer button event
+    ///
+    /// Mouse button click and release notifications.
+    ///
+    /// The location of the click is given by the last motion or
+    /// enter event.
+    /// The time argument is a timestamp with millisecond
+    /// granularity, with an undefined base.
+    ///
+    /// The button is a button code as defined in the Linux kernel's
+    /// linux/input-event-codes.h header file, e.g. BTN_LEFT.
+    ///
+    /// Any 16-bit button code value is reserved for future additions to the
+    /// kernel's event code list. All other button codes above 0xFFFF are
+    /// currently undefined but may be used in future versions of this
+    /// protocol.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial number of the button event
+    /// - `time`: timestamp with millisecond granularity
+    /// - `button`: button that produced the event
+    /// - `state`: physical state of the button
+    #[inline]
+    fn button(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = button;
+        let _ = state;
+    }
+
+    /// axis event
+    ///
+    /// Scroll and other axis notifications.
+    ///
+    /// For scroll events (vertical and horizontal scroll axes), the
+    /// value parameter is the length of a vector along the specified
+    /// axis in a coordinate space identical to those of motion events,
+    /// representing a relative movement along the specified axis.
+    ///
+    /// For devices that support movements non-parallel to axes multiple
+    /// axis events will be emitted.
+    ///
+    /// When applicable, for example for touch pads, the server can
+    /// choose to emit scroll events where the motion vector is
+    /// equivalent to a motion event vector.
+    ///
+    /// When applicable, a client can transform its content relative to the
+    /// scroll distance.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    /// - `axis`: axis type
+    /// - `value`: length of vector in surface-local coordinate space
+    #[inline]
+    fn axis(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        axis: WlPointerAxis,
+        value: Fixed,
+    ) {
+        let _ = time;
+        let _ = axis;
+        let _ = value;
+    }
+
+    /// end of a pointer event sequence
+    ///
+    /// Indicates the end of a set of events that logically belong together.
+    /// A client is expected to accumulate the data in all events within the
+    /// frame before proceeding.
+    ///
+    /// All wl_pointer events before a wl_pointer.frame event belong
+    /// logically together. For example, in a diagonal scroll motion the
+    /// compositor will send an optional wl_pointer.axis_source event, two
+    /// wl_pointer.axis events (horizontal and vertical) and finally a
+    /// wl_pointer.frame event. The client may use this information to
+    /// calculate a diagonal vector for scrolling.
+    ///
+    /// When multiple wl_pointer.axis events occur within the same frame,
+    /// the motion vector is the combined motion of all events.
+    /// When a wl_pointer.axis and a wl_pointer.axis_stop event occur within
+    /// the same frame, this indicates that axis movement in one axis has
+    /// stopped but continues in the other axis.
+    /// When multiple wl_pointer.axis_stop events occur within the same
+    /// frame, this indicates that these axes stopped in the same instance.
+    ///
+    /// A wl_pointer.frame event is sent for every logical event group,
+    /// even if the group only contains a single wl_pointer event.
+    /// Specifically, a client may get a sequence: motion, frame, button,
+    /// frame, axis, frame, axis_stop, frame.
+    ///
+    /// The wl_pointer.enter and wl_pointer.leave events are logical events
+    /// generated by the compositor and not the hardware. These events are
+    /// also grouped by a wl_pointer.frame. When a pointer moves from one
+    /// surface to another, a compositor should group the
+    /// wl_pointer.leave event within the same wl_pointer.frame.
+    /// However, a client must not rely on wl_pointer.leave and
+    /// wl_pointer.enter being in the same wl_pointer.frame.
+    /// Compositor-specific policies may require the wl_pointer.leave and
+    /// wl_pointer.enter event being split across multiple wl_pointer.frame
+    /// groups.
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &WlPointerRef) {}
+
+    /// axis source event
+    ///
+    /// Source information for scroll and other axes.
+    ///
+    /// This event does not occur on its own. It is sent before a
+    /// wl_pointer.frame event and carries the source information for
+    /// all events within that frame.
+    ///
+    /// The source specifies how this event was generated. If the source is
+    /// wl_pointer.axis_source.finger, a wl_pointer.axis_stop event will be
+    /// sent when the user lifts the finger off the device.
+    ///
+    /// If the source is wl_pointer.axis_source.wheel,
+    /// wl_pointer.axis_source.wheel_tilt or
+    /// wl_pointer.axis_source.continuous, a wl_pointer.axis_stop event may
+    /// or may not be sent. Whether a compositor sends an axis_stop event
+    /// for these sources is hardware-specific and implementation-dependent;
+    /// clients must not rely on receiving an axis_stop event for these
+    /// scroll sources and should treat scroll sequences from these scroll
+    /// sources as unterminated by default.
+    ///
+    /// This event is optional. If the source is unknown for a particular
+    /// axis event sequence, no event is sent.
+    /// Only one wl_pointer.axis_source event is permitted per frame.
+    ///
+    /// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+    /// not guaranteed.
+    ///
+    /// # Arguments
+    ///
+    /// - `axis_source`: source of the axis event
+    #[inline]
+    fn axis_source(&self, _data: &mut T, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+        let _ = axis_source;
+    }
+
+    /// axis stop event
+    ///
+    /// Stop notification for scroll and other axes.
+    ///
+    /// For some wl_pointer.axis_source types, a wl_pointer.axis_stop event
+    /// is sent to notify a client that the axis sequence has terminated.
+    /// This enables the client to implement kinetic scrolling.
+    /// See the wl_pointer.axis_source documentation for information on when
+    /// this event may be generated.
+    ///
+    /// Any wl_pointer.axis events with the same axis_source after this
+    /// event should be considered as the start of a new axis motion.
+    ///
+    /// The timestamp is to be interpreted identical to the timestamp in the
+    /// wl_pointer.axis event. The timestamp value may be the same as a
+    /// preceding wl_pointer.axis event.
+    ///
+    /// # Arguments
+    ///
+    /// - `time`: timestamp with millisecond granularity
+    /// - `axis`: the axis stopped with this event
+    #[inline]
+    fn axis_stop(&self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        let _ = time;
+        let _ = axis;
+    }
+
+    /// axis click event
+    ///
+    /// Discrete step information for scroll and other axes.
+    ///
+    /// This event carries the axis value of the wl_pointer.axis event in
+    /// discrete steps (e.g. mouse wheel clicks).
+    ///
+    /// This event is deprecated with wl_pointer version 8 - this event is not
+    /// sent to clients supporting version 8 or later.
+    ///
+    /// This event does not occur on its own, it is coupled with a
+    /// wl_pointer.axis event that represents this axis value on a
+    /// continuous scale. The protocol guarantees that each axis_discrete
+    /// event is always followed by exactly one axis event with the same
+    /// axis number within the same wl_pointer.frame. Note that the protocol
+    /// allows for other events to occur between the axis_discrete and
+    /// its coupled axis event, including other axis_discrete or axis
+    /// events. A wl_pointer.frame must not contain more than one axis_discrete
+    /// event per axis type.
+    ///
+    /// This event is optional; continuous scrolling devices
+    /// like two-finger scrolling on touchpads do not have discrete
+    /// steps and do not generate this event.
+    ///
+    /// The discrete value carries the directional information. e.g. a value
+    /// of -2 is two steps towards the negative direction of this axis.
+    ///
+    /// The axis number is identical to the axis number in the associated
+    /// axis event.
+    ///
+    /// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+    /// not guaranteed.
+    ///
+    /// # Arguments
+    ///
+    /// - `axis`: axis type
+    /// - `discrete`: number of steps
+    #[inline]
+    fn axis_discrete(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        discrete: i32,
+    ) {
+        let _ = axis;
+        let _ = discrete;
+    }
+
+    /// axis high-resolution scroll event
+    ///
+    /// Discrete high-resolution scroll information.
+    ///
+    /// This event carries high-resolution wheel scroll information,
+    /// with each multiple of 120 representing one logical scroll step
+    /// (a wheel detent). For example, an axis_value120 of 30 is one quarter of
+    /// a logical scroll step in the positive direction, a value120 of
+    /// -240 are two logical scroll steps in the negative direction within the
+    /// same hardware event.
+    /// Clients that rely on discrete scrolling should accumulate the
+    /// value120 to multiples of 120 before processing the event.
+    ///
+    /// The value120 must not be zero.
+    ///
+    /// This event replaces the wl_pointer.axis_discrete event in clients
+    /// supporting wl_pointer version 8 or later.
+    ///
+    /// Where a wl_pointer.axis_source event occurs in the same
+    /// wl_pointer.frame, the axis source applies to this event.
+    ///
+    /// The order of wl_pointer.axis_value120 and wl_pointer.axis_source is
+    /// not guaranteed.
+    ///
+    /// # Arguments
+    ///
+    /// - `axis`: axis type
+    /// - `value120`: scroll distance as fraction of 120
+    #[inline]
+    fn axis_value120(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        value120: i32,
+    ) {
+        let _ = axis;
+        let _ = value120;
+    }
+
+    /// axis relative physical direction event
+    ///
+    /// Relative directional information of the entity causing the axis
+    /// motion.
+    ///
+    /// For a wl_pointer.axis event, the wl_pointer.axis_relative_direction
+    /// event specifies the movement direction of the entity causing the
+    /// wl_pointer.axis event. For example:
+    /// - if a user's fingers on a touchpad move down and this
+    ///   causes a wl_pointer.axis vertical_scroll down event, the physical
+    ///   direction is 'identical'
+    /// - if a user's fingers on a touchpad move down and this causes a
+    ///   wl_pointer.axis vertical_scroll up scroll up event ('natural
+    ///   scrolling'), the physical direction is 'inverted'.
+    ///
+    /// A client may use this information to adjust scroll motion of
+    /// components. Specifically, enabling natural scrolling causes the
+    /// content to change direction compared to traditional scrolling.
+    /// Some widgets like volume control sliders should usually match the
+    /// physical direction regardless of whether natural scrolling is
+    /// active. This event enables clients to match the scroll direction of
+    /// a widget to the physical direction.
+    ///
+    /// This event does not occur on its own, it is coupled with a
+    /// wl_pointer.axis event that represents this axis value.
+    /// The protocol guarantees that each axis_relative_direction event is
+    /// always followed by exactly one axis event with the same
+    /// axis number within the same wl_pointer.frame. Note that the protocol
+    /// allows for other events to occur between the axis_relative_direction
+    /// and its coupled axis event.
+    ///
+    /// The axis number is identical to the axis number in the associated
+    /// axis event.
+    ///
+    /// The order of wl_pointer.axis_relative_direction,
+    /// wl_pointer.axis_discrete and wl_pointer.axis_source is not
+    /// guaranteed.
+    ///
+    /// # Arguments
+    ///
+    /// - `axis`: axis type
+    /// - `direction`: physical direction relative to axis motion
+    #[inline]
+    fn axis_relative_direction(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        let _ = axis;
+        let _ = direction;
+    }
+}
+
+/// A mutable event handler for [WlPointer] proxies.
+///
+/// This trait is the same as [WlPointerEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlPointerEventHandlerMut {
+    /// See [WlPointerEventHandler::enter].
+    #[inline]
+    fn enter(
+        &mut self,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _ = serial;
+        let _ = surface;
+        let _ = surface_x;
+        let _ = surface_y;
+    }
+
+    /// See [WlPointerEventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _slf: &WlPointerRef, serial: u32, surface: Option<&WlSurfaceRef>) {
+        let _ = serial;
+        let _ = surface;
+    }
+
+    /// See [WlPointerEventHandler::motion].
+    #[inline]
+    fn motion(&mut self, _slf: &WlPointerRef, time: u32, surface_x: Fixed, surface_y: Fixed) {
+        let _ = time;
+        let _ = surface_x;
+        let _ = surface_y;
+    }
+
+    /// See [WlPointerEventHandler::button].
+    #[inline]
+    fn button(
+        &mut self,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = button;
+        let _ = state;
+    }
+
+    /// See [WlPointerEventHandler::axis].
+    #[inline]
+    fn axis(&mut self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis, value: Fixed) {
+        let _ = time;
+        let _ = axis;
+        let _ = value;
+    }
+
+    /// See [WlPointerEventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _slf: &WlPointerRef) {}
+
+    /// See [WlPointerEventHandler::axis_source].
+    #[inline]
+    fn axis_source(&mut self, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+        let _ = axis_source;
+    }
+
+    /// See [WlPointerEventHandler::axis_stop].
+    #[inline]
+    fn axis_stop(&mut self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        let _ = time;
+        let _ = axis;
+    }
+
+    /// See [WlPointerEventHandler::axis_discrete].
+    #[inline]
+    fn axis_discrete(&mut self, _slf: &WlPointerRef, axis: WlPointerAxis, discrete: i32) {
+        let _ = axis;
+        let _ = discrete;
+    }
+
+    /// See [WlPointerEventHandler::axis_value120].
+    #[inline]
+    fn axis_value120(&mut self, _slf: &WlPointerRef, axis: WlPointerAxis, value120: i32) {
+        let _ = axis;
+        let _ = value120;
+    }
+
+    /// See [WlPointerEventHandler::axis_relative_direction].
+    #[inline]
+    fn axis_relative_direction(
+        &mut self,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        let _ = axis;
+        let _ = direction;
+    }
+}
+
+/// A mutable event handler for [WlPointer] proxies with mutable data.
+///
+/// This trait is the same as [WlPointerEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlPointerEventHandlerMutWithData<T> {
+    /// See [WlPointerEventHandler::enter].
+    #[inline]
+    fn enter(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _ = serial;
+        let _ = surface;
+        let _ = surface_x;
+        let _ = surface_y;
+    }
+
+    /// See [WlPointerEventHandler::leave].
+    #[inline]
+    fn leave(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _ = serial;
+        let _ = surface;
+    }
+
+    /// See [WlPointerEventHandler::motion].
+    #[inline]
+    fn motion(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _ = time;
+        let _ = surface_x;
+        let _ = surface_y;
+    }
+
+    /// See [WlPointerEventHandler::button].
+    #[inline]
+    fn button(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = button;
+        let _ = state;
+    }
+
+    /// See [WlPointerEventHandler::axis].
+    #[inline]
+    fn axis(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        axis: WlPointerAxis,
+        value: Fixed,
+    ) {
+        let _ = time;
+        let _ = axis;
+        let _ = value;
+    }
+
+    /// See [WlPointerEventHandler::frame].
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &WlPointerRef) {}
+
+    /// See [WlPointerEventHandler::axis_source].
+    #[inline]
+    fn axis_source(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis_source: WlPointerAxisSource,
+    ) {
+        let _ = axis_source;
+    }
+
+    /// See [WlPointerEventHandler::axis_stop].
+    #[inline]
+    fn axis_stop(&mut self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        let _ = time;
+        let _ = axis;
+    }
+
+    /// See [WlPointerEventHandler::axis_discrete].
+    #[inline]
+    fn axis_discrete(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        discrete: i32,
+    ) {
+        let _ = axis;
+        let _ = discrete;
+    }
+
+    /// See [WlPointerEventHandler::axis_value120].
+    #[inline]
+    fn axis_value120(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        value120: i32,
+    ) {
+        let _ = axis;
+        let _ = value120;
+    }
+
+    /// See [WlPointerEventHandler::axis_relative_direction].
+    #[inline]
+    fn axis_relative_direction(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        let _ = axis;
+        let _ = direction;
+    }
+}
+
+impl<H> WlPointerEventHandler for MutEventHandler<H>
+where
+    H: WlPointerEventHandlerMut,
+{
+    #[inline]
+    fn enter(
+        &self,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        self.with(|h| h.enter(_slf, serial, surface, surface_x, surface_y))
+    }
+
+    #[inline]
+    fn leave(&self, _slf: &WlPointerRef, serial: u32, surface: Option<&WlSurfaceRef>) {
+        self.with(|h| h.leave(_slf, serial, surface))
+    }
+
+    #[inline]
+    fn motion(&self, _slf: &WlPointerRef, time: u32, surface_x: Fixed, surface_y: Fixed) {
+        self.with(|h| h.motion(_slf, time, surface_x, surface_y))
+    }
+
+    #[inline]
+    fn button(
+        &self,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        self.with(|h| h.button(_slf, serial, time, button, state))
+    }
+
+    #[inline]
+    fn axis(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis, value: Fixed) {
+        self.with(|h| h.axis(_slf, time, axis, value))
+    }
+
+    #[inline]
+    fn frame(&self, _slf: &WlPointerRef) {
+        self.with(|h| h.frame(_slf))
+    }
+
+    #[inline]
+    fn axis_source(&self, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+        self.with(|h| h.axis_source(_slf, axis_source))
+    }
+
+    #[inline]
+    fn axis_stop(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        self.with(|h| h.axis_stop(_slf, time, axis))
+    }
+
+    #[inline]
+    fn axis_discrete(&self, _slf: &WlPointerRef, axis: WlPointerAxis, discrete: i32) {
+        self.with(|h| h.axis_discrete(_slf, axis, discrete))
+    }
+
+    #[inline]
+    fn axis_value120(&self, _slf: &WlPointerRef, axis: WlPointerAxis, value120: i32) {
+        self.with(|h| h.axis_value120(_slf, axis, value120))
+    }
+
+    #[inline]
+    fn axis_relative_direction(
+        &self,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        self.with(|h| h.axis_relative_direction(_slf, axis, direction))
+    }
+}
+
+impl<T, H> WlPointerEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlPointerEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn enter(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        self.with(|h| h.enter(_data, _slf, serial, surface, surface_x, surface_y))
+    }
+
+    #[inline]
+    fn leave(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        self.with(|h| h.leave(_data, _slf, serial, surface))
+    }
+
+    #[inline]
+    fn motion(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        self.with(|h| h.motion(_data, _slf, time, surface_x, surface_y))
+    }
+
+    #[inline]
+    fn button(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        self.with(|h| h.button(_data, _slf, serial, time, button, state))
+    }
+
+    #[inline]
+    fn axis(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        axis: WlPointerAxis,
+        value: Fixed,
+    ) {
+        self.with(|h| h.axis(_data, _slf, time, axis, value))
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &WlPointerRef) {
+        self.with(|h| h.frame(_data, _slf))
+    }
+
+    #[inline]
+    fn axis_source(&self, _data: &mut T, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+        self.with(|h| h.axis_source(_data, _slf, axis_source))
+    }
+
+    #[inline]
+    fn axis_stop(&self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        self.with(|h| h.axis_stop(_data, _slf, time, axis))
+    }
+
+    #[inline]
+    fn axis_discrete(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        discrete: i32,
+    ) {
+        self.with(|h| h.axis_discrete(_data, _slf, axis, discrete))
+    }
+
+    #[inline]
+    fn axis_value120(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        value120: i32,
+    ) {
+        self.with(|h| h.axis_value120(_data, _slf, axis, value120))
+    }
+
+    #[inline]
+    fn axis_relative_direction(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        self.with(|h| h.axis_relative_direction(_data, _slf, axis, direction))
+    }
+}
+
+impl<T, U, H> WlPointerEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlPointerEventHandlerWithData<U>,
+{
+    #[inline]
+    fn enter(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .enter(_data, _slf, serial, surface, surface_x, surface_y)
+    }
+
+    #[inline]
+    fn leave(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler().leave(_data, _slf, serial, surface)
+    }
+
+    #[inline]
+    fn motion(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .motion(_data, _slf, time, surface_x, surface_y)
+    }
+
+    #[inline]
+    fn button(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .button(_data, _slf, serial, time, button, state)
+    }
+
+    #[inline]
+    fn axis(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        axis: WlPointerAxis,
+        value: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler().axis(_data, _slf, time, axis, value)
+    }
+
+    #[inline]
+    fn frame(&self, _data: &mut T, _slf: &WlPointerRef) {
+        let _data = self.project(_data);
+        self.handler().frame(_data, _slf)
+    }
+
+    #[inline]
+    fn axis_source(&self, _data: &mut T, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+        let _data = self.project(_data);
+        self.handler().axis_source(_data, _slf, axis_source)
+    }
+
+    #[inline]
+    fn axis_stop(&self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        let _data = self.project(_data);
+        self.handler().axis_stop(_data, _slf, time, axis)
+    }
+
+    #[inline]
+    fn axis_discrete(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        discrete: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().axis_discrete(_data, _slf, axis, discrete)
+    }
+
+    #[inline]
+    fn axis_value120(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        value120: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().axis_value120(_data, _slf, axis, value120)
+    }
+
+    #[inline]
+    fn axis_relative_direction(
+        &self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .axis_relative_direction(_data, _slf, axis, direction)
+    }
+}
+
+impl<T, U, H> WlPointerEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlPointerEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn enter(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .enter(_data, _slf, serial, surface, surface_x, surface_y)
+    }
+
+    #[inline]
+    fn leave(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        surface: Option<&WlSurfaceRef>,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().leave(_data, _slf, serial, surface)
+    }
+
+    #[inline]
+    fn motion(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        surface_x: Fixed,
+        surface_y: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .motion(_data, _slf, time, surface_x, surface_y)
+    }
+
+    #[inline]
+    fn button(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        serial: u32,
+        time: u32,
+        button: u32,
+        state: WlPointerButtonState,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .button(_data, _slf, serial, time, button, state)
+    }
+
+    #[inline]
+    fn axis(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        time: u32,
+        axis: WlPointerAxis,
+        value: Fixed,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().axis(_data, _slf, time, axis, value)
+    }
+
+    #[inline]
+    fn frame(&mut self, _data: &mut T, _slf: &WlPointerRef) {
+        let _data = self.project(_data);
+        self.handler_mut().frame(_data, _slf)
+    }
+
+    #[inline]
+    fn axis_source(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis_source: WlPointerAxisSource,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().axis_source(_data, _slf, axis_source)
+    }
+
+    #[inline]
+    fn axis_stop(&mut self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+        let _data = self.project(_data);
+        self.handler_mut().axis_stop(_data, _slf, time, axis)
+    }
+
+    #[inline]
+    fn axis_discrete(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        discrete: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .axis_discrete(_data, _slf, axis, discrete)
+    }
+
+    #[inline]
+    fn axis_value120(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        value120: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .axis_value120(_data, _slf, axis, value120)
+    }
+
+    #[inline]
+    fn axis_relative_direction(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlPointerRef,
+        axis: WlPointerAxis,
+        direction: WlPointerAxisRelativeDirection,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .axis_relative_direction(_data, _slf, axis, direction)
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+unsafe impl<H> EventHandler for private::EventHandler<H>
+where
+    H: WlPointerEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlPointerRef>(slf) };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an object
+                let arg1 = unsafe {
+                    if let Some(p) = NonNull::new(args[1].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg1 = arg1.as_ref().map(|arg1| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg1)
+                });
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                // SAFETY: - INTERFACE requires that args[3] contains a fixed
+                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
+                self.0.enter(slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an object
+                let arg1 = unsafe {
+                    if let Some(p) = NonNull::new(args[1].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg1 = arg1.as_ref().map(|arg1| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg1)
+                });
+                self.0.leave(slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                self.0.motion(slf, arg0, arg1, arg2);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { WlPointerButtonState(args[3].u) };
+                self.0.button(slf, arg0, arg1, arg2, arg3);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WlPointerAxis(args[1].u) };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                self.0.axis(slf, arg0, arg1, arg2);
+            }
+            5 => {
+                self.0.frame(slf);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxisSource(args[0].u) };
+                self.0.axis_source(slf, arg0);
+            }
+            7 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WlPointerAxis(args[1].u) };
+                self.0.axis_stop(slf, arg0, arg1);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxis(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.axis_discrete(slf, arg0, arg1);
+            }
+            9 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxis(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.axis_value120(slf, arg0, arg1);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxis(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WlPointerAxisRelativeDirection(args[1].u) };
+                self.0.axis_relative_direction(slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("wl_pointer", opcode);
+            }
+        }
+    }
+}
+
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlPointerEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlPointerRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an object
+                let arg1 = unsafe {
+                    if let Some(p) = NonNull::new(args[1].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg1 = arg1.as_ref().map(|arg1| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg1)
+                });
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                // SAFETY: - INTERFACE requires that args[3] contains a fixed
+                let arg3 = unsafe { Fixed::from_wire(args[3].f) };
+                self.0.enter(data, slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains an object
+                let arg1 = unsafe {
+                    if let Some(p) = NonNull::new(args[1].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlSurface::WL_INTERFACE
+                let arg1 = arg1.as_ref().map(|arg1| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(arg1)
+                });
+                self.0.leave(data, slf, arg0, arg1);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a fixed
+                let arg1 = unsafe { Fixed::from_wire(args[1].f) };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                self.0.motion(data, slf, arg0, arg1, arg2);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { WlPointerButtonState(args[3].u) };
+                self.0.button(data, slf, arg0, arg1, arg2, arg3);
+            }
+            4 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WlPointerAxis(args[1].u) };
+                // SAFETY: - INTERFACE requires that args[2] contains a fixed
+                let arg2 = unsafe { Fixed::from_wire(args[2].f) };
+                self.0.axis(data, slf, arg0, arg1, arg2);
+            }
+            5 => {
+                self.0.frame(data, slf);
+            }
+            6 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxisSource(args[0].u) };
+                self.0.axis_source(data, slf, arg0);
+            }
+            7 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WlPointerAxis(args[1].u) };
+                self.0.axis_stop(data, slf, arg0, arg1);
+            }
+            8 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxis(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.axis_discrete(data, slf, arg0, arg1);
+            }
+            9 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxis(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                self.0.axis_value120(data, slf, arg0, arg1);
+            }
+            10 => {
+                // SAFETY: INTERFACE requires that there are 2 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 2]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlPointerAxis(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { WlPointerAxisRelativeDirection(args[1].u) };
+                self.0.axis_relative_direction(data, slf, arg0, arg1);
+            }
+            _ => {
+                invalid_opcode("wl_pointer", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WlPointerEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlPointerEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WlPointer {
+    /// Since when the error.role enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_ROLE__SINCE: u32 = 1;
+
+    /// Since when the button_state.released enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__BUTTON_STATE_RELEASED__SINCE: u32 = 1;
+    /// Since when the button_state.pressed enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__BUTTON_STATE_PRESSED__SINCE: u32 = 1;
+
+    /// Since when the axis.vertical_scroll enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_VERTICAL_SCROLL__SINCE: u32 = 1;
+    /// Since when the axis.horizontal_scroll enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_HORIZONTAL_SCROLL__SINCE: u32 = 1;
+
+    /// Since when the axis_source.wheel enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_SOURCE_WHEEL__SINCE: u32 = 1;
+    /// Since when the axis_source.finger enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_SOURCE_FINGER__SINCE: u32 = 1;
+    /// Since when the axis_source.continuous enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_SOURCE_CONTINUOUS__SINCE: u32 = 1;
+    /// Since when the axis_source.wheel_tilt enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_SOURCE_WHEEL_TILT__SINCE: u32 = 6;
+
+    /// Since when the axis_relative_direction.identical enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_RELATIVE_DIRECTION_IDENTICAL__SINCE: u32 = 1;
+    /// Since when the axis_relative_direction.inverted enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__AXIS_RELATIVE_DIRECTION_INVERTED__SINCE: u32 = 1;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlPointerError(pub u32);
+
+impl WlPointerError {
+    /// given wl_surface has another role
+    #[allow(dead_code)]
+    pub const ROLE: Self = Self(0);
+}
+
+impl Debug for WlPointerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::ROLE => "ROLE",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// physical button state
+///
+/// Describes the physical state of a button that produced the button
+/// event.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlPointerButtonState(pub u32);
+
+impl WlPointerButtonState {
+    /// the button is not pressed
+    #[allow(dead_code)]
+    pub const RELEASED: Self = Self(0);
+
+    /// the button is pressed
+    #[allow(dead_code)]
+    pub const PRESSED: Self = Self(1);
+}
+
+impl Debug for WlPointerButtonState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::RELEASED => "RELEASED",
+            Self::PRESSED => "PRESSED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// axis types
+///
+/// Describes the axis types of scroll events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlPointerAxis(pub u32);
+
+impl WlPointerAxis {
+    /// vertical axis
+    #[allow(dead_code)]
+    pub const VERTICAL_SCROLL: Self = Self(0);
+
+    /// horizontal axis
+    #[allow(dead_code)]
+    pub const HORIZONTAL_SCROLL: Self = Self(1);
+}
+
+impl Debug for WlPointerAxis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::VERTICAL_SCROLL => "VERTICAL_SCROLL",
+            Self::HORIZONTAL_SCROLL => "HORIZONTAL_SCROLL",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// axis source types
+///
+/// Describes the source types for axis events. This indicates to the
+/// client how an axis event was physically generated; a client may
+/// adjust the user interface accordingly. For example, scroll events
+/// from a "finger" source may be in a smooth coordinate space with
+/// kinetic scrolling whereas a "wheel" source may be in discrete steps
+/// of a number of lines.
+///
+/// The "continuous" axis source is a device generating events in a
+/// continuous coordinate space, but using something other than a
+/// finger. One example for this source is button-based scrolling where
+/// the vertical motion of a device is converted to scroll events while
+/// a button is held down.
+///
+/// The "wheel tilt" axis source indicates that the actual device is a
+/// wheel but the scroll event is not caused by a rotation but a
+/// (usually sideways) tilt of the wheel.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlPointerAxisSource(pub u32);
+
+impl WlPointerAxisSource {
+    /// a physical wheel rotation
+    #[allow(dead_code)]
+    pub const WHEEL: Self = Self(0);
+
+    /// finger on a touch surface
+    #[allow(dead_code)]
+    pub const FINGER: Self = Self(1);
+
+    /// continuous coordinate space
+    #[allow(dead_code)]
+    pub const CONTINUOUS: Self = Self(2);
+
+    /// a physical wheel tilt
+    #[allow(dead_code)]
+    pub const WHEEL_TILT: Self = Self(3);
+}
+
+impl Debug for WlPointerAxisSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::WHEEL => "WHEEL",
+            Self::FINGER => "FINGER",
+            Self::CONTINUOUS => "CONTINUOUS",
+            Self::WHEEL_TILT => "WHEEL_TILT",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// axis relative direction
+///
+/// This specifies the direction of the physical motion that caused a
+/// wl_pointer.axis event, relative to the wl_pointer.axis direction.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlPointerAxisRelativeDirection(pub u32);
+
+impl WlPointerAxisRelativeDirection {
+    /// physical motion matches axis direction
+    #[allow(dead_code)]
+    pub const IDENTICAL: Self = Self(0);
+
+    /// physical motion is the inverse of the axis direction
+    #[allow(dead_code)]
+    pub const INVERTED: Self = Self(1);
+}
+
+impl Debug for WlPointerAxisRelativeDirection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match *self {
+            Self::IDENTICAL => "IDENTICAL",
+            Self::INVERTED => "INVERTED",
+            _ => return Debug::fmt(&self.0, f),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Functional event handlers.
+pub mod event_handlers {
+    use super::*;
+
+    /// Event handler for enter events.
+    pub struct Enter<F>(F);
+    impl<F> WlPointerEventHandler for Enter<F>
+    where
+        F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+    {
+        #[inline]
+        fn enter(
+            &self,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.0(_slf, serial, surface, surface_x, surface_y)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for Enter<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+    {
+        #[inline]
+        fn enter(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.0(_data, _slf, serial, surface, surface_x, surface_y)
+        }
+    }
+
+    /// Event handler for leave events.
+    pub struct Leave<F>(F);
+    impl<F> WlPointerEventHandler for Leave<F>
+    where
+        F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(&self, _slf: &WlPointerRef, serial: u32, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, serial, surface)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for Leave<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, serial, surface)
+        }
+    }
+
+    /// Event handler for motion events.
+    pub struct Motion<F>(F);
+    impl<F> WlPointerEventHandler for Motion<F>
+    where
+        F: Fn(&WlPointerRef, u32, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(&self, _slf: &WlPointerRef, time: u32, surface_x: Fixed, surface_y: Fixed) {
+            self.0(_slf, time, surface_x, surface_y)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for Motion<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, u32, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.0(_data, _slf, time, surface_x, surface_y)
+        }
+    }
+
+    /// Event handler for button events.
+    pub struct Button<F>(F);
+    impl<F> WlPointerEventHandler for Button<F>
+    where
+        F: Fn(&WlPointerRef, u32, u32, u32, WlPointerButtonState),
+    {
+        #[inline]
+        fn button(
+            &self,
+            _slf: &WlPointerRef,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: WlPointerButtonState,
+        ) {
+            self.0(_slf, serial, time, button, state)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for Button<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, u32, u32, u32, WlPointerButtonState),
+    {
+        #[inline]
+        fn button(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: WlPointerButtonState,
+        ) {
+            self.0(_data, _slf, serial, time, button, state)
+        }
+    }
+
+    /// Event handler for axis events.
+    pub struct Axis<F>(F);
+    impl<F> WlPointerEventHandler for Axis<F>
+    where
+        F: Fn(&WlPointerRef, u32, WlPointerAxis, Fixed),
+    {
+        #[inline]
+        fn axis(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis, value: Fixed) {
+            self.0(_slf, time, axis, value)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for Axis<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, u32, WlPointerAxis, Fixed),
+    {
+        #[inline]
+        fn axis(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            axis: WlPointerAxis,
+            value: Fixed,
+        ) {
+            self.0(_data, _slf, time, axis, value)
+        }
+    }
+
+    /// Event handler for frame events.
+    pub struct Frame<F>(F);
+    impl<F> WlPointerEventHandler for Frame<F>
+    where
+        F: Fn(&WlPointerRef),
+    {
+        #[inline]
+        fn frame(&self, _slf: &WlPointerRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for Frame<F>
+    where
+        F: Fn(&mut T, &WlPointerRef),
+    {
+        #[inline]
+        fn frame(&self, _data: &mut T, _slf: &WlPointerRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Event handler for axis_source events.
+    pub struct AxisSource<F>(F);
+    impl<F> WlPointerEventHandler for AxisSource<F>
+    where
+        F: Fn(&WlPointerRef, WlPointerAxisSource),
+    {
+        #[inline]
+        fn axis_source(&self, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+            self.0(_slf, axis_source)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for AxisSource<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, WlPointerAxisSource),
+    {
+        #[inline]
+        fn axis_source(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis_source: WlPointerAxisSource,
+        ) {
+            self.0(_data, _slf, axis_source)
+        }
+    }
+
+    /// Event handler for axis_stop events.
+    pub struct AxisStop<F>(F);
+    impl<F> WlPointerEventHandler for AxisStop<F>
+    where
+        F: Fn(&WlPointerRef, u32, WlPointerAxis),
+    {
+        #[inline]
+        fn axis_stop(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+            self.0(_slf, time, axis)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for AxisStop<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, u32, WlPointerAxis),
+    {
+        #[inline]
+        fn axis_stop(&self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+            self.0(_data, _slf, time, axis)
+        }
+    }
+
+    /// Event handler for axis_discrete events.
+    pub struct AxisDiscrete<F>(F);
+    impl<F> WlPointerEventHandler for AxisDiscrete<F>
+    where
+        F: Fn(&WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_discrete(&self, _slf: &WlPointerRef, axis: WlPointerAxis, discrete: i32) {
+            self.0(_slf, axis, discrete)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for AxisDiscrete<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_discrete(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            discrete: i32,
+        ) {
+            self.0(_data, _slf, axis, discrete)
+        }
+    }
+
+    /// Event handler for axis_value120 events.
+    pub struct AxisValue120<F>(F);
+    impl<F> WlPointerEventHandler for AxisValue120<F>
+    where
+        F: Fn(&WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_value120(&self, _slf: &WlPointerRef, axis: WlPointerAxis, value120: i32) {
+            self.0(_slf, axis, value120)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for AxisValue120<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_value120(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            value120: i32,
+        ) {
+            self.0(_data, _slf, axis, value120)
+        }
+    }
+
+    /// Event handler for axis_relative_direction events.
+    pub struct AxisRelativeDirection<F>(F);
+    impl<F> WlPointerEventHandler for AxisRelativeDirection<F>
+    where
+        F: Fn(&WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+    {
+        #[inline]
+        fn axis_relative_direction(
+            &self,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            direction: WlPointerAxisRelativeDirection,
+        ) {
+            self.0(_slf, axis, direction)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerWithData<T> for AxisRelativeDirection<F>
+    where
+        F: Fn(&mut T, &WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+    {
+        #[inline]
+        fn axis_relative_direction(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            direction: WlPointerAxisRelativeDirection,
+        ) {
+            self.0(_data, _slf, axis, direction)
+        }
+    }
+
+    /// Mutable event handler for enter events.
+    pub struct EnterMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for EnterMut<F>
+    where
+        F: FnMut(&WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+    {
+        #[inline]
+        fn enter(
+            &mut self,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.0(_slf, serial, surface, surface_x, surface_y)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for EnterMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+    {
+        #[inline]
+        fn enter(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.0(_data, _slf, serial, surface, surface_x, surface_y)
+        }
+    }
+
+    /// Mutable event handler for leave events.
+    pub struct LeaveMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for LeaveMut<F>
+    where
+        F: FnMut(&WlPointerRef, u32, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(&mut self, _slf: &WlPointerRef, serial: u32, surface: Option<&WlSurfaceRef>) {
+            self.0(_slf, serial, surface)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for LeaveMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>),
+    {
+        #[inline]
+        fn leave(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.0(_data, _slf, serial, surface)
+        }
+    }
+
+    /// Mutable event handler for motion events.
+    pub struct MotionMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for MotionMut<F>
+    where
+        F: FnMut(&WlPointerRef, u32, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(&mut self, _slf: &WlPointerRef, time: u32, surface_x: Fixed, surface_y: Fixed) {
+            self.0(_slf, time, surface_x, surface_y)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for MotionMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, u32, Fixed, Fixed),
+    {
+        #[inline]
+        fn motion(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.0(_data, _slf, time, surface_x, surface_y)
+        }
+    }
+
+    /// Mutable event handler for button events.
+    pub struct ButtonMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for ButtonMut<F>
+    where
+        F: FnMut(&WlPointerRef, u32, u32, u32, WlPointerButtonState),
+    {
+        #[inline]
+        fn button(
+            &mut self,
+            _slf: &WlPointerRef,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: WlPointerButtonState,
+        ) {
+            self.0(_slf, serial, time, button, state)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for ButtonMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, u32, u32, u32, WlPointerButtonState),
+    {
+        #[inline]
+        fn button(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: WlPointerButtonState,
+        ) {
+            self.0(_data, _slf, serial, time, button, state)
+        }
+    }
+
+    /// Mutable event handler for axis events.
+    pub struct AxisMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for AxisMut<F>
+    where
+        F: FnMut(&WlPointerRef, u32, WlPointerAxis, Fixed),
+    {
+        #[inline]
+        fn axis(&mut self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis, value: Fixed) {
+            self.0(_slf, time, axis, value)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for AxisMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, u32, WlPointerAxis, Fixed),
+    {
+        #[inline]
+        fn axis(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            axis: WlPointerAxis,
+            value: Fixed,
+        ) {
+            self.0(_data, _slf, time, axis, value)
+        }
+    }
+
+    /// Mutable event handler for frame events.
+    pub struct FrameMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for FrameMut<F>
+    where
+        F: FnMut(&WlPointerRef),
+    {
+        #[inline]
+        fn frame(&mut self, _slf: &WlPointerRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for FrameMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef),
+    {
+        #[inline]
+        fn frame(&mut self, _data: &mut T, _slf: &WlPointerRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for axis_source events.
+    pub struct AxisSourceMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for AxisSourceMut<F>
+    where
+        F: FnMut(&WlPointerRef, WlPointerAxisSource),
+    {
+        #[inline]
+        fn axis_source(&mut self, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+            self.0(_slf, axis_source)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for AxisSourceMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, WlPointerAxisSource),
+    {
+        #[inline]
+        fn axis_source(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis_source: WlPointerAxisSource,
+        ) {
+            self.0(_data, _slf, axis_source)
+        }
+    }
+
+    /// Mutable event handler for axis_stop events.
+    pub struct AxisStopMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for AxisStopMut<F>
+    where
+        F: FnMut(&WlPointerRef, u32, WlPointerAxis),
+    {
+        #[inline]
+        fn axis_stop(&mut self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+            self.0(_slf, time, axis)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for AxisStopMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, u32, WlPointerAxis),
+    {
+        #[inline]
+        fn axis_stop(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            axis: WlPointerAxis,
+        ) {
+            self.0(_data, _slf, time, axis)
+        }
+    }
+
+    /// Mutable event handler for axis_discrete events.
+    pub struct AxisDiscreteMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for AxisDiscreteMut<F>
+    where
+        F: FnMut(&WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_discrete(&mut self, _slf: &WlPointerRef, axis: WlPointerAxis, discrete: i32) {
+            self.0(_slf, axis, discrete)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for AxisDiscreteMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_discrete(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            discrete: i32,
+        ) {
+            self.0(_data, _slf, axis, discrete)
+        }
+    }
+
+    /// Mutable event handler for axis_value120 events.
+    pub struct AxisValue120Mut<F>(F);
+    impl<F> WlPointerEventHandlerMut for AxisValue120Mut<F>
+    where
+        F: FnMut(&WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_value120(&mut self, _slf: &WlPointerRef, axis: WlPointerAxis, value120: i32) {
+            self.0(_slf, axis, value120)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for AxisValue120Mut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, WlPointerAxis, i32),
+    {
+        #[inline]
+        fn axis_value120(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            value120: i32,
+        ) {
+            self.0(_data, _slf, axis, value120)
+        }
+    }
+
+    /// Mutable event handler for axis_relative_direction events.
+    pub struct AxisRelativeDirectionMut<F>(F);
+    impl<F> WlPointerEventHandlerMut for AxisRelativeDirectionMut<F>
+    where
+        F: FnMut(&WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+    {
+        #[inline]
+        fn axis_relative_direction(
+            &mut self,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            direction: WlPointerAxisRelativeDirection,
+        ) {
+            self.0(_slf, axis, direction)
+        }
+    }
+    impl<T, F> WlPointerEventHandlerMutWithData<T> for AxisRelativeDirectionMut<F>
+    where
+        F: FnMut(&mut T, &WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+    {
+        #[inline]
+        fn axis_relative_direction(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            direction: WlPointerAxisRelativeDirection,
+        ) {
+            self.0(_data, _slf, axis, direction)
+        }
+    }
+
+    impl WlPointer {
+        /// Creates an event handler for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(f: F) -> Enter<F>
+        where
+            F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(f: F) -> Enter<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+        {
+            Enter(f)
+        }
+
+        /// Creates an event handler for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(f: F) -> Leave<F>
+        where
+            F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(f: F) -> Leave<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>),
+        {
+            Leave(f)
+        }
+
+        /// Creates an event handler for motion events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_motion<F>(f: F) -> Motion<F>
+        where
+            F: Fn(&WlPointerRef, u32, Fixed, Fixed),
+        {
+            Motion(f)
+        }
 
-    /// Since when the button_state.released enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__BUTTON_STATE_RELEASED__SINCE: u32 = 1;
-    /// Since when the button_state.pressed enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__BUTTON_STATE_PRESSED__SINCE: u32 = 1;
+        /// Creates an event handler with mutable data for motion events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_motion_with_data<T, F>(f: F) -> Motion<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, Fixed, Fixed),
+        {
+            Motion(f)
+        }
 
-    /// Since when the axis.vertical_scroll enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_VERTICAL_SCROLL__SINCE: u32 = 1;
-    /// Since when the axis.horizontal_scroll enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_HORIZONTAL_SCROLL__SINCE: u32 = 1;
+        /// Creates an event handler for button events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_button<F>(f: F) -> Button<F>
+        where
+            F: Fn(&WlPointerRef, u32, u32, u32, WlPointerButtonState),
+        {
+            Button(f)
+        }
 
-    /// Since when the axis_source.wheel enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_SOURCE_WHEEL__SINCE: u32 = 1;
-    /// Since when the axis_source.finger enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_SOURCE_FINGER__SINCE: u32 = 1;
-    /// Since when the axis_source.continuous enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_SOURCE_CONTINUOUS__SINCE: u32 = 1;
-    /// Since when the axis_source.wheel_tilt enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_SOURCE_WHEEL_TILT__SINCE: u32 = 6;
+        /// Creates an event handler with mutable data for button events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_button_with_data<T, F>(f: F) -> Button<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, u32, u32, WlPointerButtonState),
+        {
+            Button(f)
+        }
+
+        /// Creates an event handler for axis events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis<F>(f: F) -> Axis<F>
+        where
+            F: Fn(&WlPointerRef, u32, WlPointerAxis, Fixed),
+        {
+            Axis(f)
+        }
+
+        /// Creates an event handler with mutable data for axis events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_with_data<T, F>(f: F) -> Axis<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, WlPointerAxis, Fixed),
+        {
+            Axis(f)
+        }
+
+        /// Creates an event handler for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(f: F) -> Frame<F>
+        where
+            F: Fn(&WlPointerRef),
+        {
+            Frame(f)
+        }
+
+        /// Creates an event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_frame_with_data<T, F>(f: F) -> Frame<F>
+        where
+            F: Fn(&mut T, &WlPointerRef),
+        {
+            Frame(f)
+        }
+
+        /// Creates an event handler for axis_source events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_source<F>(f: F) -> AxisSource<F>
+        where
+            F: Fn(&WlPointerRef, WlPointerAxisSource),
+        {
+            AxisSource(f)
+        }
+
+        /// Creates an event handler with mutable data for axis_source events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_source_with_data<T, F>(f: F) -> AxisSource<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxisSource),
+        {
+            AxisSource(f)
+        }
+
+        /// Creates an event handler for axis_stop events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_stop<F>(f: F) -> AxisStop<F>
+        where
+            F: Fn(&WlPointerRef, u32, WlPointerAxis),
+        {
+            AxisStop(f)
+        }
+
+        /// Creates an event handler with mutable data for axis_stop events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_stop_with_data<T, F>(f: F) -> AxisStop<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, WlPointerAxis),
+        {
+            AxisStop(f)
+        }
+
+        /// Creates an event handler for axis_discrete events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_discrete<F>(f: F) -> AxisDiscrete<F>
+        where
+            F: Fn(&WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisDiscrete(f)
+        }
+
+        /// Creates an event handler with mutable data for axis_discrete events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_discrete_with_data<T, F>(f: F) -> AxisDiscrete<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisDiscrete(f)
+        }
+
+        /// Creates an event handler for axis_value120 events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_value120<F>(f: F) -> AxisValue120<F>
+        where
+            F: Fn(&WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisValue120(f)
+        }
+
+        /// Creates an event handler with mutable data for axis_value120 events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_value120_with_data<T, F>(f: F) -> AxisValue120<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisValue120(f)
+        }
+
+        /// Creates an event handler for axis_relative_direction events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_relative_direction<F>(f: F) -> AxisRelativeDirection<F>
+        where
+            F: Fn(&WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+        {
+            AxisRelativeDirection(f)
+        }
+
+        /// Creates an event handler with mutable data for axis_relative_direction events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_axis_relative_direction_with_data<T, F>(f: F) -> AxisRelativeDirection<F>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+        {
+            AxisRelativeDirection(f)
+        }
+
+        /// Creates a mutable event handler for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut<F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut_with_data<T, F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut<F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&WlPointerRef, u32, Option<&WlSurfaceRef>),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut_with_data<T, F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for motion events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_motion_mut<F>(f: F) -> MotionMut<F>
+        where
+            F: FnMut(&WlPointerRef, u32, Fixed, Fixed),
+        {
+            MotionMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for motion events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_motion_mut_with_data<T, F>(f: F) -> MotionMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, u32, Fixed, Fixed),
+        {
+            MotionMut(f)
+        }
+
+        /// Creates a mutable event handler for button events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_button_mut<F>(f: F) -> ButtonMut<F>
+        where
+            F: FnMut(&WlPointerRef, u32, u32, u32, WlPointerButtonState),
+        {
+            ButtonMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for button events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_button_mut_with_data<T, F>(f: F) -> ButtonMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, u32, u32, u32, WlPointerButtonState),
+        {
+            ButtonMut(f)
+        }
+
+        /// Creates a mutable event handler for axis events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_mut<F>(f: F) -> AxisMut<F>
+        where
+            F: FnMut(&WlPointerRef, u32, WlPointerAxis, Fixed),
+        {
+            AxisMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for axis events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_mut_with_data<T, F>(f: F) -> AxisMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, u32, WlPointerAxis, Fixed),
+        {
+            AxisMut(f)
+        }
+
+        /// Creates a mutable event handler for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut<F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&WlPointerRef),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for frame events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_frame_mut_with_data<T, F>(f: F) -> FrameMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef),
+        {
+            FrameMut(f)
+        }
+
+        /// Creates a mutable event handler for axis_source events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_source_mut<F>(f: F) -> AxisSourceMut<F>
+        where
+            F: FnMut(&WlPointerRef, WlPointerAxisSource),
+        {
+            AxisSourceMut(f)
+        }
 
-    /// Since when the axis_relative_direction.identical enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_RELATIVE_DIRECTION_IDENTICAL__SINCE: u32 = 1;
-    /// Since when the axis_relative_direction.inverted enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__AXIS_RELATIVE_DIRECTION_INVERTED__SINCE: u32 = 1;
-}
+        /// Creates a mutable event handler with mutable data for axis_source events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_source_mut_with_data<T, F>(f: F) -> AxisSourceMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, WlPointerAxisSource),
+        {
+            AxisSourceMut(f)
+        }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlPointerError(pub u32);
+        /// Creates a mutable event handler for axis_stop events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_stop_mut<F>(f: F) -> AxisStopMut<F>
+        where
+            F: FnMut(&WlPointerRef, u32, WlPointerAxis),
+        {
+            AxisStopMut(f)
+        }
 
-impl WlPointerError {
-    /// given wl_surface has another role
-    #[allow(dead_code)]
-    pub const ROLE: Self = Self(0);
-}
+        /// Creates a mutable event handler with mutable data for axis_stop events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_stop_mut_with_data<T, F>(f: F) -> AxisStopMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, u32, WlPointerAxis),
+        {
+            AxisStopMut(f)
+        }
 
-impl Debug for WlPointerError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::ROLE => "ROLE",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        /// Creates a mutable event handler for axis_discrete events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_discrete_mut<F>(f: F) -> AxisDiscreteMut<F>
+        where
+            F: FnMut(&WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisDiscreteMut(f)
+        }
 
-/// physical button state
-///
-/// Describes the physical state of a button that produced the button
-/// event.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlPointerButtonState(pub u32);
+        /// Creates a mutable event handler with mutable data for axis_discrete events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_discrete_mut_with_data<T, F>(f: F) -> AxisDiscreteMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisDiscreteMut(f)
+        }
 
-impl WlPointerButtonState {
-    /// the button is not pressed
-    #[allow(dead_code)]
-    pub const RELEASED: Self = Self(0);
+        /// Creates a mutable event handler for axis_value120 events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_value120_mut<F>(f: F) -> AxisValue120Mut<F>
+        where
+            F: FnMut(&WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisValue120Mut(f)
+        }
 
-    /// the button is pressed
-    #[allow(dead_code)]
-    pub const PRESSED: Self = Self(1);
-}
+        /// Creates a mutable event handler with mutable data for axis_value120 events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_value120_mut_with_data<T, F>(f: F) -> AxisValue120Mut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, WlPointerAxis, i32),
+        {
+            AxisValue120Mut(f)
+        }
 
-impl Debug for WlPointerButtonState {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::RELEASED => "RELEASED",
-            Self::PRESSED => "PRESSED",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        /// Creates a mutable event handler for axis_relative_direction events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_relative_direction_mut<F>(f: F) -> AxisRelativeDirectionMut<F>
+        where
+            F: FnMut(&WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+        {
+            AxisRelativeDirectionMut(f)
+        }
 
-/// axis types
-///
-/// Describes the axis types of scroll events.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlPointerAxis(pub u32);
+        /// Creates a mutable event handler with mutable data for axis_relative_direction events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_axis_relative_direction_mut_with_data<T, F>(f: F) -> AxisRelativeDirectionMut<F>
+        where
+            F: FnMut(&mut T, &WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+        {
+            AxisRelativeDirectionMut(f)
+        }
 
-impl WlPointerAxis {
-    /// vertical axis
-    #[allow(dead_code)]
-    pub const VERTICAL_SCROLL: Self = Self(0);
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                enter: Ignore,
+                leave: Ignore,
+                motion: Ignore,
+                button: Ignore,
+                axis: Ignore,
+                frame: Ignore,
+                axis_source: Ignore,
+                axis_stop: Ignore,
+                axis_discrete: Ignore,
+                axis_value120: Ignore,
+                axis_relative_direction: Ignore,
+            }
+        }
+    }
 
-    /// horizontal axis
-    #[allow(dead_code)]
-    pub const HORIZONTAL_SCROLL: Self = Self(1);
-}
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlPointerEventHandler for Ignore {}
+    impl<T> WlPointerEventHandlerWithData<T> for Ignore {}
 
-impl Debug for WlPointerAxis {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::VERTICAL_SCROLL => "VERTICAL_SCROLL",
-            Self::HORIZONTAL_SCROLL => "HORIZONTAL_SCROLL",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlPointer::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<
+        H0 = Ignore,
+        H1 = Ignore,
+        H2 = Ignore,
+        H3 = Ignore,
+        H4 = Ignore,
+        H5 = Ignore,
+        H6 = Ignore,
+        H7 = Ignore,
+        H8 = Ignore,
+        H9 = Ignore,
+        H10 = Ignore,
+    > {
+        enter: H0,
+        leave: H1,
+        motion: H2,
+        button: H3,
+        axis: H4,
+        frame: H5,
+        axis_source: H6,
+        axis_stop: H7,
+        axis_discrete: H8,
+        axis_value120: H9,
+        axis_relative_direction: H10,
     }
-}
 
-/// axis source types
-///
-/// Describes the source types for axis events. This indicates to the
-/// client how an axis event was physically generated; a client may
-/// adjust the user interface accordingly. For example, scroll events
-/// from a "finger" source may be in a smooth coordinate space with
-/// kinetic scrolling whereas a "wheel" source may be in discrete steps
-/// of a number of lines.
-///
-/// The "continuous" axis source is a device generating events in a
-/// continuous coordinate space, but using something other than a
-/// finger. One example for this source is button-based scrolling where
-/// the vertical motion of a device is converted to scroll events while
-/// a button is held down.
-///
-/// The "wheel tilt" axis source indicates that the actual device is a
-/// wheel but the scroll event is not caused by a rotation but a
-/// (usually sideways) tilt of the wheel.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlPointerAxisSource(pub u32);
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10> WlPointerEventHandler
+        for Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+    where
+        H0: WlPointerEventHandler,
+        H1: WlPointerEventHandler,
+        H2: WlPointerEventHandler,
+        H3: WlPointerEventHandler,
+        H4: WlPointerEventHandler,
+        H5: WlPointerEventHandler,
+        H6: WlPointerEventHandler,
+        H7: WlPointerEventHandler,
+        H8: WlPointerEventHandler,
+        H9: WlPointerEventHandler,
+        H10: WlPointerEventHandler,
+    {
+        #[inline]
+        fn enter(
+            &self,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.enter
+                .enter(_slf, serial, surface, surface_x, surface_y)
+        }
 
-impl WlPointerAxisSource {
-    /// a physical wheel rotation
-    #[allow(dead_code)]
-    pub const WHEEL: Self = Self(0);
+        #[inline]
+        fn leave(&self, _slf: &WlPointerRef, serial: u32, surface: Option<&WlSurfaceRef>) {
+            self.leave.leave(_slf, serial, surface)
+        }
 
-    /// finger on a touch surface
-    #[allow(dead_code)]
-    pub const FINGER: Self = Self(1);
+        #[inline]
+        fn motion(&self, _slf: &WlPointerRef, time: u32, surface_x: Fixed, surface_y: Fixed) {
+            self.motion.motion(_slf, time, surface_x, surface_y)
+        }
 
-    /// continuous coordinate space
-    #[allow(dead_code)]
-    pub const CONTINUOUS: Self = Self(2);
+        #[inline]
+        fn button(
+            &self,
+            _slf: &WlPointerRef,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: WlPointerButtonState,
+        ) {
+            self.button.button(_slf, serial, time, button, state)
+        }
 
-    /// a physical wheel tilt
-    #[allow(dead_code)]
-    pub const WHEEL_TILT: Self = Self(3);
-}
+        #[inline]
+        fn axis(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis, value: Fixed) {
+            self.axis.axis(_slf, time, axis, value)
+        }
 
-impl Debug for WlPointerAxisSource {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::WHEEL => "WHEEL",
-            Self::FINGER => "FINGER",
-            Self::CONTINUOUS => "CONTINUOUS",
-            Self::WHEEL_TILT => "WHEEL_TILT",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        #[inline]
+        fn frame(&self, _slf: &WlPointerRef) {
+            self.frame.frame(_slf)
+        }
 
-/// axis relative direction
-///
-/// This specifies the direction of the physical motion that caused a
-/// wl_pointer.axis event, relative to the wl_pointer.axis direction.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlPointerAxisRelativeDirection(pub u32);
+        #[inline]
+        fn axis_source(&self, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
+            self.axis_source.axis_source(_slf, axis_source)
+        }
 
-impl WlPointerAxisRelativeDirection {
-    /// physical motion matches axis direction
-    #[allow(dead_code)]
-    pub const IDENTICAL: Self = Self(0);
+        #[inline]
+        fn axis_stop(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+            self.axis_stop.axis_stop(_slf, time, axis)
+        }
 
-    /// physical motion is the inverse of the axis direction
-    #[allow(dead_code)]
-    pub const INVERTED: Self = Self(1);
-}
+        #[inline]
+        fn axis_discrete(&self, _slf: &WlPointerRef, axis: WlPointerAxis, discrete: i32) {
+            self.axis_discrete.axis_discrete(_slf, axis, discrete)
+        }
 
-impl Debug for WlPointerAxisRelativeDirection {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let name = match *self {
-            Self::IDENTICAL => "IDENTICAL",
-            Self::INVERTED => "INVERTED",
-            _ => return Debug::fmt(&self.0, f),
-        };
-        f.write_str(name)
-    }
-}
+        #[inline]
+        fn axis_value120(&self, _slf: &WlPointerRef, axis: WlPointerAxis, value120: i32) {
+            self.axis_value120.axis_value120(_slf, axis, value120)
+        }
 
-/// Functional event handlers.
-pub mod event_handlers {
-    use super::*;
+        #[inline]
+        fn axis_relative_direction(
+            &self,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            direction: WlPointerAxisRelativeDirection,
+        ) {
+            self.axis_relative_direction
+                .axis_relative_direction(_slf, axis, direction)
+        }
+    }
 
-    /// Event handler for enter events.
-    pub struct Enter<F>(F);
-    impl<F> WlPointerEventHandler for Enter<F>
+    impl<T, H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10> WlPointerEventHandlerWithData<T>
+        for Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
     where
-        F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+        H0: WlPointerEventHandlerWithData<T>,
+        H1: WlPointerEventHandlerWithData<T>,
+        H2: WlPointerEventHandlerWithData<T>,
+        H3: WlPointerEventHandlerWithData<T>,
+        H4: WlPointerEventHandlerWithData<T>,
+        H5: WlPointerEventHandlerWithData<T>,
+        H6: WlPointerEventHandlerWithData<T>,
+        H7: WlPointerEventHandlerWithData<T>,
+        H8: WlPointerEventHandlerWithData<T>,
+        H9: WlPointerEventHandlerWithData<T>,
+        H10: WlPointerEventHandlerWithData<T>,
     {
         #[inline]
         fn enter(
             &self,
+            _data: &mut T,
             _slf: &WlPointerRef,
             serial: u32,
             surface: Option<&WlSurfaceRef>,
             surface_x: Fixed,
             surface_y: Fixed,
         ) {
-            self.0(_slf, serial, surface, surface_x, surface_y)
+            self.enter
+                .enter(_data, _slf, serial, surface, surface_x, surface_y)
         }
-    }
 
-    /// Event handler for leave events.
-    pub struct Leave<F>(F);
-    impl<F> WlPointerEventHandler for Leave<F>
-    where
-        F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>),
-    {
         #[inline]
-        fn leave(&self, _slf: &WlPointerRef, serial: u32, surface: Option<&WlSurfaceRef>) {
-            self.0(_slf, serial, surface)
+        fn leave(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            serial: u32,
+            surface: Option<&WlSurfaceRef>,
+        ) {
+            self.leave.leave(_data, _slf, serial, surface)
         }
-    }
 
-    /// Event handler for motion events.
-    pub struct Motion<F>(F);
-    impl<F> WlPointerEventHandler for Motion<F>
-    where
-        F: Fn(&WlPointerRef, u32, Fixed, Fixed),
-    {
         #[inline]
-        fn motion(&self, _slf: &WlPointerRef, time: u32, surface_x: Fixed, surface_y: Fixed) {
-            self.0(_slf, time, surface_x, surface_y)
+        fn motion(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            surface_x: Fixed,
+            surface_y: Fixed,
+        ) {
+            self.motion.motion(_data, _slf, time, surface_x, surface_y)
         }
-    }
 
-    /// Event handler for button events.
-    pub struct Button<F>(F);
-    impl<F> WlPointerEventHandler for Button<F>
-    where
-        F: Fn(&WlPointerRef, u32, u32, u32, WlPointerButtonState),
-    {
         #[inline]
         fn button(
             &self,
+            _data: &mut T,
             _slf: &WlPointerRef,
             serial: u32,
             time: u32,
             button: u32,
             state: WlPointerButtonState,
         ) {
-            self.0(_slf, serial, time, button, state)
+            self.button.button(_data, _slf, serial, time, button, state)
         }
-    }
 
-    /// Event handler for axis events.
-    pub struct Axis<F>(F);
-    impl<F> WlPointerEventHandler for Axis<F>
-    where
-        F: Fn(&WlPointerRef, u32, WlPointerAxis, Fixed),
-    {
         #[inline]
-        fn axis(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis, value: Fixed) {
-            self.0(_slf, time, axis, value)
+        fn axis(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            time: u32,
+            axis: WlPointerAxis,
+            value: Fixed,
+        ) {
+            self.axis.axis(_data, _slf, time, axis, value)
         }
-    }
 
-    /// Event handler for frame events.
-    pub struct Frame<F>(F);
-    impl<F> WlPointerEventHandler for Frame<F>
-    where
-        F: Fn(&WlPointerRef),
-    {
         #[inline]
-        fn frame(&self, _slf: &WlPointerRef) {
-            self.0(_slf)
+        fn frame(&self, _data: &mut T, _slf: &WlPointerRef) {
+            self.frame.frame(_data, _slf)
         }
-    }
 
-    /// Event handler for axis_source events.
-    pub struct AxisSource<F>(F);
-    impl<F> WlPointerEventHandler for AxisSource<F>
-    where
-        F: Fn(&WlPointerRef, WlPointerAxisSource),
-    {
         #[inline]
-        fn axis_source(&self, _slf: &WlPointerRef, axis_source: WlPointerAxisSource) {
-            self.0(_slf, axis_source)
+        fn axis_source(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis_source: WlPointerAxisSource,
+        ) {
+            self.axis_source.axis_source(_data, _slf, axis_source)
         }
-    }
 
-    /// Event handler for axis_stop events.
-    pub struct AxisStop<F>(F);
-    impl<F> WlPointerEventHandler for AxisStop<F>
-    where
-        F: Fn(&WlPointerRef, u32, WlPointerAxis),
-    {
         #[inline]
-        fn axis_stop(&self, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
-            self.0(_slf, time, axis)
+        fn axis_stop(&self, _data: &mut T, _slf: &WlPointerRef, time: u32, axis: WlPointerAxis) {
+            self.axis_stop.axis_stop(_data, _slf, time, axis)
         }
-    }
 
-    /// Event handler for axis_discrete events.
-    pub struct AxisDiscrete<F>(F);
-    impl<F> WlPointerEventHandler for AxisDiscrete<F>
-    where
-        F: Fn(&WlPointerRef, WlPointerAxis, i32),
-    {
         #[inline]
-        fn axis_discrete(&self, _slf: &WlPointerRef, axis: WlPointerAxis, discrete: i32) {
-            self.0(_slf, axis, discrete)
+        fn axis_discrete(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            discrete: i32,
+        ) {
+            self.axis_discrete
+                .axis_discrete(_data, _slf, axis, discrete)
         }
-    }
 
-    /// Event handler for axis_value120 events.
-    pub struct AxisValue120<F>(F);
-    impl<F> WlPointerEventHandler for AxisValue120<F>
-    where
-        F: Fn(&WlPointerRef, WlPointerAxis, i32),
-    {
         #[inline]
-        fn axis_value120(&self, _slf: &WlPointerRef, axis: WlPointerAxis, value120: i32) {
-            self.0(_slf, axis, value120)
+        fn axis_value120(
+            &self,
+            _data: &mut T,
+            _slf: &WlPointerRef,
+            axis: WlPointerAxis,
+            value120: i32,
+        ) {
+            self.axis_value120
+                .axis_value120(_data, _slf, axis, value120)
         }
-    }
 
-    /// Event handler for axis_relative_direction events.
-    pub struct AxisRelativeDirection<F>(F);
-    impl<F> WlPointerEventHandler for AxisRelativeDirection<F>
-    where
-        F: Fn(&WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
-    {
         #[inline]
         fn axis_relative_direction(
             &self,
+            _data: &mut T,
             _slf: &WlPointerRef,
             axis: WlPointerAxis,
             direction: WlPointerAxisRelativeDirection,
         ) {
-            self.0(_slf, axis, direction)
+            self.axis_relative_direction
+                .axis_relative_direction(_data, _slf, axis, direction)
         }
     }
 
-    impl WlPointer {
-        /// Creates an event handler for enter events.
-        ///
-        /// The event handler ignores all other events.
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+    {
+        /// Sets the closure for enter events.
         #[allow(dead_code)]
-        pub fn on_enter<F>(f: F) -> Enter<F>
+        pub fn on_enter<F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
         {
-            Enter(f)
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for leave events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for enter events.
         #[allow(dead_code)]
-        pub fn on_leave<F>(f: F) -> Leave<F>
+        pub fn on_enter_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<Enter<F>, H1, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>, Fixed, Fixed),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3, H4, H5, H6, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, u32, Option<&WlSurfaceRef>),
         {
-            Leave(f)
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for motion events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for leave events.
         #[allow(dead_code)]
-        pub fn on_motion<F>(f: F) -> Motion<F>
+        pub fn on_leave_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, Leave<F>, H2, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, Option<&WlSurfaceRef>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for motion events.
+        #[allow(dead_code)]
+        pub fn on_motion<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, Motion<F>, H3, H4, H5, H6, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, u32, Fixed, Fixed),
         {
-            Motion(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: Motion(f),
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for button events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for motion events.
         #[allow(dead_code)]
-        pub fn on_button<F>(f: F) -> Button<F>
+        pub fn on_motion_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, Motion<F>, H3, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, Fixed, Fixed),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: Motion(f),
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for button events.
+        #[allow(dead_code)]
+        pub fn on_button<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, Button<F>, H4, H5, H6, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, u32, u32, u32, WlPointerButtonState),
         {
-            Button(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: Button(f),
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for axis events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for button events.
         #[allow(dead_code)]
-        pub fn on_axis<F>(f: F) -> Axis<F>
+        pub fn on_button_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, Button<F>, H4, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, u32, u32, WlPointerButtonState),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: Button(f),
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for axis events.
+        #[allow(dead_code)]
+        pub fn on_axis<F>(self, f: F) -> Handler<H0, H1, H2, H3, Axis<F>, H5, H6, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, u32, WlPointerAxis, Fixed),
         {
-            Axis(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: Axis(f),
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for frame events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for axis events.
         #[allow(dead_code)]
-        pub fn on_frame<F>(f: F) -> Frame<F>
+        pub fn on_axis_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, Axis<F>, H5, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, WlPointerAxis, Fixed),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: Axis(f),
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for frame events.
+        #[allow(dead_code)]
+        pub fn on_frame<F>(self, f: F) -> Handler<H0, H1, H2, H3, H4, Frame<F>, H6, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef),
         {
-            Frame(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: Frame(f),
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for axis_source events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for frame events.
         #[allow(dead_code)]
-        pub fn on_axis_source<F>(f: F) -> AxisSource<F>
+        pub fn on_frame_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, Frame<F>, H6, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: Frame(f),
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for axis_source events.
+        #[allow(dead_code)]
+        pub fn on_axis_source<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, AxisSource<F>, H7, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, WlPointerAxisSource),
         {
-            AxisSource(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: AxisSource(f),
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for axis_stop events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for axis_source events.
         #[allow(dead_code)]
-        pub fn on_axis_stop<F>(f: F) -> AxisStop<F>
+        pub fn on_axis_source_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, AxisSource<F>, H7, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxisSource),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: AxisSource(f),
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for axis_stop events.
+        #[allow(dead_code)]
+        pub fn on_axis_stop<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, AxisStop<F>, H8, H9, H10>
         where
             F: Fn(&WlPointerRef, u32, WlPointerAxis),
         {
-            AxisStop(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: AxisStop(f),
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for axis_discrete events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for axis_stop events.
         #[allow(dead_code)]
-        pub fn on_axis_discrete<F>(f: F) -> AxisDiscrete<F>
+        pub fn on_axis_stop_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, AxisStop<F>, H8, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, u32, WlPointerAxis),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: AxisStop(f),
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for axis_discrete events.
+        #[allow(dead_code)]
+        pub fn on_axis_discrete<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, AxisDiscrete<F>, H9, H10>
         where
             F: Fn(&WlPointerRef, WlPointerAxis, i32),
         {
-            AxisDiscrete(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: AxisDiscrete(f),
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for axis_value120 events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for axis_discrete events.
         #[allow(dead_code)]
-        pub fn on_axis_value120<F>(f: F) -> AxisValue120<F>
+        pub fn on_axis_discrete_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, AxisDiscrete<F>, H9, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxis, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: AxisDiscrete(f),
+                axis_value120: self.axis_value120,
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for axis_value120 events.
+        #[allow(dead_code)]
+        pub fn on_axis_value120<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, AxisValue120<F>, H10>
         where
             F: Fn(&WlPointerRef, WlPointerAxis, i32),
         {
-            AxisValue120(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: AxisValue120(f),
+                axis_relative_direction: self.axis_relative_direction,
+            }
         }
 
-        /// Creates an event handler for axis_relative_direction events.
-        ///
-        /// The event handler ignores all other events.
+        /// Sets the closure that takes mutable data for axis_value120 events.
         #[allow(dead_code)]
-        pub fn on_axis_relative_direction<F>(f: F) -> AxisRelativeDirection<F>
+        pub fn on_axis_value120_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, AxisValue120<F>, H10>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxis, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: AxisValue120(f),
+                axis_relative_direction: self.axis_relative_direction,
+            }
+        }
+
+        /// Sets the closure for axis_relative_direction events.
+        #[allow(dead_code)]
+        pub fn on_axis_relative_direction<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, AxisRelativeDirection<F>>
         where
             F: Fn(&WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
         {
-            AxisRelativeDirection(f)
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: AxisRelativeDirection(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for axis_relative_direction events.
+        #[allow(dead_code)]
+        pub fn on_axis_relative_direction_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, H3, H4, H5, H6, H7, H8, H9, AxisRelativeDirection<F>>
+        where
+            F: Fn(&mut T, &WlPointerRef, WlPointerAxis, WlPointerAxisRelativeDirection),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                motion: self.motion,
+                button: self.button,
+                axis: self.axis,
+                frame: self.frame,
+                axis_source: self.axis_source,
+                axis_stop: self.axis_stop,
+                axis_discrete: self.axis_discrete,
+                axis_value120: self.axis_value120,
+                axis_relative_direction: AxisRelativeDirection(f),
+            }
         }
     }
 }
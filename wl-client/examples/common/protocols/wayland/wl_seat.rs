@@ -75,6 +75,128 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_seat interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_seat",
+    protocol: "wayland",
+    version: 10,
+    requests: &[
+        info::MessageInfo {
+            name: "get_pointer",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_pointer"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_keyboard",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_keyboard"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_touch",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_touch"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "release",
+            since: 5,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "capabilities",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "capabilities",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("capability"),
+            }],
+        },
+        info::MessageInfo {
+            name: "name",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "capability",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "pointer",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "keyboard",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "touch",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "error",
+            since: 1,
+            bitfield: false,
+            entries: &[info::EntryInfo {
+                name: "missing_capability",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+    ],
+};
+
 /// An owned wl_seat proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -129,11 +251,16 @@ impl Deref for WlSeat {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -439,6 +566,180 @@ pub trait WlSeatEventHandler {
 
 impl WlSeatEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlSeat] proxies with mutable data.
+///
+/// This trait is the same as [WlSeatEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlSeatEventHandlerWithData<T> {
+    /// seat capabilities changed
+    ///
+    /// This is emitted whenever a seat gains or loses the pointer,
+    /// keyboard or touch capabilities.  The argument is a capability
+    /// enum containing the complete set of capabilities this seat has.
+    ///
+    /// When the pointer capability is added, a client may create a
+    /// wl_pointer object using the wl_seat.get_pointer request. This object
+    /// will receive pointer events until the capability is removed in the
+    /// future.
+    ///
+    /// When the pointer capability is removed, a client should destroy the
+    /// wl_pointer objects associated with the seat where the capability was
+    /// removed, using the wl_pointer.release request. No further pointer
+    /// events will be received on these objects.
+    ///
+    /// In some compositors, if a seat regains the pointer capability and a
+    /// client has a previously obtained wl_pointer object of version 4 or
+    /// less, that object may start sending pointer events again. This
+    /// behavior is considered a misinterpretation of the intended behavior
+    /// and must not be relied upon by the client. wl_pointer objects of
+    /// version 5 or later must not send events if created before the most
+    /// recent event notifying the client of an added pointer capability.
+    ///
+    /// The above behavior also applies to wl_keyboard and wl_touch with the
+    /// keyboard and touch capabilities, respectively.
+    ///
+    /// # Arguments
+    ///
+    /// - `capabilities`: capabilities of the seat
+    #[inline]
+    fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _ = capabilities;
+    }
+
+    /// unique identifier for this seat
+    ///
+    /// In a multi-seat configuration the seat name can be used by clients to
+    /// help identify which physical devices the seat represents.
+    ///
+    /// The seat name is a UTF-8 string with no convention defined for its
+    /// contents. Each name is unique among all wl_seat globals. The name is
+    /// only guaranteed to be unique for the current compositor instance.
+    ///
+    /// The same seat names are used for all clients. Thus, the name can be
+    /// shared across processes to refer to a specific wl_seat global.
+    ///
+    /// The name event is sent after binding to the seat global. This event is
+    /// only sent once per seat object, and the name does not change over the
+    /// lifetime of the wl_seat global.
+    ///
+    /// Compositors may re-use the same seat name if the wl_seat global is
+    /// destroyed and re-created later.
+    ///
+    /// # Arguments
+    ///
+    /// - `name`: seat identifier
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+        let _ = name;
+    }
+}
+
+/// A mutable event handler for [WlSeat] proxies.
+///
+/// This trait is the same as [WlSeatEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSeatEventHandlerMut {
+    /// See [WlSeatEventHandler::capabilities].
+    #[inline]
+    fn capabilities(&mut self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _ = capabilities;
+    }
+
+    /// See [WlSeatEventHandler::name].
+    #[inline]
+    fn name(&mut self, _slf: &WlSeatRef, name: &str) {
+        let _ = name;
+    }
+}
+
+/// A mutable event handler for [WlSeat] proxies with mutable data.
+///
+/// This trait is the same as [WlSeatEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSeatEventHandlerMutWithData<T> {
+    /// See [WlSeatEventHandler::capabilities].
+    #[inline]
+    fn capabilities(&mut self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _ = capabilities;
+    }
+
+    /// See [WlSeatEventHandler::name].
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+        let _ = name;
+    }
+}
+
+impl<H> WlSeatEventHandler for MutEventHandler<H>
+where
+    H: WlSeatEventHandlerMut,
+{
+    #[inline]
+    fn capabilities(&self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        self.with(|h| h.capabilities(_slf, capabilities))
+    }
+
+    #[inline]
+    fn name(&self, _slf: &WlSeatRef, name: &str) {
+        self.with(|h| h.name(_slf, name))
+    }
+}
+
+impl<T, H> WlSeatEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlSeatEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        self.with(|h| h.capabilities(_data, _slf, capabilities))
+    }
+
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+        self.with(|h| h.name(_data, _slf, name))
+    }
+}
+
+impl<T, U, H> WlSeatEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlSeatEventHandlerWithData<U>,
+{
+    #[inline]
+    fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _data = self.project(_data);
+        self.handler().capabilities(_data, _slf, capabilities)
+    }
+
+    #[inline]
+    fn name(&self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+        let _data = self.project(_data);
+        self.handler().name(_data, _slf, name)
+    }
+}
+
+impl<T, U, H> WlSeatEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlSeatEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn capabilities(&mut self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _data = self.project(_data);
+        self.handler_mut().capabilities(_data, _slf, capabilities)
+    }
+
+    #[inline]
+    fn name(&mut self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().name(_data, _slf, name)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -480,6 +781,59 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlSeatEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlSeatRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlSeatCapability(args[0].u) };
+                self.0.capabilities(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("wl_seat", "name", args[0].s) };
+                self.0.name(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_seat", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlSeatEventHandler,
@@ -492,6 +846,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlSeatEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlSeat {
     /// Since when the capability.pointer enum variant is available.
     #[allow(dead_code)]
@@ -793,6 +1160,15 @@ pub mod event_handlers {
             self.0(_slf, capabilities)
         }
     }
+    impl<T, F> WlSeatEventHandlerWithData<T> for Capabilities<F>
+    where
+        F: Fn(&mut T, &WlSeatRef, WlSeatCapability),
+    {
+        #[inline]
+        fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
 
     /// Event handler for name events.
     pub struct Name<F>(F);
@@ -805,6 +1181,62 @@ pub mod event_handlers {
             self.0(_slf, name)
         }
     }
+    impl<T, F> WlSeatEventHandlerWithData<T> for Name<F>
+    where
+        F: Fn(&mut T, &WlSeatRef, &str),
+    {
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Mutable event handler for capabilities events.
+    pub struct CapabilitiesMut<F>(F);
+    impl<F> WlSeatEventHandlerMut for CapabilitiesMut<F>
+    where
+        F: FnMut(&WlSeatRef, WlSeatCapability),
+    {
+        #[inline]
+        fn capabilities(&mut self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> WlSeatEventHandlerMutWithData<T> for CapabilitiesMut<F>
+    where
+        F: FnMut(&mut T, &WlSeatRef, WlSeatCapability),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlSeatRef,
+            capabilities: WlSeatCapability,
+        ) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
+    /// Mutable event handler for name events.
+    pub struct NameMut<F>(F);
+    impl<F> WlSeatEventHandlerMut for NameMut<F>
+    where
+        F: FnMut(&WlSeatRef, &str),
+    {
+        #[inline]
+        fn name(&mut self, _slf: &WlSeatRef, name: &str) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> WlSeatEventHandlerMutWithData<T> for NameMut<F>
+    where
+        F: FnMut(&mut T, &WlSeatRef, &str),
+    {
+        #[inline]
+        fn name(&mut self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+            self.0(_data, _slf, name)
+        }
+    }
 
     impl WlSeat {
         /// Creates an event handler for capabilities events.
@@ -818,6 +1250,17 @@ pub mod event_handlers {
             Capabilities(f)
         }
 
+        /// Creates an event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&mut T, &WlSeatRef, WlSeatCapability),
+        {
+            Capabilities(f)
+        }
+
         /// Creates an event handler for name events.
         ///
         /// The event handler ignores all other events.
@@ -828,5 +1271,177 @@ pub mod event_handlers {
         {
             Name(f)
         }
+
+        /// Creates an event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(f: F) -> Name<F>
+        where
+            F: Fn(&mut T, &WlSeatRef, &str),
+        {
+            Name(f)
+        }
+
+        /// Creates a mutable event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut<F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&WlSeatRef, WlSeatCapability),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut_with_data<T, F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&mut T, &WlSeatRef, WlSeatCapability),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut<F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&WlSeatRef, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for name events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_name_mut_with_data<T, F>(f: F) -> NameMut<F>
+        where
+            F: FnMut(&mut T, &WlSeatRef, &str),
+        {
+            NameMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                capabilities: Ignore,
+                name: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlSeatEventHandler for Ignore {}
+    impl<T> WlSeatEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlSeat::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        capabilities: H0,
+        name: H1,
+    }
+
+    impl<H0, H1> WlSeatEventHandler for Handler<H0, H1>
+    where
+        H0: WlSeatEventHandler,
+        H1: WlSeatEventHandler,
+    {
+        #[inline]
+        fn capabilities(&self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.capabilities.capabilities(_slf, capabilities)
+        }
+
+        #[inline]
+        fn name(&self, _slf: &WlSeatRef, name: &str) {
+            self.name.name(_slf, name)
+        }
+    }
+
+    impl<T, H0, H1> WlSeatEventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WlSeatEventHandlerWithData<T>,
+        H1: WlSeatEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.capabilities.capabilities(_data, _slf, capabilities)
+        }
+
+        #[inline]
+        fn name(&self, _data: &mut T, _slf: &WlSeatRef, name: &str) {
+            self.name.name(_data, _slf, name)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(self, f: F) -> Handler<Capabilities<F>, H1>
+        where
+            F: Fn(&WlSeatRef, WlSeatCapability),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+                name: self.name,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(self, f: F) -> Handler<Capabilities<F>, H1>
+        where
+            F: Fn(&mut T, &WlSeatRef, WlSeatCapability),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+                name: self.name,
+            }
+        }
+
+        /// Sets the closure for name events.
+        #[allow(dead_code)]
+        pub fn on_name<F>(self, f: F) -> Handler<H0, Name<F>>
+        where
+            F: Fn(&WlSeatRef, &str),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                name: Name(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for name events.
+        #[allow(dead_code)]
+        pub fn on_name_with_data<T, F>(self, f: F) -> Handler<H0, Name<F>>
+        where
+            F: Fn(&mut T, &WlSeatRef, &str),
+        {
+            Handler {
+                capabilities: self.capabilities,
+                name: Name(f),
+            }
+        }
     }
 }
@@ -34,6 +34,48 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_shell interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_shell",
+    protocol: "wayland",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "get_shell_surface",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[
+            info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_shell_surface"),
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "surface",
+                ty: info::ArgType::Object,
+                interface: Some("wl_surface"),
+                allow_null: false,
+                enum_: None,
+            },
+        ],
+    }],
+    events: &[],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "role",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
 /// An owned wl_shell proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -88,11 +130,16 @@ impl Deref for WlShell {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -196,6 +243,47 @@ pub trait WlShellEventHandler {}
 
 impl WlShellEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlShell] proxies with mutable data.
+///
+/// This trait is the same as [WlShellEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlShellEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlShell] proxies.
+///
+/// This trait is the same as [WlShellEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShellEventHandlerMut {}
+
+/// A mutable event handler for [WlShell] proxies with mutable data.
+///
+/// This trait is the same as [WlShellEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShellEventHandlerMutWithData<T> {}
+
+impl<H> WlShellEventHandler for MutEventHandler<H> where H: WlShellEventHandlerMut {}
+
+impl<T, H> WlShellEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlShellEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WlShellEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlShellEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WlShellEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlShellEventHandlerMutWithData<U>
+{
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -216,6 +304,35 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlShellEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_shell", opcode);
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlShellEventHandler,
@@ -228,6 +345,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlShellEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlShell {
     /// Since when the error.role enum variant is available.
     #[allow(dead_code)]
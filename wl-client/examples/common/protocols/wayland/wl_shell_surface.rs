@@ -147,6 +147,397 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_shell_surface interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_shell_surface",
+    protocol: "wayland",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "pong",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "move",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "resize",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "edges",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("resize"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_toplevel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_transient",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "parent",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "flags",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("transient"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_fullscreen",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "method",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("fullscreen_method"),
+                },
+                info::ArgInfo {
+                    name: "framerate",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "output",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_output"),
+                    allow_null: true,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_popup",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "parent",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "flags",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("transient"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_maximized",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_title",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "title",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_class",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "class_",
+                ty: info::ArgType::String,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "ping",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "configure",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "edges",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("resize"),
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "popup_done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+    ],
+    enums: &[
+        info::EnumInfo {
+            name: "resize",
+            since: 1,
+            bitfield: true,
+            entries: &[
+                info::EntryInfo {
+                    name: "none",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "top",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "left",
+                    value: 4,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "top_left",
+                    value: 5,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom_left",
+                    value: 6,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "right",
+                    value: 8,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "top_right",
+                    value: 9,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "bottom_right",
+                    value: 10,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+        info::EnumInfo {
+            name: "transient",
+            since: 1,
+            bitfield: true,
+            entries: &[info::EntryInfo {
+                name: "inactive",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            }],
+        },
+        info::EnumInfo {
+            name: "fullscreen_method",
+            since: 1,
+            bitfield: false,
+            entries: &[
+                info::EntryInfo {
+                    name: "default",
+                    value: 0,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "scale",
+                    value: 1,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "driver",
+                    value: 2,
+                    since: 1,
+                    deprecated_since: None,
+                },
+                info::EntryInfo {
+                    name: "fill",
+                    value: 3,
+                    since: 1,
+                    deprecated_since: None,
+                },
+            ],
+        },
+    ],
+};
+
 /// An owned wl_shell_surface proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -201,11 +592,16 @@ impl Deref for WlShellSurface {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -678,6 +1074,253 @@ pub trait WlShellSurfaceEventHandler {
 
 impl WlShellSurfaceEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlShellSurface] proxies with mutable data.
+///
+/// This trait is the same as [WlShellSurfaceEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlShellSurfaceEventHandlerWithData<T> {
+    /// ping client
+    ///
+    /// Ping a client to check if it is receiving events and sending
+    /// requests. A client is expected to reply with a pong request.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial number of the ping
+    #[inline]
+    fn ping(&self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+        let _ = serial;
+    }
+
+    /// suggest resize
+    ///
+    /// The configure event asks the client to resize its surface.
+    ///
+    /// The size is a hint, in the sense that the client is free to
+    /// ignore it if it doesn't resize, pick a smaller size (to
+    /// satisfy aspect ratio or resize in steps of NxM pixels).
+    ///
+    /// The edges parameter provides a hint about how the surface
+    /// was resized. The client may use this information to decide
+    /// how to adjust its content to the new size (e.g. a scrolling
+    /// area might adjust its content position to leave the viewable
+    /// content unmoved).
+    ///
+    /// The client is free to dismiss all but the last configure
+    /// event it received.
+    ///
+    /// The width and height arguments specify the size of the window
+    /// in surface-local coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// - `edges`: how the surface was resized
+    /// - `width`: new width of the surface
+    /// - `height`: new height of the surface
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = edges;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// popup interaction is done
+    ///
+    /// The popup_done event is sent out when a popup grab is broken,
+    /// that is, when the user clicks a surface that doesn't belong
+    /// to the client owning the popup surface.
+    #[inline]
+    fn popup_done(&self, _data: &mut T, _slf: &WlShellSurfaceRef) {}
+}
+
+/// A mutable event handler for [WlShellSurface] proxies.
+///
+/// This trait is the same as [WlShellSurfaceEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShellSurfaceEventHandlerMut {
+    /// See [WlShellSurfaceEventHandler::ping].
+    #[inline]
+    fn ping(&mut self, _slf: &WlShellSurfaceRef, serial: u32) {
+        let _ = serial;
+    }
+
+    /// See [WlShellSurfaceEventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = edges;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [WlShellSurfaceEventHandler::popup_done].
+    #[inline]
+    fn popup_done(&mut self, _slf: &WlShellSurfaceRef) {}
+}
+
+/// A mutable event handler for [WlShellSurface] proxies with mutable data.
+///
+/// This trait is the same as [WlShellSurfaceEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlShellSurfaceEventHandlerMutWithData<T> {
+    /// See [WlShellSurfaceEventHandler::ping].
+    #[inline]
+    fn ping(&mut self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+        let _ = serial;
+    }
+
+    /// See [WlShellSurfaceEventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = edges;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [WlShellSurfaceEventHandler::popup_done].
+    #[inline]
+    fn popup_done(&mut self, _data: &mut T, _slf: &WlShellSurfaceRef) {}
+}
+
+impl<H> WlShellSurfaceEventHandler for MutEventHandler<H>
+where
+    H: WlShellSurfaceEventHandlerMut,
+{
+    #[inline]
+    fn ping(&self, _slf: &WlShellSurfaceRef, serial: u32) {
+        self.with(|h| h.ping(_slf, serial))
+    }
+
+    #[inline]
+    fn configure(
+        &self,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        self.with(|h| h.configure(_slf, edges, width, height))
+    }
+
+    #[inline]
+    fn popup_done(&self, _slf: &WlShellSurfaceRef) {
+        self.with(|h| h.popup_done(_slf))
+    }
+}
+
+impl<T, H> WlShellSurfaceEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlShellSurfaceEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn ping(&self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+        self.with(|h| h.ping(_data, _slf, serial))
+    }
+
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        self.with(|h| h.configure(_data, _slf, edges, width, height))
+    }
+
+    #[inline]
+    fn popup_done(&self, _data: &mut T, _slf: &WlShellSurfaceRef) {
+        self.with(|h| h.popup_done(_data, _slf))
+    }
+}
+
+impl<T, U, H> WlShellSurfaceEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlShellSurfaceEventHandlerWithData<U>,
+{
+    #[inline]
+    fn ping(&self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+        let _data = self.project(_data);
+        self.handler().ping(_data, _slf, serial)
+    }
+
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().configure(_data, _slf, edges, width, height)
+    }
+
+    #[inline]
+    fn popup_done(&self, _data: &mut T, _slf: &WlShellSurfaceRef) {
+        let _data = self.project(_data);
+        self.handler().popup_done(_data, _slf)
+    }
+}
+
+impl<T, U, H> WlShellSurfaceEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlShellSurfaceEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn ping(&mut self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().ping(_data, _slf, serial)
+    }
+
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlShellSurfaceRef,
+        edges: WlShellSurfaceResize,
+        width: i32,
+        height: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .configure(_data, _slf, edges, width, height)
+    }
+
+    #[inline]
+    fn popup_done(&mut self, _data: &mut T, _slf: &WlShellSurfaceRef) {
+        let _data = self.project(_data);
+        self.handler_mut().popup_done(_data, _slf)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -725,6 +1368,65 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlShellSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlShellSurfaceRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.ping(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlShellSurfaceResize(args[0].u) };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                self.0.configure(data, slf, arg0, arg1, arg2);
+            }
+            2 => {
+                self.0.popup_done(data, slf);
+            }
+            _ => {
+                invalid_opcode("wl_shell_surface", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlShellSurfaceEventHandler,
@@ -737,6 +1439,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlShellSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlShellSurface {
     /// Since when the resize.none enum variant is available.
     #[allow(dead_code)]
@@ -1378,6 +2093,15 @@ pub mod event_handlers {
             self.0(_slf, serial)
         }
     }
+    impl<T, F> WlShellSurfaceEventHandlerWithData<T> for Ping<F>
+    where
+        F: Fn(&mut T, &WlShellSurfaceRef, u32),
+    {
+        #[inline]
+        fn ping(&self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
 
     /// Event handler for configure events.
     pub struct Configure<F>(F);
@@ -1396,6 +2120,22 @@ pub mod event_handlers {
             self.0(_slf, edges, width, height)
         }
     }
+    impl<T, F> WlShellSurfaceEventHandlerWithData<T> for Configure<F>
+    where
+        F: Fn(&mut T, &WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &WlShellSurfaceRef,
+            edges: WlShellSurfaceResize,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, edges, width, height)
+        }
+    }
 
     /// Event handler for popup_done events.
     pub struct PopupDone<F>(F);
@@ -1408,6 +2148,91 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> WlShellSurfaceEventHandlerWithData<T> for PopupDone<F>
+    where
+        F: Fn(&mut T, &WlShellSurfaceRef),
+    {
+        #[inline]
+        fn popup_done(&self, _data: &mut T, _slf: &WlShellSurfaceRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for ping events.
+    pub struct PingMut<F>(F);
+    impl<F> WlShellSurfaceEventHandlerMut for PingMut<F>
+    where
+        F: FnMut(&WlShellSurfaceRef, u32),
+    {
+        #[inline]
+        fn ping(&mut self, _slf: &WlShellSurfaceRef, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> WlShellSurfaceEventHandlerMutWithData<T> for PingMut<F>
+    where
+        F: FnMut(&mut T, &WlShellSurfaceRef, u32),
+    {
+        #[inline]
+        fn ping(&mut self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Mutable event handler for configure events.
+    pub struct ConfigureMut<F>(F);
+    impl<F> WlShellSurfaceEventHandlerMut for ConfigureMut<F>
+    where
+        F: FnMut(&WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _slf: &WlShellSurfaceRef,
+            edges: WlShellSurfaceResize,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_slf, edges, width, height)
+        }
+    }
+    impl<T, F> WlShellSurfaceEventHandlerMutWithData<T> for ConfigureMut<F>
+    where
+        F: FnMut(&mut T, &WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlShellSurfaceRef,
+            edges: WlShellSurfaceResize,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, edges, width, height)
+        }
+    }
+
+    /// Mutable event handler for popup_done events.
+    pub struct PopupDoneMut<F>(F);
+    impl<F> WlShellSurfaceEventHandlerMut for PopupDoneMut<F>
+    where
+        F: FnMut(&WlShellSurfaceRef),
+    {
+        #[inline]
+        fn popup_done(&mut self, _slf: &WlShellSurfaceRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> WlShellSurfaceEventHandlerMutWithData<T> for PopupDoneMut<F>
+    where
+        F: FnMut(&mut T, &WlShellSurfaceRef),
+    {
+        #[inline]
+        fn popup_done(&mut self, _data: &mut T, _slf: &WlShellSurfaceRef) {
+            self.0(_data, _slf)
+        }
+    }
 
     impl WlShellSurface {
         /// Creates an event handler for ping events.
@@ -1421,6 +2246,17 @@ pub mod event_handlers {
             Ping(f)
         }
 
+        /// Creates an event handler with mutable data for ping events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ping_with_data<T, F>(f: F) -> Ping<F>
+        where
+            F: Fn(&mut T, &WlShellSurfaceRef, u32),
+        {
+            Ping(f)
+        }
+
         /// Creates an event handler for configure events.
         ///
         /// The event handler ignores all other events.
@@ -1432,6 +2268,17 @@ pub mod event_handlers {
             Configure(f)
         }
 
+        /// Creates an event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(f: F) -> Configure<F>
+        where
+            F: Fn(&mut T, &WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+        {
+            Configure(f)
+        }
+
         /// Creates an event handler for popup_done events.
         ///
         /// The event handler ignores all other events.
@@ -1442,5 +2289,258 @@ pub mod event_handlers {
         {
             PopupDone(f)
         }
+
+        /// Creates an event handler with mutable data for popup_done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_popup_done_with_data<T, F>(f: F) -> PopupDone<F>
+        where
+            F: Fn(&mut T, &WlShellSurfaceRef),
+        {
+            PopupDone(f)
+        }
+
+        /// Creates a mutable event handler for ping events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ping_mut<F>(f: F) -> PingMut<F>
+        where
+            F: FnMut(&WlShellSurfaceRef, u32),
+        {
+            PingMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ping events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ping_mut_with_data<T, F>(f: F) -> PingMut<F>
+        where
+            F: FnMut(&mut T, &WlShellSurfaceRef, u32),
+        {
+            PingMut(f)
+        }
+
+        /// Creates a mutable event handler for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut<F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut_with_data<T, F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&mut T, &WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler for popup_done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_popup_done_mut<F>(f: F) -> PopupDoneMut<F>
+        where
+            F: FnMut(&WlShellSurfaceRef),
+        {
+            PopupDoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for popup_done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_popup_done_mut_with_data<T, F>(f: F) -> PopupDoneMut<F>
+        where
+            F: FnMut(&mut T, &WlShellSurfaceRef),
+        {
+            PopupDoneMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                ping: Ignore,
+                configure: Ignore,
+                popup_done: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlShellSurfaceEventHandler for Ignore {}
+    impl<T> WlShellSurfaceEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlShellSurface::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        ping: H0,
+        configure: H1,
+        popup_done: H2,
+    }
+
+    impl<H0, H1, H2> WlShellSurfaceEventHandler for Handler<H0, H1, H2>
+    where
+        H0: WlShellSurfaceEventHandler,
+        H1: WlShellSurfaceEventHandler,
+        H2: WlShellSurfaceEventHandler,
+    {
+        #[inline]
+        fn ping(&self, _slf: &WlShellSurfaceRef, serial: u32) {
+            self.ping.ping(_slf, serial)
+        }
+
+        #[inline]
+        fn configure(
+            &self,
+            _slf: &WlShellSurfaceRef,
+            edges: WlShellSurfaceResize,
+            width: i32,
+            height: i32,
+        ) {
+            self.configure.configure(_slf, edges, width, height)
+        }
+
+        #[inline]
+        fn popup_done(&self, _slf: &WlShellSurfaceRef) {
+            self.popup_done.popup_done(_slf)
+        }
+    }
+
+    impl<T, H0, H1, H2> WlShellSurfaceEventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: WlShellSurfaceEventHandlerWithData<T>,
+        H1: WlShellSurfaceEventHandlerWithData<T>,
+        H2: WlShellSurfaceEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn ping(&self, _data: &mut T, _slf: &WlShellSurfaceRef, serial: u32) {
+            self.ping.ping(_data, _slf, serial)
+        }
+
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &WlShellSurfaceRef,
+            edges: WlShellSurfaceResize,
+            width: i32,
+            height: i32,
+        ) {
+            self.configure.configure(_data, _slf, edges, width, height)
+        }
+
+        #[inline]
+        fn popup_done(&self, _data: &mut T, _slf: &WlShellSurfaceRef) {
+            self.popup_done.popup_done(_data, _slf)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for ping events.
+        #[allow(dead_code)]
+        pub fn on_ping<F>(self, f: F) -> Handler<Ping<F>, H1, H2>
+        where
+            F: Fn(&WlShellSurfaceRef, u32),
+        {
+            Handler {
+                ping: Ping(f),
+                configure: self.configure,
+                popup_done: self.popup_done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for ping events.
+        #[allow(dead_code)]
+        pub fn on_ping_with_data<T, F>(self, f: F) -> Handler<Ping<F>, H1, H2>
+        where
+            F: Fn(&mut T, &WlShellSurfaceRef, u32),
+        {
+            Handler {
+                ping: Ping(f),
+                configure: self.configure,
+                popup_done: self.popup_done,
+            }
+        }
+
+        /// Sets the closure for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(self, f: F) -> Handler<H0, Configure<F>, H2>
+        where
+            F: Fn(&WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+        {
+            Handler {
+                ping: self.ping,
+                configure: Configure(f),
+                popup_done: self.popup_done,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(self, f: F) -> Handler<H0, Configure<F>, H2>
+        where
+            F: Fn(&mut T, &WlShellSurfaceRef, WlShellSurfaceResize, i32, i32),
+        {
+            Handler {
+                ping: self.ping,
+                configure: Configure(f),
+                popup_done: self.popup_done,
+            }
+        }
+
+        /// Sets the closure for popup_done events.
+        #[allow(dead_code)]
+        pub fn on_popup_done<F>(self, f: F) -> Handler<H0, H1, PopupDone<F>>
+        where
+            F: Fn(&WlShellSurfaceRef),
+        {
+            Handler {
+                ping: self.ping,
+                configure: self.configure,
+                popup_done: PopupDone(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for popup_done events.
+        #[allow(dead_code)]
+        pub fn on_popup_done_with_data<T, F>(self, f: F) -> Handler<H0, H1, PopupDone<F>>
+        where
+            F: Fn(&mut T, &WlShellSurfaceRef),
+        {
+            Handler {
+                ping: self.ping,
+                configure: self.configure,
+                popup_done: PopupDone(f),
+            }
+        }
     }
 }
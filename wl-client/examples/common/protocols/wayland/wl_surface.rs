@@ -188,6 +188,309 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_surface interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_surface",
+    protocol: "wayland",
+    version: 6,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "attach",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "buffer",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_buffer"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "damage",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "frame",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "callback",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_callback"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_opaque_region",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "region",
+                ty: info::ArgType::Object,
+                interface: Some("wl_region"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "set_input_region",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "region",
+                ty: info::ArgType::Object,
+                interface: Some("wl_region"),
+                allow_null: true,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "commit",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "set_buffer_transform",
+            since: 2,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "transform",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_output.transform"),
+            }],
+        },
+        info::MessageInfo {
+            name: "set_buffer_scale",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "scale",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "damage_buffer",
+            since: 4,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "offset",
+            since: 5,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "enter",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "leave",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "output",
+                ty: info::ArgType::Object,
+                interface: Some("wl_output"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "preferred_buffer_scale",
+            since: 6,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "factor",
+                ty: info::ArgType::Int,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "preferred_buffer_transform",
+            since: 6,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "transform",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: Some("wl_output.transform"),
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "invalid_scale",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_transform",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_size",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_offset",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "defunct_role_object",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wl_surface proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -242,11 +545,16 @@ impl Deref for WlSurface {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -957,6 +1265,299 @@ pub trait WlSurfaceEventHandler {
 
 impl WlSurfaceEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlSurface] proxies with mutable data.
+///
+/// This trait is the same as [WlSurfaceEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlSurfaceEventHandlerWithData<T> {
+    /// surface enters an output
+    ///
+    /// This is emitted whenever a surface's creation, movement, or resizing
+    /// results in some part of it being within the scanout region of an
+    /// output.
+    ///
+    /// Note that a surface may be overlapping with zero or more outputs.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`: output entered by the surface
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// surface leaves an output
+    ///
+    /// This is emitted whenever a surface's creation, movement, or resizing
+    /// results in it no longer having any part of it within the scanout region
+    /// of an output.
+    ///
+    /// Clients should not use the number of outputs the surface is on for frame
+    /// throttling purposes. The surface might be hidden even if no leave event
+    /// has been sent, and the compositor might expect new surface content
+    /// updates even if no enter event has been sent. The frame event should be
+    /// used instead.
+    ///
+    /// # Arguments
+    ///
+    /// - `output`: output left by the surface
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// preferred buffer scale for the surface
+    ///
+    /// This event indicates the preferred buffer scale for this surface. It is
+    /// sent whenever the compositor's preference changes.
+    ///
+    /// Before receiving this event the preferred buffer scale for this surface
+    /// is 1.
+    ///
+    /// It is intended that scaling aware clients use this event to scale their
+    /// content and use wl_surface.set_buffer_scale to indicate the scale they
+    /// have rendered with. This allows clients to supply a higher detail
+    /// buffer.
+    ///
+    /// The compositor shall emit a scale value greater than 0.
+    ///
+    /// # Arguments
+    ///
+    /// - `factor`: preferred scaling factor
+    #[inline]
+    fn preferred_buffer_scale(&self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+        let _ = factor;
+    }
+
+    /// preferred buffer transform for the surface
+    ///
+    /// This event indicates the preferred buffer transform for this surface.
+    /// It is sent whenever the compositor's preference changes.
+    ///
+    /// Before receiving this event the preferred buffer transform for this
+    /// surface is normal.
+    ///
+    /// Applying this transformation to the surface buffer contents and using
+    /// wl_surface.set_buffer_transform might allow the compositor to use the
+    /// surface buffer more efficiently.
+    ///
+    /// # Arguments
+    ///
+    /// - `transform`: preferred transform
+    #[inline]
+    fn preferred_buffer_transform(
+        &self,
+        _data: &mut T,
+        _slf: &WlSurfaceRef,
+        transform: WlOutputTransform,
+    ) {
+        let _ = transform;
+    }
+}
+
+/// A mutable event handler for [WlSurface] proxies.
+///
+/// This trait is the same as [WlSurfaceEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSurfaceEventHandlerMut {
+    /// See [WlSurfaceEventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [WlSurfaceEventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [WlSurfaceEventHandler::preferred_buffer_scale].
+    #[inline]
+    fn preferred_buffer_scale(&mut self, _slf: &WlSurfaceRef, factor: i32) {
+        let _ = factor;
+    }
+
+    /// See [WlSurfaceEventHandler::preferred_buffer_transform].
+    #[inline]
+    fn preferred_buffer_transform(&mut self, _slf: &WlSurfaceRef, transform: WlOutputTransform) {
+        let _ = transform;
+    }
+}
+
+/// A mutable event handler for [WlSurface] proxies with mutable data.
+///
+/// This trait is the same as [WlSurfaceEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSurfaceEventHandlerMutWithData<T> {
+    /// See [WlSurfaceEventHandler::enter].
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [WlSurfaceEventHandler::leave].
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _ = output;
+    }
+
+    /// See [WlSurfaceEventHandler::preferred_buffer_scale].
+    #[inline]
+    fn preferred_buffer_scale(&mut self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+        let _ = factor;
+    }
+
+    /// See [WlSurfaceEventHandler::preferred_buffer_transform].
+    #[inline]
+    fn preferred_buffer_transform(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlSurfaceRef,
+        transform: WlOutputTransform,
+    ) {
+        let _ = transform;
+    }
+}
+
+impl<H> WlSurfaceEventHandler for MutEventHandler<H>
+where
+    H: WlSurfaceEventHandlerMut,
+{
+    #[inline]
+    fn enter(&self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        self.with(|h| h.enter(_slf, output))
+    }
+
+    #[inline]
+    fn leave(&self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        self.with(|h| h.leave(_slf, output))
+    }
+
+    #[inline]
+    fn preferred_buffer_scale(&self, _slf: &WlSurfaceRef, factor: i32) {
+        self.with(|h| h.preferred_buffer_scale(_slf, factor))
+    }
+
+    #[inline]
+    fn preferred_buffer_transform(&self, _slf: &WlSurfaceRef, transform: WlOutputTransform) {
+        self.with(|h| h.preferred_buffer_transform(_slf, transform))
+    }
+}
+
+impl<T, H> WlSurfaceEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlSurfaceEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        self.with(|h| h.enter(_data, _slf, output))
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        self.with(|h| h.leave(_data, _slf, output))
+    }
+
+    #[inline]
+    fn preferred_buffer_scale(&self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+        self.with(|h| h.preferred_buffer_scale(_data, _slf, factor))
+    }
+
+    #[inline]
+    fn preferred_buffer_transform(
+        &self,
+        _data: &mut T,
+        _slf: &WlSurfaceRef,
+        transform: WlOutputTransform,
+    ) {
+        self.with(|h| h.preferred_buffer_transform(_data, _slf, transform))
+    }
+}
+
+impl<T, U, H> WlSurfaceEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlSurfaceEventHandlerWithData<U>,
+{
+    #[inline]
+    fn enter(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _data = self.project(_data);
+        self.handler().enter(_data, _slf, output)
+    }
+
+    #[inline]
+    fn leave(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _data = self.project(_data);
+        self.handler().leave(_data, _slf, output)
+    }
+
+    #[inline]
+    fn preferred_buffer_scale(&self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+        let _data = self.project(_data);
+        self.handler().preferred_buffer_scale(_data, _slf, factor)
+    }
+
+    #[inline]
+    fn preferred_buffer_transform(
+        &self,
+        _data: &mut T,
+        _slf: &WlSurfaceRef,
+        transform: WlOutputTransform,
+    ) {
+        let _data = self.project(_data);
+        self.handler()
+            .preferred_buffer_transform(_data, _slf, transform)
+    }
+}
+
+impl<T, U, H> WlSurfaceEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlSurfaceEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn enter(&mut self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _data = self.project(_data);
+        self.handler_mut().enter(_data, _slf, output)
+    }
+
+    #[inline]
+    fn leave(&mut self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+        let _data = self.project(_data);
+        self.handler_mut().leave(_data, _slf, output)
+    }
+
+    #[inline]
+    fn preferred_buffer_scale(&mut self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .preferred_buffer_scale(_data, _slf, factor)
+    }
+
+    #[inline]
+    fn preferred_buffer_transform(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlSurfaceRef,
+        transform: WlOutputTransform,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .preferred_buffer_transform(_data, _slf, transform)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -1031,47 +1632,146 @@ where
     }
 }
 
-impl<H> CreateEventHandler<H> for private::ProxyApi
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
 where
-    H: WlSurfaceEventHandler,
+    H: WlSurfaceEventHandlerWithData<T>,
+    T: 'static,
 {
-    type EventHandler = private::EventHandler<H>;
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
     #[inline]
-    fn create_event_handler(handler: H) -> Self::EventHandler {
-        private::EventHandler(handler)
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
     }
-}
-
-impl WlSurface {
-    /// Since when the error.invalid_scale enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_INVALID_SCALE__SINCE: u32 = 1;
-    /// Since when the error.invalid_transform enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_INVALID_TRANSFORM__SINCE: u32 = 1;
-    /// Since when the error.invalid_size enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_INVALID_SIZE__SINCE: u32 = 1;
-    /// Since when the error.invalid_offset enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_INVALID_OFFSET__SINCE: u32 = 1;
-    /// Since when the error.defunct_role_object enum variant is available.
-    #[allow(dead_code)]
-    pub const ENM__ERROR_DEFUNCT_ROLE_OBJECT__SINCE: u32 = 1;
-}
-
-/// wl_surface error values
-///
-/// These errors can be emitted in response to wl_surface requests.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[allow(dead_code)]
-pub struct WlSurfaceError(pub u32);
 
-impl WlSurfaceError {
-    /// buffer scale value is invalid
-    #[allow(dead_code)]
-    pub const INVALID_SCALE: Self = Self(0);
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlSurfaceRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.enter(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlOutput::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlOutputRef>(arg0)
+                });
+                self.0.leave(data, slf, arg0);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                self.0.preferred_buffer_scale(data, slf, arg0);
+            }
+            3 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlOutputTransform(args[0].u) };
+                self.0.preferred_buffer_transform(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_surface", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WlSurfaceEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
+impl WlSurface {
+    /// Since when the error.invalid_scale enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SCALE__SINCE: u32 = 1;
+    /// Since when the error.invalid_transform enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_TRANSFORM__SINCE: u32 = 1;
+    /// Since when the error.invalid_size enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_SIZE__SINCE: u32 = 1;
+    /// Since when the error.invalid_offset enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_INVALID_OFFSET__SINCE: u32 = 1;
+    /// Since when the error.defunct_role_object enum variant is available.
+    #[allow(dead_code)]
+    pub const ENM__ERROR_DEFUNCT_ROLE_OBJECT__SINCE: u32 = 1;
+}
+
+/// wl_surface error values
+///
+/// These errors can be emitted in response to wl_surface requests.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(dead_code)]
+pub struct WlSurfaceError(pub u32);
+
+impl WlSurfaceError {
+    /// buffer scale value is invalid
+    #[allow(dead_code)]
+    pub const INVALID_SCALE: Self = Self(0);
 
     /// buffer transform value is invalid
     #[allow(dead_code)]
@@ -1119,6 +1819,15 @@ pub mod event_handlers {
             self.0(_slf, output)
         }
     }
+    impl<T, F> WlSurfaceEventHandlerWithData<T> for Enter<F>
+    where
+        F: Fn(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.0(_data, _slf, output)
+        }
+    }
 
     /// Event handler for leave events.
     pub struct Leave<F>(F);
@@ -1131,6 +1840,15 @@ pub mod event_handlers {
             self.0(_slf, output)
         }
     }
+    impl<T, F> WlSurfaceEventHandlerWithData<T> for Leave<F>
+    where
+        F: Fn(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.0(_data, _slf, output)
+        }
+    }
 
     /// Event handler for preferred_buffer_scale events.
     pub struct PreferredBufferScale<F>(F);
@@ -1143,6 +1861,15 @@ pub mod event_handlers {
             self.0(_slf, factor)
         }
     }
+    impl<T, F> WlSurfaceEventHandlerWithData<T> for PreferredBufferScale<F>
+    where
+        F: Fn(&mut T, &WlSurfaceRef, i32),
+    {
+        #[inline]
+        fn preferred_buffer_scale(&self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+            self.0(_data, _slf, factor)
+        }
+    }
 
     /// Event handler for preferred_buffer_transform events.
     pub struct PreferredBufferTransform<F>(F);
@@ -1155,6 +1882,113 @@ pub mod event_handlers {
             self.0(_slf, transform)
         }
     }
+    impl<T, F> WlSurfaceEventHandlerWithData<T> for PreferredBufferTransform<F>
+    where
+        F: Fn(&mut T, &WlSurfaceRef, WlOutputTransform),
+    {
+        #[inline]
+        fn preferred_buffer_transform(
+            &self,
+            _data: &mut T,
+            _slf: &WlSurfaceRef,
+            transform: WlOutputTransform,
+        ) {
+            self.0(_data, _slf, transform)
+        }
+    }
+
+    /// Mutable event handler for enter events.
+    pub struct EnterMut<F>(F);
+    impl<F> WlSurfaceEventHandlerMut for EnterMut<F>
+    where
+        F: FnMut(&WlSurfaceRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn enter(&mut self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> WlSurfaceEventHandlerMutWithData<T> for EnterMut<F>
+    where
+        F: FnMut(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn enter(&mut self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Mutable event handler for leave events.
+    pub struct LeaveMut<F>(F);
+    impl<F> WlSurfaceEventHandlerMut for LeaveMut<F>
+    where
+        F: FnMut(&WlSurfaceRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn leave(&mut self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.0(_slf, output)
+        }
+    }
+    impl<T, F> WlSurfaceEventHandlerMutWithData<T> for LeaveMut<F>
+    where
+        F: FnMut(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+    {
+        #[inline]
+        fn leave(&mut self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.0(_data, _slf, output)
+        }
+    }
+
+    /// Mutable event handler for preferred_buffer_scale events.
+    pub struct PreferredBufferScaleMut<F>(F);
+    impl<F> WlSurfaceEventHandlerMut for PreferredBufferScaleMut<F>
+    where
+        F: FnMut(&WlSurfaceRef, i32),
+    {
+        #[inline]
+        fn preferred_buffer_scale(&mut self, _slf: &WlSurfaceRef, factor: i32) {
+            self.0(_slf, factor)
+        }
+    }
+    impl<T, F> WlSurfaceEventHandlerMutWithData<T> for PreferredBufferScaleMut<F>
+    where
+        F: FnMut(&mut T, &WlSurfaceRef, i32),
+    {
+        #[inline]
+        fn preferred_buffer_scale(&mut self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+            self.0(_data, _slf, factor)
+        }
+    }
+
+    /// Mutable event handler for preferred_buffer_transform events.
+    pub struct PreferredBufferTransformMut<F>(F);
+    impl<F> WlSurfaceEventHandlerMut for PreferredBufferTransformMut<F>
+    where
+        F: FnMut(&WlSurfaceRef, WlOutputTransform),
+    {
+        #[inline]
+        fn preferred_buffer_transform(
+            &mut self,
+            _slf: &WlSurfaceRef,
+            transform: WlOutputTransform,
+        ) {
+            self.0(_slf, transform)
+        }
+    }
+    impl<T, F> WlSurfaceEventHandlerMutWithData<T> for PreferredBufferTransformMut<F>
+    where
+        F: FnMut(&mut T, &WlSurfaceRef, WlOutputTransform),
+    {
+        #[inline]
+        fn preferred_buffer_transform(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlSurfaceRef,
+            transform: WlOutputTransform,
+        ) {
+            self.0(_data, _slf, transform)
+        }
+    }
 
     impl WlSurface {
         /// Creates an event handler for enter events.
@@ -1168,6 +2002,17 @@ pub mod event_handlers {
             Enter(f)
         }
 
+        /// Creates an event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(f: F) -> Enter<F>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            Enter(f)
+        }
+
         /// Creates an event handler for leave events.
         ///
         /// The event handler ignores all other events.
@@ -1179,6 +2024,17 @@ pub mod event_handlers {
             Leave(f)
         }
 
+        /// Creates an event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(f: F) -> Leave<F>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            Leave(f)
+        }
+
         /// Creates an event handler for preferred_buffer_scale events.
         ///
         /// The event handler ignores all other events.
@@ -1190,6 +2046,17 @@ pub mod event_handlers {
             PreferredBufferScale(f)
         }
 
+        /// Creates an event handler with mutable data for preferred_buffer_scale events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_scale_with_data<T, F>(f: F) -> PreferredBufferScale<F>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, i32),
+        {
+            PreferredBufferScale(f)
+        }
+
         /// Creates an event handler for preferred_buffer_transform events.
         ///
         /// The event handler ignores all other events.
@@ -1200,5 +2067,340 @@ pub mod event_handlers {
         {
             PreferredBufferTransform(f)
         }
+
+        /// Creates an event handler with mutable data for preferred_buffer_transform events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_transform_with_data<T, F>(f: F) -> PreferredBufferTransform<F>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, WlOutputTransform),
+        {
+            PreferredBufferTransform(f)
+        }
+
+        /// Creates a mutable event handler for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut<F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for enter events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_enter_mut_with_data<T, F>(f: F) -> EnterMut<F>
+        where
+            F: FnMut(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            EnterMut(f)
+        }
+
+        /// Creates a mutable event handler for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut<F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for leave events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_leave_mut_with_data<T, F>(f: F) -> LeaveMut<F>
+        where
+            F: FnMut(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            LeaveMut(f)
+        }
+
+        /// Creates a mutable event handler for preferred_buffer_scale events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_scale_mut<F>(f: F) -> PreferredBufferScaleMut<F>
+        where
+            F: FnMut(&WlSurfaceRef, i32),
+        {
+            PreferredBufferScaleMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preferred_buffer_scale events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_scale_mut_with_data<T, F>(f: F) -> PreferredBufferScaleMut<F>
+        where
+            F: FnMut(&mut T, &WlSurfaceRef, i32),
+        {
+            PreferredBufferScaleMut(f)
+        }
+
+        /// Creates a mutable event handler for preferred_buffer_transform events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_transform_mut<F>(f: F) -> PreferredBufferTransformMut<F>
+        where
+            F: FnMut(&WlSurfaceRef, WlOutputTransform),
+        {
+            PreferredBufferTransformMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for preferred_buffer_transform events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_transform_mut_with_data<T, F>(
+            f: F,
+        ) -> PreferredBufferTransformMut<F>
+        where
+            F: FnMut(&mut T, &WlSurfaceRef, WlOutputTransform),
+        {
+            PreferredBufferTransformMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                enter: Ignore,
+                leave: Ignore,
+                preferred_buffer_scale: Ignore,
+                preferred_buffer_transform: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlSurfaceEventHandler for Ignore {}
+    impl<T> WlSurfaceEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlSurface::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore, H3 = Ignore> {
+        enter: H0,
+        leave: H1,
+        preferred_buffer_scale: H2,
+        preferred_buffer_transform: H3,
+    }
+
+    impl<H0, H1, H2, H3> WlSurfaceEventHandler for Handler<H0, H1, H2, H3>
+    where
+        H0: WlSurfaceEventHandler,
+        H1: WlSurfaceEventHandler,
+        H2: WlSurfaceEventHandler,
+        H3: WlSurfaceEventHandler,
+    {
+        #[inline]
+        fn enter(&self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.enter.enter(_slf, output)
+        }
+
+        #[inline]
+        fn leave(&self, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.leave.leave(_slf, output)
+        }
+
+        #[inline]
+        fn preferred_buffer_scale(&self, _slf: &WlSurfaceRef, factor: i32) {
+            self.preferred_buffer_scale
+                .preferred_buffer_scale(_slf, factor)
+        }
+
+        #[inline]
+        fn preferred_buffer_transform(&self, _slf: &WlSurfaceRef, transform: WlOutputTransform) {
+            self.preferred_buffer_transform
+                .preferred_buffer_transform(_slf, transform)
+        }
+    }
+
+    impl<T, H0, H1, H2, H3> WlSurfaceEventHandlerWithData<T> for Handler<H0, H1, H2, H3>
+    where
+        H0: WlSurfaceEventHandlerWithData<T>,
+        H1: WlSurfaceEventHandlerWithData<T>,
+        H2: WlSurfaceEventHandlerWithData<T>,
+        H3: WlSurfaceEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn enter(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.enter.enter(_data, _slf, output)
+        }
+
+        #[inline]
+        fn leave(&self, _data: &mut T, _slf: &WlSurfaceRef, output: Option<&WlOutputRef>) {
+            self.leave.leave(_data, _slf, output)
+        }
+
+        #[inline]
+        fn preferred_buffer_scale(&self, _data: &mut T, _slf: &WlSurfaceRef, factor: i32) {
+            self.preferred_buffer_scale
+                .preferred_buffer_scale(_data, _slf, factor)
+        }
+
+        #[inline]
+        fn preferred_buffer_transform(
+            &self,
+            _data: &mut T,
+            _slf: &WlSurfaceRef,
+            transform: WlOutputTransform,
+        ) {
+            self.preferred_buffer_transform
+                .preferred_buffer_transform(_data, _slf, transform)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2, H3> Handler<H0, H1, H2, H3> {
+        /// Sets the closure for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter<F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3>
+        where
+            F: Fn(&WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                preferred_buffer_scale: self.preferred_buffer_scale,
+                preferred_buffer_transform: self.preferred_buffer_transform,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for enter events.
+        #[allow(dead_code)]
+        pub fn on_enter_with_data<T, F>(self, f: F) -> Handler<Enter<F>, H1, H2, H3>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            Handler {
+                enter: Enter(f),
+                leave: self.leave,
+                preferred_buffer_scale: self.preferred_buffer_scale,
+                preferred_buffer_transform: self.preferred_buffer_transform,
+            }
+        }
+
+        /// Sets the closure for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave<F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3>
+        where
+            F: Fn(&WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                preferred_buffer_scale: self.preferred_buffer_scale,
+                preferred_buffer_transform: self.preferred_buffer_transform,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for leave events.
+        #[allow(dead_code)]
+        pub fn on_leave_with_data<T, F>(self, f: F) -> Handler<H0, Leave<F>, H2, H3>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, Option<&WlOutputRef>),
+        {
+            Handler {
+                enter: self.enter,
+                leave: Leave(f),
+                preferred_buffer_scale: self.preferred_buffer_scale,
+                preferred_buffer_transform: self.preferred_buffer_transform,
+            }
+        }
+
+        /// Sets the closure for preferred_buffer_scale events.
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_scale<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, PreferredBufferScale<F>, H3>
+        where
+            F: Fn(&WlSurfaceRef, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preferred_buffer_scale: PreferredBufferScale(f),
+                preferred_buffer_transform: self.preferred_buffer_transform,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preferred_buffer_scale events.
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_scale_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, PreferredBufferScale<F>, H3>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, i32),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preferred_buffer_scale: PreferredBufferScale(f),
+                preferred_buffer_transform: self.preferred_buffer_transform,
+            }
+        }
+
+        /// Sets the closure for preferred_buffer_transform events.
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_transform<F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, PreferredBufferTransform<F>>
+        where
+            F: Fn(&WlSurfaceRef, WlOutputTransform),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preferred_buffer_scale: self.preferred_buffer_scale,
+                preferred_buffer_transform: PreferredBufferTransform(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for preferred_buffer_transform events.
+        #[allow(dead_code)]
+        pub fn on_preferred_buffer_transform_with_data<T, F>(
+            self,
+            f: F,
+        ) -> Handler<H0, H1, H2, PreferredBufferTransform<F>>
+        where
+            F: Fn(&mut T, &WlSurfaceRef, WlOutputTransform),
+        {
+            Handler {
+                enter: self.enter,
+                leave: self.leave,
+                preferred_buffer_scale: self.preferred_buffer_scale,
+                preferred_buffer_transform: PreferredBufferTransform(f),
+            }
+        }
     }
 }
@@ -94,6 +94,136 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the xdg_popup interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_popup",
+    protocol: "xdg_shell",
+    version: 6,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "grab",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "seat",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_seat"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "reposition",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "positioner",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_positioner"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "token",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "configure",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "popup_done",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "repositioned",
+            since: 3,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "token",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[info::EntryInfo {
+            name: "invalid_grab",
+            value: 0,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
 /// An owned xdg_popup proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -148,11 +278,16 @@ impl Deref for XdgPopup {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -394,6 +529,258 @@ pub trait XdgPopupEventHandler {
 
 impl XdgPopupEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [XdgPopup] proxies with mutable data.
+///
+/// This trait is the same as [XdgPopupEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgPopupEventHandlerWithData<T> {
+    /// configure the popup surface
+    ///
+    /// This event asks the popup surface to configure itself given the
+    /// configuration. The configured state should not be applied immediately.
+    /// See xdg_surface.configure for details.
+    ///
+    /// The x and y arguments represent the position the popup was placed at
+    /// given the xdg_positioner rule, relative to the upper left corner of the
+    /// window geometry of the parent surface.
+    ///
+    /// For version 2 or older, the configure event for an xdg_popup is only
+    /// ever sent once for the initial configuration. Starting with version 3,
+    /// it may be sent again if the popup is setup with an xdg_positioner with
+    /// set_reactive requested, or in response to xdg_popup.reposition requests.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: x position relative to parent surface window geometry
+    /// - `y`: y position relative to parent surface window geometry
+    /// - `width`: window geometry width
+    /// - `height`: window geometry height
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &XdgPopupRef,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// popup interaction is done
+    ///
+    /// The popup_done event is sent out when a popup is dismissed by the
+    /// compositor. The client should destroy the xdg_popup object at this
+    /// point.
+    #[inline]
+    fn popup_done(&self, _data: &mut T, _slf: &XdgPopupRef) {}
+
+    /// signal the completion of a repositioned request
+    ///
+    /// The repositioned event is sent as part of a popup configuration
+    /// sequence, together with xdg_popup.configure and lastly
+    /// xdg_surface.configure to notify the completion of a reposition request.
+    ///
+    /// The repositioned event is to notify about the completion of a
+    /// xdg_popup.reposition request. The token argument is the token passed
+    /// in the xdg_popup.reposition request.
+    ///
+    /// Immediately after this event is emitted, xdg_popup.configure and
+    /// xdg_surface.configure will be sent with the updated size and position,
+    /// as well as a new configure serial.
+    ///
+    /// The client should optionally update the content of the popup, but must
+    /// acknowledge the new popup configuration for the new position to take
+    /// effect. See xdg_surface.ack_configure for details.
+    ///
+    /// # Arguments
+    ///
+    /// - `token`: reposition request token
+    #[inline]
+    fn repositioned(&self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+        let _ = token;
+    }
+}
+
+/// A mutable event handler for [XdgPopup] proxies.
+///
+/// This trait is the same as [XdgPopupEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgPopupEventHandlerMut {
+    /// See [XdgPopupEventHandler::configure].
+    #[inline]
+    fn configure(&mut self, _slf: &XdgPopupRef, x: i32, y: i32, width: i32, height: i32) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [XdgPopupEventHandler::popup_done].
+    #[inline]
+    fn popup_done(&mut self, _slf: &XdgPopupRef) {}
+
+    /// See [XdgPopupEventHandler::repositioned].
+    #[inline]
+    fn repositioned(&mut self, _slf: &XdgPopupRef, token: u32) {
+        let _ = token;
+    }
+}
+
+/// A mutable event handler for [XdgPopup] proxies with mutable data.
+///
+/// This trait is the same as [XdgPopupEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgPopupEventHandlerMutWithData<T> {
+    /// See [XdgPopupEventHandler::configure].
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &XdgPopupRef,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _ = x;
+        let _ = y;
+        let _ = width;
+        let _ = height;
+    }
+
+    /// See [XdgPopupEventHandler::popup_done].
+    #[inline]
+    fn popup_done(&mut self, _data: &mut T, _slf: &XdgPopupRef) {}
+
+    /// See [XdgPopupEventHandler::repositioned].
+    #[inline]
+    fn repositioned(&mut self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+        let _ = token;
+    }
+}
+
+impl<H> XdgPopupEventHandler for MutEventHandler<H>
+where
+    H: XdgPopupEventHandlerMut,
+{
+    #[inline]
+    fn configure(&self, _slf: &XdgPopupRef, x: i32, y: i32, width: i32, height: i32) {
+        self.with(|h| h.configure(_slf, x, y, width, height))
+    }
+
+    #[inline]
+    fn popup_done(&self, _slf: &XdgPopupRef) {
+        self.with(|h| h.popup_done(_slf))
+    }
+
+    #[inline]
+    fn repositioned(&self, _slf: &XdgPopupRef, token: u32) {
+        self.with(|h| h.repositioned(_slf, token))
+    }
+}
+
+impl<T, H> XdgPopupEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: XdgPopupEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &XdgPopupRef,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        self.with(|h| h.configure(_data, _slf, x, y, width, height))
+    }
+
+    #[inline]
+    fn popup_done(&self, _data: &mut T, _slf: &XdgPopupRef) {
+        self.with(|h| h.popup_done(_data, _slf))
+    }
+
+    #[inline]
+    fn repositioned(&self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+        self.with(|h| h.repositioned(_data, _slf, token))
+    }
+}
+
+impl<T, U, H> XdgPopupEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgPopupEventHandlerWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &self,
+        _data: &mut T,
+        _slf: &XdgPopupRef,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().configure(_data, _slf, x, y, width, height)
+    }
+
+    #[inline]
+    fn popup_done(&self, _data: &mut T, _slf: &XdgPopupRef) {
+        let _data = self.project(_data);
+        self.handler().popup_done(_data, _slf)
+    }
+
+    #[inline]
+    fn repositioned(&self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+        let _data = self.project(_data);
+        self.handler().repositioned(_data, _slf, token)
+    }
+}
+
+impl<T, U, H> XdgPopupEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgPopupEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn configure(
+        &mut self,
+        _data: &mut T,
+        _slf: &XdgPopupRef,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .configure(_data, _slf, x, y, width, height)
+    }
+
+    #[inline]
+    fn popup_done(&mut self, _data: &mut T, _slf: &XdgPopupRef) {
+        let _data = self.project(_data);
+        self.handler_mut().popup_done(_data, _slf)
+    }
+
+    #[inline]
+    fn repositioned(&mut self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().repositioned(_data, _slf, token)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -443,6 +830,67 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgPopupEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<XdgPopupRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an int
+                let arg0 = unsafe { args[0].i };
+                // SAFETY: - INTERFACE requires that args[1] contains an int
+                let arg1 = unsafe { args[1].i };
+                // SAFETY: - INTERFACE requires that args[2] contains an int
+                let arg2 = unsafe { args[2].i };
+                // SAFETY: - INTERFACE requires that args[3] contains an int
+                let arg3 = unsafe { args[3].i };
+                self.0.configure(data, slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                self.0.popup_done(data, slf);
+            }
+            2 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.repositioned(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_popup", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: XdgPopupEventHandler,
@@ -455,6 +903,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgPopupEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl XdgPopup {
     /// Since when the error.invalid_grab enum variant is available.
     #[allow(dead_code)]
@@ -496,6 +957,23 @@ pub mod event_handlers {
             self.0(_slf, x, y, width, height)
         }
     }
+    impl<T, F> XdgPopupEventHandlerWithData<T> for Configure<F>
+    where
+        F: Fn(&mut T, &XdgPopupRef, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &XdgPopupRef,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, x, y, width, height)
+        }
+    }
 
     /// Event handler for popup_done events.
     pub struct PopupDone<F>(F);
@@ -508,6 +986,15 @@ pub mod event_handlers {
             self.0(_slf)
         }
     }
+    impl<T, F> XdgPopupEventHandlerWithData<T> for PopupDone<F>
+    where
+        F: Fn(&mut T, &XdgPopupRef),
+    {
+        #[inline]
+        fn popup_done(&self, _data: &mut T, _slf: &XdgPopupRef) {
+            self.0(_data, _slf)
+        }
+    }
 
     /// Event handler for repositioned events.
     pub struct Repositioned<F>(F);
@@ -520,6 +1007,86 @@ pub mod event_handlers {
             self.0(_slf, token)
         }
     }
+    impl<T, F> XdgPopupEventHandlerWithData<T> for Repositioned<F>
+    where
+        F: Fn(&mut T, &XdgPopupRef, u32),
+    {
+        #[inline]
+        fn repositioned(&self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+            self.0(_data, _slf, token)
+        }
+    }
+
+    /// Mutable event handler for configure events.
+    pub struct ConfigureMut<F>(F);
+    impl<F> XdgPopupEventHandlerMut for ConfigureMut<F>
+    where
+        F: FnMut(&XdgPopupRef, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn configure(&mut self, _slf: &XdgPopupRef, x: i32, y: i32, width: i32, height: i32) {
+            self.0(_slf, x, y, width, height)
+        }
+    }
+    impl<T, F> XdgPopupEventHandlerMutWithData<T> for ConfigureMut<F>
+    where
+        F: FnMut(&mut T, &XdgPopupRef, i32, i32, i32, i32),
+    {
+        #[inline]
+        fn configure(
+            &mut self,
+            _data: &mut T,
+            _slf: &XdgPopupRef,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.0(_data, _slf, x, y, width, height)
+        }
+    }
+
+    /// Mutable event handler for popup_done events.
+    pub struct PopupDoneMut<F>(F);
+    impl<F> XdgPopupEventHandlerMut for PopupDoneMut<F>
+    where
+        F: FnMut(&XdgPopupRef),
+    {
+        #[inline]
+        fn popup_done(&mut self, _slf: &XdgPopupRef) {
+            self.0(_slf)
+        }
+    }
+    impl<T, F> XdgPopupEventHandlerMutWithData<T> for PopupDoneMut<F>
+    where
+        F: FnMut(&mut T, &XdgPopupRef),
+    {
+        #[inline]
+        fn popup_done(&mut self, _data: &mut T, _slf: &XdgPopupRef) {
+            self.0(_data, _slf)
+        }
+    }
+
+    /// Mutable event handler for repositioned events.
+    pub struct RepositionedMut<F>(F);
+    impl<F> XdgPopupEventHandlerMut for RepositionedMut<F>
+    where
+        F: FnMut(&XdgPopupRef, u32),
+    {
+        #[inline]
+        fn repositioned(&mut self, _slf: &XdgPopupRef, token: u32) {
+            self.0(_slf, token)
+        }
+    }
+    impl<T, F> XdgPopupEventHandlerMutWithData<T> for RepositionedMut<F>
+    where
+        F: FnMut(&mut T, &XdgPopupRef, u32),
+    {
+        #[inline]
+        fn repositioned(&mut self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+            self.0(_data, _slf, token)
+        }
+    }
 
     impl XdgPopup {
         /// Creates an event handler for configure events.
@@ -533,6 +1100,17 @@ pub mod event_handlers {
             Configure(f)
         }
 
+        /// Creates an event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(f: F) -> Configure<F>
+        where
+            F: Fn(&mut T, &XdgPopupRef, i32, i32, i32, i32),
+        {
+            Configure(f)
+        }
+
         /// Creates an event handler for popup_done events.
         ///
         /// The event handler ignores all other events.
@@ -544,6 +1122,17 @@ pub mod event_handlers {
             PopupDone(f)
         }
 
+        /// Creates an event handler with mutable data for popup_done events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_popup_done_with_data<T, F>(f: F) -> PopupDone<F>
+        where
+            F: Fn(&mut T, &XdgPopupRef),
+        {
+            PopupDone(f)
+        }
+
         /// Creates an event handler for repositioned events.
         ///
         /// The event handler ignores all other events.
@@ -554,5 +1143,253 @@ pub mod event_handlers {
         {
             Repositioned(f)
         }
+
+        /// Creates an event handler with mutable data for repositioned events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_repositioned_with_data<T, F>(f: F) -> Repositioned<F>
+        where
+            F: Fn(&mut T, &XdgPopupRef, u32),
+        {
+            Repositioned(f)
+        }
+
+        /// Creates a mutable event handler for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut<F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&XdgPopupRef, i32, i32, i32, i32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut_with_data<T, F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&mut T, &XdgPopupRef, i32, i32, i32, i32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler for popup_done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_popup_done_mut<F>(f: F) -> PopupDoneMut<F>
+        where
+            F: FnMut(&XdgPopupRef),
+        {
+            PopupDoneMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for popup_done events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_popup_done_mut_with_data<T, F>(f: F) -> PopupDoneMut<F>
+        where
+            F: FnMut(&mut T, &XdgPopupRef),
+        {
+            PopupDoneMut(f)
+        }
+
+        /// Creates a mutable event handler for repositioned events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_repositioned_mut<F>(f: F) -> RepositionedMut<F>
+        where
+            F: FnMut(&XdgPopupRef, u32),
+        {
+            RepositionedMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for repositioned events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_repositioned_mut_with_data<T, F>(f: F) -> RepositionedMut<F>
+        where
+            F: FnMut(&mut T, &XdgPopupRef, u32),
+        {
+            RepositionedMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                configure: Ignore,
+                popup_done: Ignore,
+                repositioned: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl XdgPopupEventHandler for Ignore {}
+    impl<T> XdgPopupEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`XdgPopup::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore, H2 = Ignore> {
+        configure: H0,
+        popup_done: H1,
+        repositioned: H2,
+    }
+
+    impl<H0, H1, H2> XdgPopupEventHandler for Handler<H0, H1, H2>
+    where
+        H0: XdgPopupEventHandler,
+        H1: XdgPopupEventHandler,
+        H2: XdgPopupEventHandler,
+    {
+        #[inline]
+        fn configure(&self, _slf: &XdgPopupRef, x: i32, y: i32, width: i32, height: i32) {
+            self.configure.configure(_slf, x, y, width, height)
+        }
+
+        #[inline]
+        fn popup_done(&self, _slf: &XdgPopupRef) {
+            self.popup_done.popup_done(_slf)
+        }
+
+        #[inline]
+        fn repositioned(&self, _slf: &XdgPopupRef, token: u32) {
+            self.repositioned.repositioned(_slf, token)
+        }
+    }
+
+    impl<T, H0, H1, H2> XdgPopupEventHandlerWithData<T> for Handler<H0, H1, H2>
+    where
+        H0: XdgPopupEventHandlerWithData<T>,
+        H1: XdgPopupEventHandlerWithData<T>,
+        H2: XdgPopupEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn configure(
+            &self,
+            _data: &mut T,
+            _slf: &XdgPopupRef,
+            x: i32,
+            y: i32,
+            width: i32,
+            height: i32,
+        ) {
+            self.configure.configure(_data, _slf, x, y, width, height)
+        }
+
+        #[inline]
+        fn popup_done(&self, _data: &mut T, _slf: &XdgPopupRef) {
+            self.popup_done.popup_done(_data, _slf)
+        }
+
+        #[inline]
+        fn repositioned(&self, _data: &mut T, _slf: &XdgPopupRef, token: u32) {
+            self.repositioned.repositioned(_data, _slf, token)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1, H2> Handler<H0, H1, H2> {
+        /// Sets the closure for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(self, f: F) -> Handler<Configure<F>, H1, H2>
+        where
+            F: Fn(&XdgPopupRef, i32, i32, i32, i32),
+        {
+            Handler {
+                configure: Configure(f),
+                popup_done: self.popup_done,
+                repositioned: self.repositioned,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(self, f: F) -> Handler<Configure<F>, H1, H2>
+        where
+            F: Fn(&mut T, &XdgPopupRef, i32, i32, i32, i32),
+        {
+            Handler {
+                configure: Configure(f),
+                popup_done: self.popup_done,
+                repositioned: self.repositioned,
+            }
+        }
+
+        /// Sets the closure for popup_done events.
+        #[allow(dead_code)]
+        pub fn on_popup_done<F>(self, f: F) -> Handler<H0, PopupDone<F>, H2>
+        where
+            F: Fn(&XdgPopupRef),
+        {
+            Handler {
+                configure: self.configure,
+                popup_done: PopupDone(f),
+                repositioned: self.repositioned,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for popup_done events.
+        #[allow(dead_code)]
+        pub fn on_popup_done_with_data<T, F>(self, f: F) -> Handler<H0, PopupDone<F>, H2>
+        where
+            F: Fn(&mut T, &XdgPopupRef),
+        {
+            Handler {
+                configure: self.configure,
+                popup_done: PopupDone(f),
+                repositioned: self.repositioned,
+            }
+        }
+
+        /// Sets the closure for repositioned events.
+        #[allow(dead_code)]
+        pub fn on_repositioned<F>(self, f: F) -> Handler<H0, H1, Repositioned<F>>
+        where
+            F: Fn(&XdgPopupRef, u32),
+        {
+            Handler {
+                configure: self.configure,
+                popup_done: self.popup_done,
+                repositioned: Repositioned(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for repositioned events.
+        #[allow(dead_code)]
+        pub fn on_repositioned_with_data<T, F>(self, f: F) -> Handler<H0, H1, Repositioned<F>>
+        where
+            F: Fn(&mut T, &XdgPopupRef, u32),
+        {
+            Handler {
+                configure: self.configure,
+                popup_done: self.popup_done,
+                repositioned: Repositioned(f),
+            }
+        }
     }
 }
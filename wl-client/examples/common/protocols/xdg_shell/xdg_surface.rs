@@ -119,6 +119,170 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the xdg_surface interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_surface",
+    protocol: "xdg_shell",
+    version: 6,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_toplevel",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("xdg_toplevel"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_popup",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("xdg_popup"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "parent",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_surface"),
+                    allow_null: true,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "positioner",
+                    ty: info::ArgType::Object,
+                    interface: Some("xdg_positioner"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "set_window_geometry",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "x",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "y",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "width",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "height",
+                    ty: info::ArgType::Int,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "ack_configure",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "configure",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "serial",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "not_constructed",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "already_constructed",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "unconfigured_buffer",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_serial",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_size",
+                value: 5,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "defunct_role_object",
+                value: 6,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned xdg_surface proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -173,11 +337,16 @@ impl Deref for XdgSurface {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -532,6 +701,111 @@ pub trait XdgSurfaceEventHandler {
 
 impl XdgSurfaceEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [XdgSurface] proxies with mutable data.
+///
+/// This trait is the same as [XdgSurfaceEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgSurfaceEventHandlerWithData<T> {
+    /// suggest a surface change
+    ///
+    /// The configure event marks the end of a configure sequence. A configure
+    /// sequence is a set of one or more events configuring the state of the
+    /// xdg_surface, including the final xdg_surface.configure event.
+    ///
+    /// Where applicable, xdg_surface surface roles will during a configure
+    /// sequence extend this event as a latched state sent as events before the
+    /// xdg_surface.configure event. Such events should be considered to make up
+    /// a set of atomically applied configuration states, where the
+    /// xdg_surface.configure commits the accumulated state.
+    ///
+    /// Clients should arrange their surface for the new states, and then send
+    /// an ack_configure request with the serial sent in this configure event at
+    /// some point before committing the new surface.
+    ///
+    /// If the client receives multiple configure events before it can respond
+    /// to one, it is free to discard all but the last event it received.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: serial of the configure event
+    #[inline]
+    fn configure(&self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+        let _ = serial;
+    }
+}
+
+/// A mutable event handler for [XdgSurface] proxies.
+///
+/// This trait is the same as [XdgSurfaceEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgSurfaceEventHandlerMut {
+    /// See [XdgSurfaceEventHandler::configure].
+    #[inline]
+    fn configure(&mut self, _slf: &XdgSurfaceRef, serial: u32) {
+        let _ = serial;
+    }
+}
+
+/// A mutable event handler for [XdgSurface] proxies with mutable data.
+///
+/// This trait is the same as [XdgSurfaceEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgSurfaceEventHandlerMutWithData<T> {
+    /// See [XdgSurfaceEventHandler::configure].
+    #[inline]
+    fn configure(&mut self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+        let _ = serial;
+    }
+}
+
+impl<H> XdgSurfaceEventHandler for MutEventHandler<H>
+where
+    H: XdgSurfaceEventHandlerMut,
+{
+    #[inline]
+    fn configure(&self, _slf: &XdgSurfaceRef, serial: u32) {
+        self.with(|h| h.configure(_slf, serial))
+    }
+}
+
+impl<T, H> XdgSurfaceEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: XdgSurfaceEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn configure(&self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+        self.with(|h| h.configure(_data, _slf, serial))
+    }
+}
+
+impl<T, U, H> XdgSurfaceEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgSurfaceEventHandlerWithData<U>,
+{
+    #[inline]
+    fn configure(&self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+        let _data = self.project(_data);
+        self.handler().configure(_data, _slf, serial)
+    }
+}
+
+impl<T, U, H> XdgSurfaceEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgSurfaceEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn configure(&mut self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().configure(_data, _slf, serial)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -565,6 +839,51 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<XdgSurfaceRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.configure(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_surface", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: XdgSurfaceEventHandler,
@@ -577,6 +896,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl XdgSurface {
     /// Since when the error.not_constructed enum variant is available.
     #[allow(dead_code)]
@@ -658,6 +990,36 @@ pub mod event_handlers {
             self.0(_slf, serial)
         }
     }
+    impl<T, F> XdgSurfaceEventHandlerWithData<T> for Configure<F>
+    where
+        F: Fn(&mut T, &XdgSurfaceRef, u32),
+    {
+        #[inline]
+        fn configure(&self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Mutable event handler for configure events.
+    pub struct ConfigureMut<F>(F);
+    impl<F> XdgSurfaceEventHandlerMut for ConfigureMut<F>
+    where
+        F: FnMut(&XdgSurfaceRef, u32),
+    {
+        #[inline]
+        fn configure(&mut self, _slf: &XdgSurfaceRef, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> XdgSurfaceEventHandlerMutWithData<T> for ConfigureMut<F>
+    where
+        F: FnMut(&mut T, &XdgSurfaceRef, u32),
+    {
+        #[inline]
+        fn configure(&mut self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
 
     impl XdgSurface {
         /// Creates an event handler for configure events.
@@ -670,5 +1032,111 @@ pub mod event_handlers {
         {
             Configure(f)
         }
+
+        /// Creates an event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(f: F) -> Configure<F>
+        where
+            F: Fn(&mut T, &XdgSurfaceRef, u32),
+        {
+            Configure(f)
+        }
+
+        /// Creates a mutable event handler for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut<F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&XdgSurfaceRef, u32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for configure events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_configure_mut_with_data<T, F>(f: F) -> ConfigureMut<F>
+        where
+            F: FnMut(&mut T, &XdgSurfaceRef, u32),
+        {
+            ConfigureMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { configure: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl XdgSurfaceEventHandler for Ignore {}
+    impl<T> XdgSurfaceEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`XdgSurface::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        configure: H0,
+    }
+
+    impl<H0> XdgSurfaceEventHandler for Handler<H0>
+    where
+        H0: XdgSurfaceEventHandler,
+    {
+        #[inline]
+        fn configure(&self, _slf: &XdgSurfaceRef, serial: u32) {
+            self.configure.configure(_slf, serial)
+        }
+    }
+
+    impl<T, H0> XdgSurfaceEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: XdgSurfaceEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn configure(&self, _data: &mut T, _slf: &XdgSurfaceRef, serial: u32) {
+            self.configure.configure(_data, _slf, serial)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure<F>(self, f: F) -> Handler<Configure<F>>
+        where
+            F: Fn(&XdgSurfaceRef, u32),
+        {
+            Handler {
+                configure: Configure(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for configure events.
+        #[allow(dead_code)]
+        pub fn on_configure_with_data<T, F>(self, f: F) -> Handler<Configure<F>>
+        where
+            F: Fn(&mut T, &XdgSurfaceRef, u32),
+        {
+            Handler {
+                configure: Configure(f),
+            }
+        }
     }
 }
@@ -67,6 +67,133 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the xdg_wm_base interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "xdg_wm_base",
+    protocol: "xdg_shell",
+    version: 6,
+    requests: &[
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "create_positioner",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("xdg_positioner"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_xdg_surface",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("xdg_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "surface",
+                    ty: info::ArgType::Object,
+                    interface: Some("wl_surface"),
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "pong",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "serial",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "ping",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "serial",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[info::EnumInfo {
+        name: "error",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "role",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "defunct_surfaces",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "not_the_topmost_popup",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_popup_parent",
+                value: 3,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_surface_state",
+                value: 4,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "invalid_positioner",
+                value: 5,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "unresponsive",
+                value: 6,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned xdg_wm_base proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -121,11 +248,16 @@ impl Deref for XdgWmBase {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -366,6 +498,108 @@ pub trait XdgWmBaseEventHandler {
 
 impl XdgWmBaseEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [XdgWmBase] proxies with mutable data.
+///
+/// This trait is the same as [XdgWmBaseEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait XdgWmBaseEventHandlerWithData<T> {
+    /// check if the client is alive
+    ///
+    /// The ping event asks the client if it's still alive. Pass the
+    /// serial specified in the event back to the compositor by sending
+    /// a "pong" request back with the specified serial. See xdg_wm_base.pong.
+    ///
+    /// Compositors can use this to determine if the client is still
+    /// alive. It's unspecified what will happen if the client doesn't
+    /// respond to the ping request, or in what timeframe. Clients should
+    /// try to respond in a reasonable amount of time. The “unresponsive”
+    /// error is provided for compositors that wish to disconnect unresponsive
+    /// clients.
+    ///
+    /// A compositor is free to ping in any way it wants, but a client must
+    /// always respond to any xdg_wm_base object it created.
+    ///
+    /// # Arguments
+    ///
+    /// - `serial`: pass this to the pong request
+    #[inline]
+    fn ping(&self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+        let _ = serial;
+    }
+}
+
+/// A mutable event handler for [XdgWmBase] proxies.
+///
+/// This trait is the same as [XdgWmBaseEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgWmBaseEventHandlerMut {
+    /// See [XdgWmBaseEventHandler::ping].
+    #[inline]
+    fn ping(&mut self, _slf: &XdgWmBaseRef, serial: u32) {
+        let _ = serial;
+    }
+}
+
+/// A mutable event handler for [XdgWmBase] proxies with mutable data.
+///
+/// This trait is the same as [XdgWmBaseEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait XdgWmBaseEventHandlerMutWithData<T> {
+    /// See [XdgWmBaseEventHandler::ping].
+    #[inline]
+    fn ping(&mut self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+        let _ = serial;
+    }
+}
+
+impl<H> XdgWmBaseEventHandler for MutEventHandler<H>
+where
+    H: XdgWmBaseEventHandlerMut,
+{
+    #[inline]
+    fn ping(&self, _slf: &XdgWmBaseRef, serial: u32) {
+        self.with(|h| h.ping(_slf, serial))
+    }
+}
+
+impl<T, H> XdgWmBaseEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: XdgWmBaseEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn ping(&self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+        self.with(|h| h.ping(_data, _slf, serial))
+    }
+}
+
+impl<T, U, H> XdgWmBaseEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgWmBaseEventHandlerWithData<U>,
+{
+    #[inline]
+    fn ping(&self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+        let _data = self.project(_data);
+        self.handler().ping(_data, _slf, serial)
+    }
+}
+
+impl<T, U, H> XdgWmBaseEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: XdgWmBaseEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn ping(&mut self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().ping(_data, _slf, serial)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -399,6 +633,51 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: XdgWmBaseEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<XdgWmBaseRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.ping(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("xdg_wm_base", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: XdgWmBaseEventHandler,
@@ -411,6 +690,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: XdgWmBaseEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl XdgWmBase {
     /// Since when the error.role enum variant is available.
     #[allow(dead_code)]
@@ -500,6 +792,36 @@ pub mod event_handlers {
             self.0(_slf, serial)
         }
     }
+    impl<T, F> XdgWmBaseEventHandlerWithData<T> for Ping<F>
+    where
+        F: Fn(&mut T, &XdgWmBaseRef, u32),
+    {
+        #[inline]
+        fn ping(&self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
+
+    /// Mutable event handler for ping events.
+    pub struct PingMut<F>(F);
+    impl<F> XdgWmBaseEventHandlerMut for PingMut<F>
+    where
+        F: FnMut(&XdgWmBaseRef, u32),
+    {
+        #[inline]
+        fn ping(&mut self, _slf: &XdgWmBaseRef, serial: u32) {
+            self.0(_slf, serial)
+        }
+    }
+    impl<T, F> XdgWmBaseEventHandlerMutWithData<T> for PingMut<F>
+    where
+        F: FnMut(&mut T, &XdgWmBaseRef, u32),
+    {
+        #[inline]
+        fn ping(&mut self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+            self.0(_data, _slf, serial)
+        }
+    }
 
     impl XdgWmBase {
         /// Creates an event handler for ping events.
@@ -512,5 +834,107 @@ pub mod event_handlers {
         {
             Ping(f)
         }
+
+        /// Creates an event handler with mutable data for ping events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_ping_with_data<T, F>(f: F) -> Ping<F>
+        where
+            F: Fn(&mut T, &XdgWmBaseRef, u32),
+        {
+            Ping(f)
+        }
+
+        /// Creates a mutable event handler for ping events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ping_mut<F>(f: F) -> PingMut<F>
+        where
+            F: FnMut(&XdgWmBaseRef, u32),
+        {
+            PingMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for ping events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_ping_mut_with_data<T, F>(f: F) -> PingMut<F>
+        where
+            F: FnMut(&mut T, &XdgWmBaseRef, u32),
+        {
+            PingMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { ping: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl XdgWmBaseEventHandler for Ignore {}
+    impl<T> XdgWmBaseEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`XdgWmBase::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        ping: H0,
+    }
+
+    impl<H0> XdgWmBaseEventHandler for Handler<H0>
+    where
+        H0: XdgWmBaseEventHandler,
+    {
+        #[inline]
+        fn ping(&self, _slf: &XdgWmBaseRef, serial: u32) {
+            self.ping.ping(_slf, serial)
+        }
+    }
+
+    impl<T, H0> XdgWmBaseEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: XdgWmBaseEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn ping(&self, _data: &mut T, _slf: &XdgWmBaseRef, serial: u32) {
+            self.ping.ping(_data, _slf, serial)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for ping events.
+        #[allow(dead_code)]
+        pub fn on_ping<F>(self, f: F) -> Handler<Ping<F>>
+        where
+            F: Fn(&XdgWmBaseRef, u32),
+        {
+            Handler { ping: Ping(f) }
+        }
+
+        /// Sets the closure that takes mutable data for ping events.
+        #[allow(dead_code)]
+        pub fn on_ping_with_data<T, F>(self, f: F) -> Handler<Ping<F>>
+        where
+            F: Fn(&mut T, &XdgWmBaseRef, u32),
+        {
+            Handler { ping: Ping(f) }
+        }
     }
 }
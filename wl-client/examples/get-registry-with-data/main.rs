@@ -1,5 +1,5 @@
 use {
-    crate::common::protocols_data::wayland::{wl_display::WlDisplay, wl_registry::WlRegistry},
+    crate::common::protocols::wayland::{wl_display::WlDisplay, wl_registry::WlRegistry},
     wl_client::{Libwayland, proxy},
 };
 
@@ -36,7 +36,7 @@ fn main() {
     // form.
     proxy::set_event_handler(
         &state.registry,
-        WlRegistry::on_global(|state: &mut State, _, name, interface, version| {
+        WlRegistry::on_global_with_data(|state: &mut State, _, name, interface, version| {
             state.globals.push(Global {
                 name,
                 interface: interface.to_string(),
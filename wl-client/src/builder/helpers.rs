@@ -1,5 +1,8 @@
 use {
-    crate::ffi::{wl_object, wl_proxy},
+    crate::{
+        ffi::{wl_interface, wl_object, wl_proxy},
+        proxy::{OwnedProxy, low_level::UntypedBorrowedProxy},
+    },
     std::{
         cell::RefCell,
         ffi::{CStr, c_char},
@@ -90,3 +93,37 @@ pub fn check_argument_proxy(arg: &str, proxy: Option<NonNull<wl_proxy>>) -> *mut
         Some(p) => p.as_ptr().cast(),
     }
 }
+
+/// Checks that a request opcode is valid for the interface of a proxy.
+///
+/// Proxies can have an interface defined by libwayland-client, which can be older than
+/// the protocol XML that the wrappers were generated from. If the interface of the proxy
+/// is not known and the proxy has already been destroyed, this function does nothing.
+#[inline]
+pub fn check_request_opcode(proxy: &UntypedBorrowedProxy, name: &str, opcode: u32) {
+    let Some(interface) = proxy.wl_interface() else {
+        return;
+    };
+    if opcode >= interface.method_count as u32 {
+        #[cold]
+        fn unsupported(name: &str, opcode: u32) -> ! {
+            panic!("libwayland-client does not support request {opcode} of interface {name}");
+        }
+        unsupported(name, opcode);
+    }
+}
+
+/// Returns the interface that proxies of type `P` are created with.
+///
+/// This is the interface defined by libwayland-client if `P` uses it and
+/// `P::WL_INTERFACE` otherwise.
+#[inline]
+pub fn proxy_interface<P>() -> &'static wl_interface
+where
+    P: OwnedProxy,
+{
+    match P::LIBWAYLAND_INTERFACE {
+        Some(interface) => interface.get(),
+        None => P::WL_INTERFACE,
+    }
+}
@@ -1,8 +1,16 @@
 use {
     crate::{
-        Libwayland, proxy,
+        Libwayland,
+        builder::helpers::{check_request_opcode, proxy_interface},
+        proxy::{
+            self, OwnedProxy,
+            low_level::{UntypedBorrowedProxy, to_untyped_borrowed},
+        },
         test_protocol_helpers::get_root,
-        test_protocols::core::wl_string::{WlStringEventHandler, WlStringRef},
+        test_protocols::core::{
+            wl_root::WlRoot,
+            wl_string::{WlStringEventHandler, WlStringRef},
+        },
     },
     std::cell::Cell,
 };
@@ -39,3 +47,46 @@ fn destroyed_argument() {
     proxy::destroy(&dummy);
     root.ping_dummy(&dummy);
 }
+
+#[test]
+fn request_opcode() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"queue");
+    let root = get_root(&queue);
+    let count = WlRoot::WL_INTERFACE.method_count as u32;
+    check_request_opcode(to_untyped_borrowed(&*root), "wl_root", count - 1);
+}
+
+#[test]
+#[should_panic(expected = "libwayland-client does not support request 100 of interface wl_root")]
+fn unsupported_request_opcode() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"queue");
+    let root = get_root(&queue);
+    check_request_opcode(to_untyped_borrowed(&*root), "wl_root", 100);
+}
+
+#[test]
+#[should_panic(expected = "libwayland-client does not support request 100 of interface wl_root")]
+fn unsupported_request_opcode_immutable() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"queue");
+    let root = get_root(&queue);
+    // SAFETY: root outlives the borrowed proxy.
+    let borrowed =
+        unsafe { UntypedBorrowedProxy::new_immutable(lib, proxy::wl_proxy(&*root).unwrap()) };
+    let count = WlRoot::WL_INTERFACE.method_count as u32;
+    check_request_opcode(&borrowed, "wl_root", count - 1);
+    check_request_opcode(&borrowed, "wl_root", 100);
+}
+
+#[test]
+fn proxy_interface_without_libwayland() {
+    assert!(std::ptr::eq(
+        proxy_interface::<WlRoot>(),
+        WlRoot::WL_INTERFACE
+    ));
+}
@@ -1,16 +1,19 @@
 pub use {
     crate::{
         builder::helpers::{
-            check_argument_proxy, convert_optional_string_arg, convert_string_arg, invalid_opcode,
-            unimplemented_event_handler, with_cstr_cache,
+            check_argument_proxy, check_request_opcode, convert_optional_string_arg,
+            convert_string_arg, invalid_opcode, proxy_interface, unimplemented_event_handler,
+            with_cstr_cache,
         },
-        ffi::{wl_argument, wl_array, wl_interface, wl_message, wl_proxy},
+        ffi::{LibwaylandInterface, wl_argument, wl_array, wl_interface, wl_message, wl_proxy},
         fixed::Fixed,
+        info,
         proxy::{
             self, BorrowedProxy, OwnedProxy,
             low_level::{
-                CreateEventHandler, EventHandler, UntypedBorrowedProxy,
-                UntypedBorrowedProxyWrapper, UntypedOwnedProxy, UntypedOwnedProxyWrapper,
+                CreateEventHandler, EventHandler, MutEventHandler, ProjectedEventHandler,
+                UntypedBorrowedProxy, UntypedBorrowedProxyWrapper, UntypedOwnedProxy,
+                UntypedOwnedProxyWrapper, WithData,
             },
         },
         queue::Queue,
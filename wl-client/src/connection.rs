@@ -1,7 +1,12 @@
 use {
     crate::{
         Libwayland,
-        connection::{data::ConnectionData2, flush::Flusher, read_lock::SharedReadLock},
+        connection::{
+            data::ConnectionData2,
+            flush::Flusher,
+            read_lock::SharedReadLock,
+            socket::{PeerCredentials, peer_credentials, resolve_socket_path},
+        },
         ffi::wl_display,
         utils::{executor::Executor, poller::Poller},
     },
@@ -9,14 +14,18 @@ use {
         ffi::CStr,
         fmt::{Debug, Formatter},
         io,
-        os::fd::{IntoRawFd, OwnedFd},
+        os::fd::{AsFd, IntoRawFd, OwnedFd},
+        path::{Path, PathBuf},
         ptr::{self, NonNull},
         sync::Arc,
     },
 };
 
 mod flush;
+pub(crate) mod options;
 pub(crate) mod read_lock;
+pub(crate) mod socket;
+pub(crate) mod stats;
 #[cfg(test)]
 mod tests;
 pub(crate) mod wait_for_events;
@@ -48,6 +57,8 @@ struct ConnectionData1 {
     poller: Poller,
     flusher: Flusher,
     executor: Executor,
+    /// The path of the socket that the display was connected to, if known.
+    socket_path: Option<PathBuf>,
     // Note: Keep this last so that the connection is kept open until all threads have
     // been joined. This simplifies testing with miri.
     data: Arc<ConnectionData2>,
@@ -89,11 +100,12 @@ impl Libwayland {
     }
 
     fn connect_to_display(&'static self, display_name: Option<&CStr>) -> io::Result<Connection> {
+        let socket_path = resolve_socket_path(display_name);
         let display_name = display_name.map(|n| n.as_ptr()).unwrap_or(ptr::null());
         // SAFETY: display_name is null or a CStr pointer.
         let wl_display = unsafe { self.wl_display_connect(display_name) };
         // SAFETY: wl_display was just returned by a libwayland connect function.
-        unsafe { self.wrap_owned_raw_pointer(wl_display) }
+        unsafe { self.wrap_owned_raw_pointer(wl_display, socket_path) }
     }
 
     /// Consumes an existing socket connected to a wayland compositor.
@@ -101,10 +113,18 @@ impl Libwayland {
     /// Unlike [`Libwayland::connect_to_default_display`], this function does not perform
     /// any blocking IO.
     pub fn connect_to_fd(&'static self, fd: OwnedFd) -> io::Result<Connection> {
+        self.connect_to_fd_with_path(fd, None)
+    }
+
+    fn connect_to_fd_with_path(
+        &'static self,
+        fd: OwnedFd,
+        socket_path: Option<PathBuf>,
+    ) -> io::Result<Connection> {
         // SAFETY: - fd.into_raw_fd() returns a valid file descriptor.
         let wl_display = unsafe { self.wl_display_connect_to_fd(fd.into_raw_fd()) };
         // SAFETY: wl_display was just returned by a libwayland connect function.
-        unsafe { self.wrap_owned_raw_pointer(wl_display) }
+        unsafe { self.wrap_owned_raw_pointer(wl_display, socket_path) }
     }
 
     /// # Safety
@@ -113,13 +133,14 @@ impl Libwayland {
     unsafe fn wrap_owned_raw_pointer(
         &'static self,
         wl_display: *mut wl_display,
+        socket_path: Option<PathBuf>,
     ) -> io::Result<Connection> {
         let Some(wl_display) = NonNull::new(wl_display) else {
             return Err(io::Error::last_os_error());
         };
         // SAFETY: - if libwayland returns a non-null pointer, it is valid
         //         - we just created the display so we have ownership
-        unsafe { self.wrap_owned_pointer(wl_display) }
+        unsafe { self.wrap_pointer(wl_display, true, socket_path) }
     }
 
     /// Takes ownership of an existing `wl_display`.
@@ -158,7 +179,7 @@ impl Libwayland {
         wl_display: NonNull<wl_display>,
     ) -> io::Result<Connection> {
         // SAFETY: The requirements are forwarded to the caller
-        unsafe { self.wrap_pointer(wl_display, true) }
+        unsafe { self.wrap_pointer(wl_display, true, None) }
     }
 
     /// Borrows an existing `wl_display`.
@@ -189,7 +210,7 @@ impl Libwayland {
         wl_display: NonNull<wl_display>,
     ) -> io::Result<Connection> {
         // SAFETY: owned is false and the requirements are forwarded to the caller
-        unsafe { self.wrap_pointer(wl_display, false) }
+        unsafe { self.wrap_pointer(wl_display, false, None) }
     }
 
     /// Creates a new Connection from a wl_display.
@@ -206,6 +227,7 @@ impl Libwayland {
         &'static self,
         wl_display: NonNull<wl_display>,
         owned: bool,
+        socket_path: Option<PathBuf>,
     ) -> io::Result<Connection> {
         // SAFETY: - The requirements are forwarded to the caller and Self always contains a
         //           reference to the ConnectionData2, delaying its drop until no earlier
@@ -220,6 +242,7 @@ impl Libwayland {
             poller,
             shared_read_lock: SharedReadLock::new(&data)?,
             executor,
+            socket_path,
             data,
         });
         Ok(Connection { data })
@@ -328,12 +351,66 @@ impl Connection {
     pub fn error(&self) -> io::Result<()> {
         self.data.data.error()
     }
+
+    /// Returns the credentials of the compositor process.
+    ///
+    /// The credentials are retrieved with `SO_PEERCRED` from the display file
+    /// descriptor. They are the credentials of the process that created the socket,
+    /// which is usually the compositor itself.
+    ///
+    /// On platforms other than Linux, this function returns an error of kind
+    /// [`io::ErrorKind::Unsupported`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// let cred = con.peer_credentials().unwrap();
+    /// println!("compositor pid: {}", cred.pid);
+    /// ```
+    pub fn peer_credentials(&self) -> io::Result<PeerCredentials> {
+        peer_credentials(self.data.data.as_fd())
+    }
+
+    /// Returns the path of the socket that this connection was established with.
+    ///
+    /// The path is resolved when the connection is created by
+    /// [`Libwayland::connect_to_default_display`],
+    /// [`Libwayland::connect_to_named_display`], or [`ConnectOptions`](crate::ConnectOptions),
+    /// following the rules of libwayland:
+    ///
+    /// - If the display name is an absolute path, that path is used.
+    /// - Otherwise the display name, which defaults to the `WAYLAND_DISPLAY` environment
+    ///   variable or `wayland-0`, is interpreted relative to `XDG_RUNTIME_DIR`.
+    ///
+    /// This function returns `None` if the connection was created from a file
+    /// descriptor, including a file descriptor inherited via `WAYLAND_SOCKET`, if it
+    /// was created from an abstract socket, or by wrapping an existing `wl_display`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// if let Some(path) = con.socket_path() {
+    ///     println!("connected to {}", path.display());
+    /// }
+    /// ```
+    pub fn socket_path(&self) -> Option<&Path> {
+        self.data.socket_path.as_deref()
+    }
 }
 
 pub(super) mod data {
     use {
         crate::{
             Libwayland,
+            connection::stats::ConnectionStatsData,
             ffi::{wl_display, wl_event_queue},
             utils::sync_ptr::SyncNonNull,
         },
@@ -341,7 +418,10 @@ pub(super) mod data {
             io,
             os::fd::{AsFd, BorrowedFd},
             ptr::NonNull,
-            sync::atomic::{AtomicBool, Ordering::Relaxed},
+            sync::{
+                Arc,
+                atomic::{AtomicBool, Ordering::Relaxed},
+            },
         },
     };
 
@@ -367,6 +447,8 @@ pub(super) mod data {
         /// queue to ensure that there are no pending wl_display.error messages. Such
         /// messages are always dispatched when any queue is dispatched.
         dummy_queue: SyncNonNull<wl_event_queue>,
+        /// The statistics of this connection.
+        pub(crate) stats: Arc<ConnectionStatsData>,
     }
 
     impl ConnectionData2 {
@@ -393,6 +475,7 @@ pub(super) mod data {
                 wl_display: SyncNonNull(wl_display),
                 owned: AtomicBool::new(owned),
                 dummy_queue: SyncNonNull(queue),
+                stats: Default::default(),
             }
         }
 
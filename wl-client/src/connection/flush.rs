@@ -8,6 +8,7 @@ use {
             executor::Executor,
             os_error::OsError,
             poller::{self, Poller},
+            trace,
         },
     },
     parking_lot::Mutex,
@@ -52,9 +53,12 @@ impl Flusher {
                 loop {
                     while let Err(e) = con.try_flush() {
                         match e.kind() {
-                            ErrorKind::WouldBlock => {}
+                            ErrorKind::WouldBlock => trace::flush_would_block(),
                             ErrorKind::Interrupted => continue,
-                            _ => return Err(e),
+                            _ => {
+                                trace::flush_error(&e);
+                                return Err(e);
+                            }
                         }
                         poller::writable(&poll_data).await?;
                     }
@@ -143,6 +147,7 @@ impl ConnectionData2 {
         if ret == -1 {
             return Err(io::Error::last_os_error());
         }
+        self.stats.record_write(ret as u64);
         Ok(())
     }
 }
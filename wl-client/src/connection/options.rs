@@ -0,0 +1,304 @@
+use {
+    crate::{
+        Connection, Libwayland,
+        connection::socket::resolve_socket_path_with,
+        utils::poller::{self, Poller},
+    },
+    cfg_if::cfg_if,
+    std::{
+        env,
+        ffi::{OsStr, OsString},
+        io,
+        os::{
+            fd::{FromRawFd, OwnedFd, RawFd},
+            unix::net::{SocketAddr, UnixStream},
+        },
+        path::PathBuf,
+        sync::Arc,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Options for connecting to a wayland compositor.
+///
+/// This type can be created with [`Libwayland::connect_options`]. By default, the
+/// options behave like [`Libwayland::connect_to_default_display`]:
+///
+/// 1. If the `WAYLAND_SOCKET` environment variable is set, the file descriptor
+///    contained in it is used.
+/// 2. Otherwise, the socket is `$XDG_RUNTIME_DIR/$WAYLAND_DISPLAY`, with
+///    `WAYLAND_DISPLAY` falling back to `wayland-0`. If `WAYLAND_DISPLAY` is an absolute
+///    path, it is used as is.
+///
+/// # Example
+///
+/// ```no_run
+/// # use wl_client::Libwayland;
+/// #
+/// let lib = Libwayland::open().unwrap();
+/// let _con = lib
+///     .connect_options()
+///     .inherit_wayland_socket(false)
+///     .runtime_dir("/run/user/1000")
+///     .display_name("wayland-1")
+///     .connect()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ConnectOptions {
+    libwayland: &'static Libwayland,
+    inherit_wayland_socket: bool,
+    runtime_dir: Option<PathBuf>,
+    display_name: Option<OsString>,
+    socket: Option<Socket>,
+}
+
+#[derive(Clone)]
+enum Socket {
+    Path(PathBuf),
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    Abstract(Vec<u8>),
+}
+
+enum Target {
+    Fd(OwnedFd),
+    Addr(SocketAddr, Option<PathBuf>),
+}
+
+impl Libwayland {
+    /// Creates [`ConnectOptions`] for fine-grained control over how the connection is
+    /// established.
+    pub fn connect_options(&'static self) -> ConnectOptions {
+        ConnectOptions {
+            libwayland: self,
+            inherit_wayland_socket: true,
+            runtime_dir: None,
+            display_name: None,
+            socket: None,
+        }
+    }
+}
+
+impl ConnectOptions {
+    /// Sets whether a socket inherited via `WAYLAND_SOCKET` is used.
+    ///
+    /// If this is enabled and the `WAYLAND_SOCKET` environment variable is set, the
+    /// file descriptor contained in the variable is used for the connection and all
+    /// other options are ignored. The file descriptor is marked close-on-exec and the
+    /// environment variable is removed so that it is not inherited by child processes.
+    ///
+    /// The default is `true`.
+    pub fn inherit_wayland_socket(mut self, inherit_wayland_socket: bool) -> Self {
+        self.inherit_wayland_socket = inherit_wayland_socket;
+        self
+    }
+
+    /// Sets the runtime directory that contains the display socket.
+    ///
+    /// If this is not set, the `XDG_RUNTIME_DIR` environment variable is used.
+    ///
+    /// This is ignored if the display name is an absolute path or if an explicit socket
+    /// has been set.
+    pub fn runtime_dir(mut self, runtime_dir: impl Into<PathBuf>) -> Self {
+        self.runtime_dir = Some(runtime_dir.into());
+        self
+    }
+
+    /// Sets the name of the display.
+    ///
+    /// This is usually of the form `wayland-N` and is interpreted relative to the runtime
+    /// directory. If it is an absolute path, the runtime directory is ignored.
+    ///
+    /// If this is not set, the `WAYLAND_DISPLAY` environment variable is used, falling
+    /// back to `wayland-0`.
+    pub fn display_name(mut self, display_name: impl Into<OsString>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Connects to the socket at the given path.
+    ///
+    /// This overrides the runtime directory and the display name.
+    pub fn socket_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.socket = Some(Socket::Path(path.into()));
+        self
+    }
+
+    /// Connects to the abstract socket with the given name.
+    ///
+    /// The name should not contain the leading null byte.
+    ///
+    /// This overrides the runtime directory and the display name.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn abstract_socket(mut self, name: impl Into<Vec<u8>>) -> Self {
+        self.socket = Some(Socket::Abstract(name.into()));
+        self
+    }
+
+    /// Connects to the compositor.
+    ///
+    /// This function blocks until the socket is connected.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let _con = lib.connect_options().connect().unwrap();
+    /// ```
+    pub fn connect(self) -> io::Result<Connection> {
+        let (fd, path) = match self.target()? {
+            Target::Fd(fd) => (fd, None),
+            Target::Addr(addr, path) => (UnixStream::connect_addr(&addr)?.into(), path),
+        };
+        self.libwayland.connect_to_fd_with_path(fd, path)
+    }
+
+    /// Connects to the compositor without blocking.
+    ///
+    /// The socket is connected in non-blocking mode and the returned future completes
+    /// once the connection has been established.
+    ///
+    /// If the listen backlog of the compositor is full, this function returns an error
+    /// of kind [`io::ErrorKind::WouldBlock`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// # async fn f() {
+    /// let lib = Libwayland::open().unwrap();
+    /// let _con = lib.connect_options().connect_async().await.unwrap();
+    /// # }
+    /// ```
+    pub async fn connect_async(self) -> io::Result<Connection> {
+        let (fd, path) = match self.target()? {
+            Target::Fd(fd) => (fd, None),
+            Target::Addr(addr, path) => {
+                let stream = mio::net::UnixStream::connect_addr(&addr)?;
+                let stream = Arc::new(UnixStream::from(stream));
+                {
+                    let poller = Poller::new(&stream)?;
+                    poller::writable(&poller.data).await?;
+                }
+                let stream = Arc::into_inner(stream).unwrap();
+                if let Some(err) = stream.take_error()? {
+                    return Err(err);
+                }
+                stream.set_nonblocking(false)?;
+                (stream.into(), path)
+            }
+        };
+        self.libwayland.connect_to_fd_with_path(fd, path)
+    }
+
+    fn target(&self) -> io::Result<Target> {
+        if self.inherit_wayland_socket {
+            if let Some(fd) = take_wayland_socket()? {
+                return Ok(Target::Fd(fd));
+            }
+        }
+        match &self.socket {
+            Some(Socket::Path(path)) => {
+                let addr = SocketAddr::from_pathname(path)?;
+                Ok(Target::Addr(addr, Some(path.clone())))
+            }
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            Some(Socket::Abstract(name)) => {
+                #[cfg(target_os = "android")]
+                use std::os::android::net::SocketAddrExt;
+                #[cfg(target_os = "linux")]
+                use std::os::linux::net::SocketAddrExt;
+                let addr = SocketAddr::from_abstract_name(name)?;
+                Ok(Target::Addr(addr, None))
+            }
+            None => {
+                let runtime_dir = match &self.runtime_dir {
+                    Some(dir) => Some(dir.clone().into_os_string()),
+                    None => env::var_os("XDG_RUNTIME_DIR"),
+                };
+                let path = resolve_socket_path_with(
+                    self.display_name.as_deref(),
+                    false,
+                    env::var_os("WAYLAND_DISPLAY"),
+                    runtime_dir,
+                )
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, "XDG_RUNTIME_DIR is not set")
+                })?;
+                let addr = SocketAddr::from_pathname(&path)?;
+                Ok(Target::Addr(addr, Some(path)))
+            }
+        }
+    }
+}
+
+/// Takes the file descriptor from the `WAYLAND_SOCKET` environment variable.
+///
+/// On success, the variable is removed from the environment.
+fn take_wayland_socket() -> io::Result<Option<OwnedFd>> {
+    let Some(var) = env::var_os("WAYLAND_SOCKET") else {
+        return Ok(None);
+    };
+    let fd = adopt_socket_fd(&var)?;
+    // SAFETY: wl_display_connect performs the same modification when it consumes
+    //         WAYLAND_SOCKET. Like connect_to_default_display, this function relies on
+    //         no other thread accessing the environment concurrently.
+    unsafe {
+        env::remove_var("WAYLAND_SOCKET");
+    }
+    Ok(Some(fd))
+}
+
+/// Takes ownership of the file descriptor contained in the value of `WAYLAND_SOCKET`.
+///
+/// The returned file descriptor is close-on-exec. On Linux, it has the same number as
+/// the file descriptor in the variable. On other systems, the file descriptor is
+/// duplicated and the original is closed.
+fn adopt_socket_fd(var: &OsStr) -> io::Result<OwnedFd> {
+    let fd = var
+        .to_str()
+        .and_then(|s| s.parse::<RawFd>().ok())
+        .filter(|&fd| fd >= 0)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "WAYLAND_SOCKET does not contain a file descriptor",
+            )
+        })?;
+    cfg_if! {
+        if #[cfg(target_os = "linux")] {
+            // SAFETY: F_GETFD has no requirements. If fd is not open, it fails with
+            //         EBADF.
+            let flags = unsafe { libc::fcntl(fd, libc::F_GETFD) };
+            if flags == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            // SAFETY: F_SETFD has no requirements and fd is open.
+            let ret = unsafe { libc::fcntl(fd, libc::F_SETFD, flags | libc::FD_CLOEXEC) };
+            if ret == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            // SAFETY: By setting WAYLAND_SOCKET, the parent process has handed us
+            //         ownership of the file descriptor and we've just verified that it
+            //         is open.
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        } else {
+            use std::os::fd::BorrowedFd;
+
+            // SAFETY: By setting WAYLAND_SOCKET, the parent process has handed us
+            //         ownership of the file descriptor. If it is not open, duplicating
+            //         it fails with EBADF.
+            let borrowed = unsafe { BorrowedFd::borrow_raw(fd) };
+            let owned = borrowed.try_clone_to_owned()?;
+            // SAFETY: We've just verified that the file descriptor is open and we own
+            //         it. The duplicate is close-on-exec, so the original can be closed.
+            drop(unsafe { OwnedFd::from_raw_fd(fd) });
+            Ok(owned)
+        }
+    }
+}
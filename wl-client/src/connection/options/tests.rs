@@ -0,0 +1,124 @@
+use {
+    crate::{Libwayland, connection::options::adopt_socket_fd, utils::block_on::block_on},
+    std::{
+        ffi::OsStr,
+        io::ErrorKind,
+        os::{
+            fd::{AsRawFd, IntoRawFd},
+            unix::net::{UnixListener, UnixStream},
+        },
+    },
+};
+
+#[test]
+fn socket_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("wayland-test");
+    let listener = UnixListener::bind(&path).unwrap();
+    let lib = Libwayland::open().unwrap();
+    let con = lib
+        .connect_options()
+        .inherit_wayland_socket(false)
+        .socket_path(&path)
+        .connect()
+        .unwrap();
+    assert_eq!(con.socket_path(), Some(&*path));
+    listener.accept().unwrap();
+}
+
+#[test]
+fn runtime_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("wayland-test");
+    let listener = UnixListener::bind(&path).unwrap();
+    let lib = Libwayland::open().unwrap();
+    let con = lib
+        .connect_options()
+        .inherit_wayland_socket(false)
+        .runtime_dir(dir.path())
+        .display_name("wayland-test")
+        .connect()
+        .unwrap();
+    assert_eq!(con.socket_path(), Some(&*path));
+    listener.accept().unwrap();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn abstract_socket() {
+    use std::os::{linux::net::SocketAddrExt, unix::net::SocketAddr};
+
+    let name = format!("wl-client-test-{}", std::process::id());
+    let addr = SocketAddr::from_abstract_name(&name).unwrap();
+    let listener = UnixListener::bind_addr(&addr).unwrap();
+    let lib = Libwayland::open().unwrap();
+    let con = lib
+        .connect_options()
+        .inherit_wayland_socket(false)
+        .abstract_socket(name)
+        .connect()
+        .unwrap();
+    assert!(con.socket_path().is_none());
+    listener.accept().unwrap();
+}
+
+#[test]
+fn connect_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("wayland-test");
+    let listener = UnixListener::bind(&path).unwrap();
+    let lib = Libwayland::open().unwrap();
+    let con = block_on(
+        lib.connect_options()
+            .inherit_wayland_socket(false)
+            .socket_path(&path)
+            .connect_async(),
+    )
+    .unwrap();
+    assert_eq!(con.socket_path(), Some(&*path));
+    listener.accept().unwrap();
+}
+
+#[test]
+fn connect_missing_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let lib = Libwayland::open().unwrap();
+    let res = lib
+        .connect_options()
+        .inherit_wayland_socket(false)
+        .socket_path(dir.path().join("wayland-test"))
+        .connect();
+    assert_eq!(res.err().unwrap().kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn adopt_socket() {
+    let (a, _b) = UnixStream::pair().unwrap();
+    let raw = a.into_raw_fd();
+    let fd = adopt_socket_fd(OsStr::new(&raw.to_string())).unwrap();
+    #[cfg(target_os = "linux")]
+    {
+        assert_eq!(fd.as_raw_fd(), raw);
+        // SAFETY: fd is a valid file descriptor.
+        let flags = unsafe { libc::fcntl(fd.as_raw_fd(), libc::F_GETFD) };
+        assert_ne!(flags & libc::FD_CLOEXEC, 0);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = fd.as_raw_fd();
+}
+
+#[test]
+fn adopt_socket_invalid() {
+    let err = adopt_socket_fd(OsStr::new("abc")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let err = adopt_socket_fd(OsStr::new("-1")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn adopt_socket_closed() {
+    let (a, _b) = UnixStream::pair().unwrap();
+    let raw = a.as_raw_fd();
+    drop(a);
+    assert!(adopt_socket_fd(OsStr::new(&raw.to_string())).is_err());
+}
@@ -2,7 +2,9 @@
 use crate::Queue;
 use {
     crate::{
-        BorrowedQueue, Connection, connection::data::ConnectionData2, utils::os_error::OsError,
+        BorrowedQueue, Connection,
+        connection::data::ConnectionData2,
+        utils::{os_error::OsError, trace},
     },
     parking_lot::{Condvar, Mutex},
     run_on_drop::on_drop,
@@ -304,6 +306,7 @@ fn read_thread(connection: Arc<ConnectionData2>, data: Arc<Data1>) {
         if d.state == State::ReadIfAble {
             d.state = State::Reading;
             drop(d);
+            let readable = connection.readable_bytes();
             // SAFETY: - The pointer returned by wl_display is valid.
             //         - By the invariants, ReadIfAble means that we are holding a ticket.
             //           We've consumed this ticket by changing the state to Reading.
@@ -315,7 +318,12 @@ fn read_thread(connection: Arc<ConnectionData2>, data: Arc<Data1>) {
             d = data.data.lock();
             d.last_error = None;
             if res == -1 {
-                d.last_error = Some(io::Error::last_os_error().into());
+                let err = io::Error::last_os_error();
+                trace::read_events(Err(&err));
+                d.last_error = Some(err.into());
+            } else {
+                connection.record_read(readable);
+                trace::read_events(Ok(()));
             }
             d.serial += 1;
             // SAFETY: We've consumed the ticket by calling wl_display_read_events.
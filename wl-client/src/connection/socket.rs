@@ -0,0 +1,104 @@
+use {
+    cfg_if::cfg_if,
+    std::{
+        env,
+        ffi::{CStr, OsStr, OsString},
+        io,
+        os::{fd::BorrowedFd, unix::ffi::OsStrExt},
+        path::{Path, PathBuf},
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The credentials of the process on the other end of a connection.
+///
+/// These credentials are captured by the kernel when the compositor creates or accepts
+/// the socket. They do not change if the process later changes its credentials.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PeerCredentials {
+    /// The process ID of the peer.
+    pub pid: i32,
+    /// The user ID of the peer.
+    pub uid: u32,
+    /// The group ID of the peer.
+    pub gid: u32,
+}
+
+/// Retrieves the credentials of the peer of a unix socket.
+pub(super) fn peer_credentials(fd: BorrowedFd<'_>) -> io::Result<PeerCredentials> {
+    cfg_if! {
+        if #[cfg(target_os = "linux")] {
+            use std::{mem, os::fd::AsRawFd};
+
+            // SAFETY: ucred is a plain C struct.
+            let mut cred: libc::ucred = unsafe { mem::zeroed() };
+            let mut len = size_of::<libc::ucred>() as libc::socklen_t;
+            // SAFETY: - fd is a valid file descriptor
+            //         - cred and len are valid pointers and len is the size of cred
+            let ret = unsafe {
+                libc::getsockopt(
+                    fd.as_raw_fd(),
+                    libc::SOL_SOCKET,
+                    libc::SO_PEERCRED,
+                    (&raw mut cred).cast(),
+                    &mut len,
+                )
+            };
+            if ret == -1 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(PeerCredentials {
+                pid: cred.pid,
+                uid: cred.uid,
+                gid: cred.gid,
+            })
+        } else {
+            let _ = fd;
+            Err(io::ErrorKind::Unsupported.into())
+        }
+    }
+}
+
+/// Resolves the path of the socket that `wl_display_connect` will connect to.
+///
+/// This must be called before `wl_display_connect` since that function might remove
+/// `WAYLAND_SOCKET` from the environment.
+pub(super) fn resolve_socket_path(display_name: Option<&CStr>) -> Option<PathBuf> {
+    resolve_socket_path_with(
+        display_name.map(|n| OsStr::from_bytes(n.to_bytes())),
+        env::var_os("WAYLAND_SOCKET").is_some(),
+        env::var_os("WAYLAND_DISPLAY"),
+        env::var_os("XDG_RUNTIME_DIR"),
+    )
+}
+
+/// Resolves the socket path following the rules of libwayland:
+///
+/// - If `WAYLAND_SOCKET` is set, libwayland uses the inherited file descriptor and there
+///   is no path.
+/// - Otherwise the display name is `display_name`, `WAYLAND_DISPLAY`, or `wayland-0`, in
+///   this order.
+/// - If the display name is an absolute path, that is the path of the socket.
+/// - Otherwise the display name is relative to `XDG_RUNTIME_DIR`. If that variable is
+///   not set, there is no path.
+pub(super) fn resolve_socket_path_with(
+    display_name: Option<&OsStr>,
+    wayland_socket: bool,
+    wayland_display: Option<OsString>,
+    xdg_runtime_dir: Option<OsString>,
+) -> Option<PathBuf> {
+    if wayland_socket {
+        return None;
+    }
+    let name = match display_name {
+        Some(n) => n.to_owned(),
+        None => wayland_display.unwrap_or_else(|| "wayland-0".into()),
+    };
+    let name = Path::new(&name);
+    if name.is_absolute() {
+        return Some(name.to_owned());
+    }
+    Some(Path::new(&xdg_runtime_dir?).join(name))
+}
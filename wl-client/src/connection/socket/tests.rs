@@ -0,0 +1,60 @@
+use {
+    crate::connection::socket::resolve_socket_path_with,
+    std::{
+        ffi::OsStr,
+        path::{Path, PathBuf},
+    },
+};
+
+fn resolve(
+    name: Option<&str>,
+    socket: bool,
+    display: Option<&str>,
+    runtime_dir: Option<&str>,
+) -> Option<PathBuf> {
+    resolve_socket_path_with(
+        name.map(OsStr::new),
+        socket,
+        display.map(Into::into),
+        runtime_dir.map(Into::into),
+    )
+}
+
+#[test]
+fn default_display() {
+    let path = resolve(None, false, None, Some("/run/user/1000"));
+    assert_eq!(path.as_deref(), Some(Path::new("/run/user/1000/wayland-0")));
+}
+
+#[test]
+fn wayland_display() {
+    let path = resolve(None, false, Some("wayland-2"), Some("/run/user/1000"));
+    assert_eq!(path.as_deref(), Some(Path::new("/run/user/1000/wayland-2")));
+}
+
+#[test]
+fn explicit_name() {
+    let path = resolve(Some("wayland-3"), false, Some("wayland-2"), Some("/run"));
+    assert_eq!(path.as_deref(), Some(Path::new("/run/wayland-3")));
+}
+
+#[test]
+fn absolute_name() {
+    let path = resolve(Some("/tmp/wl.sock"), false, None, None);
+    assert_eq!(path.as_deref(), Some(Path::new("/tmp/wl.sock")));
+    let path = resolve(None, false, Some("/tmp/wl.sock"), Some("/run"));
+    assert_eq!(path.as_deref(), Some(Path::new("/tmp/wl.sock")));
+}
+
+#[test]
+fn no_runtime_dir() {
+    assert_eq!(resolve(None, false, Some("wayland-1"), None), None);
+}
+
+#[test]
+fn wayland_socket() {
+    assert_eq!(
+        resolve(Some("/tmp/wl.sock"), true, None, Some("/run")),
+        None
+    );
+}
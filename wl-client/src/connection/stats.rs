@@ -0,0 +1,228 @@
+use {
+    crate::{Connection, connection::data::ConnectionData2},
+    std::{
+        sync::{
+            Arc, LazyLock,
+            atomic::{AtomicBool, AtomicU64, Ordering::Relaxed},
+        },
+        time::{Duration, Instant},
+    },
+};
+
+/// Statistics about the data sent and received over a connection.
+///
+/// Statistics are disabled by default. They can be enabled with
+/// [`Connection::set_stats_enabled`].
+///
+/// The byte counts are taken from the socket. `bytes_written` counts the bytes written
+/// by the flushes performed by this crate.
+///
+/// `bytes_read` is an approximation. When this crate reads events, it compares the
+/// number of bytes available on the socket before and after the read. Bytes that arrive
+/// while the events are being read are subtracted from the count. Reads performed by
+/// libwayland without going through this crate, such as `wl_display_dispatch` or
+/// `wl_display_roundtrip` called by other libraries, are not counted at all. The count
+/// is only available on Linux and is always 0 on other platforms.
+///
+/// libwayland does not expose the file descriptors transferred over the socket. The
+/// file descriptor counts are therefore computed from the requests sent on proxies
+/// created by this crate and from the events dispatched to event handlers set with
+/// this crate.
+///
+/// You can retrieve these statistics with [`Connection::stats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ConnectionStats {
+    /// The number of bytes written to the socket.
+    pub bytes_written: u64,
+    /// The number of file descriptors sent.
+    pub fds_written: u64,
+    /// The approximate number of bytes read from the socket.
+    pub bytes_read: u64,
+    /// The number of file descriptors received.
+    pub fds_read: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct ConnectionStatsData {
+    enabled: AtomicBool,
+    bytes_written: AtomicU64,
+    fds_written: AtomicU64,
+    bytes_read: AtomicU64,
+    fds_read: AtomicU64,
+    /// The time of the last successful read from the socket in nanoseconds since EPOCH,
+    /// or 0 if the socket has not been read since statistics were enabled.
+    last_read: AtomicU64,
+}
+
+static EPOCH: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+fn nanos_since_epoch(instant: Instant) -> u64 {
+    instant.saturating_duration_since(*EPOCH).as_nanos() as u64
+}
+
+impl ConnectionStatsData {
+    /// Returns whether statistics are enabled.
+    #[inline]
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled.load(Relaxed)
+    }
+
+    /// Records that `bytes` bytes have been written to the socket.
+    pub(crate) fn record_write(&self, bytes: u64) {
+        if self.enabled() {
+            self.bytes_written.fetch_add(bytes, Relaxed);
+        }
+    }
+
+    /// Records that file descriptors have been sent or received.
+    pub(crate) fn record_fds(&self, sent: bool, fds: u64) {
+        if !self.enabled() {
+            return;
+        }
+        let f = match sent {
+            true => &self.fds_written,
+            false => &self.fds_read,
+        };
+        f.fetch_add(fds, Relaxed);
+    }
+
+    /// Returns the time between the last read from the socket and `now`.
+    pub(crate) fn time_since_read(&self, now: Instant) -> Option<Duration> {
+        let last_read = self.last_read.load(Relaxed);
+        if last_read == 0 {
+            return None;
+        }
+        let now = nanos_since_epoch(now);
+        Some(Duration::from_nanos(now.saturating_sub(last_read)))
+    }
+
+    fn snapshot(&self, reset: bool) -> ConnectionStats {
+        let get = |v: &AtomicU64| match reset {
+            true => v.swap(0, Relaxed),
+            false => v.load(Relaxed),
+        };
+        ConnectionStats {
+            bytes_written: get(&self.bytes_written),
+            fds_written: get(&self.fds_written),
+            bytes_read: get(&self.bytes_read),
+            fds_read: get(&self.fds_read),
+        }
+    }
+}
+
+impl ConnectionData2 {
+    /// Returns the number of bytes that can be read from the socket without blocking.
+    ///
+    /// Returns `None` if statistics are disabled or if the number is not available on
+    /// this platform.
+    pub(crate) fn readable_bytes(&self) -> Option<u64> {
+        if !self.stats.enabled() {
+            return None;
+        }
+        cfg_if::cfg_if! {
+            if #[cfg(target_os = "linux")] {
+                // SAFETY: The display function returns a valid pointer.
+                let fd = unsafe { self.libwayland.wl_display_get_fd(self.wl_display().as_ptr()) };
+                let mut n: libc::c_int = 0;
+                // SAFETY: fd is the open socket of the display and FIONREAD writes a
+                //         c_int.
+                let ret = unsafe { libc::ioctl(fd, libc::FIONREAD, &mut n) };
+                (ret == 0).then_some(n as u64)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Records that events have been read from the socket.
+    ///
+    /// `readable` must be the value returned by [`Self::readable_bytes`] before the
+    /// events were read. The difference to the number of readable bytes after the read
+    /// is recorded as the number of bytes read. This undercounts if more data arrives
+    /// in between.
+    pub(crate) fn record_read(&self, readable: Option<u64>) {
+        let stats = &self.stats;
+        if !stats.enabled() {
+            return;
+        }
+        stats
+            .last_read
+            .store(nanos_since_epoch(Instant::now()).max(1), Relaxed);
+        if let Some(before) = readable {
+            let after = self.readable_bytes().unwrap_or_default();
+            stats
+                .bytes_read
+                .fetch_add(before.saturating_sub(after), Relaxed);
+        }
+    }
+}
+
+impl Connection {
+    /// Returns statistics about the data sent and received over this connection.
+    ///
+    /// See the documentation of [`ConnectionStats`] for what is counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// con.set_stats_enabled(true);
+    /// let stats = con.stats();
+    /// println!("bytes written: {}", stats.bytes_written);
+    /// ```
+    pub fn stats(&self) -> ConnectionStats {
+        self.data.data.stats.snapshot(false)
+    }
+
+    /// Resets the statistics of this connection.
+    ///
+    /// This function returns the statistics before the reset. Since the statistics are
+    /// retrieved and reset in one step, no messages are lost when sampling the statistics
+    /// periodically with this function.
+    pub fn reset_stats(&self) -> ConnectionStats {
+        self.data.data.stats.snapshot(true)
+    }
+
+    /// Enables or disables statistics for this connection and its queues.
+    ///
+    /// Statistics are disabled by default. While they are disabled, none of the
+    /// counters in [`ConnectionStats`] and [`QueueStats`](crate::QueueStats) are
+    /// updated.
+    ///
+    /// Requests and events are only counted per interface for proxies created while
+    /// statistics are enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// assert!(!con.stats_enabled());
+    /// con.set_stats_enabled(true);
+    /// assert!(con.stats_enabled());
+    /// ```
+    pub fn set_stats_enabled(&self, enabled: bool) {
+        let stats = &self.data.data.stats;
+        stats.enabled.store(enabled, Relaxed);
+        if !enabled {
+            stats.last_read.store(0, Relaxed);
+        }
+    }
+
+    /// Returns whether statistics are enabled for this connection.
+    ///
+    /// See [`Connection::set_stats_enabled`].
+    pub fn stats_enabled(&self) -> bool {
+        self.data.data.stats.enabled()
+    }
+
+    pub(crate) fn stats_data(&self) -> &Arc<ConnectionStatsData> {
+        &self.data.data.stats
+    }
+}
@@ -1,7 +1,13 @@
-use crate::{
-    Libwayland, proxy,
-    test_protocol_helpers::get_root,
-    test_protocols::core::wl_string::{WlString, WlStringEventHandler, WlStringRef},
+use {
+    crate::{
+        Libwayland, proxy,
+        test_protocol_helpers::get_root,
+        test_protocols::core::wl_string::{WlString, WlStringEventHandler, WlStringRef},
+    },
+    std::{
+        thread,
+        time::{Duration, Instant},
+    },
 };
 
 #[test]
@@ -97,3 +103,86 @@ fn get_server_name() {
         }
     }
 }
+
+#[test]
+#[cfg(target_os = "linux")]
+fn peer_credentials() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let cred = con.peer_credentials().unwrap();
+    assert_eq!(cred.pid, std::process::id() as i32);
+    // SAFETY: getuid and getgid are always safe to call.
+    unsafe {
+        assert_eq!(cred.uid, libc::getuid());
+        assert_eq!(cred.gid, libc::getgid());
+    }
+}
+
+#[test]
+fn socket_path_borrowed() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    // SAFETY: con.wl_display() is a valid display that outlives con2.
+    let con2 = unsafe { lib.wrap_borrowed_pointer(con.wl_display()).unwrap() };
+    assert!(con2.socket_path().is_none());
+}
+
+#[test]
+fn stats() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"");
+    let root = get_root(&queue);
+    con.set_stats_enabled(true);
+    let string = root.echo("abcd");
+    proxy::set_event_handler(&string, Eh);
+    queue.dispatch_roundtrip_blocking().unwrap();
+    // The flush is recorded by the flusher thread after it has returned.
+    let start = Instant::now();
+    // echo(new_id, "abcd") + sync(new_id)
+    while con.stats().bytes_written < 24 + 12 {
+        assert!(start.elapsed() < Duration::from_secs(10));
+        thread::yield_now();
+    }
+    let stats = con.stats();
+    if cfg!(target_os = "linux") {
+        assert!(stats.bytes_read > 0);
+    }
+    assert_eq!(stats.fds_written, 0);
+    assert_eq!(stats.fds_read, 0);
+    assert_eq!(con.reset_stats(), stats);
+    assert_eq!(con.stats(), Default::default());
+
+    struct Eh;
+    impl WlStringEventHandler for Eh {
+        fn string(&self, _slf: &WlStringRef, _string: &str) {}
+    }
+}
+
+#[test]
+fn stats_disabled() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    assert!(!con.stats_enabled());
+    let queue = con.create_queue(c"");
+    let root = get_root(&queue);
+    let _string = root.echo("abcd");
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(con.stats(), Default::default());
+    assert_eq!(queue.stats(), Default::default());
+}
+
+#[test]
+fn stats_fds_disabled() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let stats = &con.data.data.stats;
+    stats.record_fds(true, 1);
+    stats.record_fds(false, 1);
+    assert_eq!(con.stats(), Default::default());
+    con.set_stats_enabled(true);
+    stats.record_fds(true, 1);
+    stats.record_fds(false, 2);
+    assert_eq!(con.stats().fds_written, 1);
+    assert_eq!(con.stats().fds_read, 2);
+}
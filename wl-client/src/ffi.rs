@@ -5,10 +5,15 @@
 #![expect(non_camel_case_types)]
 
 use {
+    crate::Libwayland,
     isnt::std_1::primitive::IsntConstPtrExt,
     std::{
         ffi::{CStr, c_char, c_int, c_void},
-        ptr,
+        ptr, slice,
+        sync::atomic::{
+            AtomicPtr,
+            Ordering::{Acquire, Release},
+        },
     },
 };
 
@@ -44,6 +49,65 @@ unsafe impl Send for wl_interface {}
 // SAFETY: pointers not implementing Sync is only a lint
 unsafe impl Sync for wl_interface {}
 
+/// A `wl_interface` defined by libwayland-client.
+///
+/// Protocol wrappers generated with `with_libwayland_interfaces` use this type to refer
+/// to the `wl_*_interface` symbols of libwayland-client. The symbol is looked up in the
+/// library loaded by [`Libwayland::open`] on first use and then cached.
+///
+/// The symbol is only used if its requests and events are a prefix of the requests and
+/// events of the fallback interface. This is not the case if libwayland-client was built
+/// from a newer version of the protocol than the fallback.
+pub struct LibwaylandInterface {
+    symbol: &'static CStr,
+    fallback: &'static wl_interface,
+    interface: AtomicPtr<wl_interface>,
+}
+
+impl LibwaylandInterface {
+    /// Creates a new lazily resolved interface.
+    ///
+    /// `symbol` is the name of the symbol, such as `wl_surface_interface`. `fallback` is
+    /// used if libwayland-client cannot be loaded, does not define the symbol, or defines
+    /// an interface that is not a prefix of `fallback`.
+    pub const fn new(symbol: &'static CStr, fallback: &'static wl_interface) -> Self {
+        Self {
+            symbol,
+            fallback,
+            interface: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Returns the interface.
+    ///
+    /// This is the interface defined by libwayland-client or the fallback if
+    /// libwayland-client cannot be loaded, does not define the symbol, or defines an
+    /// interface that is not a prefix of the fallback.
+    #[inline]
+    pub fn get(&self) -> &'static wl_interface {
+        let mut interface = self.interface.load(Acquire);
+        if interface.is_null() {
+            interface = self.resolve();
+        }
+        // SAFETY: interface is only ever set to a pointer derived from a
+        //         &'static wl_interface.
+        unsafe { &*interface }
+    }
+
+    #[cold]
+    fn resolve(&self) -> *mut wl_interface {
+        let interface = Libwayland::open()
+            .ok()
+            .and_then(|lib| lib.interface_symbol(self.symbol))
+            // SAFETY: Both interfaces are valid.
+            .filter(|interface| unsafe { interface_is_prefix(interface, self.fallback) })
+            .unwrap_or(self.fallback);
+        let interface = ptr::from_ref(interface).cast_mut();
+        self.interface.store(interface, Release);
+        interface
+    }
+}
+
 /// The libwayland `wl_array` type.
 #[repr(C)]
 pub struct wl_array {
@@ -146,6 +210,82 @@ pub struct wl_event_queue(());
 /// to call wl_proxy_destroy. This would cause a fatal error in libwayland.
 pub(crate) const WL_MARSHAL_FLAG_DESTROY: u32 = 1 << 0;
 
+/// Returns the messages of an interface as a slice.
+///
+/// # Safety
+///
+/// - `messages` must be valid for `count` elements if `count` is positive.
+pub(crate) unsafe fn messages(messages: *const wl_message, count: i32) -> &'static [wl_message] {
+    if count <= 0 || messages.is_null() {
+        return &[];
+    }
+    // SAFETY: The requirement is forwarded to the caller.
+    unsafe { slice::from_raw_parts(messages, count as usize) }
+}
+
+/// Returns whether the requests and events of `l` are a prefix of the requests and
+/// events of `r`.
+///
+/// Unlike [`interface_compatible`], the interfaces of object and new_id arguments are
+/// only compared by name.
+///
+/// # Safety
+///
+/// `l` and `r` must be valid `wl_interface` definitions.
+pub(crate) unsafe fn interface_is_prefix(l: &wl_interface, r: &wl_interface) -> bool {
+    if ptr::eq(l, r) {
+        return true;
+    }
+    // SAFETY: This function requires that l and r are valid interface definitions.
+    unsafe {
+        messages_are_prefix(
+            messages(l.methods, l.method_count),
+            messages(r.methods, r.method_count),
+        ) && messages_are_prefix(
+            messages(l.events, l.event_count),
+            messages(r.events, r.event_count),
+        )
+    }
+}
+
+/// # Safety
+///
+/// The messages must be valid `wl_message` definitions.
+unsafe fn messages_are_prefix(l: &[wl_message], r: &[wl_message]) -> bool {
+    if l.len() > r.len() {
+        return false;
+    }
+    for (l, r) in l.iter().zip(r) {
+        // SAFETY: The signature of a valid message is a valid C string.
+        let sig = unsafe { CStr::from_ptr(l.signature) };
+        // SAFETY: Dito
+        if sig != unsafe { CStr::from_ptr(r.signature) } {
+            return false;
+        }
+        let num_types = sig
+            .to_bytes()
+            .iter()
+            .filter(|b| !matches!(b, b'0'..=b'9' | b'?'))
+            .count();
+        for idx in 0..num_types {
+            // SAFETY: The types array of a valid message contains one element per type in
+            //         the signature.
+            let (l_if, r_if) = unsafe { (*l.types.add(idx), *r.types.add(idx)) };
+            if l_if.is_null() != r_if.is_null() {
+                return false;
+            }
+            // SAFETY: Non-null types of a valid message are valid interfaces whose names
+            //         are valid C strings.
+            if l_if.is_not_null()
+                && unsafe { CStr::from_ptr((*l_if).name) != CStr::from_ptr((*r_if).name) }
+            {
+                return false;
+            }
+        }
+    }
+    true
+}
+
 /// # Safety
 ///
 /// `l` and `r` must be valid `wl_interface` definitions.
@@ -1,5 +1,12 @@
 use {
-    crate::ffi::{interface_compatible, wl_interface, wl_message},
+    crate::{
+        ffi::{
+            LibwaylandInterface, interface_compatible, interface_is_prefix, wl_interface,
+            wl_message,
+        },
+        protocols::wayland::wl_callback::WlCallback,
+        proxy::OwnedProxy,
+    },
     std::ptr,
 };
 
@@ -710,3 +717,200 @@ fn compatible_multiple_same_types_mixed() {
         assert!(interface_compatible(&P2, &P1));
     }
 }
+
+#[test]
+fn libwayland_interface() {
+    static FALLBACK: wl_interface = wl_interface {
+        name: c"wl_callback".as_ptr(),
+        version: 1,
+        method_count: 0,
+        methods: ptr::null(),
+        event_count: 1,
+        events: messages![wl_message {
+            name: c"done".as_ptr(),
+            signature: c"u".as_ptr(),
+            types: types![None],
+        }],
+    };
+    static INTERFACE: LibwaylandInterface =
+        LibwaylandInterface::new(c"wl_callback_interface", &FALLBACK);
+    assert!(ptr::eq(INTERFACE.get(), WlCallback::WL_INTERFACE));
+    assert!(ptr::eq(INTERFACE.get(), WlCallback::WL_INTERFACE));
+}
+
+#[test]
+fn libwayland_interface_fallback() {
+    static FALLBACK: wl_interface = wl_interface {
+        name: c"wl_unknown".as_ptr(),
+        version: 1,
+        method_count: 0,
+        methods: ptr::null(),
+        event_count: 0,
+        events: ptr::null(),
+    };
+    static INTERFACE: LibwaylandInterface =
+        LibwaylandInterface::new(c"wl_unknown_interface", &FALLBACK);
+    assert!(ptr::eq(INTERFACE.get(), &FALLBACK));
+}
+
+#[test]
+fn prefix() {
+    interface!(
+        A,
+        wl_interface {
+            name: c"a".as_ptr(),
+            version: 1,
+            method_count: 0,
+            methods: ptr::null(),
+            event_count: 0,
+            events: ptr::null(),
+        }
+    );
+    interface!(
+        P1,
+        wl_interface {
+            name: c"p".as_ptr(),
+            version: 1,
+            method_count: 1,
+            methods: messages![wl_message {
+                name: c"r1".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: types![Some(A)],
+            }],
+            event_count: 1,
+            events: messages![wl_message {
+                name: c"e1".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: types![None],
+            }],
+        }
+    );
+    interface!(
+        P2,
+        wl_interface {
+            name: c"p".as_ptr(),
+            version: 2,
+            method_count: 2,
+            methods: messages![
+                wl_message {
+                    name: c"r1".as_ptr(),
+                    signature: c"?o".as_ptr(),
+                    types: types![Some(&wl_interface {
+                        name: c"a".as_ptr(),
+                        version: 2,
+                        method_count: 0,
+                        methods: ptr::null(),
+                        event_count: 0,
+                        events: ptr::null(),
+                    })],
+                },
+                wl_message {
+                    name: c"r2".as_ptr(),
+                    signature: c"2".as_ptr(),
+                    types: types![],
+                },
+            ],
+            event_count: 2,
+            events: messages![
+                wl_message {
+                    name: c"e1".as_ptr(),
+                    signature: c"u".as_ptr(),
+                    types: types![None],
+                },
+                wl_message {
+                    name: c"e2".as_ptr(),
+                    signature: c"2i".as_ptr(),
+                    types: types![None],
+                },
+            ],
+        }
+    );
+    interface!(
+        P3,
+        wl_interface {
+            name: c"p".as_ptr(),
+            version: 1,
+            method_count: 1,
+            methods: messages![wl_message {
+                name: c"r1".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: types![Some(P1)],
+            }],
+            event_count: 1,
+            events: messages![wl_message {
+                name: c"e1".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: types![None],
+            }],
+        }
+    );
+    interface!(
+        P4,
+        wl_interface {
+            name: c"p".as_ptr(),
+            version: 1,
+            method_count: 1,
+            methods: messages![wl_message {
+                name: c"r1".as_ptr(),
+                signature: c"?o".as_ptr(),
+                types: types![Some(A)],
+            }],
+            event_count: 1,
+            events: messages![wl_message {
+                name: c"e1".as_ptr(),
+                signature: c"i".as_ptr(),
+                types: types![None],
+            }],
+        }
+    );
+    unsafe {
+        assert!(interface_is_prefix(P1, P1));
+        assert!(interface_is_prefix(P1, P2));
+        assert!(!interface_is_prefix(P2, P1));
+        assert!(!interface_is_prefix(P3, P2));
+        assert!(!interface_is_prefix(P4, P2));
+        assert!(!interface_is_prefix(P2, A));
+        assert!(interface_is_prefix(A, P2));
+    }
+}
+
+#[test]
+fn libwayland_interface_not_a_prefix() {
+    static FALLBACK: wl_interface = wl_interface {
+        name: c"wl_callback".as_ptr(),
+        version: 1,
+        method_count: 0,
+        methods: ptr::null(),
+        event_count: 0,
+        events: ptr::null(),
+    };
+    static INTERFACE: LibwaylandInterface =
+        LibwaylandInterface::new(c"wl_callback_interface", &FALLBACK);
+    assert!(ptr::eq(INTERFACE.get(), &FALLBACK));
+}
+
+#[test]
+fn libwayland_interface_prefix() {
+    static FALLBACK: wl_interface = wl_interface {
+        name: c"wl_callback".as_ptr(),
+        version: 2,
+        method_count: 0,
+        methods: ptr::null(),
+        event_count: 2,
+        events: messages![
+            wl_message {
+                name: c"done".as_ptr(),
+                signature: c"u".as_ptr(),
+                types: types![None],
+            },
+            wl_message {
+                name: c"done2".as_ptr(),
+                signature: c"2u".as_ptr(),
+                types: types![None],
+            },
+        ],
+    };
+    static INTERFACE: LibwaylandInterface =
+        LibwaylandInterface::new(c"wl_callback_interface", &FALLBACK);
+    assert!(ptr::eq(INTERFACE.get(), WlCallback::WL_INTERFACE));
+}
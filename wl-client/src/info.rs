@@ -0,0 +1,214 @@
+//! Runtime metadata about generated protocols.
+//!
+//! Every interface generated by `wl-client-builder` has a `pub static INFO: InterfaceInfo`
+//! in its module and every protocol has a `pub static PROTOCOL: ProtocolInfo` in its
+//! module. The `mod.rs` of the generated code additionally contains
+//!
+//! - `pub static PROTOCOLS: &[&ProtocolInfo]`: All protocols generated into this
+//!   directory.
+//! - `pub fn interface_info(name: &str) -> Option<&'static InterfaceInfo>`: Looks up an
+//!   interface by name.
+//!
+//! This metadata is not used by this crate itself. It allows tools such as protocol
+//! inspectors to print argument names and to decode enum values without access to the
+//! protocol XML files.
+//!
+//! # Example
+//!
+//! ```
+//! # use wl_client::test_protocols::{core::wl_seat, interface_info};
+//! #
+//! let info = interface_info("wl_seat").unwrap();
+//! assert_eq!(info.version, wl_seat::INFO.version);
+//! let capabilities = info.enum_("capability").unwrap();
+//! assert_eq!(capabilities.format(3).to_string(), "keyboard|0x1");
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+/// Metadata about a protocol.
+#[derive(Debug)]
+pub struct ProtocolInfo {
+    /// The name of the protocol, e.g. `wayland`.
+    pub name: &'static str,
+    /// The interfaces defined in the protocol.
+    pub interfaces: &'static [&'static InterfaceInfo],
+}
+
+/// Metadata about an interface.
+#[derive(Debug)]
+pub struct InterfaceInfo {
+    /// The name of the interface, e.g. `wl_surface`.
+    pub name: &'static str,
+    /// The name of the protocol that defines the interface.
+    pub protocol: &'static str,
+    /// The maximum version of the interface.
+    pub version: u32,
+    /// The requests of the interface, indexed by opcode.
+    pub requests: &'static [MessageInfo],
+    /// The events of the interface, indexed by opcode.
+    pub events: &'static [MessageInfo],
+    /// The enums defined in the interface.
+    pub enums: &'static [EnumInfo],
+}
+
+/// Metadata about a request or an event.
+#[derive(Debug)]
+pub struct MessageInfo {
+    /// The name of the message, e.g. `attach`.
+    pub name: &'static str,
+    /// The version in which the message was added.
+    pub since: u32,
+    /// The version in which the message was deprecated, if any.
+    pub deprecated_since: Option<u32>,
+    /// Whether this message is a destructor.
+    pub destructor: bool,
+    /// The arguments of the message.
+    pub args: &'static [ArgInfo],
+}
+
+/// Metadata about a message argument.
+#[derive(Debug)]
+pub struct ArgInfo {
+    /// The name of the argument.
+    pub name: &'static str,
+    /// The type of the argument.
+    pub ty: ArgType,
+    /// The interface of an `object` or `new_id` argument, if known statically.
+    pub interface: Option<&'static str>,
+    /// Whether the argument can be null.
+    pub allow_null: bool,
+    /// The enum of the argument, if any.
+    ///
+    /// This is the name as written in the protocol, i.e. either `name` for an enum of
+    /// the same interface or `interface.name` for an enum of another interface.
+    pub enum_: Option<&'static str>,
+}
+
+/// The type of a message argument.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ArgType {
+    /// A new object.
+    NewId,
+    /// A signed 32-bit integer.
+    Int,
+    /// An unsigned 32-bit integer.
+    Uint,
+    /// A signed 24.8 fixed-point number.
+    Fixed,
+    /// A string.
+    String,
+    /// An existing object.
+    Object,
+    /// A byte array.
+    Array,
+    /// A file descriptor.
+    Fd,
+}
+
+/// Metadata about an enum.
+#[derive(Debug)]
+pub struct EnumInfo {
+    /// The name of the enum, e.g. `capability`.
+    pub name: &'static str,
+    /// The version in which the enum was added.
+    pub since: u32,
+    /// Whether the enum is a bitfield.
+    pub bitfield: bool,
+    /// The entries of the enum.
+    pub entries: &'static [EntryInfo],
+}
+
+/// Metadata about an enum entry.
+#[derive(Debug)]
+pub struct EntryInfo {
+    /// The name of the entry, e.g. `keyboard`.
+    pub name: &'static str,
+    /// The value of the entry.
+    pub value: u32,
+    /// The version in which the entry was added.
+    pub since: u32,
+    /// The version in which the entry was deprecated, if any.
+    pub deprecated_since: Option<u32>,
+}
+
+impl InterfaceInfo {
+    /// Returns the request with the given opcode.
+    pub fn request(&self, opcode: u32) -> Option<&'static MessageInfo> {
+        self.requests.get(opcode as usize)
+    }
+
+    /// Returns the event with the given opcode.
+    pub fn event(&self, opcode: u32) -> Option<&'static MessageInfo> {
+        self.events.get(opcode as usize)
+    }
+
+    /// Returns the enum with the given name.
+    pub fn enum_(&self, name: &str) -> Option<&'static EnumInfo> {
+        self.enums.iter().find(|e| e.name == name)
+    }
+}
+
+impl EnumInfo {
+    /// Returns the first entry with the given value.
+    pub fn entry(&self, value: u32) -> Option<&'static EntryInfo> {
+        self.entries.iter().find(|e| e.value == value)
+    }
+
+    /// Returns a type that formats a value of this enum.
+    ///
+    /// For regular enums, this prints the name of the entry or the number if there is
+    /// no such entry. For bitfields, this prints the names of all contained non-zero
+    /// entries separated by `|` followed by any remaining bits as a hex number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::test_protocols::core::wl_seat;
+    /// #
+    /// let capability = wl_seat::INFO.enum_("capability").unwrap();
+    /// assert_eq!(capability.format(2).to_string(), "keyboard");
+    /// assert_eq!(capability.format(0x12).to_string(), "keyboard|0x10");
+    /// assert_eq!(capability.format(0).to_string(), "0");
+    /// ```
+    pub fn format(&self, value: u32) -> impl Display + use<'_> {
+        EnumValue { info: self, value }
+    }
+}
+
+struct EnumValue<'a> {
+    info: &'a EnumInfo,
+    value: u32,
+}
+
+impl Display for EnumValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.info.bitfield || self.value == 0 {
+            return match self.info.entry(self.value) {
+                Some(entry) => f.write_str(entry.name),
+                None => write!(f, "{}", self.value),
+            };
+        }
+        let mut remaining = self.value;
+        let mut first = true;
+        let mut sep = |f: &mut Formatter<'_>| {
+            if !first {
+                f.write_str("|")?;
+            }
+            first = false;
+            Ok(())
+        };
+        for entry in self.info.entries {
+            if entry.value != 0 && remaining & entry.value == entry.value {
+                sep(f)?;
+                f.write_str(entry.name)?;
+                remaining &= !entry.value;
+            }
+        }
+        if remaining != 0 {
+            sep(f)?;
+            write!(f, "0x{remaining:x}")?;
+        }
+        Ok(())
+    }
+}
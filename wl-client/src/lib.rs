@@ -100,8 +100,8 @@
 //!
 //! ```
 //! # use wl_client::{proxy, Libwayland};
-//! # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
-//! # use wl_client::test_protocols_data::core::wl_registry::WlRegistry;
+//! # use wl_client::test_protocols::core::wl_display::WlDisplay;
+//! # use wl_client::test_protocols::core::wl_registry::WlRegistry;
 //! #
 //! struct State {
 //!     registry: WlRegistry,
@@ -133,7 +133,7 @@
 //! // form.
 //! proxy::set_event_handler(
 //!     &state.registry,
-//!     WlRegistry::on_global(|state: &mut State, _, name, interface, version| {
+//!     WlRegistry::on_global_with_data(|state: &mut State, _, name, interface, version| {
 //!         state.globals.push(Global {
 //!             name,
 //!             interface: interface.to_string(),
@@ -196,9 +196,12 @@
 //!     }
 //! }
 //!
-//! // If more than one event type needs to be handled by an event handler, the convenient
-//! // functional API cannot be used. Instead the application needs to implement the
-//! // `*EventHandler` trait manually.
+//! // If more than one event type needs to be handled by an event handler, the
+//! // `*EventHandler` trait can be implemented manually. Alternatively, closures for
+//! // multiple events can be combined with
+//! // `WlKeyboard::handler().on_key(...).on_modifiers(...)`.
+//! // Event handlers that need `&mut self` can implement `WlKeyboardEventHandlerMut`
+//! // instead and be attached with `proxy::set_event_handler_local_mut`.
 //! impl WlKeyboardEventHandler for SeatEventHandler {
 //!     fn key(&self,
 //!         _slf: &WlKeyboardRef,
@@ -333,22 +336,39 @@
 //! }
 //! # }
 //! ```
+//!
+//! # Features
+//!
+//! - `tracing`: Emits [tracing](https://docs.rs/tracing) spans for queue dispatches and
+//!   event handler invocations, and events for flush, read, and connection errors.
+//! - `macros`: Provides the `generate!` macro that generates protocol wrappers without
+//!   a `build.rs`.
 
 #![allow(clippy::len_zero)]
 
 pub use {
-    connection::{Connection, wait_for_events::QueueWatcher},
+    connection::{
+        Connection, options::ConnectOptions, socket::PeerCredentials, stats::ConnectionStats,
+        wait_for_events::QueueWatcher,
+    },
     fixed::Fixed,
     libwayland::Libwayland,
     proxy::low_level::owned::scope::Scope,
-    queue::{BorrowedQueue, DispatchLock, Queue, QueueOwner, QueueWithData},
+    queue::{
+        BorrowedQueue, DispatchLock, Queue, QueueOwner, QueueWithData,
+        stats::{EventStats, InterfaceStats, QueueStats},
+    },
 };
 
+#[cfg(feature = "macros")]
+pub use wl_client_macros::generate;
+
 #[doc(hidden)]
 pub mod builder;
 mod connection;
 pub mod ffi;
 mod fixed;
+pub mod info;
 #[cfg_attr(any(test, feature = "_doctests"), path = "libwayland_test.rs")]
 mod libwayland;
 mod protocols;
@@ -358,8 +378,6 @@ mod queue;
 pub mod test_protocol_helpers;
 #[cfg(any(test, feature = "_doctests"))]
 pub mod test_protocols;
-#[cfg(any(test, feature = "_doctests"))]
-pub mod test_protocols_data;
 #[cfg(test)]
 mod tests;
 mod utils;
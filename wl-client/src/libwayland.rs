@@ -5,8 +5,9 @@ use {
     libloading::Library,
     parking_lot::Mutex,
     std::{
-        ffi::{c_char, c_int, c_void},
-        io, mem,
+        ffi::{CStr, c_char, c_int, c_void},
+        io,
+        mem::ManuallyDrop,
         sync::LazyLock,
     },
 };
@@ -15,6 +16,7 @@ use {
 ///
 /// You can obtain a reference by calling [`Self::open`].
 pub struct Libwayland {
+    lib: ManuallyDrop<Library>,
     syms: Symbols,
     syms_opt: SymbolsOpt,
 }
@@ -110,6 +112,8 @@ unsafe_symbols! {
     >,
     wl_proxy_get_queue: Option<unsafe extern "C" fn(proxy: *mut wl_proxy) -> *mut wl_event_queue>,
     wl_proxy_get_display: Option<unsafe extern "C" fn(proxy: *mut wl_proxy) -> *mut wl_display>,
+    wl_proxy_get_interface:
+        Option<unsafe extern "C" fn(proxy: *mut wl_proxy) -> *const wl_interface>,
 }
 
 // SAFETY: - There functions are as described in wayland-client-core.h.
@@ -187,6 +191,17 @@ unsafe_fwd! {
         data: *mut c_void,
     );
 
+    /// Returns the listener of the proxy.
+    ///
+    /// This is the dispatcher data if a dispatcher has been assigned.
+    ///
+    /// # Safety
+    ///
+    /// - proxy must be a valid pointer
+    /// - this reads the unprotected, mutable fields of the wl_proxy and access must be
+    ///   externally synchronized
+    fn wl_proxy_get_listener(proxy: *mut wl_proxy) -> *const c_void;
+
     /// Returns the ID of the proxy.
     ///
     /// # Safety
@@ -201,6 +216,13 @@ unsafe_fwd! {
     /// - proxy must be a valid pointer
     fn wl_proxy_get_version(proxy: *mut wl_proxy) -> u32;
 
+    /// Returns the name of the interface of the proxy.
+    ///
+    /// # Safety
+    ///
+    /// - proxy must be a valid pointer
+    fn wl_proxy_get_class(proxy: *mut wl_proxy) -> *const c_char;
+
     /// Sets the queue of the proxy.
     ///
     /// # Safety
@@ -342,8 +364,25 @@ impl Libwayland {
         let syms = unsafe { Symbols::load(&lib)? };
         // SAFETY: lib is libwayland-client.so.
         let syms_opt = unsafe { SymbolsOpt::load(&lib)? };
-        mem::forget(lib);
-        Ok(Libwayland { syms, syms_opt })
+        Ok(Libwayland {
+            lib: ManuallyDrop::new(lib),
+            syms,
+            syms_opt,
+        })
+    }
+
+    /// Looks up a `wl_interface` symbol, such as `wl_surface_interface`.
+    pub(crate) fn interface_symbol(&self, symbol: &CStr) -> Option<&'static wl_interface> {
+        // SAFETY: The `*_interface` symbols of libwayland-client are wl_interface
+        //         objects. Looking up a data symbol returns its address.
+        let interface = unsafe {
+            *self
+                .lib
+                .get::<*const wl_interface>(symbol.to_bytes_with_nul())
+                .ok()?
+        };
+        // SAFETY: The library is never unloaded, so the object lives forever.
+        unsafe { interface.as_ref() }
     }
 
     /// Creates a new queue.
@@ -378,14 +417,14 @@ mod polyfills {
 
     #[repr(C)]
     struct real_wl_object {
-        _interface: *const wl_interface,
+        interface: *const wl_interface,
         _implementation: *const c_void,
         _id: u32,
     }
 
     #[repr(C)]
     struct real_wl_proxy {
-        _object: real_wl_object,
+        object: real_wl_object,
         display: *mut wl_display,
         queue: *mut wl_event_queue,
     }
@@ -435,5 +474,25 @@ mod polyfills {
             //       might change in the future.
             unsafe { (*proxy).display }
         }
+
+        /// Get the interface of a proxy.
+        ///
+        /// # Safety
+        ///
+        /// - proxy must be a valid pointer
+        #[inline]
+        pub(crate) unsafe fn wl_proxy_get_interface(
+            &self,
+            proxy: *mut wl_proxy,
+        ) -> *const wl_interface {
+            if let Some(f) = self.syms_opt.wl_proxy_get_interface {
+                // SAFETY: The requirements are forwarded to the caller of this function.
+                return unsafe { f(proxy) };
+            }
+            let proxy = proxy.cast::<real_wl_proxy>();
+            // SAFETY: wl_proxy_get_interface was added in 2025. Before that, the layout of
+            //         wl_proxy has always been as described above.
+            unsafe { (*proxy).object.interface }
+        }
     }
 }
@@ -13,7 +13,6 @@ use {
             wl_callback::WlCallback, wl_display::WlDisplay, wl_dummy::WlDummy,
             wl_registry::WlRegistry, wl_root::WlRoot, wl_string::WlString,
         },
-        test_protocols_data,
     },
     isnt::std_1::{primitive::IsntMutPtrExt, vec::IsntVecExt},
     parking_lot::{Condvar, Mutex},
@@ -62,6 +61,7 @@ struct DisplayMut {
     num_queues: usize,
     new_events: Vec<Event>,
     new_events_after_flush: Vec<Event>,
+    unflushed_bytes: c_int,
     num_read_locks: u64,
     read_serial: u64,
     next_id: u32,
@@ -164,6 +164,34 @@ unsafe fn dec_proxy_ref_count(proxy_ptr: *mut Proxy) {
     }
 }
 
+/// Computes the size of a request on the wire.
+unsafe fn request_size(
+    interface: *const wl_interface,
+    opcode: u32,
+    args: *const wl_argument,
+) -> c_int {
+    let interface = &*interface;
+    let msg = &*interface.methods.add(opcode as usize);
+    let mut size = 8;
+    let mut idx = 0;
+    for &c in CStr::from_ptr(msg.signature).to_bytes() {
+        if c == b'?' || c.is_ascii_digit() {
+            continue;
+        }
+        let arg = &*args.add(idx);
+        idx += 1;
+        size += match c {
+            b's' if !arg.s.is_null() => {
+                4 + (CStr::from_ptr(arg.s).count_bytes() + 1).next_multiple_of(4)
+            }
+            b'a' if !arg.a.is_null() => 4 + (*arg.a).size.next_multiple_of(4),
+            b'h' => 0,
+            _ => 4,
+        };
+    }
+    size as c_int
+}
+
 unsafe fn inc_proxy_ref_count(proxy_ptr: *mut Proxy) -> *mut Proxy {
     assert!(!proxy_ptr.is_null());
     let proxy = &*proxy_ptr;
@@ -199,6 +227,19 @@ impl Libwayland {
         Ok(&SELF)
     }
 
+    pub(crate) fn interface_symbol(&self, symbol: &CStr) -> Option<&'static wl_interface> {
+        use protocols::wayland::{
+            wl_callback::WlCallback, wl_display::WlDisplay, wl_registry::WlRegistry,
+        };
+        let interface = match symbol.to_bytes() {
+            b"wl_callback_interface" => WlCallback::WL_INTERFACE,
+            b"wl_display_interface" => WlDisplay::WL_INTERFACE,
+            b"wl_registry_interface" => WlRegistry::WL_INTERFACE,
+            _ => return None,
+        };
+        Some(interface)
+    }
+
     pub(crate) unsafe fn wl_display_create_queue_with_name(
         &self,
         display_ptr: *mut wl_display,
@@ -257,6 +298,7 @@ impl Libwayland {
             return ptr::null_mut();
         }
         let display_mut = &mut *display.data.get();
+        display_mut.unflushed_bytes += request_size(proxy.interface, opcode, args);
         let mut ret = ptr::null_mut();
         if proxy.interface == WlDisplay::WL_INTERFACE {
             match opcode {
@@ -264,8 +306,6 @@ impl Libwayland {
                 0 => {
                     if interface != WlCallback::WL_INTERFACE
                         && interface != protocols::wayland::wl_callback::WlCallback::WL_INTERFACE
-                        && interface
-                            != test_protocols_data::core::wl_callback::WlCallback::WL_INTERFACE
                     {
                         unreachable!();
                     }
@@ -536,6 +576,12 @@ impl Libwayland {
         data.data = dispatcher_data;
     }
 
+    pub(crate) unsafe fn wl_proxy_get_listener(&self, proxy: *mut wl_proxy) -> *const c_void {
+        let proxy = &*proxy.cast::<Proxy>();
+        let data = &*proxy.dispatcher_data.get();
+        data.data
+    }
+
     pub(crate) unsafe fn wl_proxy_get_id(&self, proxy: *mut wl_proxy) -> u32 {
         let proxy = &*proxy.cast::<Proxy>();
         proxy.id
@@ -546,6 +592,11 @@ impl Libwayland {
         proxy.version
     }
 
+    pub(crate) unsafe fn wl_proxy_get_class(&self, proxy: *mut wl_proxy) -> *const c_char {
+        let proxy = &*proxy.cast::<Proxy>();
+        (*proxy.interface).name
+    }
+
     unsafe fn proxy_set_queue_locked(&self, proxy: *mut wl_proxy, queue: *mut wl_event_queue) {
         let proxy = &*proxy.cast::<Proxy>();
         let display = &*proxy.display;
@@ -649,6 +700,7 @@ impl Libwayland {
                 num_queues: 0,
                 new_events: vec![],
                 new_events_after_flush: vec![],
+                unflushed_bytes: 0,
                 num_read_locks: 0,
                 read_serial: 0,
                 next_id: 2,
@@ -666,8 +718,9 @@ impl Libwayland {
         display_ptr.cast()
     }
 
-    pub(crate) unsafe fn wl_display_connect_to_fd(&self, _fd: c_int) -> *mut wl_display {
-        unreachable!()
+    pub(crate) unsafe fn wl_display_connect_to_fd(&self, fd: c_int) -> *mut wl_display {
+        drop(OwnedFd::from_raw_fd(fd));
+        self.wl_display_connect(ptr::null())
     }
 
     pub(crate) unsafe fn wl_display_disconnect(&self, display: *mut wl_display) {
@@ -794,7 +847,7 @@ impl Libwayland {
                 return -1;
             }
         }
-        0
+        mem::take(&mut display_mut.unflushed_bytes)
     }
 
     pub(crate) unsafe fn wl_display_prepare_read(&self, display_ptr: *mut wl_display) -> c_int {
@@ -918,6 +971,14 @@ impl Libwayland {
         proxy.display.cast()
     }
 
+    pub(crate) unsafe fn wl_proxy_get_interface(
+        &self,
+        proxy: *mut wl_proxy,
+    ) -> *const wl_interface {
+        let proxy = &*proxy.cast::<Proxy>();
+        proxy.interface
+    }
+
     pub(crate) unsafe fn wl_display_get_error(&self, display: *mut wl_display) -> c_int {
         let display = &*display.cast::<Display>();
         let _lock = display.lock.lock();
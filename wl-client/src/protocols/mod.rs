@@ -1,5 +1,25 @@
 pub mod wayland;
 
+/// Metadata about all protocols in this module.
+#[allow(dead_code)]
+pub static PROTOCOLS: &[&crate::info::ProtocolInfo] = &[&wayland::PROTOCOL];
+
+/// Returns the metadata of the interface with the given name.
+///
+/// If multiple protocols define an interface with this name, the metadata of the
+/// preferred protocol is returned. If no protocol is preferred, `None` is returned
+/// and the interface can only be found via [`PROTOCOLS`].
+#[allow(dead_code, unreachable_code, clippy::match_single_binding)]
+pub fn interface_info(name: &str) -> Option<&'static crate::info::InterfaceInfo> {
+    let info = match name {
+        "wl_display" => &wayland::wl_display::INFO,
+        "wl_registry" => &wayland::wl_registry::INFO,
+        "wl_callback" => &wayland::wl_callback::INFO,
+        _ => return None,
+    };
+    Some(info)
+}
+
 #[allow(unused_imports)]
 mod all_types {
     pub(super) use super::wayland::{
@@ -11,3 +11,10 @@
 pub mod wl_callback;
 pub mod wl_display;
 pub mod wl_registry;
+
+/// Metadata about the wayland protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: crate::info::ProtocolInfo = crate::info::ProtocolInfo {
+    name: "wayland",
+    interfaces: &[&wl_display::INFO, &wl_registry::INFO, &wl_callback::INFO],
+};
@@ -51,6 +51,87 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_display interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_display",
+    protocol: "wayland",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "sync",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "callback",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_callback"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "get_registry",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "registry",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_registry"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "error",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "object_id",
+                    ty: info::ArgType::Object,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "code",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "message",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "delete_id",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
 /// An owned wl_display proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -105,11 +186,16 @@ impl Deref for WlDisplay {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -263,6 +349,193 @@ pub trait WlDisplayEventHandler {
 
 impl WlDisplayEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlDisplay] proxies with mutable data.
+///
+/// This trait is the same as [WlDisplayEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlDisplayEventHandlerWithData<T> {
+    /// # Arguments
+    ///
+    /// - `object_id`:
+    /// - `code`:
+    /// - `message`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn error(
+        &self,
+        _data: &mut T,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        let _ = object_id;
+        let _ = code;
+        let _ = message;
+    }
+
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn delete_id(&self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [WlDisplay] proxies.
+///
+/// This trait is the same as [WlDisplayEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDisplayEventHandlerMut {
+    /// See [WlDisplayEventHandler::error].
+    #[inline]
+    fn error(
+        &mut self,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        let _ = object_id;
+        let _ = code;
+        let _ = message;
+    }
+
+    /// See [WlDisplayEventHandler::delete_id].
+    #[inline]
+    fn delete_id(&mut self, _slf: &WlDisplayRef, id: u32) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [WlDisplay] proxies with mutable data.
+///
+/// This trait is the same as [WlDisplayEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlDisplayEventHandlerMutWithData<T> {
+    /// See [WlDisplayEventHandler::error].
+    #[inline]
+    fn error(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        let _ = object_id;
+        let _ = code;
+        let _ = message;
+    }
+
+    /// See [WlDisplayEventHandler::delete_id].
+    #[inline]
+    fn delete_id(&mut self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+        let _ = id;
+    }
+}
+
+impl<H> WlDisplayEventHandler for MutEventHandler<H>
+where
+    H: WlDisplayEventHandlerMut,
+{
+    #[inline]
+    fn error(
+        &self,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        self.with(|h| h.error(_slf, object_id, code, message))
+    }
+
+    #[inline]
+    fn delete_id(&self, _slf: &WlDisplayRef, id: u32) {
+        self.with(|h| h.delete_id(_slf, id))
+    }
+}
+
+impl<T, H> WlDisplayEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlDisplayEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn error(
+        &self,
+        _data: &mut T,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        self.with(|h| h.error(_data, _slf, object_id, code, message))
+    }
+
+    #[inline]
+    fn delete_id(&self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+        self.with(|h| h.delete_id(_data, _slf, id))
+    }
+}
+
+impl<T, U, H> WlDisplayEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlDisplayEventHandlerWithData<U>,
+{
+    #[inline]
+    fn error(
+        &self,
+        _data: &mut T,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler().error(_data, _slf, object_id, code, message)
+    }
+
+    #[inline]
+    fn delete_id(&self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+        let _data = self.project(_data);
+        self.handler().delete_id(_data, _slf, id)
+    }
+}
+
+impl<T, U, H> WlDisplayEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlDisplayEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn error(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlDisplayRef,
+        object_id: Option<&UntypedBorrowedProxy>,
+        code: u32,
+        message: &str,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .error(_data, _slf, object_id, code, message)
+    }
+
+    #[inline]
+    fn delete_id(&mut self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().delete_id(_data, _slf, id)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -315,6 +588,70 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlDisplayEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlDisplayRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                let arg0 = arg0.as_ref();
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg2 = unsafe { convert_string_arg("wl_display", "message", args[2].s) };
+                self.0.error(data, slf, arg0, arg1, arg2);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.delete_id(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_display", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlDisplayEventHandler,
@@ -327,6 +664,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlDisplayEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -348,6 +698,22 @@ pub mod event_handlers {
             self.0(_slf, object_id, code, message)
         }
     }
+    impl<T, F> WlDisplayEventHandlerWithData<T> for Error<F>
+    where
+        F: Fn(&mut T, &WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+    {
+        #[inline]
+        fn error(
+            &self,
+            _data: &mut T,
+            _slf: &WlDisplayRef,
+            object_id: Option<&UntypedBorrowedProxy>,
+            code: u32,
+            message: &str,
+        ) {
+            self.0(_data, _slf, object_id, code, message)
+        }
+    }
 
     /// Event handler for delete_id events.
     pub struct DeleteId<F>(F);
@@ -360,6 +726,70 @@ pub mod event_handlers {
             self.0(_slf, id)
         }
     }
+    impl<T, F> WlDisplayEventHandlerWithData<T> for DeleteId<F>
+    where
+        F: Fn(&mut T, &WlDisplayRef, u32),
+    {
+        #[inline]
+        fn delete_id(&self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for error events.
+    pub struct ErrorMut<F>(F);
+    impl<F> WlDisplayEventHandlerMut for ErrorMut<F>
+    where
+        F: FnMut(&WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+    {
+        #[inline]
+        fn error(
+            &mut self,
+            _slf: &WlDisplayRef,
+            object_id: Option<&UntypedBorrowedProxy>,
+            code: u32,
+            message: &str,
+        ) {
+            self.0(_slf, object_id, code, message)
+        }
+    }
+    impl<T, F> WlDisplayEventHandlerMutWithData<T> for ErrorMut<F>
+    where
+        F: FnMut(&mut T, &WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+    {
+        #[inline]
+        fn error(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlDisplayRef,
+            object_id: Option<&UntypedBorrowedProxy>,
+            code: u32,
+            message: &str,
+        ) {
+            self.0(_data, _slf, object_id, code, message)
+        }
+    }
+
+    /// Mutable event handler for delete_id events.
+    pub struct DeleteIdMut<F>(F);
+    impl<F> WlDisplayEventHandlerMut for DeleteIdMut<F>
+    where
+        F: FnMut(&WlDisplayRef, u32),
+    {
+        #[inline]
+        fn delete_id(&mut self, _slf: &WlDisplayRef, id: u32) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> WlDisplayEventHandlerMutWithData<T> for DeleteIdMut<F>
+    where
+        F: FnMut(&mut T, &WlDisplayRef, u32),
+    {
+        #[inline]
+        fn delete_id(&mut self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+            self.0(_data, _slf, id)
+        }
+    }
 
     impl WlDisplay {
         /// Creates an event handler for error events.
@@ -373,6 +803,17 @@ pub mod event_handlers {
             Error(f)
         }
 
+        /// Creates an event handler with mutable data for error events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_error_with_data<T, F>(f: F) -> Error<F>
+        where
+            F: Fn(&mut T, &WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+        {
+            Error(f)
+        }
+
         /// Creates an event handler for delete_id events.
         ///
         /// The event handler ignores all other events.
@@ -383,5 +824,190 @@ pub mod event_handlers {
         {
             DeleteId(f)
         }
+
+        /// Creates an event handler with mutable data for delete_id events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_delete_id_with_data<T, F>(f: F) -> DeleteId<F>
+        where
+            F: Fn(&mut T, &WlDisplayRef, u32),
+        {
+            DeleteId(f)
+        }
+
+        /// Creates a mutable event handler for error events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_error_mut<F>(f: F) -> ErrorMut<F>
+        where
+            F: FnMut(&WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+        {
+            ErrorMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for error events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_error_mut_with_data<T, F>(f: F) -> ErrorMut<F>
+        where
+            F: FnMut(&mut T, &WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+        {
+            ErrorMut(f)
+        }
+
+        /// Creates a mutable event handler for delete_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delete_id_mut<F>(f: F) -> DeleteIdMut<F>
+        where
+            F: FnMut(&WlDisplayRef, u32),
+        {
+            DeleteIdMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for delete_id events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_delete_id_mut_with_data<T, F>(f: F) -> DeleteIdMut<F>
+        where
+            F: FnMut(&mut T, &WlDisplayRef, u32),
+        {
+            DeleteIdMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                error: Ignore,
+                delete_id: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlDisplayEventHandler for Ignore {}
+    impl<T> WlDisplayEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlDisplay::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        error: H0,
+        delete_id: H1,
+    }
+
+    impl<H0, H1> WlDisplayEventHandler for Handler<H0, H1>
+    where
+        H0: WlDisplayEventHandler,
+        H1: WlDisplayEventHandler,
+    {
+        #[inline]
+        fn error(
+            &self,
+            _slf: &WlDisplayRef,
+            object_id: Option<&UntypedBorrowedProxy>,
+            code: u32,
+            message: &str,
+        ) {
+            self.error.error(_slf, object_id, code, message)
+        }
+
+        #[inline]
+        fn delete_id(&self, _slf: &WlDisplayRef, id: u32) {
+            self.delete_id.delete_id(_slf, id)
+        }
+    }
+
+    impl<T, H0, H1> WlDisplayEventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WlDisplayEventHandlerWithData<T>,
+        H1: WlDisplayEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn error(
+            &self,
+            _data: &mut T,
+            _slf: &WlDisplayRef,
+            object_id: Option<&UntypedBorrowedProxy>,
+            code: u32,
+            message: &str,
+        ) {
+            self.error.error(_data, _slf, object_id, code, message)
+        }
+
+        #[inline]
+        fn delete_id(&self, _data: &mut T, _slf: &WlDisplayRef, id: u32) {
+            self.delete_id.delete_id(_data, _slf, id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for error events.
+        #[allow(dead_code)]
+        pub fn on_error<F>(self, f: F) -> Handler<Error<F>, H1>
+        where
+            F: Fn(&WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+        {
+            Handler {
+                error: Error(f),
+                delete_id: self.delete_id,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for error events.
+        #[allow(dead_code)]
+        pub fn on_error_with_data<T, F>(self, f: F) -> Handler<Error<F>, H1>
+        where
+            F: Fn(&mut T, &WlDisplayRef, Option<&UntypedBorrowedProxy>, u32, &str),
+        {
+            Handler {
+                error: Error(f),
+                delete_id: self.delete_id,
+            }
+        }
+
+        /// Sets the closure for delete_id events.
+        #[allow(dead_code)]
+        pub fn on_delete_id<F>(self, f: F) -> Handler<H0, DeleteId<F>>
+        where
+            F: Fn(&WlDisplayRef, u32),
+        {
+            Handler {
+                error: self.error,
+                delete_id: DeleteId(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for delete_id events.
+        #[allow(dead_code)]
+        pub fn on_delete_id_with_data<T, F>(self, f: F) -> Handler<H0, DeleteId<F>>
+        where
+            F: Fn(&mut T, &WlDisplayRef, u32),
+        {
+            Handler {
+                error: self.error,
+                delete_id: DeleteId(f),
+            }
+        }
     }
 }
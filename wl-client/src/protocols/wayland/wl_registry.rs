@@ -39,6 +39,81 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_registry interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_registry",
+    protocol: "wayland",
+    version: 1,
+    requests: &[info::MessageInfo {
+        name: "bind",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[
+            info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+            info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            },
+        ],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "global",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "name",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "interface",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "version",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "global_remove",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "name",
+                ty: info::ArgType::Uint,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
 /// An owned wl_registry proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -93,11 +168,16 @@ impl Deref for WlRegistry {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -225,6 +305,178 @@ pub trait WlRegistryEventHandler {
 
 impl WlRegistryEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlRegistry] proxies with mutable data.
+///
+/// This trait is the same as [WlRegistryEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlRegistryEventHandlerWithData<T> {
+    /// # Arguments
+    ///
+    /// - `name`:
+    /// - `interface`:
+    /// - `version`:
+    #[inline]
+    fn global(
+        &self,
+        _data: &mut T,
+        _slf: &WlRegistryRef,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        let _ = name;
+        let _ = interface;
+        let _ = version;
+    }
+
+    /// # Arguments
+    ///
+    /// - `name`:
+    #[inline]
+    fn global_remove(&self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+        let _ = name;
+    }
+}
+
+/// A mutable event handler for [WlRegistry] proxies.
+///
+/// This trait is the same as [WlRegistryEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlRegistryEventHandlerMut {
+    /// See [WlRegistryEventHandler::global].
+    #[inline]
+    fn global(&mut self, _slf: &WlRegistryRef, name: u32, interface: &str, version: u32) {
+        let _ = name;
+        let _ = interface;
+        let _ = version;
+    }
+
+    /// See [WlRegistryEventHandler::global_remove].
+    #[inline]
+    fn global_remove(&mut self, _slf: &WlRegistryRef, name: u32) {
+        let _ = name;
+    }
+}
+
+/// A mutable event handler for [WlRegistry] proxies with mutable data.
+///
+/// This trait is the same as [WlRegistryEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlRegistryEventHandlerMutWithData<T> {
+    /// See [WlRegistryEventHandler::global].
+    #[inline]
+    fn global(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlRegistryRef,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        let _ = name;
+        let _ = interface;
+        let _ = version;
+    }
+
+    /// See [WlRegistryEventHandler::global_remove].
+    #[inline]
+    fn global_remove(&mut self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+        let _ = name;
+    }
+}
+
+impl<H> WlRegistryEventHandler for MutEventHandler<H>
+where
+    H: WlRegistryEventHandlerMut,
+{
+    #[inline]
+    fn global(&self, _slf: &WlRegistryRef, name: u32, interface: &str, version: u32) {
+        self.with(|h| h.global(_slf, name, interface, version))
+    }
+
+    #[inline]
+    fn global_remove(&self, _slf: &WlRegistryRef, name: u32) {
+        self.with(|h| h.global_remove(_slf, name))
+    }
+}
+
+impl<T, H> WlRegistryEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlRegistryEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn global(
+        &self,
+        _data: &mut T,
+        _slf: &WlRegistryRef,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        self.with(|h| h.global(_data, _slf, name, interface, version))
+    }
+
+    #[inline]
+    fn global_remove(&self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+        self.with(|h| h.global_remove(_data, _slf, name))
+    }
+}
+
+impl<T, U, H> WlRegistryEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlRegistryEventHandlerWithData<U>,
+{
+    #[inline]
+    fn global(
+        &self,
+        _data: &mut T,
+        _slf: &WlRegistryRef,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().global(_data, _slf, name, interface, version)
+    }
+
+    #[inline]
+    fn global_remove(&self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+        let _data = self.project(_data);
+        self.handler().global_remove(_data, _slf, name)
+    }
+}
+
+impl<T, U, H> WlRegistryEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlRegistryEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn global(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlRegistryRef,
+        name: u32,
+        interface: &str,
+        version: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .global(_data, _slf, name, interface, version)
+    }
+
+    #[inline]
+    fn global_remove(&mut self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+        let _data = self.project(_data);
+        self.handler_mut().global_remove(_data, _slf, name)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -270,6 +522,63 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlRegistryEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlRegistryRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 3 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 3]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg1 = unsafe { convert_string_arg("wl_registry", "interface", args[1].s) };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                self.0.global(data, slf, arg0, arg1, arg2);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                self.0.global_remove(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_registry", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlRegistryEventHandler,
@@ -282,6 +591,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlRegistryEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -297,6 +619,22 @@ pub mod event_handlers {
             self.0(_slf, name, interface, version)
         }
     }
+    impl<T, F> WlRegistryEventHandlerWithData<T> for Global<F>
+    where
+        F: Fn(&mut T, &WlRegistryRef, u32, &str, u32),
+    {
+        #[inline]
+        fn global(
+            &self,
+            _data: &mut T,
+            _slf: &WlRegistryRef,
+            name: u32,
+            interface: &str,
+            version: u32,
+        ) {
+            self.0(_data, _slf, name, interface, version)
+        }
+    }
 
     /// Event handler for global_remove events.
     pub struct GlobalRemove<F>(F);
@@ -309,6 +647,64 @@ pub mod event_handlers {
             self.0(_slf, name)
         }
     }
+    impl<T, F> WlRegistryEventHandlerWithData<T> for GlobalRemove<F>
+    where
+        F: Fn(&mut T, &WlRegistryRef, u32),
+    {
+        #[inline]
+        fn global_remove(&self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+            self.0(_data, _slf, name)
+        }
+    }
+
+    /// Mutable event handler for global events.
+    pub struct GlobalMut<F>(F);
+    impl<F> WlRegistryEventHandlerMut for GlobalMut<F>
+    where
+        F: FnMut(&WlRegistryRef, u32, &str, u32),
+    {
+        #[inline]
+        fn global(&mut self, _slf: &WlRegistryRef, name: u32, interface: &str, version: u32) {
+            self.0(_slf, name, interface, version)
+        }
+    }
+    impl<T, F> WlRegistryEventHandlerMutWithData<T> for GlobalMut<F>
+    where
+        F: FnMut(&mut T, &WlRegistryRef, u32, &str, u32),
+    {
+        #[inline]
+        fn global(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlRegistryRef,
+            name: u32,
+            interface: &str,
+            version: u32,
+        ) {
+            self.0(_data, _slf, name, interface, version)
+        }
+    }
+
+    /// Mutable event handler for global_remove events.
+    pub struct GlobalRemoveMut<F>(F);
+    impl<F> WlRegistryEventHandlerMut for GlobalRemoveMut<F>
+    where
+        F: FnMut(&WlRegistryRef, u32),
+    {
+        #[inline]
+        fn global_remove(&mut self, _slf: &WlRegistryRef, name: u32) {
+            self.0(_slf, name)
+        }
+    }
+    impl<T, F> WlRegistryEventHandlerMutWithData<T> for GlobalRemoveMut<F>
+    where
+        F: FnMut(&mut T, &WlRegistryRef, u32),
+    {
+        #[inline]
+        fn global_remove(&mut self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+            self.0(_data, _slf, name)
+        }
+    }
 
     impl WlRegistry {
         /// Creates an event handler for global events.
@@ -322,6 +718,17 @@ pub mod event_handlers {
             Global(f)
         }
 
+        /// Creates an event handler with mutable data for global events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_global_with_data<T, F>(f: F) -> Global<F>
+        where
+            F: Fn(&mut T, &WlRegistryRef, u32, &str, u32),
+        {
+            Global(f)
+        }
+
         /// Creates an event handler for global_remove events.
         ///
         /// The event handler ignores all other events.
@@ -332,5 +739,184 @@ pub mod event_handlers {
         {
             GlobalRemove(f)
         }
+
+        /// Creates an event handler with mutable data for global_remove events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_global_remove_with_data<T, F>(f: F) -> GlobalRemove<F>
+        where
+            F: Fn(&mut T, &WlRegistryRef, u32),
+        {
+            GlobalRemove(f)
+        }
+
+        /// Creates a mutable event handler for global events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_global_mut<F>(f: F) -> GlobalMut<F>
+        where
+            F: FnMut(&WlRegistryRef, u32, &str, u32),
+        {
+            GlobalMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for global events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_global_mut_with_data<T, F>(f: F) -> GlobalMut<F>
+        where
+            F: FnMut(&mut T, &WlRegistryRef, u32, &str, u32),
+        {
+            GlobalMut(f)
+        }
+
+        /// Creates a mutable event handler for global_remove events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_global_remove_mut<F>(f: F) -> GlobalRemoveMut<F>
+        where
+            F: FnMut(&WlRegistryRef, u32),
+        {
+            GlobalRemoveMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for global_remove events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_global_remove_mut_with_data<T, F>(f: F) -> GlobalRemoveMut<F>
+        where
+            F: FnMut(&mut T, &WlRegistryRef, u32),
+        {
+            GlobalRemoveMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                global: Ignore,
+                global_remove: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlRegistryEventHandler for Ignore {}
+    impl<T> WlRegistryEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlRegistry::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        global: H0,
+        global_remove: H1,
+    }
+
+    impl<H0, H1> WlRegistryEventHandler for Handler<H0, H1>
+    where
+        H0: WlRegistryEventHandler,
+        H1: WlRegistryEventHandler,
+    {
+        #[inline]
+        fn global(&self, _slf: &WlRegistryRef, name: u32, interface: &str, version: u32) {
+            self.global.global(_slf, name, interface, version)
+        }
+
+        #[inline]
+        fn global_remove(&self, _slf: &WlRegistryRef, name: u32) {
+            self.global_remove.global_remove(_slf, name)
+        }
+    }
+
+    impl<T, H0, H1> WlRegistryEventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WlRegistryEventHandlerWithData<T>,
+        H1: WlRegistryEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn global(
+            &self,
+            _data: &mut T,
+            _slf: &WlRegistryRef,
+            name: u32,
+            interface: &str,
+            version: u32,
+        ) {
+            self.global.global(_data, _slf, name, interface, version)
+        }
+
+        #[inline]
+        fn global_remove(&self, _data: &mut T, _slf: &WlRegistryRef, name: u32) {
+            self.global_remove.global_remove(_data, _slf, name)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for global events.
+        #[allow(dead_code)]
+        pub fn on_global<F>(self, f: F) -> Handler<Global<F>, H1>
+        where
+            F: Fn(&WlRegistryRef, u32, &str, u32),
+        {
+            Handler {
+                global: Global(f),
+                global_remove: self.global_remove,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for global events.
+        #[allow(dead_code)]
+        pub fn on_global_with_data<T, F>(self, f: F) -> Handler<Global<F>, H1>
+        where
+            F: Fn(&mut T, &WlRegistryRef, u32, &str, u32),
+        {
+            Handler {
+                global: Global(f),
+                global_remove: self.global_remove,
+            }
+        }
+
+        /// Sets the closure for global_remove events.
+        #[allow(dead_code)]
+        pub fn on_global_remove<F>(self, f: F) -> Handler<H0, GlobalRemove<F>>
+        where
+            F: Fn(&WlRegistryRef, u32),
+        {
+            Handler {
+                global: self.global,
+                global_remove: GlobalRemove(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for global_remove events.
+        #[allow(dead_code)]
+        pub fn on_global_remove_with_data<T, F>(self, f: F) -> Handler<H0, GlobalRemove<F>>
+        where
+            F: Fn(&mut T, &WlRegistryRef, u32),
+        {
+            Handler {
+                global: self.global,
+                global_remove: GlobalRemove(f),
+            }
+        }
     }
 }
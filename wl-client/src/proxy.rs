@@ -66,31 +66,59 @@
 //!
 //! - [`set_event_handler`] - for `Send + 'static` event handlers
 //! - [`set_event_handler_local`] - for `'static` event handlers
+//! - [`set_event_handler_local_mut`] - for `'static` event handlers that need `&mut self`
 //! - [`set_event_handler_no_op`]
+//! - [`set_dynamic_event_handler`] - for `Send + 'static` event handlers that decode
+//!   events at runtime
+//! - [`set_dynamic_event_handler_local`] - for `'static` event handlers that decode
+//!   events at runtime
 //! - [`Scope::set_event_handler`] - for `Send + 'scope` event handlers
 //! - [`Scope::set_event_handler_local`] - for `'scope` event handlers
+//! - [`Scope::set_event_handler_local_mut`] - for `'scope` event handlers that need
+//!   `&mut self`
+//! - [`Scope::set_dynamic_event_handler`] - for `Send + 'scope` event handlers that
+//!   decode events at runtime
+//! - [`Scope::set_dynamic_event_handler_local`] - for `'scope` event handlers that
+//!   decode events at runtime
 //!
 //! The `_local` variant allows setting event handlers that do not implement `Send`. The
-//! `_no_op` variant can be used to destroy compositor-created resources if
-//! the application is not otherwise interested in events.
+//! `_mut` variant accepts event handlers implementing the `*EventHandlerMut` traits, for
+//! example `FnMut` closures created with the `on_*_mut` functions. The `_no_op` variant
+//! can be used to destroy compositor-created resources if the application is not
+//! otherwise interested in events.
+//!
+//! The `_dynamic` variants accept a [`DynamicEventHandler`] which receives the arguments
+//! of every event as a `Vec` of [`DynEvent`] decoded from the `wl_interface` of the proxy.
+//! They can be used with proxies whose interface is not known at compile time.
+//!
+//! Event handlers that only need a part of the mutable data of a
+//! [`QueueWithData`](crate::QueueWithData) can be wrapped in a [`ProjectedEventHandler`].
 //!
 //! Event handlers cannot be set on owned wrapper proxies.
 //!
 //! For each proxy, the event handler can only be set once and once set it cannot be
 //! unset.
+//!
+//! # Sending requests dynamically
+//!
+//! Requests can also be sent without compile-time type information by calling
+//! [`UntypedBorrowedProxy::send_dynamic`] on the proxy returned by
+//! [`to_untyped_borrowed`](low_level::to_untyped_borrowed). The arguments are validated
+//! against the `wl_interface` of the proxy.
 
+pub use crate::proxy::low_level::{ProjectedEventHandler, borrowed::BorrowedProxyLock};
 #[expect(unused_imports)]
-use crate::Scope;
-pub use crate::proxy::low_level::borrowed::BorrowedProxyLock;
+use crate::{Scope, proxy::low_level::DynEvent};
 #[expect(unused_imports)]
 use crate::{connection::Connection, queue::QueueOwner};
 use {
     crate::{
         Queue,
-        ffi::{self, wl_interface},
+        ffi::{self, LibwaylandInterface, wl_interface},
         proxy::low_level::{
-            CreateEventHandler, EventHandler, UntypedBorrowedProxy, UntypedBorrowedProxyWrapper,
-            UntypedOwnedProxy, UntypedOwnedProxyWrapper,
+            CreateEventHandler, DynamicEventHandler, EventHandler, MutEventHandler,
+            UntypedBorrowedProxy, UntypedBorrowedProxyWrapper, UntypedOwnedProxy,
+            UntypedOwnedProxyWrapper,
         },
     },
     std::{mem, ptr::NonNull},
@@ -113,11 +141,20 @@ mod tests;
 /// - The interface of the contained proxy must be compatible with `WL_INTERFACE`.
 /// - The [`EventHandler::mutable_type`] of the [`OwnedProxy::NoOpEventHandler`] must be
 ///   `None` or the type ID of `()`.
+/// - If `LIBWAYLAND_INTERFACE` is `Some`, then the requests and events of the interface
+///   it returns must be a prefix of the requests and events of `WL_INTERFACE`, and the
+///   interface of the contained proxy may also be compatible with that interface.
 pub unsafe trait OwnedProxy: UntypedOwnedProxyWrapper {
     /// The name of the interface.
     const INTERFACE: &'static str;
     /// The libwayland interface specification.
     const WL_INTERFACE: &'static wl_interface;
+    /// The interface defined by libwayland-client that is used instead of
+    /// `WL_INTERFACE` when creating proxies of this type.
+    ///
+    /// This is `None` unless the bindings were generated with
+    /// `with_libwayland_interfaces`.
+    const LIBWAYLAND_INTERFACE: Option<&'static LibwaylandInterface> = None;
     /// An event handler that ignores all events without leaking memory.
     const NO_OP_EVENT_HANDLER: Self::NoOpEventHandler;
     /// The maximum protocol version supported by this type.
@@ -263,11 +300,11 @@ pub fn destroy(proxy: &impl UntypedOwnedProxyWrapper) {
 /// assert!(done.load(Relaxed));
 /// ```
 #[inline]
-pub fn set_event_handler<P, H>(proxy: &P, handler: H)
+pub fn set_event_handler<P, H, M>(proxy: &P, handler: H)
 where
     P: OwnedProxy,
-    P::Api: CreateEventHandler<H>,
-    <P::Api as CreateEventHandler<H>>::EventHandler: Send + 'static,
+    P::Api: CreateEventHandler<H, M>,
+    <P::Api as CreateEventHandler<H, M>>::EventHandler: Send + 'static,
 {
     get_owned(proxy).set_event_handler(P::Api::create_event_handler(handler));
 }
@@ -313,15 +350,70 @@ where
 /// assert!(done.get());
 /// ```
 #[inline]
-pub fn set_event_handler_local<P, H>(proxy: &P, handler: H)
+pub fn set_event_handler_local<P, H, M>(proxy: &P, handler: H)
 where
     P: OwnedProxy,
-    P::Api: CreateEventHandler<H>,
-    <P::Api as CreateEventHandler<H>>::EventHandler: 'static,
+    P::Api: CreateEventHandler<H, M>,
+    <P::Api as CreateEventHandler<H, M>>::EventHandler: 'static,
 {
     get_owned(proxy).set_event_handler_local(P::Api::create_event_handler(handler));
 }
 
+/// Sets a mutable `!Send` event handler of the proxy.
+///
+/// This function is the same as [`set_event_handler_local`] except that the event
+/// handler implements the `*EventHandlerMut` trait of the interface. The methods of this
+/// trait take `&mut self`, which allows the event handler to mutate its state without
+/// interior mutability. The `on_*_mut` functions of the generated bindings create such
+/// event handlers from `FnMut` closures.
+///
+/// # Panic
+///
+/// This function panics whenever [`set_event_handler_local`] panics.
+///
+/// The event handler panics if it is invoked while it is already handling an event. This
+/// happens if the event handler dispatches the queue of its proxy.
+///
+/// # Example
+///
+/// ```
+/// # use std::cell::Cell;
+/// # use std::rc::Rc;
+/// # use wl_client::{proxy, Libwayland};
+/// # use wl_client::test_protocols::core::wl_callback::WlCallback;
+/// # use wl_client::test_protocols::core::wl_display::WlDisplay;
+/// #
+/// let lib = Libwayland::open().unwrap();
+/// let con = lib.connect_to_default_display().unwrap();
+/// let queue = con.create_local_queue(c"");
+/// let display: WlDisplay = queue.display();
+/// let sync = display.sync();
+/// let done = Rc::new(Cell::new(0));
+///
+/// // Attach the event handler.
+/// let done2 = done.clone();
+/// let mut events = 0;
+/// proxy::set_event_handler_local_mut(&sync, WlCallback::on_done_mut(move |_, _| {
+///     events += 1;
+///     done2.set(events);
+/// }));
+///
+/// // Wait for the compositor to send the `done` message.
+/// queue.dispatch_roundtrip_blocking().unwrap();
+///
+/// // The event handler has counted one event.
+/// assert_eq!(done.get(), 1);
+/// ```
+#[inline]
+pub fn set_event_handler_local_mut<P, H, M>(proxy: &P, handler: H)
+where
+    P: OwnedProxy,
+    P::Api: CreateEventHandler<MutEventHandler<H>, M>,
+    <P::Api as CreateEventHandler<MutEventHandler<H>, M>>::EventHandler: 'static,
+{
+    set_event_handler_local(proxy, MutEventHandler::new(handler));
+}
+
 /// Sets the event handler of the proxy to ignore all events.
 ///
 /// This can be used in the following situation:
@@ -354,6 +446,111 @@ where
     get_owned(proxy).set_event_handler(P::NO_OP_EVENT_HANDLER);
 }
 
+/// Sets the event handler of the proxy to a [`DynamicEventHandler`].
+///
+/// The event handler receives the opcode, the name, and the decoded arguments of every
+/// event. The arguments are decoded at runtime from the `wl_interface` of the proxy.
+/// File descriptors and new proxies are passed to the event handler as owned values.
+///
+/// Unlike [`set_event_handler`], this function can be used with any proxy, including
+/// [`UntypedOwnedProxy`].
+///
+/// # Panic
+///
+/// This function panics if
+///
+/// - the proxy is a wrapper,
+/// - the proxy has already been destroyed, or
+/// - the proxy already has an event handler.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::{Arc, Mutex};
+/// # use wl_client::{proxy, Libwayland};
+/// # use wl_client::proxy::low_level::{on_dynamic_event, DynEvent};
+/// # use wl_client::test_protocol_helpers::get_root;
+/// #
+/// let lib = Libwayland::open().unwrap();
+/// let con = lib.connect_to_default_display().unwrap();
+/// let queue = con.create_queue(c"queue name");
+/// let root = get_root(&queue);
+///
+/// let received = Arc::new(Mutex::new(None));
+/// let received2 = received.clone();
+/// proxy::set_dynamic_event_handler(
+///     &root,
+///     on_dynamic_event(move |_, _, name, args| {
+///         if let [DynEvent::NewId(dummy)] = &*args {
+///             *received2.lock().unwrap() = Some((name.to_string(), dummy.clone()));
+///         }
+///     }),
+/// );
+///
+/// root.send_new_dummy();
+/// queue.dispatch_roundtrip_blocking().unwrap();
+///
+/// let (name, _dummy) = received.lock().unwrap().take().unwrap();
+/// assert_eq!(name, "new_dummy");
+/// ```
+#[inline]
+pub fn set_dynamic_event_handler<P, H>(proxy: &P, handler: H)
+where
+    P: UntypedOwnedProxyWrapper,
+    H: DynamicEventHandler + Send + 'static,
+{
+    get_owned(proxy).set_dynamic_event_handler(handler);
+}
+
+/// Sets the `!Send` event handler of the proxy to a [`DynamicEventHandler`].
+///
+/// This function is the same as [`set_dynamic_event_handler`] except that the event
+/// handler does not have to implement [`Send`] and that the queue of the proxy must be a
+/// [local queue](Connection::create_local_queue).
+///
+/// # Panic
+///
+/// This function panics whenever [`set_dynamic_event_handler`] panics and also if the
+/// queue of the proxy is not a local queue.
+///
+/// # Example
+///
+/// ```
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use wl_client::{proxy, Libwayland};
+/// # use wl_client::proxy::low_level::{on_dynamic_event, DynEvent};
+/// # use wl_client::test_protocol_helpers::get_root;
+/// #
+/// let lib = Libwayland::open().unwrap();
+/// let con = lib.connect_to_default_display().unwrap();
+/// let queue = con.create_local_queue(c"queue name");
+/// let root = get_root(&queue);
+/// let string = root.echo("hello");
+///
+/// let received = Rc::new(RefCell::new(String::new()));
+/// let received2 = received.clone();
+/// proxy::set_dynamic_event_handler_local(
+///     &string,
+///     on_dynamic_event(move |_, _, _, args| {
+///         if let [DynEvent::String(Some(s))] = &*args {
+///             *received2.borrow_mut() = s.to_string();
+///         }
+///     }),
+/// );
+///
+/// queue.dispatch_roundtrip_blocking().unwrap();
+/// assert_eq!(*received.borrow(), "hello");
+/// ```
+#[inline]
+pub fn set_dynamic_event_handler_local<P, H>(proxy: &P, handler: H)
+where
+    P: UntypedOwnedProxyWrapper,
+    H: DynamicEventHandler + 'static,
+{
+    get_owned(proxy).set_dynamic_event_handler_local(handler);
+}
+
 /// Locks the proxy for concurrent destruction.
 ///
 /// If the proxy is not already destroyed, holding this lock will prevent other threads
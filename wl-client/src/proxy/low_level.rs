@@ -7,19 +7,23 @@ pub(crate) use owned::{OwnedProxyRegistry, destruction::ProxyDataDestruction};
 use {
     crate::{
         ffi::wl_proxy,
-        proxy::{BorrowedProxy, OwnedProxy, get_owned},
+        proxy::{BorrowedProxy, OwnedProxy, get_owned, get_ref},
     },
     std::{
+        cell::RefCell,
+        marker::PhantomData,
         mem::{self, ManuallyDrop},
         ptr::NonNull,
     },
 };
 pub use {
     borrowed::{UntypedBorrowedProxy, UntypedBorrowedProxyWrapper},
+    dynamic::{DynArg, DynEvent, DynMessage, DynamicError, DynamicEventHandler, on_dynamic_event},
     owned::{EventHandler, UntypedOwnedProxy, UntypedOwnedProxyWrapper},
 };
 
 pub(super) mod borrowed;
+mod dynamic;
 pub(crate) mod owned;
 #[cfg(test)]
 mod tests;
@@ -31,13 +35,147 @@ mod tests;
 ///
 /// This type is usually implemented by bindings that are automatically generated with the
 /// `wl-client-builder` crate.
-pub trait CreateEventHandler<T> {
+///
+/// `M` is a marker type that allows one proxy type to accept multiple kinds of event
+/// handlers. It is `()` for event handlers without mutable data and [`WithData<T>`] for
+/// event handlers with mutable data of type `T`. It is inferred when an event handler is
+/// attached to a proxy.
+pub trait CreateEventHandler<T, M = ()> {
     type EventHandler: EventHandler;
 
     /// Creates a new event handler.
     fn create_event_handler(handler: T) -> Self::EventHandler;
 }
 
+/// A marker type for event handlers with mutable data of type `T`.
+///
+/// See [`CreateEventHandler`].
+pub struct WithData<T>(PhantomData<fn(&mut T)>);
+
+/// An adapter that turns a mutable event handler into a shared event handler.
+///
+/// Generated bindings implement the `*EventHandler` trait of an interface for this type
+/// if `H` implements the corresponding `*EventHandlerMut` trait. This type is used by
+/// [`set_event_handler_local_mut`](crate::proxy::set_event_handler_local_mut).
+///
+/// The handler is stored in a [`RefCell`]. If the handler is invoked while it is already
+/// handling an event, for example because it dispatched the queue of its proxy, the
+/// nested invocation panics.
+pub struct MutEventHandler<H> {
+    handler: RefCell<H>,
+}
+
+impl<H> MutEventHandler<H> {
+    /// Creates a new adapter.
+    #[inline]
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler: RefCell::new(handler),
+        }
+    }
+
+    /// Invokes `f` with mutable access to the handler.
+    ///
+    /// # Panic
+    ///
+    /// This function panics if it is called from within `f`.
+    #[inline]
+    pub fn with<R>(&self, f: impl FnOnce(&mut H) -> R) -> R {
+        match self.handler.try_borrow_mut() {
+            Ok(mut handler) => f(&mut handler),
+            Err(_) => {
+                #[cold]
+                fn reentrant() -> ! {
+                    panic!(
+                        "A mutable event handler was invoked while it was already handling an \
+                         event. This happens if the event handler dispatches the queue of its \
+                         proxy."
+                    );
+                }
+                reentrant();
+            }
+        }
+    }
+}
+
+/// An event handler for a part of the mutable data of a queue.
+///
+/// This type wraps an event handler that takes mutable data of type `U` and a projection
+/// from the mutable data of type `T` of a [`QueueWithData`](crate::QueueWithData) to
+/// `U`. Generated bindings implement the `*EventHandlerWithData<T>` traits for this type
+/// if `H` implements the corresponding `*EventHandlerWithData<U>` trait. This allows
+/// components to be written against their own state type and to be used with any
+/// application state that contains it.
+///
+/// # Example
+///
+/// ```
+/// # use wl_client::{proxy, Libwayland};
+/// # use wl_client::proxy::ProjectedEventHandler;
+/// # use wl_client::test_protocols::core::wl_callback::WlCallback;
+/// # use wl_client::test_protocols::core::wl_display::WlDisplay;
+/// #
+/// // A reusable component with its own state.
+/// #[derive(Default)]
+/// struct SyncState {
+///     done: bool,
+/// }
+///
+/// #[derive(Default)]
+/// struct State {
+///     sync: SyncState,
+/// }
+///
+/// let lib = Libwayland::open().unwrap();
+/// let con = lib.connect_to_default_display().unwrap();
+/// let (_queue, queue) = con.create_queue_with_data::<State>(c"queue name");
+/// let sync = queue.display::<WlDisplay>().sync();
+///
+/// // The event handler only has access to `SyncState`.
+/// let handler = WlCallback::on_done_with_data(|state: &mut SyncState, _, _| {
+///     state.done = true;
+/// });
+/// proxy::set_event_handler(
+///     &sync,
+///     ProjectedEventHandler::new(handler, |state: &mut State| &mut state.sync),
+/// );
+///
+/// let mut state = State::default();
+/// queue.dispatch_roundtrip_blocking(&mut state).unwrap();
+/// assert!(state.sync.done);
+/// ```
+pub struct ProjectedEventHandler<H, T, U> {
+    handler: H,
+    project: fn(&mut T) -> &mut U,
+}
+
+impl<H, T, U> ProjectedEventHandler<H, T, U> {
+    /// Creates a new event handler from an event handler for `U` and a projection from
+    /// `T` to `U`.
+    #[inline]
+    pub fn new(handler: H, project: fn(&mut T) -> &mut U) -> Self {
+        Self { handler, project }
+    }
+
+    /// Returns the wrapped event handler.
+    #[inline]
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Returns the wrapped event handler.
+    #[inline]
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Applies the projection to the mutable data of the queue.
+    #[inline]
+    pub fn project<'a>(&self, data: &'a mut T) -> &'a mut U {
+        (self.project)(data)
+    }
+}
+
 #[inline]
 pub(crate) fn check_dispatching_proxy(proxy: Option<NonNull<wl_proxy>>) -> NonNull<wl_proxy> {
     match proxy {
@@ -113,6 +251,18 @@ where
     unsafe { mem::transmute::<&UntypedBorrowedProxy, &P>(proxy) }
 }
 
+/// Returns the untyped, borrowed proxy wrapped by a proxy.
+///
+/// This can be used to call functions such as
+/// [`UntypedBorrowedProxy::send_dynamic`] on well-typed proxies.
+#[inline]
+pub fn to_untyped_borrowed<P>(proxy: &P) -> &UntypedBorrowedProxy
+where
+    P: UntypedBorrowedProxyWrapper,
+{
+    get_ref(proxy)
+}
+
 /// Creates a well-typed, owned proxy.
 ///
 /// This is a low-level API that you probably don't have to use unless you are writing
@@ -4,6 +4,7 @@ use {
         builder::prelude::UntypedOwnedProxy,
         ffi::{wl_argument, wl_interface, wl_proxy},
         proxy::low_level::{check_dispatching_proxy, check_new_proxy},
+        queue::stats::ProxyStats,
         utils::sync_ptr::SyncPtr,
     },
     parking_lot::{RwLock, RwLockReadGuard},
@@ -59,6 +60,12 @@ pub struct UntypedBorrowedProxy {
     pub(super) libwayland: &'static Libwayland,
     id: AtomicU32,
     immutable: bool,
+    /// The interface of this proxy. This is `None` for immutable proxies. For wrappers,
+    /// this is an interface that is compatible with the interface of the proxy.
+    pub(super) interface: Option<&'static wl_interface>,
+    /// The statistics of this proxy. This is `None` for immutable proxies and proxies
+    /// whose interface is unknown.
+    pub(super) stats: Option<ProxyStats>,
 }
 
 /// A transparent wrapper around [`UntypedBorrowedProxy`].
@@ -125,7 +132,7 @@ impl UntypedBorrowedProxy {
     /// - `proxy` must stay valid for the lifetime of this object.
     pub unsafe fn new_immutable(libwayland: &'static Libwayland, proxy: NonNull<wl_proxy>) -> Self {
         // SAFETY: The requirement is forwarded to the caller.
-        unsafe { Self::new(libwayland, proxy, true) }
+        unsafe { Self::new(libwayland, proxy, true, None, None) }
     }
 
     /// Creates a new [`UntypedBorrowedProxy`].
@@ -137,9 +144,11 @@ impl UntypedBorrowedProxy {
     pub(crate) unsafe fn new_internal(
         libwayland: &'static Libwayland,
         proxy: NonNull<wl_proxy>,
+        interface: Option<&'static wl_interface>,
+        stats: Option<ProxyStats>,
     ) -> Self {
         // SAFETY: The requirement is forwarded to the caller.
-        unsafe { Self::new(libwayland, proxy, false) }
+        unsafe { Self::new(libwayland, proxy, false, interface, stats) }
     }
 
     /// Creates a new [`UntypedBorrowedProxy`].
@@ -152,6 +161,8 @@ impl UntypedBorrowedProxy {
         libwayland: &'static Libwayland,
         proxy: NonNull<wl_proxy>,
         immutable: bool,
+        interface: Option<&'static wl_interface>,
+        stats: Option<ProxyStats>,
     ) -> Self {
         Self {
             proxy: AtomicPtr::new(proxy.as_ptr()),
@@ -159,6 +170,8 @@ impl UntypedBorrowedProxy {
             id: AtomicU32::new(LAST_CLIENT_ID),
             libwayland,
             immutable,
+            interface,
+            stats,
         }
     }
 
@@ -193,6 +206,26 @@ impl UntypedBorrowedProxy {
         NonNull::new(self.proxy.load(Relaxed))
     }
 
+    /// Returns the interface of this proxy.
+    ///
+    /// This is `None` if the interface is not known and the proxy has already been
+    /// destroyed.
+    pub(crate) fn wl_interface(&self) -> Option<&'static wl_interface> {
+        if self.interface.is_some() {
+            return self.interface;
+        }
+        let lock = self.lock();
+        let proxy = lock.wl_proxy()?;
+        // SAFETY: - proxy is valid while the lock is held
+        //         - the interface of a proxy is a valid interface that outlives the proxy
+        //           and, since interfaces are statically allocated, the program
+        unsafe {
+            self.libwayland
+                .wl_proxy_get_interface(proxy.as_ptr())
+                .as_ref()
+        }
+    }
+
     /// Returns the wayland object ID of this proxy.
     ///
     /// If the proxy has already been destroyed, this function returns either the original
@@ -244,6 +277,7 @@ impl UntypedBorrowedProxy {
         //           new_id element
         //         - flags does not contain WL_MARSHAL_FLAG_DESTROY
         unsafe {
+            self.record_request(opcode);
             self.libwayland.wl_proxy_marshal_array_flags(
                 proxy.as_ptr(),
                 opcode,
@@ -324,6 +358,7 @@ impl UntypedBorrowedProxy {
         //           exactly one new_id element
         //         - flags does not contain WL_MARSHAL_FLAG_DESTROY
         let new_proxy = unsafe {
+            self.record_request(opcode);
             lib.wl_proxy_marshal_array_flags(
                 proxy.as_ptr(),
                 opcode,
@@ -351,6 +386,14 @@ impl UntypedBorrowedProxy {
         unsafe { UntypedOwnedProxy::from_plain_wl_proxy(queue, new_proxy, interface) }
     }
 
+    /// Records a request in the statistics of this proxy.
+    #[inline]
+    pub(super) fn record_request(&self, opcode: u32) {
+        if let Some(stats) = &self.stats {
+            stats.record_request(opcode);
+        }
+    }
+
     /// Returns the version of this proxy object.
     ///
     /// The version of the display object is always 0.
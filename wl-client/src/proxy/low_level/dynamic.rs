@@ -0,0 +1,882 @@
+use {
+    crate::{
+        Queue,
+        builder::helpers::{convert_optional_string_arg, convert_string_arg, invalid_opcode},
+        ffi::{
+            interface_compatible, messages, wl_argument, wl_array, wl_interface, wl_message,
+            wl_object,
+        },
+        fixed::Fixed,
+        proxy::low_level::{EventHandler, UntypedBorrowedProxy, UntypedOwnedProxy},
+    },
+    std::{
+        any::TypeId,
+        error::Error,
+        ffi::{CStr, CString},
+        fmt::{Display, Formatter},
+        os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd},
+        ptr::{self, NonNull},
+        slice,
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The request sent by [`UntypedBorrowedProxy::send_dynamic`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DynMessage<'a> {
+    /// The request with this name.
+    Name(&'a str),
+    /// The request with this opcode.
+    Opcode(u32),
+}
+
+impl<'a> From<&'a str> for DynMessage<'a> {
+    fn from(name: &'a str) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<u32> for DynMessage<'_> {
+    fn from(opcode: u32) -> Self {
+        Self::Opcode(opcode)
+    }
+}
+
+/// An argument of a request sent by [`UntypedBorrowedProxy::send_dynamic`].
+#[derive(Copy, Clone)]
+pub enum DynArg<'a> {
+    /// An `int` argument.
+    Int(i32),
+    /// A `uint` argument.
+    Uint(u32),
+    /// A `fixed` argument.
+    Fixed(Fixed),
+    /// A `string` argument. `None` can only be used if the argument is nullable.
+    String(Option<&'a str>),
+    /// An `object` argument. `None` can only be used if the argument is nullable.
+    Object(Option<&'a UntypedBorrowedProxy>),
+    /// A `new_id` argument whose interface is determined by the request.
+    ///
+    /// The new object is attached to the queue.
+    NewId(&'a Queue),
+    /// A `new_id` argument whose interface is not determined by the request, such as the
+    /// `id` argument of `wl_registry.bind`.
+    ///
+    /// The new object has the given interface and version and is attached to the queue.
+    UntypedNewId {
+        queue: &'a Queue,
+        interface: &'static wl_interface,
+        version: u32,
+    },
+    /// An `array` argument.
+    Array(&'a [u8]),
+    /// An `fd` argument.
+    Fd(BorrowedFd<'a>),
+}
+
+/// A decoded argument of an event received by a [`DynamicEventHandler`].
+pub enum DynEvent<'a> {
+    /// An `int` argument.
+    Int(i32),
+    /// A `uint` argument.
+    Uint(u32),
+    /// A `fixed` argument.
+    Fixed(Fixed),
+    /// A `string` argument. `None` if the argument is nullable and null.
+    String(Option<&'a str>),
+    /// An `object` argument. `None` if the argument is nullable and null.
+    ///
+    /// Like the object arguments passed to generated event handlers, this proxy does
+    /// not know its interface.
+    Object(Option<&'a UntypedBorrowedProxy>),
+    /// A `new_id` argument.
+    ///
+    /// The new object is attached to the queue of the proxy that received the event.
+    /// If this value is dropped, the proxy is destroyed without sending a request.
+    NewId(UntypedOwnedProxy),
+    /// An `array` argument.
+    Array(&'a [u8]),
+    /// An `fd` argument.
+    Fd(OwnedFd),
+}
+
+/// An event handler that receives the events of any interface.
+///
+/// Such an event handler can be attached to any proxy that is not a wrapper via
+/// [`proxy::set_dynamic_event_handler`](crate::proxy::set_dynamic_event_handler) and
+/// related functions. The arguments of the event are decoded at runtime from the
+/// `wl_message` signatures of the proxy's `wl_interface`.
+///
+/// This trait is implemented for all functions with the signature of
+/// [`DynamicEventHandler::handle_event`]. [`on_dynamic_event`] can be used to help type
+/// inference for closures.
+pub trait DynamicEventHandler {
+    /// Handles an event.
+    ///
+    /// `opcode` is the opcode of the event and `name` is its name as recorded in the
+    /// `wl_interface`.
+    fn handle_event(
+        &self,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        name: &str,
+        args: Vec<DynEvent<'_>>,
+    );
+
+    /// Handles an event that cannot be decoded.
+    ///
+    /// This function is called instead of [`DynamicEventHandler::handle_event`] if the
+    /// arguments of the event cannot be represented as [`DynEvent`]s. This is the case
+    /// for events with untyped `new_id` arguments. Before this function is called, the
+    /// new proxies contained in the event are destroyed and its file descriptors are
+    /// closed.
+    ///
+    /// The default implementation ignores the event.
+    #[expect(unused_variables)]
+    fn handle_error(&self, slf: &UntypedBorrowedProxy, opcode: u32, error: DynamicError) {}
+}
+
+impl<F> DynamicEventHandler for F
+where
+    F: Fn(&UntypedBorrowedProxy, u32, &str, Vec<DynEvent<'_>>),
+{
+    #[inline]
+    fn handle_event(
+        &self,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        name: &str,
+        args: Vec<DynEvent<'_>>,
+    ) {
+        self(slf, opcode, name, args)
+    }
+}
+
+/// Creates a [`DynamicEventHandler`] from a closure.
+///
+/// This function returns its argument unchanged. It only exists so that the compiler
+/// can infer the argument types of the closure.
+///
+/// # Example
+///
+/// ```
+/// # use wl_client::proxy::low_level::{on_dynamic_event, DynEvent};
+/// #
+/// let handler = on_dynamic_event(|_, _, name, args| {
+///     if let [DynEvent::String(Some(s))] = &*args {
+///         println!("{name}: {s}");
+///     }
+/// });
+/// ```
+#[inline]
+pub fn on_dynamic_event<F>(f: F) -> F
+where
+    F: Fn(&UntypedBorrowedProxy, u32, &str, Vec<DynEvent<'_>>),
+{
+    f
+}
+
+/// An [`EventHandler`] that decodes events and forwards them to a
+/// [`DynamicEventHandler`].
+///
+/// The `WL_INTERFACE` of this type is a placeholder. Instead, this event handler accepts
+/// the events of proxies whose interface is compatible with the interface passed to
+/// [`Self::new`].
+pub(crate) struct DynamicEventHandlerAdapter<H> {
+    handler: H,
+    interface: &'static wl_interface,
+}
+
+/// The placeholder `WL_INTERFACE` of [`DynamicEventHandlerAdapter`].
+static ANY_INTERFACE: wl_interface = wl_interface {
+    name: c"*".as_ptr(),
+    version: 0,
+    method_count: 0,
+    methods: ptr::null(),
+    event_count: 0,
+    events: ptr::null(),
+};
+
+impl<H> DynamicEventHandlerAdapter<H> {
+    /// Creates a new adapter for proxies with the given interface.
+    pub(crate) fn new(handler: H, interface: &'static wl_interface) -> Self {
+        Self { handler, interface }
+    }
+}
+
+// SAFETY: - ANY_INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H> EventHandler for DynamicEventHandlerAdapter<H>
+where
+    H: DynamicEventHandler,
+{
+    const WL_INTERFACE: &'static wl_interface = &ANY_INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        None
+    }
+
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        _data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: The adapter is only attached to proxies whose interface is compatible
+        //         with self.interface. The other requirements are forwarded to the
+        //         caller.
+        let res = unsafe {
+            decode_event(self.interface, queue, opcode, args, |name, args| {
+                self.handler.handle_event(slf, opcode, name, args);
+            })
+        };
+        if let Err(e) = res {
+            self.handler.handle_error(slf, opcode, e);
+        }
+    }
+}
+
+/// Decodes the arguments of an event and passes them to `f`.
+///
+/// `f` receives the name of the event and its decoded arguments. New proxies and file
+/// descriptors that are not moved out of the arguments by `f` are destroyed and closed
+/// when `f` returns.
+///
+/// If the event contains an untyped `new_id` argument, `f` is not called. Instead, the
+/// new proxies and file descriptors are released and an error is returned.
+///
+/// # Safety
+///
+/// - `queue` must be the queue of the proxy that received the event.
+/// - The interface of this proxy must be compatible with `interface`.
+/// - `opcode` and `args` must conform to an event of `interface` and must have been
+///   passed to an event handler by libwayland.
+pub(crate) unsafe fn decode_event(
+    interface: &'static wl_interface,
+    queue: &Queue,
+    opcode: u32,
+    args: *mut wl_argument,
+    f: impl FnOnce(&str, Vec<DynEvent<'_>>),
+) -> Result<(), DynamicError> {
+    // SAFETY: The interface is valid.
+    let events = unsafe { messages(interface.events, interface.event_count) };
+    // SAFETY: The name of a valid interface is a valid C string.
+    let interface_name = unsafe { convert_string_arg("wl_interface", "name", interface.name) };
+    let Some(msg) = events.get(opcode as usize) else {
+        invalid_opcode(interface_name, opcode);
+    };
+    // SAFETY: The name of a valid message is a valid C string.
+    let name = unsafe { convert_string_arg(interface_name, "name", msg.name) };
+    // SAFETY: The messages of a valid interface are valid.
+    let (_, params) = unsafe { parse_signature(msg) };
+    if params.iter().any(|p| p.ty == b'n' && p.interface.is_none()) {
+        // SAFETY: args conforms to msg.
+        unsafe {
+            release_args(queue, &params, args);
+        }
+        return Err(DynamicError::UntypedNewIdEvent(name.to_string()));
+    }
+    // SAFETY: args conforms to msg which requires one argument per parameter if there
+    //         are no untyped new_id parameters.
+    let args = unsafe { slice::from_raw_parts(args, params.len()) };
+    let objects: Vec<_> = params
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            if param.ty != b'o' {
+                return None;
+            }
+            // SAFETY: - args conforms to msg which requires that this is an object
+            //         - libwayland keeps the object alive while the event is dispatched
+            NonNull::new(unsafe { arg.o }.cast())
+                .map(|p| unsafe { UntypedBorrowedProxy::new_immutable(queue.libwayland(), p) })
+        })
+        .collect();
+    let mut decoded = Vec::with_capacity(params.len());
+    for ((param, arg), object) in params.iter().zip(args).zip(&objects) {
+        // SAFETY: args conforms to msg so each argument has the type of its parameter.
+        let arg = unsafe {
+            match param.ty {
+                b'i' => DynEvent::Int(arg.i),
+                b'u' => DynEvent::Uint(arg.u),
+                b'f' => DynEvent::Fixed(Fixed::from_wire(arg.f)),
+                b's' if param.nullable => {
+                    DynEvent::String(convert_optional_string_arg(interface_name, name, arg.s))
+                }
+                b's' => DynEvent::String(Some(convert_string_arg(interface_name, name, arg.s))),
+                b'o' => DynEvent::Object(object.as_ref()),
+                b'n' => {
+                    // The untyped case was handled above.
+                    let interface = param.interface.unwrap();
+                    // SAFETY: - the new_id argument of an event is a new plain proxy
+                    //           whose ownership is transferred to the event handler
+                    //         - libwayland attaches it to the queue of the proxy
+                    //         - its interface is the interface of the parameter
+                    DynEvent::NewId(UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(arg.o.cast()),
+                        interface,
+                    ))
+                }
+                b'a' => {
+                    let a = &*arg.a;
+                    DynEvent::Array(if a.size == 0 {
+                        &[]
+                    } else {
+                        slice::from_raw_parts(a.data.cast(), a.size)
+                    })
+                }
+                _ => DynEvent::Fd(OwnedFd::from_raw_fd(arg.h)),
+            }
+        };
+        decoded.push(arg);
+    }
+    f(name, decoded);
+    Ok(())
+}
+
+/// Destroys the new proxies and closes the file descriptors of an event.
+///
+/// # Safety
+///
+/// - `queue` must be the queue of the proxy that received the event.
+/// - The interface of this proxy must be compatible with `interface`.
+/// - `opcode` and `args` must conform to an event of `interface` and must have been
+///   passed to an event handler by libwayland.
+pub(crate) unsafe fn release_event(
+    interface: &'static wl_interface,
+    queue: &Queue,
+    opcode: u32,
+    args: *mut wl_argument,
+) {
+    // SAFETY: The interface is valid.
+    let events = unsafe { messages(interface.events, interface.event_count) };
+    let Some(msg) = events.get(opcode as usize) else {
+        // SAFETY: The name of a valid interface is a valid C string.
+        invalid_opcode(
+            unsafe { convert_string_arg("wl_interface", "name", interface.name) },
+            opcode,
+        );
+    };
+    // SAFETY: The messages of a valid interface are valid.
+    let (_, params) = unsafe { parse_signature(msg) };
+    // SAFETY: The requirements are forwarded to the caller.
+    unsafe {
+        release_args(queue, &params, args);
+    }
+}
+
+/// # Safety
+///
+/// - `queue` must be the queue of the proxy that received the event.
+/// - `params` must have been parsed from the signature of a message and `args` must
+///   conform to this message and must have been passed to an event handler by
+///   libwayland.
+unsafe fn release_args(queue: &Queue, params: &[Param], mut args: *mut wl_argument) {
+    for param in params {
+        // SAFETY: - args conforms to the message so each argument has the type of its
+        //           parameter and untyped new_id arguments are preceded by their
+        //           interface name and version
+        //         - file descriptors and new proxies are owned by the event handler
+        unsafe {
+            if param.ty == b'n' && param.interface.is_none() {
+                args = args.add(2);
+            }
+            let arg = &*args;
+            args = args.add(1);
+            match param.ty {
+                b'h' => drop(OwnedFd::from_raw_fd(arg.h)),
+                b'n' if !arg.o.is_null() => match param.interface {
+                    Some(interface) => drop(UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(arg.o.cast()),
+                        interface,
+                    )),
+                    None => queue.libwayland().wl_proxy_destroy(arg.o.cast()),
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+/// An error returned by [`UntypedBorrowedProxy::send_dynamic`] or passed to
+/// [`DynamicEventHandler::handle_error`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DynamicError {
+    /// The interface of the proxy is not known.
+    ///
+    /// This is the case for proxies that were passed into event handlers as event
+    /// arguments.
+    UnknownInterface,
+    /// The proxy has already been destroyed.
+    Destroyed,
+    /// The interface has no request with this name.
+    UnknownRequest(String),
+    /// The interface has no request with this opcode.
+    InvalidOpcode(u32),
+    /// The request requires a higher version than the version of the proxy.
+    RequestVersion {
+        request: String,
+        since: u32,
+        version: u32,
+    },
+    /// The number of arguments does not match the request.
+    ArgumentCount {
+        request: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// The type of an argument does not match the request.
+    ArgumentType {
+        request: String,
+        index: usize,
+        expected: &'static str,
+        actual: &'static str,
+    },
+    /// `None` was passed for an argument that is not nullable.
+    NullArgument { request: String, index: usize },
+    /// An object argument has already been destroyed.
+    DestroyedArgument { request: String, index: usize },
+    /// An object argument has a different interface than required by the request.
+    ArgumentInterface {
+        request: String,
+        index: usize,
+        expected: String,
+        actual: String,
+    },
+    /// The request has more than one `new_id` argument, which libwayland does not
+    /// support.
+    MultipleNewIds(String),
+    /// A string argument contains a nul byte.
+    NulInString { request: String, index: usize },
+    /// An event has an untyped `new_id` argument, which cannot be decoded.
+    UntypedNewIdEvent(String),
+    /// The version of an untyped `new_id` argument is 0 or larger than the version of
+    /// its interface.
+    UnsupportedVersion {
+        request: String,
+        index: usize,
+        version: u32,
+        max_version: u32,
+    },
+}
+
+impl Display for DynamicError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownInterface => f.write_str("the interface of the proxy is not known"),
+            Self::Destroyed => f.write_str("the proxy has already been destroyed"),
+            Self::UnknownRequest(name) => write!(f, "the interface has no request `{name}`"),
+            Self::InvalidOpcode(opcode) => {
+                write!(f, "the interface has no request with opcode {opcode}")
+            }
+            Self::RequestVersion {
+                request,
+                since,
+                version,
+            } => write!(
+                f,
+                "request `{request}` requires version {since} but the proxy has version {version}",
+            ),
+            Self::ArgumentCount {
+                request,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "request `{request}` has {expected} arguments but {actual} were given",
+            ),
+            Self::ArgumentType {
+                request,
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "argument {index} of request `{request}` has type `{expected}` but `{actual}` was given",
+            ),
+            Self::NullArgument { request, index } => {
+                write!(f, "argument {index} of request `{request}` is not nullable")
+            }
+            Self::DestroyedArgument { request, index } => write!(
+                f,
+                "argument {index} of request `{request}` has already been destroyed",
+            ),
+            Self::ArgumentInterface {
+                request,
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "argument {index} of request `{request}` must have interface `{expected}` but has interface `{actual}`",
+            ),
+            Self::MultipleNewIds(request) => {
+                write!(f, "request `{request}` has more than one new_id argument")
+            }
+            Self::NulInString { request, index } => write!(
+                f,
+                "argument {index} of request `{request}` contains a nul byte",
+            ),
+            Self::UntypedNewIdEvent(event) => {
+                write!(f, "event `{event}` has an untyped new_id argument")
+            }
+            Self::UnsupportedVersion {
+                request,
+                index,
+                version,
+                max_version,
+            } => write!(
+                f,
+                "argument {index} of request `{request}` has version {version} but the interface supports versions 1 to {max_version}",
+            ),
+        }
+    }
+}
+
+impl Error for DynamicError {}
+
+/// A parameter of a request as described by the signature of its `wl_message`.
+struct Param {
+    ty: u8,
+    nullable: bool,
+    interface: Option<&'static wl_interface>,
+}
+
+impl Param {
+    fn type_name(&self) -> &'static str {
+        match self.ty {
+            b'i' => "int",
+            b'u' => "uint",
+            b'f' => "fixed",
+            b's' => "string",
+            b'o' => "object",
+            b'n' if self.interface.is_none() => "untyped new_id",
+            b'n' => "new_id",
+            b'a' => "array",
+            _ => "fd",
+        }
+    }
+}
+
+impl DynArg<'_> {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Int(_) => "int",
+            Self::Uint(_) => "uint",
+            Self::Fixed(_) => "fixed",
+            Self::String(_) => "string",
+            Self::Object(_) => "object",
+            Self::NewId(_) => "new_id",
+            Self::UntypedNewId { .. } => "untyped new_id",
+            Self::Array(_) => "array",
+            Self::Fd(_) => "fd",
+        }
+    }
+}
+
+/// Parses the signature of a message.
+///
+/// Returns the version in which the message was added, if any, and the parameters of
+/// the message. An untyped `new_id` parameter, which is encoded as `sun` in the
+/// signature, is returned as a single parameter.
+///
+/// # Safety
+///
+/// - `msg` must be a valid message.
+unsafe fn parse_signature(msg: &wl_message) -> (Option<u32>, Vec<Param>) {
+    // SAFETY: The requirement is forwarded to the caller.
+    let signature = unsafe { CStr::from_ptr(msg.signature).to_bytes() };
+    let mut since = None;
+    let mut params = vec![];
+    let mut nullable = false;
+    let mut type_idx = 0;
+    for &c in signature {
+        match c {
+            b'0'..=b'9' => {
+                let since = since.get_or_insert(0u32);
+                *since = since.saturating_mul(10).saturating_add((c - b'0') as u32);
+            }
+            b'?' => nullable = true,
+            _ => {
+                // SAFETY: The types array of a valid message contains one element per
+                //         type in the signature.
+                let interface = unsafe { (*msg.types.add(type_idx)).as_ref() };
+                type_idx += 1;
+                if c == b'n' && interface.is_none() {
+                    // The `s` and `u` that precede an untyped new_id carry its interface
+                    // name and version.
+                    params.truncate(params.len().saturating_sub(2));
+                }
+                params.push(Param {
+                    ty: c,
+                    nullable,
+                    interface,
+                });
+                nullable = false;
+            }
+        }
+    }
+    (since, params)
+}
+
+/// Returns the name of an interface.
+fn interface_name(interface: &wl_interface) -> String {
+    // SAFETY: The name of a valid interface is a valid C string.
+    unsafe { CStr::from_ptr(interface.name) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+impl UntypedBorrowedProxy {
+    /// Sends a request on this proxy without compile-time type information.
+    ///
+    /// The request is identified by its name or opcode. The arguments are validated
+    /// against the signature of the request in the `wl_interface` of this proxy. If the
+    /// request creates a new object, the new object is returned.
+    ///
+    /// Since `wl_interface` does not record which requests are destructors, sending a
+    /// destructor request does not destroy this proxy. You should call
+    /// [`proxy::destroy`](crate::proxy::destroy) afterwards.
+    ///
+    /// # Panic
+    ///
+    /// This function panics if the request creates a new object and the queue of the
+    /// `new_id` argument does not belong to the same connection as this proxy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// # use wl_client::proxy::low_level::{to_untyped_borrowed, DynArg};
+    /// # use wl_client::test_protocol_helpers::get_root;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// let queue = con.create_queue(c"");
+    /// let root = get_root(&queue);
+    /// let proxy = to_untyped_borrowed(&*root);
+    ///
+    /// let string = proxy
+    ///     .send_dynamic("echo", &[DynArg::NewId(&queue), DynArg::String(Some("hello"))])
+    ///     .unwrap();
+    /// assert!(string.is_some());
+    ///
+    /// let err = proxy
+    ///     .send_dynamic("echo", &[DynArg::NewId(&queue)])
+    ///     .err()
+    ///     .unwrap();
+    /// assert_eq!(err.to_string(), "request `echo` has 2 arguments but 1 were given");
+    /// ```
+    pub fn send_dynamic<'a>(
+        &self,
+        message: impl Into<DynMessage<'a>>,
+        args: &[DynArg<'_>],
+    ) -> Result<Option<UntypedOwnedProxy>, DynamicError> {
+        let Some(interface) = self.interface else {
+            return Err(DynamicError::UnknownInterface);
+        };
+        // SAFETY: The interface of a proxy is valid.
+        let requests = unsafe { messages(interface.methods, interface.method_count) };
+        let (opcode, msg) = match message.into() {
+            DynMessage::Name(name) => requests
+                .iter()
+                .enumerate()
+                // SAFETY: The name of a valid message is a valid C string.
+                .find(|(_, msg)| unsafe { CStr::from_ptr(msg.name) }.to_bytes() == name.as_bytes())
+                .ok_or_else(|| DynamicError::UnknownRequest(name.to_string()))?,
+            DynMessage::Opcode(opcode) => requests
+                .get(opcode as usize)
+                .map(|msg| (opcode as usize, msg))
+                .ok_or(DynamicError::InvalidOpcode(opcode))?,
+        };
+        let opcode = opcode as u32;
+        // SAFETY: The name of a valid message is a valid C string.
+        let request = unsafe { CStr::from_ptr(msg.name) }
+            .to_string_lossy()
+            .into_owned();
+        // SAFETY: The messages of a valid interface are valid.
+        let (since, params) = unsafe { parse_signature(msg) };
+
+        let lock = self.lock();
+        if lock.wl_proxy().is_none() {
+            return Err(DynamicError::Destroyed);
+        }
+        if let Some(since) = since {
+            let version = self.version();
+            if since > version {
+                return Err(DynamicError::RequestVersion {
+                    request,
+                    since,
+                    version,
+                });
+            }
+        }
+        if params.len() != args.len() {
+            return Err(DynamicError::ArgumentCount {
+                request,
+                expected: params.len(),
+                actual: args.len(),
+            });
+        }
+
+        let mut wl_args = Vec::with_capacity(params.len() + 2);
+        let mut strings = vec![];
+        let mut arrays = vec![];
+        let mut array_idxs = vec![];
+        let mut locks = vec![];
+        if params.iter().filter(|p| p.ty == b'n').count() > 1 {
+            return Err(DynamicError::MultipleNewIds(request));
+        }
+        let mut new_id = None;
+        for (index, (param, arg)) in params.iter().zip(args).enumerate() {
+            let type_error = || DynamicError::ArgumentType {
+                request: request.clone(),
+                index,
+                expected: param.type_name(),
+                actual: arg.type_name(),
+            };
+            let null_error = || DynamicError::NullArgument {
+                request: request.clone(),
+                index,
+            };
+            let arg = match (param.ty, *arg) {
+                (b'i', DynArg::Int(i)) => wl_argument { i },
+                (b'u', DynArg::Uint(u)) => wl_argument { u },
+                (b'f', DynArg::Fixed(f)) => wl_argument { f: f.to_wire() },
+                (b's', DynArg::String(s)) => match s {
+                    None if param.nullable => wl_argument { s: ptr::null() },
+                    None => return Err(null_error()),
+                    Some(s) => {
+                        let s = CString::new(s).map_err(|_| DynamicError::NulInString {
+                            request: request.clone(),
+                            index,
+                        })?;
+                        let arg = wl_argument { s: s.as_ptr() };
+                        strings.push(s);
+                        arg
+                    }
+                },
+                (b'o', DynArg::Object(o)) => match o {
+                    None if param.nullable => wl_argument { o: ptr::null_mut() },
+                    None => return Err(null_error()),
+                    Some(o) => {
+                        let lock = o.lock();
+                        let Some(proxy) = lock.wl_proxy() else {
+                            return Err(DynamicError::DestroyedArgument { request, index });
+                        };
+                        if let Some(expected) = param.interface {
+                            let compatible = match o.interface {
+                                // SAFETY: Both interfaces are valid.
+                                Some(actual) => unsafe { interface_compatible(expected, actual) },
+                                None => {
+                                    // SAFETY: proxy is valid while the lock is held.
+                                    let actual = unsafe {
+                                        CStr::from_ptr(
+                                            o.libwayland.wl_proxy_get_class(proxy.as_ptr()),
+                                        )
+                                    };
+                                    // SAFETY: The name of a valid interface is a valid C
+                                    //         string.
+                                    actual == unsafe { CStr::from_ptr(expected.name) }
+                                }
+                            };
+                            if !compatible {
+                                // SAFETY: proxy is valid while the lock is held.
+                                let actual = unsafe {
+                                    CStr::from_ptr(o.libwayland.wl_proxy_get_class(proxy.as_ptr()))
+                                };
+                                return Err(DynamicError::ArgumentInterface {
+                                    request,
+                                    index,
+                                    expected: interface_name(expected),
+                                    actual: actual.to_string_lossy().into_owned(),
+                                });
+                            }
+                        }
+                        locks.push(lock);
+                        wl_argument {
+                            o: proxy.as_ptr().cast::<wl_object>(),
+                        }
+                    }
+                },
+                (b'n', DynArg::NewId(queue)) if param.interface.is_some() => {
+                    new_id = Some((queue, param.interface.unwrap(), None));
+                    wl_argument { n: 0 }
+                }
+                (
+                    b'n',
+                    DynArg::UntypedNewId {
+                        queue,
+                        interface,
+                        version,
+                    },
+                ) if param.interface.is_none() => {
+                    let max_version = interface.version as u32;
+                    if version == 0 || version > max_version {
+                        return Err(DynamicError::UnsupportedVersion {
+                            request,
+                            index,
+                            version,
+                            max_version,
+                        });
+                    }
+                    new_id = Some((queue, interface, Some(version)));
+                    wl_args.push(wl_argument { s: interface.name });
+                    wl_args.push(wl_argument { u: version });
+                    wl_argument { n: 0 }
+                }
+                (b'a', DynArg::Array(a)) => {
+                    arrays.push(wl_array {
+                        size: a.len(),
+                        alloc: a.len(),
+                        data: a.as_ptr().cast_mut().cast(),
+                    });
+                    array_idxs.push(wl_args.len());
+                    wl_argument { a: ptr::null_mut() }
+                }
+                (b'h', DynArg::Fd(fd)) => wl_argument { h: fd.as_raw_fd() },
+                _ => return Err(type_error()),
+            };
+            wl_args.push(arg);
+        }
+        for (idx, array) in array_idxs.into_iter().zip(&mut arrays) {
+            wl_args[idx].a = array;
+        }
+
+        let proxy = match new_id {
+            None => {
+                // SAFETY: - opcode is a valid request opcode of the interface
+                //         - we've validated args against the signature of the request
+                //         - the request has no new_id argument
+                //         - the string, array, and object arguments are kept alive by
+                //           strings, arrays, and locks
+                unsafe {
+                    self.send_request(opcode, &mut wl_args);
+                }
+                None
+            }
+            Some((queue, interface, version)) => {
+                // SAFETY: - opcode is a valid request opcode of the interface
+                //         - we've validated args against the signature of the request
+                //         - the request has exactly one new_id argument whose interface
+                //           is interface
+                //         - interface is a valid wl_interface
+                //         - the string, array, and object arguments are kept alive by
+                //           strings, arrays, and locks
+                let proxy = unsafe {
+                    self.send_constructor(queue, opcode, &mut wl_args, interface, version)
+                };
+                Some(proxy)
+            }
+        };
+        Ok(proxy)
+    }
+}
@@ -0,0 +1,441 @@
+use {
+    crate::{
+        Libwayland,
+        ffi::{wl_argument, wl_interface, wl_message},
+        proxy::{
+            self, OwnedProxy,
+            low_level::{
+                DynArg, DynEvent, DynamicError, DynamicEventHandler, EventHandler,
+                UntypedBorrowedProxy, dynamic::DynamicEventHandlerAdapter, from_untyped_owned,
+                on_dynamic_event, to_untyped_borrowed,
+            },
+        },
+        test_protocol_helpers::get_root,
+        test_protocols::core::{
+            wl_display::WlDisplay,
+            wl_dummy::{WlDummy, WlDummyRef},
+            wl_root::WlRoot,
+            wl_string::WlString,
+        },
+    },
+    std::{
+        cell::RefCell,
+        os::fd::AsFd,
+        ptr,
+        rc::Rc,
+        sync::{Arc, Mutex},
+    },
+};
+
+#[test]
+fn constructor() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"");
+    let root = get_root(&queue);
+    let string = to_untyped_borrowed(&*root)
+        .send_dynamic(
+            "echo",
+            &[DynArg::NewId(&queue), DynArg::String(Some("hello"))],
+        )
+        .unwrap()
+        .unwrap();
+    // SAFETY: echo creates a wl_string.
+    let string: WlString = unsafe { from_untyped_owned(string) };
+    let received = Rc::new(RefCell::new(String::new()));
+    let received2 = received.clone();
+    proxy::set_event_handler_local(
+        &string,
+        WlString::on_string(move |_, s| *received2.borrow_mut() = s.to_string()),
+    );
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(*received.borrow(), "hello");
+}
+
+#[test]
+fn request_by_opcode() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"");
+    let root = get_root(&queue);
+    let dummy = root.create_dummy();
+    let ponged = Rc::new(RefCell::new(false));
+    let ponged2 = ponged.clone();
+    let dummy2 = dummy.clone();
+    proxy::set_event_handler_local(
+        &root,
+        WlRoot::on_pong_dummy(move |_, d: Option<&WlDummyRef>| {
+            assert_eq!(d.unwrap(), &*dummy2);
+            *ponged2.borrow_mut() = true;
+        }),
+    );
+    let res = to_untyped_borrowed(&*root)
+        .send_dynamic(1, &[DynArg::Object(Some(to_untyped_borrowed(&*dummy)))])
+        .unwrap();
+    assert!(res.is_none());
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert!(*ponged.borrow());
+}
+
+#[test]
+fn untyped_new_id() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"");
+    let root = get_root(&queue);
+    let proxy = to_untyped_borrowed(&*root);
+    let new_id = |version| DynArg::UntypedNewId {
+        queue: &queue,
+        interface: WlDummy::WL_INTERFACE,
+        version,
+    };
+    let dummy = proxy.send_dynamic("bind", &[new_id(1)]).unwrap().unwrap();
+    // SAFETY: bind was called with the wl_dummy interface.
+    let dummy: WlDummy = unsafe { from_untyped_owned(dummy) };
+    assert_eq!(proxy::version(&*dummy), 1);
+    assert_eq!(
+        proxy.send_dynamic("bind", &[new_id(0)]).err().unwrap(),
+        DynamicError::UnsupportedVersion {
+            request: "bind".to_string(),
+            index: 0,
+            version: 0,
+            max_version: 1,
+        },
+    );
+    assert_eq!(
+        proxy
+            .send_dynamic("bind", &[DynArg::NewId(&queue)])
+            .err()
+            .unwrap(),
+        DynamicError::ArgumentType {
+            request: "bind".to_string(),
+            index: 0,
+            expected: "untyped new_id",
+            actual: "new_id",
+        },
+    );
+}
+
+#[test]
+fn errors() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"");
+    let root = get_root(&queue);
+    let proxy = to_untyped_borrowed(&*root);
+    assert_eq!(
+        proxy.send_dynamic("unknown", &[]).err().unwrap(),
+        DynamicError::UnknownRequest("unknown".to_string()),
+    );
+    assert_eq!(
+        proxy.send_dynamic(100, &[]).err().unwrap(),
+        DynamicError::InvalidOpcode(100),
+    );
+    assert_eq!(
+        proxy.send_dynamic("echo", &[]).err().unwrap(),
+        DynamicError::ArgumentCount {
+            request: "echo".to_string(),
+            expected: 2,
+            actual: 0,
+        },
+    );
+    assert_eq!(
+        proxy
+            .send_dynamic("echo", &[DynArg::NewId(&queue), DynArg::Uint(1)])
+            .err()
+            .unwrap(),
+        DynamicError::ArgumentType {
+            request: "echo".to_string(),
+            index: 1,
+            expected: "string",
+            actual: "uint",
+        },
+    );
+    assert_eq!(
+        proxy
+            .send_dynamic("echo", &[DynArg::NewId(&queue), DynArg::String(None)])
+            .err()
+            .unwrap(),
+        DynamicError::NullArgument {
+            request: "echo".to_string(),
+            index: 1,
+        },
+    );
+    assert_eq!(
+        proxy
+            .send_dynamic(
+                "echo",
+                &[DynArg::NewId(&queue), DynArg::String(Some("a\0b"))]
+            )
+            .err()
+            .unwrap(),
+        DynamicError::NulInString {
+            request: "echo".to_string(),
+            index: 1,
+        },
+    );
+    let stdout = std::io::stdout();
+    assert_eq!(
+        proxy
+            .send_dynamic("ping_dummy", &[DynArg::Fd(stdout.as_fd())])
+            .err()
+            .unwrap(),
+        DynamicError::ArgumentType {
+            request: "ping_dummy".to_string(),
+            index: 0,
+            expected: "object",
+            actual: "fd",
+        },
+    );
+    assert_eq!(
+        proxy
+            .send_dynamic("ping_dummy", &[DynArg::Object(Some(proxy))])
+            .err()
+            .unwrap(),
+        DynamicError::ArgumentInterface {
+            request: "ping_dummy".to_string(),
+            index: 0,
+            expected: "wl_dummy".to_string(),
+            actual: "wl_root".to_string(),
+        },
+    );
+    let dummy = root.create_dummy();
+    proxy::destroy(&dummy);
+    assert_eq!(
+        proxy
+            .send_dynamic(
+                "ping_dummy",
+                &[DynArg::Object(Some(to_untyped_borrowed(&*dummy)))],
+            )
+            .err()
+            .unwrap(),
+        DynamicError::DestroyedArgument {
+            request: "ping_dummy".to_string(),
+            index: 0,
+        },
+    );
+    proxy::destroy(&root);
+    assert_eq!(
+        proxy.send_dynamic("send_new_dummy", &[]).err().unwrap(),
+        DynamicError::Destroyed,
+    );
+}
+
+#[test]
+fn unknown_interface() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"");
+    let display = queue.display::<WlDisplay>();
+    // SAFETY: The display outlives the borrowed proxy.
+    let borrowed =
+        unsafe { UntypedBorrowedProxy::new_immutable(lib, proxy::wl_proxy(&*display).unwrap()) };
+    assert_eq!(
+        borrowed.send_dynamic("sync", &[]).err().unwrap(),
+        DynamicError::UnknownInterface,
+    );
+}
+
+#[test]
+fn immutable_argument_interface() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"");
+    let root = get_root(&queue);
+    let proxy = to_untyped_borrowed(&*root);
+    // SAFETY: The root outlives the borrowed proxy.
+    let borrowed =
+        unsafe { UntypedBorrowedProxy::new_immutable(lib, proxy::wl_proxy(&*root).unwrap()) };
+    assert_eq!(
+        proxy
+            .send_dynamic("ping_dummy", &[DynArg::Object(Some(&borrowed))])
+            .err()
+            .unwrap(),
+        DynamicError::ArgumentInterface {
+            request: "ping_dummy".to_string(),
+            index: 0,
+            expected: "wl_dummy".to_string(),
+            actual: "wl_root".to_string(),
+        },
+    );
+    let dummy = root.create_dummy();
+    // SAFETY: The dummy outlives the borrowed proxy.
+    let borrowed =
+        unsafe { UntypedBorrowedProxy::new_immutable(lib, proxy::wl_proxy(&*dummy).unwrap()) };
+    proxy
+        .send_dynamic("ping_dummy", &[DynArg::Object(Some(&borrowed))])
+        .unwrap();
+}
+
+#[test]
+fn event_handler() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"");
+    let root = get_root(&queue);
+    let dummy = root.create_dummy();
+    let events = Arc::new(Mutex::new(vec![]));
+    let events2 = events.clone();
+    let root2 = root.clone();
+    let dummy2 = dummy.clone();
+    proxy::set_dynamic_event_handler(
+        &root,
+        on_dynamic_event(move |slf, opcode, name, args| {
+            assert!(slf == to_untyped_borrowed(&*root2));
+            let new_id = match &*args {
+                [DynEvent::Object(Some(d))] => {
+                    assert!(*d == to_untyped_borrowed(&*dummy2));
+                    None
+                }
+                [DynEvent::NewId(d)] => Some(d.clone()),
+                _ => unreachable!(),
+            };
+            events2
+                .lock()
+                .unwrap()
+                .push((opcode, name.to_string(), new_id));
+        }),
+    );
+    root.ping_dummy(&dummy);
+    root.send_new_dummy();
+    queue.dispatch_roundtrip_blocking().unwrap();
+    let mut events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].0, &*events[0].1), (0, "pong_dummy"));
+    assert!(events[0].2.is_none());
+    assert_eq!((events[1].0, &*events[1].1), (1, "new_dummy"));
+    let new_dummy = events[1].2.take().unwrap();
+    assert_eq!(new_dummy.queue(), &*queue);
+    // SAFETY: new_dummy creates a wl_dummy.
+    let new_dummy: WlDummy = unsafe { from_untyped_owned(new_dummy) };
+    assert!(proxy::wl_proxy(&*new_dummy).is_some());
+}
+
+#[test]
+fn event_handler_local() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"");
+    let root = get_root(&queue);
+    let string = to_untyped_borrowed(&*root)
+        .send_dynamic(
+            "echo",
+            &[DynArg::NewId(&queue), DynArg::String(Some("hello"))],
+        )
+        .unwrap()
+        .unwrap();
+    let received = Rc::new(RefCell::new(None));
+    let received2 = received.clone();
+    proxy::set_dynamic_event_handler_local(
+        &string,
+        on_dynamic_event(move |_, _, name, args| {
+            let [DynEvent::String(Some(s))] = &*args else {
+                unreachable!();
+            };
+            *received2.borrow_mut() = Some(format!("{name}: {s}"));
+        }),
+    );
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(received.borrow().as_deref(), Some("string: hello"));
+}
+
+#[test]
+fn event_handler_scoped() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"");
+    let root = get_root(&queue);
+    let names = RefCell::new(vec![]);
+    queue.dispatch_scope_blocking(|scope| {
+        scope.set_dynamic_event_handler_local(
+            &root,
+            on_dynamic_event(|_, _, name, _| names.borrow_mut().push(name.to_string())),
+        );
+        root.send_new_dummy();
+        queue.dispatch_roundtrip_blocking().unwrap();
+    });
+    assert_eq!(*names.borrow(), ["new_dummy"]);
+    // After the end of the scope, the new proxy is destroyed by the fallback.
+    root.send_new_dummy();
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(names.borrow().len(), 1);
+}
+
+#[test]
+fn untyped_new_id_event() {
+    static INTERFACE: wl_interface = wl_interface {
+        name: c"wl_factory".as_ptr(),
+        version: 1,
+        method_count: 0,
+        methods: ptr::null(),
+        event_count: 1,
+        events: {
+            static MESSAGES: [wl_message; 1] = [wl_message {
+                name: c"created".as_ptr(),
+                signature: c"sun".as_ptr(),
+                types: {
+                    static TYPES: [Option<&'static wl_interface>; 3] = [None, None, None];
+                    TYPES.as_ptr().cast()
+                },
+            }];
+            MESSAGES.as_ptr()
+        },
+    };
+
+    #[derive(Default)]
+    struct Handler {
+        events: RefCell<Vec<String>>,
+        errors: RefCell<Vec<(u32, DynamicError)>>,
+    }
+
+    impl DynamicEventHandler for &Handler {
+        fn handle_event(
+            &self,
+            _slf: &UntypedBorrowedProxy,
+            _opcode: u32,
+            name: &str,
+            _args: Vec<DynEvent<'_>>,
+        ) {
+            self.events.borrow_mut().push(name.to_string());
+        }
+
+        fn handle_error(&self, _slf: &UntypedBorrowedProxy, opcode: u32, error: DynamicError) {
+            self.errors.borrow_mut().push((opcode, error));
+        }
+    }
+
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"");
+    let root = get_root(&queue);
+    let handler = Handler::default();
+    let adapter = DynamicEventHandlerAdapter::new(&handler, &INTERFACE);
+    let mut args = [
+        wl_argument {
+            s: c"wl_dummy".as_ptr(),
+        },
+        wl_argument { u: 1 },
+        wl_argument { o: ptr::null_mut() },
+    ];
+    // SAFETY: - root is a proxy on queue
+    //         - the arguments conform to the only event of INTERFACE and do not
+    //           contain any new proxies or file descriptors
+    unsafe {
+        adapter.handle_event(
+            &queue,
+            ptr::null_mut(),
+            to_untyped_borrowed(&*root),
+            0,
+            args.as_mut_ptr(),
+        );
+    }
+    assert!(handler.events.borrow().is_empty());
+    assert_eq!(
+        *handler.errors.borrow(),
+        [(0, DynamicError::UntypedNewIdEvent("created".to_string()))],
+    );
+    assert_eq!(
+        handler.errors.borrow()[0].1.to_string(),
+        "event `created` has an untyped new_id argument",
+    );
+}
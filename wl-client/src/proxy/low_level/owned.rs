@@ -1,16 +1,20 @@
 use {
     crate::{
         ffi::{
-            WL_MARSHAL_FLAG_DESTROY, interface_compatible, wl_argument, wl_dispatcher_func_t,
-            wl_interface, wl_message, wl_proxy,
+            LibwaylandInterface, WL_MARSHAL_FLAG_DESTROY, interface_compatible, wl_argument,
+            wl_dispatcher_func_t, wl_interface, wl_message, wl_proxy,
         },
         proxy::low_level::{
             borrowed::UntypedBorrowedProxy,
             check_dispatching_proxy, check_new_proxy,
+            dynamic::{DynamicEventHandler, DynamicEventHandlerAdapter},
             owned::scope::{Scope, ScopeData},
         },
-        queue::Queue,
-        utils::sync_ptr::{SyncNonNull, SyncPtr},
+        queue::{Queue, stats::ProxyStats},
+        utils::{
+            sync_ptr::{SyncNonNull, SyncPtr},
+            trace::HandlerSpan,
+        },
     },
     destruction::ProxyDataDestruction,
     parking_lot::Mutex,
@@ -32,6 +36,7 @@ use {
                 fence,
             },
         },
+        time::Instant,
     },
 };
 
@@ -160,7 +165,7 @@ impl UntypedOwnedProxy {
         // SAFETY: - proxy is a plain proxy and interface is its interface
         //         - proxy does not have an event handler assigned
         //         - queue is the queue of the queue
-        unsafe { Self::new(queue, proxy, Some(interface)) }
+        unsafe { Self::new(queue, proxy, Some(interface), false) }
     }
 
     /// Creates a new [`UntypedOwnedProxy`] from a wrapper `wl_proxy`.
@@ -173,7 +178,86 @@ impl UntypedOwnedProxy {
     pub unsafe fn from_wrapper_wl_proxy(queue: &Queue, proxy: NonNull<wl_proxy>) -> Self {
         // SAFETY: - proxy is a valid wrapper and as such does not have an event handler
         //         - queue is the queue of the proxy
-        unsafe { Self::new(queue, proxy, None) }
+        unsafe { Self::new(queue, proxy, None, true) }
+    }
+
+    /// Creates a new [`UntypedOwnedProxy`] from a wrapper `wl_proxy` whose interface is
+    /// known.
+    ///
+    /// # Safety
+    ///
+    /// - `proxy` must be a valid pointer to a wrapper `wl_proxy`.
+    /// - `proxy` must remain valid for the lifetime of this object and its clones.
+    /// - `queue` must be the queue of the proxy.
+    /// - `interface` must be compatible with the interface of the proxy.
+    pub(crate) unsafe fn from_typed_wrapper_wl_proxy(
+        queue: &Queue,
+        proxy: NonNull<wl_proxy>,
+        interface: &'static wl_interface,
+    ) -> Self {
+        // SAFETY: - proxy is a valid wrapper and as such does not have an event handler
+        //         - queue is the queue of the proxy
+        //         - interface is compatible with the interface of the proxy
+        unsafe { Self::new(queue, proxy, Some(interface), true) }
+    }
+
+    /// Takes ownership of a plain `wl_proxy` that was created by foreign code.
+    ///
+    /// This can be used with proxies that were created by another library, e.g. EGL or
+    /// a C library, that hands the `wl_proxy` over and gives up handling its events.
+    ///
+    /// The proxy is moved to `queue`. Afterwards, event handlers and scopes can be used
+    /// with the returned object just like with any other proxy created by this crate.
+    /// The proxy is destroyed when it is explicitly destroyed or when the last clone of
+    /// the returned object is dropped.
+    ///
+    /// # Panic
+    ///
+    /// - Panics if the proxy and the queue don't belong to the same `wl_display`.
+    /// - Panics if the proxy already has a listener or dispatcher assigned.
+    ///
+    /// # Safety
+    ///
+    /// - `proxy` must be a valid pointer to a plain `wl_proxy`.
+    /// - This function takes ownership of the proxy. The foreign code must not use or
+    ///   destroy the proxy afterwards and must not access the proxy concurrently with
+    ///   this function.
+    /// - `proxy` must not have a dispatcher that was assigned with a null listener.
+    ///   Such a dispatcher cannot be detected.
+    /// - If the proxy is currently attached to a queue other than `queue`, that queue
+    ///   must not contain any events for the proxy.
+    /// - `interface` must be the interface of the proxy.
+    pub unsafe fn adopt_foreign(
+        queue: &Queue,
+        proxy: NonNull<wl_proxy>,
+        interface: &'static wl_interface,
+    ) -> Self {
+        let lib = queue.libwayland();
+        // SAFETY: - proxy is a valid pointer
+        let display = unsafe { lib.wl_proxy_get_display(proxy.as_ptr()) };
+        assert_eq!(
+            display,
+            queue.connection().wl_display().as_ptr(),
+            "Proxy belongs to a different display",
+        );
+        // SAFETY: - proxy is a valid pointer
+        //         - the caller has handed us ownership of the proxy, there can be no
+        //           concurrent access
+        let listener = unsafe { lib.wl_proxy_get_listener(proxy.as_ptr()) };
+        assert!(listener.is_null(), "Proxy already has a listener");
+        // SAFETY: - proxy is a valid pointer
+        //         - queue.wl_event_queue() is valid as long as queue is valid
+        //         - the UntypedOwnedProxy created below will hold a reference to queue
+        //         - we've verified above that the proxy belongs to the display of queue
+        unsafe {
+            lib.wl_proxy_set_queue(proxy.as_ptr(), queue.wl_event_queue().as_ptr());
+        }
+        // SAFETY: - proxy is a plain proxy and interface is its interface
+        //         - we've checked that proxy does not have a listener and the caller
+        //           guarantees that it does not have a dispatcher without a listener
+        //         - ownership has been transferred to this function
+        //         - we've just set the queue of the proxy to queue
+        unsafe { Self::new(queue, proxy, Some(interface), false) }
     }
 
     /// # Safety
@@ -183,14 +267,23 @@ impl UntypedOwnedProxy {
     /// - this function takes ownership of the proxy
     /// - the proxy's event handler must not be modified except through this object
     /// - queue must be the queue of the proxy
-    /// - if interface is not None, it must be the interface of the wl_proxy
-    /// - if this is a wrapper, then interface must be None
-    /// - if this is not a wrapper, then interface must not be None
+    /// - if this is not a wrapper, then interface must be the interface of the wl_proxy
+    /// - if this is a wrapper and interface is not None, then interface must be
+    ///   compatible with the interface of the wl_proxy
     unsafe fn new(
         queue: &Queue,
         proxy: NonNull<wl_proxy>,
         interface: Option<&'static wl_interface>,
+        is_wrapper: bool,
     ) -> Self {
+        // SAFETY: By the safety requirements of this function, interface is valid.
+        let stats = interface.and_then(|i| unsafe { ProxyStats::new(queue, i) });
+        let proxy_interface = interface;
+        let interface = match is_wrapper {
+            true => None,
+            false => interface,
+        };
+        debug_assert!(is_wrapper || interface.is_some());
         let data = Box::new(UntypedOwnedProxyData {
             ref_count: AtomicUsize::new(1),
             queue: queue.clone(),
@@ -199,7 +292,14 @@ impl UntypedOwnedProxy {
                 //           pointer.
                 //         - Whenever we destroy the proxy we first set it to a null
                 //           pointer.
-                unsafe { UntypedBorrowedProxy::new_internal(queue.libwayland(), proxy) }
+                unsafe {
+                    UntypedBorrowedProxy::new_internal(
+                        queue.libwayland(),
+                        proxy,
+                        proxy_interface,
+                        stats,
+                    )
+                }
             },
             interface,
             ever_had_event_handler: Default::default(),
@@ -259,6 +359,7 @@ impl UntypedOwnedProxy {
         //         - We've checked above that the proxy is not a wrapper.
         //         - We've set the proxy pointer to null.
         unsafe {
+            slf.proxy.record_request(opcode);
             slf.proxy.libwayland.wl_proxy_marshal_array_flags(
                 proxy.as_ptr(),
                 opcode,
@@ -340,6 +441,7 @@ impl UntypedOwnedProxy {
         //           that the proxy is not a wrapper and we've set the proxy pointer to
         //           null.
         let new_proxy = unsafe {
+            slf.proxy.record_request(opcode);
             slf.proxy.libwayland.wl_proxy_marshal_array_flags(
                 proxy.as_ptr(),
                 opcode,
@@ -374,7 +476,7 @@ impl UntypedOwnedProxy {
     {
         // SAFETY: T implements Send.
         unsafe {
-            self.set_event_handler2(handler);
+            self.set_event_handler2(handler, self.event_handler_interface::<T>());
         }
     }
 
@@ -387,14 +489,88 @@ impl UntypedOwnedProxy {
         }
         // SAFETY: We've just checked that the queue is thread local.
         unsafe {
-            self.set_event_handler2(handler);
+            self.set_event_handler2(handler, self.event_handler_interface::<T>());
+        }
+    }
+
+    pub(crate) fn set_dynamic_event_handler<H>(&self, handler: H)
+    where
+        H: DynamicEventHandler + Send + 'static,
+    {
+        let (handler, interface) = self.dynamic_event_handler(handler);
+        // SAFETY: - H implements Send.
+        //         - interface is the interface of this proxy.
+        unsafe {
+            self.set_event_handler2(handler, interface);
+        }
+    }
+
+    pub(crate) fn set_dynamic_event_handler_local<H>(&self, handler: H)
+    where
+        H: DynamicEventHandler + 'static,
+    {
+        if self.data().queue.is_non_local() {
+            panic!("Queue is not a local queue");
+        }
+        let (handler, interface) = self.dynamic_event_handler(handler);
+        // SAFETY: - We've just checked that the queue is thread local.
+        //         - interface is the interface of this proxy.
+        unsafe {
+            self.set_event_handler2(handler, interface);
+        }
+    }
+
+    /// Returns the interface that this proxy is checked against when attaching an event
+    /// handler of type `T`.
+    ///
+    /// This is the libwayland-client interface of `T` if this proxy has that interface
+    /// and `T::WL_INTERFACE` otherwise. `T` accepts the events of both.
+    pub(crate) fn event_handler_interface<T>(&self) -> &'static wl_interface
+    where
+        T: EventHandler,
+    {
+        if let (Some(libwayland), Some(interface)) =
+            (T::LIBWAYLAND_INTERFACE, self.data().interface)
+        {
+            let libwayland = libwayland.get();
+            if ptr::eq(libwayland, interface) {
+                return libwayland;
+            }
         }
+        T::WL_INTERFACE
+    }
+
+    /// Wraps a [`DynamicEventHandler`] so that it can be used for this proxy.
+    ///
+    /// Returns the wrapped event handler and the interface of this proxy. The wrapped
+    /// event handler accepts the events of proxies whose interface is compatible with
+    /// the returned interface.
+    ///
+    /// # Panic
+    ///
+    /// Panics if this proxy is a wrapper.
+    fn dynamic_event_handler<H>(
+        &self,
+        handler: H,
+    ) -> (DynamicEventHandlerAdapter<H>, &'static wl_interface)
+    where
+        H: DynamicEventHandler,
+    {
+        let Some(interface) = self.data().interface else {
+            panic!("Proxy is a wrapper");
+        };
+        (
+            DynamicEventHandlerAdapter::new(handler, interface),
+            interface,
+        )
     }
 
     /// # Safety
     ///
-    /// If T does not implement Send, then the queue must be a local queue.
-    unsafe fn set_event_handler2<T>(&self, event_handler: T)
+    /// - If T does not implement Send, then the queue must be a local queue.
+    /// - If the interface of the proxy is compatible with `interface`, then opcode and
+    ///   args of its events must be accepted by the event handler.
+    unsafe fn set_event_handler2<T>(&self, event_handler: T, interface: &'static wl_interface)
     where
         T: EventHandler + 'static,
     {
@@ -403,7 +579,8 @@ impl UntypedOwnedProxy {
         //         - libwayland only ever calls event handlers while preserving a
         //           valid pointer to the proxy and all pointers in args
         //         - set_event_handler4 checks that the interface of the proxy is
-        //           T::WL_INTERFACE
+        //           compatible with interface which, by the safety requirements,
+        //           implies that the event handler accepts its events
         //         - libwayland ensures that opcode and args conform to the
         //           interface before calling the event handler
         //         - set_event_handler4 sets event_handler to a pointer to T
@@ -416,7 +593,7 @@ impl UntypedOwnedProxy {
         //           on which the queue was created
         //         - we always hold the queue lock while dispatching
         unsafe {
-            self.set_event_handler3(event_handler, event_handler_func::<T>, None);
+            self.set_event_handler3(event_handler, interface, event_handler_func::<T>, None);
         }
     }
 
@@ -424,13 +601,15 @@ impl UntypedOwnedProxy {
     ///
     /// - if T does not implement Send, then the queue must be a local queue
     /// - the safety requirements of event_handler_func must be satisfied whenever it is
-    ///   called
+    ///   called by libwayland as part of a dispatch of a proxy whose interface is
+    ///   compatible with `interface`
     /// - if scope is Some, then either
     ///   - the event handler must be destroyed before the end of 'scope, OR
     ///   - ScopeData::handle_destruction must never run the destructions
     unsafe fn set_event_handler3<'scope, T>(
         &self,
         event_handler: T,
+        interface: &'static wl_interface,
         event_handler_func: wl_dispatcher_func_t,
         scope: Option<&'scope Scope<'scope, '_>>,
     ) where
@@ -451,7 +630,7 @@ impl UntypedOwnedProxy {
             //           of the event handler.
             unsafe { drop::<T>(event_handler) }
         });
-        // SAFETY: - interface is T::WL_INTERFACE
+        // SAFETY: - mutable_data_type is T::mutable_type()
         //         - event_handler is a pointer to T
         //         - we've called Box::into_raw on the event handler so it stays valid
         //           valid pointer to the proxy and all pointers in args
@@ -459,7 +638,7 @@ impl UntypedOwnedProxy {
         //         - the other requirements are forwarded to the caller of this function
         unsafe {
             self.set_event_handler4(
-                T::WL_INTERFACE,
+                interface,
                 T::mutable_type(),
                 event_handler,
                 drop::<T> as *mut u8,
@@ -482,7 +661,6 @@ impl UntypedOwnedProxy {
     ///
     /// There must be a type `T: EventHandler` such that
     ///
-    /// - interface is T::WL_INTERFACE
     /// - mutable_data_type is T::mutable_type()
     /// - event_handler is a pointer to a T
     /// - if T does not implement Send, then the queue must be a local queue
@@ -490,7 +668,8 @@ impl UntypedOwnedProxy {
     /// - drop_event_handler is a pointer to a unsafe fn(*mut u8)
     ///   that can be called once with event_handler
     /// - the safety requirements of event_handler_func must be satisfied whenever it is
-    ///   called by libwayland as part of a dispatch
+    ///   called by libwayland as part of a dispatch of a proxy whose interface is
+    ///   compatible with `interface`
     /// - if scope is Some, then either
     ///   - the event handler must be destroyed before the end of 'scope, OR
     ///   - ScopeData::handle_destruction must never run the destructions
@@ -942,10 +1121,19 @@ impl OwnedProxyRegistry {
 ///
 /// - `WL_INTERFACE` must be a valid wl_interface.
 /// - `mutable_type` must always return the same value.
+/// - If `LIBWAYLAND_INTERFACE` is `Some`, then the events of the interface it returns
+///   must be a prefix of the events of `WL_INTERFACE`.
 pub unsafe trait EventHandler {
     /// The type of interface that can be handled by this event handler.
     const WL_INTERFACE: &'static wl_interface;
 
+    /// The interface defined by libwayland-client that proxies handled by this event
+    /// handler are created with.
+    ///
+    /// If this is `Some`, the event handler can be attached to proxies whose interface
+    /// is compatible with either this interface or `WL_INTERFACE`.
+    const LIBWAYLAND_INTERFACE: Option<&'static LibwaylandInterface> = None;
+
     /// Returns the mutable data type required by this event handler.
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
@@ -979,10 +1167,11 @@ pub unsafe trait EventHandler {
 /// # Safety
 ///
 /// - target must be a valid wl_proxy pointer
-/// - the interface of target must be compatible with T::WL_INTERFACE
+/// - T must accept the events of target, which is the case if the interface of target is
+///   compatible with T::WL_INTERFACE
 /// - event_handler_data must be a pointer to UntypedOwnedProxyData
 /// - the event_handler field in the UntypedOwnedProxy must contain a pointer to T
-/// - opcode and args must conform to T::WL_INTERFACE
+/// - opcode and args must conform to the interface of target
 /// - all pointers must remain valid for the duration of this function call
 /// - the previous point includes pointers inside args
 /// - if T is not `Send`, then the current thread must be the thread on which the
@@ -994,7 +1183,7 @@ unsafe extern "C" fn event_handler_func<T>(
     event_handler_data: *const c_void,
     target: *mut c_void,
     opcode: u32,
-    _msg: *const wl_message,
+    msg: *const wl_message,
     args: *mut wl_argument,
 ) -> c_int
 where
@@ -1003,6 +1192,11 @@ where
     // SAFETY: By the safety requirements of this function, event_handler is a valid pointer
     //         to UntypedOwnedProxyData.
     let proxy_data = unsafe { &*(event_handler_data as *const UntypedOwnedProxyData) };
+    // SAFETY: - T::WL_INTERFACE and the interface of the proxy are valid interfaces
+    //         - by the safety requirements of this function, msg is the message of the
+    //           event of the interface of the proxy, which is always known for proxies
+    //           with event handlers
+    let _span = unsafe { HandlerSpan::enter(proxy_data.interface.unwrap_or(T::WL_INTERFACE), msg) };
     // SAFETY: Dito, target is and stays valid.
     let target = unsafe { NonNull::new_unchecked(target.cast()) };
     // SAFETY: Dito, target is and stays valid.
@@ -1017,10 +1211,11 @@ where
     let event_handler = unsafe { &*event_handler };
     // SAFETY: - Dito, the queue mutex is held.
     let data = unsafe { proxy_data.queue.data() };
+    let start = record_event(proxy_data, opcode);
     let res = catch_unwind(AssertUnwindSafe(|| {
-        // SAFETY: - Dito, the interface of target is compatible with T::WL_INTERFACE
+        // SAFETY: - Dito, T accepts the events of target
         //         - Dito, target is a valid pointer and stays valid
-        //         - Dito, opcode and args conform to T::WL_INTERFACE
+        //         - Dito, opcode and args conform to the interface of target
         //         - Dito, we're being called by libwayland as part of a libwayland
         //           dispatch. If `T::mutable_data` returns `Some`, then Queue::data
         //           guarantees that `data` can be dereferenced to that type.
@@ -1028,12 +1223,31 @@ where
             event_handler.handle_event(&proxy_data.queue, data, &target, opcode, args);
         }
     }));
+    if let Some(start) = start {
+        proxy_data
+            .queue
+            .stats_data()
+            .record_handler_time(start.elapsed());
+    }
     if let Err(e) = res {
         DISPATCH_PANIC.set(Some(e));
     }
     0
 }
 
+/// Records an event in the statistics of a proxy.
+///
+/// Returns the time at which the event handler is invoked if statistics are enabled.
+#[inline]
+fn record_event(proxy_data: &UntypedOwnedProxyData, opcode: u32) -> Option<Instant> {
+    let stats = proxy_data.proxy.stats.as_ref()?;
+    if !stats.enabled() {
+        return None;
+    }
+    stats.record_event(opcode);
+    Some(Instant::now())
+}
+
 thread_local! {
     pub(crate) static DISPATCH_PANIC: Cell<Option<Box<dyn Any + Send>>> = const { Cell::new(None) };
 }
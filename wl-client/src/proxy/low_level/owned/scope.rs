@@ -6,12 +6,14 @@ use {
     crate::{
         DispatchLock, Queue,
         builder::prelude::{CreateEventHandler, EventHandler, UntypedBorrowedProxy},
-        ffi::{wl_argument, wl_interface, wl_message},
+        ffi::{LibwaylandInterface, wl_argument, wl_interface, wl_message},
         proxy::{
             OwnedProxy, get_owned,
             low_level::{
-                OwnedProxyRegistry, ProxyDataDestruction,
-                owned::{UntypedOwnedProxyData, event_handler_func},
+                MutEventHandler, OwnedProxyRegistry, ProxyDataDestruction, UntypedOwnedProxy,
+                UntypedOwnedProxyWrapper,
+                dynamic::{DynamicEventHandler, release_event},
+                owned::{UntypedOwnedProxyData, event_handler_func, record_event},
             },
         },
         utils::{
@@ -461,15 +463,23 @@ impl<'scope> Scope<'scope, '_> {
     /// assert!(done.load(Relaxed));
     /// ```
     #[inline]
-    pub fn set_event_handler<P, H>(&'scope self, proxy: &P, handler: H)
+    pub fn set_event_handler<P, H, M>(&'scope self, proxy: &P, handler: H)
     where
         P: OwnedProxy,
-        P::Api: CreateEventHandler<H>,
-        <P::Api as CreateEventHandler<H>>::EventHandler: Send + 'scope,
+        P::Api: CreateEventHandler<H, M>,
+        <P::Api as CreateEventHandler<H, M>>::EventHandler: Send + 'scope,
     {
+        let handler = P::Api::create_event_handler(handler);
         // SAFETY: - The event handler is Send
+        //         - The interface of the proxy is compatible with P::WL_INTERFACE.
+        //         - The event handler accepts the events of its WL_INTERFACE.
         unsafe {
-            set_event_handler(self, proxy, P::Api::create_event_handler(handler));
+            set_event_handler::<NoOpFallback<P>, _>(
+                self,
+                get_owned(proxy),
+                wl_interface_of(get_owned(proxy), &handler),
+                handler,
+            );
         }
     }
 
@@ -515,18 +525,202 @@ impl<'scope> Scope<'scope, '_> {
     /// assert!(done.get());
     /// ```
     #[inline]
-    pub fn set_event_handler_local<P, H>(&'scope self, proxy: &P, handler: H)
+    pub fn set_event_handler_local<P, H, M>(&'scope self, proxy: &P, handler: H)
     where
         P: OwnedProxy,
-        P::Api: CreateEventHandler<H>,
-        <P::Api as CreateEventHandler<H>>::EventHandler: 'scope,
+        P::Api: CreateEventHandler<H, M>,
+        <P::Api as CreateEventHandler<H, M>>::EventHandler: 'scope,
     {
         if self.data.queue.is_non_local() {
             panic!("Queue is not a local queue");
         }
+        let handler = P::Api::create_event_handler(handler);
         // SAFETY: - We've checked that the queue is a local queue.
+        //         - The interface of the proxy is compatible with P::WL_INTERFACE.
+        //         - The event handler accepts the events of its WL_INTERFACE.
         unsafe {
-            set_event_handler(self, proxy, P::Api::create_event_handler(handler));
+            set_event_handler::<NoOpFallback<P>, _>(
+                self,
+                get_owned(proxy),
+                wl_interface_of(get_owned(proxy), &handler),
+                handler,
+            );
+        }
+    }
+
+    /// Sets a mutable `!Send` event handler of the proxy.
+    ///
+    /// This function is the same as [`proxy::set_event_handler_local_mut`] except that
+    /// the event handler does not have to implement `'static` and that the event handler
+    /// will not be invoked after `'scope`.
+    ///
+    /// The proxy must belong to the queue that was used to create this scope.
+    ///
+    /// # Panic
+    ///
+    /// This function panics whenever [`Scope::set_event_handler_local`] panics.
+    ///
+    /// The event handler panics if it is invoked while it is already handling an event.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::{proxy, Libwayland};
+    /// # use wl_client::test_protocols::core::wl_callback::WlCallback;
+    /// # use wl_client::test_protocols::core::wl_display::WlDisplay;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// let queue = con.create_local_queue(c"queue name");
+    /// let display: WlDisplay = queue.display();
+    /// let sync = display.sync();
+    /// let mut done = false;
+    ///
+    /// queue.dispatch_scope_blocking(|scope| {
+    ///     // Attach the event handler.
+    ///     scope.set_event_handler_local_mut(&sync, WlCallback::on_done_mut(|_, _| done = true));
+    ///
+    ///     // Wait for the compositor to send the `done` message.
+    ///     queue.dispatch_roundtrip_blocking().unwrap();
+    /// });
+    ///
+    /// // The event handler sets the value to `true`.
+    /// assert!(done);
+    /// ```
+    #[inline]
+    pub fn set_event_handler_local_mut<P, H, M>(&'scope self, proxy: &P, handler: H)
+    where
+        P: OwnedProxy,
+        P::Api: CreateEventHandler<MutEventHandler<H>, M>,
+        <P::Api as CreateEventHandler<MutEventHandler<H>, M>>::EventHandler: 'scope,
+    {
+        self.set_event_handler_local(proxy, MutEventHandler::new(handler));
+    }
+
+    /// Sets the event handler of the proxy to a [`DynamicEventHandler`].
+    ///
+    /// This function is the same as [`proxy::set_dynamic_event_handler`] except that the
+    /// event handler does not have to implement `'static` and that the event handler will
+    /// not be invoked after `'scope`.
+    ///
+    /// The proxy must belong to the queue that was used to create this scope.
+    ///
+    /// # Panic
+    ///
+    /// This function panics whenever [`proxy::set_dynamic_event_handler`] panics and also
+    /// if the proxy does not belong to the queue that was used to create this scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Mutex;
+    /// # use wl_client::{proxy, Libwayland};
+    /// # use wl_client::proxy::low_level::{on_dynamic_event, DynEvent};
+    /// # use wl_client::test_protocol_helpers::get_root;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// let queue = con.create_queue(c"queue name");
+    /// let root = get_root(&queue);
+    /// let string = root.echo("hello");
+    /// let received = Mutex::new(String::new());
+    ///
+    /// queue.dispatch_scope_blocking(|scope| {
+    ///     // Attach the event handler.
+    ///     scope.set_dynamic_event_handler(
+    ///         &string,
+    ///         on_dynamic_event(|_, _, _, args| {
+    ///             if let [DynEvent::String(Some(s))] = &*args {
+    ///                 *received.lock().unwrap() = s.to_string();
+    ///             }
+    ///         }),
+    ///     );
+    ///
+    ///     // Wait for the compositor to send the `string` message.
+    ///     queue.dispatch_roundtrip_blocking().unwrap();
+    /// });
+    ///
+    /// assert_eq!(*received.lock().unwrap(), "hello");
+    /// ```
+    #[inline]
+    pub fn set_dynamic_event_handler<P, H>(&'scope self, proxy: &P, handler: H)
+    where
+        P: UntypedOwnedProxyWrapper,
+        H: DynamicEventHandler + Send + 'scope,
+    {
+        let proxy = get_owned(proxy);
+        let (handler, interface) = proxy.dynamic_event_handler(handler);
+        // SAFETY: - The event handler is Send.
+        //         - The event handler and DynamicFallback accept the events of proxies
+        //           whose interface is compatible with interface.
+        unsafe {
+            set_event_handler::<DynamicFallback, _>(self, proxy, interface, handler);
+        }
+    }
+
+    /// Sets the `!Send` event handler of the proxy to a [`DynamicEventHandler`].
+    ///
+    /// This function is the same as [`proxy::set_dynamic_event_handler_local`] except that
+    /// the event handler does not have to implement `'static` and that the event handler
+    /// will not be invoked after `'scope`.
+    ///
+    /// The proxy must belong to the queue that was used to create this scope.
+    ///
+    /// # Panic
+    ///
+    /// This function panics whenever [`proxy::set_dynamic_event_handler_local`] panics
+    /// and also if the proxy does not belong to the queue that was used to create this
+    /// scope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use wl_client::{proxy, Libwayland};
+    /// # use wl_client::proxy::low_level::{on_dynamic_event, DynEvent};
+    /// # use wl_client::test_protocol_helpers::get_root;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// let queue = con.create_local_queue(c"queue name");
+    /// let root = get_root(&queue);
+    /// let dummy = root.create_dummy();
+    /// let ponged = Cell::new(false);
+    ///
+    /// queue.dispatch_scope_blocking(|scope| {
+    ///     // Attach the event handler.
+    ///     scope.set_dynamic_event_handler_local(
+    ///         &root,
+    ///         on_dynamic_event(|_, _, name, args| {
+    ///             if let [DynEvent::Object(Some(_))] = &*args {
+    ///                 ponged.set(name == "pong_dummy");
+    ///             }
+    ///         }),
+    ///     );
+    ///
+    ///     // Wait for the compositor to send the `pong_dummy` message.
+    ///     root.ping_dummy(&dummy);
+    ///     queue.dispatch_roundtrip_blocking().unwrap();
+    /// });
+    ///
+    /// assert!(ponged.get());
+    /// ```
+    #[inline]
+    pub fn set_dynamic_event_handler_local<P, H>(&'scope self, proxy: &P, handler: H)
+    where
+        P: UntypedOwnedProxyWrapper,
+        H: DynamicEventHandler + 'scope,
+    {
+        if self.data.queue.is_non_local() {
+            panic!("Queue is not a local queue");
+        }
+        let proxy = get_owned(proxy);
+        let (handler, interface) = proxy.dynamic_event_handler(handler);
+        // SAFETY: - We've checked that the queue is a local queue.
+        //         - The event handler and DynamicFallback accept the events of proxies
+        //           whose interface is compatible with interface.
+        unsafe {
+            set_event_handler::<DynamicFallback, _>(self, proxy, interface, handler);
         }
     }
 }
@@ -541,6 +735,7 @@ where
     H: EventHandler,
 {
     const WL_INTERFACE: &'static wl_interface = H::WL_INTERFACE;
+    const LIBWAYLAND_INTERFACE: Option<&'static LibwaylandInterface> = H::LIBWAYLAND_INTERFACE;
 
     #[inline]
     fn mutable_type() -> Option<(TypeId, &'static str)> {
@@ -563,15 +758,27 @@ where
     }
 }
 
+/// Returns the interface that a proxy is checked against when attaching an event
+/// handler.
+fn wl_interface_of<H>(proxy: &UntypedOwnedProxy, _: &H) -> &'static wl_interface
+where
+    H: EventHandler,
+{
+    proxy.event_handler_interface::<H>()
+}
+
 /// # Safety
 ///
 /// - if T does not implement Send, then the queue must be a local queue
-unsafe fn set_event_handler<'scope, P, H>(
+/// - if the interface of the proxy is compatible with `interface`, then opcode and args
+///   of its events must be accepted by the event handler and by `F`
+unsafe fn set_event_handler<'scope, F, H>(
     scope: &'scope Scope<'scope, '_>,
-    proxy: &P,
+    proxy: &UntypedOwnedProxy,
+    interface: &'static wl_interface,
     event_handler: H,
 ) where
-    P: OwnedProxy,
+    F: ScopeFallback,
     H: EventHandler + 'scope,
 {
     if mem::needs_drop::<H>() {
@@ -604,12 +811,12 @@ unsafe fn set_event_handler<'scope, P, H>(
         });
         // SAFETY: The requirements are forwarded to the caller.
         unsafe {
-            set_event_handler2(scope, proxy, event_handler);
+            set_event_handler2::<F, _>(scope, proxy, interface, event_handler);
         }
     } else {
         // SAFETY: The requirements are forwarded to the caller.
         unsafe {
-            set_event_handler2(scope, proxy, event_handler);
+            set_event_handler2::<F, _>(scope, proxy, interface, event_handler);
         }
     }
 }
@@ -617,25 +824,26 @@ unsafe fn set_event_handler<'scope, P, H>(
 /// # Safety
 ///
 /// - if T does not implement Send, then the queue must be a local queue
-unsafe fn set_event_handler2<'scope, P, H>(
+/// - if the interface of the proxy is compatible with `interface`, then opcode and args
+///   of its events must be accepted by the event handler and by `F`
+unsafe fn set_event_handler2<'scope, F, H>(
     scope: &'scope Scope<'scope, '_>,
-    proxy: &P,
+    proxy: &UntypedOwnedProxy,
+    interface: &'static wl_interface,
     event_handler: H,
 ) where
-    P: OwnedProxy,
+    F: ScopeFallback,
     H: EventHandler + 'scope,
 {
-    let proxy = get_owned(proxy);
     assert_eq!(proxy.queue(), &scope.data.queue);
     // SAFETY: - all requirements except the callability of event_handler_func as part
     //           of a libwayland dispatch are trivially satisfied
     //         - libwayland only ever calls event handlers while preserving a
     //           valid pointer to the proxy and all pointers in args
     //         - set_event_handler4 checks that the interface of the proxy is
-    //           H::WL_INTERFACE
-    //         - by the safety requirements, P::WL_INTERFACE is compatible with the
-    //           proxy's interface which is compatible with H::WL_INTERFACE by the
-    //           previous point
+    //           compatible with interface
+    //         - by the safety requirements, the event handler and F accept the events
+    //           of such a proxy
     //         - libwayland ensures that opcode and args conform to the
     //           interface before calling the event handler
     //         - set_event_handler4 sets event_handler to a pointer to H
@@ -658,7 +866,8 @@ unsafe fn set_event_handler2<'scope, P, H>(
     unsafe {
         proxy.set_event_handler3(
             event_handler,
-            event_handler_func_scoped::<P, H>,
+            interface,
+            event_handler_func_scoped::<F, H>,
             Some(scope),
         );
     }
@@ -698,11 +907,84 @@ impl ScopeData {
     }
 }
 
+/// The event handler that is invoked for a proxy after the end of the scope of its
+/// event handler.
+pub(crate) trait ScopeFallback {
+    /// Handles an event.
+    ///
+    /// # Safety
+    ///
+    /// - `interface` must be the interface of the proxy.
+    /// - The requirements of [`EventHandler::handle_event`] for an event handler
+    ///   accepting the events of the proxy whose `mutable_type` returns `None` must be
+    ///   satisfied.
+    unsafe fn handle_event(
+        interface: &'static wl_interface,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    );
+}
+
+/// A [`ScopeFallback`] that calls [`OwnedProxy::NO_OP_EVENT_HANDLER`].
+///
+/// This fallback accepts the events of proxies whose interface is compatible with
+/// `P::WL_INTERFACE`.
+struct NoOpFallback<P>(PhantomData<P>);
+
+impl<P> ScopeFallback for NoOpFallback<P>
+where
+    P: OwnedProxy,
+{
+    #[inline]
+    unsafe fn handle_event(
+        _interface: &'static wl_interface,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: - The requirements are forwarded to the caller.
+        //         - The mutable_type of the NO_OP_EVENT_HANDLER is required to be `None`
+        //           or the type ID of `()`. Any non-null pointer can be dereferenced to
+        //           `()`.
+        unsafe {
+            P::NO_OP_EVENT_HANDLER.handle_event(queue, data, slf, opcode, args);
+        }
+    }
+}
+
+/// A [`ScopeFallback`] that drops the arguments of events.
+///
+/// This destroys new proxies and closes file descriptors contained in the events. This
+/// fallback accepts the events of all proxies.
+struct DynamicFallback;
+
+impl ScopeFallback for DynamicFallback {
+    #[inline]
+    unsafe fn handle_event(
+        interface: &'static wl_interface,
+        queue: &Queue,
+        _data: *mut u8,
+        _slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: The requirements are forwarded to the caller.
+        unsafe {
+            release_event(interface, queue, opcode, args);
+        }
+    }
+}
+
 /// The event handler function for scoped event handlers.
 ///
 /// This function is the same as [`event_handler_func`] except that it additionally checks
 /// that [`ScopeData::may_dispatch`] is true. If it is not, then, instead of calling the
-/// normal event handler, [`P::NO_OP_EVENT_HANDLER`] is called.
+/// normal event handler, `F` is called.
 ///
 /// This allows the event handler to be destroyed long before the proxy itself is
 /// destroyed as long as `may_dispatch` remains false afterwards.
@@ -714,9 +996,8 @@ impl ScopeData {
 /// - the queue lock of the proxy must be held
 /// - if scope_data.may_dispatch, then all safety requirements of event_handler_func::<T>
 ///   must be satisfied
-/// - the interface of the proxy must be compatible with P::WL_INTERFACE
-/// - P::WL_INTERFACE and T::WL_INTERFACE must be compatible
-unsafe extern "C" fn event_handler_func_scoped<P, T>(
+/// - F must accept the events of the proxy
+unsafe extern "C" fn event_handler_func_scoped<F, T>(
     event_handler_data: *const c_void,
     target: *mut c_void,
     opcode: u32,
@@ -724,7 +1005,7 @@ unsafe extern "C" fn event_handler_func_scoped<P, T>(
     args: *mut wl_argument,
 ) -> c_int
 where
-    P: OwnedProxy,
+    F: ScopeFallback,
     T: EventHandler,
 {
     // SAFETY: By the safety requirements of this function, event_handler is a valid pointer
@@ -738,6 +1019,7 @@ where
         //         event_handler_func are satisfied.
         return unsafe { event_handler_func::<T>(event_handler_data, target, opcode, msg, args) };
     }
+    record_event(proxy_data, opcode);
     // SAFETY: Dito, target is and stays valid.
     let target = unsafe { NonNull::new_unchecked(target.cast()) };
     // SAFETY: Dito, target is and stays valid.
@@ -745,16 +1027,13 @@ where
         unsafe { UntypedBorrowedProxy::new_immutable(proxy_data.proxy.libwayland, target) };
     // SAFETY: Dito, the queue lock is being held.
     let data = unsafe { proxy_data.queue.data() };
-    // SAFETY: - Dito, the interface of the proxy is compatible with P::WL_INTERFACE
+    // SAFETY: - Dito, F accepts the events of the proxy
+    //         - Event handlers can only be set on proxies that are not wrappers and
+    //           those always have an interface.
     //         - Dito, target is a valid pointer and stays valid
-    //         - Dito, opcode and args conform to P::WL_INTERFACE
-    //         - The mutable_type of the NO_OP_EVENT_HANDLER is required to be `None` or
-    //           the type ID of `()`. If it is `None`, then there is nothing to show.
-    //           Otherwise, Queue::data guarantees that `data` can be dereferenced to
-    //           `T::mutable_type` or `()` if `mutable_type` returns None. Any non-null
-    //           pointer can be dereferenced to `()`.
+    let interface = proxy_data.interface.unwrap();
     unsafe {
-        P::NO_OP_EVENT_HANDLER.handle_event(&proxy_data.queue, data, &target, opcode, args);
+        F::handle_event(interface, &proxy_data.queue, data, &target, opcode, args);
     }
     0
 }
@@ -1,7 +1,8 @@
 use {
     crate::{
-        Libwayland,
-        proxy::{self, get_owned},
+        Libwayland, Queue,
+        ffi::{wl_argument, wl_proxy},
+        proxy::{self, OwnedProxy, get_owned, low_level::UntypedOwnedProxy},
         test_protocol_helpers::{callback, get_root},
         test_protocols::core::{
             wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef},
@@ -15,6 +16,7 @@ use {
     run_on_drop::on_drop,
     std::{
         cell::Cell,
+        ptr::{self, NonNull},
         rc::Rc,
         sync::{
             Arc, Barrier,
@@ -561,6 +563,69 @@ fn panic_in_dispatch() {
     queue.dispatch_roundtrip_blocking().unwrap();
 }
 
+fn foreign_sync(queue: &Queue) -> NonNull<wl_proxy> {
+    let display = queue.display::<WlDisplay>();
+    let mut args = [wl_argument { n: 0 }];
+    // SAFETY: - display is a valid wrapper
+    //         - opcode 0 is the sync request which has a single new_id argument
+    let sync = unsafe {
+        queue.libwayland().wl_proxy_marshal_array_flags(
+            proxy::wl_proxy(&*display).unwrap().as_ptr(),
+            0,
+            WlCallback::WL_INTERFACE,
+            1,
+            0,
+            args.as_mut_ptr(),
+        )
+    };
+    NonNull::new(sync).unwrap()
+}
+
+#[test]
+fn adopt_foreign() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"queue name");
+    let sync = foreign_sync(&queue);
+    // SAFETY: - sync is a plain proxy that is not used anywhere else
+    //         - sync has the interface WlCallback::WL_INTERFACE
+    let sync = unsafe { UntypedOwnedProxy::adopt_foreign(&queue, sync, WlCallback::WL_INTERFACE) };
+    // SAFETY: - sync has the interface WlCallback::WL_INTERFACE
+    let sync: WlCallback = unsafe { proxy::low_level::from_untyped_owned(sync) };
+    let done = Arc::new(AtomicBool::new(false));
+    let done2 = done.clone();
+    proxy::set_event_handler(&sync, callback(move || done2.store(true, Relaxed)));
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert!(done.load(Relaxed));
+}
+
+#[test]
+#[should_panic(expected = "Proxy already has a listener")]
+fn adopt_foreign_with_listener() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let queue = con.create_queue(c"queue name");
+    let sync = foreign_sync(&queue);
+    // SAFETY: - sync is a valid plain proxy that is not used afterwards
+    let _destroy = on_drop(|| unsafe { lib.wl_proxy_destroy(sync.as_ptr()) });
+    let listener = 0u8;
+    // SAFETY: - sync is a valid plain proxy
+    //         - the dispatcher is never invoked since the queue is not dispatched
+    unsafe {
+        lib.wl_proxy_add_dispatcher(
+            sync.as_ptr(),
+            None,
+            ptr::from_ref(&listener).cast_mut().cast(),
+            ptr::null_mut(),
+        );
+    }
+    // SAFETY: - sync is a plain proxy that is not used anywhere else
+    //         - sync has the interface WlCallback::WL_INTERFACE
+    unsafe {
+        UntypedOwnedProxy::adopt_foreign(&queue, sync, WlCallback::WL_INTERFACE);
+    }
+}
+
 #[cfg(feature = "_leaking-tests")]
 mod leaking {
     use {
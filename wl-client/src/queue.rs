@@ -15,11 +15,13 @@ use {
                 check_dispatching_proxy, check_new_proxy, owned::DISPATCH_PANIC,
             },
         },
+        queue::stats::QueueStatsData,
         utils::{
             block_on::block_on,
             reentrant_mutex::{ReentrantMutex, ReentrantMutexGuard},
             sync_cell::SyncCell,
             sync_ptr::{SyncNonNull, SyncPtr},
+            trace::DispatchSpan,
         },
     },
     parking_lot::Mutex,
@@ -38,9 +40,11 @@ use {
         sync::Arc,
         task::{Poll, Waker},
         thread::panicking,
+        time::Instant,
     },
 };
 
+pub(crate) mod stats;
 #[cfg(test)]
 mod tests;
 mod with_data;
@@ -335,6 +339,8 @@ struct QueueData {
     /// The registry for proxies that need manual destruction when the connection is
     /// dropped.
     owned_proxy_registry: OwnedProxyRegistry,
+    /// The statistics of this queue.
+    stats: QueueStatsData,
 }
 
 #[derive(Default)]
@@ -713,6 +719,9 @@ impl Queue {
                 }
             }
         });
+        let span = DispatchSpan::enter(&d.name);
+        let connection = d.borrowed.connection.stats_data();
+        let start = connection.enabled().then(Instant::now);
         let res = self.with_dispatch(|| {
             let md = &self.queue_data.mut_data;
             // SAFETY: - We're holding the queue lock.
@@ -739,11 +748,17 @@ impl Queue {
                 )
             }
         });
-        if res == -1 {
-            return Err(io::Error::last_os_error());
+        let res = if res == -1 {
+            Err(io::Error::last_os_error())
+        } else {
+            assert!(res >= 0);
+            Ok(res as u64)
+        };
+        if let (Ok(n), Some(start)) = (&res, start) {
+            d.stats.record_dispatch(connection, start, *n);
         }
-        assert!(res >= 0);
-        Ok(res as u64)
+        span.finish(&res);
+        res
     }
 
     /// Blocks the current thread until the compositor has processed all previous requests
@@ -1026,8 +1041,11 @@ impl Queue {
         //         - wrapper is a wrapper so it doesn't have an event handler
         //         - we have ownership of wrapper and hand it over
         //         - we just assigned self as the queue of wrapper
-        //         - the interface is none since this is a wrapper
-        let wrapper = unsafe { UntypedOwnedProxy::from_wrapper_wl_proxy(self, wrapper) };
+        //         - it's a requirement of this function that the interface of the proxy
+        //           is compatible with P
+        let wrapper = unsafe {
+            UntypedOwnedProxy::from_typed_wrapper_wl_proxy(self, wrapper, P::WL_INTERFACE)
+        };
         // SAFETY: - the requirement is forwarded to the caller
         unsafe { proxy::low_level::from_untyped_owned(wrapper) }
     }
@@ -1236,6 +1254,7 @@ impl Connection {
                     mut_data_type_name,
                     mut_data: SyncCell::new(SyncPtr(ptr::from_mut(&mut ()).cast())),
                     owned_proxy_registry: Default::default(),
+                    stats: Default::default(),
                 }),
             },
         }
@@ -0,0 +1,322 @@
+use {
+    crate::{
+        Queue,
+        connection::stats::ConnectionStatsData,
+        ffi::{messages, wl_interface, wl_message},
+    },
+    parking_lot::Mutex,
+    std::{
+        collections::{BTreeMap, HashMap},
+        ffi::CStr,
+        ptr,
+        sync::{
+            Arc,
+            atomic::{AtomicU64, Ordering::Relaxed},
+        },
+        time::{Duration, Instant},
+    },
+};
+
+/// Statistics about the events dispatched by a queue and the requests sent by proxies
+/// attached to it.
+///
+/// Statistics are disabled by default. They can be enabled with
+/// [`Connection::set_stats_enabled`](crate::Connection::set_stats_enabled).
+///
+/// Only proxies created by this crate while statistics are enabled and whose interface
+/// is known are counted per interface. Events are counted per event name if they are
+/// dispatched to an event handler set with this crate.
+///
+/// You can retrieve these statistics with [`Queue::stats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct QueueStats {
+    /// The number of events dispatched by libwayland.
+    pub events_dispatched: u64,
+    /// The total time spent in event handlers.
+    pub handler_time: Duration,
+    /// The number of dispatches that dispatched at least one event.
+    pub batches: u64,
+    /// The largest number of events dispatched by a single dispatch.
+    pub largest_batch: u64,
+    /// The sum, over all batches, of the time between the last read from the socket and
+    /// the start of the dispatch.
+    pub total_read_to_dispatch_latency: Duration,
+    /// The largest time between the last read from the socket and the start of a
+    /// dispatch.
+    pub max_read_to_dispatch_latency: Duration,
+    /// Per-interface statistics, sorted by interface name.
+    pub interfaces: Vec<InterfaceStats>,
+}
+
+/// Statistics about the messages of a single interface.
+///
+/// This is part of [`QueueStats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct InterfaceStats {
+    /// The name of the interface.
+    pub interface: String,
+    /// The number of requests sent.
+    pub requests_sent: u64,
+    /// The number of events dispatched to event handlers, in the order of the events
+    /// in the interface.
+    pub events: Vec<EventStats>,
+}
+
+/// Statistics about a single event.
+///
+/// This is part of [`InterfaceStats`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EventStats {
+    /// The name of the event.
+    pub event: String,
+    /// The number of times the event was dispatched to an event handler.
+    pub dispatched: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct QueueStatsData {
+    events_dispatched: AtomicU64,
+    handler_time: AtomicU64,
+    batches: AtomicU64,
+    largest_batch: AtomicU64,
+    total_latency: AtomicU64,
+    max_latency: AtomicU64,
+    /// The statistics of all interfaces that have had proxies attached to this queue,
+    /// keyed by the address of the interface. This is only accessed when creating a
+    /// proxy while statistics are enabled or when taking a snapshot.
+    interfaces: Mutex<HashMap<usize, Arc<InterfaceStatsData>>>,
+}
+
+struct InterfaceStatsData {
+    name: String,
+    requests_sent: AtomicU64,
+    /// The number of file descriptors of each request, indexed by opcode.
+    request_fds: Box<[u8]>,
+    /// The event names, counters, and numbers of file descriptors, indexed by opcode.
+    events: Box<[(String, AtomicU64, u8)]>,
+}
+
+/// The statistics attached to a proxy.
+pub(crate) struct ProxyStats {
+    data: Arc<InterfaceStatsData>,
+    connection: Arc<ConnectionStatsData>,
+}
+
+fn load(v: &AtomicU64, reset: bool) -> u64 {
+    match reset {
+        true => v.swap(0, Relaxed),
+        false => v.load(Relaxed),
+    }
+}
+
+/// Returns the number of file descriptors in a message.
+///
+/// # Safety
+///
+/// - `msg` must be a valid message.
+unsafe fn message_fds(msg: &wl_message) -> u8 {
+    // SAFETY: The requirement is forwarded to the caller.
+    let signature = unsafe { CStr::from_ptr(msg.signature) };
+    signature.to_bytes().iter().filter(|&&c| c == b'h').count() as u8
+}
+
+impl QueueStatsData {
+    /// Records a dispatch that started at `start` and dispatched `events` events.
+    pub(crate) fn record_dispatch(
+        &self,
+        connection: &ConnectionStatsData,
+        start: Instant,
+        events: u64,
+    ) {
+        if events == 0 {
+            return;
+        }
+        self.events_dispatched.fetch_add(events, Relaxed);
+        self.batches.fetch_add(1, Relaxed);
+        self.largest_batch.fetch_max(events, Relaxed);
+        if let Some(latency) = connection.time_since_read(start) {
+            let latency = latency.as_nanos() as u64;
+            self.total_latency.fetch_add(latency, Relaxed);
+            self.max_latency.fetch_max(latency, Relaxed);
+        }
+    }
+
+    /// Records time spent in an event handler.
+    pub(crate) fn record_handler_time(&self, time: Duration) {
+        self.handler_time.fetch_add(time.as_nanos() as u64, Relaxed);
+    }
+
+    fn snapshot(&self, reset: bool) -> QueueStats {
+        // Different interface objects can have the same name, for example, if they
+        // have been generated by different crates. They are merged here.
+        let mut interfaces = BTreeMap::<&str, InterfaceStats>::new();
+        let data = self.interfaces.lock();
+        for data in data.values() {
+            let stats = interfaces
+                .entry(&data.name)
+                .or_insert_with(|| InterfaceStats {
+                    interface: data.name.clone(),
+                    ..Default::default()
+                });
+            stats.requests_sent += load(&data.requests_sent, reset);
+            for (event, dispatched, _) in &data.events {
+                let dispatched = load(dispatched, reset);
+                match stats.events.iter_mut().find(|e| e.event == *event) {
+                    Some(e) => e.dispatched += dispatched,
+                    None => stats.events.push(EventStats {
+                        event: event.clone(),
+                        dispatched,
+                    }),
+                }
+            }
+        }
+        QueueStats {
+            events_dispatched: load(&self.events_dispatched, reset),
+            handler_time: Duration::from_nanos(load(&self.handler_time, reset)),
+            batches: load(&self.batches, reset),
+            largest_batch: load(&self.largest_batch, reset),
+            total_read_to_dispatch_latency: Duration::from_nanos(load(&self.total_latency, reset)),
+            max_read_to_dispatch_latency: Duration::from_nanos(load(&self.max_latency, reset)),
+            interfaces: interfaces.into_values().collect(),
+        }
+    }
+}
+
+impl ProxyStats {
+    /// Creates the statistics for a proxy with the given interface attached to the
+    /// queue.
+    ///
+    /// Returns `None` if statistics are disabled.
+    ///
+    /// # Safety
+    ///
+    /// - `interface` must be a valid interface.
+    pub(crate) unsafe fn new(queue: &Queue, interface: &'static wl_interface) -> Option<Self> {
+        let connection = queue.connection().stats_data();
+        if !connection.enabled() {
+            return None;
+        }
+        let data = queue
+            .queue_data
+            .stats
+            .interfaces
+            .lock()
+            .entry(ptr::from_ref(interface) as usize)
+            .or_insert_with(|| {
+                // SAFETY: The requirement is forwarded to the caller.
+                let (name, requests, events) = unsafe {
+                    (
+                        CStr::from_ptr(interface.name),
+                        messages(interface.methods, interface.method_count),
+                        messages(interface.events, interface.event_count),
+                    )
+                };
+                // SAFETY: The messages of a valid interface are valid.
+                let request_fds = requests.iter().map(|msg| unsafe { message_fds(msg) });
+                let events = events.iter().map(|msg| {
+                    // SAFETY: The messages of a valid interface are valid.
+                    let (name, fds) = unsafe { (CStr::from_ptr(msg.name), message_fds(msg)) };
+                    (name.to_string_lossy().into_owned(), AtomicU64::new(0), fds)
+                });
+                Arc::new(InterfaceStatsData {
+                    name: name.to_string_lossy().into_owned(),
+                    requests_sent: Default::default(),
+                    request_fds: request_fds.collect(),
+                    events: events.collect(),
+                })
+            })
+            .clone();
+        Some(Self {
+            data,
+            connection: connection.clone(),
+        })
+    }
+
+    /// Returns whether statistics are enabled.
+    #[inline]
+    pub(crate) fn enabled(&self) -> bool {
+        self.connection.enabled()
+    }
+
+    /// Records a request sent on the proxy.
+    pub(crate) fn record_request(&self, opcode: u32) {
+        if !self.enabled() {
+            return;
+        }
+        self.data.requests_sent.fetch_add(1, Relaxed);
+        if let Some(&fds) = self.data.request_fds.get(opcode as usize) {
+            if fds > 0 {
+                self.connection.record_fds(true, fds as u64);
+            }
+        }
+    }
+
+    /// Records an event dispatched to the event handler of the proxy.
+    pub(crate) fn record_event(&self, opcode: u32) {
+        if !self.enabled() {
+            return;
+        }
+        if let Some((_, count, fds)) = self.data.events.get(opcode as usize) {
+            count.fetch_add(1, Relaxed);
+            if *fds > 0 {
+                self.connection.record_fds(false, *fds as u64);
+            }
+        }
+    }
+}
+
+impl Queue {
+    /// Returns statistics about the events dispatched by this queue and the requests
+    /// sent by proxies attached to it.
+    ///
+    /// Statistics are disabled by default. They can be enabled with
+    /// [`Connection::set_stats_enabled`](crate::Connection::set_stats_enabled). See the
+    /// documentation of [`QueueStats`] for which messages are counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// con.set_stats_enabled(true);
+    /// let queue = con.create_queue(c"queue name");
+    /// queue.dispatch_roundtrip_blocking().unwrap();
+    /// let stats = queue.stats();
+    /// println!("events dispatched: {}", stats.events_dispatched);
+    /// ```
+    pub fn stats(&self) -> QueueStats {
+        self.queue_data.stats.snapshot(false)
+    }
+
+    /// Resets the statistics of this queue.
+    ///
+    /// This function returns the statistics before the reset. Since the statistics are
+    /// retrieved and reset in one step, no events are lost when sampling the statistics
+    /// periodically with this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use wl_client::Libwayland;
+    /// #
+    /// let lib = Libwayland::open().unwrap();
+    /// let con = lib.connect_to_default_display().unwrap();
+    /// con.set_stats_enabled(true);
+    /// let queue = con.create_queue(c"queue name");
+    /// queue.dispatch_roundtrip_blocking().unwrap();
+    /// let _stats = queue.reset_stats();
+    /// assert_eq!(queue.stats().events_dispatched, 0);
+    /// ```
+    pub fn reset_stats(&self) -> QueueStats {
+        self.queue_data.stats.snapshot(true)
+    }
+
+    pub(crate) fn stats_data(&self) -> &QueueStatsData {
+        &self.queue_data.stats
+    }
+}
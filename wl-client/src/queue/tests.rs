@@ -1,6 +1,6 @@
 use {
     crate::{
-        Libwayland, Queue, proxy,
+        Libwayland, Queue, protocols, proxy,
         test_protocol_helpers::{callback, get_root},
         test_protocols::core::{
             wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef},
@@ -359,3 +359,55 @@ fn drop_queue_owner_off_thread() {
     .unwrap();
     drop(queue);
 }
+
+#[test]
+fn stats() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    con.set_stats_enabled(true);
+    let queue = con.create_local_queue(c"queue name");
+    let sync = queue.display::<WlDisplay>().sync();
+    proxy::set_event_handler_local(&sync, WlCallback::on_done(|_, _| ()));
+    // A different interface object with the same name.
+    let sync2 = queue
+        .display::<protocols::wayland::wl_display::WlDisplay>()
+        .sync();
+    proxy::set_event_handler_local(
+        &sync2,
+        protocols::wayland::wl_callback::WlCallback::on_done(|_, _| ()),
+    );
+    queue.dispatch_blocking().unwrap();
+    queue.dispatch_roundtrip_blocking().unwrap();
+    let stats = queue.stats();
+    assert!(stats.max_read_to_dispatch_latency > Duration::ZERO);
+    assert!(stats.total_read_to_dispatch_latency >= stats.max_read_to_dispatch_latency);
+    assert!(stats.events_dispatched >= 1);
+    assert!(stats.batches >= 1);
+    assert!(stats.largest_batch >= 1);
+    let interface = |name: &str| {
+        stats
+            .interfaces
+            .iter()
+            .find(|i| i.interface == name)
+            .unwrap()
+    };
+    assert!(interface("wl_display").requests_sent >= 1);
+    let callback = interface("wl_callback");
+    assert_eq!(callback.events.len(), 1);
+    assert_eq!(callback.events[0].event, "done");
+    // The roundtrip itself also uses a wl_callback.
+    assert_eq!(callback.events[0].dispatched, 3);
+    assert_eq!(
+        stats
+            .interfaces
+            .iter()
+            .filter(|i| i.interface == "wl_callback")
+            .count(),
+        1,
+    );
+    assert_eq!(queue.reset_stats(), stats);
+    let stats = queue.stats();
+    assert_eq!(stats.events_dispatched, 0);
+    assert_eq!(stats.handler_time, Duration::ZERO);
+    assert!(stats.interfaces.iter().all(|i| i.requests_sent == 0));
+}
@@ -26,12 +26,17 @@ mod tests;
 /// functions above. It derefs to [`Queue`] but re-declares all of the dispatching
 /// functions to also accept a `&mut T` that will be passed to the event handlers.
 ///
+/// Event handlers that take the `&mut T` implement the `*EventHandlerWithData<T>` trait
+/// of their interface. The `on_*_with_data` functions of the generated bindings create
+/// such event handlers from closures. Event handlers that don't take mutable data can be
+/// used as well.
+///
 /// # Example
 ///
 /// ```
 /// # use wl_client::{proxy, Libwayland};
-/// # use wl_client::test_protocols_data::core::wl_callback::WlCallback;
-/// # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
+/// # use wl_client::test_protocols::core::wl_callback::WlCallback;
+/// # use wl_client::test_protocols::core::wl_display::WlDisplay;
 /// #
 /// let lib = Libwayland::open().unwrap();
 /// let con = lib.connect_to_default_display().unwrap();
@@ -45,7 +50,7 @@ mod tests;
 /// };
 ///
 /// let sync = queue.display::<WlDisplay>().sync();
-/// proxy::set_event_handler(&sync, WlCallback::on_done(|state: &mut State, _, _| {
+/// proxy::set_event_handler(&sync, WlCallback::on_done_with_data(|state: &mut State, _, _| {
 ///     state.done = true;
 /// }));
 /// queue.dispatch_roundtrip_blocking(&mut state).unwrap();
@@ -216,7 +221,7 @@ where
     ///
     /// ```
     /// # use wl_client::Libwayland;
-    /// # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
+    /// # use wl_client::test_protocols::core::wl_display::WlDisplay;
     /// #
     /// let lib = Libwayland::open().unwrap();
     /// let con = lib.connect_to_default_display().unwrap();
@@ -252,7 +257,7 @@ where
     ///
     /// ```
     /// # use wl_client::Libwayland;
-    /// # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
+    /// # use wl_client::test_protocols::core::wl_display::WlDisplay;
     /// #
     /// # tokio_test::block_on(async {
     /// let lib = Libwayland::open().unwrap();
@@ -295,8 +300,8 @@ where
     /// # use std::sync::atomic::AtomicBool;
     /// # use std::sync::atomic::Ordering::Relaxed;
     /// # use wl_client::{proxy, Libwayland};
-    /// # use wl_client::test_protocols_data::core::wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef};
-    /// # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
+    /// # use wl_client::test_protocols::core::wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef};
+    /// # use wl_client::test_protocols::core::wl_display::WlDisplay;
     /// #
     /// let lib = Libwayland::open().unwrap();
     /// let con = lib.connect_to_default_display().unwrap();
@@ -312,7 +317,7 @@ where
     ///
     /// // send some messages to the compositor
     /// let sync = display.sync();
-    /// proxy::set_event_handler(&sync, WlCallback::on_done(move |state: &mut State, _, _| {
+    /// proxy::set_event_handler(&sync, WlCallback::on_done_with_data(move |state: &mut State, _, _| {
     ///     state.done = true;
     /// }));
     ///
@@ -348,8 +353,8 @@ where
     /// # use std::sync::atomic::AtomicBool;
     /// # use std::sync::atomic::Ordering::Relaxed;
     /// # use wl_client::{proxy, Libwayland};
-    /// # use wl_client::test_protocols_data::core::wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef};
-    /// # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
+    /// # use wl_client::test_protocols::core::wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef};
+    /// # use wl_client::test_protocols::core::wl_display::WlDisplay;
     /// #
     /// # tokio_test::block_on(async {
     /// let lib = Libwayland::open().unwrap();
@@ -366,7 +371,7 @@ where
     ///
     /// // send some messages to the compositor
     /// let sync = display.sync();
-    /// proxy::set_event_handler(&sync, WlCallback::on_done(move |state: &mut State, _, _| {
+    /// proxy::set_event_handler(&sync, WlCallback::on_done_with_data(move |state: &mut State, _, _| {
     ///     state.done = true;
     /// }));
     ///
@@ -400,8 +405,8 @@ where
     /// # use std::sync::atomic::Ordering::Relaxed;
     /// # use wl_client::{proxy, Libwayland};
     /// # use wl_client::test_protocol_helpers::callback;
-    /// # use wl_client::test_protocols_data::core::wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef};
-    /// # use wl_client::test_protocols_data::core::wl_display::WlDisplay;
+    /// # use wl_client::test_protocols::core::wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef};
+    /// # use wl_client::test_protocols::core::wl_display::WlDisplay;
     /// #
     /// # tokio_test::block_on(async {
     /// let lib = Libwayland::open().unwrap();
@@ -417,7 +422,7 @@ where
     /// };
     ///
     /// let sync = display.sync();
-    /// proxy::set_event_handler(&sync, WlCallback::on_done(move |state: &mut State, _, _| {
+    /// proxy::set_event_handler(&sync, WlCallback::on_done_with_data(move |state: &mut State, _, _| {
     ///     state.done = true;
     /// }));
     ///
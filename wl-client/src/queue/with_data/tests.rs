@@ -3,17 +3,20 @@ use {
         Libwayland, Queue,
         ffi::{wl_argument, wl_interface},
         proxy::{
-            self, BorrowedProxy, OwnedProxy,
+            self, BorrowedProxy, OwnedProxy, ProjectedEventHandler,
             low_level::{
                 CreateEventHandler, EventHandler, UntypedBorrowedProxy,
                 UntypedBorrowedProxyWrapper, UntypedOwnedProxy, UntypedOwnedProxyWrapper,
             },
         },
-        test_protocols_data::core::{wl_callback::WlCallback, wl_display::WlDisplay},
+        test_protocols::core::{
+            wl_callback::{WlCallback, WlCallbackEventHandlerWithData, WlCallbackRef},
+            wl_display::WlDisplay,
+        },
         utils::block_on::block_on,
     },
     isnt::std_1::primitive::IsntMutPtrExt,
-    std::{any::TypeId, mem},
+    std::{any::TypeId, cell::Cell, mem, rc::Rc},
 };
 
 #[test]
@@ -25,7 +28,24 @@ fn with_data() {
     let mut data = 0u8;
     proxy::set_event_handler_local(
         &sync,
-        WlCallback::on_done(|d: &mut u8, _, _| {
+        WlCallback::on_done_with_data(|d: &mut u8, _, _| {
+            *d += 1;
+        }),
+    );
+    queue.dispatch_roundtrip_blocking(&mut data).unwrap();
+    assert_eq!(data, 1);
+}
+
+#[test]
+fn with_data_multi_event_handler() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let (_queue, queue) = con.create_local_queue_with_data::<u8>(c"queue name");
+    let sync = queue.display::<WlDisplay>().sync();
+    let mut data = 0u8;
+    proxy::set_event_handler_local(
+        &sync,
+        WlCallback::handler().on_done_with_data(|d: &mut u8, _, _| {
             *d += 1;
         }),
     );
@@ -33,6 +53,109 @@ fn with_data() {
     assert_eq!(data, 1);
 }
 
+#[test]
+fn with_data_mut_event_handler() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let (_queue, queue) = con.create_local_queue_with_data::<u8>(c"queue name");
+    let sync = queue.display::<WlDisplay>().sync();
+    let mut data = 0u8;
+    let mut events = 0u8;
+    proxy::set_event_handler_local_mut(
+        &sync,
+        WlCallback::on_done_mut_with_data(move |d: &mut u8, _, _| {
+            events += 1;
+            *d = events;
+        }),
+    );
+    queue.dispatch_roundtrip_blocking(&mut data).unwrap();
+    assert_eq!(data, 1);
+}
+
+#[test]
+fn with_data_mixed_event_handlers() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let (_queue, queue) = con.create_local_queue_with_data::<u8>(c"queue name");
+    let plain_done = Rc::new(Cell::new(false));
+    let plain_done2 = plain_done.clone();
+    let sync1 = queue.display::<WlDisplay>().sync();
+    proxy::set_event_handler_local(
+        &sync1,
+        WlCallback::on_done(move |_, _| plain_done2.set(true)),
+    );
+    let sync2 = queue.display::<WlDisplay>().sync();
+    proxy::set_event_handler_local(
+        &sync2,
+        WlCallback::on_done_with_data(|d: &mut u8, _, _| *d += 1),
+    );
+    let sync3 = queue.display::<WlDisplay>().sync();
+    proxy::set_event_handler_local(&sync3, Eh);
+    let mut data = 0u8;
+    queue.dispatch_roundtrip_blocking(&mut data).unwrap();
+    assert!(plain_done.get());
+    assert_eq!(data, 11);
+
+    struct Eh;
+    impl WlCallbackEventHandlerWithData<u8> for Eh {
+        fn done(&self, data: &mut u8, _slf: &WlCallbackRef, _callback_data: u32) {
+            *data += 10;
+        }
+    }
+}
+
+#[test]
+fn with_data_projected_event_handler() {
+    #[derive(Default)]
+    struct State {
+        a: u8,
+        b: u8,
+    }
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let (_queue, queue) = con.create_local_queue_with_data::<State>(c"queue name");
+    let sync1 = queue.display::<WlDisplay>().sync();
+    proxy::set_event_handler(
+        &sync1,
+        ProjectedEventHandler::new(
+            WlCallback::on_done_with_data(|a: &mut u8, _, _| *a += 1),
+            |s: &mut State| &mut s.a,
+        ),
+    );
+    let sync2 = queue.display::<WlDisplay>().sync();
+    let mut events = 0;
+    proxy::set_event_handler_local_mut(
+        &sync2,
+        ProjectedEventHandler::new(
+            WlCallback::on_done_mut_with_data(move |b: &mut u8, _, _| {
+                events += 1;
+                *b = events * 10;
+            }),
+            |s: &mut State| &mut s.b,
+        ),
+    );
+    let mut state = State::default();
+    queue.dispatch_roundtrip_blocking(&mut state).unwrap();
+    assert_eq!(state.a, 1);
+    assert_eq!(state.b, 10);
+}
+
+#[test]
+#[should_panic(expected = "requires type `(u8, u8)`")]
+fn with_data_projected_event_handler_wrong_type() {
+    let lib = Libwayland::open().unwrap();
+    let con = lib.connect_to_default_display().unwrap();
+    let (_queue, queue) = con.create_local_queue_with_data::<u16>(c"queue name");
+    let sync = queue.display::<WlDisplay>().sync();
+    proxy::set_event_handler(
+        &sync,
+        ProjectedEventHandler::new(
+            WlCallback::on_done_with_data(|_: &mut u8, _, _| ()),
+            |s: &mut (u8, u8)| &mut s.0,
+        ),
+    );
+}
+
 #[test]
 fn with_data_no_op() {
     let lib = Libwayland::open().unwrap();
@@ -62,7 +185,7 @@ fn attach_different_data() {
     let con = lib.connect_to_default_display().unwrap();
     let (_queue, queue) = con.create_local_queue_with_data::<u8>(c"queue name");
     let sync = queue.display::<WlDisplay>().sync();
-    proxy::set_event_handler_local(&sync, WlCallback::on_done(|_: &mut u16, _, _| {}));
+    proxy::set_event_handler_local(&sync, WlCallback::on_done_with_data(|_: &mut u16, _, _| {}));
 }
 
 #[test]
@@ -75,10 +198,13 @@ fn nested_dispatch() {
     let mut data = 0u8;
     proxy::set_event_handler(
         &sync,
-        WlCallback::on_done(move |d: &mut u8, _, _| {
+        WlCallback::on_done_with_data(move |d: &mut u8, _, _| {
             let sync = queue2.display::<WlDisplay>().sync();
             *d += 1;
-            proxy::set_event_handler(&sync, WlCallback::on_done(|d: &mut u8, _, _| *d += 1));
+            proxy::set_event_handler(
+                &sync,
+                WlCallback::on_done_with_data(|d: &mut u8, _, _| *d += 1),
+            );
             queue2.dispatch_roundtrip_blocking(d).unwrap();
         }),
     );
@@ -96,11 +222,14 @@ fn nested_dispatch_other_data() {
     let mut data = Box::new(0u8);
     proxy::set_event_handler(
         &sync,
-        WlCallback::on_done(move |d: &mut u8, _, _| {
+        WlCallback::on_done_with_data(move |d: &mut u8, _, _| {
             {
                 let sync = queue2.display::<WlDisplay>().sync();
                 let mut d2 = Box::new(0u8);
-                proxy::set_event_handler(&sync, WlCallback::on_done(|d: &mut u8, _, _| *d += 1));
+                proxy::set_event_handler(
+                    &sync,
+                    WlCallback::on_done_with_data(|d: &mut u8, _, _| *d += 1),
+                );
                 queue2.dispatch_roundtrip_blocking(&mut *d2).unwrap();
                 assert_eq!(*d2, 1);
                 *d += 1;
@@ -109,7 +238,7 @@ fn nested_dispatch_other_data() {
             let sync = queue2.display::<WlDisplay>().sync();
             proxy::set_event_handler(
                 &sync.clone(),
-                WlCallback::on_done(move |d: &mut u8, _, _| {
+                WlCallback::on_done_with_data(move |d: &mut u8, _, _| {
                     *d += 1;
                     let _v = &sync;
                 }),
@@ -207,7 +336,7 @@ fn scope_mutable_data() {
         let dummy = Box::new(0);
         scope.set_event_handler(
             &sync,
-            WlCallback::on_done(move |data: &mut bool, _, _| {
+            WlCallback::on_done_with_data(move |data: &mut bool, _, _| {
                 let _ = &dummy;
                 *data = true;
             }),
@@ -230,7 +359,7 @@ fn scope_mutable_data_wrong_type() {
         let dummy = Box::new(0);
         scope.set_event_handler(
             &sync,
-            WlCallback::on_done(move |_: &mut u16, _, _| {
+            WlCallback::on_done_with_data(move |_: &mut u16, _, _| {
                 let _ = &dummy;
             }),
         );
@@ -246,7 +375,10 @@ fn mutable_data_without_queue_data() {
     let con = lib.connect_to_default_display().unwrap();
     let queue = con.create_queue(c"queue name");
     let sync = queue.display::<WlDisplay>().sync();
-    proxy::set_event_handler(&sync, WlCallback::on_done(move |_: &mut bool, _, _| ()));
+    proxy::set_event_handler(
+        &sync,
+        WlCallback::on_done_with_data(move |_: &mut bool, _, _| ()),
+    );
 }
 
 #[test]
@@ -319,7 +451,7 @@ fn dispatch_blocking() {
     let mut done = false;
     proxy::set_event_handler(
         &sync,
-        WlCallback::on_done(|done: &mut bool, _, _| *done = true),
+        WlCallback::on_done_with_data(|done: &mut bool, _, _| *done = true),
     );
     queue.dispatch_blocking(&mut done).unwrap();
     assert!(done);
@@ -334,7 +466,7 @@ async fn dispatch_async() {
     let mut done = false;
     proxy::set_event_handler(
         &sync,
-        WlCallback::on_done(|done: &mut bool, _, _| *done = true),
+        WlCallback::on_done_with_data(|done: &mut bool, _, _| *done = true),
     );
     queue.dispatch_async(&mut done).await.unwrap();
     assert!(done);
@@ -349,7 +481,7 @@ fn dispatch_roundtrip_blocking() {
     let mut done = false;
     proxy::set_event_handler(
         &sync,
-        WlCallback::on_done(|done: &mut bool, _, _| *done = true),
+        WlCallback::on_done_with_data(|done: &mut bool, _, _| *done = true),
     );
     queue.dispatch_roundtrip_blocking(&mut done).unwrap();
     assert!(done);
@@ -364,7 +496,7 @@ async fn dispatch_roundtrip_async() {
     let mut done = false;
     proxy::set_event_handler(
         &sync,
-        WlCallback::on_done(|done: &mut bool, _, _| *done = true),
+        WlCallback::on_done_with_data(|done: &mut bool, _, _| *done = true),
     );
     queue.dispatch_roundtrip_async(&mut done).await.unwrap();
     assert!(done);
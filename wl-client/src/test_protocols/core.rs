@@ -17,3 +17,20 @@ pub mod wl_root;
 pub mod wl_seat;
 pub mod wl_string;
 pub mod wl_surface;
+
+/// Metadata about the core protocol.
+#[allow(dead_code)]
+pub static PROTOCOL: crate::info::ProtocolInfo = crate::info::ProtocolInfo {
+    name: "core",
+    interfaces: &[
+        &wl_display::INFO,
+        &wl_registry::INFO,
+        &wl_callback::INFO,
+        &wl_root::INFO,
+        &wl_dummy::INFO,
+        &wl_string::INFO,
+        &wl_surface::INFO,
+        &wl_seat::INFO,
+        &wl_keyboard::INFO,
+    ],
+};
@@ -40,6 +40,127 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_keyboard interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_keyboard",
+    protocol: "core",
+    version: 10,
+    requests: &[info::MessageInfo {
+        name: "release",
+        since: 3,
+        deprecated_since: None,
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        info::MessageInfo {
+            name: "key",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "time",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "key",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "state",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: Some("key_state"),
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "modifiers",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "serial",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mods_depressed",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mods_latched",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "mods_locked",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "group",
+                    ty: info::ArgType::Uint,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+    ],
+    enums: &[info::EnumInfo {
+        name: "key_state",
+        since: 1,
+        bitfield: false,
+        entries: &[
+            info::EntryInfo {
+                name: "released",
+                value: 0,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "pressed",
+                value: 1,
+                since: 1,
+                deprecated_since: None,
+            },
+            info::EntryInfo {
+                name: "repeated",
+                value: 2,
+                since: 1,
+                deprecated_since: None,
+            },
+        ],
+    }],
+};
+
 /// An owned wl_keyboard proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -94,11 +215,16 @@ impl Deref for WlKeyboard {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -206,6 +332,313 @@ pub trait WlKeyboardEventHandler {
 
 impl WlKeyboardEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlKeyboard] proxies with mutable data.
+///
+/// This trait is the same as [WlKeyboardEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlKeyboardEventHandlerWithData<T> {
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `time`:
+    /// - `key`:
+    /// - `state`:
+    #[inline]
+    fn key(
+        &self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = key;
+        let _ = state;
+    }
+
+    /// # Arguments
+    ///
+    /// - `serial`:
+    /// - `mods_depressed`:
+    /// - `mods_latched`:
+    /// - `mods_locked`:
+    /// - `group`:
+    #[inline]
+    fn modifiers(
+        &self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let _ = serial;
+        let _ = mods_depressed;
+        let _ = mods_latched;
+        let _ = mods_locked;
+        let _ = group;
+    }
+}
+
+/// A mutable event handler for [WlKeyboard] proxies.
+///
+/// This trait is the same as [WlKeyboardEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlKeyboardEventHandlerMut {
+    /// See [WlKeyboardEventHandler::key].
+    #[inline]
+    fn key(
+        &mut self,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = key;
+        let _ = state;
+    }
+
+    /// See [WlKeyboardEventHandler::modifiers].
+    #[inline]
+    fn modifiers(
+        &mut self,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let _ = serial;
+        let _ = mods_depressed;
+        let _ = mods_latched;
+        let _ = mods_locked;
+        let _ = group;
+    }
+}
+
+/// A mutable event handler for [WlKeyboard] proxies with mutable data.
+///
+/// This trait is the same as [WlKeyboardEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlKeyboardEventHandlerMutWithData<T> {
+    /// See [WlKeyboardEventHandler::key].
+    #[inline]
+    fn key(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let _ = serial;
+        let _ = time;
+        let _ = key;
+        let _ = state;
+    }
+
+    /// See [WlKeyboardEventHandler::modifiers].
+    #[inline]
+    fn modifiers(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let _ = serial;
+        let _ = mods_depressed;
+        let _ = mods_latched;
+        let _ = mods_locked;
+        let _ = group;
+    }
+}
+
+impl<H> WlKeyboardEventHandler for MutEventHandler<H>
+where
+    H: WlKeyboardEventHandlerMut,
+{
+    #[inline]
+    fn key(
+        &self,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        self.with(|h| h.key(_slf, serial, time, key, state))
+    }
+
+    #[inline]
+    fn modifiers(
+        &self,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        self.with(|h| {
+            h.modifiers(
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        })
+    }
+}
+
+impl<T, H> WlKeyboardEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlKeyboardEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn key(
+        &self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        self.with(|h| h.key(_data, _slf, serial, time, key, state))
+    }
+
+    #[inline]
+    fn modifiers(
+        &self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        self.with(|h| {
+            h.modifiers(
+                _data,
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        })
+    }
+}
+
+impl<T, U, H> WlKeyboardEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlKeyboardEventHandlerWithData<U>,
+{
+    #[inline]
+    fn key(
+        &self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let _data = self.project(_data);
+        self.handler().key(_data, _slf, serial, time, key, state)
+    }
+
+    #[inline]
+    fn modifiers(
+        &self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler().modifiers(
+            _data,
+            _slf,
+            serial,
+            mods_depressed,
+            mods_latched,
+            mods_locked,
+            group,
+        )
+    }
+}
+
+impl<T, U, H> WlKeyboardEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlKeyboardEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn key(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        time: u32,
+        key: u32,
+        state: WlKeyboardKeyState,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut()
+            .key(_data, _slf, serial, time, key, state)
+    }
+
+    #[inline]
+    fn modifiers(
+        &mut self,
+        _data: &mut T,
+        _slf: &WlKeyboardRef,
+        serial: u32,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) {
+        let _data = self.project(_data);
+        self.handler_mut().modifiers(
+            _data,
+            _slf,
+            serial,
+            mods_depressed,
+            mods_latched,
+            mods_locked,
+            group,
+        )
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -260,15 +693,94 @@ where
     }
 }
 
-impl<H> CreateEventHandler<H> for private::ProxyApi
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
 where
-    H: WlKeyboardEventHandler,
+    H: WlKeyboardEventHandlerWithData<T>,
+    T: 'static,
 {
-    type EventHandler = private::EventHandler<H>;
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
 
     #[inline]
-    fn create_event_handler(handler: H) -> Self::EventHandler {
-        private::EventHandler(handler)
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlKeyboardRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 4 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 4]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { WlKeyboardKeyState(args[3].u) };
+                self.0.key(data, slf, arg0, arg1, arg2, arg3);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 5 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 5]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { args[0].u };
+                // SAFETY: - INTERFACE requires that args[1] contains a uint
+                let arg1 = unsafe { args[1].u };
+                // SAFETY: - INTERFACE requires that args[2] contains a uint
+                let arg2 = unsafe { args[2].u };
+                // SAFETY: - INTERFACE requires that args[3] contains a uint
+                let arg3 = unsafe { args[3].u };
+                // SAFETY: - INTERFACE requires that args[4] contains a uint
+                let arg4 = unsafe { args[4].u };
+                self.0.modifiers(data, slf, arg0, arg1, arg2, arg3, arg4);
+            }
+            _ => {
+                invalid_opcode("wl_keyboard", opcode);
+            }
+        }
+    }
+}
+
+impl<H> CreateEventHandler<H> for private::ProxyApi
+where
+    H: WlKeyboardEventHandler,
+{
+    type EventHandler = private::EventHandler<H>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandler(handler)
+    }
+}
+
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlKeyboardEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
     }
 }
 
@@ -333,6 +845,23 @@ pub mod event_handlers {
             self.0(_slf, serial, time, key, state)
         }
     }
+    impl<T, F> WlKeyboardEventHandlerWithData<T> for Key<F>
+    where
+        F: Fn(&mut T, &WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+    {
+        #[inline]
+        fn key(
+            &self,
+            _data: &mut T,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: WlKeyboardKeyState,
+        ) {
+            self.0(_data, _slf, serial, time, key, state)
+        }
+    }
 
     /// Event handler for modifiers events.
     pub struct Modifiers<F>(F);
@@ -360,6 +889,121 @@ pub mod event_handlers {
             )
         }
     }
+    impl<T, F> WlKeyboardEventHandlerWithData<T> for Modifiers<F>
+    where
+        F: Fn(&mut T, &WlKeyboardRef, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn modifiers(
+            &self,
+            _data: &mut T,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ) {
+            self.0(
+                _data,
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        }
+    }
+
+    /// Mutable event handler for key events.
+    pub struct KeyMut<F>(F);
+    impl<F> WlKeyboardEventHandlerMut for KeyMut<F>
+    where
+        F: FnMut(&WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+    {
+        #[inline]
+        fn key(
+            &mut self,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: WlKeyboardKeyState,
+        ) {
+            self.0(_slf, serial, time, key, state)
+        }
+    }
+    impl<T, F> WlKeyboardEventHandlerMutWithData<T> for KeyMut<F>
+    where
+        F: FnMut(&mut T, &WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+    {
+        #[inline]
+        fn key(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: WlKeyboardKeyState,
+        ) {
+            self.0(_data, _slf, serial, time, key, state)
+        }
+    }
+
+    /// Mutable event handler for modifiers events.
+    pub struct ModifiersMut<F>(F);
+    impl<F> WlKeyboardEventHandlerMut for ModifiersMut<F>
+    where
+        F: FnMut(&WlKeyboardRef, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn modifiers(
+            &mut self,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ) {
+            self.0(
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        }
+    }
+    impl<T, F> WlKeyboardEventHandlerMutWithData<T> for ModifiersMut<F>
+    where
+        F: FnMut(&mut T, &WlKeyboardRef, u32, u32, u32, u32, u32),
+    {
+        #[inline]
+        fn modifiers(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ) {
+            self.0(
+                _data,
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        }
+    }
 
     impl WlKeyboard {
         /// Creates an event handler for key events.
@@ -373,6 +1017,17 @@ pub mod event_handlers {
             Key(f)
         }
 
+        /// Creates an event handler with mutable data for key events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_key_with_data<T, F>(f: F) -> Key<F>
+        where
+            F: Fn(&mut T, &WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+        {
+            Key(f)
+        }
+
         /// Creates an event handler for modifiers events.
         ///
         /// The event handler ignores all other events.
@@ -383,5 +1038,224 @@ pub mod event_handlers {
         {
             Modifiers(f)
         }
+
+        /// Creates an event handler with mutable data for modifiers events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_modifiers_with_data<T, F>(f: F) -> Modifiers<F>
+        where
+            F: Fn(&mut T, &WlKeyboardRef, u32, u32, u32, u32, u32),
+        {
+            Modifiers(f)
+        }
+
+        /// Creates a mutable event handler for key events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_key_mut<F>(f: F) -> KeyMut<F>
+        where
+            F: FnMut(&WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+        {
+            KeyMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for key events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_key_mut_with_data<T, F>(f: F) -> KeyMut<F>
+        where
+            F: FnMut(&mut T, &WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+        {
+            KeyMut(f)
+        }
+
+        /// Creates a mutable event handler for modifiers events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modifiers_mut<F>(f: F) -> ModifiersMut<F>
+        where
+            F: FnMut(&WlKeyboardRef, u32, u32, u32, u32, u32),
+        {
+            ModifiersMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for modifiers events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_modifiers_mut_with_data<T, F>(f: F) -> ModifiersMut<F>
+        where
+            F: FnMut(&mut T, &WlKeyboardRef, u32, u32, u32, u32, u32),
+        {
+            ModifiersMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                key: Ignore,
+                modifiers: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlKeyboardEventHandler for Ignore {}
+    impl<T> WlKeyboardEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlKeyboard::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        key: H0,
+        modifiers: H1,
+    }
+
+    impl<H0, H1> WlKeyboardEventHandler for Handler<H0, H1>
+    where
+        H0: WlKeyboardEventHandler,
+        H1: WlKeyboardEventHandler,
+    {
+        #[inline]
+        fn key(
+            &self,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: WlKeyboardKeyState,
+        ) {
+            self.key.key(_slf, serial, time, key, state)
+        }
+
+        #[inline]
+        fn modifiers(
+            &self,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ) {
+            self.modifiers.modifiers(
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        }
+    }
+
+    impl<T, H0, H1> WlKeyboardEventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WlKeyboardEventHandlerWithData<T>,
+        H1: WlKeyboardEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn key(
+            &self,
+            _data: &mut T,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: WlKeyboardKeyState,
+        ) {
+            self.key.key(_data, _slf, serial, time, key, state)
+        }
+
+        #[inline]
+        fn modifiers(
+            &self,
+            _data: &mut T,
+            _slf: &WlKeyboardRef,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ) {
+            self.modifiers.modifiers(
+                _data,
+                _slf,
+                serial,
+                mods_depressed,
+                mods_latched,
+                mods_locked,
+                group,
+            )
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for key events.
+        #[allow(dead_code)]
+        pub fn on_key<F>(self, f: F) -> Handler<Key<F>, H1>
+        where
+            F: Fn(&WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+        {
+            Handler {
+                key: Key(f),
+                modifiers: self.modifiers,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for key events.
+        #[allow(dead_code)]
+        pub fn on_key_with_data<T, F>(self, f: F) -> Handler<Key<F>, H1>
+        where
+            F: Fn(&mut T, &WlKeyboardRef, u32, u32, u32, WlKeyboardKeyState),
+        {
+            Handler {
+                key: Key(f),
+                modifiers: self.modifiers,
+            }
+        }
+
+        /// Sets the closure for modifiers events.
+        #[allow(dead_code)]
+        pub fn on_modifiers<F>(self, f: F) -> Handler<H0, Modifiers<F>>
+        where
+            F: Fn(&WlKeyboardRef, u32, u32, u32, u32, u32),
+        {
+            Handler {
+                key: self.key,
+                modifiers: Modifiers(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for modifiers events.
+        #[allow(dead_code)]
+        pub fn on_modifiers_with_data<T, F>(self, f: F) -> Handler<H0, Modifiers<F>>
+        where
+            F: Fn(&mut T, &WlKeyboardRef, u32, u32, u32, u32, u32),
+        {
+            Handler {
+                key: self.key,
+                modifiers: Modifiers(f),
+            }
+        }
     }
 }
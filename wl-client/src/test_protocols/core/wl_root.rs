@@ -95,6 +95,133 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_root interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_root",
+    protocol: "core",
+    version: 1,
+    requests: &[
+        info::MessageInfo {
+            name: "create_dummy",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_dummy"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "ping_dummy",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::Object,
+                interface: Some("wl_dummy"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "destroy",
+            since: 1,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "get_server_name",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_string"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "send_new_dummy",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[],
+        },
+        info::MessageInfo {
+            name: "echo",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[
+                info::ArgInfo {
+                    name: "id",
+                    ty: info::ArgType::NewId,
+                    interface: Some("wl_string"),
+                    allow_null: false,
+                    enum_: None,
+                },
+                info::ArgInfo {
+                    name: "str",
+                    ty: info::ArgType::String,
+                    interface: None,
+                    allow_null: false,
+                    enum_: None,
+                },
+            ],
+        },
+        info::MessageInfo {
+            name: "bind",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: None,
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    events: &[
+        info::MessageInfo {
+            name: "pong_dummy",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::Object,
+                interface: Some("wl_dummy"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "new_dummy",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_dummy"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+    ],
+    enums: &[],
+};
+
 /// An owned wl_root proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -149,11 +276,16 @@ impl Deref for WlRoot {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -446,6 +578,137 @@ pub trait WlRootEventHandler {
 
 impl WlRootEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlRoot] proxies with mutable data.
+///
+/// This trait is the same as [WlRootEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlRootEventHandlerWithData<T> {
+    /// # Arguments
+    ///
+    /// - `id`:
+    ///
+    /// All borrowed proxies passed to this function are guaranteed to be
+    /// immutable and non-null.
+    #[inline]
+    fn pong_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        let _ = id;
+    }
+
+    /// # Arguments
+    ///
+    /// - `id`:
+    #[inline]
+    fn new_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [WlRoot] proxies.
+///
+/// This trait is the same as [WlRootEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlRootEventHandlerMut {
+    /// See [WlRootEventHandler::pong_dummy].
+    #[inline]
+    fn pong_dummy(&mut self, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        let _ = id;
+    }
+
+    /// See [WlRootEventHandler::new_dummy].
+    #[inline]
+    fn new_dummy(&mut self, _slf: &WlRootRef, id: WlDummy) {
+        let _ = id;
+    }
+}
+
+/// A mutable event handler for [WlRoot] proxies with mutable data.
+///
+/// This trait is the same as [WlRootEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlRootEventHandlerMutWithData<T> {
+    /// See [WlRootEventHandler::pong_dummy].
+    #[inline]
+    fn pong_dummy(&mut self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        let _ = id;
+    }
+
+    /// See [WlRootEventHandler::new_dummy].
+    #[inline]
+    fn new_dummy(&mut self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+        let _ = id;
+    }
+}
+
+impl<H> WlRootEventHandler for MutEventHandler<H>
+where
+    H: WlRootEventHandlerMut,
+{
+    #[inline]
+    fn pong_dummy(&self, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        self.with(|h| h.pong_dummy(_slf, id))
+    }
+
+    #[inline]
+    fn new_dummy(&self, _slf: &WlRootRef, id: WlDummy) {
+        self.with(|h| h.new_dummy(_slf, id))
+    }
+}
+
+impl<T, H> WlRootEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlRootEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn pong_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        self.with(|h| h.pong_dummy(_data, _slf, id))
+    }
+
+    #[inline]
+    fn new_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+        self.with(|h| h.new_dummy(_data, _slf, id))
+    }
+}
+
+impl<T, U, H> WlRootEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlRootEventHandlerWithData<U>,
+{
+    #[inline]
+    fn pong_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        let _data = self.project(_data);
+        self.handler().pong_dummy(_data, _slf, id)
+    }
+
+    #[inline]
+    fn new_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+        let _data = self.project(_data);
+        self.handler().new_dummy(_data, _slf, id)
+    }
+}
+
+impl<T, U, H> WlRootEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlRootEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn pong_dummy(&mut self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+        let _data = self.project(_data);
+        self.handler_mut().pong_dummy(_data, _slf, id)
+    }
+
+    #[inline]
+    fn new_dummy(&mut self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+        let _data = self.project(_data);
+        self.handler_mut().new_dummy(_data, _slf, id)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -506,6 +769,78 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlRootEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlRootRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                let arg0 = unsafe {
+                    if let Some(p) = NonNull::new(args[0].o.cast()) {
+                        Some(UntypedBorrowedProxy::new_immutable(queue.libwayland(), p))
+                    } else {
+                        None
+                    }
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlDummy::WL_INTERFACE
+                let arg0 = arg0.as_ref().map(|arg0| unsafe {
+                    proxy::low_level::from_untyped_borrowed::<WlDummyRef>(arg0)
+                });
+                self.0.pong_dummy(data, slf, arg0);
+            }
+            1 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains an object
+                //         - ownership is transferred to this function
+                //         - INTERFACE requires that the object has the interface WlDummy::WL_INTERFACE
+                let arg0 = unsafe {
+                    UntypedOwnedProxy::from_plain_wl_proxy(
+                        queue,
+                        NonNull::new_unchecked(args[0].o.cast()),
+                        WlDummy::WL_INTERFACE,
+                    )
+                };
+                // SAFETY: - INTERFACE requires that the object has the interface WlDummy::WL_INTERFACE
+                let arg0 = unsafe { proxy::low_level::from_untyped_owned::<WlDummy>(arg0) };
+                self.0.new_dummy(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_root", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlRootEventHandler,
@@ -518,6 +853,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlRootEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -533,6 +881,15 @@ pub mod event_handlers {
             self.0(_slf, id)
         }
     }
+    impl<T, F> WlRootEventHandlerWithData<T> for PongDummy<F>
+    where
+        F: Fn(&mut T, &WlRootRef, Option<&WlDummyRef>),
+    {
+        #[inline]
+        fn pong_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+            self.0(_data, _slf, id)
+        }
+    }
 
     /// Event handler for new_dummy events.
     pub struct NewDummy<F>(F);
@@ -545,6 +902,57 @@ pub mod event_handlers {
             self.0(_slf, id)
         }
     }
+    impl<T, F> WlRootEventHandlerWithData<T> for NewDummy<F>
+    where
+        F: Fn(&mut T, &WlRootRef, WlDummy),
+    {
+        #[inline]
+        fn new_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for pong_dummy events.
+    pub struct PongDummyMut<F>(F);
+    impl<F> WlRootEventHandlerMut for PongDummyMut<F>
+    where
+        F: FnMut(&WlRootRef, Option<&WlDummyRef>),
+    {
+        #[inline]
+        fn pong_dummy(&mut self, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> WlRootEventHandlerMutWithData<T> for PongDummyMut<F>
+    where
+        F: FnMut(&mut T, &WlRootRef, Option<&WlDummyRef>),
+    {
+        #[inline]
+        fn pong_dummy(&mut self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+            self.0(_data, _slf, id)
+        }
+    }
+
+    /// Mutable event handler for new_dummy events.
+    pub struct NewDummyMut<F>(F);
+    impl<F> WlRootEventHandlerMut for NewDummyMut<F>
+    where
+        F: FnMut(&WlRootRef, WlDummy),
+    {
+        #[inline]
+        fn new_dummy(&mut self, _slf: &WlRootRef, id: WlDummy) {
+            self.0(_slf, id)
+        }
+    }
+    impl<T, F> WlRootEventHandlerMutWithData<T> for NewDummyMut<F>
+    where
+        F: FnMut(&mut T, &WlRootRef, WlDummy),
+    {
+        #[inline]
+        fn new_dummy(&mut self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+            self.0(_data, _slf, id)
+        }
+    }
 
     impl WlRoot {
         /// Creates an event handler for pong_dummy events.
@@ -558,6 +966,17 @@ pub mod event_handlers {
             PongDummy(f)
         }
 
+        /// Creates an event handler with mutable data for pong_dummy events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_pong_dummy_with_data<T, F>(f: F) -> PongDummy<F>
+        where
+            F: Fn(&mut T, &WlRootRef, Option<&WlDummyRef>),
+        {
+            PongDummy(f)
+        }
+
         /// Creates an event handler for new_dummy events.
         ///
         /// The event handler ignores all other events.
@@ -568,5 +987,177 @@ pub mod event_handlers {
         {
             NewDummy(f)
         }
+
+        /// Creates an event handler with mutable data for new_dummy events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_new_dummy_with_data<T, F>(f: F) -> NewDummy<F>
+        where
+            F: Fn(&mut T, &WlRootRef, WlDummy),
+        {
+            NewDummy(f)
+        }
+
+        /// Creates a mutable event handler for pong_dummy events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_pong_dummy_mut<F>(f: F) -> PongDummyMut<F>
+        where
+            F: FnMut(&WlRootRef, Option<&WlDummyRef>),
+        {
+            PongDummyMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for pong_dummy events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_pong_dummy_mut_with_data<T, F>(f: F) -> PongDummyMut<F>
+        where
+            F: FnMut(&mut T, &WlRootRef, Option<&WlDummyRef>),
+        {
+            PongDummyMut(f)
+        }
+
+        /// Creates a mutable event handler for new_dummy events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_new_dummy_mut<F>(f: F) -> NewDummyMut<F>
+        where
+            F: FnMut(&WlRootRef, WlDummy),
+        {
+            NewDummyMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for new_dummy events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_new_dummy_mut_with_data<T, F>(f: F) -> NewDummyMut<F>
+        where
+            F: FnMut(&mut T, &WlRootRef, WlDummy),
+        {
+            NewDummyMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                pong_dummy: Ignore,
+                new_dummy: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlRootEventHandler for Ignore {}
+    impl<T> WlRootEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlRoot::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore, H1 = Ignore> {
+        pong_dummy: H0,
+        new_dummy: H1,
+    }
+
+    impl<H0, H1> WlRootEventHandler for Handler<H0, H1>
+    where
+        H0: WlRootEventHandler,
+        H1: WlRootEventHandler,
+    {
+        #[inline]
+        fn pong_dummy(&self, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+            self.pong_dummy.pong_dummy(_slf, id)
+        }
+
+        #[inline]
+        fn new_dummy(&self, _slf: &WlRootRef, id: WlDummy) {
+            self.new_dummy.new_dummy(_slf, id)
+        }
+    }
+
+    impl<T, H0, H1> WlRootEventHandlerWithData<T> for Handler<H0, H1>
+    where
+        H0: WlRootEventHandlerWithData<T>,
+        H1: WlRootEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn pong_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: Option<&WlDummyRef>) {
+            self.pong_dummy.pong_dummy(_data, _slf, id)
+        }
+
+        #[inline]
+        fn new_dummy(&self, _data: &mut T, _slf: &WlRootRef, id: WlDummy) {
+            self.new_dummy.new_dummy(_data, _slf, id)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0, H1> Handler<H0, H1> {
+        /// Sets the closure for pong_dummy events.
+        #[allow(dead_code)]
+        pub fn on_pong_dummy<F>(self, f: F) -> Handler<PongDummy<F>, H1>
+        where
+            F: Fn(&WlRootRef, Option<&WlDummyRef>),
+        {
+            Handler {
+                pong_dummy: PongDummy(f),
+                new_dummy: self.new_dummy,
+            }
+        }
+
+        /// Sets the closure that takes mutable data for pong_dummy events.
+        #[allow(dead_code)]
+        pub fn on_pong_dummy_with_data<T, F>(self, f: F) -> Handler<PongDummy<F>, H1>
+        where
+            F: Fn(&mut T, &WlRootRef, Option<&WlDummyRef>),
+        {
+            Handler {
+                pong_dummy: PongDummy(f),
+                new_dummy: self.new_dummy,
+            }
+        }
+
+        /// Sets the closure for new_dummy events.
+        #[allow(dead_code)]
+        pub fn on_new_dummy<F>(self, f: F) -> Handler<H0, NewDummy<F>>
+        where
+            F: Fn(&WlRootRef, WlDummy),
+        {
+            Handler {
+                pong_dummy: self.pong_dummy,
+                new_dummy: NewDummy(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for new_dummy events.
+        #[allow(dead_code)]
+        pub fn on_new_dummy_with_data<T, F>(self, f: F) -> Handler<H0, NewDummy<F>>
+        where
+            F: Fn(&mut T, &WlRootRef, WlDummy),
+        {
+            Handler {
+                pong_dummy: self.pong_dummy,
+                new_dummy: NewDummy(f),
+            }
+        }
     }
 }
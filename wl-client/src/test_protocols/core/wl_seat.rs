@@ -40,6 +40,60 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_seat interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_seat",
+    protocol: "core",
+    version: 10,
+    requests: &[
+        info::MessageInfo {
+            name: "get_keyboard",
+            since: 1,
+            deprecated_since: None,
+            destructor: false,
+            args: &[info::ArgInfo {
+                name: "id",
+                ty: info::ArgType::NewId,
+                interface: Some("wl_keyboard"),
+                allow_null: false,
+                enum_: None,
+            }],
+        },
+        info::MessageInfo {
+            name: "release",
+            since: 5,
+            deprecated_since: None,
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[info::MessageInfo {
+        name: "capabilities",
+        since: 1,
+        deprecated_since: None,
+        destructor: false,
+        args: &[info::ArgInfo {
+            name: "capabilities",
+            ty: info::ArgType::Uint,
+            interface: None,
+            allow_null: false,
+            enum_: Some("capability"),
+        }],
+    }],
+    enums: &[info::EnumInfo {
+        name: "capability",
+        since: 1,
+        bitfield: true,
+        entries: &[info::EntryInfo {
+            name: "keyboard",
+            value: 2,
+            since: 1,
+            deprecated_since: None,
+        }],
+    }],
+};
+
 /// An owned wl_seat proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -94,11 +148,16 @@ impl Deref for WlSeat {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -205,6 +264,92 @@ pub trait WlSeatEventHandler {
 
 impl WlSeatEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlSeat] proxies with mutable data.
+///
+/// This trait is the same as [WlSeatEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlSeatEventHandlerWithData<T> {
+    /// # Arguments
+    ///
+    /// - `capabilities`:
+    #[inline]
+    fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _ = capabilities;
+    }
+}
+
+/// A mutable event handler for [WlSeat] proxies.
+///
+/// This trait is the same as [WlSeatEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSeatEventHandlerMut {
+    /// See [WlSeatEventHandler::capabilities].
+    #[inline]
+    fn capabilities(&mut self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _ = capabilities;
+    }
+}
+
+/// A mutable event handler for [WlSeat] proxies with mutable data.
+///
+/// This trait is the same as [WlSeatEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSeatEventHandlerMutWithData<T> {
+    /// See [WlSeatEventHandler::capabilities].
+    #[inline]
+    fn capabilities(&mut self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _ = capabilities;
+    }
+}
+
+impl<H> WlSeatEventHandler for MutEventHandler<H>
+where
+    H: WlSeatEventHandlerMut,
+{
+    #[inline]
+    fn capabilities(&self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        self.with(|h| h.capabilities(_slf, capabilities))
+    }
+}
+
+impl<T, H> WlSeatEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlSeatEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        self.with(|h| h.capabilities(_data, _slf, capabilities))
+    }
+}
+
+impl<T, U, H> WlSeatEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlSeatEventHandlerWithData<U>,
+{
+    #[inline]
+    fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _data = self.project(_data);
+        self.handler().capabilities(_data, _slf, capabilities)
+    }
+}
+
+impl<T, U, H> WlSeatEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlSeatEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn capabilities(&mut self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+        let _data = self.project(_data);
+        self.handler_mut().capabilities(_data, _slf, capabilities)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -238,6 +383,51 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlSeatEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlSeatRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a uint
+                let arg0 = unsafe { WlSeatCapability(args[0].u) };
+                self.0.capabilities(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_seat", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlSeatEventHandler,
@@ -250,6 +440,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlSeatEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 impl WlSeat {
     /// Since when the capability.keyboard enum variant is available.
     #[allow(dead_code)]
@@ -487,6 +690,41 @@ pub mod event_handlers {
             self.0(_slf, capabilities)
         }
     }
+    impl<T, F> WlSeatEventHandlerWithData<T> for Capabilities<F>
+    where
+        F: Fn(&mut T, &WlSeatRef, WlSeatCapability),
+    {
+        #[inline]
+        fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
+
+    /// Mutable event handler for capabilities events.
+    pub struct CapabilitiesMut<F>(F);
+    impl<F> WlSeatEventHandlerMut for CapabilitiesMut<F>
+    where
+        F: FnMut(&WlSeatRef, WlSeatCapability),
+    {
+        #[inline]
+        fn capabilities(&mut self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.0(_slf, capabilities)
+        }
+    }
+    impl<T, F> WlSeatEventHandlerMutWithData<T> for CapabilitiesMut<F>
+    where
+        F: FnMut(&mut T, &WlSeatRef, WlSeatCapability),
+    {
+        #[inline]
+        fn capabilities(
+            &mut self,
+            _data: &mut T,
+            _slf: &WlSeatRef,
+            capabilities: WlSeatCapability,
+        ) {
+            self.0(_data, _slf, capabilities)
+        }
+    }
 
     impl WlSeat {
         /// Creates an event handler for capabilities events.
@@ -499,5 +737,113 @@ pub mod event_handlers {
         {
             Capabilities(f)
         }
+
+        /// Creates an event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(f: F) -> Capabilities<F>
+        where
+            F: Fn(&mut T, &WlSeatRef, WlSeatCapability),
+        {
+            Capabilities(f)
+        }
+
+        /// Creates a mutable event handler for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut<F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&WlSeatRef, WlSeatCapability),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for capabilities events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_capabilities_mut_with_data<T, F>(f: F) -> CapabilitiesMut<F>
+        where
+            F: FnMut(&mut T, &WlSeatRef, WlSeatCapability),
+        {
+            CapabilitiesMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler {
+                capabilities: Ignore,
+            }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlSeatEventHandler for Ignore {}
+    impl<T> WlSeatEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlSeat::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        capabilities: H0,
+    }
+
+    impl<H0> WlSeatEventHandler for Handler<H0>
+    where
+        H0: WlSeatEventHandler,
+    {
+        #[inline]
+        fn capabilities(&self, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.capabilities.capabilities(_slf, capabilities)
+        }
+    }
+
+    impl<T, H0> WlSeatEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WlSeatEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn capabilities(&self, _data: &mut T, _slf: &WlSeatRef, capabilities: WlSeatCapability) {
+            self.capabilities.capabilities(_data, _slf, capabilities)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities<F>(self, f: F) -> Handler<Capabilities<F>>
+        where
+            F: Fn(&WlSeatRef, WlSeatCapability),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+            }
+        }
+
+        /// Sets the closure that takes mutable data for capabilities events.
+        #[allow(dead_code)]
+        pub fn on_capabilities_with_data<T, F>(self, f: F) -> Handler<Capabilities<F>>
+        where
+            F: Fn(&mut T, &WlSeatRef, WlSeatCapability),
+        {
+            Handler {
+                capabilities: Capabilities(f),
+            }
+        }
     }
 }
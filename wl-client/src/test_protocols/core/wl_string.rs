@@ -19,6 +19,29 @@ static INTERFACE: wl_interface = wl_interface {
     },
 };
 
+/// Metadata about the wl_string interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_string",
+    protocol: "core",
+    version: 1,
+    requests: &[],
+    events: &[info::MessageInfo {
+        name: "string",
+        since: 1,
+        deprecated_since: None,
+        destructor: true,
+        args: &[info::ArgInfo {
+            name: "string",
+            ty: info::ArgType::String,
+            interface: None,
+            allow_null: false,
+            enum_: None,
+        }],
+    }],
+    enums: &[],
+};
+
 /// An owned wl_string proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -73,11 +96,16 @@ impl Deref for WlString {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -126,6 +154,92 @@ pub trait WlStringEventHandler {
 
 impl WlStringEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlString] proxies with mutable data.
+///
+/// This trait is the same as [WlStringEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlStringEventHandlerWithData<T> {
+    /// # Arguments
+    ///
+    /// - `string`:
+    #[inline]
+    fn string(&self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+        let _ = string;
+    }
+}
+
+/// A mutable event handler for [WlString] proxies.
+///
+/// This trait is the same as [WlStringEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlStringEventHandlerMut {
+    /// See [WlStringEventHandler::string].
+    #[inline]
+    fn string(&mut self, _slf: &WlStringRef, string: &str) {
+        let _ = string;
+    }
+}
+
+/// A mutable event handler for [WlString] proxies with mutable data.
+///
+/// This trait is the same as [WlStringEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlStringEventHandlerMutWithData<T> {
+    /// See [WlStringEventHandler::string].
+    #[inline]
+    fn string(&mut self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+        let _ = string;
+    }
+}
+
+impl<H> WlStringEventHandler for MutEventHandler<H>
+where
+    H: WlStringEventHandlerMut,
+{
+    #[inline]
+    fn string(&self, _slf: &WlStringRef, string: &str) {
+        self.with(|h| h.string(_slf, string))
+    }
+}
+
+impl<T, H> WlStringEventHandlerWithData<T> for MutEventHandler<H>
+where
+    H: WlStringEventHandlerMutWithData<T>,
+{
+    #[inline]
+    fn string(&self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+        self.with(|h| h.string(_data, _slf, string))
+    }
+}
+
+impl<T, U, H> WlStringEventHandlerWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlStringEventHandlerWithData<U>,
+{
+    #[inline]
+    fn string(&self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+        let _data = self.project(_data);
+        self.handler().string(_data, _slf, string)
+    }
+}
+
+impl<T, U, H> WlStringEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U>
+where
+    H: WlStringEventHandlerMutWithData<U>,
+{
+    #[inline]
+    fn string(&mut self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+        let _data = self.project(_data);
+        self.handler_mut().string(_data, _slf, string)
+    }
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -160,6 +274,52 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlStringEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        // SAFETY: This function requires that slf has the interface INTERFACE
+        let slf = unsafe { proxy::low_level::from_untyped_borrowed::<WlStringRef>(slf) };
+        // SAFETY: This function requires that data is `&mut T` where `T`
+        //         has the type id returned by `Self::mutable_type`.
+        let data: &mut T = unsafe { &mut *data.cast() };
+        match opcode {
+            0 => {
+                // SAFETY: INTERFACE requires that there are 1 arguments
+                let args = unsafe { &*args.cast::<[wl_argument; 1]>() };
+                // SAFETY: - INTERFACE requires that args[0] contains a string
+                //         - if the pointer is not null, then it is a c string
+                let arg0 = unsafe { convert_string_arg("wl_string", "string", args[0].s) };
+                self.0.string(data, slf, arg0);
+            }
+            _ => {
+                invalid_opcode("wl_string", opcode);
+            }
+        }
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlStringEventHandler,
@@ -172,6 +332,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlStringEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -187,6 +360,36 @@ pub mod event_handlers {
             self.0(_slf, string)
         }
     }
+    impl<T, F> WlStringEventHandlerWithData<T> for String<F>
+    where
+        F: Fn(&mut T, &WlStringRef, &str),
+    {
+        #[inline]
+        fn string(&self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+            self.0(_data, _slf, string)
+        }
+    }
+
+    /// Mutable event handler for string events.
+    pub struct StringMut<F>(F);
+    impl<F> WlStringEventHandlerMut for StringMut<F>
+    where
+        F: FnMut(&WlStringRef, &str),
+    {
+        #[inline]
+        fn string(&mut self, _slf: &WlStringRef, string: &str) {
+            self.0(_slf, string)
+        }
+    }
+    impl<T, F> WlStringEventHandlerMutWithData<T> for StringMut<F>
+    where
+        F: FnMut(&mut T, &WlStringRef, &str),
+    {
+        #[inline]
+        fn string(&mut self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+            self.0(_data, _slf, string)
+        }
+    }
 
     impl WlString {
         /// Creates an event handler for string events.
@@ -199,5 +402,107 @@ pub mod event_handlers {
         {
             String(f)
         }
+
+        /// Creates an event handler with mutable data for string events.
+        ///
+        /// The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn on_string_with_data<T, F>(f: F) -> String<F>
+        where
+            F: Fn(&mut T, &WlStringRef, &str),
+        {
+            String(f)
+        }
+
+        /// Creates a mutable event handler for string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_string_mut<F>(f: F) -> StringMut<F>
+        where
+            F: FnMut(&WlStringRef, &str),
+        {
+            StringMut(f)
+        }
+
+        /// Creates a mutable event handler with mutable data for string events.
+        ///
+        /// The event handler ignores all other events. It can be attached with
+        /// [`proxy::set_event_handler_local_mut`].
+        #[allow(dead_code)]
+        pub fn on_string_mut_with_data<T, F>(f: F) -> StringMut<F>
+        where
+            F: FnMut(&mut T, &WlStringRef, &str),
+        {
+            StringMut(f)
+        }
+
+        /// Creates an event handler for multiple events.
+        ///
+        /// Use the `on_*` functions of the returned [`Handler`] to add closures for
+        /// individual events. The event handler ignores all other events.
+        #[allow(dead_code)]
+        pub fn handler() -> Handler {
+            Handler { string: Ignore }
+        }
+    }
+
+    /// An event handler that ignores all events.
+    ///
+    /// This is used by [`Handler`] for events without a closure.
+    pub struct Ignore;
+    impl WlStringEventHandler for Ignore {}
+    impl<T> WlStringEventHandlerWithData<T> for Ignore {}
+
+    /// An event handler for multiple events.
+    ///
+    /// This event handler is created with [`WlString::handler`]. Each type parameter
+    /// is the event handler of one event. Events without a closure are ignored.
+    ///
+    /// The closures must either all take mutable data or all not take mutable data.
+    pub struct Handler<H0 = Ignore> {
+        string: H0,
+    }
+
+    impl<H0> WlStringEventHandler for Handler<H0>
+    where
+        H0: WlStringEventHandler,
+    {
+        #[inline]
+        fn string(&self, _slf: &WlStringRef, string: &str) {
+            self.string.string(_slf, string)
+        }
+    }
+
+    impl<T, H0> WlStringEventHandlerWithData<T> for Handler<H0>
+    where
+        H0: WlStringEventHandlerWithData<T>,
+    {
+        #[inline]
+        fn string(&self, _data: &mut T, _slf: &WlStringRef, string: &str) {
+            self.string.string(_data, _slf, string)
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    impl<H0> Handler<H0> {
+        /// Sets the closure for string events.
+        #[allow(dead_code)]
+        pub fn on_string<F>(self, f: F) -> Handler<String<F>>
+        where
+            F: Fn(&WlStringRef, &str),
+        {
+            Handler { string: String(f) }
+        }
+
+        /// Sets the closure that takes mutable data for string events.
+        #[allow(dead_code)]
+        pub fn on_string_with_data<T, F>(self, f: F) -> Handler<String<F>>
+        where
+            F: Fn(&mut T, &WlStringRef, &str),
+        {
+            Handler { string: String(f) }
+        }
     }
 }
@@ -9,6 +9,17 @@ static INTERFACE: wl_interface = wl_interface {
     events: ptr::null(),
 };
 
+/// Metadata about the wl_surface interface.
+#[allow(dead_code)]
+pub static INFO: info::InterfaceInfo = info::InterfaceInfo {
+    name: "wl_surface",
+    protocol: "core",
+    version: 1,
+    requests: &[],
+    events: &[],
+    enums: &[],
+};
+
 /// An owned wl_surface proxy.
 ///
 /// See the documentation of [the module][self] for the interface description.
@@ -63,11 +74,16 @@ impl Deref for WlSurface {
 }
 
 mod private {
+    use std::marker::PhantomData;
+
     pub struct ProxyApi;
 
     #[allow(dead_code)]
     pub struct EventHandler<H>(pub(super) H);
 
+    #[allow(dead_code)]
+    pub struct EventHandlerWithData<H, T>(pub(super) H, pub(super) PhantomData<fn(&mut T)>);
+
     #[allow(dead_code)]
     pub struct NoOpEventHandler;
 }
@@ -102,6 +118,47 @@ pub trait WlSurfaceEventHandler {}
 
 impl WlSurfaceEventHandler for private::NoOpEventHandler {}
 
+/// An event handler for [WlSurface] proxies with mutable data.
+///
+/// This trait is the same as [WlSurfaceEventHandler] except that the functions take an
+/// additional parameter that gives access to the mutable data that was passed in when
+/// dispatching the queue.
+#[allow(dead_code)]
+pub trait WlSurfaceEventHandlerWithData<T> {}
+
+/// A mutable event handler for [WlSurface] proxies.
+///
+/// This trait is the same as [WlSurfaceEventHandler] except that the functions take
+/// `&mut self`. Such event handlers can be attached to proxies on local queues with
+/// [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSurfaceEventHandlerMut {}
+
+/// A mutable event handler for [WlSurface] proxies with mutable data.
+///
+/// This trait is the same as [WlSurfaceEventHandlerWithData] except that the functions
+/// take `&mut self`. Such event handlers can be attached to proxies on local queues
+/// with [`proxy::set_event_handler_local_mut`].
+#[allow(dead_code)]
+pub trait WlSurfaceEventHandlerMutWithData<T> {}
+
+impl<H> WlSurfaceEventHandler for MutEventHandler<H> where H: WlSurfaceEventHandlerMut {}
+
+impl<T, H> WlSurfaceEventHandlerWithData<T> for MutEventHandler<H> where
+    H: WlSurfaceEventHandlerMutWithData<T>
+{
+}
+
+impl<T, U, H> WlSurfaceEventHandlerWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlSurfaceEventHandlerWithData<U>
+{
+}
+
+impl<T, U, H> WlSurfaceEventHandlerMutWithData<T> for ProjectedEventHandler<H, T, U> where
+    H: WlSurfaceEventHandlerMutWithData<U>
+{
+}
+
 // SAFETY: - INTERFACE is a valid wl_interface
 unsafe impl<H> EventHandler for private::EventHandler<H>
 where
@@ -122,6 +179,35 @@ where
     }
 }
 
+// SAFETY: - INTERFACE is a valid wl_interface
+//         - mutable_type always returns the same value
+unsafe impl<H, T> EventHandler for private::EventHandlerWithData<H, T>
+where
+    H: WlSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    const WL_INTERFACE: &'static wl_interface = &INTERFACE;
+
+    #[inline]
+    fn mutable_type() -> Option<(TypeId, &'static str)> {
+        let id = TypeId::of::<T>();
+        let name = std::any::type_name::<T>();
+        Some((id, name))
+    }
+
+    #[allow(unused_variables)]
+    unsafe fn handle_event(
+        &self,
+        queue: &Queue,
+        data: *mut u8,
+        slf: &UntypedBorrowedProxy,
+        opcode: u32,
+        args: *mut wl_argument,
+    ) {
+        invalid_opcode("wl_surface", opcode);
+    }
+}
+
 impl<H> CreateEventHandler<H> for private::ProxyApi
 where
     H: WlSurfaceEventHandler,
@@ -134,6 +220,19 @@ where
     }
 }
 
+impl<H, T> CreateEventHandler<H, WithData<T>> for private::ProxyApi
+where
+    H: WlSurfaceEventHandlerWithData<T>,
+    T: 'static,
+{
+    type EventHandler = private::EventHandlerWithData<H, T>;
+
+    #[inline]
+    fn create_event_handler(handler: H) -> Self::EventHandler {
+        private::EventHandlerWithData(handler, PhantomData)
+    }
+}
+
 /// Functional event handlers.
 pub mod event_handlers {
     use super::*;
@@ -1,5 +1,31 @@
 pub mod core;
 
+/// Metadata about all protocols in this module.
+#[allow(dead_code)]
+pub static PROTOCOLS: &[&crate::info::ProtocolInfo] = &[&core::PROTOCOL];
+
+/// Returns the metadata of the interface with the given name.
+///
+/// If multiple protocols define an interface with this name, the metadata of the
+/// preferred protocol is returned. If no protocol is preferred, `None` is returned
+/// and the interface can only be found via [`PROTOCOLS`].
+#[allow(dead_code, unreachable_code, clippy::match_single_binding)]
+pub fn interface_info(name: &str) -> Option<&'static crate::info::InterfaceInfo> {
+    let info = match name {
+        "wl_display" => &core::wl_display::INFO,
+        "wl_registry" => &core::wl_registry::INFO,
+        "wl_callback" => &core::wl_callback::INFO,
+        "wl_root" => &core::wl_root::INFO,
+        "wl_dummy" => &core::wl_dummy::INFO,
+        "wl_string" => &core::wl_string::INFO,
+        "wl_surface" => &core::wl_surface::INFO,
+        "wl_seat" => &core::wl_seat::INFO,
+        "wl_keyboard" => &core::wl_keyboard::INFO,
+        _ => return None,
+    };
+    Some(info)
+}
+
 #[allow(unused_imports)]
 mod all_types {
     pub(super) use super::core::{
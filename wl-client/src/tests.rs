@@ -1,18 +1,19 @@
 use {
     crate::{
-        Libwayland, proxy,
+        Libwayland, Queue, proxy,
         test_protocol_helpers::get_root,
         test_protocols::core::{
             wl_callback::{WlCallback, WlCallbackEventHandler, WlCallbackRef},
             wl_display::WlDisplay,
             wl_dummy::WlDummyRef,
-            wl_root::{WlRootEventHandler, WlRootRef},
+            wl_root::{WlRoot, WlRootEventHandler, WlRootEventHandlerMut, WlRootRef},
         },
     },
     futures_util::join,
     std::{
         cell::Cell,
         ops::Deref,
+        ptr,
         rc::Rc,
         sync::{
             Arc, Barrier,
@@ -104,6 +105,122 @@ fn ping_pong_destroyed() {
     }
 }
 
+#[test]
+fn multi_event_handler() {
+    let libwayland = Libwayland::open().unwrap();
+    let con = libwayland.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"abc");
+    let root = get_root(&queue);
+    let dummy = root.create_dummy();
+    let pongs = Rc::new(Cell::new(0));
+    let new_dummies = Rc::new(Cell::new(0));
+    let pongs2 = pongs.clone();
+    let new_dummies2 = new_dummies.clone();
+    proxy::set_event_handler_local(
+        &root,
+        WlRoot::handler()
+            .on_pong_dummy(move |_, id| {
+                assert!(id.is_some());
+                pongs2.set(pongs2.get() + 1);
+            })
+            .on_new_dummy(move |_, _| new_dummies2.set(new_dummies2.get() + 1)),
+    );
+    root.ping_dummy(&dummy);
+    root.send_new_dummy();
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(pongs.get(), 1);
+    assert_eq!(new_dummies.get(), 1);
+}
+
+#[test]
+fn mut_event_handler() {
+    let libwayland = Libwayland::open().unwrap();
+    let con = libwayland.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"abc");
+    let sync = queue.display::<WlDisplay>().sync();
+    let done = Rc::new(Cell::new(0));
+    let done2 = done.clone();
+    let mut events = 0;
+    proxy::set_event_handler_local_mut(
+        &sync,
+        WlCallback::on_done_mut(move |_, _| {
+            events += 1;
+            done2.set(events);
+        }),
+    );
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(done.get(), 1);
+}
+
+#[test]
+fn mut_event_handler_struct() {
+    let libwayland = Libwayland::open().unwrap();
+    let con = libwayland.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"abc");
+    let root = get_root(&queue);
+    let dummy = root.create_dummy();
+    let pongs = Rc::new(Cell::new(0));
+    proxy::set_event_handler_local_mut(
+        &root,
+        Eh {
+            pongs: 0,
+            out: pongs.clone(),
+        },
+    );
+    root.ping_dummy(&dummy);
+    root.ping_dummy(&dummy);
+    queue.dispatch_roundtrip_blocking().unwrap();
+    assert_eq!(pongs.get(), 2);
+
+    struct Eh {
+        pongs: u32,
+        out: Rc<Cell<u32>>,
+    }
+    impl WlRootEventHandlerMut for Eh {
+        fn pong_dummy(&mut self, _slf: &WlRootRef, _id: Option<&WlDummyRef>) {
+            self.pongs += 1;
+            self.out.set(self.pongs);
+        }
+    }
+}
+
+#[test]
+fn scope_mut_event_handler() {
+    let libwayland = Libwayland::open().unwrap();
+    let con = libwayland.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"abc");
+    let sync = queue.display::<WlDisplay>().sync();
+    let mut done = 0;
+    queue.dispatch_scope_blocking(|scope| {
+        scope.set_event_handler_local_mut(&sync, WlCallback::on_done_mut(|_, _| done += 1));
+        queue.dispatch_roundtrip_blocking().unwrap();
+    });
+    assert_eq!(done, 1);
+}
+
+#[test]
+#[should_panic(
+    expected = "A mutable event handler was invoked while it was already handling an event"
+)]
+fn mut_event_handler_reentrant() {
+    let libwayland = Libwayland::open().unwrap();
+    let con = libwayland.connect_to_default_display().unwrap();
+    let queue = con.create_local_queue(c"abc");
+    let root = get_root(&queue);
+    let dummy = root.create_dummy();
+    proxy::set_event_handler_local_mut(&root, Eh(queue.clone()));
+    root.ping_dummy(&dummy);
+    root.ping_dummy(&dummy);
+    queue.dispatch_roundtrip_blocking().unwrap();
+
+    struct Eh(Queue);
+    impl WlRootEventHandlerMut for Eh {
+        fn pong_dummy(&mut self, _slf: &WlRootRef, _id: Option<&WlDummyRef>) {
+            self.0.dispatch_pending().unwrap();
+        }
+    }
+}
+
 #[test]
 fn construct_from_ref() {
     let lib = Libwayland::open().unwrap();
@@ -591,3 +708,30 @@ fn dispatch_lock() {
     drop(lock);
     thread.join().unwrap();
 }
+
+#[test]
+fn interface_info() {
+    use crate::{
+        info::ArgType,
+        test_protocols::{PROTOCOLS, core::wl_keyboard, interface_info},
+    };
+
+    assert_eq!(PROTOCOLS.len(), 1);
+    assert_eq!(PROTOCOLS[0].name, "core");
+    for interface in PROTOCOLS[0].interfaces {
+        assert!(ptr::eq(interface_info(interface.name).unwrap(), *interface));
+    }
+    assert!(interface_info("wl_compositor").is_none());
+
+    let info = interface_info("wl_keyboard").unwrap();
+    assert!(ptr::eq(info, &wl_keyboard::INFO));
+    assert_eq!(info.protocol, "core");
+    let key = info.events.iter().find(|e| e.name == "key").unwrap();
+    let state = key.args.iter().find(|a| a.name == "state").unwrap();
+    assert_eq!(state.ty, ArgType::Uint);
+    assert_eq!(state.enum_, Some("key_state"));
+    let key_state = info.enum_("key_state").unwrap();
+    assert!(!key_state.bitfield);
+    assert_eq!(key_state.format(1).to_string(), "pressed");
+    assert_eq!(key_state.format(100).to_string(), "100");
+}
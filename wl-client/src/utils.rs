@@ -8,3 +8,4 @@ pub(crate) mod reentrant_mutex;
 pub(crate) mod sync_cell;
 pub(crate) mod sync_ptr;
 pub(crate) mod thread_id;
+pub(crate) mod trace;
@@ -1,5 +1,5 @@
 use {
-    crate::utils::{eventfd::Eventfd, os_error::OsError},
+    crate::utils::{eventfd::Eventfd, os_error::OsError, trace},
     io::ErrorKind,
     mio::{Events, Interest, Token, unix::SourceFd},
     parking_lot::Mutex,
@@ -53,6 +53,7 @@ impl Poller {
                 .name("wl-client-poll".to_string())
                 .spawn(move || {
                     if let Err(e) = poll_thread(con, &data, eventfd2) {
+                        trace::connection_error(&e);
                         let d = &mut *data.lock();
                         d.last_error = Some(e.into());
                         d.readable_serial += 1;
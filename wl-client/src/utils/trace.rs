@@ -0,0 +1,104 @@
+//! Instrumentation for the optional `tracing` feature.
+//!
+//! All functions in this module are no-ops unless the feature is enabled.
+
+#![cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+
+use {
+    crate::ffi::{wl_interface, wl_message},
+    std::{ffi::CStr, io},
+};
+
+/// A span covering a single queue dispatch.
+pub(crate) struct DispatchSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+}
+
+/// A span covering a single event handler invocation.
+pub(crate) struct HandlerSpan {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+}
+
+impl DispatchSpan {
+    /// Enters a span for a dispatch of the queue with the given name.
+    #[inline]
+    pub(crate) fn enter(queue: &CStr) -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::debug_span!(
+                "dispatch_pending",
+                queue = %queue.to_string_lossy(),
+                events = tracing::field::Empty,
+            )
+            .entered(),
+        }
+    }
+
+    /// Records the result of the dispatch.
+    #[inline]
+    pub(crate) fn finish(self, res: &io::Result<u64>) {
+        #[cfg(feature = "tracing")]
+        match res {
+            Ok(n) => {
+                self.span.record("events", n);
+            }
+            Err(e) => connection_error(e),
+        }
+    }
+}
+
+impl HandlerSpan {
+    /// Enters a span for an event handler invocation.
+    ///
+    /// # Safety
+    ///
+    /// - `interface` must be a valid interface.
+    /// - `message` must be a valid pointer to a message of that interface.
+    #[inline]
+    pub(crate) unsafe fn enter(interface: &wl_interface, message: *const wl_message) -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            _span: tracing::trace_span!(
+                "event_handler",
+                // SAFETY: The requirements are forwarded to the caller.
+                interface = %unsafe { CStr::from_ptr(interface.name) }.to_string_lossy(),
+                // SAFETY: The requirements are forwarded to the caller.
+                event = %unsafe { CStr::from_ptr((*message).name) }.to_string_lossy(),
+            )
+            .entered(),
+        }
+    }
+}
+
+/// Reports that a flush could not complete because the socket buffer is full.
+#[inline]
+pub(crate) fn flush_would_block() {
+    #[cfg(feature = "tracing")]
+    tracing::debug!("flush would block, waiting for the socket to become writable");
+}
+
+/// Reports a fatal flush error.
+#[inline]
+pub(crate) fn flush_error(err: &io::Error) {
+    #[cfg(feature = "tracing")]
+    tracing::error!(error = %err, "could not flush the connection");
+}
+
+/// Reports that the read thread has read from the socket.
+#[inline]
+pub(crate) fn read_events(res: Result<(), &io::Error>) {
+    #[cfg(feature = "tracing")]
+    match res {
+        Ok(()) => tracing::trace!("read thread read events"),
+        Err(err) => tracing::error!(error = %err, "read thread could not read events"),
+    }
+}
+
+/// Reports a fatal connection error.
+#[inline]
+pub(crate) fn connection_error(err: &io::Error) {
+    #[cfg(feature = "tracing")]
+    tracing::error!(error = %err, "connection error");
+}